@@ -0,0 +1,183 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: Boundaries are traced along the directed edges between grid cells, keeping the
+region on the right-hand side, so that outer rings are returned in clockwise order and
+holes in counter-clockwise order, as the Shapefile format requires. Where two cells of a
+region only touch at a corner, the trace turns right, which keeps each ring simple.
+*/
+
+use std::collections::HashMap;
+
+/// The rings that bound all of the grid cells sharing one region ID.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GridPolygon {
+    /// The region ID.
+    pub id: i64,
+    /// Closed rings of cell-corner vertices, as (row, column) pairs. Outer rings are in
+    /// clockwise order and holes in counter-clockwise order, viewed with row zero at the top.
+    pub rings: Vec<Vec<(isize, isize)>>,
+}
+
+impl GridPolygon {
+    /// Returns true if the ring is a hole, i.e. its vertices are in counter-clockwise order.
+    pub fn is_hole(&self, ring: usize) -> bool {
+        // With rows increasing downwards, a positive signed area is clockwise.
+        let points = &self.rings[ring];
+        let mut area = 0isize;
+        for i in 0..points.len() - 1 {
+            area += points[i].1 * points[i + 1].0 - points[i + 1].1 * points[i].0;
+        }
+        area < 0
+    }
+}
+
+/// Traces the boundaries of the regions of a grid, returning one polygon for each region ID,
+/// in the order that the regions are first encountered scanning the grid by rows. The `id`
+/// function returns the region ID of a cell; cells with an ID of zero are background and are
+/// not traced. All of the cells sharing an ID are dissolved into a single polygon, which may
+/// have several outer rings and holes.
+pub fn trace_grid_polygons<F>(rows: isize, columns: isize, id: F) -> Vec<GridPolygon>
+where
+    F: Fn(isize, isize) -> i64,
+{
+    // Directions: 0 = east, 1 = south, 2 = west, 3 = north.
+    let dr = [0isize, 1, 0, -1];
+    let dc = [1isize, 0, -1, 0];
+    let value = |row: isize, col: isize| -> i64 {
+        if row < 0 || col < 0 || row >= rows || col >= columns {
+            0
+        } else {
+            id(row, col)
+        }
+    };
+    // Returns the ID of the region on the right of the edge leaving the vertex in the
+    // direction, or zero if there is no boundary edge there.
+    let edge = |row: isize, col: isize, dir: usize| -> i64 {
+        let (inside, outside) = match dir {
+            0 => ((row, col), (row - 1, col)),
+            1 => ((row, col - 1), (row, col)),
+            2 => ((row - 1, col - 1), (row, col - 1)),
+            _ => ((row - 1, col), (row - 1, col - 1)),
+        };
+        let z = value(inside.0, inside.1);
+        if z != 0 && z != value(outside.0, outside.1) {
+            z
+        } else {
+            0
+        }
+    };
+
+    let mut polygons: Vec<GridPolygon> = vec![];
+    let mut polygon_index: HashMap<i64, usize> = HashMap::new();
+    let mut visited = vec![0u8; ((rows + 1) * (columns + 1)) as usize];
+    for row in 0..=rows {
+        for col in 0..=columns {
+            for start_dir in 0..4 {
+                if visited[(row * (columns + 1) + col) as usize] & (1 << start_dir) != 0 {
+                    continue;
+                }
+                let z = edge(row, col, start_dir);
+                if z == 0 {
+                    continue;
+                }
+                let mut ring = vec![(row, col)];
+                let (mut r, mut c, mut dir) = (row, col, start_dir);
+                loop {
+                    visited[(r * (columns + 1) + c) as usize] |= 1 << dir;
+                    r += dr[dir];
+                    c += dc[dir];
+                    // Prefer turning right, then going straight, then turning left.
+                    let mut next = dir;
+                    for turn in &[1usize, 0, 3] {
+                        let d = (dir + turn) % 4;
+                        if edge(r, c, d) == z {
+                            next = d;
+                            break;
+                        }
+                    }
+                    if next != dir {
+                        ring.push((r, c));
+                    }
+                    if r == row && c == col && next == start_dir {
+                        break;
+                    }
+                    dir = next;
+                }
+                if ring[ring.len() - 1] != ring[0] {
+                    ring.push(ring[0]);
+                }
+                let i = *polygon_index.entry(z).or_insert_with(|| {
+                    polygons.push(GridPolygon {
+                        id: z,
+                        rings: vec![],
+                    });
+                    polygons.len() - 1
+                });
+                polygons[i].rings.push(ring);
+            }
+        }
+    }
+    polygons
+}
+
+#[cfg(test)]
+mod test {
+    use super::trace_grid_polygons;
+
+    fn grid<'a>(data: &'a [&'a [i64]]) -> impl Fn(isize, isize) -> i64 + 'a {
+        move |row, col| data[row as usize][col as usize]
+    }
+
+    #[test]
+    fn test_single_cell() {
+        let data: &[&[i64]] = &[&[7]];
+        let polygons = trace_grid_polygons(1, 1, grid(data));
+        assert_eq!(polygons.len(), 1);
+        assert_eq!(polygons[0].id, 7);
+        assert_eq!(
+            polygons[0].rings,
+            vec![vec![(0, 0), (0, 1), (1, 1), (1, 0), (0, 0)]]
+        );
+        assert!(!polygons[0].is_hole(0));
+    }
+
+    #[test]
+    fn test_hole() {
+        let data: &[&[i64]] = &[&[1, 1, 1], &[1, 0, 1], &[1, 1, 1]];
+        let polygons = trace_grid_polygons(3, 3, grid(data));
+        assert_eq!(polygons.len(), 1);
+        assert_eq!(polygons[0].rings.len(), 2);
+        assert_eq!(
+            polygons[0].rings[0],
+            vec![(0, 0), (0, 3), (3, 3), (3, 0), (0, 0)]
+        );
+        assert!(!polygons[0].is_hole(0));
+        assert_eq!(
+            polygons[0].rings[1],
+            vec![(1, 1), (2, 1), (2, 2), (1, 2), (1, 1)]
+        );
+        assert!(polygons[0].is_hole(1));
+    }
+
+    #[test]
+    fn test_dissolved_regions() {
+        // The two cells of region 2 only touch diagonally, and region 3 is split in two.
+        let data: &[&[i64]] = &[&[2, 3, 3], &[3, 2, 0], &[3, 0, 0]];
+        let polygons = trace_grid_polygons(3, 3, grid(data));
+        assert_eq!(polygons.len(), 2);
+        assert_eq!(polygons[0].id, 2);
+        assert_eq!(polygons[0].rings.len(), 2);
+        assert_eq!(polygons[1].id, 3);
+        assert_eq!(polygons[1].rings.len(), 2);
+        for polygon in &polygons {
+            for ring in 0..polygon.rings.len() {
+                assert!(!polygon.is_hole(ring));
+            }
+        }
+    }
+}
@@ -8,6 +8,7 @@ License: MIT
 // private sub-module defined in other files
 mod convex_hull;
 mod delaunay_triangulation;
+mod grid_polygons;
 mod is_clockwise_order;
 mod line_ops;
 // mod lzw;
@@ -20,6 +21,7 @@ mod smallest_enclosing_circle;
 // exports identifiers from private sub-modules in the current module namespace
 pub use self::convex_hull::convex_hull;
 pub use self::delaunay_triangulation::{triangulate, Triangulation};
+pub use self::grid_polygons::{trace_grid_polygons, GridPolygon};
 pub use self::is_clockwise_order::is_clockwise_order;
 pub use self::line_ops::{
     do_polylines_intersect, find_line_intersections, find_split_points_at_line_intersections,
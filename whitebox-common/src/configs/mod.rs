@@ -1,62 +1,428 @@
-use serde_json;
 use serde::{Deserialize, Serialize};
+use serde_json;
+use serde_json::Value;
+use std::cell::RefCell;
+use std::env;
 use std::fs;
 use std::fs::File;
-use std::io::Error;
 use std::io::prelude::*;
+use std::io::{Error, ErrorKind};
 use std::path;
+use std::path::PathBuf;
+use std::sync::Mutex;
 
-/// A structure to hold environment settings. Backed by settings.json file in same directory
+/// A structure to hold environment settings.
+///
+/// The settings of a run are resolved in layers, each of which overrides the one before:
+/// the defaults, the settings file (settings.json in the current directory, or the file
+/// named by the WBT_CONFIG_FILE environment variable), the environment variables named
+/// after each setting (e.g. WBT_MAX_PROCS), and the settings given on the command line.
+/// Settings are only written to the settings file by an explicit call to `save_configs`.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Configs {
     pub verbose_mode: bool,
     pub working_directory: String,
     pub compress_rasters: bool,
+    /// The method used to compress GeoTIFF output when `compress_rasters` is true; one of
+    /// 'deflate' (the default), 'lzw', or 'packbits'.
+    #[serde(default = "default_compression_method")]
+    pub compression_method: String,
+    /// The predictor applied to GeoTIFF output before LZW or DEFLATE compression; one of 'none'
+    /// (the default), 'horizontal', or 'floating_point'. The floating-point predictor applies
+    /// only to floating-point rasters; integer rasters use the horizontal predictor instead.
+    #[serde(default = "default_predictor")]
+    pub predictor: String,
+    /// Whether GeoTIFF output is divided into square tiles, rather than into strips.
+    #[serde(default)]
+    pub tiled_rasters: bool,
+    /// The width and height, in cells, of the tiles of tiled GeoTIFF output. This must be a
+    /// multiple of 16.
+    #[serde(default = "default_tile_size")]
+    pub tile_size: usize,
+    /// Whether GeoTIFF output is written as a Cloud-Optimized GeoTIFF (COG), i.e. tiled, with
+    /// internal overviews, and with all of the IFDs preceding the image data.
+    #[serde(default)]
+    pub cloud_optimized_rasters: bool,
+    /// The resampling method used to create the overviews of Cloud-Optimized GeoTIFF output;
+    /// one of 'nearest' (the default), 'average', 'mode', 'min', or 'max'.
+    #[serde(default = "default_overview_resampling")]
+    pub overview_resampling: String,
     pub max_procs: isize,
+    /// The maximum amount of memory, in megabytes, that tools supporting block-cached raster
+    /// access may devote to raster data; rasters that would exceed it are streamed from disk
+    /// instead of being read into memory. Values less than or equal to zero indicate no limit.
+    #[serde(default = "default_max_memory_mb")]
+    pub max_memory_mb: isize,
+    /// The file extension, and so the format, of output rasters whose file names don't have
+    /// one, e.g. 'tif' (the default) or 'dep'.
+    #[serde(default = "default_raster_format")]
+    pub default_raster_format: String,
+    /// The nodata value of rasters that don't specify one.
+    #[serde(default = "default_nodata")]
+    pub default_nodata: f64,
+    /// The directory that holds the scratch files of rasters written block-by-block. If it
+    /// is empty (the default), they are written beside the output files.
+    #[serde(default)]
+    pub temp_directory: String,
+    /// Whether the provenance records of tool outputs include the SHA-256 hash of each input
+    /// file. Hashing reads each input in full before the tool runs, so it is off by default,
+    /// in which case inputs are described by their modification times alone.
+    #[serde(default)]
+    pub hash_provenance_inputs: bool,
+}
+
+fn default_compression_method() -> String {
+    "deflate".to_string()
+}
+
+fn default_predictor() -> String {
+    "none".to_string()
+}
+
+fn default_tile_size() -> usize {
+    256
+}
+
+fn default_overview_resampling() -> String {
+    "nearest".to_string()
+}
+
+fn default_max_memory_mb() -> isize {
+    -1
+}
+
+fn default_raster_format() -> String {
+    "tif".to_string()
+}
+
+fn default_nodata() -> f64 {
+    -32768.0
 }
 
 impl Configs {
     pub fn new() -> Configs {
-        Configs{ 
+        Configs {
             verbose_mode: true,
             working_directory: String::new(),
             compress_rasters: true,
-            max_procs: -1
+            compression_method: default_compression_method(),
+            predictor: default_predictor(),
+            tiled_rasters: false,
+            tile_size: default_tile_size(),
+            cloud_optimized_rasters: false,
+            overview_resampling: default_overview_resampling(),
+            max_procs: -1,
+            max_memory_mb: -1,
+            default_raster_format: default_raster_format(),
+            default_nodata: default_nodata(),
+            temp_directory: String::new(),
+            hash_provenance_inputs: false,
         }
     }
+
+    /// Sets a setting from its string form, e.g. `set("max_procs", "4")`, returning an
+    /// error of kind `InvalidInput` if the setting doesn't exist or the value is invalid.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        let invalid = |msg: String| Error::new(ErrorKind::InvalidInput, msg);
+        let mut values = match serde_json::to_value(&*self)? {
+            Value::Object(values) => values,
+            _ => unreachable!(),
+        };
+        let new_value = match values.get(key) {
+            Some(Value::Bool(_)) => match value.trim().to_lowercase().as_str() {
+                "true" | "t" | "1" | "yes" | "" => Value::Bool(true),
+                "false" | "f" | "0" | "no" => Value::Bool(false),
+                _ => {
+                    return Err(invalid(format!(
+                        "Invalid value {} for setting {}; expected true or false.",
+                        value, key
+                    )))
+                }
+            },
+            Some(Value::Number(n)) => {
+                let number = if n.is_f64() {
+                    value
+                        .trim()
+                        .parse::<f64>()
+                        .ok()
+                        .and_then(serde_json::Number::from_f64)
+                } else {
+                    value
+                        .trim()
+                        .parse::<i64>()
+                        .ok()
+                        .map(serde_json::Number::from)
+                };
+                match number {
+                    Some(number) => Value::Number(number),
+                    None => {
+                        return Err(invalid(format!(
+                            "Invalid value {} for setting {}; expected a number.",
+                            value, key
+                        )))
+                    }
+                }
+            }
+            Some(_) => Value::String(value.to_string()),
+            None => return Err(invalid(format!("Unrecognized setting {}.", key))),
+        };
+        values.insert(key.to_string(), new_value);
+        *self = serde_json::from_value(Value::Object(values)).map_err(|e| {
+            invalid(format!(
+                "Invalid value {} for setting {}: {}",
+                value, key, e
+            ))
+        })?;
+        Ok(())
+    }
+
+    /// Returns each of the settings in its string form, e.g. `("max_procs", "4")`, from which
+    /// `set` restores it.
+    pub fn to_settings(&self) -> Vec<(String, String)> {
+        let values = match serde_json::to_value(self) {
+            Ok(Value::Object(values)) => values,
+            _ => return vec![],
+        };
+        values
+            .into_iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    Value::String(s) => s,
+                    v => v.to_string(),
+                };
+                (key, value)
+            })
+            .collect()
+    }
+
+    /// Returns the environment variables that pass these settings on to another process,
+    /// e.g. to a plugin tool.
+    pub fn to_env_vars(&self) -> Vec<(String, String)> {
+        self.to_settings()
+            .into_iter()
+            .map(|(key, value)| (env_var_name(&key), value))
+            .collect()
+    }
+
+    // Applies the settings given by environment variables, which are looked up by `lookup`.
+    fn apply_env_vars<F: Fn(&str) -> Option<String>>(&mut self, lookup: F) -> Result<(), Error> {
+        let keys: Vec<String> = match serde_json::to_value(&*self)? {
+            Value::Object(values) => values.keys().cloned().collect(),
+            _ => vec![],
+        };
+        for key in keys {
+            let var = env_var_name(&key);
+            if let Some(value) = lookup(&var) {
+                self.set(&key, &value).map_err(|e| {
+                    Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("{} (from the {} environment variable)", e, var),
+                    )
+                })?;
+            }
+        }
+        Ok(())
+    }
 }
 
-pub fn get_configs() -> std::result::Result<Configs, Error> {
-    let mut exe_path = std::env::current_dir()?.to_str().unwrap_or("No exe path found.").to_string();
+/// The environment variable that holds a setting, e.g. WBT_MAX_PROCS for max_procs.
+pub fn env_var_name(key: &str) -> String {
+    format!("WBT_{}", key.to_uppercase())
+}
+
+// The settings given on the command line, which override all of the other layers.
+static OVERRIDES: Mutex<Vec<(String, String)>> = Mutex::new(vec![]);
+
+/// Sets the settings given on the command line, e.g. `("max_procs", "4")`, which take
+/// precedence over all other settings for the rest of the process. They are validated
+/// but never written to the settings file.
+pub fn set_overrides(overrides: Vec<(String, String)>) -> Result<(), Error> {
+    let mut configs = Configs::new();
+    for (key, value) in &overrides {
+        configs.set(key, value)?;
+    }
+    *OVERRIDES.lock().unwrap_or_else(|e| e.into_inner()) = overrides;
+    Ok(())
+}
+
+thread_local! {
+    // Settings that apply only to the current thread, e.g. to one of the jobs of a batch.
+    static THREAD_OVERRIDES: RefCell<Vec<(String, String)>> = const { RefCell::new(vec![]) };
+}
+
+/// Sets settings that take precedence over all others, including those given on the
+/// command line, but only for tools run on the current thread. This allows, e.g., the
+/// jobs of a batch that run in parallel to share out the processors between them.
+pub fn set_thread_overrides(overrides: Vec<(String, String)>) -> Result<(), Error> {
+    let mut configs = Configs::new();
+    for (key, value) in &overrides {
+        configs.set(key, value)?;
+    }
+    THREAD_OVERRIDES.with(|o| *o.borrow_mut() = overrides);
+    Ok(())
+}
+
+/// Returns the path of the settings file: the value of the WBT_CONFIG_FILE environment
+/// variable if it is set, or otherwise settings.json in the current directory.
+pub fn settings_file() -> Result<PathBuf, Error> {
+    if let Ok(file) = env::var("WBT_CONFIG_FILE") {
+        if !file.trim().is_empty() {
+            return Ok(PathBuf::from(file));
+        }
+    }
+    let mut exe_path = std::env::current_dir()?
+        .to_str()
+        .unwrap_or("No exe path found.")
+        .to_string();
     let plugin_dir = path::MAIN_SEPARATOR.to_string() + "plugins";
     if exe_path.ends_with(&plugin_dir) {
         exe_path = exe_path.replace(&plugin_dir, "");
     }
-    let config_file = exe_path + &path::MAIN_SEPARATOR.to_string() + "settings.json";
-    // let contents = fs::read_to_string(config_file).expect("Failed to open config_file.json file.");
-    // let configs: Configs = serde_json::from_str(&contents).expect("Failed to parse config_file.json file.");
-    let configs: Configs = match fs::read_to_string(config_file) {
-        Ok(contents) => {
-            serde_json::from_str(&contents).expect("Failed to parse config_file.json file.")
-        }, 
-        Err(_) => {
-            Configs::new()
+    Ok(PathBuf::from(exe_path).join("settings.json"))
+}
+
+/// Reads the settings file, without the environment variables and command-line settings
+/// that override it. The defaults are used if there is no settings file.
+pub fn read_settings_file() -> Result<Configs, Error> {
+    let config_file = settings_file()?;
+    match fs::read_to_string(&config_file) {
+        Ok(contents) => serde_json::from_str(&contents).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "Error parsing the settings file {}: {}",
+                    config_file.display(),
+                    e
+                ),
+            )
+        }),
+        Err(_) => Ok(Configs::new()),
+    }
+}
+
+/// Returns the settings of the current run: those of the settings file, overridden by
+/// any environment variables, then by any settings given on the command line, and then
+/// by any set for the current thread.
+pub fn get_configs() -> std::result::Result<Configs, Error> {
+    let mut configs = read_settings_file()?;
+    configs.apply_env_vars(|var| env::var(var).ok())?;
+    for (key, value) in OVERRIDES.lock().unwrap_or_else(|e| e.into_inner()).iter() {
+        configs.set(key, value)?;
+    }
+    THREAD_OVERRIDES.with(|o| -> Result<(), Error> {
+        for (key, value) in o.borrow().iter() {
+            configs.set(key, value)?;
         }
-    };
+        Ok(())
+    })?;
     Ok(configs)
 }
 
+/// Writes settings to the settings file.
 pub fn save_configs<'a>(configs: &Configs) -> std::result::Result<(), Error> {
-    let configs_json = serde_json::to_string_pretty(&configs).expect("Error converting Configs object to JSON.");
-    let mut exe_path = std::env::current_dir()?.to_str().unwrap_or("No exe path found.").to_string();
-    let plugin_dir = path::MAIN_SEPARATOR.to_string() + "plugins";
-    if exe_path.ends_with(&plugin_dir) {
-        exe_path = exe_path.replace(&plugin_dir, "");
-    }
-    let config_file = exe_path + &path::MAIN_SEPARATOR.to_string() + "settings.json";
+    let configs_json =
+        serde_json::to_string_pretty(&configs).expect("Error converting Configs object to JSON.");
+    let config_file = settings_file()?;
     let mut file = File::create(config_file).expect("Error creating output settings.json file.");
-    file.write_all(configs_json.as_bytes()).expect("Error writing to output settings.json file.");
+    file.write_all(configs_json.as_bytes())
+        .expect("Error writing to output settings.json file.");
 
     Ok(())
 }
 
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_set() {
+        let mut configs = Configs::new();
+        configs.set("max_procs", "4").unwrap();
+        configs.set("compress_rasters", "false").unwrap();
+        configs.set("default_nodata", "-9999").unwrap();
+        configs.set("default_raster_format", "dep").unwrap();
+        assert_eq!(configs.max_procs, 4);
+        assert!(!configs.compress_rasters);
+        assert_eq!(configs.default_nodata, -9999.0);
+        assert_eq!(configs.default_raster_format, "dep");
+
+        assert_eq!(
+            configs.set("max_procs", "four").unwrap_err().kind(),
+            ErrorKind::InvalidInput
+        );
+        assert_eq!(
+            configs.set("tile_size", "-1").unwrap_err().kind(),
+            ErrorKind::InvalidInput
+        );
+        assert_eq!(
+            configs.set("no_such_setting", "1").unwrap_err().kind(),
+            ErrorKind::InvalidInput
+        );
+        assert_eq!(configs.max_procs, 4);
+    }
+
+    #[test]
+    fn test_env_vars() {
+        let mut configs = Configs::new();
+        configs.set("max_procs", "2").unwrap();
+        configs.set("temp_directory", "/tmp/wbt").unwrap();
+        let vars: HashMap<String, String> = configs.to_env_vars().into_iter().collect();
+        assert_eq!(vars["WBT_MAX_PROCS"], "2");
+        assert_eq!(vars["WBT_TEMP_DIRECTORY"], "/tmp/wbt");
+
+        // the settings pass from one process to another through the environment
+        let mut passed = Configs::new();
+        passed.apply_env_vars(|var| vars.get(var).cloned()).unwrap();
+        assert_eq!(passed.max_procs, 2);
+        assert_eq!(passed.temp_directory, "/tmp/wbt");
+        assert_eq!(passed.default_nodata, -32768.0);
+
+        let bad = |var: &str| {
+            if var == "WBT_MAX_PROCS" {
+                Some("x".to_string())
+            } else {
+                None
+            }
+        };
+        assert!(Configs::new().apply_env_vars(bad).is_err());
+    }
+
+    #[test]
+    fn test_to_settings() {
+        let mut configs = Configs::new();
+        configs.set("max_procs", "3").unwrap();
+        configs.set("default_nodata", "-9999.5").unwrap();
+        configs.set("compress_rasters", "false").unwrap();
+        let mut restored = Configs::new();
+        for (key, value) in configs.to_settings() {
+            restored.set(&key, &value).unwrap();
+        }
+        assert_eq!(restored.to_settings(), configs.to_settings());
+        assert_eq!(restored.max_procs, 3);
+        assert_eq!(restored.default_nodata, -9999.5);
+        assert!(!restored.compress_rasters);
+    }
+
+    #[test]
+    fn test_thread_overrides() {
+        let max_procs = get_configs().unwrap().max_procs;
+        std::thread::spawn(move || {
+            set_thread_overrides(vec![("max_procs".to_string(), "1".to_string())]).unwrap();
+            assert_eq!(get_configs().unwrap().max_procs, 1);
+            assert!(set_thread_overrides(vec![("max_procs".to_string(), "x".to_string())])
+                .is_err());
+            set_thread_overrides(vec![]).unwrap();
+            assert_eq!(get_configs().unwrap().max_procs, max_procs);
+        })
+        .join()
+        .unwrap();
+        // other threads are unaffected
+        assert_eq!(
+            std::thread::spawn(|| get_configs().unwrap().max_procs)
+                .join()
+                .unwrap(),
+            get_configs().unwrap().max_procs
+        );
+    }
+}
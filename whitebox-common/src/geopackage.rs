@@ -0,0 +1,454 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+
+Notes: A GeoPackage (OGC 12-128r18) is an SQLite database holding any number of vector
+feature tables and raster tile pyramids, each of which is listed within the gpkg_contents
+table. This module manages the database and the metadata tables that are shared by the
+vector and raster drivers; the feature and tile tables themselves are read and written by
+the drivers within the whitebox_vector and whitebox_raster crates. SQLite is compiled from
+the bundled source, and so no system library is needed.
+*/
+
+use crate::spatial_ref_system::{epsg_from_wkt, esri_wkt_from_epsg};
+use rusqlite::{params, Connection, OpenFlags, OptionalExtension, NO_PARAMS};
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+
+/// The SQLite application_id of a GeoPackage, the ASCII string 'GPKG'.
+pub const GPKG_APPLICATION_ID: i32 = 0x4750_4B47;
+/// The SQLite user_version of a GeoPackage, identifying version 1.2 of the standard.
+pub const GPKG_USER_VERSION: i32 = 10200;
+
+/// Splits a file name of the form `file.gpkg:layer` into the database file name and the name
+/// of the layer (table) within it. The layer is `None` if the file name does not name one.
+pub fn split_layer_name(file_name: &str) -> (String, Option<String>) {
+    // The layer separator follows the extension, which keeps Windows drive letters intact.
+    if let Some(i) = file_name
+        .as_bytes()
+        .windows(6)
+        .rposition(|w| w.eq_ignore_ascii_case(b".gpkg:"))
+    {
+        let layer = &file_name[i + 6..];
+        if !layer.is_empty() {
+            return (file_name[..i + 5].to_string(), Some(layer.to_string()));
+        }
+        return (file_name[..i + 5].to_string(), None);
+    }
+    (file_name.to_string(), None)
+}
+
+/// Returns true if the file name, excluding any layer name, has the .gpkg extension.
+pub fn is_geopackage(file_name: &str) -> bool {
+    let (file_name, _) = split_layer_name(file_name);
+    Path::new(&file_name)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("gpkg"))
+}
+
+/// Converts an SQLite error into an I/O error.
+pub fn sql_error(e: rusqlite::Error) -> Error {
+    Error::other(format!("GeoPackage error: {}", e))
+}
+
+/// Quotes a table or column name for use within an SQL statement.
+pub fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// An entry of the gpkg_contents table, describing one layer of a GeoPackage.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ContentsEntry {
+    pub table_name: String,
+    /// 'features', 'tiles', or '2d-gridded-coverage'.
+    pub data_type: String,
+    pub identifier: String,
+    pub description: String,
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+    pub srs_id: i32,
+}
+
+/// An open GeoPackage database.
+pub struct GeoPackage {
+    pub conn: Connection,
+}
+
+impl GeoPackage {
+    /// Opens an existing GeoPackage for reading.
+    pub fn open(file_name: &str) -> Result<GeoPackage, Error> {
+        if !Path::new(file_name).exists() {
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                format!("The GeoPackage {} does not exist.", file_name),
+            ));
+        }
+        let conn = Connection::open_with_flags(file_name, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .map_err(sql_error)?;
+        let gpkg = GeoPackage { conn };
+        if !gpkg.table_exists("gpkg_contents")? {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "{} is not a GeoPackage; it has no gpkg_contents table.",
+                    file_name
+                ),
+            ));
+        }
+        Ok(gpkg)
+    }
+
+    /// Opens a GeoPackage for writing, creating the file and the required metadata tables if
+    /// they do not already exist.
+    pub fn create(file_name: &str) -> Result<GeoPackage, Error> {
+        let conn = Connection::open(file_name).map_err(sql_error)?;
+        conn.execute_batch(&format!(
+            "PRAGMA application_id = {};
+            PRAGMA user_version = {};
+            CREATE TABLE IF NOT EXISTS gpkg_spatial_ref_sys (
+                srs_name TEXT NOT NULL,
+                srs_id INTEGER NOT NULL PRIMARY KEY,
+                organization TEXT NOT NULL,
+                organization_coordsys_id INTEGER NOT NULL,
+                definition TEXT NOT NULL,
+                description TEXT
+            );
+            CREATE TABLE IF NOT EXISTS gpkg_contents (
+                table_name TEXT NOT NULL PRIMARY KEY,
+                data_type TEXT NOT NULL,
+                identifier TEXT UNIQUE,
+                description TEXT DEFAULT '',
+                last_change DATETIME NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ','now')),
+                min_x DOUBLE,
+                min_y DOUBLE,
+                max_x DOUBLE,
+                max_y DOUBLE,
+                srs_id INTEGER,
+                CONSTRAINT fk_gc_r_srs_id FOREIGN KEY (srs_id) REFERENCES gpkg_spatial_ref_sys(srs_id)
+            );
+            INSERT OR IGNORE INTO gpkg_spatial_ref_sys VALUES
+                ('Undefined cartesian SRS', -1, 'NONE', -1, 'undefined', 'undefined cartesian coordinate reference system'),
+                ('Undefined geographic SRS', 0, 'NONE', 0, 'undefined', 'undefined geographic coordinate reference system'),
+                ('WGS 84 geodetic', 4326, 'EPSG', 4326, '{}', 'longitude/latitude coordinates in decimal degrees on the WGS 84 spheroid');",
+            GPKG_APPLICATION_ID,
+            GPKG_USER_VERSION,
+            esri_wkt_from_epsg(4326).replace('\'', "''"),
+        ))
+        .map_err(sql_error)?;
+        Ok(GeoPackage { conn })
+    }
+
+    /// Returns true if the database contains a table with the specified name.
+    pub fn table_exists(&self, table_name: &str) -> Result<bool, Error> {
+        self.conn
+            .query_row(
+                "SELECT count(*) FROM sqlite_master WHERE type = 'table' AND name = ?1",
+                params![table_name],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .map_err(sql_error)
+    }
+
+    /// Returns the layers of the GeoPackage having one of the specified data types, in the
+    /// order in which they were added.
+    pub fn contents(&self, data_types: &[&str]) -> Result<Vec<ContentsEntry>, Error> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT table_name, data_type, identifier, description, min_x, min_y, max_x, max_y, srs_id
+                FROM gpkg_contents ORDER BY rowid",
+            )
+            .map_err(sql_error)?;
+        let rows = stmt
+            .query_map(NO_PARAMS, |row| {
+                Ok(ContentsEntry {
+                    table_name: row.get(0)?,
+                    data_type: row.get(1)?,
+                    identifier: row.get::<_, Option<String>>(2)?.unwrap_or_default(),
+                    description: row.get::<_, Option<String>>(3)?.unwrap_or_default(),
+                    min_x: row.get::<_, Option<f64>>(4)?.unwrap_or(f64::NAN),
+                    min_y: row.get::<_, Option<f64>>(5)?.unwrap_or(f64::NAN),
+                    max_x: row.get::<_, Option<f64>>(6)?.unwrap_or(f64::NAN),
+                    max_y: row.get::<_, Option<f64>>(7)?.unwrap_or(f64::NAN),
+                    srs_id: row.get::<_, Option<i32>>(8)?.unwrap_or(-1),
+                })
+            })
+            .map_err(sql_error)?;
+        let mut entries = vec![];
+        for entry in rows {
+            let entry = entry.map_err(sql_error)?;
+            if data_types
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(&entry.data_type))
+            {
+                entries.push(entry);
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Finds the layer with the specified name, or the first layer if no name is given, among
+    /// the layers having one of the specified data types.
+    pub fn find_contents(
+        &self,
+        layer: Option<&str>,
+        data_types: &[&str],
+    ) -> Result<ContentsEntry, Error> {
+        let entries = self.contents(data_types)?;
+        let found = match layer {
+            Some(name) => entries
+                .iter()
+                .find(|e| e.table_name.eq_ignore_ascii_case(name))
+                .cloned(),
+            None => entries.first().cloned(),
+        };
+        found.ok_or_else(|| {
+            let names: Vec<&str> = entries.iter().map(|e| e.table_name.as_str()).collect();
+            let msg = match layer {
+                Some(name) => format!(
+                    "The GeoPackage does not contain a {} layer named '{}'. Available layers: {}",
+                    data_types.join(" or "),
+                    name,
+                    names.join(", ")
+                ),
+                None => format!(
+                    "The GeoPackage does not contain any {} layers.",
+                    data_types.join(" or ")
+                ),
+            };
+            Error::new(ErrorKind::NotFound, msg)
+        })
+    }
+
+    /// Adds a layer to the gpkg_contents table, replacing any existing entry for the table.
+    pub fn add_contents(&self, entry: &ContentsEntry) -> Result<(), Error> {
+        let identifier = if entry.identifier.is_empty() {
+            &entry.table_name
+        } else {
+            &entry.identifier
+        };
+        self.conn
+            .execute(
+                "INSERT OR REPLACE INTO gpkg_contents
+                (table_name, data_type, identifier, description, min_x, min_y, max_x, max_y, srs_id)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                params![
+                    entry.table_name,
+                    entry.data_type,
+                    identifier,
+                    entry.description,
+                    entry.min_x,
+                    entry.min_y,
+                    entry.max_x,
+                    entry.max_y,
+                    entry.srs_id
+                ],
+            )
+            .map_err(sql_error)?;
+        Ok(())
+    }
+
+    /// Removes a layer, i.e. its table and all of the metadata describing it.
+    pub fn delete_layer(&self, table_name: &str) -> Result<(), Error> {
+        // The tables referring to gpkg_contents are cleared before it.
+        for (metadata_table, column) in &[
+            ("gpkg_geometry_columns", "table_name"),
+            ("gpkg_2d_gridded_tile_ancillary", "tpudt_name"),
+            ("gpkg_2d_gridded_coverage_ancillary", "tile_matrix_set_name"),
+            ("gpkg_tile_matrix", "table_name"),
+            ("gpkg_tile_matrix_set", "table_name"),
+            ("gpkg_extensions", "table_name"),
+            ("gpkg_contents", "table_name"),
+        ] {
+            if self.table_exists(metadata_table)? {
+                self.conn
+                    .execute(
+                        &format!(
+                            "DELETE FROM {} WHERE lower({}) = lower(?1)",
+                            metadata_table, column
+                        ),
+                        params![table_name],
+                    )
+                    .map_err(sql_error)?;
+            }
+        }
+        self.conn
+            .execute(
+                &format!("DROP TABLE IF EXISTS {}", quote_identifier(table_name)),
+                NO_PARAMS,
+            )
+            .map_err(sql_error)?;
+        Ok(())
+    }
+
+    /// Registers the use of an extension by a table, creating the gpkg_extensions table if
+    /// needed.
+    pub fn add_extension(
+        &self,
+        table_name: Option<&str>,
+        column_name: Option<&str>,
+        extension_name: &str,
+        definition: &str,
+        scope: &str,
+    ) -> Result<(), Error> {
+        self.conn
+            .execute_batch(
+                "CREATE TABLE IF NOT EXISTS gpkg_extensions (
+                    table_name TEXT,
+                    column_name TEXT,
+                    extension_name TEXT NOT NULL,
+                    definition TEXT NOT NULL,
+                    scope TEXT NOT NULL,
+                    CONSTRAINT ge_tce UNIQUE (table_name, column_name, extension_name)
+                );",
+            )
+            .map_err(sql_error)?;
+        self.conn
+            .execute(
+                "INSERT OR REPLACE INTO gpkg_extensions VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![table_name, column_name, extension_name, definition, scope],
+            )
+            .map_err(sql_error)?;
+        Ok(())
+    }
+
+    /// Returns the WKT definition of a spatial reference system, or an empty string if the
+    /// system is undefined.
+    pub fn get_srs_wkt(&self, srs_id: i32) -> Result<String, Error> {
+        let row: Option<(String, String, i64)> = self
+            .conn
+            .query_row(
+                "SELECT definition, organization, organization_coordsys_id
+                FROM gpkg_spatial_ref_sys WHERE srs_id = ?1",
+                params![srs_id],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .optional()
+            .map_err(sql_error)?;
+        Ok(match row {
+            Some((definition, organization, code)) => {
+                if !definition.trim().is_empty() && definition.trim() != "undefined" {
+                    definition
+                } else if organization.eq_ignore_ascii_case("EPSG") && code > 0 && code < 65536 {
+                    let wkt = esri_wkt_from_epsg(code as u16);
+                    if wkt.starts_with("Unknown") {
+                        String::new()
+                    } else {
+                        wkt
+                    }
+                } else {
+                    String::new()
+                }
+            }
+            None => String::new(),
+        })
+    }
+
+    /// Returns the identifier of the spatial reference system described by the WKT, adding
+    /// the system to the gpkg_spatial_ref_sys table if it is not already present. Systems
+    /// with an EPSG code use the code as their identifier; an empty WKT is the undefined
+    /// cartesian system, -1.
+    pub fn get_srs_id(&self, wkt: &str) -> Result<i32, Error> {
+        let wkt = wkt.trim();
+        if wkt.is_empty() || wkt == "not specified" {
+            return Ok(-1);
+        }
+        if let Some(code) = epsg_from_wkt(wkt) {
+            self.conn
+                .execute(
+                    "INSERT OR IGNORE INTO gpkg_spatial_ref_sys VALUES (?1, ?2, 'EPSG', ?2, ?3, '')",
+                    params![srs_name(wkt), code as i32, wkt],
+                )
+                .map_err(sql_error)?;
+            return Ok(code as i32);
+        }
+        let existing: Option<i32> = self
+            .conn
+            .query_row(
+                "SELECT srs_id FROM gpkg_spatial_ref_sys WHERE definition = ?1",
+                params![wkt],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(sql_error)?;
+        if let Some(srs_id) = existing {
+            return Ok(srs_id);
+        }
+        // User-defined systems are numbered from 100000, above the range of EPSG codes.
+        let srs_id: i32 = self
+            .conn
+            .query_row(
+                "SELECT max(100000, coalesce(max(srs_id) + 1, 0)) FROM gpkg_spatial_ref_sys",
+                NO_PARAMS,
+                |row| row.get(0),
+            )
+            .map_err(sql_error)?;
+        self.conn
+            .execute(
+                "INSERT INTO gpkg_spatial_ref_sys VALUES (?1, ?2, 'NONE', ?2, ?3, '')",
+                params![srs_name(wkt), srs_id, wkt],
+            )
+            .map_err(sql_error)?;
+        Ok(srs_id)
+    }
+}
+
+// The name of a coordinate reference system is the first quoted string of its WKT.
+fn srs_name(wkt: &str) -> String {
+    wkt.split('"').nth(1).unwrap_or("Unknown").to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_layer_name() {
+        assert_eq!(
+            split_layer_name("data.gpkg:roads"),
+            ("data.gpkg".to_string(), Some("roads".to_string()))
+        );
+        assert_eq!(
+            split_layer_name("C:\\data\\Data.GPKG:roads"),
+            ("C:\\data\\Data.GPKG".to_string(), Some("roads".to_string()))
+        );
+        assert_eq!(
+            split_layer_name("data.gpkg"),
+            ("data.gpkg".to_string(), None)
+        );
+        assert_eq!(
+            split_layer_name("C:\\dem.tif"),
+            ("C:\\dem.tif".to_string(), None)
+        );
+        assert!(is_geopackage("data.gpkg:roads"));
+        assert!(!is_geopackage("roads.shp"));
+    }
+
+    #[test]
+    fn test_srs_ids() -> Result<(), Error> {
+        let file_name = std::env::temp_dir()
+            .join("whitebox_common_test_srs.gpkg")
+            .to_string_lossy()
+            .to_string();
+        let _ = std::fs::remove_file(&file_name);
+        let gpkg = GeoPackage::create(&file_name)?;
+        assert_eq!(gpkg.get_srs_id("")?, -1);
+        assert_eq!(gpkg.get_srs_id(&esri_wkt_from_epsg(26917))?, 26917);
+        assert_eq!(gpkg.get_srs_wkt(26917)?, esri_wkt_from_epsg(26917));
+        let custom = "LOCAL_CS[\"Site grid\",UNIT[\"Meter\",1.0]]";
+        assert_eq!(gpkg.get_srs_id(custom)?, 100000);
+        assert_eq!(gpkg.get_srs_id(custom)?, 100000);
+        assert_eq!(gpkg.get_srs_wkt(100000)?, custom);
+        assert_eq!(gpkg.get_srs_wkt(-1)?, "");
+        drop(gpkg);
+        let _ = std::fs::remove_file(&file_name);
+        Ok(())
+    }
+}
@@ -1,6 +1,9 @@
 pub mod algorithms;
 pub mod configs;
+pub mod geopackage;
+pub mod memory_store;
 pub mod plugins;
+pub mod provenance;
 pub mod rendering;
 pub mod spatial_ref_system;
 pub mod structures;
@@ -0,0 +1,117 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: File names beginning with mem:// refer to data that are held in memory rather
+than on disk, so that tools run in the same process, e.g. by a workflow, can pass
+their outputs to one another without writing and re-reading files. Writing a raster,
+vector or LiDAR file with such a name stores a copy of it within a MemoryStore, from
+which it is copied again when it is read. Each of the whitebox_raster, whitebox_vector
+and whitebox_lidar crates has its own store; the stores only exist for the life of
+the process.
+*/
+
+use std::sync::Mutex;
+
+/// The prefix of the names of in-memory files.
+pub const MEMORY_FILE_PREFIX: &str = "mem://";
+
+/// Returns true if the file name refers to an in-memory file, e.g. `mem://filled.tif`.
+pub fn is_memory_file(file_name: &str) -> bool {
+    file_name.starts_with(MEMORY_FILE_PREFIX)
+}
+
+/// A store of in-memory files of type `T`, keyed by file name. It is intended to be
+/// used as a static.
+pub struct MemoryStore<T> {
+    items: Mutex<Vec<(String, T)>>,
+}
+
+impl<T: Clone> MemoryStore<T> {
+    pub const fn new() -> MemoryStore<T> {
+        MemoryStore {
+            items: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Stores an item, replacing any existing item with the same name.
+    pub fn insert(&self, file_name: &str, item: T) {
+        let mut items = self.items.lock().unwrap();
+        match items.iter_mut().find(|(n, _)| n == file_name) {
+            Some(entry) => entry.1 = item,
+            None => items.push((file_name.to_string(), item)),
+        }
+    }
+
+    /// Returns a copy of an item, leaving it within the store.
+    pub fn get(&self, file_name: &str) -> Option<T> {
+        self.items
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(n, _)| n == file_name)
+            .map(|(_, item)| item.clone())
+    }
+
+    pub fn contains(&self, file_name: &str) -> bool {
+        self.items
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|(n, _)| n == file_name)
+    }
+
+    /// Removes an item from the store, returning it.
+    pub fn remove(&self, file_name: &str) -> Option<T> {
+        let mut items = self.items.lock().unwrap();
+        let index = items.iter().position(|(n, _)| n == file_name)?;
+        Some(items.remove(index).1)
+    }
+
+    /// Returns the names of the stored items.
+    pub fn names(&self) -> Vec<String> {
+        self.items
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(n, _)| n.clone())
+            .collect()
+    }
+
+    pub fn clear(&self) {
+        self.items.lock().unwrap().clear();
+    }
+}
+
+impl<T: Clone> Default for MemoryStore<T> {
+    fn default() -> MemoryStore<T> {
+        MemoryStore::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_memory_store() {
+        static STORE: MemoryStore<Vec<f64>> = MemoryStore::new();
+        assert!(is_memory_file("mem://filled.tif"));
+        assert!(!is_memory_file("/data/mem.tif"));
+
+        STORE.insert("mem://a", vec![1.0, 2.0]);
+        STORE.insert("mem://b", vec![3.0]);
+        STORE.insert("mem://a", vec![4.0]);
+        assert_eq!(STORE.get("mem://a"), Some(vec![4.0]));
+        assert!(STORE.contains("mem://b"));
+        assert_eq!(STORE.names(), vec!["mem://a", "mem://b"]);
+        assert_eq!(STORE.remove("mem://b"), Some(vec![3.0]));
+        assert!(!STORE.contains("mem://b"));
+        assert_eq!(STORE.get("mem://c"), None);
+        STORE.clear();
+        assert!(STORE.names().is_empty());
+    }
+}
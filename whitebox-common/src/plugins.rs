@@ -1,6 +1,11 @@
 pub static CORE_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub static RUSTC_VERSION: &str = env!("RUSTC_VERSION");
 
+/// The version of the interface through which the host calls the plugins exported with
+/// `export_plugin!`, i.e. of the `Function` and `PluginRegistrar` traits. It must be
+/// incremented whenever either of them changes.
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
 pub trait Function {
     fn call(&self, args: &[f64]) -> Result<f64, InvocationError>;
 
@@ -22,13 +27,39 @@ impl<S: ToString> From<S> for InvocationError {
     }
 }
 
-#[derive(Copy, Clone)]
-pub struct PluginDeclaration {
+/// The declaration that a plugin library exports, which the host uses to check that the
+/// library was built against the same interface, and with the same compiler and core
+/// version, before calling `register`. `R` is the registrar that the library registers
+/// its items with, e.g. the `ToolRegistrar` of the whitebox_tools crate for libraries
+/// of tools, and `abi_version` is the version of the interface defined by the crate of
+/// `R`. The ABI version is the first field of a fixed layout, so that the host can
+/// read it before relying on anything else in the declaration.
+#[repr(C)]
+pub struct PluginDeclaration<R: ?Sized + 'static = dyn PluginRegistrar> {
+    pub abi_version: u32,
     pub rustc_version: &'static str,
     pub core_version: &'static str,
-    pub register: unsafe extern "C" fn(&mut dyn PluginRegistrar),
+    pub register: unsafe extern "C" fn(&mut R),
+}
+
+impl<R: ?Sized + 'static> PluginDeclaration<R> {
+    /// Returns true if the plugin was built against version `abi_version` of the plugin
+    /// interface, and with the same version of rustc and of this crate as the host.
+    pub fn is_compatible(&self, abi_version: u32) -> bool {
+        self.abi_version == abi_version
+            && self.rustc_version == RUSTC_VERSION
+            && self.core_version == CORE_VERSION
+    }
 }
 
+impl<R: ?Sized + 'static> Clone for PluginDeclaration<R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R: ?Sized + 'static> Copy for PluginDeclaration<R> {}
+
 pub trait PluginRegistrar {
     fn register_function(&mut self, name: &str, function: Box<dyn Function>);
 }
@@ -40,9 +71,40 @@ macro_rules! export_plugin {
         #[no_mangle]
         pub static plugin_declaration: $crate::plugins::PluginDeclaration =
             $crate::plugins::PluginDeclaration {
+                abi_version: $crate::plugins::PLUGIN_ABI_VERSION,
                 rustc_version: $crate::plugins::RUSTC_VERSION,
                 core_version: $crate::plugins::CORE_VERSION,
                 register: $register,
             };
     };
 }
+
+#[cfg(test)]
+mod test {
+    use super::{PluginDeclaration, PluginRegistrar, CORE_VERSION, RUSTC_VERSION};
+
+    #[allow(improper_ctypes_definitions)]
+    unsafe extern "C" fn register(_registrar: &mut (dyn PluginRegistrar + 'static)) {}
+
+    #[test]
+    fn test_is_compatible() {
+        let declaration: PluginDeclaration = PluginDeclaration {
+            abi_version: 3,
+            rustc_version: RUSTC_VERSION,
+            core_version: CORE_VERSION,
+            register,
+        };
+        assert!(declaration.is_compatible(3));
+        assert!(!declaration.is_compatible(4));
+        assert!(!PluginDeclaration {
+            rustc_version: "rustc 1.0.0",
+            ..declaration
+        }
+        .is_compatible(3));
+        assert!(!PluginDeclaration {
+            core_version: "0.0.0",
+            ..declaration
+        }
+        .is_compatible(3));
+    }
+}
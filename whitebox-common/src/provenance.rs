@@ -0,0 +1,299 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: A provenance record describes the run of a tool that produced a file: the tool
+and its version, the time of the run, the full set of parameters, and each input file
+along with its modification time and, if the hash_provenance_inputs setting is on, the
+SHA-256 hash of its contents. Because the inputs of a run are
+often the outputs of earlier runs, following the records of the inputs gives the
+lineage of a file. While a tool runs, the record for the run is the current provenance
+of the thread that runs it (and of the tool's worker threads, if no other tool is running
+at the same time), and the raster, vector and LiDAR writers embed the current provenance
+in each file that they write: GeoTIFFs in the GDAL_METADATA tag, Whitebox
+rasters in the .dep header file, Shapefiles in a .provenance.json sidecar file, and
+LAS files in a VLR. Files written outside of a tool run carry no provenance.
+*/
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{BufReader, Error, ErrorKind, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use crate::memory_store::is_memory_file;
+
+/// A record of the tool run that produced a file.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Provenance {
+    pub tool: String,
+    pub version: String,
+    /// The time at which the tool was run, in UTC.
+    pub created: String,
+    /// The arguments of the run, keyed by the parameter's flag without leading dashes.
+    pub parameters: BTreeMap<String, String>,
+    pub inputs: Vec<ProvenanceInput>,
+}
+
+/// An input file of a tool run.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProvenanceInput {
+    pub file: String,
+    /// The SHA-256 hash of the file's contents at the time of the run, in hexadecimal, or
+    /// empty if the inputs weren't hashed.
+    #[serde(default)]
+    pub sha256: String,
+    /// The time at which the file was last modified before the run, in UTC.
+    #[serde(default)]
+    pub modified: String,
+}
+
+impl Provenance {
+    /// Creates a record of a run of a tool that starts now.
+    pub fn new(tool: &str, version: &str) -> Provenance {
+        Provenance {
+            tool: tool.to_string(),
+            version: version.to_string(),
+            created: format_time(SystemTime::now()),
+            ..Default::default()
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn from_json(json: &str) -> Result<Provenance, Error> {
+        serde_json::from_str(json).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("Error reading the provenance record: {}", e),
+            )
+        })
+    }
+}
+
+impl ProvenanceInput {
+    /// Describes an input file by its modification time and, if `hash` is true, by the
+    /// hash of its contents, which requires reading the whole file. The data files that
+    /// accompany a header file, e.g. the .tas file of a Whitebox raster, are described
+    /// along with it (see `dataset_files`). In-memory files and files that can't be read
+    /// are only named.
+    pub fn new(file_name: &str, hash: bool) -> ProvenanceInput {
+        let mut input = ProvenanceInput {
+            file: file_name.to_string(),
+            ..Default::default()
+        };
+        if !is_memory_file(file_name) && Path::new(file_name).is_file() {
+            if hash {
+                input.sha256 = sha256_dataset(file_name).unwrap_or_default();
+            }
+            input.modified = dataset_modified(file_name).unwrap_or_default();
+        }
+        input
+    }
+}
+
+/// Returns the files that hold the contents of a file: the file itself, followed by any
+/// of the data files that Whitebox rasters (.dep and .tas), Idrisi rasters (.rdc and
+/// .rst), SAGA rasters (.sgrd and .sdat) and Shapefiles (.shp, .shx and .dbf) keep
+/// beside the named file, as the readers of those formats find them.
+pub fn dataset_files(file_name: &str) -> Vec<PathBuf> {
+    let path = Path::new(file_name);
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    let companions: &[&str] = match extension.as_str() {
+        "dep" => &["tas"],
+        "rdc" => &["rst"],
+        "sgrd" => &["sdat"],
+        "shp" => &["shx", "dbf"],
+        _ => &[],
+    };
+    let mut files = vec![path.to_path_buf()];
+    for companion in companions {
+        let companion = path.with_extension(companion);
+        if companion.is_file() {
+            files.push(companion);
+        }
+    }
+    files
+}
+
+/// Returns the SHA-256 hash of the contents of a file and of its data files (see
+/// `dataset_files`), in hexadecimal.
+pub fn sha256_dataset(file_name: &str) -> Result<String, Error> {
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; 1 << 16];
+    for file in dataset_files(file_name) {
+        let mut reader = BufReader::new(File::open(file)?);
+        loop {
+            let n = reader.read(&mut buffer)?;
+            if n == 0 {
+                break;
+            }
+            hasher.update(&buffer[..n]);
+        }
+    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
+
+/// Returns the time at which a file or any of its data files (see `dataset_files`) was
+/// last modified, in UTC.
+pub fn dataset_modified(file_name: &str) -> Option<String> {
+    dataset_files(file_name)
+        .iter()
+        .filter_map(|f| fs::metadata(f).and_then(|m| m.modified()).ok())
+        .max()
+        .map(format_time)
+}
+
+thread_local! {
+    static CURRENT_PROVENANCE: RefCell<Option<Provenance>> = const { RefCell::new(None) };
+}
+
+// The tool runs in progress, by id. Tools that write their outputs from worker threads,
+// which don't share the current provenance of the thread running the tool, use the
+// record of the run in progress, but only when there is exactly one, since otherwise it
+// can't be known which run a thread belongs to.
+static RUNS_IN_PROGRESS: Mutex<Vec<(u64, Provenance)>> = Mutex::new(vec![]);
+static NEXT_RUN_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Sets the provenance that is embedded in the files written by the current thread,
+/// or clears it.
+pub fn set_current_provenance(provenance: Option<Provenance>) {
+    CURRENT_PROVENANCE.with(|p| *p.borrow_mut() = provenance);
+}
+
+/// Returns the provenance of the tool run on the current thread, or if the thread isn't
+/// running a tool, that of the only tool run in progress.
+pub fn current_provenance() -> Option<Provenance> {
+    CURRENT_PROVENANCE.with(|p| p.borrow().clone()).or_else(|| {
+        let runs = RUNS_IN_PROGRESS.lock().unwrap_or_else(|e| e.into_inner());
+        match runs.as_slice() {
+            [(_, provenance)] => Some(provenance.clone()),
+            _ => None,
+        }
+    })
+}
+
+/// Starts a tool run on the current thread, making its provenance current until the
+/// returned `ProvenanceScope` is dropped.
+pub fn begin_tool_run(provenance: Provenance) -> ProvenanceScope {
+    let id = NEXT_RUN_ID.fetch_add(1, Ordering::SeqCst);
+    RUNS_IN_PROGRESS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .push((id, provenance.clone()));
+    set_current_provenance(Some(provenance));
+    ProvenanceScope { id }
+}
+
+/// A tool run in progress, which ends when the scope is dropped, even if the tool panics.
+pub struct ProvenanceScope {
+    id: u64,
+}
+
+impl Drop for ProvenanceScope {
+    fn drop(&mut self) {
+        set_current_provenance(None);
+        RUNS_IN_PROGRESS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|(id, _)| *id != self.id);
+    }
+}
+
+/// Formats a time in UTC, e.g. 2026-10-17T14:03:27Z.
+pub fn format_time(time: SystemTime) -> String {
+    DateTime::<Utc>::from(time).to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_provenance() {
+        let file_name = env::temp_dir()
+            .join("whitebox_provenance_test.txt")
+            .to_string_lossy()
+            .to_string();
+        fs::write(&file_name, "abc").unwrap();
+        let input = ProvenanceInput::new(&file_name, true);
+        assert_eq!(
+            input.sha256,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert!(!input.modified.is_empty());
+        let unhashed = ProvenanceInput::new(&file_name, false);
+        assert!(unhashed.sha256.is_empty());
+        assert_eq!(unhashed.modified, input.modified);
+        fs::remove_file(&file_name).unwrap();
+        assert!(ProvenanceInput::new("mem://dem.tif", true)
+            .sha256
+            .is_empty());
+
+        let mut provenance = Provenance::new("Slope", "1.5.0");
+        provenance
+            .parameters
+            .insert("units".to_string(), "percent".to_string());
+        provenance.inputs.push(input);
+        assert_eq!(
+            Provenance::from_json(&provenance.to_json()).unwrap(),
+            provenance
+        );
+
+        assert_eq!(current_provenance(), None);
+        set_current_provenance(Some(provenance.clone()));
+        assert_eq!(current_provenance(), Some(provenance.clone()));
+        set_current_provenance(None);
+        assert_eq!(current_provenance(), None);
+
+        // a worker thread of the only run in progress shares its provenance
+        let scope = begin_tool_run(provenance.clone());
+        let worker = std::thread::spawn(current_provenance).join().unwrap();
+        assert_eq!(worker, Some(provenance));
+        drop(scope);
+        assert_eq!(current_provenance(), None);
+    }
+
+    #[test]
+    fn test_dataset_hash() {
+        // the hash of a Whitebox raster covers its .tas data file as well as its header
+        let header = env::temp_dir().join("whitebox_provenance_test.dep");
+        let data = header.with_extension("tas");
+        fs::write(&header, "ab").unwrap();
+        fs::write(&data, "c").unwrap();
+        let header = header.to_string_lossy().to_string();
+        assert_eq!(dataset_files(&header).len(), 2);
+        assert_eq!(
+            ProvenanceInput::new(&header, true).sha256,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        fs::write(&data, "d").unwrap();
+        assert_ne!(
+            ProvenanceInput::new(&header, true).sha256,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        fs::remove_file(&data).unwrap();
+        assert_eq!(dataset_files(&header).len(), 1);
+        fs::remove_file(&header).unwrap();
+    }
+}
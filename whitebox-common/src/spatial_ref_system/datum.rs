@@ -0,0 +1,194 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+*/
+
+use super::ellipsoid::Ellipsoid;
+
+/// Seven-parameter Helmert transformation (position vector convention) from a datum
+/// to WGS84. Translations are in metres, rotations in arc-seconds and scale in ppm.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HelmertParams {
+    pub dx: f64,
+    pub dy: f64,
+    pub dz: f64,
+    pub rx: f64,
+    pub ry: f64,
+    pub rz: f64,
+    pub ds: f64,
+}
+
+impl HelmertParams {
+    pub fn new(dx: f64, dy: f64, dz: f64, rx: f64, ry: f64, rz: f64, ds: f64) -> HelmertParams {
+        HelmertParams {
+            dx,
+            dy,
+            dz,
+            rx,
+            ry,
+            rz,
+            ds,
+        }
+    }
+
+    /// Creates a translation-only (geocentric translation) transformation.
+    pub fn translation(dx: f64, dy: f64, dz: f64) -> HelmertParams {
+        HelmertParams::new(dx, dy, dz, 0f64, 0f64, 0f64, 0f64)
+    }
+
+    pub fn is_identity(&self) -> bool {
+        *self == HelmertParams::default()
+    }
+
+    /// Applies the transformation to geocentric coordinates.
+    pub fn apply(&self, x: f64, y: f64, z: f64) -> (f64, f64, f64) {
+        let (rx, ry, rz) = self.rotations_in_radians();
+        let m = 1f64 + self.ds * 1e-6;
+        (
+            self.dx + m * (x - rz * y + ry * z),
+            self.dy + m * (rz * x + y - rx * z),
+            self.dz + m * (-ry * x + rx * y + z),
+        )
+    }
+
+    /// Applies the reverse transformation to geocentric coordinates.
+    pub fn apply_inverse(&self, x: f64, y: f64, z: f64) -> (f64, f64, f64) {
+        let (rx, ry, rz) = self.rotations_in_radians();
+        let m = 1f64 + self.ds * 1e-6;
+        let (x, y, z) = ((x - self.dx) / m, (y - self.dy) / m, (z - self.dz) / m);
+        // the rotation matrix is orthogonal for small angles, so its transpose is its inverse
+        (
+            x + rz * y - ry * z,
+            -rz * x + y + rx * z,
+            ry * x - rx * y + z,
+        )
+    }
+
+    fn rotations_in_radians(&self) -> (f64, f64, f64) {
+        let sec_to_rad = std::f64::consts::PI / (180f64 * 3600f64);
+        (self.rx * sec_to_rad, self.ry * sec_to_rad, self.rz * sec_to_rad)
+    }
+}
+
+/// A geodetic datum: an ellipsoid plus its relation to WGS84. `to_wgs84` is `None` when
+/// the relation is unknown, in which case no datum shift can be computed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Datum {
+    pub name: String,
+    pub ellipsoid: Ellipsoid,
+    pub to_wgs84: Option<HelmertParams>,
+}
+
+impl Datum {
+    pub fn new(name: &str, ellipsoid: Ellipsoid, to_wgs84: Option<HelmertParams>) -> Datum {
+        Datum {
+            name: name.to_string(),
+            ellipsoid,
+            to_wgs84,
+        }
+    }
+
+    /// Creates a datum from its name, looking up the transformation to WGS84 from a
+    /// table of commonly used datums. Modern, earth-centred datums on the GRS80 or
+    /// WGS84 ellipsoids (e.g. NAD83, ETRS89, GDA94) are treated as coincident with WGS84.
+    pub fn from_name(name: &str, ellipsoid: Ellipsoid) -> Datum {
+        let to_wgs84 = known_to_wgs84(name).or_else(|| {
+            if ellipsoid.is_equivalent(&Ellipsoid::wgs84()) {
+                Some(HelmertParams::default())
+            } else {
+                None
+            }
+        });
+        Datum::new(name, ellipsoid, to_wgs84)
+    }
+
+    pub fn wgs84() -> Datum {
+        Datum::new("D_WGS_1984", Ellipsoid::wgs84(), Some(HelmertParams::default()))
+    }
+
+    /// Returns true if no datum shift is needed to move coordinates between the two datums.
+    pub fn is_equivalent(&self, other: &Datum) -> bool {
+        if !self.ellipsoid.is_equivalent(&other.ellipsoid) {
+            return false;
+        }
+        if normalize_name(&self.name) == normalize_name(&other.name) {
+            return true;
+        }
+        match (self.to_wgs84, other.to_wgs84) {
+            (Some(a), Some(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+fn normalize_name(name: &str) -> String {
+    let n = name.to_lowercase().replace(' ', "_");
+    n.trim_start_matches("d_").to_string()
+}
+
+fn known_to_wgs84(name: &str) -> Option<HelmertParams> {
+    let n = normalize_name(name);
+    // Order matters; more specific names must precede the general ones.
+    let params = if n.contains("wgs_1972") || n.contains("wgs72") || n.contains("world_geodetic_system_1972") {
+        HelmertParams::new(0.0, 0.0, 4.5, 0.0, 0.0, 0.554, 0.2263)
+    } else if n.contains("north_american_1927") || n.contains("north_american_datum_1927") || n == "nad27" {
+        HelmertParams::translation(-8.0, 160.0, 176.0)
+    } else if n.contains("european_1950") || n.contains("european_datum_1950") || n == "ed50" {
+        HelmertParams::translation(-87.0, -98.0, -121.0)
+    } else if n.contains("osgb_1936") || n.contains("ordnance_survey_great_britain_1936") {
+        HelmertParams::new(446.448, -125.157, 542.06, 0.15, 0.247, 0.842, -20.489)
+    } else if n.contains("deutsches_hauptdreiecksnetz") || n == "dhdn" {
+        HelmertParams::new(598.1, 73.7, 418.2, 0.202, 0.045, -2.455, 6.7)
+    } else if n.contains("pulkovo_1942") {
+        HelmertParams::new(23.92, -141.27, -80.9, 0.0, 0.35, 0.82, -0.12)
+    } else if n.contains("tokyo") {
+        HelmertParams::translation(-146.414, 507.337, 680.507)
+    } else if n.contains("ntf") || n.contains("nouvelle_triangulation_francaise") {
+        HelmertParams::translation(-168.0, -60.0, 320.0)
+    } else if n == "mgi" || n.contains("militar_geographische_institut") {
+        HelmertParams::new(577.326, 90.129, 463.919, 5.137, 1.474, 5.297, 2.4232)
+    } else if n.contains("south_american_1969") {
+        HelmertParams::translation(-57.0, 1.0, -41.0)
+    } else if n.contains("australian_1984") || n.contains("australian_geodetic_datum_1984") {
+        HelmertParams::translation(-134.0, -48.0, 149.0)
+    } else if n.contains("australian_1966") || n.contains("australian_geodetic_datum_1966") {
+        HelmertParams::translation(-133.0, -48.0, 148.0)
+    } else if n.contains("new_zealand_1949") || n.contains("new_zealand_geodetic_datum_1949") {
+        HelmertParams::new(59.47, -5.04, 187.44, 0.47, -0.1, 1.024, -4.5993)
+    } else if n.contains("beijing_1954") {
+        HelmertParams::translation(15.8, -154.4, -82.3)
+    } else {
+        return None;
+    };
+    Some(params)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Datum, HelmertParams};
+    use crate::spatial_ref_system::Ellipsoid;
+
+    #[test]
+    fn test_helmert_round_trip() {
+        let h = HelmertParams::new(446.448, -125.157, 542.06, 0.15, 0.247, 0.842, -20.489);
+        let (x, y, z) = (3_909_833.018, -147_097.138, 5_020_322.362);
+        let (x2, y2, z2) = h.apply(x, y, z);
+        let (x3, y3, z3) = h.apply_inverse(x2, y2, z2);
+        assert!((x - x3).abs() < 0.01 && (y - y3).abs() < 0.01 && (z - z3).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_datum_lookup() {
+        let nad83 = Datum::from_name("D_North_American_1983", Ellipsoid::grs80());
+        assert!(nad83.is_equivalent(&Datum::wgs84()));
+        let nad27 = Datum::from_name(
+            "D_North_American_1927",
+            Ellipsoid::new("Clarke_1866", 6378206.4, 294.9786982),
+        );
+        assert_eq!(nad27.to_wgs84, Some(HelmertParams::translation(-8.0, 160.0, 176.0)));
+        assert!(!nad27.is_equivalent(&Datum::wgs84()));
+    }
+}
@@ -0,0 +1,128 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+*/
+
+/// A reference ellipsoid, defined by its semi-major axis (`a`, in metres) and
+/// inverse flattening. An inverse flattening of zero denotes a sphere.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ellipsoid {
+    pub name: String,
+    pub a: f64,
+    pub inv_flattening: f64,
+}
+
+impl Ellipsoid {
+    pub fn new(name: &str, a: f64, inv_flattening: f64) -> Ellipsoid {
+        Ellipsoid {
+            name: name.to_string(),
+            a,
+            inv_flattening,
+        }
+    }
+
+    pub fn wgs84() -> Ellipsoid {
+        Ellipsoid::new("WGS_1984", 6378137.0, 298.257223563)
+    }
+
+    pub fn grs80() -> Ellipsoid {
+        Ellipsoid::new("GRS_1980", 6378137.0, 298.257222101)
+    }
+
+    pub fn flattening(&self) -> f64 {
+        if self.inv_flattening == 0f64 {
+            0f64
+        } else {
+            1f64 / self.inv_flattening
+        }
+    }
+
+    /// Returns the semi-minor axis, in metres.
+    pub fn b(&self) -> f64 {
+        self.a * (1f64 - self.flattening())
+    }
+
+    /// Returns the square of the first eccentricity.
+    pub fn e2(&self) -> f64 {
+        let f = self.flattening();
+        f * (2f64 - f)
+    }
+
+    /// Returns the first eccentricity.
+    pub fn e(&self) -> f64 {
+        self.e2().sqrt()
+    }
+
+    /// Returns true if the two ellipsoids share the same shape and size, to within a
+    /// millimetre, regardless of name. GRS80 and WGS84 are therefore equivalent.
+    pub fn is_equivalent(&self, other: &Ellipsoid) -> bool {
+        (self.a - other.a).abs() < 1e-3 && (self.b() - other.b()).abs() < 1e-3
+    }
+
+    /// Converts geodetic coordinates (longitude and latitude in radians, ellipsoidal
+    /// height in metres) into earth-centred, earth-fixed (geocentric) X, Y, Z.
+    pub fn geodetic_to_geocentric(&self, lon: f64, lat: f64, h: f64) -> (f64, f64, f64) {
+        let e2 = self.e2();
+        let sin_lat = lat.sin();
+        let n = self.a / (1f64 - e2 * sin_lat * sin_lat).sqrt();
+        let x = (n + h) * lat.cos() * lon.cos();
+        let y = (n + h) * lat.cos() * lon.sin();
+        let z = (n * (1f64 - e2) + h) * sin_lat;
+        (x, y, z)
+    }
+
+    /// Converts geocentric X, Y, Z coordinates into geodetic coordinates (longitude and
+    /// latitude in radians, ellipsoidal height in metres), using Bowring's method
+    /// refined by iteration.
+    pub fn geocentric_to_geodetic(&self, x: f64, y: f64, z: f64) -> (f64, f64, f64) {
+        let e2 = self.e2();
+        let a = self.a;
+        let b = self.b();
+        let lon = y.atan2(x);
+        let p = (x * x + y * y).sqrt();
+        if p < 1e-9 {
+            // on the polar axis
+            let lat = if z >= 0f64 {
+                std::f64::consts::FRAC_PI_2
+            } else {
+                -std::f64::consts::FRAC_PI_2
+            };
+            return (lon, lat, z.abs() - b);
+        }
+        let ep2 = (a * a - b * b) / (b * b);
+        let theta = (z * a).atan2(p * b);
+        let mut lat = (z + ep2 * b * theta.sin().powi(3)).atan2(p - e2 * a * theta.cos().powi(3));
+        let mut h = 0f64;
+        for _ in 0..5 {
+            let sin_lat = lat.sin();
+            let n = a / (1f64 - e2 * sin_lat * sin_lat).sqrt();
+            h = p / lat.cos() - n;
+            let new_lat = (z / p / (1f64 - e2 * n / (n + h))).atan();
+            if (new_lat - lat).abs() < 1e-14 {
+                lat = new_lat;
+                break;
+            }
+            lat = new_lat;
+        }
+        (lon, lat, h)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Ellipsoid;
+
+    #[test]
+    fn test_geocentric_round_trip() {
+        let ellipsoid = Ellipsoid::wgs84();
+        let (lon, lat, h) = (-80.25f64.to_radians(), 43.5f64.to_radians(), 325.0);
+        let (x, y, z) = ellipsoid.geodetic_to_geocentric(lon, lat, h);
+        let (lon2, lat2, h2) = ellipsoid.geocentric_to_geodetic(x, y, z);
+        assert!((lon - lon2).abs() < 1e-12);
+        assert!((lat - lat2).abs() < 1e-12);
+        assert!((h - h2).abs() < 1e-6);
+    }
+}
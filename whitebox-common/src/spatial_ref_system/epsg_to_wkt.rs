@@ -6,6 +6,30 @@ macro_rules! hashmap {
     }}
 }
 
+/// Returns the EPSG code of a coordinate reference system described in WKT, if the outermost
+/// element carries an EPSG authority, e.g. `AUTHORITY["EPSG","26917"]]` or `ID["EPSG",26917]]`.
+pub fn epsg_from_wkt(wkt: &str) -> Option<u16> {
+    let wkt = wkt.trim();
+    if !wkt.ends_with("]]") {
+        return None;
+    }
+    let body = &wkt[..wkt.len() - 2];
+    let start = body.rfind('[')?;
+    let keyword_start = body[..start]
+        .rfind(|c: char| !c.is_ascii_alphabetic())
+        .map_or(0, |i| i + 1);
+    let keyword = body[keyword_start..start].to_uppercase();
+    if keyword != "AUTHORITY" && keyword != "ID" {
+        return None;
+    }
+    let mut args = body[start + 1..].split(',');
+    let authority = args.next()?.trim().trim_matches('"');
+    if !authority.eq_ignore_ascii_case("EPSG") {
+        return None;
+    }
+    args.next()?.trim().trim_matches('"').parse::<u16>().ok()
+}
+
 pub fn esri_wkt_from_epsg(code: u16) -> String {
     let hm = hashmap![
         3819=>"GEOGCS[\"GCS_HD1909\",DATUM[\"D_Hungarian_Datum_1909\",SPHEROID[\"Bessel_1841\",6377397.155,299.1528128]],PRIMEM[\"Greenwich\",0.0],UNIT[\"Degree\",0.0174532925199433],AUTHORITY[\"EPSG\",3819]]",
@@ -4973,3 +4997,22 @@ pub fn esri_wkt_from_epsg(code: u16) -> String {
     };
     s
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_epsg_from_wkt() {
+        assert_eq!(epsg_from_wkt(&esri_wkt_from_epsg(4326)), Some(4326));
+        assert_eq!(
+            epsg_from_wkt("PROJCRS[\"x\",BASEGEOGCRS[\"y\",ID[\"EPSG\",4326]],ID[\"EPSG\",32617]]"),
+            Some(32617)
+        );
+        assert_eq!(
+            epsg_from_wkt("GEOGCS[\"y\",DATUM[\"d\",AUTHORITY[\"EPSG\",\"6326\"]]]"),
+            None
+        );
+        assert_eq!(epsg_from_wkt(""), None);
+    }
+}
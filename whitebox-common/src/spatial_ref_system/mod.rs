@@ -1,3 +1,16 @@
+// private sub-module defined in other files
+mod datum;
+mod ellipsoid;
 mod epsg_to_wkt;
+mod projections;
+mod spatial_reference;
+mod transform;
+mod wkt;
 
-pub use self::epsg_to_wkt::esri_wkt_from_epsg;
+// exports identifiers from private sub-modules in the current module namespace
+pub use self::datum::{Datum, HelmertParams};
+pub use self::ellipsoid::Ellipsoid;
+pub use self::epsg_to_wkt::{epsg_from_wkt, esri_wkt_from_epsg};
+pub use self::projections::Projection;
+pub use self::spatial_reference::SpatialReference;
+pub use self::transform::CoordinateTransform;
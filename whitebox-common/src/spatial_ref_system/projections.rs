@@ -0,0 +1,764 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+*/
+
+use super::ellipsoid::Ellipsoid;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+use std::io::{Error, ErrorKind};
+
+/// The map projections supported by the coordinate transformation engine. Angles are
+/// in degrees and false eastings and northings are in metres. Formulas follow the
+/// EPSG Guidance Note 7-2, with the Krüger series used for Transverse Mercator.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Projection {
+    /// Unprojected longitude and latitude.
+    Geographic,
+    TransverseMercator {
+        central_meridian: f64,
+        latitude_of_origin: f64,
+        scale_factor: f64,
+        false_easting: f64,
+        false_northing: f64,
+    },
+    /// Lambert Conformal Conic. The one-standard-parallel form is described by setting
+    /// both standard parallels equal to the latitude of origin.
+    LambertConformalConic {
+        central_meridian: f64,
+        latitude_of_origin: f64,
+        standard_parallel_1: f64,
+        standard_parallel_2: f64,
+        scale_factor: f64,
+        false_easting: f64,
+        false_northing: f64,
+    },
+    AlbersEqualArea {
+        central_meridian: f64,
+        latitude_of_origin: f64,
+        standard_parallel_1: f64,
+        standard_parallel_2: f64,
+        false_easting: f64,
+        false_northing: f64,
+    },
+    /// Polar Stereographic. When `latitude_of_true_scale` is +/-90 the `scale_factor`
+    /// applies at the pole (variant A), otherwise the scale is true along the
+    /// specified parallel (variant B) and `scale_factor` is ignored.
+    PolarStereographic {
+        central_meridian: f64,
+        latitude_of_true_scale: f64,
+        scale_factor: f64,
+        north_pole: bool,
+        false_easting: f64,
+        false_northing: f64,
+    },
+    /// Mercator. The scale is true along `standard_parallel`, multiplied by `scale_factor`.
+    Mercator {
+        central_meridian: f64,
+        standard_parallel: f64,
+        scale_factor: f64,
+        false_easting: f64,
+        false_northing: f64,
+    },
+    /// Spherical ('Pseudo') Mercator, as used by web mapping services (EPSG:3857).
+    WebMercator {
+        central_meridian: f64,
+        false_easting: f64,
+        false_northing: f64,
+    },
+}
+
+/// A projection with its ellipsoid-dependent constants precomputed, so that large
+/// numbers of points can be projected cheaply.
+#[derive(Debug, Clone)]
+pub(crate) enum Projector {
+    Geographic,
+    TransverseMercator {
+        lon0: f64,
+        k0: f64,
+        a_hat: f64,
+        e: f64,
+        m0: f64,
+        alpha: [f64; 6],
+        beta: [f64; 6],
+        delta: [f64; 6],
+        fe: f64,
+        fn_: f64,
+    },
+    LambertConformalConic {
+        lon0: f64,
+        a: f64,
+        e: f64,
+        n: f64,
+        f: f64,
+        k0: f64,
+        r0: f64,
+        fe: f64,
+        fn_: f64,
+    },
+    AlbersEqualArea {
+        lon0: f64,
+        a: f64,
+        e: f64,
+        e2: f64,
+        n: f64,
+        c: f64,
+        rho0: f64,
+        fe: f64,
+        fn_: f64,
+    },
+    PolarStereographic {
+        lon0: f64,
+        a: f64,
+        e: f64,
+        k0: f64,
+        north_pole: bool,
+        fe: f64,
+        fn_: f64,
+    },
+    Mercator {
+        lon0: f64,
+        a: f64,
+        e: f64,
+        k0: f64,
+        fe: f64,
+        fn_: f64,
+    },
+    WebMercator {
+        lon0: f64,
+        a: f64,
+        fe: f64,
+        fn_: f64,
+    },
+}
+
+impl Projector {
+    pub fn new(projection: &Projection, ellipsoid: &Ellipsoid) -> Result<Projector, Error> {
+        let a = ellipsoid.a;
+        let e = ellipsoid.e();
+        let e2 = ellipsoid.e2();
+        let p = match *projection {
+            Projection::Geographic => Projector::Geographic,
+            Projection::TransverseMercator {
+                central_meridian,
+                latitude_of_origin,
+                scale_factor,
+                false_easting,
+                false_northing,
+            } => {
+                let n = ellipsoid.flattening() / (2f64 - ellipsoid.flattening());
+                let n2 = n * n;
+                let n3 = n2 * n;
+                let n4 = n3 * n;
+                let n5 = n4 * n;
+                let n6 = n5 * n;
+                let a_hat = a / (1f64 + n) * (1f64 + n2 / 4f64 + n4 / 64f64 + n6 / 256f64);
+                let alpha = [
+                    n / 2.0 - 2.0 * n2 / 3.0 + 5.0 * n3 / 16.0 + 41.0 * n4 / 180.0 - 127.0 * n5 / 288.0
+                        + 7891.0 * n6 / 37800.0,
+                    13.0 * n2 / 48.0 - 3.0 * n3 / 5.0 + 557.0 * n4 / 1440.0 + 281.0 * n5 / 630.0
+                        - 1983433.0 * n6 / 1935360.0,
+                    61.0 * n3 / 240.0 - 103.0 * n4 / 140.0 + 15061.0 * n5 / 26880.0
+                        + 167603.0 * n6 / 181440.0,
+                    49561.0 * n4 / 161280.0 - 179.0 * n5 / 168.0 + 6601661.0 * n6 / 7257600.0,
+                    34729.0 * n5 / 80640.0 - 3418889.0 * n6 / 1995840.0,
+                    212378941.0 * n6 / 319334400.0,
+                ];
+                let beta = [
+                    n / 2.0 - 2.0 * n2 / 3.0 + 37.0 * n3 / 96.0 - n4 / 360.0 - 81.0 * n5 / 512.0
+                        + 96199.0 * n6 / 604800.0,
+                    n2 / 48.0 + n3 / 15.0 - 437.0 * n4 / 1440.0 + 46.0 * n5 / 105.0
+                        - 1118711.0 * n6 / 3870720.0,
+                    17.0 * n3 / 480.0 - 37.0 * n4 / 840.0 - 209.0 * n5 / 4480.0 + 5569.0 * n6 / 90720.0,
+                    4397.0 * n4 / 161280.0 - 11.0 * n5 / 504.0 - 830251.0 * n6 / 7257600.0,
+                    4583.0 * n5 / 161280.0 - 108847.0 * n6 / 3991680.0,
+                    20648693.0 * n6 / 638668800.0,
+                ];
+                let delta = [
+                    2.0 * n - 2.0 * n2 / 3.0 - 2.0 * n3 + 116.0 * n4 / 45.0 + 26.0 * n5 / 45.0
+                        - 2854.0 * n6 / 675.0,
+                    7.0 * n2 / 3.0 - 8.0 * n3 / 5.0 - 227.0 * n4 / 45.0 + 2704.0 * n5 / 315.0
+                        + 2323.0 * n6 / 945.0,
+                    56.0 * n3 / 15.0 - 136.0 * n4 / 35.0 - 1262.0 * n5 / 105.0 + 73814.0 * n6 / 2835.0,
+                    4279.0 * n4 / 630.0 - 332.0 * n5 / 35.0 - 399572.0 * n6 / 14175.0,
+                    4174.0 * n5 / 315.0 - 144838.0 * n6 / 6237.0,
+                    601676.0 * n6 / 22275.0,
+                ];
+                let mut p = Projector::TransverseMercator {
+                    lon0: central_meridian.to_radians(),
+                    k0: scale_factor,
+                    a_hat,
+                    e,
+                    m0: 0f64,
+                    alpha,
+                    beta,
+                    delta,
+                    fe: false_easting,
+                    fn_: false_northing,
+                };
+                // The northing of the latitude of origin along the central meridian.
+                let (_, y0) = p.forward(central_meridian.to_radians(), latitude_of_origin.to_radians())?;
+                if let Projector::TransverseMercator { ref mut m0, .. } = p {
+                    *m0 = y0 - false_northing;
+                }
+                p
+            }
+            Projection::LambertConformalConic {
+                central_meridian,
+                latitude_of_origin,
+                standard_parallel_1,
+                standard_parallel_2,
+                scale_factor,
+                false_easting,
+                false_northing,
+            } => {
+                let lat0 = latitude_of_origin.to_radians();
+                let lat1 = standard_parallel_1.to_radians();
+                let lat2 = standard_parallel_2.to_radians();
+                let m1 = conformal_m(lat1, e2);
+                let m2 = conformal_m(lat2, e2);
+                let t0 = conformal_t(lat0, e);
+                let t1 = conformal_t(lat1, e);
+                let t2 = conformal_t(lat2, e);
+                let n = if (lat1 - lat2).abs() > 1e-10 {
+                    (m1.ln() - m2.ln()) / (t1.ln() - t2.ln())
+                } else {
+                    lat1.sin()
+                };
+                if n.abs() < 1e-10 {
+                    return Err(projection_error(
+                        "the standard parallels of a Lambert Conformal Conic projection cannot be symmetric about the equator",
+                    ));
+                }
+                let f = m1 / (n * t1.powf(n));
+                let r0 = a * f * t0.powf(n) * scale_factor;
+                Projector::LambertConformalConic {
+                    lon0: central_meridian.to_radians(),
+                    a,
+                    e,
+                    n,
+                    f,
+                    k0: scale_factor,
+                    r0,
+                    fe: false_easting,
+                    fn_: false_northing,
+                }
+            }
+            Projection::AlbersEqualArea {
+                central_meridian,
+                latitude_of_origin,
+                standard_parallel_1,
+                standard_parallel_2,
+                false_easting,
+                false_northing,
+            } => {
+                let lat0 = latitude_of_origin.to_radians();
+                let lat1 = standard_parallel_1.to_radians();
+                let lat2 = standard_parallel_2.to_radians();
+                let m1 = conformal_m(lat1, e2);
+                let m2 = conformal_m(lat2, e2);
+                let q0 = authalic_q(lat0, e, e2);
+                let q1 = authalic_q(lat1, e, e2);
+                let q2 = authalic_q(lat2, e, e2);
+                let n = if (lat1 - lat2).abs() > 1e-10 {
+                    (m1 * m1 - m2 * m2) / (q2 - q1)
+                } else {
+                    lat1.sin()
+                };
+                if n.abs() < 1e-10 {
+                    return Err(projection_error(
+                        "the standard parallels of an Albers projection cannot be symmetric about the equator",
+                    ));
+                }
+                let c = m1 * m1 + n * q1;
+                let rho0 = a * (c - n * q0).max(0f64).sqrt() / n;
+                Projector::AlbersEqualArea {
+                    lon0: central_meridian.to_radians(),
+                    a,
+                    e,
+                    e2,
+                    n,
+                    c,
+                    rho0,
+                    fe: false_easting,
+                    fn_: false_northing,
+                }
+            }
+            Projection::PolarStereographic {
+                central_meridian,
+                latitude_of_true_scale,
+                scale_factor,
+                north_pole,
+                false_easting,
+                false_northing,
+            } => {
+                let k0 = if (latitude_of_true_scale.abs() - 90f64).abs() < 1e-10 {
+                    scale_factor
+                } else {
+                    let lat_c = latitude_of_true_scale.to_radians();
+                    let t_c = polar_t(lat_c, e, north_pole);
+                    let m_c = conformal_m(lat_c, e2);
+                    m_c * polar_constant(e) / (2f64 * t_c)
+                };
+                Projector::PolarStereographic {
+                    lon0: central_meridian.to_radians(),
+                    a,
+                    e,
+                    k0,
+                    north_pole,
+                    fe: false_easting,
+                    fn_: false_northing,
+                }
+            }
+            Projection::Mercator {
+                central_meridian,
+                standard_parallel,
+                scale_factor,
+                false_easting,
+                false_northing,
+            } => Projector::Mercator {
+                lon0: central_meridian.to_radians(),
+                a,
+                e,
+                k0: scale_factor * conformal_m(standard_parallel.to_radians(), e2),
+                fe: false_easting,
+                fn_: false_northing,
+            },
+            Projection::WebMercator {
+                central_meridian,
+                false_easting,
+                false_northing,
+            } => Projector::WebMercator {
+                lon0: central_meridian.to_radians(),
+                a,
+                fe: false_easting,
+                fn_: false_northing,
+            },
+        };
+        Ok(p)
+    }
+
+    /// Projects a longitude and latitude (radians) into projected coordinates (metres).
+    /// For a geographic projection the coordinates are returned unchanged.
+    pub fn forward(&self, lon: f64, lat: f64) -> Result<(f64, f64), Error> {
+        let (x, y) = match *self {
+            Projector::Geographic => (lon, lat),
+            Projector::TransverseMercator {
+                lon0,
+                k0,
+                a_hat,
+                e,
+                m0,
+                ref alpha,
+                fe,
+                fn_,
+                ..
+            } => {
+                let dlon = normalize_angle(lon - lon0);
+                let sin_lat = lat.sin();
+                let t = (sin_lat.atanh() - e * (e * sin_lat).atanh()).sinh();
+                let xi_p = t.atan2(dlon.cos());
+                let eta_p = (dlon.sin() / (1f64 + t * t).sqrt()).atanh();
+                let mut xi = xi_p;
+                let mut eta = eta_p;
+                for (j, alpha_j) in alpha.iter().enumerate() {
+                    let k = 2f64 * (j + 1) as f64;
+                    xi += alpha_j * (k * xi_p).sin() * (k * eta_p).cosh();
+                    eta += alpha_j * (k * xi_p).cos() * (k * eta_p).sinh();
+                }
+                (fe + k0 * a_hat * eta, fn_ + k0 * a_hat * xi - m0)
+            }
+            Projector::LambertConformalConic {
+                lon0,
+                a,
+                e,
+                n,
+                f,
+                k0,
+                r0,
+                fe,
+                fn_,
+            } => {
+                let t = conformal_t(lat, e);
+                let r = a * f * t.powf(n) * k0;
+                let theta = n * normalize_angle(lon - lon0);
+                (fe + r * theta.sin(), fn_ + r0 - r * theta.cos())
+            }
+            Projector::AlbersEqualArea {
+                lon0,
+                a,
+                e,
+                e2,
+                n,
+                c,
+                rho0,
+                fe,
+                fn_,
+            } => {
+                let q = authalic_q(lat, e, e2);
+                let rho = a * (c - n * q).max(0f64).sqrt() / n;
+                let theta = n * normalize_angle(lon - lon0);
+                (fe + rho * theta.sin(), fn_ + rho0 - rho * theta.cos())
+            }
+            Projector::PolarStereographic {
+                lon0,
+                a,
+                e,
+                k0,
+                north_pole,
+                fe,
+                fn_,
+            } => {
+                let t = polar_t(lat, e, north_pole);
+                let rho = 2f64 * a * k0 * t / polar_constant(e);
+                let dlon = lon - lon0;
+                if north_pole {
+                    (fe + rho * dlon.sin(), fn_ - rho * dlon.cos())
+                } else {
+                    (fe + rho * dlon.sin(), fn_ + rho * dlon.cos())
+                }
+            }
+            Projector::Mercator {
+                lon0,
+                a,
+                e,
+                k0,
+                fe,
+                fn_,
+            } => {
+                let es = e * lat.sin();
+                let y = ((FRAC_PI_4 + lat / 2f64).tan() * ((1f64 - es) / (1f64 + es)).powf(e / 2f64)).ln();
+                (fe + a * k0 * normalize_angle(lon - lon0), fn_ + a * k0 * y)
+            }
+            Projector::WebMercator { lon0, a, fe, fn_ } => (
+                fe + a * normalize_angle(lon - lon0),
+                fn_ + a * (FRAC_PI_4 + lat / 2f64).tan().ln(),
+            ),
+        };
+        if !x.is_finite() || !y.is_finite() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "The location ({}, {}) cannot be projected.",
+                    lon.to_degrees(),
+                    lat.to_degrees()
+                ),
+            ));
+        }
+        Ok((x, y))
+    }
+
+    /// Converts projected coordinates (metres) into a longitude and latitude (radians).
+    pub fn inverse(&self, x: f64, y: f64) -> Result<(f64, f64), Error> {
+        let (lon, lat) = match *self {
+            Projector::Geographic => (x, y),
+            Projector::TransverseMercator {
+                lon0,
+                k0,
+                a_hat,
+                m0,
+                ref beta,
+                ref delta,
+                fe,
+                fn_,
+                ..
+            } => {
+                let xi = (y - fn_ + m0) / (k0 * a_hat);
+                let eta = (x - fe) / (k0 * a_hat);
+                let mut xi_p = xi;
+                let mut eta_p = eta;
+                for (j, beta_j) in beta.iter().enumerate() {
+                    let k = 2f64 * (j + 1) as f64;
+                    xi_p -= beta_j * (k * xi).sin() * (k * eta).cosh();
+                    eta_p -= beta_j * (k * xi).cos() * (k * eta).sinh();
+                }
+                let chi = (xi_p.sin() / eta_p.cosh()).asin();
+                let mut lat = chi;
+                for (j, delta_j) in delta.iter().enumerate() {
+                    lat += delta_j * (2f64 * (j + 1) as f64 * chi).sin();
+                }
+                (lon0 + eta_p.sinh().atan2(xi_p.cos()), lat)
+            }
+            Projector::LambertConformalConic {
+                lon0,
+                a,
+                e,
+                n,
+                f,
+                k0,
+                r0,
+                fe,
+                fn_,
+            } => {
+                let dx = x - fe;
+                let dy = r0 - (y - fn_);
+                let r = n.signum() * (dx * dx + dy * dy).sqrt();
+                let t = (r / (a * k0 * f)).powf(1f64 / n);
+                let theta = if n > 0f64 {
+                    dx.atan2(dy)
+                } else {
+                    (-dx).atan2(-dy)
+                };
+                (lon0 + theta / n, latitude_from_t(t, e))
+            }
+            Projector::AlbersEqualArea {
+                lon0,
+                a,
+                e,
+                e2,
+                n,
+                c,
+                rho0,
+                fe,
+                fn_,
+            } => {
+                let dx = x - fe;
+                let dy = rho0 - (y - fn_);
+                let rho = (dx * dx + dy * dy).sqrt();
+                let q = (c - rho * rho * n * n / (a * a)) / n;
+                let theta = if n > 0f64 {
+                    dx.atan2(dy)
+                } else {
+                    (-dx).atan2(-dy)
+                };
+                (lon0 + theta / n, latitude_from_q(q, e, e2))
+            }
+            Projector::PolarStereographic {
+                lon0,
+                a,
+                e,
+                k0,
+                north_pole,
+                fe,
+                fn_,
+            } => {
+                let dx = x - fe;
+                let dy = y - fn_;
+                let rho = (dx * dx + dy * dy).sqrt();
+                let t = rho * polar_constant(e) / (2f64 * a * k0);
+                let chi = if north_pole {
+                    FRAC_PI_2 - 2f64 * t.atan()
+                } else {
+                    2f64 * t.atan() - FRAC_PI_2
+                };
+                let lat = latitude_from_conformal(chi, e);
+                let lon = if rho == 0f64 {
+                    lon0
+                } else if north_pole {
+                    lon0 + dx.atan2(-dy)
+                } else {
+                    lon0 + dx.atan2(dy)
+                };
+                (lon, lat)
+            }
+            Projector::Mercator {
+                lon0,
+                a,
+                e,
+                k0,
+                fe,
+                fn_,
+            } => {
+                let t = ((fn_ - y) / (a * k0)).exp();
+                let chi = FRAC_PI_2 - 2f64 * t.atan();
+                (lon0 + (x - fe) / (a * k0), latitude_from_conformal(chi, e))
+            }
+            Projector::WebMercator { lon0, a, fe, fn_ } => (
+                lon0 + (x - fe) / a,
+                FRAC_PI_2 - 2f64 * ((fn_ - y) / a).exp().atan(),
+            ),
+        };
+        if !lon.is_finite() || !lat.is_finite() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("The location ({}, {}) cannot be unprojected.", x, y),
+            ));
+        }
+        Ok((normalize_angle(lon), lat))
+    }
+}
+
+fn projection_error(msg: &str) -> Error {
+    Error::new(ErrorKind::InvalidInput, format!("Invalid projection: {}.", msg))
+}
+
+/// Wraps an angle (radians) into the range [-PI, PI].
+fn normalize_angle(angle: f64) -> f64 {
+    if angle.abs() <= PI {
+        return angle;
+    }
+    let a = (angle + PI).rem_euclid(2f64 * PI) - PI;
+    if a == -PI && angle > 0f64 {
+        PI
+    } else {
+        a
+    }
+}
+
+fn conformal_m(lat: f64, e2: f64) -> f64 {
+    let s = lat.sin();
+    lat.cos() / (1f64 - e2 * s * s).sqrt()
+}
+
+fn conformal_t(lat: f64, e: f64) -> f64 {
+    let es = e * lat.sin();
+    (FRAC_PI_4 - lat / 2f64).tan() / ((1f64 - es) / (1f64 + es)).powf(e / 2f64)
+}
+
+fn latitude_from_t(t: f64, e: f64) -> f64 {
+    let mut lat = FRAC_PI_2 - 2f64 * t.atan();
+    for _ in 0..15 {
+        let es = e * lat.sin();
+        let new_lat = FRAC_PI_2 - 2f64 * (t * ((1f64 - es) / (1f64 + es)).powf(e / 2f64)).atan();
+        if (new_lat - lat).abs() < 1e-14 {
+            return new_lat;
+        }
+        lat = new_lat;
+    }
+    lat
+}
+
+fn authalic_q(lat: f64, e: f64, e2: f64) -> f64 {
+    let s = lat.sin();
+    if e < 1e-12 {
+        return 2f64 * s;
+    }
+    let es = e * s;
+    (1f64 - e2) * (s / (1f64 - es * es) - (1f64 / (2f64 * e)) * ((1f64 - es) / (1f64 + es)).ln())
+}
+
+fn latitude_from_q(q: f64, e: f64, e2: f64) -> f64 {
+    let mut lat = (q / 2f64).clamp(-1f64, 1f64).asin();
+    if e < 1e-12 {
+        return lat;
+    }
+    for _ in 0..15 {
+        let s = lat.sin();
+        let es = e * s;
+        let one_es2 = 1f64 - es * es;
+        let dlat = one_es2 * one_es2 / (2f64 * lat.cos())
+            * (q / (1f64 - e2) - s / one_es2 + (1f64 / (2f64 * e)) * ((1f64 - es) / (1f64 + es)).ln());
+        lat += dlat;
+        if dlat.abs() < 1e-14 {
+            break;
+        }
+    }
+    lat
+}
+
+fn polar_t(lat: f64, e: f64, north_pole: bool) -> f64 {
+    let es = e * lat.sin();
+    if north_pole {
+        (FRAC_PI_4 - lat / 2f64).tan() / ((1f64 - es) / (1f64 + es)).powf(e / 2f64)
+    } else {
+        (FRAC_PI_4 + lat / 2f64).tan() / ((1f64 + es) / (1f64 - es)).powf(e / 2f64)
+    }
+}
+
+fn polar_constant(e: f64) -> f64 {
+    ((1f64 + e).powf(1f64 + e) * (1f64 - e).powf(1f64 - e)).sqrt()
+}
+
+/// Converts a conformal latitude into a geodetic latitude.
+fn latitude_from_conformal(chi: f64, e: f64) -> f64 {
+    let e2 = e * e;
+    let e4 = e2 * e2;
+    let e6 = e4 * e2;
+    let e8 = e4 * e4;
+    chi + (e2 / 2.0 + 5.0 * e4 / 24.0 + e6 / 12.0 + 13.0 * e8 / 360.0) * (2.0 * chi).sin()
+        + (7.0 * e4 / 48.0 + 29.0 * e6 / 240.0 + 811.0 * e8 / 11520.0) * (4.0 * chi).sin()
+        + (7.0 * e6 / 120.0 + 81.0 * e8 / 1120.0) * (6.0 * chi).sin()
+        + (4279.0 * e8 / 161280.0) * (8.0 * chi).sin()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Projection, Projector};
+    use crate::spatial_ref_system::Ellipsoid;
+
+    fn round_trip(projection: Projection, ellipsoid: Ellipsoid, lon: f64, lat: f64) -> (f64, f64) {
+        let p = Projector::new(&projection, &ellipsoid).unwrap();
+        let (x, y) = p.forward(lon.to_radians(), lat.to_radians()).unwrap();
+        let (lon2, lat2) = p.inverse(x, y).unwrap();
+        assert!((lon - lon2.to_degrees()).abs() < 1e-9, "longitude {} != {}", lon, lon2.to_degrees());
+        assert!((lat - lat2.to_degrees()).abs() < 1e-9, "latitude {} != {}", lat, lat2.to_degrees());
+        (x, y)
+    }
+
+    #[test]
+    fn test_transverse_mercator() {
+        // UTM zone 17N; reference values computed with the Snyder (1987) series.
+        let utm17 = Projection::TransverseMercator {
+            central_meridian: -81.0,
+            latitude_of_origin: 0.0,
+            scale_factor: 0.9996,
+            false_easting: 500000.0,
+            false_northing: 0.0,
+        };
+        let (x, y) = round_trip(utm17, Ellipsoid::grs80(), -80.0, 43.5);
+        assert!((x - 580844.55).abs() < 0.01);
+        assert!((y - 4816827.04).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_lambert_conformal_conic() {
+        // EPSG Guidance Note 7-2 example for Lambert Conic Conformal (2SP), converted to metres.
+        let ft = 0.3048006096012192;
+        let lcc = Projection::LambertConformalConic {
+            central_meridian: -99.0,
+            latitude_of_origin: 27.0 + 50.0 / 60.0,
+            standard_parallel_1: 28.0 + 23.0 / 60.0,
+            standard_parallel_2: 30.0 + 17.0 / 60.0,
+            scale_factor: 1.0,
+            false_easting: 2000000.0 * ft,
+            false_northing: 0.0,
+        };
+        let clarke1866 = Ellipsoid::new("Clarke_1866", 6378206.4, 294.9786982);
+        let (x, y) = round_trip(lcc, clarke1866, -96.0, 28.5);
+        assert!((x / ft - 2963503.91).abs() < 0.05);
+        assert!((y / ft - 254759.80).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_albers_equal_area() {
+        let albers = Projection::AlbersEqualArea {
+            central_meridian: -96.0,
+            latitude_of_origin: 23.0,
+            standard_parallel_1: 29.5,
+            standard_parallel_2: 45.5,
+            false_easting: 0.0,
+            false_northing: 0.0,
+        };
+        round_trip(albers, Ellipsoid::grs80(), -80.25, 43.5);
+    }
+
+    #[test]
+    fn test_polar_stereographic() {
+        // EPSG Guidance Note 7-2 example for Polar Stereographic (variant B).
+        let ps = Projection::PolarStereographic {
+            central_meridian: 70.0,
+            latitude_of_true_scale: -71.0,
+            scale_factor: 1.0,
+            north_pole: false,
+            false_easting: 6000000.0,
+            false_northing: 6000000.0,
+        };
+        let (x, y) = round_trip(ps, Ellipsoid::wgs84(), 120.0, -75.0);
+        assert!((x - 7255380.79).abs() < 0.01);
+        assert!((y - 7053389.56).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_web_mercator() {
+        let wm = Projection::WebMercator {
+            central_meridian: 0.0,
+            false_easting: 0.0,
+            false_northing: 0.0,
+        };
+        let (x, y) = round_trip(wm, Ellipsoid::wgs84(), -100.333333333333, 24.381786944444);
+        assert!((x + 11169055.58).abs() < 0.01);
+        assert!((y - 2800000.00).abs() < 0.01);
+    }
+}
@@ -0,0 +1,353 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+*/
+
+use super::datum::{Datum, HelmertParams};
+use super::ellipsoid::Ellipsoid;
+use super::epsg_to_wkt::esri_wkt_from_epsg;
+use super::projections::{Projection, Projector};
+use super::wkt::{WktNode, WktValue};
+use std::io::{Error, ErrorKind};
+
+/// A coordinate reference system, either geographic or projected, that can convert
+/// its coordinates to and from geodetic longitude and latitude on its own datum.
+#[derive(Debug, Clone)]
+pub struct SpatialReference {
+    pub name: String,
+    pub epsg_code: Option<u16>,
+    pub datum: Datum,
+    /// Longitude of the prime meridian relative to Greenwich, in degrees.
+    pub prime_meridian: f64,
+    pub projection: Projection,
+    /// Size of the linear unit of a projected system, in metres.
+    pub linear_unit: f64,
+    /// Size of the angular unit of a geographic system, in radians.
+    pub angular_unit: f64,
+    projector: Projector,
+}
+
+impl SpatialReference {
+    pub fn new(
+        name: &str,
+        datum: Datum,
+        projection: Projection,
+        linear_unit: f64,
+    ) -> Result<SpatialReference, Error> {
+        let projector = Projector::new(&projection, &datum.ellipsoid)?;
+        Ok(SpatialReference {
+            name: name.to_string(),
+            epsg_code: None,
+            datum,
+            prime_meridian: 0f64,
+            projection,
+            linear_unit,
+            angular_unit: 1f64.to_radians(),
+            projector,
+        })
+    }
+
+    /// Creates a spatial reference from an EPSG code.
+    pub fn from_epsg(epsg_code: u16) -> Result<SpatialReference, Error> {
+        let wkt = esri_wkt_from_epsg(epsg_code);
+        if wkt == "Unknown EPSG Code" {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("Unrecognized EPSG code ({}).", epsg_code),
+            ));
+        }
+        let mut sr = SpatialReference::from_wkt(&wkt)?;
+        sr.epsg_code = Some(epsg_code);
+        Ok(sr)
+    }
+
+    /// Creates a spatial reference from an OGC or ESRI well-known text (WKT1) description.
+    pub fn from_wkt(wkt: &str) -> Result<SpatialReference, Error> {
+        let root = WktNode::parse(wkt.trim())?;
+        let (geogcs, projcs) = if root.keyword.eq_ignore_ascii_case("PROJCS") {
+            let geogcs = root.child("GEOGCS").ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    "The WKT coordinate system does not contain a GEOGCS.",
+                )
+            })?;
+            (geogcs, Some(&root))
+        } else if root.keyword.eq_ignore_ascii_case("GEOGCS") {
+            (&root, None)
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Unsupported WKT coordinate system type ({}).", root.keyword),
+            ));
+        };
+
+        let datum_node = geogcs.child("DATUM").ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidData,
+                "The WKT coordinate system does not contain a DATUM.",
+            )
+        })?;
+        let spheroid = datum_node.child("SPHEROID").ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidData,
+                "The WKT coordinate system does not contain a SPHEROID.",
+            )
+        })?;
+        let axes = spheroid.numbers();
+        if axes.len() < 2 || axes[0] <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "The WKT SPHEROID definition is invalid.",
+            ));
+        }
+        let ellipsoid = Ellipsoid::new(spheroid.name().unwrap_or(""), axes[0], axes[1]);
+        let datum_name = datum_node.name().unwrap_or("");
+        let datum = match datum_node.child("TOWGS84").map(|n| n.numbers()) {
+            Some(ref p) if p.len() == 7 => Datum::new(
+                datum_name,
+                ellipsoid,
+                Some(HelmertParams::new(p[0], p[1], p[2], p[3], p[4], p[5], p[6])),
+            ),
+            Some(ref p) if p.len() == 3 => Datum::new(
+                datum_name,
+                ellipsoid,
+                Some(HelmertParams::translation(p[0], p[1], p[2])),
+            ),
+            _ => Datum::from_name(datum_name, ellipsoid),
+        };
+        let prime_meridian = geogcs
+            .child("PRIMEM")
+            .and_then(|n| n.numbers().first().copied())
+            .unwrap_or(0f64);
+        let angular_unit = geogcs
+            .child("UNIT")
+            .and_then(|n| n.numbers().first().copied())
+            .unwrap_or_else(|| 1f64.to_radians());
+
+        let (name, projection, linear_unit) = match projcs {
+            Some(projcs) => {
+                let linear_unit = projcs
+                    .child("UNIT")
+                    .and_then(|n| n.numbers().first().copied())
+                    .unwrap_or(1f64);
+                let projection_name = projcs
+                    .child("PROJECTION")
+                    .and_then(|n| n.name())
+                    .unwrap_or("");
+                let projection = projection_from_wkt(projcs, projection_name, linear_unit)?;
+                (projcs.name().unwrap_or(""), projection, linear_unit)
+            }
+            None => (geogcs.name().unwrap_or(""), Projection::Geographic, 1f64),
+        };
+
+        let mut sr = SpatialReference::new(name, datum, projection, linear_unit)?;
+        // PRIMEM is expressed in the angular unit of the GEOGCS
+        sr.prime_meridian = prime_meridian * angular_unit.to_degrees();
+        sr.angular_unit = angular_unit;
+        sr.epsg_code = authority_code(&root);
+        Ok(sr)
+    }
+
+    /// Returns a WGS84 geographic coordinate system (EPSG:4326).
+    pub fn wgs84() -> SpatialReference {
+        let mut sr = SpatialReference::new("GCS_WGS_1984", Datum::wgs84(), Projection::Geographic, 1f64)
+            .expect("Error creating the WGS84 coordinate system");
+        sr.epsg_code = Some(4326);
+        sr
+    }
+
+    pub fn is_geographic(&self) -> bool {
+        self.projection == Projection::Geographic
+    }
+
+    /// Converts coordinates in this system into longitude and latitude (radians, relative
+    /// to Greenwich) on this system's datum.
+    pub fn to_geographic(&self, x: f64, y: f64) -> Result<(f64, f64), Error> {
+        let (lon, lat) = if self.is_geographic() {
+            (x * self.angular_unit, y * self.angular_unit)
+        } else {
+            self.projector
+                .inverse(x * self.linear_unit, y * self.linear_unit)?
+        };
+        Ok((lon + self.prime_meridian.to_radians(), lat))
+    }
+
+    /// Converts longitude and latitude (radians, relative to Greenwich) on this system's
+    /// datum into coordinates in this system.
+    pub fn from_geographic(&self, lon: f64, lat: f64) -> Result<(f64, f64), Error> {
+        let lon = lon - self.prime_meridian.to_radians();
+        if self.is_geographic() {
+            Ok((lon / self.angular_unit, lat / self.angular_unit))
+        } else {
+            let (x, y) = self.projector.forward(lon, lat)?;
+            Ok((x / self.linear_unit, y / self.linear_unit))
+        }
+    }
+}
+
+fn authority_code(node: &WktNode) -> Option<u16> {
+    let authority = node.child("AUTHORITY")?;
+    if !authority.name().is_some_and(|n| n.eq_ignore_ascii_case("EPSG")) {
+        return None;
+    }
+    authority.values.iter().skip(1).find_map(|v| match v {
+        WktValue::Number(n) => Some(*n as u16),
+        WktValue::Text(s) => s.trim().parse::<u16>().ok(),
+        _ => None,
+    })
+}
+
+fn projection_from_wkt(
+    projcs: &WktNode,
+    projection_name: &str,
+    linear_unit: f64,
+) -> Result<Projection, Error> {
+    let param = |names: &[&str]| names.iter().find_map(|n| projcs.parameter(n));
+    let central_meridian = param(&["Central_Meridian", "Longitude_Of_Origin", "Longitude_Of_Center"])
+        .unwrap_or(0f64);
+    let latitude_of_origin = param(&["Latitude_Of_Origin", "Latitude_Of_Center"]).unwrap_or(0f64);
+    let scale_factor = param(&["Scale_Factor"]).unwrap_or(1f64);
+    let false_easting = param(&["False_Easting"]).unwrap_or(0f64) * linear_unit;
+    let false_northing = param(&["False_Northing"]).unwrap_or(0f64) * linear_unit;
+    let standard_parallel_1 = param(&["Standard_Parallel_1", "Latitude_Of_Standard_Parallel"]);
+    let standard_parallel_2 = param(&["Standard_Parallel_2"]);
+
+    let projection = match projection_name.to_lowercase().as_str() {
+        "transverse_mercator" | "gauss_kruger" => Projection::TransverseMercator {
+            central_meridian,
+            latitude_of_origin,
+            scale_factor,
+            false_easting,
+            false_northing,
+        },
+        "lambert_conformal_conic" | "lambert_conformal_conic_1sp" | "lambert_conformal_conic_2sp" => {
+            let sp1 = standard_parallel_1.unwrap_or(latitude_of_origin);
+            Projection::LambertConformalConic {
+                central_meridian,
+                latitude_of_origin,
+                standard_parallel_1: sp1,
+                standard_parallel_2: standard_parallel_2.unwrap_or(sp1),
+                scale_factor,
+                false_easting,
+                false_northing,
+            }
+        }
+        "albers" | "albers_conic_equal_area" => {
+            let sp1 = standard_parallel_1.unwrap_or(latitude_of_origin);
+            Projection::AlbersEqualArea {
+                central_meridian,
+                latitude_of_origin,
+                standard_parallel_1: sp1,
+                standard_parallel_2: standard_parallel_2.unwrap_or(sp1),
+                false_easting,
+                false_northing,
+            }
+        }
+        "stereographic_north_pole" | "stereographic_south_pole" => {
+            let north_pole = projection_name.to_lowercase().ends_with("north_pole");
+            let default_lat = if north_pole { 90f64 } else { -90f64 };
+            Projection::PolarStereographic {
+                central_meridian,
+                latitude_of_true_scale: standard_parallel_1.unwrap_or(default_lat),
+                scale_factor,
+                north_pole,
+                false_easting,
+                false_northing,
+            }
+        }
+        "polar_stereographic" | "polar_stereographic_variant_a" | "polar_stereographic_variant_b" => {
+            let lat_ts = standard_parallel_1.unwrap_or(latitude_of_origin);
+            Projection::PolarStereographic {
+                central_meridian,
+                latitude_of_true_scale: lat_ts,
+                scale_factor,
+                north_pole: lat_ts > 0f64,
+                false_easting,
+                false_northing,
+            }
+        }
+        "stereographic" | "oblique_stereographic" if (latitude_of_origin.abs() - 90f64).abs() < 1e-10 => {
+            Projection::PolarStereographic {
+                central_meridian,
+                latitude_of_true_scale: latitude_of_origin,
+                scale_factor,
+                north_pole: latitude_of_origin > 0f64,
+                false_easting,
+                false_northing,
+            }
+        }
+        "mercator" | "mercator_1sp" | "mercator_2sp" => Projection::Mercator {
+            central_meridian,
+            standard_parallel: standard_parallel_1.unwrap_or(0f64),
+            scale_factor,
+            false_easting,
+            false_northing,
+        },
+        "mercator_auxiliary_sphere" | "popular_visualisation_pseudo_mercator" => {
+            Projection::WebMercator {
+                central_meridian,
+                false_easting,
+                false_northing,
+            }
+        }
+        _ => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "The {} projection is not currently supported.",
+                    projection_name
+                ),
+            ))
+        }
+    };
+    Ok(projection)
+}
+
+#[cfg(test)]
+mod test {
+    use super::SpatialReference;
+    use crate::spatial_ref_system::Projection;
+
+    #[test]
+    fn test_from_epsg() {
+        let sr = SpatialReference::from_epsg(26917).unwrap();
+        assert_eq!(sr.epsg_code, Some(26917));
+        assert!(!sr.is_geographic());
+        match sr.projection {
+            Projection::TransverseMercator {
+                central_meridian,
+                scale_factor,
+                false_easting,
+                ..
+            } => {
+                assert_eq!(central_meridian, -81.0);
+                assert_eq!(scale_factor, 0.9996);
+                assert_eq!(false_easting, 500000.0);
+            }
+            _ => panic!("Expected a Transverse Mercator projection"),
+        }
+        assert!(SpatialReference::from_epsg(4326).unwrap().is_geographic());
+        assert!(SpatialReference::from_epsg(1).is_err());
+    }
+
+    #[test]
+    fn test_from_ogc_wkt() {
+        let wkt = "PROJCS[\"NAD27 / UTM zone 17N\",GEOGCS[\"NAD27\",DATUM[\"North_American_Datum_1927\",SPHEROID[\"Clarke 1866\",6378206.4,294.9786982138982],TOWGS84[-8,160,176,0,0,0,0]],PRIMEM[\"Greenwich\",0],UNIT[\"degree\",0.0174532925199433]],PROJECTION[\"Transverse_Mercator\"],PARAMETER[\"latitude_of_origin\",0],PARAMETER[\"central_meridian\",-81],PARAMETER[\"scale_factor\",0.9996],PARAMETER[\"false_easting\",500000],PARAMETER[\"false_northing\",0],UNIT[\"metre\",1],AUTHORITY[\"EPSG\",\"26717\"]]";
+        let sr = SpatialReference::from_wkt(wkt).unwrap();
+        assert_eq!(sr.epsg_code, Some(26717));
+        assert!(sr.datum.to_wgs84.is_some());
+    }
+
+    #[test]
+    fn test_feet_units() {
+        // NAD83 / New York Long Island (ftUS); false easting of 984250 ftUS is 300000 m.
+        let sr = SpatialReference::from_epsg(2263).unwrap();
+        let (lon, lat) = sr.to_geographic(984250.0, 0.0).unwrap();
+        assert!((lon.to_degrees() + 74.0).abs() < 1e-9);
+        let (x, y) = sr.from_geographic(lon, lat).unwrap();
+        assert!((x - 984250.0).abs() < 1e-6 && y.abs() < 1e-6);
+    }
+}
@@ -0,0 +1,165 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+*/
+
+use super::spatial_reference::SpatialReference;
+use crate::structures::BoundingBox;
+use std::io::{Error, ErrorKind};
+
+/// Transforms coordinates from one spatial reference to another. When the two systems
+/// are based on different datums, a seven-parameter Helmert shift through WGS84 is
+/// applied to geocentric coordinates.
+///
+/// Example:
+///
+/// ```
+/// use whitebox_common::spatial_ref_system::{CoordinateTransform, SpatialReference};
+///
+/// let wgs84 = SpatialReference::from_epsg(4326).unwrap();
+/// let utm = SpatialReference::from_epsg(32617).unwrap();
+/// let transform = CoordinateTransform::new(&wgs84, &utm).unwrap();
+/// let (x, y) = transform.transform(-81.0, 0.0).unwrap();
+/// assert!((x - 500000.0).abs() < 1e-6 && y.abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CoordinateTransform {
+    pub source: SpatialReference,
+    pub target: SpatialReference,
+    datum_shift: bool,
+}
+
+impl CoordinateTransform {
+    pub fn new(
+        source: &SpatialReference,
+        target: &SpatialReference,
+    ) -> Result<CoordinateTransform, Error> {
+        let datum_shift = !source.datum.is_equivalent(&target.datum);
+        if datum_shift && (source.datum.to_wgs84.is_none() || target.datum.to_wgs84.is_none()) {
+            let unknown = if source.datum.to_wgs84.is_none() {
+                &source.datum.name
+            } else {
+                &target.datum.name
+            };
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "A datum transformation is required but the parameters for the {} datum are unknown.",
+                    unknown
+                ),
+            ));
+        }
+        Ok(CoordinateTransform {
+            source: source.clone(),
+            target: target.clone(),
+            datum_shift,
+        })
+    }
+
+    /// Returns the transformation from the target system back to the source system.
+    pub fn inverse(&self) -> CoordinateTransform {
+        CoordinateTransform {
+            source: self.target.clone(),
+            target: self.source.clone(),
+            datum_shift: self.datum_shift,
+        }
+    }
+
+    pub fn transform(&self, x: f64, y: f64) -> Result<(f64, f64), Error> {
+        let (x, y, _) = self.transform_3d(x, y, 0f64)?;
+        Ok((x, y))
+    }
+
+    /// Transforms a three-dimensional coordinate. The z value is treated as an
+    /// ellipsoidal height and is only modified by a datum shift.
+    pub fn transform_3d(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64), Error> {
+        let (mut lon, mut lat) = self.source.to_geographic(x, y)?;
+        let mut h = z;
+        if self.datum_shift {
+            let (gx, gy, gz) = self.source.datum.ellipsoid.geodetic_to_geocentric(lon, lat, h);
+            // Both parameter sets are known; this was checked in new().
+            let (gx, gy, gz) = self.source.datum.to_wgs84.unwrap_or_default().apply(gx, gy, gz);
+            let (gx, gy, gz) = self
+                .target
+                .datum
+                .to_wgs84
+                .unwrap_or_default()
+                .apply_inverse(gx, gy, gz);
+            let (lon2, lat2, h2) = self.target.datum.ellipsoid.geocentric_to_geodetic(gx, gy, gz);
+            lon = lon2;
+            lat = lat2;
+            h = h2;
+        }
+        let (x, y) = self.target.from_geographic(lon, lat)?;
+        Ok((x, y, h))
+    }
+
+    /// Transforms a bounding box, sampling `densify` intermediate points along each edge
+    /// so that the curvature of the edges in the target system is accounted for.
+    pub fn transform_bounding_box(
+        &self,
+        bb: &BoundingBox,
+        densify: usize,
+    ) -> Result<BoundingBox, Error> {
+        let mut out = BoundingBox::default();
+        let steps = densify + 1;
+        let mut num_valid = 0usize;
+        for i in 0..=steps {
+            let fx = bb.min_x + bb.get_width() * i as f64 / steps as f64;
+            let fy = bb.min_y + bb.get_height() * i as f64 / steps as f64;
+            for (x, y) in &[(fx, bb.min_y), (fx, bb.max_y), (bb.min_x, fy), (bb.max_x, fy)] {
+                if let Ok((x2, y2)) = self.transform(*x, *y) {
+                    out.min_x = out.min_x.min(x2);
+                    out.max_x = out.max_x.max(x2);
+                    out.min_y = out.min_y.min(y2);
+                    out.max_y = out.max_y.max(y2);
+                    num_valid += 1;
+                }
+            }
+        }
+        if num_valid == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "The bounding box could not be transformed into the target coordinate system.",
+            ));
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CoordinateTransform;
+    use crate::spatial_ref_system::SpatialReference;
+
+    #[test]
+    fn test_utm_to_web_mercator_round_trip() {
+        let utm = SpatialReference::from_epsg(26917).unwrap();
+        let web = SpatialReference::from_epsg(3857).unwrap();
+        let t = CoordinateTransform::new(&utm, &web).unwrap();
+        let (x, y) = t.transform(580844.55, 4816827.04).unwrap();
+        let (lon, lat) = (x / 6378137.0, (y / 6378137.0).sinh().atan());
+        assert!((lon.to_degrees() + 80.0).abs() < 1e-6);
+        assert!((lat.to_degrees() - 43.5).abs() < 1e-6);
+        let (x2, y2) = t.inverse().transform(x, y).unwrap();
+        assert!((x2 - 580844.55).abs() < 1e-4 && (y2 - 4816827.04).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_datum_shift() {
+        // NAD27 to WGS84 shifts by tens of metres in southern Ontario.
+        let nad27 = SpatialReference::from_epsg(4267).unwrap();
+        let wgs84 = SpatialReference::from_epsg(4326).unwrap();
+        let t = CoordinateTransform::new(&nad27, &wgs84).unwrap();
+        let (lon, lat, h) = t.transform_3d(-80.0, 43.5, 0.0).unwrap();
+        let dx = (lon + 80.0) * 111_320.0 * 43.5f64.to_radians().cos();
+        let dy = (lat - 43.5) * 111_320.0;
+        let shift = (dx * dx + dy * dy).sqrt();
+        assert!(shift > 10.0 && shift < 100.0);
+        let (lon2, lat2, h2) = t.inverse().transform_3d(lon, lat, h).unwrap();
+        assert!((lon2 + 80.0).abs() < 1e-9 && (lat2 - 43.5).abs() < 1e-9 && h2.abs() < 1e-3);
+    }
+}
@@ -0,0 +1,208 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+*/
+
+use std::io::{Error, ErrorKind};
+
+/// A node within a parsed well-known text (WKT) coordinate system description,
+/// e.g. `PARAMETER["False_Easting",500000.0]`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct WktNode {
+    pub keyword: String,
+    pub values: Vec<WktValue>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum WktValue {
+    Text(String),
+    Number(f64),
+    Identifier(String),
+    Node(WktNode),
+}
+
+impl WktNode {
+    /// Parses a WKT string. Both the ESRI and OGC dialects are accepted, as are
+    /// either square or round brackets.
+    pub fn parse(wkt: &str) -> Result<WktNode, Error> {
+        let chars: Vec<char> = wkt.chars().collect();
+        let mut pos = 0usize;
+        let node = parse_node(&chars, &mut pos)?;
+        skip_whitespace(&chars, &mut pos);
+        if pos != chars.len() {
+            return Err(wkt_error("unexpected trailing characters"));
+        }
+        Ok(node)
+    }
+
+    /// Returns the first child node with the specified keyword (case insensitive).
+    pub fn child(&self, keyword: &str) -> Option<&WktNode> {
+        self.values.iter().find_map(|v| match v {
+            WktValue::Node(n) if n.keyword.eq_ignore_ascii_case(keyword) => Some(n),
+            _ => None,
+        })
+    }
+
+    /// Returns all child nodes with the specified keyword (case insensitive).
+    pub fn children<'a>(&'a self, keyword: &'a str) -> impl Iterator<Item = &'a WktNode> + 'a {
+        self.values.iter().filter_map(move |v| match v {
+            WktValue::Node(n) if n.keyword.eq_ignore_ascii_case(keyword) => Some(n),
+            _ => None,
+        })
+    }
+
+    /// Returns the first quoted text value of the node, which is typically its name.
+    pub fn name(&self) -> Option<&str> {
+        self.values.iter().find_map(|v| match v {
+            WktValue::Text(s) => Some(s.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Returns the numeric values of the node, in order.
+    pub fn numbers(&self) -> Vec<f64> {
+        self.values
+            .iter()
+            .filter_map(|v| match v {
+                WktValue::Number(n) => Some(*n),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the value of the `PARAMETER` child with the specified name (case insensitive).
+    pub fn parameter(&self, name: &str) -> Option<f64> {
+        self.children("PARAMETER")
+            .find(|p| p.name().is_some_and(|n| n.eq_ignore_ascii_case(name)))
+            .and_then(|p| p.numbers().first().copied())
+    }
+}
+
+fn wkt_error(msg: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("Error parsing WKT coordinate system: {}.", msg),
+    )
+}
+
+fn skip_whitespace(chars: &[char], pos: &mut usize) {
+    while *pos < chars.len() && chars[*pos].is_whitespace() {
+        *pos += 1;
+    }
+}
+
+fn parse_node(chars: &[char], pos: &mut usize) -> Result<WktNode, Error> {
+    skip_whitespace(chars, pos);
+    let keyword = parse_identifier(chars, pos);
+    if keyword.is_empty() {
+        return Err(wkt_error("expected a keyword"));
+    }
+    skip_whitespace(chars, pos);
+    if *pos >= chars.len() || (chars[*pos] != '[' && chars[*pos] != '(') {
+        return Err(wkt_error(&format!("expected an opening bracket after {}", keyword)));
+    }
+    *pos += 1;
+    let mut values = vec![];
+    loop {
+        skip_whitespace(chars, pos);
+        if *pos >= chars.len() {
+            return Err(wkt_error("unbalanced brackets"));
+        }
+        let c = chars[*pos];
+        if c == ']' || c == ')' {
+            *pos += 1;
+            break;
+        } else if c == ',' {
+            *pos += 1;
+        } else if c == '"' {
+            *pos += 1;
+            let mut s = String::new();
+            while *pos < chars.len() {
+                if chars[*pos] == '"' {
+                    // a doubled quote is an escaped quote
+                    if *pos + 1 < chars.len() && chars[*pos + 1] == '"' {
+                        s.push('"');
+                        *pos += 2;
+                        continue;
+                    }
+                    break;
+                }
+                s.push(chars[*pos]);
+                *pos += 1;
+            }
+            if *pos >= chars.len() {
+                return Err(wkt_error("unterminated string"));
+            }
+            *pos += 1;
+            values.push(WktValue::Text(s));
+        } else if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' {
+            let start = *pos;
+            while *pos < chars.len()
+                && (chars[*pos].is_ascii_digit()
+                    || chars[*pos] == '-'
+                    || chars[*pos] == '+'
+                    || chars[*pos] == '.'
+                    || chars[*pos] == 'e'
+                    || chars[*pos] == 'E')
+            {
+                *pos += 1;
+            }
+            let s: String = chars[start..*pos].iter().collect();
+            let n = s
+                .parse::<f64>()
+                .map_err(|_| wkt_error(&format!("invalid number {}", s)))?;
+            values.push(WktValue::Number(n));
+        } else {
+            let start = *pos;
+            let ident = parse_identifier(chars, pos);
+            skip_whitespace(chars, pos);
+            if *pos < chars.len() && (chars[*pos] == '[' || chars[*pos] == '(') {
+                *pos = start;
+                values.push(WktValue::Node(parse_node(chars, pos)?));
+            } else if !ident.is_empty() {
+                values.push(WktValue::Identifier(ident));
+            } else {
+                return Err(wkt_error(&format!("unexpected character '{}'", c)));
+            }
+        }
+    }
+    Ok(WktNode { keyword, values })
+}
+
+fn parse_identifier(chars: &[char], pos: &mut usize) -> String {
+    let mut s = String::new();
+    while *pos < chars.len() && (chars[*pos].is_ascii_alphanumeric() || chars[*pos] == '_') {
+        s.push(chars[*pos]);
+        *pos += 1;
+    }
+    s
+}
+
+#[cfg(test)]
+mod test {
+    use super::WktNode;
+
+    #[test]
+    fn test_parse_esri_wkt() {
+        let wkt = "PROJCS[\"NAD_1983_UTM_Zone_17N\",GEOGCS[\"GCS_North_American_1983\",DATUM[\"D_North_American_1983\",SPHEROID[\"GRS_1980\",6378137.0,298.257222101]],PRIMEM[\"Greenwich\",0.0],UNIT[\"Degree\",0.0174532925199433]],PROJECTION[\"Transverse_Mercator\"],PARAMETER[\"False_Easting\",500000.0],PARAMETER[\"Central_Meridian\",-81.0],UNIT[\"Meter\",1.0]]";
+        let node = WktNode::parse(wkt).unwrap();
+        assert_eq!(node.keyword, "PROJCS");
+        assert_eq!(node.name(), Some("NAD_1983_UTM_Zone_17N"));
+        assert_eq!(node.parameter("false_easting"), Some(500000.0));
+        assert_eq!(node.parameter("Central_Meridian"), Some(-81.0));
+        let spheroid = node
+            .child("GEOGCS")
+            .and_then(|g| g.child("DATUM"))
+            .and_then(|d| d.child("SPHEROID"))
+            .unwrap();
+        assert_eq!(spheroid.numbers(), vec![6378137.0, 298.257222101]);
+    }
+
+    #[test]
+    fn test_parse_unbalanced_wkt() {
+        assert!(WktNode::parse("GEOGCS[\"WGS 84\",DATUM[\"WGS_1984\"").is_err());
+    }
+}
@@ -0,0 +1,333 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: The Extra Bytes VLR (user ID 'LASF_Spec', record ID 4) describes the bytes that
+follow the standard fields of each point record. It contains one 192-byte descriptor for
+each attribute, listed in the order that the attributes are stored within the record.
+*/
+
+use super::vlr::Vlr;
+use std::convert::TryInto;
+
+pub(crate) const EXTRA_BYTES_USER_ID: &str = "LASF_Spec";
+pub(crate) const EXTRA_BYTES_RECORD_ID: u16 = 4;
+const DESCRIPTOR_SIZE: usize = 192;
+
+/// The data type of an extra-bytes attribute.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExtraByteType {
+    /// Bytes of the given length that are carried through without interpretation.
+    Undocumented(u8),
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    F32,
+    F64,
+}
+
+impl ExtraByteType {
+    /// The number of bytes that the attribute occupies in each point record.
+    pub fn size(&self) -> usize {
+        match self {
+            ExtraByteType::Undocumented(n) => *n as usize,
+            ExtraByteType::U8 | ExtraByteType::I8 => 1,
+            ExtraByteType::U16 | ExtraByteType::I16 => 2,
+            ExtraByteType::U32 | ExtraByteType::I32 | ExtraByteType::F32 => 4,
+            ExtraByteType::U64 | ExtraByteType::I64 | ExtraByteType::F64 => 8,
+        }
+    }
+
+    fn from_code(code: u8, options: u8) -> ExtraByteType {
+        match code {
+            1 => ExtraByteType::U8,
+            2 => ExtraByteType::I8,
+            3 => ExtraByteType::U16,
+            4 => ExtraByteType::I16,
+            5 => ExtraByteType::U32,
+            6 => ExtraByteType::I32,
+            7 => ExtraByteType::U64,
+            8 => ExtraByteType::I64,
+            9 => ExtraByteType::F32,
+            10 => ExtraByteType::F64,
+            11..=30 => {
+                // deprecated two- and three-element arrays; keep their bytes intact
+                let elements = if code <= 20 { 2 } else { 3 };
+                let base = ExtraByteType::from_code((code - 1) % 10 + 1, 0);
+                ExtraByteType::Undocumented((elements * base.size()) as u8)
+            }
+            _ => ExtraByteType::Undocumented(options),
+        }
+    }
+
+    fn code(&self) -> u8 {
+        match self {
+            ExtraByteType::Undocumented(_) => 0,
+            ExtraByteType::U8 => 1,
+            ExtraByteType::I8 => 2,
+            ExtraByteType::U16 => 3,
+            ExtraByteType::I16 => 4,
+            ExtraByteType::U32 => 5,
+            ExtraByteType::I32 => 6,
+            ExtraByteType::U64 => 7,
+            ExtraByteType::I64 => 8,
+            ExtraByteType::F32 => 9,
+            ExtraByteType::F64 => 10,
+        }
+    }
+
+    fn is_float(&self) -> bool {
+        *self == ExtraByteType::F32 || *self == ExtraByteType::F64
+    }
+
+    fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            ExtraByteType::I8 | ExtraByteType::I16 | ExtraByteType::I32 | ExtraByteType::I64
+        )
+    }
+}
+
+/// The description of one extra-bytes attribute. The optional `no_data`, `min`, and `max`
+/// values are in the units of the stored (unscaled) values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExtraByteDefinition {
+    pub name: String,
+    pub description: String,
+    pub data_type: ExtraByteType,
+    pub no_data: Option<f64>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub scale: Option<f64>,
+    pub offset: Option<f64>,
+}
+
+impl ExtraByteDefinition {
+    pub fn new(name: &str, data_type: ExtraByteType, description: &str) -> ExtraByteDefinition {
+        ExtraByteDefinition {
+            name: name.to_string(),
+            description: description.to_string(),
+            data_type,
+            no_data: None,
+            min: None,
+            max: None,
+            scale: None,
+            offset: None,
+        }
+    }
+
+    /// The number of bytes that the attribute occupies in each point record.
+    pub fn size(&self) -> usize {
+        self.data_type.size()
+    }
+
+    /// Reads the attribute value from the bytes of a point record, applying the
+    /// scale and offset. Returns `None` for undocumented attributes.
+    pub fn read_value(&self, bytes: &[u8]) -> Option<f64> {
+        let v = self.read_raw(bytes)?;
+        Some(v * self.scale.unwrap_or(1f64) + self.offset.unwrap_or(0f64))
+    }
+
+    /// Writes an attribute value to the bytes of a point record, removing the scale
+    /// and offset. Values of undocumented attributes cannot be written.
+    pub fn write_value(&self, value: f64, bytes: &mut [u8]) {
+        let raw = (value - self.offset.unwrap_or(0f64)) / self.scale.unwrap_or(1f64);
+        self.write_raw(raw, bytes);
+    }
+
+    /// Returns true if `bytes` holds the attribute's no-data value.
+    pub fn is_no_data(&self, bytes: &[u8]) -> bool {
+        match (self.no_data, self.read_raw(bytes)) {
+            (Some(no_data), Some(v)) => v == no_data,
+            _ => false,
+        }
+    }
+
+    fn read_raw(&self, bytes: &[u8]) -> Option<f64> {
+        let v = match self.data_type {
+            ExtraByteType::Undocumented(_) => return None,
+            ExtraByteType::U8 => bytes[0] as f64,
+            ExtraByteType::I8 => bytes[0] as i8 as f64,
+            ExtraByteType::U16 => u16::from_le_bytes(bytes[0..2].try_into().unwrap()) as f64,
+            ExtraByteType::I16 => i16::from_le_bytes(bytes[0..2].try_into().unwrap()) as f64,
+            ExtraByteType::U32 => u32::from_le_bytes(bytes[0..4].try_into().unwrap()) as f64,
+            ExtraByteType::I32 => i32::from_le_bytes(bytes[0..4].try_into().unwrap()) as f64,
+            ExtraByteType::U64 => u64::from_le_bytes(bytes[0..8].try_into().unwrap()) as f64,
+            ExtraByteType::I64 => i64::from_le_bytes(bytes[0..8].try_into().unwrap()) as f64,
+            ExtraByteType::F32 => f32::from_le_bytes(bytes[0..4].try_into().unwrap()) as f64,
+            ExtraByteType::F64 => f64::from_le_bytes(bytes[0..8].try_into().unwrap()),
+        };
+        Some(v)
+    }
+
+    fn write_raw(&self, raw: f64, bytes: &mut [u8]) {
+        // integer types are rounded and saturate at the limits of the type
+        let r = raw.round();
+        match self.data_type {
+            ExtraByteType::Undocumented(_) => {}
+            ExtraByteType::U8 => bytes[0] = r as u8,
+            ExtraByteType::I8 => bytes[0] = r as i8 as u8,
+            ExtraByteType::U16 => bytes[0..2].copy_from_slice(&(r as u16).to_le_bytes()),
+            ExtraByteType::I16 => bytes[0..2].copy_from_slice(&(r as i16).to_le_bytes()),
+            ExtraByteType::U32 => bytes[0..4].copy_from_slice(&(r as u32).to_le_bytes()),
+            ExtraByteType::I32 => bytes[0..4].copy_from_slice(&(r as i32).to_le_bytes()),
+            ExtraByteType::U64 => bytes[0..8].copy_from_slice(&(r as u64).to_le_bytes()),
+            ExtraByteType::I64 => bytes[0..8].copy_from_slice(&(r as i64).to_le_bytes()),
+            ExtraByteType::F32 => bytes[0..4].copy_from_slice(&(raw as f32).to_le_bytes()),
+            ExtraByteType::F64 => bytes[0..8].copy_from_slice(&raw.to_le_bytes()),
+        }
+    }
+
+    /// The bytes that new points receive for this attribute: the no-data value if
+    /// there is one, otherwise zeros.
+    pub(crate) fn default_bytes(&self, bytes: &mut [u8]) {
+        for b in bytes.iter_mut() {
+            *b = 0u8;
+        }
+        if let Some(no_data) = self.no_data {
+            self.write_raw(no_data, bytes);
+        }
+    }
+
+    fn from_bytes(d: &[u8]) -> ExtraByteDefinition {
+        let data_type = ExtraByteType::from_code(d[2], d[3]);
+        let options = d[3];
+        let any_value = |offset: usize| -> f64 {
+            let b: [u8; 8] = d[offset..offset + 8].try_into().unwrap();
+            if data_type.is_float() {
+                f64::from_le_bytes(b)
+            } else if data_type.is_signed() {
+                i64::from_le_bytes(b) as f64
+            } else {
+                u64::from_le_bytes(b) as f64
+            }
+        };
+        let float_value =
+            |offset: usize| f64::from_le_bytes(d[offset..offset + 8].try_into().unwrap());
+        let has = |bit: u8| data_type.code() != 0 && options & (1 << bit) != 0;
+        ExtraByteDefinition {
+            name: read_string(&d[4..36]),
+            description: read_string(&d[160..192]),
+            data_type,
+            no_data: if has(0) { Some(any_value(40)) } else { None },
+            min: if has(1) { Some(any_value(64)) } else { None },
+            max: if has(2) { Some(any_value(88)) } else { None },
+            scale: if has(3) { Some(float_value(112)) } else { None },
+            offset: if has(4) { Some(float_value(136)) } else { None },
+        }
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut d = vec![0u8; DESCRIPTOR_SIZE];
+        d[2] = self.data_type.code();
+        let mut options = 0u8;
+        let mut write_any = |value: Option<f64>, offset: usize, bit: u8, d: &mut Vec<u8>| {
+            if let Some(v) = value {
+                options |= 1 << bit;
+                let b = if self.data_type.is_float() {
+                    v.to_le_bytes()
+                } else if self.data_type.is_signed() {
+                    (v as i64).to_le_bytes()
+                } else {
+                    (v as u64).to_le_bytes()
+                };
+                d[offset..offset + 8].copy_from_slice(&b);
+            }
+        };
+        write_any(self.no_data, 40, 0, &mut d);
+        write_any(self.min, 64, 1, &mut d);
+        write_any(self.max, 88, 2, &mut d);
+        if let Some(scale) = self.scale {
+            options |= 1 << 3;
+            d[112..120].copy_from_slice(&scale.to_le_bytes());
+        }
+        if let Some(offset) = self.offset {
+            options |= 1 << 4;
+            d[136..144].copy_from_slice(&offset.to_le_bytes());
+        }
+        d[3] = match self.data_type {
+            ExtraByteType::Undocumented(n) => n,
+            _ => options,
+        };
+        write_string(&self.name, &mut d[4..36]);
+        write_string(&self.description, &mut d[160..192]);
+        d
+    }
+}
+
+fn read_string(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes)
+        .trim_end_matches(char::from(0))
+        .trim()
+        .to_string()
+}
+
+fn write_string(s: &str, bytes: &mut [u8]) {
+    // the last byte is left as a null terminator
+    let n = s.len().min(bytes.len() - 1);
+    bytes[..n].copy_from_slice(&s.as_bytes()[..n]);
+}
+
+/// Returns true if the VLR is an Extra Bytes VLR.
+pub(crate) fn is_extra_bytes_vlr(vlr: &Vlr) -> bool {
+    vlr.record_id == EXTRA_BYTES_RECORD_ID
+        && vlr.user_id.trim_end_matches(char::from(0)).trim() == EXTRA_BYTES_USER_ID
+}
+
+/// Parses the attribute descriptors of an Extra Bytes VLR.
+pub(crate) fn parse_extra_bytes_vlr(vlr: &Vlr) -> Vec<ExtraByteDefinition> {
+    vlr.binary_data
+        .chunks_exact(DESCRIPTOR_SIZE)
+        .map(ExtraByteDefinition::from_bytes)
+        .collect()
+}
+
+/// Creates the Extra Bytes VLR describing `definitions`.
+pub(crate) fn create_extra_bytes_vlr(definitions: &[ExtraByteDefinition]) -> Vlr {
+    let binary_data: Vec<u8> = definitions.iter().flat_map(|d| d.to_bytes()).collect();
+    Vlr {
+        reserved: 0u16,
+        user_id: EXTRA_BYTES_USER_ID.to_string(),
+        record_id: EXTRA_BYTES_RECORD_ID,
+        record_length_after_header: binary_data.len() as u16,
+        description: "Extra Bytes Record".to_string(),
+        binary_data,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_extra_bytes_vlr_round_trip() {
+        let mut height = ExtraByteDefinition::new("height", ExtraByteType::I32, "Height");
+        height.scale = Some(0.01);
+        height.no_data = Some(-9999f64);
+        let mut reflectance = ExtraByteDefinition::new("Reflectance", ExtraByteType::F32, "");
+        reflectance.min = Some(-40.5);
+        let padding = ExtraByteDefinition::new("", ExtraByteType::Undocumented(3), "");
+        let definitions = vec![height, reflectance, padding];
+
+        let vlr = create_extra_bytes_vlr(&definitions);
+        assert!(is_extra_bytes_vlr(&vlr));
+        assert_eq!(vlr.binary_data.len(), 3 * DESCRIPTOR_SIZE);
+        assert_eq!(parse_extra_bytes_vlr(&vlr), definitions);
+
+        let mut record = [0u8; 4];
+        definitions[0].write_value(12.345, &mut record);
+        assert_eq!(i32::from_le_bytes(record), 1235);
+        assert!((definitions[0].read_value(&record).unwrap() - 12.35).abs() < 1e-9);
+        definitions[0].default_bytes(&mut record);
+        assert!(definitions[0].is_no_data(&record));
+        assert_eq!(definitions[2].read_value(&record), None);
+    }
+}
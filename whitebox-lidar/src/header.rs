@@ -229,7 +229,7 @@ impl LasHeader {
         header.header_size = bor.read_u16()?;
         header.offset_to_points = bor.read_u32()?;
         header.number_of_vlrs = bor.read_u32()?;
-        header.point_format = bor.read_u8()?;
+        header.point_format = bor.read_u8()? & 0x3F; // LAZ files set the two high bits
         header.point_record_length = bor.read_u16()?;
         header.number_of_points_old = bor.read_u32()?;
 
@@ -2,17 +2,23 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 15/01/2017
-Last Modified: 07/12/2018
+Last Modified: 17/10/2026
 License: MIT
 */
 
 #![allow(dead_code, unused_assignments)]
 extern crate brotli;
+use super::extra_bytes::{
+    create_extra_bytes_vlr, is_extra_bytes_vlr, parse_extra_bytes_vlr, ExtraByteDefinition,
+};
 use super::header::LasHeader;
+use super::laz::{compress_las, decompress_laz, is_copc_file, is_laz, read_copc_bounds};
 use super::point_data::{ ColourData, PointData, WaveformPacket };
 use super::vlr::Vlr;
 use super::zlidar_compression::{ZlidarCompression};
 use whitebox_raster::geotiff::geokeys::GeoKeys;
+use whitebox_common::memory_store::{is_memory_file, MemoryStore};
+use whitebox_common::provenance::{current_provenance, Provenance};
 use whitebox_common::spatial_ref_system::esri_wkt_from_epsg;
 use whitebox_common::structures::{ BoundingBox, Point3D };
 use whitebox_common::utils::{ ByteOrderReader, Endianness };
@@ -28,7 +34,7 @@ use std::fmt;
 use std::fs;
 use std::fs::File;
 use std::io::prelude::*;
-use std::io::{BufWriter, Cursor, Error, ErrorKind, Read, Seek};
+use std::io::{BufReader, BufWriter, Cursor, Error, ErrorKind, Read, Seek};
 use std::mem;
 use std::ops::Index;
 use std::path::Path;
@@ -51,6 +57,9 @@ pub struct LasFile {
     gps_data: Vec<f64>,
     colour_data: Vec<ColourData>,
     waveform_data: Vec<WaveformPacket>,
+    extra_bytes: Vec<ExtraByteDefinition>,
+    // the extra bytes of each point, stored contiguously; empty if no point has any
+    extra_byte_data: Vec<u8>,
     pub geokeys: GeoKeys,
     pub wkt: String,
     // starting_point: usize,
@@ -77,6 +86,19 @@ impl Index<usize> for LasFile {
     }
 }
 
+/// The in-memory LiDAR files, i.e. those with file names beginning with `mem://`.
+/// Writing such a file stores a copy of it here, and reading it returns a copy.
+pub static MEMORY_LIDAR: MemoryStore<LasFile> = MemoryStore::new();
+
+// The user ID and record ID of the VLR that holds the provenance of a file, as JSON.
+const PROVENANCE_USER_ID: &str = "WhiteboxTools";
+const PROVENANCE_RECORD_ID: u16 = 1u16;
+
+fn is_provenance_vlr(vlr: &Vlr) -> bool {
+    vlr.record_id == PROVENANCE_RECORD_ID
+        && vlr.user_id.trim_end_matches(char::from(0)).trim() == PROVENANCE_USER_ID
+}
+
 impl LasFile {
     /// Constructs a new `LasFile` based on a file.
     /// The function takes the name of an existing raster file (`file_name`)
@@ -90,6 +112,16 @@ impl LasFile {
             ..Default::default()
         };
         lf.file_mode = file_mode.to_lowercase();
+        if is_memory_file(file_name) && (lf.file_mode == "r" || lf.file_mode == "rh") {
+            let mut input = MEMORY_LIDAR.get(file_name).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::NotFound,
+                    format!("The in-memory LiDAR file {} does not exist.", file_name),
+                )
+            })?;
+            input.file_mode = lf.file_mode;
+            return Ok(input);
+        }
         if lf.file_mode == "r" || lf.file_mode == "rh" {
             lf.read()?;
         } else {
@@ -101,6 +133,21 @@ impl LasFile {
         Ok(lf)
     }
 
+    /// Creates a `LasFile` from the bytes of a LAS file, in the same way as `new`
+    /// does from a file on disk.
+    pub(crate) fn from_buffer(file_name: &str, file_mode: &str, buffer: Vec<u8>) -> Result<LasFile, Error> {
+        let mut lf = LasFile {
+            file_name: file_name.to_string(),
+            file_mode: file_mode.to_string(),
+            wkt: String::new(),
+            ..Default::default()
+        };
+        lf.read_from_buffer(buffer)?;
+        lf.use_point_intensity = true;
+        lf.use_point_userdata = true;
+        Ok(lf)
+    }
+
     /// This function returns a new `LasFile` that has been initialized using another
     /// `LasFile`.
     /// Input Parameters:
@@ -127,6 +174,7 @@ impl LasFile {
         for i in 0..(input.header.number_of_vlrs as usize) {
             output.add_vlr(input.vlr_data[i].clone());
         }
+        output.extra_bytes = input.extra_bytes.clone();
 
         output
     }
@@ -351,6 +399,216 @@ impl LasFile {
         if which_return <= 5 {
             self.header.number_of_points_by_return[which_return - 1] += 1;
         }
+
+        if !self.extra_byte_data.is_empty() {
+            self.push_default_extra_bytes();
+        }
+    }
+
+    /// Adds point `index` of `input` to the file, along with the values of any
+    /// extra-bytes attributes that the two files share (matched by name).
+    pub fn add_point_record_from(&mut self, input: &LasFile, index: usize) {
+        self.add_point_record_with_extra_bytes(input.get_record(index), input, index);
+    }
+
+    /// Adds a point record that was derived from point `index` of `input`, e.g. one
+    /// that has been reclassified, keeping the extra-bytes attribute values of the
+    /// original point.
+    pub fn add_point_record_with_extra_bytes(
+        &mut self,
+        point: LidarPointRecord,
+        input: &LasFile,
+        index: usize,
+    ) {
+        self.add_point_record(point);
+        if self.file_mode == "r" || input.extra_byte_data.is_empty() || self.extra_bytes.is_empty() {
+            return;
+        }
+        self.ensure_extra_byte_data();
+        let n = self.point_data.len() - 1;
+        let src = input.get_extra_bytes(index);
+        if self.extra_bytes == input.extra_bytes {
+            self.get_extra_bytes_mut(n).copy_from_slice(src);
+            return;
+        }
+        for a in 0..self.extra_bytes.len() {
+            if let Some(b) = input.get_extra_byte_index(&self.extra_bytes[a].name) {
+                if input.extra_bytes[b].data_type == self.extra_bytes[a].data_type {
+                    let from = input.extra_byte_offset(b);
+                    let to = self.extra_byte_offset(a);
+                    let size = self.extra_bytes[a].size();
+                    self.get_extra_bytes_mut(n)[to..to + size]
+                        .copy_from_slice(&src[from..from + size]);
+                }
+            }
+        }
+    }
+
+    /// Returns the descriptions of the file's extra-bytes attributes.
+    pub fn get_extra_byte_definitions(&self) -> &[ExtraByteDefinition] {
+        &self.extra_bytes
+    }
+
+    /// Returns true if the points in the file have extra-bytes attribute values.
+    pub fn has_extra_bytes(&self) -> bool {
+        !self.extra_byte_data.is_empty()
+    }
+
+    /// Returns the index of the extra-bytes attribute with the name `name`.
+    pub fn get_extra_byte_index(&self, name: &str) -> Option<usize> {
+        self.extra_bytes.iter().position(|d| d.name == name)
+    }
+
+    /// Adds an extra-bytes attribute to a file that is being written and returns its
+    /// index. If the file already has an attribute with the same name, the index of
+    /// the existing attribute is returned instead. Points that have already been added
+    /// receive the attribute's no-data value, or zero if it has none.
+    pub fn add_extra_byte_definition(&mut self, definition: ExtraByteDefinition) -> usize {
+        if let Some(index) = self.get_extra_byte_index(&definition.name) {
+            return index;
+        }
+        if !self.extra_byte_data.is_empty() {
+            let old_size = self.extra_byte_record_size();
+            let size = old_size + definition.size();
+            let mut data = vec![0u8; self.point_data.len() * size];
+            for (i, record) in data.chunks_exact_mut(size).enumerate() {
+                record[..old_size]
+                    .copy_from_slice(&self.extra_byte_data[i * old_size..(i + 1) * old_size]);
+                definition.default_bytes(&mut record[old_size..]);
+            }
+            self.extra_byte_data = data;
+        }
+        self.extra_bytes.push(definition);
+        self.extra_bytes.len() - 1
+    }
+
+    /// Returns the value of extra-bytes attribute `attribute` for point `index`, with
+    /// the attribute's scale and offset applied. `None` is returned if the point does
+    /// not have a value, i.e. it is the no-data value or the attribute is undocumented.
+    pub fn get_extra_byte_value(&self, index: usize, attribute: usize) -> Option<f64> {
+        if self.extra_byte_data.is_empty() {
+            return None;
+        }
+        let def = &self.extra_bytes[attribute];
+        let offset = self.extra_byte_offset(attribute);
+        let bytes = &self.get_extra_bytes(index)[offset..offset + def.size()];
+        if def.is_no_data(bytes) {
+            return None;
+        }
+        def.read_value(bytes)
+    }
+
+    /// Sets the value of extra-bytes attribute `attribute` for point `index`.
+    pub fn set_extra_byte_value(&mut self, index: usize, attribute: usize, value: f64) {
+        self.ensure_extra_byte_data();
+        let offset = self.extra_byte_offset(attribute);
+        let size = self.extra_bytes[attribute].size();
+        let size_total = self.extra_byte_record_size();
+        let start = index * size_total + offset;
+        self.extra_bytes[attribute].write_value(value, &mut self.extra_byte_data[start..start + size]);
+    }
+
+    /// Returns the raw extra bytes of point `index`.
+    pub fn get_extra_bytes(&self, index: usize) -> &[u8] {
+        let size = self.extra_byte_record_size();
+        &self.extra_byte_data[index * size..(index + 1) * size]
+    }
+
+    fn get_extra_bytes_mut(&mut self, index: usize) -> &mut [u8] {
+        let size = self.extra_byte_record_size();
+        &mut self.extra_byte_data[index * size..(index + 1) * size]
+    }
+
+    fn extra_byte_record_size(&self) -> usize {
+        self.extra_bytes.iter().map(|d| d.size()).sum()
+    }
+
+    fn extra_byte_offset(&self, attribute: usize) -> usize {
+        self.extra_bytes[..attribute].iter().map(|d| d.size()).sum()
+    }
+
+    fn push_default_extra_bytes(&mut self) {
+        let mut offset = self.extra_byte_data.len();
+        self.extra_byte_data
+            .resize(offset + self.extra_byte_record_size(), 0u8);
+        for def in &self.extra_bytes {
+            def.default_bytes(&mut self.extra_byte_data[offset..offset + def.size()]);
+            offset += def.size();
+        }
+    }
+
+    /// Creates the extra-bytes values of the points added so far, if they don't yet exist.
+    pub(crate) fn ensure_extra_byte_data(&mut self) {
+        if self.extra_byte_data.is_empty() {
+            for _ in 0..self.point_data.len() {
+                self.push_default_extra_bytes();
+            }
+        }
+    }
+
+    /// Removes the points of a file that is being written, keeping its header and VLRs.
+    pub(crate) fn clear_points(&mut self) {
+        self.point_data.clear();
+        self.gps_data.clear();
+        self.colour_data.clear();
+        self.waveform_data.clear();
+        self.extra_byte_data.clear();
+        self.header.number_of_points = 0;
+        self.header.number_of_points_by_return = [0; 15];
+        self.header.min_x = f64::INFINITY;
+        self.header.max_x = f64::NEG_INFINITY;
+        self.header.min_y = f64::INFINITY;
+        self.header.max_y = f64::NEG_INFINITY;
+        self.header.min_z = f64::INFINITY;
+        self.header.max_z = f64::NEG_INFINITY;
+    }
+
+    /// Discards the extra-bytes values of the points, so that they are written without them.
+    pub(crate) fn clear_extra_byte_data(&mut self) {
+        self.extra_byte_data.clear();
+    }
+
+    /// Replaces the Extra Bytes VLR with one describing the attributes that are written
+    /// with the points, or removes it if the points don't have extra bytes.
+    /// Returns the provenance record of the file, i.e. the record of the tool run that
+    /// wrote it, which is held in a WhiteboxTools VLR.
+    pub fn get_provenance(&self) -> Option<Provenance> {
+        self.vlr_data
+            .iter()
+            .find(|vlr| is_provenance_vlr(vlr))
+            .and_then(|vlr| {
+                let json = String::from_utf8_lossy(&vlr.binary_data);
+                Provenance::from_json(json.trim_end_matches(char::from(0))).ok()
+            })
+    }
+
+    // Replaces any provenance VLR, e.g. one copied from an input file, with one that holds
+    // the provenance of the current tool run, if there is one.
+    fn update_provenance_vlr(&mut self) {
+        self.vlr_data.retain(|vlr| !is_provenance_vlr(vlr));
+        if let Some(provenance) = current_provenance() {
+            let binary_data = provenance.to_json().into_bytes();
+            // a VLR's length is held in two bytes; longer records are left out
+            if binary_data.len() <= u16::MAX as usize {
+                self.vlr_data.push(Vlr {
+                    reserved: 0u16,
+                    user_id: PROVENANCE_USER_ID.to_string(),
+                    record_id: PROVENANCE_RECORD_ID,
+                    record_length_after_header: binary_data.len() as u16,
+                    description: "Provenance".to_string(),
+                    binary_data,
+                });
+            }
+        }
+        self.header.number_of_vlrs = self.vlr_data.len() as u32;
+    }
+
+    fn update_extra_bytes_vlr(&mut self, include: bool) {
+        self.vlr_data.retain(|vlr| !is_extra_bytes_vlr(vlr));
+        if include && !self.extra_byte_data.is_empty() {
+            self.vlr_data.push(create_extra_bytes_vlr(&self.extra_bytes));
+        }
+        self.header.number_of_vlrs = self.vlr_data.len() as u32;
     }
 
     pub fn get_record(&self, index: usize) -> LidarPointRecord {
@@ -512,6 +770,164 @@ impl LasFile {
         if self.file_name.to_lowercase().ends_with(".zlidar") {
             return self.read_zlidar_data();
         }
+        let buffer = self.read_file_bytes()?;
+        self.read_from_buffer(buffer)
+    }
+
+    /// Reads only the points that are within `bounds`, discarding anything read before.
+    /// `LasFile::read_within` opens a file in this way without reading it first.
+    ///
+    /// For COPC (Cloud Optimized Point Cloud) files, only the octree nodes that
+    /// intersect `bounds` are decoded, down to a depth of `max_depth` (`None` reads
+    /// every level). Since each level of the octree adds detail, a shallow `max_depth`
+    /// gives a thinned version of the point cloud. For other files, all of the points
+    /// are read and then clipped to `bounds`, and `max_depth` is ignored.
+    pub fn read_bounds(&mut self, bounds: &BoundingBox, max_depth: Option<u32>) -> Result<(), Error> {
+        if is_memory_file(&self.file_name) {
+            let mut input = MEMORY_LIDAR.get(&self.file_name).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::NotFound,
+                    format!("The in-memory LiDAR file {} does not exist.", self.file_name),
+                )
+            })?;
+            input.file_mode = "r".to_string();
+            *self = input;
+            self.retain_points_within(bounds);
+            return Ok(());
+        }
+
+        // Discard anything read by an earlier call to read().
+        self.header = Default::default();
+        self.vlr_data.clear();
+        self.geokeys = Default::default();
+        self.wkt = String::new();
+        self.point_data.clear();
+        self.gps_data.clear();
+        self.colour_data.clear();
+        self.waveform_data.clear();
+        self.extra_bytes.clear();
+        self.extra_byte_data.clear();
+        self.file_mode = "r".to_string();
+
+        let file_name = self.file_name.to_lowercase();
+        if file_name.ends_with(".zlidar") {
+            self.read_zlidar_data()?;
+        } else if !file_name.ends_with(".zip") {
+            // COPC files are read through a seekable handle, so that only the parts
+            // of the file holding the selected octree nodes are loaded.
+            let mut f = BufReader::new(File::open(&self.file_name)?);
+            let buffer = if is_copc_file(&mut f)? {
+                read_copc_bounds(&mut f, bounds, max_depth)?
+            } else {
+                self.read_file_bytes()?
+            };
+            self.read_from_buffer(buffer)?;
+        } else {
+            let buffer = self.read_file_bytes()?;
+            self.read_from_buffer(buffer)?;
+        }
+        self.retain_points_within(bounds);
+        Ok(())
+    }
+
+    /// Opens a LAS file and reads only the points that are within `bounds`, as
+    /// `read_bounds` does.
+    pub fn read_within(
+        file_name: &str,
+        bounds: &BoundingBox,
+        max_depth: Option<u32>,
+    ) -> Result<LasFile, Error> {
+        let mut lf = LasFile {
+            file_name: file_name.to_string(),
+            wkt: String::new(),
+            ..Default::default()
+        };
+        lf.read_bounds(bounds, max_depth)?;
+        lf.use_point_intensity = true;
+        lf.use_point_userdata = true;
+        Ok(lf)
+    }
+
+    /// Returns true if the file is a COPC (Cloud Optimized Point Cloud) file, from which
+    /// `read_bounds` decodes only the octree nodes that it needs. Only the header and VLRs
+    /// are read.
+    pub fn is_copc(file_name: &str) -> Result<bool, Error> {
+        let lower = file_name.to_lowercase();
+        if is_memory_file(file_name) || lower.ends_with(".zlidar") || lower.ends_with(".zip") {
+            return Ok(false);
+        }
+        is_copc_file(&mut BufReader::new(File::open(file_name)?))
+    }
+
+    /// Removes the points that are outside of `bounds` and updates the point counts
+    /// and extent of the header.
+    fn retain_points_within(&mut self, bounds: &BoundingBox) {
+        let keep: Vec<bool> = (0..self.point_data.len())
+            .map(|i| {
+                let p = self.get_transformed_coords(i);
+                p.x >= bounds.min_x && p.x <= bounds.max_x && p.y >= bounds.min_y && p.y <= bounds.max_y
+            })
+            .collect();
+        fn retain<T>(data: &mut Vec<T>, keep: &[bool]) {
+            if data.len() == keep.len() {
+                let mut i = 0;
+                data.retain(|_| {
+                    i += 1;
+                    keep[i - 1]
+                });
+            }
+        }
+        retain(&mut self.point_data, &keep);
+        retain(&mut self.gps_data, &keep);
+        retain(&mut self.colour_data, &keep);
+        retain(&mut self.waveform_data, &keep);
+        if !self.extra_byte_data.is_empty() {
+            let size = self.extra_byte_record_size();
+            let mut i = 0;
+            self.extra_byte_data = self
+                .extra_byte_data
+                .chunks_exact(size)
+                .filter(|_| {
+                    i += 1;
+                    keep[i - 1]
+                })
+                .flatten()
+                .copied()
+                .collect();
+        }
+
+        self.header.number_of_points = self.point_data.len() as u64;
+        if self.header.number_of_points_old != 0 {
+            self.header.number_of_points_old = self.point_data.len() as u32;
+        }
+        self.header.number_of_points_by_return = [0u64; 15];
+        self.header.min_x = f64::INFINITY;
+        self.header.max_x = f64::NEG_INFINITY;
+        self.header.min_y = f64::INFINITY;
+        self.header.max_y = f64::NEG_INFINITY;
+        self.header.min_z = f64::INFINITY;
+        self.header.max_z = f64::NEG_INFINITY;
+        for i in 0..self.point_data.len() {
+            let r = self.point_data[i].return_number() as usize;
+            if (1..=15).contains(&r) {
+                self.header.number_of_points_by_return[r - 1] += 1;
+            }
+            let p = self.get_transformed_coords(i);
+            self.header.min_x = self.header.min_x.min(p.x);
+            self.header.max_x = self.header.max_x.max(p.x);
+            self.header.min_y = self.header.min_y.min(p.y);
+            self.header.max_y = self.header.max_y.max(p.y);
+            self.header.min_z = self.header.min_z.min(p.z);
+            self.header.max_z = self.header.max_z.max(p.z);
+        }
+        for r in 0..5 {
+            self.header.number_of_points_by_return_old[r] =
+                self.header.number_of_points_by_return[r] as u32;
+        }
+    }
+
+    /// Reads the bytes of the file, or of the LAS file within a zip archive.
+    fn read_file_bytes(&self) -> Result<Vec<u8>, Error> {
         let buffer = match self.file_name.to_lowercase().ends_with(".zip") {
             false => {
                 let mut f = File::open(&self.file_name).expect("Error opening LAS file.");
@@ -577,6 +993,16 @@ impl LasFile {
                 buffer
             }
         };
+        Ok(buffer)
+    }
+
+    fn read_from_buffer(&mut self, buffer: Vec<u8>) -> Result<(), Error> {
+        // LAZ files are decompressed into the bytes of the equivalent LAS file.
+        let buffer = if is_laz(&buffer) {
+            decompress_laz(buffer, self.file_mode == "rh")?
+        } else {
+            buffer
+        };
 
         if buffer.len() < 375 {
             // The buffer is less than the header size. This is a sign
@@ -720,6 +1146,8 @@ impl LasFile {
                     String::from_utf8_lossy(&vlr.binary_data[0..vlr.binary_data.len() - skip])
                         .trim()
                         .to_string();
+            } else if is_extra_bytes_vlr(&vlr) {
+                self.extra_bytes = parse_extra_bytes_vlr(&vlr);
             }
             self.vlr_data.push(vlr);
         }
@@ -1152,6 +1580,22 @@ impl LasFile {
                     }
                 }
             }
+
+            // Read the values of any extra-bytes attributes, which follow the standard fields.
+            let extra_size = self.extra_byte_record_size();
+            if extra_size > skip_bytes {
+                // the descriptors don't match the point records
+                self.extra_bytes.clear();
+            } else if extra_size > 0 {
+                let record_length = self.header.point_record_length as usize;
+                self.extra_byte_data = vec![0u8; extra_size * self.point_data.len()];
+                let mut pos = self.header.offset_to_points as usize + record_length - skip_bytes;
+                for record in self.extra_byte_data.chunks_exact_mut(extra_size) {
+                    bor.seek(pos);
+                    bor.read_exact(record)?;
+                    pos += record_length;
+                }
+            }
         }
 
         // for i in 100_000..100_200 {
@@ -2627,8 +3071,19 @@ impl LasFile {
             self.header.z_scale_factor = dec;
         }
 
-        if !self.file_name.to_lowercase().ends_with(".zip")
+        if is_memory_file(&self.file_name) {
+            // The file is stored as it would be read back from disk, with the scaled
+            // coordinates and completed header of a LAS file.
+            let mut buffer = vec![];
+            self.write_data(&mut buffer)?;
+            if buffer.len() < 375 {
+                buffer.resize(375, 0u8);
+            }
+            let output = LasFile::from_buffer(&self.file_name, "r", buffer)?;
+            MEMORY_LIDAR.insert(&self.file_name, output);
+        } else if !self.file_name.to_lowercase().ends_with(".zip")
             && !self.file_name.to_lowercase().ends_with(".zlidar")
+            && !self.file_name.to_lowercase().ends_with(".laz")
         {
             let f = File::create(&self.file_name)?;
             let mut writer = BufWriter::new(f);
@@ -2641,6 +3096,12 @@ impl LasFile {
                 self.compression = ZlidarCompression::Brotli { level: 5u8 };
             }
             self.write_zlidar_data(&mut writer)?;
+        } else if self.file_name.to_lowercase().ends_with(".laz") {
+            let mut las_data = vec![];
+            self.write_data(&mut las_data)?;
+            let f = File::create(&self.file_name)?;
+            let mut writer = BufWriter::new(f);
+            writer.write_all(&compress_las(&las_data)?)?;
         } else {
             let f = File::create(&self.file_name)?;
             let mut writer = ZipWriter::new(f);
@@ -2661,7 +3122,15 @@ impl LasFile {
         Ok(())
     }
 
-    fn write_data<W: Write>(&mut self, writer: &mut W) -> Result<(), Error> {
+    pub(crate) fn write_data<W: Write>(&mut self, writer: &mut W) -> Result<(), Error> {
+        self.update_extra_bytes_vlr(true);
+        self.update_provenance_vlr();
+        let extra_size = if self.extra_byte_data.is_empty() {
+            0
+        } else {
+            self.extra_byte_record_size()
+        };
+
         /////////////////////////////////
         // Write the header to the file /
         /////////////////////////////////
@@ -2808,6 +3277,7 @@ impl LasFile {
             //if !self.use_point_intensity && !self.use_point_userdata {
             self.header.point_record_length = rec_lengths[self.header.point_format as usize][3];
         }
+        self.header.point_record_length += extra_size as u16;
 
         u16_bytes = unsafe { mem::transmute(self.header.point_record_length) };
         writer.write_all(&u16_bytes)?;
@@ -2956,6 +3426,10 @@ impl LasFile {
 
                     u16_bytes = unsafe { mem::transmute(self.point_data[i].point_source_id) };
                     writer.write_all(&u16_bytes)?;
+
+                    if extra_size > 0 {
+                        writer.write_all(self.get_extra_bytes(i))?;
+                    }
                 }
             }
             1 => {
@@ -3011,6 +3485,10 @@ impl LasFile {
 
                     u64_bytes = unsafe { mem::transmute(self.gps_data[i]) };
                     writer.write_all(&u64_bytes)?;
+
+                    if extra_size > 0 {
+                        writer.write_all(self.get_extra_bytes(i))?;
+                    }
                 }
             }
             2 => {
@@ -3072,6 +3550,10 @@ impl LasFile {
 
                     u16_bytes = unsafe { mem::transmute(self.colour_data[i].blue) };
                     writer.write_all(&u16_bytes)?;
+
+                    if extra_size > 0 {
+                        writer.write_all(self.get_extra_bytes(i))?;
+                    }
                 }
             }
             3 => {
@@ -3136,6 +3618,10 @@ impl LasFile {
 
                     u16_bytes = unsafe { mem::transmute(self.colour_data[i].blue) };
                     writer.write_all(&u16_bytes)?;
+
+                    if extra_size > 0 {
+                        writer.write_all(self.get_extra_bytes(i))?;
+                    }
                 }
             }
             _ => {
@@ -3147,6 +3633,10 @@ impl LasFile {
     }
 
     fn write_zlidar_data<W: Write>(&mut self, writer: &mut W) -> Result<(), Error> {
+        // zLidar files do not store extra bytes
+        self.update_extra_bytes_vlr(false);
+        self.update_provenance_vlr();
+
         /////////////////////////////////
         // Write the header to the file /
         /////////////////////////////////
@@ -6428,6 +6918,24 @@ impl LidarPointRecord {
             LidarPointRecord::PointRecord10 { point_data, .. } => point_data.clone(),
         };
     }
+
+    /// Replaces the point data (coordinates, intensity, classification, etc.) of the record,
+    /// leaving the GPS time, colour and waveform data unchanged.
+    pub fn set_point_data(&mut self, value: PointData) {
+        match self {
+            LidarPointRecord::PointRecord0 { point_data } => *point_data = value,
+            LidarPointRecord::PointRecord1 { point_data, .. } => *point_data = value,
+            LidarPointRecord::PointRecord2 { point_data, .. } => *point_data = value,
+            LidarPointRecord::PointRecord3 { point_data, .. } => *point_data = value,
+            LidarPointRecord::PointRecord4 { point_data, .. } => *point_data = value,
+            LidarPointRecord::PointRecord5 { point_data, .. } => *point_data = value,
+            LidarPointRecord::PointRecord6 { point_data, .. } => *point_data = value,
+            LidarPointRecord::PointRecord7 { point_data, .. } => *point_data = value,
+            LidarPointRecord::PointRecord8 { point_data, .. } => *point_data = value,
+            LidarPointRecord::PointRecord9 { point_data, .. } => *point_data = value,
+            LidarPointRecord::PointRecord10 { point_data, .. } => *point_data = value,
+        }
+    }
 }
 
 #[derive(Default, Copy, Clone, Debug)]
@@ -0,0 +1,664 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: LasReader and LasWriter process LAS and LAZ files in chunks of points, so
+that the memory needed by point-wise tools does not depend on the size of the file.
+Each chunk is returned as a LasFile holding the header and VLRs of the file and a
+subset of its points, so tools can use the usual LasFile functions on it. Zipped
+LAS and zLidar files can't be streamed and are held in memory in their entirety, as
+are in-memory (mem://) files.
+*/
+
+use super::header::LasHeader;
+use super::las::{LasFile, LidarPointRecord};
+use super::laz::{is_laz, LazStreamReader, LazStreamWriter};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Error, ErrorKind, Read, Seek, SeekFrom, Write};
+use whitebox_common::memory_store::is_memory_file;
+
+/// The default number of points in each chunk.
+const CHUNK_SIZE: usize = 1_000_000;
+
+/// The minimum size of a buffer that `LasFile` will parse.
+const MIN_BUFFER_SIZE: usize = 375;
+
+/// The sizes of the LAS 1.3 header written by `LasFile` and of the LAS 1.4 header,
+/// which adds the extended VLR fields and the 64-bit point counts.
+const LAS13_HEADER_SIZE: usize = 235;
+const LAS14_HEADER_SIZE: usize = 375;
+
+enum PointSource {
+    Las {
+        reader: BufReader<File>,
+        remaining: u64,
+    },
+    Laz(LazStreamReader),
+    InMemory {
+        done: bool,
+    },
+}
+
+/// Reads the points of a LAS or LAZ file in chunks, without reading the whole file
+/// into memory. A `LasReader` is an iterator over the chunks, each of which is a
+/// `LasFile` containing up to `chunk_size` points. The header of each chunk is that
+/// of the file, apart from the number of points.
+///
+/// ```ignore
+/// let mut reader = LasReader::new("input.las")?;
+/// let mut writer = LasWriter::new("output.laz", reader.get_header_file())?;
+/// for chunk in &mut reader {
+///     let input = chunk?;
+///     for i in 0..input.header.number_of_points as usize {
+///         if input[i].classification() == 2 {
+///             writer.add_point_record_from(&input, i)?;
+///         }
+///     }
+/// }
+/// writer.close()?;
+/// ```
+pub struct LasReader {
+    file_name: String,
+    header_file: LasFile,
+    // the bytes of the header and VLRs of the equivalent LAS file
+    prefix: Vec<u8>,
+    source: PointSource,
+    // decoded point records that have not yet been returned
+    pending: Vec<u8>,
+    chunk_size: usize,
+    points_read: u64,
+}
+
+impl LasReader {
+    /// Opens a LAS or LAZ file for reading in chunks, reading only its header and VLRs.
+    pub fn new(file_name: &str) -> Result<LasReader, Error> {
+        let lower = file_name.to_lowercase();
+        if lower.ends_with(".zip") || lower.ends_with(".zlidar") || is_memory_file(file_name) {
+            return Ok(LasReader {
+                file_name: file_name.to_string(),
+                header_file: LasFile::new(file_name, "rh")?,
+                prefix: vec![],
+                source: PointSource::InMemory { done: false },
+                pending: vec![],
+                chunk_size: CHUNK_SIZE,
+                points_read: 0,
+            });
+        }
+
+        let mut reader = BufReader::new(File::open(file_name)?);
+        let mut header = vec![];
+        reader.by_ref().take(227).read_to_end(&mut header)?;
+        if header.len() < 227 || &header[0..4] != b"LASF" {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("The file {} does not appear to be a LAS file.", file_name),
+            ));
+        }
+        let offset_to_points = u32::from_le_bytes([header[96], header[97], header[98], header[99]]);
+        let mut prefix = vec![0u8; offset_to_points as usize];
+        reader.seek(SeekFrom::Start(0))?;
+        reader.read_exact(&mut prefix)?;
+
+        let source = if is_laz(&prefix) {
+            match LazStreamReader::new(reader, &prefix)? {
+                Some((stream, las_prefix)) => {
+                    prefix = las_prefix;
+                    PointSource::Laz(stream)
+                }
+                None => {
+                    // the points are not chunked and must all be decoded at once
+                    return Ok(LasReader {
+                        file_name: file_name.to_string(),
+                        header_file: LasFile::new(file_name, "rh")?,
+                        prefix: vec![],
+                        source: PointSource::InMemory { done: false },
+                        pending: vec![],
+                        chunk_size: CHUNK_SIZE,
+                        points_read: 0,
+                    });
+                }
+            }
+        } else {
+            PointSource::Las {
+                reader,
+                remaining: 0,
+            }
+        };
+
+        let header_file = LasFile::from_buffer(file_name, "rh", padded(prefix.clone()))?;
+        let source = match source {
+            PointSource::Las { reader, .. } => PointSource::Las {
+                reader,
+                remaining: header_file.header.number_of_points,
+            },
+            s => s,
+        };
+        Ok(LasReader {
+            file_name: file_name.to_string(),
+            header_file,
+            prefix,
+            source,
+            pending: vec![],
+            chunk_size: CHUNK_SIZE,
+            points_read: 0,
+        })
+    }
+
+    /// Sets the maximum number of points in each chunk (1,000,000 by default).
+    pub fn set_chunk_size(&mut self, chunk_size: usize) {
+        self.chunk_size = chunk_size.max(1);
+    }
+
+    /// Returns a `LasFile` holding the header and VLRs of the file, but no points.
+    /// It can be used to create a `LasWriter` for a file with the same header.
+    pub fn get_header_file(&self) -> &LasFile {
+        &self.header_file
+    }
+
+    /// Returns the number of points that have been read so far.
+    pub fn get_points_read(&self) -> u64 {
+        self.points_read
+    }
+
+    /// Reads the next chunk of points, returning `None` once all of the points have
+    /// been read.
+    pub fn read_chunk(&mut self) -> Result<Option<LasFile>, Error> {
+        let record_length = self.header_file.header.point_record_length as usize;
+        let chunk_bytes = self.chunk_size * record_length;
+        let records = match &mut self.source {
+            PointSource::Las { reader, remaining } => {
+                if *remaining == 0 {
+                    return Ok(None);
+                }
+                let n = (*remaining).min(self.chunk_size as u64);
+                let mut records = vec![0u8; n as usize * record_length];
+                reader.read_exact(&mut records)?;
+                *remaining -= n;
+                records
+            }
+            PointSource::Laz(stream) => {
+                while self.pending.len() < chunk_bytes {
+                    match stream.next_chunk()? {
+                        Some(records) => self.pending.extend_from_slice(&records),
+                        None => break,
+                    }
+                }
+                if self.pending.is_empty() {
+                    return Ok(None);
+                }
+                let n = self.pending.len().min(chunk_bytes);
+                self.pending.drain(..n).collect()
+            }
+            PointSource::InMemory { done } => {
+                if *done {
+                    return Ok(None);
+                }
+                *done = true;
+                let input = LasFile::new(&self.file_name, "r")?;
+                self.points_read += input.header.number_of_points;
+                return Ok(Some(input));
+            }
+        };
+        self.points_read += (records.len() / record_length) as u64;
+        self.create_chunk(records).map(Some)
+    }
+
+    fn create_chunk(&self, records: Vec<u8>) -> Result<LasFile, Error> {
+        let num_points = records.len() / self.header_file.header.point_record_length as usize;
+        let mut buffer = Vec::with_capacity(self.prefix.len() + records.len());
+        buffer.extend_from_slice(&self.prefix);
+        if self.header_file.header.number_of_points_old != 0 {
+            buffer[107..111].copy_from_slice(&(num_points as u32).to_le_bytes());
+        }
+        if self.header_file.header.version_minor > 3 && self.prefix.len() >= 255 {
+            buffer[247..255].copy_from_slice(&(num_points as u64).to_le_bytes());
+        }
+        buffer.extend_from_slice(&records);
+        LasFile::from_buffer(&self.file_name, "r", padded(buffer))
+    }
+}
+
+impl Iterator for LasReader {
+    type Item = Result<LasFile, Error>;
+
+    fn next(&mut self) -> Option<Result<LasFile, Error>> {
+        self.read_chunk().transpose()
+    }
+}
+
+fn padded(mut buffer: Vec<u8>) -> Vec<u8> {
+    if buffer.len() < MIN_BUFFER_SIZE {
+        buffer.resize(MIN_BUFFER_SIZE, 0u8);
+    }
+    buffer
+}
+
+/// Writes a LAS or LAZ file incrementally. Points are added in the same way as they
+/// are to a `LasFile` and are written to disk in chunks; the point counts and extent
+/// in the header are updated when the file is closed. Files with a .zip or .zlidar
+/// extension, and in-memory files, are held in memory and written when the file is
+/// closed.
+///
+/// The point counts are written in the 32-bit fields of a LAS 1.3 header, unless the
+/// input has more points than these can hold, in which case the file is written with
+/// a LAS 1.4 header and 64-bit counts. Adding more points than the header of the file
+/// can hold returns an error before any of them are written.
+pub struct LasWriter {
+    buffer: LasFile,
+    writer: Option<BufWriter<File>>,
+    laz: Option<LazStreamWriter>,
+    is_laz: bool,
+    started: bool,
+    include_extra_bytes: bool,
+    wide_point_counts: bool,
+    chunk_table_position: u64,
+    number_of_points: u64,
+    number_of_points_by_return: [u64; 15],
+    min: [f64; 3],
+    max: [f64; 3],
+}
+
+impl LasWriter {
+    /// Creates a file with the header and VLRs of `input`, as with
+    /// `LasFile::initialize_using_file`.
+    pub fn new(file_name: &str, input: &LasFile) -> Result<LasWriter, Error> {
+        let lower = file_name.to_lowercase();
+        let writer =
+            if lower.ends_with(".zip") || lower.ends_with(".zlidar") || is_memory_file(file_name) {
+                None
+            } else {
+                Some(BufWriter::new(File::create(file_name)?))
+            };
+        Ok(LasWriter {
+            buffer: LasFile::initialize_using_file(file_name, input),
+            writer,
+            laz: None,
+            is_laz: lower.ends_with(".laz"),
+            started: false,
+            include_extra_bytes: false,
+            wide_point_counts: input.header.number_of_points > u32::MAX as u64,
+            chunk_table_position: 0,
+            number_of_points: 0,
+            number_of_points_by_return: [0; 15],
+            min: [f64::INFINITY; 3],
+            max: [f64::NEG_INFINITY; 3],
+        })
+    }
+
+    /// Returns the header, e.g. to set the system ID. The point counts and extent
+    /// are set when the file is closed.
+    pub fn header_mut(&mut self) -> &mut LasHeader {
+        &mut self.buffer.header
+    }
+
+    /// Returns the number of points added so far.
+    pub fn get_number_of_points(&self) -> u64 {
+        self.number_of_points + self.buffer.header.number_of_points
+    }
+
+    /// Adds a point record; see `LasFile::add_point_record`.
+    pub fn add_point_record(&mut self, point: LidarPointRecord) -> Result<(), Error> {
+        self.buffer.add_point_record(point);
+        self.flush_if_full()
+    }
+
+    /// Adds point `index` of `input`; see `LasFile::add_point_record_from`.
+    pub fn add_point_record_from(&mut self, input: &LasFile, index: usize) -> Result<(), Error> {
+        self.buffer.add_point_record_from(input, index);
+        self.flush_if_full()
+    }
+
+    /// Adds a point record derived from point `index` of `input`; see
+    /// `LasFile::add_point_record_with_extra_bytes`.
+    pub fn add_point_record_with_extra_bytes(
+        &mut self,
+        point: LidarPointRecord,
+        input: &LasFile,
+        index: usize,
+    ) -> Result<(), Error> {
+        self.buffer
+            .add_point_record_with_extra_bytes(point, input, index);
+        self.flush_if_full()
+    }
+
+    fn flush_if_full(&mut self) -> Result<(), Error> {
+        if self.writer.is_some() && self.buffer.header.number_of_points as usize >= CHUNK_SIZE {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Writes the buffered points, preceded by the header and VLRs if this is the
+    /// first chunk.
+    fn flush(&mut self) -> Result<(), Error> {
+        // The first chunk decides whether the points have extra bytes, so that all
+        // of the point records have the same length.
+        if !self.started {
+            self.include_extra_bytes = self.buffer.has_extra_bytes();
+        }
+        if self.include_extra_bytes {
+            self.buffer.ensure_extra_byte_data();
+        } else {
+            self.buffer.clear_extra_byte_data();
+        }
+
+        if !self.wide_point_counts
+            && self.number_of_points + self.buffer.header.number_of_points > u32::MAX as u64
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The number of points in this file requires a 64-bit format, which is only written when the input file has more than 4,294,967,295 points.",
+            ));
+        }
+
+        let mut bytes = vec![];
+        self.buffer.write_data(&mut bytes)?;
+        if !self.started && self.wide_point_counts {
+            bytes = las14_header(bytes);
+        }
+        let header = &self.buffer.header;
+        self.number_of_points += header.number_of_points;
+        for (total, n) in self
+            .number_of_points_by_return
+            .iter_mut()
+            .zip(header.number_of_points_by_return.iter())
+        {
+            *total += n;
+        }
+        if header.number_of_points > 0 {
+            let (min, max) = (
+                [header.min_x, header.min_y, header.min_z],
+                [header.max_x, header.max_y, header.max_z],
+            );
+            for i in 0..3 {
+                self.min[i] = self.min[i].min(min[i]);
+                self.max[i] = self.max[i].max(max[i]);
+            }
+        }
+
+        let offset_to_points =
+            u32::from_le_bytes([bytes[96], bytes[97], bytes[98], bytes[99]]) as usize;
+        let writer = self.writer.as_mut().unwrap();
+        if !self.started {
+            if self.is_laz {
+                let (laz, laz_header) = LazStreamWriter::new(&bytes[..offset_to_points])?;
+                writer.write_all(&laz_header)?;
+                self.chunk_table_position = laz_header.len() as u64 - 8;
+                self.laz = Some(laz);
+            } else {
+                writer.write_all(&bytes[..offset_to_points])?;
+            }
+            self.started = true;
+        }
+        match self.laz.as_mut() {
+            Some(laz) => writer.write_all(&laz.write(&bytes[offset_to_points..]))?,
+            None => writer.write_all(&bytes[offset_to_points..])?,
+        }
+        self.buffer.clear_points();
+        Ok(())
+    }
+
+    /// Writes any remaining points and completes the header of the file.
+    pub fn close(mut self) -> Result<(), Error> {
+        if self.writer.is_none() {
+            return self.buffer.write();
+        }
+        self.flush()?;
+        let writer = self.writer.as_mut().unwrap();
+        if let Some(laz) = self.laz.take() {
+            let (points, table) = laz.finish();
+            writer.write_all(&points)?;
+            let table_offset = writer.stream_position()?;
+            writer.write_all(&table)?;
+            writer.seek(SeekFrom::Start(self.chunk_table_position))?;
+            writer.write_all(&table_offset.to_le_bytes())?;
+        }
+
+        // the header fields that depend on all of the points. The legacy counts of a
+        // LAS 1.4 file are zero if there are too many points for them to hold.
+        let legacy = self.number_of_points <= u32::MAX as u64;
+        let legacy_count = |n: u64| if legacy { n as u32 } else { 0 };
+        writer.seek(SeekFrom::Start(107))?;
+        writer.write_all(&legacy_count(self.number_of_points).to_le_bytes())?;
+        for n in &self.number_of_points_by_return[0..5] {
+            writer.write_all(&legacy_count(*n).to_le_bytes())?;
+        }
+        writer.seek(SeekFrom::Start(179))?;
+        for i in 0..3 {
+            writer.write_all(&self.max[i].to_le_bytes())?;
+            writer.write_all(&self.min[i].to_le_bytes())?;
+        }
+        if self.wide_point_counts {
+            writer.seek(SeekFrom::Start(247))?;
+            writer.write_all(&self.number_of_points.to_le_bytes())?;
+            for n in &self.number_of_points_by_return {
+                writer.write_all(&n.to_le_bytes())?;
+            }
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Converts the LAS 1.3 header and VLRs written by `LasFile` into a LAS 1.4 header and
+/// the same VLRs. The fields added in LAS 1.4 are zero, the point counts being written
+/// when the file is closed.
+fn las14_header(mut bytes: Vec<u8>) -> Vec<u8> {
+    let extra = LAS14_HEADER_SIZE - LAS13_HEADER_SIZE;
+    bytes.splice(
+        LAS13_HEADER_SIZE..LAS13_HEADER_SIZE,
+        std::iter::repeat_n(0u8, extra),
+    );
+    bytes[25] = 4;
+    bytes[94..96].copy_from_slice(&(LAS14_HEADER_SIZE as u16).to_le_bytes());
+    let offset_to_points = u32::from_le_bytes([bytes[96], bytes[97], bytes[98], bytes[99]]);
+    bytes[96..100].copy_from_slice(&(offset_to_points + extra as u32).to_le_bytes());
+    bytes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{LasHeader, PointData};
+    use std::env;
+    use std::fs;
+
+    fn test_file(name: &str) -> String {
+        env::temp_dir()
+            .join(format!("wbt_las_stream_{}", name))
+            .to_string_lossy()
+            .to_string()
+    }
+
+    #[test]
+    fn test_streaming_round_trip() {
+        // more points than in one LASzip chunk, so that chunks are split and joined
+        let num_points = 120_000usize;
+        let las_file = test_file("input.las");
+        let mut input = LasFile::new(&las_file, "w").unwrap();
+        input.add_header(LasHeader {
+            project_id_used: true,
+            point_format: 1,
+            x_scale_factor: 0.01,
+            y_scale_factor: 0.01,
+            z_scale_factor: 0.01,
+            x_offset: 0.0,
+            y_offset: 0.0,
+            z_offset: 0.0,
+            ..Default::default()
+        });
+        for i in 0..num_points {
+            let mut point_data = PointData {
+                x: (i % 1000) as i32 * 7,
+                y: (i / 1000) as i32 * 11,
+                z: (i * 31 % 5000) as i32,
+                intensity: (i % 65536) as u16,
+                ..Default::default()
+            };
+            point_data.set_return_number(1);
+            point_data.set_number_of_returns(1);
+            point_data.set_classification((i % 7) as u8);
+            input.add_point_record(LidarPointRecord::PointRecord1 {
+                point_data,
+                gps_data: i as f64 * 0.5,
+            });
+        }
+        input.write().unwrap();
+        let input = LasFile::new(&las_file, "r").unwrap();
+
+        // copy every other class through the streaming reader and writer
+        let laz_file = test_file("output.laz");
+        let mut reader = LasReader::new(&las_file).unwrap();
+        reader.set_chunk_size(7_000);
+        let mut writer = LasWriter::new(&laz_file, reader.get_header_file()).unwrap();
+        let mut num_chunks = 0;
+        for chunk in &mut reader {
+            let chunk = chunk.unwrap();
+            assert!(chunk.header.number_of_points <= 7_000);
+            for i in 0..chunk.header.number_of_points as usize {
+                if chunk[i].classification().is_multiple_of(2) {
+                    writer.add_point_record_from(&chunk, i).unwrap();
+                }
+            }
+            num_chunks += 1;
+        }
+        writer.close().unwrap();
+        assert_eq!(num_chunks, num_points.div_ceil(7_000));
+        assert_eq!(reader.get_points_read(), num_points as u64);
+
+        let expected: Vec<usize> = (0..num_points)
+            .filter(|&i| input[i].classification().is_multiple_of(2))
+            .collect();
+        let output = LasFile::new(&laz_file, "r").unwrap();
+        assert_eq!(output.header.number_of_points, expected.len() as u64);
+        assert_eq!(output.header.max_x, input.header.max_x);
+        assert_eq!(output.header.min_y, input.header.min_y);
+
+        // and read the LAZ output back in chunks
+        let mut reader = LasReader::new(&laz_file).unwrap();
+        reader.set_chunk_size(30_000);
+        let mut n = 0;
+        for chunk in &mut reader {
+            let chunk = chunk.unwrap();
+            for i in 0..chunk.header.number_of_points as usize {
+                let j = expected[n];
+                assert_eq!(chunk[i].x, input[j].x);
+                assert_eq!(chunk[i].z, input[j].z);
+                assert_eq!(chunk.get_gps_time(i), input.get_gps_time(j));
+                n += 1;
+            }
+        }
+        assert_eq!(n, expected.len());
+
+        let _ = fs::remove_file(&las_file);
+        let _ = fs::remove_file(&laz_file);
+    }
+
+    #[test]
+    fn test_64_bit_point_counts() {
+        let las_file = test_file("narrow.las");
+        let mut input = LasFile::new(&las_file, "w").unwrap();
+        input.add_header(LasHeader {
+            project_id_used: true,
+            point_format: 1,
+            x_scale_factor: 0.01,
+            y_scale_factor: 0.01,
+            z_scale_factor: 0.01,
+            ..Default::default()
+        });
+        for i in 0..10 {
+            let mut point_data = PointData {
+                x: i * 100,
+                y: i * 200,
+                ..Default::default()
+            };
+            point_data.set_return_number(1);
+            point_data.set_number_of_returns(1);
+            input.add_point_record(LidarPointRecord::PointRecord1 {
+                point_data,
+                gps_data: i as f64,
+            });
+        }
+        input.write().unwrap();
+
+        // an input with more points than a 32-bit count can hold is copied to LAS 1.4 files
+        let mut input = LasFile::new(&las_file, "r").unwrap();
+        input.header.number_of_points = u32::MAX as u64 + 1;
+        for name in ["wide.las", "wide.laz"] {
+            let output_file = test_file(name);
+            let mut writer = LasWriter::new(&output_file, &input).unwrap();
+            for i in 0..10 {
+                writer.add_point_record_from(&input, i).unwrap();
+            }
+            writer.close().unwrap();
+
+            let bytes = fs::read(&output_file).unwrap();
+            assert_eq!(bytes[25], 4);
+            assert_eq!(u16::from_le_bytes([bytes[94], bytes[95]]), 375);
+            let mut count = [0u8; 8];
+            count.copy_from_slice(&bytes[247..255]);
+            assert_eq!(u64::from_le_bytes(count), 10);
+            count.copy_from_slice(&bytes[255..263]);
+            assert_eq!(u64::from_le_bytes(count), 10);
+
+            let output = LasFile::new(&output_file, "r").unwrap();
+            assert_eq!(output.header.version_minor, 4);
+            assert_eq!(output.header.number_of_points, 10);
+            for i in 0..10 {
+                assert_eq!(output[i].x, input[i].x);
+                assert_eq!(output[i].y, input[i].y);
+                assert_eq!(output.get_gps_time(i), input.get_gps_time(i));
+            }
+            let _ = fs::remove_file(&output_file);
+        }
+        let _ = fs::remove_file(&las_file);
+    }
+
+    #[test]
+    fn test_provenance_vlr() {
+        use whitebox_common::provenance::{set_current_provenance, Provenance};
+        let las_file = test_file("provenance.las");
+        let mut output = LasFile::new(&las_file, "w").unwrap();
+        output.add_header(LasHeader {
+            project_id_used: true,
+            point_format: 0,
+            x_scale_factor: 0.01,
+            y_scale_factor: 0.01,
+            z_scale_factor: 0.01,
+            ..Default::default()
+        });
+        for i in 0..10 {
+            let mut point_data = PointData {
+                x: i * 100,
+                y: i * 200,
+                ..Default::default()
+            };
+            point_data.set_return_number(1);
+            point_data.set_number_of_returns(1);
+            output.add_point_record(LidarPointRecord::PointRecord0 { point_data });
+        }
+        let provenance = Provenance::new("LidarElevationSlice", "1.5.0");
+        set_current_provenance(Some(provenance.clone()));
+        output.write().unwrap();
+        set_current_provenance(None);
+        assert_eq!(crate::read_provenance(&las_file).unwrap(), Some(provenance));
+
+        // a copy written outside of a tool run doesn't inherit the record
+        let input = LasFile::new(&las_file, "r").unwrap();
+        let copy_file = test_file("provenance_copy.las");
+        let mut copy = LasFile::initialize_using_file(&copy_file, &input);
+        for i in 0..10 {
+            copy.add_point_record(input.get_record(i));
+        }
+        copy.write().unwrap();
+        let copy = LasFile::new(&copy_file, "r").unwrap();
+        assert_eq!(copy.get_provenance(), None);
+        assert_eq!(copy.header.number_of_points, 10);
+
+        let _ = fs::remove_file(&las_file);
+        let _ = fs::remove_file(&copy_file);
+    }
+}
@@ -0,0 +1,532 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+
+Notes: The adaptive arithmetic coder used by LASzip. It is a port of Amir Said's
+FastAC coder, with the modifications made by Martin Isenburg for LASzip. The
+encoder and decoder must produce and consume identical byte streams for the
+compressed files to be readable by other LAZ software, so the arithmetic below
+deliberately mirrors the reference implementation, including its 32-bit
+wrapping behaviour.
+*/
+
+const AC_MIN_LENGTH: u32 = 0x0100_0000;
+const AC_MAX_LENGTH: u32 = 0xFFFF_FFFF;
+
+const BM_LENGTH_SHIFT: u32 = 13;
+const BM_MAX_COUNT: u32 = 1 << BM_LENGTH_SHIFT;
+
+const DM_LENGTH_SHIFT: u32 = 15;
+const DM_MAX_COUNT: u32 = 1 << DM_LENGTH_SHIFT;
+
+/// An adaptive model of the probabilities of a multi-symbol alphabet.
+#[derive(Clone)]
+pub(crate) struct ArithmeticModel {
+    symbols: u32,
+    compress: bool,
+    distribution: Vec<u32>,
+    symbol_count: Vec<u32>,
+    decoder_table: Vec<u32>,
+    total_count: u32,
+    update_cycle: u32,
+    symbols_until_update: u32,
+    last_symbol: u32,
+    table_size: u32,
+    table_shift: u32,
+}
+
+impl ArithmeticModel {
+    /// Creates a model for an alphabet of `symbols` symbols. Models used for
+    /// decoding (`compress == false`) with more than 16 symbols build a lookup
+    /// table that speeds up the symbol search.
+    pub fn new(symbols: u32, compress: bool) -> ArithmeticModel {
+        debug_assert!((2..=2048).contains(&symbols));
+        let (table_size, table_shift) = if !compress && symbols > 16 {
+            let mut table_bits = 3u32;
+            while symbols > (1 << (table_bits + 2)) {
+                table_bits += 1;
+            }
+            (1u32 << table_bits, DM_LENGTH_SHIFT - table_bits)
+        } else {
+            (0, 0)
+        };
+        let mut model = ArithmeticModel {
+            symbols,
+            compress,
+            distribution: vec![0u32; symbols as usize],
+            symbol_count: vec![1u32; symbols as usize],
+            decoder_table: vec![
+                0u32;
+                if table_size > 0 {
+                    table_size as usize + 2
+                } else {
+                    0
+                }
+            ],
+            total_count: 0,
+            update_cycle: symbols,
+            symbols_until_update: 0,
+            last_symbol: symbols - 1,
+            table_size,
+            table_shift,
+        };
+        model.update();
+        model.update_cycle = (symbols + 6) >> 1;
+        model.symbols_until_update = model.update_cycle;
+        model
+    }
+
+    fn update(&mut self) {
+        self.total_count = self.total_count.wrapping_add(self.update_cycle);
+        if self.total_count > DM_MAX_COUNT {
+            self.total_count = 0;
+            for count in self.symbol_count.iter_mut() {
+                *count = (*count + 1) >> 1;
+                self.total_count += *count;
+            }
+        }
+
+        let scale = 0x8000_0000u32 / self.total_count;
+        let mut sum = 0u32;
+        if self.compress || self.table_size == 0 {
+            for k in 0..self.symbols as usize {
+                self.distribution[k] = scale.wrapping_mul(sum) >> (31 - DM_LENGTH_SHIFT);
+                sum = sum.wrapping_add(self.symbol_count[k]);
+            }
+        } else {
+            let mut s = 0u32;
+            for k in 0..self.symbols as usize {
+                self.distribution[k] = scale.wrapping_mul(sum) >> (31 - DM_LENGTH_SHIFT);
+                sum = sum.wrapping_add(self.symbol_count[k]);
+                let w = self.distribution[k] >> self.table_shift;
+                while s < w {
+                    s += 1;
+                    self.decoder_table[s as usize] = k as u32 - 1;
+                }
+            }
+            self.decoder_table[0] = 0;
+            while s <= self.table_size {
+                s += 1;
+                self.decoder_table[s as usize] = self.symbols - 1;
+            }
+        }
+
+        self.update_cycle = (5 * self.update_cycle) >> 2;
+        let max_cycle = (self.symbols + 6) << 3;
+        if self.update_cycle > max_cycle {
+            self.update_cycle = max_cycle;
+        }
+        self.symbols_until_update = self.update_cycle;
+    }
+}
+
+/// An adaptive model of the probability of a single bit.
+#[derive(Clone)]
+pub(crate) struct ArithmeticBitModel {
+    bit_0_count: u32,
+    bit_count: u32,
+    bit_0_prob: u32,
+    bits_until_update: u32,
+    update_cycle: u32,
+}
+
+impl ArithmeticBitModel {
+    pub fn new() -> ArithmeticBitModel {
+        ArithmeticBitModel {
+            bit_0_count: 1,
+            bit_count: 2,
+            bit_0_prob: 1 << (BM_LENGTH_SHIFT - 1),
+            bits_until_update: 4,
+            update_cycle: 4,
+        }
+    }
+
+    fn update(&mut self) {
+        self.bit_count += self.update_cycle;
+        if self.bit_count > BM_MAX_COUNT {
+            self.bit_count = (self.bit_count + 1) >> 1;
+            self.bit_0_count = (self.bit_0_count + 1) >> 1;
+            if self.bit_0_count == self.bit_count {
+                self.bit_count += 1;
+            }
+        }
+        let scale = 0x8000_0000u32 / self.bit_count;
+        self.bit_0_prob = self.bit_0_count.wrapping_mul(scale) >> (31 - BM_LENGTH_SHIFT);
+        self.update_cycle = (5 * self.update_cycle) >> 2;
+        if self.update_cycle > 64 {
+            self.update_cycle = 64;
+        }
+        self.bits_until_update = self.update_cycle;
+    }
+}
+
+/// Encodes symbols into an in-memory byte stream.
+pub(crate) struct ArithmeticEncoder {
+    base: u32,
+    length: u32,
+    out: Vec<u8>,
+}
+
+impl ArithmeticEncoder {
+    pub fn new() -> ArithmeticEncoder {
+        ArithmeticEncoder {
+            base: 0,
+            length: AC_MAX_LENGTH,
+            out: Vec::new(),
+        }
+    }
+
+    pub fn encode_bit(&mut self, m: &mut ArithmeticBitModel, sym: u32) {
+        let x = m.bit_0_prob * (self.length >> BM_LENGTH_SHIFT);
+        if sym == 0 {
+            self.length = x;
+            m.bit_0_count += 1;
+        } else {
+            let init_base = self.base;
+            self.base = self.base.wrapping_add(x);
+            self.length -= x;
+            if init_base > self.base {
+                self.propagate_carry();
+            }
+        }
+        if self.length < AC_MIN_LENGTH {
+            self.renorm_enc_interval();
+        }
+        m.bits_until_update -= 1;
+        if m.bits_until_update == 0 {
+            m.update();
+        }
+    }
+
+    pub fn encode_symbol(&mut self, m: &mut ArithmeticModel, sym: u32) {
+        debug_assert!(sym <= m.last_symbol);
+        let init_base = self.base;
+        if sym == m.last_symbol {
+            let x = m.distribution[sym as usize].wrapping_mul(self.length >> DM_LENGTH_SHIFT);
+            self.base = self.base.wrapping_add(x);
+            self.length = self.length.wrapping_sub(x);
+        } else {
+            self.length >>= DM_LENGTH_SHIFT;
+            let x = m.distribution[sym as usize].wrapping_mul(self.length);
+            self.base = self.base.wrapping_add(x);
+            self.length = m.distribution[sym as usize + 1]
+                .wrapping_mul(self.length)
+                .wrapping_sub(x);
+        }
+        if init_base > self.base {
+            self.propagate_carry();
+        }
+        if self.length < AC_MIN_LENGTH {
+            self.renorm_enc_interval();
+        }
+        m.symbol_count[sym as usize] += 1;
+        m.symbols_until_update -= 1;
+        if m.symbols_until_update == 0 {
+            m.update();
+        }
+    }
+
+    pub fn write_bits(&mut self, mut bits: u32, mut sym: u32) {
+        debug_assert!(bits > 0 && bits <= 32);
+        if bits > 19 {
+            self.write_short((sym & 0xFFFF) as u16);
+            sym >>= 16;
+            bits -= 16;
+        }
+        let init_base = self.base;
+        self.length >>= bits;
+        self.base = self.base.wrapping_add(sym.wrapping_mul(self.length));
+        if init_base > self.base {
+            self.propagate_carry();
+        }
+        if self.length < AC_MIN_LENGTH {
+            self.renorm_enc_interval();
+        }
+    }
+
+    pub fn write_short(&mut self, sym: u16) {
+        let init_base = self.base;
+        self.length >>= 16;
+        self.base = self
+            .base
+            .wrapping_add((sym as u32).wrapping_mul(self.length));
+        if init_base > self.base {
+            self.propagate_carry();
+        }
+        if self.length < AC_MIN_LENGTH {
+            self.renorm_enc_interval();
+        }
+    }
+
+    pub fn write_int(&mut self, sym: u32) {
+        self.write_short((sym & 0xFFFF) as u16);
+        self.write_short((sym >> 16) as u16);
+    }
+
+    pub fn write_int64(&mut self, sym: u64) {
+        self.write_int((sym & 0xFFFF_FFFF) as u32);
+        self.write_int((sym >> 32) as u32);
+    }
+
+    /// Flushes the coder and returns the encoded bytes. The trailing zero
+    /// bytes keep the stream in sync with the look-ahead of the decoder.
+    pub fn done(&mut self) -> Vec<u8> {
+        let init_base = self.base;
+        let mut another_byte = true;
+        if self.length > 2 * AC_MIN_LENGTH {
+            self.base = self.base.wrapping_add(AC_MIN_LENGTH);
+            self.length = AC_MIN_LENGTH >> 1;
+        } else {
+            self.base = self.base.wrapping_add(AC_MIN_LENGTH >> 1);
+            self.length = AC_MIN_LENGTH >> 9;
+            another_byte = false;
+        }
+        if init_base > self.base {
+            self.propagate_carry();
+        }
+        self.renorm_enc_interval();
+        self.out.push(0);
+        self.out.push(0);
+        if another_byte {
+            self.out.push(0);
+        }
+        let out = std::mem::take(&mut self.out);
+        self.base = 0;
+        self.length = AC_MAX_LENGTH;
+        out
+    }
+
+    fn propagate_carry(&mut self) {
+        for b in self.out.iter_mut().rev() {
+            if *b == 0xFF {
+                *b = 0;
+            } else {
+                *b += 1;
+                break;
+            }
+        }
+    }
+
+    fn renorm_enc_interval(&mut self) {
+        loop {
+            self.out.push((self.base >> 24) as u8);
+            self.base <<= 8;
+            self.length <<= 8;
+            if self.length >= AC_MIN_LENGTH {
+                break;
+            }
+        }
+    }
+}
+
+/// Decodes symbols from a byte stream. Reading beyond the end of the data
+/// yields zero bytes, which matches the padding written by the encoder.
+pub(crate) struct ArithmeticDecoder<T: AsRef<[u8]>> {
+    data: T,
+    pos: usize,
+    value: u32,
+    length: u32,
+}
+
+impl<T: AsRef<[u8]>> ArithmeticDecoder<T> {
+    pub fn new(data: T) -> ArithmeticDecoder<T> {
+        let mut dec = ArithmeticDecoder {
+            data,
+            pos: 0,
+            value: 0,
+            length: AC_MAX_LENGTH,
+        };
+        for _ in 0..4 {
+            dec.value = (dec.value << 8) | dec.next_byte() as u32;
+        }
+        dec
+    }
+
+    /// The number of bytes consumed from the stream so far.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    fn next_byte(&mut self) -> u8 {
+        let b = self.data.as_ref().get(self.pos).copied().unwrap_or(0);
+        self.pos += 1;
+        b
+    }
+
+    pub fn decode_bit(&mut self, m: &mut ArithmeticBitModel) -> u32 {
+        let x = m.bit_0_prob * (self.length >> BM_LENGTH_SHIFT);
+        let sym = if self.value < x {
+            self.length = x;
+            m.bit_0_count += 1;
+            0
+        } else {
+            self.value -= x;
+            self.length -= x;
+            1
+        };
+        if self.length < AC_MIN_LENGTH {
+            self.renorm_dec_interval();
+        }
+        m.bits_until_update -= 1;
+        if m.bits_until_update == 0 {
+            m.update();
+        }
+        sym
+    }
+
+    pub fn decode_symbol(&mut self, m: &mut ArithmeticModel) -> u32 {
+        let mut y = self.length;
+        let mut sym: u32;
+        let x: u32;
+        if m.table_size > 0 {
+            self.length >>= DM_LENGTH_SHIFT;
+            let dv = self.value / self.length;
+            let t = (dv >> m.table_shift) as usize;
+            sym = m.decoder_table[t];
+            let mut n = m.decoder_table[t + 1] + 1;
+            while n > sym + 1 {
+                let k = (sym + n) >> 1;
+                if m.distribution[k as usize] > dv {
+                    n = k;
+                } else {
+                    sym = k;
+                }
+            }
+            x = m.distribution[sym as usize].wrapping_mul(self.length);
+            if sym != m.last_symbol {
+                y = m.distribution[sym as usize + 1].wrapping_mul(self.length);
+            }
+        } else {
+            let mut lo = 0u32;
+            sym = 0;
+            self.length >>= DM_LENGTH_SHIFT;
+            let mut n = m.symbols;
+            let mut k = n >> 1;
+            loop {
+                let z = self.length.wrapping_mul(m.distribution[k as usize]);
+                if z > self.value {
+                    n = k;
+                    y = z;
+                } else {
+                    sym = k;
+                    lo = z;
+                }
+                k = (sym + n) >> 1;
+                if k == sym {
+                    break;
+                }
+            }
+            x = lo;
+        }
+        self.value = self.value.wrapping_sub(x);
+        self.length = y.wrapping_sub(x);
+        if self.length < AC_MIN_LENGTH {
+            self.renorm_dec_interval();
+        }
+        m.symbol_count[sym as usize] += 1;
+        m.symbols_until_update -= 1;
+        if m.symbols_until_update == 0 {
+            m.update();
+        }
+        sym
+    }
+
+    pub fn read_bits(&mut self, mut bits: u32) -> u32 {
+        debug_assert!(bits > 0 && bits <= 32);
+        if bits > 19 {
+            let tmp = self.read_short() as u32;
+            bits -= 16;
+            let tmp1 = self.read_bits(bits) << 16;
+            return tmp1 | tmp;
+        }
+        self.length >>= bits;
+        let sym = self.value / self.length;
+        self.value -= self.length * sym;
+        if self.length < AC_MIN_LENGTH {
+            self.renorm_dec_interval();
+        }
+        sym
+    }
+
+    pub fn read_short(&mut self) -> u16 {
+        self.length >>= 16;
+        let sym = self.value / self.length;
+        self.value -= self.length * sym;
+        if self.length < AC_MIN_LENGTH {
+            self.renorm_dec_interval();
+        }
+        sym as u16
+    }
+
+    pub fn read_int(&mut self) -> u32 {
+        let lower = self.read_short() as u32;
+        let upper = self.read_short() as u32;
+        (upper << 16) | lower
+    }
+
+    pub fn read_int64(&mut self) -> u64 {
+        let lower = self.read_int() as u64;
+        let upper = self.read_int() as u64;
+        (upper << 32) | lower
+    }
+
+    fn renorm_dec_interval(&mut self) {
+        loop {
+            self.value = (self.value << 8) | self.next_byte() as u32;
+            self.length <<= 8;
+            if self.length >= AC_MIN_LENGTH {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ArithmeticBitModel, ArithmeticDecoder, ArithmeticEncoder, ArithmeticModel};
+
+    // The expected bytes below were worked out by hand from LASzip's
+    // ArithmeticEncoder, so that a coder that is merely self-consistent can't pass.
+
+    #[test]
+    fn test_empty_stream() {
+        // done() moves the base by AC__MinLength, emits one byte, then pads with three
+        // zero bytes for the decoder's look-ahead
+        assert_eq!(ArithmeticEncoder::new().done(), vec![0x01, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_raw_bits_known_answer() {
+        let mut enc = ArithmeticEncoder::new();
+        enc.write_int(0x1234_5678);
+        let bytes = enc.done();
+        assert_eq!(bytes, vec![0x56, 0x77, 0xBB, 0xBB, 0xEE, 0, 0, 0]);
+        let mut dec = ArithmeticDecoder::new(&bytes);
+        assert_eq!(dec.read_int(), 0x1234_5678);
+    }
+
+    #[test]
+    fn test_models_known_answer() {
+        // A new bit model and a new two-symbol model both split the interval at
+        // 0x7FFFF000 and 0x7FFFC000 respectively, so that the second symbol moves
+        // the base past 0x80000000.
+        for &(sym, expected) in &[(0u32, 0x01u8), (1, 0x80)] {
+            let mut enc = ArithmeticEncoder::new();
+            enc.encode_bit(&mut ArithmeticBitModel::new(), sym);
+            let bytes = enc.done();
+            assert_eq!(bytes, vec![expected, 0, 0, 0]);
+            let mut dec = ArithmeticDecoder::new(&bytes);
+            assert_eq!(dec.decode_bit(&mut ArithmeticBitModel::new()), sym);
+
+            let mut enc = ArithmeticEncoder::new();
+            enc.encode_symbol(&mut ArithmeticModel::new(2, true), sym);
+            let bytes = enc.done();
+            assert_eq!(bytes, vec![expected, 0, 0, 0]);
+            let mut dec = ArithmeticDecoder::new(&bytes);
+            assert_eq!(dec.decode_symbol(&mut ArithmeticModel::new(2, false)), sym);
+        }
+    }
+}
@@ -0,0 +1,521 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: Spatial queries of COPC (Cloud Optimized Point Cloud) files. A COPC file is
+a LAS 1.4 LAZ file whose points are organized into an octree, with the points of
+each octree node stored in an independently compressed chunk. The 'copc' info VLR
+gives the extent of the root node and the location of the hierarchy EVLR, which
+lists the file offset, size, and point count of each node. The hierarchy may be
+split into pages, in which case an entry with a point count of -1 refers to a
+page rather than to a chunk of points. Because each node holds a subsample of the
+points within its cube, reading the nodes down to some depth gives a version of
+the point cloud with a lower density, i.e. a level of detail. Queries read the
+file through a seekable handle, loading only the header and VLRs, the hierarchy
+pages that are reached, and the chunks of the selected nodes.
+*/
+
+use super::{invalid_laz, read_u32, read_u64, ChunkDecoder, LasLayout, LazVlr};
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind, Read, Seek, SeekFrom};
+use whitebox_common::structures::BoundingBox;
+
+const COPC_USER_ID: &str = "copc";
+const COPC_INFO_RECORD_ID: u16 = 1;
+const HIERARCHY_ENTRY_SIZE: usize = 32;
+
+/// The contents of the COPC info VLR that are needed to traverse the octree.
+struct CopcInfo {
+    center_x: f64,
+    center_y: f64,
+    halfsize: f64,
+    root_hier_offset: usize,
+    root_hier_size: usize,
+}
+
+impl CopcInfo {
+    fn from_bytes(data: &[u8]) -> Result<CopcInfo, Error> {
+        if data.len() < 56 {
+            return Err(invalid_laz("the COPC info VLR is truncated"));
+        }
+        let read_f64 = |offset: usize| f64::from_bits(read_u64(data, offset));
+        Ok(CopcInfo {
+            center_x: read_f64(0),
+            center_y: read_f64(8),
+            halfsize: read_f64(24),
+            root_hier_offset: read_u64(data, 40) as usize,
+            root_hier_size: read_u64(data, 48) as usize,
+        })
+    }
+}
+
+/// Identifies an octree node by its depth and its position within that level.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+struct VoxelKey {
+    level: i32,
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+impl VoxelKey {
+    fn children(&self) -> Vec<VoxelKey> {
+        (0..8)
+            .map(|i| VoxelKey {
+                level: self.level + 1,
+                x: 2 * self.x + (i & 1),
+                y: 2 * self.y + ((i >> 1) & 1),
+                z: 2 * self.z + ((i >> 2) & 1),
+            })
+            .collect()
+    }
+
+    /// The horizontal extent of the node.
+    fn bounds(&self, info: &CopcInfo) -> BoundingBox {
+        let size = 2.0 * info.halfsize / 2f64.powi(self.level);
+        let min_x = info.center_x - info.halfsize + self.x as f64 * size;
+        let min_y = info.center_y - info.halfsize + self.y as f64 * size;
+        BoundingBox::new(min_x, min_x + size, min_y, min_y + size)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct HierarchyEntry {
+    offset: usize,
+    byte_size: usize,
+    point_count: i32,
+}
+
+/// Reads `size` bytes from `offset`, reporting `msg` if the file ends first.
+fn read_range<R: Read + Seek>(
+    reader: &mut R,
+    offset: usize,
+    size: usize,
+    msg: &str,
+) -> Result<Vec<u8>, Error> {
+    let mut bytes = vec![0u8; size];
+    reader.seek(SeekFrom::Start(offset as u64))?;
+    reader.read_exact(&mut bytes).map_err(|e| match e.kind() {
+        ErrorKind::UnexpectedEof => invalid_laz(msg),
+        _ => e,
+    })?;
+    Ok(bytes)
+}
+
+/// Reads the header and VLRs of a LAS file, i.e. the bytes before the point data.
+fn read_las_head<R: Read + Seek>(reader: &mut R) -> Result<Vec<u8>, Error> {
+    let msg = "the file does not have a valid LAS header";
+    let header = read_range(reader, 0, 100, msg)?;
+    if &header[0..4] != b"LASF" {
+        return Err(invalid_laz(msg));
+    }
+    let offset_to_points = read_u32(&header, 96) as usize;
+    read_range(reader, 0, offset_to_points, msg)
+}
+
+fn read_hierarchy_page<R: Read + Seek>(
+    reader: &mut R,
+    offset: usize,
+    size: usize,
+    entries: &mut HashMap<VoxelKey, HierarchyEntry>,
+) -> Result<(), Error> {
+    let page = read_range(
+        reader,
+        offset,
+        size,
+        "a COPC hierarchy page extends beyond the end of the file",
+    )?;
+    for entry in page.chunks_exact(HIERARCHY_ENTRY_SIZE) {
+        let key = VoxelKey {
+            level: read_u32(entry, 0) as i32,
+            x: read_u32(entry, 4) as i32,
+            y: read_u32(entry, 8) as i32,
+            z: read_u32(entry, 12) as i32,
+        };
+        entries.insert(
+            key,
+            HierarchyEntry {
+                offset: read_u64(entry, 16) as usize,
+                byte_size: read_u32(entry, 24) as usize,
+                point_count: read_u32(entry, 28) as i32,
+            },
+        );
+    }
+    Ok(())
+}
+
+/// Returns true if the file read by `reader` is a COPC file. Only the header and
+/// VLRs are read.
+pub(crate) fn is_copc_file<R: Read + Seek>(reader: &mut R) -> Result<bool, Error> {
+    match read_las_head(reader) {
+        Ok(head) => Ok(is_copc(&head)),
+        Err(e) if e.kind() == ErrorKind::InvalidData => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns true if the bytes of a LAS file, or of its header and VLRs, are those
+/// of a COPC file.
+fn is_copc(buffer: &[u8]) -> bool {
+    match LasLayout::parse(buffer) {
+        Ok(layout) => {
+            layout
+                .find_vlr(buffer, COPC_USER_ID, COPC_INFO_RECORD_ID)
+                .is_some()
+                && layout.find_laszip_vlr(buffer).is_some()
+        }
+        Err(_) => false,
+    }
+}
+
+/// Reads the points of the octree nodes of a COPC file that intersect `bounds`
+/// and are no deeper than `max_depth`, returning the bytes of a LAS file that
+/// contains only these points. Points are selected by node, and so some of the
+/// returned points may lie outside of `bounds`. The chunks of the nodes that are
+/// not selected are never read.
+pub(crate) fn read_copc_bounds<R: Read + Seek>(
+    reader: &mut R,
+    bounds: &BoundingBox,
+    max_depth: Option<u32>,
+) -> Result<Vec<u8>, Error> {
+    let head = &read_las_head(reader)?;
+    let layout = LasLayout::parse(head)?;
+    let info_index = layout
+        .find_vlr(head, COPC_USER_ID, COPC_INFO_RECORD_ID)
+        .ok_or_else(|| invalid_laz("the COPC info VLR is missing"))?;
+    let laz_index = layout
+        .find_laszip_vlr(head)
+        .ok_or_else(|| invalid_laz("the LASzip VLR is missing"))?;
+    let (start, end) = layout.vlrs[info_index];
+    let info = CopcInfo::from_bytes(&head[start + super::VLR_HEADER_SIZE..end])?;
+    let (start, end) = layout.vlrs[laz_index];
+    let laz_vlr = LazVlr::from_bytes(&head[start + super::VLR_HEADER_SIZE..end])?;
+
+    // Find the nodes to read, loading hierarchy pages as they are reached.
+    let mut entries = HashMap::new();
+    read_hierarchy_page(
+        reader,
+        info.root_hier_offset,
+        info.root_hier_size,
+        &mut entries,
+    )?;
+    let mut nodes = vec![];
+    let mut stack = vec![VoxelKey {
+        level: 0,
+        x: 0,
+        y: 0,
+        z: 0,
+    }];
+    while let Some(key) = stack.pop() {
+        if max_depth.is_some_and(|d| key.level as u32 > d) || !key.bounds(&info).overlaps(*bounds) {
+            continue;
+        }
+        let mut entry = match entries.get(&key) {
+            Some(e) => *e,
+            None => continue,
+        };
+        if entry.point_count == -1 {
+            // The entry refers to a page, which holds the entry of this node and
+            // those of its descendants.
+            read_hierarchy_page(reader, entry.offset, entry.byte_size, &mut entries)?;
+            entry = match entries.get(&key) {
+                Some(e) if e.point_count != -1 => *e,
+                _ => continue,
+            };
+        }
+        if entry.point_count > 0 {
+            nodes.push(entry);
+        }
+        stack.extend(key.children());
+    }
+    nodes.sort_by_key(|n| n.offset);
+
+    // Decode the chunks of the selected nodes.
+    let record_length = layout.record_length;
+    let num_points: usize = nodes.iter().map(|n| n.point_count as usize).sum();
+    let mut points = vec![0u8; num_points * record_length];
+    let mut decoder = ChunkDecoder::new(&laz_vlr)?;
+    let mut point = 0usize;
+    for node in &nodes {
+        let chunk = read_range(
+            reader,
+            node.offset,
+            node.byte_size,
+            "a COPC node extends beyond the end of the file",
+        )?;
+        let (decoded, _) = decoder.decode(
+            &chunk,
+            0,
+            node.point_count as usize,
+            &mut points[point * record_length..],
+        )?;
+        point += decoded;
+    }
+    points.truncate(point * record_length);
+
+    // The LAS file, without the VLRs that describe the COPC and LAZ structure
+    // and without the hierarchy EVLR.
+    let mut output = Vec::with_capacity(layout.header_size + points.len() + 1024);
+    output.extend_from_slice(&head[0..layout.header_size]);
+    let mut number_of_vlrs = 0u32;
+    for (i, &(start, end)) in layout.vlrs.iter().enumerate() {
+        if i != info_index && i != laz_index {
+            output.extend_from_slice(&head[start..end]);
+            number_of_vlrs += 1;
+        }
+    }
+    let offset_to_points = output.len() as u32;
+    output[96..100].copy_from_slice(&offset_to_points.to_le_bytes());
+    output[100..104].copy_from_slice(&number_of_vlrs.to_le_bytes());
+    output[104] = layout.point_format & 0x3F;
+    if read_u32(head, 107) != 0 {
+        output[107..111].copy_from_slice(&(point as u32).to_le_bytes());
+    }
+    if layout.header_size >= 375 {
+        output[235..247].copy_from_slice(&[0u8; 12]);
+        output[247..255].copy_from_slice(&(point as u64).to_le_bytes());
+    }
+    output.extend_from_slice(&points);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{
+        decompress_laz, write_chunk_table, ChunkEncoder, LazVlr, VARIABLE_CHUNK_SIZE,
+    };
+    use super::{is_copc, is_copc_file, read_copc_bounds, read_u64};
+    use std::io::{Cursor, Read, Result, Seek, SeekFrom};
+    use whitebox_common::structures::BoundingBox;
+
+    /// A reader that records the byte ranges that are read from it.
+    struct RecordingReader {
+        inner: Cursor<Vec<u8>>,
+        reads: Vec<(usize, usize)>,
+    }
+
+    impl Read for RecordingReader {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            let start = self.inner.position() as usize;
+            let n = self.inner.read(buf)?;
+            self.reads.push((start, start + n));
+            Ok(n)
+        }
+    }
+
+    impl Seek for RecordingReader {
+        fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+            self.inner.seek(pos)
+        }
+    }
+
+    fn vlr(user_id: &str, record_id: u16, data: &[u8]) -> Vec<u8> {
+        let mut vlr = vec![0u8; 54];
+        vlr[2..2 + user_id.len()].copy_from_slice(user_id.as_bytes());
+        vlr[18..20].copy_from_slice(&record_id.to_le_bytes());
+        vlr[20..22].copy_from_slice(&(data.len() as u16).to_le_bytes());
+        vlr.extend_from_slice(data);
+        vlr
+    }
+
+    fn entry(key: [i32; 4], offset: usize, byte_size: usize, point_count: i32) -> Vec<u8> {
+        let mut e = vec![];
+        for k in key.iter() {
+            e.extend_from_slice(&k.to_le_bytes());
+        }
+        e.extend_from_slice(&(offset as u64).to_le_bytes());
+        e.extend_from_slice(&(byte_size as i32).to_le_bytes());
+        e.extend_from_slice(&point_count.to_le_bytes());
+        e
+    }
+
+    fn point(x: i32, y: i32, z: i32, gps_time: f64) -> Vec<u8> {
+        let mut p = vec![0u8; 30];
+        p[0..4].copy_from_slice(&x.to_le_bytes());
+        p[4..8].copy_from_slice(&y.to_le_bytes());
+        p[8..12].copy_from_slice(&z.to_le_bytes());
+        p[14] = 0x11;
+        p[16] = 2;
+        p[22..30].copy_from_slice(&gps_time.to_le_bytes());
+        p
+    }
+
+    /// Builds a two-level COPC file covering the cube [0, 100] with a unit scale.
+    /// The root node holds every tenth point and the four level-1 nodes of the
+    /// lower half of the cube hold the others. The hierarchy of the level-1 node
+    /// (1, 1, 1, 0) is stored in a page of its own. Also returns the byte range of
+    /// the chunk of each node, in the order root, (1, 0, 0, 0), (1, 1, 0, 0),
+    /// (1, 0, 1, 0), (1, 1, 1, 0).
+    fn make_copc() -> (Vec<u8>, usize, Vec<(usize, usize)>) {
+        let mut nodes: Vec<([i32; 4], Vec<u8>)> = vec![([0, 0, 0, 0], vec![])];
+        for i in 0..4 {
+            nodes.push(([1, i & 1, i >> 1, 0], vec![]));
+        }
+        let mut n = 0;
+        for x in 0..100 {
+            for y in 0..100 {
+                let z = (x + y) % 50;
+                let p = point(x, y, z, n as f64 * 0.001);
+                let node = if n % 10 == 0 {
+                    0
+                } else {
+                    1 + (x / 50) as usize + 2 * (y / 50) as usize
+                };
+                nodes[node].1.extend_from_slice(&p);
+                n += 1;
+            }
+        }
+
+        let mut laz_vlr = LazVlr::for_point_format(6, 30).unwrap();
+        laz_vlr.chunk_size = VARIABLE_CHUNK_SIZE;
+        let mut info = vec![0u8; 160];
+        info[0..8].copy_from_slice(&50f64.to_le_bytes());
+        info[8..16].copy_from_slice(&50f64.to_le_bytes());
+        info[16..24].copy_from_slice(&50f64.to_le_bytes());
+        info[24..32].copy_from_slice(&50f64.to_le_bytes());
+
+        let mut las = vec![0u8; 375];
+        las[0..4].copy_from_slice(b"LASF");
+        las[24] = 1;
+        las[25] = 4;
+        las[94..96].copy_from_slice(&375u16.to_le_bytes());
+        las[100..104].copy_from_slice(&2u32.to_le_bytes());
+        las[104] = 6 | 0x80;
+        las[105..107].copy_from_slice(&30u16.to_le_bytes());
+        las[131..155].copy_from_slice(&[1f64.to_le_bytes(); 3].concat());
+        las[247..255].copy_from_slice(&(n as u64).to_le_bytes());
+        let info_start = las.len() + 54;
+        las.extend_from_slice(&vlr("copc", 1, &info));
+        las.extend_from_slice(&laz_vlr.to_vlr_bytes());
+        let offset_to_points = las.len();
+        las[96..100].copy_from_slice(&(offset_to_points as u32).to_le_bytes());
+        las.extend_from_slice(&[0u8; 8]);
+
+        let mut encoder = ChunkEncoder::new(&laz_vlr).unwrap();
+        let mut chunks = vec![];
+        let mut locations = vec![];
+        for (_, points) in &nodes {
+            let start = las.len();
+            encoder.encode(points, &mut las);
+            chunks.push((points.len() / 30, las.len() - start));
+            locations.push((start, las.len() - start, (points.len() / 30) as i32));
+        }
+        let table_offset = las.len() as u64;
+        las[offset_to_points..offset_to_points + 8].copy_from_slice(&table_offset.to_le_bytes());
+        write_chunk_table(&mut las, &chunks, true);
+
+        // the hierarchy EVLR, with a root page and a page for node (1, 1, 1, 0)
+        let evlr_start = las.len();
+        let mut evlr = vec![0u8; 60];
+        evlr[2..6].copy_from_slice(b"copc");
+        evlr[18..20].copy_from_slice(&1000u16.to_le_bytes());
+        let page_start = evlr_start + 60;
+        let root_page_size = 5 * 32;
+        let mut page = vec![];
+        for (i, (key, _)) in nodes.iter().enumerate() {
+            let (offset, size, count) = locations[i];
+            if *key == [1, 1, 1, 0] {
+                page.extend(entry(*key, page_start + root_page_size, 32, -1));
+            } else {
+                page.extend(entry(*key, offset, size, count));
+            }
+        }
+        let (offset, size, count) = locations[4];
+        page.extend(entry([1, 1, 1, 0], offset, size, count));
+        evlr[20..28].copy_from_slice(&(page.len() as u64).to_le_bytes());
+        evlr.extend_from_slice(&page);
+        las.extend_from_slice(&evlr);
+        las[info_start + 40..info_start + 48].copy_from_slice(&(page_start as u64).to_le_bytes());
+        las[info_start + 48..info_start + 56]
+            .copy_from_slice(&(root_page_size as u64).to_le_bytes());
+        las[235..243].copy_from_slice(&(evlr_start as u64).to_le_bytes());
+        las[243..247].copy_from_slice(&1u32.to_le_bytes());
+        let chunk_ranges = locations
+            .iter()
+            .map(|&(offset, size, _)| (offset, offset + size))
+            .collect();
+        (las, n, chunk_ranges)
+    }
+
+    fn points_of(las: &[u8]) -> Vec<(i32, i32)> {
+        let offset = super::read_u32(las, 96) as usize;
+        let count = read_u64(las, 247) as usize;
+        las[offset..offset + count * 30]
+            .chunks(30)
+            .map(|p| (super::read_u32(p, 0) as i32, super::read_u32(p, 4) as i32))
+            .collect()
+    }
+
+    #[test]
+    fn test_copc_queries() {
+        let (copc, n, _) = make_copc();
+        assert!(is_copc(&copc));
+        assert!(is_copc_file(&mut Cursor::new(&copc)).unwrap());
+        let las = decompress_laz(copc.clone(), false).unwrap();
+        assert!(!is_copc_file(&mut Cursor::new(&las)).unwrap());
+
+        // the whole file, as an ordinary LAZ file
+        let las = decompress_laz(copc.clone(), false).unwrap();
+        assert_eq!(points_of(&las).len(), n);
+
+        // all levels of the nodes intersecting the upper right quadrant
+        let bounds = BoundingBox::new(60.0, 90.0, 60.0, 90.0);
+        let las = read_copc_bounds(&mut Cursor::new(&copc), &bounds, None).unwrap();
+        let points = points_of(&las);
+        assert_eq!(
+            las.len(),
+            super::read_u32(&las, 96) as usize + points.len() * 30
+        );
+        assert_eq!(points.len(), n / 10 + 2250);
+        assert!(points
+            .iter()
+            .all(|&(x, y)| (x >= 50 && y >= 50) || (x * 100 + y) % 10 == 0));
+
+        // the level of detail of the root node only
+        let las = read_copc_bounds(&mut Cursor::new(&copc), &bounds, Some(0)).unwrap();
+        assert_eq!(points_of(&las).len(), n / 10);
+
+        // bounds that are outside of the octree
+        let bounds = BoundingBox::new(200.0, 300.0, 200.0, 300.0);
+        let las = read_copc_bounds(&mut Cursor::new(&copc), &bounds, None).unwrap();
+        assert!(points_of(&las).is_empty());
+    }
+
+    #[test]
+    fn test_copc_reads_only_selected_chunks() {
+        let (copc, n, chunk_ranges) = make_copc();
+        let file_size = copc.len();
+        let mut reader = RecordingReader {
+            inner: Cursor::new(copc),
+            reads: vec![],
+        };
+
+        // only the root node and node (1, 1, 1, 0) intersect the upper right quadrant
+        let bounds = BoundingBox::new(60.0, 90.0, 60.0, 90.0);
+        let las = read_copc_bounds(&mut reader, &bounds, None).unwrap();
+        assert_eq!(points_of(&las).len(), n / 10 + 2250);
+        let overlaps = |reads: &[(usize, usize)], range: (usize, usize)| {
+            reads
+                .iter()
+                .any(|&(start, end)| start < range.1 && end > range.0)
+        };
+        assert!(overlaps(&reader.reads, chunk_ranges[0]));
+        assert!(overlaps(&reader.reads, chunk_ranges[4]));
+        for &range in &chunk_ranges[1..4] {
+            assert!(!overlaps(&reader.reads, range));
+        }
+        let bytes_read: usize = reader.reads.iter().map(|(start, end)| end - start).sum();
+        assert!(bytes_read < file_size / 2);
+
+        // the root node alone, without reading the page of node (1, 1, 1, 0)
+        reader.reads.clear();
+        read_copc_bounds(&mut reader, &bounds, Some(0)).unwrap();
+        assert!(overlaps(&reader.reads, chunk_ranges[0]));
+        for &range in &chunk_ranges[1..] {
+            assert!(!overlaps(&reader.reads, range));
+        }
+    }
+}
@@ -0,0 +1,252 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+
+Notes: The LASzip integer compressor codes the difference between a predicted and
+an actual integer value. The corrector is split into the number of bits that it
+needs, k, which is entropy coded in one of several contexts, and the k-bit value
+itself, of which only the 'bits_high' most significant bits are entropy coded.
+*/
+
+use super::arithmetic::{
+    ArithmeticBitModel, ArithmeticDecoder, ArithmeticEncoder, ArithmeticModel,
+};
+
+const BITS_HIGH: u32 = 8;
+
+pub(crate) struct IntegerCompressor {
+    k: u32,
+    corr_range: u32,
+    corr_min: i32,
+    corr_max: i32,
+    m_bits: Vec<ArithmeticModel>,
+    m_corrector0: ArithmeticBitModel,
+    m_corrector: Vec<ArithmeticModel>,
+}
+
+impl IntegerCompressor {
+    /// Creates a compressor for `bits`-bit integers with `contexts` contexts.
+    /// `compress` selects whether the models are used for encoding or decoding.
+    pub fn new(bits: u32, contexts: u32, compress: bool) -> IntegerCompressor {
+        let (corr_bits, corr_range, corr_min, corr_max) = if bits > 0 && bits < 32 {
+            let corr_range = 1u32 << bits;
+            let corr_min = -((corr_range / 2) as i32);
+            (bits, corr_range, corr_min, corr_min + corr_range as i32 - 1)
+        } else {
+            (32, 0, i32::MIN, i32::MAX)
+        };
+        let m_bits = (0..contexts)
+            .map(|_| ArithmeticModel::new(corr_bits + 1, compress))
+            .collect();
+        let m_corrector = (1..=corr_bits)
+            .map(|i| {
+                if i <= BITS_HIGH {
+                    ArithmeticModel::new(1 << i, compress)
+                } else {
+                    ArithmeticModel::new(1 << BITS_HIGH, compress)
+                }
+            })
+            .collect();
+        IntegerCompressor {
+            k: 0,
+            corr_range,
+            corr_min,
+            corr_max,
+            m_bits,
+            m_corrector0: ArithmeticBitModel::new(),
+            m_corrector,
+        }
+    }
+
+    /// The number of bits of the last coded corrector, which several items use
+    /// to select the context of the next value.
+    pub fn k(&self) -> u32 {
+        self.k
+    }
+
+    pub fn compress(&mut self, enc: &mut ArithmeticEncoder, pred: i32, real: i32, context: u32) {
+        let mut corr = real.wrapping_sub(pred);
+        if corr < self.corr_min {
+            corr = corr.wrapping_add(self.corr_range as i32);
+        } else if corr > self.corr_max {
+            corr = corr.wrapping_sub(self.corr_range as i32);
+        }
+        self.write_corrector(enc, corr, context as usize);
+    }
+
+    pub fn decompress<T: AsRef<[u8]>>(
+        &mut self,
+        dec: &mut ArithmeticDecoder<T>,
+        pred: i32,
+        context: u32,
+    ) -> i32 {
+        let mut real = pred.wrapping_add(self.read_corrector(dec, context as usize));
+        if real < 0 {
+            real = real.wrapping_add(self.corr_range as i32);
+        } else if self.corr_range != 0 && real as u32 >= self.corr_range {
+            real = real.wrapping_sub(self.corr_range as i32);
+        }
+        real
+    }
+
+    fn write_corrector(&mut self, enc: &mut ArithmeticEncoder, mut c: i32, context: usize) {
+        // find the tightest interval [ - (2^k - 1) ... + (2^k) ] that contains c
+        let mut c1 = if c <= 0 {
+            c.wrapping_neg() as u32
+        } else {
+            (c - 1) as u32
+        };
+        self.k = 0;
+        while c1 != 0 {
+            c1 >>= 1;
+            self.k += 1;
+        }
+        let k = self.k;
+        enc.encode_symbol(&mut self.m_bits[context], k);
+        if k != 0 {
+            if k < 32 {
+                // translate c into the k-bit interval [ 0 ... 2^k - 1 ]
+                if c < 0 {
+                    c = c.wrapping_add(((1u32 << k) - 1) as i32);
+                } else {
+                    c -= 1;
+                }
+                let c = c as u32;
+                if k <= BITS_HIGH {
+                    enc.encode_symbol(&mut self.m_corrector[k as usize - 1], c);
+                } else {
+                    let k1 = k - BITS_HIGH;
+                    let low = c & ((1 << k1) - 1);
+                    enc.encode_symbol(&mut self.m_corrector[k as usize - 1], c >> k1);
+                    enc.write_bits(k1, low);
+                }
+            }
+        } else {
+            enc.encode_bit(&mut self.m_corrector0, c as u32);
+        }
+    }
+
+    fn read_corrector<T: AsRef<[u8]>>(
+        &mut self,
+        dec: &mut ArithmeticDecoder<T>,
+        context: usize,
+    ) -> i32 {
+        self.k = dec.decode_symbol(&mut self.m_bits[context]);
+        let k = self.k;
+        if k != 0 {
+            if k < 32 {
+                let c = if k <= BITS_HIGH {
+                    dec.decode_symbol(&mut self.m_corrector[k as usize - 1])
+                } else {
+                    let k1 = k - BITS_HIGH;
+                    let high = dec.decode_symbol(&mut self.m_corrector[k as usize - 1]);
+                    (high << k1) | dec.read_bits(k1)
+                };
+                // translate c back into its correct interval
+                if c >= (1u32 << (k - 1)) {
+                    (c as i32).wrapping_add(1)
+                } else {
+                    (c as i32).wrapping_sub(((1u32 << k) - 1) as i32)
+                }
+            } else {
+                self.corr_min
+            }
+        } else {
+            dec.decode_bit(&mut self.m_corrector0) as i32
+        }
+    }
+}
+
+/// Tracks the median of the last five values added, using the same update
+/// rule as LASzip so that encoder and decoder predictions agree.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct StreamingMedian5 {
+    values: [i32; 5],
+    high: bool,
+}
+
+impl StreamingMedian5 {
+    pub fn new() -> StreamingMedian5 {
+        StreamingMedian5 {
+            values: [0; 5],
+            high: true,
+        }
+    }
+
+    pub fn add(&mut self, v: i32) {
+        let values = &mut self.values;
+        if self.high {
+            if v < values[2] {
+                values[4] = values[3];
+                values[3] = values[2];
+                if v < values[0] {
+                    values[2] = values[1];
+                    values[1] = values[0];
+                    values[0] = v;
+                } else if v < values[1] {
+                    values[2] = values[1];
+                    values[1] = v;
+                } else {
+                    values[2] = v;
+                }
+            } else {
+                if v < values[3] {
+                    values[4] = values[3];
+                    values[3] = v;
+                } else {
+                    values[4] = v;
+                }
+                self.high = false;
+            }
+        } else if values[2] < v {
+            values[0] = values[1];
+            values[1] = values[2];
+            if values[4] < v {
+                values[2] = values[3];
+                values[3] = values[4];
+                values[4] = v;
+            } else if values[3] < v {
+                values[2] = values[3];
+                values[3] = v;
+            } else {
+                values[2] = v;
+            }
+        } else {
+            if values[1] < v {
+                values[0] = values[1];
+                values[1] = v;
+            } else {
+                values[0] = v;
+            }
+            self.high = true;
+        }
+    }
+
+    pub fn get(&self) -> i32 {
+        self.values[2]
+    }
+}
+
+/// Wraps a difference into the range of a byte.
+pub(crate) fn u8_fold(n: i32) -> u8 {
+    n as u8
+}
+
+pub(crate) fn u8_clamp(n: i32) -> i32 {
+    n.clamp(0, 255)
+}
+
+pub(crate) fn i32_quantize(n: f32) -> i32 {
+    if n >= 0.0 {
+        (n + 0.5) as i32
+    } else {
+        (n - 0.5) as i32
+    }
+}
+
+pub(crate) fn u32_zero_bit_0(n: u32) -> u32 {
+    n & 0xFFFF_FFFE
+}
@@ -0,0 +1,1197 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+
+Notes: The layered LASzip items (POINT14, RGB14, RGBNIR14, WAVEPACKET14 and BYTE14,
+versions 3 and 4), which are used for the LAS 1.4 point formats 6-10. Each
+attribute is coded into its own layer so that a reader can skip the layers it
+does not need; layers whose values never change within a chunk are omitted.
+The items keep separate prediction contexts for each of the four scanner
+channels, and the POINT14 item selects the context for all of the other items.
+*/
+
+use super::arithmetic::{ArithmeticDecoder, ArithmeticEncoder, ArithmeticModel};
+use super::integer::{u32_zero_bit_0, u8_fold, IntegerCompressor, StreamingMedian5};
+use super::pointwise::{
+    pack_rgb, read_i32, read_u16, read_u32, read_u64, unpack_rgb, GpsTimeState, RgbModels,
+    WavepacketModels, GPSTIME_MULTI_CODE_FULL,
+};
+
+pub(crate) trait LayeredCompressor {
+    fn init(&mut self, first: &[u8], context: &mut usize);
+    fn compress(&mut self, item: &[u8], context: &mut usize);
+    fn write_layer_sizes(&mut self, out: &mut Vec<u8>);
+    fn write_layers(&mut self, out: &mut Vec<u8>);
+}
+
+pub(crate) trait LayeredDecompressor {
+    fn read_layer_sizes(&mut self, src: &[u8], pos: &mut usize);
+    fn read_layers(&mut self, src: &[u8], pos: &mut usize);
+    fn init(&mut self, first: &[u8], context: &mut usize);
+    fn decompress(&mut self, item: &mut [u8], context: &mut usize);
+}
+
+const NUMBER_RETURN_MAP_6CTX: [[u8; 16]; 16] = [
+    [0, 1, 2, 3, 4, 5, 3, 4, 4, 5, 5, 5, 5, 5, 5, 5],
+    [1, 0, 1, 3, 4, 5, 3, 4, 4, 5, 5, 5, 5, 5, 5, 5],
+    [2, 1, 2, 4, 5, 5, 4, 5, 5, 5, 5, 5, 5, 5, 5, 5],
+    [3, 3, 4, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5],
+    [4, 4, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5],
+    [5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5],
+    [3, 3, 4, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5],
+    [4, 4, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5],
+    [4, 4, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5],
+    [5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5],
+    [5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5],
+    [5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5],
+    [5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5],
+    [5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5],
+    [5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5],
+    [5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5],
+];
+
+fn number_return_level_8ctx(n: usize, r: usize) -> usize {
+    n.abs_diff(r).min(7)
+}
+
+/// The 30-byte core of point formats 6-10.
+#[derive(Clone, Copy, Default)]
+struct Point14 {
+    x: i32,
+    y: i32,
+    z: i32,
+    intensity: u16,
+    return_number: u8,
+    number_of_returns: u8,
+    classification_flags: u8,
+    scanner_channel: u8,
+    scan_direction_flag: u8,
+    edge_of_flight_line: u8,
+    classification: u8,
+    user_data: u8,
+    scan_angle: i16,
+    point_source_id: u16,
+    gps_time: u64,
+    gps_time_change: bool,
+}
+
+impl Point14 {
+    fn unpack(b: &[u8]) -> Point14 {
+        Point14 {
+            x: read_i32(b, 0),
+            y: read_i32(b, 4),
+            z: read_i32(b, 8),
+            intensity: read_u16(b, 12),
+            return_number: b[14] & 0x0F,
+            number_of_returns: b[14] >> 4,
+            classification_flags: b[15] & 0x0F,
+            scanner_channel: (b[15] >> 4) & 0x03,
+            scan_direction_flag: (b[15] >> 6) & 1,
+            edge_of_flight_line: b[15] >> 7,
+            classification: b[16],
+            user_data: b[17],
+            scan_angle: read_u16(b, 18) as i16,
+            point_source_id: read_u16(b, 20),
+            gps_time: read_u64(b, 22),
+            gps_time_change: false,
+        }
+    }
+
+    fn pack(&self, b: &mut [u8]) {
+        b[0..4].copy_from_slice(&self.x.to_le_bytes());
+        b[4..8].copy_from_slice(&self.y.to_le_bytes());
+        b[8..12].copy_from_slice(&self.z.to_le_bytes());
+        b[12..14].copy_from_slice(&self.intensity.to_le_bytes());
+        b[14] = (self.return_number & 0x0F) | (self.number_of_returns << 4);
+        b[15] = (self.classification_flags & 0x0F)
+            | ((self.scanner_channel & 0x03) << 4)
+            | ((self.scan_direction_flag & 1) << 6)
+            | (self.edge_of_flight_line << 7);
+        b[16] = self.classification;
+        b[17] = self.user_data;
+        b[18..20].copy_from_slice(&self.scan_angle.to_le_bytes());
+        b[20..22].copy_from_slice(&self.point_source_id.to_le_bytes());
+        b[22..30].copy_from_slice(&self.gps_time.to_le_bytes());
+    }
+
+    fn flags(&self) -> u32 {
+        ((self.edge_of_flight_line as u32) << 5)
+            | ((self.scan_direction_flag as u32) << 4)
+            | self.classification_flags as u32
+    }
+
+    fn gps_time_f64(&self) -> f64 {
+        f64::from_bits(self.gps_time)
+    }
+}
+
+/// Creates a model on first use; models in LASzip are created lazily, which
+/// is equivalent to starting from a freshly initialized model.
+fn lazy_model(
+    models: &mut [Option<ArithmeticModel>],
+    index: usize,
+    symbols: u32,
+    compress: bool,
+) -> &mut ArithmeticModel {
+    models[index].get_or_insert_with(|| ArithmeticModel::new(symbols, compress))
+}
+
+struct Point14Context {
+    last: Point14,
+    last_intensity: [u16; 8],
+    last_x_diff_median5: [StreamingMedian5; 12],
+    last_y_diff_median5: [StreamingMedian5; 12],
+    last_z: [i32; 8],
+    m_changed_values: Vec<ArithmeticModel>,
+    m_scanner_channel: ArithmeticModel,
+    m_number_of_returns: Vec<Option<ArithmeticModel>>,
+    m_return_number_gps_same: ArithmeticModel,
+    m_return_number: Vec<Option<ArithmeticModel>>,
+    ic_dx: IntegerCompressor,
+    ic_dy: IntegerCompressor,
+    ic_z: IntegerCompressor,
+    m_classification: Vec<Option<ArithmeticModel>>,
+    m_flags: Vec<Option<ArithmeticModel>>,
+    m_user_data: Vec<Option<ArithmeticModel>>,
+    ic_intensity: IntegerCompressor,
+    ic_scan_angle: IntegerCompressor,
+    ic_point_source_id: IntegerCompressor,
+    gps: GpsTimeState,
+}
+
+impl Point14Context {
+    fn new(item: &Point14, compress: bool) -> Point14Context {
+        let mut last = *item;
+        last.gps_time_change = false;
+        Point14Context {
+            last,
+            last_intensity: [item.intensity; 8],
+            last_x_diff_median5: [StreamingMedian5::new(); 12],
+            last_y_diff_median5: [StreamingMedian5::new(); 12],
+            last_z: [item.z; 8],
+            m_changed_values: vec![ArithmeticModel::new(128, compress); 8],
+            m_scanner_channel: ArithmeticModel::new(3, compress),
+            m_number_of_returns: vec![None; 16],
+            m_return_number_gps_same: ArithmeticModel::new(13, compress),
+            m_return_number: vec![None; 16],
+            ic_dx: IntegerCompressor::new(32, 2, compress),
+            ic_dy: IntegerCompressor::new(32, 22, compress),
+            ic_z: IntegerCompressor::new(32, 20, compress),
+            m_classification: vec![None; 64],
+            m_flags: vec![None; 64],
+            m_user_data: vec![None; 64],
+            ic_intensity: IntegerCompressor::new(16, 4, compress),
+            ic_scan_angle: IntegerCompressor::new(16, 2, compress),
+            ic_point_source_id: IntegerCompressor::new(16, 1, compress),
+            gps: GpsTimeState::new(item.gps_time as i64, 5, compress),
+        }
+    }
+}
+
+const POINT14_LAYERS: usize = 9;
+const LAYER_XY: usize = 0;
+const LAYER_Z: usize = 1;
+const LAYER_CLASSIFICATION: usize = 2;
+const LAYER_FLAGS: usize = 3;
+const LAYER_INTENSITY: usize = 4;
+const LAYER_SCAN_ANGLE: usize = 5;
+const LAYER_USER_DATA: usize = 6;
+const LAYER_POINT_SOURCE: usize = 7;
+const LAYER_GPS_TIME: usize = 8;
+
+/// Finishes a set of layer encoders, returning the bytes of the layers that
+/// changed and empty layers for the others.
+fn finish_layers(encoders: &mut [ArithmeticEncoder], changed: &[bool]) -> Vec<Vec<u8>> {
+    encoders
+        .iter_mut()
+        .zip(changed.iter())
+        .map(|(enc, &changed)| {
+            let bytes = enc.done();
+            if changed {
+                bytes
+            } else {
+                vec![]
+            }
+        })
+        .collect()
+}
+
+fn read_layer_sizes_into(sizes: &mut [usize], src: &[u8], pos: &mut usize) {
+    for size in sizes.iter_mut() {
+        *size = src
+            .get(*pos..*pos + 4)
+            .map(|b| read_u32(b, 0) as usize)
+            .unwrap_or(0);
+        *pos += 4;
+    }
+}
+
+fn read_layers_into(
+    layers: &mut Vec<ArithmeticDecoder<Vec<u8>>>,
+    sizes: &[usize],
+    src: &[u8],
+    pos: &mut usize,
+) {
+    layers.clear();
+    for &size in sizes {
+        let start = (*pos).min(src.len());
+        let end = (*pos + size).min(src.len());
+        layers.push(ArithmeticDecoder::new(src[start..end].to_vec()));
+        *pos += size;
+    }
+}
+
+pub(crate) struct Point14Compressor {
+    contexts: Vec<Option<Point14Context>>,
+    current_context: usize,
+    encoders: Vec<ArithmeticEncoder>,
+    changed: [bool; POINT14_LAYERS],
+    layers: Vec<Vec<u8>>,
+}
+
+impl Point14Compressor {
+    pub fn new() -> Point14Compressor {
+        Point14Compressor {
+            contexts: (0..4).map(|_| None).collect(),
+            current_context: 0,
+            encoders: (0..POINT14_LAYERS)
+                .map(|_| ArithmeticEncoder::new())
+                .collect(),
+            changed: [false; POINT14_LAYERS],
+            layers: vec![],
+        }
+    }
+
+    fn write_gps_time(&mut self, gps_time: i64) {
+        let enc = &mut self.encoders[LAYER_GPS_TIME];
+        let s = &mut self.contexts[self.current_context].as_mut().unwrap().gps;
+        loop {
+            let last = s.last;
+            let curr_gpstime_diff_64 = gps_time.wrapping_sub(s.last_gpstime[last]);
+            let curr_gpstime_diff = curr_gpstime_diff_64 as i32;
+            let fits = curr_gpstime_diff_64 == curr_gpstime_diff as i64;
+            if s.last_gpstime_diff[last] == 0 {
+                if fits {
+                    enc.encode_symbol(&mut s.m_gpstime_0diff, 0);
+                    s.ic_gpstime.compress(enc, 0, curr_gpstime_diff, 0);
+                    s.last_gpstime_diff[last] = curr_gpstime_diff;
+                    s.multi_extreme_counter[last] = 0;
+                } else if let Some(i) = s.find_sequence(gps_time) {
+                    enc.encode_symbol(&mut s.m_gpstime_0diff, i as u32 + 1);
+                    s.last = (s.last + i) & 3;
+                    continue;
+                } else {
+                    enc.encode_symbol(&mut s.m_gpstime_0diff, 1);
+                    s.compress_full(enc, gps_time);
+                }
+            } else if fits {
+                s.compress_with_multiplier(enc, curr_gpstime_diff);
+            } else if let Some(i) = s.find_sequence(gps_time) {
+                enc.encode_symbol(
+                    &mut s.m_gpstime_multi,
+                    (GPSTIME_MULTI_CODE_FULL + i as i32) as u32,
+                );
+                s.last = (s.last + i) & 3;
+                continue;
+            } else {
+                enc.encode_symbol(&mut s.m_gpstime_multi, GPSTIME_MULTI_CODE_FULL as u32);
+                s.compress_full(enc, gps_time);
+            }
+            s.last_gpstime[s.last] = gps_time;
+            return;
+        }
+    }
+}
+
+impl LayeredCompressor for Point14Compressor {
+    fn init(&mut self, first: &[u8], context: &mut usize) {
+        let item = Point14::unpack(first);
+        for c in self.contexts.iter_mut() {
+            *c = None;
+        }
+        for enc in self.encoders.iter_mut() {
+            *enc = ArithmeticEncoder::new();
+        }
+        // the Z layer is always written
+        self.changed = [false; POINT14_LAYERS];
+        self.changed[LAYER_XY] = true;
+        self.changed[LAYER_Z] = true;
+        self.current_context = item.scanner_channel as usize;
+        *context = self.current_context;
+        self.contexts[self.current_context] = Some(Point14Context::new(&item, true));
+    }
+
+    fn compress(&mut self, item_bytes: &[u8], context: &mut usize) {
+        let item = Point14::unpack(item_bytes);
+        let (lpr, last_changed_values_ctx) = {
+            let last = &self.contexts[self.current_context].as_ref().unwrap().last;
+            let mut lpr = (last.return_number == 1) as usize;
+            lpr += if last.return_number >= last.number_of_returns {
+                2
+            } else {
+                0
+            };
+            lpr += if last.gps_time_change { 4 } else { 0 };
+            (lpr, self.current_context)
+        };
+
+        let scanner_channel = item.scanner_channel as usize;
+        let mut last_ctx = self.current_context;
+        if scanner_channel != self.current_context && self.contexts[scanner_channel].is_some() {
+            last_ctx = scanner_channel;
+        }
+        let last = self.contexts[last_ctx].as_ref().unwrap().last;
+
+        let point_source_change = item.point_source_id != last.point_source_id;
+        let gps_time_change = item.gps_time_f64() != last.gps_time_f64();
+        let scan_angle_change = item.scan_angle != last.scan_angle;
+
+        let last_n = last.number_of_returns as usize;
+        let last_r = last.return_number as usize;
+        let n = item.number_of_returns as usize;
+        let r = item.return_number as usize;
+
+        let mut changed_values = ((scanner_channel != self.current_context) as u32) << 6
+            | (point_source_change as u32) << 5
+            | (gps_time_change as u32) << 4
+            | (scan_angle_change as u32) << 3
+            | ((n != last_n) as u32) << 2;
+        if r != last_r {
+            if r == (last_r + 1) % 16 {
+                changed_values |= 1;
+            } else if r == (last_r + 15) % 16 {
+                changed_values |= 2;
+            } else {
+                changed_values |= 3;
+            }
+        }
+
+        {
+            let enc = &mut self.encoders[LAYER_XY];
+            let ctx = self.contexts[last_changed_values_ctx].as_mut().unwrap();
+            enc.encode_symbol(&mut ctx.m_changed_values[lpr], changed_values);
+            if changed_values & (1 << 6) != 0 {
+                let diff = scanner_channel as i32 - self.current_context as i32;
+                let sym = if diff > 0 { diff - 1 } else { diff - 1 + 4 };
+                enc.encode_symbol(&mut ctx.m_scanner_channel, sym as u32);
+            }
+        }
+        if changed_values & (1 << 6) != 0 {
+            if self.contexts[scanner_channel].is_none() {
+                let from = self.contexts[self.current_context].as_ref().unwrap().last;
+                self.contexts[scanner_channel] = Some(Point14Context::new(&from, true));
+            }
+            self.current_context = scanner_channel;
+        }
+        *context = self.current_context;
+
+        let ctx = self.contexts[self.current_context].as_mut().unwrap();
+        let enc = &mut self.encoders[LAYER_XY];
+        if changed_values & (1 << 2) != 0 {
+            let model = lazy_model(&mut ctx.m_number_of_returns, last_n, 16, true);
+            enc.encode_symbol(model, n as u32);
+        }
+        if changed_values & 3 == 3 {
+            if gps_time_change {
+                let model = lazy_model(&mut ctx.m_return_number, last_r, 16, true);
+                enc.encode_symbol(model, r as u32);
+            } else {
+                let diff = r as i32 - last_r as i32;
+                let sym = if diff > 1 { diff - 2 } else { diff - 2 + 16 };
+                enc.encode_symbol(&mut ctx.m_return_number_gps_same, sym as u32);
+            }
+        }
+
+        let m = NUMBER_RETURN_MAP_6CTX[n][r] as usize;
+        let l = number_return_level_8ctx(n, r);
+        let mut cpr = if r == 1 { 2 } else { 0 };
+        cpr += if r >= n { 1 } else { 0 };
+        let gps_bit = gps_time_change as usize;
+
+        // X and Y
+        let idx = (m << 1) | gps_bit;
+        let median = ctx.last_x_diff_median5[idx].get();
+        let diff = item.x.wrapping_sub(last.x);
+        ctx.ic_dx.compress(enc, median, diff, (n == 1) as u32);
+        ctx.last_x_diff_median5[idx].add(diff);
+
+        let k_bits = ctx.ic_dx.k();
+        let median = ctx.last_y_diff_median5[idx].get();
+        let diff = item.y.wrapping_sub(last.y);
+        let c = (n == 1) as u32
+            + if k_bits < 20 {
+                u32_zero_bit_0(k_bits)
+            } else {
+                20
+            };
+        ctx.ic_dy.compress(enc, median, diff, c);
+        ctx.last_y_diff_median5[idx].add(diff);
+
+        // Z
+        let k_bits = (ctx.ic_dx.k() + ctx.ic_dy.k()) / 2;
+        let c = (n == 1) as u32
+            + if k_bits < 18 {
+                u32_zero_bit_0(k_bits)
+            } else {
+                18
+            };
+        ctx.ic_z
+            .compress(&mut self.encoders[LAYER_Z], ctx.last_z[l], item.z, c);
+        ctx.last_z[l] = item.z;
+
+        // classification
+        if item.classification != last.classification {
+            self.changed[LAYER_CLASSIFICATION] = true;
+        }
+        let ccc = (((last.classification & 0x1F) as usize) << 1) + if cpr == 3 { 1 } else { 0 };
+        let model = lazy_model(&mut ctx.m_classification, ccc, 256, true);
+        self.encoders[LAYER_CLASSIFICATION].encode_symbol(model, item.classification as u32);
+
+        // flags
+        let last_flags = last.flags();
+        let flags = item.flags();
+        if flags != last_flags {
+            self.changed[LAYER_FLAGS] = true;
+        }
+        let model = lazy_model(&mut ctx.m_flags, last_flags as usize, 64, true);
+        self.encoders[LAYER_FLAGS].encode_symbol(model, flags);
+
+        // intensity
+        if item.intensity != last.intensity {
+            self.changed[LAYER_INTENSITY] = true;
+        }
+        let idx = (cpr << 1) | gps_bit;
+        ctx.ic_intensity.compress(
+            &mut self.encoders[LAYER_INTENSITY],
+            ctx.last_intensity[idx] as i32,
+            item.intensity as i32,
+            cpr as u32,
+        );
+        ctx.last_intensity[idx] = item.intensity;
+
+        // scan angle
+        if scan_angle_change {
+            self.changed[LAYER_SCAN_ANGLE] = true;
+            ctx.ic_scan_angle.compress(
+                &mut self.encoders[LAYER_SCAN_ANGLE],
+                last.scan_angle as i32,
+                item.scan_angle as i32,
+                gps_bit as u32,
+            );
+        }
+
+        // user data
+        if item.user_data != last.user_data {
+            self.changed[LAYER_USER_DATA] = true;
+        }
+        let model = lazy_model(&mut ctx.m_user_data, last.user_data as usize / 4, 256, true);
+        self.encoders[LAYER_USER_DATA].encode_symbol(model, item.user_data as u32);
+
+        // point source ID
+        if point_source_change {
+            self.changed[LAYER_POINT_SOURCE] = true;
+            ctx.ic_point_source_id.compress(
+                &mut self.encoders[LAYER_POINT_SOURCE],
+                last.point_source_id as i32,
+                item.point_source_id as i32,
+                0,
+            );
+        }
+
+        let mut new_last = item;
+        new_last.gps_time_change = gps_time_change;
+        ctx.last = new_last;
+
+        // GPS time
+        if gps_time_change {
+            self.changed[LAYER_GPS_TIME] = true;
+            self.write_gps_time(item.gps_time as i64);
+        }
+    }
+
+    fn write_layer_sizes(&mut self, out: &mut Vec<u8>) {
+        self.layers = finish_layers(&mut self.encoders, &self.changed);
+        for layer in &self.layers {
+            out.extend_from_slice(&(layer.len() as u32).to_le_bytes());
+        }
+    }
+
+    fn write_layers(&mut self, out: &mut Vec<u8>) {
+        for layer in self.layers.drain(..) {
+            out.extend_from_slice(&layer);
+        }
+    }
+}
+
+pub(crate) struct Point14Decompressor {
+    contexts: Vec<Option<Point14Context>>,
+    current_context: usize,
+    sizes: [usize; POINT14_LAYERS],
+    decoders: Vec<ArithmeticDecoder<Vec<u8>>>,
+}
+
+impl Point14Decompressor {
+    pub fn new() -> Point14Decompressor {
+        Point14Decompressor {
+            contexts: (0..4).map(|_| None).collect(),
+            current_context: 0,
+            sizes: [0; POINT14_LAYERS],
+            decoders: vec![],
+        }
+    }
+
+    fn read_gps_time(&mut self) {
+        let dec = &mut self.decoders[LAYER_GPS_TIME];
+        let s = &mut self.contexts[self.current_context].as_mut().unwrap().gps;
+        loop {
+            if s.last_gpstime_diff[s.last] == 0 {
+                let multi = dec.decode_symbol(&mut s.m_gpstime_0diff) as usize;
+                if multi == 0 {
+                    let last = s.last;
+                    s.last_gpstime_diff[last] = s.ic_gpstime.decompress(dec, 0, 0);
+                    s.last_gpstime[last] =
+                        s.last_gpstime[last].wrapping_add(s.last_gpstime_diff[last] as i64);
+                    s.multi_extreme_counter[last] = 0;
+                } else if multi == 1 {
+                    s.decompress_full(dec);
+                } else {
+                    s.last = (s.last + multi - 1) & 3;
+                    continue;
+                }
+            } else {
+                let multi = dec.decode_symbol(&mut s.m_gpstime_multi) as i32;
+                if multi < GPSTIME_MULTI_CODE_FULL {
+                    s.decompress_with_multiplier(dec, multi);
+                } else if multi == GPSTIME_MULTI_CODE_FULL {
+                    s.decompress_full(dec);
+                } else {
+                    s.last = (s.last + (multi - GPSTIME_MULTI_CODE_FULL) as usize) & 3;
+                    continue;
+                }
+            }
+            return;
+        }
+    }
+}
+
+impl LayeredDecompressor for Point14Decompressor {
+    fn read_layer_sizes(&mut self, src: &[u8], pos: &mut usize) {
+        read_layer_sizes_into(&mut self.sizes, src, pos);
+    }
+
+    fn read_layers(&mut self, src: &[u8], pos: &mut usize) {
+        read_layers_into(&mut self.decoders, &self.sizes, src, pos);
+    }
+
+    fn init(&mut self, first: &[u8], context: &mut usize) {
+        let item = Point14::unpack(first);
+        for c in self.contexts.iter_mut() {
+            *c = None;
+        }
+        self.current_context = item.scanner_channel as usize;
+        *context = self.current_context;
+        self.contexts[self.current_context] = Some(Point14Context::new(&item, false));
+    }
+
+    fn decompress(&mut self, item: &mut [u8], context: &mut usize) {
+        let changed_values = {
+            let ctx = self.contexts[self.current_context].as_mut().unwrap();
+            let last = &ctx.last;
+            let mut lpr = (last.return_number == 1) as usize;
+            lpr += if last.return_number >= last.number_of_returns {
+                2
+            } else {
+                0
+            };
+            lpr += if last.gps_time_change { 4 } else { 0 };
+            self.decoders[LAYER_XY].decode_symbol(&mut ctx.m_changed_values[lpr])
+        };
+
+        if changed_values & (1 << 6) != 0 {
+            let diff = {
+                let ctx = self.contexts[self.current_context].as_mut().unwrap();
+                self.decoders[LAYER_XY].decode_symbol(&mut ctx.m_scanner_channel) as usize
+            };
+            let scanner_channel = (self.current_context + diff + 1) % 4;
+            if self.contexts[scanner_channel].is_none() {
+                let from = self.contexts[self.current_context].as_ref().unwrap().last;
+                self.contexts[scanner_channel] = Some(Point14Context::new(&from, false));
+            }
+            self.current_context = scanner_channel;
+            self.contexts[scanner_channel]
+                .as_mut()
+                .unwrap()
+                .last
+                .scanner_channel = scanner_channel as u8;
+        }
+        *context = self.current_context;
+
+        let point_source_change = changed_values & (1 << 5) != 0;
+        let gps_time_change = changed_values & (1 << 4) != 0;
+        let scan_angle_change = changed_values & (1 << 3) != 0;
+
+        let ctx = self.contexts[self.current_context].as_mut().unwrap();
+        let dec = &mut self.decoders[LAYER_XY];
+        let last_n = ctx.last.number_of_returns as usize;
+        let last_r = ctx.last.return_number as usize;
+
+        let n = if changed_values & (1 << 2) != 0 {
+            let model = lazy_model(&mut ctx.m_number_of_returns, last_n, 16, false);
+            let n = dec.decode_symbol(model) as usize;
+            ctx.last.number_of_returns = n as u8;
+            n
+        } else {
+            last_n
+        };
+
+        let r = match changed_values & 3 {
+            0 => last_r,
+            1 => (last_r + 1) % 16,
+            2 => (last_r + 15) % 16,
+            _ => {
+                if gps_time_change {
+                    let model = lazy_model(&mut ctx.m_return_number, last_r, 16, false);
+                    dec.decode_symbol(model) as usize
+                } else {
+                    let sym = dec.decode_symbol(&mut ctx.m_return_number_gps_same) as usize;
+                    (last_r + sym + 2) % 16
+                }
+            }
+        };
+        ctx.last.return_number = r as u8;
+
+        let m = NUMBER_RETURN_MAP_6CTX[n][r] as usize;
+        let l = number_return_level_8ctx(n, r);
+        let mut cpr = if r == 1 { 2 } else { 0 };
+        cpr += if r >= n { 1 } else { 0 };
+        let gps_bit = gps_time_change as usize;
+
+        // X and Y
+        let idx = (m << 1) | gps_bit;
+        let median = ctx.last_x_diff_median5[idx].get();
+        let diff = ctx.ic_dx.decompress(dec, median, (n == 1) as u32);
+        ctx.last.x = ctx.last.x.wrapping_add(diff);
+        ctx.last_x_diff_median5[idx].add(diff);
+
+        let median = ctx.last_y_diff_median5[idx].get();
+        let k_bits = ctx.ic_dx.k();
+        let c = (n == 1) as u32
+            + if k_bits < 20 {
+                u32_zero_bit_0(k_bits)
+            } else {
+                20
+            };
+        let diff = ctx.ic_dy.decompress(dec, median, c);
+        ctx.last.y = ctx.last.y.wrapping_add(diff);
+        ctx.last_y_diff_median5[idx].add(diff);
+
+        // Z
+        if self.sizes[LAYER_Z] > 0 {
+            let k_bits = (ctx.ic_dx.k() + ctx.ic_dy.k()) / 2;
+            let c = (n == 1) as u32
+                + if k_bits < 18 {
+                    u32_zero_bit_0(k_bits)
+                } else {
+                    18
+                };
+            ctx.last.z = ctx
+                .ic_z
+                .decompress(&mut self.decoders[LAYER_Z], ctx.last_z[l], c);
+            ctx.last_z[l] = ctx.last.z;
+        }
+
+        if self.sizes[LAYER_CLASSIFICATION] > 0 {
+            let ccc =
+                (((ctx.last.classification & 0x1F) as usize) << 1) + if cpr == 3 { 1 } else { 0 };
+            let model = lazy_model(&mut ctx.m_classification, ccc, 256, false);
+            ctx.last.classification =
+                self.decoders[LAYER_CLASSIFICATION].decode_symbol(model) as u8;
+        }
+
+        if self.sizes[LAYER_FLAGS] > 0 {
+            let last_flags = ctx.last.flags() as usize;
+            let model = lazy_model(&mut ctx.m_flags, last_flags, 64, false);
+            let flags = self.decoders[LAYER_FLAGS].decode_symbol(model);
+            ctx.last.edge_of_flight_line = ((flags >> 5) & 1) as u8;
+            ctx.last.scan_direction_flag = ((flags >> 4) & 1) as u8;
+            ctx.last.classification_flags = (flags & 0x0F) as u8;
+        }
+
+        if self.sizes[LAYER_INTENSITY] > 0 {
+            let idx = (cpr << 1) | gps_bit;
+            let intensity = ctx.ic_intensity.decompress(
+                &mut self.decoders[LAYER_INTENSITY],
+                ctx.last_intensity[idx] as i32,
+                cpr as u32,
+            ) as u16;
+            ctx.last_intensity[idx] = intensity;
+            ctx.last.intensity = intensity;
+        }
+
+        if self.sizes[LAYER_SCAN_ANGLE] > 0 && scan_angle_change {
+            ctx.last.scan_angle = ctx.ic_scan_angle.decompress(
+                &mut self.decoders[LAYER_SCAN_ANGLE],
+                ctx.last.scan_angle as i32,
+                gps_bit as u32,
+            ) as i16;
+        }
+
+        if self.sizes[LAYER_USER_DATA] > 0 {
+            let model = lazy_model(
+                &mut ctx.m_user_data,
+                ctx.last.user_data as usize / 4,
+                256,
+                false,
+            );
+            ctx.last.user_data = self.decoders[LAYER_USER_DATA].decode_symbol(model) as u8;
+        }
+
+        if self.sizes[LAYER_POINT_SOURCE] > 0 && point_source_change {
+            ctx.last.point_source_id = ctx.ic_point_source_id.decompress(
+                &mut self.decoders[LAYER_POINT_SOURCE],
+                ctx.last.point_source_id as i32,
+                0,
+            ) as u16;
+        }
+
+        if self.sizes[LAYER_GPS_TIME] > 0 && gps_time_change {
+            self.read_gps_time();
+            let ctx = self.contexts[self.current_context].as_mut().unwrap();
+            ctx.last.gps_time = ctx.gps.last_gpstime[ctx.gps.last] as u64;
+        }
+
+        let ctx = self.contexts[self.current_context].as_mut().unwrap();
+        ctx.last.pack(item);
+        ctx.last.gps_time_change = gps_time_change;
+    }
+}
+
+/// The per-scanner-channel state of the items that follow POINT14.
+struct ChannelContexts<T> {
+    contexts: Vec<Option<T>>,
+    current: usize,
+}
+
+impl<T> ChannelContexts<T> {
+    fn new() -> ChannelContexts<T> {
+        ChannelContexts {
+            contexts: (0..4).map(|_| None).collect(),
+            current: 0,
+        }
+    }
+
+    fn reset(&mut self, context: usize, first: T) {
+        for c in self.contexts.iter_mut() {
+            *c = None;
+        }
+        self.current = context;
+        self.contexts[context] = Some(first);
+    }
+
+    /// Switches to `context`, creating it from the current context's state if
+    /// it has not yet been used in this chunk, and returns the context.
+    fn switch<F: Fn(&T) -> T>(&mut self, context: usize, create: F) -> &mut T {
+        if context != self.current {
+            if self.contexts[context].is_none() {
+                let created = create(self.contexts[self.current].as_ref().unwrap());
+                self.contexts[context] = Some(created);
+            }
+            self.current = context;
+        }
+        self.contexts[self.current].as_mut().unwrap()
+    }
+}
+
+struct RgbContext {
+    rgb: RgbModels,
+    nir_used: ArithmeticModel,
+    nir_diff: [ArithmeticModel; 2],
+    last: [u16; 4],
+}
+
+impl RgbContext {
+    fn new(last: [u16; 4], compress: bool) -> RgbContext {
+        RgbContext {
+            rgb: RgbModels::new(compress),
+            nir_used: ArithmeticModel::new(4, compress),
+            nir_diff: [
+                ArithmeticModel::new(256, compress),
+                ArithmeticModel::new(256, compress),
+            ],
+            last,
+        }
+    }
+}
+
+fn unpack_rgbnir(b: &[u8], nir: bool) -> [u16; 4] {
+    let rgb = unpack_rgb(b);
+    [rgb[0], rgb[1], rgb[2], if nir { read_u16(b, 6) } else { 0 }]
+}
+
+/// The RGB14 (`nir == false`) and RGBNIR14 (`nir == true`) items.
+pub(crate) struct RgbNir14Compressor {
+    nir: bool,
+    contexts: ChannelContexts<RgbContext>,
+    encoders: [ArithmeticEncoder; 2],
+    changed: [bool; 2],
+    layers: Vec<Vec<u8>>,
+}
+
+impl RgbNir14Compressor {
+    pub fn new(nir: bool) -> RgbNir14Compressor {
+        RgbNir14Compressor {
+            nir,
+            contexts: ChannelContexts::new(),
+            encoders: [ArithmeticEncoder::new(), ArithmeticEncoder::new()],
+            changed: [false; 2],
+            layers: vec![],
+        }
+    }
+
+    fn num_layers(&self) -> usize {
+        if self.nir {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+impl LayeredCompressor for RgbNir14Compressor {
+    fn init(&mut self, first: &[u8], context: &mut usize) {
+        self.encoders = [ArithmeticEncoder::new(), ArithmeticEncoder::new()];
+        self.changed = [false; 2];
+        self.contexts.reset(
+            *context,
+            RgbContext::new(unpack_rgbnir(first, self.nir), true),
+        );
+    }
+
+    fn compress(&mut self, item: &[u8], context: &mut usize) {
+        let ctx = self
+            .contexts
+            .switch(*context, |c| RgbContext::new(c.last, true));
+        let this = unpack_rgbnir(item, self.nir);
+        let last_rgb = [ctx.last[0], ctx.last[1], ctx.last[2]];
+        let sym = ctx.rgb.compress(
+            &mut self.encoders[0],
+            &last_rgb,
+            &[this[0], this[1], this[2]],
+        );
+        if sym & 0x3F != 0 {
+            self.changed[0] = true;
+        }
+        if self.nir {
+            let enc = &mut self.encoders[1];
+            let lo = |v: u16| (v & 0x00FF) as i32;
+            let hi = |v: u16| (v >> 8) as i32;
+            let sym = (lo(ctx.last[3]) != lo(this[3])) as u32
+                | ((hi(ctx.last[3]) != hi(this[3])) as u32) << 1;
+            enc.encode_symbol(&mut ctx.nir_used, sym);
+            if sym & 1 != 0 {
+                let diff = lo(this[3]) - lo(ctx.last[3]);
+                enc.encode_symbol(&mut ctx.nir_diff[0], u8_fold(diff) as u32);
+            }
+            if sym & 2 != 0 {
+                let diff = hi(this[3]) - hi(ctx.last[3]);
+                enc.encode_symbol(&mut ctx.nir_diff[1], u8_fold(diff) as u32);
+            }
+            if sym != 0 {
+                self.changed[1] = true;
+            }
+        }
+        ctx.last = this;
+    }
+
+    fn write_layer_sizes(&mut self, out: &mut Vec<u8>) {
+        let n = self.num_layers();
+        self.layers = finish_layers(&mut self.encoders[0..n], &self.changed[0..n]);
+        for layer in &self.layers {
+            out.extend_from_slice(&(layer.len() as u32).to_le_bytes());
+        }
+    }
+
+    fn write_layers(&mut self, out: &mut Vec<u8>) {
+        for layer in self.layers.drain(..) {
+            out.extend_from_slice(&layer);
+        }
+    }
+}
+
+pub(crate) struct RgbNir14Decompressor {
+    nir: bool,
+    contexts: ChannelContexts<RgbContext>,
+    sizes: Vec<usize>,
+    decoders: Vec<ArithmeticDecoder<Vec<u8>>>,
+}
+
+impl RgbNir14Decompressor {
+    pub fn new(nir: bool) -> RgbNir14Decompressor {
+        RgbNir14Decompressor {
+            nir,
+            contexts: ChannelContexts::new(),
+            sizes: vec![0; if nir { 2 } else { 1 }],
+            decoders: vec![],
+        }
+    }
+}
+
+impl LayeredDecompressor for RgbNir14Decompressor {
+    fn read_layer_sizes(&mut self, src: &[u8], pos: &mut usize) {
+        read_layer_sizes_into(&mut self.sizes, src, pos);
+    }
+
+    fn read_layers(&mut self, src: &[u8], pos: &mut usize) {
+        read_layers_into(&mut self.decoders, &self.sizes, src, pos);
+    }
+
+    fn init(&mut self, first: &[u8], context: &mut usize) {
+        self.contexts.reset(
+            *context,
+            RgbContext::new(unpack_rgbnir(first, self.nir), false),
+        );
+    }
+
+    fn decompress(&mut self, item: &mut [u8], context: &mut usize) {
+        let ctx = self
+            .contexts
+            .switch(*context, |c| RgbContext::new(c.last, false));
+        if self.sizes[0] > 0 {
+            let last_rgb = [ctx.last[0], ctx.last[1], ctx.last[2]];
+            let rgb = ctx.rgb.decompress(&mut self.decoders[0], &last_rgb);
+            ctx.last[0..3].copy_from_slice(&rgb);
+        }
+        if self.nir && self.sizes[1] > 0 {
+            let dec = &mut self.decoders[1];
+            let last = ctx.last[3];
+            let sym = dec.decode_symbol(&mut ctx.nir_used);
+            let mut nir = if sym & 1 != 0 {
+                let corr = dec.decode_symbol(&mut ctx.nir_diff[0]) as i32;
+                u8_fold(corr + (last & 0x00FF) as i32) as u16
+            } else {
+                last & 0x00FF
+            };
+            if sym & 2 != 0 {
+                let corr = dec.decode_symbol(&mut ctx.nir_diff[1]) as i32;
+                nir |= (u8_fold(corr + (last >> 8) as i32) as u16) << 8;
+            } else {
+                nir |= last & 0xFF00;
+            }
+            ctx.last[3] = nir;
+        }
+        pack_rgb(&[ctx.last[0], ctx.last[1], ctx.last[2]], item);
+        if self.nir {
+            item[6..8].copy_from_slice(&ctx.last[3].to_le_bytes());
+        }
+    }
+}
+
+pub(crate) struct Wavepacket14Compressor {
+    contexts: ChannelContexts<WavepacketModels>,
+    encoder: ArithmeticEncoder,
+    changed: bool,
+    layer: Vec<u8>,
+}
+
+impl Wavepacket14Compressor {
+    pub fn new() -> Wavepacket14Compressor {
+        Wavepacket14Compressor {
+            contexts: ChannelContexts::new(),
+            encoder: ArithmeticEncoder::new(),
+            changed: false,
+            layer: vec![],
+        }
+    }
+}
+
+impl LayeredCompressor for Wavepacket14Compressor {
+    fn init(&mut self, first: &[u8], context: &mut usize) {
+        self.encoder = ArithmeticEncoder::new();
+        self.changed = false;
+        self.contexts
+            .reset(*context, WavepacketModels::new(first, true));
+    }
+
+    fn compress(&mut self, item: &[u8], context: &mut usize) {
+        let ctx = self
+            .contexts
+            .switch(*context, |c| WavepacketModels::new(&c.last, true));
+        if item[0..29] != ctx.last[..] {
+            self.changed = true;
+        }
+        ctx.compress(&mut self.encoder, item);
+    }
+
+    fn write_layer_sizes(&mut self, out: &mut Vec<u8>) {
+        let bytes = self.encoder.done();
+        self.layer = if self.changed { bytes } else { vec![] };
+        out.extend_from_slice(&(self.layer.len() as u32).to_le_bytes());
+    }
+
+    fn write_layers(&mut self, out: &mut Vec<u8>) {
+        out.append(&mut self.layer);
+    }
+}
+
+pub(crate) struct Wavepacket14Decompressor {
+    contexts: ChannelContexts<WavepacketModels>,
+    sizes: [usize; 1],
+    decoders: Vec<ArithmeticDecoder<Vec<u8>>>,
+}
+
+impl Wavepacket14Decompressor {
+    pub fn new() -> Wavepacket14Decompressor {
+        Wavepacket14Decompressor {
+            contexts: ChannelContexts::new(),
+            sizes: [0],
+            decoders: vec![],
+        }
+    }
+}
+
+impl LayeredDecompressor for Wavepacket14Decompressor {
+    fn read_layer_sizes(&mut self, src: &[u8], pos: &mut usize) {
+        read_layer_sizes_into(&mut self.sizes, src, pos);
+    }
+
+    fn read_layers(&mut self, src: &[u8], pos: &mut usize) {
+        read_layers_into(&mut self.decoders, &self.sizes, src, pos);
+    }
+
+    fn init(&mut self, first: &[u8], context: &mut usize) {
+        self.contexts
+            .reset(*context, WavepacketModels::new(first, false));
+    }
+
+    fn decompress(&mut self, item: &mut [u8], context: &mut usize) {
+        let ctx = self
+            .contexts
+            .switch(*context, |c| WavepacketModels::new(&c.last, false));
+        if self.sizes[0] > 0 {
+            ctx.decompress(&mut self.decoders[0], item);
+        } else {
+            item[0..29].copy_from_slice(&ctx.last);
+        }
+    }
+}
+
+struct ByteContext {
+    models: Vec<ArithmeticModel>,
+    last: Vec<u8>,
+}
+
+impl ByteContext {
+    fn new(last: &[u8], compress: bool) -> ByteContext {
+        ByteContext {
+            models: vec![ArithmeticModel::new(256, compress); last.len()],
+            last: last.to_vec(),
+        }
+    }
+}
+
+/// Extra bytes, each of which is coded into a layer of its own.
+pub(crate) struct Byte14Compressor {
+    count: usize,
+    contexts: ChannelContexts<ByteContext>,
+    encoders: Vec<ArithmeticEncoder>,
+    changed: Vec<bool>,
+    layers: Vec<Vec<u8>>,
+}
+
+impl Byte14Compressor {
+    pub fn new(count: usize) -> Byte14Compressor {
+        Byte14Compressor {
+            count,
+            contexts: ChannelContexts::new(),
+            encoders: (0..count).map(|_| ArithmeticEncoder::new()).collect(),
+            changed: vec![false; count],
+            layers: vec![],
+        }
+    }
+}
+
+impl LayeredCompressor for Byte14Compressor {
+    fn init(&mut self, first: &[u8], context: &mut usize) {
+        self.encoders = (0..self.count).map(|_| ArithmeticEncoder::new()).collect();
+        self.changed = vec![false; self.count];
+        self.contexts
+            .reset(*context, ByteContext::new(&first[0..self.count], true));
+    }
+
+    fn compress(&mut self, item: &[u8], context: &mut usize) {
+        let ctx = self
+            .contexts
+            .switch(*context, |c| ByteContext::new(&c.last, true));
+        for (i, &value) in item.iter().enumerate().take(self.count) {
+            let diff = value as i32 - ctx.last[i] as i32;
+            self.encoders[i].encode_symbol(&mut ctx.models[i], u8_fold(diff) as u32);
+            if diff != 0 {
+                self.changed[i] = true;
+            }
+            ctx.last[i] = value;
+        }
+    }
+
+    fn write_layer_sizes(&mut self, out: &mut Vec<u8>) {
+        self.layers = finish_layers(&mut self.encoders, &self.changed);
+        for layer in &self.layers {
+            out.extend_from_slice(&(layer.len() as u32).to_le_bytes());
+        }
+    }
+
+    fn write_layers(&mut self, out: &mut Vec<u8>) {
+        for layer in self.layers.drain(..) {
+            out.extend_from_slice(&layer);
+        }
+    }
+}
+
+pub(crate) struct Byte14Decompressor {
+    count: usize,
+    contexts: ChannelContexts<ByteContext>,
+    sizes: Vec<usize>,
+    decoders: Vec<ArithmeticDecoder<Vec<u8>>>,
+}
+
+impl Byte14Decompressor {
+    pub fn new(count: usize) -> Byte14Decompressor {
+        Byte14Decompressor {
+            count,
+            contexts: ChannelContexts::new(),
+            sizes: vec![0; count],
+            decoders: vec![],
+        }
+    }
+}
+
+impl LayeredDecompressor for Byte14Decompressor {
+    fn read_layer_sizes(&mut self, src: &[u8], pos: &mut usize) {
+        read_layer_sizes_into(&mut self.sizes, src, pos);
+    }
+
+    fn read_layers(&mut self, src: &[u8], pos: &mut usize) {
+        read_layers_into(&mut self.decoders, &self.sizes, src, pos);
+    }
+
+    fn init(&mut self, first: &[u8], context: &mut usize) {
+        self.contexts
+            .reset(*context, ByteContext::new(&first[0..self.count], false));
+    }
+
+    fn decompress(&mut self, item: &mut [u8], context: &mut usize) {
+        let ctx = self
+            .contexts
+            .switch(*context, |c| ByteContext::new(&c.last, false));
+        for (i, value) in item.iter_mut().enumerate().take(self.count) {
+            if self.sizes[i] > 0 {
+                let value =
+                    ctx.last[i] as i32 + self.decoders[i].decode_symbol(&mut ctx.models[i]) as i32;
+                ctx.last[i] = u8_fold(value);
+            }
+            *value = ctx.last[i];
+        }
+    }
+}
@@ -0,0 +1,985 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: Reading and writing of LAZ files, i.e. LAS files whose point records have
+been compressed with the LASzip arithmetic coder. A LAZ file is a LAS file with
+an additional 'laszip encoded' VLR that describes the compressed point items,
+followed by the compressed points in chunks and a table of the chunk sizes.
+Point formats 0-5 are coded point-wise (LASzip item version 2) and point formats
+6-10 are coded in layers (LASzip item versions 3 and 4). Rather than
+duplicating the LAS reader and writer, the functions in this module convert
+between the bytes of a LAZ file and those of the equivalent LAS file. The
+coding follows LASzip's, but it has only been tested against files written
+by this module, since the fixtures written by LASzip are still to be added.
+*/
+
+mod arithmetic;
+mod copc;
+mod integer;
+mod layered;
+mod pointwise;
+mod stream;
+
+pub(crate) use self::copc::{is_copc_file, read_copc_bounds};
+pub(crate) use self::stream::{LazStreamReader, LazStreamWriter};
+
+use self::arithmetic::{ArithmeticDecoder, ArithmeticEncoder};
+use self::integer::IntegerCompressor;
+use self::layered::*;
+use self::pointwise::*;
+use std::io::{Error, ErrorKind};
+
+const LASZIP_USER_ID: &str = "laszip encoded";
+const LASZIP_RECORD_ID: u16 = 22204;
+const VLR_HEADER_SIZE: usize = 54;
+
+const COMPRESSOR_POINTWISE: u16 = 1;
+const COMPRESSOR_POINTWISE_CHUNKED: u16 = 2;
+const COMPRESSOR_LAYERED_CHUNKED: u16 = 3;
+
+const DEFAULT_CHUNK_SIZE: u32 = 50_000;
+const VARIABLE_CHUNK_SIZE: u32 = u32::MAX;
+
+const ITEM_BYTE: u16 = 0;
+const ITEM_POINT10: u16 = 6;
+const ITEM_GPSTIME11: u16 = 7;
+const ITEM_RGB12: u16 = 8;
+const ITEM_WAVEPACKET13: u16 = 9;
+const ITEM_POINT14: u16 = 10;
+const ITEM_RGB14: u16 = 11;
+const ITEM_RGBNIR14: u16 = 12;
+const ITEM_WAVEPACKET14: u16 = 13;
+const ITEM_BYTE14: u16 = 14;
+
+/// The size of the core point record of each LAS point format.
+const POINT_FORMAT_SIZES: [u16; 11] = [20, 28, 26, 34, 57, 63, 30, 36, 38, 59, 67];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct LazItem {
+    item_type: u16,
+    size: u16,
+    version: u16,
+}
+
+impl LazItem {
+    fn new(item_type: u16, size: u16, version: u16) -> LazItem {
+        LazItem {
+            item_type,
+            size,
+            version,
+        }
+    }
+}
+
+/// The contents of the LASzip VLR.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct LazVlr {
+    compressor: u16,
+    coder: u16,
+    version_major: u8,
+    version_minor: u8,
+    version_revision: u16,
+    options: u32,
+    chunk_size: u32,
+    number_of_special_evlrs: i64,
+    offset_to_special_evlrs: i64,
+    items: Vec<LazItem>,
+}
+
+impl LazVlr {
+    /// Creates the VLR used to write a file of the given point format and record
+    /// length. Any bytes beyond the core point record are coded as extra bytes.
+    fn for_point_format(point_format: u8, record_length: u16) -> Result<LazVlr, Error> {
+        if point_format as usize >= POINT_FORMAT_SIZES.len() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "LAZ compression does not support point format {}.",
+                    point_format
+                ),
+            ));
+        }
+        let core_size = POINT_FORMAT_SIZES[point_format as usize];
+        if record_length < core_size {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "The point record length ({}) is too short for point format {}.",
+                    record_length, point_format
+                ),
+            ));
+        }
+        let extra_bytes = record_length - core_size;
+        let mut items = vec![];
+        let compressor = if point_format <= 5 {
+            items.push(LazItem::new(ITEM_POINT10, 20, 2));
+            if point_format != 0 && point_format != 2 {
+                items.push(LazItem::new(ITEM_GPSTIME11, 8, 2));
+            }
+            if point_format == 2 || point_format == 3 || point_format == 5 {
+                items.push(LazItem::new(ITEM_RGB12, 6, 2));
+            }
+            if point_format == 4 || point_format == 5 {
+                items.push(LazItem::new(ITEM_WAVEPACKET13, 29, 1));
+            }
+            if extra_bytes > 0 {
+                items.push(LazItem::new(ITEM_BYTE, extra_bytes, 2));
+            }
+            COMPRESSOR_POINTWISE_CHUNKED
+        } else {
+            items.push(LazItem::new(ITEM_POINT14, 30, 3));
+            match point_format {
+                7 => items.push(LazItem::new(ITEM_RGB14, 6, 3)),
+                8 | 10 => items.push(LazItem::new(ITEM_RGBNIR14, 8, 3)),
+                _ => {}
+            }
+            if point_format == 9 || point_format == 10 {
+                items.push(LazItem::new(ITEM_WAVEPACKET14, 29, 3));
+            }
+            if extra_bytes > 0 {
+                items.push(LazItem::new(ITEM_BYTE14, extra_bytes, 3));
+            }
+            COMPRESSOR_LAYERED_CHUNKED
+        };
+        Ok(LazVlr {
+            compressor,
+            coder: 0,
+            version_major: 3,
+            version_minor: 4,
+            version_revision: 3,
+            options: 0,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            number_of_special_evlrs: -1,
+            offset_to_special_evlrs: -1,
+            items,
+        })
+    }
+
+    fn from_bytes(data: &[u8]) -> Result<LazVlr, Error> {
+        if data.len() < 34 {
+            return Err(invalid_laz("the LASzip VLR is truncated"));
+        }
+        let num_items = read_u16(data, 32) as usize;
+        if data.len() < 34 + 6 * num_items {
+            return Err(invalid_laz("the LASzip VLR is truncated"));
+        }
+        let items = (0..num_items)
+            .map(|i| {
+                let offset = 34 + 6 * i;
+                LazItem::new(
+                    read_u16(data, offset),
+                    read_u16(data, offset + 2),
+                    read_u16(data, offset + 4),
+                )
+            })
+            .collect();
+        Ok(LazVlr {
+            compressor: read_u16(data, 0),
+            coder: read_u16(data, 2),
+            version_major: data[4],
+            version_minor: data[5],
+            version_revision: read_u16(data, 6),
+            options: read_u32(data, 8),
+            chunk_size: read_u32(data, 12),
+            number_of_special_evlrs: read_u64(data, 16) as i64,
+            offset_to_special_evlrs: read_u64(data, 24) as i64,
+            items,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(34 + 6 * self.items.len());
+        data.extend_from_slice(&self.compressor.to_le_bytes());
+        data.extend_from_slice(&self.coder.to_le_bytes());
+        data.push(self.version_major);
+        data.push(self.version_minor);
+        data.extend_from_slice(&self.version_revision.to_le_bytes());
+        data.extend_from_slice(&self.options.to_le_bytes());
+        data.extend_from_slice(&self.chunk_size.to_le_bytes());
+        data.extend_from_slice(&self.number_of_special_evlrs.to_le_bytes());
+        data.extend_from_slice(&self.offset_to_special_evlrs.to_le_bytes());
+        data.extend_from_slice(&(self.items.len() as u16).to_le_bytes());
+        for item in &self.items {
+            data.extend_from_slice(&item.item_type.to_le_bytes());
+            data.extend_from_slice(&item.size.to_le_bytes());
+            data.extend_from_slice(&item.version.to_le_bytes());
+        }
+        data
+    }
+
+    fn record_length(&self) -> usize {
+        self.items.iter().map(|item| item.size as usize).sum()
+    }
+
+    /// The byte range of each item within a point record.
+    fn item_ranges(&self) -> Vec<(usize, usize)> {
+        self.items
+            .iter()
+            .scan(0usize, |start, item| {
+                let range = (*start, *start + item.size as usize);
+                *start += item.size as usize;
+                Some(range)
+            })
+            .collect()
+    }
+
+    /// The complete VLR, including the VLR header.
+    fn to_vlr_bytes(&self) -> Vec<u8> {
+        let data = self.to_bytes();
+        let mut vlr = Vec::with_capacity(VLR_HEADER_SIZE + data.len());
+        vlr.extend_from_slice(&0u16.to_le_bytes());
+        vlr.extend_from_slice(&fixed_length_bytes(LASZIP_USER_ID, 16));
+        vlr.extend_from_slice(&LASZIP_RECORD_ID.to_le_bytes());
+        vlr.extend_from_slice(&(data.len() as u16).to_le_bytes());
+        vlr.extend_from_slice(&fixed_length_bytes("by WhiteboxTools", 32));
+        vlr.extend_from_slice(&data);
+        vlr
+    }
+
+    fn pointwise_compressors(&self) -> Result<Vec<Box<dyn PointwiseCompressor>>, Error> {
+        self.items
+            .iter()
+            .map(|item| -> Result<Box<dyn PointwiseCompressor>, Error> {
+                match item.item_type {
+                    ITEM_POINT10 => Ok(Box::new(Point10Compressor::new())),
+                    ITEM_GPSTIME11 => Ok(Box::new(GpsTime11Compressor::new())),
+                    ITEM_RGB12 => Ok(Box::new(Rgb12Compressor::new())),
+                    ITEM_WAVEPACKET13 => Ok(Box::new(Wavepacket13Compressor::new())),
+                    ITEM_BYTE => Ok(Box::new(ByteCompressor::new(item.size as usize))),
+                    _ => Err(unsupported_item(item)),
+                }
+            })
+            .collect()
+    }
+
+    fn pointwise_decompressors(&self) -> Result<Vec<Box<dyn PointwiseDecompressor>>, Error> {
+        self.items
+            .iter()
+            .map(|item| -> Result<Box<dyn PointwiseDecompressor>, Error> {
+                match (item.item_type, item.version) {
+                    (ITEM_POINT10, 2) => Ok(Box::new(Point10Decompressor::new())),
+                    (ITEM_GPSTIME11, 2) => Ok(Box::new(GpsTime11Decompressor::new())),
+                    (ITEM_RGB12, 2) => Ok(Box::new(Rgb12Decompressor::new())),
+                    (ITEM_WAVEPACKET13, 1) => Ok(Box::new(Wavepacket13Decompressor::new())),
+                    (ITEM_BYTE, 2) => Ok(Box::new(ByteDecompressor::new(item.size as usize))),
+                    _ => Err(unsupported_item(item)),
+                }
+            })
+            .collect()
+    }
+
+    fn layered_compressors(&self) -> Result<Vec<Box<dyn LayeredCompressor>>, Error> {
+        self.items
+            .iter()
+            .map(|item| -> Result<Box<dyn LayeredCompressor>, Error> {
+                match item.item_type {
+                    ITEM_POINT14 => Ok(Box::new(Point14Compressor::new())),
+                    ITEM_RGB14 => Ok(Box::new(RgbNir14Compressor::new(false))),
+                    ITEM_RGBNIR14 => Ok(Box::new(RgbNir14Compressor::new(true))),
+                    ITEM_WAVEPACKET14 => Ok(Box::new(Wavepacket14Compressor::new())),
+                    ITEM_BYTE14 => Ok(Box::new(Byte14Compressor::new(item.size as usize))),
+                    _ => Err(unsupported_item(item)),
+                }
+            })
+            .collect()
+    }
+
+    fn layered_decompressors(&self) -> Result<Vec<Box<dyn LayeredDecompressor>>, Error> {
+        self.items
+            .iter()
+            .map(|item| -> Result<Box<dyn LayeredDecompressor>, Error> {
+                if item.version != 3 && item.version != 4 {
+                    return Err(unsupported_item(item));
+                }
+                match item.item_type {
+                    ITEM_POINT14 => Ok(Box::new(Point14Decompressor::new())),
+                    ITEM_RGB14 => Ok(Box::new(RgbNir14Decompressor::new(false))),
+                    ITEM_RGBNIR14 => Ok(Box::new(RgbNir14Decompressor::new(true))),
+                    ITEM_WAVEPACKET14 => Ok(Box::new(Wavepacket14Decompressor::new())),
+                    ITEM_BYTE14 => Ok(Box::new(Byte14Decompressor::new(item.size as usize))),
+                    _ => Err(unsupported_item(item)),
+                }
+            })
+            .collect()
+    }
+}
+
+fn invalid_laz(msg: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("Error reading LAZ file: {}.", msg),
+    )
+}
+
+fn unsupported_item(item: &LazItem) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!(
+            "Error reading LAZ file: unsupported LASzip item (type {}, size {}, version {}).",
+            item.item_type, item.size, item.version
+        ),
+    )
+}
+
+/// The parts of a LAS header and its VLRs that are needed to (de)compress the points.
+struct LasLayout {
+    header_size: usize,
+    offset_to_points: usize,
+    point_format: u8,
+    record_length: usize,
+    number_of_points: u64,
+    /// The byte ranges of each VLR, including the VLR header.
+    vlrs: Vec<(usize, usize)>,
+    /// The offset and number of extended VLRs of a LAS 1.4 file, if any.
+    evlrs: Option<(usize, u32)>,
+}
+
+impl LasLayout {
+    fn parse(buffer: &[u8]) -> Result<LasLayout, Error> {
+        if buffer.len() < 227 || &buffer[0..4] != b"LASF" {
+            return Err(invalid_laz("the file does not have a valid LAS header"));
+        }
+        let version_minor = buffer[25];
+        let header_size = read_u16(buffer, 94) as usize;
+        let offset_to_points = read_u32(buffer, 96) as usize;
+        let number_of_vlrs = read_u32(buffer, 100) as usize;
+        let mut number_of_points = read_u32(buffer, 107) as u64;
+        let mut evlrs = None;
+        if version_minor >= 4 && header_size >= 375 && buffer.len() >= 375 {
+            if number_of_points == 0 {
+                number_of_points = read_u64(buffer, 247);
+            }
+            let offset = read_u64(buffer, 235) as usize;
+            let count = read_u32(buffer, 243);
+            if offset > 0 && count > 0 {
+                evlrs = Some((offset, count));
+            }
+        }
+        let mut vlrs = Vec::with_capacity(number_of_vlrs);
+        let mut pos = header_size;
+        for _ in 0..number_of_vlrs {
+            if pos + VLR_HEADER_SIZE > buffer.len() {
+                return Err(invalid_laz("a VLR extends beyond the end of the file"));
+            }
+            let end = pos + VLR_HEADER_SIZE + read_u16(buffer, pos + 20) as usize;
+            vlrs.push((pos, end));
+            pos = end;
+        }
+        if pos > offset_to_points {
+            return Err(invalid_laz("the VLRs overlap the point data"));
+        }
+        Ok(LasLayout {
+            header_size,
+            offset_to_points,
+            point_format: buffer[104],
+            record_length: read_u16(buffer, 105) as usize,
+            number_of_points,
+            vlrs,
+            evlrs,
+        })
+    }
+
+    fn vlrs_end(&self) -> usize {
+        self.vlrs.last().map(|v| v.1).unwrap_or(self.header_size)
+    }
+
+    /// Returns the index of the VLR with the given user ID and record ID.
+    fn find_vlr(&self, buffer: &[u8], user_id: &str, record_id: u16) -> Option<usize> {
+        self.vlrs.iter().position(|&(start, _)| {
+            let id = &buffer[start + 2..start + 18];
+            let len = id.iter().position(|&b| b == 0).unwrap_or(16);
+            &id[..len] == user_id.as_bytes() && read_u16(buffer, start + 18) == record_id
+        })
+    }
+
+    fn find_laszip_vlr(&self, buffer: &[u8]) -> Option<usize> {
+        self.find_vlr(buffer, LASZIP_USER_ID, LASZIP_RECORD_ID)
+    }
+}
+
+/// Returns true if the bytes of a LAS file contain a LASzip VLR, i.e. the
+/// point records are compressed.
+pub(crate) fn is_laz(buffer: &[u8]) -> bool {
+    match LasLayout::parse(buffer) {
+        Ok(layout) => layout.find_laszip_vlr(buffer).is_some(),
+        Err(_) => false,
+    }
+}
+
+/// Converts the bytes of a LAZ file into those of the equivalent LAS file. If
+/// `header_only` is true, the points are not decoded and the compressed point
+/// data are returned in their place, so that the buffer keeps the size that a
+/// reader expects of a complete file.
+pub(crate) fn decompress_laz(buffer: Vec<u8>, header_only: bool) -> Result<Vec<u8>, Error> {
+    let layout = LasLayout::parse(&buffer)?;
+    let vlr_index = layout
+        .find_laszip_vlr(&buffer)
+        .ok_or_else(|| invalid_laz("the LASzip VLR is missing"))?;
+    let (vlr_start, vlr_end) = layout.vlrs[vlr_index];
+    let laz_vlr = LazVlr::from_bytes(&buffer[vlr_start + VLR_HEADER_SIZE..vlr_end])?;
+    if laz_vlr.coder != 0 {
+        return Err(invalid_laz("unsupported entropy coder"));
+    }
+    if laz_vlr.record_length() != layout.record_length {
+        return Err(invalid_laz(
+            "the LASzip items do not match the point record length",
+        ));
+    }
+
+    // The header and the remaining VLRs, along with any bytes between the last
+    // VLR and the start of the points.
+    let vlr_size = vlr_end - vlr_start;
+    let mut output = Vec::with_capacity(
+        buffer.len() + (layout.number_of_points as usize) * layout.record_length,
+    );
+    output.extend_from_slice(&buffer[0..vlr_start]);
+    output.extend_from_slice(&buffer[vlr_end..layout.offset_to_points]);
+    output[104] = layout.point_format & 0x3F;
+    output[96..100].copy_from_slice(&((layout.offset_to_points - vlr_size) as u32).to_le_bytes());
+    output[100..104].copy_from_slice(&((layout.vlrs.len() - 1) as u32).to_le_bytes());
+    if header_only {
+        output.extend_from_slice(&buffer[layout.offset_to_points..]);
+        return Ok(output);
+    }
+
+    let points = match laz_vlr.compressor {
+        COMPRESSOR_POINTWISE | COMPRESSOR_POINTWISE_CHUNKED | COMPRESSOR_LAYERED_CHUNKED => {
+            decompress_points(&buffer, &layout, &laz_vlr)?
+        }
+        _ => return Err(invalid_laz("unsupported LASzip compressor")),
+    };
+    output.extend_from_slice(&points);
+
+    if let Some((offset, _)) = layout.evlrs {
+        if offset < buffer.len() && offset >= layout.offset_to_points {
+            let new_offset = output.len() as u64;
+            output.extend_from_slice(&buffer[offset..]);
+            output[235..243].copy_from_slice(&new_offset.to_le_bytes());
+        }
+    }
+    Ok(output)
+}
+
+/// Reads the chunk table, returning the number of points (if the chunks are of
+/// variable size) and the number of bytes of each chunk.
+fn read_chunk_table(buffer: &[u8], offset: usize, variable: bool) -> Option<Vec<(u32, usize)>> {
+    if offset + 8 > buffer.len() {
+        return None;
+    }
+    if read_u32(buffer, offset) != 0 {
+        return None;
+    }
+    let number_of_chunks = read_u32(buffer, offset + 4) as usize;
+    let mut dec = ArithmeticDecoder::new(&buffer[offset + 8..]);
+    let mut ic = IntegerCompressor::new(32, 2, false);
+    let mut chunks = Vec::with_capacity(number_of_chunks);
+    let (mut count, mut bytes) = (0i32, 0i32);
+    for _ in 0..number_of_chunks {
+        if variable {
+            count = ic.decompress(&mut dec, count, 0);
+        }
+        bytes = ic.decompress(&mut dec, bytes, 1);
+        chunks.push((count as u32, bytes as u32 as usize));
+    }
+    Some(chunks)
+}
+
+/// Decodes the chunks of a LAZ file. Each chunk is coded independently of the
+/// others, which allows a reader to decode any subset of the chunks.
+pub(crate) struct ChunkDecoder {
+    record_length: usize,
+    item_ranges: Vec<(usize, usize)>,
+    pointwise: Vec<Box<dyn PointwiseDecompressor>>,
+    layers: Vec<Box<dyn LayeredDecompressor>>,
+}
+
+impl ChunkDecoder {
+    pub fn new(laz_vlr: &LazVlr) -> Result<ChunkDecoder, Error> {
+        let layered = laz_vlr.compressor == COMPRESSOR_LAYERED_CHUNKED;
+        let item_ranges = laz_vlr.item_ranges();
+        Ok(ChunkDecoder {
+            record_length: laz_vlr.record_length(),
+            item_ranges,
+            pointwise: if layered {
+                vec![]
+            } else {
+                laz_vlr.pointwise_decompressors()?
+            },
+            layers: if layered {
+                laz_vlr.layered_decompressors()?
+            } else {
+                vec![]
+            },
+        })
+    }
+
+    /// Decodes up to `count` points of the chunk that starts at `pos` into
+    /// `points`, returning the number of points decoded and the position in
+    /// `buffer` of the end of the data that were read.
+    pub fn decode(
+        &mut self,
+        buffer: &[u8],
+        mut pos: usize,
+        mut count: usize,
+        points: &mut [u8],
+    ) -> Result<(usize, usize), Error> {
+        let record_length = self.record_length;
+        count = count.min(points.len() / record_length);
+        if count == 0 || pos + record_length > buffer.len() {
+            return Err(invalid_laz("the point data are truncated"));
+        }
+
+        // the first point of each chunk is stored raw
+        let first = &buffer[pos..pos + record_length];
+        points[0..record_length].copy_from_slice(first);
+        pos += record_length;
+
+        let item_ranges = &self.item_ranges;
+        if !self.layers.is_empty() {
+            if pos + 4 > buffer.len() {
+                return Err(invalid_laz("the point data are truncated"));
+            }
+            count = (read_u32(buffer, pos) as usize).clamp(1, count);
+            pos += 4;
+            for item in self.layers.iter_mut() {
+                item.read_layer_sizes(buffer, &mut pos);
+            }
+            for item in self.layers.iter_mut() {
+                item.read_layers(buffer, &mut pos);
+            }
+            let mut context = 0usize;
+            for (item, &(start, end)) in self.layers.iter_mut().zip(item_ranges.iter()) {
+                item.init(&first[start..end], &mut context);
+            }
+            for record in points[record_length..count * record_length].chunks_mut(record_length) {
+                for (item, &(start, end)) in self.layers.iter_mut().zip(item_ranges.iter()) {
+                    item.decompress(&mut record[start..end], &mut context);
+                }
+            }
+        } else {
+            for (item, &(start, end)) in self.pointwise.iter_mut().zip(item_ranges.iter()) {
+                item.init(&first[start..end]);
+            }
+            let mut dec = ArithmeticDecoder::new(&buffer[pos.min(buffer.len())..]);
+            for record in points[record_length..count * record_length].chunks_mut(record_length) {
+                for (item, &(start, end)) in self.pointwise.iter_mut().zip(item_ranges.iter()) {
+                    item.decompress(&mut dec, &mut record[start..end]);
+                }
+            }
+            pos += dec.position();
+        }
+        Ok((count, pos))
+    }
+}
+
+fn decompress_points(
+    buffer: &[u8],
+    layout: &LasLayout,
+    laz_vlr: &LazVlr,
+) -> Result<Vec<u8>, Error> {
+    let record_length = layout.record_length;
+    let num_points = layout.number_of_points as usize;
+    let mut points = vec![0u8; num_points * record_length];
+    if num_points == 0 {
+        return Ok(points);
+    }
+
+    // The point-wise compressor without chunks codes all of the points in a
+    // single stream, which is equivalent to one very large chunk.
+    let (chunk_size, mut pos, chunks) = if laz_vlr.compressor == COMPRESSOR_POINTWISE {
+        (num_points as u32, layout.offset_to_points, None)
+    } else {
+        if layout.offset_to_points + 8 > buffer.len() {
+            return Err(invalid_laz("the point data are truncated"));
+        }
+        let mut table_offset = read_u64(buffer, layout.offset_to_points) as i64;
+        if table_offset == -1 {
+            // The table offset is written at the end of the file by writers that
+            // cannot seek back to the start of the points.
+            table_offset = read_u64(buffer, buffer.len() - 8) as i64;
+        }
+        let variable = laz_vlr.chunk_size == VARIABLE_CHUNK_SIZE;
+        let chunks = if table_offset > 0 {
+            read_chunk_table(buffer, table_offset as usize, variable)
+        } else {
+            None
+        };
+        if variable && chunks.is_none() {
+            return Err(invalid_laz("the chunk table is missing"));
+        }
+        (laz_vlr.chunk_size, layout.offset_to_points + 8, chunks)
+    };
+
+    let mut decoder = ChunkDecoder::new(laz_vlr)?;
+    let mut point = 0usize;
+    let mut chunk = 0usize;
+    while point < num_points {
+        let chunk_start = pos;
+        let count = match &chunks {
+            Some(c) if laz_vlr.chunk_size == VARIABLE_CHUNK_SIZE => {
+                c.get(chunk).map(|c| c.0 as usize).unwrap_or(0)
+            }
+            _ => chunk_size as usize,
+        };
+        let (decoded, end) = decoder.decode(
+            buffer,
+            chunk_start,
+            count,
+            &mut points[point * record_length..],
+        )?;
+        pos = end;
+
+        // Prefer the chunk table for locating the next chunk, since readers are
+        // not required to consume every padding byte of a chunk.
+        if let Some(c) = chunks.as_ref().and_then(|c| c.get(chunk)) {
+            pos = chunk_start + c.1;
+        }
+        point += decoded;
+        chunk += 1;
+    }
+    Ok(points)
+}
+
+/// Encodes the chunks of a LAZ file, the inverse of `ChunkDecoder`.
+pub(crate) struct ChunkEncoder {
+    record_length: usize,
+    item_ranges: Vec<(usize, usize)>,
+    pointwise: Vec<Box<dyn PointwiseCompressor>>,
+    layers: Vec<Box<dyn LayeredCompressor>>,
+}
+
+impl ChunkEncoder {
+    pub fn new(laz_vlr: &LazVlr) -> Result<ChunkEncoder, Error> {
+        let layered = laz_vlr.compressor == COMPRESSOR_LAYERED_CHUNKED;
+        Ok(ChunkEncoder {
+            record_length: laz_vlr.record_length(),
+            item_ranges: laz_vlr.item_ranges(),
+            pointwise: if layered {
+                vec![]
+            } else {
+                laz_vlr.pointwise_compressors()?
+            },
+            layers: if layered {
+                laz_vlr.layered_compressors()?
+            } else {
+                vec![]
+            },
+        })
+    }
+
+    /// Appends the point records in `points` to `output` as a single chunk.
+    pub fn encode(&mut self, points: &[u8], output: &mut Vec<u8>) {
+        let record_length = self.record_length;
+        let item_ranges = &self.item_ranges;
+        let first = &points[0..record_length];
+        output.extend_from_slice(first);
+        let count = points.len() / record_length;
+        if !self.layers.is_empty() {
+            let mut context = 0usize;
+            for (item, &(start, end)) in self.layers.iter_mut().zip(item_ranges.iter()) {
+                item.init(&first[start..end], &mut context);
+            }
+            for record in points.chunks(record_length).skip(1) {
+                for (item, &(start, end)) in self.layers.iter_mut().zip(item_ranges.iter()) {
+                    item.compress(&record[start..end], &mut context);
+                }
+            }
+            output.extend_from_slice(&(count as u32).to_le_bytes());
+            for item in self.layers.iter_mut() {
+                item.write_layer_sizes(output);
+            }
+            for item in self.layers.iter_mut() {
+                item.write_layers(output);
+            }
+        } else {
+            for (item, &(start, end)) in self.pointwise.iter_mut().zip(item_ranges.iter()) {
+                item.init(&first[start..end]);
+            }
+            let mut enc = ArithmeticEncoder::new();
+            for record in points.chunks(record_length).skip(1) {
+                for (item, &(start, end)) in self.pointwise.iter_mut().zip(item_ranges.iter()) {
+                    item.compress(&mut enc, &record[start..end]);
+                }
+            }
+            output.extend_from_slice(&enc.done());
+        }
+    }
+}
+
+/// Appends the chunk table, given the number of points and bytes of each chunk.
+/// The point counts are only stored for files with variable-sized chunks.
+fn write_chunk_table(output: &mut Vec<u8>, chunks: &[(usize, usize)], variable: bool) {
+    output.extend_from_slice(&0u32.to_le_bytes());
+    output.extend_from_slice(&(chunks.len() as u32).to_le_bytes());
+    let mut enc = ArithmeticEncoder::new();
+    let mut ic = IntegerCompressor::new(32, 2, true);
+    let (mut last_count, mut last_bytes) = (0i32, 0i32);
+    for &(count, bytes) in chunks {
+        if variable {
+            ic.compress(&mut enc, last_count, count as i32, 0);
+            last_count = count as i32;
+        }
+        ic.compress(&mut enc, last_bytes, bytes as i32, 1);
+        last_bytes = bytes as i32;
+    }
+    output.extend_from_slice(&enc.done());
+}
+
+/// Returns the header and VLRs of the LAZ file equivalent to the LAS file `las`,
+/// including the LASzip VLR and a placeholder for the offset to the chunk table.
+fn laz_header(las: &[u8], layout: &LasLayout, laz_vlr: &LazVlr) -> Vec<u8> {
+    let vlrs_end = layout.vlrs_end();
+    let mut output = Vec::with_capacity(layout.offset_to_points + 512);
+    output.extend_from_slice(&las[0..vlrs_end]);
+    output.extend_from_slice(&laz_vlr.to_vlr_bytes());
+    output.extend_from_slice(&las[vlrs_end..layout.offset_to_points]);
+    let offset_to_points = output.len();
+    output[96..100].copy_from_slice(&(offset_to_points as u32).to_le_bytes());
+    output[100..104].copy_from_slice(&((layout.vlrs.len() + 1) as u32).to_le_bytes());
+    output[104] = layout.point_format | 0x80;
+    output.extend_from_slice(&[0u8; 8]);
+    output
+}
+
+/// Converts the bytes of a LAS file into those of the equivalent LAZ file.
+pub(crate) fn compress_las(las: &[u8]) -> Result<Vec<u8>, Error> {
+    let layout = LasLayout::parse(las)?;
+    if layout.find_laszip_vlr(las).is_some() {
+        return Ok(las.to_vec());
+    }
+    let laz_vlr = LazVlr::for_point_format(layout.point_format, layout.record_length as u16)?;
+    let num_points = layout.number_of_points as usize;
+    let record_length = layout.record_length;
+    if las.len() < layout.offset_to_points + num_points * record_length {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "The LAS data are shorter than the number of points in the header.",
+        ));
+    }
+
+    let mut output = Vec::with_capacity(las.len() / 4);
+    output.extend_from_slice(&laz_header(las, &layout, &laz_vlr));
+    let offset_to_points = output.len() - 8;
+
+    let points =
+        &las[layout.offset_to_points..layout.offset_to_points + num_points * record_length];
+    let mut encoder = ChunkEncoder::new(&laz_vlr)?;
+    let mut chunks = vec![];
+    for chunk in points.chunks(laz_vlr.chunk_size as usize * record_length) {
+        let chunk_start = output.len();
+        encoder.encode(chunk, &mut output);
+        chunks.push((chunk.len() / record_length, output.len() - chunk_start));
+    }
+
+    let table_offset = output.len() as u64;
+    output[offset_to_points..offset_to_points + 8].copy_from_slice(&table_offset.to_le_bytes());
+    write_chunk_table(&mut output, &chunks, false);
+
+    if let Some((offset, _)) = layout.evlrs {
+        if offset < las.len() && offset >= layout.offset_to_points {
+            let new_offset = output.len() as u64;
+            output.extend_from_slice(&las[offset..]);
+            output[235..243].copy_from_slice(&new_offset.to_le_bytes());
+        }
+    }
+    Ok(output)
+}
+
+fn fixed_length_bytes(s: &str, len: usize) -> Vec<u8> {
+    let mut bytes = s.as_bytes().to_vec();
+    bytes.resize(len, 0);
+    bytes
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        compress_las, decompress_laz, is_laz, LasLayout, POINT_FORMAT_SIZES, VLR_HEADER_SIZE,
+    };
+    use std::path::Path;
+
+    /// Builds a LAS 1.4 file with pseudo-random but spatially coherent points.
+    fn make_las(point_format: u8, extra_bytes: u16, num_points: usize) -> Vec<u8> {
+        let record_length = POINT_FORMAT_SIZES[point_format as usize] + extra_bytes;
+        let header_size = 375usize;
+        let mut las = vec![0u8; header_size];
+        las[0..4].copy_from_slice(b"LASF");
+        las[24] = 1;
+        las[25] = 4;
+        las[94..96].copy_from_slice(&(header_size as u16).to_le_bytes());
+        las[96..100].copy_from_slice(&(header_size as u32).to_le_bytes());
+        las[104] = point_format;
+        las[105..107].copy_from_slice(&record_length.to_le_bytes());
+        if point_format <= 5 {
+            las[107..111].copy_from_slice(&(num_points as u32).to_le_bytes());
+        }
+        las[247..255].copy_from_slice(&(num_points as u64).to_le_bytes());
+
+        let mut seed = 12345u32;
+        let mut rand = move || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            seed >> 8
+        };
+        let mut gps_time = 1000.0f64;
+        for i in 0..num_points {
+            let mut record = vec![0u8; record_length as usize];
+            let x = (i as i32) * 25 + (rand() % 50) as i32;
+            let y = 1_000_000 - (i as i32) * 3 + (rand() % 10) as i32;
+            let z = 5000 + (rand() % 3000) as i32 - 1500;
+            record[0..4].copy_from_slice(&x.to_le_bytes());
+            record[4..8].copy_from_slice(&y.to_le_bytes());
+            record[8..12].copy_from_slice(&z.to_le_bytes());
+            record[12..14].copy_from_slice(&((rand() % 400) as u16).to_le_bytes());
+            let n = 1 + (rand() % 3) as u8;
+            let r = 1 + (i as u8 % n);
+            if i % 3 == 0 {
+                gps_time += 0.000_013 * (1 + rand() % 5) as f64;
+            }
+            let core = if point_format <= 5 {
+                record[14] = r | (n << 3) | (((i / 100) % 2) as u8) << 6;
+                record[15] = if rand() % 10 == 0 { 6 } else { 2 };
+                record[16] = (rand() % 30) as u8;
+                record[17] = (i % 4) as u8;
+                record[18..20].copy_from_slice(&((i / 1000) as u16).to_le_bytes());
+                let mut pos = 20;
+                if point_format != 0 && point_format != 2 {
+                    record[pos..pos + 8].copy_from_slice(&gps_time.to_le_bytes());
+                    pos += 8;
+                }
+                pos
+            } else {
+                record[14] = r | (n << 4);
+                record[15] = (((i % 2) as u8) << 4) | ((((i / 100) % 2) as u8) << 6);
+                record[16] = if rand() % 10 == 0 { 6 } else { 2 };
+                record[17] = (i % 4) as u8;
+                record[18..20].copy_from_slice(&(((rand() % 200) as i16) - 100).to_le_bytes());
+                record[20..22].copy_from_slice(&((i / 1000) as u16).to_le_bytes());
+                record[22..30].copy_from_slice(&gps_time.to_le_bytes());
+                30
+            };
+            let end = POINT_FORMAT_SIZES[point_format as usize] as usize;
+            for b in record[core..end].iter_mut() {
+                *b = (rand() % 7) as u8;
+            }
+            for (j, b) in record[end..].iter_mut().enumerate() {
+                *b = ((i + j) % 5) as u8;
+            }
+            las.extend_from_slice(&record);
+        }
+        las
+    }
+
+    fn round_trip(point_format: u8, extra_bytes: u16, num_points: usize) {
+        let las = make_las(point_format, extra_bytes, num_points);
+        let laz = compress_las(&las).unwrap();
+        assert!(is_laz(&laz));
+        assert!(!is_laz(&las));
+        assert!(laz.len() < las.len());
+        let decoded = decompress_laz(laz, false).unwrap();
+        assert_eq!(decoded.len(), las.len());
+        assert!(
+            decoded == las,
+            "point format {} did not round trip",
+            point_format
+        );
+    }
+
+    #[test]
+    fn test_pointwise_round_trip() {
+        for point_format in 0..=5 {
+            round_trip(point_format, 0, 2000);
+        }
+        round_trip(1, 3, 2000);
+    }
+
+    #[test]
+    fn test_layered_round_trip() {
+        for point_format in 6..=10 {
+            round_trip(point_format, 0, 2000);
+        }
+        round_trip(6, 2, 2000);
+    }
+
+    #[test]
+    fn test_multiple_chunks() {
+        round_trip(3, 0, 120_000);
+        round_trip(7, 0, 120_000);
+    }
+
+    #[test]
+    fn test_decode_hand_assembled_file() {
+        // A LAZ file laid out by hand as LASzip writes point format 1 with one point per
+        // chunk: the LASzip VLR (point-wise chunked compressor 2, arithmetic coder 0,
+        // version 2.2, items POINT10 v2 and GPSTIME11 v2), the offset to the chunk table,
+        // then each chunk as its raw point followed by the four bytes that LASzip's
+        // arithmetic encoder writes when it is closed without having coded a symbol.
+        let las = make_las(1, 0, 3);
+        let offset_to_points = 375;
+        let mut vlr_data = vec![];
+        vlr_data.extend_from_slice(&[2, 0, 0, 0, 2, 2, 0, 0]);
+        vlr_data.extend_from_slice(&0u32.to_le_bytes());
+        vlr_data.extend_from_slice(&1u32.to_le_bytes());
+        vlr_data.extend_from_slice(&(-1i64).to_le_bytes());
+        vlr_data.extend_from_slice(&(-1i64).to_le_bytes());
+        vlr_data.extend_from_slice(&[2, 0, 6, 0, 20, 0, 2, 0, 7, 0, 8, 0, 2, 0]);
+        let mut laz = las[0..offset_to_points].to_vec();
+        let mut vlr = vec![0u8; VLR_HEADER_SIZE];
+        vlr[2..16].copy_from_slice(b"laszip encoded");
+        vlr[18..20].copy_from_slice(&22204u16.to_le_bytes());
+        vlr[20..22].copy_from_slice(&(vlr_data.len() as u16).to_le_bytes());
+        laz.extend_from_slice(&vlr);
+        laz.extend_from_slice(&vlr_data);
+        let laz_offset_to_points = laz.len() as u32;
+        laz[96..100].copy_from_slice(&laz_offset_to_points.to_le_bytes());
+        laz[100..104].copy_from_slice(&1u32.to_le_bytes());
+        laz[104] = 1 | 0x80;
+        // no chunk table, which readers handle for files with fixed-size chunks
+        laz.extend_from_slice(&0u64.to_le_bytes());
+        for point in las[offset_to_points..].chunks(28) {
+            laz.extend_from_slice(point);
+            laz.extend_from_slice(&[0x01, 0, 0, 0]);
+        }
+
+        assert!(is_laz(&laz));
+        let decoded = decompress_laz(laz, false).unwrap();
+        assert_eq!(decoded, las);
+    }
+
+    // The point formats of the files written by tests/data/laz/make_laz_fixtures.py.
+    const FIXTURE_POINT_FORMATS: [u8; 7] = [0, 1, 2, 3, 6, 7, 8];
+
+    /// Returns the points of a LAS file, as written after its header and VLRs.
+    fn point_records(las: &[u8]) -> &[u8] {
+        let layout = LasLayout::parse(las).unwrap();
+        let size = layout.number_of_points as usize * layout.record_length;
+        &las[layout.offset_to_points..layout.offset_to_points + size]
+    }
+
+    #[test]
+    #[ignore = "the LASzip fixtures haven't been generated; run tests/data/laz/make_laz_fixtures.py"]
+    fn test_decode_laszip_fixtures() {
+        // Decodes the files that LASzip compressed from the LAS files of
+        // tests/data/laz/make_laz_fixtures.py, and compares their points with the originals.
+        // The fixtures are generated with that script, where LASzip or PDAL is installed.
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data/laz");
+        let laz_file = |point_format: u8| dir.join(format!("point_format_{}.laz", point_format));
+        for point_format in FIXTURE_POINT_FORMATS {
+            let laz = std::fs::read(laz_file(point_format)).unwrap();
+            let las =
+                std::fs::read(dir.join(format!("point_format_{}.las", point_format))).unwrap();
+            assert!(is_laz(&laz), "point format {}", point_format);
+            let decoded = decompress_laz(laz, false).unwrap();
+            assert_eq!(decoded[104], point_format);
+            assert_eq!(
+                point_records(&decoded),
+                point_records(&las),
+                "point format {}",
+                point_format
+            );
+        }
+    }
+}
@@ -0,0 +1,1136 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+
+Notes: The point-wise LASzip items (POINT10 v2, GPSTIME11 v2, RGB12 v2, BYTE v2
+and WAVEPACKET13 v1), which are used for the LAS 1.0-1.3 point formats 0-5. All
+of the items of a point are coded into a single arithmetic-coded stream. Each
+item predicts its values from the last point, which the compressor and the
+decompressor update identically.
+*/
+
+use super::arithmetic::{ArithmeticDecoder, ArithmeticEncoder, ArithmeticModel};
+use super::integer::{
+    i32_quantize, u32_zero_bit_0, u8_clamp, u8_fold, IntegerCompressor, StreamingMedian5,
+};
+use std::convert::TryInto;
+
+pub(crate) trait PointwiseCompressor {
+    fn init(&mut self, first: &[u8]);
+    fn compress(&mut self, enc: &mut ArithmeticEncoder, item: &[u8]);
+}
+
+pub(crate) trait PointwiseDecompressor {
+    fn init(&mut self, first: &[u8]);
+    fn decompress(&mut self, dec: &mut ArithmeticDecoder<&[u8]>, item: &mut [u8]);
+}
+
+const NUMBER_RETURN_MAP: [[u8; 8]; 8] = [
+    [15, 14, 13, 12, 11, 10, 9, 8],
+    [14, 0, 1, 3, 6, 10, 10, 9],
+    [13, 1, 2, 4, 7, 11, 11, 10],
+    [12, 3, 4, 5, 8, 12, 12, 11],
+    [11, 6, 7, 8, 9, 13, 13, 12],
+    [10, 10, 11, 12, 13, 14, 14, 13],
+    [9, 10, 11, 12, 13, 14, 15, 14],
+    [8, 9, 10, 11, 12, 13, 14, 15],
+];
+
+const NUMBER_RETURN_LEVEL: [[u8; 8]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7],
+    [1, 0, 1, 2, 3, 4, 5, 6],
+    [2, 1, 0, 1, 2, 3, 4, 5],
+    [3, 2, 1, 0, 1, 2, 3, 4],
+    [4, 3, 2, 1, 0, 1, 2, 3],
+    [5, 4, 3, 2, 1, 0, 1, 2],
+    [6, 5, 4, 3, 2, 1, 0, 1],
+    [7, 6, 5, 4, 3, 2, 1, 0],
+];
+
+pub(crate) fn read_i32(b: &[u8], offset: usize) -> i32 {
+    i32::from_le_bytes(b[offset..offset + 4].try_into().unwrap())
+}
+
+pub(crate) fn read_u16(b: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes(b[offset..offset + 2].try_into().unwrap())
+}
+
+pub(crate) fn read_u32(b: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(b[offset..offset + 4].try_into().unwrap())
+}
+
+pub(crate) fn read_u64(b: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(b[offset..offset + 8].try_into().unwrap())
+}
+
+/// The 20-byte core of point formats 0-5.
+#[derive(Clone, Copy, Default)]
+struct Point10 {
+    x: i32,
+    y: i32,
+    z: i32,
+    intensity: u16,
+    bit_byte: u8,
+    classification: u8,
+    scan_angle_rank: u8,
+    user_data: u8,
+    point_source_id: u16,
+}
+
+impl Point10 {
+    fn unpack(b: &[u8]) -> Point10 {
+        Point10 {
+            x: read_i32(b, 0),
+            y: read_i32(b, 4),
+            z: read_i32(b, 8),
+            intensity: read_u16(b, 12),
+            bit_byte: b[14],
+            classification: b[15],
+            scan_angle_rank: b[16],
+            user_data: b[17],
+            point_source_id: read_u16(b, 18),
+        }
+    }
+
+    fn pack(&self, b: &mut [u8]) {
+        b[0..4].copy_from_slice(&self.x.to_le_bytes());
+        b[4..8].copy_from_slice(&self.y.to_le_bytes());
+        b[8..12].copy_from_slice(&self.z.to_le_bytes());
+        b[12..14].copy_from_slice(&self.intensity.to_le_bytes());
+        b[14] = self.bit_byte;
+        b[15] = self.classification;
+        b[16] = self.scan_angle_rank;
+        b[17] = self.user_data;
+        b[18..20].copy_from_slice(&self.point_source_id.to_le_bytes());
+    }
+
+    fn return_number(&self) -> usize {
+        (self.bit_byte & 7) as usize
+    }
+
+    fn number_of_returns(&self) -> usize {
+        ((self.bit_byte >> 3) & 7) as usize
+    }
+
+    fn scan_direction_flag(&self) -> usize {
+        ((self.bit_byte >> 6) & 1) as usize
+    }
+}
+
+struct Point10Models {
+    changed_values: ArithmeticModel,
+    ic_intensity: IntegerCompressor,
+    scan_angle_rank: [ArithmeticModel; 2],
+    ic_point_source_id: IntegerCompressor,
+    bit_byte: Vec<Option<ArithmeticModel>>,
+    classification: Vec<Option<ArithmeticModel>>,
+    user_data: Vec<Option<ArithmeticModel>>,
+    ic_dx: IntegerCompressor,
+    ic_dy: IntegerCompressor,
+    ic_z: IntegerCompressor,
+    last_intensity: [u16; 16],
+    last_x_diff_median5: [StreamingMedian5; 16],
+    last_y_diff_median5: [StreamingMedian5; 16],
+    last_height: [i32; 8],
+    last: Point10,
+    compress: bool,
+}
+
+impl Point10Models {
+    fn new(compress: bool) -> Point10Models {
+        Point10Models {
+            changed_values: ArithmeticModel::new(64, compress),
+            ic_intensity: IntegerCompressor::new(16, 4, compress),
+            scan_angle_rank: [
+                ArithmeticModel::new(256, compress),
+                ArithmeticModel::new(256, compress),
+            ],
+            ic_point_source_id: IntegerCompressor::new(16, 1, compress),
+            bit_byte: vec![None; 256],
+            classification: vec![None; 256],
+            user_data: vec![None; 256],
+            ic_dx: IntegerCompressor::new(32, 2, compress),
+            ic_dy: IntegerCompressor::new(32, 22, compress),
+            ic_z: IntegerCompressor::new(32, 20, compress),
+            last_intensity: [0; 16],
+            last_x_diff_median5: [StreamingMedian5::new(); 16],
+            last_y_diff_median5: [StreamingMedian5::new(); 16],
+            last_height: [0; 8],
+            last: Point10::default(),
+            compress,
+        }
+    }
+}
+
+fn lazy_model(
+    models: &mut [Option<ArithmeticModel>],
+    index: usize,
+    symbols: u32,
+    compress: bool,
+) -> &mut ArithmeticModel {
+    models[index].get_or_insert_with(|| ArithmeticModel::new(symbols, compress))
+}
+
+pub(crate) struct Point10Compressor {
+    m: Point10Models,
+}
+
+impl Point10Compressor {
+    pub fn new() -> Point10Compressor {
+        Point10Compressor {
+            m: Point10Models::new(true),
+        }
+    }
+}
+
+impl PointwiseCompressor for Point10Compressor {
+    fn init(&mut self, first: &[u8]) {
+        self.m = Point10Models::new(true);
+        self.m.last = Point10::unpack(first);
+    }
+
+    fn compress(&mut self, enc: &mut ArithmeticEncoder, item: &[u8]) {
+        let m = &mut self.m;
+        let cur = Point10::unpack(item);
+        let r = cur.return_number();
+        let n = cur.number_of_returns();
+        let mm = NUMBER_RETURN_MAP[n][r] as usize;
+        let l = NUMBER_RETURN_LEVEL[n][r] as usize;
+
+        let changed_values = ((m.last.bit_byte != cur.bit_byte) as u32) << 5
+            | ((m.last_intensity[mm] != cur.intensity) as u32) << 4
+            | ((m.last.classification != cur.classification) as u32) << 3
+            | ((m.last.scan_angle_rank != cur.scan_angle_rank) as u32) << 2
+            | ((m.last.user_data != cur.user_data) as u32) << 1
+            | (m.last.point_source_id != cur.point_source_id) as u32;
+        enc.encode_symbol(&mut m.changed_values, changed_values);
+
+        if changed_values & 32 != 0 {
+            let model = lazy_model(&mut m.bit_byte, m.last.bit_byte as usize, 256, true);
+            enc.encode_symbol(model, cur.bit_byte as u32);
+        }
+        if changed_values & 16 != 0 {
+            m.ic_intensity.compress(
+                enc,
+                m.last_intensity[mm] as i32,
+                cur.intensity as i32,
+                if mm < 3 { mm as u32 } else { 3 },
+            );
+            m.last_intensity[mm] = cur.intensity;
+        }
+        if changed_values & 8 != 0 {
+            let model = lazy_model(
+                &mut m.classification,
+                m.last.classification as usize,
+                256,
+                true,
+            );
+            enc.encode_symbol(model, cur.classification as u32);
+        }
+        if changed_values & 4 != 0 {
+            let diff = cur.scan_angle_rank as i32 - m.last.scan_angle_rank as i32;
+            enc.encode_symbol(
+                &mut m.scan_angle_rank[cur.scan_direction_flag()],
+                u8_fold(diff) as u32,
+            );
+        }
+        if changed_values & 2 != 0 {
+            let model = lazy_model(&mut m.user_data, m.last.user_data as usize, 256, true);
+            enc.encode_symbol(model, cur.user_data as u32);
+        }
+        if changed_values & 1 != 0 {
+            m.ic_point_source_id.compress(
+                enc,
+                m.last.point_source_id as i32,
+                cur.point_source_id as i32,
+                0,
+            );
+        }
+
+        // x
+        let median = m.last_x_diff_median5[mm].get();
+        let diff = cur.x.wrapping_sub(m.last.x);
+        m.ic_dx.compress(enc, median, diff, (n == 1) as u32);
+        m.last_x_diff_median5[mm].add(diff);
+
+        // y
+        let k_bits = m.ic_dx.k();
+        let median = m.last_y_diff_median5[mm].get();
+        let diff = cur.y.wrapping_sub(m.last.y);
+        let context = (n == 1) as u32
+            + if k_bits < 20 {
+                u32_zero_bit_0(k_bits)
+            } else {
+                20
+            };
+        m.ic_dy.compress(enc, median, diff, context);
+        m.last_y_diff_median5[mm].add(diff);
+
+        // z
+        let k_bits = (m.ic_dx.k() + m.ic_dy.k()) / 2;
+        let context = (n == 1) as u32
+            + if k_bits < 18 {
+                u32_zero_bit_0(k_bits)
+            } else {
+                18
+            };
+        m.ic_z.compress(enc, m.last_height[l], cur.z, context);
+        m.last_height[l] = cur.z;
+
+        m.last = cur;
+    }
+}
+
+pub(crate) struct Point10Decompressor {
+    m: Point10Models,
+}
+
+impl Point10Decompressor {
+    pub fn new() -> Point10Decompressor {
+        Point10Decompressor {
+            m: Point10Models::new(false),
+        }
+    }
+}
+
+impl PointwiseDecompressor for Point10Decompressor {
+    fn init(&mut self, first: &[u8]) {
+        self.m = Point10Models::new(false);
+        self.m.last = Point10::unpack(first);
+    }
+
+    fn decompress(&mut self, dec: &mut ArithmeticDecoder<&[u8]>, item: &mut [u8]) {
+        let m = &mut self.m;
+        let compress = m.compress;
+        let changed_values = dec.decode_symbol(&mut m.changed_values);
+        let (n, mm, l);
+        if changed_values != 0 {
+            if changed_values & 32 != 0 {
+                let model = lazy_model(&mut m.bit_byte, m.last.bit_byte as usize, 256, compress);
+                m.last.bit_byte = dec.decode_symbol(model) as u8;
+            }
+            let r = m.last.return_number();
+            n = m.last.number_of_returns();
+            mm = NUMBER_RETURN_MAP[n][r] as usize;
+            l = NUMBER_RETURN_LEVEL[n][r] as usize;
+
+            if changed_values & 16 != 0 {
+                let intensity = m.ic_intensity.decompress(
+                    dec,
+                    m.last_intensity[mm] as i32,
+                    if mm < 3 { mm as u32 } else { 3 },
+                ) as u16;
+                m.last_intensity[mm] = intensity;
+            }
+            m.last.intensity = m.last_intensity[mm];
+
+            if changed_values & 8 != 0 {
+                let model = lazy_model(
+                    &mut m.classification,
+                    m.last.classification as usize,
+                    256,
+                    compress,
+                );
+                m.last.classification = dec.decode_symbol(model) as u8;
+            }
+            if changed_values & 4 != 0 {
+                let val = dec.decode_symbol(&mut m.scan_angle_rank[m.last.scan_direction_flag()]);
+                m.last.scan_angle_rank = u8_fold(val as i32 + m.last.scan_angle_rank as i32);
+            }
+            if changed_values & 2 != 0 {
+                let model = lazy_model(&mut m.user_data, m.last.user_data as usize, 256, compress);
+                m.last.user_data = dec.decode_symbol(model) as u8;
+            }
+            if changed_values & 1 != 0 {
+                m.last.point_source_id =
+                    m.ic_point_source_id
+                        .decompress(dec, m.last.point_source_id as i32, 0)
+                        as u16;
+            }
+        } else {
+            let r = m.last.return_number();
+            n = m.last.number_of_returns();
+            mm = NUMBER_RETURN_MAP[n][r] as usize;
+            l = NUMBER_RETURN_LEVEL[n][r] as usize;
+        }
+
+        // x
+        let median = m.last_x_diff_median5[mm].get();
+        let diff = m.ic_dx.decompress(dec, median, (n == 1) as u32);
+        m.last.x = m.last.x.wrapping_add(diff);
+        m.last_x_diff_median5[mm].add(diff);
+
+        // y
+        let median = m.last_y_diff_median5[mm].get();
+        let k_bits = m.ic_dx.k();
+        let context = (n == 1) as u32
+            + if k_bits < 20 {
+                u32_zero_bit_0(k_bits)
+            } else {
+                20
+            };
+        let diff = m.ic_dy.decompress(dec, median, context);
+        m.last.y = m.last.y.wrapping_add(diff);
+        m.last_y_diff_median5[mm].add(diff);
+
+        // z
+        let k_bits = (m.ic_dx.k() + m.ic_dy.k()) / 2;
+        let context = (n == 1) as u32
+            + if k_bits < 18 {
+                u32_zero_bit_0(k_bits)
+            } else {
+                18
+            };
+        m.last.z = m.ic_z.decompress(dec, m.last_height[l], context);
+        m.last_height[l] = m.last.z;
+
+        m.last.pack(item);
+    }
+}
+
+const GPSTIME_MULTI: i32 = 500;
+const GPSTIME_MULTI_MINUS: i32 = -10;
+const GPSTIME_MULTI_UNCHANGED: i32 = GPSTIME_MULTI - GPSTIME_MULTI_MINUS + 1;
+pub(crate) const GPSTIME_MULTI_CODE_FULL: i32 = GPSTIME_MULTI - GPSTIME_MULTI_MINUS + 2;
+pub(crate) const GPSTIME_MULTI_TOTAL: i32 = GPSTIME_MULTI - GPSTIME_MULTI_MINUS + 6;
+
+/// The GPS time prediction state, which tracks up to four interleaved time
+/// sequences. It is shared by the GPSTIME11 item and the layered POINT14 item.
+pub(crate) struct GpsTimeState {
+    pub last: usize,
+    pub next: usize,
+    pub last_gpstime: [i64; 4],
+    pub last_gpstime_diff: [i32; 4],
+    pub multi_extreme_counter: [i32; 4],
+    pub m_gpstime_multi: ArithmeticModel,
+    pub m_gpstime_0diff: ArithmeticModel,
+    pub ic_gpstime: IntegerCompressor,
+}
+
+impl GpsTimeState {
+    pub fn new(first: i64, zero_diff_symbols: u32, compress: bool) -> GpsTimeState {
+        GpsTimeState {
+            last: 0,
+            next: 0,
+            last_gpstime: [first, 0, 0, 0],
+            last_gpstime_diff: [0; 4],
+            multi_extreme_counter: [0; 4],
+            m_gpstime_multi: ArithmeticModel::new(GPSTIME_MULTI_TOTAL as u32, compress),
+            m_gpstime_0diff: ArithmeticModel::new(zero_diff_symbols, compress),
+            ic_gpstime: IntegerCompressor::new(32, 9, compress),
+        }
+    }
+
+    /// Codes a time stamp whose last integer difference was non-zero. This part
+    /// of the scheme is identical in the point-wise and the layered items.
+    pub fn compress_with_multiplier(
+        &mut self,
+        enc: &mut ArithmeticEncoder,
+        curr_gpstime_diff: i32,
+    ) {
+        let last = self.last;
+        let multi_f = curr_gpstime_diff as f32 / self.last_gpstime_diff[last] as f32;
+        let multi = i32_quantize(multi_f);
+        let last_diff = self.last_gpstime_diff[last];
+        if multi == 1 {
+            enc.encode_symbol(&mut self.m_gpstime_multi, 1);
+            self.ic_gpstime
+                .compress(enc, last_diff, curr_gpstime_diff, 1);
+            self.multi_extreme_counter[last] = 0;
+        } else if multi > 0 {
+            if multi < GPSTIME_MULTI {
+                enc.encode_symbol(&mut self.m_gpstime_multi, multi as u32);
+                let context = if multi < 10 { 2 } else { 3 };
+                self.ic_gpstime.compress(
+                    enc,
+                    multi.wrapping_mul(last_diff),
+                    curr_gpstime_diff,
+                    context,
+                );
+            } else {
+                enc.encode_symbol(&mut self.m_gpstime_multi, GPSTIME_MULTI as u32);
+                self.ic_gpstime.compress(
+                    enc,
+                    GPSTIME_MULTI.wrapping_mul(last_diff),
+                    curr_gpstime_diff,
+                    4,
+                );
+                self.count_extreme(curr_gpstime_diff);
+            }
+        } else if multi < 0 {
+            if multi > GPSTIME_MULTI_MINUS {
+                enc.encode_symbol(&mut self.m_gpstime_multi, (GPSTIME_MULTI - multi) as u32);
+                self.ic_gpstime
+                    .compress(enc, multi.wrapping_mul(last_diff), curr_gpstime_diff, 5);
+            } else {
+                enc.encode_symbol(
+                    &mut self.m_gpstime_multi,
+                    (GPSTIME_MULTI - GPSTIME_MULTI_MINUS) as u32,
+                );
+                self.ic_gpstime.compress(
+                    enc,
+                    GPSTIME_MULTI_MINUS.wrapping_mul(last_diff),
+                    curr_gpstime_diff,
+                    6,
+                );
+                self.count_extreme(curr_gpstime_diff);
+            }
+        } else {
+            enc.encode_symbol(&mut self.m_gpstime_multi, 0);
+            self.ic_gpstime.compress(enc, 0, curr_gpstime_diff, 7);
+            self.count_extreme(curr_gpstime_diff);
+        }
+    }
+
+    /// The inverse of `compress_with_multiplier` for a decoded multiplier
+    /// symbol that is less than GPSTIME_MULTI_CODE_FULL.
+    pub fn decompress_with_multiplier<T: AsRef<[u8]>>(
+        &mut self,
+        dec: &mut ArithmeticDecoder<T>,
+        multi: i32,
+    ) {
+        let last = self.last;
+        let last_diff = self.last_gpstime_diff[last];
+        let gpstime_diff = if multi == 1 {
+            let diff = self.ic_gpstime.decompress(dec, last_diff, 1);
+            self.multi_extreme_counter[last] = 0;
+            diff
+        } else if multi == 0 {
+            let diff = self.ic_gpstime.decompress(dec, 0, 7);
+            self.count_extreme(diff);
+            diff
+        } else if multi < GPSTIME_MULTI {
+            let context = if multi < 10 { 2 } else { 3 };
+            self.ic_gpstime
+                .decompress(dec, multi.wrapping_mul(last_diff), context)
+        } else if multi == GPSTIME_MULTI {
+            let diff = self
+                .ic_gpstime
+                .decompress(dec, GPSTIME_MULTI.wrapping_mul(last_diff), 4);
+            self.count_extreme(diff);
+            diff
+        } else {
+            let multi = GPSTIME_MULTI - multi;
+            if multi > GPSTIME_MULTI_MINUS {
+                self.ic_gpstime
+                    .decompress(dec, multi.wrapping_mul(last_diff), 5)
+            } else {
+                let diff =
+                    self.ic_gpstime
+                        .decompress(dec, GPSTIME_MULTI_MINUS.wrapping_mul(last_diff), 6);
+                self.count_extreme(diff);
+                diff
+            }
+        };
+        self.last_gpstime[last] = self.last_gpstime[last].wrapping_add(gpstime_diff as i64);
+    }
+
+    fn count_extreme(&mut self, curr_gpstime_diff: i32) {
+        let last = self.last;
+        self.multi_extreme_counter[last] += 1;
+        if self.multi_extreme_counter[last] > 3 {
+            self.last_gpstime_diff[last] = curr_gpstime_diff;
+            self.multi_extreme_counter[last] = 0;
+        }
+    }
+
+    /// Finds another of the tracked sequences whose last time stamp is within
+    /// a 32-bit integer difference of `gpstime`.
+    pub fn find_sequence(&self, gpstime: i64) -> Option<usize> {
+        (1..4).find(|i| {
+            let diff = gpstime.wrapping_sub(self.last_gpstime[(self.last + i) & 3]);
+            diff == diff as i32 as i64
+        })
+    }
+
+    /// Starts a new sequence by coding the full time stamp.
+    pub fn compress_full(&mut self, enc: &mut ArithmeticEncoder, gpstime: i64) {
+        self.ic_gpstime.compress(
+            enc,
+            (self.last_gpstime[self.last] as u64 >> 32) as i32,
+            (gpstime as u64 >> 32) as i32,
+            8,
+        );
+        enc.write_int(gpstime as u64 as u32);
+        self.next = (self.next + 1) & 3;
+        self.last = self.next;
+        self.last_gpstime_diff[self.last] = 0;
+        self.multi_extreme_counter[self.last] = 0;
+    }
+
+    pub fn decompress_full<T: AsRef<[u8]>>(&mut self, dec: &mut ArithmeticDecoder<T>) {
+        self.next = (self.next + 1) & 3;
+        let high =
+            self.ic_gpstime
+                .decompress(dec, (self.last_gpstime[self.last] as u64 >> 32) as i32, 8)
+                as u32 as u64;
+        let low = dec.read_int() as u64;
+        self.last_gpstime[self.next] = ((high << 32) | low) as i64;
+        self.last = self.next;
+        self.last_gpstime_diff[self.last] = 0;
+        self.multi_extreme_counter[self.last] = 0;
+    }
+}
+
+pub(crate) struct GpsTime11Compressor {
+    s: GpsTimeState,
+}
+
+impl GpsTime11Compressor {
+    pub fn new() -> GpsTime11Compressor {
+        GpsTime11Compressor {
+            s: GpsTimeState::new(0, 6, true),
+        }
+    }
+
+    fn write(&mut self, enc: &mut ArithmeticEncoder, this_gpstime: i64) {
+        let s = &mut self.s;
+        let last = s.last;
+        if s.last_gpstime_diff[last] == 0 {
+            if this_gpstime == s.last_gpstime[last] {
+                enc.encode_symbol(&mut s.m_gpstime_0diff, 0);
+            } else {
+                let curr_gpstime_diff_64 = this_gpstime.wrapping_sub(s.last_gpstime[last]);
+                let curr_gpstime_diff = curr_gpstime_diff_64 as i32;
+                if curr_gpstime_diff_64 == curr_gpstime_diff as i64 {
+                    enc.encode_symbol(&mut s.m_gpstime_0diff, 1);
+                    s.ic_gpstime.compress(enc, 0, curr_gpstime_diff, 0);
+                    s.last_gpstime_diff[last] = curr_gpstime_diff;
+                    s.multi_extreme_counter[last] = 0;
+                } else {
+                    if let Some(i) = s.find_sequence(this_gpstime) {
+                        enc.encode_symbol(&mut s.m_gpstime_0diff, i as u32 + 2);
+                        s.last = (s.last + i) & 3;
+                        return self.write(enc, this_gpstime);
+                    }
+                    enc.encode_symbol(&mut s.m_gpstime_0diff, 2);
+                    s.compress_full(enc, this_gpstime);
+                }
+                s.last_gpstime[s.last] = this_gpstime;
+            }
+        } else if this_gpstime == s.last_gpstime[last] {
+            enc.encode_symbol(&mut s.m_gpstime_multi, GPSTIME_MULTI_UNCHANGED as u32);
+        } else {
+            let curr_gpstime_diff_64 = this_gpstime.wrapping_sub(s.last_gpstime[last]);
+            let curr_gpstime_diff = curr_gpstime_diff_64 as i32;
+            if curr_gpstime_diff_64 == curr_gpstime_diff as i64 {
+                s.compress_with_multiplier(enc, curr_gpstime_diff);
+            } else {
+                if let Some(i) = s.find_sequence(this_gpstime) {
+                    enc.encode_symbol(
+                        &mut s.m_gpstime_multi,
+                        (GPSTIME_MULTI_CODE_FULL + i as i32) as u32,
+                    );
+                    s.last = (s.last + i) & 3;
+                    return self.write(enc, this_gpstime);
+                }
+                enc.encode_symbol(&mut s.m_gpstime_multi, GPSTIME_MULTI_CODE_FULL as u32);
+                s.compress_full(enc, this_gpstime);
+            }
+            s.last_gpstime[s.last] = this_gpstime;
+        }
+    }
+}
+
+impl PointwiseCompressor for GpsTime11Compressor {
+    fn init(&mut self, first: &[u8]) {
+        self.s = GpsTimeState::new(read_u64(first, 0) as i64, 6, true);
+    }
+
+    fn compress(&mut self, enc: &mut ArithmeticEncoder, item: &[u8]) {
+        self.write(enc, read_u64(item, 0) as i64);
+    }
+}
+
+pub(crate) struct GpsTime11Decompressor {
+    s: GpsTimeState,
+}
+
+impl GpsTime11Decompressor {
+    pub fn new() -> GpsTime11Decompressor {
+        GpsTime11Decompressor {
+            s: GpsTimeState::new(0, 6, false),
+        }
+    }
+
+    fn read(&mut self, dec: &mut ArithmeticDecoder<&[u8]>) {
+        let s = &mut self.s;
+        if s.last_gpstime_diff[s.last] == 0 {
+            let multi = dec.decode_symbol(&mut s.m_gpstime_0diff) as usize;
+            if multi == 1 {
+                let last = s.last;
+                s.last_gpstime_diff[last] = s.ic_gpstime.decompress(dec, 0, 0);
+                s.last_gpstime[last] =
+                    s.last_gpstime[last].wrapping_add(s.last_gpstime_diff[last] as i64);
+                s.multi_extreme_counter[last] = 0;
+            } else if multi == 2 {
+                s.decompress_full(dec);
+            } else if multi > 2 {
+                s.last = (s.last + multi - 2) & 3;
+                self.read(dec);
+            }
+        } else {
+            let multi = dec.decode_symbol(&mut s.m_gpstime_multi) as i32;
+            if multi < GPSTIME_MULTI_UNCHANGED {
+                s.decompress_with_multiplier(dec, multi);
+            } else if multi == GPSTIME_MULTI_CODE_FULL {
+                s.decompress_full(dec);
+            } else if multi > GPSTIME_MULTI_CODE_FULL {
+                s.last = (s.last + (multi - GPSTIME_MULTI_CODE_FULL) as usize) & 3;
+                self.read(dec);
+            }
+        }
+    }
+}
+
+impl PointwiseDecompressor for GpsTime11Decompressor {
+    fn init(&mut self, first: &[u8]) {
+        self.s = GpsTimeState::new(read_u64(first, 0) as i64, 6, false);
+    }
+
+    fn decompress(&mut self, dec: &mut ArithmeticDecoder<&[u8]>, item: &mut [u8]) {
+        self.read(dec);
+        item[0..8].copy_from_slice(&self.s.last_gpstime[self.s.last].to_le_bytes());
+    }
+}
+
+/// The models used to code one RGB triplet, shared with the layered items.
+#[derive(Clone)]
+pub(crate) struct RgbModels {
+    byte_used: ArithmeticModel,
+    rgb_diff: [ArithmeticModel; 6],
+}
+
+impl RgbModels {
+    pub fn new(compress: bool) -> RgbModels {
+        RgbModels {
+            byte_used: ArithmeticModel::new(128, compress),
+            rgb_diff: [
+                ArithmeticModel::new(256, compress),
+                ArithmeticModel::new(256, compress),
+                ArithmeticModel::new(256, compress),
+                ArithmeticModel::new(256, compress),
+                ArithmeticModel::new(256, compress),
+                ArithmeticModel::new(256, compress),
+            ],
+        }
+    }
+
+    /// Codes `item` relative to `last` and returns the byte-used mask.
+    pub fn compress(
+        &mut self,
+        enc: &mut ArithmeticEncoder,
+        last: &[u16; 3],
+        item: &[u16; 3],
+    ) -> u32 {
+        let lo = |v: u16| (v & 0x00FF) as i32;
+        let hi = |v: u16| (v >> 8) as i32;
+        let mut sym = (lo(last[0]) != lo(item[0])) as u32;
+        sym |= ((hi(last[0]) != hi(item[0])) as u32) << 1;
+        sym |= ((lo(last[1]) != lo(item[1])) as u32) << 2;
+        sym |= ((hi(last[1]) != hi(item[1])) as u32) << 3;
+        sym |= ((lo(last[2]) != lo(item[2])) as u32) << 4;
+        sym |= ((hi(last[2]) != hi(item[2])) as u32) << 5;
+        sym |= ((lo(item[0]) != lo(item[1])
+            || lo(item[0]) != lo(item[2])
+            || hi(item[0]) != hi(item[1])
+            || hi(item[0]) != hi(item[2])) as u32)
+            << 6;
+        enc.encode_symbol(&mut self.byte_used, sym);
+
+        let mut diff_l = 0;
+        let mut diff_h = 0;
+        if sym & 1 != 0 {
+            diff_l = lo(item[0]) - lo(last[0]);
+            enc.encode_symbol(&mut self.rgb_diff[0], u8_fold(diff_l) as u32);
+        }
+        if sym & 2 != 0 {
+            diff_h = hi(item[0]) - hi(last[0]);
+            enc.encode_symbol(&mut self.rgb_diff[1], u8_fold(diff_h) as u32);
+        }
+        if sym & 64 != 0 {
+            if sym & 4 != 0 {
+                let corr = lo(item[1]) - u8_clamp(diff_l + lo(last[1]));
+                enc.encode_symbol(&mut self.rgb_diff[2], u8_fold(corr) as u32);
+            }
+            if sym & 16 != 0 {
+                diff_l = (diff_l + lo(item[1]) - lo(last[1])) / 2;
+                let corr = lo(item[2]) - u8_clamp(diff_l + lo(last[2]));
+                enc.encode_symbol(&mut self.rgb_diff[4], u8_fold(corr) as u32);
+            }
+            if sym & 8 != 0 {
+                let corr = hi(item[1]) - u8_clamp(diff_h + hi(last[1]));
+                enc.encode_symbol(&mut self.rgb_diff[3], u8_fold(corr) as u32);
+            }
+            if sym & 32 != 0 {
+                diff_h = (diff_h + hi(item[1]) - hi(last[1])) / 2;
+                let corr = hi(item[2]) - u8_clamp(diff_h + hi(last[2]));
+                enc.encode_symbol(&mut self.rgb_diff[5], u8_fold(corr) as u32);
+            }
+        }
+        sym
+    }
+
+    pub fn decompress<T: AsRef<[u8]>>(
+        &mut self,
+        dec: &mut ArithmeticDecoder<T>,
+        last: &[u16; 3],
+    ) -> [u16; 3] {
+        let lo = |v: u16| (v & 0x00FF) as i32;
+        let hi = |v: u16| (v >> 8) as i32;
+        let mut item = [0u16; 3];
+        let sym = dec.decode_symbol(&mut self.byte_used);
+        if sym & 1 != 0 {
+            let corr = dec.decode_symbol(&mut self.rgb_diff[0]) as i32;
+            item[0] = u8_fold(corr + lo(last[0])) as u16;
+        } else {
+            item[0] = last[0] & 0x00FF;
+        }
+        if sym & 2 != 0 {
+            let corr = dec.decode_symbol(&mut self.rgb_diff[1]) as i32;
+            item[0] |= (u8_fold(corr + hi(last[0])) as u16) << 8;
+        } else {
+            item[0] |= last[0] & 0xFF00;
+        }
+        if sym & 64 != 0 {
+            let mut diff = lo(item[0]) - lo(last[0]);
+            if sym & 4 != 0 {
+                let corr = dec.decode_symbol(&mut self.rgb_diff[2]) as i32;
+                item[1] = u8_fold(corr + u8_clamp(diff + lo(last[1]))) as u16;
+            } else {
+                item[1] = last[1] & 0x00FF;
+            }
+            if sym & 16 != 0 {
+                let corr = dec.decode_symbol(&mut self.rgb_diff[4]) as i32;
+                diff = (diff + lo(item[1]) - lo(last[1])) / 2;
+                item[2] = u8_fold(corr + u8_clamp(diff + lo(last[2]))) as u16;
+            } else {
+                item[2] = last[2] & 0x00FF;
+            }
+            diff = hi(item[0]) - hi(last[0]);
+            if sym & 8 != 0 {
+                let corr = dec.decode_symbol(&mut self.rgb_diff[3]) as i32;
+                item[1] |= (u8_fold(corr + u8_clamp(diff + hi(last[1]))) as u16) << 8;
+            } else {
+                item[1] |= last[1] & 0xFF00;
+            }
+            if sym & 32 != 0 {
+                let corr = dec.decode_symbol(&mut self.rgb_diff[5]) as i32;
+                diff = (diff + hi(item[1]) - hi(last[1])) / 2;
+                item[2] |= (u8_fold(corr + u8_clamp(diff + hi(last[2]))) as u16) << 8;
+            } else {
+                item[2] |= last[2] & 0xFF00;
+            }
+        } else {
+            item[1] = item[0];
+            item[2] = item[0];
+        }
+        item
+    }
+}
+
+pub(crate) fn unpack_rgb(b: &[u8]) -> [u16; 3] {
+    [read_u16(b, 0), read_u16(b, 2), read_u16(b, 4)]
+}
+
+pub(crate) fn pack_rgb(rgb: &[u16; 3], b: &mut [u8]) {
+    for i in 0..3 {
+        b[2 * i..2 * i + 2].copy_from_slice(&rgb[i].to_le_bytes());
+    }
+}
+
+pub(crate) struct Rgb12Compressor {
+    models: RgbModels,
+    last: [u16; 3],
+}
+
+impl Rgb12Compressor {
+    pub fn new() -> Rgb12Compressor {
+        Rgb12Compressor {
+            models: RgbModels::new(true),
+            last: [0; 3],
+        }
+    }
+}
+
+impl PointwiseCompressor for Rgb12Compressor {
+    fn init(&mut self, first: &[u8]) {
+        self.models = RgbModels::new(true);
+        self.last = unpack_rgb(first);
+    }
+
+    fn compress(&mut self, enc: &mut ArithmeticEncoder, item: &[u8]) {
+        let rgb = unpack_rgb(item);
+        self.models.compress(enc, &self.last, &rgb);
+        self.last = rgb;
+    }
+}
+
+pub(crate) struct Rgb12Decompressor {
+    models: RgbModels,
+    last: [u16; 3],
+}
+
+impl Rgb12Decompressor {
+    pub fn new() -> Rgb12Decompressor {
+        Rgb12Decompressor {
+            models: RgbModels::new(false),
+            last: [0; 3],
+        }
+    }
+}
+
+impl PointwiseDecompressor for Rgb12Decompressor {
+    fn init(&mut self, first: &[u8]) {
+        self.models = RgbModels::new(false);
+        self.last = unpack_rgb(first);
+    }
+
+    fn decompress(&mut self, dec: &mut ArithmeticDecoder<&[u8]>, item: &mut [u8]) {
+        self.last = self.models.decompress(dec, &self.last);
+        pack_rgb(&self.last, item);
+    }
+}
+
+/// Extra bytes, each coded as a difference to the same byte of the last point.
+pub(crate) struct ByteCompressor {
+    models: Vec<ArithmeticModel>,
+    last: Vec<u8>,
+}
+
+impl ByteCompressor {
+    pub fn new(count: usize) -> ByteCompressor {
+        ByteCompressor {
+            models: vec![ArithmeticModel::new(256, true); count],
+            last: vec![0u8; count],
+        }
+    }
+}
+
+impl PointwiseCompressor for ByteCompressor {
+    fn init(&mut self, first: &[u8]) {
+        let count = self.last.len();
+        self.models = vec![ArithmeticModel::new(256, true); count];
+        self.last.copy_from_slice(&first[0..count]);
+    }
+
+    fn compress(&mut self, enc: &mut ArithmeticEncoder, item: &[u8]) {
+        let values = self.last.iter_mut().zip(self.models.iter_mut());
+        for ((last, model), &value) in values.zip(item.iter()) {
+            let diff = value as i32 - *last as i32;
+            enc.encode_symbol(model, u8_fold(diff) as u32);
+            *last = value;
+        }
+    }
+}
+
+pub(crate) struct ByteDecompressor {
+    models: Vec<ArithmeticModel>,
+    last: Vec<u8>,
+}
+
+impl ByteDecompressor {
+    pub fn new(count: usize) -> ByteDecompressor {
+        ByteDecompressor {
+            models: vec![ArithmeticModel::new(256, false); count],
+            last: vec![0u8; count],
+        }
+    }
+}
+
+impl PointwiseDecompressor for ByteDecompressor {
+    fn init(&mut self, first: &[u8]) {
+        let count = self.last.len();
+        self.models = vec![ArithmeticModel::new(256, false); count];
+        self.last.copy_from_slice(&first[0..count]);
+    }
+
+    fn decompress(&mut self, dec: &mut ArithmeticDecoder<&[u8]>, item: &mut [u8]) {
+        let values = self.last.iter_mut().zip(self.models.iter_mut());
+        for ((last, model), value) in values.zip(item.iter_mut()) {
+            *last = u8_fold(*last as i32 + dec.decode_symbol(model) as i32);
+            *value = *last;
+        }
+    }
+}
+
+/// The 28 bytes of a waveform packet that follow its descriptor index.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct Wavepacket {
+    offset: u64,
+    packet_size: u32,
+    return_point: i32,
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+impl Wavepacket {
+    fn unpack(b: &[u8]) -> Wavepacket {
+        Wavepacket {
+            offset: read_u64(b, 0),
+            packet_size: read_u32(b, 8),
+            return_point: read_i32(b, 12),
+            x: read_i32(b, 16),
+            y: read_i32(b, 20),
+            z: read_i32(b, 24),
+        }
+    }
+
+    fn pack(&self, b: &mut [u8]) {
+        b[0..8].copy_from_slice(&self.offset.to_le_bytes());
+        b[8..12].copy_from_slice(&self.packet_size.to_le_bytes());
+        b[12..16].copy_from_slice(&self.return_point.to_le_bytes());
+        b[16..20].copy_from_slice(&self.x.to_le_bytes());
+        b[20..24].copy_from_slice(&self.y.to_le_bytes());
+        b[24..28].copy_from_slice(&self.z.to_le_bytes());
+    }
+}
+
+/// The models and state used to code waveform packets, shared with the
+/// layered WAVEPACKET14 item.
+pub(crate) struct WavepacketModels {
+    packet_index: ArithmeticModel,
+    offset_diff: [ArithmeticModel; 4],
+    ic_offset_diff: IntegerCompressor,
+    ic_packet_size: IntegerCompressor,
+    ic_return_point: IntegerCompressor,
+    ic_xyz: IntegerCompressor,
+    last_diff_32: i32,
+    sym_last_offset_diff: usize,
+    pub last: [u8; 29],
+}
+
+impl WavepacketModels {
+    pub fn new(first: &[u8], compress: bool) -> WavepacketModels {
+        let mut last = [0u8; 29];
+        last.copy_from_slice(&first[0..29]);
+        WavepacketModels {
+            packet_index: ArithmeticModel::new(256, compress),
+            offset_diff: [
+                ArithmeticModel::new(4, compress),
+                ArithmeticModel::new(4, compress),
+                ArithmeticModel::new(4, compress),
+                ArithmeticModel::new(4, compress),
+            ],
+            ic_offset_diff: IntegerCompressor::new(32, 1, compress),
+            ic_packet_size: IntegerCompressor::new(32, 1, compress),
+            ic_return_point: IntegerCompressor::new(32, 1, compress),
+            ic_xyz: IntegerCompressor::new(32, 3, compress),
+            last_diff_32: 0,
+            sym_last_offset_diff: 0,
+            last,
+        }
+    }
+
+    pub fn compress(&mut self, enc: &mut ArithmeticEncoder, item: &[u8]) {
+        enc.encode_symbol(&mut self.packet_index, item[0] as u32);
+        let this = Wavepacket::unpack(&item[1..29]);
+        let last = Wavepacket::unpack(&self.last[1..29]);
+
+        let curr_diff_64 = this.offset.wrapping_sub(last.offset) as i64;
+        let curr_diff_32 = curr_diff_64 as i32;
+        let sym = if curr_diff_64 == curr_diff_32 as i64 {
+            if curr_diff_32 == 0 {
+                0
+            } else if curr_diff_32 == last.packet_size as i32 {
+                1
+            } else {
+                2
+            }
+        } else {
+            3
+        };
+        enc.encode_symbol(&mut self.offset_diff[self.sym_last_offset_diff], sym);
+        self.sym_last_offset_diff = sym as usize;
+        if sym == 2 {
+            self.ic_offset_diff
+                .compress(enc, self.last_diff_32, curr_diff_32, 0);
+            self.last_diff_32 = curr_diff_32;
+        } else if sym == 3 {
+            enc.write_int64(this.offset);
+        }
+        self.ic_packet_size
+            .compress(enc, last.packet_size as i32, this.packet_size as i32, 0);
+        self.ic_return_point
+            .compress(enc, last.return_point, this.return_point, 0);
+        self.ic_xyz.compress(enc, last.x, this.x, 0);
+        self.ic_xyz.compress(enc, last.y, this.y, 1);
+        self.ic_xyz.compress(enc, last.z, this.z, 2);
+        self.last.copy_from_slice(&item[0..29]);
+    }
+
+    pub fn decompress<T: AsRef<[u8]>>(&mut self, dec: &mut ArithmeticDecoder<T>, item: &mut [u8]) {
+        item[0] = dec.decode_symbol(&mut self.packet_index) as u8;
+        let last = Wavepacket::unpack(&self.last[1..29]);
+        let mut this = Wavepacket::default();
+
+        self.sym_last_offset_diff =
+            dec.decode_symbol(&mut self.offset_diff[self.sym_last_offset_diff]) as usize;
+        this.offset = match self.sym_last_offset_diff {
+            0 => last.offset,
+            1 => last.offset.wrapping_add(last.packet_size as u64),
+            2 => {
+                self.last_diff_32 = self.ic_offset_diff.decompress(dec, self.last_diff_32, 0);
+                last.offset.wrapping_add(self.last_diff_32 as i64 as u64)
+            }
+            _ => dec.read_int64(),
+        };
+        this.packet_size = self
+            .ic_packet_size
+            .decompress(dec, last.packet_size as i32, 0) as u32;
+        this.return_point = self.ic_return_point.decompress(dec, last.return_point, 0);
+        this.x = self.ic_xyz.decompress(dec, last.x, 0);
+        this.y = self.ic_xyz.decompress(dec, last.y, 1);
+        this.z = self.ic_xyz.decompress(dec, last.z, 2);
+        this.pack(&mut item[1..29]);
+        self.last.copy_from_slice(&item[0..29]);
+    }
+}
+
+pub(crate) struct Wavepacket13Compressor {
+    models: WavepacketModels,
+}
+
+impl Wavepacket13Compressor {
+    pub fn new() -> Wavepacket13Compressor {
+        Wavepacket13Compressor {
+            models: WavepacketModels::new(&[0u8; 29], true),
+        }
+    }
+}
+
+impl PointwiseCompressor for Wavepacket13Compressor {
+    fn init(&mut self, first: &[u8]) {
+        self.models = WavepacketModels::new(first, true);
+    }
+
+    fn compress(&mut self, enc: &mut ArithmeticEncoder, item: &[u8]) {
+        self.models.compress(enc, item);
+    }
+}
+
+pub(crate) struct Wavepacket13Decompressor {
+    models: WavepacketModels,
+}
+
+impl Wavepacket13Decompressor {
+    pub fn new() -> Wavepacket13Decompressor {
+        Wavepacket13Decompressor {
+            models: WavepacketModels::new(&[0u8; 29], false),
+        }
+    }
+}
+
+impl PointwiseDecompressor for Wavepacket13Decompressor {
+    fn init(&mut self, first: &[u8]) {
+        self.models = WavepacketModels::new(first, false);
+    }
+
+    fn decompress(&mut self, dec: &mut ArithmeticDecoder<&[u8]>, item: &mut [u8]) {
+        self.models.decompress(dec, item);
+    }
+}
@@ -0,0 +1,172 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: Incremental reading and writing of LAZ point data, one LASzip chunk at a
+time, for files that are too large to decompress in memory. Files compressed
+without chunks, or without a chunk table, cannot be read this way.
+*/
+
+use super::*;
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+
+/// Decodes the point records of a LAZ file one chunk at a time.
+pub(crate) struct LazStreamReader {
+    reader: BufReader<File>,
+    decoder: ChunkDecoder,
+    record_length: usize,
+    chunk_size: u32,
+    chunks: Vec<(u32, usize)>,
+    next_chunk: usize,
+    remaining: u64,
+}
+
+impl LazStreamReader {
+    /// Creates a reader for the points of the LAZ file `reader`, given the bytes of
+    /// the file up to the start of the points (`prefix`). Also returns the header
+    /// and VLRs of the equivalent LAS file. Returns `None` if the points were not
+    /// compressed in chunks that can be located with a chunk table.
+    pub fn new(
+        mut reader: BufReader<File>,
+        prefix: &[u8],
+    ) -> Result<Option<(LazStreamReader, Vec<u8>)>, Error> {
+        let layout = LasLayout::parse(prefix)?;
+        let vlr_index = layout
+            .find_laszip_vlr(prefix)
+            .ok_or_else(|| invalid_laz("the LASzip VLR is missing"))?;
+        let (vlr_start, vlr_end) = layout.vlrs[vlr_index];
+        let laz_vlr = LazVlr::from_bytes(&prefix[vlr_start + VLR_HEADER_SIZE..vlr_end])?;
+        if laz_vlr.coder != 0 || laz_vlr.compressor == COMPRESSOR_POINTWISE {
+            return Ok(None);
+        }
+        if laz_vlr.record_length() != layout.record_length {
+            return Err(invalid_laz(
+                "the LASzip items do not match the point record length",
+            ));
+        }
+
+        let file_len = reader.seek(SeekFrom::End(0))?;
+        let mut bytes = [0u8; 8];
+        reader.seek(SeekFrom::Start(layout.offset_to_points as u64))?;
+        reader.read_exact(&mut bytes)?;
+        let mut table_offset = u64::from_le_bytes(bytes) as i64;
+        if table_offset == -1 {
+            reader.seek(SeekFrom::Start(file_len - 8))?;
+            reader.read_exact(&mut bytes)?;
+            table_offset = u64::from_le_bytes(bytes) as i64;
+        }
+        if table_offset <= layout.offset_to_points as i64 || table_offset as u64 >= file_len {
+            return Ok(None);
+        }
+        let table_end = match layout.evlrs {
+            Some((offset, _)) if offset as i64 > table_offset => offset as u64,
+            _ => file_len,
+        };
+        let mut table = vec![0u8; (table_end - table_offset as u64) as usize];
+        reader.seek(SeekFrom::Start(table_offset as u64))?;
+        reader.read_exact(&mut table)?;
+        let variable = laz_vlr.chunk_size == VARIABLE_CHUNK_SIZE;
+        let chunks = match read_chunk_table(&table, 0, variable) {
+            Some(chunks) => chunks,
+            None => return Ok(None),
+        };
+        reader.seek(SeekFrom::Start(layout.offset_to_points as u64 + 8))?;
+
+        let las_header = decompress_laz(prefix[..layout.offset_to_points].to_vec(), true)?;
+        let stream = LazStreamReader {
+            reader,
+            decoder: ChunkDecoder::new(&laz_vlr)?,
+            record_length: layout.record_length,
+            chunk_size: laz_vlr.chunk_size,
+            chunks,
+            next_chunk: 0,
+            remaining: layout.number_of_points,
+        };
+        Ok(Some((stream, las_header)))
+    }
+
+    /// Decodes the next chunk, returning its point records, or `None` once all of
+    /// the points have been read.
+    pub fn next_chunk(&mut self) -> Result<Option<Vec<u8>>, Error> {
+        if self.remaining == 0 || self.next_chunk >= self.chunks.len() {
+            return Ok(None);
+        }
+        let (count, size) = self.chunks[self.next_chunk];
+        self.next_chunk += 1;
+        let count = if self.chunk_size == VARIABLE_CHUNK_SIZE {
+            count as u64
+        } else {
+            self.chunk_size as u64
+        }
+        .min(self.remaining) as usize;
+        let mut compressed = vec![0u8; size];
+        self.reader.read_exact(&mut compressed)?;
+        let mut points = vec![0u8; count * self.record_length];
+        let (decoded, _) = self.decoder.decode(&compressed, 0, count, &mut points)?;
+        points.truncate(decoded * self.record_length);
+        self.remaining -= decoded as u64;
+        Ok(Some(points))
+    }
+}
+
+/// Compresses point records incrementally, the inverse of `LazStreamReader`.
+pub(crate) struct LazStreamWriter {
+    encoder: ChunkEncoder,
+    record_length: usize,
+    chunk_size: usize,
+    pending: Vec<u8>,
+    chunks: Vec<(usize, usize)>,
+}
+
+impl LazStreamWriter {
+    /// Creates a writer for points described by the LAS header and VLRs in
+    /// `las_header`. Also returns the header and VLRs of the LAZ file, which end with
+    /// a placeholder for the offset to the chunk table.
+    pub fn new(las_header: &[u8]) -> Result<(LazStreamWriter, Vec<u8>), Error> {
+        let layout = LasLayout::parse(las_header)?;
+        let laz_vlr = LazVlr::for_point_format(layout.point_format, layout.record_length as u16)?;
+        let stream = LazStreamWriter {
+            encoder: ChunkEncoder::new(&laz_vlr)?,
+            record_length: layout.record_length,
+            chunk_size: laz_vlr.chunk_size as usize,
+            pending: vec![],
+            chunks: vec![],
+        };
+        Ok((stream, laz_header(las_header, &layout, &laz_vlr)))
+    }
+
+    /// Adds point records, returning the compressed bytes of any chunks that
+    /// have been completed.
+    pub fn write(&mut self, records: &[u8]) -> Vec<u8> {
+        self.pending.extend_from_slice(records);
+        let chunk_bytes = self.chunk_size * self.record_length;
+        let complete = self.pending.len() / chunk_bytes * chunk_bytes;
+        let mut output = vec![];
+        for chunk in self.pending[..complete].chunks(chunk_bytes) {
+            let start = output.len();
+            self.encoder.encode(chunk, &mut output);
+            self.chunks
+                .push((chunk.len() / self.record_length, output.len() - start));
+        }
+        self.pending.drain(..complete);
+        output
+    }
+
+    /// Compresses the remaining records, returning their bytes and those of the
+    /// chunk table, which follows them in the file.
+    pub fn finish(mut self) -> (Vec<u8>, Vec<u8>) {
+        let mut output = vec![];
+        if !self.pending.is_empty() {
+            self.encoder.encode(&self.pending, &mut output);
+            self.chunks
+                .push((self.pending.len() / self.record_length, output.len()));
+        }
+        let mut table = vec![];
+        write_chunk_table(&mut table, &self.chunks, false);
+        (output, table)
+    }
+}
@@ -2,24 +2,30 @@
 This code is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 21, 2017
-Last Modified: 12/04/2018
+Last Modified: 17/10/2026
 License: MIT
 */
 
 // private sub-module defined in other files
+mod extra_bytes;
 mod header;
 mod las;
+mod las_stream;
+mod laz;
 mod point_data;
 mod vlr;
 mod zlidar_compression;
 
 // exports identifiers from private sub-modules in the current module namespace
+pub use self::extra_bytes::ExtraByteDefinition;
+pub use self::extra_bytes::ExtraByteType;
 pub use self::header::LasHeader;
 pub use self::las::CoordinateReferenceSystem;
 pub use self::las::GlobalEncodingField;
 pub use self::las::GpsTimeType;
 pub use self::las::LasFile;
 pub use self::las::LidarPointRecord;
+pub use self::las::MEMORY_LIDAR;
 pub use self::las::PointRecord0;
 pub use self::las::PointRecord1;
 pub use self::las::PointRecord10;
@@ -31,9 +37,19 @@ pub use self::las::PointRecord6;
 pub use self::las::PointRecord7;
 pub use self::las::PointRecord8;
 pub use self::las::PointRecord9;
+pub use self::las_stream::LasReader;
+pub use self::las_stream::LasWriter;
 pub use self::point_data::convert_class_val_to_class_string;
 pub use self::point_data::ColourData;
 pub use self::point_data::PointData;
 pub use self::point_data::WaveformPacket;
 pub use self::vlr::Vlr;
 pub use self::zlidar_compression::ZlidarCompression;
+
+/// Reads the provenance record of a LiDAR file, i.e. the record of the tool run that
+/// wrote it. Only the header and VLRs of the file are read.
+pub fn read_provenance(
+    file_name: &str,
+) -> Result<Option<whitebox_common::provenance::Provenance>, std::io::Error> {
+    Ok(LasFile::new(file_name, "rh")?.get_provenance())
+}
@@ -0,0 +1,116 @@
+# Builds the LAS files of point formats 0-3 and 6-8 whose LAZ compressions, written by
+# LASzip (or by PDAL when LASzip isn't installed), the LAZ decoder is checked against. The
+# LAS files are written here, independently of the WhiteboxTools encoder, and are kept as
+# the known answers for the decoded points. LASzip is asked for small chunks so that each
+# file holds several of them.
+#
+# Usage: python3 make_laz_fixtures.py whitebox-lidar/tests/data/laz
+
+import os
+import shutil
+import struct
+import subprocess
+import sys
+
+HEADER_SIZE = 375
+POINT_FORMAT_SIZES = {0: 20, 1: 28, 2: 26, 3: 34, 6: 30, 7: 36, 8: 38}
+NUM_POINTS = 230
+CHUNK_SIZE = 50
+SCALE = 0.01
+
+
+def records(point_format):
+    """Returns the point records of a test file. The points follow a scan line, with noisy
+    coordinates, a few returns per pulse and GPS times shared by each pulse, so that all of
+    the LASzip models are exercised."""
+    seed = 12345
+
+    def rand():
+        nonlocal seed
+        seed = (seed * 1103515245 + 12345) % 2**32
+        return seed >> 8
+
+    size = POINT_FORMAT_SIZES[point_format]
+    gps_time = 1000.0
+    points = []
+    for i in range(NUM_POINTS):
+        record = bytearray(size)
+        x = i * 25 + rand() % 50
+        y = 1_000_000 - i * 3 + rand() % 10
+        z = 5000 + rand() % 3000 - 1500
+        struct.pack_into("<iiiH", record, 0, x, y, z, rand() % 400)
+        n = 1 + rand() % 3
+        r = 1 + i % n
+        if i % 3 == 0:
+            gps_time += 0.000_013 * (1 + rand() % 5)
+        classification = 6 if rand() % 10 == 0 else 2
+        if point_format <= 5:
+            record[14] = r | (n << 3) | (((i // 100) % 2) << 6)
+            record[15] = classification
+            record[16] = rand() % 30
+            record[17] = i % 4
+            struct.pack_into("<H", record, 18, (i // 100) % 3)
+            pos = 20
+            if point_format in (1, 3):
+                struct.pack_into("<d", record, pos, gps_time)
+                pos += 8
+        else:
+            record[14] = r | (n << 4)
+            record[15] = ((i % 2) << 4) | (((i // 100) % 2) << 6)
+            record[16] = classification
+            record[17] = i % 4
+            struct.pack_into("<hHd", record, 18, rand() % 200 - 100, (i // 100) % 3, gps_time)
+            pos = 30
+        # RGB, and NIR for point format 8, which vary slowly along the scan line
+        while pos < size:
+            struct.pack_into("<H", record, pos, (i * 257 + pos * 4099 + rand() % 16) % 65536)
+            pos += 2
+        points.append(bytes(record))
+    return points
+
+
+def las_file(point_format):
+    """Returns a LAS 1.4 file holding the test points, with no VLRs."""
+    points = records(point_format)
+    xs, ys, zs = zip(*(struct.unpack_from("<iii", p) for p in points))
+    header = bytearray(HEADER_SIZE)
+    header[0:4] = b"LASF"
+    header[24] = 1
+    header[25] = 4
+    header[26:58] = b"WhiteboxTools LAZ fixtures".ljust(32, b"\0")
+    header[58:90] = b"make_laz_fixtures.py".ljust(32, b"\0")
+    struct.pack_into("<HIIBH", header, 94, HEADER_SIZE, HEADER_SIZE, 0, point_format,
+                     POINT_FORMAT_SIZES[point_format])
+    if point_format <= 5:
+        struct.pack_into("<I", header, 107, NUM_POINTS)
+    struct.pack_into("<ddd", header, 131, SCALE, SCALE, SCALE)
+    struct.pack_into("<dddddd", header, 179, max(xs) * SCALE, min(xs) * SCALE,
+                     max(ys) * SCALE, min(ys) * SCALE, max(zs) * SCALE, min(zs) * SCALE)
+    struct.pack_into("<Q", header, 247, NUM_POINTS)
+    return bytes(header) + b"".join(points)
+
+
+def compress(las_name, laz_name):
+    if shutil.which("laszip"):
+        subprocess.run(["laszip", "-i", las_name, "-o", laz_name,
+                        "-chunk_size", str(CHUNK_SIZE)], check=True)
+    elif shutil.which("pdal"):
+        subprocess.run(["pdal", "translate", las_name, laz_name,
+                        "--writers.las.compression=laszip",
+                        "--writers.las.minor_version=4",
+                        "--writers.las.forward=all"], check=True)
+    else:
+        sys.exit("neither laszip nor pdal was found")
+
+
+def main():
+    directory = sys.argv[1] if len(sys.argv) > 1 else "."
+    for point_format in POINT_FORMAT_SIZES:
+        name = os.path.join(directory, "point_format_{}".format(point_format))
+        with open(name + ".las", "wb") as f:
+            f.write(las_file(point_format))
+        compress(name + ".las", name + ".laz")
+
+
+if __name__ == "__main__":
+    main()
@@ -364,6 +364,7 @@ fn run(args: &Vec<String>) -> Result<(), std::io::Error> {
     }
 
     let mut output = Raster::initialize_using_config(&output_file, &header);
+    output.widen_to_f64();
     output.reinitialize_values(1f64);
     let dx = [1, 1, 1, 0, -1, -1, -1, 0];
     let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
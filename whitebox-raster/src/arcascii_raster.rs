@@ -9,7 +9,7 @@ use std::io::Error;
 pub fn read_arcascii(
     file_name: &String,
     configs: &mut RasterConfigs,
-    data: &mut Vec<f64>,
+    data: &mut RasterData,
 ) -> Result<(), Error> {
     // read the file
     let f = File::open(file_name)?;
@@ -30,14 +30,8 @@ pub fn read_arcascii(
         }
         if vec[0].to_lowercase().contains("nrows") {
             configs.rows = vec[vec.len() - 1].trim().parse::<f32>().unwrap() as usize;
-            if configs.columns > 0 {
-                data.reserve(configs.rows * configs.columns);
-            }
         } else if vec[0].to_lowercase().contains("ncols") {
             configs.columns = vec[vec.len() - 1].trim().parse::<f32>().unwrap() as usize;
-            if configs.rows > 0 {
-                data.reserve(configs.rows * configs.columns);
-            }
         } else if vec[0].to_lowercase().contains("xllcorner") {
             xllcorner = vec[vec.len() - 1]
                 .trim()
@@ -87,6 +81,13 @@ pub fn read_arcascii(
                 .unwrap();
         } else {
             // it's a data line
+            if data.is_empty() {
+                *data = RasterData::with_capacity(
+                    configs.data_type,
+                    configs.nodata,
+                    configs.rows * configs.columns,
+                );
+            }
             for val in vec {
                 if !val.trim().to_string().is_empty() {
                     data.push(val.trim().to_string().parse::<f64>().unwrap());
@@ -147,9 +148,9 @@ pub fn write_arcascii<'a>(r: &'a mut Raster) -> Result<(), Error> {
     let mut col = 0;
     for i in 0..num_cells {
         if col < r.configs.columns - 1 {
-            s2 += &format!("{:.*} ", 2, r.data[i]);
+            s2 += &format!("{:.*} ", 2, r.data.get(i));
         } else {
-            s2 += &format!("{:.*}\n", 2, r.data[i]);
+            s2 += &format!("{:.*}\n", 2, r.data.get(i));
         }
         col += 1;
         if col == r.configs.columns {
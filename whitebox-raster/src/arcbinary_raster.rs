@@ -12,7 +12,7 @@ use std::mem;
 pub fn read_arcbinary(
     file_name: &String,
     configs: &mut RasterConfigs,
-    data: &mut Vec<f64>,
+    data: &mut RasterData,
 ) -> Result<(), Error> {
     // read the header file
     // let header_file = file_name.replace(".flt", ".hdr");
@@ -106,7 +106,11 @@ pub fn read_arcbinary(
             yllcenter + (0.5 * configs.resolution_y) + (configs.rows as f64) * configs.resolution_y;
     }
 
-    data.reserve(configs.rows * configs.columns);
+    *data = RasterData::with_capacity(
+        configs.data_type,
+        configs.nodata,
+        configs.rows * configs.columns,
+    );
 
     // read the data file
     // let data_file = file_name.replace(".hdr", ".flt");
@@ -219,7 +223,7 @@ pub fn write_arcbinary<'a>(r: &'a mut Raster) -> Result<(), Error> {
 
     let num_cells: usize = r.configs.rows * r.configs.columns;
     for i in 0..num_cells {
-        u32_bytes = unsafe { mem::transmute(r.data[i] as f32) };
+        u32_bytes = unsafe { mem::transmute(r.data.get(i) as f32) };
         writer.write(&u32_bytes)?;
     }
 
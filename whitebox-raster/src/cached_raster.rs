@@ -0,0 +1,883 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 17/10/2026
+License: MIT
+*/
+
+use super::*;
+use crate::geotiff::tiff_consts::{IM_GRAY, IM_GRAYINVERT};
+use crate::geotiff::{
+    decompress_block, read_geotiff_layout, read_sample, write_geotiff_rows, GeoTiffLayout,
+};
+use byteorder::{LittleEndian, WriteBytesExt};
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::{BufWriter, Cursor, SeekFrom};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// The number of cells held in each block of a Whitebox raster, or of a scratch file.
+const CELLS_PER_ROW_BAND_BLOCK: usize = 1_048_576;
+// The default amount of memory used to cache blocks.
+const DEFAULT_CACHE_SIZE_IN_BYTES: usize = 256 * 1024 * 1024;
+// The approximate size of the row bands suggested by `get_row_band_height`.
+const ROW_BAND_SIZE_IN_BYTES: usize = 64 * 1024 * 1024;
+
+/// A raster that is accessed block-by-block from disk rather than being read into memory in
+/// its entirety. Blocks are the strips or tiles of a GeoTIFF file, or bands of rows for a
+/// Whitebox raster, and the most recently used blocks are held in a cache of a fixed size.
+/// This allows tools to process rasters that are larger than the available memory, typically
+/// by working through the raster one band of rows (`read_window`/`write_window`) at a time.
+///
+/// Only single-band GeoTIFF and Whitebox rasters are currently supported. A raster opened for
+/// writing is held in a scratch file until `write` is called, when it is converted into the
+/// output format.
+pub struct CachedRaster {
+    pub file_name: String,
+    pub file_mode: String,
+    pub raster_type: RasterType,
+    pub configs: RasterConfigs,
+    source: BlockSource,
+    block_width: usize,
+    block_height: usize,
+    blocks_across: usize,
+    blocks_down: usize,
+    cache: HashMap<usize, Block>,
+    max_cached_blocks: usize,
+    access_count: usize,
+    written_blocks: Vec<bool>,
+}
+
+enum BlockSource {
+    Whitebox { file: File, encoding: CellEncoding },
+    GeoTiff { file: File, layout: GeoTiffLayout },
+    Scratch { file: File, file_name: String },
+}
+
+// A block number, along with the ranges of grid rows and columns that it shares with a window.
+type WindowBlock = (usize, (usize, usize), (usize, usize));
+
+struct Block {
+    values: Vec<f64>,
+    dirty: bool,
+    last_used: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CellEncoding {
+    F64,
+    F32,
+    I32,
+    I16,
+    U8,
+}
+
+impl CellEncoding {
+    fn size(&self) -> usize {
+        match self {
+            CellEncoding::F64 => 8,
+            CellEncoding::F32 | CellEncoding::I32 => 4,
+            CellEncoding::I16 => 2,
+            CellEncoding::U8 => 1,
+        }
+    }
+
+    fn read(&self, bor: &mut ByteOrderReader<Cursor<Vec<u8>>>) -> Result<f64, Error> {
+        let value = match self {
+            CellEncoding::F64 => bor.read_f64()?,
+            CellEncoding::F32 => bor.read_f32()? as f64,
+            CellEncoding::I32 => bor.read_i32()? as f64,
+            CellEncoding::I16 => bor.read_i16()? as f64,
+            CellEncoding::U8 => bor.read_u8()? as f64,
+        };
+        Ok(value)
+    }
+
+    // Values are always written in little-endian byte order.
+    fn write(&self, buf: &mut Vec<u8>, value: f64) -> Result<(), Error> {
+        match self {
+            CellEncoding::F64 => buf.write_f64::<LittleEndian>(value)?,
+            CellEncoding::F32 => buf.write_f32::<LittleEndian>(value as f32)?,
+            CellEncoding::I32 => buf.write_i32::<LittleEndian>(value as i32)?,
+            CellEncoding::I16 => buf.write_i16::<LittleEndian>(value as i16)?,
+            CellEncoding::U8 => buf.write_u8(value as u8)?,
+        }
+        Ok(())
+    }
+
+    // The encoding of the data (.tas) file of an existing Whitebox raster.
+    fn for_whitebox_input(data_type: DataType) -> Result<CellEncoding, Error> {
+        match data_type {
+            DataType::F64 => Ok(CellEncoding::F64),
+            DataType::F32 => Ok(CellEncoding::F32),
+            DataType::I32 => Ok(CellEncoding::I32),
+            DataType::I16 => Ok(CellEncoding::I16),
+            DataType::U8 => Ok(CellEncoding::U8),
+            _ => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "Whitebox rasters of data type {:?} cannot be read block-by-block.",
+                    data_type
+                ),
+            )),
+        }
+    }
+
+    // The encoding used by `write_whitebox` for a given data type.
+    fn for_whitebox_output(configs: &RasterConfigs) -> Result<CellEncoding, Error> {
+        match configs.data_type {
+            DataType::F64 | DataType::U32
+                if configs.photometric_interp != PhotometricInterpretation::RGB =>
+            {
+                Ok(CellEncoding::F64)
+            }
+            DataType::F32 | DataType::U16 | DataType::I32 => Ok(CellEncoding::F32),
+            DataType::I16 => Ok(CellEncoding::I16),
+            DataType::U8 | DataType::I8 => Ok(CellEncoding::U8),
+            _ => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "Whitebox rasters of data type {:?} cannot be written block-by-block.",
+                    configs.data_type
+                ),
+            )),
+        }
+    }
+}
+
+impl CachedRaster {
+    /// Opens an existing GeoTIFF or Whitebox raster for block-by-block reading. Only the
+    /// header of the file is read.
+    pub fn new<'a>(file_name: &'a str, file_mode: &'a str) -> Result<CachedRaster, Error> {
+        let fm = file_mode.to_lowercase();
+        if fm.contains("w") {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Use CachedRaster::initialize_using_config to create a new cached raster.",
+            ));
+        }
+        let raster_type = get_raster_type_from_file(file_name.to_string(), fm.clone());
+        let mut configs = RasterConfigs {
+            nodata: whitebox_common::configs::get_configs()?.default_nodata,
+            ..Default::default()
+        };
+        let (source, block_width, block_height) = match raster_type {
+            RasterType::Whitebox => {
+                read_whitebox_header(file_name, &mut configs)?;
+                let encoding = CellEncoding::for_whitebox_input(configs.data_type)?;
+                let data_file = Path::new(file_name)
+                    .with_extension("tas")
+                    .into_os_string()
+                    .into_string()
+                    .unwrap();
+                let file = File::open(data_file)?;
+                let block_height = row_band_block_height(configs.columns);
+                (
+                    BlockSource::Whitebox { file, encoding },
+                    configs.columns,
+                    block_height,
+                )
+            }
+            RasterType::GeoTiff => {
+                let layout = read_geotiff_layout(file_name, &mut configs)?;
+                if layout.mode != IM_GRAY && layout.mode != IM_GRAYINVERT {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "Only greyscale GeoTIFF files can be read block-by-block.",
+                    ));
+                }
+                configs.bands = layout.num_bands as u8;
+                configs.band_sequential = layout.num_planes > 1;
+                let file = File::open(file_name)?;
+                let (block_width, block_height) = (layout.block_width, layout.block_height);
+                (
+                    BlockSource::GeoTiff { file, layout },
+                    block_width,
+                    block_height,
+                )
+            }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Only GeoTIFF and Whitebox rasters can be read block-by-block.",
+                ));
+            }
+        };
+
+        let mut r = CachedRaster {
+            file_name: file_name.to_string(),
+            file_mode: fm,
+            raster_type,
+            configs,
+            source,
+            block_width,
+            block_height,
+            blocks_across: 0,
+            blocks_down: 0,
+            cache: HashMap::new(),
+            max_cached_blocks: 0,
+            access_count: 0,
+            written_blocks: vec![],
+        };
+        r.initialize_blocks(true);
+        Ok(r)
+    }
+
+    /// Creates a new `CachedRaster` for writing, with grid extent and location based on
+    /// specified configurations contained within a `RasterConfigs`. Every cell is initially
+    /// nodata.
+    pub fn initialize_using_config<'a>(
+        file_name: &'a str,
+        configs: &'a RasterConfigs,
+    ) -> Result<CachedRaster, Error> {
+        let new_file_name = output_file_name(file_name);
+        let raster_type = get_raster_type_from_file(new_file_name.clone(), "w".to_string());
+        if raster_type != RasterType::GeoTiff && raster_type != RasterType::Whitebox {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Only GeoTIFF and Whitebox rasters can be written block-by-block.",
+            ));
+        }
+
+        let mut output_configs = RasterConfigs {
+            ..Default::default()
+        };
+        output_configs.rows = configs.rows;
+        output_configs.columns = configs.columns;
+        output_configs.north = configs.north;
+        output_configs.south = configs.south;
+        output_configs.east = configs.east;
+        output_configs.west = configs.west;
+        output_configs.resolution_x = configs.resolution_x;
+        output_configs.resolution_y = configs.resolution_y;
+        output_configs.nodata = configs.nodata;
+        output_configs.data_type = configs.data_type;
+        output_configs.photometric_interp = configs.photometric_interp;
+        output_configs.palette = configs.palette.clone();
+        output_configs.projection = configs.projection.clone();
+        output_configs.xy_units = configs.xy_units.clone();
+        output_configs.z_units = configs.z_units.clone();
+        output_configs.endian = configs.endian;
+        output_configs.pixel_is_area = configs.pixel_is_area;
+        output_configs.epsg_code = configs.epsg_code;
+        output_configs.coordinate_ref_system_wkt = configs.coordinate_ref_system_wkt.clone();
+        output_configs.model_tiepoint = configs.model_tiepoint.clone();
+        output_configs.model_pixel_scale = configs.model_pixel_scale;
+        output_configs.model_transformation = configs.model_transformation;
+        output_configs.geo_key_directory = configs.geo_key_directory.clone();
+        output_configs.geo_double_params = configs.geo_double_params.clone();
+        output_configs.geo_ascii_params = configs.geo_ascii_params.clone();
+
+        // The cells are held in a scratch file, in double precision, until the raster is
+        // written, so that the output data type may still be changed after initialization.
+        let scratch_file = scratch_file_name(&new_file_name)?;
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&scratch_file)?;
+
+        let block_width = output_configs.columns;
+        let block_height = row_band_block_height(output_configs.columns);
+        let mut r = CachedRaster {
+            file_name: new_file_name,
+            file_mode: "w".to_string(),
+            raster_type,
+            configs: output_configs,
+            source: BlockSource::Scratch {
+                file,
+                file_name: scratch_file,
+            },
+            block_width,
+            block_height,
+            blocks_across: 0,
+            blocks_down: 0,
+            cache: HashMap::new(),
+            max_cached_blocks: 0,
+            access_count: 0,
+            written_blocks: vec![],
+        };
+        r.initialize_blocks(false);
+        Ok(r)
+    }
+
+    /// Creates a new `CachedRaster` for writing, with grid extent, location and cache size
+    /// based on an existing `CachedRaster`.
+    pub fn initialize_using_file<'a>(
+        file_name: &'a str,
+        input: &'a CachedRaster,
+    ) -> Result<CachedRaster, Error> {
+        let mut output = CachedRaster::initialize_using_config(file_name, &input.configs)?;
+        output.set_cache_size(input.get_cache_size());
+        Ok(output)
+    }
+
+    /// Opens `file_name` for block-by-block reading if reading it into memory, along with
+    /// `num_copies` in-memory rasters of the same size (e.g. the tool's output), would exceed
+    /// the `max_memory_mb` setting. `None` is returned when no memory limit is set, when the
+    /// raster fits within the limit, or when the file cannot be read block-by-block, in which
+    /// case the raster should be read into memory as usual.
+    pub fn open_if_exceeds_memory(
+        file_name: &str,
+        num_copies: usize,
+    ) -> Result<Option<CachedRaster>, Error> {
+        let max_memory_mb = whitebox_common::configs::get_configs()?.max_memory_mb;
+        if max_memory_mb <= 0 {
+            return Ok(None);
+        }
+        if is_memory_file(file_name) {
+            return Ok(None);
+        }
+        let raster_type = get_raster_type_from_file(file_name.to_string(), "r".to_string());
+        if raster_type != RasterType::GeoTiff && raster_type != RasterType::Whitebox {
+            return Ok(None);
+        }
+        let mut input = match CachedRaster::new(file_name, "r") {
+            Ok(r) => r,
+            // Any error will be reported when the raster is read into memory instead.
+            Err(_) => return Ok(None),
+        };
+        let max_memory = max_memory_mb as usize * 1024 * 1024;
+        if input.get_data_size_in_bytes() * num_copies.max(1) <= max_memory {
+            return Ok(None);
+        }
+        // leave room for the cache of the output raster and the windows being processed.
+        input.set_cache_size((max_memory / 4).min(DEFAULT_CACHE_SIZE_IN_BYTES));
+        Ok(Some(input))
+    }
+
+    fn initialize_blocks(&mut self, written: bool) {
+        let (rows, columns) = (self.configs.rows, self.configs.columns);
+        self.block_width = self.block_width.max(1);
+        self.block_height = self.block_height.max(1);
+        self.blocks_across = columns.div_ceil(self.block_width);
+        self.blocks_down = rows.div_ceil(self.block_height);
+        self.written_blocks = vec![written; self.blocks_across * self.blocks_down];
+        self.set_cache_size(DEFAULT_CACHE_SIZE_IN_BYTES);
+    }
+
+    /// Sets the amount of memory, in bytes, used to cache blocks. At least two blocks are
+    /// always cached.
+    pub fn set_cache_size(&mut self, bytes: usize) {
+        let block_size = self.block_width * self.block_height * 8;
+        self.max_cached_blocks = (bytes / block_size.max(1)).max(2);
+        while self.cache.len() > self.max_cached_blocks {
+            // A block that cannot be stored will be reported when the raster is written.
+            if self.evict_block().is_err() {
+                break;
+            }
+        }
+    }
+
+    /// Returns the amount of memory, in bytes, used to cache blocks.
+    pub fn get_cache_size(&self) -> usize {
+        self.max_cached_blocks * self.block_width * self.block_height * 8
+    }
+
+    /// Returns the size, in bytes, that the raster would occupy if read into memory.
+    pub fn get_data_size_in_bytes(&self) -> usize {
+        self.configs.rows * self.configs.columns * 8
+    }
+
+    /// Returns a suitable number of rows to process at a time. It is a multiple of the block
+    /// height so that each block is read from disk only once when working down the raster.
+    pub fn get_row_band_height(&self) -> usize {
+        let band_size = ROW_BAND_SIZE_IN_BYTES.min(self.get_cache_size() / 4);
+        let rows = band_size / (self.configs.columns.max(1) * 8);
+        ((rows / self.block_height) * self.block_height).max(self.block_height)
+    }
+
+    pub fn get_short_filename(&self) -> String {
+        let path = Path::new(&self.file_name);
+        let file_name = path.file_stem().unwrap();
+        let f = file_name.to_str().unwrap();
+        f.to_string()
+    }
+
+    /// Returns the value contained within a grid cell specified by `row` and `column`.
+    /// Cells outside of the grid are nodata.
+    pub fn get_value(&mut self, row: isize, column: isize) -> Result<f64, Error> {
+        if row < 0
+            || column < 0
+            || row as usize >= self.configs.rows
+            || column as usize >= self.configs.columns
+        {
+            return Ok(self.configs.nodata);
+        }
+        let (row, column) = (row as usize, column as usize);
+        let (bw, bh) = (self.block_width, self.block_height);
+        let block_num = (row / bh) * self.blocks_across + column / bw;
+        let block = self.get_block(block_num)?;
+        Ok(block.values[(row % bh) * bw + column % bw])
+    }
+
+    /// Sets the value of the grid cell specified by `row` and `column`. Cells outside of the
+    /// grid are ignored.
+    pub fn set_value(&mut self, row: isize, column: isize, value: f64) -> Result<(), Error> {
+        self.check_write_mode()?;
+        if row < 0
+            || column < 0
+            || row as usize >= self.configs.rows
+            || column as usize >= self.configs.columns
+        {
+            return Ok(());
+        }
+        self.update_min_max(value);
+        let (row, column) = (row as usize, column as usize);
+        let (bw, bh) = (self.block_width, self.block_height);
+        let block_num = (row / bh) * self.blocks_across + column / bw;
+        let block = self.get_block(block_num)?;
+        block.values[(row % bh) * bw + column % bw] = value;
+        block.dirty = true;
+        Ok(())
+    }
+
+    /// Returns the values of a row of the grid.
+    pub fn get_row_data(&mut self, row: isize) -> Result<Vec<f64>, Error> {
+        let window = self.read_window(row, 0, 1, self.configs.columns)?;
+        Ok(window.get_row_data(0))
+    }
+
+    /// Reads the block of cells with its upper-left corner at (`row`, `column`) into an
+    /// `Array2D`. The window may extend beyond the edges of the grid, in which case the cells
+    /// outside of the grid are nodata.
+    pub fn read_window(
+        &mut self,
+        row: isize,
+        column: isize,
+        rows: usize,
+        columns: usize,
+    ) -> Result<Array2D<f64>, Error> {
+        let nodata = self.configs.nodata;
+        let mut window: Array2D<f64> =
+            Array2D::new(rows as isize, columns as isize, nodata, nodata)?;
+        let (bw, bh) = (self.block_width, self.block_height);
+        for (block_num, (row_start, row_end), (col_start, col_end)) in
+            self.blocks_in_window(row, column, rows, columns)
+        {
+            let block_row = (block_num / self.blocks_across) * bh;
+            let block_col = (block_num % self.blocks_across) * bw;
+            let block = self.get_block(block_num)?;
+            for r in row_start..row_end {
+                for c in col_start..col_end {
+                    window.set_value(
+                        r as isize - row,
+                        c as isize - column,
+                        block.values[(r - block_row) * bw + c - block_col],
+                    );
+                }
+            }
+        }
+        Ok(window)
+    }
+
+    /// Writes the values of `window` into the grid, with the window's upper-left corner at
+    /// (`row`, `column`). Cells of the window that fall outside of the grid are ignored.
+    pub fn write_window(
+        &mut self,
+        row: isize,
+        column: isize,
+        window: &Array2D<f64>,
+    ) -> Result<(), Error> {
+        self.check_write_mode()?;
+        let (bw, bh) = (self.block_width, self.block_height);
+        let (rows, columns) = (window.rows() as usize, window.columns() as usize);
+        for (block_num, (row_start, row_end), (col_start, col_end)) in
+            self.blocks_in_window(row, column, rows, columns)
+        {
+            let nodata = self.configs.nodata;
+            let (mut min_val, mut max_val) = (f64::INFINITY, f64::NEG_INFINITY);
+            let block_row = (block_num / self.blocks_across) * bh;
+            let block_col = (block_num % self.blocks_across) * bw;
+            let block = self.get_block(block_num)?;
+            for r in row_start..row_end {
+                for c in col_start..col_end {
+                    let value = window.get_value(r as isize - row, c as isize - column);
+                    block.values[(r - block_row) * bw + c - block_col] = value;
+                    if value != nodata {
+                        min_val = min_val.min(value);
+                        max_val = max_val.max(value);
+                    }
+                }
+            }
+            block.dirty = true;
+            self.update_min_max(min_val);
+            self.update_min_max(max_val);
+        }
+        Ok(())
+    }
+
+    /// Saves the raster to `file_name`. Any block that has never been written is nodata.
+    pub fn write(&mut self) -> Result<(), Error> {
+        self.check_write_mode()?;
+        let block_nums: Vec<usize> = self.cache.keys().cloned().collect();
+        for block_num in block_nums {
+            self.store_block(block_num)?;
+        }
+
+        if self.configs.display_min == f64::INFINITY {
+            self.configs.display_min = self.configs.minimum;
+        }
+        if self.configs.display_max == f64::NEG_INFINITY {
+            self.configs.display_max = self.configs.maximum;
+        }
+        self.configs.provenance = current_provenance();
+
+        let mut r = Raster {
+            file_name: self.file_name.clone(),
+            file_mode: "w".to_string(),
+            raster_type: self.raster_type.clone(),
+            configs: self.configs.clone(),
+            ..Default::default()
+        };
+        let rows = self.configs.rows;
+        match self.raster_type {
+            RasterType::GeoTiff => {
+                write_geotiff_rows(&mut r, |row| self.get_row_data(row as isize))?;
+            }
+            RasterType::Whitebox => {
+                // The data file is written in little-endian byte order.
+                r.configs.endian = Endianness::LittleEndian;
+                let encoding = CellEncoding::for_whitebox_output(&r.configs)?;
+                write_whitebox_header(&mut r)?;
+                let data_file = Path::new(&r.file_name)
+                    .with_extension("tas")
+                    .into_os_string()
+                    .into_string()
+                    .unwrap();
+                let f = File::create(&data_file)?;
+                let mut writer = BufWriter::new(f);
+                for row in 0..rows {
+                    let values = self.get_row_data(row as isize)?;
+                    let mut buf = Vec::with_capacity(values.len() * encoding.size());
+                    for value in values {
+                        encoding.write(&mut buf, value)?;
+                    }
+                    writer.write_all(&buf)?;
+                }
+                writer.flush()?;
+            }
+            _ => {
+                return Err(Error::other("Unrecognized raster type"));
+            }
+        }
+        self.configs = r.configs;
+        if let BlockSource::Scratch { file_name, .. } = &self.source {
+            let _ = std::fs::remove_file(file_name);
+        }
+        Ok(())
+    }
+
+    pub fn get_x_from_column(&self, column: isize) -> f64 {
+        self.configs.west
+            + self.configs.resolution_x / 2f64
+            + column as f64 * self.configs.resolution_x
+    }
+
+    pub fn get_y_from_row(&self, row: isize) -> f64 {
+        self.configs.north
+            - self.configs.resolution_y / 2f64
+            - row as f64 * self.configs.resolution_y
+    }
+
+    pub fn is_in_geographic_coordinates(&self) -> bool {
+        if self.configs.epsg_code == 4322
+            || self.configs.epsg_code == 4326
+            || self.configs.epsg_code == 4629
+            || self.configs.epsg_code == 4277
+        {
+            return true;
+        }
+        let wkt = self.configs.coordinate_ref_system_wkt.to_lowercase();
+        if !wkt.contains("projcs[") && wkt.contains("geogcs[") {
+            return true;
+        }
+        if wkt.contains("projcs[") {
+            return false;
+        }
+        if self.configs.west < -180f64
+            || self.configs.east > 180f64
+            || self.configs.south < -90f64
+            || self.configs.north > 90f64
+        {
+            return false;
+        }
+        false
+    }
+
+    pub fn add_metadata_entry(&mut self, value: String) {
+        self.configs.metadata.push(value);
+    }
+
+    fn check_write_mode(&self) -> Result<(), Error> {
+        if !self.file_mode.contains("w") {
+            return Err(Error::other(
+                "Cannot modify a raster that is not created in write mode ('w').",
+            ));
+        }
+        Ok(())
+    }
+
+    fn update_min_max(&mut self, value: f64) {
+        if value != self.configs.nodata && value.is_finite() {
+            if value < self.configs.minimum {
+                self.configs.minimum = value;
+            }
+            if value > self.configs.maximum {
+                self.configs.maximum = value;
+            }
+        }
+    }
+
+    // Returns each block overlapping a window.
+    fn blocks_in_window(
+        &self,
+        row: isize,
+        column: isize,
+        rows: usize,
+        columns: usize,
+    ) -> Vec<WindowBlock> {
+        let mut blocks = vec![];
+        let row_start = row.max(0) as usize;
+        let row_end = (row + rows as isize).min(self.configs.rows as isize);
+        let col_start = column.max(0) as usize;
+        let col_end = (column + columns as isize).min(self.configs.columns as isize);
+        if row_end <= row_start as isize || col_end <= col_start as isize {
+            return blocks;
+        }
+        let (row_end, col_end) = (row_end as usize, col_end as usize);
+        let (bw, bh) = (self.block_width, self.block_height);
+        for j in row_start / bh..=(row_end - 1) / bh {
+            for i in col_start / bw..=(col_end - 1) / bw {
+                blocks.push((
+                    j * self.blocks_across + i,
+                    (row_start.max(j * bh), row_end.min((j + 1) * bh)),
+                    (col_start.max(i * bw), col_end.min((i + 1) * bw)),
+                ));
+            }
+        }
+        blocks
+    }
+
+    // Returns a cached block, reading it from disk, and evicting the least recently used
+    // block from the cache, if necessary.
+    fn get_block(&mut self, block_num: usize) -> Result<&mut Block, Error> {
+        self.access_count += 1;
+        let access_count = self.access_count;
+        if !self.cache.contains_key(&block_num) {
+            while self.cache.len() >= self.max_cached_blocks {
+                self.evict_block()?;
+            }
+            let values = self.read_block(block_num)?;
+            self.cache.insert(
+                block_num,
+                Block {
+                    values,
+                    dirty: false,
+                    last_used: 0,
+                },
+            );
+        }
+        let block = self.cache.get_mut(&block_num).unwrap();
+        block.last_used = access_count;
+        Ok(block)
+    }
+
+    fn evict_block(&mut self) -> Result<(), Error> {
+        let lru = self
+            .cache
+            .iter()
+            .min_by_key(|(_, block)| block.last_used)
+            .map(|(block_num, _)| *block_num);
+        if let Some(block_num) = lru {
+            self.store_block(block_num)?;
+            self.cache.remove(&block_num);
+        }
+        Ok(())
+    }
+
+    // Writes a cached block to the scratch file if it has been modified.
+    fn store_block(&mut self, block_num: usize) -> Result<(), Error> {
+        let columns = self.configs.columns;
+        let first_row = block_num * self.block_height;
+        let num_rows = self.block_height.min(self.configs.rows - first_row);
+        let block = match self.cache.get_mut(&block_num) {
+            Some(block) if block.dirty => block,
+            _ => return Ok(()),
+        };
+        if let BlockSource::Scratch { file, .. } = &mut self.source {
+            let mut buf = Vec::with_capacity(num_rows * columns * 8);
+            for value in &block.values[0..num_rows * columns] {
+                buf.write_f64::<LittleEndian>(*value)?;
+            }
+            file.seek(SeekFrom::Start((first_row * columns * 8) as u64))?;
+            file.write_all(&buf)?;
+            block.dirty = false;
+            self.written_blocks[block_num] = true;
+        }
+        Ok(())
+    }
+
+    fn read_block(&mut self, block_num: usize) -> Result<Vec<f64>, Error> {
+        let nodata = self.configs.nodata;
+        let (rows, columns) = (self.configs.rows, self.configs.columns);
+        let mut values = vec![nodata; self.block_width * self.block_height];
+        match &mut self.source {
+            BlockSource::Whitebox { file, encoding } => {
+                let first_row = block_num * self.block_height;
+                let num_rows = self.block_height.min(rows - first_row);
+                read_row_band(
+                    file,
+                    *encoding,
+                    self.configs.endian,
+                    first_row * columns,
+                    &mut values[0..num_rows * columns],
+                )?;
+            }
+            BlockSource::Scratch { file, .. } => {
+                if self.written_blocks[block_num] {
+                    let first_row = block_num * self.block_height;
+                    let num_rows = self.block_height.min(rows - first_row);
+                    read_row_band(
+                        file,
+                        CellEncoding::F64,
+                        Endianness::LittleEndian,
+                        first_row * columns,
+                        &mut values[0..num_rows * columns],
+                    )?;
+                }
+            }
+            BlockSource::GeoTiff { file, layout } => {
+                read_geotiff_block(file, layout, block_num, &self.configs, &mut values)?;
+            }
+        }
+        Ok(values)
+    }
+}
+
+impl Drop for CachedRaster {
+    fn drop(&mut self) {
+        if let BlockSource::Scratch { file_name, .. } = &self.source {
+            let _ = std::fs::remove_file(file_name);
+        }
+    }
+}
+
+static NEXT_SCRATCH_FILE: AtomicUsize = AtomicUsize::new(0);
+
+// The scratch file of an output raster is written beside it, or within the temp directory
+// setting if one is set, where its name is made unique to the process.
+fn scratch_file_name(file_name: &str) -> Result<String, Error> {
+    let temp_directory = whitebox_common::configs::get_configs()?.temp_directory;
+    if temp_directory.trim().is_empty() {
+        return Ok(format!("{}.tmp", file_name));
+    }
+    let name = Path::new(file_name)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    Ok(Path::new(temp_directory.trim())
+        .join(format!(
+            "{}.{}.{}.tmp",
+            name,
+            std::process::id(),
+            NEXT_SCRATCH_FILE.fetch_add(1, Ordering::SeqCst)
+        ))
+        .to_string_lossy()
+        .to_string())
+}
+
+// The number of rows in each block of a raster that is stored in bands of rows.
+fn row_band_block_height(columns: usize) -> usize {
+    (CELLS_PER_ROW_BAND_BLOCK / columns.max(1)).max(1)
+}
+
+// Reads `values.len()` consecutive cells, starting with cell `first_cell`, from a file of
+// uncompressed values.
+fn read_row_band(
+    file: &mut File,
+    encoding: CellEncoding,
+    endian: Endianness,
+    first_cell: usize,
+    values: &mut [f64],
+) -> Result<(), Error> {
+    let size = encoding.size();
+    let mut buf = vec![0u8; values.len() * size];
+    file.seek(SeekFrom::Start((first_cell * size) as u64))?;
+    file.read_exact(&mut buf)?;
+    let mut bor = ByteOrderReader::<Cursor<Vec<u8>>>::new(Cursor::new(buf), endian);
+    for value in values.iter_mut() {
+        *value = encoding.read(&mut bor)?;
+    }
+    Ok(())
+}
+
+// Reads the first band of a single GeoTIFF strip or tile. Sparse blocks are nodata.
+fn read_geotiff_block(
+    file: &mut File,
+    layout: &GeoTiffLayout,
+    block_num: usize,
+    configs: &RasterConfigs,
+    values: &mut [f64],
+) -> Result<(), Error> {
+    let n = layout.block_counts[block_num] as usize;
+    if n == 0 {
+        return Ok(());
+    }
+    let (bw, bh) = (layout.block_width, layout.block_height);
+    let i = block_num % layout.blocks_across;
+    let j = block_num / layout.blocks_across;
+    let mut blk_w = bw;
+    if !layout.block_padding && i == layout.blocks_across - 1 && !configs.columns.is_multiple_of(bw)
+    {
+        blk_w = configs.columns % bw;
+    }
+    let mut blk_h = bh;
+    if !layout.block_padding && j == layout.blocks_down - 1 && !configs.rows.is_multiple_of(bh) {
+        blk_h = configs.rows % bh;
+    }
+
+    let mut compressed = vec![0u8; n];
+    file.seek(SeekFrom::Start(layout.block_offsets[block_num]))?;
+    file.read_exact(&mut compressed)?;
+    let bits_per_sample = &layout.bits_per_sample;
+    let max_uncompressed_length = bw * bh * bits_per_sample.len() * bits_per_sample[0] as usize / 8;
+    let buf = decompress_block(
+        compressed,
+        &layout.block_encoding(configs.endian),
+        layout.block_row_length(),
+        max_uncompressed_length,
+    )?;
+
+    // In a chunky configuration, the samples of each pixel are interleaved.
+    let samples_per_pixel = if layout.num_planes == 1 {
+        layout.num_bands
+    } else {
+        1
+    };
+    let mut bytes_per_pixel = 0usize;
+    for b in 0..samples_per_pixel {
+        bytes_per_pixel += bits_per_sample[b.min(bits_per_sample.len() - 1)] as usize / 8;
+    }
+    let mut bor = ByteOrderReader::<Cursor<Vec<u8>>>::new(Cursor::new(buf), configs.endian);
+    for y in 0..blk_h {
+        for x in 0..blk_w {
+            if bor.pos() + bytes_per_pixel > bor.len() {
+                break;
+            }
+            for b in 0..samples_per_pixel {
+                let value = read_sample(
+                    &mut bor,
+                    layout.sample_format[b.min(layout.sample_format.len() - 1)],
+                    bits_per_sample[b.min(bits_per_sample.len() - 1)],
+                )?;
+                if b == 0 {
+                    values[y * bw + x] = value;
+                }
+            }
+        }
+    }
+    Ok(())
+}
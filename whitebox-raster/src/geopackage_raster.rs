@@ -0,0 +1,939 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+
+Notes: Reads and writes the tile-pyramid rasters of GeoPackage files. Layers are addressed
+using the 'file.gpkg:layer' syntax; without a layer name, the first raster table is read,
+and a written raster is named after the file. The most detailed zoom level of a pyramid
+is read. RGB rasters are written as 'tiles' layers of PNG images, and all other rasters
+as '2d-gridded-coverage' layers (OGC 17-066r1), using 16-bit PNG tiles if the range of an
+integer raster allows and 32-bit floating-point TIFF tiles otherwise. The pyramid has 256 x
+256 cell tiles and halves in resolution from one zoom level to the next, down to a single
+tile, using the overview resampling method of the settings.
+*/
+
+use super::*;
+use crate::geotiff::cog::OverviewResampling;
+use crate::geotiff::tiff_consts::COMPRESS_LZW;
+use crate::geotiff::{compress_block, read_geotiff_from_bytes, BlockEncoding};
+use rusqlite::types::ValueRef;
+use rusqlite::{params, OptionalExtension, NO_PARAMS};
+use whitebox_common::geopackage::*;
+use whitebox_common::spatial_ref_system::{epsg_from_wkt, esri_wkt_from_epsg};
+
+const TILE_SIZE: usize = 256;
+// the unscaled value marking nodata cells within 16-bit coverage tiles
+const INTEGER_NULL: f64 = 65535.0;
+const COVERAGE_EXTENSION: &str = "http://docs.opengeospatial.org/is/17-066r1/17-066r1.html";
+
+struct TileMatrix {
+    tile_width: usize,
+    tile_height: usize,
+    pixel_x_size: f64,
+    pixel_y_size: f64,
+}
+
+// The values of a decoded tile image, one vector of sample values per band.
+struct TileImage {
+    width: usize,
+    height: usize,
+    bands: Vec<Vec<f64>>,
+}
+
+pub fn read_geopackage_raster(
+    file_name: &str,
+    configs: &mut RasterConfigs,
+    data: &mut RasterData,
+) -> Result<(), Error> {
+    let (file_name, layer_name) = split_layer_name(file_name);
+    let gpkg = GeoPackage::open(&file_name)?;
+    let contents = gpkg.find_contents(layer_name.as_deref(), &["tiles", "2d-gridded-coverage"])?;
+    let table = contents.table_name.clone();
+    let is_coverage = contents
+        .data_type
+        .eq_ignore_ascii_case("2d-gridded-coverage");
+
+    let (tms_min_x, tms_min_y, tms_max_x, tms_max_y, srs_id): (f64, f64, f64, f64, i32) = gpkg
+        .conn
+        .query_row(
+            "SELECT min_x, min_y, max_x, max_y, srs_id FROM gpkg_tile_matrix_set
+            WHERE lower(table_name) = lower(?1)",
+            params![table],
+            |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                ))
+            },
+        )
+        .map_err(sql_error)?;
+
+    // The full-resolution image is the most detailed zoom level holding tiles.
+    let zoom_level: Option<i64> = gpkg
+        .conn
+        .query_row(
+            &format!("SELECT max(zoom_level) FROM {}", quote_identifier(&table)),
+            NO_PARAMS,
+            |row| row.get(0),
+        )
+        .map_err(sql_error)?;
+    let zoom_level = zoom_level.ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "The GeoPackage raster '{}' does not contain any tiles.",
+                table
+            ),
+        )
+    })?;
+    let matrix = gpkg
+        .conn
+        .query_row(
+            "SELECT tile_width, tile_height, pixel_x_size, pixel_y_size FROM gpkg_tile_matrix
+            WHERE lower(table_name) = lower(?1) AND zoom_level = ?2",
+            params![table, zoom_level],
+            |row| {
+                Ok(TileMatrix {
+                    tile_width: row.get::<_, i64>(0)? as usize,
+                    tile_height: row.get::<_, i64>(1)? as usize,
+                    pixel_x_size: row.get(2)?,
+                    pixel_y_size: row.get(3)?,
+                })
+            },
+        )
+        .map_err(sql_error)?;
+
+    // The raster covers the extent of the layer, which may be smaller than that of the
+    // tile matrix set.
+    let (min_x, min_y, max_x, max_y) = if contents.min_x.is_finite()
+        && contents.min_y.is_finite()
+        && contents.max_x.is_finite()
+        && contents.max_y.is_finite()
+    {
+        (
+            contents.min_x.max(tms_min_x),
+            contents.min_y.max(tms_min_y),
+            contents.max_x.min(tms_max_x),
+            contents.max_y.min(tms_max_y),
+        )
+    } else {
+        (tms_min_x, tms_min_y, tms_max_x, tms_max_y)
+    };
+    let (px, py) = (matrix.pixel_x_size, matrix.pixel_y_size);
+    let column_offset = ((min_x - tms_min_x) / px).round().max(0.0) as usize;
+    let row_offset = ((tms_max_y - max_y) / py).round().max(0.0) as usize;
+    configs.columns = (((max_x - min_x) / px).round() as usize).max(1);
+    configs.rows = (((max_y - min_y) / py).round() as usize).max(1);
+    configs.resolution_x = px;
+    configs.resolution_y = py;
+    configs.west = tms_min_x + column_offset as f64 * px;
+    configs.north = tms_max_y - row_offset as f64 * py;
+    configs.east = configs.west + configs.columns as f64 * px;
+    configs.south = configs.north - configs.rows as f64 * py;
+    configs.coordinate_ref_system_wkt = gpkg.get_srs_wkt(srs_id)?;
+    configs.projection = configs.coordinate_ref_system_wkt.clone();
+    configs.epsg_code = epsg_from_wkt(&configs.coordinate_ref_system_wkt).unwrap_or(0);
+
+    // The coverage-wide scaling of the tile values.
+    let (mut scale, mut offset, mut data_null) = (1f64, 0f64, None);
+    if is_coverage {
+        let ancillary: Option<(String, f64, f64, Option<f64>)> = gpkg
+            .conn
+            .query_row(
+                "SELECT datatype, scale, offset, data_null FROM gpkg_2d_gridded_coverage_ancillary
+                WHERE lower(tile_matrix_set_name) = lower(?1)",
+                params![table],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+            )
+            .optional()
+            .map_err(sql_error)?;
+        let mut is_float = false;
+        if let Some((datatype, s, o, n)) = ancillary {
+            is_float = datatype.eq_ignore_ascii_case("float");
+            scale = s;
+            offset = o;
+            data_null = n;
+        }
+        if !is_float && scale == 1.0 && offset.fract() == 0.0 {
+            configs.data_type = DataType::I32;
+        } else {
+            configs.data_type = DataType::F32;
+        }
+        configs.photometric_interp = PhotometricInterpretation::Continuous;
+        configs.nodata = match data_null {
+            // the null value of integer coverages is an unscaled tile value
+            Some(n) if !is_float => n * scale + offset,
+            Some(n) => n,
+            None => -32768.0,
+        };
+    } else {
+        configs.data_type = DataType::RGBA32;
+        configs.photometric_interp = PhotometricInterpretation::RGB;
+        configs.nodata = 0.0;
+    }
+
+    *data = RasterData::new(
+        configs.data_type,
+        configs.nodata,
+        configs.rows * configs.columns,
+        configs.nodata,
+    );
+    let tile_ancillary = is_coverage && gpkg.table_exists("gpkg_2d_gridded_tile_ancillary")?;
+    let sql = if tile_ancillary {
+        format!(
+            "SELECT t.tile_column, t.tile_row, t.tile_data, a.scale, a.offset FROM {} t
+            LEFT JOIN gpkg_2d_gridded_tile_ancillary a
+            ON a.tpudt_id = t.id AND lower(a.tpudt_name) = lower(?2)
+            WHERE t.zoom_level = ?1",
+            quote_identifier(&table)
+        )
+    } else {
+        format!(
+            "SELECT tile_column, tile_row, tile_data, 1.0, 0.0, ?2 FROM {} WHERE zoom_level = ?1",
+            quote_identifier(&table)
+        )
+    };
+    let mut stmt = gpkg.conn.prepare(&sql).map_err(sql_error)?;
+    let mut rows = stmt.query(params![zoom_level, table]).map_err(sql_error)?;
+    while let Some(row) = rows.next().map_err(sql_error)? {
+        let tile_column: i64 = row.get(0).map_err(sql_error)?;
+        let tile_row: i64 = row.get(1).map_err(sql_error)?;
+        let tile = match row.get_raw(2) {
+            ValueRef::Blob(blob) => decode_tile(blob)?,
+            _ => continue,
+        };
+        let tile_scale = row
+            .get::<_, Option<f64>>(3)
+            .map_err(sql_error)?
+            .unwrap_or(1.0);
+        let tile_offset = row
+            .get::<_, Option<f64>>(4)
+            .map_err(sql_error)?
+            .unwrap_or(0.0);
+        let first_row = tile_row as isize * matrix.tile_height as isize - row_offset as isize;
+        let first_column =
+            tile_column as isize * matrix.tile_width as isize - column_offset as isize;
+        for y in 0..tile.height.min(matrix.tile_height) {
+            let r = first_row + y as isize;
+            if r < 0 || r >= configs.rows as isize {
+                continue;
+            }
+            for x in 0..tile.width.min(matrix.tile_width) {
+                let c = first_column + x as isize;
+                if c < 0 || c >= configs.columns as isize {
+                    continue;
+                }
+                let i = y * tile.width + x;
+                let value = if is_coverage {
+                    let v = tile.bands[0][i];
+                    if Some(v) == data_null || v.is_nan() {
+                        continue;
+                    }
+                    (v * tile_scale + tile_offset) * scale + offset
+                } else {
+                    rgba_value(&tile, i)
+                };
+                data.set(r as usize * configs.columns + c as usize, value);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Packs the samples of a pixel of a PNG or JPEG image into an RGBA32 value; fully transparent
+// pixels are nodata.
+fn rgba_value(tile: &TileImage, i: usize) -> f64 {
+    let (red, green, blue, a) = match tile.bands.len() {
+        1 => (tile.bands[0][i], tile.bands[0][i], tile.bands[0][i], 255.0),
+        2 => (
+            tile.bands[0][i],
+            tile.bands[0][i],
+            tile.bands[0][i],
+            tile.bands[1][i],
+        ),
+        3 => (tile.bands[0][i], tile.bands[1][i], tile.bands[2][i], 255.0),
+        _ => (
+            tile.bands[0][i],
+            tile.bands[1][i],
+            tile.bands[2][i],
+            tile.bands[3][i],
+        ),
+    };
+    if a == 0.0 {
+        return 0.0;
+    }
+    (((a as u32) << 24) | ((blue as u32) << 16) | ((green as u32) << 8) | red as u32) as f64
+}
+
+// Decodes a tile image, which may be a PNG, JPEG, or TIFF file.
+fn decode_tile(blob: &[u8]) -> Result<TileImage, Error> {
+    let decode_error = |msg: String| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("Error decoding GeoPackage tile: {}", msg),
+        )
+    };
+    if blob.starts_with(&[0x89, b'P', b'N', b'G']) {
+        let mut decoder = png::Decoder::new(blob);
+        decoder.set_transformations(png::Transformations::EXPAND);
+        let (info, mut reader) = decoder
+            .read_info()
+            .map_err(|e| decode_error(e.to_string()))?;
+        let mut buf = vec![0u8; info.buffer_size()];
+        reader
+            .next_frame(&mut buf)
+            .map_err(|e| decode_error(e.to_string()))?;
+        let (width, height) = (info.width as usize, info.height as usize);
+        let num_bands = info.color_type.samples();
+        let sixteen_bit = info.bit_depth == png::BitDepth::Sixteen;
+        let mut bands = vec![Vec::with_capacity(width * height); num_bands];
+        for y in 0..height {
+            let line = &buf[y * info.line_size..(y + 1) * info.line_size];
+            for x in 0..width {
+                for (b, band) in bands.iter_mut().enumerate() {
+                    let k = x * num_bands + b;
+                    band.push(if sixteen_bit {
+                        u16::from_be_bytes([line[2 * k], line[2 * k + 1]]) as f64
+                    } else {
+                        line[k] as f64
+                    });
+                }
+            }
+        }
+        Ok(TileImage {
+            width,
+            height,
+            bands,
+        })
+    } else if blob.starts_with(&[0xFF, 0xD8]) {
+        let mut decoder = jpeg_decoder::Decoder::new(blob);
+        let pixels = decoder.decode().map_err(|e| decode_error(e.to_string()))?;
+        let info = decoder
+            .info()
+            .ok_or_else(|| decode_error("missing JPEG header".to_string()))?;
+        let num_bands = match info.pixel_format {
+            jpeg_decoder::PixelFormat::L8 => 1,
+            jpeg_decoder::PixelFormat::RGB24 => 3,
+            jpeg_decoder::PixelFormat::CMYK32 => {
+                return Err(decode_error("CMYK JPEG images are unsupported".to_string()))
+            }
+        };
+        let mut bands = vec![vec![]; num_bands];
+        for (i, v) in pixels.iter().enumerate() {
+            bands[i % num_bands].push(*v as f64);
+        }
+        Ok(TileImage {
+            width: info.width as usize,
+            height: info.height as usize,
+            bands,
+        })
+    } else if blob.starts_with(b"II") || blob.starts_with(b"MM") {
+        let mut configs = RasterConfigs {
+            ..Default::default()
+        };
+        let mut values = RasterData::default();
+        let mut band_data = vec![];
+        read_geotiff_from_bytes(blob.to_vec(), &mut configs, &mut values, &mut band_data)?;
+        Ok(TileImage {
+            width: configs.columns,
+            height: configs.rows,
+            bands: vec![values.to_vec()],
+        })
+    } else {
+        Err(decode_error("unrecognized image format".to_string()))
+    }
+}
+
+// How the cell values of a raster are stored within its tiles.
+#[derive(Clone, Copy, PartialEq)]
+enum TileEncoding {
+    /// 8-bit RGBA PNG images, within a 'tiles' layer.
+    Rgba,
+    /// 16-bit grayscale PNG images of the cell values less the offset.
+    Integer { offset: f64 },
+    /// 32-bit floating-point TIFF images.
+    Float,
+}
+
+pub fn write_geopackage_raster(r: &mut Raster) -> Result<(), Error> {
+    let settings = whitebox_common::configs::get_configs()?;
+    let (file_name, layer_name) = split_layer_name(&r.file_name);
+    let table = layer_name.unwrap_or_else(|| {
+        Path::new(&file_name)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("raster")
+            .to_string()
+    });
+    let rows = r.configs.rows;
+    let columns = r.configs.columns;
+    let nodata = r.configs.nodata;
+
+    let is_rgb = matches!(
+        r.configs.data_type,
+        DataType::RGB24 | DataType::RGBA32 | DataType::RGB48
+    ) || r.configs.photometric_interp == PhotometricInterpretation::RGB;
+    let is_integer = !matches!(r.configs.data_type, DataType::F32 | DataType::F64);
+    let encoding = if is_rgb {
+        TileEncoding::Rgba
+    } else if is_integer {
+        // Integer values are stored in 16-bit tiles if their range allows it.
+        let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+        for row in 0..rows as isize {
+            for col in 0..columns as isize {
+                let z = r.get_value(row, col);
+                if z != nodata {
+                    min = min.min(z);
+                    max = max.max(z);
+                }
+            }
+        }
+        if min > max {
+            TileEncoding::Integer { offset: 0.0 }
+        } else if max - min < INTEGER_NULL {
+            TileEncoding::Integer { offset: min }
+        } else {
+            TileEncoding::Float
+        }
+    } else {
+        TileEncoding::Float
+    };
+    // The mixing of packed colour values would create colours absent from the image.
+    let resampling = if is_rgb {
+        OverviewResampling::Nearest
+    } else {
+        OverviewResampling::from_name(&settings.overview_resampling)?
+    };
+
+    let wkt = if r.configs.epsg_code > 0
+        && !esri_wkt_from_epsg(r.configs.epsg_code).starts_with("Unknown")
+    {
+        esri_wkt_from_epsg(r.configs.epsg_code)
+    } else if !r.configs.coordinate_ref_system_wkt.trim().is_empty() {
+        r.configs.coordinate_ref_system_wkt.clone()
+    } else {
+        r.configs.projection.clone()
+    };
+
+    // The zoom levels of the pyramid, the coarsest of which is a single tile.
+    let mut max_zoom = 0u32;
+    while rows.max(columns) > TILE_SIZE << max_zoom {
+        max_zoom += 1;
+    }
+    let extent = (TILE_SIZE << max_zoom) as f64;
+    let (tms_min_x, tms_max_y) = (r.configs.west, r.configs.north);
+    let tms_max_x = tms_min_x + extent * r.configs.resolution_x;
+    let tms_min_y = tms_max_y - extent * r.configs.resolution_y;
+
+    let gpkg = GeoPackage::create(&file_name)?;
+    gpkg.delete_layer(&table)?;
+    let srs_id = gpkg.get_srs_id(&wkt)?;
+    gpkg.conn.execute_batch("BEGIN").map_err(sql_error)?;
+    gpkg.conn
+        .execute_batch(&format!(
+            "CREATE TABLE IF NOT EXISTS gpkg_tile_matrix_set (
+                table_name TEXT NOT NULL PRIMARY KEY,
+                srs_id INTEGER NOT NULL,
+                min_x DOUBLE NOT NULL,
+                min_y DOUBLE NOT NULL,
+                max_x DOUBLE NOT NULL,
+                max_y DOUBLE NOT NULL,
+                CONSTRAINT fk_gtms_table_name FOREIGN KEY (table_name) REFERENCES gpkg_contents(table_name),
+                CONSTRAINT fk_gtms_srs FOREIGN KEY (srs_id) REFERENCES gpkg_spatial_ref_sys (srs_id)
+            );
+            CREATE TABLE IF NOT EXISTS gpkg_tile_matrix (
+                table_name TEXT NOT NULL,
+                zoom_level INTEGER NOT NULL,
+                matrix_width INTEGER NOT NULL,
+                matrix_height INTEGER NOT NULL,
+                tile_width INTEGER NOT NULL,
+                tile_height INTEGER NOT NULL,
+                pixel_x_size DOUBLE NOT NULL,
+                pixel_y_size DOUBLE NOT NULL,
+                CONSTRAINT pk_ttm PRIMARY KEY (table_name, zoom_level),
+                CONSTRAINT fk_tmm_table_name FOREIGN KEY (table_name) REFERENCES gpkg_contents(table_name)
+            );
+            CREATE TABLE {} (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                zoom_level INTEGER NOT NULL,
+                tile_column INTEGER NOT NULL,
+                tile_row INTEGER NOT NULL,
+                tile_data BLOB NOT NULL,
+                UNIQUE (zoom_level, tile_column, tile_row)
+            );",
+            quote_identifier(&table)
+        ))
+        .map_err(sql_error)?;
+
+    gpkg.add_contents(&ContentsEntry {
+        table_name: table.clone(),
+        data_type: if encoding == TileEncoding::Rgba {
+            "tiles".to_string()
+        } else {
+            "2d-gridded-coverage".to_string()
+        },
+        min_x: r.configs.west,
+        min_y: r.configs.south,
+        max_x: r.configs.east,
+        max_y: r.configs.north,
+        srs_id,
+        ..Default::default()
+    })?;
+    gpkg.conn
+        .execute(
+            "INSERT INTO gpkg_tile_matrix_set VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![table, srs_id, tms_min_x, tms_min_y, tms_max_x, tms_max_y],
+        )
+        .map_err(sql_error)?;
+    for zoom in 0..=max_zoom {
+        let factor = (1u32 << (max_zoom - zoom)) as f64;
+        gpkg.conn
+            .execute(
+                "INSERT INTO gpkg_tile_matrix VALUES (?1, ?2, ?3, ?3, ?4, ?4, ?5, ?6)",
+                params![
+                    table,
+                    zoom,
+                    1u32 << zoom,
+                    TILE_SIZE as u32,
+                    r.configs.resolution_x * factor,
+                    r.configs.resolution_y * factor
+                ],
+            )
+            .map_err(sql_error)?;
+    }
+    if encoding != TileEncoding::Rgba {
+        add_coverage_metadata(&gpkg, &table, encoding, nodata)?;
+    }
+
+    // The full-resolution image is read directly from the raster; each overview is then
+    // created from the last.
+    write_tiles(
+        &gpkg,
+        &table,
+        max_zoom,
+        rows,
+        columns,
+        nodata,
+        encoding,
+        r.configs.data_type,
+        |row, col| r.get_value(row as isize, col as isize),
+    )?;
+    let (mut level_rows, mut level_columns) = (rows, columns);
+    let mut level: Option<Vec<f64>> = None;
+    for zoom in (0..max_zoom).rev() {
+        let get_value = |row: usize, col: usize| match &level {
+            Some(values) => values[row * level_columns + col],
+            None => r.get_value(row as isize, col as isize),
+        };
+        let overview = create_overview(
+            level_rows,
+            level_columns,
+            nodata,
+            resampling,
+            is_integer,
+            get_value,
+        );
+        level_rows = level_rows.div_ceil(2);
+        level_columns = level_columns.div_ceil(2);
+        write_tiles(
+            &gpkg,
+            &table,
+            zoom,
+            level_rows,
+            level_columns,
+            nodata,
+            encoding,
+            r.configs.data_type,
+            |row, col| overview[row * level_columns + col],
+        )?;
+        level = Some(overview);
+    }
+
+    gpkg.conn.execute_batch("COMMIT").map_err(sql_error)
+}
+
+// Adds the tables and extension registrations describing a gridded coverage layer.
+fn add_coverage_metadata(
+    gpkg: &GeoPackage,
+    table: &str,
+    encoding: TileEncoding,
+    nodata: f64,
+) -> Result<(), Error> {
+    gpkg.conn
+        .execute_batch(
+            "CREATE TABLE IF NOT EXISTS gpkg_2d_gridded_coverage_ancillary (
+                id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+                tile_matrix_set_name TEXT NOT NULL UNIQUE,
+                datatype TEXT NOT NULL DEFAULT 'integer',
+                scale REAL NOT NULL DEFAULT 1.0,
+                offset REAL NOT NULL DEFAULT 0.0,
+                precision REAL DEFAULT 1.0,
+                data_null REAL,
+                grid_cell_encoding TEXT DEFAULT 'grid-value-is-center',
+                uom TEXT,
+                field_name TEXT DEFAULT 'Height',
+                quantity_definition TEXT DEFAULT 'Height',
+                CONSTRAINT fk_g2dgtct_name FOREIGN KEY(tile_matrix_set_name) REFERENCES gpkg_tile_matrix_set (table_name),
+                CHECK (datatype in ('integer','float'))
+            );
+            CREATE TABLE IF NOT EXISTS gpkg_2d_gridded_tile_ancillary (
+                id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+                tpudt_name TEXT NOT NULL,
+                tpudt_id INTEGER NOT NULL,
+                scale REAL NOT NULL DEFAULT 1.0,
+                offset REAL NOT NULL DEFAULT 0.0,
+                min REAL DEFAULT NULL,
+                max REAL DEFAULT NULL,
+                mean REAL DEFAULT NULL,
+                std_dev REAL DEFAULT NULL,
+                CONSTRAINT fk_g2dgtat_name FOREIGN KEY (tpudt_name) REFERENCES gpkg_contents(table_name),
+                UNIQUE (tpudt_name, tpudt_id)
+            );",
+        )
+        .map_err(sql_error)?;
+    let (datatype, offset, data_null) = match encoding {
+        TileEncoding::Integer { offset } => ("integer", offset, INTEGER_NULL),
+        _ => ("float", 0.0, nodata),
+    };
+    gpkg.conn
+        .execute(
+            "INSERT INTO gpkg_2d_gridded_coverage_ancillary
+            (tile_matrix_set_name, datatype, scale, offset, precision, data_null, grid_cell_encoding)
+            VALUES (?1, ?2, 1.0, ?3, 1.0, ?4, 'grid-value-is-area')",
+            params![table, datatype, offset, data_null],
+        )
+        .map_err(sql_error)?;
+    for (table_name, column_name) in &[
+        ("gpkg_2d_gridded_coverage_ancillary", None),
+        ("gpkg_2d_gridded_tile_ancillary", None),
+        (table, Some("tile_data")),
+    ] {
+        gpkg.add_extension(
+            Some(table_name),
+            *column_name,
+            "gpkg_2d_gridded_coverage",
+            COVERAGE_EXTENSION,
+            "read-write",
+        )?;
+    }
+    Ok(())
+}
+
+// Creates an image of half the width and height of an image, each cell of which summarizes
+// the 2 x 2 block of valid cells that it covers.
+fn create_overview<F: Fn(usize, usize) -> f64>(
+    rows: usize,
+    columns: usize,
+    nodata: f64,
+    resampling: OverviewResampling,
+    is_integer: bool,
+    get_value: F,
+) -> Vec<f64> {
+    let (out_rows, out_columns) = (rows.div_ceil(2), columns.div_ceil(2));
+    let mut output = vec![nodata; out_rows * out_columns];
+    let mut values = Vec::with_capacity(4);
+    for row in 0..out_rows {
+        for col in 0..out_columns {
+            values.clear();
+            for (dr, dc) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+                let (row_n, col_n) = (row * 2 + dr, col * 2 + dc);
+                if row_n < rows && col_n < columns {
+                    let z = get_value(row_n, col_n);
+                    if z != nodata {
+                        values.push(z);
+                    }
+                }
+            }
+            if !values.is_empty() {
+                output[row * out_columns + col] = resampling.summarize(&values, is_integer);
+            }
+        }
+    }
+    output
+}
+
+// Writes the tiles of one zoom level of the pyramid. Tiles containing only nodata are
+// omitted.
+#[allow(clippy::too_many_arguments)]
+fn write_tiles<F: Fn(usize, usize) -> f64>(
+    gpkg: &GeoPackage,
+    table: &str,
+    zoom: u32,
+    rows: usize,
+    columns: usize,
+    nodata: f64,
+    encoding: TileEncoding,
+    data_type: DataType,
+    get_value: F,
+) -> Result<(), Error> {
+    let mut insert_tile = gpkg
+        .conn
+        .prepare(&format!(
+            "INSERT INTO {} (zoom_level, tile_column, tile_row, tile_data) VALUES (?1, ?2, ?3, ?4)",
+            quote_identifier(table)
+        ))
+        .map_err(sql_error)?;
+    let mut values = vec![nodata; TILE_SIZE * TILE_SIZE];
+    for tile_row in 0..rows.div_ceil(TILE_SIZE) {
+        for tile_column in 0..columns.div_ceil(TILE_SIZE) {
+            let mut stats = (0usize, 0f64, 0f64, f64::INFINITY, f64::NEG_INFINITY);
+            for y in 0..TILE_SIZE {
+                let row = tile_row * TILE_SIZE + y;
+                for x in 0..TILE_SIZE {
+                    let col = tile_column * TILE_SIZE + x;
+                    let z = if row < rows && col < columns {
+                        get_value(row, col)
+                    } else {
+                        nodata
+                    };
+                    values[y * TILE_SIZE + x] = z;
+                    if z != nodata {
+                        stats.0 += 1;
+                        stats.1 += z;
+                        stats.2 += z * z;
+                        stats.3 = stats.3.min(z);
+                        stats.4 = stats.4.max(z);
+                    }
+                }
+            }
+            if stats.0 == 0 {
+                continue;
+            }
+            let tile_data = match encoding {
+                TileEncoding::Rgba => encode_rgba_png(&values, nodata, data_type)?,
+                TileEncoding::Integer { offset } => encode_integer_png(&values, nodata, offset)?,
+                TileEncoding::Float => encode_float_tiff(&values, nodata)?,
+            };
+            insert_tile
+                .execute(params![
+                    zoom,
+                    tile_column as i64,
+                    tile_row as i64,
+                    tile_data
+                ])
+                .map_err(sql_error)?;
+            if encoding != TileEncoding::Rgba {
+                let (n, sum, sum_sq, min, max) = stats;
+                let mean = sum / n as f64;
+                let std_dev = (sum_sq / n as f64 - mean * mean).max(0.0).sqrt();
+                gpkg.conn
+                    .execute(
+                        "INSERT INTO gpkg_2d_gridded_tile_ancillary
+                        (tpudt_name, tpudt_id, scale, offset, min, max, mean, std_dev)
+                        VALUES (?1, ?2, 1.0, 0.0, ?3, ?4, ?5, ?6)",
+                        params![
+                            table,
+                            gpkg.conn.last_insert_rowid(),
+                            min,
+                            max,
+                            mean,
+                            std_dev
+                        ],
+                    )
+                    .map_err(sql_error)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn encode_png(
+    bytes: &[u8],
+    color_type: png::ColorType,
+    bit_depth: png::BitDepth,
+) -> Result<Vec<u8>, Error> {
+    let mut buf = vec![];
+    {
+        let mut encoder = png::Encoder::new(&mut buf, TILE_SIZE as u32, TILE_SIZE as u32);
+        encoder.set_color(color_type);
+        encoder.set_depth(bit_depth);
+        let mut writer = encoder
+            .write_header()
+            .map_err(|e| Error::other(e.to_string()))?;
+        writer
+            .write_image_data(bytes)
+            .map_err(|e| Error::other(e.to_string()))?;
+    }
+    Ok(buf)
+}
+
+fn encode_rgba_png(values: &[f64], nodata: f64, data_type: DataType) -> Result<Vec<u8>, Error> {
+    let mut bytes = Vec::with_capacity(values.len() * 4);
+    for &z in values {
+        if z == nodata {
+            bytes.extend_from_slice(&[0, 0, 0, 0]);
+            continue;
+        }
+        let v = z as u32;
+        let a = if data_type == DataType::RGBA32 {
+            (v >> 24) as u8
+        } else {
+            255
+        };
+        bytes.extend_from_slice(&[v as u8, (v >> 8) as u8, (v >> 16) as u8, a]);
+    }
+    encode_png(&bytes, png::ColorType::RGBA, png::BitDepth::Eight)
+}
+
+fn encode_integer_png(values: &[f64], nodata: f64, offset: f64) -> Result<Vec<u8>, Error> {
+    let mut bytes = Vec::with_capacity(values.len() * 2);
+    for &z in values {
+        let v = if z == nodata {
+            INTEGER_NULL as u16
+        } else {
+            (z - offset).round() as u16
+        };
+        bytes.extend_from_slice(&v.to_be_bytes());
+    }
+    encode_png(&bytes, png::ColorType::Grayscale, png::BitDepth::Sixteen)
+}
+
+// Encodes a tile as a single-strip, LZW-compressed, 32-bit floating-point TIFF image.
+fn encode_float_tiff(values: &[f64], nodata: f64) -> Result<Vec<u8>, Error> {
+    let mut data = Vec::with_capacity(values.len() * 4);
+    for &z in values {
+        let v = if z == nodata { nodata as f32 } else { z as f32 };
+        data.extend_from_slice(&v.to_le_bytes());
+    }
+    let encoding = BlockEncoding {
+        compression: COMPRESS_LZW,
+        predictor: 1,
+        samples_per_pixel: 1,
+        bytes_per_sample: 4,
+        endian: Endianness::LittleEndian,
+    };
+    let strip = compress_block(&data, &encoding, TILE_SIZE * 4)?;
+
+    // The header and IFD precede the image data.
+    let entries: [(u16, u16, u32); 11] = [
+        (256, 3, TILE_SIZE as u32), // ImageWidth
+        (257, 3, TILE_SIZE as u32), // ImageLength
+        (258, 3, 32),               // BitsPerSample
+        (259, 3, COMPRESS_LZW as u32),
+        (262, 3, 1),                   // PhotometricInterpretation, BlackIsZero
+        (273, 4, 8 + 2 + 11 * 12 + 4), // StripOffsets
+        (277, 3, 1),                   // SamplesPerPixel
+        (278, 3, TILE_SIZE as u32),    // RowsPerStrip
+        (279, 4, strip.len() as u32),  // StripByteCounts
+        (284, 3, 1),                   // PlanarConfiguration, chunky
+        (339, 3, 3),                   // SampleFormat, floating point
+    ];
+    let mut buf = Vec::with_capacity(strip.len() + 200);
+    buf.extend_from_slice(b"II");
+    buf.extend_from_slice(&42u16.to_le_bytes());
+    buf.extend_from_slice(&8u32.to_le_bytes());
+    buf.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+    for (tag, field_type, value) in &entries {
+        buf.extend_from_slice(&tag.to_le_bytes());
+        buf.extend_from_slice(&field_type.to_le_bytes());
+        buf.extend_from_slice(&1u32.to_le_bytes());
+        if *field_type == 3 {
+            buf.extend_from_slice(&(*value as u16).to_le_bytes());
+            buf.extend_from_slice(&[0, 0]);
+        } else {
+            buf.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+    buf.extend_from_slice(&0u32.to_le_bytes()); // no further IFDs
+    buf.extend_from_slice(&strip);
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn temp_file(name: &str) -> String {
+        std::env::temp_dir()
+            .join(name)
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    fn make_raster(file_name: &str, rows: usize, columns: usize, data_type: DataType) -> Raster {
+        let configs = RasterConfigs {
+            rows,
+            columns,
+            north: 5000.0,
+            south: 5000.0 - rows as f64 * 2.0,
+            west: 1000.0,
+            east: 1000.0 + columns as f64 * 2.0,
+            resolution_x: 2.0,
+            resolution_y: 2.0,
+            nodata: -32768.0,
+            data_type,
+            epsg_code: 26917,
+            ..Default::default()
+        };
+        Raster::initialize_using_config(file_name, &configs)
+    }
+
+    #[test]
+    fn test_float_tiff_tile_round_trip() {
+        let values: Vec<f64> = (0..TILE_SIZE * TILE_SIZE)
+            .map(|i| if i % 7 == 0 { -9999.0 } else { i as f64 * 0.25 })
+            .collect();
+        let tile = decode_tile(&encode_float_tiff(&values, -9999.0).unwrap()).unwrap();
+        assert_eq!((tile.width, tile.height), (TILE_SIZE, TILE_SIZE));
+        assert_eq!(tile.bands[0], values);
+    }
+
+    #[test]
+    fn test_coverage_round_trip() {
+        let file_name = temp_file("wbt_geopackage_raster_test.gpkg");
+        let _ = std::fs::remove_file(&file_name);
+        // a float raster spanning 3 x 2 full-resolution tiles, and an integer raster
+        let (rows, columns) = (300, 600);
+        let mut dem = make_raster(&format!("{}:dem", file_name), rows, columns, DataType::F32);
+        let mut classes = make_raster(&format!("{}:classes", file_name), 20, 30, DataType::I16);
+        for row in 0..rows as isize {
+            for col in 0..columns as isize {
+                if (row + col) % 11 != 0 {
+                    dem.set_value(row, col, 100.5 + (row * 3 + col) as f64 / 8.0);
+                }
+                classes.set_value(row % 20, col % 30, -10.0 + ((row + col) % 5) as f64);
+            }
+        }
+        classes.set_value(3, 4, -32768.0);
+        dem.write().unwrap();
+        classes.write().unwrap();
+
+        let back = Raster::new(&format!("{}:dem", file_name), "r").unwrap();
+        assert_eq!((back.configs.rows, back.configs.columns), (rows, columns));
+        assert_eq!(back.configs.west, 1000.0);
+        assert_eq!(back.configs.north, 5000.0);
+        assert_eq!(back.configs.epsg_code, 26917);
+        for row in 0..rows as isize {
+            for col in 0..columns as isize {
+                assert_eq!(back.get_value(row, col), dem.get_value(row, col));
+            }
+        }
+
+        let back = Raster::new(&format!("{}:classes", file_name), "r").unwrap();
+        assert_eq!(back.configs.data_type, DataType::I32);
+        assert_eq!(back.get_value(3, 4), back.configs.nodata);
+        assert_eq!(back.get_value(2, 2), -6.0);
+        assert_eq!(back.get_value(19, 29), classes.get_value(19, 29));
+
+        // the first raster layer, an RGB image written into the same file
+        let mut image = make_raster(&file_name, 10, 10, DataType::RGBA32);
+        image.configs.photometric_interp = PhotometricInterpretation::RGB;
+        image.set_value(0, 0, ((255u32 << 24) | (30 << 16) | (20 << 8) | 10) as f64);
+        image.write().unwrap();
+        let back = Raster::new(&format!("{}:dem", file_name), "r").unwrap();
+        assert_eq!(back.configs.rows, rows);
+        let back = Raster::new(&format!("{}:wbt_geopackage_raster_test", file_name), "r").unwrap();
+        assert_eq!(back.configs.data_type, DataType::RGBA32);
+        assert_eq!(back.get_value(0, 0), image.get_value(0, 0));
+        assert_eq!(back.get_value(5, 5), 0.0);
+        let _ = std::fs::remove_file(&file_name);
+    }
+}
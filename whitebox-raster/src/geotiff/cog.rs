@@ -0,0 +1,592 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+*/
+
+use super::tiff_consts::*;
+use super::*;
+use crate::{DataType, PhotometricInterpretation, Raster};
+use std::fs::File;
+use std::io::{BufWriter, Error, ErrorKind, Seek, SeekFrom};
+use whitebox_common::configs::Configs;
+use whitebox_common::utils::Endianness;
+
+/// The resampling methods used to create the reduced-resolution overviews of a
+/// Cloud-Optimized GeoTIFF. Each overview cell summarizes the valid (non-nodata) cells
+/// of the 2 x 2 block of cells that it covers within the next-larger image.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum OverviewResampling {
+    Nearest,
+    Average,
+    Mode,
+    Minimum,
+    Maximum,
+}
+
+impl OverviewResampling {
+    pub fn from_name(name: &str) -> Result<OverviewResampling, Error> {
+        match name.to_lowercase().trim() {
+            "nearest" => Ok(OverviewResampling::Nearest),
+            "average" | "mean" => Ok(OverviewResampling::Average),
+            "mode" => Ok(OverviewResampling::Mode),
+            "min" | "minimum" => Ok(OverviewResampling::Minimum),
+            "max" | "maximum" => Ok(OverviewResampling::Maximum),
+            m => Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "Unrecognized overview resampling method '{}'; use 'nearest', 'average', 'mode', 'min', or 'max'.",
+                    m
+                ),
+            )),
+        }
+    }
+
+    /// Summarizes the valid values of a block of cells, which must not be empty. The mean of
+    /// integer values is rounded.
+    pub fn summarize(&self, values: &[f64], is_integer: bool) -> f64 {
+        match self {
+            OverviewResampling::Nearest => values[0],
+            OverviewResampling::Average => {
+                let mean = values.iter().sum::<f64>() / values.len() as f64;
+                if is_integer {
+                    mean.round()
+                } else {
+                    mean
+                }
+            }
+            OverviewResampling::Mode => {
+                // the most frequent value; ties go to the first encountered
+                let mut mode = values[0];
+                let mut max_count = 0;
+                for &v in values {
+                    let count = values.iter().filter(|&&w| w == v).count();
+                    if count > max_count {
+                        max_count = count;
+                        mode = v;
+                    }
+                }
+                mode
+            }
+            OverviewResampling::Minimum => values.iter().cloned().fold(f64::INFINITY, f64::min),
+            OverviewResampling::Maximum => {
+                values.iter().cloned().fold(f64::NEG_INFINITY, f64::max)
+            }
+        }
+    }
+}
+
+/// The options controlling how GeoTIFF files are written, as set within the `Configs`.
+pub(crate) struct GeoTiffOptions {
+    pub compression: u16,
+    /// The requested predictor, 1 (none), 2 (horizontal), or 3 (floating-point).
+    pub predictor: u16,
+    /// The width and height of tiles, or zero if the image data are stored in strips.
+    pub tile_size: usize,
+    pub cloud_optimized: bool,
+    pub resampling: OverviewResampling,
+}
+
+impl GeoTiffOptions {
+    pub fn from_configs(configs: &Configs) -> Result<GeoTiffOptions, Error> {
+        let compression = if !configs.compress_rasters {
+            COMPRESS_NONE
+        } else {
+            match configs.compression_method.to_lowercase().trim() {
+                "deflate" | "zlib" => COMPRESS_DEFLATE,
+                "lzw" => COMPRESS_LZW,
+                "packbits" => COMPRESS_PACKBITS,
+                "none" => COMPRESS_NONE,
+                m => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "Unrecognized compression method '{}'; use 'deflate', 'lzw', or 'packbits'.",
+                            m
+                        ),
+                    ))
+                }
+            }
+        };
+        let predictor = match configs.predictor.to_lowercase().trim() {
+            "none" | "1" => 1,
+            "horizontal" | "2" => 2,
+            "floating_point" | "float" | "3" => 3,
+            p => return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "Unrecognized predictor '{}'; use 'none', 'horizontal', or 'floating_point'.",
+                    p
+                ),
+            )),
+        };
+        let resampling = OverviewResampling::from_name(&configs.overview_resampling)?;
+        let tile_size = if configs.tiled_rasters || configs.cloud_optimized_rasters {
+            if configs.tile_size < 16
+                || !configs.tile_size.is_multiple_of(16)
+                || configs.tile_size > 4096
+            {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "The GeoTIFF tile size ({}) must be a multiple of 16, no larger than 4096.",
+                        configs.tile_size
+                    ),
+                ));
+            }
+            configs.tile_size
+        } else {
+            0
+        };
+        Ok(GeoTiffOptions {
+            compression,
+            predictor,
+            tile_size,
+            cloud_optimized: configs.cloud_optimized_rasters,
+            resampling,
+        })
+    }
+}
+
+/// Writes a tiled GeoTIFF file. If the options call for a Cloud-Optimized GeoTIFF, a pyramid of
+/// reduced-resolution overviews, each half the size of the last, is added until the smallest
+/// fits within a single tile. In that case, the IFDs of the full-resolution image and of each
+/// overview are written first, in that order, followed by the image data, beginning with the
+/// smallest overview, such that a reader can fetch any level of the pyramid with few requests.
+pub(crate) fn write_tiled_geotiff(r: &mut Raster, options: &GeoTiffOptions) -> Result<(), Error> {
+    let total_bytes_per_pixel = r.configs.data_type.get_data_size();
+    if total_bytes_per_pixel == 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("Unknown data type: {:?}.", r.configs.data_type),
+        ));
+    }
+
+    // At the moment, categorical and paletted output is not supported.
+    if r.configs.photometric_interp == PhotometricInterpretation::Categorical
+        || r.configs.photometric_interp == PhotometricInterpretation::Paletted
+    {
+        r.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    }
+
+    let num_bands = r.num_bands();
+    let is_multiband = num_bands > 1
+        && r.configs.data_type != DataType::RGB24
+        && r.configs.data_type != DataType::RGBA32
+        && r.configs.data_type != DataType::RGB48;
+    let num_samples = if is_multiband { num_bands } else { 1 };
+    let tile_size = options.tile_size;
+    let encoding = BlockEncoding::new(r, options.compression, options.predictor);
+
+    let mut overviews: Vec<Raster> = vec![];
+    if options.cloud_optimized {
+        // Averaging packed colour values is meaningless.
+        let resampling = if r.configs.data_type == DataType::RGB24
+            || r.configs.data_type == DataType::RGBA32
+            || r.configs.photometric_interp == PhotometricInterpretation::RGB
+        {
+            OverviewResampling::Nearest
+        } else {
+            options.resampling
+        };
+        loop {
+            let previous = overviews.last().unwrap_or(&*r);
+            if previous.configs.rows <= tile_size && previous.configs.columns <= tile_size {
+                break;
+            }
+            let overview = create_overview(previous, resampling);
+            overviews.push(overview);
+        }
+    }
+    let levels: Vec<&Raster> = std::iter::once(&*r).chain(overviews.iter()).collect();
+
+    // is it a BigTiff? The size of the uncompressed data is an upper bound on the file size,
+    // leaving some allowance for the (rare) expansion of incompressible data.
+    let data_size: usize = levels
+        .iter()
+        .map(|level| num_tiles(level, tile_size) * tile_size * tile_size * total_bytes_per_pixel)
+        .sum::<usize>()
+        * num_samples;
+    let is_big_tiff = data_size + data_size / 64 + 1_000_000 >= 4_000_000_000;
+    let header_size = if !is_big_tiff { 8u64 } else { 16u64 };
+
+    let f = File::create(r.file_name.clone())?;
+    let mut writer = BufWriter::new(f);
+
+    //////////////////////
+    // Write the header //
+    //////////////////////
+    // Unless the IFDs precede the data, the offset to the first IFD is not yet known.
+    let first_ifd = if options.cloud_optimized {
+        header_size
+    } else {
+        0u64
+    };
+    if r.configs.endian == Endianness::LittleEndian {
+        write_bytes(&mut writer, "II".as_bytes())?;
+    } else {
+        write_bytes(&mut writer, "MM".as_bytes())?;
+    }
+    if !is_big_tiff {
+        write_u16(&mut writer, r.configs.endian, 42u16)?;
+        write_u32(&mut writer, r.configs.endian, first_ifd as u32)?;
+    } else {
+        write_u16(&mut writer, r.configs.endian, 43u16)?;
+        write_u16(&mut writer, r.configs.endian, 8u16)?;
+        write_u16(&mut writer, r.configs.endian, 0u16)?;
+        write_u64(&mut writer, r.configs.endian, first_ifd)?;
+    }
+
+    if !options.cloud_optimized {
+        // The image data are followed by the IFD, as with stripped output.
+        let (block_offsets, block_byte_counts) =
+            write_tiles(&mut writer, r, tile_size, &encoding, header_size)?;
+        let ifd_start = block_offsets
+            .iter()
+            .zip(&block_byte_counts)
+            .map(|(offset, count)| offset + count + count % 2)
+            .max()
+            .unwrap_or(header_size);
+        if !is_big_tiff {
+            writer.seek(SeekFrom::Start(4))?;
+            write_u32(&mut writer, r.configs.endian, ifd_start as u32)?;
+        } else {
+            writer.seek(SeekFrom::Start(8))?;
+            write_u64(&mut writer, r.configs.endian, ifd_start)?;
+        }
+        writer.seek(SeekFrom::Start(ifd_start))?;
+        let image = IfdImage {
+            layout: BlockLayout::Tiles(tile_size),
+            block_offsets,
+            block_byte_counts,
+            encoding,
+            next_ifd: 0,
+            is_overview: false,
+        };
+        return write_geotiff_ifd(&mut writer, r, ifd_start, image, is_big_tiff);
+    }
+
+    // The length of each IFD does not depend on the values of the block offsets, which are
+    // not yet known, and so each is measured by writing it to memory with placeholder values.
+    let mut ifd_starts = Vec::with_capacity(levels.len());
+    let mut current_offset = header_size;
+    for (i, level) in levels.iter().enumerate() {
+        let n = num_tiles(level, tile_size) * num_planes(level);
+        let image = IfdImage {
+            layout: BlockLayout::Tiles(tile_size),
+            block_offsets: vec![0u64; n],
+            block_byte_counts: vec![0u64; n],
+            encoding,
+            next_ifd: 0,
+            is_overview: i > 0,
+        };
+        let mut buf = BufWriter::new(vec![]);
+        write_geotiff_ifd(&mut buf, level, current_offset, image, is_big_tiff)?;
+        let ifd_length = buf.buffer().len() as u64;
+        ifd_starts.push(current_offset);
+        // Each IFD must begin on a word boundary.
+        current_offset += ifd_length + ifd_length % 2;
+    }
+
+    // The image data, from the smallest overview to the full-resolution image.
+    let mut blocks = vec![(vec![], vec![]); levels.len()];
+    writer.seek(SeekFrom::Start(current_offset))?;
+    for i in (0..levels.len()).rev() {
+        let (block_offsets, block_byte_counts) =
+            write_tiles(&mut writer, levels[i], tile_size, &encoding, current_offset)?;
+        current_offset = block_offsets
+            .iter()
+            .zip(&block_byte_counts)
+            .map(|(offset, count)| offset + count + count % 2)
+            .max()
+            .unwrap_or(current_offset);
+        blocks[i] = (block_offsets, block_byte_counts);
+    }
+
+    // The IFDs, which immediately follow the header.
+    writer.seek(SeekFrom::Start(header_size))?;
+    for (i, (block_offsets, block_byte_counts)) in blocks.into_iter().enumerate() {
+        let image = IfdImage {
+            layout: BlockLayout::Tiles(tile_size),
+            block_offsets,
+            block_byte_counts,
+            encoding,
+            next_ifd: if i + 1 < levels.len() {
+                ifd_starts[i + 1]
+            } else {
+                0
+            },
+            is_overview: i > 0,
+        };
+        writer.seek(SeekFrom::Start(ifd_starts[i]))?;
+        write_geotiff_ifd(&mut writer, levels[i], ifd_starts[i], image, is_big_tiff)?;
+    }
+    writer.flush()?;
+
+    Ok(())
+}
+
+// The number of tiles within each plane of an image.
+fn num_tiles(r: &Raster, tile_size: usize) -> usize {
+    let tiles_across = r.configs.columns.div_ceil(tile_size);
+    let tiles_down = r.configs.rows.div_ceil(tile_size);
+    tiles_across * tiles_down
+}
+
+// The number of planes of an image. The bands of a multiband raster are stored in separate
+// planes in the band-sequential configuration, and interleaved within a single plane otherwise.
+fn num_planes(r: &Raster) -> usize {
+    if r.num_bands() > 1 && r.configs.band_sequential {
+        r.num_bands()
+    } else {
+        1
+    }
+}
+
+// Writes the tiles of an image, beginning at `start`, and returns their offsets and byte
+// counts. Tiles are ordered by plane, then row, then column; tiles that extend beyond the
+// edges of the image are padded with nodata.
+fn write_tiles(
+    writer: &mut BufWriter<File>,
+    r: &Raster,
+    tile_size: usize,
+    encoding: &BlockEncoding,
+    start: u64,
+) -> Result<(Vec<u64>, Vec<u64>), Error> {
+    let num_bands = r.num_bands();
+    let is_multiband = num_bands > 1
+        && r.configs.data_type != DataType::RGB24
+        && r.configs.data_type != DataType::RGBA32
+        && r.configs.data_type != DataType::RGB48;
+    let num_planes = if is_multiband { num_planes(r) } else { 1 };
+    let bands_per_plane = if is_multiband {
+        num_bands / num_planes
+    } else {
+        1
+    };
+    let bytes_per_pixel = r.configs.data_type.get_data_size() * bands_per_plane;
+    let tiles_across = r.configs.columns.div_ceil(tile_size);
+    let tiles_down = r.configs.rows.div_ceil(tile_size);
+
+    let mut block_offsets = Vec::with_capacity(tiles_across * tiles_down * num_planes);
+    let mut block_byte_counts = Vec::with_capacity(tiles_across * tiles_down * num_planes);
+    let mut current_offset = start;
+    for plane in 0..num_planes {
+        let bands = plane * bands_per_plane..(plane + 1) * bands_per_plane;
+        let nodata: Vec<f64> = bands.clone().map(|b| r.get_band_nodata(b)).collect();
+        for tile_row in 0..tiles_down {
+            for tile_col in 0..tiles_across {
+                let mut data = Vec::with_capacity(tile_size * tile_size * bytes_per_pixel);
+                for y in 0..tile_size {
+                    let row = tile_row * tile_size + y;
+                    for x in 0..tile_size {
+                        let col = tile_col * tile_size + x;
+                        for (b, band) in bands.clone().enumerate() {
+                            let value = if row < r.configs.rows && col < r.configs.columns {
+                                r.get_value_band(band, row as isize, col as isize)
+                            } else {
+                                nodata[b]
+                            };
+                            write_pixel(&mut data, r, value)?;
+                        }
+                    }
+                }
+                if encoding.compression != COMPRESS_NONE {
+                    data = compress_block(&data, encoding, tile_size * bytes_per_pixel)?;
+                }
+                write_bytes(writer, &data)?;
+                let tile_length_in_bytes = data.len() as u64;
+                block_offsets.push(current_offset);
+                block_byte_counts.push(tile_length_in_bytes);
+                current_offset += tile_length_in_bytes;
+                if !tile_length_in_bytes.is_multiple_of(2) {
+                    // This is just because the data must start on a word (i.e. an even value).
+                    write_u8(writer, 0u8)?;
+                    current_offset += 1;
+                }
+            }
+        }
+    }
+    Ok((block_offsets, block_byte_counts))
+}
+
+// Appends the bytes of a single pixel value, unpacking packed colour values into their
+// red, green, blue (and alpha) components.
+fn write_pixel(buf: &mut Vec<u8>, r: &Raster, value: f64) -> Result<(), Error> {
+    let rgba = value as u32;
+    match r.configs.data_type {
+        DataType::RGB24 => {
+            buf.extend_from_slice(&[
+                (rgba & 0xFF) as u8,
+                ((rgba >> 8) & 0xFF) as u8,
+                ((rgba >> 16) & 0xFF) as u8,
+            ]);
+        }
+        DataType::RGBA32 => buf.extend_from_slice(&rgba.to_le_bytes()),
+        DataType::U32 if r.configs.photometric_interp == PhotometricInterpretation::RGB => {
+            buf.extend_from_slice(&rgba.to_le_bytes())
+        }
+        data_type => write_sample(buf, data_type, r.configs.endian, value)?,
+    }
+    Ok(())
+}
+
+// Creates an image of half the width and height of `r`, each cell of which summarizes the
+// 2 x 2 block of cells of `r` that it covers.
+fn create_overview(r: &Raster, resampling: OverviewResampling) -> Raster {
+    let mut configs = r.configs.clone();
+    configs.rows = r.configs.rows.div_ceil(2);
+    configs.columns = r.configs.columns.div_ceil(2);
+    configs.resolution_x = (configs.east - configs.west) / configs.columns as f64;
+    configs.resolution_y = (configs.north - configs.south) / configs.rows as f64;
+    let mut output = Raster::initialize_using_config(&r.file_name, &configs);
+    output.configs.band_sequential = r.configs.band_sequential;
+    output.set_num_bands(r.num_bands());
+    let is_integer = !matches!(r.configs.data_type, DataType::F32 | DataType::F64);
+
+    let mut values = Vec::with_capacity(4);
+    for band in 0..r.num_bands() {
+        let nodata = r.get_band_nodata(band);
+        output.set_band_nodata(band, nodata);
+        for row in 0..configs.rows as isize {
+            for col in 0..configs.columns as isize {
+                values.clear();
+                for (dr, dc) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+                    let (row_n, col_n) = (row * 2 + dr, col * 2 + dc);
+                    if (row_n as usize) < r.configs.rows && (col_n as usize) < r.configs.columns {
+                        let z = r.get_value_band(band, row_n, col_n);
+                        if z != nodata {
+                            values.push(z);
+                        }
+                    }
+                }
+                if values.is_empty() {
+                    continue;
+                }
+                let z = resampling.summarize(&values, is_integer);
+                output.set_value_band(band, row, col, z);
+            }
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::{write_tiled_geotiff, GeoTiffOptions, OverviewResampling};
+    use crate::geotiff::tiff_consts::*;
+    use crate::geotiff::{compress_block, decompress_block, BlockEncoding};
+    use crate::{DataType, PhotometricInterpretation, Raster, RasterConfigs};
+    use whitebox_common::utils::Endianness;
+
+    #[test]
+    fn test_block_compression_round_trip() {
+        // a mix of runs and noise, long enough to fill the LZW code table several times
+        let mut data = vec![];
+        let mut state = 12345u32;
+        for i in 0..192_000usize {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            let value = if (i / 960) % 2 == 0 {
+                (state >> 16) as u8
+            } else {
+                (i / 7) as u8
+            };
+            data.push(value);
+        }
+        for &(compression, predictor, samples_per_pixel, bytes_per_sample, endian) in &[
+            (COMPRESS_LZW, 1, 1, 1, Endianness::LittleEndian),
+            (COMPRESS_PACKBITS, 1, 1, 1, Endianness::LittleEndian),
+            (COMPRESS_DEFLATE, 1, 1, 1, Endianness::LittleEndian),
+            (COMPRESS_DEFLATE, 2, 3, 1, Endianness::LittleEndian),
+            (COMPRESS_LZW, 2, 1, 2, Endianness::LittleEndian),
+            (COMPRESS_DEFLATE, 2, 2, 4, Endianness::BigEndian),
+            (COMPRESS_DEFLATE, 3, 1, 4, Endianness::LittleEndian),
+            (COMPRESS_LZW, 3, 1, 4, Endianness::BigEndian),
+            (COMPRESS_DEFLATE, 3, 2, 8, Endianness::LittleEndian),
+        ] {
+            let encoding = BlockEncoding {
+                compression,
+                predictor,
+                samples_per_pixel,
+                bytes_per_sample,
+                endian,
+            };
+            let compressed = compress_block(&data, &encoding, 960).unwrap();
+            let decompressed = decompress_block(compressed, &encoding, 960, data.len()).unwrap();
+            assert_eq!(decompressed, data);
+        }
+    }
+
+    #[test]
+    fn test_tiled_geotiff_round_trip() {
+        for &(data_type, compression, predictor, cloud_optimized) in &[
+            (DataType::I16, COMPRESS_NONE, 1, false),
+            (DataType::I16, COMPRESS_LZW, 1, true),
+            (DataType::I16, COMPRESS_PACKBITS, 1, true),
+            (DataType::I16, COMPRESS_DEFLATE, 1, true),
+            (DataType::I16, COMPRESS_LZW, 2, false),
+            (DataType::F32, COMPRESS_DEFLATE, 3, false),
+            (DataType::F64, COMPRESS_LZW, 3, true),
+        ] {
+            let file_name = std::env::temp_dir()
+                .join(format!(
+                    "wbt_tiled_test_{:?}_{}_{}_{}.tif",
+                    data_type, compression, predictor, cloud_optimized
+                ))
+                .to_str()
+                .unwrap()
+                .to_string();
+            let configs = RasterConfigs {
+                rows: 45,
+                columns: 70,
+                north: 45.0,
+                south: 0.0,
+                east: 70.0,
+                west: 0.0,
+                resolution_x: 1.0,
+                resolution_y: 1.0,
+                nodata: -32768.0,
+                data_type,
+                photometric_interp: PhotometricInterpretation::Continuous,
+                ..Default::default()
+            };
+            // Floating-point values have fractional parts, but are exactly representable.
+            let scale = if data_type == DataType::I16 {
+                1.0
+            } else {
+                0.125
+            };
+            let mut output = Raster::initialize_using_config(&file_name, &configs);
+            for row in 0..45 {
+                for col in 0..70 {
+                    output.set_value(row, col, (row * 100 - col) as f64 * scale);
+                }
+            }
+            output.set_value(10, 10, -32768.0);
+            let options = GeoTiffOptions {
+                compression,
+                predictor,
+                tile_size: 16,
+                cloud_optimized,
+                resampling: OverviewResampling::Average,
+            };
+            write_tiled_geotiff(&mut output, &options).unwrap();
+
+            let input = Raster::new(&file_name, "r").unwrap();
+            assert_eq!(input.configs.rows, 45);
+            assert_eq!(input.configs.columns, 70);
+            assert_eq!(input.configs.north, 45.0);
+            assert_eq!(input.get_value(10, 10), -32768.0);
+            for row in 0..45 {
+                for col in 0..70 {
+                    if row != 10 || col != 10 {
+                        assert_eq!(input.get_value(row, col), (row * 100 - col) as f64 * scale);
+                    }
+                }
+            }
+            let _ = std::fs::remove_file(&file_name);
+        }
+    }
+}
@@ -1,10 +1,12 @@
 #![allow(unused_assignments, dead_code)]
+pub(crate) mod cog;
 pub mod geokeys;
 pub mod ifd;
 pub mod tiff_consts;
 
 // use flate2::read::GzDecoder;
 // use super::use_compression;
+use crate::geotiff::cog::*;
 use crate::geotiff::geokeys::*;
 use crate::geotiff::tiff_consts::*;
 use crate::*;
@@ -228,19 +230,74 @@ pub fn print_tags<'a>(file_name: &'a String) -> Result<(), Error> {
     Ok(())
 }
 
-pub fn read_geotiff<'a>(
-    file_name: &'a String,
+/// The arrangement of the image data within a GeoTIFF file, as described by its first IFD.
+pub(crate) struct GeoTiffLayout {
+    pub compression: u16,
+    pub predictor: u16,
+    pub mode: u16,
+    pub palette: Vec<u32>,
+    pub bits_per_sample: Vec<u16>,
+    pub sample_format: Vec<u16>,
+    pub num_bands: usize,
+    pub num_planes: usize,
+    pub block_padding: bool,
+    pub block_width: usize,
+    pub block_height: usize,
+    pub blocks_across: usize,
+    pub blocks_down: usize,
+    pub block_offsets: Vec<u64>,
+    pub block_counts: Vec<u64>,
+}
+
+impl GeoTiffLayout {
+    /// Returns the encoding of the strips or tiles, given the byte order of the file.
+    pub fn block_encoding(&self, endian: Endianness) -> BlockEncoding {
+        BlockEncoding {
+            compression: self.compression,
+            predictor: self.predictor,
+            samples_per_pixel: self.samples_per_block_pixel(),
+            bytes_per_sample: self.bits_per_sample[0] as usize / 8,
+            endian,
+        }
+    }
+
+    /// Returns the length, in bytes, of a single row of a strip or tile.
+    pub fn block_row_length(&self) -> usize {
+        self.block_width * self.samples_per_block_pixel() * self.bits_per_sample[0] as usize / 8
+    }
+
+    // In a chunky configuration, the samples of each pixel are interleaved within a block.
+    fn samples_per_block_pixel(&self) -> usize {
+        if self.num_planes == 1 {
+            self.bits_per_sample.len()
+        } else {
+            1
+        }
+    }
+}
+
+/// Reads the header and first IFD of a GeoTIFF file into `configs`, without reading any of
+/// the image data, and returns the layout of the image data within the file.
+pub(crate) fn read_geotiff_layout<'a>(
+    file_name: &'a str,
     configs: &'a mut RasterConfigs,
-    data: &'a mut Vec<f64>,
-) -> Result<(), Error> {
-    let f = File::open(file_name.clone())?;
+) -> Result<GeoTiffLayout, Error> {
+    let f = File::open(file_name)?;
+    let br = BufReader::new(f);
+    let mut th = ByteOrderReader::<BufReader<File>>::new(br, configs.endian);
+    read_tiff_layout(&mut th, configs, true)
+}
 
+// Reads the header and first IFD of TIFF data from any seekable source. Plain TIFF images,
+// lacking geokeys, are only accepted if they need not be `georeferenced`.
+fn read_tiff_layout<R: Read + Seek>(
+    th: &mut ByteOrderReader<R>,
+    configs: &mut RasterConfigs,
+    georeferenced: bool,
+) -> Result<GeoTiffLayout, Error> {
     //////////////////////////
     // Read the TIFF header //
     //////////////////////////
-    let br = BufReader::new(f);
-    let mut th = ByteOrderReader::<BufReader<File>>::new(br, configs.endian);
-
     let bo_indicator1 = th.read_u8()?;
     let bo_indicator2 = th.read_u8()?;
     if bo_indicator1 == 73 && bo_indicator2 == 73 {
@@ -485,10 +542,16 @@ pub fn read_geotiff<'a>(
         }
     };
 
-    // let num_samples = match ifd_map.get(&277) {
-    //     Some(ifd) => ifd.interpret_as_u16()[0],
-    //     _ => 0,
-    // };
+    let samples_per_pixel = match ifd_map.get(&TAG_SAMPLESPERPIXEL) {
+        Some(ifd) => ifd.interpret_as_u16()[0] as usize,
+        _ => bits_per_sample.len(),
+    };
+
+    // PlanarConfiguration: 1 = chunky (samples interleaved by pixel), 2 = planar (one plane per sample)
+    let planar_config = match ifd_map.get(&TAG_PLANARCONFIGURATION) {
+        Some(ifd) => ifd.interpret_as_u16()[0],
+        _ => 1u16,
+    };
 
     match ifd_map.get(&280) {
         Some(ifd) => {
@@ -522,7 +585,12 @@ pub fn read_geotiff<'a>(
                 ifd.interpret_as_ascii().parse::<f64>().unwrap_or(-32768f64)
             }
         }
-        _ => -32768f64,
+        _ => configs.nodata,
+    };
+
+    configs.provenance = match ifd_map.get(&TAG_GDAL_METADATA) {
+        Some(ifd) => read_gdal_metadata_provenance(&ifd.interpret_as_ascii()),
+        _ => None,
     };
 
     // GeoKeyDirectoryTag
@@ -531,6 +599,7 @@ pub fn read_geotiff<'a>(
             configs.geo_key_directory = ifd.interpret_as_u16();
             geokeys.add_key_directory(&ifd.data, configs.endian);
         }
+        _ if !georeferenced => {}
         _ => {
             return Err(Error::new(
                 ErrorKind::InvalidData,
@@ -557,7 +626,11 @@ pub fn read_geotiff<'a>(
         _ => {}
     };
 
-    let geokeys_map = geokeys.get_ifd_map(configs.endian);
+    let geokeys_map = if configs.geo_key_directory.is_empty() {
+        HashMap::new()
+    } else {
+        geokeys.get_ifd_map(configs.endian)
+    };
 
     // ModelTiePointTag
     configs.model_tiepoint = match ifd_map.get(&33922) {
@@ -765,7 +838,7 @@ pub fn read_geotiff<'a>(
         configs.east = lower_right_x.max(upper_right_x);
         configs.south = lower_left_y.min(lower_right_y);
         configs.north = upper_left_y.max(upper_right_y);
-    } else {
+    } else if georeferenced {
         return Err(Error::new(
             ErrorKind::InvalidData,
             "The model-space/raster-space transformation cannot be defined.",
@@ -874,6 +947,26 @@ pub fn read_geotiff<'a>(
         ));
     }
 
+    // Multispectral images are stored as greyscale images with more than one sample
+    // per pixel. Each sample is read into a separate band.
+    let num_bands = if mode == IM_GRAY || mode == IM_GRAYINVERT {
+        samples_per_pixel.max(1)
+    } else {
+        1
+    };
+    if num_bands > u8::MAX as usize {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "The GeoTIFF contains more bands than are currently supported (255).",
+        ));
+    }
+    // With a planar configuration, each band is stored in its own set of strips or tiles.
+    let num_planes = if num_bands > 1 && planar_config == 2 {
+        num_bands
+    } else {
+        1
+    };
+
     let width = configs.columns;
     let height = configs.rows;
 
@@ -1082,463 +1175,601 @@ pub fn read_geotiff<'a>(
         };
     }
 
-    ////////////////////
-    // Read the data! //
-    ////////////////////
-    if data.len() > 0 {
-        data.clear();
-    }
-    data.reserve_exact(configs.rows * configs.columns);
-    unsafe {
-        // The memory will be initialized when we read
-        // the pixel values.
-        data.set_len(configs.rows * configs.columns);
-    }
-
-    for i in 0..blocks_across {
-        let mut blk_w = block_width;
-        if !block_padding && i == blocks_across - 1 && width % block_width != 0 {
-            blk_w = width % block_width;
-        }
-        for j in 0..blocks_down {
-            let mut blk_h = block_height;
-            if !block_padding && j == blocks_down - 1 && height % block_height != 0 {
-                blk_h = height % block_height;
-            }
-            let offset = block_offsets[j * blocks_across + i] as usize;
-            let n = block_counts[j * blocks_across + i] as usize;
-            let mut buf: Vec<u8> = vec![];
-            if n != 0 {
-                // it's not a sparse tile
-                match compression {
-                    COMPRESS_NONE => {
-                        // no compression
-                        // buf = vec![0u8; n];
-                        buf.reserve_exact(n);
-                        unsafe { buf.set_len(n); }
-                        th.seek(offset);
-                        th.read_exact(&mut buf)?;
-                    }
-                    COMPRESS_PACKBITS => {
-                        // buf = packbits_decoder(th.buffer[offset..(offset + n)].to_vec());
-                        let mut b = vec![0u8; n];
-                        th.seek(offset);
-                        th.read_exact(&mut b).expect("Error reading bytes from file.");
-                        buf = packbits_decoder(b);
-                    }
-                    COMPRESS_LZW => {
-                        let mut compressed = vec![0; n];
-                        th.seek(offset);
-                        th.read_exact(&mut compressed).expect("Error reading bytes from file.");
-                        let max_uncompressed_length = block_width * block_height * bits_per_sample.len() * bits_per_sample[0] as usize / 8;
-                        buf = Vec::with_capacity(max_uncompressed_length);
-                        let mut decoder = lzw::DecoderEarlyChange::new(lzw::MsbReader::new(), 8);
-                        let mut bytes_read = 0;
-                        while bytes_read < n && buf.len() < max_uncompressed_length {
-                            let (len, bytes) = decoder.decode_bytes(&compressed[bytes_read..]).expect("Error encountered while decoding the LZW compressed GeoTIFF file.");
-                            bytes_read += len;
-                            buf.extend_from_slice(bytes);
+    // Determine the data type from the image mode
+    match mode {
+        IM_GRAYINVERT | IM_GRAY => {
+            //ImageMode::GrayInvert | ImageMode::Gray => {
+            configs.photometric_interp = PhotometricInterpretation::Continuous;
+            match sample_format[0] {
+                1 => {
+                    // unsigned integer
+                    match bits_per_sample[0] {
+                        8 => {
+                            configs.data_type = DataType::U8;
+                        }
+                        16 => {
+                            configs.data_type = DataType::U16;
+                        }
+                        32 => {
+                            configs.data_type = DataType::U32;
+                        }
+                        64 => {
+                            configs.data_type = DataType::U64;
+                        }
+                        _ => {
+                            return Err(Error::new(
+                                ErrorKind::InvalidData,
+                                "The raster was not read correctly",
+                            ))
                         }
                     }
-                    COMPRESS_DEFLATE => {
-                        // let mut dec = GzDecoder::new(th.buffer[offset..(offset + n)].to_vec());
-                        // let compressed = &th.buffer[offset..(offset + n)];
-                        // let mut decoder = Decoder::new(&compressed[..]).unwrap();
-                        // decoder.read_to_end(&mut buf).unwrap();
-                        th.seek(offset);
-                        let mut compressed = vec![0u8; n];
-                        th.read_exact(&mut compressed).expect("Error reading bytes from file.");
-                        // let mut decoder = Decoder::new(&compressed[..])?;
-                        // decoder.read_to_end(&mut buf).unwrap();
-                        buf.extend(decompress_to_vec_zlib(&compressed).expect("Error encountered while decoding the DEFLATE compressed GeoTIFF file."));
+                }
+                2 => {
+                    // signed integer
+                    match bits_per_sample[0] {
+                        8 => {
+                            configs.data_type = DataType::I8;
+                        }
+                        16 => {
+                            configs.data_type = DataType::I16;
+                        }
+                        32 => {
+                            configs.data_type = DataType::I32;
+                        }
+                        64 => {
+                            configs.data_type = DataType::I64;
+                        }
+                        _ => {
+                            return Err(Error::new(
+                                ErrorKind::InvalidData,
+                                "The raster was not read correctly",
+                            ))
+                        }
                     }
-                    _ => {
-                        return Err(Error::new(
-                            ErrorKind::InvalidData,
-                            "The WhiteboxTools GeoTIFF decoder currently only supports PACKBITS and DEFLATE compression.",
-                        ))
+                }
+                3 => {
+                    // floating point
+                    match bits_per_sample[0] {
+                        32 => {
+                            configs.data_type = DataType::F32;
+                        }
+                        64 => {
+                            configs.data_type = DataType::F64;
+                        }
+                        _ => {
+                            return Err(Error::new(
+                                ErrorKind::InvalidData,
+                                "The raster was not read correctly",
+                            ))
+                        }
                     }
                 }
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "The raster was not read correctly",
+                    ))
+                }
+            }
+        }
+        IM_PALETTED => {
+            //ImageMode::Paletted => {
+            configs.photometric_interp = PhotometricInterpretation::Categorical;
+            configs.data_type = DataType::U8;
+        }
+        IM_RGB => {
+            configs.photometric_interp = PhotometricInterpretation::RGB;
+            if bits_per_sample[0] == 8 {
+                configs.data_type = DataType::U8;
+            } else if bits_per_sample[0] == 16 {
+                configs.data_type = DataType::U16;
+            } else {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "The raster was not read correctly",
+                ));
+            }
+        }
+        IM_NRGBA | IM_RGBA => {
+            // if bits_per_sample[0] == 8 {
+            //     configs.data_type = DataType::U32;
+            // } else if bits_per_sample[0] == 16 {
+            //     configs.data_type = DataType::U64;
+            // } else {
+            //     return Err(Error::new(
+            //         ErrorKind::InvalidData,
+            //         "The raster was not read correctly",
+            //     ));
+            // }
+            if bits_per_sample[0] == 8 && bits_per_sample.len() == 4 {
+                configs.data_type = DataType::RGBA32;
+            } else if bits_per_sample[0] == 8 && bits_per_sample.len() == 3 {
+                configs.data_type = DataType::RGB24;
+            } else if bits_per_sample[0] == 16 {
+                configs.data_type = DataType::U16;
+            } else {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "The raster was not read correctly",
+                ));
             }
+        }
+        _ => {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "The raster was not read correctly",
+            ))
+        }
+    }
+
+    // Check to see if a predictor is used with LZW and DEFLATE
+    let predictor = match ifd_map.get(&TAG_PREDICTOR) {
+        Some(ifd) => ifd.interpret_as_u16()[0],
+        _ => 1u16,
+    };
+    if predictor > 3 || (predictor > 1 && bits_per_sample.iter().any(|&b| b % 8 != 0)) {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "The GeoTIFF reader does not support this predictor (PREDICTOR={}).",
+                predictor
+            ),
+        ));
+    }
+
+    let mut map_sorter = vec![];
+    for (key, _) in ifd_map.iter() {
+        map_sorter.push(key);
+    }
+    map_sorter.sort();
+
+    map_sorter.clear();
+    for (key, _) in geokeys_map.iter() {
+        map_sorter.push(key);
+    }
 
-            // println!("{:?}", &buf[0..8]);
-            let mut bor = ByteOrderReader::<Cursor<Vec<u8>>>::new(Cursor::new(buf), configs.endian);
+    Ok(GeoTiffLayout {
+        compression,
+        predictor,
+        mode,
+        palette,
+        bits_per_sample,
+        sample_format,
+        num_bands,
+        num_planes,
+        block_padding,
+        block_width,
+        block_height,
+        blocks_across,
+        blocks_down,
+        block_offsets,
+        block_counts,
+    })
+}
 
-            let xmin = i * block_width;
-            let ymin = j * block_height;
-            let mut xmax = xmin + blk_w;
-            let mut ymax = ymin + blk_h;
+pub fn read_geotiff<'a>(
+    file_name: &'a String,
+    configs: &'a mut RasterConfigs,
+    data: &'a mut RasterData,
+    band_data: &'a mut Vec<RasterData>,
+) -> Result<(), Error> {
+    let f = File::open(file_name.clone())?;
+    let br = BufReader::new(f);
+    let mut th = ByteOrderReader::<BufReader<File>>::new(br, configs.endian);
+    read_tiff(&mut th, configs, data, band_data, true)
+}
 
-            xmax = min(xmax, width);
-            ymax = min(ymax, height);
+/// Reads a TIFF file held in memory, e.g. a tile stored within a database. The image need
+/// not be georeferenced.
+pub(crate) fn read_geotiff_from_bytes(
+    bytes: Vec<u8>,
+    configs: &mut RasterConfigs,
+    data: &mut RasterData,
+    band_data: &mut Vec<RasterData>,
+) -> Result<(), Error> {
+    let mut th = ByteOrderReader::<Cursor<Vec<u8>>>::new(Cursor::new(bytes), configs.endian);
+    read_tiff(&mut th, configs, data, band_data, false)
+}
 
-            let skip_bytes = if xmin + blk_w > width {
-                xmin + blk_w - width
-            } else {
-                0
-            };
+fn read_tiff<R: Read + Seek>(
+    th: &mut ByteOrderReader<R>,
+    configs: &mut RasterConfigs,
+    data: &mut RasterData,
+    band_data: &mut Vec<RasterData>,
+    georeferenced: bool,
+) -> Result<(), Error> {
+    let layout = read_tiff_layout(th, configs, georeferenced)?;
+    let encoding = layout.block_encoding(configs.endian);
+    let row_length = layout.block_row_length();
+    let GeoTiffLayout {
+        mode,
+        palette,
+        bits_per_sample,
+        sample_format,
+        num_bands,
+        num_planes,
+        block_padding,
+        block_width,
+        block_height,
+        blocks_across,
+        blocks_down,
+        block_offsets,
+        block_counts,
+        ..
+    } = layout;
 
-            let mut off = 0;
-            let mut i: usize;
-            let (mut red, mut green, mut blue): (u32, u32, u32);
-            if n != 0 {
-                match mode {
-                    IM_GRAYINVERT | IM_GRAY => {
-                        match sample_format[0] {
-                            1 => {
-                                // unsigned integer
-                                match bits_per_sample[0] {
-                                    8 => {
-                                        for y in ymin..ymax {
-                                            for x in xmin..xmax {
-                                                if off <= bor.len() {
-                                                    i = y * width + x;
-                                                    data[i] = bor.read_u8()? as f64;
-                                                    off += 1;
+    let width = configs.columns;
+    let height = configs.rows;
+    th.set_byte_order(configs.endian);
+
+    ////////////////////
+    // Read the data! //
+    ////////////////////
+    // The values are decoded straight into storage of the raster's data type.
+    let num_cells = configs.rows * configs.columns;
+    *data = RasterData::new(configs.data_type, configs.nodata, num_cells, configs.nodata);
+    band_data.clear();
+    for _ in 1..num_bands {
+        band_data.push(RasterData::new(
+            configs.data_type,
+            configs.nodata,
+            num_cells,
+            configs.nodata,
+        ));
+    }
+
+    for plane in 0..num_planes {
+        for i in 0..blocks_across {
+            let mut blk_w = block_width;
+            if !block_padding && i == blocks_across - 1 && !width.is_multiple_of(block_width) {
+                blk_w = width % block_width;
+            }
+            for j in 0..blocks_down {
+                let mut blk_h = block_height;
+                if !block_padding && j == blocks_down - 1 && !height.is_multiple_of(block_height) {
+                    blk_h = height % block_height;
+                }
+                let block_num = plane * blocks_across * blocks_down + j * blocks_across + i;
+                let offset = block_offsets[block_num] as usize;
+                let n = block_counts[block_num] as usize;
+                let mut buf: Vec<u8> = vec![];
+                if n != 0 {
+                    // it's not a sparse tile
+                    let mut compressed = vec![0u8; n];
+                    th.seek(offset);
+                    th.read_exact(&mut compressed)?;
+                    let max_uncompressed_length = block_width * block_height * bits_per_sample.len() * bits_per_sample[0] as usize / 8;
+                    buf = decompress_block(
+                        compressed,
+                        &encoding,
+                        row_length,
+                        max_uncompressed_length,
+                    )?;
+                }
+
+                // println!("{:?}", &buf[0..8]);
+                let mut bor = ByteOrderReader::<Cursor<Vec<u8>>>::new(Cursor::new(buf), configs.endian);
+
+                let xmin = i * block_width;
+                let ymin = j * block_height;
+                let mut xmax = xmin + blk_w;
+                let mut ymax = ymin + blk_h;
+
+                xmax = min(xmax, width);
+                ymax = min(ymax, height);
+
+                let skip_bytes = if xmin + blk_w > width {
+                    xmin + blk_w - width
+                } else {
+                    0
+                };
+
+                let mut off = 0;
+                let mut i: usize;
+                let (mut red, mut green, mut blue): (u32, u32, u32);
+                if n != 0 && num_bands > 1 && num_planes == 1 {
+                    // chunky configuration; the samples of each pixel are interleaved
+                    let mut bytes_per_pixel = 0usize;
+                    for b in 0..num_bands {
+                        bytes_per_pixel += bits_per_sample[b.min(bits_per_sample.len() - 1)] as usize / 8;
+                    }
+                    for y in ymin..ymax {
+                        for x in xmin..xmax {
+                            i = y * width + x;
+                            for b in 0..num_bands {
+                                let value = read_sample(
+                                    &mut bor,
+                                    sample_format[b.min(sample_format.len() - 1)],
+                                    bits_per_sample[b.min(bits_per_sample.len() - 1)],
+                                )?;
+                                if b == 0 {
+                                    data.set(i, value);
+                                } else {
+                                    band_data[b - 1].set(i, value);
+                                }
+                            }
+                        }
+                        if skip_bytes > 0 {
+                            bor.inc_pos(skip_bytes * bytes_per_pixel);
+                        }
+                    }
+                } else if n != 0 {
+                    // In a planar configuration, each plane is read into its own band.
+                    let data: &mut RasterData = if plane == 0 {
+                        &mut *data
+                    } else {
+                        &mut band_data[plane - 1]
+                    };
+                    match mode {
+                        IM_GRAYINVERT | IM_GRAY => {
+                            match sample_format[0] {
+                                1 => {
+                                    // unsigned integer
+                                    match bits_per_sample[0] {
+                                        8 => {
+                                            for y in ymin..ymax {
+                                                for x in xmin..xmax {
+                                                    if off <= bor.len() {
+                                                        i = y * width + x;
+                                                        data.set(i, bor.read_u8()? as f64);
+                                                        off += 1;
+                                                    }
+                                                }
+                                                if skip_bytes > 0 {
+                                                    bor.inc_pos(skip_bytes);
                                                 }
-                                            }
-                                            if skip_bytes > 0 {
-                                                bor.inc_pos(skip_bytes);
                                             }
                                         }
-                                    }
-                                    16 => {
-                                        for y in ymin..ymax {
-                                            for x in xmin..xmax {
-                                                if off <= bor.len() {
-                                                    i = y * width + x;
-                                                    data[i] = bor.read_u16()? as f64;
-                                                    off += 2;
+                                        16 => {
+                                            for y in ymin..ymax {
+                                                for x in xmin..xmax {
+                                                    if off <= bor.len() {
+                                                        i = y * width + x;
+                                                        data.set(i, bor.read_u16()? as f64);
+                                                        off += 2;
+                                                    }
+                                                }
+                                                if skip_bytes > 0 {
+                                                    bor.inc_pos(skip_bytes * 2);
                                                 }
-                                            }
-                                            if skip_bytes > 0 {
-                                                bor.inc_pos(skip_bytes * 2);
                                             }
                                         }
-                                    }
-                                    32 => {
-                                        for y in ymin..ymax {
-                                            for x in xmin..xmax {
-                                                if off <= bor.len() {
-                                                    i = y * width + x;
-                                                    data[i] = bor.read_u32()? as f64;
-                                                    off += 4;
+                                        32 => {
+                                            for y in ymin..ymax {
+                                                for x in xmin..xmax {
+                                                    if off <= bor.len() {
+                                                        i = y * width + x;
+                                                        data.set(i, bor.read_u32()? as f64);
+                                                        off += 4;
+                                                    }
+                                                }
+                                                if skip_bytes > 0 {
+                                                    bor.inc_pos(skip_bytes * 4);
                                                 }
-                                            }
-                                            if skip_bytes > 0 {
-                                                bor.inc_pos(skip_bytes * 4);
                                             }
                                         }
-                                    }
-                                    64 => {
-                                        for y in ymin..ymax {
-                                            for x in xmin..xmax {
-                                                if off <= bor.len() {
-                                                    i = y * width + x;
-                                                    data[i] = bor.read_u64()? as f64;
-                                                    off += 8;
+                                        64 => {
+                                            for y in ymin..ymax {
+                                                for x in xmin..xmax {
+                                                    if off <= bor.len() {
+                                                        i = y * width + x;
+                                                        data.set(i, bor.read_u64()? as f64);
+                                                        off += 8;
+                                                    }
+                                                }
+                                                if skip_bytes > 0 {
+                                                    bor.inc_pos(skip_bytes * 8);
                                                 }
-                                            }
-                                            if skip_bytes > 0 {
-                                                bor.inc_pos(skip_bytes * 8);
                                             }
                                         }
-                                    }
-                                    _ => {
-                                        return Err(Error::new(
-                                            ErrorKind::InvalidData,
-                                            "The raster was not read correctly",
-                                        ))
+                                        _ => {
+                                            return Err(Error::new(
+                                                ErrorKind::InvalidData,
+                                                "The raster was not read correctly",
+                                            ))
+                                        }
                                     }
                                 }
-                            }
-                            2 => {
-                                // signed integer
-                                match bits_per_sample[0] {
-                                    8 => {
-                                        for y in ymin..ymax {
-                                            for x in xmin..xmax {
-                                                if off <= bor.len() {
-                                                    i = y * width + x;
-                                                    data[i] = bor.read_i8()? as f64;
-                                                    off += 1;
+                                2 => {
+                                    // signed integer
+                                    match bits_per_sample[0] {
+                                        8 => {
+                                            for y in ymin..ymax {
+                                                for x in xmin..xmax {
+                                                    if off <= bor.len() {
+                                                        i = y * width + x;
+                                                        data.set(i, bor.read_i8()? as f64);
+                                                        off += 1;
+                                                    }
+                                                }
+                                                if skip_bytes > 0 {
+                                                    bor.inc_pos(skip_bytes);
                                                 }
-                                            }
-                                            if skip_bytes > 0 {
-                                                bor.inc_pos(skip_bytes);
                                             }
                                         }
-                                    }
-                                    16 => {
-                                        for y in ymin..ymax {
-                                            for x in xmin..xmax {
-                                                if off <= bor.len() {
-                                                    i = y * width + x;
-                                                    data[i] = bor.read_i16()? as f64;
-                                                    off += 2;
+                                        16 => {
+                                            for y in ymin..ymax {
+                                                for x in xmin..xmax {
+                                                    if off <= bor.len() {
+                                                        i = y * width + x;
+                                                        data.set(i, bor.read_i16()? as f64);
+                                                        off += 2;
+                                                    }
+                                                }
+                                                if skip_bytes > 0 {
+                                                    bor.inc_pos(skip_bytes * 2);
                                                 }
-                                            }
-                                            if skip_bytes > 0 {
-                                                bor.inc_pos(skip_bytes * 2);
                                             }
                                         }
-                                    }
-                                    32 => {
-                                        for y in ymin..ymax {
-                                            for x in xmin..xmax {
-                                                if off <= bor.len() {
-                                                    i = y * width + x;
-                                                    data[i] = bor.read_i32()? as f64;
-                                                    off += 4;
+                                        32 => {
+                                            for y in ymin..ymax {
+                                                for x in xmin..xmax {
+                                                    if off <= bor.len() {
+                                                        i = y * width + x;
+                                                        data.set(i, bor.read_i32()? as f64);
+                                                        off += 4;
+                                                    }
+                                                }
+                                                if skip_bytes > 0 {
+                                                    bor.inc_pos(skip_bytes * 4);
                                                 }
-                                            }
-                                            if skip_bytes > 0 {
-                                                bor.inc_pos(skip_bytes * 4);
                                             }
                                         }
-                                    }
-                                    64 => {
-                                        for y in ymin..ymax {
-                                            for x in xmin..xmax {
-                                                if off <= bor.len() {
-                                                    i = y * width + x;
-                                                    data[i] = bor.read_i64()? as f64;
-                                                    off += 8;
+                                        64 => {
+                                            for y in ymin..ymax {
+                                                for x in xmin..xmax {
+                                                    if off <= bor.len() {
+                                                        i = y * width + x;
+                                                        data.set(i, bor.read_i64()? as f64);
+                                                        off += 8;
+                                                    }
+                                                }
+                                                if skip_bytes > 0 {
+                                                    bor.inc_pos(skip_bytes * 8);
                                                 }
-                                            }
-                                            if skip_bytes > 0 {
-                                                bor.inc_pos(skip_bytes * 8);
                                             }
                                         }
-                                    }
-                                    _ => {
-                                        return Err(Error::new(
-                                            ErrorKind::InvalidData,
-                                            "The raster was not read correctly",
-                                        ))
-                                    }
-                                }
-                            }
-                            3 => {
-                                // floating point
-                                match bits_per_sample[0] {
-                                    32 => {
-                                        for y in ymin..ymax {
-                                            for x in xmin..xmax {
-                                                i = y * width + x;
-                                                data[i] = bor.read_f32()? as f64;
-                                                off += 4;
-                                            }
-                                            if skip_bytes > 0 {
-                                                bor.inc_pos(skip_bytes * 4);
-                                            }
+                                        _ => {
+                                            return Err(Error::new(
+                                                ErrorKind::InvalidData,
+                                                "The raster was not read correctly",
+                                            ))
                                         }
                                     }
-                                    64 => {
-                                        for y in ymin..ymax {
-                                            for x in xmin..xmax {
-                                                if off <= bor.len() {
+                                }
+                                3 => {
+                                    // floating point
+                                    match bits_per_sample[0] {
+                                        32 => {
+                                            for y in ymin..ymax {
+                                                for x in xmin..xmax {
                                                     i = y * width + x;
-                                                    data[i] = bor.read_f64()?;
-                                                    off += 8;
+                                                    data.set(i, bor.read_f32()? as f64);
+                                                    off += 4;
+                                                }
+                                                if skip_bytes > 0 {
+                                                    bor.inc_pos(skip_bytes * 4);
                                                 }
                                             }
-                                            if skip_bytes > 0 {
-                                                bor.inc_pos(skip_bytes * 8);
+                                        }
+                                        64 => {
+                                            for y in ymin..ymax {
+                                                for x in xmin..xmax {
+                                                    if off <= bor.len() {
+                                                        i = y * width + x;
+                                                        data.set(i, bor.read_f64()?);
+                                                        off += 8;
+                                                    }
+                                                }
+                                                if skip_bytes > 0 {
+                                                    bor.inc_pos(skip_bytes * 8);
+                                                }
                                             }
                                         }
-                                    }
-                                    _ => {
-                                        return Err(Error::new(
-                                            ErrorKind::InvalidData,
-                                            "The raster was not read correctly",
-                                        ))
+                                        _ => {
+                                            return Err(Error::new(
+                                                ErrorKind::InvalidData,
+                                                "The raster was not read correctly",
+                                            ))
+                                        }
                                     }
                                 }
-                            }
-                            _ => {
-                                return Err(Error::new(
-                                    ErrorKind::InvalidData,
-                                    "The raster was not read correctly",
-                                ))
-                            }
-                        }
-                    }
-                    IM_PALETTED => {
-                        let mut value: usize;
-                        for y in ymin..ymax {
-                            for x in xmin..xmax {
-                                i = y * width + x;
-                                value = bor.read_u8()? as usize;
-                                data[i] = palette[value] as f64;
+                                _ => {
+                                    return Err(Error::new(
+                                        ErrorKind::InvalidData,
+                                        "The raster was not read correctly",
+                                    ))
+                                }
                             }
                         }
-                    }
-                    IM_RGB => {
-                        let mut value: u32;
-                        let mut a: u32;
-                        if bits_per_sample[0] == 8 {
+                        IM_PALETTED => {
+                            let mut value: usize;
                             for y in ymin..ymax {
                                 for x in xmin..xmax {
-                                    red = bor.read_u8()? as u32; //uint32(g.buf[g.off]);
-                                    green = bor.read_u8()? as u32; //uint32(g.buf[g.off+1]);
-                                    blue = bor.read_u8()? as u32; //uint32(g.buf[g.off+2]);
-                                    a = 255u32;
-                                    value = (a << 24) | (blue << 16) | (green << 8) | red;
                                     i = y * width + x;
-                                    data[i] = value as f64;
+                                    value = bor.read_u8()? as usize;
+                                    data.set(i, palette[value] as f64);
                                 }
                             }
-                        } else if bits_per_sample[0] == 16 {
-                            // the spec doesn't talk about 16-bit RGB images so
-                            // I'm not sure why I bother with this. They specifically
-                            // say that RGB images are 8-bits per channel. Anyhow,
-                            // I rescale the 16-bits to an 8-bit channel for simplicity.
+                        }
+                        IM_RGB => {
                             let mut value: u32;
                             let mut a: u32;
-                            for y in ymin..ymax {
-                                for x in xmin..xmax {
-                                    red = (bor.read_u16()? as f64 / 65535f64 * 255f64) as u32;
-                                    green = (bor.read_u16()? as f64 / 65535f64 * 255f64) as u32;
-                                    blue = (bor.read_u16()? as f64 / 65535f64 * 255f64) as u32;
-                                    a = 255u32;
-                                    value = (a << 24) | (blue << 16) | (green << 8) | red;
-                                    i = y * width + x;
-                                    data[i] = value as f64;
+                            if bits_per_sample[0] == 8 {
+                                for y in ymin..ymax {
+                                    for x in xmin..xmax {
+                                        red = bor.read_u8()? as u32; //uint32(g.buf[g.off]);
+                                        green = bor.read_u8()? as u32; //uint32(g.buf[g.off+1]);
+                                        blue = bor.read_u8()? as u32; //uint32(g.buf[g.off+2]);
+                                        a = 255u32;
+                                        value = (a << 24) | (blue << 16) | (green << 8) | red;
+                                        i = y * width + x;
+                                        data.set(i, value as f64);
+                                    }
                                 }
-                            }
-                        } else {
-                            return Err(Error::new(
-                                ErrorKind::InvalidData,
-                                "The raster was not read correctly",
-                            ));
-                        }
-                    }
-                    IM_NRGBA | IM_RGBA => {
-                        let mut value: u32;
-                        let mut a: u32;
-                        if bits_per_sample[0] == 8 {
-                            for y in ymin..ymax {
-                                for x in xmin..xmax {
-                                    red = bor.read_u8()? as u32; //uint32(g.buf[g.off]);
-                                    green = bor.read_u8()? as u32; //uint32(g.buf[g.off+1]);
-                                    blue = bor.read_u8()? as u32; //uint32(g.buf[g.off+2]);
-                                    a = bor.read_u8()? as u32;
-                                    value = (a << 24) | (blue << 16) | (green << 8) | red;
-                                    i = y * width + x;
-                                    data[i] = value as f64;
+                            } else if bits_per_sample[0] == 16 {
+                                // the spec doesn't talk about 16-bit RGB images so
+                                // I'm not sure why I bother with this. They specifically
+                                // say that RGB images are 8-bits per channel. Anyhow,
+                                // I rescale the 16-bits to an 8-bit channel for simplicity.
+                                let mut value: u32;
+                                let mut a: u32;
+                                for y in ymin..ymax {
+                                    for x in xmin..xmax {
+                                        red = (bor.read_u16()? as f64 / 65535f64 * 255f64) as u32;
+                                        green = (bor.read_u16()? as f64 / 65535f64 * 255f64) as u32;
+                                        blue = (bor.read_u16()? as f64 / 65535f64 * 255f64) as u32;
+                                        a = 255u32;
+                                        value = (a << 24) | (blue << 16) | (green << 8) | red;
+                                        i = y * width + x;
+                                        data.set(i, value as f64);
+                                    }
                                 }
+                            } else {
+                                return Err(Error::new(
+                                    ErrorKind::InvalidData,
+                                    "The raster was not read correctly",
+                                ));
                             }
-                        } else if bits_per_sample[0] == 16 {
-                            // the spec doesn't talk about 16-bit RGB images so
-                            // I'm not sure why I bother with this. They specifically
-                            // say that RGB images are 8-bits per channel. Anyhow,
-                            // I rescale the 16-bits to an 8-bit channel for simplicity.
+                        }
+                        IM_NRGBA | IM_RGBA => {
                             let mut value: u32;
                             let mut a: u32;
-                            for y in ymin..ymax {
-                                for x in xmin..xmax {
-                                    red = (bor.read_u16()? as f64 / 65535f64 * 255f64) as u32;
-                                    green = (bor.read_u16()? as f64 / 65535f64 * 255f64) as u32;
-                                    blue = (bor.read_u16()? as f64 / 65535f64 * 255f64) as u32;
-                                    a = (bor.read_u16()? as f64 / 65535f64 * 255f64) as u32;
-                                    value = (a << 24) | (blue << 16) | (green << 8) | red;
-                                    i = y * width + x;
-                                    data[i] = value as f64;
+                            if bits_per_sample[0] == 8 {
+                                for y in ymin..ymax {
+                                    for x in xmin..xmax {
+                                        red = bor.read_u8()? as u32; //uint32(g.buf[g.off]);
+                                        green = bor.read_u8()? as u32; //uint32(g.buf[g.off+1]);
+                                        blue = bor.read_u8()? as u32; //uint32(g.buf[g.off+2]);
+                                        a = bor.read_u8()? as u32;
+                                        value = (a << 24) | (blue << 16) | (green << 8) | red;
+                                        i = y * width + x;
+                                        data.set(i, value as f64);
+                                    }
                                 }
-                            }
-                        } else {
-                            return Err(Error::new(
-                                ErrorKind::InvalidData,
-                                "The raster was not read correctly",
-                            ));
-                        }
-                    }
-                    _ => {
-                        return Err(Error::new(
-                            ErrorKind::InvalidData,
-                            "The raster was not read correctly",
-                        ))
-                    }
-                }
-            } else {
-                // GDAL supports sparse tiles. That is, if the block count is zero,
-                // instead of reading the block, simply assume it is filled with either
-                // nodata, if the value is defined, or zeros otherwise.
-                for y in ymin..ymax {
-                    for x in xmin..xmax {
-                        i = y * width + x;
-                        data[i] = configs.nodata;
-                    }
-                }
-            }
-
-            match mode {
-                IM_GRAYINVERT | IM_GRAY => {
-                    //ImageMode::GrayInvert | ImageMode::Gray => {
-                    configs.photometric_interp = PhotometricInterpretation::Continuous;
-                    match sample_format[0] {
-                        1 => {
-                            // unsigned integer
-                            match bits_per_sample[0] {
-                                8 => {
-                                    configs.data_type = DataType::U8;
-                                }
-                                16 => {
-                                    configs.data_type = DataType::U16;
-                                }
-                                32 => {
-                                    configs.data_type = DataType::U32;
-                                }
-                                64 => {
-                                    configs.data_type = DataType::U64;
-                                }
-                                _ => {
-                                    return Err(Error::new(
-                                        ErrorKind::InvalidData,
-                                        "The raster was not read correctly",
-                                    ))
-                                }
-                            }
-                        }
-                        2 => {
-                            // signed integer
-                            match bits_per_sample[0] {
-                                8 => {
-                                    configs.data_type = DataType::I8;
-                                }
-                                16 => {
-                                    configs.data_type = DataType::I16;
-                                }
-                                32 => {
-                                    configs.data_type = DataType::I32;
-                                }
-                                64 => {
-                                    configs.data_type = DataType::I64;
-                                }
-                                _ => {
-                                    return Err(Error::new(
-                                        ErrorKind::InvalidData,
-                                        "The raster was not read correctly",
-                                    ))
-                                }
-                            }
-                        }
-                        3 => {
-                            // floating point
-                            match bits_per_sample[0] {
-                                32 => {
-                                    configs.data_type = DataType::F32;
-                                }
-                                64 => {
-                                    configs.data_type = DataType::F64;
-                                }
-                                _ => {
-                                    return Err(Error::new(
-                                        ErrorKind::InvalidData,
-                                        "The raster was not read correctly",
-                                    ))
+                            } else if bits_per_sample[0] == 16 {
+                                // the spec doesn't talk about 16-bit RGB images so
+                                // I'm not sure why I bother with this. They specifically
+                                // say that RGB images are 8-bits per channel. Anyhow,
+                                // I rescale the 16-bits to an 8-bit channel for simplicity.
+                                let mut value: u32;
+                                let mut a: u32;
+                                for y in ymin..ymax {
+                                    for x in xmin..xmax {
+                                        red = (bor.read_u16()? as f64 / 65535f64 * 255f64) as u32;
+                                        green = (bor.read_u16()? as f64 / 65535f64 * 255f64) as u32;
+                                        blue = (bor.read_u16()? as f64 / 65535f64 * 255f64) as u32;
+                                        a = (bor.read_u16()? as f64 / 65535f64 * 255f64) as u32;
+                                        value = (a << 24) | (blue << 16) | (green << 8) | red;
+                                        i = y * width + x;
+                                        data.set(i, value as f64);
+                                    }
                                 }
+                            } else {
+                                return Err(Error::new(
+                                    ErrorKind::InvalidData,
+                                    "The raster was not read correctly",
+                                ));
                             }
                         }
                         _ => {
@@ -1548,112 +1779,20 @@ pub fn read_geotiff<'a>(
                             ))
                         }
                     }
-                }
-                IM_PALETTED => {
-                    //ImageMode::Paletted => {
-                    configs.photometric_interp = PhotometricInterpretation::Categorical;
-                    configs.data_type = DataType::U8;
-                }
-                IM_RGB => {
-                    configs.photometric_interp = PhotometricInterpretation::RGB;
-                    if bits_per_sample[0] == 8 {
-                        configs.data_type = DataType::U8;
-                    } else if bits_per_sample[0] == 16 {
-                        configs.data_type = DataType::U16;
-                    } else {
-                        return Err(Error::new(
-                            ErrorKind::InvalidData,
-                            "The raster was not read correctly",
-                        ));
-                    }
-                }
-                IM_NRGBA | IM_RGBA => {
-                    // if bits_per_sample[0] == 8 {
-                    //     configs.data_type = DataType::U32;
-                    // } else if bits_per_sample[0] == 16 {
-                    //     configs.data_type = DataType::U64;
-                    // } else {
-                    //     return Err(Error::new(
-                    //         ErrorKind::InvalidData,
-                    //         "The raster was not read correctly",
-                    //     ));
-                    // }
-                    if bits_per_sample[0] == 8 && bits_per_sample.len() == 4 {
-                        configs.data_type = DataType::RGBA32;
-                    } else if bits_per_sample[0] == 8 && bits_per_sample.len() == 3 {
-                        configs.data_type = DataType::RGB24;
-                    } else if bits_per_sample[0] == 16 {
-                        configs.data_type = DataType::U16;
-                    } else {
-                        return Err(Error::new(
-                            ErrorKind::InvalidData,
-                            "The raster was not read correctly",
-                        ));
-                    }
-                }
-                _ => {
-                    return Err(Error::new(
-                        ErrorKind::InvalidData,
-                        "The raster was not read correctly",
-                    ))
-                }
-            }
-        }
-    }
-
-    // Check to see if a predictor is used with LZW and DEFLATE
-    match ifd_map.get(&317) {
-        Some(ifd) => {
-            if ifd.interpret_as_u16()[0] == 2 {
-                // Horizontal predictor
-                // transform the data
-                let mut idx: usize;
-                for row in 0..configs.rows {
-                    for col in 1..configs.columns {
-                        idx = row * configs.columns + col;
-                        data[idx] += data[idx - 1];
+                } else if plane == 0 {
+                    // GDAL supports sparse tiles. That is, if the block count is zero,
+                    // instead of reading the block, simply assume it is filled with either
+                    // nodata, if the value is defined, or zeros otherwise. Any additional
+                    // bands are already initialized to nodata.
+                    for y in ymin..ymax {
+                        for x in xmin..xmax {
+                            i = y * width + x;
+                            data.set(i, configs.nodata);
+                        }
                     }
                 }
             }
-            // if ifd.interpret_as_u16()[0] == 3 {
-            //     if configs.endian == Endianness::LittleEndian {
-            //         if configs.data_type == DataType::F32 {
-            //             let mut in_val: f32;
-            //             let mut out_val: f32;
-            //             let mut in_bytes: [u8; 4];
-            //             let mut out_bytes = [0u8; 4];
-            //             let mut idx: usize;
-            //             for row in 0..configs.rows {
-            //                 for col in 1..configs.columns {
-            //                     idx = row * configs.columns + col;
-            //                     in_val = data[idx] as f32;
-            //                     in_bytes = in_val.to_le_bytes();
-            //                     for b in 0..4 {
-            //                         out_bytes[4-b-1] = in_bytes[b];
-            //                     }
-            //                     out_val = f32::from_le_bytes(out_bytes);
-            //                     data[idx] = out_val as f64;
-            //                 }
-            //             }
-            //         } else { // F64
-
-            //         }
-            //     } else {
-            //         if configs.data_type == DataType::F32 {
-
-            //         } else { // F64
-
-            //         }
-            //     }
-
-            if ifd.interpret_as_u16()[0] == 3 {
-                return Err(Error::new(
-                    ErrorKind::InvalidData,
-                    "The GeoTIFF reader does not currently support floating-point predictors (PREDICTOR=3).",
-                ));
-            }
         }
-        _ => {} // do nothing,
     }
 
     // match geokeys_map.get(&1024) {
@@ -1661,26 +1800,49 @@ pub fn read_geotiff<'a>(
     //     _ => return Err(Error::new(ErrorKind::InvalidData, "The TIFF file does not contain geokeys")),
     // };
 
-    let mut map_sorter = vec![];
-    for (key, _) in ifd_map.iter() {
-        map_sorter.push(key);
-    }
-    map_sorter.sort();
-
-    map_sorter.clear();
-    for (key, _) in geokeys_map.iter() {
-        map_sorter.push(key);
-    }
+    configs.bands = num_bands as u8;
+    configs.band_nodata.clear();
+    configs.band_sequential = num_planes > 1;
 
     Ok(())
 }
 
+// The GDAL_METADATA XML that holds a provenance record, as a PROVENANCE item.
+fn gdal_metadata_xml(provenance: &Provenance) -> String {
+    let json = provenance
+        .to_json()
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;");
+    format!(
+        "<GDALMetadata><Item name=\"PROVENANCE\">{}</Item></GDALMetadata>",
+        json
+    )
+}
+
+// Reads the provenance record from GDAL_METADATA XML, if it holds one.
+fn read_gdal_metadata_provenance(xml: &str) -> Option<Provenance> {
+    let start = xml.find("<Item name=\"PROVENANCE\">")? + "<Item name=\"PROVENANCE\">".len();
+    let end = start + xml[start..].find("</Item>")?;
+    let json = xml[start..end]
+        .replace("&quot;", "\"")
+        .replace("&gt;", ">")
+        .replace("&lt;", "<")
+        .replace("&amp;", "&");
+    Provenance::from_json(&json).ok()
+}
+
 pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
     // We'll need to look at the configuations to see if compression should be used
     let configs = whitebox_common::configs::get_configs()?;
-    let use_compression = configs.compress_rasters;
+    let options = GeoTiffOptions::from_configs(&configs)?;
+    if options.tile_size > 0 && r.configs.data_type != DataType::RGB48 {
+        return write_tiled_geotiff(r, &options);
+    }
+    let encoding = BlockEncoding::new(r, options.compression, options.predictor);
+    let use_compression = encoding.compression != COMPRESS_NONE;
 
-    
     // get the ByteOrderWriter
     let f = File::create(r.file_name.clone())?;
     let mut writer = BufWriter::new(f);
@@ -1699,9 +1861,18 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         ));
     }
 
+    // Multiband rasters are written as greyscale images with one sample per band. Packed
+    // colour (RGB) rasters are always written as a single band.
+    let num_bands = r.num_bands();
+    let is_multiband = num_bands > 1
+        && r.configs.data_type != DataType::RGB24
+        && r.configs.data_type != DataType::RGBA32
+        && r.configs.data_type != DataType::RGB48;
+    let num_samples = if is_multiband { num_bands } else { 1 };
+
     // is it a BigTiff?
     let is_big_tiff = if 8usize
-        + (r.configs.rows * r.configs.columns) as usize * total_bytes_per_pixel
+        + (r.configs.rows * r.configs.columns) as usize * total_bytes_per_pixel * num_samples
         >= 4_000_000_000
     {
         true
@@ -1713,7 +1884,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
 
     // get the offset to the first ifd
     let mut ifd_start_needs_extra_byte = false;
-    let mut ifd_start = if !use_compression {
+    let mut ifd_start = if !use_compression && !is_multiband {
         let mut val = header_size
             + (r.configs.rows * r.configs.columns) as u64 * total_bytes_per_pixel as u64;
         if val % 2 == 1 {
@@ -1764,7 +1935,44 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
     let mut strip_offsets = vec![];
     let mut strip_byte_counts = vec![];
     let mut current_offset = header_size;
-    if use_compression {
+    if is_multiband {
+        // Each strip contains a single row. In the chunky configuration, a strip holds the
+        // samples of all bands, interleaved by pixel. In the planar configuration, a strip
+        // holds the samples of one band, and the strips of each band follow one another.
+        let band_values: Vec<&RasterData> = (0..num_bands).map(|b| r.get_band_data(b)).collect();
+        let (num_planes, samples_per_strip) = if r.configs.band_sequential {
+            (num_bands, 1)
+        } else {
+            (1, num_bands)
+        };
+        for plane in 0..num_planes {
+            for row in 0..r.configs.rows {
+                let mut data = Vec::with_capacity(
+                    r.configs.columns * samples_per_strip * total_bytes_per_pixel,
+                );
+                for col in 0..r.configs.columns {
+                    let i = row * r.configs.columns + col;
+                    for values in &band_values[plane..plane + samples_per_strip] {
+                        let value = values.get(i);
+                        write_sample(&mut data, r.configs.data_type, r.configs.endian, value)?;
+                    }
+                }
+                if use_compression {
+                    data = compress_block(&data, &encoding, data.len())?;
+                }
+                write_bytes(&mut writer, &data).expect("Error writing byte data to file.");
+                let strip_length_in_bytes = data.len() as u64;
+                strip_byte_counts.push(strip_length_in_bytes);
+                strip_offsets.push(current_offset);
+                current_offset += strip_length_in_bytes;
+                if !strip_length_in_bytes.is_multiple_of(2) {
+                    // This is just because the data must start on a word (i.e. an even value).
+                    write_u8(&mut writer, 0u8).expect("Error writing data to file.");
+                    current_offset += 1;
+                }
+            }
+        }
+    } else if use_compression {
         // DEFLATE is the only supported compression method at present
 
         // let mut current_offset = header_size;
@@ -1780,18 +1988,18 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                         if r.configs.endian == Endianness::LittleEndian {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_f64::<LittleEndian>(r.data[i] as f64)
+                                data.write_f64::<LittleEndian>(r.data.get(i))
                                     .expect("Error writing byte data.");
                             }
                         } else {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_f64::<BigEndian>(r.data[i] as f64)
+                                data.write_f64::<BigEndian>(r.data.get(i))
                                     .expect("Error writing byte data.");
                             }
                         }
                         // compress the data vec
-                        let compressed = compress_to_vec_zlib(&data, 6);
+                        let compressed = compress_block(&data, &encoding, data.len())?;
                         write_bytes(&mut writer, &compressed)
                             .expect("Error writing byte data to file.");
                         row_length_in_bytes = compressed.len() as u64;
@@ -1812,18 +2020,18 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                         if r.configs.endian == Endianness::LittleEndian {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_f32::<LittleEndian>(r.data[i] as f32)
+                                data.write_f32::<LittleEndian>(r.data.get(i) as f32)
                                     .expect("Error writing byte data.");
                             }
                         } else {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_f32::<BigEndian>(r.data[i] as f32)
+                                data.write_f32::<BigEndian>(r.data.get(i) as f32)
                                     .expect("Error writing byte data.");
                             }
                         }
                         // compress the data vec
-                        let compressed = compress_to_vec_zlib(&data, 6);
+                        let compressed = compress_block(&data, &encoding, data.len())?;
                         write_bytes(&mut writer, &compressed)
                             .expect("Error writing byte data to file.");
                         row_length_in_bytes = compressed.len() as u64;
@@ -1844,18 +2052,18 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                         if r.configs.endian == Endianness::LittleEndian {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_u64::<LittleEndian>(r.data[i] as u64)
+                                data.write_u64::<LittleEndian>(r.data.get(i) as u64)
                                     .expect("Error writing byte data.");
                             }
                         } else {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_u64::<BigEndian>(r.data[i] as u64)
+                                data.write_u64::<BigEndian>(r.data.get(i) as u64)
                                     .expect("Error writing byte data.");
                             }
                         }
                         // compress the data vec
-                        let compressed = compress_to_vec_zlib(&data, 6);
+                        let compressed = compress_block(&data, &encoding, data.len())?;
                         write_bytes(&mut writer, &compressed)
                             .expect("Error writing byte data to file.");
                         row_length_in_bytes = compressed.len() as u64;
@@ -1876,18 +2084,18 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                         if r.configs.endian == Endianness::LittleEndian {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_u32::<LittleEndian>(r.data[i] as u32)
+                                data.write_u32::<LittleEndian>(r.data.get(i) as u32)
                                     .expect("Error writing byte data.");
                             }
                         } else {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_u32::<BigEndian>(r.data[i] as u32)
+                                data.write_u32::<BigEndian>(r.data.get(i) as u32)
                                     .expect("Error writing byte data.");
                             }
                         }
                         // compress the data vec
-                        let compressed = compress_to_vec_zlib(&data, 6);
+                        let compressed = compress_block(&data, &encoding, data.len())?;
                         write_bytes(&mut writer, &compressed)
                             .expect("Error writing byte data to file.");
                         row_length_in_bytes = compressed.len() as u64;
@@ -1908,18 +2116,18 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                         if r.configs.endian == Endianness::LittleEndian {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_u16::<LittleEndian>(r.data[i] as u16)
+                                data.write_u16::<LittleEndian>(r.data.get(i) as u16)
                                     .expect("Error writing byte data.");
                             }
                         } else {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_u16::<BigEndian>(r.data[i] as u16)
+                                data.write_u16::<BigEndian>(r.data.get(i) as u16)
                                     .expect("Error writing byte data.");
                             }
                         }
                         // compress the data vec
-                        let compressed = compress_to_vec_zlib(&data, 6);
+                        let compressed = compress_block(&data, &encoding, data.len())?;
                         write_bytes(&mut writer, &compressed)
                             .expect("Error writing byte data to file.");
                         row_length_in_bytes = compressed.len() as u64;
@@ -1940,18 +2148,18 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                         if r.configs.endian == Endianness::LittleEndian {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_u8(r.data[i] as u8)
+                                data.write_u8(r.data.get(i) as u8)
                                     .expect("Error writing byte data.");
                             }
                         } else {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_u8(r.data[i] as u8)
+                                data.write_u8(r.data.get(i) as u8)
                                     .expect("Error writing byte data.");
                             }
                         }
                         // compress the data vec
-                        let compressed = compress_to_vec_zlib(&data, 6);
+                        let compressed = compress_block(&data, &encoding, data.len())?;
                         write_bytes(&mut writer, &compressed)
                             .expect("Error writing byte data to file.");
                         row_length_in_bytes = compressed.len() as u64;
@@ -1972,18 +2180,18 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                         if r.configs.endian == Endianness::LittleEndian {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_i64::<LittleEndian>(r.data[i] as i64)
+                                data.write_i64::<LittleEndian>(r.data.get(i) as i64)
                                     .expect("Error writing byte data.");
                             }
                         } else {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_i64::<BigEndian>(r.data[i] as i64)
+                                data.write_i64::<BigEndian>(r.data.get(i) as i64)
                                     .expect("Error writing byte data.");
                             }
                         }
                         // compress the data vec
-                        let compressed = compress_to_vec_zlib(&data, 6);
+                        let compressed = compress_block(&data, &encoding, data.len())?;
                         write_bytes(&mut writer, &compressed)
                             .expect("Error writing byte data to file.");
                         row_length_in_bytes = compressed.len() as u64;
@@ -2004,18 +2212,18 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                         if r.configs.endian == Endianness::LittleEndian {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_i32::<LittleEndian>(r.data[i] as i32)
+                                data.write_i32::<LittleEndian>(r.data.get(i) as i32)
                                     .expect("Error writing byte data.");
                             }
                         } else {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_i32::<BigEndian>(r.data[i] as i32)
+                                data.write_i32::<BigEndian>(r.data.get(i) as i32)
                                     .expect("Error writing byte data.");
                             }
                         }
                         // compress the data vec
-                        let compressed = compress_to_vec_zlib(&data, 6);
+                        let compressed = compress_block(&data, &encoding, data.len())?;
                         write_bytes(&mut writer, &compressed)
                             .expect("Error writing byte data to file.");
                         row_length_in_bytes = compressed.len() as u64;
@@ -2036,18 +2244,18 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                         if r.configs.endian == Endianness::LittleEndian {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_i16::<LittleEndian>(r.data[i] as i16)
+                                data.write_i16::<LittleEndian>(r.data.get(i) as i16)
                                     .expect("Error writing byte data.");
                             }
                         } else {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_i16::<BigEndian>(r.data[i] as i16)
+                                data.write_i16::<BigEndian>(r.data.get(i) as i16)
                                     .expect("Error writing byte data.");
                             }
                         }
                         // compress the data vec
-                        let compressed = compress_to_vec_zlib(&data, 6);
+                        let compressed = compress_block(&data, &encoding, data.len())?;
                         write_bytes(&mut writer, &compressed)
                             .expect("Error writing byte data to file.");
                         row_length_in_bytes = compressed.len() as u64;
@@ -2068,18 +2276,18 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                         if r.configs.endian == Endianness::LittleEndian {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_i8(r.data[i] as i8)
+                                data.write_i8(r.data.get(i) as i8)
                                     .expect("Error writing byte data.");
                             }
                         } else {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_i8(r.data[i] as i8)
+                                data.write_i8(r.data.get(i) as i8)
                                     .expect("Error writing byte data.");
                             }
                         }
                         // compress the data vec
-                        let compressed = compress_to_vec_zlib(&data, 6);
+                        let compressed = compress_block(&data, &encoding, data.len())?;
                         write_bytes(&mut writer, &compressed)
                             .expect("Error writing byte data to file.");
                         row_length_in_bytes = compressed.len() as u64;
@@ -2113,7 +2321,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                             let mut data = Vec::with_capacity(r.configs.columns * 3);
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                val = r.data[i] as u32;
+                                val = r.data.get(i) as u32;
                                 data.write_u8((val & 0xFF) as u8)
                                     .expect("Error writing byte data."); // red
 
@@ -2124,7 +2332,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                                     .expect("Error writing byte data."); // blue
                             }
                             // compress the data vec
-                            let compressed = compress_to_vec_zlib(&data, 6);
+                            let compressed = compress_block(&data, &encoding, data.len())?;
                             write_bytes(&mut writer, &compressed)
                                 .expect("Error writing byte data to file.");
                             row_length_in_bytes = compressed.len() as u64;
@@ -2146,7 +2354,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                             let mut data = Vec::with_capacity(r.configs.columns * 4);
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                val = r.data[i] as u32;
+                                val = r.data.get(i) as u32;
                                 data.write_u8((val & 0xFF) as u8)
                                     .expect("Error writing byte data."); // red
 
@@ -2161,7 +2369,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                             }
                             // for col in 0..r.configs.columns {
                             //     i = row * r.configs.columns + col;
-                            //     val = r.data[i] as u32;
+                            //     val = r.data.get(i) as u32;
                             //     bytes[2] = ((val >> 16u32) & 0xFF) as u8; // blue
                             //     bytes[1] = ((val >> 8u32) & 0xFF) as u8; // green
                             //     bytes[0] = (val & 0xFF) as u8; // red
@@ -2170,7 +2378,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                             // }
 
                             // compress the data vec
-                            let compressed = compress_to_vec_zlib(&data, 6);
+                            let compressed = compress_block(&data, &encoding, data.len())?;
                             write_bytes(&mut writer, &compressed)
                                 .expect("Error writing byte data to file.");
                             row_length_in_bytes = compressed.len() as u64;
@@ -2218,7 +2426,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            write_f64(&mut writer, r.configs.endian, r.data[i])?;
+                            write_f64(&mut writer, r.configs.endian, r.data.get(i))?;
                         }
                     }
                 }
@@ -2227,7 +2435,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            write_f32(&mut writer, r.configs.endian, r.data[i] as f32)?;
+                            write_f32(&mut writer, r.configs.endian, r.data.get(i) as f32)?;
                         }
                     }
                 }
@@ -2236,7 +2444,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            write_u64(&mut writer, r.configs.endian, r.data[i] as u64)?;
+                            write_u64(&mut writer, r.configs.endian, r.data.get(i) as u64)?;
                         }
                     }
                 }
@@ -2245,7 +2453,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            write_u32(&mut writer, r.configs.endian, r.data[i] as u32)?;
+                            write_u32(&mut writer, r.configs.endian, r.data.get(i) as u32)?;
                         }
                     }
                 }
@@ -2254,7 +2462,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            write_u16(&mut writer, r.configs.endian, r.data[i] as u16)?;
+                            write_u16(&mut writer, r.configs.endian, r.data.get(i) as u16)?;
                         }
                     }
                 }
@@ -2263,7 +2471,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            write_u8(&mut writer, r.data[i] as u8)?;
+                            write_u8(&mut writer, r.data.get(i) as u8)?;
                         }
                     }
                 }
@@ -2272,7 +2480,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            write_i64(&mut writer, r.configs.endian, r.data[i] as i64)?;
+                            write_i64(&mut writer, r.configs.endian, r.data.get(i) as i64)?;
                         }
                     }
                 }
@@ -2281,7 +2489,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            write_i32(&mut writer, r.configs.endian, r.data[i] as i32)?;
+                            write_i32(&mut writer, r.configs.endian, r.data.get(i) as i32)?;
                         }
                     }
                 }
@@ -2290,7 +2498,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            write_i16(&mut writer, r.configs.endian, r.data[i] as i16)?;
+                            write_i16(&mut writer, r.configs.endian, r.data.get(i) as i16)?;
                         }
                     }
                 }
@@ -2299,7 +2507,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            write_i8(&mut writer, r.data[i] as i8)?;
+                            write_i8(&mut writer, r.data.get(i) as i8)?;
                         }
                     }
                 }
@@ -2321,7 +2529,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                         for row in 0..r.configs.rows {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                let val = r.data[i] as u32;
+                                let val = r.data.get(i) as u32;
                                 bytes[2] = ((val >> 16u32) & 0xFF) as u8; // blue
                                 bytes[1] = ((val >> 8u32) & 0xFF) as u8; // green
                                 bytes[0] = (val & 0xFF) as u8; // red
@@ -2335,7 +2543,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                         for row in 0..r.configs.rows {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                let val = r.data[i] as u32;
+                                let val = r.data.get(i) as u32;
                                 bytes[2] = ((val >> 16u32) & 0xFF) as u8; // blue
                                 bytes[1] = ((val >> 8u32) & 0xFF) as u8; // green
                                 bytes[0] = (val & 0xFF) as u8; // red
@@ -2370,7 +2578,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         }
     }
 
-    if use_compression {
+    if use_compression || is_multiband {
         ifd_start = current_offset; // header_size + strip_byte_counts.iter().sum();
         if ifd_start % 2 == 1 {
             ifd_start += 1;
@@ -2392,6 +2600,62 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         write_u8(&mut writer, 0u8).expect("Error writing byte data.");
     }
 
+    let image = IfdImage {
+        layout: BlockLayout::Strips,
+        block_offsets: strip_offsets,
+        block_byte_counts: strip_byte_counts,
+        encoding,
+        next_ifd: 0,
+        is_overview: false,
+    };
+    write_geotiff_ifd(&mut writer, r, ifd_start, image, is_big_tiff)
+}
+
+/// How the image data described by an IFD are divided into blocks.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum BlockLayout {
+    /// Strips, each a single row high.
+    Strips,
+    /// Square tiles, of the given width and height.
+    Tiles(usize),
+}
+
+/// The properties of a single image within a GeoTIFF file that are recorded in its IFD, in
+/// addition to those held by the configurations of the raster.
+pub(crate) struct IfdImage {
+    pub layout: BlockLayout,
+    /// The offsets and byte counts of each block. For strips, these may be left empty, in which
+    /// case the data are assumed to be uncompressed and to immediately follow the header.
+    pub block_offsets: Vec<u64>,
+    pub block_byte_counts: Vec<u64>,
+    pub encoding: BlockEncoding,
+    /// The offset of the IFD of the following image, or zero if this is the last image.
+    pub next_ifd: u64,
+    /// Reduced-resolution images (i.e. overviews) are flagged as such and carry no
+    /// georeferencing tags.
+    pub is_overview: bool,
+}
+
+/// Writes the IFD of a GeoTIFF image, followed by any tag values that do not fit within the IFD
+/// itself, at the current position of `writer`, which must be `ifd_start`. The length of the
+/// output depends only upon the raster and the number of blocks, and not upon the values of the
+/// block offsets, such that it can be measured before the offsets are known.
+pub(crate) fn write_geotiff_ifd<W: Write>(
+    writer: &mut BufWriter<W>,
+    r: &Raster,
+    ifd_start: u64,
+    image: IfdImage,
+    is_big_tiff: bool,
+) -> Result<(), Error> {
+    let header_size = if !is_big_tiff { 8u64 } else { 16u64 };
+    let total_bytes_per_pixel = r.configs.data_type.get_data_size();
+    let num_bands = r.num_bands();
+    let is_multiband = num_bands > 1
+        && r.configs.data_type != DataType::RGB24
+        && r.configs.data_type != DataType::RGBA32
+        && r.configs.data_type != DataType::RGB48;
+    let has_strip_offsets = !image.block_offsets.is_empty();
+
     ////////////////////////////
     // Create the IFD entries //
     ////////////////////////////
@@ -2461,6 +2725,11 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
     // let mut larger_values_data: Vec<u8> = vec![];
     let mut larger_values_data = ByteOrderWriter::<Vec<u8>>::new(vec![], r.configs.endian);
 
+    if image.is_overview {
+        // NewSubfileType tag (254); a reduced-resolution version of another image
+        ifd_entries.push(Entry::new(TAG_NEWSUBFILETYPE, DT_LONG, 1u64, 1u64));
+    }
+
     /*
     Classic TIFF IFD entries
 
@@ -2509,6 +2778,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
     };
 
     let samples_per_pixel = match r.configs.data_type {
+        _ if is_multiband => num_bands as u16,
         DataType::I8 | DataType::U8 => 1u16,
         DataType::I16 | DataType::U16 => 1u16,
         DataType::I32 | DataType::U32 | DataType::F32 => 1u16,
@@ -2522,7 +2792,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
     };
 
     // BitsPerSample tag (258)
-    if r.configs.photometric_interp != PhotometricInterpretation::Boolean {
+    if r.configs.photometric_interp != PhotometricInterpretation::Boolean || is_multiband {
         if samples_per_pixel == 1 {
             ifd_entries.push(Entry::new(
                 TAG_BITSPERSAMPLE,
@@ -2530,6 +2800,14 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                 samples_per_pixel as u64,
                 bits_per_sample as u64,
             ));
+        } else if samples_per_pixel == 2 {
+            // two equal shorts fit within the value offset
+            ifd_entries.push(Entry::new(
+                TAG_BITSPERSAMPLE,
+                DT_SHORT,
+                2u64,
+                bits_per_sample as u64 | (bits_per_sample as u64) << 16,
+            ));
         } else {
             ifd_entries.push(Entry::new(
                 TAG_BITSPERSAMPLE,
@@ -2544,21 +2822,12 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
     }
 
     // Compression tag (259)
-    if use_compression {
-        ifd_entries.push(Entry::new(
-            TAG_COMPRESSION,
-            DT_SHORT,
-            1u64,
-            COMPRESS_DEFLATE as u64,
-        ));
-    } else {
-        ifd_entries.push(Entry::new(
-            TAG_COMPRESSION,
-            DT_SHORT,
-            1u64,
-            COMPRESS_NONE as u64,
-        ));
-    }
+    ifd_entries.push(Entry::new(
+        TAG_COMPRESSION,
+        DT_SHORT,
+        1u64,
+        image.encoding.compression as u64,
+    ));
 
     // PhotometricInterpretation tag (262)
     let pi = match r.configs.photometric_interp {
@@ -2580,73 +2849,32 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         pi as u64,
     ));
 
-    // StripOffsets tag (273)
-    if !is_big_tiff {
-        ifd_entries.push(Entry::new(
-            TAG_STRIPOFFSETS,
-            DT_LONG,
-            r.configs.rows as u64,
-            larger_values_data.len() as u64,
-        ));
-        if use_compression {
-            for val in strip_offsets {
-                larger_values_data
-                    .write_u32(val as u32)
-                    .expect("Error writing the TIFF strip offsets tag");
-            }
-        } else {
-            let row_length_in_bytes: u32 = r.configs.columns as u32 * total_bytes_per_pixel as u32;
-            for i in 0..r.configs.rows as u32 {
-                larger_values_data
-                    .write_u32(header_size as u32 + row_length_in_bytes * i)
-                    .expect("Error writing the TIFF strip offsets tag");
-            }
-        }
+    // Uncompressed strips that were written without recording their offsets immediately follow
+    // the header, one row after another.
+    let (block_offsets, block_byte_counts) = if has_strip_offsets {
+        (image.block_offsets, image.block_byte_counts)
     } else {
-        ifd_entries.push(Entry::new(
-            TAG_STRIPOFFSETS,
-            DT_TIFF_LONG8,
-            r.configs.rows as u64,
-            larger_values_data.len() as u64,
-        ));
-        if use_compression {
-            for val in strip_offsets {
-                larger_values_data
-                    .write_u64(val)
-                    .expect("Error writing the TIFF strip offsets tag");
-            }
-        } else {
-            let row_length_in_bytes: u64 = r.configs.columns as u64 * total_bytes_per_pixel as u64;
-            for i in 0..r.configs.rows as u64 {
-                larger_values_data
-                    .write_u64(header_size + row_length_in_bytes * i)
-                    .expect("Error writing the TIFF strip offsets");
-            }
-        }
-    }
-    // if !is_big_tiff {
-    //     ifd_entries.push(Entry::new(
-    //         TAG_STRIPOFFSETS,
-    //         DT_LONG,
-    //         r.configs.rows as u64,
-    //         larger_values_data.len() as u64,
-    //     ));
-    //     let row_length_in_bytes: u32 = r.configs.columns as u32 * total_bytes_per_pixel as u32;
-    //     for i in 0..r.configs.rows as u32 {
-    //         larger_values_data.write_u32(8u32 + row_length_in_bytes * i)?;
-    //     }
-    // } else {
-    //     ifd_entries.push(Entry::new(
-    //         TAG_STRIPOFFSETS,
-    //         DT_TIFF_LONG8,
-    //         r.configs.rows as u64,
-    //         larger_values_data.len() as u64,
-    //     ));
-    //     let row_length_in_bytes: u64 = r.configs.columns as u64 * total_bytes_per_pixel as u64;
-    //     for i in 0..r.configs.rows as u64 {
-    //         larger_values_data.write_u64(8u64 + row_length_in_bytes * i)?;
-    //     }
-    // }
+        let row_length_in_bytes = (r.configs.columns * total_bytes_per_pixel) as u64;
+        (
+            (0..r.configs.rows as u64)
+                .map(|i| header_size + row_length_in_bytes * i)
+                .collect(),
+            vec![row_length_in_bytes; r.configs.rows],
+        )
+    };
+    let (offsets_tag, byte_counts_tag) = match image.layout {
+        BlockLayout::Strips => (TAG_STRIPOFFSETS, TAG_STRIPBYTECOUNTS),
+        BlockLayout::Tiles(_) => (TAG_TILEOFFSETS, TAG_TILEBYTECOUNTS),
+    };
+
+    // StripOffsets (273) or TileOffsets (324) tag
+    push_block_values(
+        &mut ifd_entries,
+        &mut larger_values_data,
+        offsets_tag,
+        &block_offsets,
+        is_big_tiff,
+    )?;
 
     // SamplesPerPixel tag (277)
     ifd_entries.push(Entry::new(
@@ -2656,77 +2884,47 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         samples_per_pixel as u64,
     ));
 
-    // RowsPerStrip tag (278)
-    ifd_entries.push(Entry::new(TAG_ROWSPERSTRIP, DT_SHORT, 1u64, 1u64));
+    match image.layout {
+        BlockLayout::Strips => {
+            // RowsPerStrip tag (278)
+            ifd_entries.push(Entry::new(TAG_ROWSPERSTRIP, DT_SHORT, 1u64, 1u64));
+        }
+        BlockLayout::Tiles(tile_size) => {
+            // TileWidth (322) and TileLength (323) tags
+            ifd_entries.push(Entry::new(TAG_TILEWIDTH, DT_SHORT, 1u64, tile_size as u64));
+            ifd_entries.push(Entry::new(TAG_TILELENGTH, DT_SHORT, 1u64, tile_size as u64));
+        }
+    }
 
-    // StripByteCounts tag (279)
-    if !is_big_tiff {
+    if is_multiband {
+        // PlanarConfiguration tag (284)
+        let planar_config = if r.configs.band_sequential { 2u64 } else { 1u64 };
         ifd_entries.push(Entry::new(
-            TAG_STRIPBYTECOUNTS,
-            DT_LONG,
-            r.configs.rows as u64,
-            larger_values_data.len() as u64,
+            TAG_PLANARCONFIGURATION,
+            DT_SHORT,
+            1u64,
+            planar_config,
         ));
-        let total_bytes_per_pixel = match r.configs.data_type {
-            DataType::I8 | DataType::U8 => 1u32,
-            DataType::I16 | DataType::U16 => 2u32,
-            DataType::I32 | DataType::U32 | DataType::F32 => 4u32,
-            DataType::I64 | DataType::U64 | DataType::F64 => 8u32,
-            DataType::RGB24 => 3u32,
-            DataType::RGBA32 => 4u32,
-            DataType::RGB48 => 6u32,
-            _ => {
-                return Err(Error::new(ErrorKind::InvalidData, "Unknown data type."));
-            }
-        };
-        if use_compression {
-            for val in strip_byte_counts {
-                larger_values_data
-                    .write_u32(val as u32)
-                    .expect("Error writing the TIFF strip byte counts tag");
-            }
-        } else {
-            let row_length_in_bytes: u32 = r.configs.columns as u32 * total_bytes_per_pixel;
-            for _ in 0..r.configs.rows as u32 {
-                larger_values_data
-                    .write_u32(row_length_in_bytes)
-                    .expect("Error writing the TIFF strip byte counts tag");
-            }
-        }
-    } else {
+    }
+
+    if image.encoding.predictor > 1 {
+        // Predictor tag (317)
         ifd_entries.push(Entry::new(
-            TAG_STRIPBYTECOUNTS,
-            DT_TIFF_LONG8,
-            r.configs.rows as u64,
-            larger_values_data.len() as u64,
+            TAG_PREDICTOR,
+            DT_SHORT,
+            1u64,
+            image.encoding.predictor as u64,
         ));
-        let total_bytes_per_pixel = match r.configs.data_type {
-            DataType::I8 | DataType::U8 => 1u64,
-            DataType::I16 | DataType::U16 => 2u64,
-            DataType::I32 | DataType::U32 | DataType::F32 => 4u64,
-            DataType::I64 | DataType::U64 | DataType::F64 => 8u64,
-            DataType::RGB24 => 3u64,
-            DataType::RGBA32 => 4u64,
-            DataType::RGB48 => 6u64,
-            _ => {
-                return Err(Error::new(ErrorKind::InvalidData, "Unknown data type."));
-            }
-        };
-        if use_compression {
-            for val in strip_byte_counts {
-                larger_values_data
-                    .write_u64(val)
-                    .expect("Error writing the TIFF strip byte counts tag");
-            }
-        } else {
-            let row_length_in_bytes: u64 = r.configs.columns as u64 * total_bytes_per_pixel;
-            for _ in 0..r.configs.rows as u32 {
-                larger_values_data
-                    .write_u64(row_length_in_bytes)
-                    .expect("Error writing the TIFF strip byte counts tag");
-            }
-        }
     }
+
+    // StripByteCounts (279) or TileByteCounts (325) tag
+    push_block_values(
+        &mut ifd_entries,
+        &mut larger_values_data,
+        byte_counts_tag,
+        &block_byte_counts,
+        is_big_tiff,
+    )?;
     /*
     if !is_big_tiff {
         ifd_entries.push(Entry::new(
@@ -2813,7 +3011,23 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
     ));
     larger_values_data.write_bytes(&soft_bytes)?;
 
-    if samples_per_pixel == 4 {
+    if is_multiband {
+        // ExtraSamples tag (338); the samples beyond the first are of unspecified meaning (0)
+        let num_extra = samples_per_pixel as u64 - 1;
+        if num_extra <= 2 {
+            ifd_entries.push(Entry::new(TAG_EXTRASAMPLES, DT_SHORT, num_extra, 0u64));
+        } else {
+            ifd_entries.push(Entry::new(
+                TAG_EXTRASAMPLES,
+                DT_SHORT,
+                num_extra,
+                larger_values_data.len() as u64,
+            ));
+            for _ in 0..num_extra {
+                larger_values_data.write_u16(0u16)?;
+            }
+        }
+    } else if samples_per_pixel == 4 {
         // ExtraSamples tag (338)
         ifd_entries.push(Entry::new(TAG_EXTRASAMPLES, DT_SHORT, 1u64, 2u64));
     }
@@ -2835,6 +3049,14 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
             samples_per_pixel as u64,
             samples_format as u64,
         ));
+    } else if samples_per_pixel == 2 {
+        // two equal shorts fit within the value offset
+        ifd_entries.push(Entry::new(
+            TAG_SAMPLEFORMAT,
+            DT_SHORT,
+            2u64,
+            samples_format as u64 | (samples_format as u64) << 16,
+        ));
     } else {
         ifd_entries.push(Entry::new(
             TAG_SAMPLEFORMAT,
@@ -2847,72 +3069,6 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         }
     }
 
-    // ModelPixelScaleTag tag (33550)
-    if r.configs.model_pixel_scale[0] == 0f64
-        && r.configs.model_tiepoint.is_empty()
-        && r.configs.model_transformation[0] == 0f64
-    {
-        ifd_entries.push(Entry::new(
-            TAG_MODELPIXELSCALETAG,
-            DT_DOUBLE,
-            3u64,
-            larger_values_data.len() as u64,
-        ));
-        larger_values_data.write_f64(r.configs.resolution_x)?;
-        larger_values_data.write_f64(r.configs.resolution_y)?;
-        larger_values_data.write_f64(0f64)?;
-    } else if r.configs.model_pixel_scale[0] != 0f64 {
-        ifd_entries.push(Entry::new(
-            TAG_MODELPIXELSCALETAG,
-            DT_DOUBLE,
-            3u64,
-            larger_values_data.len() as u64,
-        ));
-        larger_values_data.write_f64(r.configs.model_pixel_scale[0])?;
-        larger_values_data.write_f64(r.configs.model_pixel_scale[1])?;
-        larger_values_data.write_f64(r.configs.model_pixel_scale[2])?;
-    }
-
-    if r.configs.model_tiepoint.is_empty() && r.configs.model_transformation[0] == 0f64 {
-        // ModelTiepointTag tag (33922)
-        ifd_entries.push(Entry::new(
-            TAG_MODELTIEPOINTTAG,
-            DT_DOUBLE,
-            6u64,
-            larger_values_data.len() as u64,
-        ));
-        larger_values_data.write_f64(0f64)?; // I
-        larger_values_data.write_f64(0f64)?; // J
-        larger_values_data.write_f64(0f64)?; // K
-        larger_values_data.write_f64(r.configs.west)?; // X
-        larger_values_data.write_f64(r.configs.north)?; // Y
-        larger_values_data.write_f64(0f64)?; // Z
-    } else if !r.configs.model_tiepoint.is_empty() {
-        // ModelTiepointTag tag (33922)
-        ifd_entries.push(Entry::new(
-            TAG_MODELTIEPOINTTAG,
-            DT_DOUBLE,
-            r.configs.model_tiepoint.len() as u64,
-            larger_values_data.len() as u64,
-        ));
-        for i in 0..r.configs.model_tiepoint.len() {
-            larger_values_data.write_f64(r.configs.model_tiepoint[i])?;
-        }
-    }
-
-    if r.configs.model_transformation[0] != 0f64 {
-        // ModelTransformationTag tag (33920)
-        ifd_entries.push(Entry::new(
-            TAG_MODELTRANSFORMATIONTAG,
-            DT_DOUBLE,
-            16u64,
-            larger_values_data.len() as u64,
-        ));
-        for i in 0..16 {
-            larger_values_data.write_f64(r.configs.model_transformation[i])?;
-        }
-    }
-
     // TAG_GDAL_NODATA tag (42113)
     let nodata_str = format!("{}", r.configs.nodata);
     let mut nodata_bytes = nodata_str.into_bytes();
@@ -2956,309 +3112,395 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         larger_values_data.write_bytes(&nodata_bytes)?;
     }
 
-    let kw_map = get_keyword_map();
-    let geographic_type_map = match kw_map.get(&2048u16) {
-        Some(map) => map,
-        None => {
-            return Err(Error::new(
-                ErrorKind::InvalidData,
-                "Error generating geographic type map.",
-            ))
+    // TAG_GDAL_METADATA tag (42112), which holds the provenance of the raster
+    if let (Some(provenance), false) = (&r.configs.provenance, image.is_overview) {
+        let mut metadata_bytes = gdal_metadata_xml(provenance).into_bytes();
+        // the string and its terminating null keep the next value word-aligned
+        if metadata_bytes.len().is_multiple_of(2) {
+            metadata_bytes.push(32);
         }
-    };
-    let projected_cs_type_map = match kw_map.get(&3072u16) {
-        Some(map) => map,
-        None => {
-            return Err(Error::new(
-                ErrorKind::InvalidData,
-                "Error generating projected coordinate system type map.",
-            ))
+        metadata_bytes.push(0);
+        ifd_entries.push(Entry::new(
+            TAG_GDAL_METADATA,
+            DT_ASCII,
+            metadata_bytes.len() as u64,
+            larger_values_data.len() as u64,
+        ));
+        larger_values_data.write_bytes(&metadata_bytes)?;
+    }
+
+    // The georeferencing of overviews is that of the full-resolution image.
+    if !image.is_overview {
+        // ModelPixelScaleTag tag (33550)
+        if r.configs.model_pixel_scale[0] == 0f64
+            && r.configs.model_tiepoint.is_empty()
+            && r.configs.model_transformation[0] == 0f64
+        {
+            ifd_entries.push(Entry::new(
+                TAG_MODELPIXELSCALETAG,
+                DT_DOUBLE,
+                3u64,
+                larger_values_data.len() as u64,
+            ));
+            larger_values_data.write_f64(r.configs.resolution_x)?;
+            larger_values_data.write_f64(r.configs.resolution_y)?;
+            larger_values_data.write_f64(0f64)?;
+        } else if r.configs.model_pixel_scale[0] != 0f64 {
+            ifd_entries.push(Entry::new(
+                TAG_MODELPIXELSCALETAG,
+                DT_DOUBLE,
+                3u64,
+                larger_values_data.len() as u64,
+            ));
+            larger_values_data.write_f64(r.configs.model_pixel_scale[0])?;
+            larger_values_data.write_f64(r.configs.model_pixel_scale[1])?;
+            larger_values_data.write_f64(r.configs.model_pixel_scale[2])?;
         }
-    };
 
-    //let key_map = get_keys_map();
-    let mut gk_entries: Vec<GeoKeyEntry> = vec![];
-    let mut ascii_params = String::new(); //: Vec<u8> = vec![];
-    let double_params: Vec<f64> = vec![];
-    if geographic_type_map.contains_key(&r.configs.epsg_code) {
-        // tGTModelTypeGeoKey (1024)
-        gk_entries.push(GeoKeyEntry {
-            tag: TAG_GTMODELTYPEGEOKEY,
-            location: 0u16,
-            count: 1u16,
-            value_offset: 2u16,
-        });
+        if r.configs.model_tiepoint.is_empty() && r.configs.model_transformation[0] == 0f64 {
+            // ModelTiepointTag tag (33922)
+            ifd_entries.push(Entry::new(
+                TAG_MODELTIEPOINTTAG,
+                DT_DOUBLE,
+                6u64,
+                larger_values_data.len() as u64,
+            ));
+            larger_values_data.write_f64(0f64)?; // I
+            larger_values_data.write_f64(0f64)?; // J
+            larger_values_data.write_f64(0f64)?; // K
+            larger_values_data.write_f64(r.configs.west)?; // X
+            larger_values_data.write_f64(r.configs.north)?; // Y
+            larger_values_data.write_f64(0f64)?; // Z
+        } else if !r.configs.model_tiepoint.is_empty() {
+            // ModelTiepointTag tag (33922)
+            ifd_entries.push(Entry::new(
+                TAG_MODELTIEPOINTTAG,
+                DT_DOUBLE,
+                r.configs.model_tiepoint.len() as u64,
+                larger_values_data.len() as u64,
+            ));
+            for i in 0..r.configs.model_tiepoint.len() {
+                larger_values_data.write_f64(r.configs.model_tiepoint[i])?;
+            }
+        }
 
-        // GTRasterTypeGeoKey (1025)
-        if r.configs.pixel_is_area {
-            gk_entries.push(GeoKeyEntry {
-                tag: TAG_GTRASTERTYPEGEOKEY,
-                location: 0u16,
-                count: 1u16,
-                value_offset: 1u16,
-            });
-        } else {
+        if r.configs.model_transformation[0] != 0f64 {
+            // ModelTransformationTag tag (33920)
+            ifd_entries.push(Entry::new(
+                TAG_MODELTRANSFORMATIONTAG,
+                DT_DOUBLE,
+                16u64,
+                larger_values_data.len() as u64,
+            ));
+            for i in 0..16 {
+                larger_values_data.write_f64(r.configs.model_transformation[i])?;
+            }
+        }
+
+        let kw_map = get_keyword_map();
+        let geographic_type_map = match kw_map.get(&2048u16) {
+            Some(map) => map,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "Error generating geographic type map.",
+                ))
+            }
+        };
+        let projected_cs_type_map = match kw_map.get(&3072u16) {
+            Some(map) => map,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "Error generating projected coordinate system type map.",
+                ))
+            }
+        };
+
+        //let key_map = get_keys_map();
+        let mut gk_entries: Vec<GeoKeyEntry> = vec![];
+        let mut ascii_params = String::new(); //: Vec<u8> = vec![];
+        let double_params: Vec<f64> = vec![];
+        if geographic_type_map.contains_key(&r.configs.epsg_code) {
+            // tGTModelTypeGeoKey (1024)
             gk_entries.push(GeoKeyEntry {
-                tag: TAG_GTRASTERTYPEGEOKEY,
+                tag: TAG_GTMODELTYPEGEOKEY,
                 location: 0u16,
                 count: 1u16,
                 value_offset: 2u16,
             });
-        }
 
-        // tGTCitationGeoKey (1026)
-        let mut v = String::from(
-            geographic_type_map
-                .get(&r.configs.epsg_code)
-                .unwrap()
-                .clone(),
-        );
-        v.push_str("|");
-        v = v.replace("_", " ");
-        gk_entries.push(GeoKeyEntry {
-            tag: TAG_GTCITATIONGEOKEY,
-            location: 34737u16,
-            count: v.len() as u16,
-            value_offset: ascii_params.len() as u16,
-        });
-        ascii_params.push_str(&v);
-
-        // tGeographicTypeGeoKey (2048)
-        gk_entries.push(GeoKeyEntry {
-            tag: TAG_GEOGRAPHICTYPEGEOKEY,
-            location: 0u16,
-            count: 1u16,
-            value_offset: r.configs.epsg_code,
-        });
-
-        if r.configs.z_units.to_lowercase() != "not specified" {
-            // VerticalUnitsGeoKey (4099)
-            let units = r.configs.z_units.to_lowercase();
-            if units.contains("met") {
+            // GTRasterTypeGeoKey (1025)
+            if r.configs.pixel_is_area {
                 gk_entries.push(GeoKeyEntry {
-                    tag: TAG_VERTICALUNITSGEOKEY,
+                    tag: TAG_GTRASTERTYPEGEOKEY,
                     location: 0u16,
                     count: 1u16,
-                    value_offset: 9001u16,
+                    value_offset: 1u16,
                 });
-            } else if units.contains("ft") | units.contains("feet") | units.contains("foot") {
+            } else {
                 gk_entries.push(GeoKeyEntry {
-                    tag: TAG_VERTICALUNITSGEOKEY,
+                    tag: TAG_GTRASTERTYPEGEOKEY,
                     location: 0u16,
                     count: 1u16,
-                    value_offset: 9002u16,
+                    value_offset: 2u16,
                 });
             }
-        }
-    } else if projected_cs_type_map.contains_key(&r.configs.epsg_code) {
-        // tGTModelTypeGeoKey (1024)
-        gk_entries.push(GeoKeyEntry {
-            tag: TAG_GTMODELTYPEGEOKEY,
-            location: 0u16,
-            count: 1u16,
-            value_offset: 1u16,
-        });
 
-        // GTRasterTypeGeoKey (1025)
-        if r.configs.pixel_is_area {
+            // tGTCitationGeoKey (1026)
+            let mut v = String::from(
+                geographic_type_map
+                    .get(&r.configs.epsg_code)
+                    .unwrap()
+                    .clone(),
+            );
+            v.push_str("|");
+            v = v.replace("_", " ");
             gk_entries.push(GeoKeyEntry {
-                tag: TAG_GTRASTERTYPEGEOKEY,
-                location: 0u16,
-                count: 1u16,
-                value_offset: 1u16,
+                tag: TAG_GTCITATIONGEOKEY,
+                location: 34737u16,
+                count: v.len() as u16,
+                value_offset: ascii_params.len() as u16,
             });
-        } else {
+            ascii_params.push_str(&v);
+
+            // tGeographicTypeGeoKey (2048)
             gk_entries.push(GeoKeyEntry {
-                tag: TAG_GTRASTERTYPEGEOKEY,
+                tag: TAG_GEOGRAPHICTYPEGEOKEY,
                 location: 0u16,
                 count: 1u16,
-                value_offset: 2u16,
+                value_offset: r.configs.epsg_code,
             });
-        }
-
-        // tProjectedCSTypeGeoKey (3072)
-        gk_entries.push(GeoKeyEntry {
-            tag: TAG_PROJECTEDCSTYPEGEOKEY,
-            location: 0u16,
-            count: 1u16,
-            value_offset: r.configs.epsg_code,
-        });
-
-        // PCSCitationGeoKey (3073)
-        let mut v = String::from(
-            projected_cs_type_map
-                .get(&r.configs.epsg_code)
-                .unwrap()
-                .clone(),
-        );
-        v.push_str("|");
-        v = v.replace("_", " ");
-        gk_entries.push(GeoKeyEntry {
-            tag: 3073u16,
-            location: 34737u16,
-            count: v.len() as u16,
-            value_offset: ascii_params.len() as u16,
-        });
-        ascii_params.push_str(&v);
 
-        if r.configs.xy_units.to_lowercase() != "not specified" {
-            // ProjLinearUnitsGeoKey (3076)
-            let units = r.configs.xy_units.to_lowercase();
-            if units.contains("met") {
-                gk_entries.push(GeoKeyEntry {
-                    tag: TAG_PROJLINEARUNITSGEOKEY,
-                    location: 0u16,
-                    count: 1u16,
-                    value_offset: 9001u16,
-                });
-            } else if units.contains("ft") | units.contains("feet") | units.contains("foot") {
-                gk_entries.push(GeoKeyEntry {
-                    tag: TAG_PROJLINEARUNITSGEOKEY,
-                    location: 0u16,
-                    count: 1u16,
-                    value_offset: 9002u16,
-                });
+            if r.configs.z_units.to_lowercase() != "not specified" {
+                // VerticalUnitsGeoKey (4099)
+                let units = r.configs.z_units.to_lowercase();
+                if units.contains("met") {
+                    gk_entries.push(GeoKeyEntry {
+                        tag: TAG_VERTICALUNITSGEOKEY,
+                        location: 0u16,
+                        count: 1u16,
+                        value_offset: 9001u16,
+                    });
+                } else if units.contains("ft") | units.contains("feet") | units.contains("foot") {
+                    gk_entries.push(GeoKeyEntry {
+                        tag: TAG_VERTICALUNITSGEOKEY,
+                        location: 0u16,
+                        count: 1u16,
+                        value_offset: 9002u16,
+                    });
+                }
             }
-        }
+        } else if projected_cs_type_map.contains_key(&r.configs.epsg_code) {
+            // tGTModelTypeGeoKey (1024)
+            gk_entries.push(GeoKeyEntry {
+                tag: TAG_GTMODELTYPEGEOKEY,
+                location: 0u16,
+                count: 1u16,
+                value_offset: 1u16,
+            });
 
-        if r.configs.z_units.to_lowercase() != "not specified" {
-            // VerticalUnitsGeoKey (4099)
-            let units = r.configs.z_units.to_lowercase();
-            if units.contains("met") {
+            // GTRasterTypeGeoKey (1025)
+            if r.configs.pixel_is_area {
                 gk_entries.push(GeoKeyEntry {
-                    tag: TAG_VERTICALUNITSGEOKEY,
+                    tag: TAG_GTRASTERTYPEGEOKEY,
                     location: 0u16,
                     count: 1u16,
-                    value_offset: 9001u16,
+                    value_offset: 1u16,
                 });
-            } else if units.contains("ft") | units.contains("feet") | units.contains("foot") {
+            } else {
                 gk_entries.push(GeoKeyEntry {
-                    tag: TAG_VERTICALUNITSGEOKEY,
+                    tag: TAG_GTRASTERTYPEGEOKEY,
                     location: 0u16,
                     count: 1u16,
-                    value_offset: 9002u16,
+                    value_offset: 2u16,
                 });
             }
-        }
-    } else {
-        // we don't know much about the coordinate system used.
-
-        // tGTModelTypeGeoKey (1024)
-        gk_entries.push(GeoKeyEntry {
-            tag: TAG_GTMODELTYPEGEOKEY,
-            location: 0u16,
-            count: 1u16,
-            value_offset: 0u16,
-        });
 
-        // GTRasterTypeGeoKey (1025)
-        if r.configs.pixel_is_area {
+            // tProjectedCSTypeGeoKey (3072)
             gk_entries.push(GeoKeyEntry {
-                tag: TAG_GTRASTERTYPEGEOKEY,
+                tag: TAG_PROJECTEDCSTYPEGEOKEY,
                 location: 0u16,
                 count: 1u16,
-                value_offset: 1u16,
+                value_offset: r.configs.epsg_code,
             });
+
+            // PCSCitationGeoKey (3073)
+            let mut v = String::from(
+                projected_cs_type_map
+                    .get(&r.configs.epsg_code)
+                    .unwrap()
+                    .clone(),
+            );
+            v.push_str("|");
+            v = v.replace("_", " ");
+            gk_entries.push(GeoKeyEntry {
+                tag: 3073u16,
+                location: 34737u16,
+                count: v.len() as u16,
+                value_offset: ascii_params.len() as u16,
+            });
+            ascii_params.push_str(&v);
+
+            if r.configs.xy_units.to_lowercase() != "not specified" {
+                // ProjLinearUnitsGeoKey (3076)
+                let units = r.configs.xy_units.to_lowercase();
+                if units.contains("met") {
+                    gk_entries.push(GeoKeyEntry {
+                        tag: TAG_PROJLINEARUNITSGEOKEY,
+                        location: 0u16,
+                        count: 1u16,
+                        value_offset: 9001u16,
+                    });
+                } else if units.contains("ft") | units.contains("feet") | units.contains("foot") {
+                    gk_entries.push(GeoKeyEntry {
+                        tag: TAG_PROJLINEARUNITSGEOKEY,
+                        location: 0u16,
+                        count: 1u16,
+                        value_offset: 9002u16,
+                    });
+                }
+            }
+
+            if r.configs.z_units.to_lowercase() != "not specified" {
+                // VerticalUnitsGeoKey (4099)
+                let units = r.configs.z_units.to_lowercase();
+                if units.contains("met") {
+                    gk_entries.push(GeoKeyEntry {
+                        tag: TAG_VERTICALUNITSGEOKEY,
+                        location: 0u16,
+                        count: 1u16,
+                        value_offset: 9001u16,
+                    });
+                } else if units.contains("ft") | units.contains("feet") | units.contains("foot") {
+                    gk_entries.push(GeoKeyEntry {
+                        tag: TAG_VERTICALUNITSGEOKEY,
+                        location: 0u16,
+                        count: 1u16,
+                        value_offset: 9002u16,
+                    });
+                }
+            }
         } else {
+            // we don't know much about the coordinate system used.
+
+            // tGTModelTypeGeoKey (1024)
             gk_entries.push(GeoKeyEntry {
-                tag: TAG_GTRASTERTYPEGEOKEY,
+                tag: TAG_GTMODELTYPEGEOKEY,
                 location: 0u16,
                 count: 1u16,
-                value_offset: 2u16,
+                value_offset: 0u16,
             });
-        }
-    }
 
-    if r.configs.geo_key_directory.is_empty() {
-        // create the GeoKeyDirectoryTag tag (34735)
-        ifd_entries.push(Entry::new(
-            TAG_GEOKEYDIRECTORYTAG,
-            DT_SHORT,
-            (4 + gk_entries.len() * 4) as u64,
-            larger_values_data.len() as u64,
-        ));
-        larger_values_data.write_u16(1u16)?; // KeyDirectoryVersion
-        larger_values_data.write_u16(1u16)?; // KeyRevision
-        larger_values_data.write_u16(0u16)?; // MinorRevision
-        larger_values_data.write_u16(gk_entries.len() as u16)?; // NumberOfKeys
-
-        for entry in gk_entries {
-            larger_values_data.write_u16(entry.tag)?; // KeyID
-            larger_values_data.write_u16(entry.location)?; // TIFFTagLocation
-            larger_values_data.write_u16(entry.count)?; // Count
-            larger_values_data.write_u16(entry.value_offset)?; // Value_Offset
-        }
-
-        if double_params.len() > 0 {
-            // create the GeoDoubleParamsTag tag (34736)
-            ifd_entries.push(Entry::new(
-                TAG_GEODOUBLEPARAMSTAG,
-                DT_DOUBLE,
-                double_params.len() as u64,
-                larger_values_data.len() as u64,
-            ));
-            for double_val in double_params {
-                larger_values_data.write_f64(double_val)?;
+            // GTRasterTypeGeoKey (1025)
+            if r.configs.pixel_is_area {
+                gk_entries.push(GeoKeyEntry {
+                    tag: TAG_GTRASTERTYPEGEOKEY,
+                    location: 0u16,
+                    count: 1u16,
+                    value_offset: 1u16,
+                });
+            } else {
+                gk_entries.push(GeoKeyEntry {
+                    tag: TAG_GTRASTERTYPEGEOKEY,
+                    location: 0u16,
+                    count: 1u16,
+                    value_offset: 2u16,
+                });
             }
         }
 
-        if ascii_params.len() > 0 {
-            // create the GeoAsciiParamsTag tag (34737)
-            let mut ascii_params_bytes = ascii_params.into_bytes();
-            ascii_params_bytes.push(0);
+        if r.configs.geo_key_directory.is_empty() {
+            // create the GeoKeyDirectoryTag tag (34735)
             ifd_entries.push(Entry::new(
-                TAG_GEOASCIIPARAMSTAG,
-                DT_ASCII,
-                ascii_params_bytes.len() as u64,
+                TAG_GEOKEYDIRECTORYTAG,
+                DT_SHORT,
+                (4 + gk_entries.len() * 4) as u64,
                 larger_values_data.len() as u64,
             ));
-            if ascii_params_bytes.len() % 2 == 1 {
-                // it has to end on a word so that the next value starts on a word
-                ascii_params_bytes.push(0);
+            larger_values_data.write_u16(1u16)?; // KeyDirectoryVersion
+            larger_values_data.write_u16(1u16)?; // KeyRevision
+            larger_values_data.write_u16(0u16)?; // MinorRevision
+            larger_values_data.write_u16(gk_entries.len() as u16)?; // NumberOfKeys
+
+            for entry in gk_entries {
+                larger_values_data.write_u16(entry.tag)?; // KeyID
+                larger_values_data.write_u16(entry.location)?; // TIFFTagLocation
+                larger_values_data.write_u16(entry.count)?; // Count
+                larger_values_data.write_u16(entry.value_offset)?; // Value_Offset
             }
-            larger_values_data.write_bytes(&ascii_params_bytes)?;
-        }
-    } else {
-        // let num_keys = (r.configs.geo_key_directory.len() - 4) / 4;
-        // output the GeoKeyDirectoryTag tag (34735)
-        ifd_entries.push(Entry::new(
-            TAG_GEOKEYDIRECTORYTAG,
-            DT_SHORT,
-            r.configs.geo_key_directory.len() as u64,
-            larger_values_data.len() as u64,
-        ));
-        for val in &r.configs.geo_key_directory {
-            larger_values_data.write_u16(*val)?;
-        }
 
-        if r.configs.geo_double_params.len() > 0 {
-            // create the GeoDoubleParamsTag tag (34736)
-            ifd_entries.push(Entry::new(
-                TAG_GEODOUBLEPARAMSTAG,
-                DT_DOUBLE,
-                r.configs.geo_double_params.len() as u64,
-                larger_values_data.len() as u64,
-            ));
-            for double_val in &r.configs.geo_double_params {
-                larger_values_data.write_f64(*double_val)?;
+            if double_params.len() > 0 {
+                // create the GeoDoubleParamsTag tag (34736)
+                ifd_entries.push(Entry::new(
+                    TAG_GEODOUBLEPARAMSTAG,
+                    DT_DOUBLE,
+                    double_params.len() as u64,
+                    larger_values_data.len() as u64,
+                ));
+                for double_val in double_params {
+                    larger_values_data.write_f64(double_val)?;
+                }
             }
-        }
 
-        if !r.configs.geo_ascii_params.is_empty() {
-            // create the GeoAsciiParamsTag tag (34737)
-            let mut ascii_params_bytes = r.configs.geo_ascii_params.clone().into_bytes();
-            ascii_params_bytes.push(0);
+            if ascii_params.len() > 0 {
+                // create the GeoAsciiParamsTag tag (34737)
+                let mut ascii_params_bytes = ascii_params.into_bytes();
+                ascii_params_bytes.push(0);
+                ifd_entries.push(Entry::new(
+                    TAG_GEOASCIIPARAMSTAG,
+                    DT_ASCII,
+                    ascii_params_bytes.len() as u64,
+                    larger_values_data.len() as u64,
+                ));
+                if ascii_params_bytes.len() % 2 == 1 {
+                    // it has to end on a word so that the next value starts on a word
+                    ascii_params_bytes.push(0);
+                }
+                larger_values_data.write_bytes(&ascii_params_bytes)?;
+            }
+        } else {
+            // let num_keys = (r.configs.geo_key_directory.len() - 4) / 4;
+            // output the GeoKeyDirectoryTag tag (34735)
             ifd_entries.push(Entry::new(
-                TAG_GEOASCIIPARAMSTAG,
-                DT_ASCII,
-                ascii_params_bytes.len() as u64,
+                TAG_GEOKEYDIRECTORYTAG,
+                DT_SHORT,
+                r.configs.geo_key_directory.len() as u64,
                 larger_values_data.len() as u64,
             ));
-            if ascii_params_bytes.len() % 2 == 1 {
-                // it has to end on a word so that the next value starts on a word
+            for val in &r.configs.geo_key_directory {
+                larger_values_data.write_u16(*val)?;
+            }
+
+            if r.configs.geo_double_params.len() > 0 {
+                // create the GeoDoubleParamsTag tag (34736)
+                ifd_entries.push(Entry::new(
+                    TAG_GEODOUBLEPARAMSTAG,
+                    DT_DOUBLE,
+                    r.configs.geo_double_params.len() as u64,
+                    larger_values_data.len() as u64,
+                ));
+                for double_val in &r.configs.geo_double_params {
+                    larger_values_data.write_f64(*double_val)?;
+                }
+            }
+
+            if !r.configs.geo_ascii_params.is_empty() {
+                // create the GeoAsciiParamsTag tag (34737)
+                let mut ascii_params_bytes = r.configs.geo_ascii_params.clone().into_bytes();
                 ascii_params_bytes.push(0);
+                ifd_entries.push(Entry::new(
+                    TAG_GEOASCIIPARAMSTAG,
+                    DT_ASCII,
+                    ascii_params_bytes.len() as u64,
+                    larger_values_data.len() as u64,
+                ));
+                if ascii_params_bytes.len() % 2 == 1 {
+                    // it has to end on a word so that the next value starts on a word
+                    ascii_params_bytes.push(0);
+                }
+                larger_values_data.write_bytes(&ascii_params_bytes)?;
             }
-            larger_values_data.write_bytes(&ascii_params_bytes)?;
         }
     }
 
@@ -3268,7 +3510,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
 
     // Number of Directory Entries.
     if !is_big_tiff {
-        write_u16(&mut writer, r.configs.endian, ifd_entries.len() as u16)?;
+        write_u16(writer, r.configs.endian, ifd_entries.len() as u16)?;
 
         // Sort the IFD entries
         ifd_entries.sort_by(|a, b| a.tag.cmp(&b.tag));
@@ -3277,34 +3519,33 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         let ifd_length = 2u64 + ifd_entries.len() as u64 * 12u64 + 4u64;
 
         for ifde in ifd_entries {
-            write_u16(&mut writer, r.configs.endian, ifde.tag)?; // Tag
-            write_u16(&mut writer, r.configs.endian, ifde.ifd_type)?; // Field type
-            write_u32(&mut writer, r.configs.endian, ifde.num_values as u32)?; // Num of values
+            write_u16(writer, r.configs.endian, ifde.tag)?; // Tag
+            write_u16(writer, r.configs.endian, ifde.ifd_type)?; // Field type
+            write_u32(writer, r.configs.endian, ifde.num_values as u32)?; // Num of values
             if ifde.ifd_type == DT_SHORT && ifde.num_values == 1 {
                 // it's a value
-                write_u16(&mut writer, r.configs.endian, ifde.offset as u16)?; // Value
-                write_u16(&mut writer, r.configs.endian, 0u16)?; // Fill the remaining 2 right bytes of the u32
+                write_u16(writer, r.configs.endian, ifde.offset as u16)?; // Value
+                write_u16(writer, r.configs.endian, 0u16)?; // Fill the remaining 2 right bytes of the u32
             } else if ifde.ifd_type == DT_LONG && ifde.num_values == 1 {
                 // it's a value
-                write_u32(&mut writer, r.configs.endian, ifde.offset as u32)?;
+                write_u32(writer, r.configs.endian, ifde.offset as u32)?;
             } else if ifde.ifd_type == DT_SHORT && ifde.num_values == 2 {
                 // I'm not really sure about this one. Two shorts will fit in the value_offset, but will they be interpreted correctly?
-                write_u32(&mut writer, r.configs.endian, ifde.offset as u32)?; // Value
+                write_u32(writer, r.configs.endian, ifde.offset as u32)?; // Value
             } else {
                 // it's an offset
                 write_u32(
-                    &mut writer,
+                    writer,
                     r.configs.endian,
                     ifd_start as u32 + ifd_length as u32 + ifde.offset as u32,
                 )?;
             }
         }
 
-        // 4-byte offset of the next IFD; Note, only single image TIFFs are currently supported
-        // and therefore, this will always be set to '0'.
-        write_u32(&mut writer, r.configs.endian, 0u32)?;
+        // 4-byte offset of the next IFD
+        write_u32(writer, r.configs.endian, image.next_ifd as u32)?;
     } else {
-        write_u64(&mut writer, r.configs.endian, ifd_entries.len() as u64)?;
+        write_u64(writer, r.configs.endian, ifd_entries.len() as u64)?;
 
         // Sort the IFD entries
         ifd_entries.sort_by(|a, b| a.tag.cmp(&b.tag));
@@ -3313,50 +3554,187 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
         let ifd_length = 8u64 + ifd_entries.len() as u64 * 20u64 + 8u64;
 
         for ifde in ifd_entries {
-            write_u16(&mut writer, r.configs.endian, ifde.tag)?; // Tag
-            write_u16(&mut writer, r.configs.endian, ifde.ifd_type)?; // Field type
-            write_u64(&mut writer, r.configs.endian, ifde.num_values)?; // Num of values
+            write_u16(writer, r.configs.endian, ifde.tag)?; // Tag
+            write_u16(writer, r.configs.endian, ifde.ifd_type)?; // Field type
+            write_u64(writer, r.configs.endian, ifde.num_values)?; // Num of values
             if ifde.ifd_type == DT_SHORT && ifde.num_values == 1 {
                 // it's a value
-                write_u16(&mut writer, r.configs.endian, ifde.offset as u16)?; // Value
-                write_u16(&mut writer, r.configs.endian, 0u16)?; // Fill the remaining bytes of the u64
-                write_u32(&mut writer, r.configs.endian, 0u32)?; // Fill the remaining bytes of the u64
+                write_u16(writer, r.configs.endian, ifde.offset as u16)?; // Value
+                write_u16(writer, r.configs.endian, 0u16)?; // Fill the remaining bytes of the u64
+                write_u32(writer, r.configs.endian, 0u32)?; // Fill the remaining bytes of the u64
             } else if ifde.ifd_type == DT_SHORT && ifde.num_values == 2 {
                 // I'm not really sure about this one. Two shorts will fit in the value_offset, but will they be interpreted correctly?
-                write_u32(&mut writer, r.configs.endian, ifde.offset as u32)?; // Value
-                write_u32(&mut writer, r.configs.endian, 0u32)?; // Fill the remaining bytes of the u64
+                write_u32(writer, r.configs.endian, ifde.offset as u32)?; // Value
+                write_u32(writer, r.configs.endian, 0u32)?; // Fill the remaining bytes of the u64
             } else if ifde.ifd_type == DT_LONG && ifde.num_values == 1 {
                 // it's a value
-                write_u32(&mut writer, r.configs.endian, ifde.offset as u32)?;
-                write_u32(&mut writer, r.configs.endian, 0u32)?; // Fill the remaining bytes of the u64
+                write_u32(writer, r.configs.endian, ifde.offset as u32)?;
+                write_u32(writer, r.configs.endian, 0u32)?; // Fill the remaining bytes of the u64
             } else if (ifde.ifd_type == DT_LONG && ifde.num_values == 2)
                 || (ifde.ifd_type == DT_TIFF_LONG8 && ifde.num_values == 1)
             {
                 // it's a value
-                write_u64(&mut writer, r.configs.endian, ifde.offset)?;
+                write_u64(writer, r.configs.endian, ifde.offset)?;
             } else {
                 // it's an offset
                 write_u64(
-                    &mut writer,
+                    writer,
                     r.configs.endian,
                     ifd_start + ifd_length + ifde.offset,
                 )?;
             }
         }
 
-        // 4-byte offset of the next IFD; Note, only single image TIFFs are currently supported
-        // and therefore, this will always be set to '0'.
-        write_u64(&mut writer, r.configs.endian, 0u64)?;
+        // 8-byte offset of the next IFD
+        write_u64(writer, r.configs.endian, image.next_ifd)?;
     }
 
     //////////////////////////////////
     // Write the larger_values_data //
     //////////////////////////////////
-    write_bytes(&mut writer, larger_values_data.get_inner())?;
+    write_bytes(writer, larger_values_data.get_inner())?;
+
+    Ok(())
+}
 
+// Adds the IFD entry holding the offsets or byte counts of the blocks of an image. As the TIFF
+// specification requires, a single value is held within the entry itself.
+fn push_block_values(
+    ifd_entries: &mut Vec<Entry>,
+    larger_values_data: &mut ByteOrderWriter<Vec<u8>>,
+    tag: u16,
+    values: &[u64],
+    is_big_tiff: bool,
+) -> Result<(), Error> {
+    let field_type = if !is_big_tiff { DT_LONG } else { DT_TIFF_LONG8 };
+    if values.len() == 1 {
+        ifd_entries.push(Entry::new(tag, field_type, 1u64, values[0]));
+        return Ok(());
+    }
+    ifd_entries.push(Entry::new(
+        tag,
+        field_type,
+        values.len() as u64,
+        larger_values_data.len() as u64,
+    ));
+    for &val in values {
+        if !is_big_tiff {
+            larger_values_data.write_u32(val as u32)?;
+        } else {
+            larger_values_data.write_u64(val)?;
+        }
+    }
     Ok(())
 }
 
+/// Writes a single-band GeoTIFF file, the rows of which are supplied, in order, by `get_row`.
+/// This allows a raster to be written without its cell values ever being held in memory all at
+/// once; only the configurations of `r` are used.
+pub(crate) fn write_geotiff_rows<F>(r: &mut Raster, mut get_row: F) -> Result<(), Error>
+where
+    F: FnMut(usize) -> Result<Vec<f64>, Error>,
+{
+    let configs = whitebox_common::configs::get_configs()?;
+    let options = GeoTiffOptions::from_configs(&configs)?;
+
+    let total_bytes_per_pixel = r.configs.data_type.get_data_size();
+    if total_bytes_per_pixel == 0
+        || r.configs.data_type == DataType::RGB24
+        || r.configs.data_type == DataType::RGBA32
+        || r.configs.data_type == DataType::RGB48
+    {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "Rasters of data type {:?} cannot be written one row at a time.",
+                r.configs.data_type
+            ),
+        ));
+    }
+    let encoding = BlockEncoding::new(r, options.compression, options.predictor);
+
+    let is_big_tiff = 8usize + r.configs.rows * r.configs.columns * total_bytes_per_pixel
+        >= 4_000_000_000;
+    let header_size = if !is_big_tiff { 8u64 } else { 16u64 };
+
+    let f = File::create(r.file_name.clone())?;
+    let mut writer = BufWriter::new(f);
+
+    //////////////////////
+    // Write the header //
+    //////////////////////
+    // The offset to the first IFD is not known until the data have been written.
+    if r.configs.endian == Endianness::LittleEndian {
+        write_bytes(&mut writer, "II".as_bytes())?;
+    } else {
+        write_bytes(&mut writer, "MM".as_bytes())?;
+    }
+    if !is_big_tiff {
+        write_u16(&mut writer, r.configs.endian, 42u16)?;
+        write_u32(&mut writer, r.configs.endian, 0u32)?;
+    } else {
+        write_u16(&mut writer, r.configs.endian, 43u16)?;
+        write_u16(&mut writer, r.configs.endian, 8u16)?;
+        write_u16(&mut writer, r.configs.endian, 0u16)?;
+        write_u64(&mut writer, r.configs.endian, 0u64)?;
+    }
+
+    // At the moment, categorical and paletted output is not supported.
+    if r.configs.photometric_interp == PhotometricInterpretation::Categorical
+        || r.configs.photometric_interp == PhotometricInterpretation::Paletted
+    {
+        r.configs.photometric_interp = PhotometricInterpretation::Continuous;
+    }
+
+    //////////////////////////
+    // Write the image data //
+    //////////////////////////
+    let mut strip_offsets = Vec::with_capacity(r.configs.rows);
+    let mut strip_byte_counts = Vec::with_capacity(r.configs.rows);
+    let mut current_offset = header_size;
+    for row in 0..r.configs.rows {
+        let values = get_row(row)?;
+        let mut data = Vec::with_capacity(r.configs.columns * total_bytes_per_pixel);
+        for &value in values.iter().take(r.configs.columns) {
+            write_sample(&mut data, r.configs.data_type, r.configs.endian, value)?;
+        }
+        if encoding.compression != COMPRESS_NONE {
+            data = compress_block(&data, &encoding, data.len())?;
+        }
+        write_bytes(&mut writer, &data)?;
+        let strip_length_in_bytes = data.len() as u64;
+        strip_byte_counts.push(strip_length_in_bytes);
+        strip_offsets.push(current_offset);
+        current_offset += strip_length_in_bytes;
+        if !strip_length_in_bytes.is_multiple_of(2) {
+            // This is just because the data must start on a word (i.e. an even value).
+            write_u8(&mut writer, 0u8)?;
+            current_offset += 1;
+        }
+    }
+
+    // Every strip ends on a word, and so the IFD can immediately follow the data.
+    let ifd_start = current_offset;
+    if !is_big_tiff {
+        writer.seek(SeekFrom::Start(4))?;
+        write_u32(&mut writer, r.configs.endian, ifd_start as u32)?;
+    } else {
+        writer.seek(SeekFrom::Start(8))?;
+        write_u64(&mut writer, r.configs.endian, ifd_start)?;
+    }
+    writer.seek(SeekFrom::End(0))?;
+
+    let image = IfdImage {
+        layout: BlockLayout::Strips,
+        block_offsets: strip_offsets,
+        block_byte_counts: strip_byte_counts,
+        encoding,
+        next_ifd: 0,
+        is_overview: false,
+    };
+    write_geotiff_ifd(&mut writer, r, ifd_start, image, is_big_tiff)
+}
+
 /*
 pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
     // get the ByteOrderWriter
@@ -3463,7 +3841,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            bow.write_f64(r.data[i])?;
+                            bow.write_f64(r.data.get(i))?;
                         }
                     }
                 }
@@ -3474,12 +3852,12 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                         if r.configs.endian == Endianness::LittleEndian {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_f32::<LittleEndian>(r.data[i] as f32).expect("Error writing byte data.");
+                                data.write_f32::<LittleEndian>(r.data.get(i) as f32).expect("Error writing byte data.");
                             }
                         } else {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                data.write_f32::<BigEndian>(r.data[i] as f32).expect("Error writing byte data.");
+                                data.write_f32::<BigEndian>(r.data.get(i) as f32).expect("Error writing byte data.");
                             }
                         }
                         // compress the data vec
@@ -3502,7 +3880,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            bow.write_u64(r.data[i] as u64)?;
+                            bow.write_u64(r.data.get(i) as u64)?;
                         }
                     }
                 }
@@ -3511,7 +3889,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            bow.write_u32(r.data[i] as u32)?;
+                            bow.write_u32(r.data.get(i) as u32)?;
                         }
                     }
                 }
@@ -3520,7 +3898,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            bow.write_u16(r.data[i] as u16)?;
+                            bow.write_u16(r.data.get(i) as u16)?;
                         }
                     }
                 }
@@ -3529,7 +3907,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            bow.write_u8(r.data[i] as u8)?;
+                            bow.write_u8(r.data.get(i) as u8)?;
                         }
                     }
                 }
@@ -3538,7 +3916,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            bow.write_i64(r.data[i] as i64)?;
+                            bow.write_i64(r.data.get(i) as i64)?;
                         }
                     }
                 }
@@ -3547,7 +3925,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            bow.write_i32(r.data[i] as i32)?;
+                            bow.write_i32(r.data.get(i) as i32)?;
                         }
                     }
                 }
@@ -3556,7 +3934,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            bow.write_i16(r.data[i] as i16)?;
+                            bow.write_i16(r.data.get(i) as i16)?;
                         }
                     }
                 }
@@ -3565,7 +3943,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            bow.write_i8(r.data[i] as i8)?;
+                            bow.write_i8(r.data.get(i) as i8)?;
                         }
                     }
                 }
@@ -3587,7 +3965,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                         for row in 0..r.configs.rows {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                let val = r.data[i] as u32;
+                                let val = r.data.get(i) as u32;
                                 bytes[2] = ((val >> 16u32) & 0xFF) as u8; // blue
                                 bytes[1] = ((val >> 8u32) & 0xFF) as u8; // green
                                 bytes[0] = (val & 0xFF) as u8; // red
@@ -3601,7 +3979,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                         for row in 0..r.configs.rows {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                let val = r.data[i] as u32;
+                                let val = r.data.get(i) as u32;
                                 bytes[2] = ((val >> 16u32) & 0xFF) as u8; // blue
                                 bytes[1] = ((val >> 8u32) & 0xFF) as u8; // green
                                 bytes[0] = (val & 0xFF) as u8; // red
@@ -3645,7 +4023,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            bow.write_f64(r.data[i]).expect("Error writing byte data to file.");
+                            bow.write_f64(r.data.get(i)).expect("Error writing byte data to file.");
                         }
                     }
                 }
@@ -3654,7 +4032,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            bow.write_f32(r.data[i] as f32).expect("Error writing byte data to file.");
+                            bow.write_f32(r.data.get(i) as f32).expect("Error writing byte data to file.");
                         }
                     }
                 }
@@ -3663,7 +4041,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            bow.write_u64(r.data[i] as u64).expect("Error writing byte data to file.");
+                            bow.write_u64(r.data.get(i) as u64).expect("Error writing byte data to file.");
                         }
                     }
                 }
@@ -3672,7 +4050,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            bow.write_u32(r.data[i] as u32).expect("Error writing byte data to file.");
+                            bow.write_u32(r.data.get(i) as u32).expect("Error writing byte data to file.");
                         }
                     }
                 }
@@ -3681,7 +4059,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            bow.write_u16(r.data[i] as u16).expect("Error writing byte data to file.");
+                            bow.write_u16(r.data.get(i) as u16).expect("Error writing byte data to file.");
                         }
                     }
                 }
@@ -3690,7 +4068,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            bow.write_u8(r.data[i] as u8).expect("Error writing byte data to file.");
+                            bow.write_u8(r.data.get(i) as u8).expect("Error writing byte data to file.");
                         }
                     }
                 }
@@ -3699,7 +4077,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            bow.write_i64(r.data[i] as i64).expect("Error writing byte data to file.");
+                            bow.write_i64(r.data.get(i) as i64).expect("Error writing byte data to file.");
                         }
                     }
                 }
@@ -3708,7 +4086,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            bow.write_i32(r.data[i] as i32).expect("Error writing byte data to file.");
+                            bow.write_i32(r.data.get(i) as i32).expect("Error writing byte data to file.");
                         }
                     }
                 }
@@ -3717,7 +4095,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            bow.write_i16(r.data[i] as i16).expect("Error writing byte data to file.");
+                            bow.write_i16(r.data.get(i) as i16).expect("Error writing byte data to file.");
                         }
                     }
                 }
@@ -3726,7 +4104,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                     for row in 0..r.configs.rows {
                         for col in 0..r.configs.columns {
                             i = row * r.configs.columns + col;
-                            bow.write_i8(r.data[i] as i8).expect("Error writing byte data to file.");
+                            bow.write_i8(r.data.get(i) as i8).expect("Error writing byte data to file.");
                         }
                     }
                 }
@@ -3748,7 +4126,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                         for row in 0..r.configs.rows {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                let val = r.data[i] as u32;
+                                let val = r.data.get(i) as u32;
                                 bytes[2] = ((val >> 16u32) & 0xFF) as u8; // blue
                                 bytes[1] = ((val >> 8u32) & 0xFF) as u8; // green
                                 bytes[0] = (val & 0xFF) as u8; // red
@@ -3762,7 +4140,7 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
                         for row in 0..r.configs.rows {
                             for col in 0..r.configs.columns {
                                 i = row * r.configs.columns + col;
-                                let val = r.data[i] as u32;
+                                let val = r.data.get(i) as u32;
                                 bytes[2] = ((val >> 16u32) & 0xFF) as u8; // blue
                                 bytes[1] = ((val >> 8u32) & 0xFF) as u8; // green
                                 bytes[0] = (val & 0xFF) as u8; // red
@@ -4716,6 +5094,295 @@ pub fn write_geotiff<'a>(r: &'a mut Raster) -> Result<(), Error> {
 
 */
 
+// Appends a single sample of the given data type to a strip buffer.
+pub(crate) fn write_sample(
+    buf: &mut Vec<u8>,
+    data_type: DataType,
+    endian: Endianness,
+    value: f64,
+) -> Result<(), Error> {
+    if endian == Endianness::LittleEndian {
+        match data_type {
+            DataType::F64 => buf.write_f64::<LittleEndian>(value)?,
+            DataType::F32 => buf.write_f32::<LittleEndian>(value as f32)?,
+            DataType::U64 => buf.write_u64::<LittleEndian>(value as u64)?,
+            DataType::U32 => buf.write_u32::<LittleEndian>(value as u32)?,
+            DataType::U16 => buf.write_u16::<LittleEndian>(value as u16)?,
+            DataType::U8 => buf.write_u8(value as u8)?,
+            DataType::I64 => buf.write_i64::<LittleEndian>(value as i64)?,
+            DataType::I32 => buf.write_i32::<LittleEndian>(value as i32)?,
+            DataType::I16 => buf.write_i16::<LittleEndian>(value as i16)?,
+            DataType::I8 => buf.write_i8(value as i8)?,
+            _ => return Err(Error::new(ErrorKind::InvalidData, "Unknown data type.")),
+        }
+    } else {
+        match data_type {
+            DataType::F64 => buf.write_f64::<BigEndian>(value)?,
+            DataType::F32 => buf.write_f32::<BigEndian>(value as f32)?,
+            DataType::U64 => buf.write_u64::<BigEndian>(value as u64)?,
+            DataType::U32 => buf.write_u32::<BigEndian>(value as u32)?,
+            DataType::U16 => buf.write_u16::<BigEndian>(value as u16)?,
+            DataType::U8 => buf.write_u8(value as u8)?,
+            DataType::I64 => buf.write_i64::<BigEndian>(value as i64)?,
+            DataType::I32 => buf.write_i32::<BigEndian>(value as i32)?,
+            DataType::I16 => buf.write_i16::<BigEndian>(value as i16)?,
+            DataType::I8 => buf.write_i8(value as i8)?,
+            _ => return Err(Error::new(ErrorKind::InvalidData, "Unknown data type.")),
+        }
+    }
+    Ok(())
+}
+
+/// How the bytes of each strip or tile of an image are encoded, i.e. compressed and, for LZW
+/// and DEFLATE compression, differenced by a predictor beforehand.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct BlockEncoding {
+    pub compression: u16,
+    /// 1 (none), 2 (horizontal differencing), or 3 (floating-point).
+    pub predictor: u16,
+    pub samples_per_pixel: usize,
+    pub bytes_per_sample: usize,
+    pub endian: Endianness,
+}
+
+impl BlockEncoding {
+    /// Returns the encoding used to write a raster. The floating-point predictor is only
+    /// defined for floating-point data; integer rasters use horizontal differencing instead.
+    pub fn new(r: &Raster, compression: u16, predictor: u16) -> BlockEncoding {
+        let (samples_per_pixel, bytes_per_sample) = match r.configs.data_type {
+            DataType::RGB24 => (3, 1),
+            DataType::RGBA32 => (4, 1),
+            DataType::RGB48 => (3, 2),
+            DataType::U32 if r.configs.photometric_interp == PhotometricInterpretation::RGB => {
+                (4, 1)
+            }
+            data_type => {
+                let num_bands = r.num_bands();
+                if num_bands > 1 && !r.configs.band_sequential {
+                    (num_bands, data_type.get_data_size())
+                } else {
+                    (1, data_type.get_data_size())
+                }
+            }
+        };
+        let is_float = matches!(r.configs.data_type, DataType::F32 | DataType::F64);
+        let predictor = if compression != COMPRESS_LZW && compression != COMPRESS_DEFLATE {
+            1
+        } else {
+            match predictor {
+                3 if is_float => 3,
+                2 | 3 => 2,
+                _ => 1,
+            }
+        };
+        BlockEncoding {
+            compression,
+            predictor,
+            samples_per_pixel,
+            bytes_per_sample,
+            endian: r.configs.endian,
+        }
+    }
+}
+
+/// Decompresses the bytes of a single strip or tile, the rows of which are `row_length` bytes
+/// long, and reverses any predictor.
+pub(crate) fn decompress_block(
+    compressed: Vec<u8>,
+    encoding: &BlockEncoding,
+    row_length: usize,
+    max_uncompressed_length: usize,
+) -> Result<Vec<u8>, Error> {
+    let mut buf = match encoding.compression {
+        COMPRESS_NONE => compressed,
+        COMPRESS_PACKBITS => packbits_decoder(compressed),
+        COMPRESS_LZW => {
+            let mut buf = Vec::with_capacity(max_uncompressed_length);
+            let mut decoder = lzw::DecoderEarlyChange::new(lzw::MsbReader::new(), 8);
+            let mut bytes_read = 0;
+            while bytes_read < compressed.len() && buf.len() < max_uncompressed_length {
+                let (len, bytes) = decoder.decode_bytes(&compressed[bytes_read..]).map_err(|_| {
+                    Error::new(
+                        ErrorKind::InvalidData,
+                        "Error encountered while decoding the LZW compressed GeoTIFF file.",
+                    )
+                })?;
+                bytes_read += len;
+                buf.extend_from_slice(bytes);
+            }
+            buf
+        }
+        COMPRESS_DEFLATE => decompress_to_vec_zlib(&compressed).map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidData,
+                "Error encountered while decoding the DEFLATE compressed GeoTIFF file.",
+            )
+        })?,
+        _ => return Err(Error::new(
+            ErrorKind::InvalidData,
+            "The WhiteboxTools GeoTIFF decoder currently only supports PACKBITS, LZW, and DEFLATE compression.",
+        )),
+    };
+    if encoding.predictor > 1 {
+        for row in buf.chunks_mut(row_length.max(1)) {
+            undo_predictor(row, encoding);
+        }
+    }
+    Ok(buf)
+}
+
+/// Compresses the bytes of a single strip or tile, the rows of which are `row_length` bytes
+/// long, after applying any predictor. PackBits encodes each row separately, as the TIFF
+/// specification requires.
+pub(crate) fn compress_block(
+    data: &[u8],
+    encoding: &BlockEncoding,
+    row_length: usize,
+) -> Result<Vec<u8>, Error> {
+    let mut predicted;
+    let data = if encoding.predictor > 1 {
+        predicted = data.to_vec();
+        for row in predicted.chunks_mut(row_length.max(1)) {
+            apply_predictor(row, encoding);
+        }
+        &predicted[..]
+    } else {
+        data
+    };
+    match encoding.compression {
+        COMPRESS_NONE => Ok(data.to_vec()),
+        COMPRESS_DEFLATE => Ok(compress_to_vec_zlib(data, 6)),
+        COMPRESS_LZW => Ok(lzw_encoder(data)),
+        COMPRESS_PACKBITS => {
+            let mut output_data = Vec::with_capacity(data.len());
+            for row in data.chunks(row_length.max(1)) {
+                packbits_encoder(row, &mut output_data);
+            }
+            Ok(output_data)
+        }
+        _ => Err(Error::new(
+            ErrorKind::InvalidInput,
+            "The WhiteboxTools GeoTIFF encoder currently only supports PACKBITS, LZW, and DEFLATE compression.",
+        )),
+    }
+}
+
+/*
+The horizontal predictor (2) replaces each sample with its difference from the corresponding
+sample of the preceding pixel, in integer arithmetic. The floating-point predictor (3) first
+rearranges the bytes of a row such that the most significant bytes of each sample come first,
+followed by the next most significant bytes, and so on, and then differences each byte from the
+byte one pixel before it. Either way, rows of smoothly varying values become runs of small
+numbers, which compress far better.
+*/
+
+// Differences a single row of samples in place.
+fn apply_predictor(row: &mut [u8], encoding: &BlockEncoding) {
+    let spp = encoding.samples_per_pixel;
+    let bps = encoding.bytes_per_sample;
+    if encoding.predictor == 3 {
+        let wc = row.len() / bps;
+        let mut tmp = vec![0u8; row.len()];
+        for k in 0..wc {
+            for b in 0..bps {
+                tmp[b * wc + k] = row[bps * k + significance_position(b, bps, encoding.endian)];
+            }
+        }
+        for i in (spp..wc * bps).rev() {
+            tmp[i] = tmp[i].wrapping_sub(tmp[i - spp]);
+        }
+        row[..wc * bps].copy_from_slice(&tmp[..wc * bps]);
+    } else {
+        let stride = spp * bps;
+        let n = row.len() / bps * bps;
+        for i in (stride..n).step_by(bps).rev() {
+            let previous = read_uint(&row[i - stride..i - stride + bps], encoding.endian);
+            let value = read_uint(&row[i..i + bps], encoding.endian).wrapping_sub(previous);
+            write_uint(&mut row[i..i + bps], value, encoding.endian);
+        }
+    }
+}
+
+// Reverses the differencing of a single row of samples in place.
+fn undo_predictor(row: &mut [u8], encoding: &BlockEncoding) {
+    let spp = encoding.samples_per_pixel;
+    let bps = encoding.bytes_per_sample;
+    if encoding.predictor == 3 {
+        let wc = row.len() / bps;
+        for i in spp..wc * bps {
+            row[i] = row[i].wrapping_add(row[i - spp]);
+        }
+        let tmp = row[..wc * bps].to_vec();
+        for k in 0..wc {
+            for b in 0..bps {
+                row[bps * k + significance_position(b, bps, encoding.endian)] = tmp[b * wc + k];
+            }
+        }
+    } else {
+        let stride = spp * bps;
+        let n = row.len() / bps * bps;
+        for i in (stride..n).step_by(bps) {
+            let previous = read_uint(&row[i - stride..i - stride + bps], encoding.endian);
+            let value = read_uint(&row[i..i + bps], encoding.endian).wrapping_add(previous);
+            write_uint(&mut row[i..i + bps], value, encoding.endian);
+        }
+    }
+}
+
+// The position within a sample, in the given byte order, of its `b`th most significant byte.
+fn significance_position(b: usize, bps: usize, endian: Endianness) -> usize {
+    if endian == Endianness::BigEndian {
+        b
+    } else {
+        bps - 1 - b
+    }
+}
+
+// Reads an unsigned integer of one to eight bytes.
+fn read_uint(bytes: &[u8], endian: Endianness) -> u64 {
+    let mut value = 0u64;
+    for b in 0..bytes.len() {
+        value = (value << 8) | bytes[significance_position(b, bytes.len(), endian)] as u64;
+    }
+    value
+}
+
+// Writes the low-order bytes of an unsigned integer, discarding any overflow.
+fn write_uint(bytes: &mut [u8], value: u64, endian: Endianness) {
+    let bps = bytes.len();
+    for b in 0..bps {
+        bytes[significance_position(b, bps, endian)] = (value >> (8 * (bps - 1 - b))) as u8;
+    }
+}
+
+// Reads a single sample from a decoded strip or tile, given its SampleFormat and BitsPerSample.
+pub(crate) fn read_sample(
+    bor: &mut ByteOrderReader<Cursor<Vec<u8>>>,
+    sample_format: u16,
+    bits_per_sample: u16,
+) -> Result<f64, Error> {
+    let value = match (sample_format, bits_per_sample) {
+        // unsigned integer is the default when SampleFormat is absent
+        (0, 8) | (1, 8) => bor.read_u8()? as f64,
+        (0, 16) | (1, 16) => bor.read_u16()? as f64,
+        (0, 32) | (1, 32) => bor.read_u32()? as f64,
+        (0, 64) | (1, 64) => bor.read_u64()? as f64,
+        (2, 8) => bor.read_i8()? as f64,
+        (2, 16) => bor.read_i16()? as f64,
+        (2, 32) => bor.read_i32()? as f64,
+        (2, 64) => bor.read_i64()? as f64,
+        (3, 32) => bor.read_f32()? as f64,
+        (3, 64) => bor.read_f64()?,
+        _ => {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "The raster was not read correctly",
+            ))
+        }
+    };
+    Ok(value)
+}
+
 // An implimentation of a PackBits reader
 pub fn packbits_decoder(input_data: Vec<u8>) -> Vec<u8> {
     let mut output_data = vec![];
@@ -4739,6 +5406,104 @@ pub fn packbits_decoder(input_data: Vec<u8>) -> Vec<u8> {
     output_data
 }
 
+// An implementation of a PackBits writer. Runs of three or more identical bytes are replicated;
+// all other bytes are copied literally, in groups of at most 128.
+fn packbits_encoder(input_data: &[u8], output_data: &mut Vec<u8>) {
+    let n = input_data.len();
+    let mut i = 0usize;
+    while i < n {
+        // the length of the run beginning at i
+        let mut run = 1usize;
+        while i + run < n && run < 128 && input_data[i + run] == input_data[i] {
+            run += 1;
+        }
+        if run >= 3 {
+            output_data.push((257 - run) as u8);
+            output_data.push(input_data[i]);
+            i += run;
+        } else {
+            // copy literally up to the start of the next run of three
+            let start = i;
+            while i < n && i - start < 128 {
+                if i + 2 < n
+                    && input_data[i] == input_data[i + 1]
+                    && input_data[i] == input_data[i + 2]
+                {
+                    break;
+                }
+                i += 1;
+            }
+            output_data.push((i - start - 1) as u8);
+            output_data.extend_from_slice(&input_data[start..i]);
+        }
+    }
+}
+
+// An implementation of a TIFF LZW writer. TIFF LZW differs from that of GIF in that codes are
+// written most-significant bit first and the code width increases one code early, which is the
+// behaviour of libtiff and is expected by the decoder.
+fn lzw_encoder(input_data: &[u8]) -> Vec<u8> {
+    const CLEAR_CODE: u32 = 256;
+    const END_OF_INFORMATION: u32 = 257;
+    const FIRST_CODE: u32 = 258;
+    // the table is reset before it would require 13-bit codes
+    const MAX_CODE: u32 = 4094;
+
+    let mut output_data = Vec::with_capacity(input_data.len() / 2 + 16);
+    let mut bit_buffer = 0u64;
+    let mut num_bits = 0u32;
+    let mut put_code = |code: u32, width: u32, output_data: &mut Vec<u8>| {
+        bit_buffer = (bit_buffer << width) | code as u64;
+        num_bits += width;
+        while num_bits >= 8 {
+            num_bits -= 8;
+            output_data.push((bit_buffer >> num_bits) as u8);
+        }
+    };
+
+    // The string table maps a (prefix code, next byte) pair onto a code.
+    let mut table: HashMap<u32, u32> = HashMap::with_capacity(MAX_CODE as usize);
+    let mut width = 9u32;
+    let mut next_code = FIRST_CODE;
+    put_code(CLEAR_CODE, width, &mut output_data);
+    if let Some((&first, rest)) = input_data.split_first() {
+        let mut prefix = first as u32;
+        for &byte in rest {
+            let key = (prefix << 8) | byte as u32;
+            if let Some(&code) = table.get(&key) {
+                prefix = code;
+                continue;
+            }
+            put_code(prefix, width, &mut output_data);
+            table.insert(key, next_code);
+            next_code += 1;
+            if next_code == MAX_CODE {
+                put_code(CLEAR_CODE, width, &mut output_data);
+                table.clear();
+                width = 9;
+                next_code = FIRST_CODE;
+            } else if next_code > (1 << width) - 1 {
+                width += 1;
+            }
+            prefix = byte as u32;
+        }
+        put_code(prefix, width, &mut output_data);
+        // the decoder adds an entry upon reading the final code
+        next_code += 1;
+        if next_code == MAX_CODE {
+            put_code(CLEAR_CODE, width, &mut output_data);
+            width = 9;
+        } else if next_code > (1 << width) - 1 {
+            width += 1;
+        }
+    }
+    put_code(END_OF_INFORMATION, width, &mut output_data);
+    if num_bits > 0 {
+        output_data.push((bit_buffer << (8 - num_bits)) as u8);
+    }
+    output_data
+}
+
 pub fn write_u8<W: Write>(writer: &mut BufWriter<W>, value: u8) -> Result<(), Error> {
     writer.write_u8(value)
 }
@@ -4846,3 +5611,96 @@ pub fn write_f64<W: Write>(
         writer.write_f64::<BigEndian>(value)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{compress_block, decompress_block, BlockEncoding};
+    use crate::geotiff::tiff_consts::{COMPRESS_DEFLATE, COMPRESS_LZW};
+    use crate::Raster;
+    use whitebox_common::utils::Endianness;
+
+    fn fixture(name: &str) -> String {
+        format!("{}/tests/data/{}", env!("CARGO_MANIFEST_DIR"), name)
+    }
+
+    // The values written by tests/data/make_predictor_tiffs.py.
+    fn float_value(row: isize, col: isize, columns: isize) -> f64 {
+        (row * columns + col) as f64 * 0.75 - 3.1 + (row - col).pow(3) as f64 * 0.01
+    }
+
+    #[test]
+    fn test_read_float_predictor_f32_little_endian() {
+        let r = Raster::new(&fixture("float_predictor_f32_le.tif"), "r").unwrap();
+        assert_eq!((r.configs.rows, r.configs.columns), (5, 7));
+        for row in 0..5 {
+            for col in 0..7 {
+                let expected = float_value(row, col, 7) as f32 as f64;
+                assert_eq!(r.get_value(row, col), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_float_predictor_f64_big_endian() {
+        let r = Raster::new(&fixture("float_predictor_f64_be.tif"), "r").unwrap();
+        assert_eq!((r.configs.rows, r.configs.columns), (4, 6));
+        for row in 0..4 {
+            for col in 0..6 {
+                assert_eq!(r.get_value(row, col), float_value(row, col, 6));
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_horizontal_predictor_u16() {
+        let r = Raster::new(&fixture("horizontal_predictor_u16_le.tif"), "r").unwrap();
+        for row in 0..5 {
+            for col in 0..7 {
+                let expected = ((row * 977 + col * 23251) % 65536) as f64;
+                assert_eq!(r.get_value(row, col), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_predictor_round_trip() {
+        // rows of smoothly varying samples with some pseudo-random noise, which overflow
+        // when differenced
+        let mut seed = 12345u32;
+        let width = 9;
+        let num_rows = 3;
+        for &bytes_per_sample in &[1usize, 2, 4, 8] {
+            for &samples_per_pixel in &[1usize, 3] {
+                for &endian in &[Endianness::LittleEndian, Endianness::BigEndian] {
+                    let row_length = width * samples_per_pixel * bytes_per_sample;
+                    let data: Vec<u8> = (0..row_length * num_rows)
+                        .map(|i| {
+                            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+                            (i as u32 * 7 + (seed >> 28)) as u8
+                        })
+                        .collect();
+                    for &predictor in &[2u16, 3] {
+                        for &compression in &[COMPRESS_DEFLATE, COMPRESS_LZW] {
+                            let encoding = BlockEncoding {
+                                compression,
+                                predictor,
+                                samples_per_pixel,
+                                bytes_per_sample,
+                                endian,
+                            };
+                            let compressed = compress_block(&data, &encoding, row_length).unwrap();
+                            let decoded =
+                                decompress_block(compressed, &encoding, row_length, data.len())
+                                    .unwrap();
+                            assert_eq!(
+                                decoded, data,
+                                "predictor {} with {} bytes per sample",
+                                predictor, bytes_per_sample
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -9,7 +9,7 @@ use std::io::Error;
 pub fn read_grass_raster(
     file_name: &String,
     configs: &mut RasterConfigs,
-    data: &mut Vec<f64>,
+    data: &mut RasterData,
 ) -> Result<(), Error> {
     // read the file
     let f = File::open(file_name)?;
@@ -25,14 +25,8 @@ pub fn read_grass_raster(
         let vec = line_split.collect::<Vec<&str>>();
         if vec[0].to_lowercase().contains("rows") {
             configs.rows = vec[1].trim().parse::<f32>().unwrap() as usize;
-            if configs.columns > 0 {
-                data.reserve(configs.rows * configs.columns);
-            }
         } else if vec[0].to_lowercase().contains("cols") {
             configs.columns = vec[1].trim().parse::<f32>().unwrap() as usize;
-            if configs.rows > 0 {
-                data.reserve(configs.rows * configs.columns);
-            }
         } else if vec[0].to_lowercase().contains("north") {
             configs.north = vec[1].trim().to_string().parse::<f64>().unwrap();
         } else if vec[0].to_lowercase().contains("south") {
@@ -74,6 +68,13 @@ pub fn read_grass_raster(
             multiplier = vec[1].trim().to_string().parse::<f64>().unwrap();
         } else {
             // it's a data line
+            if data.is_empty() {
+                *data = RasterData::with_capacity(
+                    configs.data_type,
+                    configs.nodata,
+                    configs.rows * configs.columns,
+                );
+            }
             if !null_is_str {
                 let mut val_num;
                 for val in vec {
@@ -172,9 +173,9 @@ pub fn write_grass_raster<'a>(r: &'a mut Raster) -> Result<(), Error> {
     if r.configs.data_type == DataType::F32 || r.configs.data_type == DataType::F64 {
         for i in 0..num_cells {
             if col < r.configs.columns - 1 {
-                s2 += &format!("{:.*} ", 2, r.data[i]);
+                s2 += &format!("{:.*} ", 2, r.data.get(i));
             } else {
-                s2 += &format!("{:.*}\n", 2, r.data[i]);
+                s2 += &format!("{:.*}\n", 2, r.data.get(i));
             }
             col += 1;
             if col == r.configs.columns {
@@ -186,9 +187,9 @@ pub fn write_grass_raster<'a>(r: &'a mut Raster) -> Result<(), Error> {
     } else {
         for i in 0..num_cells {
             if col < r.configs.columns - 1 {
-                s2 += &format!("{:.*} ", 0, r.data[i]);
+                s2 += &format!("{:.*} ", 0, r.data.get(i));
             } else {
-                s2 += &format!("{:.*}\n", 0, r.data[i]);
+                s2 += &format!("{:.*}\n", 0, r.data.get(i));
             }
             col += 1;
             if col == r.configs.columns {
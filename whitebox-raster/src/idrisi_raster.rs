@@ -12,7 +12,7 @@ use std::mem;
 pub fn read_idrisi(
     file_name: &String,
     configs: &mut RasterConfigs,
-    data: &mut Vec<f64>,
+    data: &mut RasterData,
 ) -> Result<(), Error> {
     // read the header file
     // let header_file = file_name.replace(".rst", ".rdc");
@@ -127,7 +127,11 @@ pub fn read_idrisi(
     configs.resolution_x = (configs.east - configs.west) / configs.columns as f64;
     configs.resolution_y = (configs.north - configs.south) / configs.rows as f64;
 
-    data.reserve(configs.rows * configs.columns);
+    *data = RasterData::with_capacity(
+        configs.data_type,
+        configs.nodata,
+        configs.rows * configs.columns,
+    );
 
     // read the data file
     // let data_file = file_name.replace(".rdc", ".rst");
@@ -234,8 +238,7 @@ pub fn read_idrisi(
 
 pub fn write_idrisi<'a>(r: &'a mut Raster) -> Result<(), Error> {
     // figure out the minimum and maximum values
-    for val in &r.data {
-        let v = *val;
+    for v in r.data.iter() {
         if v != r.configs.nodata {
             if v < r.configs.minimum {
                 r.configs.minimum = v;
@@ -374,7 +377,7 @@ pub fn write_idrisi<'a>(r: &'a mut Raster) -> Result<(), Error> {
     match r.configs.data_type {
         DataType::F32 => {
             for i in 0..num_cells {
-                u32_bytes = unsafe { mem::transmute(r.data[i] as f32) };
+                u32_bytes = unsafe { mem::transmute(r.data.get(i) as f32) };
                 writer.write(&u32_bytes)?;
             }
         }
@@ -385,19 +388,19 @@ pub fn write_idrisi<'a>(r: &'a mut Raster) -> Result<(), Error> {
                 "Writing RGB24 raster is not currently supported.",
             ));
             // for i in 0..num_cells {
-            //     u24_bytes = unsafe { mem::transmute(r.data[i] as u32) };
+            //     u24_bytes = unsafe { mem::transmute(r.data.get(i) as u32) };
             //     writer.write(&u16_bytes)?;
             // }
         }
         DataType::I16 => {
             for i in 0..num_cells {
-                u16_bytes = unsafe { mem::transmute(r.data[i] as u16) };
+                u16_bytes = unsafe { mem::transmute(r.data.get(i) as u16) };
                 writer.write(&u16_bytes)?;
             }
         }
         DataType::U8 => {
             for i in 0..num_cells {
-                writer.write(&[r.data[i] as u8])?;
+                writer.write(&[r.data.get(i) as u8])?;
             }
         }
         _ => {
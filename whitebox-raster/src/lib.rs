@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 02/06/2017
-Last Modified: 09/02/2019
+Last Modified: 17/10/2026
 License: MIT
 */
 
@@ -15,10 +15,13 @@ License: MIT
 
 mod arcascii_raster;
 mod arcbinary_raster;
+mod cached_raster;
 mod esri_bil;
+mod geopackage_raster;
 pub mod geotiff;
 mod grass_raster;
 mod idrisi_raster;
+mod raster_data;
 mod saga_raster;
 mod surfer7_raster;
 mod surfer_ascii_raster;
@@ -26,16 +29,22 @@ mod whitebox_raster;
 
 use self::arcascii_raster::*;
 use self::arcbinary_raster::*;
+pub use self::cached_raster::*;
 use self::esri_bil::*;
+use self::geopackage_raster::*;
 use self::geotiff::*;
 use self::grass_raster::*;
 use self::idrisi_raster::*;
+pub use self::raster_data::*;
 use self::saga_raster::*;
 use self::surfer7_raster::*;
 use self::surfer_ascii_raster::*;
 use self::whitebox_raster::*;
+use whitebox_common::memory_store::{is_memory_file, MemoryStore};
+use whitebox_common::provenance::{current_provenance, Provenance};
 use whitebox_common::structures::{Array2D, BoundingBox};
 use whitebox_common::utils::*;
+use std::borrow::Cow;
 use std::cmp::Ordering::Equal;
 use std::default::Default;
 use std::f64;
@@ -44,7 +53,7 @@ use std::io::prelude::*;
 use std::io::BufReader;
 use std::io::Error;
 use std::io::ErrorKind;
-use std::ops::{AddAssign, Index, IndexMut, SubAssign};
+use std::ops::{AddAssign, SubAssign};
 use std::path::Path;
 use std::sync::mpsc;
 use std::sync::Arc;
@@ -52,8 +61,9 @@ use std::thread;
 // use rayon::prelude::*;
 
 /// Raster is a common data structure that abstracts over several raster data formats,
-/// including GeoTIFFs, ArcGIS ASCII and binary rasters, Whitebox rasters, Idrisi
-/// rasters, Saga rasters, and GRASS ASCII rasters.
+/// including GeoTIFFs, GeoPackage tile pyramids, ArcGIS ASCII and binary rasters, Whitebox
+/// rasters, Idrisi rasters, Saga rasters, and GRASS ASCII rasters. A raster layer within a
+/// GeoPackage holding several layers is addressed as 'file.gpkg:layer'.
 ///
 /// Examples:
 ///
@@ -71,60 +81,50 @@ pub struct Raster {
     pub file_mode: String,
     pub raster_type: RasterType,
     pub configs: RasterConfigs,
-    data: Vec<f64>,
+    data: RasterData,
+    // Cell values of the second and subsequent bands of a multiband raster. The
+    // first band is always held in `data`.
+    band_data: Vec<RasterData>,
 }
 
-impl Index<(isize, isize)> for Raster {
-    type Output = f64;
-
-    fn index<'a>(&'a self, index: (isize, isize)) -> &'a f64 {
-        let row = index.0;
-        let column = index.1;
-
-        if column < 0 {
-            return &self.configs.nodata;
-        }
-        if row < 0 {
-            return &self.configs.nodata;
-        }
-
-        let c: usize = column as usize;
-        let r: usize = row as usize;
-
-        if c >= self.configs.columns {
-            return &self.configs.nodata;
-        }
-        if r >= self.configs.rows {
-            return &self.configs.nodata;
-        }
-        let idx: usize = r * self.configs.columns + c;
-        &self.data[idx]
+/// The in-memory rasters, i.e. those with file names beginning with `mem://`. Writing
+/// such a raster stores a copy of it here, and reading it returns a copy.
+pub static MEMORY_RASTERS: MemoryStore<Raster> = MemoryStore::new();
+
+/// Reads the provenance record of a raster, i.e. the record of the tool run that wrote it.
+/// Only the headers of GeoTIFF and Whitebox rasters are read; other formats don't hold
+/// provenance records.
+pub fn read_provenance(file_name: &str) -> Result<Option<Provenance>, Error> {
+    if is_memory_file(file_name) {
+        return match MEMORY_RASTERS.get(file_name) {
+            Some(r) => Ok(r.configs.provenance),
+            None => Err(Error::new(
+                ErrorKind::NotFound,
+                format!("The in-memory raster {} does not exist.", file_name),
+            )),
+        };
     }
-}
-
-impl IndexMut<(isize, isize)> for Raster {
-    fn index_mut<'a>(&'a mut self, index: (isize, isize)) -> &'a mut f64 {
-        let row = index.0;
-        let column = index.1;
-        if column < 0 {
-            return &mut self.configs.nodata;
-        }
-        if row < 0 {
-            return &mut self.configs.nodata;
-        }
-        let c: usize = column as usize;
-        let r: usize = row as usize;
-        if c >= self.configs.columns {
-            return &mut self.configs.nodata;
-        }
-        if r >= self.configs.rows {
-            return &mut self.configs.nodata;
+    let mut configs = RasterConfigs::default();
+    match get_raster_type_from_file(file_name.to_string(), "r".to_string()) {
+        RasterType::GeoTiff => {
+            read_geotiff_layout(file_name, &mut configs)?;
+        }
+        RasterType::Whitebox => {
+            read_whitebox_header(file_name, &mut configs)?;
+        }
+        _ => {
+            if !Path::new(file_name).exists() {
+                return Err(Error::new(
+                    ErrorKind::NotFound,
+                    format!("The file {} does not exist.", file_name),
+                ));
+            }
         }
-        let idx = r * self.configs.columns + c;
-        &mut self.data[idx as usize]
     }
+    Ok(configs.provenance)
 }
 
+
 impl Raster {
     /// Creates an in-memory `Raster` object. The data are either
     /// read from an existing file (`file_name`; `file_mode` is 'r') or
@@ -135,59 +135,79 @@ impl Raster {
     /// `initialize_using_config` or `initialize_using_file` functions instead.
     pub fn new<'a>(file_name: &'a str, file_mode: &'a str) -> Result<Raster, Error> {
         let fm: String = file_mode.to_lowercase();
+        if is_memory_file(file_name) && fm.contains("r") {
+            let mut r = MEMORY_RASTERS.get(file_name).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::NotFound,
+                    format!("The in-memory raster {} does not exist.", file_name),
+                )
+            })?;
+            r.file_mode = fm;
+            r.update_min_max();
+            return Ok(r);
+        }
+        // files that don't specify a nodata value are given that of the default nodata setting
         let mut r = Raster {
             file_name: file_name.to_string(),
             file_mode: fm.clone(),
             raster_type: get_raster_type_from_file(file_name.to_string(), fm.clone()),
+            configs: RasterConfigs {
+                nodata: whitebox_common::configs::get_configs()?.default_nodata,
+                ..Default::default()
+            },
             ..Default::default()
         };
         if r.file_mode.contains("r") {
+            let data = &mut r.data;
             match get_raster_type_from_file(file_name.to_string(), fm) {
                 RasterType::ArcBinary => {
-                    let _ = read_arcbinary(&r.file_name, &mut r.configs, &mut r.data)?;
-                    return Ok(r);
+                    let _ = read_arcbinary(&r.file_name, &mut r.configs, data)?;
                 }
                 RasterType::ArcAscii => {
-                    let _ = read_arcascii(&r.file_name, &mut r.configs, &mut r.data)?;
-                    return Ok(r);
+                    let _ = read_arcascii(&r.file_name, &mut r.configs, data)?;
                 }
                 RasterType::EsriBil => {
-                    let _ = read_esri_bil(&r.file_name, &mut r.configs, &mut r.data)?;
-                    return Ok(r);
+                    let _ = read_esri_bil(&r.file_name, &mut r.configs, data)?;
+                }
+                RasterType::GeoPackage => {
+                    let _ = read_geopackage_raster(&r.file_name, &mut r.configs, data)?;
                 }
                 RasterType::GeoTiff => {
-                    let _ = read_geotiff(&r.file_name, &mut r.configs, &mut r.data)?;
-                    r.update_min_max();
-                    return Ok(r);
+                    let _ = read_geotiff(&r.file_name, &mut r.configs, data, &mut r.band_data)?;
                 }
                 RasterType::GrassAscii => {
-                    let _ = read_grass_raster(&r.file_name, &mut r.configs, &mut r.data)?;
-                    return Ok(r);
+                    let _ = read_grass_raster(&r.file_name, &mut r.configs, data)?;
                 }
                 RasterType::IdrisiBinary => {
-                    let _ = read_idrisi(&r.file_name, &mut r.configs, &mut r.data)?;
-                    return Ok(r);
+                    let _ = read_idrisi(&r.file_name, &mut r.configs, data)?;
                 }
                 RasterType::SagaBinary => {
-                    let _ = read_saga(&r.file_name, &mut r.configs, &mut r.data)?;
-                    return Ok(r);
+                    let _ = read_saga(&r.file_name, &mut r.configs, data)?;
                 }
                 RasterType::Surfer7Binary => {
-                    let _ = read_surfer7(&r.file_name, &mut r.configs, &mut r.data)?;
-                    return Ok(r);
+                    let _ = read_surfer7(&r.file_name, &mut r.configs, data)?;
                 }
                 RasterType::SurferAscii => {
-                    let _ = read_surfer_ascii_raster(&r.file_name, &mut r.configs, &mut r.data)?;
-                    return Ok(r);
+                    let _ = read_surfer_ascii_raster(&r.file_name, &mut r.configs, data)?;
                 }
                 RasterType::Whitebox => {
-                    let _ = read_whitebox(&r.file_name, &mut r.configs, &mut r.data)?;
-                    return Ok(r);
+                    let _ = read_whitebox(&r.file_name, &mut r.configs, data)?;
                 }
                 RasterType::Unknown => {
                     return Err(Error::new(ErrorKind::Other, "Unrecognized raster type"));
                 }
             }
+            // The readers decode values straight into storage suited to the raster's data
+            // type, which must also be able to hold each band's nodata value.
+            r.data.widen(r.configs.nodata);
+            for b in 1..r.num_bands() {
+                let nodata = r.get_band_nodata(b);
+                r.band_data[b - 1].widen(nodata);
+            }
+            if r.raster_type == RasterType::GeoTiff || r.raster_type == RasterType::GeoPackage {
+                r.update_min_max();
+            }
+            Ok(r)
         } else {
             // write
             return Ok(r);
@@ -198,12 +218,7 @@ impl Raster {
     /// Creates a new in-memory `Raster` object with grid extent and location
     /// based on specified configurations contained within a `RasterConfigs`.
     pub fn initialize_using_config<'a>(file_name: &'a str, configs: &'a RasterConfigs) -> Raster {
-        let new_file_name = if file_name.contains(".") {
-            file_name.to_string()
-        } else {
-            // likely no extension provided; default to .tif
-            format!("{}.tif", file_name)
-        };
+        let new_file_name = output_file_name(file_name);
         let mut output = Raster {
             file_name: new_file_name.clone(),
             // configs: configs.clone(),
@@ -243,10 +258,13 @@ impl Raster {
         {
             output.configs.nodata = 1.71041e38;
         }
-        output
-            .data
-            .reserve(output.configs.rows * output.configs.columns);
-        output.data = vec![output.configs.nodata; output.configs.rows * output.configs.columns];
+        let nodata = output.configs.nodata;
+        output.data = RasterData::new(
+            output.configs.data_type,
+            nodata,
+            output.configs.rows * output.configs.columns,
+            nodata,
+        );
 
         output
     }
@@ -254,12 +272,7 @@ impl Raster {
     /// Creates a new in-memory `Raster` object with grid extent and location based
     /// on an existing `Raster` contained within `file_name`.
     pub fn initialize_using_file<'a>(file_name: &'a str, input: &'a Raster) -> Raster {
-        let new_file_name = if file_name.contains(".") {
-            file_name.to_string()
-        } else {
-            // likely no extension provided; default to .tif
-            format!("{}.tif", file_name)
-        };
+        let new_file_name = output_file_name(file_name);
         let mut output = Raster {
             file_name: new_file_name.clone(),
             ..Default::default()
@@ -298,10 +311,13 @@ impl Raster {
         {
             output.configs.nodata = 1.71041e38;
         }
-        output
-            .data
-            .reserve(output.configs.rows * output.configs.columns);
-        output.data = vec![output.configs.nodata; output.configs.rows * output.configs.columns];
+        let nodata = output.configs.nodata;
+        output.data = RasterData::new(
+            output.configs.data_type,
+            nodata,
+            output.configs.rows * output.configs.columns,
+            nodata,
+        );
         output
     }
 
@@ -310,12 +326,7 @@ impl Raster {
         configs: &'a RasterConfigs,
         array: &'a Array2D<T>,
     ) -> Raster {
-        let new_file_name = if file_name.contains(".") {
-            file_name.to_string()
-        } else {
-            // likely no extension provided; default to .tif
-            format!("{}.tif", file_name)
-        };
+        let new_file_name = output_file_name(file_name);
         let mut output = Raster {
             file_name: new_file_name.clone(),
             ..Default::default()
@@ -356,14 +367,13 @@ impl Raster {
         {
             output.configs.nodata = 1.71041e38;
         }
-        output
-            .data
-            .reserve_exact(output.configs.rows * output.configs.columns);
+        let mut data = Vec::with_capacity(output.configs.rows * output.configs.columns);
         for row in 0..array.rows {
             for col in 0..array.columns {
-                output.data.push(array.get_value(row, col).into());
+                data.push(array.get_value(row, col).into());
             }
         }
+        output.data = RasterData::from_vec(data, output.configs.data_type, output.configs.nodata);
         output
     }
 
@@ -399,7 +409,7 @@ impl Raster {
             let r: usize = row as usize;
 
             let idx: usize = r * self.configs.columns + c;
-            return self.data[idx];
+            return self.data.get(idx);
         }
 
         // it's not within the area of the data
@@ -441,21 +451,27 @@ impl Raster {
             let r: usize = row as usize;
             if c < self.configs.columns && r < self.configs.rows {
                 let idx = r * self.configs.columns + c;
-                self.data[idx] = value;
+                self.set_data(idx, value);
             }
         }
     }
 
+    // Sets a cell of the first band, in the manner of `RasterData::set`.
+    #[inline]
+    fn set_data(&mut self, idx: usize, value: f64) {
+        self.data.set(idx, value);
+    }
+
     pub fn decrement(&mut self, row: isize, column: isize, value: f64) {
         if column >= 0 && row >= 0 {
             let c: usize = column as usize;
             let r: usize = row as usize;
             if c < self.configs.columns && r < self.configs.rows {
                 let idx = r * self.configs.columns + c;
-                if self.data[idx] != self.configs.nodata {
-                    self.data[idx] -= value;
+                if self.data.get(idx) != self.configs.nodata {
+                    self.set_data(idx, self.data.get(idx) - value);
                 } else {
-                    self.data[idx] = value;
+                    self.set_data(idx, value);
                 }
             }
         }
@@ -467,10 +483,10 @@ impl Raster {
             let r: usize = row as usize;
             if c < self.configs.columns && r < self.configs.rows {
                 let idx = r * self.configs.columns + c;
-                if self.data[idx] != self.configs.nodata {
-                    self.data[idx] += value;
+                if self.data.get(idx) != self.configs.nodata {
+                    self.set_data(idx, self.data.get(idx) + value);
                 } else {
-                    self.data[idx] = value;
+                    self.set_data(idx, value);
                 }
             }
         }
@@ -483,7 +499,7 @@ impl Raster {
                 let r: usize = row as usize;
                 if c < self.configs.columns && r < self.configs.rows {
                     let idx = r * self.configs.columns + c;
-                    self.data[idx] = values[c];
+                    self.set_data(idx, values[c]);
                 }
             }
         }
@@ -493,7 +509,7 @@ impl Raster {
         let mut values: Vec<f64> = vec![self.configs.nodata; self.configs.columns];
         if row >= 0 && row < self.configs.rows as isize {
             for column in 0..values.len() {
-                values[column] = self.data[row as usize * self.configs.columns + column];
+                values[column] = self.data.get(row as usize * self.configs.columns + column);
             }
         }
         values
@@ -510,10 +526,10 @@ impl Raster {
         }
         for column in 0..self.configs.columns {
             let idx = r * self.configs.columns + column;
-            if self.data[idx] != self.configs.nodata {
-                self.data[idx] += values[column];
+            if self.data.get(idx) != self.configs.nodata {
+                self.set_data(idx, self.data.get(idx) + values[column]);
             } else {
-                self.data[idx] = values[column];
+                self.set_data(idx, values[column]);
             }
         }
     }
@@ -529,10 +545,10 @@ impl Raster {
         }
         for column in 0..self.configs.columns {
             let idx = r * self.configs.columns + column;
-            if self.data[idx] != self.configs.nodata {
-                self.data[idx] -= values[column];
+            if self.data.get(idx) != self.configs.nodata {
+                self.set_data(idx, self.data.get(idx) - values[column]);
             } else {
-                self.data[idx] = values[column];
+                self.set_data(idx, values[column]);
             }
         }
     }
@@ -601,7 +617,7 @@ impl Raster {
         for row in 0..array.rows {
             for col in 0..array.columns {
                 i = row as usize * self.configs.columns + col as usize;
-                self.data[i] = array.get_value(row, col).into();
+                self.set_data(i, array.get_value(row, col).into());
             }
         }
         self.configs.nodata = array.nodata().into();
@@ -609,7 +625,7 @@ impl Raster {
     }
 
     pub fn reinitialize_values(&mut self, value: f64) {
-        self.data = vec![value; self.configs.rows * self.configs.columns];
+        self.data.fill(value);
     }
 
     pub fn get_value_as_rgba(&self, row: isize, column: isize) -> (u8, u8, u8, u8) {
@@ -630,7 +646,7 @@ impl Raster {
             return (0, 0, 0, 0);
         }
         let idx: usize = r * self.configs.columns + c;
-        let z = self.data[idx];
+        let z = self.data.get(idx);
 
         let r = (z as u32 & 0xFF) as u8;
         let g = ((z as u32 >> 8) & 0xFF) as u8;
@@ -647,15 +663,246 @@ impl Raster {
             if c < self.configs.columns && r < self.configs.rows {
                 let idx = r * self.configs.columns + c;
                 let (r, g, b, a) = rgba;
-                self.data[idx] += ((a << 24) | (b << 16) | (g << 8) | r) as f64;
+                self.set_data(
+                    idx,
+                    self.data.get(idx) + ((a << 24) | (b << 16) | (g << 8) | r) as f64,
+                );
             }
         }
     }
 
     /// Returns the size of the pixel data in bytes.
     pub fn get_data_size_in_bytes(&self) -> usize {
-        use std::mem;
-        mem::size_of_val(&*self.data)
+        self.data.size_in_bytes()
+            + self
+                .band_data
+                .iter()
+                .map(|b| b.size_in_bytes())
+                .sum::<usize>()
+    }
+
+    /// Holds the values of every band as `f64` from now on, so that values set in the raster
+    /// keep their full precision until it is written, whatever its data type. Otherwise,
+    /// single-precision values are rounded as they are set. Tools that read back the values
+    /// they've set, e.g. using an output raster as a working surface, call this first.
+    pub fn widen_to_f64(&mut self) {
+        self.data.widen_to_f64();
+        for data in self.band_data.iter_mut() {
+            data.widen_to_f64();
+        }
+    }
+
+    /// Returns the number of bands in the raster. Single-band rasters return 1.
+    pub fn num_bands(&self) -> usize {
+        1 + self.band_data.len()
+    }
+
+    /// Sets the number of bands in the raster. Bands that are added are filled
+    /// with the nodata value; bands beyond `bands` are discarded. A raster always
+    /// has at least one band and at most 255 bands.
+    pub fn set_num_bands(&mut self, bands: usize) {
+        let bands = bands.clamp(1, u8::MAX as usize);
+        let num_cells = self.num_cells();
+        let nodata = self.configs.nodata;
+        let data_type = self.configs.data_type;
+        self.band_data.truncate(bands - 1);
+        while self.band_data.len() < bands - 1 {
+            self.band_data
+                .push(RasterData::new(data_type, nodata, num_cells, nodata));
+        }
+        self.configs.band_nodata.truncate(bands);
+        self.configs.bands = bands as u8;
+    }
+
+    /// Returns the value contained within a grid cell of a band, where `band`
+    /// is zero-based. Off-grid cells are treated in the same way as `get_value`.
+    pub fn get_value_band(&self, band: usize, row: isize, column: isize) -> f64 {
+        if band == 0 {
+            return self.get_value(row, column);
+        }
+        if band >= self.num_bands() {
+            return self.configs.nodata;
+        }
+        match self.get_band_index(row, column) {
+            Some(idx) => self.band_data[band - 1].get(idx),
+            None => self.get_band_nodata(band),
+        }
+    }
+
+    /// Sets the value of a grid cell within a band, where `band` is zero-based.
+    pub fn set_value_band(&mut self, band: usize, row: isize, column: isize, value: f64) {
+        if band == 0 {
+            self.set_value(row, column, value);
+            return;
+        }
+        if band < self.num_bands()
+            && column >= 0
+            && row >= 0
+            && (column as usize) < self.configs.columns
+            && (row as usize) < self.configs.rows
+        {
+            let idx = row as usize * self.configs.columns + column as usize;
+            self.band_data[band - 1].set(idx, value);
+        }
+    }
+
+    // Returns the position of a cell within the band data, reflecting off-grid
+    // cells back onto the grid if `reflect_at_edges` is set.
+    fn get_band_index(&self, row: isize, column: isize) -> Option<usize> {
+        let rows = self.configs.rows as isize;
+        let columns = self.configs.columns as isize;
+        let (mut r, mut c) = (row, column);
+        if self.configs.reflect_at_edges {
+            if r < 0 {
+                r = -r - 1;
+            }
+            if r >= rows {
+                r = rows - (r - rows) - 1;
+            }
+            if c < 0 {
+                c = -c - 1;
+            }
+            if c >= columns {
+                c = columns - (c - columns) - 1;
+            }
+        }
+        if r >= 0 && c >= 0 && r < rows && c < columns {
+            Some(r as usize * self.configs.columns + c as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the nodata value of a band. Unless a band has been assigned its own
+    /// nodata value, it shares the raster's `configs.nodata` value.
+    pub fn get_band_nodata(&self, band: usize) -> f64 {
+        if band == 0 {
+            return self.configs.nodata;
+        }
+        match self.configs.band_nodata.get(band) {
+            Some(nodata) => *nodata,
+            None => self.configs.nodata,
+        }
+    }
+
+    /// Assigns a nodata value to a band. Setting the nodata value of the first
+    /// band also sets `configs.nodata`.
+    pub fn set_band_nodata(&mut self, band: usize, nodata: f64) {
+        if band >= self.num_bands() {
+            return;
+        }
+        if band == 0 {
+            self.configs.nodata = nodata;
+        }
+        let num_bands = self.num_bands();
+        if self.configs.band_nodata.len() < num_bands {
+            let default_nodata = self.configs.nodata;
+            self.configs.band_nodata.resize(num_bands, default_nodata);
+        }
+        self.configs.band_nodata[band] = nodata;
+    }
+
+    /// Returns the cell values of the first band, in row-major order. The typed
+    /// accessors of `RasterData` (e.g. `as_i16`) allow hot loops to read the values
+    /// in their storage type.
+    pub fn get_data(&self) -> &RasterData {
+        &self.data
+    }
+
+    /// Returns the cell values of a band, in row-major order.
+    pub fn get_band_data(&self, band: usize) -> &RasterData {
+        if band == 0 {
+            &self.data
+        } else {
+            &self.band_data[band - 1]
+        }
+    }
+
+    /// Returns an iterator over the cell values of a band, in row-major order.
+    pub fn iter_band(&self, band: usize) -> impl Iterator<Item = f64> + '_ {
+        self.get_band_data(band).iter()
+    }
+
+    /// Returns an iterator over the bands of the raster, yielding the cell values of
+    /// each band in turn.
+    pub fn iter_bands(&self) -> impl Iterator<Item = &RasterData> + '_ {
+        (0..self.num_bands()).map(move |band| self.get_band_data(band))
+    }
+
+    /// Calculates the minimum, maximum, mean, and standard deviation of the valid
+    /// (i.e. non-nodata) cells of a band.
+    pub fn calculate_band_statistics(&self, band: usize) -> BandStatistics {
+        let nodata = self.get_band_nodata(band);
+        let mut stats = BandStatistics {
+            minimum: f64::INFINITY,
+            maximum: f64::NEG_INFINITY,
+            ..Default::default()
+        };
+        let mut sum = 0f64;
+        let mut sq_sum = 0f64;
+        for value in self.iter_band(band) {
+            if value != nodata && !value.is_nan() {
+                stats.minimum = stats.minimum.min(value);
+                stats.maximum = stats.maximum.max(value);
+                sum += value;
+                sq_sum += value * value;
+                stats.num_valid_cells += 1;
+            }
+        }
+        if stats.num_valid_cells > 0 {
+            let n = stats.num_valid_cells as f64;
+            stats.mean = sum / n;
+            stats.std_dev = (sq_sum / n - stats.mean * stats.mean).max(0f64).sqrt();
+        }
+        stats
+    }
+
+    /// Returns a copy of a single band as a new single-band `Raster`, sharing the
+    /// file name, extent, and configurations of this raster.
+    pub fn get_band_as_raster(&self, band: usize) -> Raster {
+        let mut output = Raster {
+            file_name: self.file_name.clone(),
+            file_mode: self.file_mode.clone(),
+            raster_type: self.raster_type.clone(),
+            configs: self.configs.clone(),
+            data: self.get_band_data(band).clone(),
+            band_data: vec![],
+        };
+        output.configs.bands = 1;
+        output.configs.band_nodata.clear();
+        output.configs.nodata = self.get_band_nodata(band);
+        output.configs.display_min = f64::INFINITY;
+        output.configs.display_max = f64::NEG_INFINITY;
+        output.update_min_max();
+        output
+    }
+
+    /// Splits the raster into single-band rasters, one for each band. Unlike
+    /// `get_band_as_raster`, the cell values are moved rather than copied.
+    pub fn into_bands(mut self) -> Vec<Raster> {
+        let num_bands = self.num_bands();
+        let band_nodata: Vec<f64> = (0..num_bands).map(|b| self.get_band_nodata(b)).collect();
+        let mut band_data = std::mem::take(&mut self.band_data);
+        band_data.insert(0, std::mem::take(&mut self.data));
+        let mut bands = Vec::with_capacity(num_bands);
+        for (data, nodata) in band_data.into_iter().zip(band_nodata) {
+            let mut band = Raster {
+                file_name: self.file_name.clone(),
+                file_mode: self.file_mode.clone(),
+                raster_type: self.raster_type.clone(),
+                configs: self.configs.clone(),
+                data,
+                band_data: vec![],
+            };
+            band.configs.bands = 1;
+            band.configs.band_nodata.clear();
+            band.configs.nodata = nodata;
+            band.configs.display_min = f64::INFINITY;
+            band.configs.display_max = f64::NEG_INFINITY;
+            band.update_min_max();
+            bands.push(band);
+        }
+        bands
     }
 
     pub fn get_x_from_column(&self, column: isize) -> f64 {
@@ -683,7 +930,7 @@ impl Raster {
 
     pub fn clip_display_min_max(&mut self, percent: f64) {
         let t = (percent / 100.0 * (self.configs.rows * self.configs.columns) as f64) as usize;
-        let mut d = self.data.clone();
+        let mut d = self.data.to_vec();
         d.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
         let mut sum = 0;
         for i in 0..d.len() {
@@ -710,7 +957,7 @@ impl Raster {
 
     pub fn clip_display_min(&mut self, percent: f64) {
         let t = (percent / 100.0 * (self.configs.rows * self.configs.columns) as f64) as usize;
-        let mut d = self.data.clone();
+        let mut d = self.data.to_vec();
         d.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
         let mut sum = 0;
         for i in 0..d.len() {
@@ -726,7 +973,7 @@ impl Raster {
 
     pub fn clip_display_max(&mut self, percent: f64) {
         let t = (percent / 100.0 * (self.configs.rows * self.configs.columns) as f64) as usize;
-        let mut d = self.data.clone();
+        let mut d = self.data.to_vec();
         d.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
         let mut sum = 0;
         for i in (0..d.len()).rev() {
@@ -778,7 +1025,7 @@ impl Raster {
 
     pub fn clip_min_by_percent(&mut self, percent: f64) {
         let t = (percent / 100.0 * (self.configs.rows * self.configs.columns) as f64) as usize;
-        let mut d = self.data.clone();
+        let mut d = self.data.to_vec();
         d.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
         let mut sum = 0;
         let mut val = 0.0;
@@ -793,10 +1040,8 @@ impl Raster {
         }
 
         for i in 0..self.data.len() {
-            if self.data[i] != self.configs.nodata {
-                if self.data[i] < val {
-                    self.data[i] = val;
-                }
+            if self.data.get(i) != self.configs.nodata && self.data.get(i) < val {
+                self.set_data(i, val);
             }
         }
 
@@ -805,7 +1050,7 @@ impl Raster {
 
     pub fn clip_max_by_percent(&mut self, percent: f64) {
         let t = (percent / 100.0 * (self.configs.rows * self.configs.columns) as f64) as usize;
-        let mut d = self.data.clone();
+        let mut d = self.data.to_vec();
         d.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
         let mut sum = 0;
         let mut val = 0.0;
@@ -820,10 +1065,8 @@ impl Raster {
         }
 
         for i in 0..self.data.len() {
-            if self.data[i] != self.configs.nodata {
-                if self.data[i] > val {
-                    self.data[i] = val;
-                }
+            if self.data.get(i) != self.configs.nodata && self.data.get(i) > val {
+                self.set_data(i, val);
             }
         }
 
@@ -832,7 +1075,7 @@ impl Raster {
 
     pub fn clip_min_and_max_by_percent(&mut self, percent: f64) {
         let t = (percent / 100.0 * (self.configs.rows * self.configs.columns) as f64) as usize;
-        let mut d = self.data.clone();
+        let mut d = self.data.to_vec();
         d.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
         let mut sum = 0;
         let mut lower_val = 0.0;
@@ -859,11 +1102,11 @@ impl Raster {
         }
 
         for i in 0..self.data.len() {
-            if self.data[i] != self.configs.nodata {
-                if self.data[i] < lower_val {
-                    self.data[i] = lower_val;
-                } else if self.data[i] > upper_val {
-                    self.data[i] = upper_val;
+            if self.data.get(i) != self.configs.nodata {
+                if self.data.get(i) < lower_val {
+                    self.set_data(i, lower_val);
+                } else if self.data.get(i) > upper_val {
+                    self.set_data(i, upper_val);
                 }
             }
         }
@@ -887,7 +1130,7 @@ impl Raster {
                 let mut max_val = f64::NEG_INFINITY;
                 let mut value: f64;
                 for i in (0..values.len()).filter(|v| v % num_procs == tid) {
-                    value = values[i];
+                    value = values.get(i);
                     if value != nodata {
                         if value < min_val {
                             min_val = value;
@@ -947,7 +1190,7 @@ impl Raster {
             thread::spawn(move || {
                 let mut count = 0usize;
                 for i in (0..num_cells).filter(|r| r % num_procs == tid) {
-                    if values[i] != nodata {
+                    if values.get(i) != nodata {
                         count += 1;
                     }
                 }
@@ -979,8 +1222,8 @@ impl Raster {
                 let mut sum = 0.0f64;
                 let mut count = 0.0f64;
                 for i in (0..num_cells).filter(|r| r % num_procs == tid) {
-                    if values[i] != nodata {
-                        sum += values[i];
+                    if values.get(i) != nodata {
+                        sum += values.get(i);
                         count += 1.0;
                     }
                 }
@@ -1017,8 +1260,8 @@ impl Raster {
                 let mut sq_diff_sum = 0.0f64;
                 let mut count = 0.0f64;
                 for i in (0..num_cells).filter(|r| r % num_procs == tid) {
-                    if values[i] != nodata {
-                        sq_diff_sum += (values[i] - mean) * (values[i] - mean);
+                    if values.get(i) != nodata {
+                        sq_diff_sum += (values.get(i) - mean) * (values.get(i) - mean);
                         count += 1.0;
                     }
                     tx.send((sq_diff_sum, count)).unwrap();
@@ -1041,7 +1284,7 @@ impl Raster {
         let t = (percent / 100.0 * (self.configs.rows * self.configs.columns) as f64) as usize;
         let mut lower_tail = f64::NEG_INFINITY;
         let mut upper_tail = f64::NEG_INFINITY;
-        let mut d = self.data.clone();
+        let mut d = self.data.to_vec();
         d.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
         let mut sum = 0;
         for i in 0..d.len() {
@@ -1075,6 +1318,21 @@ impl Raster {
                 "Cannot write raster that is not created in write mmode ('w').",
             ));
         }
+        self.configs.provenance = current_provenance();
+        if is_memory_file(&self.file_name) {
+            MEMORY_RASTERS.insert(&self.file_name, self.clone());
+            return Ok(());
+        }
+        if self.num_bands() > 1 && self.raster_type != RasterType::GeoTiff {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "Only GeoTIFFs support multiple bands; {} has {} bands.",
+                    self.file_name,
+                    self.num_bands()
+                ),
+            ));
+        }
         match self.raster_type {
             RasterType::ArcAscii => {
                 let _ = match write_arcascii(self) {
@@ -1094,6 +1352,9 @@ impl Raster {
                     Err(e) => println!("error while writing: {:?}", e),
                 };
             }
+            RasterType::GeoPackage => {
+                write_geopackage_raster(self)?;
+            }
             RasterType::GeoTiff => {
                 let _ = match write_geotiff(self) {
                     Ok(_) => (),
@@ -1189,6 +1450,121 @@ impl Raster {
     }
 }
 
+/// The list of rasters supplied to a tool through an `--inputs` parameter. When the list
+/// names a single multiband raster, each of its bands is treated as a separate input.
+/// Otherwise, the files are only read as they are requested, one at a time.
+pub struct RasterInputs {
+    file_names: Vec<String>,
+    // the bands of a multiband input, which are read once and lent out by `get`
+    bands: Vec<Raster>,
+}
+
+impl RasterInputs {
+    pub fn new(file_names: &[String]) -> Result<RasterInputs, Error> {
+        let mut bands = vec![];
+        if file_names.len() == 1 {
+            let input = Raster::new(&file_names[0], "r")?;
+            bands = input.into_bands();
+        }
+        Ok(RasterInputs {
+            file_names: file_names.to_vec(),
+            bands,
+        })
+    }
+
+    /// Creates the list from the value of an `--inputs` parameter, in which the file names
+    /// are separated by semicolons or commas. File names that do not contain a directory
+    /// are assumed to be located within `working_directory`.
+    pub fn from_list(list: &str, working_directory: &str) -> Result<RasterInputs, Error> {
+        let sep = std::path::MAIN_SEPARATOR.to_string();
+        let delimiter = if list.contains(';') { ';' } else { ',' };
+        let mut file_names = vec![];
+        for value in list.split(delimiter) {
+            let mut file_name = value.trim().to_string();
+            if file_name.is_empty() {
+                continue;
+            }
+            if !file_name.contains(&sep) && !file_name.contains('/') {
+                file_name = format!("{}{}", working_directory, file_name);
+            }
+            file_names.push(file_name);
+        }
+        RasterInputs::new(&file_names)
+    }
+
+    /// Returns the number of inputs, i.e. the number of files, or the number of
+    /// bands if a single multiband raster was specified.
+    pub fn len(&self) -> usize {
+        if !self.bands.is_empty() {
+            return self.bands.len();
+        }
+        self.file_names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the input raster at `index`. The bands of a multiband input are borrowed,
+    /// while the files of a list are read as they are requested.
+    pub fn get(&self, index: usize) -> Result<Cow<'_, Raster>, Error> {
+        if !self.bands.is_empty() {
+            return match self.bands.get(index) {
+                Some(band) => Ok(Cow::Borrowed(band)),
+                None => Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Input band {} does not exist.", index + 1),
+                )),
+            };
+        }
+        match self.file_names.get(index) {
+            Some(file_name) => Ok(Cow::Owned(Raster::new(file_name, "r")?)),
+            None => Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("Input file {} does not exist.", index + 1),
+            )),
+        }
+    }
+
+    /// Returns the input raster at `index`, for tools that hold all of their inputs at
+    /// once. The bands of a multiband input are moved out of the list rather than
+    /// copied, and so each of them can only be taken once.
+    pub fn take(&mut self, index: usize) -> Result<Raster, Error> {
+        if !self.bands.is_empty() {
+            return match self.bands.get_mut(index) {
+                Some(band) => Ok(std::mem::take(band)),
+                None => Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Input band {} does not exist.", index + 1),
+                )),
+            };
+        }
+        self.get(index).map(Cow::into_owned)
+    }
+
+    /// Returns a short name for the input at `index`, suitable for reporting. This is
+    /// the file name without directory and extension, followed by the band number for
+    /// the bands of a multiband raster.
+    pub fn get_short_name(&self, index: usize) -> String {
+        let file_name = if self.file_names.len() == 1 {
+            &self.file_names[0]
+        } else {
+            match self.file_names.get(index) {
+                Some(f) => f,
+                None => return String::new(),
+            }
+        };
+        let stem = match Path::new(file_name).file_stem() {
+            Some(s) => s.to_str().unwrap_or("").to_string(),
+            None => String::new(),
+        };
+        if self.bands.len() > 1 {
+            return format!("{} (band {})", stem, index + 1);
+        }
+        stem
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct RasterConfigs {
     pub title: String,
@@ -1196,6 +1572,8 @@ pub struct RasterConfigs {
     pub columns: usize,
     pub bands: u8,
     pub nodata: f64,
+    pub band_nodata: Vec<f64>,
+    pub band_sequential: bool,
     pub north: f64,
     pub south: f64,
     pub east: f64,
@@ -1225,6 +1603,8 @@ pub struct RasterConfigs {
     pub geo_double_params: Vec<f64>,
     pub geo_ascii_params: String,
     pub metadata: Vec<String>,
+    /// The record of the tool run that produced the raster, if it is known.
+    pub provenance: Option<Provenance>,
 }
 
 impl Default for RasterConfigs {
@@ -1235,6 +1615,8 @@ impl Default for RasterConfigs {
             rows: 0,
             columns: 0,
             nodata: -32768.0,
+            band_nodata: vec![],
+            band_sequential: false,
             north: f64::NEG_INFINITY,
             south: f64::INFINITY,
             east: f64::NEG_INFINITY,
@@ -1264,16 +1646,28 @@ impl Default for RasterConfigs {
             geo_double_params: vec![],
             geo_ascii_params: String::new(),
             metadata: vec![],
+            provenance: None,
         }
     }
 }
 
+/// Summary statistics of the valid cells within a raster band.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct BandStatistics {
+    pub minimum: f64,
+    pub maximum: f64,
+    pub mean: f64,
+    pub std_dev: f64,
+    pub num_valid_cells: usize,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum RasterType {
     Unknown,
     ArcAscii,
     ArcBinary,
     EsriBil,
+    GeoPackage,
     GeoTiff,
     GrassAscii,
     IdrisiBinary,
@@ -1289,7 +1683,27 @@ impl Default for RasterType {
     }
 }
 
+// Output file names without an extension are given that of the default raster format
+// setting, e.g. 'tif'.
+pub(crate) fn output_file_name(file_name: &str) -> String {
+    if Path::new(file_name).extension().is_some() || is_memory_file(file_name) {
+        return file_name.to_string();
+    }
+    let format = whitebox_common::configs::get_configs()
+        .map(|c| c.default_raster_format)
+        .unwrap_or_else(|_| "tif".to_string());
+    format!("{}.{}", file_name, format.trim_start_matches('.'))
+}
+
 fn get_raster_type_from_file(file_name: String, file_mode: String) -> RasterType {
+    // in-memory rasters without an extension are treated as GeoTIFFs
+    if is_memory_file(&file_name) && Path::new(&file_name).extension().is_none() {
+        return RasterType::GeoTiff;
+    }
+    // the layers of a GeoPackage are addressed as 'file.gpkg:layer'
+    if whitebox_common::geopackage::is_geopackage(&file_name) {
+        return RasterType::GeoPackage;
+    }
     // get the file extension
     let extension: String = match Path::new(&file_name).extension().unwrap().to_str() {
         Some(n) => n.to_string().to_lowercase(),
@@ -1430,3 +1844,335 @@ impl Default for PhotometricInterpretation {
         PhotometricInterpretation::Unknown
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{
+        output_file_name, read_provenance, CachedRaster, DataType, PhotometricInterpretation,
+        Raster, RasterConfigs, RasterData, RasterInputs,
+    };
+    use std::borrow::Cow;
+    use whitebox_common::provenance::{set_current_provenance, Provenance};
+
+    #[test]
+    fn test_multiband_geotiff_round_trip() {
+        for &band_sequential in &[false, true] {
+            let file_name = std::env::temp_dir()
+                .join(format!("wbt_multiband_test_{}.tif", band_sequential))
+                .to_str()
+                .unwrap()
+                .to_string();
+            let configs = RasterConfigs {
+                rows: 3,
+                columns: 4,
+                north: 30.0,
+                south: 0.0,
+                east: 40.0,
+                west: 0.0,
+                resolution_x: 10.0,
+                resolution_y: 10.0,
+                nodata: -999.0,
+                data_type: DataType::F32,
+                photometric_interp: PhotometricInterpretation::Continuous,
+                ..Default::default()
+            };
+            let mut output = Raster::initialize_using_config(&file_name, &configs);
+            output.configs.band_sequential = band_sequential;
+            output.set_num_bands(3);
+            for band in 0..3 {
+                for row in 0..3 {
+                    for col in 0..4 {
+                        let value = (band * 100 + row * 10 + col) as f64;
+                        output.set_value_band(band, row as isize, col as isize, value);
+                    }
+                }
+            }
+            output.set_value_band(2, 1, 1, -999.0);
+            output.write().unwrap();
+
+            let input = Raster::new(&file_name, "r").unwrap();
+            assert_eq!(input.num_bands(), 3);
+            assert_eq!(input.configs.band_sequential, band_sequential);
+            assert_eq!(input.get_value(2, 3), 23.0);
+            assert_eq!(input.get_value_band(1, 0, 2), 102.0);
+            assert_eq!(input.get_value_band(2, 2, 0), 220.0);
+            let stats = input.calculate_band_statistics(2);
+            assert_eq!(stats.num_valid_cells, 11);
+            assert_eq!(stats.minimum, 200.0);
+            assert_eq!(stats.maximum, 223.0);
+            let band = input.get_band_as_raster(1);
+            assert_eq!(band.num_bands(), 1);
+            assert_eq!(band.configs.minimum, 100.0);
+            let _ = std::fs::remove_file(&file_name);
+        }
+    }
+
+    #[test]
+    fn test_multiband_raster_inputs() {
+        let file_name = std::env::temp_dir()
+            .join("wbt_raster_inputs_test.tif")
+            .to_str()
+            .unwrap()
+            .to_string();
+        let configs = RasterConfigs {
+            rows: 2,
+            columns: 2,
+            north: 20.0,
+            south: 0.0,
+            east: 20.0,
+            west: 0.0,
+            resolution_x: 10.0,
+            resolution_y: 10.0,
+            nodata: -999.0,
+            data_type: DataType::F32,
+            photometric_interp: PhotometricInterpretation::Continuous,
+            ..Default::default()
+        };
+        let mut output = Raster::initialize_using_config(&file_name, &configs);
+        output.set_num_bands(3);
+        for band in 0..3 {
+            output.set_value_band(band, 1, 1, band as f64 + 1.0);
+        }
+        output.write().unwrap();
+
+        let mut inputs = RasterInputs::from_list(&file_name, "").unwrap();
+        assert_eq!(inputs.len(), 3);
+        assert!(inputs.get_short_name(1).ends_with("(band 2)"));
+        // the bands are lent out rather than copied
+        assert!(matches!(inputs.get(1).unwrap(), Cow::Borrowed(_)));
+        assert_eq!(inputs.get(1).unwrap().get_value(1, 1), 2.0);
+        let band = inputs.take(2).unwrap();
+        assert_eq!(band.num_bands(), 1);
+        assert_eq!(band.get_value(1, 1), 3.0);
+        assert_eq!(band.configs.maximum, 3.0);
+        assert!(inputs.get(3).is_err());
+        let _ = std::fs::remove_file(&file_name);
+    }
+
+    #[test]
+    fn test_multiband_non_geotiff_write() {
+        let file_name = std::env::temp_dir()
+            .join("wbt_multiband_test.dep")
+            .to_str()
+            .unwrap()
+            .to_string();
+        let configs = RasterConfigs {
+            rows: 3,
+            columns: 4,
+            north: 30.0,
+            south: 0.0,
+            east: 40.0,
+            west: 0.0,
+            resolution_x: 10.0,
+            resolution_y: 10.0,
+            nodata: -999.0,
+            data_type: DataType::F32,
+            ..Default::default()
+        };
+        let mut output = Raster::initialize_using_config(&file_name, &configs);
+        output.set_num_bands(2);
+        let err = output.write().unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        assert!(!std::path::Path::new(&file_name).exists());
+
+        output.set_num_bands(1);
+        output.write().unwrap();
+        assert!(std::path::Path::new(&file_name).exists());
+        let _ = std::fs::remove_file(&file_name);
+        let _ = std::fs::remove_file(file_name.replace(".dep", ".tas"));
+    }
+
+    #[test]
+    fn test_cached_raster_round_trip() {
+        for extension in &["tif", "dep"] {
+            let file_name = std::env::temp_dir()
+                .join(format!("wbt_cached_raster_test.{}", extension))
+                .to_str()
+                .unwrap()
+                .to_string();
+            let configs = RasterConfigs {
+                rows: 50,
+                columns: 40,
+                north: 500.0,
+                south: 0.0,
+                east: 400.0,
+                west: 0.0,
+                resolution_x: 10.0,
+                resolution_y: 10.0,
+                nodata: -999.0,
+                data_type: DataType::F32,
+                photometric_interp: PhotometricInterpretation::Continuous,
+                ..Default::default()
+            };
+            let mut output = CachedRaster::initialize_using_config(&file_name, &configs).unwrap();
+            // a tiny cache forces blocks to be evicted and re-read.
+            output.set_cache_size(0);
+            for row in (0..45).step_by(15) {
+                let mut window = output.read_window(row, 0, 15, 40).unwrap();
+                for r in 0..15 {
+                    for c in 0..40 {
+                        window.set_value(r, c, ((row + r) * 100 + c) as f64);
+                    }
+                }
+                output.write_window(row, 0, &window).unwrap();
+            }
+            output.set_value(3, 4, -999.0).unwrap();
+            output.write().unwrap();
+
+            let input = Raster::new(&file_name, "r").unwrap();
+            assert_eq!(input.configs.rows, 50);
+            assert_eq!(input.get_value(44, 39), 4439.0);
+            assert_eq!(input.get_value(3, 4), -999.0);
+            // rows that were never written are nodata
+            assert_eq!(input.get_value(45, 0), -999.0);
+
+            let mut cached = CachedRaster::new(&file_name, "r").unwrap();
+            cached.set_cache_size(0);
+            let window = cached.read_window(-1, -1, 52, 42).unwrap();
+            for row in 0..50 {
+                for col in 0..40 {
+                    assert_eq!(window.get_value(row + 1, col + 1), input.get_value(row, col));
+                }
+            }
+            assert_eq!(window.get_value(0, 0), -999.0);
+            assert_eq!(cached.get_value(10, 20).unwrap(), 1020.0);
+            assert!(cached.set_value(0, 0, 1.0).is_err());
+            let _ = std::fs::remove_file(&file_name);
+            let _ = std::fs::remove_file(std::path::Path::new(&file_name).with_extension("tas"));
+        }
+    }
+
+    #[test]
+    fn test_typed_raster_storage() {
+        let configs = RasterConfigs {
+            rows: 10,
+            columns: 10,
+            nodata: -32768.0,
+            data_type: DataType::I16,
+            ..Default::default()
+        };
+        let mut r = Raster::initialize_using_config("pntr.tif", &configs);
+        assert_eq!(r.get_data().storage_type(), DataType::I16);
+        assert_eq!(r.get_data_size_in_bytes(), 200);
+        r.set_value(2, 3, 128.0);
+        assert_eq!(r.get_value(2, 3), 128.0);
+        assert_eq!(r.get_value(0, 0), -32768.0);
+        assert_eq!(r.get_data().as_i16().unwrap()[23], 128);
+
+        // a value that the storage can't hold exactly widens it
+        r.set_value(4, 4, 0.5);
+        assert_eq!(r.get_data().storage_type(), DataType::F64);
+        assert_eq!(r.get_value(4, 4), 0.5);
+        assert_eq!(r.get_value(2, 3), 128.0);
+
+        let mut data = RasterData::from_vec(vec![0.0, 255.0], DataType::U8, 0.0);
+        assert_eq!(data.storage_type(), DataType::U8);
+        data.set(0, -1.0);
+        assert_eq!(data.storage_type(), DataType::I16);
+        assert_eq!(data.to_vec(), vec![-1.0, 255.0]);
+        assert_eq!(
+            RasterData::from_vec(vec![1.0, 70000.0], DataType::I16, -1.0).storage_type(),
+            DataType::F64
+        );
+    }
+
+    #[test]
+    fn test_single_precision_storage() {
+        let file_name = std::env::temp_dir()
+            .join("wbt_single_precision_test.tif")
+            .to_str()
+            .unwrap()
+            .to_string();
+        let configs = RasterConfigs {
+            rows: 10,
+            columns: 10,
+            north: 100.0,
+            south: 0.0,
+            east: 100.0,
+            west: 0.0,
+            resolution_x: 10.0,
+            resolution_y: 10.0,
+            nodata: -32768.0,
+            data_type: DataType::F32,
+            photometric_interp: PhotometricInterpretation::Continuous,
+            ..Default::default()
+        };
+        let mut output = Raster::initialize_using_config(&file_name, &configs);
+        assert_eq!(output.get_data().storage_type(), DataType::F32);
+        assert_eq!(output.get_data_size_in_bytes(), 400);
+
+        // values keep the declared type, being rounded as they would be when written
+        output.set_value(0, 0, 0.5);
+        output.set_value(0, 1, 500.25);
+        output.set_value(0, 2, 0.1);
+        assert_eq!(output.get_data().storage_type(), DataType::F32);
+        assert_eq!(output.get_data_size_in_bytes(), 400);
+        assert_eq!(output.get_value(0, 2), 0.1f32 as f64);
+
+        // unless the caller opts in to full precision
+        let mut working = Raster::initialize_using_config(&file_name, &configs);
+        working.widen_to_f64();
+        working.set_value(0, 2, 0.1);
+        assert_eq!(working.get_data().storage_type(), DataType::F64);
+        assert_eq!(working.get_value(0, 2), 0.1);
+        output.write().unwrap();
+
+        // the file is decoded straight into single-precision storage
+        let input = Raster::new(&file_name, "r").unwrap();
+        assert_eq!(input.get_data().storage_type(), DataType::F32);
+        assert_eq!(input.get_value(0, 1), 500.25);
+        assert_eq!(input.get_value(0, 2), 0.1f32 as f64);
+        assert_eq!(input.get_value(9, 9), -32768.0);
+        let _ = std::fs::remove_file(&file_name);
+    }
+
+    #[test]
+    fn test_provenance_round_trip() {
+        let mut provenance = Provenance::new("Slope", "1.5.0");
+        provenance
+            .parameters
+            .insert("dem".to_string(), "<dem & \"1\">.tif".to_string());
+        set_current_provenance(Some(provenance.clone()));
+        for extension in &["tif", "dep"] {
+            let file_name = std::env::temp_dir()
+                .join(format!("wbt_provenance_test.{}", extension))
+                .to_str()
+                .unwrap()
+                .to_string();
+            let configs = RasterConfigs {
+                rows: 2,
+                columns: 2,
+                north: 20.0,
+                south: 0.0,
+                east: 20.0,
+                west: 0.0,
+                resolution_x: 10.0,
+                resolution_y: 10.0,
+                data_type: DataType::F32,
+                photometric_interp: PhotometricInterpretation::Continuous,
+                ..Default::default()
+            };
+            let mut output = Raster::initialize_using_config(&file_name, &configs);
+            output.set_value(0, 0, 1.0);
+            output.write().unwrap();
+            assert_eq!(
+                read_provenance(&file_name).unwrap(),
+                Some(provenance.clone())
+            );
+            let input = Raster::new(&file_name, "r").unwrap();
+            assert_eq!(input.configs.provenance, Some(provenance.clone()));
+            assert_eq!(input.get_value(0, 0), 1.0);
+            let _ = std::fs::remove_file(&file_name);
+            let _ = std::fs::remove_file(file_name.replace(".dep", ".tas"));
+        }
+        set_current_provenance(None);
+    }
+
+    #[test]
+    fn test_output_file_name() {
+        assert_eq!(output_file_name("dem.dep"), "dem.dep");
+        assert_eq!(output_file_name("mem://dem"), "mem://dem");
+        // a dot within a directory name is not an extension
+        assert_eq!(output_file_name("/data/v1.2/dem"), "/data/v1.2/dem.tif");
+    }
+}
@@ -0,0 +1,376 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 17/10/2026
+License: MIT
+*/
+
+use super::DataType;
+use std::mem;
+
+/// The in-memory cell values of a raster band. Rather than always holding values as `f64`,
+/// the storage follows the raster's data type, so that a byte raster occupies one byte per
+/// cell, a 16-bit integer raster (e.g. a D8 pointer) two bytes per cell, and a
+/// single-precision raster four bytes per cell. Values are read and written as `f64`.
+///
+/// Single-precision storage keeps its type, values being rounded to `f32` as they are set, just
+/// as they would be when the raster is written. Integer storage is widened whenever a value is
+/// set that it cannot hold exactly (including a change of nodata value), since truncating the
+/// value would alter it. A caller that needs values to be kept at full precision, e.g. a tool
+/// that uses its output raster as a working surface, opts in to this with `widen_to_f64`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RasterData {
+    U8(Vec<u8>),
+    I16(Vec<i16>),
+    F32(Vec<f32>),
+    F64(Vec<f64>),
+}
+
+impl Default for RasterData {
+    fn default() -> RasterData {
+        RasterData::F64(vec![])
+    }
+}
+
+impl RasterData {
+    /// Creates storage of `len` cells, each containing `value`, suited to `data_type`. The
+    /// storage is wide enough to hold both `value` and `nodata`.
+    pub fn new(data_type: DataType, nodata: f64, len: usize, value: f64) -> RasterData {
+        match storage_type(data_type, &[nodata, value]) {
+            DataType::U8 => RasterData::U8(vec![value as u8; len]),
+            DataType::I16 => RasterData::I16(vec![value as i16; len]),
+            DataType::F32 => RasterData::F32(vec![value as f32; len]),
+            _ => RasterData::F64(vec![value; len]),
+        }
+    }
+
+    /// Creates empty storage suited to `data_type`, with room for `capacity` cells. Values
+    /// are added using `push`, allowing readers to decode a file straight into the storage.
+    pub fn with_capacity(data_type: DataType, nodata: f64, capacity: usize) -> RasterData {
+        match storage_type(data_type, &[nodata]) {
+            DataType::U8 => RasterData::U8(Vec::with_capacity(capacity)),
+            DataType::I16 => RasterData::I16(Vec::with_capacity(capacity)),
+            DataType::F32 => RasterData::F32(Vec::with_capacity(capacity)),
+            _ => RasterData::F64(Vec::with_capacity(capacity)),
+        }
+    }
+
+    /// Creates storage suited to `data_type` from a vector of values. Single-precision values
+    /// are rounded; integer values are kept as `f64` unless every one of them, and `nodata`, can
+    /// be held by the narrower type.
+    pub fn from_vec(values: Vec<f64>, data_type: DataType, nodata: f64) -> RasterData {
+        let mut target = storage_type(data_type, &[nodata]);
+        if target == DataType::F64 {
+            return RasterData::F64(values);
+        }
+        if target == DataType::F32 {
+            return RasterData::F32(values.iter().map(|&v| v as f32).collect());
+        }
+        for &value in &values {
+            if target == DataType::U8 && !fits_u8(value) {
+                target = DataType::I16;
+            }
+            if target == DataType::I16 && !fits_i16(value) {
+                return RasterData::F64(values);
+            }
+        }
+        match target {
+            DataType::U8 => RasterData::U8(values.iter().map(|&v| v as u8).collect()),
+            _ => RasterData::I16(values.iter().map(|&v| v as i16).collect()),
+        }
+    }
+
+    /// Returns the number of cells.
+    pub fn len(&self) -> usize {
+        match self {
+            RasterData::U8(values) => values.len(),
+            RasterData::I16(values) => values.len(),
+            RasterData::F32(values) => values.len(),
+            RasterData::F64(values) => values.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the data type of the in-memory storage, i.e. `U8`, `I16`, `F32`, or `F64`.
+    pub fn storage_type(&self) -> DataType {
+        match self {
+            RasterData::U8(_) => DataType::U8,
+            RasterData::I16(_) => DataType::I16,
+            RasterData::F32(_) => DataType::F32,
+            RasterData::F64(_) => DataType::F64,
+        }
+    }
+
+    /// Returns the size of the storage in bytes.
+    pub fn size_in_bytes(&self) -> usize {
+        match self {
+            RasterData::U8(values) => mem::size_of_val(&**values),
+            RasterData::I16(values) => mem::size_of_val(&**values),
+            RasterData::F32(values) => mem::size_of_val(&**values),
+            RasterData::F64(values) => mem::size_of_val(&**values),
+        }
+    }
+
+    /// Returns the value of cell `i`.
+    #[inline]
+    pub fn get(&self, i: usize) -> f64 {
+        match self {
+            RasterData::U8(values) => values[i] as f64,
+            RasterData::I16(values) => values[i] as f64,
+            RasterData::F32(values) => values[i] as f64,
+            RasterData::F64(values) => values[i],
+        }
+    }
+
+    /// Sets the value of cell `i` in the storage's own type. Single-precision storage rounds
+    /// `value`; integer storage is widened if it cannot hold `value` exactly.
+    #[inline]
+    pub fn set(&mut self, i: usize, value: f64) {
+        match self {
+            RasterData::U8(values) if fits_u8(value) => values[i] = value as u8,
+            RasterData::I16(values) if fits_i16(value) => values[i] = value as i16,
+            RasterData::F32(values) => values[i] = value as f32,
+            RasterData::F64(values) => values[i] = value,
+            _ => {
+                self.widen(value);
+                self.set(i, value);
+            }
+        }
+    }
+
+    /// Appends a value, in the same manner as `set`.
+    #[inline]
+    pub fn push(&mut self, value: f64) {
+        match self {
+            RasterData::U8(values) if fits_u8(value) => values.push(value as u8),
+            RasterData::I16(values) if fits_i16(value) => values.push(value as i16),
+            RasterData::F32(values) => values.push(value as f32),
+            RasterData::F64(values) => values.push(value),
+            _ => {
+                self.widen(value);
+                self.push(value);
+            }
+        }
+    }
+
+    /// Sets every cell to `value`, in the same manner as `set`.
+    pub fn fill(&mut self, value: f64) {
+        self.widen(value);
+        match self {
+            RasterData::U8(values) => values.iter_mut().for_each(|v| *v = value as u8),
+            RasterData::I16(values) => values.iter_mut().for_each(|v| *v = value as i16),
+            RasterData::F32(values) => values.iter_mut().for_each(|v| *v = value as f32),
+            RasterData::F64(values) => values.iter_mut().for_each(|v| *v = value),
+        }
+    }
+
+    /// Widens integer storage, if necessary, so that it can hold `value` exactly.
+    pub fn widen(&mut self, value: f64) {
+        let new_data = match self {
+            RasterData::U8(values) if !fits_u8(value) => {
+                if fits_i16(value) {
+                    RasterData::I16(values.iter().map(|&v| v as i16).collect())
+                } else {
+                    RasterData::F64(values.iter().map(|&v| v as f64).collect())
+                }
+            }
+            RasterData::I16(values) if !fits_i16(value) => {
+                RasterData::F64(values.iter().map(|&v| v as f64).collect())
+            }
+            _ => return,
+        };
+        *self = new_data;
+    }
+
+    /// Converts the storage to `f64`, so that values are kept at full precision from now on,
+    /// whatever the data type.
+    pub fn widen_to_f64(&mut self) {
+        if let RasterData::F64(_) = self {
+            return;
+        }
+        *self = RasterData::F64(self.to_vec());
+    }
+
+    /// Returns the values if they are stored as `u8`.
+    pub fn as_u8(&self) -> Option<&[u8]> {
+        match self {
+            RasterData::U8(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if they are stored as `i16`.
+    pub fn as_i16(&self) -> Option<&[i16]> {
+        match self {
+            RasterData::I16(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if they are stored as `f32`.
+    pub fn as_f32(&self) -> Option<&[f32]> {
+        match self {
+            RasterData::F32(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if they are stored as `f64`.
+    pub fn as_f64(&self) -> Option<&[f64]> {
+        match self {
+            RasterData::F64(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the cell values.
+    pub fn iter(&self) -> impl Iterator<Item = f64> + '_ {
+        (0..self.len()).map(move |i| self.get(i))
+    }
+
+    /// Returns a copy of the cell values as `f64`.
+    pub fn to_vec(&self) -> Vec<f64> {
+        match self {
+            RasterData::F64(values) => values.clone(),
+            _ => self.iter().collect(),
+        }
+    }
+}
+
+// The narrowest storage for a data type that can also hold each of `values`.
+fn storage_type(data_type: DataType, values: &[f64]) -> DataType {
+    let mut target = match data_type {
+        DataType::U8 => DataType::U8,
+        DataType::I8 | DataType::I16 => DataType::I16,
+        DataType::F32 if values.iter().all(|&v| fits_f32(v)) => return DataType::F32,
+        _ => return DataType::F64,
+    };
+    for &value in values {
+        if target == DataType::U8 && !fits_u8(value) {
+            target = DataType::I16;
+        }
+        if target == DataType::I16 && !fits_i16(value) {
+            return DataType::F64;
+        }
+    }
+    target
+}
+
+#[inline]
+fn fits_u8(value: f64) -> bool {
+    (0f64..=255f64).contains(&value) && value.fract() == 0f64 && value.is_sign_positive()
+}
+
+#[inline]
+fn fits_i16(value: f64) -> bool {
+    (-32768f64..=32767f64).contains(&value)
+        && value.fract() == 0f64
+        && (value != 0f64 || value.is_sign_positive())
+}
+
+#[inline]
+fn fits_f32(value: f64) -> bool {
+    value as f32 as f64 == value || value.is_nan()
+}
+
+#[cfg(test)]
+mod test {
+    use super::RasterData;
+    use crate::DataType;
+
+    #[test]
+    fn test_storage_types() {
+        let storage = |data_type, nodata| RasterData::new(data_type, nodata, 4, nodata);
+        assert_eq!(storage(DataType::U8, 0.0).storage_type(), DataType::U8);
+        assert_eq!(storage(DataType::U8, -1.0).storage_type(), DataType::I16);
+        assert_eq!(storage(DataType::I8, -128.0).storage_type(), DataType::I16);
+        assert_eq!(
+            storage(DataType::I16, -32768.0).storage_type(),
+            DataType::I16
+        );
+        assert_eq!(
+            storage(DataType::I32, -32768.0).storage_type(),
+            DataType::F64
+        );
+        assert_eq!(
+            storage(DataType::F32, -32768.0).storage_type(),
+            DataType::F32
+        );
+        assert_eq!(
+            storage(DataType::F64, -32768.0).storage_type(),
+            DataType::F64
+        );
+        // a nodata value that a single-precision float can't hold exactly
+        assert_eq!(
+            storage(DataType::F32, 1.71041e38).storage_type(),
+            DataType::F64
+        );
+        assert_eq!(storage(DataType::F32, -32768.0).size_in_bytes(), 16);
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let data = RasterData::from_vec(vec![0.0, 255.0], DataType::U8, 0.0);
+        assert_eq!(data, RasterData::U8(vec![0, 255]));
+        let data = RasterData::from_vec(vec![-1.0, 255.0], DataType::U8, 0.0);
+        assert_eq!(data, RasterData::I16(vec![-1, 255]));
+        let data = RasterData::from_vec(vec![1.0, 1.5], DataType::I16, -1.0);
+        assert_eq!(data, RasterData::F64(vec![1.0, 1.5]));
+        let data = RasterData::from_vec(vec![0.5, -32768.0], DataType::F32, -32768.0);
+        assert_eq!(data, RasterData::F32(vec![0.5, -32768.0]));
+        assert_eq!(data.to_vec(), vec![0.5, -32768.0]);
+    }
+
+    #[test]
+    fn test_integer_widening() {
+        let mut data = RasterData::new(DataType::U8, 0.0, 3, 0.0);
+        data.set(0, 255.0);
+        assert_eq!(data.storage_type(), DataType::U8);
+        data.set(1, 256.0);
+        assert_eq!(data.storage_type(), DataType::I16);
+        // negative zero can't be held by integer storage
+        data.set(2, -0.0);
+        assert_eq!(data.storage_type(), DataType::F64);
+        assert!(data.get(2).is_sign_negative());
+        assert_eq!(data.to_vec(), vec![255.0, 256.0, -0.0]);
+
+        let mut data = RasterData::with_capacity(DataType::U8, 0.0, 3);
+        data.push(1.0);
+        data.push(-1.0);
+        assert_eq!(data, RasterData::I16(vec![1, -1]));
+        data.push(0.5);
+        assert_eq!(data, RasterData::F64(vec![1.0, -1.0, 0.5]));
+
+        let mut data = RasterData::new(DataType::I16, -1.0, 2, 0.0);
+        data.fill(70000.0);
+        assert_eq!(data, RasterData::F64(vec![70000.0; 2]));
+    }
+
+    #[test]
+    fn test_single_precision() {
+        let mut data = RasterData::new(DataType::F32, -32768.0, 3, -32768.0);
+        data.set(0, 0.5);
+        data.push(1e30f32 as f64);
+        data.fill(0.25);
+        assert_eq!(data.storage_type(), DataType::F32);
+        assert_eq!(data.as_f32(), Some(&[0.25f32; 4][..]));
+
+        // values are rounded rather than widening the storage, unless the caller opts in
+        data.set(1, 500.00001);
+        assert_eq!(data.storage_type(), DataType::F32);
+        assert_eq!(data.get(1), 500.00001f32 as f64);
+        let data = RasterData::from_vec(vec![0.1, -32768.0], DataType::F32, -32768.0);
+        assert_eq!(data, RasterData::F32(vec![0.1, -32768.0]));
+        assert_eq!(data.size_in_bytes(), 8);
+
+        let mut data = RasterData::F32(vec![0.25; 2]);
+        data.widen_to_f64();
+        data.set(1, 500.00001);
+        data.push(0.1);
+        assert_eq!(data, RasterData::F64(vec![0.25, 500.00001, 0.1]));
+    }
+}
@@ -10,7 +10,7 @@ use std::path::Path;
 pub fn read_saga(
     file_name: &String,
     configs: &mut RasterConfigs,
-    data: &mut Vec<f64>,
+    data: &mut RasterData,
 ) -> Result<(), Error> {
     // read the header file
     // let header_file = file_name.replace(".sdat", ".sgrd");
@@ -158,8 +158,6 @@ pub fn read_saga(
         row_start = configs.rows - 1;
     }
 
-    data.reserve(configs.rows * configs.columns);
-
     // read the data file
     // let data_file = file_name.replace(".sgrd", ".sdat");
     let data_file = Path::new(&file_name)
@@ -185,11 +183,7 @@ pub fn read_saga(
     };
 
     let num_cells = configs.rows * configs.columns;
-    data.clear();
-    data.reserve(num_cells);
-    for _ in 0..num_cells {
-        data.push(configs.nodata);
-    }
+    *data = RasterData::new(configs.data_type, configs.nodata, num_cells, configs.nodata);
 
     let buf_size = 1_000_000usize;
     let mut j = 0;
@@ -212,7 +206,7 @@ pub fn read_saga(
             DataType::F64 => {
                 for _ in 0..buf_size {
                     k = row * configs.columns + col;
-                    data[k] = bor.read_f64()? as f64 * z_factor;
+                    data.set(k, bor.read_f64()? as f64 * z_factor);
 
                     j += 1;
                     if j == num_cells {
@@ -232,7 +226,7 @@ pub fn read_saga(
             DataType::F32 => {
                 for _ in 0..buf_size {
                     k = row * configs.columns + col;
-                    data[k] = bor.read_f32()? as f64 * z_factor;
+                    data.set(k, bor.read_f32()? as f64 * z_factor);
 
                     j += 1;
                     if j == num_cells {
@@ -252,7 +246,7 @@ pub fn read_saga(
             DataType::I32 => {
                 for _ in 0..buf_size {
                     k = row * configs.columns + col;
-                    data[k] = bor.read_i32()? as f64 * z_factor;
+                    data.set(k, bor.read_i32()? as f64 * z_factor);
 
                     j += 1;
                     if j == num_cells {
@@ -272,7 +266,7 @@ pub fn read_saga(
             DataType::U32 => {
                 for _ in 0..buf_size {
                     k = row * configs.columns + col;
-                    data[k] = bor.read_u32()? as f64 * z_factor;
+                    data.set(k, bor.read_u32()? as f64 * z_factor);
 
                     j += 1;
                     if j == num_cells {
@@ -292,7 +286,7 @@ pub fn read_saga(
             DataType::I16 => {
                 for _ in 0..buf_size {
                     k = row * configs.columns + col;
-                    data[k] = bor.read_i16()? as f64 * z_factor;
+                    data.set(k, bor.read_i16()? as f64 * z_factor);
 
                     j += 1;
                     if j == num_cells {
@@ -312,7 +306,7 @@ pub fn read_saga(
             DataType::U16 => {
                 for _ in 0..buf_size {
                     k = row * configs.columns + col;
-                    data[k] = bor.read_u16()? as f64 * z_factor;
+                    data.set(k, bor.read_u16()? as f64 * z_factor);
 
                     j += 1;
                     if j == num_cells {
@@ -332,7 +326,7 @@ pub fn read_saga(
             DataType::I8 => {
                 for _ in 0..buf_size {
                     k = row * configs.columns + col;
-                    data[k] = bor.read_i8()? as f64 * z_factor;
+                    data.set(k, bor.read_i8()? as f64 * z_factor);
                     j += 1;
                     if j == num_cells {
                         break;
@@ -351,7 +345,7 @@ pub fn read_saga(
             DataType::U8 => {
                 for _ in 0..buf_size {
                     k = row * configs.columns + col;
-                    data[k] = bor.read_u8()? as f64 * z_factor;
+                    data.set(k, bor.read_u8()? as f64 * z_factor);
                     j += 1;
                     if j == num_cells {
                         break;
@@ -403,8 +397,7 @@ pub fn read_saga(
 
 pub fn write_saga<'a>(r: &'a mut Raster) -> Result<(), Error> {
     // figure out the minimum and maximum values
-    for val in &r.data {
-        let v = *val;
+    for v in r.data.iter() {
         if v != r.configs.nodata {
             if v < r.configs.minimum {
                 r.configs.minimum = v;
@@ -539,7 +532,7 @@ pub fn write_saga<'a>(r: &'a mut Raster) -> Result<(), Error> {
             for row in (0..r.configs.rows).rev() {
                 for col in 0..r.configs.columns {
                     i = row * r.configs.columns + col;
-                    u64_bytes = unsafe { mem::transmute(r.data[i]) };
+                    u64_bytes = unsafe { mem::transmute(r.data.get(i)) };
                     writer.write(&u64_bytes)?;
                 }
             }
@@ -548,7 +541,7 @@ pub fn write_saga<'a>(r: &'a mut Raster) -> Result<(), Error> {
             for row in (0..r.configs.rows).rev() {
                 for col in 0..r.configs.columns {
                     i = row * r.configs.columns + col;
-                    u32_bytes = unsafe { mem::transmute(r.data[i] as f32) };
+                    u32_bytes = unsafe { mem::transmute(r.data.get(i) as f32) };
                     writer.write(&u32_bytes)?;
                 }
             }
@@ -557,7 +550,7 @@ pub fn write_saga<'a>(r: &'a mut Raster) -> Result<(), Error> {
             for row in (0..r.configs.rows).rev() {
                 for col in 0..r.configs.columns {
                     i = row * r.configs.columns + col;
-                    u32_bytes = unsafe { mem::transmute(r.data[i] as i32) };
+                    u32_bytes = unsafe { mem::transmute(r.data.get(i) as i32) };
                     writer.write(&u32_bytes)?;
                 }
             }
@@ -566,7 +559,7 @@ pub fn write_saga<'a>(r: &'a mut Raster) -> Result<(), Error> {
             for row in (0..r.configs.rows).rev() {
                 for col in 0..r.configs.columns {
                     i = row * r.configs.columns + col;
-                    u32_bytes = unsafe { mem::transmute(r.data[i] as u32) };
+                    u32_bytes = unsafe { mem::transmute(r.data.get(i) as u32) };
                     writer.write(&u32_bytes)?;
                 }
             }
@@ -575,7 +568,7 @@ pub fn write_saga<'a>(r: &'a mut Raster) -> Result<(), Error> {
             for row in (0..r.configs.rows).rev() {
                 for col in 0..r.configs.columns {
                     i = row * r.configs.columns + col;
-                    u16_bytes = unsafe { mem::transmute(r.data[i] as i16) };
+                    u16_bytes = unsafe { mem::transmute(r.data.get(i) as i16) };
                     writer.write(&u16_bytes)?;
                 }
             }
@@ -584,7 +577,7 @@ pub fn write_saga<'a>(r: &'a mut Raster) -> Result<(), Error> {
             for row in (0..r.configs.rows).rev() {
                 for col in 0..r.configs.columns {
                     i = row * r.configs.columns + col;
-                    u16_bytes = unsafe { mem::transmute(r.data[i] as u16) };
+                    u16_bytes = unsafe { mem::transmute(r.data.get(i) as u16) };
                     writer.write(&u16_bytes)?;
                 }
             }
@@ -593,7 +586,7 @@ pub fn write_saga<'a>(r: &'a mut Raster) -> Result<(), Error> {
             for row in (0..r.configs.rows).rev() {
                 for col in 0..r.configs.columns {
                     i = row * r.configs.columns + col;
-                    writer.write(&[r.data[i] as u8])?;
+                    writer.write(&[r.data.get(i) as u8])?;
                 }
             }
         }
@@ -11,7 +11,7 @@ use std::mem;
 pub fn read_surfer7(
     file_name: &String,
     configs: &mut RasterConfigs,
-    data: &mut Vec<f64>,
+    data: &mut RasterData,
 ) -> Result<(), Error> {
     // read data file
     let mut f = File::open(file_name.clone())?;
@@ -120,8 +120,6 @@ pub fn read_surfer7(
     } as usize;
     offset += 4;
 
-    data.reserve(configs.rows * configs.columns);
-
     configs.west = unsafe {
         mem::transmute::<[u8; 8], f64>([
             buffer[offset],
@@ -246,10 +244,7 @@ pub fn read_surfer7(
     configs.data_type = DataType::F64;
 
     let num_cells = configs.rows * configs.columns;
-    data.clear();
-    for _ in 0..num_cells {
-        data.push(configs.nodata);
-    }
+    *data = RasterData::new(configs.data_type, configs.nodata, num_cells, configs.nodata);
 
     if version == 2 {
         let mut i: usize;
@@ -270,9 +265,9 @@ pub fn read_surfer7(
                     ])
                 };
                 if value != configs.nodata {
-                    data[i] = value;
+                    data.set(i, value);
                 } else {
-                    data[i] = configs.nodata;
+                    data.set(i, configs.nodata);
                 }
             }
         }
@@ -295,9 +290,9 @@ pub fn read_surfer7(
                     ])
                 };
                 if value <= configs.nodata {
-                    data[i] = value;
+                    data.set(i, value);
                 } else {
-                    data[i] = configs.nodata;
+                    data.set(i, configs.nodata);
                 }
             }
         }
@@ -310,8 +305,7 @@ pub fn read_surfer7(
 
 pub fn write_surfer7<'a>(r: &'a mut Raster) -> Result<(), Error> {
     // figure out the minimum and maximum values
-    for val in &r.data {
-        let v = *val;
+    for v in r.data.iter() {
         if v != r.configs.nodata {
             if v < r.configs.minimum {
                 r.configs.minimum = v;
@@ -385,7 +379,7 @@ pub fn write_surfer7<'a>(r: &'a mut Raster) -> Result<(), Error> {
     for row in (0..r.configs.rows).rev() {
         for col in 0..r.configs.columns {
             i = row * r.configs.columns + col;
-            u64_bytes = unsafe { mem::transmute(r.data[i]) };
+            u64_bytes = unsafe { mem::transmute(r.data.get(i)) };
             writer.write(&u64_bytes)?;
         }
     }
@@ -10,7 +10,7 @@ use std::io::ErrorKind;
 pub fn read_surfer_ascii_raster(
     file_name: &String,
     configs: &mut RasterConfigs,
-    data: &mut Vec<f64>,
+    data: &mut RasterData,
 ) -> Result<(), Error> {
     // read the file
     let f = File::open(file_name)?;
@@ -48,13 +48,9 @@ pub fn read_surfer_ascii_raster(
             }
             configs.columns = vec[0].trim().parse::<f32>().unwrap() as usize;
             configs.rows = vec[1].trim().parse::<f32>().unwrap() as usize;
-            data.reserve(configs.rows * configs.columns);
             row = configs.rows - 1; // files are stored row major, bottom-to-top
             num_cells = configs.rows * configs.columns;
-            data.clear();
-            for _ in 0..num_cells {
-                data.push(configs.nodata);
-            }
+            *data = RasterData::new(configs.data_type, configs.nodata, num_cells, configs.nodata);
         } else if line_num == 2 {
             if vec.len() != 2 {
                 return Err(Error::new(
@@ -94,9 +90,9 @@ pub fn read_surfer_ascii_raster(
                     i = row * configs.columns + col;
                     if !val.contains("1.71041e38") {
                         val_num = val.trim().to_string().parse::<f64>().unwrap();
-                        data[i] = val_num;
+                        data.set(i, val_num);
                     } else {
-                        data[i] = configs.nodata;
+                        data.set(i, configs.nodata);
                     }
                     col += 1;
                     if col == configs.columns {
@@ -122,8 +118,7 @@ pub fn write_surfer_ascii_raster<'a>(r: &'a mut Raster) -> Result<(), Error> {
     }
 
     // figure out the minimum and maximum values
-    for val in &r.data {
-        let v = *val;
+    for v in r.data.iter() {
         if v != r.configs.nodata {
             if v < r.configs.minimum {
                 r.configs.minimum = v;
@@ -155,14 +150,14 @@ pub fn write_surfer_ascii_raster<'a>(r: &'a mut Raster) -> Result<(), Error> {
         for col in 0..r.configs.columns {
             let i = row * r.configs.columns + col;
             if col < r.configs.columns - 1 {
-                if r.data[i] != r.configs.nodata {
-                    s2 += &format!("{:.*} ", num_decimals, r.data[i]);
+                if r.data.get(i) != r.configs.nodata {
+                    s2 += &format!("{:.*} ", num_decimals, r.data.get(i));
                 } else {
                     s2 += &format!("1.71041e38 ");
                 }
             } else {
-                if r.data[i] != r.configs.nodata {
-                    s2 += &format!("{:.*}\n", num_decimals, r.data[i]);
+                if r.data.get(i) != r.configs.nodata {
+                    s2 += &format!("{:.*}\n", num_decimals, r.data.get(i));
                 } else {
                     s2 += &format!("1.71041e38\n");
                 }
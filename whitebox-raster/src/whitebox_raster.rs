@@ -13,109 +13,9 @@ use std::path::Path;
 pub fn read_whitebox(
     file_name: &String,
     configs: &mut RasterConfigs,
-    data: &mut Vec<f64>,
+    data: &mut RasterData,
 ) -> Result<(), Error> {
-    // read the header file
-    // let header_file = file_name.replace(".tas", ".dep");
-    let header_file = Path::new(&file_name)
-        .with_extension("dep")
-        .into_os_string()
-        .into_string()
-        .unwrap();
-    let f = File::open(header_file)?;
-    let f = BufReader::new(f);
-
-    for line in f.lines() {
-        let line_unwrapped = line.unwrap();
-        // println!("{}", line_unwrapped);
-        let line_split = line_unwrapped.split(":");
-        let vec = line_split.collect::<Vec<&str>>();
-        if vec[0].to_lowercase().contains("rows") {
-            configs.rows = vec[1].trim().parse::<f32>().unwrap() as usize;
-        } else if vec[0].to_lowercase().contains("col") {
-            configs.columns = vec[1].trim().parse::<f32>().unwrap() as usize;
-        } else if vec[0].to_lowercase().contains("stacks") {
-            configs.bands = vec[1].trim().to_string().parse::<u8>().unwrap();
-        } else if vec[0].to_lowercase().contains("north") {
-            configs.north = vec[1].trim().to_string().parse::<f64>().unwrap();
-        } else if vec[0].to_lowercase().contains("south") {
-            configs.south = vec[1].trim().to_string().parse::<f64>().unwrap();
-        } else if vec[0].to_lowercase().contains("east") {
-            configs.east = vec[1].trim().to_string().parse::<f64>().unwrap();
-        } else if vec[0].to_lowercase().contains("west") {
-            configs.west = vec[1].trim().to_string().parse::<f64>().unwrap();
-        } else if vec[0].to_lowercase().contains("display min") {
-            configs.display_min = vec[1].trim().to_string().parse::<f64>().unwrap();
-        } else if vec[0].to_lowercase().contains("display max") {
-            configs.display_max = vec[1].trim().to_string().parse::<f64>().unwrap();
-        } else if vec[0].to_lowercase().contains("min")
-            && !vec[0].to_lowercase().contains("display")
-        {
-            configs.minimum = vec[1].trim().to_string().parse::<f64>().unwrap();
-        } else if vec[0].to_lowercase().contains("max")
-            && !vec[0].to_lowercase().contains("display")
-        {
-            configs.maximum = vec[1].trim().to_string().parse::<f64>().unwrap();
-        } else if vec[0].to_lowercase().contains("data type") {
-            if vec[1].trim().to_lowercase().to_string().contains("double") {
-                configs.data_type = DataType::F64;
-            } else if vec[1].trim().to_lowercase().to_string().contains("float") {
-                configs.data_type = DataType::F32;
-            } else if vec[1].trim().to_lowercase().to_string().contains("integer") {
-                configs.data_type = DataType::I16;
-            } else if vec[1].trim().to_lowercase().to_string().contains("byte") {
-                configs.data_type = DataType::U8;
-            } else if vec[1].trim().to_lowercase().to_string().contains("i32") {
-                configs.data_type = DataType::I32;
-            }
-        } else if vec[0].to_lowercase().contains("data scale") {
-            if vec[1]
-                .trim()
-                .to_lowercase()
-                .to_string()
-                .contains("continuous")
-            {
-                configs.photometric_interp = PhotometricInterpretation::Continuous;
-            } else if vec[1]
-                .trim()
-                .to_lowercase()
-                .to_string()
-                .contains("categorical")
-            {
-                configs.photometric_interp = PhotometricInterpretation::Categorical;
-            } else if vec[1].trim().to_lowercase().to_string().contains("boolean") {
-                configs.photometric_interp = PhotometricInterpretation::Boolean;
-            } else if vec[1].trim().to_lowercase().to_string().contains("rgb") {
-                configs.photometric_interp = PhotometricInterpretation::RGB;
-                configs.data_type = DataType::RGBA32;
-            }
-        } else if vec[0].to_lowercase().contains("z units") {
-            configs.z_units = vec[1].trim().to_string();
-        } else if vec[0].to_lowercase().contains("xy units") {
-            configs.xy_units = vec[1].trim().to_string();
-        } else if vec[0].to_lowercase().contains("projection") {
-            configs.projection = vec[1].trim().to_string();
-        } else if vec[0].to_lowercase().contains("nodata") {
-            configs.nodata = vec[1].trim().to_string().parse::<f64>().unwrap();
-        } else if vec[0].to_lowercase().contains("preferred palette") {
-            configs.palette = vec[1].trim().to_string();
-        } else if vec[0].to_lowercase().contains("nonlinearity") {
-            configs.palette_nonlinearity = vec[1].trim().to_string().parse::<f64>().unwrap();
-        } else if vec[0].to_lowercase().contains("byte order") {
-            if vec[1].trim().to_lowercase().contains("little")
-                || vec[1].trim().to_lowercase().contains("lsb")
-            {
-                configs.endian = Endianness::LittleEndian;
-            } else {
-                configs.endian = Endianness::BigEndian;
-            }
-        } else if vec[0].to_lowercase().contains("metadata") {
-            configs.metadata.push(vec[1].trim().to_string());
-        }
-    }
-
-    configs.resolution_x = (configs.east - configs.west) / configs.columns as f64;
-    configs.resolution_y = (configs.north - configs.south) / configs.rows as f64;
+    read_whitebox_header(file_name, configs)?;
 
     // read the data file
     // let data_file = file_name.replace(".dep", ".tas");
@@ -144,7 +44,11 @@ pub fn read_whitebox(
         1
     };
 
-    data.reserve(configs.rows * configs.columns);
+    *data = RasterData::with_capacity(
+        configs.data_type,
+        configs.nodata,
+        configs.rows * configs.columns,
+    );
 
     let num_cells = configs.rows * configs.columns;
     let buf_size = if num_cells > 10_000_000usize {
@@ -238,10 +142,120 @@ pub fn read_whitebox(
     Ok(())
 }
 
+/// Reads the header (.dep) file of a Whitebox raster into `configs`.
+pub(crate) fn read_whitebox_header(file_name: &str, configs: &mut RasterConfigs) -> Result<(), Error> {
+    // read the header file
+    // let header_file = file_name.replace(".tas", ".dep");
+    let header_file = Path::new(&file_name)
+        .with_extension("dep")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    let f = File::open(header_file)?;
+    let f = BufReader::new(f);
+
+    for line in f.lines() {
+        let line_unwrapped = line.unwrap();
+        // println!("{}", line_unwrapped);
+        let line_split = line_unwrapped.split(":");
+        let vec = line_split.collect::<Vec<&str>>();
+        if vec[0].to_lowercase().starts_with("provenance") {
+            // the record is JSON, which contains colons of its own
+            let json = line_unwrapped.split_once(':').map_or("", |(_, json)| json);
+            configs.provenance = Provenance::from_json(json.trim()).ok();
+        } else if vec[0].to_lowercase().contains("rows") {
+            configs.rows = vec[1].trim().parse::<f32>().unwrap() as usize;
+        } else if vec[0].to_lowercase().contains("col") {
+            configs.columns = vec[1].trim().parse::<f32>().unwrap() as usize;
+        } else if vec[0].to_lowercase().contains("stacks") {
+            configs.bands = vec[1].trim().to_string().parse::<u8>().unwrap();
+        } else if vec[0].to_lowercase().contains("north") {
+            configs.north = vec[1].trim().to_string().parse::<f64>().unwrap();
+        } else if vec[0].to_lowercase().contains("south") {
+            configs.south = vec[1].trim().to_string().parse::<f64>().unwrap();
+        } else if vec[0].to_lowercase().contains("east") {
+            configs.east = vec[1].trim().to_string().parse::<f64>().unwrap();
+        } else if vec[0].to_lowercase().contains("west") {
+            configs.west = vec[1].trim().to_string().parse::<f64>().unwrap();
+        } else if vec[0].to_lowercase().contains("display min") {
+            configs.display_min = vec[1].trim().to_string().parse::<f64>().unwrap();
+        } else if vec[0].to_lowercase().contains("display max") {
+            configs.display_max = vec[1].trim().to_string().parse::<f64>().unwrap();
+        } else if vec[0].to_lowercase().contains("min")
+            && !vec[0].to_lowercase().contains("display")
+        {
+            configs.minimum = vec[1].trim().to_string().parse::<f64>().unwrap();
+        } else if vec[0].to_lowercase().contains("max")
+            && !vec[0].to_lowercase().contains("display")
+        {
+            configs.maximum = vec[1].trim().to_string().parse::<f64>().unwrap();
+        } else if vec[0].to_lowercase().contains("data type") {
+            if vec[1].trim().to_lowercase().to_string().contains("double") {
+                configs.data_type = DataType::F64;
+            } else if vec[1].trim().to_lowercase().to_string().contains("float") {
+                configs.data_type = DataType::F32;
+            } else if vec[1].trim().to_lowercase().to_string().contains("integer") {
+                configs.data_type = DataType::I16;
+            } else if vec[1].trim().to_lowercase().to_string().contains("byte") {
+                configs.data_type = DataType::U8;
+            } else if vec[1].trim().to_lowercase().to_string().contains("i32") {
+                configs.data_type = DataType::I32;
+            }
+        } else if vec[0].to_lowercase().contains("data scale") {
+            if vec[1]
+                .trim()
+                .to_lowercase()
+                .to_string()
+                .contains("continuous")
+            {
+                configs.photometric_interp = PhotometricInterpretation::Continuous;
+            } else if vec[1]
+                .trim()
+                .to_lowercase()
+                .to_string()
+                .contains("categorical")
+            {
+                configs.photometric_interp = PhotometricInterpretation::Categorical;
+            } else if vec[1].trim().to_lowercase().to_string().contains("boolean") {
+                configs.photometric_interp = PhotometricInterpretation::Boolean;
+            } else if vec[1].trim().to_lowercase().to_string().contains("rgb") {
+                configs.photometric_interp = PhotometricInterpretation::RGB;
+                configs.data_type = DataType::RGBA32;
+            }
+        } else if vec[0].to_lowercase().contains("z units") {
+            configs.z_units = vec[1].trim().to_string();
+        } else if vec[0].to_lowercase().contains("xy units") {
+            configs.xy_units = vec[1].trim().to_string();
+        } else if vec[0].to_lowercase().contains("projection") {
+            configs.projection = vec[1].trim().to_string();
+        } else if vec[0].to_lowercase().contains("nodata") {
+            configs.nodata = vec[1].trim().to_string().parse::<f64>().unwrap();
+        } else if vec[0].to_lowercase().contains("preferred palette") {
+            configs.palette = vec[1].trim().to_string();
+        } else if vec[0].to_lowercase().contains("nonlinearity") {
+            configs.palette_nonlinearity = vec[1].trim().to_string().parse::<f64>().unwrap();
+        } else if vec[0].to_lowercase().contains("byte order") {
+            if vec[1].trim().to_lowercase().contains("little")
+                || vec[1].trim().to_lowercase().contains("lsb")
+            {
+                configs.endian = Endianness::LittleEndian;
+            } else {
+                configs.endian = Endianness::BigEndian;
+            }
+        } else if vec[0].to_lowercase().contains("metadata") {
+            configs.metadata.push(vec[1].trim().to_string());
+        }
+    }
+
+    configs.resolution_x = (configs.east - configs.west) / configs.columns as f64;
+    configs.resolution_y = (configs.north - configs.south) / configs.rows as f64;
+
+    Ok(())
+}
+
 pub fn write_whitebox<'a>(r: &'a mut Raster) -> Result<(), Error> {
     // figure out the minimum and maximum values
-    for val in &r.data {
-        let v = *val;
+    for v in r.data.iter() {
         if v != r.configs.nodata {
             if v < r.configs.minimum {
                 r.configs.minimum = v;
@@ -259,6 +273,92 @@ pub fn write_whitebox<'a>(r: &'a mut Raster) -> Result<(), Error> {
         r.configs.display_max = r.configs.maximum;
     }
 
+    write_whitebox_header(r)?;
+
+    // write the data file
+    // let data_file = r.file_name.replace(".dep", ".tas");
+    let data_file = Path::new(&r.file_name)
+        .with_extension("tas")
+        .into_os_string()
+        .into_string()
+        .unwrap();
+    let f = File::create(&data_file)?;
+    let mut writer = BufWriter::new(f);
+
+    // let mut u16_bytes: [u8; 2];
+    let mut u32_bytes: [u8; 4];
+    let mut u64_bytes: [u8; 8];
+
+    let num_cells: usize = r.configs.rows * r.configs.columns;
+    match r.configs.data_type {
+        DataType::F64 | DataType::U32 => {
+            if r.configs.photometric_interp != PhotometricInterpretation::RGB {
+                for i in 0..num_cells {
+                    u64_bytes = unsafe { mem::transmute(r.data.get(i)) };
+                    writer.write(&u64_bytes)?;
+                }
+            } else {
+                for i in 0..num_cells {
+                    u32_bytes = unsafe { mem::transmute(r.data.get(i) as u32) };
+                    writer.write(&u32_bytes)?;
+                }
+            }
+        }
+        DataType::F32 | DataType::U16 => {
+            for i in 0..num_cells {
+                writer.write_f32::<LittleEndian>(r.data.get(i) as f32)?;
+            }
+        }
+        DataType::I32 => {
+            for i in 0..num_cells {
+                writer.write_f32::<LittleEndian>(r.data.get(i) as f32)?;
+            }
+        }
+        DataType::RGBA32 => {
+            for i in 0..num_cells {
+                u32_bytes = unsafe { mem::transmute(r.data.get(i) as u32 as i32 as f32) };
+                writer.write(&u32_bytes)?;
+            }
+        }
+        DataType::RGB24 => {
+            // The Whitebox raster format doesn't really support a 24-bit RGB;
+            // instead use a 32-bit RGBa with saturated alpha channel.
+            let mut val: u32;
+            let alpha_mask = (255 << 24) as u32;
+            for i in 0..num_cells {
+                val = alpha_mask | (r.data.get(i) as u32);
+                u32_bytes = unsafe { mem::transmute(val) };
+                writer.write(&u32_bytes)?;
+            }
+        }
+        DataType::I16 => {
+            for i in 0..num_cells {
+                // u16_bytes = unsafe { mem::transmute(r.data.get(i) as u16) };
+                // writer.write(&u16_bytes)?;
+                writer.write_i16::<LittleEndian>(r.data.get(i) as i16)?;
+            }
+        }
+        DataType::U8 | DataType::I8 => {
+            for i in 0..num_cells {
+                writer.write(&[r.data.get(i) as u8])?;
+            }
+        }
+        _ => {
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                "Raster data type is unknown.",
+            ));
+        }
+    }
+
+    let _ = writer.flush();
+
+    Ok(())
+}
+
+/// Writes the header (.dep) file of a Whitebox raster, deleting any existing statistics
+/// (.wstat) file.
+pub(crate) fn write_whitebox_header(r: &mut Raster) -> Result<(), Error> {
     // Delete the wstat file if it exists
     // let wstat_string = r.file_name.replace(".tas", ".wstat").replace(".dep", ".wstat");
     let wstat_string = Path::new(&r.file_name)
@@ -410,82 +510,9 @@ pub fn write_whitebox<'a>(r: &'a mut Raster) -> Result<(), Error> {
         writer.write_all(s.as_bytes())?;
     }
 
-    let _ = writer.flush();
-
-    // write the data file
-    // let data_file = r.file_name.replace(".dep", ".tas");
-    let data_file = Path::new(&r.file_name)
-        .with_extension("tas")
-        .into_os_string()
-        .into_string()
-        .unwrap();
-    let f = File::create(&data_file)?;
-    let mut writer = BufWriter::new(f);
-
-    // let mut u16_bytes: [u8; 2];
-    let mut u32_bytes: [u8; 4];
-    let mut u64_bytes: [u8; 8];
-
-    let num_cells: usize = r.configs.rows * r.configs.columns;
-    match r.configs.data_type {
-        DataType::F64 | DataType::U32 => {
-            if r.configs.photometric_interp != PhotometricInterpretation::RGB {
-                for i in 0..num_cells {
-                    u64_bytes = unsafe { mem::transmute(r.data[i]) };
-                    writer.write(&u64_bytes)?;
-                }
-            } else {
-                for i in 0..num_cells {
-                    u32_bytes = unsafe { mem::transmute(r.data[i] as u32) };
-                    writer.write(&u32_bytes)?;
-                }
-            }
-        }
-        DataType::F32 | DataType::U16 => {
-            for i in 0..num_cells {
-                writer.write_f32::<LittleEndian>(r.data[i] as f32)?;
-            }
-        }
-        DataType::I32 => {
-            for i in 0..num_cells {
-                writer.write_f32::<LittleEndian>(r.data[i] as f32)?;
-            }
-        }
-        DataType::RGBA32 => {
-            for i in 0..num_cells {
-                u32_bytes = unsafe { mem::transmute(r.data[i] as u32 as i32 as f32) };
-                writer.write(&u32_bytes)?;
-            }
-        }
-        DataType::RGB24 => {
-            // The Whitebox raster format doesn't really support a 24-bit RGB;
-            // instead use a 32-bit RGBa with saturated alpha channel.
-            let mut val: u32;
-            let alpha_mask = (255 << 24) as u32;
-            for i in 0..num_cells {
-                val = alpha_mask | (r.data[i] as u32);
-                u32_bytes = unsafe { mem::transmute(val) };
-                writer.write(&u32_bytes)?;
-            }
-        }
-        DataType::I16 => {
-            for i in 0..num_cells {
-                // u16_bytes = unsafe { mem::transmute(r.data[i] as u16) };
-                // writer.write(&u16_bytes)?;
-                writer.write_i16::<LittleEndian>(r.data[i] as i16)?;
-            }
-        }
-        DataType::U8 | DataType::I8 => {
-            for i in 0..num_cells {
-                writer.write(&[r.data[i] as u8])?;
-            }
-        }
-        _ => {
-            return Err(Error::new(
-                ErrorKind::NotFound,
-                "Raster data type is unknown.",
-            ));
-        }
+    if let Some(provenance) = &r.configs.provenance {
+        let s = format!("Provenance:\t{}\n", provenance.to_json());
+        writer.write_all(s.as_bytes())?;
     }
 
     let _ = writer.flush();
@@ -0,0 +1,170 @@
+# Builds the small deflate-compressed TIFFs with the floating-point (3) and horizontal (2)
+# predictors that the GeoTIFF tests read, following TIFF 6.0 and Adobe Photoshop TIFF
+# Technical Note 3, independently of the WhiteboxTools encoder. The files decode to the
+# expected values with the tiff crate of the image-rs project.
+#
+# Usage: python3 make_predictor_tiffs.py whitebox-raster/tests/data
+
+import struct
+import sys
+import zlib
+
+# TIFF field types: SHORT, LONG and DOUBLE.
+SHORT = 3
+LONG = 4
+DOUBLE = 12
+FIELD_FORMATS = {SHORT: "H", LONG: "I", DOUBLE: "d"}
+
+STRIP_OFFSETS_TAG = 273
+
+
+def cell_values(rows, columns, kind):
+    """Returns the cell values of a test image in row-major order. Floating-point ('f')
+    images hold a non-linear surface, so that the predictor's byte planes vary, and
+    unsigned 16-bit ('u') images hold a pattern that wraps around."""
+    values = []
+    for row in range(rows):
+        for col in range(columns):
+            if kind == "f":
+                values.append((row * columns + col) * 0.75 - 3.1 + (row - col) ** 3 * 0.01)
+            else:
+                values.append((row * 977 + col * 23251) % 65536)
+    return values
+
+
+def encode_row(values, value_format, bytes_per_sample, predictor, endian):
+    """Encodes one row of an image with a predictor, before compression."""
+    raw = b"".join(struct.pack(endian + value_format, v) for v in values)
+
+    if predictor == 3:
+        # The floating-point predictor splits the row into byte planes, most significant
+        # byte first, and then differences consecutive bytes.
+        width = len(values)
+        planes = bytearray(width * bytes_per_sample)
+        for k in range(width):
+            sample = raw[k * bytes_per_sample:(k + 1) * bytes_per_sample]
+            if endian == "<":
+                sample = sample[::-1]
+            for b in range(bytes_per_sample):
+                planes[b * width + k] = sample[b]
+        for i in range(len(planes) - 1, 0, -1):
+            planes[i] = (planes[i] - planes[i - 1]) & 0xFF
+        return bytes(planes)
+
+    if predictor == 2:
+        # The horizontal predictor differences consecutive 16-bit samples.
+        samples = [
+            struct.unpack(endian + "H", raw[2 * i:2 * i + 2])[0] for i in range(len(values))
+        ]
+        differences = [samples[0]] + [
+            (samples[i] - samples[i - 1]) & 0xFFFF for i in range(1, len(samples))
+        ]
+        return b"".join(struct.pack(endian + "H", d) for d in differences)
+
+    return raw
+
+
+def write_tiff(
+    path,
+    rows,
+    columns,
+    value_format,
+    bytes_per_sample,
+    sample_format,
+    predictor,
+    endian,
+    rows_per_strip,
+):
+    """Writes a single-band GeoTIFF of deflate-compressed strips and returns its values."""
+    kind = "f" if sample_format == 3 else "u"
+    values = cell_values(rows, columns, kind)
+
+    strips = []
+    for first_row in range(0, rows, rows_per_strip):
+        last_row = min(rows, first_row + rows_per_strip)
+        data = b"".join(
+            encode_row(
+                values[row * columns:(row + 1) * columns],
+                value_format,
+                bytes_per_sample,
+                predictor,
+                endian,
+            )
+            for row in range(first_row, last_row)
+        )
+        strips.append(zlib.compress(data))
+
+    byte_order = b"II" if endian == "<" else b"MM"
+    header = byte_order + struct.pack(endian + "HI", 42, 8)
+
+    # (tag, type, values). The strip offsets are filled in once the layout is known. The
+    # GeoKeyDirectory holds only GTModelTypeGeoKey = 2 (geographic) and
+    # GTRasterTypeGeoKey = 1 (pixel is area).
+    entries = [
+        (256, SHORT, [columns]),
+        (257, SHORT, [rows]),
+        (258, SHORT, [bytes_per_sample * 8]),
+        (259, SHORT, [8]),
+        (262, SHORT, [1]),
+        (STRIP_OFFSETS_TAG, LONG, [0] * len(strips)),
+        (277, SHORT, [1]),
+        (278, SHORT, [rows_per_strip]),
+        (279, LONG, [len(strip) for strip in strips]),
+        (284, SHORT, [1]),
+        (317, SHORT, [predictor]),
+        (339, SHORT, [sample_format]),
+        (33550, DOUBLE, [0.5, 0.5, 0.0]),
+        (33922, DOUBLE, [0.0, 0.0, 0.0, -80.0, 45.0, 0.0]),
+        (34735, SHORT, [1, 1, 0, 2, 1024, 0, 1, 2, 1025, 0, 1, 1]),
+    ]
+
+    # Values that don't fit within an IFD entry are stored after the IFD, followed by
+    # the strips.
+    ifd_size = 2 + 12 * len(entries) + 4
+    arrays_offset = 8 + ifd_size
+    arrays = bytearray()
+    array_offsets = {}
+    for tag, field_type, field_values in entries:
+        size = struct.calcsize(FIELD_FORMATS[field_type]) * len(field_values)
+        if size > 4:
+            array_offsets[tag] = arrays_offset + len(arrays)
+            arrays += b"\0" * size
+
+    strip_offsets = []
+    offset = arrays_offset + len(arrays)
+    for strip in strips:
+        strip_offsets.append(offset)
+        offset += len(strip)
+
+    ifd = struct.pack(endian + "H", len(entries))
+    for tag, field_type, field_values in entries:
+        if tag == STRIP_OFFSETS_TAG:
+            field_values = strip_offsets
+        packed = b"".join(
+            struct.pack(endian + FIELD_FORMATS[field_type], v) for v in field_values
+        )
+        if tag in array_offsets:
+            start = array_offsets[tag] - arrays_offset
+            arrays[start:start + len(packed)] = packed
+            ifd += struct.pack(
+                endian + "HHII", tag, field_type, len(field_values), array_offsets[tag]
+            )
+        else:
+            ifd += struct.pack(endian + "HHI", tag, field_type, len(field_values))
+            ifd += packed.ljust(4, b"\0")
+    ifd += struct.pack(endian + "I", 0)
+
+    with open(path, "wb") as f:
+        f.write(header + ifd + bytes(arrays) + b"".join(strips))
+    return values
+
+
+def main():
+    out_dir = sys.argv[1]
+    write_tiff(out_dir + "/float_predictor_f32_le.tif", 5, 7, "f", 4, 3, 3, "<", 2)
+    write_tiff(out_dir + "/float_predictor_f64_be.tif", 4, 6, "d", 8, 3, 3, ">", 3)
+    write_tiff(out_dir + "/horizontal_predictor_u16_le.tif", 5, 7, "H", 2, 1, 2, "<", 2)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,143 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: An example of a plugin library of tools. Building it with
+
+    cargo build --release --example example_tool_plugin
+
+produces a dynamic library in target/release/examples, which adds the CountValidCells
+tool to whitebox_tools when it is copied into the 'plugins' directory next to the
+executable. The library must be rebuilt whenever whitebox_tools is updated.
+
+The tool reads its settings, e.g. max_procs, exactly as the built-in tools do; the
+host passes the settings of each run, including those given on the command line,
+to the library before running it.
+*/
+
+use serde_json::json;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+use std::sync::{mpsc, Arc};
+use std::thread;
+use whitebox_common::configs::get_configs;
+use whitebox_raster::Raster;
+use whitebox_tools::tools::{ToolContext, ToolRegistrar, WhiteboxTool};
+
+whitebox_tools::export_tool_plugin!(register);
+
+fn register(registrar: &mut dyn ToolRegistrar) {
+    registrar.register_tool(Box::new(CountValidCells));
+}
+
+/// Counts the cells of a raster that are not nodata.
+struct CountValidCells;
+
+impl WhiteboxTool for CountValidCells {
+    fn get_tool_name(&self) -> String {
+        "CountValidCells".to_string()
+    }
+
+    fn get_tool_description(&self) -> String {
+        "Counts the cells of a raster that are not nodata.".to_string()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        json!({"parameters": [{
+            "name": "Input File",
+            "flags": ["-i", "--input"],
+            "description": "Input raster file.",
+            "parameter_type": {"ExistingFile": "Raster"},
+            "default_value": null,
+            "optional": false
+        }]})
+        .to_string()
+    }
+
+    fn get_example_usage(&self) -> String {
+        ">>./whitebox_tools -r=CountValidCells -v --wd=\"/path/to/data/\" -i=DEM.tif".to_string()
+    }
+
+    fn get_toolbox(&self) -> String {
+        "Math and Stats Tools".to_string()
+    }
+
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn run(
+        &self,
+        args: Vec<String>,
+        working_directory: &str,
+        context: &ToolContext,
+    ) -> Result<(), Error> {
+        let input_file = args
+            .iter()
+            .find_map(|arg| {
+                let arg = arg.replace("\"", "");
+                let (flag, value) = arg.split_once('=')?;
+                match flag.to_lowercase().as_str() {
+                    "-i" | "--input" => Some(value.to_string()),
+                    _ => None,
+                }
+            })
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "No input file was specified."))?;
+        let input_file = if Path::new(&input_file).is_absolute() {
+            input_file
+        } else {
+            format!("{}{}", working_directory, input_file)
+        };
+
+        let configs = get_configs()?;
+        let num_procs = if configs.max_procs > 0 {
+            configs.max_procs
+        } else {
+            num_cpus::get() as isize
+        };
+
+        let input = Arc::new(Raster::new(&input_file, "r")?);
+        let rows = input.configs.rows as isize;
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let input = input.clone();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                let nodata = input.configs.nodata;
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let n = input
+                        .get_row_data(row)
+                        .iter()
+                        .filter(|&&z| z != nodata)
+                        .count();
+                    if tx.send(n).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        let mut num_valid_cells = 0usize;
+        let mut old_progress = usize::MAX;
+        for row in 0..rows {
+            num_valid_cells += rx
+                .recv()
+                .map_err(|_| Error::other("A worker thread failed."))?;
+            let progress = (100.0_f64 * (row + 1) as f64 / rows as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
+            }
+        }
+
+        if context.verbose {
+            context.info(format!("Number of valid cells: {}", num_valid_cells));
+        }
+        context.set_result("num_valid_cells", num_valid_cells);
+        context.set_result("num_procs", num_procs);
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+*/
+
+/*!
+The WhiteboxTools library, which contains the tools that are run by the
+`whitebox_tools` command-line program. Tools can be run from Rust code through a
+`ToolManager`, using typed arguments rather than command-line strings:
+
+```no_run
+use whitebox_tools::tools::{ToolArgs, ToolManager};
+
+let tm = ToolManager::new("/path/to/data/", &false)?;
+let args = ToolArgs::new()
+    .set("dem", "DEM.tif")
+    .set("output", "slope.tif")
+    .set("units", "percent");
+tm.run_tool_with("Slope", args)?;
+# Ok::<(), std::io::Error>(())
+```
+
+Tools report their progress and messages, and check for cancellation, through a
+`ToolContext`. Use `ToolManager::run_tool_with_context` to receive these through
+your own `ProgressSink` and `LogSink`, or to stop a tool with a `CancellationToken`.
+
+Rasters, vectors and LiDAR files with names beginning with `mem://`, e.g.
+`mem://filled.tif`, are held in memory rather than on disk, so that tools run within
+the same process can be chained without writing intermediate files. A `Workflow` of
+tool calls that pass such files to one another can be run with
+`ToolManager::run_workflow`, or from the command line with `--workflow`.
+*/
+
+pub mod tools;
+
+use nalgebra as na;
+
+#[macro_use]
+extern crate serde_derive;
@@ -2,7 +2,7 @@
 This code is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 21/06/2017
-Last Modified: 10/02/2021
+Last Modified: 17/10/2026
 License: MIT
 */
 
@@ -17,17 +17,24 @@ by the WhiteboxTools library:
 
 | Command           | Description                                                                                       |
 | ----------------- | ------------------------------------------------------------------------------------------------- |
+| --batch           | Runs a tool on each file matching a glob; --batch=dem --dem="*.tif" -o="{stem}_slope.tif".        |
 | --cd, --wd        | Changes the working directory; used in conjunction with --run flag.                               |
 | -h, --help        | Prints help information.                                                                          |
+| --json            | Prints the outcome of a tool run as JSON; used with --run flag; errors have a stable code.        |
 | -l, --license     | Prints the whitebox-tools license. Tool names may also be used, --license=\"Slope\"               |
 | --listtools       | Lists all available tools, with tool descriptions. Keywords may also be used, --listtools slope.  |
+| --provenance      | Prints the lineage of a file written by the tools; --provenance="slope.tif".                      |
 | -r, --run         | Runs a tool; used in conjunction with --cd flag; -r="LidarInfo".                                  |
+| --save_settings   | Saves the settings given on the command line, e.g. --max_procs=4, to the settings file.           |
+| --serve           | Runs a local HTTP/JSON service for listing and running tools; --serve=127.0.0.1:8125.             |
+| --schema          | Prints the JSON Schema of a tool's arguments, or of all tools (OpenAPI); --schema=Slope.          |
 | --toolbox         | Prints the toolbox associated with a tool; --toolbox=Slope.                                       |
 | --toolhelp        | Prints the help associated with a tool; --toolhelp="LidarInfo".                                   |
 | --toolparameters  | Prints the parameters (in json form) for a specific tool; --toolparameters=\"LidarInfo\".         |
 | -v                | Verbose mode. Without this flag, tool outputs will not be printed.                                |
 | --viewcode        | Opens the source code of a tool in a web browser; --viewcode=\"LidarInfo\".                       |
 | --version         | Prints the version information.                                                                   |
+| --workflow        | Runs a workflow of tool calls described in a JSON or YAML file; --workflow=\"pipeline.json\".      |
 
 */
 
@@ -37,19 +44,14 @@ by the WhiteboxTools library:
 // pub mod rendering;
 // pub mod spatial_ref_system;
 // pub mod structures;
-pub mod tools;
 // pub mod utils;
 // pub mod vector;
 
-use crate::tools::ToolManager;
-use nalgebra as na;
 // use rstar;
 use std::env;
 use std::io::Error;
 use std::path;
-
-#[macro_use]
-extern crate serde_derive;
+use whitebox_tools::tools::{ToolManager, Workflow, DEFAULT_SERVER_ADDRESS};
 
 // extern crate late_static;
 // use late_static::LateStatic;
@@ -93,10 +95,18 @@ fn run() -> Result<(), Error> {
     let mut run_tool = false;
     let mut tool_help = false;
     let mut tool_parameters = false;
+    let mut schema = false;
     let mut toolbox = false;
     let mut list_tools = false;
     let mut keywords: Vec<String> = vec![];
     let mut view_code = false;
+    let mut workflow_file = String::new();
+    let mut provenance_file = String::new();
+    let mut serve = false;
+    let mut server_address = String::new();
+    let mut batch = false;
+    let mut batch_arg = String::new();
+    let mut json = false;
     let mut tool_args_vec: Vec<String> = vec![];
     // let mut verbose = false;
     let mut finding_working_dir = false;
@@ -112,8 +122,10 @@ fn run() -> Result<(), Error> {
         return Ok(());
     }
 
-    let mut configs = whitebox_common::configs::get_configs()?;
-    let mut configs_modified = false;
+    // settings given on the command line apply to this run only, unless --save_settings
+    // is used to write them to the settings file
+    let mut overrides: Vec<(String, String)> = vec![];
+    let mut save_settings = false;
 
     // if args.contains(&String::from("--compress_rasters")) {
     //     // unsafe {
@@ -159,15 +171,8 @@ fn run() -> Result<(), Error> {
                 v.push_str(sep);
             }
             working_dir = v.to_string();
-
-            let sep = path::MAIN_SEPARATOR;
-            if !working_dir.ends_with(sep) {
-                working_dir.push_str(&(sep.to_string()));
-                configs.working_directory = working_dir.clone();
-            }
-            if configs.working_directory != working_dir { // update the value
-                configs.working_directory = working_dir.clone();
-                configs_modified = true;
+            if !finding_working_dir {
+                overrides.push(("working_directory".to_string(), working_dir.clone()));
             }
         } else if arg.starts_with("-run") || arg.starts_with("--run") || arg.starts_with("-r") {
             let mut v = arg
@@ -203,6 +208,17 @@ fn run() -> Result<(), Error> {
             }
             tool_name = v;
             tool_parameters = true;
+        } else if arg.starts_with("-schema") || arg.starts_with("--schema") {
+            let mut v = arg
+                .replace("--schema", "")
+                .replace("-schema", "")
+                .replace("\"", "")
+                .replace("\'", "");
+            if v.starts_with("=") {
+                v = v[1..v.len()].to_string();
+            }
+            tool_name = v;
+            schema = true;
         } else if arg.starts_with("-toolbox") || arg.starts_with("--toolbox") {
             let mut v = arg
                 .replace("--toolbox", "")
@@ -247,6 +263,7 @@ fn run() -> Result<(), Error> {
             if tool_name.starts_with("=") {
                 tool_name = tool_name[1..tool_name.len()].to_string();
                 if !tool_name.is_empty() {
+                    let configs = whitebox_common::configs::get_configs()?;
                     let tm = ToolManager::new(&configs.working_directory, &configs.verbose_mode)?;
                     return tm.tool_license(tool_name);
                 }
@@ -263,17 +280,8 @@ fn run() -> Result<(), Error> {
             if v.starts_with("=") {
                 v = v[1..v.len()].to_string();
             }
-            if v.to_lowercase().contains("t") || v.is_empty() {
-                if !configs.compress_rasters { // update value
-                    configs.compress_rasters = true;
-                    configs_modified = true;
-                }
-            } else {
-                if configs.compress_rasters { // update value
-                    configs.compress_rasters = false;
-                    configs_modified = true;
-                }
-            }
+            let compress = v.to_lowercase().contains("t") || v.is_empty();
+            overrides.push(("compress_rasters".to_string(), compress.to_string()));
         } else if arg.starts_with("-v") || arg.starts_with("--verbose") {
             let mut v = arg
                 .replace("-v", "")
@@ -284,17 +292,8 @@ fn run() -> Result<(), Error> {
             if v.starts_with("=") {
                 v = v[1..v.len()].to_string();
             }
-            if v.to_lowercase().contains("t") || v.is_empty() {
-                if !configs.verbose_mode {
-                    configs.verbose_mode = true;
-                    configs_modified = true;
-                }
-            } else {
-                if configs.verbose_mode {
-                    configs.verbose_mode = false;
-                    configs_modified = true;
-                }
-            }
+            let verbose = v.to_lowercase().contains("t") || v.is_empty();
+            overrides.push(("verbose_mode".to_string(), verbose.to_string()));
         } else if arg.starts_with("-max_procs") || arg.starts_with("--max_procs") {
             let mut v = arg
                 .replace("--max_procs", "")
@@ -304,11 +303,80 @@ fn run() -> Result<(), Error> {
             if v.starts_with("=") {
                 v = v[1..v.len()].to_string();
             }
-            let val = v.parse::<isize>().expect(&format!("Error parsing {}", v));
-            if val != configs.max_procs {
-                configs.max_procs = val;
-                configs_modified = true;
+            overrides.push(("max_procs".to_string(), v));
+        } else if arg.starts_with("-max_memory") || arg.starts_with("--max_memory") {
+            let mut v = arg
+                .replace("--max_memory", "")
+                .replace("-max_memory", "")
+                .replace("\"", "")
+                .replace("\'", "");
+            if v.starts_with("=") {
+                v = v[1..v.len()].to_string();
+            }
+            overrides.push(("max_memory_mb".to_string(), v));
+        } else if arg.starts_with("-default_raster_format")
+            || arg.starts_with("--default_raster_format")
+            || arg.starts_with("-default_nodata")
+            || arg.starts_with("--default_nodata")
+            || arg.starts_with("-temp_directory")
+            || arg.starts_with("--temp_directory")
+            || arg.starts_with("-hash_provenance_inputs")
+            || arg.starts_with("--hash_provenance_inputs")
+        {
+            // these flags are named after their settings
+            let (key, mut v) = match arg.trim_start_matches('-').split_once('=') {
+                Some((key, v)) => (key.to_string(), v.to_string()),
+                None => (arg.trim_start_matches('-').to_string(), String::new()),
+            };
+            v = v.replace("\"", "").replace("\'", "");
+            overrides.push((key, v));
+        } else if arg == "-save_settings" || arg == "--save_settings" {
+            save_settings = true;
+        } else if arg.starts_with("-workflow") || arg.starts_with("--workflow") {
+            let mut v = arg
+                .replace("--workflow", "")
+                .replace("-workflow", "")
+                .replace("\"", "")
+                .replace("\'", "");
+            if v.starts_with("=") {
+                v = v[1..v.len()].to_string();
+            }
+            workflow_file = v;
+        } else if arg.starts_with("-provenance") || arg.starts_with("--provenance") {
+            let mut v = arg
+                .replace("--provenance", "")
+                .replace("-provenance", "")
+                .replace("\"", "")
+                .replace("\'", "");
+            if v.starts_with("=") {
+                v = v[1..v.len()].to_string();
+            }
+            provenance_file = v;
+        } else if arg.starts_with("-serve") || arg.starts_with("--serve") {
+            let mut v = arg
+                .replace("--serve", "")
+                .replace("-serve", "")
+                .replace("\"", "")
+                .replace("\'", "");
+            if v.starts_with("=") {
+                v = v[1..v.len()].to_string();
             }
+            server_address = v;
+            serve = true;
+        } else if arg.starts_with("-batch") || arg.starts_with("--batch") {
+            // the value names the argument whose glob pattern is expanded
+            let mut v = arg
+                .replace("--batch", "")
+                .replace("-batch", "")
+                .replace("\"", "")
+                .replace("\'", "");
+            if v.starts_with("=") {
+                v = v[1..v.len()].to_string();
+            }
+            batch_arg = v;
+            batch = true;
+        } else if arg == "-json" || arg == "--json" {
+            json = true;
         } else if arg.starts_with("-version") || arg.starts_with("--version") {
             version();
             return Ok(());
@@ -335,9 +403,11 @@ fn run() -> Result<(), Error> {
             );
             if finding_working_dir {
                 working_dir = arg.trim().to_string().clone();
+                if !working_dir.ends_with(sep) {
+                    working_dir.push_str(sep);
+                }
                 finding_working_dir = false;
-                configs.working_directory = working_dir.clone();
-                configs_modified = true;
+                overrides.push(("working_directory".to_string(), working_dir.clone()));
             } else if tool_args_vec.len() > 0 {
                 tool_args_vec.push(arg.trim().to_string().clone());
             }
@@ -350,15 +420,65 @@ fn run() -> Result<(), Error> {
     //     configs.working_directory = working_dir.clone();
     // }
 
-    if configs_modified {
-        whitebox_common::configs::save_configs(&configs)?;
+    whitebox_common::configs::set_overrides(overrides.clone())?;
+    if save_settings {
+        // the settings file is only ever updated on request
+        let mut file_configs = whitebox_common::configs::read_settings_file()?;
+        for (key, value) in &overrides {
+            file_configs.set(key, value)?;
+        }
+        whitebox_common::configs::save_configs(&file_configs)?;
     }
+    let configs = whitebox_common::configs::get_configs()?;
 
     let tm = ToolManager::new(&configs.working_directory, &configs.verbose_mode)?;
-    if run_tool {
+    if !workflow_file.is_empty() {
+        if !workflow_file.contains(sep) && !workflow_file.contains("/") {
+            workflow_file = format!("{}{}", tm.working_dir, workflow_file);
+        }
+        return tm.run_workflow(&Workflow::from_file(&workflow_file)?);
+    } else if !provenance_file.is_empty() {
+        return tm.provenance(provenance_file);
+    } else if serve {
+        if server_address.is_empty() {
+            server_address = DEFAULT_SERVER_ADDRESS.to_string();
+        }
+        return tm.serve(&server_address);
+    } else if run_tool {
         if tool_name.is_empty() && keywords.len() > 0 {
             tool_name = keywords[0].clone();
         }
+        // the files of a batch are processed in parallel, up to max_procs at once
+        let max_jobs = if configs.max_procs > 0 {
+            configs.max_procs as usize
+        } else {
+            num_cpus::get()
+        };
+        if json {
+            // the report is the last line of the output, and failures set the exit code
+            let report = if batch {
+                tm.run_batch_report(&tool_name, tool_args_vec, &batch_arg, max_jobs)
+            } else {
+                tm.run_tool_report(&tool_name, tool_args_vec)
+            };
+            println!("{}", report.to_json());
+            if !report.is_ok() {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        if batch {
+            let results = tm.run_batch(&tool_name, tool_args_vec, &batch_arg, max_jobs)?;
+            let num_failed = results.iter().filter(|r| r.result.is_err()).count();
+            if num_failed > 0 {
+                return Err(Error::other(format!(
+                    "{} of the {} files of the batch failed.",
+                    num_failed,
+                    results.len()
+                )));
+            }
+            return Ok(());
+        }
         return tm.run_tool(tool_name, tool_args_vec);
     } else if tool_help {
         if tool_name.is_empty() && keywords.len() > 0 {
@@ -370,6 +490,15 @@ fn run() -> Result<(), Error> {
             tool_name = keywords[0].clone();
         }
         return tm.tool_parameters(tool_name);
+    } else if schema {
+        // without a tool name, the schemas of all of the tools are printed
+        let schema = if tool_name.is_empty() {
+            tm.schema()?
+        } else {
+            tm.tool_schema(&tool_name)?
+        };
+        println!("{}", serde_json::to_string_pretty(&schema)?);
+        return Ok(());
     } else if toolbox {
         if tool_name.is_empty() && keywords.len() > 0 {
             tool_name = keywords[0].clone();
@@ -405,17 +534,24 @@ fn help() {
     let s = "WhiteboxTools Help
 
 The following commands are recognized:
+--batch          Runs a tool on each file matching a glob; used with --run flag; --batch=dem --dem=\"*.tif\" -o=\"{stem}_slope.tif\".
 --cd, --wd       Changes the working directory; used in conjunction with --run flag.
 -h, --help       Prints help information.
+--json           Prints the outcome of a tool run as JSON; used with --run flag; errors have a stable code.
 -l, --license    Prints the whitebox-tools license. Tool names may also be used, --license=\"Slope\"
 --listtools      Lists all available tools. Keywords may also be used, --listtools slope.
+--provenance     Prints the lineage of a file written by the tools; --provenance=\"slope.tif\".
 -r, --run        Runs a tool; used in conjuction with --wd flag; -r=\"LidarInfo\".
+--save_settings  Saves the settings given on the command line, e.g. --max_procs=4, to the settings file.
+--serve          Runs a local HTTP/JSON service for listing and running tools; --serve=127.0.0.1:8125.
+--schema         Prints the JSON Schema of a tool's arguments, or of all tools (OpenAPI); --schema=Slope.
 --toolbox        Prints the toolbox associated with a tool; --toolbox=Slope.
 --toolhelp       Prints the help associated with a tool; --toolhelp=\"LidarInfo\".
 --toolparameters Prints the parameters (in json form) for a specific tool; --toolparameters=\"LidarInfo\".
 -v               Verbose mode. Without this flag, tool outputs will not be printed.
 --viewcode       Opens the source code of a tool in a web browser; --viewcode=\"LidarInfo\".
 --version        Prints the version information.
+--workflow       Runs a workflow of tool calls described in a JSON or YAML file; --workflow=\"pipeline.json\".
 
 Example Usage:
 >> .*EXE_NAME -r=lidar_info --cd=\"*path*to*data*\" -i=input.las --vlr --geokeys
@@ -0,0 +1,402 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: In batch mode, a tool is run once for each of the files that match a glob
+pattern, e.g. --input='*.tif', given as the value of the argument named by --batch,
+e.g. --batch=input. Other arguments are never expanded, even if they contain glob
+characters, so literal file names such as 'dem[1].tif' are passed on unchanged.
+The other arguments may contain the placeholders {stem}, {name}, {ext} and {dir},
+which are replaced by the file stem, file name, extension and directory of each
+input file, e.g. --output='{stem}_slope.tif'. Every output file argument must
+contain {stem} or {name}, so that the runs don't overwrite one another's outputs.
+Files are processed in parallel, up to a maximum number at once, and the processors
+are shared out between them, so that the tools run with fewer threads each. The runs
+are quiet; each file's failure is reported as it happens without stopping the batch.
+*/
+
+use super::workflow::same_flag;
+use super::{panic_message, ParameterType, ToolContext, ToolManager, ToolResults};
+use std::io::{Error, ErrorKind};
+use std::panic;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Instant;
+use whitebox_common::configs::set_thread_overrides;
+use whitebox_common::utils::get_formatted_elapsed_time;
+
+/// The outcome of running a tool on one of the input files of a batch.
+#[derive(Debug)]
+pub struct BatchResult {
+    pub input: String,
+    /// The arguments that the tool was run with for this file.
+    pub args: Vec<String>,
+    pub result: Result<(), Error>,
+    /// The structured results recorded by the tool for this file.
+    pub results: ToolResults,
+}
+
+// A run of the tool on one input file.
+struct BatchJob {
+    input: String,
+    args: Vec<String>,
+}
+
+impl ToolManager {
+    /// Runs a tool once for each file matching the glob pattern that is the value of
+    /// the argument `batch_arg`, e.g. `input`, processing up to `max_jobs` files at once.
+    /// Other arguments may contain the placeholders {stem}, {name}, {ext} and {dir}, e.g.
+    /// `--output={stem}_slope.tif`. An error is only returned if the batch can't be
+    /// started; the outcome for each file is given by its `BatchResult`.
+    pub fn run_batch(
+        &self,
+        tool_name: &str,
+        args: Vec<String>,
+        batch_arg: &str,
+        max_jobs: usize,
+    ) -> Result<Vec<BatchResult>, Error> {
+        self.run_batch_with_context(
+            tool_name,
+            args,
+            batch_arg,
+            max_jobs,
+            &ToolContext::new(self.verbose),
+        )
+    }
+
+    /// Runs a batch in the given context; see `run_batch`. The tool runs are quiet,
+    /// but share the context's sinks and cancellation token.
+    pub fn run_batch_with_context(
+        &self,
+        tool_name: &str,
+        args: Vec<String>,
+        batch_arg: &str,
+        max_jobs: usize,
+        context: &ToolContext,
+    ) -> Result<Vec<BatchResult>, Error> {
+        let start = Instant::now();
+        let jobs = self.plan_batch(tool_name, &args, batch_arg)?;
+        let num_files = jobs.len();
+        let mut job_context = context.clone();
+        job_context.verbose = false;
+
+        let next_job = AtomicUsize::new(0);
+        let num_completed = AtomicUsize::new(0);
+        let results: Mutex<Vec<Option<BatchResult>>> =
+            Mutex::new((0..num_files).map(|_| None).collect());
+        // when files are processed in parallel, each run gets its share of the processors
+        let num_threads = max_jobs.max(1).min(num_files);
+        let procs_per_job = (max_jobs / num_threads).max(1);
+        thread::scope(|s| {
+            for _ in 0..num_threads {
+                s.spawn(|| {
+                    if num_threads > 1 {
+                        let share = vec![("max_procs".to_string(), procs_per_job.to_string())];
+                        if let Err(e) = set_thread_overrides(share) {
+                            context.error(e.to_string());
+                        }
+                    }
+                    loop {
+                        let i = next_job.fetch_add(1, Ordering::SeqCst);
+                        if i >= num_files {
+                            break;
+                        }
+                        let job = &jobs[i];
+                        let job_start = Instant::now();
+                        let job_results = ToolResults::new();
+                        let job_context = job_context.clone().with_results(job_results.clone());
+                        let result = match job_context.check_cancelled() {
+                            Ok(()) => match panic::catch_unwind(panic::AssertUnwindSafe(|| {
+                                self.run_tool_in_context(tool_name, job.args.clone(), &job_context)
+                            })) {
+                                Ok(result) => result,
+                                Err(e) => Err(Error::other(panic_message(e.as_ref()))),
+                            },
+                            Err(e) => Err(e),
+                        };
+                        let n = num_completed.fetch_add(1, Ordering::SeqCst) + 1;
+                        match &result {
+                            Ok(()) => {
+                                if context.verbose {
+                                    context.info(format!(
+                                        "[{}/{}] {}: completed in {}",
+                                        n,
+                                        num_files,
+                                        job.input,
+                                        get_formatted_elapsed_time(job_start)
+                                    ));
+                                }
+                            }
+                            Err(e) => context.error(format!(
+                                "[{}/{}] {}: failed: {}",
+                                n, num_files, job.input, e
+                            )),
+                        }
+                        results.lock().unwrap()[i] = Some(BatchResult {
+                            input: job.input.clone(),
+                            args: job.args.clone(),
+                            result,
+                            results: job_results,
+                        });
+                    }
+                });
+            }
+        });
+
+        let results: Vec<BatchResult> = results
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .collect();
+        if context.verbose {
+            let num_failed = results.iter().filter(|r| r.result.is_err()).count();
+            context.info(format!(
+                "{} of {} files were processed successfully in {}",
+                num_files - num_failed,
+                num_files,
+                get_formatted_elapsed_time(start)
+            ));
+        }
+        Ok(results)
+    }
+
+    // Finds the input files of a batch and the arguments of the tool for each.
+    fn plan_batch(
+        &self,
+        tool_name: &str,
+        args: &[String],
+        batch_arg: &str,
+    ) -> Result<Vec<BatchJob>, Error> {
+        let invalid = |msg: String| Error::new(ErrorKind::InvalidInput, msg);
+        let parameters = self.get_tool_parameter_list(tool_name)?;
+        let is_output = |flag: &str| {
+            parameters.iter().any(|p| {
+                matches!(p.parameter_type, ParameterType::NewFile(_))
+                    && p.flags.iter().any(|f| same_flag(f, flag))
+            })
+        };
+
+        // find the pattern, and check that each output will differ between files
+        let mut pattern: Option<(usize, String)> = None;
+        let mut value_flag = "";
+        for (i, arg) in args.iter().enumerate() {
+            let (flag, value) = split_arg(arg);
+            let flag = match flag {
+                Some(f) => f,
+                None => value_flag,
+            };
+            value_flag = if value.is_none() { flag } else { "" };
+            let value = match value {
+                Some(v) => v,
+                None => continue,
+            };
+            if same_flag(flag, batch_arg) {
+                if pattern.is_some() {
+                    return Err(invalid(format!(
+                        "The batch argument --{} was given more than once.",
+                        batch_arg.trim_start_matches('-')
+                    )));
+                }
+                pattern = Some((i, value.to_string()));
+            } else if is_output(flag) && !value.contains("{stem}") && !value.contains("{name}") {
+                return Err(invalid(format!(
+                    "The output {} must contain {{stem}} or {{name}}, e.g. {{stem}}_output.tif, so that each file of the batch has its own output.",
+                    value
+                )));
+            }
+        }
+        let (pattern_index, pattern) = pattern.ok_or_else(|| {
+            let batch_arg = batch_arg.trim_start_matches('-');
+            invalid(format!(
+                "In batch mode, the argument named by --batch, --{}, must be given a glob pattern, e.g. --{}='*.tif'.",
+                batch_arg, batch_arg
+            ))
+        })?;
+
+        // a relative pattern is within the working directory
+        let pattern = if Path::new(&pattern).is_relative() {
+            format!("{}{}", self.working_dir, pattern)
+        } else {
+            pattern
+        };
+        let mut files = vec![];
+        for entry in glob::glob(&pattern)
+            .map_err(|e| invalid(format!("The glob pattern {} is invalid: {}", pattern, e)))?
+        {
+            let path = entry.map_err(Error::from)?;
+            if path.is_file() {
+                files.push(path);
+            }
+        }
+        if files.is_empty() {
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                format!("No files match the pattern {}.", pattern),
+            ));
+        }
+        files.sort();
+
+        Ok(files
+            .iter()
+            .map(|file| {
+                let component = |s: Option<&std::ffi::OsStr>| {
+                    s.and_then(|s| s.to_str()).unwrap_or("").to_string()
+                };
+                let input = file.to_string_lossy().to_string();
+                let stem = component(file.file_stem());
+                let name = component(file.file_name());
+                let ext = component(file.extension());
+                let dir = component(file.parent().map(|p| p.as_os_str()));
+                let args = args
+                    .iter()
+                    .enumerate()
+                    .map(|(i, arg)| {
+                        let (flag, _) = split_arg(arg);
+                        if i == pattern_index {
+                            match flag {
+                                Some(f) => format!("{}={}", f, input),
+                                None => input.clone(),
+                            }
+                        } else {
+                            arg.replace("{stem}", &stem)
+                                .replace("{name}", &name)
+                                .replace("{ext}", &ext)
+                                .replace("{dir}", &dir)
+                        }
+                    })
+                    .collect();
+                BatchJob { input, args }
+            })
+            .collect())
+    }
+}
+
+// Splits a command-line argument into its flag and its value, which is unquoted. A flag
+// given without a value, e.g. -i, is either a Boolean or is followed by its value.
+pub(super) fn split_arg(arg: &str) -> (Option<&str>, Option<&str>) {
+    fn unquote(value: &str) -> &str {
+        value.trim().trim_matches(|c| c == '"' || c == '\'')
+    }
+    if arg.starts_with('-') {
+        match arg.split_once('=') {
+            Some((flag, value)) => (Some(flag), Some(unquote(value))),
+            None => (Some(arg), None),
+        }
+    } else {
+        (None, Some(unquote(arg)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::split_arg;
+    use crate::tools::ToolManager;
+    use std::env;
+    use std::fs;
+    use std::io::ErrorKind;
+
+    // A working directory holding empty files with the given names.
+    fn tool_manager(dir_name: &str, file_names: &[&str]) -> ToolManager {
+        let dir = env::temp_dir().join(dir_name);
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        for file_name in file_names {
+            fs::write(dir.join(file_name), b"").unwrap();
+        }
+        ToolManager::new(&format!("{}/", dir.to_string_lossy()), &false).unwrap()
+    }
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn test_split_arg() {
+        assert_eq!(split_arg("--dem=dem.tif"), (Some("--dem"), Some("dem.tif")));
+        assert_eq!(split_arg("-i='a b.tif'"), (Some("-i"), Some("a b.tif")));
+        assert_eq!(split_arg("--fix_flats"), (Some("--fix_flats"), None));
+        assert_eq!(split_arg("\"*.tif\""), (None, Some("*.tif")));
+        // only the first equals sign separates the flag from its value
+        assert_eq!(split_arg("--where=a=b"), (Some("--where"), Some("a=b")));
+    }
+
+    #[test]
+    fn test_plan_batch() {
+        let tm = tool_manager("wbt_batch_plan", &["b.tif", "a.tif", "c.dep"]);
+        let jobs = tm
+            .plan_batch(
+                "Slope",
+                &args(&[
+                    "--dem=*.tif",
+                    "--output={dir}/{stem}_slope.{ext}",
+                    "--zfactor=2",
+                ]),
+                "dem",
+            )
+            .unwrap();
+        assert_eq!(jobs.len(), 2);
+        let dir = env::temp_dir().join("wbt_batch_plan");
+        let a = dir.join("a.tif").to_string_lossy().to_string();
+        assert_eq!(jobs[0].input, a);
+        assert_eq!(
+            jobs[0].args,
+            vec![
+                format!("--dem={}", a),
+                format!("--output={}/a_slope.tif", dir.to_string_lossy()),
+                "--zfactor=2".to_string(),
+            ]
+        );
+
+        // the pattern may follow its flag, and {name} keeps the extension
+        let jobs = tm
+            .plan_batch("Slope", &args(&["-i", "*.dep", "-o", "{name}.tif"]), "-i")
+            .unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].args[1], jobs[0].input);
+        assert_eq!(jobs[0].args[3], "c.dep.tif");
+    }
+
+    #[test]
+    fn test_plan_batch_literal_names() {
+        let tm = tool_manager("wbt_batch_literal", &["a.tif", "b.tif"]);
+        // arguments other than the batch argument are not expanded, even if they
+        // contain glob characters
+        let jobs = tm
+            .plan_batch(
+                "Slope",
+                &args(&["--dem=*.tif", "--output=slope[{stem}]?.tif"]),
+                "dem",
+            )
+            .unwrap();
+        assert_eq!(jobs.len(), 2);
+        assert_eq!(jobs[1].args[1], "--output=slope[b]?.tif");
+    }
+
+    #[test]
+    fn test_plan_batch_errors() {
+        let tm = tool_manager("wbt_batch_errors", &["a.tif"]);
+        let error = |a: &[&str], batch_arg: &str| {
+            tm.plan_batch("Slope", &args(a), batch_arg).err().unwrap()
+        };
+
+        // the batch argument must be named, and given
+        let e = error(&["--dem=*.tif", "--output={stem}_slope.tif"], "");
+        assert_eq!(e.kind(), ErrorKind::InvalidInput);
+        let e = error(&["--dem=*.tif", "--output={stem}_slope.tif"], "input");
+        assert!(e.to_string().contains("--input"));
+
+        // each file must have its own output
+        let e = error(&["--dem=*.tif", "--output=slope.tif"], "dem");
+        assert_eq!(e.kind(), ErrorKind::InvalidInput);
+        assert!(e.to_string().contains("{stem}"));
+
+        let e = error(&["--dem=*.dep", "--output={stem}.tif"], "dem");
+        assert_eq!(e.kind(), ErrorKind::NotFound);
+    }
+}
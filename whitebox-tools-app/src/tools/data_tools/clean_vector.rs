@@ -119,8 +119,9 @@ impl WhiteboxTool for CleanVector {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
 
@@ -166,11 +167,11 @@ impl WhiteboxTool for CleanVector {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -250,23 +251,21 @@ impl WhiteboxTool for CleanVector {
                 }
             }
 
-            if verbose {
-                progress =
-                    (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -275,7 +274,7 @@ impl WhiteboxTool for CleanVector {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
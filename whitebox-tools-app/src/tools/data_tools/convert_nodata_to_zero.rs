@@ -118,8 +118,9 @@ impl WhiteboxTool for ConvertNodataToZero {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
 
@@ -157,11 +158,11 @@ impl WhiteboxTool for ConvertNodataToZero {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -201,13 +202,15 @@ impl WhiteboxTool for ConvertNodataToZero {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data = vec![nodata; columns as usize];
                     for col in 0..columns {
-                        if input[(row, col)] != nodata {
-                            data[col as usize] = input[(row, col)];
+                        if input.get_value(row, col) != nodata {
+                            data[col as usize] = input.get_value(row, col);
                         } else {
                             data[col as usize] = 0.0f64;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -215,12 +218,10 @@ impl WhiteboxTool for ConvertNodataToZero {
         for row in 0..rows {
             let data = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(data.0, data.1);
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -233,22 +234,19 @@ impl WhiteboxTool for ConvertNodataToZero {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -114,8 +114,9 @@ impl WhiteboxTool for ConvertRasterFormat {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
 
@@ -153,11 +154,11 @@ impl WhiteboxTool for ConvertRasterFormat {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -170,14 +171,14 @@ impl WhiteboxTool for ConvertRasterFormat {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
         let start = Instant::now();
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
-        println!("Initializing the output raster...");
+        context.info("Initializing the output raster...");
         match output.set_data_from_raster(&input) {
             Ok(_) => (), // do nothings
             Err(err) => return Err(err),
@@ -193,22 +194,19 @@ impl WhiteboxTool for ConvertRasterFormat {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -148,8 +148,9 @@ impl WhiteboxTool for CsvPointsToVector {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         // let mut field_definitions = String::new();
@@ -230,11 +231,11 @@ impl WhiteboxTool for CsvPointsToVector {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -253,7 +254,7 @@ impl WhiteboxTool for CsvPointsToVector {
         let start = Instant::now();
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         // read in the CSV file
@@ -409,22 +410,20 @@ impl WhiteboxTool for CsvPointsToVector {
                 .attributes
                 .add_record(data[record_num].clone(), false);
 
-            if verbose {
-                progress = (100.0_f64 * (rec_num + 1) as f64 / data.len() as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (rec_num + 1) as f64 / data.len() as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -433,7 +432,7 @@ impl WhiteboxTool for CsvPointsToVector {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -128,8 +128,9 @@ impl WhiteboxTool for ExportTableToCsv {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut headers = false;
@@ -173,11 +174,11 @@ impl WhiteboxTool for ExportTableToCsv {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -193,7 +194,7 @@ impl WhiteboxTool for ExportTableToCsv {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -249,22 +250,17 @@ impl WhiteboxTool for ExportTableToCsv {
             s.push_str("\n");
             writer.write_all(s.as_bytes())?;
 
-            if verbose {
-                progress =
-                    (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Writing attributes: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Writing attributes", progress)?;
+                old_progress = progress;
             }
         }
 
         if verbose {
             let elapsed_time = get_formatted_elapsed_time(start);
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
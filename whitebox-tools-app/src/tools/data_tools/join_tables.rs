@@ -180,8 +180,9 @@ impl WhiteboxTool for JoinTables {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input1_file = String::new();
         let mut primary_key = String::new();
         let mut input2_file = String::new();
@@ -241,11 +242,11 @@ impl WhiteboxTool for JoinTables {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -261,7 +262,7 @@ impl WhiteboxTool for JoinTables {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let input1 = Shapefile::read(&input1_file)?;
         let input2 = Shapefile::read(&input2_file)?;
@@ -366,23 +367,21 @@ impl WhiteboxTool for JoinTables {
 
             output.attributes.add_record(atts, false);
 
-            if verbose {
-                progress =
-                    (100.0_f64 * (record_num + 1) as f64 / input1.num_records as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * (record_num + 1) as f64 / input1.num_records as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -391,7 +390,7 @@ impl WhiteboxTool for JoinTables {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -134,8 +134,9 @@ impl WhiteboxTool for RasterToVectorLines {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
 
@@ -174,11 +175,11 @@ impl WhiteboxTool for RasterToVectorLines {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -194,7 +195,7 @@ impl WhiteboxTool for RasterToVectorLines {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let input = Raster::new(&input_file, "r")?;
 
@@ -252,17 +253,15 @@ impl WhiteboxTool for RasterToVectorLines {
                     num_cells += 1;
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         if verbose {
-            println!("Tracing raster lines...");
+            context.info("Tracing raster lines...");
         }
         let (mut row, mut col): (isize, isize);
         let (mut row_n, mut col_n): (isize, isize);
@@ -341,19 +340,17 @@ impl WhiteboxTool for RasterToVectorLines {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         // The above procedure will not catch closed loops that are disconnected from any line end.
         // Pass over the raster looking for any untraced lines.
         if verbose {
-            println!("Searching for closed loops...");
+            context.info("Searching for closed loops...");
         }
         let (mut row2, mut col2): (isize, isize);
         for row in 0..rows {
@@ -421,14 +418,12 @@ impl WhiteboxTool for RasterToVectorLines {
 
                         current_id += 1;
 
-                        if verbose {
-                            progress = (100.0_f64 * num_solved_cells as f64
-                                / (num_cells - 1) as f64)
-                                as usize;
-                            if progress != old_progress {
-                                println!("Progress: {}%", progress);
-                                old_progress = progress;
-                            }
+                        progress = (100.0_f64 * num_solved_cells as f64
+                            / (num_cells - 1) as f64)
+                            as usize;
+                        if progress != old_progress {
+                            context.progress("Progress", progress)?;
+                            old_progress = progress;
                         }
                     }
                 }
@@ -438,22 +433,19 @@ impl WhiteboxTool for RasterToVectorLines {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -130,8 +130,9 @@ impl WhiteboxTool for RasterToVectorPolygons {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
 
@@ -173,11 +174,11 @@ impl WhiteboxTool for RasterToVectorPolygons {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -190,7 +191,7 @@ impl WhiteboxTool for RasterToVectorPolygons {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -261,12 +262,10 @@ impl WhiteboxTool for RasterToVectorPolygons {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Clumping polygons: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Clumping polygons", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -343,12 +342,10 @@ impl WhiteboxTool for RasterToVectorPolygons {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Finding edges: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Finding edges", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -499,13 +496,11 @@ impl WhiteboxTool for RasterToVectorPolygons {
                     }
                 }
             }
-            if verbose {
-                progress =
-                    (100.0_f64 * node as f64 / (line_segments.len() * 2 - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Tracing polygons: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * node as f64 / (line_segments.len() * 2 - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Tracing polygons", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -660,7 +655,7 @@ impl WhiteboxTool for RasterToVectorPolygons {
                 progress =
                     (100.0_f64 * line_segment as f64 / (line_segments.len() - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Tracing polygons: {}%", progress);
+                    context.progress("Tracing polygons", progress)?;
                     old_progress = progress;
                 }
             }
@@ -677,33 +672,28 @@ impl WhiteboxTool for RasterToVectorPolygons {
                 false,
             );
 
-            if verbose {
-                progress = (100.0_f64 * fid as f64 / (geometries.len() - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Creating geometries: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * fid as f64 / (geometries.len() - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Creating geometries", progress)?;
+                old_progress = progress;
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
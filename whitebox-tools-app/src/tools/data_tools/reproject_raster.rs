@@ -0,0 +1,444 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+*/
+
+use whitebox_raster::*;
+use whitebox_common::spatial_ref_system::{esri_wkt_from_epsg, CoordinateTransform, SpatialReference};
+use whitebox_common::structures::BoundingBox;
+use crate::tools::image_analysis::{interpolate_raster_value, ResampleMethod};
+use crate::tools::*;
+use num_cpus;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+
+/// This tool transforms a raster (`--input`) from its native coordinate reference system into
+/// the coordinate reference system identified by an EPSG code (`--epsg`). The source system is
+/// read from the input raster's georeferencing information, or it may be specified explicitly
+/// using the `--src_epsg` parameter, which is necessary when the input file lacks this
+/// information. Transverse Mercator (including UTM and Gauss-Kruger), Lambert Conformal Conic,
+/// Albers Equal Area, Polar Stereographic, Mercator and Web Mercator projections are supported,
+/// as are geographic coordinates. When the source and target systems are based on different
+/// datums, a seven-parameter Helmert datum transformation is applied.
+///
+/// The extent of the output raster is determined by projecting the boundary of the input raster.
+/// The output grid resolution can be specified using `--cell_size`, in target system units; by
+/// default, a resolution is chosen that preserves the number of grid cells in the input raster.
+/// Output cell values are estimated from the input grid using one of three resampling methods
+/// (`--method`), nearest neighbour ('nn'), bilinear interpolation ('bilinear'), and cubic
+/// convolution ('cc'), which are the same methods used by the `Resample` tool. Nearest neighbour
+/// resampling should be used with categorical data.
+///
+/// # See Also
+/// `ReprojectVector`, `ReprojectLidar`, `Resample`
+pub struct ReprojectRaster {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ReprojectRaster {
+    pub fn new() -> ReprojectRaster {
+        // public constructor
+        let name = "ReprojectRaster".to_string();
+        let toolbox = "Data Tools".to_string();
+        let description =
+            "Transforms a raster into a different coordinate reference system.".to_string();
+
+        let parameters = vec![
+            ToolParameter {
+                name: "Input File".to_owned(),
+                flags: vec!["-i".to_owned(), "--input".to_owned()],
+                description: "Input raster file.".to_owned(),
+                parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+                default_value: None,
+                optional: false,
+            },
+            ToolParameter {
+                name: "Output File".to_owned(),
+                flags: vec!["-o".to_owned(), "--output".to_owned()],
+                description: "Output raster file.".to_owned(),
+                parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+                default_value: None,
+                optional: false,
+            },
+            ToolParameter {
+                name: "Target EPSG Code".to_owned(),
+                flags: vec!["--epsg".to_owned()],
+                description: "EPSG code of the output coordinate reference system.".to_owned(),
+                parameter_type: ParameterType::Integer,
+                default_value: None,
+                optional: false,
+            },
+            ToolParameter {
+                name: "Source EPSG Code (optional)".to_owned(),
+                flags: vec!["--src_epsg".to_owned()],
+                description: "Optional EPSG code of the input coordinate reference system, overriding that of the input file.".to_owned(),
+                parameter_type: ParameterType::Integer,
+                default_value: None,
+                optional: true,
+            },
+            ToolParameter {
+                name: "Cell Size (optional)".to_owned(),
+                flags: vec!["--cell_size".to_owned()],
+                description: "Optionally specified cell size of output raster, in target coordinate system units.".to_owned(),
+                parameter_type: ParameterType::Float,
+                default_value: None,
+                optional: true,
+            },
+            ToolParameter {
+                name: "Resampling Method".to_owned(),
+                flags: vec!["--method".to_owned()],
+                description: "Resampling method; options include 'nn' (nearest neighbour), 'bilinear', and 'cc' (cubic convolution)".to_owned(),
+                parameter_type: ParameterType::OptionList(vec![
+                    "nn".to_owned(),
+                    "bilinear".to_owned(),
+                    "cc".to_owned(),
+                ]),
+                default_value: Some("cc".to_owned()),
+                optional: true,
+            },
+        ];
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{} -r={} -v --wd=\"*path*to*data*\" -i=DEM.tif -o=output.tif --epsg=3857 --method=bilinear",
+            short_exe, name
+        )
+        .replace("*", &sep);
+
+        ReprojectRaster {
+            name,
+            description,
+            toolbox,
+            parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl Default for ReprojectRaster {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WhiteboxTool for ReprojectRaster {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run(
+        &self,
+        args: Vec<String>,
+        working_directory: &str,
+        context: &ToolContext,
+    ) -> Result<(), Error> {
+        let verbose = context.verbose;
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut epsg = 0u16;
+        let mut src_epsg = 0u16;
+        let mut cell_size = 0f64;
+        let mut method = String::from("cc");
+
+        if args.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no parameters.",
+            ));
+        }
+        for i in 0..args.len() {
+            let mut arg = args[i].replace("\"", "");
+            arg = arg.replace("\'", "");
+            let cmd = arg.split("="); // in case an equals sign was used
+            let vec = cmd.collect::<Vec<&str>>();
+            let mut keyval = false;
+            if vec.len() > 1 {
+                keyval = true;
+            }
+            let flag_val = vec[0].to_lowercase().replace("--", "-");
+            if flag_val == "-i" || flag_val == "-input" {
+                input_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-o" || flag_val == "-output" {
+                output_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-epsg" || flag_val == "-src_epsg" {
+                let val = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+                let code = val.trim().parse::<u16>().map_err(|_| {
+                    Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("Error parsing {}; EPSG codes must be integers.", flag_val),
+                    )
+                })?;
+                if flag_val == "-epsg" {
+                    epsg = code;
+                } else {
+                    src_epsg = code;
+                }
+            } else if flag_val == "-cell_size" {
+                cell_size = if keyval {
+                    vec[1]
+                        .to_string()
+                        .parse::<f64>()
+                        .unwrap_or_else(|_| panic!("Error parsing {}", flag_val))
+                } else {
+                    args[i + 1]
+                        .to_string()
+                        .parse::<f64>()
+                        .unwrap_or_else(|_| panic!("Error parsing {}", flag_val))
+                };
+            } else if flag_val == "-method" {
+                method = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            }
+        }
+        // unrecognized methods are treated as bilinear
+        let method = method.parse().unwrap_or(ResampleMethod::Bilinear);
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28);
+            // 28 = length of the 'Powered by' by statement.
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !input_file.contains(&sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if epsg == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The target EPSG code (--epsg) must be specified.",
+            ));
+        }
+
+        if verbose {
+            context.info("Reading data...")
+        };
+        let input = Raster::new(&input_file, "r")?;
+
+        let start = Instant::now();
+
+        let src_sr = if src_epsg != 0 {
+            SpatialReference::from_epsg(src_epsg)?
+        } else if input.configs.epsg_code != 0 {
+            SpatialReference::from_epsg(input.configs.epsg_code)?
+        } else if !input.configs.coordinate_ref_system_wkt.trim().is_empty()
+            && input.configs.coordinate_ref_system_wkt != "Unknown EPSG Code"
+        {
+            SpatialReference::from_wkt(&input.configs.coordinate_ref_system_wkt)?
+        } else if !input.configs.projection.trim().is_empty() {
+            SpatialReference::from_wkt(&input.configs.projection)?
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The coordinate reference system of the input raster is unknown. Please specify it using --src_epsg.",
+            ));
+        };
+        let dst_sr = SpatialReference::from_epsg(epsg)?;
+        let transform = CoordinateTransform::new(&src_sr, &dst_sr)?;
+
+        // Find the output extent by projecting the densified boundary of the input.
+        let src_bb = BoundingBox::new(
+            input.configs.west,
+            input.configs.east,
+            input.configs.south,
+            input.configs.north,
+        );
+        let dst_bb = transform.transform_bounding_box(&src_bb, 100)?;
+        if cell_size <= 0f64 {
+            // preserve the number of grid cells
+            cell_size = (dst_bb.get_width() * dst_bb.get_height()
+                / (input.configs.rows * input.configs.columns) as f64)
+                .sqrt();
+        }
+        if !cell_size.is_finite() || cell_size <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Error, the output cell size could not be determined; please specify --cell_size.",
+            ));
+        }
+
+        let west = dst_bb.min_x;
+        let north = dst_bb.max_y;
+        let rows = ((north - dst_bb.min_y) / cell_size).ceil().max(1f64) as isize;
+        let columns = ((dst_bb.max_x - west) / cell_size).ceil().max(1f64) as isize;
+
+        let mut configs = input.configs.clone();
+        configs.rows = rows as usize;
+        configs.columns = columns as usize;
+        configs.north = north;
+        configs.south = north - rows as f64 * cell_size;
+        configs.west = west;
+        configs.east = west + columns as f64 * cell_size;
+        configs.resolution_x = cell_size;
+        configs.resolution_y = cell_size;
+        configs.epsg_code = epsg;
+        configs.coordinate_ref_system_wkt = esri_wkt_from_epsg(epsg);
+        configs.projection = configs.coordinate_ref_system_wkt.clone();
+        configs.model_tiepoint = vec![];
+        configs.model_pixel_scale = [0f64; 3];
+        configs.model_transformation = [0f64; 16];
+        configs.geo_key_directory = vec![];
+        configs.geo_double_params = vec![];
+        configs.geo_ascii_params = String::new();
+        if method != ResampleMethod::NearestNeighbour {
+            configs.photometric_interp = PhotometricInterpretation::Continuous;
+            configs.data_type = DataType::F32;
+        }
+        let mut output = Raster::initialize_using_config(&output_file, &configs);
+        let nodata = output.configs.nodata;
+
+        // create the x and y arrays
+        let mut x: Vec<f64> = Vec::with_capacity(columns as usize);
+        for col in 0..columns {
+            x.push(output.get_x_from_column(col));
+        }
+
+        let mut y: Vec<f64> = Vec::with_capacity(rows as usize);
+        for row in 0..rows {
+            y.push(output.get_y_from_row(row));
+        }
+
+        let x = Arc::new(x);
+        let y = Arc::new(y);
+        let input = Arc::new(input);
+        let inverse = Arc::new(transform.inverse());
+        let mut num_procs = num_cpus::get() as isize;
+        let configs = whitebox_common::configs::get_configs()?;
+        let max_procs = configs.max_procs;
+        if max_procs > 0 && max_procs < num_procs {
+            num_procs = max_procs;
+        }
+        let (tx, rx) = mpsc::channel();
+        for tid in 0..num_procs {
+            let input = input.clone();
+            let inverse = inverse.clone();
+            let x = x.clone();
+            let y = y.clone();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let mut data = vec![nodata; columns as usize];
+                    for col in 0..columns as usize {
+                        if let Ok((x_src, y_src)) = inverse.transform(x[col], y[row as usize]) {
+                            if let Some(z) = interpolate_raster_value(&input, x_src, y_src, method) {
+                                data[col] = z;
+                            }
+                        }
+                    }
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        for r in 0..rows {
+            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            output.set_row_data(row, data);
+            progress = (100.0_f64 * r as f64 / (rows - 1).max(1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Target EPSG code: {}", epsg));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            context.info("Saving data...")
+        };
+        match output.write() {
+            Ok(_) => {
+                if verbose {
+                    context.info("Output file written")
+                }
+            }
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,313 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+*/
+
+use crate::tools::*;
+use whitebox_common::spatial_ref_system::{esri_wkt_from_epsg, CoordinateTransform, SpatialReference};
+use whitebox_common::structures::Point2D;
+use whitebox_vector::*;
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+
+/// This tool transforms the coordinates of a vector file (`--input`) from its native coordinate
+/// reference system into the coordinate reference system identified by an EPSG code (`--epsg`).
+/// The source system is read from the input file's projection (.prj) file, or it may be
+/// specified explicitly using the `--src_epsg` parameter. Transverse Mercator (including UTM and
+/// Gauss-Kruger), Lambert Conformal Conic, Albers Equal Area, Polar Stereographic, Mercator and
+/// Web Mercator projections are supported, as are geographic coordinates. When the source and
+/// target systems are based on different datums, a seven-parameter Helmert datum transformation
+/// is applied. Z and M values, and the attribute table, are copied to the output unchanged.
+///
+/// Notice that only vertices are transformed; straight line segments in the input remain
+/// straight in the output, and so long segments may need to be densified prior to reprojection.
+///
+/// # See Also
+/// `ReprojectRaster`, `ReprojectLidar`
+pub struct ReprojectVector {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ReprojectVector {
+    pub fn new() -> ReprojectVector {
+        // public constructor
+        let name = "ReprojectVector".to_string();
+        let toolbox = "Data Tools".to_string();
+        let description =
+            "Transforms a vector file into a different coordinate reference system.".to_string();
+
+        let parameters = vec![
+            ToolParameter {
+                name: "Input Vector File".to_owned(),
+                flags: vec!["-i".to_owned(), "--input".to_owned()],
+                description: "Input vector file.".to_owned(),
+                parameter_type: ParameterType::ExistingFile(ParameterFileType::Vector(
+                    VectorGeometryType::Any,
+                )),
+                default_value: None,
+                optional: false,
+            },
+            ToolParameter {
+                name: "Output Vector File".to_owned(),
+                flags: vec!["-o".to_owned(), "--output".to_owned()],
+                description: "Output vector file.".to_owned(),
+                parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                    VectorGeometryType::Any,
+                )),
+                default_value: None,
+                optional: false,
+            },
+            ToolParameter {
+                name: "Target EPSG Code".to_owned(),
+                flags: vec!["--epsg".to_owned()],
+                description: "EPSG code of the output coordinate reference system.".to_owned(),
+                parameter_type: ParameterType::Integer,
+                default_value: None,
+                optional: false,
+            },
+            ToolParameter {
+                name: "Source EPSG Code (optional)".to_owned(),
+                flags: vec!["--src_epsg".to_owned()],
+                description: "Optional EPSG code of the input coordinate reference system, overriding that of the input file.".to_owned(),
+                parameter_type: ParameterType::Integer,
+                default_value: None,
+                optional: true,
+            },
+        ];
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(
+            ">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=input.shp -o=output.shp --epsg=4326",
+            short_exe, name
+        )
+        .replace("*", &sep);
+
+        ReprojectVector {
+            name,
+            description,
+            toolbox,
+            parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl Default for ReprojectVector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WhiteboxTool for ReprojectVector {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run(
+        &self,
+        args: Vec<String>,
+        working_directory: &str,
+        context: &ToolContext,
+    ) -> Result<(), Error> {
+        let verbose = context.verbose;
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut epsg = 0u16;
+        let mut src_epsg = 0u16;
+
+        // read the arguments
+        if args.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no parameters.",
+            ));
+        }
+        for i in 0..args.len() {
+            let mut arg = args[i].replace("\"", "");
+            arg = arg.replace("\'", "");
+            let cmd = arg.split("="); // in case an equals sign was used
+            let vec = cmd.collect::<Vec<&str>>();
+            let mut keyval = false;
+            if vec.len() > 1 {
+                keyval = true;
+            }
+            let flag_val = vec[0].to_lowercase().replace("--", "-");
+            if flag_val == "-i" || flag_val == "-input" {
+                input_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-o" || flag_val == "-output" {
+                output_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-epsg" || flag_val == "-src_epsg" {
+                let val = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+                let code = val.trim().parse::<u16>().map_err(|_| {
+                    Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("Error parsing {}; EPSG codes must be integers.", flag_val),
+                    )
+                })?;
+                if flag_val == "-epsg" {
+                    epsg = code;
+                } else {
+                    src_epsg = code;
+                }
+            }
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28);
+            // 28 = length of the 'Powered by' by statement.
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
+        }
+
+        if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if epsg == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The target EPSG code (--epsg) must be specified.",
+            ));
+        }
+
+        let input = Shapefile::read(&input_file)?;
+
+        let src_sr = if src_epsg != 0 {
+            SpatialReference::from_epsg(src_epsg)?
+        } else if !input.projection.trim().is_empty() {
+            SpatialReference::from_wkt(&input.projection)?
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The coordinate reference system of the input vector is unknown. Please specify it using --src_epsg.",
+            ));
+        };
+        let dst_sr = SpatialReference::from_epsg(epsg)?;
+        let transform = CoordinateTransform::new(&src_sr, &dst_sr)?;
+
+        // create output file
+        let mut output =
+            Shapefile::initialize_using_file(&output_file, &input, input.header.shape_type, true)?;
+        output.projection = esri_wkt_from_epsg(epsg);
+
+        for record_num in 0..input.num_records {
+            let record = input.get_record(record_num);
+            let mut new_record = record.clone();
+            new_record.x_min = f64::INFINITY;
+            new_record.y_min = f64::INFINITY;
+            new_record.x_max = f64::NEG_INFINITY;
+            new_record.y_max = f64::NEG_INFINITY;
+            for p in new_record.points.iter_mut() {
+                let (x, y) = transform.transform(p.x, p.y).map_err(|e| {
+                    Error::new(
+                        e.kind(),
+                        format!("Error transforming record {}: {}", record_num + 1, e),
+                    )
+                })?;
+                *p = Point2D::new(x, y);
+                new_record.x_min = new_record.x_min.min(x);
+                new_record.y_min = new_record.y_min.min(y);
+                new_record.x_max = new_record.x_max.max(x);
+                new_record.y_max = new_record.y_max.max(y);
+            }
+            output.add_record(new_record);
+            let atts = input.attributes.get_record(record_num);
+            output.attributes.add_record(atts.clone(), false);
+
+            progress =
+                (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
+            }
+        }
+
+        if verbose {
+            context.info("Saving data...")
+        };
+        match output.write() {
+            Ok(_) => {
+                if verbose {
+                    context.info("Output file written")
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            context.info(format!("Elapsed Time: {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
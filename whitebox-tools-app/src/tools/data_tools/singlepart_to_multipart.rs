@@ -147,8 +147,9 @@ impl WhiteboxTool for SinglePartToMultiPart {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut field_name = String::new();
@@ -203,11 +204,11 @@ impl WhiteboxTool for SinglePartToMultiPart {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -269,14 +270,12 @@ impl WhiteboxTool for SinglePartToMultiPart {
                             z_values.push(record.z_array[0]);
                         }
 
-                        if verbose {
-                            progress = (100.0_f64 * (record_num + 1) as f64
-                                / input.num_records as f64)
-                                as usize;
-                            if progress != old_progress {
-                                println!("Progress: {}%", progress);
-                                old_progress = progress;
-                            }
+                        progress = (100.0_f64 * (record_num + 1) as f64
+                            / input.num_records as f64)
+                            as usize;
+                        if progress != old_progress {
+                            context.progress("Progress", progress)?;
+                            old_progress = progress;
                         }
                     }
 
@@ -298,12 +297,12 @@ impl WhiteboxTool for SinglePartToMultiPart {
                         .add_record(vec![FieldData::Int(1i32)], false);
 
                     if verbose {
-                        println!("Saving data...")
+                        context.info("Saving data...")
                     };
                     let _ = match output.write() {
                         Ok(_) => {
                             if verbose {
-                                println!("Output file written")
+                                context.info("Output file written")
                             }
                         }
                         Err(e) => return Err(e),
@@ -346,14 +345,12 @@ impl WhiteboxTool for SinglePartToMultiPart {
                             }
                         }
 
-                        if verbose {
-                            progress = (100.0_f64 * (record_num + 1) as f64
-                                / input.num_records as f64)
-                                as usize;
-                            if progress != old_progress {
-                                println!("Progress: {}%", progress);
-                                old_progress = progress;
-                            }
+                        progress = (100.0_f64 * (record_num + 1) as f64
+                            / input.num_records as f64)
+                            as usize;
+                        if progress != old_progress {
+                            context.progress("Progress", progress)?;
+                            old_progress = progress;
                         }
                     }
 
@@ -363,12 +360,12 @@ impl WhiteboxTool for SinglePartToMultiPart {
                         .add_record(vec![FieldData::Int(1i32)], false);
 
                     if verbose {
-                        println!("Saving data...")
+                        context.info("Saving data...")
                     };
                     let _ = match output.write() {
                         Ok(_) => {
                             if verbose {
-                                println!("Output file written")
+                                context.info("Output file written")
                             }
                         }
                         Err(e) => return Err(e),
@@ -448,14 +445,12 @@ impl WhiteboxTool for SinglePartToMultiPart {
                             }
                         }
 
-                        if verbose {
-                            progress = (100.0_f64 * (record_num + 1) as f64
-                                / input.num_records as f64)
-                                as usize;
-                            if progress != old_progress {
-                                println!("Progress: {}%", progress);
-                                old_progress = progress;
-                            }
+                        progress = (100.0_f64 * (record_num + 1) as f64
+                            / input.num_records as f64)
+                            as usize;
+                        if progress != old_progress {
+                            context.progress("Progress", progress)?;
+                            old_progress = progress;
                         }
                     }
 
@@ -465,12 +460,12 @@ impl WhiteboxTool for SinglePartToMultiPart {
                         .add_record(vec![FieldData::Int(1i32)], false);
 
                     if verbose {
-                        println!("Saving data...")
+                        context.info("Saving data...")
                     };
                     let _ = match output.write() {
                         Ok(_) => {
                             if verbose {
-                                println!("Output file written")
+                                context.info("Output file written")
                             }
                         }
                         Err(e) => return Err(e),
@@ -500,9 +495,7 @@ impl WhiteboxTool for SinglePartToMultiPart {
             // Is the field numeric?
             if !input.attributes.is_field_numeric(field_index) {
                 if input.attributes.fields[field_index].decimal_count > 0 {
-                    println!(
-                        "WARNING: The attribute field does not appear to be categorical. This may produce unexpected results."
-                    )
+                    context.warning("WARNING: The attribute field does not appear to be categorical. This may produce unexpected results.")
                 }
             }
 
@@ -610,22 +603,20 @@ impl WhiteboxTool for SinglePartToMultiPart {
                             false,
                         );
 
-                        if verbose {
-                            progress = (100.0_f64 * (id + 1) as f64 / max_id as f64) as usize;
-                            if progress != old_progress {
-                                println!("Progress: {}%", progress);
-                                old_progress = progress;
-                            }
+                        progress = (100.0_f64 * (id + 1) as f64 / max_id as f64) as usize;
+                        if progress != old_progress {
+                            context.progress("Progress", progress)?;
+                            old_progress = progress;
                         }
                     }
 
                     if verbose {
-                        println!("Saving data...")
+                        context.info("Saving data...")
                     };
                     let _ = match output.write() {
                         Ok(_) => {
                             if verbose {
-                                println!("Output file written")
+                                context.info("Output file written")
                             }
                         }
                         Err(e) => return Err(e),
@@ -717,22 +708,20 @@ impl WhiteboxTool for SinglePartToMultiPart {
                             false,
                         );
 
-                        if verbose {
-                            progress = (100.0_f64 * (id + 1) as f64 / max_id as f64) as usize;
-                            if progress != old_progress {
-                                println!("Progress: {}%", progress);
-                                old_progress = progress;
-                            }
+                        progress = (100.0_f64 * (id + 1) as f64 / max_id as f64) as usize;
+                        if progress != old_progress {
+                            context.progress("Progress", progress)?;
+                            old_progress = progress;
                         }
                     }
 
                     if verbose {
-                        println!("Saving data...")
+                        context.info("Saving data...")
                     };
                     let _ = match output.write() {
                         Ok(_) => {
                             if verbose {
-                                println!("Output file written")
+                                context.info("Output file written")
                             }
                         }
                         Err(e) => return Err(e),
@@ -856,22 +845,20 @@ impl WhiteboxTool for SinglePartToMultiPart {
                             false,
                         );
 
-                        if verbose {
-                            progress = (100.0_f64 * (id + 1) as f64 / max_id as f64) as usize;
-                            if progress != old_progress {
-                                println!("Progress: {}%", progress);
-                                old_progress = progress;
-                            }
+                        progress = (100.0_f64 * (id + 1) as f64 / max_id as f64) as usize;
+                        if progress != old_progress {
+                            context.progress("Progress", progress)?;
+                            old_progress = progress;
                         }
                     }
 
                     if verbose {
-                        println!("Saving data...")
+                        context.info("Saving data...")
                     };
                     let _ = match output.write() {
                         Ok(_) => {
                             if verbose {
-                                println!("Output file written")
+                                context.info("Output file written")
                             }
                         }
                         Err(e) => return Err(e),
@@ -890,7 +877,7 @@ impl WhiteboxTool for SinglePartToMultiPart {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -172,8 +172,9 @@ impl WhiteboxTool for VectorLinesToRaster {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut field_name = String::from("FID");
         let mut output_file = String::new();
@@ -245,11 +246,11 @@ impl WhiteboxTool for VectorLinesToRaster {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -265,7 +266,7 @@ impl WhiteboxTool for VectorLinesToRaster {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let vector_data = Shapefile::read(&input_file).expect("Error reading input Shapefile.");
 
@@ -287,7 +288,7 @@ impl WhiteboxTool for VectorLinesToRaster {
             None => {
                 // Field not found use FID
                 if verbose {
-                    println!("Warning: Attribute not found in table. FID will be used instead.");
+                    context.warning("Warning: Attribute not found in table. FID will be used instead.");
                 }
                 field_name = "FID".to_string();
                 0
@@ -298,7 +299,7 @@ impl WhiteboxTool for VectorLinesToRaster {
         if !vector_data.attributes.is_field_numeric(field_index) {
             // Warn user of non-numeric
             if verbose {
-                println!("Warning: Non-numeric attributes cannot be rasterized. FID will be used instead.");
+                context.warning("Warning: Non-numeric attributes cannot be rasterized. FID will be used instead.");
             }
             field_name = "FID".to_string(); // Can't use non-numeric field; use FID instead.
         }
@@ -375,13 +376,11 @@ impl WhiteboxTool for VectorLinesToRaster {
                 attribute_data[record_num] = (record_num + 1) as f64;
             }
 
-            if verbose {
-                progress =
-                    (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Reading attributes: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Reading attributes", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -514,17 +513,10 @@ impl WhiteboxTool for VectorLinesToRaster {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
-                if progress != old_progress {
-                    println!(
-                        "Rasterizing {} of {}: {}%",
-                        record_num + 1,
-                        num_records,
-                        progress
-                    );
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
+            if progress != old_progress {
+                context.progress(&format!("Rasterizing {} of {}", record_num + 1, num_records), progress)?;
+                old_progress = progress;
             }
         }
 
@@ -537,26 +529,23 @@ impl WhiteboxTool for VectorLinesToRaster {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if !output_something && verbose {
-            println!("Warning: No polylines were output to the raster.");
+            context.warning("Warning: No polylines were output to the raster.");
         }
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
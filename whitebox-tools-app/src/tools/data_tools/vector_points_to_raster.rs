@@ -176,8 +176,9 @@ impl WhiteboxTool for VectorPointsToRaster {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut field_name = String::from("FID");
         let mut output_file = String::new();
@@ -256,11 +257,11 @@ impl WhiteboxTool for VectorPointsToRaster {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -276,7 +277,7 @@ impl WhiteboxTool for VectorPointsToRaster {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -296,7 +297,7 @@ impl WhiteboxTool for VectorPointsToRaster {
             None => {
                 // Field not found use FID
                 if verbose {
-                    println!("Warning: Attribute not found in table. FID will be used instead.");
+                    context.warning("Warning: Attribute not found in table. FID will be used instead.");
                 }
                 field_name = "FID".to_string();
                 0
@@ -307,7 +308,7 @@ impl WhiteboxTool for VectorPointsToRaster {
         if !vector_data.attributes.is_field_numeric(field_index) {
             // Warn user of non-numeric
             if verbose {
-                println!("Warning: Non-numeric attributes cannot be rasterized. FID will be used instead.");
+                context.warning("Warning: Non-numeric attributes cannot be rasterized. FID will be used instead.");
             }
             field_name = "FID".to_string(); // Can't use non-numeric field; use FID instead.
         }
@@ -351,6 +352,7 @@ impl WhiteboxTool for VectorPointsToRaster {
 
             Raster::initialize_using_config(&output_file, &configs)
         };
+        output.widen_to_f64();
 
         if background_val != nodata {
             output.reinitialize_values(background_val);
@@ -382,13 +384,11 @@ impl WhiteboxTool for VectorPointsToRaster {
                 attribute_data[record_num] = (record_num + 1) as f64;
             }
 
-            if verbose {
-                progress =
-                    (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Reading attributes: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Reading attributes", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -406,17 +406,10 @@ impl WhiteboxTool for VectorPointsToRaster {
                     col = output.get_column_from_x(x);
                     output.set_value(row, col, attribute_data[record_num]);
                 }
-                if verbose {
-                    progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
-                    if progress != old_progress {
-                        println!(
-                            "Rasterizing {} of {}: {}%",
-                            record_num + 1,
-                            num_records,
-                            progress
-                        );
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Rasterizing {} of {}", record_num + 1, num_records), progress)?;
+                    old_progress = progress;
                 }
             }
         } else if assign_op.contains("first") {
@@ -432,17 +425,10 @@ impl WhiteboxTool for VectorPointsToRaster {
                         output.set_value(row, col, attribute_data[record_num]);
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
-                    if progress != old_progress {
-                        println!(
-                            "Rasterizing {} of {}: {}%",
-                            record_num + 1,
-                            num_records,
-                            progress
-                        );
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Rasterizing {} of {}", record_num + 1, num_records), progress)?;
+                    old_progress = progress;
                 }
             }
         } else if assign_op.contains("min") {
@@ -458,17 +444,10 @@ impl WhiteboxTool for VectorPointsToRaster {
                         output.set_value(row, col, attribute_data[record_num]);
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
-                    if progress != old_progress {
-                        println!(
-                            "Rasterizing {} of {}: {}%",
-                            record_num + 1,
-                            num_records,
-                            progress
-                        );
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Rasterizing {} of {}", record_num + 1, num_records), progress)?;
+                    old_progress = progress;
                 }
             }
         } else if assign_op.contains("max") {
@@ -484,17 +463,10 @@ impl WhiteboxTool for VectorPointsToRaster {
                         output.set_value(row, col, attribute_data[record_num]);
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
-                    if progress != old_progress {
-                        println!(
-                            "Rasterizing {} of {}: {}%",
-                            record_num + 1,
-                            num_records,
-                            progress
-                        );
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Rasterizing {} of {}", record_num + 1, num_records), progress)?;
+                    old_progress = progress;
                 }
             }
         } else if assign_op.contains("sum") || assign_op.contains("total") {
@@ -512,17 +484,10 @@ impl WhiteboxTool for VectorPointsToRaster {
                         output.set_value(row, col, z + attribute_data[record_num]);
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
-                    if progress != old_progress {
-                        println!(
-                            "Rasterizing {} of {}: {}%",
-                            record_num + 1,
-                            num_records,
-                            progress
-                        );
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Rasterizing {} of {}", record_num + 1, num_records), progress)?;
+                    old_progress = progress;
                 }
             }
         }
@@ -536,22 +501,19 @@ impl WhiteboxTool for VectorPointsToRaster {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -150,8 +150,9 @@ impl WhiteboxTool for VectorPolygonsToRaster {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut field_name = String::from("FID");
         let mut output_file = String::new();
@@ -223,11 +224,11 @@ impl WhiteboxTool for VectorPolygonsToRaster {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -243,7 +244,7 @@ impl WhiteboxTool for VectorPolygonsToRaster {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -263,7 +264,7 @@ impl WhiteboxTool for VectorPolygonsToRaster {
             None => {
                 // Field not found use FID
                 if verbose {
-                    println!("Warning: Attribute not found in table. FID will be used instead.");
+                    context.warning("Warning: Attribute not found in table. FID will be used instead.");
                 }
                 field_name = "FID".to_string();
                 0
@@ -276,8 +277,8 @@ impl WhiteboxTool for VectorPolygonsToRaster {
         if !vector_data.attributes.is_field_numeric(field_index) {
             // Warn user of non-numeric
             // if verbose {
-            println!("Warning: Non-numeric attributes cannot be directly assigned to raster data. A key will be established.");
-            println!("\nKey, Value");
+            context.warning("Warning: Non-numeric attributes cannot be directly assigned to raster data. A key will be established.");
+            context.info("\nKey, Value");
             // }
             // field_name = "FID".to_string(); // Can't use non-numeric field; use FID instead.
             let mut id = 1f64;
@@ -291,7 +292,7 @@ impl WhiteboxTool for VectorPolygonsToRaster {
                     FieldData::Null => "null".to_string(),
                 };
                 if !freq_data.contains_key(&key) {
-                    println!("{},{}", key, id);
+                    context.info(format!("{},{}", key, id));
                     freq_data.insert(key, id);
                     id += 1f64;
                 }
@@ -373,13 +374,11 @@ impl WhiteboxTool for VectorPolygonsToRaster {
                 attribute_data[record_num] = (record_num + 1) as f64;
             }
 
-            if verbose {
-                progress =
-                    (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Reading attributes: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Reading attributes", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -479,19 +478,12 @@ impl WhiteboxTool for VectorPolygonsToRaster {
                                     holes.set_value(r, c, record_i32);
                                 }
                             }
-                            if verbose {
-                                progress = (100.0_f64 * r as f64
-                                    / (ending_row - starting_row + 1) as f64)
-                                    as usize;
-                                if progress != old_progress {
-                                    println!(
-                                        "Rasterizing {} of {}: {}%",
-                                        record_num + 1,
-                                        num_records,
-                                        progress
-                                    );
-                                    old_progress = progress;
-                                }
+                            progress = (100.0_f64 * r as f64
+                                / (ending_row - starting_row + 1) as f64)
+                                as usize;
+                            if progress != old_progress {
+                                context.progress(&format!("Rasterizing {} of {}", record_num + 1, num_records), progress)?;
+                                old_progress = progress;
                             }
                         }
                     }
@@ -568,35 +560,21 @@ impl WhiteboxTool for VectorPolygonsToRaster {
                                     }
                                 }
                             }
-                            if verbose {
-                                progress = (100.0_f64 * r as f64
-                                    / (ending_row - starting_row + 1) as f64)
-                                    as usize;
-                                if progress != old_progress {
-                                    println!(
-                                        "Rasterizing {} of {}: {}%",
-                                        record_num + 1,
-                                        num_records,
-                                        progress
-                                    );
-                                    old_progress = progress;
-                                }
+                            progress = (100.0_f64 * r as f64
+                                / (ending_row - starting_row + 1) as f64)
+                                as usize;
+                            if progress != old_progress {
+                                context.progress(&format!("Rasterizing {} of {}", record_num + 1, num_records), progress)?;
+                                old_progress = progress;
                             }
                         }
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
-                if progress != old_progress {
-                    println!(
-                        "Rasterizing {} of {}: {}%",
-                        record_num + 1,
-                        num_records,
-                        progress
-                    );
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
+            if progress != old_progress {
+                context.progress(&format!("Rasterizing {} of {}", record_num + 1, num_records), progress)?;
+                old_progress = progress;
             }
         }
 
@@ -609,26 +587,23 @@ impl WhiteboxTool for VectorPolygonsToRaster {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if !output_something && verbose {
-            println!("Warning: No polygons were output to the raster.");
+            context.warning("Warning: No polygons were output to the raster.");
         }
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
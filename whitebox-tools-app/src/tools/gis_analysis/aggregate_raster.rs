@@ -141,8 +141,9 @@ impl WhiteboxTool for AggregateRaster {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut agg_factor = 2isize;
@@ -183,9 +184,7 @@ impl WhiteboxTool for AggregateRaster {
                     agg_factor = args[i + 1].to_string().parse::<isize>().unwrap();
                 }
                 if agg_factor < 2isize {
-                    println!(
-                        "WARNING: Aggregation factor cannot be less than 2. It has been modified."
-                    );
+                    context.warning("WARNING: Aggregation factor cannot be less than 2. It has been modified.");
                     agg_factor = 2isize;
                 }
             } else if flag_val == "-type" {
@@ -201,11 +200,11 @@ impl WhiteboxTool for AggregateRaster {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -221,7 +220,7 @@ impl WhiteboxTool for AggregateRaster {
         }
 
         if verbose {
-            println!("Reading input data...")
+            context.info("Reading input data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
@@ -298,7 +297,9 @@ impl WhiteboxTool for AggregateRaster {
                                     data[col as usize] = stat;
                                 }
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                break;
+                            }
                         }
                     });
                 }
@@ -306,12 +307,10 @@ impl WhiteboxTool for AggregateRaster {
                 for r in 0..rows_out {
                     let (row, data) = rx.recv().expect("Error receiving data from thread.");
                     output.set_row_data(row, data);
-                    if verbose {
-                        progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -345,7 +344,9 @@ impl WhiteboxTool for AggregateRaster {
                                     data[col as usize] = stat;
                                 }
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                break;
+                            }
                         }
                     });
                 }
@@ -353,12 +354,10 @@ impl WhiteboxTool for AggregateRaster {
                 for r in 0..rows_out {
                     let (row, data) = rx.recv().expect("Error receiving data from thread.");
                     output.set_row_data(row, data);
-                    if verbose {
-                        progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -394,7 +393,9 @@ impl WhiteboxTool for AggregateRaster {
                                     data[col as usize] = stat;
                                 }
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                break;
+                            }
                         }
                     });
                 }
@@ -402,12 +403,10 @@ impl WhiteboxTool for AggregateRaster {
                 for r in 0..rows_out {
                     let (row, data) = rx.recv().expect("Error receiving data from thread.");
                     output.set_row_data(row, data);
-                    if verbose {
-                        progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -443,7 +442,9 @@ impl WhiteboxTool for AggregateRaster {
                                     data[col as usize] = stat;
                                 }
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                break;
+                            }
                         }
                     });
                 }
@@ -451,12 +452,10 @@ impl WhiteboxTool for AggregateRaster {
                 for r in 0..rows_out {
                     let (row, data) = rx.recv().expect("Error receiving data from thread.");
                     output.set_row_data(row, data);
-                    if verbose {
-                        progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -497,7 +496,9 @@ impl WhiteboxTool for AggregateRaster {
                                     data[col as usize] = max_val - min_val;
                                 }
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                break;
+                            }
                         }
                     });
                 }
@@ -505,12 +506,10 @@ impl WhiteboxTool for AggregateRaster {
                 for r in 0..rows_out {
                     let (row, data) = rx.recv().expect("Error receiving data from thread.");
                     output.set_row_data(row, data);
-                    if verbose {
-                        progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * r as f64 / (rows_out - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -530,22 +529,19 @@ impl WhiteboxTool for AggregateRaster {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -47,7 +47,7 @@ impl AverageOverlay {
         parameters.push(ToolParameter {
             name: "Input Files".to_owned(),
             flags: vec!["-i".to_owned(), "--inputs".to_owned()],
-            description: "Input raster files.".to_owned(),
+            description: "Input raster files, or a single multiband raster.".to_owned(),
             parameter_type: ParameterType::FileList(ParameterFileType::Raster),
             default_value: None,
             optional: false,
@@ -117,8 +117,9 @@ impl WhiteboxTool for AverageOverlay {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_files = String::new();
         let mut output_file = String::new();
 
@@ -157,11 +158,11 @@ impl WhiteboxTool for AverageOverlay {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -173,13 +174,8 @@ impl WhiteboxTool for AverageOverlay {
             output_file = format!("{}{}", working_directory, output_file);
         }
 
-        let mut cmd = input_files.split(";");
-        let mut vec = cmd.collect::<Vec<&str>>();
-        if vec.len() == 1 {
-            cmd = input_files.split(",");
-            vec = cmd.collect::<Vec<&str>>();
-        }
-        let num_files = vec.len();
+        let inputs = RasterInputs::from_list(&input_files, working_directory)?;
+        let num_files = inputs.len();
         if num_files < 2 {
             return Err(Error::new(ErrorKind::InvalidInput,
                                 "There is something incorrect with the input files. At least two inputs are required to operate this tool."));
@@ -190,6 +186,7 @@ impl WhiteboxTool for AverageOverlay {
         // We need to initialize output and n here, but in reality this can't be done
         // until we know the size of rows and columns, which occurs during the first loop.
         let mut output: Raster = Raster::new(&output_file, "w")?;
+        output.widen_to_f64();
 
         let mut n: Array2D<i16> = Array2D::new(0, 0, 0i16, i16::MIN)?; // use i16::MIN as the nodata value
         let mut rows = 0isize;
@@ -198,83 +195,66 @@ impl WhiteboxTool for AverageOverlay {
         let mut in_nodata: f64;
         let mut z: f64;
         let mut read_first_file = false;
-        let mut i = 1;
-        for value in vec {
-            if !value.trim().is_empty() {
-                if verbose {
-                    println!("Reading data...")
-                };
-
-                let mut input_file = value.trim().to_owned();
-                if !input_file.contains(&sep) && !input_file.contains("/") {
-                    input_file = format!("{}{}", working_directory, input_file);
-                }
-                let input = Raster::new(&input_file, "r")?;
-                in_nodata = input.configs.nodata;
-                if !read_first_file {
-                    read_first_file = true;
-                    rows = input.configs.rows as isize;
-                    columns = input.configs.columns as isize;
-                    out_nodata = in_nodata;
-
-                    // initialize the output file and n
-                    output = Raster::initialize_using_file(&output_file, &input);
-                    n = Array2D::new(rows, columns, 0i16, i16::MIN)?;
-                }
-                // check to ensure that all inputs have the same rows and columns
-                if input.configs.rows as isize != rows || input.configs.columns as isize != columns
-                {
-                    return Err(Error::new(ErrorKind::InvalidInput,
-                                "The input files must have the same number of rows and columns and spatial extent."));
-                }
+        for file_num in 0..num_files {
+            if verbose {
+                context.info("Reading data...")
+            };
+
+            let input = inputs.get(file_num)?;
+            in_nodata = input.configs.nodata;
+            if !read_first_file {
+                read_first_file = true;
+                rows = input.configs.rows as isize;
+                columns = input.configs.columns as isize;
+                out_nodata = in_nodata;
+
+                // initialize the output file and n
+                output = Raster::initialize_using_file(&output_file, &input);
+                n = Array2D::new(rows, columns, 0i16, i16::MIN)?;
+            }
+            // check to ensure that all inputs have the same rows and columns
+            if input.configs.rows as isize != rows || input.configs.columns as isize != columns
+            {
+                return Err(Error::new(ErrorKind::InvalidInput,
+                            "The input files must have the same number of rows and columns and spatial extent."));
+            }
 
-                for row in 0..rows {
-                    for col in 0..columns {
-                        z = input[(row, col)];
-                        if z != in_nodata {
-                            if output[(row, col)] != out_nodata {
-                                output.increment(row, col, z);
-                                n.increment(row, col, 1i16);
-                            } else {
-                                output[(row, col)] = z;
-                                n[(row, col)] = 1i16;
-                            }
-                        }
-                    }
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress (loop {} of {}): {}%", i, num_files + 1, progress);
-                            old_progress = progress;
+            for row in 0..rows {
+                for col in 0..columns {
+                    z = input.get_value(row, col);
+                    if z != in_nodata {
+                        if output.get_value(row, col) != out_nodata {
+                            output.increment(row, col, z);
+                            n.increment(row, col, 1i16);
+                        } else {
+                            output.set_value(row, col, z);
+                            n[(row, col)] = 1i16;
                         }
                     }
                 }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Progress (loop {} of {})", file_num + 1, num_files + 1), progress)?;
+                    old_progress = progress;
+                }
             }
-            i += 1;
         }
 
         for row in 0..rows {
             for col in 0..columns {
-                z = output[(row, col)];
+                z = output.get_value(row, col);
                 if z != out_nodata {
                     if n[(row, col)] > 0i16 {
-                        output[(row, col)] = z / n[(row, col)] as f64;
+                        output.set_value(row, col, z / n[(row, col)] as f64);
                     } else {
-                        output[(row, col)] = 0.0f64;
+                        output.set_value(row, col, 0.0f64);
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!(
-                        "Progress (loop {} of {}): {}%",
-                        num_files + 1,
-                        num_files + 1,
-                        progress
-                    );
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress(&format!("Progress (loop {} of {})", num_files + 1, num_files + 1), progress)?;
+                old_progress = progress;
             }
         }
 
@@ -286,22 +266,19 @@ impl WhiteboxTool for AverageOverlay {
         output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
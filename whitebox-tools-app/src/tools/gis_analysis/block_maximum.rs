@@ -51,7 +51,7 @@ impl BlockMaximumGridding {
                 "--input".to_string(),
             ),
             default_value: None,
-            optional: false,
+            optional: true,
         });
 
         parameters.push(ToolParameter {
@@ -146,8 +146,9 @@ impl WhiteboxTool for BlockMaximumGridding {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut field_name = String::new();
         let mut use_z = false;
@@ -218,11 +219,11 @@ impl WhiteboxTool for BlockMaximumGridding {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -238,7 +239,7 @@ impl WhiteboxTool for BlockMaximumGridding {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -292,6 +293,7 @@ impl WhiteboxTool for BlockMaximumGridding {
 
             Raster::initialize_using_config(&output_file, &configs)
         };
+        output.widen_to_f64();
 
         let rows = output.configs.rows as isize;
         let columns = output.configs.columns as isize;
@@ -350,14 +352,12 @@ impl WhiteboxTool for BlockMaximumGridding {
                     output.set_value(row, col, z);
                 }
 
-                if verbose {
-                    progress = (100.0_f64 * record_num as f64
-                        / (vector_data.num_records - 1) as f64)
-                        as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * record_num as f64
+                    / (vector_data.num_records - 1) as f64)
+                    as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
         } else {
@@ -387,14 +387,12 @@ impl WhiteboxTool for BlockMaximumGridding {
                     }
                 }
 
-                if verbose {
-                    progress = (100.0_f64 * record_num as f64
-                        / (vector_data.num_records - 1) as f64)
-                        as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * record_num as f64
+                    / (vector_data.num_records - 1) as f64)
+                    as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
         };
@@ -408,22 +406,19 @@ impl WhiteboxTool for BlockMaximumGridding {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
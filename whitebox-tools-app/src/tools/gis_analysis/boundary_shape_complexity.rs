@@ -126,8 +126,9 @@ impl WhiteboxTool for BoundaryShapeComplexity {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
 
@@ -166,11 +167,11 @@ impl WhiteboxTool for BoundaryShapeComplexity {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -187,7 +188,7 @@ impl WhiteboxTool for BoundaryShapeComplexity {
         }
 
         if verbose {
-            println!("Reading data...");
+            context.info("Reading data...");
         }
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -216,22 +217,25 @@ impl WhiteboxTool for BoundaryShapeComplexity {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data: Vec<f64> = vec![nodata; columns as usize];
                     for col in 0..columns {
-                        if input[(row, col)] > 0.0 && input[(row, col)] != nodata {
+                        if input.get_value(row, col) > 0.0 && input.get_value(row, col) != nodata {
                             data[col as usize] = 1.0;
-                        } else if input[(row, col)] == 0.0 {
+                        } else if input.get_value(row, col) == 0.0 {
                             data[col as usize] = 0.0;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
 
         if verbose {
-            println!("Performing line-thinning...");
+            context.info("Performing line-thinning...");
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.widen_to_f64();
         let out_nodata = -999f64;
         output.reinitialize_values(out_nodata);
         output.configs.nodata = out_nodata;
@@ -242,12 +246,10 @@ impl WhiteboxTool for BoundaryShapeComplexity {
             let (row, data) = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(row, data);
 
-            if verbose {
-                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Initializing output: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Initializing output", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -282,11 +284,11 @@ impl WhiteboxTool for BoundaryShapeComplexity {
             for a in 0..4 {
                 for row in 0..rows {
                     for col in 0..columns {
-                        z = output[(row, col)];
+                        z = output.get_value(row, col);
                         if z > 0.0 && z != nodata {
                             // fill the neighbours array
                             for i in 0..8 {
-                                neighbours[i] = output[(row + dy[i], col + dx[i])];
+                                neighbours[i] = output.get_value(row + dy[i], col + dx[i]);
                             }
 
                             // scan through element
@@ -298,7 +300,7 @@ impl WhiteboxTool for BoundaryShapeComplexity {
                             }
 
                             if pattern_match {
-                                output[(row, col)] = 0.0;
+                                output.set_value(row, col, 0.0);
                                 did_something = true;
                             } else {
                                 pattern_match = true;
@@ -309,19 +311,17 @@ impl WhiteboxTool for BoundaryShapeComplexity {
                                 }
 
                                 if pattern_match {
-                                    output[(row, col)] = 0.0;
+                                    output.set_value(row, col, 0.0);
                                     did_something = true;
                                 }
                             }
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * (a + 1) as f64 / 4.0) as usize;
-                    if progress != old_progress {
-                        println!("Loop Number {}: {}%", loop_num, progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * (a + 1) as f64 / 4.0) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Loop Number {}", loop_num), progress)?;
+                    old_progress = progress;
                 }
             }
         }
@@ -406,18 +406,18 @@ impl WhiteboxTool for BoundaryShapeComplexity {
         let mut polyid: f64;
         for row in 0..rows {
             for col in 0..columns {
-                z = output[(row, col)];
+                z = output.get_value(row, col);
                 if z > 0f64 {
-                    polyid = input[(row, col)];
+                    polyid = input.get_value(row, col);
                     num_line_thinned_neighbours = 0;
                     for a in 0..8 {
-                        zn = output[(row + dy[a], col + dx[a])];
-                        if zn == 1f64 && input[(row + dy[a], col + dx[a])] == polyid {
+                        zn = output.get_value(row + dy[a], col + dx[a]);
+                        if zn == 1f64 && input.get_value(row + dy[a], col + dx[a]) == polyid {
                             num_line_thinned_neighbours += 1
                         }
                     }
 
-                    bin = (input[(row, col)] - min_val).floor() as usize;
+                    bin = (input.get_value(row, col) - min_val).floor() as usize;
                     num_cells[bin] += 1;
                     if num_line_thinned_neighbours == 1 {
                         num_end_nodes[bin] += 1f64;
@@ -431,8 +431,10 @@ impl WhiteboxTool for BoundaryShapeComplexity {
                             num_line_thinned_neighbours = 0;
                             next_n = 8;
                             for a in 0..8 {
-                                zn = output[(row_n + dy[a], col_n + dx[a])];
-                                if zn == 1f64 && input[(row_n + dy[a], col_n + dx[a])] == polyid {
+                                zn = output.get_value(row_n + dy[a], col_n + dx[a]);
+                                if zn == 1f64
+                                    && input.get_value(row_n + dy[a], col_n + dx[a]) == polyid
+                                {
                                     num_line_thinned_neighbours += 1;
                                     if visited.get_value(row_n + dy[a], col_n + dx[a]) == 0 {
                                         next_n = a;
@@ -485,12 +487,10 @@ impl WhiteboxTool for BoundaryShapeComplexity {
                 //     }
                 // }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Calculating Index: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Calculating Index", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -517,20 +517,18 @@ impl WhiteboxTool for BoundaryShapeComplexity {
 
         for row in 0..rows {
             for col in 0..columns {
-                z = input[(row, col)];
+                z = input.get_value(row, col);
                 if z != nodata && z != 0f64 {
                     bin = (z - min_val).floor() as usize;
-                    output[(row, col)] = num_end_nodes[bin];
+                    output.set_value(row, col, num_end_nodes[bin]);
                 } else if z == 0f64 {
-                    output[(row, col)] = 0f64;
+                    output.set_value(row, col, 0f64);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Calculating Index: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Calculating Index", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -543,22 +541,19 @@ impl WhiteboxTool for BoundaryShapeComplexity {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
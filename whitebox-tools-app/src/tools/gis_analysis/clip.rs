@@ -154,8 +154,9 @@ impl WhiteboxTool for Clip {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut clip_file = String::new();
         let mut output_file = String::new();
@@ -208,11 +209,11 @@ impl WhiteboxTool for Clip {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if !input_file.contains(&sep) && !input_file.contains("/") {
@@ -226,7 +227,7 @@ impl WhiteboxTool for Clip {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let clip = Arc::new(Shapefile::read(&clip_file)?);
@@ -320,7 +321,9 @@ impl WhiteboxTool for Clip {
                                     }
                                 }
                             }
-                            tx.send((record_num, out)).unwrap();
+                            if tx.send((record_num, out)).is_err() {
+                                break;
+                            }
                         }
                     });
                 }
@@ -331,12 +334,10 @@ impl WhiteboxTool for Clip {
                     if out {
                         output_feature[record_num] = true;
                     }
-                    if verbose {
-                        progress = (100.0_f64 * r as f64 / (input.num_records - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * r as f64 / (input.num_records - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
 
@@ -357,12 +358,10 @@ impl WhiteboxTool for Clip {
                                 .add_record(input.attributes.get_record(r).clone(), false)
                         }
                     }
-                    if verbose {
-                        progress = (100.0_f64 * r as f64 / (input.num_records - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * r as f64 / (input.num_records - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -396,7 +395,9 @@ impl WhiteboxTool for Clip {
                                         }
                                     }
                                 }
-                                tx.send((point_num, out)).unwrap();
+                                if tx.send((point_num, out)).is_err() {
+                                    break;
+                                }
                             }
                         });
                     }
@@ -455,13 +456,11 @@ impl WhiteboxTool for Clip {
                         }
                     }
 
-                    if verbose {
-                        progress = (100.0_f64 * record_num as f64 / (input.num_records - 1) as f64)
-                            as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * record_num as f64 / (input.num_records - 1) as f64)
+                        as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -555,14 +554,12 @@ impl WhiteboxTool for Clip {
                         }
                     }
 
-                    if verbose {
-                        progress = (100.0_f64 * (record_num1 + 1) as f64
-                            / features_polylines.len() as f64)
-                            as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * (record_num1 + 1) as f64
+                        / features_polylines.len() as f64)
+                        as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -972,11 +969,11 @@ impl WhiteboxTool for Clip {
                             }
 
                             if verbose {
-                                println!("Saving data...")
+                                context.info("Saving data...")
                             };
                             let _ = match output2.write() {
                                 Ok(_) => if verbose {
-                                    println!("Output file written")
+                                    context.info("Output file written")
                                 },
                                 Err(e) => return Err(e),
                             };
@@ -1623,13 +1620,11 @@ impl WhiteboxTool for Clip {
                         // }
                     }
 
-                    if verbose {
-                        progress = (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64)
-                            as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64)
+                        as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -1701,7 +1696,7 @@ impl WhiteboxTool for Clip {
         let capacity_per_node = 64;
         let mut snap_tree = KdTree::new_with_capacity(dimensions, capacity_per_node);
         let mut p: Point2D;
-        println!("Creating tree...");
+        context.info("Creating tree...");
         for i in 0..polygons.len() {
             for j in 0..polygons[i].len() {
                 p = polygons[i][j];
@@ -1771,7 +1766,7 @@ impl WhiteboxTool for Clip {
                 progress = (100.0_f64 * (i + 1) as f64 / features_polylines2.len() as f64)
                     as usize;
                 if progress != old_progress {
-                    println!("Searching for duplicate lines: {}%", progress);
+                    context.progress("Searching for duplicate lines", progress)?;
                     old_progress = progress;
                 }
             }
@@ -1799,11 +1794,11 @@ impl WhiteboxTool for Clip {
         }
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                context.info("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -1844,7 +1839,7 @@ impl WhiteboxTool for Clip {
         / features_polylines2.len() as f64)
         as usize;
         if progress != old_progress {
-        println!("Finding line intersections: {}%", progress);
+        context.progress("Finding line intersections", progress)?;
         old_progress = progress;
         }
         }
@@ -1863,7 +1858,7 @@ impl WhiteboxTool for Clip {
         if verbose {
         progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
         if progress != old_progress {
-        println!("Searching for duplicate lines: {}%", progress);
+        context.progress("Searching for duplicate lines", progress)?;
         old_progress = progress;
         }
         }
@@ -1892,7 +1887,7 @@ impl WhiteboxTool for Clip {
         let mut p2: Point2D;
         let mut p3: Point2D;
         let mut p4: Point2D;
-        println!("Creating tree...");
+        context.info("Creating tree...");
         for i in 0..polylines.len() {
         p1 = polylines[i].first_vertex();
         kdtree.add([p1.x, p1.y], first_node_id(i)).unwrap();
@@ -1903,7 +1898,7 @@ impl WhiteboxTool for Clip {
 
         // Find the neighbours of each endnode and check for dangling arcs
         // and self-closing arcs which form single-line polys.
-        println!("Finding node vertices...");
+        context.info("Finding node vertices...");
         let mut is_acyclic_arc = vec![false; polylines.len()];
         let mut node_angles: Vec<Vec<f64>> = vec![vec![]; num_endnodes];
         let mut heading: f64;
@@ -1977,7 +1972,7 @@ impl WhiteboxTool for Clip {
         if verbose {
         progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
         if progress != old_progress {
-        println!("Finding node vertices: {}%", progress);
+        context.progress("Finding node vertices", progress)?;
         old_progress = progress;
         }
         }
@@ -1987,7 +1982,7 @@ impl WhiteboxTool for Clip {
         // travel from one endnode to the other is to travel through the polyline. They
         // can be safely removed from the graph.
         if verbose {
-        println!("Finding acyclic arcs");
+        context.info("Finding acyclic arcs");
         }
         let mut source_node: usize;
         let mut target_node: usize;
@@ -2046,7 +2041,7 @@ impl WhiteboxTool for Clip {
         if verbose {
         progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
         if progress != old_progress {
-        println!("Finding acyclic arcs: {}%", progress);
+        context.progress("Finding acyclic arcs", progress)?;
         old_progress = progress;
         }
         }
@@ -2501,7 +2496,7 @@ impl WhiteboxTool for Clip {
         if verbose {
         progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
         if progress != old_progress {
-        println!("Finding polygons: {}%", progress);
+        context.progress("Finding polygons", progress)?;
         old_progress = progress;
         }
         }
@@ -2551,12 +2546,12 @@ impl WhiteboxTool for Clip {
         //     .add_field(&AttributeField::new("FID", FieldDataType::Int, 7u8, 0u8));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -2565,7 +2560,7 @@ impl WhiteboxTool for Clip {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
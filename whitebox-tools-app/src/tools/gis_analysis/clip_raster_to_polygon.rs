@@ -141,8 +141,9 @@ impl WhiteboxTool for ClipRasterToPolygon {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut polygons_file = String::new();
         let mut output_file = String::new();
@@ -193,11 +194,11 @@ impl WhiteboxTool for ClipRasterToPolygon {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -216,7 +217,7 @@ impl WhiteboxTool for ClipRasterToPolygon {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let input = Raster::new(&input_file, "r")?;
 
@@ -300,20 +301,12 @@ impl WhiteboxTool for ClipRasterToPolygon {
                                     output.set_value(r, c, input.get_value(r, c));
                                 }
                             }
-                            if verbose {
-                                progress = (100.0_f64 * (r - starting_row) as f64
-                                    / (ending_row - starting_row) as f64)
-                                    as usize;
-                                if progress != old_progress {
-                                    println!(
-                                        "Progress (rec {} of {} part {}): {}%",
-                                        record_num + 1,
-                                        num_records,
-                                        part_num,
-                                        progress
-                                    );
-                                    old_progress = progress;
-                                }
+                            progress = (100.0_f64 * (r - starting_row) as f64
+                                / (ending_row - starting_row) as f64)
+                                as usize;
+                            if progress != old_progress {
+                                context.progress(&format!("Progress (rec {} of {} part {})", record_num + 1, num_records, part_num), progress)?;
+                                old_progress = progress;
                             }
                         }
                         part_num += 1;
@@ -364,20 +357,12 @@ impl WhiteboxTool for ClipRasterToPolygon {
                                     output.set_value(r, c, nodata);
                                 }
                             }
-                            if verbose {
-                                progress = (100.0_f64 * (r - starting_row) as f64
-                                    / (ending_row - starting_row) as f64)
-                                    as usize;
-                                if progress != old_progress {
-                                    println!(
-                                        "Progress (rec {} of {} part {}): {}%",
-                                        record_num + 1,
-                                        num_records,
-                                        part_num,
-                                        progress
-                                    );
-                                    old_progress = progress;
-                                }
+                            progress = (100.0_f64 * (r - starting_row) as f64
+                                / (ending_row - starting_row) as f64)
+                                as usize;
+                            if progress != old_progress {
+                                context.progress(&format!("Progress (rec {} of {} part {})", record_num + 1, num_records, part_num), progress)?;
+                                old_progress = progress;
                             }
                         }
                         part_num += 1;
@@ -394,22 +379,19 @@ impl WhiteboxTool for ClipRasterToPolygon {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                context.info("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
             };
 
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
         } else {
             // we'll need to trim the raster to the extent of the polygons.
@@ -524,20 +506,12 @@ impl WhiteboxTool for ClipRasterToPolygon {
                                     output.set_value(r, c, input.get_value(row_in, col_in));
                                 }
                             }
-                            if verbose {
-                                progress = (100.0_f64 * (r - starting_row) as f64
-                                    / (ending_row - starting_row) as f64)
-                                    as usize;
-                                if progress != old_progress {
-                                    println!(
-                                        "Progress (rec {} of {} part {}): {}%",
-                                        record_num + 1,
-                                        num_records,
-                                        part_num,
-                                        progress
-                                    );
-                                    old_progress = progress;
-                                }
+                            progress = (100.0_f64 * (r - starting_row) as f64
+                                / (ending_row - starting_row) as f64)
+                                as usize;
+                            if progress != old_progress {
+                                context.progress(&format!("Progress (rec {} of {} part {})", record_num + 1, num_records, part_num), progress)?;
+                                old_progress = progress;
                             }
                         }
                         part_num += 1;
@@ -588,20 +562,12 @@ impl WhiteboxTool for ClipRasterToPolygon {
                                     output.set_value(r, c, nodata);
                                 }
                             }
-                            if verbose {
-                                progress = (100.0_f64 * (r - starting_row) as f64
-                                    / (ending_row - starting_row) as f64)
-                                    as usize;
-                                if progress != old_progress {
-                                    println!(
-                                        "Progress (rec {} of {} part {}): {}%",
-                                        record_num + 1,
-                                        num_records,
-                                        part_num,
-                                        progress
-                                    );
-                                    old_progress = progress;
-                                }
+                            progress = (100.0_f64 * (r - starting_row) as f64
+                                / (ending_row - starting_row) as f64)
+                                as usize;
+                            if progress != old_progress {
+                                context.progress(&format!("Progress (rec {} of {} part {})", record_num + 1, num_records, part_num), progress)?;
+                                old_progress = progress;
                             }
                         }
                         part_num += 1;
@@ -618,22 +584,19 @@ impl WhiteboxTool for ClipRasterToPolygon {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                context.info("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
             };
 
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
         }
 
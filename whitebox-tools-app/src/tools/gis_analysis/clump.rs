@@ -141,8 +141,9 @@ impl WhiteboxTool for Clump {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut diag = false;
@@ -191,11 +192,11 @@ impl WhiteboxTool for Clump {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -211,7 +212,7 @@ impl WhiteboxTool for Clump {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -223,6 +224,7 @@ impl WhiteboxTool for Clump {
         let columns = input.configs.columns as isize;
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.widen_to_f64();
         let out_nodata = -999f64;
         output.reinitialize_values(out_nodata);
         output.configs.nodata = out_nodata;
@@ -250,11 +252,11 @@ impl WhiteboxTool for Clump {
         let mut count: usize; // this is just used to update the progress after every 1000 cells solved.
         for row in 0..rows {
             for col in 0..columns {
-                zin = input[(row, col)];
-                zout = output[(row, col)];
+                zin = input.get_value(row, col);
+                zout = output.get_value(row, col);
                 if zin != nodata && zin != back_val && zout == out_nodata {
                     fid += 1f64;
-                    output[(row, col)] = fid;
+                    output.set_value(row, col, fid);
                     num_solved_cells += 1;
                     stack.push((row, col));
                     count = 0;
@@ -265,21 +267,19 @@ impl WhiteboxTool for Clump {
                         count += 1;
                         if count == 1000 {
                             count = 0;
-                            if verbose {
-                                progress = (100.0_f64 * num_solved_cells as f64
-                                    / (num_cells - 1) as f64)
-                                    as usize;
-                                if progress != old_progress {
-                                    println!("Performing analysis: {}%", progress);
-                                    old_progress = progress;
-                                }
+                            progress = (100.0_f64 * num_solved_cells as f64
+                                / (num_cells - 1) as f64)
+                                as usize;
+                            if progress != old_progress {
+                                context.progress("Performing analysis", progress)?;
+                                old_progress = progress;
                             }
                         }
                         for i in 0..num_neighbours {
-                            zn = input[(r + dy[i], c + dx[i])];
-                            zout = output[(r + dy[i], c + dx[i])];
+                            zn = input.get_value(r + dy[i], c + dx[i]);
+                            zout = output.get_value(r + dy[i], c + dx[i]);
                             if zn == zin && zout == out_nodata {
-                                output[(r + dy[i], c + dx[i])] = fid;
+                                output.set_value(r + dy[i], c + dx[i], fid);
                                 num_solved_cells += 1;
                                 stack.push((r + dy[i], c + dx[i]));
                             }
@@ -289,15 +289,13 @@ impl WhiteboxTool for Clump {
                     num_solved_cells += 1;
                 } else if zin == back_val {
                     num_solved_cells += 1;
-                    output[(row, col)] = back_val;
+                    output.set_value(row, col, back_val);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Performing analysis: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Performing analysis", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -312,22 +310,19 @@ impl WhiteboxTool for Clump {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
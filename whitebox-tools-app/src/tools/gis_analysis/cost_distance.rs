@@ -157,8 +157,9 @@ impl WhiteboxTool for CostDistance {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut source_file = String::new();
         let mut cost_file = String::new();
         let mut accum_file = String::new();
@@ -211,11 +212,11 @@ impl WhiteboxTool for CostDistance {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -237,12 +238,12 @@ impl WhiteboxTool for CostDistance {
         }
 
         if verbose {
-            println!("Reading source data...")
+            context.info("Reading source data...")
         };
         let source = Raster::new(&source_file, "r")?;
 
         if verbose {
-            println!("Reading cost data...")
+            context.info("Reading cost data...")
         };
         let cost = Raster::new(&cost_file, "r")?;
 
@@ -263,6 +264,7 @@ impl WhiteboxTool for CostDistance {
         let nodata = cost.configs.nodata;
 
         let mut output = Raster::initialize_using_file(&accum_file, &cost);
+        output.widen_to_f64();
         output.configs.data_type = DataType::F32;
         let background_val = (i32::max_value() - 1) as f64;
         output.reinitialize_values(background_val);
@@ -288,12 +290,10 @@ impl WhiteboxTool for CostDistance {
                     solved_cells += 1;
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Initializing: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Initializing", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -347,12 +347,10 @@ impl WhiteboxTool for CostDistance {
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * solved_cells as f64 / (num_cells - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
         }
@@ -403,12 +401,10 @@ impl WhiteboxTool for CostDistance {
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Loop {}", loop_num), progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -436,12 +432,10 @@ impl WhiteboxTool for CostDistance {
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Loop {}", loop_num), progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -469,12 +463,10 @@ impl WhiteboxTool for CostDistance {
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Loop {}", loop_num), progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -502,12 +494,10 @@ impl WhiteboxTool for CostDistance {
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Loop {}", loop_num), progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -537,12 +527,10 @@ impl WhiteboxTool for CostDistance {
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Loop {}", loop_num), progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -570,12 +558,10 @@ impl WhiteboxTool for CostDistance {
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Loop {}", loop_num), progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -603,12 +589,10 @@ impl WhiteboxTool for CostDistance {
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Loop {}", loop_num), progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -636,12 +620,10 @@ impl WhiteboxTool for CostDistance {
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Loop {}: {}%", loop_num, progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Loop {}", loop_num), progress)?;
+                    old_progress = progress;
                 }
             }
         }
@@ -659,12 +641,12 @@ impl WhiteboxTool for CostDistance {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -682,17 +664,14 @@ impl WhiteboxTool for CostDistance {
         let _ = match backlink.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -137,8 +137,9 @@ impl WhiteboxTool for CostPathway {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut destination_file = String::new();
         let mut backlink_file = String::new();
         let mut output_file = String::new();
@@ -192,11 +193,11 @@ impl WhiteboxTool for CostPathway {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -215,12 +216,12 @@ impl WhiteboxTool for CostPathway {
         }
 
         if verbose {
-            println!("Reading destination data...")
+            context.info("Reading destination data...")
         };
         let destination = Raster::new(&destination_file, "r")?;
 
         if verbose {
-            println!("Reading backlink data...")
+            context.info("Reading backlink data...")
         };
         let backlink = Raster::new(&backlink_file, "r")?;
 
@@ -243,6 +244,7 @@ impl WhiteboxTool for CostPathway {
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &destination);
+        output.widen_to_f64();
         output.configs.data_type = DataType::F32;
         output.reinitialize_values(background_val);
 
@@ -264,18 +266,18 @@ impl WhiteboxTool for CostPathway {
         let mut dir: f64;
         for row in 0..rows {
             for col in 0..columns {
-                if destination[(row, col)] > 0.0 && backlink[(row, col)] != nodata {
+                if destination.get_value(row, col) > 0.0 && backlink.get_value(row, col) != nodata {
                     flag = false;
                     x = col;
                     y = row;
                     while !flag {
-                        if output[(y, x)] == background_val {
-                            output[(y, x)] = 1.0;
+                        if output.get_value(y, x) == background_val {
+                            output.set_value(y, x, 1.0);
                         } else {
                             output.increment(y, x, 1.0);
                         }
                         // find its downslope neighbour
-                        dir = backlink[(y, x)];
+                        dir = backlink.get_value(y, x);
                         if dir != nodata && dir > 0.0 {
                             // move x and y accordingly
                             x += dx[pntr_matches[dir as usize]];
@@ -284,16 +286,14 @@ impl WhiteboxTool for CostPathway {
                             flag = true;
                         }
                     }
-                } else if backlink[(row, col)] == nodata {
-                    output[(row, col)] = nodata;
+                } else if backlink.get_value(row, col) == nodata {
+                    output.set_value(row, col, nodata);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -310,22 +310,19 @@ impl WhiteboxTool for CostPathway {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -165,8 +165,9 @@ impl WhiteboxTool for Difference {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut overlay_file = String::new();
         let mut output_file = String::new();
@@ -219,11 +220,11 @@ impl WhiteboxTool for Difference {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if !input_file.contains(&sep) && !input_file.contains("/") {
@@ -237,7 +238,7 @@ impl WhiteboxTool for Difference {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let overlay = Shapefile::read(&overlay_file)?;
@@ -339,12 +340,10 @@ impl WhiteboxTool for Difference {
                         let atts = input.attributes.get_record(record_num);
                         output.attributes.add_record(atts, false);
                     }
-                    if verbose {
-                        progress = (100.0_f64 * record_num as f64 / num_total_points) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * record_num as f64 / num_total_points) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -388,12 +387,10 @@ impl WhiteboxTool for Difference {
                                 break;
                             }
                         }
-                        if verbose {
-                            progress = (100.0_f64 * i as f64 / num_total_points) as usize;
-                            if progress != old_progress {
-                                println!("Progress: {}%", progress);
-                                old_progress = progress;
-                            }
+                        progress = (100.0_f64 * i as f64 / num_total_points) as usize;
+                        if progress != old_progress {
+                            context.progress("Progress", progress)?;
+                            old_progress = progress;
                         }
                     }
                 }
@@ -452,7 +449,7 @@ impl WhiteboxTool for Difference {
                         .attributes
                         .add_record(vec![FieldData::Int(1i32)], false);
                 } else {
-                    println!("WARNING: no features were ouput from the tool.");
+                    context.warning("WARNING: no features were ouput from the tool.");
                 }
             }
             ShapeType::PolyLine => {
@@ -545,12 +542,10 @@ impl WhiteboxTool for Difference {
 
                     num_neighbours.push(line_num_neighbours);
 
-                    if verbose {
-                        progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
 
@@ -578,12 +573,10 @@ impl WhiteboxTool for Difference {
                     }
                     features_polylines.push(pl.clone());
 
-                    if verbose {
-                        progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
 
@@ -1692,14 +1685,12 @@ impl WhiteboxTool for Difference {
                             output.attributes.add_record(atts, false);
                         }
 
-                        if verbose {
-                            progress = (100.0_f64 * (record_num + 1) as f64
-                                / multipolylines.len() as f64)
-                                as usize;
-                            if progress != old_progress {
-                                println!("Progress: {}%", progress);
-                                old_progress = progress;
-                            }
+                        progress = (100.0_f64 * (record_num + 1) as f64
+                            / multipolylines.len() as f64)
+                            as usize;
+                        if progress != old_progress {
+                            context.progress("Progress", progress)?;
+                            old_progress = progress;
                         }
                     }
                 }
@@ -1713,12 +1704,12 @@ impl WhiteboxTool for Difference {
         }
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -1727,7 +1718,7 @@ impl WhiteboxTool for Difference {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
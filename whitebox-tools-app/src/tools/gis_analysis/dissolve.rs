@@ -158,8 +158,9 @@ impl WhiteboxTool for Dissolve {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut dissolve_key = String::new();
         let mut output_file = String::new();
@@ -228,11 +229,11 @@ impl WhiteboxTool for Dissolve {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if !input_file.contains(&sep) && !input_file.contains("/") {
@@ -243,7 +244,7 @@ impl WhiteboxTool for Dissolve {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Shapefile::read(&input_file)?;
@@ -345,7 +346,7 @@ impl WhiteboxTool for Dissolve {
 
         // Break the polygons up into line segments at junction points and endnodes.
         if verbose {
-            println!("Breaking polygons into line segments...")
+            context.info("Breaking polygons into line segments...")
         };
         let dimensions = 2;
         let capacity_per_node = 64;
@@ -443,12 +444,10 @@ impl WhiteboxTool for Dissolve {
                     pl.vertices.push(polygons[i][j]);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * (i + 1) as f64 / num_polygons as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (i + 1) as f64 / num_polygons as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -468,7 +467,7 @@ impl WhiteboxTool for Dissolve {
         }
 
         if verbose {
-            println!("Removing duplicate line segments...")
+            context.info("Removing duplicate line segments...")
         };
         // Find duplicate polylines and remove them
         let mut duplicate = vec![false; polylines.len()];
@@ -485,12 +484,10 @@ impl WhiteboxTool for Dissolve {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
         for i in (0..polylines.len()).rev() {
@@ -517,11 +514,11 @@ impl WhiteboxTool for Dissolve {
         }
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output2.write() {
             Ok(_) => if verbose {
-                println!("Output file written")
+                context.info("Output file written")
             },
             Err(e) => return Err(e),
         };
@@ -530,7 +527,7 @@ impl WhiteboxTool for Dissolve {
         */
 
         if verbose {
-            println!("Rebuilding polygons...")
+            context.info("Rebuilding polygons...")
         };
 
         let num_endnodes = num_polylines * 2;
@@ -1101,18 +1098,16 @@ impl WhiteboxTool for Dissolve {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * (i + 1) as f64 / num_polylines as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (i + 1) as f64 / num_polylines as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         // can any of the hulls be added as holes in other polygons?
         if verbose {
-            println!("Resolving polygon holes...")
+            context.info("Resolving polygon holes...")
         };
         for a in 0..hull_geometries.len() {
             let hull_bb = hull_geometries[a].get_bounding_box();
@@ -1156,12 +1151,12 @@ impl WhiteboxTool for Dissolve {
         }
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -1170,7 +1165,7 @@ impl WhiteboxTool for Dissolve {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
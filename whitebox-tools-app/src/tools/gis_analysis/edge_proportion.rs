@@ -132,8 +132,9 @@ impl WhiteboxTool for EdgeProportion {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut output_text = false;
@@ -178,11 +179,11 @@ impl WhiteboxTool for EdgeProportion {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -198,7 +199,7 @@ impl WhiteboxTool for EdgeProportion {
         }
 
         if verbose {
-            println!("Reading input data...");
+            context.info("Reading input data...");
         }
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let rows = input.configs.rows as isize;
@@ -229,13 +230,13 @@ impl WhiteboxTool for EdgeProportion {
                 let mut bin: usize;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     for col in 0..columns {
-                        z = input[(row, col)];
+                        z = input.get_value(row, col);
                         if z > 0f64 && z != nodata {
                             bin = z.floor() as usize;
                             num_cells[bin] += 1;
                             is_edge = false;
                             for n in 0..8 {
-                                zn = input[(row + dy[n], col + dx[n])];
+                                zn = input.get_value(row + dy[n], col + dx[n]);
                                 if zn != z {
                                     is_edge = true;
                                     break;
@@ -247,7 +248,7 @@ impl WhiteboxTool for EdgeProportion {
                         }
                     }
                 }
-                tx.send((num_cells, num_edge_cells)).unwrap();
+                let _ = tx.send((num_cells, num_edge_cells));
             });
         }
 
@@ -259,12 +260,10 @@ impl WhiteboxTool for EdgeProportion {
                 num_cells[bin] += vec1[bin];
                 num_edge_cells[bin] += vec2[bin];
             }
-            if verbose {
-                progress = (100.0_f64 * tid as f64 / (num_procs - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (Loop 1 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * tid as f64 / (num_procs - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (Loop 1 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -287,13 +286,15 @@ impl WhiteboxTool for EdgeProportion {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data = vec![nodata; columns as usize];
                     for col in 0..columns {
-                        z = input[(row, col)];
+                        z = input.get_value(row, col);
                         if z > 0f64 && z != nodata {
                             bin = z.floor() as usize;
                             data[col as usize] = edge_props[bin];
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -306,12 +307,10 @@ impl WhiteboxTool for EdgeProportion {
         for r in 0..rows {
             let (row, data) = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(row, data);
-            if verbose {
-                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (Loop 2 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (Loop 2 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -324,31 +323,28 @@ impl WhiteboxTool for EdgeProportion {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if output_text {
-            println!("Edge Proportion\nPatch ID\tValue");
+            context.info("Edge Proportion\nPatch ID\tValue");
             for bin in 0..max_val + 1 {
                 if edge_props[bin] > 0f64 && edge_props[bin] != nodata {
-                    println!("{}\t{}", bin, edge_props[bin]);
+                    context.info(format!("{}\t{}", bin, edge_props[bin]));
                 }
             }
         }
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
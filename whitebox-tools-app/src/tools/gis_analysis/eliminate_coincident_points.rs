@@ -137,8 +137,9 @@ impl WhiteboxTool for EliminateCoincidentPoints {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut tolerance = 0f64;
@@ -197,11 +198,11 @@ impl WhiteboxTool for EliminateCoincidentPoints {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if tolerance <= 0f64 {
@@ -249,12 +250,10 @@ impl WhiteboxTool for EliminateCoincidentPoints {
             y = record.points[0].y;
             frs.insert(x, y, record_num);
 
-            if verbose {
-                progress = (100.0_f64 * record_num as f64 / (num_points - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Building fixed-radius search: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * record_num as f64 / (num_points - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Building fixed-radius search", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -276,22 +275,20 @@ impl WhiteboxTool for EliminateCoincidentPoints {
                 let atts = input.attributes.get_record(record_num);
                 output.attributes.add_record(atts.clone(), false);
             }
-            if verbose {
-                progress = (100.0_f64 * record_num as f64 / (num_points - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * record_num as f64 / (num_points - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -300,7 +297,7 @@ impl WhiteboxTool for EliminateCoincidentPoints {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -151,8 +151,9 @@ impl WhiteboxTool for Erase {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut erase_file = String::new();
         let mut output_file = String::new();
@@ -205,11 +206,11 @@ impl WhiteboxTool for Erase {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if !input_file.contains(&sep) && !input_file.contains("/") {
@@ -223,7 +224,7 @@ impl WhiteboxTool for Erase {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let erase = Arc::new(Shapefile::read(&erase_file)?);
@@ -317,7 +318,9 @@ impl WhiteboxTool for Erase {
                                     }
                                 }
                             }
-                            tx.send((record_num, out)).unwrap();
+                            if tx.send((record_num, out)).is_err() {
+                                break;
+                            }
                         }
                     });
                 }
@@ -328,12 +331,10 @@ impl WhiteboxTool for Erase {
                     if !out {
                         output_feature[record_num] = true;
                     }
-                    if verbose {
-                        progress = (100.0_f64 * r as f64 / (input.num_records - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * r as f64 / (input.num_records - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
 
@@ -354,12 +355,10 @@ impl WhiteboxTool for Erase {
                                 .add_record(input.attributes.get_record(r).clone(), false)
                         }
                     }
-                    if verbose {
-                        progress = (100.0_f64 * r as f64 / (input.num_records - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * r as f64 / (input.num_records - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -393,7 +392,9 @@ impl WhiteboxTool for Erase {
                                         }
                                     }
                                 }
-                                tx.send((point_num, out)).unwrap();
+                                if tx.send((point_num, out)).is_err() {
+                                    break;
+                                }
                             }
                         });
                     }
@@ -452,13 +453,11 @@ impl WhiteboxTool for Erase {
                         }
                     }
 
-                    if verbose {
-                        progress = (100.0_f64 * record_num as f64 / (input.num_records - 1) as f64)
-                            as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * record_num as f64 / (input.num_records - 1) as f64)
+                        as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -552,14 +551,12 @@ impl WhiteboxTool for Erase {
                         }
                     }
 
-                    if verbose {
-                        progress = (100.0_f64 * (record_num1 + 1) as f64
-                            / features_polylines.len() as f64)
-                            as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * (record_num1 + 1) as f64
+                        / features_polylines.len() as f64)
+                        as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -971,11 +968,11 @@ impl WhiteboxTool for Erase {
                             }
 
                             if verbose {
-                                println!("Saving data...")
+                                context.info("Saving data...")
                             };
                             let _ = match output2.write() {
                                 Ok(_) => if verbose {
-                                    println!("Output file written")
+                                    context.info("Output file written")
                                 },
                                 Err(e) => return Err(e),
                             };
@@ -1580,13 +1577,11 @@ impl WhiteboxTool for Erase {
                         }
                     }
 
-                    if verbose {
-                        progress = (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64)
-                            as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64)
+                        as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -1599,12 +1594,12 @@ impl WhiteboxTool for Erase {
         }
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -1613,7 +1608,7 @@ impl WhiteboxTool for Erase {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -123,8 +123,9 @@ impl WhiteboxTool for ErasePolygonFromRaster {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut polygons_file = String::new();
         let mut output_file = String::new();
@@ -170,11 +171,11 @@ impl WhiteboxTool for ErasePolygonFromRaster {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -193,7 +194,7 @@ impl WhiteboxTool for ErasePolygonFromRaster {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let input = Raster::new(&input_file, "r")?;
 
@@ -277,19 +278,11 @@ impl WhiteboxTool for ErasePolygonFromRaster {
                                 output.set_value(r, c, nodata);
                             }
                         }
-                        if verbose {
-                            progress = (100.0_f64 * r as f64 / (ending_row - starting_row) as f64)
-                                as usize;
-                            if progress != old_progress {
-                                println!(
-                                    "Progress (rec {} of {} part {}): {}%",
-                                    record_num + 1,
-                                    num_records,
-                                    part_num,
-                                    progress
-                                );
-                                old_progress = progress;
-                            }
+                        progress = (100.0_f64 * r as f64 / (ending_row - starting_row) as f64)
+                            as usize;
+                        if progress != old_progress {
+                            context.progress(&format!("Progress (rec {} of {} part {})", record_num + 1, num_records, part_num), progress)?;
+                            old_progress = progress;
                         }
                     }
                     part_num += 1;
@@ -340,19 +333,11 @@ impl WhiteboxTool for ErasePolygonFromRaster {
                                 output.set_value(r, c, input.get_value(r, c));
                             }
                         }
-                        if verbose {
-                            progress = (100.0_f64 * r as f64 / (ending_row - starting_row) as f64)
-                                as usize;
-                            if progress != old_progress {
-                                println!(
-                                    "Progress (rec {} of {} part {}): {}%",
-                                    record_num + 1,
-                                    num_records,
-                                    part_num,
-                                    progress
-                                );
-                                old_progress = progress;
-                            }
+                        progress = (100.0_f64 * r as f64 / (ending_row - starting_row) as f64)
+                            as usize;
+                        if progress != old_progress {
+                            context.progress(&format!("Progress (rec {} of {} part {})", record_num + 1, num_records, part_num), progress)?;
+                            old_progress = progress;
                         }
                     }
                     part_num += 1;
@@ -369,22 +354,19 @@ impl WhiteboxTool for ErasePolygonFromRaster {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -129,8 +129,9 @@ impl WhiteboxTool for EuclideanDistance {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
 
@@ -169,11 +170,11 @@ impl WhiteboxTool for EuclideanDistance {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -189,7 +190,7 @@ impl WhiteboxTool for EuclideanDistance {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -204,6 +205,7 @@ impl WhiteboxTool for EuclideanDistance {
         let mut ry: Array2D<f64> = Array2D::new(rows, columns, 0f64, nodata)?;
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.widen_to_f64();
         output.configs.data_type = DataType::F32;
 
         let mut h: f64;
@@ -224,12 +226,10 @@ impl WhiteboxTool for EuclideanDistance {
                     output.set_value(row, col, inf_val);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Initializing Rasters: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Initializing Rasters", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -266,12 +266,10 @@ impl WhiteboxTool for EuclideanDistance {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (1 of 3): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (1 of 3)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -300,7 +298,7 @@ impl WhiteboxTool for EuclideanDistance {
                         }
                     }
                     if z_min < z {
-                        output[(row, col)] = z_min;
+                        output.set_value(row, col, z_min);
                         x = col + dx[which_cell];
                         y = row + dy[which_cell];
                         rx.set_value(row, col, rx.get_value(y, x) + gx[which_cell]);
@@ -308,12 +306,10 @@ impl WhiteboxTool for EuclideanDistance {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * (rows - row) as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (2 of 3): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (rows - row) as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (2 of 3)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -326,12 +322,10 @@ impl WhiteboxTool for EuclideanDistance {
                     output.set_value(row, col, nodata);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (3 of 3): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (3 of 3)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -345,22 +339,19 @@ impl WhiteboxTool for EuclideanDistance {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
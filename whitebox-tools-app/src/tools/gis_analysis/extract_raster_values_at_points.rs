@@ -134,8 +134,9 @@ impl WhiteboxTool for ExtractRasterValuesAtPoints {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_files = String::new();
         let mut points_file = String::new();
         let mut output_text = false;
@@ -179,11 +180,11 @@ impl WhiteboxTool for ExtractRasterValuesAtPoints {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -238,7 +239,7 @@ impl WhiteboxTool for ExtractRasterValuesAtPoints {
         for value in v {
             if !value.trim().is_empty() {
                 if verbose {
-                    println!("Reading data...")
+                    context.info("Reading data...")
                 };
 
                 let mut input_file = value.trim().to_owned();
@@ -267,34 +268,31 @@ impl WhiteboxTool for ExtractRasterValuesAtPoints {
         }
 
         if output_text {
-            println!("Point values:");
+            context.info("Point values:");
             for record_num in 0..num_records {
-                println!(
+                context.info(format!(
                     "Point {} values: {:?}",
                     record_num + 1,
                     raster_values[record_num]
-                );
+                ));
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match points.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
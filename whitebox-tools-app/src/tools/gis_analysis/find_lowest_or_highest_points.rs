@@ -137,8 +137,9 @@ impl WhiteboxTool for FindLowestOrHighestPoints {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut out_type = "lowest".to_owned();
@@ -196,11 +197,11 @@ impl WhiteboxTool for FindLowestOrHighestPoints {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -213,7 +214,7 @@ impl WhiteboxTool for FindLowestOrHighestPoints {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -245,6 +246,7 @@ impl WhiteboxTool for FindLowestOrHighestPoints {
             let input = input.clone();
             let rows_completed = rows_completed.clone();
             let old_progress = old_progress.clone();
+            let context = context.clone();
             let tx = tx.clone();
             thread::spawn(move || {
                 let mut z: f64;
@@ -276,17 +278,16 @@ impl WhiteboxTool for FindLowestOrHighestPoints {
                         Some(val) => val,
                         None => 0, // There are no more tiles to interpolate
                     };
-                    if verbose {
-                        progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                        let mut p = old_progress.lock().unwrap();
-                        if progress != *p {
-                            println!("Progress: {}%", progress);
-                            *p = progress;
+                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                    let mut p = old_progress.lock().unwrap();
+                    if progress != *p {
+                        if context.progress("Progress", progress).is_err() {
+                            break;
                         }
+                        *p = progress;
                     }
                 }
-                tx.send((low_z, low_col, low_row, high_z, high_col, high_row))
-                    .unwrap();
+                let _ = tx.send((low_z, low_col, low_row, high_z, high_col, high_row));
             });
         }
 
@@ -309,6 +310,7 @@ impl WhiteboxTool for FindLowestOrHighestPoints {
                 high_row = data.5;
             }
         }
+        context.check_cancelled()?;
 
         // add the vector record(s)
         let mut rec_num = 1i32;
@@ -337,21 +339,18 @@ impl WhiteboxTool for FindLowestOrHighestPoints {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
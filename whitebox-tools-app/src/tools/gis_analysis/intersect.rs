@@ -172,8 +172,9 @@ impl WhiteboxTool for Intersect {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut overlay_file = String::new();
         let mut output_file = String::new();
@@ -242,11 +243,11 @@ impl WhiteboxTool for Intersect {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if !input_file.contains(&sep) && !input_file.contains("/") {
@@ -260,7 +261,7 @@ impl WhiteboxTool for Intersect {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let overlay = Shapefile::read(&overlay_file)?;
@@ -372,12 +373,10 @@ impl WhiteboxTool for Intersect {
                         }
                         output.attributes.add_record(out_atts, false);
                     }
-                    if verbose {
-                        progress = (100.0_f64 * record_num as f64 / num_total_points) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * record_num as f64 / num_total_points) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -421,12 +420,10 @@ impl WhiteboxTool for Intersect {
                                 break;
                             }
                         }
-                        if verbose {
-                            progress = (100.0_f64 * i as f64 / (total_points - 1) as f64) as usize;
-                            if progress != old_progress {
-                                println!("Progress: {}%", progress);
-                                old_progress = progress;
-                            }
+                        progress = (100.0_f64 * i as f64 / (total_points - 1) as f64) as usize;
+                        if progress != old_progress {
+                            context.progress("Progress", progress)?;
+                            old_progress = progress;
                         }
                     }
                 }
@@ -486,7 +483,7 @@ impl WhiteboxTool for Intersect {
                         .attributes
                         .add_record(vec![FieldData::Int(1i32)], false);
                 } else {
-                    println!("WARNING: no features were ouput from the tool.");
+                    context.warning("WARNING: no features were ouput from the tool.");
                 }
             }
             ShapeType::PolyLine => {
@@ -575,12 +572,10 @@ impl WhiteboxTool for Intersect {
 
                     num_neighbours.push(line_num_neighbours);
 
-                    if verbose {
-                        progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
 
@@ -608,12 +603,10 @@ impl WhiteboxTool for Intersect {
                     }
                     features_polylines.push(pl.clone());
 
-                    if verbose {
-                        progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
 
@@ -1762,14 +1755,12 @@ impl WhiteboxTool for Intersect {
                             }
                         }
 
-                        if verbose {
-                            progress = (100.0_f64 * (record_num + 1) as f64
-                                / multipolylines.len() as f64)
-                                as usize;
-                            if progress != old_progress {
-                                println!("Progress: {}%", progress);
-                                old_progress = progress;
-                            }
+                        progress = (100.0_f64 * (record_num + 1) as f64
+                            / multipolylines.len() as f64)
+                            as usize;
+                        if progress != old_progress {
+                            context.progress("Progress", progress)?;
+                            old_progress = progress;
                         }
                     }
                 }
@@ -1783,12 +1774,12 @@ impl WhiteboxTool for Intersect {
         }
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -1797,7 +1788,7 @@ impl WhiteboxTool for Intersect {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
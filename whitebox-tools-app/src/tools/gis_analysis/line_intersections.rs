@@ -145,8 +145,9 @@ impl WhiteboxTool for LineIntersections {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input1_file: String = "".to_string();
         let mut input2_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -199,11 +200,11 @@ impl WhiteboxTool for LineIntersections {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if !input1_file.contains(path::MAIN_SEPARATOR) && !input1_file.contains("/") {
@@ -361,10 +362,11 @@ impl WhiteboxTool for LineIntersections {
                             }
                         }
                     }
-                    tx2.send(num_intersections).unwrap();
+                    if tx2.send(num_intersections).is_err() {
+                        break;
+                    }
                 }
-                tx1.send((intersection_points.clone(), print_warning))
-                    .unwrap();
+                let _ = tx1.send((intersection_points.clone(), print_warning));
             });
         }
 
@@ -437,33 +439,28 @@ impl WhiteboxTool for LineIntersections {
         for i in 0..input1.num_records {
             let found_intersections = rx2.recv().unwrap();
             num_intersections += found_intersections;
-            if verbose {
-                progress = (100.0_f64 * (i + 1) as f64 / input1.num_records as f64) as usize;
-                if progress != old_progress {
-                    println!(
-                        "Progress ({} intersections found): {}%",
-                        num_intersections, progress
-                    );
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (i + 1) as f64 / input1.num_records as f64) as usize;
+            if progress != old_progress {
+                context.progress(&format!("Progress ({} intersections found)", num_intersections), progress)?;
+                old_progress = progress;
             }
         }
 
         if print_warning {
-            println!("Warning: Some of the input line features intersect at line segments rather than points.")
+            context.warning("Warning: Some of the input line features intersect at line segments rather than points.")
         }
 
         if fid == 1 {
-            println!("Warning: No intersections were found between the input features.")
+            context.warning("Warning: No intersections were found between the input features.")
         } else {
             // Some features were found. Save the output file.
             if verbose {
-                println!("Saving data...")
+                context.info("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
@@ -473,7 +470,7 @@ impl WhiteboxTool for LineIntersections {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -47,7 +47,7 @@ impl LowestPosition {
         parameters.push(ToolParameter {
             name: "Input Files".to_owned(),
             flags: vec!["-i".to_owned(), "--inputs".to_owned()],
-            description: "Input raster files.".to_owned(),
+            description: "Input raster files, or a single multiband raster.".to_owned(),
             parameter_type: ParameterType::FileList(ParameterFileType::Raster),
             default_value: None,
             optional: false,
@@ -117,8 +117,9 @@ impl WhiteboxTool for LowestPosition {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_files = String::new();
         let mut output_file = String::new();
 
@@ -156,11 +157,11 @@ impl WhiteboxTool for LowestPosition {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -172,13 +173,8 @@ impl WhiteboxTool for LowestPosition {
             output_file = format!("{}{}", working_directory, output_file);
         }
 
-        let mut cmd = input_files.split(";");
-        let mut vec = cmd.collect::<Vec<&str>>();
-        if vec.len() == 1 {
-            cmd = input_files.split(",");
-            vec = cmd.collect::<Vec<&str>>();
-        }
-        let num_files = vec.len();
+        let inputs = RasterInputs::from_list(&input_files, working_directory)?;
+        let num_files = inputs.len();
         if num_files < 2 {
             return Err(Error::new(ErrorKind::InvalidInput,
                                 "There is something incorrect about the input files. At least two inputs are required to operate this tool."));
@@ -195,55 +191,45 @@ impl WhiteboxTool for LowestPosition {
         let mut in_nodata: f64;
         let mut z: f64;
         let mut read_first_file = false;
-        let mut i = 1;
-        for value in vec {
-            if !value.trim().is_empty() {
-                if verbose {
-                    println!("Reading data...")
-                };
+        for file_num in 0..num_files {
+            if verbose {
+                context.info("Reading data...")
+            };
 
-                let mut input_file = value.trim().to_owned();
-                if !input_file.contains(&sep) && !input_file.contains("/") {
-                    input_file = format!("{}{}", working_directory, input_file);
-                }
-                let input = Raster::new(&input_file, "r")?;
-                in_nodata = input.configs.nodata;
-                if !read_first_file {
-                    read_first_file = true;
-                    rows = input.configs.rows as isize;
-                    columns = input.configs.columns as isize;
+            let input = inputs.get(file_num)?;
+            in_nodata = input.configs.nodata;
+            if !read_first_file {
+                read_first_file = true;
+                rows = input.configs.rows as isize;
+                columns = input.configs.columns as isize;
 
-                    // initialize the output file and low_val
-                    output = Raster::initialize_using_file(&output_file, &input);
-                    low_val = Array2D::new(rows, columns, f64::MAX, in_nodata)?;
-                }
-                // check to ensure that all inputs have the same rows and columns
-                if input.configs.rows as isize != rows || input.configs.columns as isize != columns
-                {
-                    return Err(Error::new(ErrorKind::InvalidInput,
-                                "The input files must have the same number of rows and columns and spatial extent."));
-                }
+                // initialize the output file and low_val
+                output = Raster::initialize_using_file(&output_file, &input);
+                low_val = Array2D::new(rows, columns, f64::MAX, in_nodata)?;
+            }
+            // check to ensure that all inputs have the same rows and columns
+            if input.configs.rows as isize != rows || input.configs.columns as isize != columns
+            {
+                return Err(Error::new(ErrorKind::InvalidInput,
+                            "The input files must have the same number of rows and columns and spatial extent."));
+            }
 
-                for row in 0..rows {
-                    for col in 0..columns {
-                        z = input[(row, col)];
-                        if z != in_nodata {
-                            if z < low_val[(row, col)] {
-                                low_val[(row, col)] = z;
-                                output[(row, col)] = i as f64;
-                            }
-                        }
-                    }
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress (loop {} of {}): {}%", i, num_files, progress);
-                            old_progress = progress;
+            for row in 0..rows {
+                for col in 0..columns {
+                    z = input.get_value(row, col);
+                    if z != in_nodata {
+                        if z < low_val[(row, col)] {
+                            low_val[(row, col)] = z;
+                            output.set_value(row, col, (file_num + 1) as f64);
                         }
                     }
                 }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Progress (loop {} of {})", file_num + 1, num_files), progress)?;
+                    old_progress = progress;
+                }
             }
-            i += 1;
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
@@ -254,22 +240,19 @@ impl WhiteboxTool for LowestPosition {
         output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -122,8 +122,9 @@ impl WhiteboxTool for NarrownessIndex {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
 
@@ -162,11 +163,11 @@ impl WhiteboxTool for NarrownessIndex {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -183,7 +184,7 @@ impl WhiteboxTool for NarrownessIndex {
         }
 
         if verbose {
-            println!("Reading data...");
+            context.info("Reading data...");
         }
 
         // let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -203,9 +204,10 @@ impl WhiteboxTool for NarrownessIndex {
 
         // calcuate the distance from an edge
         if verbose {
-            println!("Calculating maximum distance from edge for each patch...");
+            context.info("Calculating maximum distance from edge for each patch...");
         }
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.widen_to_f64();
         let out_nodata = -999f64;
         output.reinitialize_values(out_nodata);
         output.configs.nodata = out_nodata;
@@ -235,9 +237,9 @@ impl WhiteboxTool for NarrownessIndex {
 
         for row in 0..rows {
             for col in 0..columns {
-                z = input[(row, col)];
+                z = input.get_value(row, col);
                 if z == 0.0 || z == nodata {
-                    output[(row, col)] = 0.0;
+                    output.set_value(row, col, 0.0);
                 } else {
                     bin = (z - min_val).floor() as usize;
                     area_data[bin] += 1;
@@ -245,39 +247,37 @@ impl WhiteboxTool for NarrownessIndex {
                     // is it an edge cell?
                     is_edge = false;
                     for a in 0..8 {
-                        z2 = input[(row + d_y[a], col + d_x[a])];
+                        z2 = input.get_value(row + d_y[a], col + d_x[a]);
                         if z2 != z {
                             is_edge = true;
                             break;
                         }
                     }
                     if !is_edge {
-                        output[(row, col)] = inf_val;
+                        output.set_value(row, col, inf_val);
                     } else {
-                        output[(row, col)] = cell_size;
+                        output.set_value(row, col, cell_size);
                         max_width[bin] = cell_size;
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Initializing Rasters: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Initializing Rasters", progress)?;
+                old_progress = progress;
             }
         }
 
         for row in 0..rows {
             for col in 0..columns {
-                z = output[(row, col)];
+                z = output.get_value(row, col);
                 if z != 0.0 {
                     z_min = inf_val;
                     which_cell = 0;
                     for i in 0..4 {
                         x = col + d_x[i];
                         y = row + d_y[i];
-                        z2 = output[(y, x)];
+                        z2 = output.get_value(y, x);
                         if z2 != out_nodata {
                             h = match i {
                                 0 => 2.0 * r_x[(y, x)] + 1.0,
@@ -293,7 +293,7 @@ impl WhiteboxTool for NarrownessIndex {
                         }
                     }
                     if z_min < z {
-                        output[(row, col)] = z_min;
+                        output.set_value(row, col, z_min);
                         x = col + d_x[which_cell];
                         y = row + d_y[which_cell];
                         r_x[(row, col)] = r_x[(y, x)] + g_x[which_cell];
@@ -301,25 +301,23 @@ impl WhiteboxTool for NarrownessIndex {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (1 of 4): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (1 of 4)", progress)?;
+                old_progress = progress;
             }
         }
 
         for row in (0..rows).rev() {
             for col in (0..columns).rev() {
-                z = output[(row, col)];
+                z = output.get_value(row, col);
                 if z != 0.0 {
                     z_min = inf_val;
                     which_cell = 0;
                     for i in 4..8 {
                         x = col + d_x[i];
                         y = row + d_y[i];
-                        z2 = output[(y, x)];
+                        z2 = output.get_value(y, x);
                         if z2 != out_nodata {
                             h = match i {
                                 5 => 2.0 * (r_x[(y, x)] + r_y[(y, x)] + 1.0),
@@ -335,7 +333,7 @@ impl WhiteboxTool for NarrownessIndex {
                         }
                     }
                     if z_min < z {
-                        output[(row, col)] = z_min;
+                        output.set_value(row, col, z_min);
                         x = col + d_x[which_cell];
                         y = row + d_y[which_cell];
                         r_x[(row, col)] = r_x[(y, x)] + g_x[which_cell];
@@ -343,38 +341,34 @@ impl WhiteboxTool for NarrownessIndex {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * (rows - row) as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (2 of 4): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (rows - row) as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (2 of 4)", progress)?;
+                old_progress = progress;
             }
         }
 
         for row in 0..rows {
             for col in 0..columns {
-                z = input[(row, col)];
+                z = input.get_value(row, col);
                 if z != nodata {
                     if z != 0f64 {
-                        output[(row, col)] = output[(row, col)].sqrt() * cell_size;
+                        output.set_value(row, col, output.get_value(row, col).sqrt() * cell_size);
                         bin = (z - min_val).floor() as usize;
-                        if output[(row, col)] > max_width[bin] {
-                            max_width[bin] = output[(row, col)];
+                        if output.get_value(row, col) > max_width[bin] {
+                            max_width[bin] = output.get_value(row, col);
                         }
                     } else {
-                        output[(row, col)] = 0f64;
+                        output.set_value(row, col, 0f64);
                     }
                 } else {
-                    output[(row, col)] = out_nodata;
+                    output.set_value(row, col, out_nodata);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (3 of 4): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (3 of 4)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -386,18 +380,16 @@ impl WhiteboxTool for NarrownessIndex {
 
         for row in 0..rows {
             for col in 0..columns {
-                z = input[(row, col)];
+                z = input.get_value(row, col);
                 if z != nodata && z != 0f64 {
                     bin = (z - min_val).floor() as usize;
-                    output[(row, col)] = max_width[bin];
+                    output.set_value(row, col, max_width[bin]);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (4 of 4): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (4 of 4)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -520,22 +512,19 @@ impl WhiteboxTool for NarrownessIndex {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
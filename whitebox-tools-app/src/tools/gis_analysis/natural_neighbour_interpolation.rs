@@ -207,8 +207,9 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut field_name = String::new();
         let mut use_z = false;
@@ -293,11 +294,11 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -435,18 +436,16 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
                 p += 1;
             }
 
-            if verbose {
-                progress =
-                    (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
-                if progress != old_progress {
-                    println!("Reading points: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
+            if progress != old_progress {
+                context.progress("Reading points", progress)?;
+                old_progress = progress;
             }
         }
 
         if verbose {
-            println!("Performing triangulation...");
+            context.info("Performing triangulation...");
         }
         // this is where the heavy-lifting is
         let delaunay = triangulate(&points).expect("No triangulation exists.");
@@ -459,7 +458,7 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
         }
 
         if verbose {
-            println!("Creating point-halfedge mapping...");
+            context.info("Creating point-halfedge mapping...");
         }
         const EMPTY: usize = usize::max_value();
         let mut point_edge_map = HashMap::new(); // point id to half-edge id
@@ -468,13 +467,11 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
             if !point_edge_map.contains_key(&endpoint) || delaunay.halfedges[edge] == EMPTY {
                 point_edge_map.insert(endpoint, edge);
             }
-            if verbose {
-                progress =
-                    (100.0_f64 * edge as f64 / (delaunay.triangles.len() - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * edge as f64 / (delaunay.triangles.len() - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -482,7 +479,7 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
         let res_y = output.configs.resolution_y;
 
         if verbose {
-            println!("Interpolating...");
+            context.info("Interpolating...");
         }
         let points = Arc::new(points);
         let z_values = Arc::new(z_values);
@@ -711,7 +708,9 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
                             };
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -719,12 +718,10 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
         for row in 0..rows {
             let data = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(data.0, data.1);
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Interpolating: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Interpolating", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -746,12 +743,12 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
         output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -760,7 +757,7 @@ impl WhiteboxTool for NaturalNeighbourInterpolation {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -56,7 +56,7 @@ impl NearestNeighbourGridding {
                 "--input".to_string(),
             ),
             default_value: None,
-            optional: false,
+            optional: true,
         });
 
         parameters.push(ToolParameter {
@@ -160,8 +160,9 @@ impl WhiteboxTool for NearestNeighbourGridding {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut field_name = String::new();
         let mut use_z = false;
@@ -245,11 +246,11 @@ impl WhiteboxTool for NearestNeighbourGridding {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -265,7 +266,7 @@ impl WhiteboxTool for NearestNeighbourGridding {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -330,14 +331,12 @@ impl WhiteboxTool for NearestNeighbourGridding {
                     }
                 }
 
-                if verbose {
-                    progress = (100.0_f64 * record_num as f64
-                        / (vector_data.num_records - 1) as f64)
-                        as usize;
-                    if progress != old_progress {
-                        println!("Creating search structure: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * record_num as f64
+                    / (vector_data.num_records - 1) as f64)
+                    as usize;
+                if progress != old_progress {
+                    context.progress("Creating search structure", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -366,14 +365,12 @@ impl WhiteboxTool for NearestNeighbourGridding {
                     frs.insert(x, y, z);
                 }
 
-                if verbose {
-                    progress = (100.0_f64 * record_num as f64
-                        / (vector_data.num_records - 1) as f64)
-                        as usize;
-                    if progress != old_progress {
-                        println!("Creating search structure: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * record_num as f64
+                    / (vector_data.num_records - 1) as f64)
+                    as usize;
+                if progress != old_progress {
+                    context.progress("Creating search structure", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -454,7 +451,9 @@ impl WhiteboxTool for NearestNeighbourGridding {
                             }
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -462,12 +461,10 @@ impl WhiteboxTool for NearestNeighbourGridding {
         for row in 0..rows {
             let data = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(data.0, data.1);
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -480,22 +477,19 @@ impl WhiteboxTool for NearestNeighbourGridding {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
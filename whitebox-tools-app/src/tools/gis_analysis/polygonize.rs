@@ -137,8 +137,9 @@ impl WhiteboxTool for Polygonize {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_files = String::new();
         let mut output_file: String = "".to_string();
 
@@ -184,11 +185,11 @@ impl WhiteboxTool for Polygonize {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if !output_file.contains(&sep) && !output_file.contains("/") {
@@ -213,7 +214,7 @@ impl WhiteboxTool for Polygonize {
         let mut bb: Vec<BoundingBox> = vec![];
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let mut projection = String::new();
         for value in vec {
@@ -296,13 +297,11 @@ impl WhiteboxTool for Polygonize {
                 }
             }
 
-            if verbose {
-                progress =
-                    (100.0_f64 * (record_num1 + 1) as f64 / in_polylines.len() as f64) as usize;
-                if progress != old_progress {
-                    println!("Finding line intersections: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * (record_num1 + 1) as f64 / in_polylines.len() as f64) as usize;
+            if progress != old_progress {
+                context.progress("Finding line intersections", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -341,7 +340,7 @@ impl WhiteboxTool for Polygonize {
         let mut p2: Point2D;
         let mut p3: Point2D;
         let mut p4: Point2D;
-        println!("Creating tree...");
+        context.info("Creating tree...");
         for i in 0..polylines.len() {
             p1 = polylines[i].first_vertex();
             kdtree.add([p1.x, p1.y], first_node_id(i)).unwrap();
@@ -352,7 +351,7 @@ impl WhiteboxTool for Polygonize {
 
         // Find the neighbours of each endnode and check for dangling arcs
         // and self-closing arcs which form single-line polys.
-        println!("Finding node vertices...");
+        context.info("Finding node vertices...");
         let mut is_acyclic_arc = vec![false; polylines.len()];
         let mut node_angles: Vec<Vec<f64>> = vec![vec![]; num_endnodes];
         let mut heading: f64;
@@ -473,12 +472,10 @@ impl WhiteboxTool for Polygonize {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
-                if progress != old_progress {
-                    println!("Finding acyclic arcs: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
+            if progress != old_progress {
+                context.progress("Finding acyclic arcs", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -792,12 +789,10 @@ impl WhiteboxTool for Polygonize {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
-                if progress != old_progress {
-                    println!("Finding polygons: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
+            if progress != old_progress {
+                context.progress("Finding polygons", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -1199,12 +1194,12 @@ impl WhiteboxTool for Polygonize {
         // }
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -1213,7 +1208,7 @@ impl WhiteboxTool for Polygonize {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
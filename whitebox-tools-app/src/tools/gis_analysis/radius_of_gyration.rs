@@ -130,8 +130,9 @@ impl WhiteboxTool for RadiusOfGyration {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut text_output = false;
@@ -175,11 +176,11 @@ impl WhiteboxTool for RadiusOfGyration {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -195,7 +196,7 @@ impl WhiteboxTool for RadiusOfGyration {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -237,7 +238,9 @@ impl WhiteboxTool for RadiusOfGyration {
                             total_n[a] += 1usize;
                         }
                     }
-                    tx.send((total_columns, total_rows, total_n)).unwrap();
+                    if tx.send((total_columns, total_rows, total_n)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -255,12 +258,10 @@ impl WhiteboxTool for RadiusOfGyration {
                 total_n[a] += n[a];
             }
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (Loop 1 of 3): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (Loop 1 of 3)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -296,7 +297,9 @@ impl WhiteboxTool for RadiusOfGyration {
                                     * ((row as f64 - centroid_y[a]) * resolution_y)
                         }
                     }
-                    tx.send(gyradius).unwrap();
+                    if tx.send(gyradius).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -308,12 +311,10 @@ impl WhiteboxTool for RadiusOfGyration {
                 gyradius[a] += g[a];
             }
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (Loop 2 of 3): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (Loop 2 of 3)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -328,7 +329,7 @@ impl WhiteboxTool for RadiusOfGyration {
         let mut a: usize;
         for row in 0..rows {
             for col in 0..columns {
-                z = input[(row, col)];
+                z = input.get_value(row, col);
                 if z > 0f64 && z != nodata {
                     a = (z - min_val as f64) as usize;
                     output.set_value(row, col, gyradius[a]);
@@ -336,20 +337,18 @@ impl WhiteboxTool for RadiusOfGyration {
                     output.set_value(row, col, z);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (Loop 3 of 3): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (Loop 3 of 3)", progress)?;
+                old_progress = progress;
             }
         }
 
         if text_output {
-            println!("Patch Radius of Gyration\nPatch ID\tValue");
+            context.info("Patch Radius of Gyration\nPatch ID\tValue");
             for a in 0..range + 1 {
                 if total_n[a] > 0 {
-                    println!("{:.0}\t{:.4}", (a + min_val), gyradius[a]);
+                    context.info(format!("{:.0}\t{:.4}", (a + min_val), gyradius[a]));
                 }
             }
         }
@@ -365,22 +364,19 @@ impl WhiteboxTool for RadiusOfGyration {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -158,8 +158,9 @@ impl WhiteboxTool for RasterArea {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut output_raster = false;
@@ -217,11 +218,11 @@ impl WhiteboxTool for RasterArea {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -234,7 +235,7 @@ impl WhiteboxTool for RasterArea {
         }
 
         if !output_raster && !output_text {
-            println!("Warning: Niether a raster nor text outputs were selected. An area raster will be generated.");
+            context.warning("Warning: Niether a raster nor text outputs were selected. An area raster will be generated.");
             output_file = String::from("area.tif");
             output_raster = true;
         }
@@ -246,7 +247,7 @@ impl WhiteboxTool for RasterArea {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -287,7 +288,7 @@ impl WhiteboxTool for RasterArea {
                             }
                         }
                     }
-                    tx.send(freq_data).unwrap();
+                    let _ = tx.send(freq_data);
                 });
             }
 
@@ -298,12 +299,10 @@ impl WhiteboxTool for RasterArea {
                     freq_data[a] += data[a];
                 }
 
-                if verbose {
-                    progress = (100.0_f64 * (tid + 1) as f64 / num_procs as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * (tid + 1) as f64 / num_procs as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -324,12 +323,10 @@ impl WhiteboxTool for RasterArea {
                             output.set_value(row, col, freq_data[bin] as f64);
                         }
                     }
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Outputting raster: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Outputting raster", progress)?;
+                        old_progress = progress;
                     }
                 }
 
@@ -343,23 +340,23 @@ impl WhiteboxTool for RasterArea {
                     .add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
                 if verbose {
-                    println!("Saving data...")
+                    context.info("Saving data...")
                 };
                 let _ = match output.write() {
                     Ok(_) => {
                         if verbose {
-                            println!("Output file written")
+                            context.info("Output file written")
                         }
                     }
                     Err(e) => return Err(e),
                 };
             }
             if output_text {
-                println!("Class,Cells");
+                context.info("Class,Cells");
                 for a in 0..num_bins {
                     if freq_data[a] > 0 {
                         val = (a as f64 + min_val).floor();
-                        println!("{},{}", val, freq_data[a]);
+                        context.info(format!("{},{}", val, freq_data[a]));
                     }
                 }
             }
@@ -367,7 +364,7 @@ impl WhiteboxTool for RasterArea {
             // map units
             let is_geographic = input.is_in_geographic_coordinates();
             if is_geographic && verbose {
-                println!("Warning: the input file does not appear to be in a projected coordinate system. Area values will only be estimates.");
+                context.warning("Warning: the input file does not appear to be in a projected coordinate system. Area values will only be estimates.");
             }
 
             let mut num_procs = num_cpus::get() as isize;
@@ -404,7 +401,7 @@ impl WhiteboxTool for RasterArea {
                             }
                         }
                     }
-                    tx.send(area_data).unwrap();
+                    let _ = tx.send(area_data);
                 });
             }
 
@@ -418,12 +415,10 @@ impl WhiteboxTool for RasterArea {
                     area_data[a] += data[a];
                 }
 
-                if verbose {
-                    progress = (100.0_f64 * (tid + 1) as f64 / num_procs as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * (tid + 1) as f64 / num_procs as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -444,12 +439,10 @@ impl WhiteboxTool for RasterArea {
                             output.set_value(row, col, area_data[bin]);
                         }
                     }
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Outputting raster: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Outputting raster", progress)?;
+                        old_progress = progress;
                     }
                 }
 
@@ -463,23 +456,23 @@ impl WhiteboxTool for RasterArea {
                     .add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
                 if verbose {
-                    println!("Saving data...")
+                    context.info("Saving data...")
                 };
                 let _ = match output.write() {
                     Ok(_) => {
                         if verbose {
-                            println!("Output file written")
+                            context.info("Output file written")
                         }
                     }
                     Err(e) => return Err(e),
                 };
             }
             if output_text {
-                println!("Class,Area");
+                context.info("Class,Area");
                 for a in 0..num_bins {
                     if area_data[a] > 0f64 {
                         val = (a as f64 + min_val).floor();
-                        println!("{},{}", val, area_data[a]);
+                        context.info(format!("{},{}", val, area_data[a]));
                     }
                 }
             }
@@ -123,8 +123,9 @@ impl WhiteboxTool for RasterCellAssignment {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut what_to_assign = String::from("column");
@@ -171,11 +172,11 @@ impl WhiteboxTool for RasterCellAssignment {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -192,7 +193,7 @@ impl WhiteboxTool for RasterCellAssignment {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
@@ -220,7 +221,9 @@ impl WhiteboxTool for RasterCellAssignment {
                             for col in 0..columns {
                                 data[col as usize] = col as f64;
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                break;
+                            }
                         }
                     });
                 }
@@ -234,7 +237,9 @@ impl WhiteboxTool for RasterCellAssignment {
                             for col in 0..columns {
                                 data[col as usize] = row as f64;
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                break;
+                            }
                         }
                     });
                 }
@@ -249,7 +254,9 @@ impl WhiteboxTool for RasterCellAssignment {
                             for col in 0..columns {
                                 data[col as usize] = input.get_x_from_column(col);
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                break;
+                            }
                         }
                     });
                 }
@@ -264,7 +271,9 @@ impl WhiteboxTool for RasterCellAssignment {
                             for col in 0..columns {
                                 data[col as usize] = input.get_y_from_row(row);
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                break;
+                            }
                         }
                     });
                 }
@@ -283,12 +292,10 @@ impl WhiteboxTool for RasterCellAssignment {
             let (row, data) = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(row, data);
 
-            if verbose {
-                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -301,22 +308,19 @@ impl WhiteboxTool for RasterCellAssignment {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
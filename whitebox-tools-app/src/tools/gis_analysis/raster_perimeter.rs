@@ -158,8 +158,9 @@ impl WhiteboxTool for RasterPerimeter {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut output_raster = false;
@@ -217,11 +218,11 @@ impl WhiteboxTool for RasterPerimeter {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -234,7 +235,7 @@ impl WhiteboxTool for RasterPerimeter {
         }
 
         if !output_raster && !output_text {
-            println!("Warning: Niether a raster nor text outputs were selected. An area raster will be generated.");
+            context.warning("Warning: Niether a raster nor text outputs were selected. An area raster will be generated.");
             output_file = String::from("area.tif");
             output_raster = true;
         }
@@ -246,7 +247,7 @@ impl WhiteboxTool for RasterPerimeter {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -530,7 +531,7 @@ impl WhiteboxTool for RasterPerimeter {
 
         let is_geographic = input.is_in_geographic_coordinates();
         if is_geographic && verbose {
-            println!("Warning: the input file does not appear to be in a projected coordinate system. Perimeter values will only be estimates.");
+            context.warning("Warning: the input file does not appear to be in a projected coordinate system. Perimeter values will only be estimates.");
         }
 
         let mut num_procs = num_cpus::get() as isize;
@@ -580,7 +581,7 @@ impl WhiteboxTool for RasterPerimeter {
                         }
                     }
                 }
-                tx.send(data).unwrap();
+                let _ = tx.send(data);
             });
         }
 
@@ -591,12 +592,10 @@ impl WhiteboxTool for RasterPerimeter {
                 data[a] += data_rx[a] * avg_res;
             }
 
-            if verbose {
-                progress = (100.0_f64 * (tid + 1) as f64 / num_procs as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (tid + 1) as f64 / num_procs as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -617,12 +616,10 @@ impl WhiteboxTool for RasterPerimeter {
                         output.set_value(row, col, data[bin] as f64);
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Outputting raster: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Outputting raster", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -635,23 +632,23 @@ impl WhiteboxTool for RasterPerimeter {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                context.info("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
             };
         }
         if output_text {
-            println!("Class,Perimeter");
+            context.info("Class,Perimeter");
             for a in 0..num_bins {
                 if data[a] > 0f64 {
                     val = (a as f64 + min_val).floor();
-                    println!("{},{}", val, data[a]);
+                    context.info(format!("{},{}", val, data[a]));
                 }
             }
         }
@@ -118,8 +118,9 @@ impl WhiteboxTool for ShapeComplexityIndexRaster {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
 
@@ -158,11 +159,11 @@ impl WhiteboxTool for ShapeComplexityIndexRaster {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -179,7 +180,7 @@ impl WhiteboxTool for ShapeComplexityIndexRaster {
         }
 
         if verbose {
-            println!("Reading data...");
+            context.info("Reading data...");
         }
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -256,8 +257,7 @@ impl WhiteboxTool for ShapeComplexityIndexRaster {
                     }
                 }
 
-                tx.send((freq_data, min_row, max_row, min_col, max_col))
-                    .unwrap();
+                let _ = tx.send((freq_data, min_row, max_row, min_col, max_col));
             });
         }
 
@@ -285,12 +285,10 @@ impl WhiteboxTool for ShapeComplexityIndexRaster {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * (tid + 1) as f64 / num_procs as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (tid + 1) as f64 / num_procs as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -322,12 +320,10 @@ impl WhiteboxTool for ShapeComplexityIndexRaster {
                     output.set_value(row, col, 0f64);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -340,22 +336,19 @@ impl WhiteboxTool for ShapeComplexityIndexRaster {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -150,8 +150,9 @@ impl WhiteboxTool for SplitWithLines {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input1_file: String = "".to_string();
         let mut input2_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -204,11 +205,11 @@ impl WhiteboxTool for SplitWithLines {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if !input1_file.contains(path::MAIN_SEPARATOR) && !input1_file.contains("/") {
@@ -540,24 +541,22 @@ impl WhiteboxTool for SplitWithLines {
                     output.attributes.add_record(atts, false);
                 }
 
-                if verbose {
-                    progress =
-                        (100.0_f64 * (record_num1 + 1) as f64 / polylines1.len() as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress =
+                    (100.0_f64 * (record_num1 + 1) as f64 / polylines1.len() as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
 
             // Some features were found. Save the output file.
             if verbose {
-                println!("Saving data...")
+                context.info("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
@@ -658,13 +657,11 @@ impl WhiteboxTool for SplitWithLines {
                     lengths.push(split_lines[j].length());
                 }
 
-                if verbose {
-                    progress =
-                        (100.0_f64 * (record_num1 + 1) as f64 / polylines1.len() as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress =
+                    (100.0_f64 * (record_num1 + 1) as f64 / polylines1.len() as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -698,12 +695,10 @@ impl WhiteboxTool for SplitWithLines {
                 p = polylines[i].last_vertex();
                 kdtree.add([p.x, p.y], last_node_id(i)).unwrap();
 
-                if verbose {
-                    progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
-                    if progress != old_progress {
-                        println!("Creating tree: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Creating tree", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -742,12 +737,10 @@ impl WhiteboxTool for SplitWithLines {
                     }
                 }
 
-                if verbose {
-                    progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
-                    if progress != old_progress {
-                        println!("Finding node vertices: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Finding node vertices", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -894,23 +887,21 @@ impl WhiteboxTool for SplitWithLines {
                     }
                 }
 
-                if verbose {
-                    progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
-                    if progress != old_progress {
-                        println!("Finding polygons: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Finding polygons", progress)?;
+                    old_progress = progress;
                 }
             }
 
             // Some features were found. Save the output file.
             if verbose {
-                println!("Saving data...")
+                context.info("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
@@ -920,7 +911,7 @@ impl WhiteboxTool for SplitWithLines {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -167,8 +167,9 @@ impl WhiteboxTool for Union {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut overlay_file = String::new();
         let mut output_file = String::new();
@@ -237,11 +238,11 @@ impl WhiteboxTool for Union {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if !input_file.contains(&sep) && !input_file.contains("/") {
@@ -255,7 +256,7 @@ impl WhiteboxTool for Union {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let overlay = Shapefile::read(&overlay_file)?;
@@ -380,12 +381,10 @@ impl WhiteboxTool for Union {
                         }
                         output.attributes.add_record(out_atts, false);
                     }
-                    if verbose {
-                        progress = (100.0_f64 * record_num as f64 / num_total_points) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * record_num as f64 / num_total_points) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
 
@@ -417,13 +416,11 @@ impl WhiteboxTool for Union {
                         }
                         output.attributes.add_record(out_atts, false);
                     }
-                    if verbose {
-                        progress = (100.0_f64 * (record_num + input.num_records) as f64
-                            / num_total_points) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * (record_num + input.num_records) as f64
+                        / num_total_points) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -470,13 +467,11 @@ impl WhiteboxTool for Union {
                                 break;
                             }
                         }
-                        if verbose {
-                            progress = (100.0_f64 * (i + num_points_input) as f64
-                                / num_total_points) as usize;
-                            if progress != old_progress {
-                                println!("Progress: {}%", progress);
-                                old_progress = progress;
-                            }
+                        progress = (100.0_f64 * (i + num_points_input) as f64
+                            / num_total_points) as usize;
+                        if progress != old_progress {
+                            context.progress("Progress", progress)?;
+                            old_progress = progress;
                         }
                     }
                 }
@@ -560,7 +555,7 @@ impl WhiteboxTool for Union {
                         .attributes
                         .add_record(vec![FieldData::Int(1i32)], false);
                 } else {
-                    println!("WARNING: no features were ouput from the tool.");
+                    context.warning("WARNING: no features were ouput from the tool.");
                 }
             }
             ShapeType::PolyLine => {
@@ -641,12 +636,10 @@ impl WhiteboxTool for Union {
 
                     num_neighbours.push(line_num_neighbours);
 
-                    if verbose {
-                        progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
 
@@ -674,12 +667,10 @@ impl WhiteboxTool for Union {
                     }
                     features_polylines.push(pl.clone());
 
-                    if verbose {
-                        progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * (i + 1) as f64 / polylines.len() as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
 
@@ -784,16 +775,11 @@ impl WhiteboxTool for Union {
                             }
                         }
                     }
-                    if verbose {
-                        progress =
-                            (100.0_f64 * (i + 1) as f64 / features_polylines.len() as f64) as usize;
-                        if progress != old_progress {
-                            println!(
-                                "Finding duplicate polylines ({}): {}%",
-                                num_duplicates, progress
-                            );
-                            old_progress = progress;
-                        }
+                    progress =
+                        (100.0_f64 * (i + 1) as f64 / features_polylines.len() as f64) as usize;
+                    if progress != old_progress {
+                        context.progress(&format!("Finding duplicate polylines ({})", num_duplicates), progress)?;
+                        old_progress = progress;
                     }
                 }
 
@@ -1987,14 +1973,12 @@ impl WhiteboxTool for Union {
                         output.attributes.add_record(out_atts, false);
                     }
 
-                    if verbose {
-                        progress = (100.0_f64 * (record_num + 1) as f64
-                            / multipolylines.len() as f64)
-                            as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * (record_num + 1) as f64
+                        / multipolylines.len() as f64)
+                        as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -2007,12 +1991,12 @@ impl WhiteboxTool for Union {
         }
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -2021,7 +2005,7 @@ impl WhiteboxTool for Union {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -160,8 +160,9 @@ impl WhiteboxTool for VectorHexBinning {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut width = 0f64;
@@ -234,11 +235,11 @@ impl WhiteboxTool for VectorHexBinning {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if width <= 0f64 {
@@ -347,12 +348,10 @@ impl WhiteboxTool for VectorHexBinning {
                     hex_index = ret[0].0;
                     count[hex_index] += 1;
                 }
-                if verbose {
-                    progress = (100.0_f64 * i as f64 / num_points as f64) as usize;
-                    if progress != old_progress {
-                        println!("Binning points: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * i as f64 / num_points as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Binning points", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -390,12 +389,10 @@ impl WhiteboxTool for VectorHexBinning {
                     rec_num += 1i32;
                 }
 
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
         } else {
@@ -436,12 +433,10 @@ impl WhiteboxTool for VectorHexBinning {
                     hex_index = ret[0].0;
                     count[hex_index] += 1;
                 }
-                if verbose {
-                    progress = (100.0_f64 * i as f64 / num_points as f64) as usize;
-                    if progress != old_progress {
-                        println!("Binning points: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * i as f64 / num_points as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Binning points", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -478,23 +473,21 @@ impl WhiteboxTool for VectorHexBinning {
                     rec_num += 1i32;
                 }
 
-                if verbose {
-                    progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * col as f64 / (columns - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -503,7 +496,7 @@ impl WhiteboxTool for VectorHexBinning {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
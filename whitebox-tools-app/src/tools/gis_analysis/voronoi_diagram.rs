@@ -144,8 +144,9 @@ impl WhiteboxTool for VoronoiDiagram {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
 
@@ -191,11 +192,11 @@ impl WhiteboxTool for VoronoiDiagram {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -213,7 +214,7 @@ impl WhiteboxTool for VoronoiDiagram {
             && input.header.shape_type.base_shape_type() != ShapeType::MultiPoint
         {
             if verbose {
-                println!("Warning: The input file is not of a POINT base shape type. Unexpected results may occur.");
+                context.warning("Warning: The input file is not of a POINT base shape type. Unexpected results may occur.");
             }
             // return Err(Error::new(
             //     ErrorKind::InvalidInput,
@@ -242,13 +243,11 @@ impl WhiteboxTool for VoronoiDiagram {
                 record_numbers.push(record_num);
             }
 
-            if verbose {
-                progress =
-                    (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
-                if progress != old_progress {
-                    println!("Reading points: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
+            if progress != old_progress {
+                context.progress("Reading points", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -293,13 +292,13 @@ impl WhiteboxTool for VoronoiDiagram {
 
         // Do the Delaunay triangulation
         if verbose {
-            println!("Performing triangulation...");
+            context.info("Performing triangulation...");
         }
         // this is where the heavy-lifting is
         let delaunay = triangulate(&points).expect("No triangulation exists.");
 
         if verbose {
-            println!("Creating point-halfedge mapping...");
+            context.info("Creating point-halfedge mapping...");
         }
         let mut point_edge_map = HashMap::new(); // point id to half-edge id
         for edge in 0..delaunay.triangles.len() {
@@ -307,13 +306,11 @@ impl WhiteboxTool for VoronoiDiagram {
             if !point_edge_map.contains_key(&endpoint) || delaunay.halfedges[edge] == EMPTY {
                 point_edge_map.insert(endpoint, edge);
             }
-            if verbose {
-                progress =
-                    (100.0_f64 * edge as f64 / (delaunay.triangles.len() - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * edge as f64 / (delaunay.triangles.len() - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -357,22 +354,20 @@ impl WhiteboxTool for VoronoiDiagram {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * p as f64 / (input.num_records - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Creating Voronoi cells: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * p as f64 / (input.num_records - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Creating Voronoi cells", progress)?;
+                old_progress = progress;
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -381,7 +376,7 @@ impl WhiteboxTool for VoronoiDiagram {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -162,8 +162,9 @@ impl WhiteboxTool for WeightedOverlay {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_files = String::new();
         let mut weights_list = String::new();
         let mut cost_list = String::new();
@@ -236,11 +237,11 @@ impl WhiteboxTool for WeightedOverlay {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -327,6 +328,7 @@ impl WhiteboxTool for WeightedOverlay {
         // We need to initialize output here, but in reality this can't be done
         // until we know the size of rows and columns, which occurs during the first loop.
         let mut output: Raster = Raster::new(&output_file, "w")?;
+        output.widen_to_f64();
         let mut rows = 0isize;
         let mut columns = 0isize;
         let mut in_nodata: f64;
@@ -340,7 +342,7 @@ impl WhiteboxTool for WeightedOverlay {
         for value in vec {
             if !value.trim().is_empty() {
                 if verbose {
-                    println!("Reading data...")
+                    context.info("Reading data...")
                 };
 
                 let mut input_file = value.trim().to_string();
@@ -385,15 +387,10 @@ impl WhiteboxTool for WeightedOverlay {
                             }
                         }
                     }
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!(
-                                "Processing Factors (loop {} of {}): {}%",
-                                i, num_files, progress
-                            );
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress(&format!("Processing Factors (loop {} of {})", i, num_files), progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -414,7 +411,7 @@ impl WhiteboxTool for WeightedOverlay {
         for value in constraint_file_names {
             if !value.trim().is_empty() {
                 if verbose {
-                    println!("Reading data...")
+                    context.info("Reading data...")
                 };
 
                 let mut input_file = value.trim().to_string();
@@ -441,15 +438,10 @@ impl WhiteboxTool for WeightedOverlay {
                             output.set_value(row, col, out_nodata);
                         } // else it stays unaltered
                     }
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!(
-                                "Processing Constraints (loop {} of {}): {}%",
-                                i, num_constraints, progress
-                            );
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress(&format!("Processing Constraints (loop {} of {})", i, num_constraints), progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -465,22 +457,19 @@ impl WhiteboxTool for WeightedOverlay {
         output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -122,8 +122,9 @@ impl WhiteboxTool for AverageFlowpathSlope {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
 
@@ -162,11 +163,11 @@ impl WhiteboxTool for AverageFlowpathSlope {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -182,7 +183,7 @@ impl WhiteboxTool for AverageFlowpathSlope {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -231,13 +232,13 @@ impl WhiteboxTool for AverageFlowpathSlope {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data: Vec<i8> = vec![-1i8; columns as usize];
                     for col in 0..columns {
-                        z = input[(row, col)];
+                        z = input.get_value(row, col);
                         if z != nodata {
                             dir = 0i8;
                             max_slope = f64::MIN;
                             neighbouring_nodata = false;
                             for i in 0..8 {
-                                z_n = input[(row + d_y[i], col + d_x[i])];
+                                z_n = input.get_value(row + d_y[i], col + d_x[i]);
                                 if z_n != nodata {
                                     slope = (z - z_n) / grid_lengths[i];
                                     if slope > max_slope && slope > 0f64 {
@@ -260,7 +261,9 @@ impl WhiteboxTool for AverageFlowpathSlope {
                             data[col as usize] = -1i8;
                         }
                     }
-                    tx.send((row, data, interior_pit_found)).unwrap();
+                    if tx.send((row, data, interior_pit_found)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -272,12 +275,10 @@ impl WhiteboxTool for AverageFlowpathSlope {
             if pit {
                 interior_pit_found = true;
             }
-            if verbose {
-                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Flow directions: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Flow directions", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -299,7 +300,7 @@ impl WhiteboxTool for AverageFlowpathSlope {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data: Vec<i8> = vec![-1i8; columns as usize];
                     for col in 0..columns {
-                        z = input[(row, col)];
+                        z = input.get_value(row, col);
                         if z != nodata {
                             count = 0i8;
                             for i in 0..8 {
@@ -312,7 +313,9 @@ impl WhiteboxTool for AverageFlowpathSlope {
                             data[col as usize] = -1i8;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -331,18 +334,16 @@ impl WhiteboxTool for AverageFlowpathSlope {
                     stack.push((row, col));
                     total_flowpath_length[(row, col)] = 0.0;
                     num_flowpaths[(row, col)] = 1;
-                    total_upslope_divide_elev[(row, col)] = input[(row, col)];
+                    total_upslope_divide_elev[(row, col)] = input.get_value(row, col);
                 } else if num_inflowing[(row, col)] == -1i8 {
                     num_solved_cells += 1;
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Num. inflowing neighbours: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Num. inflowing neighbours", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -393,19 +394,20 @@ impl WhiteboxTool for AverageFlowpathSlope {
             }
 
             z_mean = total_upslope_divide_elev[(row, col)] / num_flowpaths[(row, col)] as f64;
-            z_diff = z_mean - input[(row, col)];
-            output[(row, col)] = (z_diff
-                / (total_flowpath_length[(row, col)] / num_flowpaths[(row, col)] as f64))
-                .atan()
-                .to_degrees();
-
-            if verbose {
-                num_solved_cells += 1;
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Flowpath tracing: {}%", progress);
-                    old_progress = progress;
-                }
+            z_diff = z_mean - input.get_value(row, col);
+            output.set_value(
+                row,
+                col,
+                (z_diff / (total_flowpath_length[(row, col)] / num_flowpaths[(row, col)] as f64))
+                    .atan()
+                    .to_degrees(),
+            );
+
+            num_solved_cells += 1;
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Flowpath tracing", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -419,28 +421,25 @@ impl WhiteboxTool for AverageFlowpathSlope {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
         }
         if interior_pit_found {
-            println!("**********************************************************************************");
-            println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
+            context.info("**********************************************************************************");
+            context.warning("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
             DEM needs to be processed to remove topographic depressions and flats prior to
-            running this tool.");
-            println!("**********************************************************************************");
+            running this tool.".to_string());
+            context.info("**********************************************************************************");
         }
 
         Ok(())
@@ -0,0 +1,436 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: Shared by the watershedding tools (Watershed, Basins, Subbasins, Isobasins,
+StrahlerOrderBasins and UnnestBasins) to write their optional vector polygon outputs.
+Each basin ID is dissolved into a single polygon. The outlet of a basin is its cell with
+the largest D8 contributing area, which is the cell that all of the basin drains through.
+*/
+
+use crate::tools::ToolContext;
+use std::collections::HashMap;
+use std::io::Error;
+use whitebox_common::algorithms::trace_grid_polygons;
+use whitebox_common::structures::{Array2D, Point2D};
+use whitebox_raster::{Raster, RasterConfigs};
+use whitebox_vector::*;
+
+/// The names of the fields that every basin polygon output holds.
+const BASIN_FIELDS: [&str; 7] = [
+    "FID",
+    "BASIN_ID",
+    "AREA",
+    "PERIMETER",
+    "OUTLET_X",
+    "OUTLET_Y",
+    "MEAN_ELEV",
+];
+
+/// A dissolved basin polygon and its attributes. Areas and lengths are in map units.
+pub(super) struct BasinPolygon {
+    pub id: i64,
+    pub geometry: ShapefileGeometry,
+    pub area: f64,
+    pub perimeter: f64,
+    pub outlet_x: f64,
+    pub outlet_y: f64,
+    pub mean_elev: Option<f64>,
+}
+
+/// Counts the cells that drain through each cell of a grid, including the cell itself, i.e.
+/// the D8 contributing area of each cell in cells. `flow_dir` returns the D8 flow direction
+/// of a cell, as an index into the usual dx and dy offsets, or a negative value if it has none.
+pub(super) fn contributing_area<F>(
+    rows: isize,
+    columns: isize,
+    flow_dir: F,
+) -> Result<Array2D<u32>, Error>
+where
+    F: Fn(isize, isize) -> i8,
+{
+    let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+    let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+    let mut num_inflowing: Array2D<i8> = Array2D::new(rows, columns, 0, -1)?;
+    for row in 0..rows {
+        for col in 0..columns {
+            let dir = flow_dir(row, col);
+            if dir >= 0 {
+                num_inflowing.increment(row + dy[dir as usize], col + dx[dir as usize], 1);
+            }
+        }
+    }
+    let mut accum: Array2D<u32> = Array2D::new(rows, columns, 1, 0)?;
+    let mut stack = vec![];
+    for row in 0..rows {
+        for col in 0..columns {
+            if num_inflowing.get_value(row, col) == 0 {
+                stack.push((row, col));
+            }
+        }
+    }
+    while let Some((row, col)) = stack.pop() {
+        let dir = flow_dir(row, col);
+        if dir >= 0 {
+            let row_n = row + dy[dir as usize];
+            let col_n = col + dx[dir as usize];
+            if num_inflowing.get_value(row_n, col_n) > 0 {
+                let a = accum.get_value(row, col);
+                accum.increment(row_n, col_n, a);
+                num_inflowing.decrement(row_n, col_n, 1);
+                if num_inflowing.get_value(row_n, col_n) == 0 {
+                    stack.push((row_n, col_n));
+                }
+            }
+        }
+    }
+    Ok(accum)
+}
+
+/// Traces the polygons of a raster of basin IDs, in order of basin ID. Cells that are NoData
+/// or less than one are background.
+/// `accum` is the contributing area of each cell, from `contributing_area`, which locates the
+/// basin outlets; `dem`, if given, supplies mean elevations.
+pub(super) fn trace_basin_polygons(
+    basins: &Raster,
+    accum: &Array2D<u32>,
+    dem: Option<&Raster>,
+    context: &ToolContext,
+) -> Result<Vec<BasinPolygon>, Error> {
+    let rows = basins.configs.rows as isize;
+    let columns = basins.configs.columns as isize;
+    let nodata = basins.configs.nodata;
+    let basin_id = |row: isize, col: isize| -> i64 {
+        let z = basins.get_value(row, col);
+        if z != nodata && z >= 1f64 {
+            z.round() as i64
+        } else {
+            0
+        }
+    };
+
+    let polygons = trace_grid_polygons(rows, columns, basin_id);
+    context.check_cancelled()?;
+    let index: HashMap<i64, usize> = polygons
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (p.id, i))
+        .collect();
+
+    // Gather the outlets and elevation sums of the basins.
+    let num_basins = polygons.len();
+    let mut outlets = vec![(0isize, 0isize, 0u32); num_basins];
+    let mut elev_sums = vec![0f64; num_basins];
+    let mut elev_counts = vec![0usize; num_basins];
+    let mut cell_counts = vec![0usize; num_basins];
+    for row in 0..rows {
+        for col in 0..columns {
+            let id = basin_id(row, col);
+            if id == 0 {
+                continue;
+            }
+            let i = index[&id];
+            cell_counts[i] += 1;
+            let a = accum.get_value(row, col);
+            if a > outlets[i].2 {
+                outlets[i] = (row, col, a);
+            }
+            if let Some(dem) = dem {
+                let z = dem.get_value(row, col);
+                if z != dem.configs.nodata {
+                    elev_sums[i] += z;
+                    elev_counts[i] += 1;
+                }
+            }
+        }
+    }
+
+    let west = basins.configs.west;
+    let north = basins.configs.north;
+    let res_x = basins.configs.resolution_x;
+    let res_y = basins.configs.resolution_y;
+    let mut basin_polygons = Vec::with_capacity(num_basins);
+    for (i, polygon) in polygons.into_iter().enumerate() {
+        let mut geometry = ShapefileGeometry::new(ShapeType::Polygon);
+        let mut perimeter = 0f64;
+        for ring in &polygon.rings {
+            for v in 1..ring.len() {
+                perimeter += (ring[v].0 - ring[v - 1].0).abs() as f64 * res_y
+                    + (ring[v].1 - ring[v - 1].1).abs() as f64 * res_x;
+            }
+            let points: Vec<Point2D> = ring
+                .iter()
+                .map(|(r, c)| Point2D::new(west + *c as f64 * res_x, north - *r as f64 * res_y))
+                .collect();
+            geometry.add_part(&points);
+        }
+        basin_polygons.push(BasinPolygon {
+            id: polygon.id,
+            geometry,
+            area: cell_counts[i] as f64 * res_x * res_y,
+            perimeter,
+            outlet_x: basins.get_x_from_column(outlets[i].1),
+            outlet_y: basins.get_y_from_row(outlets[i].0),
+            mean_elev: if dem.is_some() && elev_counts[i] > 0 {
+                Some(elev_sums[i] / elev_counts[i] as f64)
+            } else {
+                None
+            },
+        });
+    }
+    basin_polygons.sort_by_key(|p| p.id);
+    Ok(basin_polygons)
+}
+
+/// Writes basin polygons to a shapefile. Each record holds the standard basin fields, with
+/// MEAN_ELEV only when `has_dem` is true, followed by the `extra_fields`, whose values are
+/// returned for each polygon by `extra_values`.
+pub(super) fn write_basin_polygons<F>(
+    file_name: &str,
+    polygons: &[BasinPolygon],
+    configs: &RasterConfigs,
+    has_dem: bool,
+    extra_fields: &[AttributeField],
+    extra_values: F,
+) -> Result<(), Error>
+where
+    F: Fn(&BasinPolygon) -> Vec<FieldData>,
+{
+    let mut output = Shapefile::new(file_name, ShapeType::Polygon)?;
+    output.projection = configs.coordinate_ref_system_wkt.clone();
+    output
+        .attributes
+        .add_field(&AttributeField::new("FID", FieldDataType::Int, 10u8, 0u8));
+    output.attributes.add_field(&AttributeField::new(
+        "BASIN_ID",
+        FieldDataType::Int,
+        10u8,
+        0u8,
+    ));
+    output
+        .attributes
+        .add_field(&AttributeField::new("AREA", FieldDataType::Real, 18u8, 4u8));
+    output.attributes.add_field(&AttributeField::new(
+        "PERIMETER",
+        FieldDataType::Real,
+        18u8,
+        4u8,
+    ));
+    output.attributes.add_field(&AttributeField::new(
+        "OUTLET_X",
+        FieldDataType::Real,
+        18u8,
+        6u8,
+    ));
+    output.attributes.add_field(&AttributeField::new(
+        "OUTLET_Y",
+        FieldDataType::Real,
+        18u8,
+        6u8,
+    ));
+    if has_dem {
+        output.attributes.add_field(&AttributeField::new(
+            "MEAN_ELEV",
+            FieldDataType::Real,
+            12u8,
+            4u8,
+        ));
+    }
+    for field in extra_fields {
+        output.attributes.add_field(field);
+    }
+
+    for (fid, polygon) in polygons.iter().enumerate() {
+        output.add_record(polygon.geometry.clone());
+        let mut record = vec![
+            FieldData::Int(fid as i32 + 1),
+            FieldData::Int(polygon.id as i32),
+            FieldData::Real(polygon.area),
+            FieldData::Real(polygon.perimeter),
+            FieldData::Real(polygon.outlet_x),
+            FieldData::Real(polygon.outlet_y),
+        ];
+        if has_dem {
+            record.push(match polygon.mean_elev {
+                Some(z) => FieldData::Real(z),
+                None => FieldData::Null,
+            });
+        }
+        record.extend(extra_values(polygon));
+        output.attributes.add_record(record, false);
+    }
+    output.write()
+}
+
+/// Returns the attribute fields of a pour-point vector that can be copied to basin polygons,
+/// and their indices in its table. Fields sharing a name with a standard basin field, or with
+/// one of the `reserved` names of a tool's own fields, are skipped.
+pub(super) fn pour_point_fields(
+    pourpts: &Shapefile,
+    reserved: &[&str],
+) -> (Vec<AttributeField>, Vec<usize>) {
+    let mut fields = vec![];
+    let mut indices = vec![];
+    for (i, field) in pourpts.attributes.get_fields().iter().enumerate() {
+        if !BASIN_FIELDS
+            .iter()
+            .chain(reserved.iter())
+            .any(|name| name.eq_ignore_ascii_case(field.name.trim()))
+        {
+            fields.push(field.clone());
+            indices.push(i);
+        }
+    }
+    (fields, indices)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::tools::hydro_analysis::UnnestBasins;
+    use crate::tools::{ToolContext, WhiteboxTool};
+    use std::env;
+    use std::fs;
+    use whitebox_raster::*;
+    use whitebox_vector::*;
+
+    const WEST: f64 = 1000.0;
+    const NORTH: f64 = 2003.0;
+
+    fn write_raster(file_name: &str, value: impl Fn(isize, isize) -> f64) {
+        let configs = RasterConfigs {
+            rows: 3,
+            columns: 8,
+            north: NORTH,
+            south: NORTH - 3.0,
+            east: WEST + 8.0,
+            west: WEST,
+            resolution_x: 1.0,
+            resolution_y: 1.0,
+            nodata: -32768.0,
+            data_type: DataType::F32,
+            ..Default::default()
+        };
+        let mut raster = Raster::initialize_using_config(file_name, &configs);
+        for row in 0..3 {
+            for col in 0..8 {
+                raster.set_value(row, col, value(row, col));
+            }
+        }
+        raster.write().unwrap();
+    }
+
+    fn real(value: FieldData) -> f64 {
+        match value {
+            FieldData::Real(v) => v,
+            FieldData::Int(v) => v as f64,
+            v => panic!("{:?} is not a number", v),
+        }
+    }
+
+    #[test]
+    fn test_nested_basin_polygons() {
+        // The middle row is a stream that flows east to the last column, fed by the rows on
+        // either side of it. Outlets 1 and 4 drain to outlet 2, which drains to outlet 3.
+        write_raster("mem://unnest_pntr", |row, col| match (row, col) {
+            (0, _) => 8.0,
+            (2, _) => 128.0,
+            (_, 7) => 0.0,
+            _ => 2.0,
+        });
+        write_raster("mem://unnest_dem", |_, col| col as f64);
+        let dir = env::temp_dir();
+        let name = |file: &str| {
+            dir.join(format!("unnest_{}_{}", std::process::id(), file))
+                .to_string_lossy()
+                .to_string()
+        };
+        let mut pourpts = Shapefile::new(&name("pourpts.shp"), ShapeType::Point).unwrap();
+        pourpts
+            .attributes
+            .add_field(&AttributeField::new("NAME", FieldDataType::Text, 10u8, 0u8));
+        pourpts
+            .attributes
+            .add_field(&AttributeField::new("AREA", FieldDataType::Real, 10u8, 2u8));
+        for (row, col, outlet_name) in &[
+            (1, 2, "upper"),
+            (1, 5, "middle"),
+            (1, 7, "lower"),
+            (0, 4, "side"),
+        ] {
+            pourpts.add_point_record(WEST + *col as f64 + 0.5, NORTH - *row as f64 - 0.5);
+            pourpts.attributes.add_record(
+                vec![
+                    FieldData::Text(outlet_name.to_string()),
+                    FieldData::Real(-1.0),
+                ],
+                false,
+            );
+        }
+        pourpts.write().unwrap();
+
+        let args = vec![
+            "--d8_pntr=mem://unnest_pntr".to_string(),
+            format!("--pour_pts={}", name("pourpts.shp")),
+            "-o=mem://unnest_basins.tif".to_string(),
+            "--dem=mem://unnest_dem".to_string(),
+            format!("--out_polygons={}", name("basins.shp")),
+        ];
+        UnnestBasins::new()
+            .run(args, "", &ToolContext::new(false))
+            .unwrap();
+        let polygons = Shapefile::read(&name("basins.shp")).unwrap();
+
+        // (basin ID, nesting order, downstream outlet, upstream outlets, area, outlet column,
+        // outlet row, mean elevation, name)
+        let expected = [
+            (1, 1, 2, 0, 9.0, 2, 1, 1.0, "upper"),
+            (2, 2, 3, 2, 18.0, 5, 1, 2.5, "middle"),
+            (3, 3, 0, 1, 24.0, 7, 1, 3.5, "lower"),
+            (4, 1, 2, 0, 1.0, 4, 0, 4.0, "side"),
+        ];
+        assert_eq!(polygons.num_records, expected.len());
+        let attributes = &polygons.attributes;
+        for (i, e) in expected.iter().enumerate() {
+            assert_eq!(real(attributes.get_value(i, "BASIN_ID")), e.0 as f64);
+            assert_eq!(real(attributes.get_value(i, "NEST_ORDER")), e.1 as f64);
+            assert_eq!(real(attributes.get_value(i, "DOWNSTREAM")), e.2 as f64);
+            assert_eq!(real(attributes.get_value(i, "NUM_UPSTRM")), e.3 as f64);
+            assert_eq!(real(attributes.get_value(i, "AREA")), e.4);
+            assert_eq!(
+                real(attributes.get_value(i, "OUTLET_X")),
+                WEST + e.5 as f64 + 0.5
+            );
+            assert_eq!(
+                real(attributes.get_value(i, "OUTLET_Y")),
+                NORTH - e.6 as f64 - 0.5
+            );
+            assert_eq!(real(attributes.get_value(i, "MEAN_ELEV")), e.7);
+            match attributes.get_value(i, "NAME") {
+                FieldData::Text(s) => assert_eq!(s.trim(), e.8),
+                v => panic!("unexpected NAME {:?}", v),
+            }
+        }
+        // a pour-point field named after a basin field is not copied
+        let num_area_fields = attributes
+            .get_fields()
+            .iter()
+            .filter(|f| f.name.trim() == "AREA")
+            .count();
+        assert_eq!(num_area_fields, 1);
+
+        for file_name in &["mem://unnest_pntr", "mem://unnest_dem"] {
+            MEMORY_RASTERS.remove(file_name);
+        }
+        for order in 1..=3 {
+            MEMORY_RASTERS.remove(&format!("mem://unnest_basins_{}.tif", order));
+        }
+        for file in &["pourpts", "basins"] {
+            for ext in &["shp", "shx", "dbf", "prj"] {
+                let _ = fs::remove_file(name(&format!("{}.{}", file, ext)));
+            }
+        }
+    }
+}
@@ -2,10 +2,11 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 01/07/2017
-Last Modified: 18/10/2019
+Last Modified: 17/10/2026
 License: MIT
 */
 
+use super::basin_polygons::{contributing_area, trace_basin_polygons, write_basin_polygons};
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
 use crate::tools::*;
@@ -36,6 +37,10 @@ use std::path;
 /// fill the containing raster, if it is irregular shaped, and NoData regions may occupy the peripherals. Thus, the entire
 /// region of valid data in the flow pointer raster will be divided into a set of mutually exclusive basins using this tool.
 ///
+/// The basins may optionally also be output as a vector of polygons (`--out_polygons`), with one polygon
+/// for each basin. The attribute table contains the basin ID, area, perimeter, and outlet coordinates,
+/// along with the mean elevation of each basin when a DEM (`--dem`) is specified.
+///
 /// # See Also
 /// `Watershed`, `D8Pointer`, `BreachDepressions`
 pub struct Basins {
@@ -81,6 +86,26 @@ impl Basins {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["--dem".to_owned()],
+            description: "Optional input DEM, used to calculate the mean elevation of the output polygons.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Polygons File".to_owned(),
+            flags: vec!["--out_polygons".to_owned()],
+            description: "Optional output vector polygons file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -140,11 +165,14 @@ impl WhiteboxTool for Basins {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut d8_file = String::new();
         let mut output_file = String::new();
         let mut esri_style = false;
+        let mut dem_file = String::new();
+        let mut polygons_file = String::new();
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -178,6 +206,18 @@ impl WhiteboxTool for Basins {
                 if vec.len() == 1 || !vec[1].to_string().to_lowercase().contains("false") {
                     esri_style = true;
                 }
+            } else if flag_val == "-dem" {
+                dem_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-out_polygons" {
+                polygons_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
             }
         }
 
@@ -185,11 +225,11 @@ impl WhiteboxTool for Basins {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -203,12 +243,30 @@ impl WhiteboxTool for Basins {
         if !output_file.contains(&sep) && !output_file.contains("/") {
             output_file = format!("{}{}", working_directory, output_file);
         }
+        if !dem_file.is_empty() && !dem_file.contains(&sep) && !dem_file.contains("/") {
+            dem_file = format!("{}{}", working_directory, dem_file);
+        }
+        if !polygons_file.is_empty() && !polygons_file.contains(&sep) && !polygons_file.contains("/") {
+            polygons_file = format!("{}{}", working_directory, polygons_file);
+        }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let pntr = Raster::new(&d8_file, "r")?;
+        let dem = if !polygons_file.is_empty() && !dem_file.is_empty() {
+            let dem = Raster::new(&dem_file, "r")?;
+            if dem.configs.rows != pntr.configs.rows || dem.configs.columns != pntr.configs.columns {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input files must have the same number of rows and columns and spatial extent.",
+                ));
+            }
+            Some(dem)
+        } else {
+            None
+        };
 
         let start = Instant::now();
 
@@ -221,6 +279,7 @@ impl WhiteboxTool for Basins {
 
         let mut flow_dir: Array2D<i8> = Array2D::new(rows, columns, -2, -2)?;
         let mut output = Raster::initialize_using_file(&output_file, &pntr);
+        output.widen_to_f64();
         output.configs.data_type = DataType::F32;
         output.configs.palette = "qual.plt".to_string();
         output.configs.photometric_interp = PhotometricInterpretation::Categorical;
@@ -260,25 +319,23 @@ impl WhiteboxTool for Basins {
         let mut z: f64;
         for row in 0..rows {
             for col in 0..columns {
-                z = pntr[(row, col)];
+                z = pntr.get_value(row, col);
                 if z != nodata {
                     if z > 0.0 {
                         flow_dir[(row, col)] = pntr_matches[z as usize];
                     } else {
                         flow_dir[(row, col)] = -1i8;
                         basin_id += 1f64;
-                        output[(row, col)] = basin_id;
+                        output.set_value(row, col, basin_id);
                     }
                 } else {
-                    output[(row, col)] = nodata;
+                    output.set_value(row, col, nodata);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Initializing: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Initializing", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -288,7 +345,7 @@ impl WhiteboxTool for Basins {
         let mut outlet_id: f64;
         for row in 0..rows {
             for col in 0..columns {
-                if output[(row, col)] == low_value {
+                if output.get_value(row, col) == low_value {
                     // && flow_dir[(row, col)] != -2i8 {
                     flag = false;
                     x = col;
@@ -303,7 +360,7 @@ impl WhiteboxTool for Basins {
                             y += dy[dir as usize];
 
                             // if the new cell already has a value in the output, use that as the outletID
-                            z = output[(y, x)];
+                            z = output.get_value(y, x);
                             if z != low_value {
                                 outlet_id = z;
                                 flag = true;
@@ -316,7 +373,7 @@ impl WhiteboxTool for Basins {
                     flag = false;
                     x = col;
                     y = row;
-                    output[(y, x)] = outlet_id;
+                    output.set_value(y, x, outlet_id);
                     while !flag {
                         // find its downslope neighbour
                         dir = flow_dir[(y, x)];
@@ -326,22 +383,20 @@ impl WhiteboxTool for Basins {
                             y += dy[dir as usize];
 
                             // if the new cell already has a value in the output, use that as the outletID
-                            if output[(y, x)] != low_value {
+                            if output.get_value(y, x) != low_value {
                                 flag = true;
                             }
                         } else {
                             flag = true;
                         }
-                        output[(y, x)] = outlet_id;
+                        output.set_value(y, x, outlet_id);
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -354,21 +409,41 @@ impl WhiteboxTool for Basins {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
+        if !polygons_file.is_empty() {
+            if verbose {
+                context.info("Creating basin polygons...")
+            };
+            let accum = contributing_area(rows, columns, |row, col| flow_dir.get_value(row, col))?;
+            let polygons = trace_basin_polygons(
+                &output,
+                &accum,
+                dem.as_ref(),
+                context,
+            )?;
+            write_basin_polygons(
+                &polygons_file,
+                &polygons,
+                &output.configs,
+                dem.is_some(),
+                &[],
+                |_| vec![],
+            )?;
+            if verbose {
+                context.info("Polygons file written")
+            }
+        }
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
         }
 
         Ok(())
@@ -2,12 +2,13 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 28/06/2017
-Last Modified: 24/11/2019
+Last Modified: 17/10/2026
 License: MIT
 */
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use super::tiled_depressions::breach_depressions;
 use crate::tools::*;
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
@@ -49,6 +50,12 @@ use std::path;
 /// well suited to breaching through road embankments. Nonetheless, there are applications for which full depression filling
 /// using the  `FillDepressions` tool may be preferred.
 ///
+/// Depressions are breached in parallel tiles on as many threads as the `max_procs` setting allows, and the output
+/// is the same whatever the number of threads. Cells of equal elevation are flooded in row-major order. When either
+/// `--max_depth` or `--max_length` is specified, the tool runs on a single thread instead, because whether a breach
+/// channel is within those limits depends on the channels that were carved before it. The whole DEM is read into
+/// memory in either case.
+///
 /// # Reference
 /// Lindsay JB. 2016. *Efficient hybrid breaching-filling sink removal methods for
 /// flow path enforcement in digital elevation models.* **Hydrological Processes**,
@@ -186,8 +193,9 @@ impl WhiteboxTool for BreachDepressions {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut max_depth = f64::INFINITY;
@@ -273,11 +281,11 @@ impl WhiteboxTool for BreachDepressions {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -293,14 +301,15 @@ impl WhiteboxTool for BreachDepressions {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         if verbose && constrained_mode {
-            println!("Breaching in constrained mode...");
+            context.info("Breaching in constrained mode...");
         }
 
         let mut input = Raster::new(&input_file, "r")?;
+        input.widen_to_f64();
 
         let start = Instant::now();
         let rows = input.configs.rows as isize;
@@ -349,183 +358,117 @@ impl WhiteboxTool for BreachDepressions {
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Filling pits: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Filling pits", progress)?;
+                    old_progress = progress;
                 }
             }
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.widen_to_f64();
         output.configs.data_type = DataType::F64;
         let background_val = (i32::min_value() + 1) as f64;
         output.reinitialize_values(background_val);
 
-        let mut flow_dir: Array2D<i8> = Array2D::new(rows, columns, -1, -1)?;
-
-        /*
-        Find the data edges. This is complicated by the fact that DEMs frequently
-        have nodata edges, whereby the DEM does not occupy the full extent of
-        the raster. One approach to doing this would be simply to scan the
-        raster, looking for cells that neighbour nodata values. However, this
-        assumes that there are no interior nodata holes in the dataset. Instead,
-        the approach used here is to perform a region-growing operation, looking
-        for nodata values along the raster's edges.
-        */
-
-        let mut queue: VecDeque<(isize, isize)> =
-            VecDeque::with_capacity((rows * columns) as usize);
-        for row in 0..rows {
+        if !constrained_mode {
+            let mut num_procs = num_cpus::get() as isize;
+            let max_procs = whitebox_common::configs::get_configs()?.max_procs;
+            if max_procs > 0 && max_procs < num_procs {
+                num_procs = max_procs;
+            }
+            breach_depressions(
+                &mut input,
+                &mut output,
+                nodata,
+                background_val,
+                small_num,
+                num_procs,
+                context,
+            )?;
+        } else {
+            let mut flow_dir: Array2D<i8> = Array2D::new(rows, columns, -1, -1)?;
+
             /*
-            Note that this is only possible because Whitebox rasters
-            allow you to address cells beyond the raster extent but
-            return the nodata value for these regions.
+            Find the data edges. This is complicated by the fact that DEMs frequently
+            have nodata edges, whereby the DEM does not occupy the full extent of
+            the raster. One approach to doing this would be simply to scan the
+            raster, looking for cells that neighbour nodata values. However, this
+            assumes that there are no interior nodata holes in the dataset. Instead,
+            the approach used here is to perform a region-growing operation, looking
+            for nodata values along the raster's edges.
             */
-            queue.push_back((row, -1));
-            queue.push_back((row, columns));
-        }
 
-        for col in 0..columns {
-            queue.push_back((-1, col));
-            queue.push_back((rows, col));
-        }
-
-        /*
-        minheap is the priority queue. Note that I've tested using integer-based
-        priority values, by multiplying the elevations, but this didn't result
-        in a significant performance gain over the use of f64s.
-        */
-        let mut minheap = BinaryHeap::with_capacity((rows * columns) as usize);
-        let mut num_solved_cells = 0;
-        let mut zin_n: f64; // value of neighbour of row, col in input raster
-        let mut zout: f64; // value of row, col in output raster
-        let mut zout_n: f64; // value of neighbour of row, col in output raster
-        let (mut row, mut col): (isize, isize);
-        let (mut row_n, mut col_n): (isize, isize);
-        while !queue.is_empty() {
-            let cell = queue.pop_front().unwrap();
-            row = cell.0;
-            col = cell.1;
-            for n in 0..8 {
-                row_n = row + dy[n];
-                col_n = col + dx[n];
-                zin_n = input.get_value(row_n, col_n);
-                zout_n = output.get_value(row_n, col_n);
-                if zout_n == background_val {
-                    if zin_n == nodata {
-                        output.set_value(row_n, col_n, nodata);
-                        queue.push_back((row_n, col_n));
-                    } else {
-                        output.set_value(row_n, col_n, zin_n);
-                        // Push it onto the priority queue for the priority flood operation
-                        minheap.push(GridCell {
-                            row: row_n,
-                            column: col_n,
-                            priority: zin_n,
-                        });
-                    }
-                    num_solved_cells += 1;
-                }
+            let mut queue: VecDeque<(isize, isize)> =
+                VecDeque::with_capacity((rows * columns) as usize);
+            for row in 0..rows {
+                /*
+                Note that this is only possible because Whitebox rasters
+                allow you to address cells beyond the raster extent but
+                return the nodata value for these regions.
+                */
+                queue.push_back((row, -1));
+                queue.push_back((row, columns));
             }
 
-            if verbose {
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            for col in 0..columns {
+                queue.push_back((-1, col));
+                queue.push_back((rows, col));
             }
-        }
 
-        // Perform the priority flood operation.
-        let back_link = [4i8, 5i8, 6i8, 7i8, 0i8, 1i8, 2i8, 3i8];
-        let (mut x, mut y): (isize, isize);
-        let mut z_target: f64;
-        let mut dir: i8;
-        let mut flag: bool;
-
-        if !constrained_mode {
-            while !minheap.is_empty() {
-                let cell = minheap.pop().expect("Error during pop operation.");
-                row = cell.row;
-                col = cell.column;
-                zout = output.get_value(row, col);
+            /*
+            minheap is the priority queue. Note that I've tested using integer-based
+            priority values, by multiplying the elevations, but this didn't result
+            in a significant performance gain over the use of f64s.
+            */
+            let mut minheap = BinaryHeap::with_capacity((rows * columns) as usize);
+            let mut num_solved_cells = 0;
+            let mut zin_n: f64; // value of neighbour of row, col in input raster
+            let mut zout: f64; // value of row, col in output raster
+            let mut zout_n: f64; // value of neighbour of row, col in output raster
+            let (mut row, mut col): (isize, isize);
+            let (mut row_n, mut col_n): (isize, isize);
+            while !queue.is_empty() {
+                let cell = queue.pop_front().unwrap();
+                row = cell.0;
+                col = cell.1;
                 for n in 0..8 {
                     row_n = row + dy[n];
                     col_n = col + dx[n];
+                    zin_n = input.get_value(row_n, col_n);
                     zout_n = output.get_value(row_n, col_n);
                     if zout_n == background_val {
-                        zin_n = input.get_value(row_n, col_n);
-                        if zin_n != nodata {
-                            flow_dir.set_value(row_n, col_n, back_link[n]);
+                        if zin_n == nodata {
+                            output.set_value(row_n, col_n, nodata);
+                            queue.push_back((row_n, col_n));
+                        } else {
                             output.set_value(row_n, col_n, zin_n);
+                            // Push it onto the priority queue for the priority flood operation
                             minheap.push(GridCell {
                                 row: row_n,
                                 column: col_n,
                                 priority: zin_n,
                             });
-                            if zin_n < (zout + small_num) {
-                                // Trace the flowpath back to a lower cell, if it exists.
-                                x = col_n;
-                                y = row_n;
-                                z_target = output.get_value(row_n, col_n);
-                                flag = true;
-                                while flag {
-                                    dir = flow_dir[(y, x)];
-                                    if dir >= 0 {
-                                        y += dy[dir as usize];
-                                        x += dx[dir as usize];
-                                        z_target -= small_num;
-                                        if output.get_value(y, x) > z_target {
-                                            output.set_value(y, x, z_target);
-                                        } else {
-                                            flag = false;
-                                        }
-                                    } else {
-                                        flag = false;
-                                    }
-                                }
-                            }
-                        } else {
-                            // Interior nodata cells are still treated as nodata and are not filled.
-                            output.set_value(row_n, col_n, nodata);
-                            num_solved_cells += 1;
-                            // region growing operation to find all attached nodata cells
-                            queue.push_back((row_n, col_n));
-                            while !queue.is_empty() {
-                                let cell = queue.pop_front().unwrap();
-                                for n2 in 0..8 {
-                                    let row2 = cell.0 + dy[n2];
-                                    let col2 = cell.1 + dx[n2];
-                                    if input.get_value(row2, col2) == nodata
-                                        && output.get_value(row2, col2) == background_val
-                                    {
-                                        if row2 >= 0 && row2 < rows && col2 >= 0 && col2 < columns {
-                                            output.set_value(row2, col2, nodata);
-                                            num_solved_cells += 1;
-                                            queue.push_back((row2, col2));
-                                        }
-                                    }
-                                }
-                            }
                         }
+                        num_solved_cells += 1;
                     }
                 }
 
-                if verbose {
-                    num_solved_cells += 1;
-                    progress =
-                        (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
-        } else {
+
+            // Perform the priority flood operation.
+            let back_link = [4i8, 5i8, 6i8, 7i8, 0i8, 1i8, 2i8, 3i8];
+            let (mut x, mut y): (isize, isize);
+            let mut z_target: f64;
+            let mut dir: i8;
+            let mut flag: bool;
+
             // constrained mode
             let mut channel_depth: f64;
             let mut channel_length: f64;
@@ -724,14 +667,12 @@ impl WhiteboxTool for BreachDepressions {
                     }
                 }
 
-                if verbose {
-                    num_solved_cells += 1;
-                    progress =
-                        (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                num_solved_cells += 1;
+                progress =
+                    (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -759,14 +700,12 @@ impl WhiteboxTool for BreachDepressions {
                             }
                         }
                     }
-                    if verbose {
-                        num_solved_cells += 1;
-                        progress =
-                            (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Filling DEM: {}%", progress);
-                            old_progress = progress;
-                        }
+                    num_solved_cells += 1;
+                    progress =
+                        (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Filling DEM", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -789,21 +728,18 @@ impl WhiteboxTool for BreachDepressions {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -838,3 +774,36 @@ impl Ord for GridCell {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::super::tiled_depressions::test::{raster_digest, write_synthetic_dem};
+    use super::BreachDepressions;
+    use crate::tools::{temp_file, ToolContext, WhiteboxTool};
+
+    // The outputs must be identical to those that the tool wrote, from the same DEM, before
+    // its progress was reported through the ToolContext.
+    #[test]
+    fn test_output_matches_previous_release() {
+        let input_file = temp_file("breach_depressions", "dem.tif");
+        write_synthetic_dem(&input_file);
+        let tool = BreachDepressions::new();
+        let context = ToolContext::new(false);
+        let cases = [
+            (vec![], 0xa511_3cb1_2ede_2a77u64),
+            (
+                vec!["--max_depth=1.0", "--max_length=20", "--fill_pits"],
+                0x1e28_ee9f_7ec4_0be6,
+            ),
+        ];
+        for (i, (flags, digest)) in cases.iter().enumerate() {
+            let output_file = temp_file("breach_depressions", &format!("output{}.tif", i));
+            let mut args = vec![format!("--dem={}", input_file), format!("-o={}", output_file)];
+            args.extend(flags.iter().map(|f| f.to_string()));
+            tool.run(args, "", &context).unwrap();
+            assert_eq!(raster_digest(&output_file), *digest, "case {}", i);
+            let _ = std::fs::remove_file(output_file);
+        }
+        let _ = std::fs::remove_file(input_file);
+    }
+}
@@ -2,24 +2,21 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 01/11/2019
-Last Modified: 24/11/2019
+Last Modified: 17/10/2026
 License: MIT
 */
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use super::tiled_depressions::{
+    find_pits, fix_flats, grid_maximum, map_bands, solve_depressions, sort_pits, BreachTask,
+    CachedFlags, FillTask, Grid, Grids, Pit, PitOrder,
+};
 use crate::tools::*;
-use std::cmp::Ordering;
-use std::cmp::Ordering::Equal;
-use std::collections::{BinaryHeap, VecDeque};
 use std::env;
 use std::f64;
-use std::i32;
 use std::io::{Error, ErrorKind};
 use std::path;
-use std::sync::mpsc;
-use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to perform a type of optimal depression breaching to prepare a
 /// digital elevation model (DEM) for hydrological analysis. Depression breaching is a common
@@ -85,6 +82,12 @@ use std::thread;
 /// provide an adequate solution in these cases. Nonetheless, there are applications for which full depression filling
 /// using the  `FillDepressions` tool may be preferred.
 ///
+/// Pits are breached, and filled, in parallel on as many threads as the `max_procs` setting allows. Each pit is
+/// solved speculatively and the solutions are committed in the order in which the pits would be solved serially,
+/// so that the output is the same whatever the number of threads; the DEM isn't split into tiles that are merged
+/// afterwards. DEMs that are too large to be read into memory, given the
+/// `max_memory_mb` setting, are processed from disk, with the output held in a scratch file until it is written.
+///
 /// # Reference
 /// Lindsay J, Dhun K. 2015. Modelling surface drainage patterns in altered landscapes using LiDAR.
 /// *International Journal of Geographical Information Science*, 29: 1-15. DOI: 10.1080/13658816.2014.975715
@@ -192,13 +195,101 @@ impl BreachDepressionsLeastCost {
         .replace("*", &sep);
 
         BreachDepressionsLeastCost {
-            name: name,
-            description: description,
-            toolbox: toolbox,
-            parameters: parameters,
+            name,
+            description,
+            toolbox,
+            parameters,
             example_usage: usage,
         }
     }
+
+    // Breaches the depressions of a DEM that is too large to be read into memory. The
+    // output, along with the flags used while filling, is held in scratch files.
+    #[allow(clippy::too_many_arguments)]
+    fn run_cached(
+        &self,
+        mut input: CachedRaster,
+        input_file: &str,
+        output_file: &str,
+        max_dist: isize,
+        max_cost: f64,
+        flat_increment: f64,
+        fill_deps: bool,
+        minimize_dist: bool,
+        context: &ToolContext,
+    ) -> Result<(), Error> {
+        let verbose = context.verbose;
+        let start = Instant::now();
+        let nodata = input.configs.nodata;
+        let resx = input.configs.resolution_x;
+        let resy = input.configs.resolution_y;
+        let diagres = (resx * resx + resy * resy).sqrt();
+
+        if flat_increment.is_nan() && input.raster_type == RasterType::GeoTiff {
+            // The largest elevation isn't recorded in the header of a GeoTIFF.
+            let mut num_procs = num_cpus::get() as isize;
+            let max_procs = whitebox_common::configs::get_configs()?.max_procs;
+            if max_procs > 0 && max_procs < num_procs {
+                num_procs = max_procs;
+            }
+            input.configs.maximum = grid_maximum(&mut input, nodata, num_procs, context)?;
+        }
+        let small_num = flats_increment(flat_increment, input.configs.maximum, diagres);
+
+        let mut output = CachedRaster::initialize_using_file(output_file, &input)?;
+        output.configs.data_type = DataType::F32;
+        let display_min = input.configs.display_min;
+        let display_max = input.configs.display_max;
+
+        let task = BreachTask {
+            nodata,
+            small_num,
+            max_dist,
+            max_cost,
+            minimize_dist,
+            cost_dist: [diagres, resx, diagres, resy, diagres, resx, diagres, resy],
+        };
+        let cache_size = input.get_cache_size();
+        let grids = Grids {
+            elevations: output,
+            visited: CachedFlags::new(
+                &format!("{}.visited.tif", output_file),
+                &input.configs,
+                cache_size,
+            )?,
+            flats: CachedFlags::new(
+                &format!("{}.flats.tif", output_file),
+                &input.configs,
+                cache_size,
+            )?,
+        };
+        let mut output = breach_depressions(&mut input, grids, &task, fill_deps, context)?;
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.configs.display_min = display_min;
+        output.configs.display_max = display_max;
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Maximum search distance: {}", max_dist));
+        output.add_metadata_entry(format!("Maximum breach cost: {}", max_cost));
+        output.add_metadata_entry(format!("Flat elevation increment: {}", small_num));
+        output.add_metadata_entry(format!("Remaining depressions filled: {}", fill_deps));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            context.info("Saving data...")
+        };
+        output.write()?;
+        if verbose {
+            context.info("Output file written");
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        Ok(())
+    }
 }
 
 impl WhiteboxTool for BreachDepressionsLeastCost {
@@ -233,8 +324,9 @@ impl WhiteboxTool for BreachDepressionsLeastCost {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut max_cost = f64::INFINITY;
@@ -316,18 +408,15 @@ impl WhiteboxTool for BreachDepressionsLeastCost {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
 
-        let mut progress: usize;
-        let mut old_progress: usize = 1;
-
         if !input_file.contains(&sep) && !input_file.contains("/") {
             input_file = format!("{}{}", working_directory, input_file);
         }
@@ -335,577 +424,57 @@ impl WhiteboxTool for BreachDepressionsLeastCost {
             output_file = format!("{}{}", working_directory, output_file);
         }
 
+        if let Some(input) = CachedRaster::open_if_exceeds_memory(&input_file, 2)? {
+            return self.run_cached(
+                input,
+                &input_file,
+                &output_file,
+                max_dist,
+                max_cost,
+                flat_increment,
+                fill_deps,
+                minimize_dist,
+                context,
+            );
+        }
+
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
-        let input = Arc::new(Raster::new(&input_file, "r").expect("Error reading input raster"));
+        let mut input = Raster::new(&input_file, "r")?;
 
         let start = Instant::now();
 
         let rows = input.configs.rows as isize;
         let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
-        let (mut col, mut row): (isize, isize);
-        let (mut rn, mut cn): (isize, isize);
-        let mut accum: f64;
-        let (mut z, mut zn, mut zout): (f64, f64, f64);
-        let dx = [1, 1, 1, 0, -1, -1, -1, 0];
-        let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
-        let mut flag: bool;
-        let mut num_solved: usize;
-        // let mut overall_num_solved = 0;
-        let mut num_unsolved = 0;
         let resx = input.configs.resolution_x;
         let resy = input.configs.resolution_y;
         let diagres = (resx * resx + resy * resy).sqrt();
-        let cost_dist = [diagres, resx, diagres, resy, diagres, resx, diagres, resy];
-        let mut cost1: f64;
-        let mut cost2: f64;
-        let mut new_cost: f64;
-        let mut length: i16;
-        let mut length_n: i16;
-        let mut b: usize;
-        let mut num_procs = num_cpus::get() as isize;
-        let configs = whitebox_common::configs::get_configs()?;
-        let max_procs = configs.max_procs;
-        if max_procs > 0 && max_procs < num_procs {
-            num_procs = max_procs;
-        }
-
-        let small_num = if !flat_increment.is_nan() || flat_increment == 0f64 {
-            flat_increment
-        } else {
-            let elev_digits = (input.configs.maximum as i32).to_string().len();
-            let elev_multiplier = 10.0_f64.powi((9 - elev_digits) as i32);
-            1.0_f64 / elev_multiplier as f64 * diagres.ceil()
-        };
+        let small_num = flats_increment(flat_increment, input.configs.maximum, diagres);
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.widen_to_f64();
         // Even if the input is f32, the output will need to be 64-bit to represent the small elevation differences
         output.configs.data_type = DataType::F32;
         let display_min = input.configs.display_min;
         let display_max = input.configs.display_max;
 
-        // Raise pit cells to minimize the depth of breach channels.
-        let (tx, rx) = mpsc::channel();
-        for tid in 0..num_procs {
-            let input = input.clone();
-            let tx = tx.clone();
-            thread::spawn(move || {
-                let (mut z, mut zn, mut min_zn): (f64, f64, f64);
-                let mut flag: bool;
-                let dx = [1, 1, 1, 0, -1, -1, -1, 0];
-                let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
-                for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    let mut data = input.get_row_data(row);
-                    let mut pits = vec![];
-                    for col in 0..columns {
-                        z = input.get_value(row, col);
-                        if z != nodata {
-                            flag = true;
-                            min_zn = f64::INFINITY;
-                            for n in 0..8 {
-                                zn = input.get_value(row + dy[n], col + dx[n]);
-                                if zn < min_zn {
-                                    min_zn = zn;
-                                }
-                                if zn == nodata {
-                                    // It's an edge cell.
-                                    flag = false;
-                                    break;
-                                }
-                                if zn < z {
-                                    // There's a lower neighbour
-                                    flag = false;
-                                    break;
-                                }
-                            }
-                            if flag {
-                                data[col as usize] = min_zn - small_num;
-                                pits.push((row, col, z));
-                            }
-                        }
-                    }
-                    tx.send((row, data, pits)).unwrap();
-                }
-            });
-        }
-
-        let mut undefined_flow_cells: Vec<(isize, isize, f64)> = vec![];
-        let mut undefined_flow_cells2 = vec![];
-        for r in 0..rows {
-            let (row, data, mut pits) = rx.recv().expect("Error receiving data from thread.");
-            output.set_row_data(row, data);
-            undefined_flow_cells.append(&mut pits);
-
-            if verbose {
-                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Finding pits: {}%", progress);
-                    old_progress = progress;
-                }
-            }
-        }
-
-        ////////////////////////////////////////////////////////////////////////////////////////////
-        // We need to visit and (potentially) solve each undefined-flow cell in order from lowest //
-        // to highest. This is because some higher pits can be solved, or partially solved using  //
-        // the breach paths of lower pits.                                                        //
-        ////////////////////////////////////////////////////////////////////////////////////////////
-
-        /* Vec is a stack and so if we want to pop the values from lowest to highest, we need to sort
-        them from highest to lowest. */
-        undefined_flow_cells.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(Equal));
-        let num_deps = undefined_flow_cells.len();
-        if num_deps == 0 && verbose {
-            println!("No depressions found. Process ending...");
-        }
-
-        num_solved = 0;
-        let backlink_dir = [4i8, 5, 6, 7, 0, 1, 2, 3];
-        let mut backlink: Array2D<i8> = Array2D::new(rows, columns, -1, -2)?;
-        let mut encountered: Array2D<i8> = Array2D::new(rows, columns, 0, -1)?;
-        let mut path_length: Array2D<i16> = Array2D::new(rows, columns, 0, -1)?;
-        let mut scanned_cells = vec![];
-        let max_length = max_dist as i16;
-        let filter_size = ((max_dist * 2 + 1) * (max_dist * 2 + 1)) as usize;
-        let mut minheap = BinaryHeap::with_capacity(filter_size);
-        while let Some(cell) = undefined_flow_cells.pop() {
-            row = cell.0;
-            col = cell.1;
-            z = output.get_value(row, col);
-
-            // Is it still a pit cell? It may have been solved during a previous depression solution.
-            flag = true;
-            for n in 0..8 {
-                zn = output.get_value(row + dy[n], col + dx[n]);
-                if zn < z && zn != nodata {
-                    // It has a lower non-nodata cell
-                    // Resolving some other pit cell resulted in a solution for this one.
-                    num_solved += 1;
-                    flag = false;
-                    break;
-                }
-            }
-            if flag {
-                // Perform the cost-accumulation operation.
-                encountered.set_value(row, col, 1i8);
-                if !minheap.is_empty() {
-                    minheap.clear();
-                }
-                minheap.push(GridCell {
-                    row: row,
-                    column: col,
-                    priority: 0f64,
-                });
-                scanned_cells.push((row, col));
-                flag = true;
-                while !minheap.is_empty() && flag {
-                    let cell2 = minheap.pop().expect("Error during pop operation.");
-                    accum = cell2.priority;
-                    if accum > max_cost {
-                        // There isn't a breach channel cheap enough
-                        undefined_flow_cells2.push((row, col, z)); // Add it to the list for the filling step
-                        num_unsolved += 1;
-                        flag = false;
-                        break;
-                    }
-                    length = path_length.get_value(cell2.row, cell2.column);
-                    zn = output.get_value(cell2.row, cell2.column);
-                    cost1 = zn - z + length as f64 * small_num;
-                    for n in 0..8 {
-                        cn = cell2.column + dx[n];
-                        rn = cell2.row + dy[n];
-                        if encountered.get_value(rn, cn) != 1i8 {
-                            scanned_cells.push((rn, cn));
-                            // not yet encountered
-                            length_n = length + 1;
-                            path_length.set_value(rn, cn, length_n);
-                            backlink.set_value(rn, cn, backlink_dir[n]);
-                            zn = output.get_value(rn, cn);
-                            zout = z - (length_n as f64 * small_num);
-                            if zn > zout && zn != nodata {
-                                cost2 = zn - zout;
-                                new_cost = if minimize_dist {
-                                    accum + (cost1 + cost2) / 2f64 * cost_dist[n]
-                                } else {
-                                    accum + cost2
-                                };
-                                encountered.set_value(rn, cn, 1i8);
-                                if length_n <= max_length {
-                                    minheap.push(GridCell {
-                                        row: rn,
-                                        column: cn,
-                                        priority: new_cost,
-                                    });
-                                }
-                            } else if zn <= zout || zn == nodata {
-                                // We're at a cell that we can breach to
-                                while flag {
-                                    // Find which cell to go to from here
-                                    if backlink.get_value(rn, cn) > -1i8 {
-                                        b = backlink.get_value(rn, cn) as usize;
-                                        rn += dy[b];
-                                        cn += dx[b];
-                                        zn = output.get_value(rn, cn);
-                                        length = path_length.get_value(rn, cn);
-                                        zout = z - (length as f64 * small_num);
-                                        if zn > zout {
-                                            output.set_value(rn, cn, zout);
-                                        }
-                                    } else {
-                                        flag = false;
-                                    }
-                                }
-                                num_solved += 1;
-                                flag = false;
-                                break; // don't check any more neighbours.
-                            }
-                        }
-                    }
-                }
-
-                // clear the intermediate rasters
-                while let Some(cell2) = scanned_cells.pop() {
-                    backlink.set_value(cell2.0, cell2.1, -1i8);
-                    encountered.set_value(cell2.0, cell2.1, 0i8);
-                    path_length.set_value(cell2.0, cell2.1, 0i16);
-                }
-
-                if flag {
-                    // Didn't find any lower cells.
-                    undefined_flow_cells2.push((row, col, z)); // Add it to the list for the next iteration
-                    num_unsolved += 1;
-                }
-            }
-
-            if verbose {
-                progress = (100.0_f64
-                    * (1f64 - (undefined_flow_cells.len()) as f64 / (num_deps - 1) as f64))
-                    as usize;
-                if progress != old_progress {
-                    println!("Breaching: {}%", progress);
-                    old_progress = progress;
-                }
-            }
-        }
-        if verbose {
-            println!("Num. solved pits: {}", num_solved);
-            println!("Num. unsolved pits: {}", num_unsolved);
-        }
-
-        // Solve any remaining pits by filling
-        if fill_deps && num_unsolved > 0 {
-            if verbose {
-                println!("Filling remaining depressions...");
-            }
-            // Find pit cells. This step is parallelized.
-            let output2 = Arc::new(output);
-            let (tx, rx) = mpsc::channel();
-            for tid in 0..num_procs {
-                let output2 = output2.clone();
-                let tx = tx.clone();
-                thread::spawn(move || {
-                    let mut z: f64;
-                    let mut zn: f64;
-                    let mut flag: bool;
-                    let mut pits = vec![];
-                    for row in (1..rows - 1).filter(|r| r % num_procs == tid) {
-                        for col in 1..columns - 1 {
-                            z = output2.get_value(row, col);
-                            if z != nodata {
-                                flag = true;
-                                for n in 0..8 {
-                                    zn = output2.get_value(row + dy[n], col + dx[n]);
-                                    if zn < z || zn == nodata {
-                                        // It either has a lower neighbour or is an edge cell.
-                                        flag = false;
-                                        break;
-                                    }
-                                }
-                                if flag {
-                                    // it's a cell with undefined flow
-                                    pits.push((row, col, z));
-                                }
-                            }
-                        }
-                    }
-                    tx.send(pits).unwrap();
-                });
-            }
-
-            let mut undefined_flow_cells = vec![];
-            for p in 0..num_procs {
-                let mut pits = rx.recv().expect("Error receiving data from thread.");
-                undefined_flow_cells.append(&mut pits);
-
-                if verbose {
-                    progress = (100.0_f64 * (p + 1) as f64 / num_procs as f64) as usize;
-                    if progress != old_progress {
-                        println!("Finding pit cells: {}%", progress);
-                        old_progress = progress;
-                    }
-                }
-            }
-
-            output = match Arc::try_unwrap(output2) {
-                Ok(val) => val,
-                Err(_) => panic!("Error unwrapping 'output'"),
-            };
-
-            let num_deps = undefined_flow_cells.len();
-
-            // Now we need to perform an in-place depression filling
-            let mut minheap = BinaryHeap::new();
-            let mut minheap2 = BinaryHeap::new();
-            let mut visited: Array2D<i8> = Array2D::new(rows, columns, 0, -1)?;
-            let mut flats: Array2D<i8> = Array2D::new(rows, columns, 0, -1)?;
-            let mut possible_outlets = vec![];
-            // solve from highest to lowest
-            undefined_flow_cells.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(Equal));
-            let mut pit_id = 1;
-            let mut flag: bool;
-            while let Some(cell) = undefined_flow_cells.pop() {
-                row = cell.0;
-                col = cell.1;
-                // if it's already in a solved site, don't do it a second time.
-                if flats.get_value(row, col) != 1 {
-                    // First there is a priority region-growing operation to find the outlets.
-                    z = output.get_value(row, col);
-                    minheap.clear();
-                    minheap.push(GridCell {
-                        row: row,
-                        column: col,
-                        priority: z,
-                    });
-                    visited.set_value(row, col, 1);
-                    let mut outlet_found = false;
-                    let mut outlet_z = f64::INFINITY;
-                    let mut queue = VecDeque::new();
-                    while let Some(cell2) = minheap.pop() {
-                        z = cell2.priority;
-                        if outlet_found && z > outlet_z {
-                            break;
-                        }
-                        if !outlet_found {
-                            for n in 0..8 {
-                                cn = cell2.column + dx[n];
-                                rn = cell2.row + dy[n];
-                                if visited.get_value(rn, cn) == 0 {
-                                    zn = output.get_value(rn, cn);
-                                    if !outlet_found {
-                                        if zn >= z && zn != nodata {
-                                            minheap.push(GridCell {
-                                                row: rn,
-                                                column: cn,
-                                                priority: zn,
-                                            });
-                                            visited.set_value(rn, cn, 1);
-                                        } else if zn != nodata {
-                                            // zn < z
-                                            // 'cell' has a lower neighbour that hasn't already passed through minheap.
-                                            // Therefore, 'cell' is a pour point cell.
-                                            outlet_found = true;
-                                            outlet_z = z;
-                                            queue.push_back((cell2.row, cell2.column));
-                                            possible_outlets.push((cell2.row, cell2.column));
-                                        }
-                                    } else if zn == outlet_z {
-                                        // We've found the outlet but are still looking for additional outlets.
-                                        minheap.push(GridCell {
-                                            row: rn,
-                                            column: cn,
-                                            priority: zn,
-                                        });
-                                        visited.set_value(rn, cn, 1);
-                                    }
-                                }
-                            }
-                        } else {
-                            if z == outlet_z {
-                                flag = false;
-                                for n in 0..8 {
-                                    cn = cell2.column + dx[n];
-                                    rn = cell2.row + dy[n];
-                                    if visited.get_value(rn, cn) == 0 {
-                                        zn = output.get_value(rn, cn);
-                                        if zn < z {
-                                            flag = true;
-                                        } else if zn == outlet_z {
-                                            minheap.push(GridCell {
-                                                row: rn,
-                                                column: cn,
-                                                priority: zn,
-                                            });
-                                            visited.set_value(rn, cn, 1);
-                                        }
-                                    }
-                                }
-                                if flag {
-                                    // it's an outlet
-                                    queue.push_back((cell2.row, cell2.column));
-                                    possible_outlets.push((cell2.row, cell2.column));
-                                } else {
-                                    visited.set_value(cell2.row, cell2.column, 1);
-                                }
-                            }
-                        }
-                    }
-
-                    // Now that we have the outlets, raise the interior of the depression
-                    if outlet_found {
-                        while let Some(cell2) = queue.pop_front() {
-                            for n in 0..8 {
-                                rn = cell2.0 + dy[n];
-                                cn = cell2.1 + dx[n];
-                                if visited.get_value(rn, cn) == 1 {
-                                    visited.set_value(rn, cn, 0);
-                                    queue.push_back((rn, cn));
-                                    z = output.get_value(rn, cn);
-                                    if z < outlet_z {
-                                        output.set_value(rn, cn, outlet_z);
-                                        flats.set_value(rn, cn, 1);
-                                    } else if z == outlet_z {
-                                        flats.set_value(rn, cn, 1);
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-
-                if verbose {
-                    progress = (100.0_f64 * pit_id as f64 / num_deps as f64) as usize;
-                    if progress != old_progress {
-                        println!("Filling depressions: {}%", progress);
-                        old_progress = progress;
-                    }
-                }
-                pit_id += 1;
-            }
-
-            drop(visited);
-
-            if small_num > 0f64 {
-                // fix the flats
-                if verbose {
-                    println!("Fixing flow on flats...");
-                    println!("Flats increment value: {}", small_num);
-                }
-                // Some of the potential outlets really will have lower cells.
-                // let mut queue = VecDeque::new();
-                minheap.clear();
-                while let Some(cell) = possible_outlets.pop() {
-                    z = output.get_value(cell.0, cell.1);
-                    flag = false;
-                    for n in 0..8 {
-                        rn = cell.0 + dy[n];
-                        cn = cell.1 + dx[n];
-                        zn = output.get_value(rn, cn);
-                        if zn < z && zn != nodata {
-                            flag = true;
-                            break;
-                        }
-                    }
-                    if flag {
-                        // it's confirmed as an outlet
-                        minheap.push(GridCell {
-                            row: cell.0,
-                            column: cell.1,
-                            priority: z,
-                        });
-                    }
-                }
-
-                let num_outlets = minheap.len();
-
-                while let Some(cell) = minheap.pop() {
-                    if flats.get_value(cell.row, cell.column) != 3 {
-                        z = output.get_value(cell.row, cell.column);
-                        flats.set_value(cell.row, cell.column, 3);
-                        let mut outlets = vec![];
-                        outlets.push(cell);
-                        // Are there any other outlet cells at the same elevation (likely for the same feature)
-                        flag = true;
-                        while flag {
-                            match minheap.peek() {
-                                Some(cell2) => {
-                                    if cell2.priority == z {
-                                        flats.set_value(cell2.row, cell2.column, 3);
-                                        outlets.push(
-                                            minheap.pop().expect("Error during pop operation."),
-                                        );
-                                    } else {
-                                        flag = false;
-                                    }
-                                }
-                                None => {
-                                    flag = false;
-                                }
-                            }
-                        }
-                        // let mut queue = VecDeque::new();
-                        // let mut minheap2 = BinaryHeap::new();
-                        if !minheap2.is_empty() {
-                            minheap2.clear();
-                        }
-                        for cell2 in &outlets {
-                            z = output.get_value(cell2.row, cell2.column);
-                            for n in 0..8 {
-                                rn = cell2.row + dy[n];
-                                cn = cell2.column + dx[n];
-                                if flats.get_value(rn, cn) != 3 {
-                                    zn = output.get_value(rn, cn);
-                                    if zn == z && zn != nodata {
-                                        // queue.push_back((rn, cn, z));
-                                        minheap2.push(GridCell2 {
-                                            row: rn,
-                                            column: cn,
-                                            z: z,
-                                            priority: input.get_value(rn, cn),
-                                        });
-                                        output.set_value(rn, cn, z + small_num);
-                                        flats.set_value(rn, cn, 3);
-                                    }
-                                }
-                            }
-                        }
-                        // Now fix the flats
-                        while let Some(cell2) = minheap2.pop() {
-                            z = output.get_value(cell2.row, cell2.column);
-                            for n in 0..8 {
-                                rn = cell2.row + dy[n];
-                                cn = cell2.column + dx[n];
-                                if flats.get_value(rn, cn) != 3 {
-                                    zn = output.get_value(rn, cn);
-                                    if zn < z + small_num && zn >= cell2.z && zn != nodata {
-                                        // queue.push_back((rn, cn, cell2.2));
-                                        minheap2.push(GridCell2 {
-                                            row: rn,
-                                            column: cn,
-                                            z: cell2.z,
-                                            priority: input.get_value(rn, cn),
-                                        });
-                                        output.set_value(rn, cn, z + small_num);
-                                        flats.set_value(rn, cn, 3);
-                                    }
-                                }
-                            }
-                        }
-                    }
-
-                    if verbose {
-                        progress = (100.0_f64 * (1f64 - minheap.len() as f64 / num_outlets as f64))
-                            as usize;
-                        if progress != old_progress {
-                            println!("Fixing flats: {}%", progress);
-                            old_progress = progress;
-                        }
-                    }
-                }
-            }
-        }
+        let task = BreachTask {
+            nodata,
+            small_num,
+            max_dist,
+            max_cost,
+            minimize_dist,
+            cost_dist: [diagres, resx, diagres, resy, diagres, resx, diagres, resy],
+        };
+        let grids = Grids {
+            elevations: output,
+            visited: Array2D::new(rows, columns, 0, -1)?,
+            flats: Array2D::new(rows, columns, 0, -1)?,
+        };
+        let mut output = breach_depressions(&mut input, grids, &task, fill_deps, context)?;
 
         let elapsed_time = get_formatted_elapsed_time(start);
         output.configs.display_min = display_min;
@@ -922,66 +491,254 @@ impl WhiteboxTool for BreachDepressionsLeastCost {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
     }
 }
 
-#[derive(PartialEq, Debug)]
-struct GridCell {
-    row: isize,
-    column: isize,
-    priority: f64,
+// The elevation increment used along breach channels and to fix flats. Unless it is
+// specified, it is the smallest increment that can be represented at the largest elevation
+// of the DEM.
+fn flats_increment(flat_increment: f64, maximum: f64, diagres: f64) -> f64 {
+    if !flat_increment.is_nan() || flat_increment == 0f64 {
+        flat_increment
+    } else {
+        let elev_digits = (maximum as i32).to_string().len();
+        let elev_multiplier = 10.0_f64.powi((9 - elev_digits) as i32);
+        1.0_f64 / elev_multiplier * diagres.ceil()
+    }
 }
 
-impl Eq for GridCell {}
-
-impl PartialOrd for GridCell {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        other.priority.partial_cmp(&self.priority)
+// Breaches the depressions of `input`, writing the breached DEM to the elevations of
+// `grids`, and then optionally fills those depressions that couldn't be breached, returning
+// the breached DEM.
+fn breach_depressions<I, E, F>(
+    input: &mut I,
+    mut grids: Grids<E, F>,
+    task: &BreachTask,
+    fill_deps: bool,
+    context: &ToolContext,
+) -> Result<E, Error>
+where
+    I: Grid<f64>,
+    E: Grid<f64>,
+    F: Grid<i8>,
+{
+    let verbose = context.verbose;
+    let nodata = task.nodata;
+    let small_num = task.small_num;
+    let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+    let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+    let mut num_procs = num_cpus::get() as isize;
+    let configs = whitebox_common::configs::get_configs()?;
+    let max_procs = configs.max_procs;
+    if max_procs > 0 && max_procs < num_procs {
+        num_procs = max_procs;
     }
-}
 
-impl Ord for GridCell {
-    fn cmp(&self, other: &GridCell) -> Ordering {
-        self.partial_cmp(other).unwrap()
+    // Raise pit cells to minimize the depth of breach channels.
+    let bands = map_bands(input, num_procs, context, "Finding pits", |band| {
+        let (mut z, mut zn, mut min_zn): (f64, f64, f64);
+        let mut flag: bool;
+        let mut data = Vec::with_capacity((band.rows * band.columns) as usize);
+        let mut pits = vec![];
+        for row in band.row..band.row + band.rows {
+            for col in 0..band.columns {
+                z = band.get_value(row, col);
+                data.push(z);
+                if z != nodata {
+                    flag = true;
+                    min_zn = f64::INFINITY;
+                    for n in 0..8 {
+                        zn = band.get_value(row + dy[n], col + dx[n]);
+                        if zn < min_zn {
+                            min_zn = zn;
+                        }
+                        if zn == nodata {
+                            // It's an edge cell.
+                            flag = false;
+                            break;
+                        }
+                        if zn < z {
+                            // There's a lower neighbour
+                            flag = false;
+                            break;
+                        }
+                    }
+                    if flag {
+                        *data.last_mut().unwrap() = min_zn - small_num;
+                        pits.push(Pit {
+                            row,
+                            column: col,
+                            z,
+                        });
+                    }
+                }
+            }
+        }
+        (band.row, band.columns, data, pits)
+    })?;
+    let mut undefined_flow_cells = vec![];
+    for (row, columns, data, mut pits) in bands {
+        for (i, value) in data.into_iter().enumerate() {
+            let i = i as isize;
+            grids.elevations.set(row + i / columns, i % columns, value)?;
+        }
+        undefined_flow_cells.append(&mut pits);
     }
-}
 
-#[derive(PartialEq, Debug)]
-struct GridCell2 {
-    row: isize,
-    column: isize,
-    z: f64,
-    priority: f64,
-}
+    ////////////////////////////////////////////////////////////////////////////////////////////
+    // We need to visit and (potentially) solve each undefined-flow cell in order from lowest //
+    // to highest. This is because some higher pits can be solved, or partially solved using  //
+    // the breach paths of lower pits.                                                        //
+    ////////////////////////////////////////////////////////////////////////////////////////////
+    sort_pits(&mut undefined_flow_cells, PitOrder::LowestFirst);
+    if undefined_flow_cells.is_empty() && verbose {
+        context.info("No depressions found. Process ending...");
+    }
+    let solved = solve_depressions(
+        &mut grids,
+        &undefined_flow_cells,
+        task,
+        nodata,
+        num_procs,
+        context,
+        "Breaching",
+    )?;
+    let num_solved = solved.iter().filter(|s| **s).count();
+    let num_unsolved = solved.len() - num_solved;
+    if verbose {
+        context.info(format!("Num. solved pits: {}", num_solved));
+        context.info(format!("Num. unsolved pits: {}", num_unsolved));
+    }
 
-impl Eq for GridCell2 {}
+    if !fill_deps || num_unsolved == 0 {
+        return Ok(grids.elevations);
+    }
 
-impl PartialOrd for GridCell2 {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        other.priority.partial_cmp(&self.priority)
+    // Solve any remaining pits by filling
+    if verbose {
+        context.info("Filling remaining depressions...");
+    }
+    let mut undefined_flow_cells = find_pits(&mut grids.elevations, nodata, num_procs, context)?;
+    // solve from highest to lowest
+    sort_pits(&mut undefined_flow_cells, PitOrder::HighestFirst);
+    let task = FillTask {
+        nodata,
+        max_depth: f64::INFINITY,
+        clear_unfilled: false,
+    };
+    let possible_outlets: Vec<(isize, isize)> = solve_depressions(
+        &mut grids,
+        &undefined_flow_cells,
+        &task,
+        nodata,
+        num_procs,
+        context,
+        "Filling depressions",
+    )?
+    .into_iter()
+    .flatten()
+    .collect();
+
+    let Grids {
+        elevations: mut output,
+        visited,
+        mut flats,
+    } = grids;
+    drop(visited);
+
+    if small_num > 0f64 {
+        // fix the flats
+        if verbose {
+            context.info("Fixing flow on flats...");
+            context.info(format!("Flats increment value: {}", small_num));
+        }
+        fix_flats(
+            &mut output,
+            input,
+            &mut flats,
+            possible_outlets,
+            small_num,
+            nodata,
+            context,
+        )?;
     }
+    Ok(output)
 }
 
-impl Ord for GridCell2 {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+#[cfg(test)]
+mod test {
+    use super::super::tiled_depressions::test::{raster_digest, write_synthetic_dem};
+    use super::BreachDepressionsLeastCost;
+    use crate::tools::{temp_file, ToolContext, WhiteboxTool};
+    use std::f64;
+    use whitebox_raster::*;
+
+    // The outputs must be identical to those that the tool wrote, from the same DEM, before
+    // its depressions were solved in parallel (see tiled_depressions.rs), both when the DEM is
+    // held in memory and when it is read from disk.
+    #[test]
+    fn test_output_matches_previous_release() {
+        let input_file = temp_file("breach_least_cost", "dem.tif");
+        write_synthetic_dem(&input_file);
+        let tool = BreachDepressionsLeastCost::new();
+        let context = ToolContext::new(false);
+        let cases = [
+            (30isize, f64::INFINITY, false, false, 0x5cfd_d732_9d93_5d0fu64),
+            (10, 2.0, true, true, 0xeb31_e185_de3b_b05c),
+        ];
+        for (i, &(max_dist, max_cost, fill_deps, minimize_dist, digest)) in cases.iter().enumerate()
+        {
+            let output_file = temp_file("breach_least_cost", &format!("output{}.tif", i));
+            let mut args = vec![
+                format!("--dem={}", input_file),
+                format!("-o={}", output_file),
+                format!("--dist={}", max_dist),
+            ];
+            if max_cost.is_finite() {
+                args.push(format!("--max_cost={}", max_cost));
+            }
+            if fill_deps {
+                args.push("--fill".to_string());
+            }
+            if minimize_dist {
+                args.push("--min_dist".to_string());
+            }
+            tool.run(args, "", &context).unwrap();
+            assert_eq!(raster_digest(&output_file), digest, "in memory, case {}", i);
+
+            let mut input = CachedRaster::new(&input_file, "r").unwrap();
+            // small enough that the cache is cycled while breaching
+            input.set_cache_size(256 * 1024);
+            tool.run_cached(
+                input,
+                &input_file,
+                &output_file,
+                max_dist,
+                max_cost,
+                f64::NAN,
+                fill_deps,
+                minimize_dist,
+                &context,
+            )
+            .unwrap();
+            assert_eq!(raster_digest(&output_file), digest, "cached, case {}", i);
+            let _ = std::fs::remove_file(output_file);
+        }
+        let _ = std::fs::remove_file(input_file);
     }
 }
@@ -116,8 +116,9 @@ impl WhiteboxTool for BreachSingleCellPits {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
 
@@ -158,11 +159,11 @@ impl WhiteboxTool for BreachSingleCellPits {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -178,7 +179,7 @@ impl WhiteboxTool for BreachSingleCellPits {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -203,11 +204,11 @@ impl WhiteboxTool for BreachSingleCellPits {
         let mut flag: bool;
         for row in 0..rows {
             for col in 0..columns {
-                z = input[(row, col)];
+                z = input.get_value(row, col);
                 if z != nodata {
                     flag = true;
                     for i in 0..8 {
-                        zn = input[(row + dy[i], col + dx[i])];
+                        zn = input.get_value(row + dy[i], col + dx[i]);
                         if zn < z && zn != nodata {
                             flag = false;
                             break;
@@ -216,21 +217,22 @@ impl WhiteboxTool for BreachSingleCellPits {
                     if flag {
                         // it's a pit cell
                         for i in 0..16 {
-                            zn = input[(row + dy2[i], col + dx2[i])];
+                            zn = input.get_value(row + dy2[i], col + dx2[i]);
                             if zn < z && zn != nodata {
-                                output[(row + dy[breachcell[i]], col + dx[breachcell[i]])] =
-                                    (z + zn) / 2f64;
+                                output.set_value(
+                                    row + dy[breachcell[i]],
+                                    col + dx[breachcell[i]],
+                                    (z + zn) / 2f64,
+                                );
                             }
                         }
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -244,21 +246,18 @@ impl WhiteboxTool for BreachSingleCellPits {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -160,8 +160,9 @@ impl WhiteboxTool for BurnStreamsAtRoads {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut streams_file = String::new();
         let mut roads_file = String::new();
         let mut dem_file = String::new();
@@ -227,11 +228,11 @@ impl WhiteboxTool for BurnStreamsAtRoads {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -250,13 +251,13 @@ impl WhiteboxTool for BurnStreamsAtRoads {
         }
 
         if verbose {
-            println!("Reading streams and roads data...")
+            context.info("Reading streams and roads data...")
         };
         let streams = Shapefile::read(&streams_file)?;
         let roads = Shapefile::read(&roads_file)?;
 
         if verbose {
-            println!("Reading DEM raster...")
+            context.info("Reading DEM raster...")
         };
         let dem = Raster::new(&dem_file, "r")?;
         let rows = dem.configs.rows as isize;
@@ -284,6 +285,7 @@ impl WhiteboxTool for BurnStreamsAtRoads {
 
         // create the output raster file
         let mut output = Raster::initialize_using_file(&output_file, &dem);
+        output.widen_to_f64();
         output.set_data_from_raster(&dem)?;
         drop(dem);
 
@@ -401,12 +403,10 @@ impl WhiteboxTool for BurnStreamsAtRoads {
             }
 
             count += 1f64;
-            if verbose {
-                progress = (100.0_f64 * count / (streams.num_records - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Rasterizing Streams: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * count / (streams.num_records - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Rasterizing Streams", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -592,12 +592,10 @@ impl WhiteboxTool for BurnStreamsAtRoads {
             }
 
             count += 1f64;
-            if verbose {
-                progress = (100.0_f64 * count / (roads.num_records - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Finding road intersections: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * count / (roads.num_records - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Finding road intersections", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -690,21 +688,18 @@ impl WhiteboxTool for BurnStreamsAtRoads {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
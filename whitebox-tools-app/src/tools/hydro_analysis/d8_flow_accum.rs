@@ -185,8 +185,9 @@ impl WhiteboxTool for D8FlowAccumulation {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut out_type = String::from("sca");
@@ -260,11 +261,11 @@ impl WhiteboxTool for D8FlowAccumulation {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -280,7 +281,7 @@ impl WhiteboxTool for D8FlowAccumulation {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -337,7 +338,7 @@ impl WhiteboxTool for D8FlowAccumulation {
                                 max_slope = f64::MIN;
                                 neighbouring_nodata = false;
                                 for i in 0..8 {
-                                    z_n = input[(row + dy[i], col + dx[i])];
+                                    z_n = input.get_value(row + dy[i], col + dx[i]);
                                     if z_n != nodata {
                                         slope = (z - z_n) / grid_lengths[i];
                                         if slope > max_slope && slope > 0f64 {
@@ -358,7 +359,9 @@ impl WhiteboxTool for D8FlowAccumulation {
                                 }
                             }
                         }
-                        tx.send((row, data, interior_pit_found)).unwrap();
+                        if tx.send((row, data, interior_pit_found)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -369,12 +372,10 @@ impl WhiteboxTool for D8FlowAccumulation {
                 if pit {
                     interior_pit_found = true;
                 }
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Flow directions: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Flow directions", progress)?;
+                    old_progress = progress;
                 }
             }
         } else {
@@ -419,10 +420,15 @@ impl WhiteboxTool for D8FlowAccumulation {
                         pntr_matches[64] = 7i8;
                         pntr_matches[128] = 0i8;
                     }
+                    // Pointers are usually stored as 16-bit integers and can be read directly.
+                    let pntr_values = input.get_data().as_i16();
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
                         let mut data: Vec<i8> = vec![-1i8; columns as usize];
                         for col in 0..columns {
-                            z = input.get_value(row, col);
+                            z = match pntr_values {
+                                Some(values) => values[(row * columns + col) as usize] as f64,
+                                None => input.get_value(row, col),
+                            };
                             if z != nodata {
                                 if z > 0f64 {
                                     data[col as usize] = pntr_matches[z as usize];
@@ -441,7 +447,9 @@ impl WhiteboxTool for D8FlowAccumulation {
                                 }
                             }
                         }
-                        tx.send((row, data, interior_pit_found)).unwrap();
+                        if tx.send((row, data, interior_pit_found)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -452,17 +460,16 @@ impl WhiteboxTool for D8FlowAccumulation {
                 if pit {
                     interior_pit_found = true;
                 }
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Flow directions: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Flow directions", progress)?;
+                    old_progress = progress;
                 }
             }
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.widen_to_f64();
         output.configs.photometric_interp = PhotometricInterpretation::Continuous; // if the input is a pointer, this may not be the case by default.
         output.reinitialize_values(1.0);
         drop(input);
@@ -500,7 +507,9 @@ impl WhiteboxTool for D8FlowAccumulation {
                             data[col as usize] = -1i8;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -518,12 +527,10 @@ impl WhiteboxTool for D8FlowAccumulation {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Num. inflowing neighbours: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Num. inflowing neighbours", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -537,7 +544,7 @@ impl WhiteboxTool for D8FlowAccumulation {
             let cell = stack.pop().expect("Error during pop operation.");
             row = cell.0;
             col = cell.1;
-            fa = output[(row, col)];
+            fa = output.get_value(row, col);
             num_inflowing.decrement(row, col, 1i8);
             dir = flow_dir[(row, col)];
             if dir >= 0 {
@@ -550,13 +557,11 @@ impl WhiteboxTool for D8FlowAccumulation {
                 }
             }
 
-            if verbose {
-                num_solved_cells += 1;
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Flow accumulation: {}%", progress);
-                    old_progress = progress;
-                }
+            num_solved_cells += 1;
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Flow accumulation", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -599,25 +604,31 @@ impl WhiteboxTool for D8FlowAccumulation {
                 for col in 0..columns {
                     // if input[(row, col)] == nodata {
                     if flow_dir.get_value(row, col) == -2 {
-                        output[(row, col)] = nodata;
+                        output.set_value(row, col, nodata);
                     } else {
                         let dir = flow_dir[(row, col)];
                         if dir >= 0 {
-                            output[(row, col)] =
-                                (output[(row, col)] * cell_area / flow_widths[dir as usize]).ln();
+                            output.set_value(
+                                row,
+                                col,
+                                (output.get_value(row, col) * cell_area
+                                    / flow_widths[dir as usize])
+                                    .ln(),
+                            );
                         } else {
-                            output[(row, col)] =
-                                (output[(row, col)] * cell_area / flow_widths[3]).ln();
+                            output.set_value(
+                                row,
+                                col,
+                                (output.get_value(row, col) * cell_area / flow_widths[3]).ln(),
+                            );
                         }
                     }
                 }
 
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Correcting values: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Correcting values", progress)?;
+                    old_progress = progress;
                 }
             }
         } else {
@@ -625,7 +636,7 @@ impl WhiteboxTool for D8FlowAccumulation {
                 for col in 0..columns {
                     // if input[(row, col)] == nodata {
                     if flow_dir.get_value(row, col) == -2 {
-                        output[(row, col)] = nodata;
+                        output.set_value(row, col, nodata);
                     } else {
                         let dir = flow_dir.get_value(row, col);
                         if dir >= 0 {
@@ -644,12 +655,10 @@ impl WhiteboxTool for D8FlowAccumulation {
                     }
                 }
 
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Correcting values: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Correcting values", progress)?;
+                    old_progress = progress;
                 }
             }
         }
@@ -667,28 +676,25 @@ impl WhiteboxTool for D8FlowAccumulation {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
         if interior_pit_found {
-            println!("**********************************************************************************");
-            println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
+            context.info("**********************************************************************************");
+            context.warning("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
             DEM needs to be processed to remove topographic depressions and flats prior to
-            running this tool.");
-            println!("**********************************************************************************");
+            running this tool.".to_string());
+            context.info("**********************************************************************************");
         }
 
         Ok(())
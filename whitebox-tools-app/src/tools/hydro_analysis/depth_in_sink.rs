@@ -136,8 +136,9 @@ impl WhiteboxTool for DepthInSink {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut zero_background = false;
@@ -180,11 +181,11 @@ impl WhiteboxTool for DepthInSink {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -200,7 +201,7 @@ impl WhiteboxTool for DepthInSink {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -255,7 +256,7 @@ impl WhiteboxTool for DepthInSink {
                         }
                     }
                 }
-                tx.send(pits).unwrap();
+                let _ = tx.send(pits);
             });
         }
 
@@ -264,12 +265,10 @@ impl WhiteboxTool for DepthInSink {
             let mut pits = rx.recv().expect("Error receiving data from thread.");
             undefined_flow_cells.append(&mut pits);
 
-            if verbose {
-                progress = (100.0_f64 * (p + 1) as f64 / num_procs as f64) as usize;
-                if progress != old_progress {
-                    println!("Finding pit cells: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (p + 1) as f64 / num_procs as f64) as usize;
+            if progress != old_progress {
+                context.progress("Finding pit cells", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -400,12 +399,10 @@ impl WhiteboxTool for DepthInSink {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * pit_id as f64 / num_deps as f64) as usize;
-                if progress != old_progress {
-                    println!("Finding depressions: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * pit_id as f64 / num_deps as f64) as usize;
+            if progress != old_progress {
+                context.progress("Finding depressions", progress)?;
+                old_progress = progress;
             }
             pit_id += 1;
         }
@@ -442,13 +439,11 @@ impl WhiteboxTool for DepthInSink {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * (1.0 - possible_outlets.len() as f64 / num_outlets as f64))
-                    as usize;
-                if progress != old_progress {
-                    println!("Estimating depths: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (1.0 - possible_outlets.len() as f64 / num_outlets as f64))
+                as usize;
+            if progress != old_progress {
+                context.progress("Estimating depths", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -461,21 +456,18 @@ impl WhiteboxTool for DepthInSink {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -140,8 +140,9 @@ impl WhiteboxTool for DownslopeDistanceToStream {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut dem_file = String::new();
         let mut streams_file = String::new();
         let mut output_file = String::new();
@@ -192,11 +193,11 @@ impl WhiteboxTool for DownslopeDistanceToStream {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -215,11 +216,11 @@ impl WhiteboxTool for DownslopeDistanceToStream {
         }
 
         if verbose {
-            println!("Reading DEM data...")
+            context.info("Reading DEM data...")
         };
         let dem = Arc::new(Raster::new(&dem_file, "r")?);
         if verbose {
-            println!("Reading streams data...")
+            context.info("Reading streams data...")
         };
         let streams = Raster::new(&streams_file, "r")?;
 
@@ -309,7 +310,9 @@ impl WhiteboxTool for DownslopeDistanceToStream {
                                 }
                             }
                         }
-                        tx.send((row, data, interior_pit_found)).unwrap();
+                        if tx.send((row, data, interior_pit_found)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -317,6 +320,7 @@ impl WhiteboxTool for DownslopeDistanceToStream {
             let mut flow_dir: Array2D<i8> = Array2D::new(rows, columns, flow_nodata, flow_nodata)?;
             let mut interior_pit_found = false;
             let mut output = Raster::initialize_using_file(&output_file, &dem);
+            output.widen_to_f64();
             let background_value = f64::MIN;
             output.reinitialize_values(background_value);
             let mut stack = Vec::with_capacity((rows * columns) as usize);
@@ -347,12 +351,10 @@ impl WhiteboxTool for DownslopeDistanceToStream {
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Flow directions: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Flow directions", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -395,14 +397,12 @@ impl WhiteboxTool for DownslopeDistanceToStream {
                         }
                     }
                 }
-                if verbose {
-                    num_solved_cells += 1;
-                    progress =
-                        (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                num_solved_cells += 1;
+                progress =
+                    (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -416,29 +416,26 @@ impl WhiteboxTool for DownslopeDistanceToStream {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                context.info("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
 
             if interior_pit_found {
-                println!("**********************************************************************************");
-                println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
+                context.info("**********************************************************************************");
+                context.warning("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
                 DEM needs to be processed to remove topographic depressions and flats prior to
-                running this tool.");
-                println!("**********************************************************************************");
+                running this tool.".to_string());
+                context.info("**********************************************************************************");
             }
         } else {
             // calculate the D-inf flow directions
@@ -489,7 +486,7 @@ impl WhiteboxTool for DownslopeDistanceToStream {
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
                         let mut data: Vec<f64> = vec![nodata; columns as usize];
                         for col in 0..columns {
-                            e0 = input[(row, col)];
+                            e0 = input.get_value(row, col);
                             if e0 != nodata {
                                 dir = 360.0;
                                 max_slope = f64::MIN;
@@ -497,8 +494,8 @@ impl WhiteboxTool for DownslopeDistanceToStream {
                                 for i in 0..8 {
                                     ac = ac_vals[i];
                                     af = af_vals[i];
-                                    e1 = input[(row + e1_row[i], col + e1_col[i])];
-                                    e2 = input[(row + e2_row[i], col + e2_col[i])];
+                                    e1 = input.get_value(row + e1_row[i], col + e1_col[i]);
+                                    e2 = input.get_value(row + e2_row[i], col + e2_col[i]);
                                     if e1 != nodata && e2 != nodata {
                                         if e0 > e1 && e0 > e2 {
                                             s1 = (e0 - e1) / grid_res;
@@ -565,7 +562,9 @@ impl WhiteboxTool for DownslopeDistanceToStream {
                                 data[col as usize] = -1f64;
                             }
                         }
-                        tx.send((row, data, interior_pit_found)).unwrap();
+                        if tx.send((row, data, interior_pit_found)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -576,16 +575,15 @@ impl WhiteboxTool for DownslopeDistanceToStream {
                 if pit {
                     interior_pit_found = true;
                 }
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Flow directions: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Flow directions", progress)?;
+                    old_progress = progress;
                 }
             }
 
             let mut output = Raster::initialize_using_file(&output_file, &dem);
+            output.widen_to_f64();
             output.reinitialize_values(f64::MAX);
             let mut queue = VecDeque::new();
             let mut num_outflowing = Array2D::new(rows, columns, 0i8, -1i8)?;
@@ -680,7 +678,7 @@ impl WhiteboxTool for DownslopeDistanceToStream {
 
                 progress = ((num_solved_cells as f64 / num_cells as f64) * 100.0) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    context.progress("Progress", progress)?;
                     old_progress = progress;
                 }
             }
@@ -693,7 +691,7 @@ impl WhiteboxTool for DownslopeDistanceToStream {
 
                         progress = ((num_solved_cells as f64 / num_cells as f64) * 100.0) as usize;
                         if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                            context.progress("Progress", progress)?;
                             old_progress = progress;
                         }
                     }
@@ -710,29 +708,26 @@ impl WhiteboxTool for DownslopeDistanceToStream {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                context.info("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
 
             if interior_pit_found {
-                println!("**********************************************************************************");
-                println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
+                context.info("**********************************************************************************");
+                context.warning("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
                 DEM needs to be processed to remove topographic depressions and flats prior to
-                running this tool.");
-                println!("**********************************************************************************");
+                running this tool.".to_string());
+                context.info("**********************************************************************************");
             }
         }
 
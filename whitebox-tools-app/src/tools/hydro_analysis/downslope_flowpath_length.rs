@@ -155,8 +155,9 @@ impl WhiteboxTool for DownslopeFlowpathLength {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut d8_file = String::new();
         let mut watersheds_file = String::new();
         let mut weights_file = String::new();
@@ -214,11 +215,11 @@ impl WhiteboxTool for DownslopeFlowpathLength {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -252,7 +253,7 @@ impl WhiteboxTool for DownslopeFlowpathLength {
         }
 
         if verbose {
-            println!("Reading pointer data...")
+            context.info("Reading pointer data...")
         };
         let pntr = Raster::new(&d8_file, "r")?;
         let rows = pntr.configs.rows as isize;
@@ -263,7 +264,7 @@ impl WhiteboxTool for DownslopeFlowpathLength {
         let diag_cell_size = (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
 
         if verbose {
-            println!("Initializing watershed data...")
+            context.info("Initializing watershed data...")
         };
         let watersheds: Array2D<f64> = match use_watersheds {
             false => Array2D::new(1, 1, 1f64, 1f64)?,
@@ -280,7 +281,7 @@ impl WhiteboxTool for DownslopeFlowpathLength {
         // let watershed_nodata = watersheds.nodata;
 
         if verbose {
-            println!("Initializing weights data...")
+            context.info("Initializing weights data...")
         };
         let weights: Array2D<f64> = match use_weights {
             false => Array2D::new(1, 1, 1f64, 1f64)?,
@@ -298,6 +299,7 @@ impl WhiteboxTool for DownslopeFlowpathLength {
         let start = Instant::now();
 
         let mut output = Raster::initialize_using_file(&output_file, &pntr);
+        output.widen_to_f64();
         let out_nodata = -32768f64;
         output.configs.nodata = out_nodata;
         output.reinitialize_values(-999f64);
@@ -410,12 +412,10 @@ impl WhiteboxTool for DownslopeFlowpathLength {
                     output.set_value(row, col, out_nodata);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -437,21 +437,18 @@ impl WhiteboxTool for DownslopeFlowpathLength {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
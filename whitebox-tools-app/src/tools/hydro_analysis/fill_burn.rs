@@ -140,8 +140,9 @@ impl WhiteboxTool for FillBurn {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut dem_file = String::new();
         let mut streams_file = String::new();
         let mut output_file = String::new();
@@ -187,11 +188,11 @@ impl WhiteboxTool for FillBurn {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -210,12 +211,12 @@ impl WhiteboxTool for FillBurn {
         }
 
         if verbose {
-            println!("Reading streams data...")
+            context.info("Reading streams data...")
         };
         let streams = Shapefile::read(&streams_file)?;
 
         if verbose {
-            println!("Reading DEM data...")
+            context.info("Reading DEM data...")
         };
         let dem = Arc::new(Raster::new(&dem_file, "r")?);
         let rows = dem.configs.rows as isize;
@@ -345,12 +346,10 @@ impl WhiteboxTool for FillBurn {
             }
 
             count += 1f64;
-            if verbose {
-                progress = (100.0_f64 * count / (streams.num_records - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Rasterizing Streams: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * count / (streams.num_records - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Rasterizing Streams", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -411,12 +410,10 @@ impl WhiteboxTool for FillBurn {
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * a as f64 / 7.0) as usize;
-                    if progress != old_progress {
-                        println!("Line Thinning {}: {}%", loop_num, progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * a as f64 / 7.0) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Line Thinning {}", loop_num), progress)?;
+                    old_progress = progress;
                 }
             }
         }
@@ -447,22 +444,23 @@ impl WhiteboxTool for FillBurn {
                             data[col as usize] = z - 10000f64;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &dem);
+        output.widen_to_f64();
         for r in 0..rows {
             let (row, data) = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(row, data);
 
-            if verbose {
-                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Initializing output: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Initializing output", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -533,12 +531,10 @@ impl WhiteboxTool for FillBurn {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -574,13 +570,11 @@ impl WhiteboxTool for FillBurn {
                 }
             }
 
-            if verbose {
-                num_solved_cells += 1;
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Filling DEM: {}%", progress);
-                    old_progress = progress;
-                }
+            num_solved_cells += 1;
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Filling DEM", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -598,12 +592,10 @@ impl WhiteboxTool for FillBurn {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Updating Stream Elevations: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Updating Stream Elevations", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -616,12 +608,10 @@ impl WhiteboxTool for FillBurn {
                     output.set_value(row, col, z);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Updating Stream Elevations: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Updating Stream Elevations", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -636,21 +626,18 @@ impl WhiteboxTool for FillBurn {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
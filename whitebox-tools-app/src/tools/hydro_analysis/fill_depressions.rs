@@ -2,24 +2,21 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 28/06/2017
-Last Modified: 12/12/2019
+Last Modified: 17/10/2026
 License: MIT
 */
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
+use super::tiled_depressions::{
+    self, find_pits, grid_maximum, solve_depressions, sort_pits, CachedFlags, FillTask, Grid,
+    Grids, PitOrder,
+};
 use crate::tools::*;
-use std::cmp::Ordering;
-use std::cmp::Ordering::Equal;
-use std::collections::{BinaryHeap, VecDeque};
 use std::env;
 use std::f64;
-use std::i32;
 use std::io::{Error, ErrorKind};
 use std::path;
-use std::sync::mpsc;
-use std::sync::Arc;
-use std::thread;
 
 /// This tool can be used to fill all of the depressions in a digital elevation model (DEM) and to remove the
 /// flat areas. This is a common pre-processing step required by many flow-path analysis tools to ensure continuous
@@ -54,6 +51,11 @@ use std::thread;
 /// Note that this tool will not fill in NoData regions within the DEM. It is advisable to remove such regions using the
 /// `FillMissingData` tool prior to application.
 ///
+/// Pits are filled in parallel on as many threads as the `max_procs` setting allows. Each is filled speculatively
+/// and the fills are committed in the order in which the pits would be filled serially, so that the output is
+/// the same whatever the number of threads; the DEM isn't split into tiles that are merged afterwards. DEMs that are too large to be read into memory, given the `max_memory_mb`
+/// setting, are processed from disk, with the output held in a scratch file until it is written.
+///
 /// # See Also
 /// `BreachDepressionsLeastCost`, `BreachDepressions`, `Sink`, `DepthInSink`, `FillMissingData`
 pub struct FillDepressions {
@@ -137,13 +139,101 @@ impl FillDepressions {
         .replace("*", &sep);
 
         FillDepressions {
-            name: name,
-            description: description,
-            toolbox: toolbox,
-            parameters: parameters,
+            name,
+            description,
+            toolbox,
+            parameters,
             example_usage: usage,
         }
     }
+
+    // Fills the depressions of a DEM that is too large to be read into memory. The output,
+    // along with the flags used while filling, is held in scratch files.
+    #[allow(clippy::too_many_arguments)]
+    fn run_cached(
+        &self,
+        mut input: CachedRaster,
+        input_file: &str,
+        output_file: &str,
+        fix_flats: bool,
+        flat_increment: f64,
+        max_depth: f64,
+        context: &ToolContext,
+    ) -> Result<(), Error> {
+        let verbose = context.verbose;
+        let start = Instant::now();
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let nodata = input.configs.nodata;
+        let resx = input.configs.resolution_x;
+        let resy = input.configs.resolution_y;
+        let diagres = (resx * resx + resy * resy).sqrt();
+
+        if fix_flats && flat_increment.is_nan() && input.raster_type == RasterType::GeoTiff {
+            // The largest elevation isn't recorded in the header of a GeoTIFF.
+            let mut num_procs = num_cpus::get() as isize;
+            let max_procs = whitebox_common::configs::get_configs()?.max_procs;
+            if max_procs > 0 && max_procs < num_procs {
+                num_procs = max_procs;
+            }
+            input.configs.maximum = grid_maximum(&mut input, nodata, num_procs, context)?;
+        }
+        let small_num = flats_increment(fix_flats, flat_increment, input.configs.maximum, diagres);
+
+        let mut output = CachedRaster::initialize_using_file(output_file, &input)?;
+        output.configs.data_type = DataType::F32;
+        output.configs.display_min = input.configs.display_min;
+        output.configs.display_max = input.configs.display_max;
+        let band_height = input.get_row_band_height() as isize;
+        let mut band_start = 0isize;
+        while band_start < rows {
+            let band_rows = band_height.min(rows - band_start);
+            let window = input.read_window(band_start, 0, band_rows as usize, columns as usize)?;
+            output.write_window(band_start, 0, &window)?;
+            band_start += band_rows;
+        }
+
+        let cache_size = input.get_cache_size();
+        let grids = Grids {
+            elevations: output,
+            visited: CachedFlags::new(
+                &format!("{}.visited.tif", output_file),
+                &input.configs,
+                cache_size,
+            )?,
+            flats: CachedFlags::new(
+                &format!("{}.flats.tif", output_file),
+                &input.configs,
+                cache_size,
+            )?,
+        };
+        let mut output = fill_depressions(
+            &mut input, grids, fix_flats, small_num, max_depth, nodata, context,
+        )?;
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Fix flats: {}", fix_flats));
+        if fix_flats {
+            output.add_metadata_entry(format!("Flat increment value: {}", small_num));
+        }
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            context.info("Saving data...")
+        };
+        output.write()?;
+        if verbose {
+            context.info("Output file written");
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        Ok(())
+    }
 }
 
 impl WhiteboxTool for FillDepressions {
@@ -178,8 +268,9 @@ impl WhiteboxTool for FillDepressions {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut fix_flats = false;
@@ -249,18 +340,15 @@ impl WhiteboxTool for FillDepressions {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
 
-        let mut progress: usize;
-        let mut old_progress: usize = 1;
-
         if !input_file.contains(&sep) && !input_file.contains("/") {
             input_file = format!("{}{}", working_directory, input_file);
         }
@@ -268,11 +356,23 @@ impl WhiteboxTool for FillDepressions {
             output_file = format!("{}{}", working_directory, output_file);
         }
 
+        if let Some(input) = CachedRaster::open_if_exceeds_memory(&input_file, 2)? {
+            return self.run_cached(
+                input,
+                &input_file,
+                &output_file,
+                fix_flats,
+                flat_increment,
+                max_depth,
+                context,
+            );
+        }
+
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
-        let input = Raster::new(&input_file, "r")?;
+        let mut input = Raster::new(&input_file, "r")?;
 
         let start = Instant::now();
         let rows = input.configs.rows as isize;
@@ -282,442 +382,23 @@ impl WhiteboxTool for FillDepressions {
         let resy = input.configs.resolution_y;
         let diagres = (resx * resx + resy * resy).sqrt();
 
-        let small_num = if fix_flats && !flat_increment.is_nan() {
-            flat_increment
-        } else if fix_flats {
-            let elev_digits = (input.configs.maximum as i64).to_string().len();
-            let elev_multiplier = 10.0_f64.powi((9 - elev_digits) as i32);
-            1.0_f64 / elev_multiplier as f64 * diagres.ceil()
-        } else {
-            0f64
-        };
+        let small_num = flats_increment(fix_flats, flat_increment, input.configs.maximum, diagres);
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.widen_to_f64();
         output.set_data_from_raster(&input)?;
         output.configs.data_type = DataType::F32;
         output.configs.display_min = input.configs.display_min;
         output.configs.display_max = input.configs.display_max;
 
-        // drop(input); // input is no longer needed.
-
-        let (mut col, mut row): (isize, isize);
-        let (mut rn, mut cn): (isize, isize);
-        let (mut z, mut zn): (f64, f64);
-        let dx = [1, 1, 1, 0, -1, -1, -1, 0];
-        let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
-
-        // Find pit cells. This step is parallelized.
-        let mut num_procs = num_cpus::get() as isize;
-        let configs = whitebox_common::configs::get_configs()?;
-        let max_procs = configs.max_procs;
-        if max_procs > 0 && max_procs < num_procs {
-            num_procs = max_procs;
-        }
-        let output2 = Arc::new(output);
-        let (tx, rx) = mpsc::channel();
-        for tid in 0..num_procs {
-            let output2 = output2.clone();
-            let tx = tx.clone();
-            thread::spawn(move || {
-                let mut z: f64;
-                let mut zn: f64;
-                let mut flag: bool;
-                let mut pits = vec![];
-                for row in (1..rows - 1).filter(|r| r % num_procs == tid) {
-                    for col in 1..columns - 1 {
-                        z = output2.get_value(row, col);
-                        if z != nodata {
-                            flag = true;
-                            for n in 0..8 {
-                                zn = output2.get_value(row + dy[n], col + dx[n]);
-                                if zn < z || zn == nodata {
-                                    // It either has a lower neighbour or is an edge cell.
-                                    flag = false;
-                                    break;
-                                }
-                            }
-                            if flag {
-                                // it's a cell with undefined flow
-                                pits.push((row, col, z));
-                            }
-                        }
-                    }
-                }
-                tx.send(pits).unwrap();
-            });
-        }
-
-        let mut undefined_flow_cells = vec![];
-        for p in 0..num_procs {
-            let mut pits = rx.recv().expect("Error receiving data from thread.");
-            undefined_flow_cells.append(&mut pits);
-
-            if verbose {
-                progress = (100.0_f64 * (p + 1) as f64 / num_procs as f64) as usize;
-                if progress != old_progress {
-                    println!("Finding pit cells: {}%", progress);
-                    old_progress = progress;
-                }
-            }
-        }
-
-        output = match Arc::try_unwrap(output2) {
-            Ok(val) => val,
-            Err(_) => panic!("Error unwrapping 'output'"),
+        let grids = Grids {
+            elevations: output,
+            visited: Array2D::new(rows, columns, 0, -1)?,
+            flats: Array2D::new(rows, columns, 0, -1)?,
         };
-
-        let num_deps = undefined_flow_cells.len();
-
-        // Now we need to perform an in-place depression filling
-        let mut minheap = BinaryHeap::new();
-        let mut minheap2 = BinaryHeap::new();
-        let mut visited: Array2D<i8> = Array2D::new(rows, columns, 0, -1)?;
-        let mut flats: Array2D<i8> = Array2D::new(rows, columns, 0, -1)?;
-        let mut possible_outlets = vec![];
-        // solve from highest to lowest
-        undefined_flow_cells.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(Equal));
-        let mut pit_id = 1;
-        let mut flag: bool;
-        let mut z_pit: f64;
-
-        let mut outlet_found: bool;
-        let mut outlet_z: f64;
-        let mut queue = VecDeque::new();
-
-        while let Some(cell) = undefined_flow_cells.pop() {
-            row = cell.0;
-            col = cell.1;
-            // if it's already in a solved site, don't do it a second time.
-            if flats.get_value(row, col) != 1 {
-                // First there is a priority region-growing operation to find the outlets.
-                z_pit = output.get_value(row, col);
-                minheap.clear();
-                minheap.push(GridCell {
-                    row: row,
-                    column: col,
-                    priority: z_pit,
-                });
-                visited.set_value(row, col, 1);
-                outlet_found = false;
-                outlet_z = f64::INFINITY;
-                if !queue.is_empty() {
-                    queue.clear();
-                }
-                while let Some(cell2) = minheap.pop() {
-                    z = cell2.priority;
-                    if outlet_found && z > outlet_z {
-                        break;
-                    }
-                    if z - z_pit > max_depth {
-                        // No outlet could be found that was low enough.
-                        break;
-                    }
-                    if !outlet_found {
-                        for n in 0..8 {
-                            cn = cell2.column + dx[n];
-                            rn = cell2.row + dy[n];
-                            if visited.get_value(rn, cn) == 0 {
-                                zn = output.get_value(rn, cn);
-                                if !outlet_found {
-                                    if zn >= z && zn != nodata {
-                                        minheap.push(GridCell {
-                                            row: rn,
-                                            column: cn,
-                                            priority: zn,
-                                        });
-                                        visited.set_value(rn, cn, 1);
-                                    } else if zn != nodata {
-                                        // zn < z
-                                        // 'cell' has a lower neighbour that hasn't already passed through minheap.
-                                        // Therefore, 'cell' is a pour point cell.
-                                        outlet_found = true;
-                                        outlet_z = z;
-                                        queue.push_back((cell2.row, cell2.column));
-                                        possible_outlets.push((cell2.row, cell2.column));
-                                    }
-                                } else if zn == outlet_z {
-                                    // We've found the outlet but are still looking for additional depression cells.
-                                    minheap.push(GridCell {
-                                        row: rn,
-                                        column: cn,
-                                        priority: zn,
-                                    });
-                                    visited.set_value(rn, cn, 1);
-                                }
-                            }
-                        }
-                    } else {
-                        // We've found the outlet but are still looking for additional depression cells and potential outlets.
-                        if z == outlet_z {
-                            flag = false;
-                            for n in 0..8 {
-                                cn = cell2.column + dx[n];
-                                rn = cell2.row + dy[n];
-                                if visited.get_value(rn, cn) == 0 {
-                                    zn = output.get_value(rn, cn);
-                                    if zn < z {
-                                        flag = true;
-                                    } else if zn == outlet_z {
-                                        minheap.push(GridCell {
-                                            row: rn,
-                                            column: cn,
-                                            priority: zn,
-                                        });
-                                        visited.set_value(rn, cn, 1);
-                                    }
-                                }
-                            }
-                            if flag {
-                                // it's an outlet
-                                queue.push_back((cell2.row, cell2.column));
-                                possible_outlets.push((cell2.row, cell2.column));
-                            } else {
-                                visited.set_value(cell2.row, cell2.column, 1);
-                            }
-                        }
-                    }
-                }
-
-                if outlet_found {
-                    // Now that we have the outlets, raise the interior of the depression.
-                    // Start from the outlets.
-                    while let Some(cell2) = queue.pop_front() {
-                        for n in 0..8 {
-                            rn = cell2.0 + dy[n];
-                            cn = cell2.1 + dx[n];
-                            if visited.get_value(rn, cn) == 1 {
-                                visited.set_value(rn, cn, 0);
-                                queue.push_back((rn, cn));
-                                z = output.get_value(rn, cn);
-                                if z < outlet_z {
-                                    output.set_value(rn, cn, outlet_z);
-                                    flats.set_value(rn, cn, 1);
-                                } else if z == outlet_z {
-                                    flats.set_value(rn, cn, 1);
-                                }
-                            }
-                        }
-                    }
-                } else {
-                    queue.push_back((row, col)); // start at the pit cell and clean up visited
-                    while let Some(cell2) = queue.pop_front() {
-                        for n in 0..8 {
-                            rn = cell2.0 + dy[n];
-                            cn = cell2.1 + dx[n];
-                            if visited.get_value(rn, cn) == 1 {
-                                visited.set_value(rn, cn, 0);
-                                queue.push_back((rn, cn));
-                            }
-                        }
-                    }
-                }
-            }
-
-            if verbose {
-                progress = (100.0_f64 * pit_id as f64 / num_deps as f64) as usize;
-                if progress != old_progress {
-                    println!("Filling depressions: {}%", progress);
-                    old_progress = progress;
-                }
-            }
-            pit_id += 1;
-        }
-
-        drop(visited);
-
-        if small_num > 0f64 && fix_flats {
-            // fix the flats
-            if verbose {
-                println!("Fixing flow on flats...");
-                println!("Flats increment value: {}", small_num);
-            }
-            // Some of the potential outlets really will have lower cells.
-            // let mut queue = VecDeque::new();
-            minheap.clear();
-            while let Some(cell) = possible_outlets.pop() {
-                z = output.get_value(cell.0, cell.1);
-                flag = false;
-                for n in 0..8 {
-                    rn = cell.0 + dy[n];
-                    cn = cell.1 + dx[n];
-                    zn = output.get_value(rn, cn);
-                    if zn < z && zn != nodata {
-                        flag = true;
-                        break;
-                    }
-                }
-                if flag {
-                    // it's confirmed as an outlet
-                    minheap.push(GridCell {
-                        row: cell.0,
-                        column: cell.1,
-                        priority: z,
-                    });
-                }
-            }
-
-            let num_outlets = minheap.len();
-            let mut outlets = vec![];
-            while let Some(cell) = minheap.pop() {
-                if flats.get_value(cell.row, cell.column) != 3 {
-                    z = output.get_value(cell.row, cell.column);
-                    flats.set_value(cell.row, cell.column, 3);
-                    // let mut outlets = vec![];
-                    if !outlets.is_empty() {
-                        outlets.clear();
-                    }
-                    outlets.push(cell);
-                    // Are there any other outlet cells at the same elevation (likely for the same feature)
-                    flag = true;
-                    while flag {
-                        match minheap.peek() {
-                            Some(cell2) => {
-                                if cell2.priority == z {
-                                    flats.set_value(cell2.row, cell2.column, 3);
-                                    outlets
-                                        .push(minheap.pop().expect("Error during pop operation."));
-                                } else {
-                                    flag = false;
-                                }
-                            }
-                            None => {
-                                flag = false;
-                            }
-                        }
-                    }
-                    if !minheap2.is_empty() {
-                        minheap2.clear();
-                    }
-                    for cell2 in &outlets {
-                        z = output.get_value(cell2.row, cell2.column);
-                        for n in 0..8 {
-                            rn = cell2.row + dy[n];
-                            cn = cell2.column + dx[n];
-                            if flats.get_value(rn, cn) != 3 {
-                                zn = output.get_value(rn, cn);
-                                if zn == z && zn != nodata {
-                                    // queue.push_back((rn, cn, z));
-                                    minheap2.push(GridCell2 {
-                                        row: rn,
-                                        column: cn,
-                                        z: z,
-                                        priority: input.get_value(rn, cn),
-                                    });
-                                    output.set_value(rn, cn, z + small_num);
-                                    flats.set_value(rn, cn, 3);
-                                }
-                            }
-                        }
-                    }
-                    // Now fix the flats
-                    while let Some(cell2) = minheap2.pop() {
-                        z = output.get_value(cell2.row, cell2.column);
-                        for n in 0..8 {
-                            rn = cell2.row + dy[n];
-                            cn = cell2.column + dx[n];
-                            if flats.get_value(rn, cn) != 3 {
-                                zn = output.get_value(rn, cn);
-                                if zn < z + small_num && zn >= cell2.z && zn != nodata {
-                                    // queue.push_back((rn, cn, cell2.2));
-                                    minheap2.push(GridCell2 {
-                                        row: rn,
-                                        column: cn,
-                                        z: cell2.z,
-                                        priority: input.get_value(rn, cn),
-                                    });
-                                    output.set_value(rn, cn, z + small_num);
-                                    flats.set_value(rn, cn, 3);
-                                }
-                            }
-                        }
-                    }
-                    // while let Some(cell2) = queue.pop_front() {
-                    //     z = output.get_value(cell2.0, cell2.1);
-                    //     for n in 0..8 {
-                    //         rn = cell2.0 + dy[n];
-                    //         cn = cell2.1 + dx[n];
-                    //         if flats.get_value(rn, cn) != 3 {
-                    //             zn = output.get_value(rn, cn);
-                    //             if zn < z + small_num && zn >= cell2.2 && zn != nodata {
-                    //                 queue.push_back((rn, cn, cell2.2));
-                    //                 output.set_value(rn, cn, z + small_num);
-                    //                 flats.set_value(rn, cn, 3);
-                    //             }
-                    //         }
-                    //     }
-                    // }
-                }
-
-                if verbose {
-                    progress =
-                        (100.0_f64 * (1f64 - minheap.len() as f64 / num_outlets as f64)) as usize;
-                    if progress != old_progress {
-                        println!("Fixing flats: {}%", progress);
-                        old_progress = progress;
-                    }
-                }
-            }
-
-            // let mut queue = VecDeque::new();
-            // minheap.clear();
-            // while let Some(cell) = possible_outlets.pop() {
-            //     z = output.get_value(cell.0, cell.1);
-            //     flag = false;
-            //     for n in 0..8 {
-            //         rn = cell.0 + dy[n];
-            //         cn = cell.1 + dx[n];
-            //         zn = output.get_value(rn, cn);
-            //         if zn < z && zn != nodata {
-            //             flag = true;
-            //             break;
-            //         }
-            //     }
-            //     if flag {
-            //         queue.push_back((cell.0, cell.1, z));
-            //         flats.set_value(cell.0, cell.1, 2);
-            //     } else {
-            //         flats.set_value(cell.0, cell.1, 1);
-            //     }
-            // }
-
-            // let mut flats_value: i8;
-
-            // while let Some(cell) = queue.pop_front() {
-            //     z = output.get_value(cell.0, cell.1);
-            //     flats_value = flats.get_value(cell.0, cell.1);
-            //     if flats_value == 2 { // outlet cell
-            //         for n in 0..8 {
-            //             rn = cell.0 + dy[n];
-            //             cn = cell.1 + dx[n];
-            //             if flats.get_value(rn, cn) == 1 {
-            //                 zn = output.get_value(rn, cn);
-            //                 if zn == z {
-            //                     queue.push_back((rn, cn, z));
-            //                     output.set_value(rn, cn, z + small_num);
-            //                     flats.set_value(rn, cn, 3);
-            //                 }
-            //             }
-            //         }
-            //         flats.set_value(cell.0, cell.1, 3);
-            //     } else { // non-outlet cell
-            //         for n in 0..8 {
-            //             rn = cell.0 + dy[n];
-            //             cn = cell.1 + dx[n];
-            //             flats_value = flats.get_value(rn, cn);
-            //             if flats_value == 0 || flats_value == 1 {
-            //                 zn = output.get_value(rn, cn);
-            //                 if zn < z + small_num && zn >= cell.2 && zn != nodata {
-            //                     queue.push_back((rn, cn, cell.2));
-            //                     output.set_value(rn, cn, z + small_num);
-            //                     flats.set_value(rn, cn, 3);
-            //                 } else if flats_value == 1 {
-            //                     flats.set_value(rn, cn, 3);
-            //                 }
-            //             }
-            //         }
-            //     }
-            // }
-        }
+        let mut output = fill_depressions(
+            &mut input, grids, fix_flats, small_num, max_depth, nodata, context,
+        )?;
 
         let elapsed_time = get_formatted_elapsed_time(start);
         output.add_metadata_entry(format!(
@@ -732,66 +413,222 @@ impl WhiteboxTool for FillDepressions {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
     }
 }
 
-#[derive(PartialEq, Debug)]
-struct GridCell {
-    row: isize,
-    column: isize,
-    priority: f64,
-}
-
-impl Eq for GridCell {}
-
-impl PartialOrd for GridCell {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        other.priority.partial_cmp(&self.priority)
+// The elevation increment used to fix flats. Unless it is specified, it is the smallest
+// increment that can be represented at the largest elevation of the DEM.
+fn flats_increment(fix_flats: bool, flat_increment: f64, maximum: f64, diagres: f64) -> f64 {
+    if fix_flats && !flat_increment.is_nan() {
+        flat_increment
+    } else if fix_flats {
+        let elev_digits = (maximum as i64).to_string().len();
+        let elev_multiplier = 10.0_f64.powi((9 - elev_digits) as i32);
+        1.0_f64 / elev_multiplier * diagres.ceil()
+    } else {
+        0f64
     }
 }
 
-impl Ord for GridCell {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+// Fills the depressions of the DEM held by `grids`, which is initially a copy of `input`,
+// and then optionally fixes the flats, returning the filled DEM.
+fn fill_depressions<I, E, F>(
+    input: &mut I,
+    mut grids: Grids<E, F>,
+    fix_flats: bool,
+    small_num: f64,
+    max_depth: f64,
+    nodata: f64,
+    context: &ToolContext,
+) -> Result<E, Error>
+where
+    I: Grid<f64>,
+    E: Grid<f64>,
+    F: Grid<i8>,
+{
+    let mut num_procs = num_cpus::get() as isize;
+    let configs = whitebox_common::configs::get_configs()?;
+    let max_procs = configs.max_procs;
+    if max_procs > 0 && max_procs < num_procs {
+        num_procs = max_procs;
     }
-}
 
-#[derive(PartialEq, Debug)]
-struct GridCell2 {
-    row: isize,
-    column: isize,
-    z: f64,
-    priority: f64,
+    // Find pit cells and solve them from highest to lowest.
+    let mut pits = find_pits(&mut grids.elevations, nodata, num_procs, context)?;
+    sort_pits(&mut pits, PitOrder::HighestFirst);
+    let task = FillTask {
+        nodata,
+        max_depth,
+        clear_unfilled: true,
+    };
+    let possible_outlets: Vec<(isize, isize)> = solve_depressions(
+        &mut grids,
+        &pits,
+        &task,
+        nodata,
+        num_procs,
+        context,
+        "Filling depressions",
+    )?
+    .into_iter()
+    .flatten()
+    .collect();
+
+    let Grids {
+        elevations: mut output,
+        visited,
+        mut flats,
+    } = grids;
+    drop(visited);
+
+    if small_num > 0f64 && fix_flats {
+        if context.verbose {
+            context.info("Fixing flow on flats...");
+            context.info(format!("Flats increment value: {}", small_num));
+        }
+        tiled_depressions::fix_flats(
+            &mut output,
+            input,
+            &mut flats,
+            possible_outlets,
+            small_num,
+            nodata,
+            context,
+        )?;
+    }
+    Ok(output)
 }
 
-impl Eq for GridCell2 {}
-
-impl PartialOrd for GridCell2 {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        other.priority.partial_cmp(&self.priority)
+#[cfg(test)]
+mod test {
+    use super::super::tiled_depressions::test::{raster_digest, write_synthetic_dem};
+    use super::FillDepressions;
+    use crate::tools::{temp_file, ToolContext, WhiteboxTool};
+    use std::f64;
+    use whitebox_raster::*;
+
+    // The outputs must be identical to those that the tool wrote, from the same DEM, before
+    // its depressions were solved in parallel (see tiled_depressions.rs), both when the DEM is
+    // held in memory and when it is read from disk.
+    #[test]
+    fn test_output_matches_previous_release() {
+        let input_file = temp_file("fill_depressions", "dem.tif");
+        write_synthetic_dem(&input_file);
+        let tool = FillDepressions::new();
+        let context = ToolContext::new(false);
+        let cases = [
+            (false, f64::NAN, f64::INFINITY, 0x2841_f1d4_23ab_fcf7u64),
+            (true, f64::NAN, f64::INFINITY, 0xde34_4369_f77c_2bcf),
+            (true, 0.0001, 1.5, 0x183b_3df6_9ccf_a076),
+        ];
+        for (i, &(fix_flats, flat_increment, max_depth, digest)) in cases.iter().enumerate() {
+            let output_file = temp_file("fill_depressions", &format!("output{}.tif", i));
+            let mut args = vec![format!("--dem={}", input_file), format!("-o={}", output_file)];
+            if fix_flats {
+                args.push("--fix_flats".to_string());
+            }
+            if !flat_increment.is_nan() {
+                args.push(format!("--flat_increment={}", flat_increment));
+            }
+            if max_depth.is_finite() {
+                args.push(format!("--max_depth={}", max_depth));
+            }
+            tool.run(args, "", &context).unwrap();
+            assert_eq!(raster_digest(&output_file), digest, "in memory, case {}", i);
+
+            let mut input = CachedRaster::new(&input_file, "r").unwrap();
+            // small enough that the cache is cycled while filling
+            input.set_cache_size(256 * 1024);
+            tool.run_cached(
+                input,
+                &input_file,
+                &output_file,
+                fix_flats,
+                flat_increment,
+                max_depth,
+                &context,
+            )
+            .unwrap();
+            assert_eq!(raster_digest(&output_file), digest, "cached, case {}", i);
+            let _ = std::fs::remove_file(output_file);
+        }
+        let _ = std::fs::remove_file(input_file);
     }
-}
 
-impl Ord for GridCell2 {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+    // Fixing flats raises the cells of a filled depression by increments far smaller than the
+    // precision of an f32 at realistic elevations. Those increments must accumulate in memory
+    // and only be rounded when the output is written, whatever the data type of the DEM.
+    #[test]
+    fn test_single_precision_flats_keep_their_gradient() {
+        let mut outputs = vec![];
+        for &data_type in &[DataType::F32, DataType::F64] {
+            let input_file = temp_file("fill_depressions", &format!("flats_{:?}.tif", data_type));
+            let configs = RasterConfigs {
+                rows: 40,
+                columns: 40,
+                north: 40.0,
+                south: 0.0,
+                east: 40.0,
+                west: 0.0,
+                resolution_x: 1.0,
+                resolution_y: 1.0,
+                nodata: -32768.0,
+                data_type,
+                photometric_interp: PhotometricInterpretation::Continuous,
+                ..Default::default()
+            };
+            let mut dem = Raster::initialize_using_config(&input_file, &configs);
+            for row in 0..40 {
+                for col in 0..40 {
+                    let depression = (10..30).contains(&row) && (10..30).contains(&col);
+                    let z = if depression { 490.0 } else { 500.0 + col as f64 * 0.5 };
+                    dem.set_value(row, col, z);
+                }
+            }
+            dem.write().unwrap();
+
+            let output_file = temp_file(
+                "fill_depressions",
+                &format!("flats_output_{:?}.tif", data_type),
+            );
+            let args = vec![
+                format!("--dem={}", input_file),
+                format!("-o={}", output_file),
+                "--fix_flats".to_string(),
+            ];
+            FillDepressions::new()
+                .run(args, "", &ToolContext::new(false))
+                .unwrap();
+            let output = Raster::new(&output_file, "r").unwrap();
+            let mut filled = vec![];
+            for row in 10..30 {
+                for col in 10..30 {
+                    filled.push(output.get_value(row, col));
+                }
+            }
+            filled.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            filled.dedup();
+            assert!(filled[0] >= 504.5);
+            assert!(filled.len() > 1, "the filled depression is flat");
+            outputs.push(raster_digest(&output_file));
+            let _ = std::fs::remove_file(input_file);
+            let _ = std::fs::remove_file(output_file);
+        }
+        assert_eq!(outputs[0], outputs[1]);
     }
 }
@@ -148,8 +148,9 @@ impl WhiteboxTool for FillDepressionsPlanchonAndDarboux {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut fix_flats = false;
@@ -206,11 +207,11 @@ impl WhiteboxTool for FillDepressionsPlanchonAndDarboux {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -226,7 +227,7 @@ impl WhiteboxTool for FillDepressionsPlanchonAndDarboux {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let start_inclusive = Instant::now();
@@ -254,6 +255,7 @@ impl WhiteboxTool for FillDepressionsPlanchonAndDarboux {
         let nodata_output = -32768.0f64;
         let large_value = f64::INFINITY;
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.widen_to_f64();
         output.configs.data_type = DataType::F64;
         output.configs.nodata = nodata_output;
         output.reinitialize_values(large_value);
@@ -416,12 +418,10 @@ impl WhiteboxTool for FillDepressionsPlanchonAndDarboux {
                             }
                         }
                     }
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("progress (Loop {}): {}%", loop_num, progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress(&format!("progress (Loop {})", loop_num), progress)?;
+                        old_progress = progress;
                     }
                 }
             } else if i == 1 {
@@ -455,12 +455,10 @@ impl WhiteboxTool for FillDepressionsPlanchonAndDarboux {
                             }
                         }
                     }
-                    if verbose {
-                        progress = (100.0_f64 * (rows - row) as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("progress (Loop {}): {}%", loop_num, progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * (rows - row) as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress(&format!("progress (Loop {})", loop_num), progress)?;
+                        old_progress = progress;
                     }
                 }
             } else if i == 2 {
@@ -494,12 +492,10 @@ impl WhiteboxTool for FillDepressionsPlanchonAndDarboux {
                             }
                         }
                     }
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("progress (Loop {}): {}%", loop_num, progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress(&format!("progress (Loop {})", loop_num), progress)?;
+                        old_progress = progress;
                     }
                 }
             } else {
@@ -534,12 +530,10 @@ impl WhiteboxTool for FillDepressionsPlanchonAndDarboux {
                             }
                         }
                     }
-                    if verbose {
-                        progress = (100.0_f64 * (rows - row) as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("progress (Loop {}): {}%", loop_num, progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * (rows - row) as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress(&format!("progress (Loop {})", loop_num), progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -548,7 +542,7 @@ impl WhiteboxTool for FillDepressionsPlanchonAndDarboux {
                 i = 0;
             }
             if verbose {
-                println!("Loop {} (Num. modified cells: {})", loop_num, num_modified);
+                context.info(format!("Loop {} (Num. modified cells: {})", loop_num, num_modified));
             }
         }
 
@@ -567,26 +561,20 @@ impl WhiteboxTool for FillDepressionsPlanchonAndDarboux {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             let elapsed_time = get_formatted_elapsed_time(start_inclusive);
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -128,8 +128,9 @@ impl WhiteboxTool for FindParallelFlow {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut d8_file = String::new();
         let mut use_streams = false;
         let mut streams_file = String::new();
@@ -178,11 +179,11 @@ impl WhiteboxTool for FindParallelFlow {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -212,7 +213,7 @@ impl WhiteboxTool for FindParallelFlow {
             }
             true => {
                 if verbose {
-                    println!("Reading streams data...")
+                    context.info("Reading streams data...")
                 };
                 let r = Raster::new(&streams_file, "r")?;
                 if r.configs.rows != rows as usize || r.configs.columns != columns as usize {
@@ -251,13 +252,13 @@ impl WhiteboxTool for FindParallelFlow {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data = vec![nodata; columns as usize];
                     for col in 0..columns {
-                        z = pntr[(row, col)];
+                        z = pntr.get_value(row, col);
                         stream_val = streams[(row, col)];
                         if z != nodata && stream_val != streams_nodata && stream_val > 0f64 {
                             is_parallel = false;
                             for n in 0..8 {
                                 if z != outflowing_vals[n] {
-                                    zn = pntr[(row + dy[n], col + dx[n])];
+                                    zn = pntr.get_value(row + dy[n], col + dx[n]);
                                     stream_valn = streams[(row + dy[n], col + dx[n])];
                                     if zn == z
                                         && zn != inflowing_vals[n]
@@ -276,7 +277,9 @@ impl WhiteboxTool for FindParallelFlow {
                             }
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -284,12 +287,10 @@ impl WhiteboxTool for FindParallelFlow {
         for row in 0..rows {
             let data = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(data.0, data.1);
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -305,22 +306,19 @@ impl WhiteboxTool for FindParallelFlow {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -133,8 +133,9 @@ impl WhiteboxTool for FlattenLakes {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut polygons_file = String::new();
         let mut output_file = String::new();
@@ -180,11 +181,11 @@ impl WhiteboxTool for FlattenLakes {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -203,7 +204,7 @@ impl WhiteboxTool for FlattenLakes {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let input = Raster::new(&input_file, "r")?;
 
@@ -376,12 +377,10 @@ impl WhiteboxTool for FlattenLakes {
             }
 
             count += 1f64;
-            if verbose {
-                progress = (100.0_f64 * count / (polygons.num_records - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Finding lake elevations: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * count / (polygons.num_records - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Finding lake elevations", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -454,17 +453,12 @@ impl WhiteboxTool for FlattenLakes {
                                     output.set_value(r, c, min_elevs[feature_num]);
                                 }
                             }
-                            if verbose && num_records < 25 {
+                            if num_records < 25 {
                                 progress = (100.0_f64 * (r - starting_row) as f64
                                     / (ending_row - starting_row) as f64)
                                     as usize;
                                 if progress != old_progress {
-                                    println!(
-                                        "Updating lake elevations ({} of {}): {}%",
-                                        record_num + 1,
-                                        num_records,
-                                        progress
-                                    );
+                                    context.progress(&format!("Updating lake elevations ({} of {})", record_num + 1, num_records), progress)?;
                                     old_progress = progress;
                                 }
                             }
@@ -530,17 +524,12 @@ impl WhiteboxTool for FlattenLakes {
                                 output.set_value(r, c, input.get_value(r, c));
                             }
                         }
-                        if verbose && num_records < 25 {
+                        if num_records < 25 {
                             progress = (100.0_f64 * (r - starting_row) as f64
                                 / (ending_row - starting_row) as f64)
                                 as usize;
                             if progress != old_progress {
-                                println!(
-                                    "Updating lake elevations ({} of {}): {}%",
-                                    record_num + 1,
-                                    num_records,
-                                    progress
-                                );
+                                context.progress(&format!("Updating lake elevations ({} of {})", record_num + 1, num_records), progress)?;
                                 old_progress = progress;
                             }
                         }
@@ -548,10 +537,10 @@ impl WhiteboxTool for FlattenLakes {
                 }
             }
 
-            if verbose && num_records > 25 {
+            if num_records > 25 {
                 progress = (100.0_f64 * (record_num as f64) / (num_records - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    context.progress("Progress", progress)?;
                     old_progress = progress;
                 }
             }
@@ -566,22 +555,19 @@ impl WhiteboxTool for FlattenLakes {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -0,0 +1,1271 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+*/
+
+use crate::tools::*;
+use std::collections::HashMap;
+use std::env;
+use std::f64;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{BufReader, BufWriter, Error, ErrorKind};
+use std::path;
+use whitebox_common::algorithms::trace_grid_polygons;
+use whitebox_common::structures::{Array2D, Point2D};
+use whitebox_raster::*;
+use whitebox_vector::*;
+
+/// This tool maps the extent and depth of flooding along a stream network using the height above
+/// the nearest drainage (HAND) approach of Zheng et al. (2018). The user must specify a HAND raster
+/// (`--hand`), such as that created by the `ElevationAboveStream` tool, a D8 pointer raster
+/// (`--d8_pntr`), a stream link identifier raster (`--linkid`), created by `StreamLinkIdentifier`,
+/// and a raster of the average slope of each stream link (`--link_slope`), in degrees, created by
+/// `StreamLinkSlope`. All of these rasters must be derived from the same DEM and stream network.
+/// Each grid cell is assigned to the stream link (reach) that it drains to along the D8 flowpath.
+///
+/// For each reach, the tool first estimates a synthetic rating curve. At a series of stage heights,
+/// from zero up to the maximum stage (`--max_stage`) in increments of `--stage_step`, every cell of
+/// the reach's catchment with a HAND value below the stage is inundated. The volume of water held in
+/// the reach is the sum of the inundation depths multiplied by the cell area, and the wetted bed
+/// area is the inundated area adjusted for the local gradient of the HAND surface. Dividing these by
+/// the reach length gives the channel cross-sectional area and wetted perimeter, and thus the
+/// hydraulic radius, from which Manning's equation yields the discharge at each stage, using the
+/// reach slope and a roughness coefficient (`--mannings_n`).
+///
+/// The stage or discharge of each reach to be mapped is read from a CSV file (`--values`). Each
+/// row of the file contains a reach (link) ID and a value, separated by a comma, semi-colon, tab,
+/// or space; an optional header row is ignored. The `--value_type` parameter determines whether the
+/// values are stage heights ('stage'), in the vertical units of the HAND raster, or discharges
+/// ('flow'), in cubic units of the HAND raster per second. Discharges are converted to stage heights
+/// by interpolating the reach's rating curve; discharges exceeding the curve are mapped at the
+/// maximum stage, and a warning is issued.
+///
+/// The output raster (`--output`) contains the inundation depth of each grid cell, i.e. the stage
+/// of its reach less its HAND value, and zero where cells are dry or their reach is not listed in
+/// the values file. Cells that do not drain to a stream are assigned NoData. Optionally, the tool
+/// will also output the flood extent as a vector of polygons (`--out_polygons`), with one
+/// (multipart) polygon for each flooded reach and attributes for the reach ID, stage, discharge,
+/// flooded area, and maximum depth, and the rating curves as a CSV file (`--rating_curves`).
+///
+/// HAND-based inundation maps assume that the water surface of each reach is level and that
+/// flooding is confined to the reach's catchment. They are best suited to regional-scale,
+/// preliminary flood mapping using high-accuracy DEM data; the DEM should be hydrologically
+/// conditioned (see `BreachDepressionsLeastCost`) before the HAND and pointer rasters are derived.
+///
+/// # Reference
+/// Renno, C. D., Nobre, A. D., Cuartas, L. A., Soares, J. V., Hodnett, M. G., Tomasella, J., & Waterloo, M. J.
+/// (2008). HAND, a new terrain descriptor using SRTM-DEM: Mapping terra-firme rainforest environments in Amazonia.
+/// Remote Sensing of Environment, 112(9), 3469-3481.
+///
+/// Zheng, X., Tarboton, D. G., Maidment, D. R., Liu, Y. Y., & Passalacqua, P. (2018). River channel geometry and
+/// rating curve estimation using height above the nearest drainage. Journal of the American Water Resources
+/// Association, 54(4), 785-806.
+///
+/// # See Also
+/// `ElevationAboveStream`, `StreamLinkIdentifier`, `StreamLinkSlope`, `D8Pointer`
+pub struct FloodInundationMap {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl FloodInundationMap {
+    pub fn new() -> FloodInundationMap {
+        // public constructor
+        let name = "FloodInundationMap".to_string();
+        let toolbox = "Hydrological Analysis".to_string();
+        let description =
+            "Maps flood depths and extents from height above the nearest drainage and reach stages or flows."
+                .to_string();
+
+        let parameters = vec![
+            ToolParameter {
+                name: "Input HAND File".to_owned(),
+                flags: vec!["--hand".to_owned()],
+                description:
+                    "Input raster of height above the nearest drainage (ElevationAboveStream)."
+                        .to_owned(),
+                parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+                default_value: None,
+                optional: false,
+            },
+            ToolParameter {
+                name: "Input D8 Pointer File".to_owned(),
+                flags: vec!["--d8_pntr".to_owned()],
+                description: "Input D8 pointer raster file.".to_owned(),
+                parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+                default_value: None,
+                optional: false,
+            },
+            ToolParameter {
+                name: "Input Stream Link (Label) File".to_owned(),
+                flags: vec!["--linkid".to_owned()],
+                description: "Input raster stream link ID file (StreamLinkIdentifier).".to_owned(),
+                parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+                default_value: None,
+                optional: false,
+            },
+            ToolParameter {
+                name: "Input Stream Link Slope File".to_owned(),
+                flags: vec!["--link_slope".to_owned()],
+                description: "Input raster of stream link slopes, in degrees (StreamLinkSlope)."
+                    .to_owned(),
+                parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+                default_value: None,
+                optional: false,
+            },
+            ToolParameter {
+                name: "Input Reach Values File".to_owned(),
+                flags: vec!["--values".to_owned()],
+                description: "Input CSV file of reach (link) IDs and their stage or flow values."
+                    .to_owned(),
+                parameter_type: ParameterType::ExistingFile(ParameterFileType::Csv),
+                default_value: None,
+                optional: false,
+            },
+            ToolParameter {
+                name: "Value Type".to_owned(),
+                flags: vec!["--value_type".to_owned()],
+                description: "Type of the reach values; one of 'stage' and 'flow' (default)."
+                    .to_owned(),
+                parameter_type: ParameterType::OptionList(vec![
+                    "stage".to_owned(),
+                    "flow".to_owned(),
+                ]),
+                default_value: Some("flow".to_owned()),
+                optional: true,
+            },
+            ToolParameter {
+                name: "Manning's n".to_owned(),
+                flags: vec!["--mannings_n".to_owned()],
+                description: "Manning's roughness coefficient of the channels and floodplains."
+                    .to_owned(),
+                parameter_type: ParameterType::Float,
+                default_value: Some("0.05".to_owned()),
+                optional: true,
+            },
+            ToolParameter {
+                name: "Rating Curve Stage Step".to_owned(),
+                flags: vec!["--stage_step".to_owned()],
+                description: "Stage height increment of the synthetic rating curves.".to_owned(),
+                parameter_type: ParameterType::Float,
+                default_value: Some("0.25".to_owned()),
+                optional: true,
+            },
+            ToolParameter {
+                name: "Maximum Stage".to_owned(),
+                flags: vec!["--max_stage".to_owned()],
+                description: "Maximum stage height of the synthetic rating curves.".to_owned(),
+                parameter_type: ParameterType::Float,
+                default_value: Some("20.0".to_owned()),
+                optional: true,
+            },
+            ToolParameter {
+                name: "Does the pointer file use the ESRI pointer scheme?".to_owned(),
+                flags: vec!["--esri_pntr".to_owned()],
+                description: "D8 pointer uses the ESRI style scheme.".to_owned(),
+                parameter_type: ParameterType::Boolean,
+                default_value: Some("false".to_owned()),
+                optional: true,
+            },
+            ToolParameter {
+                name: "Output Depth File".to_owned(),
+                flags: vec!["-o".to_owned(), "--output".to_owned()],
+                description: "Output raster file of inundation depths.".to_owned(),
+                parameter_type: ParameterType::NewFile(ParameterFileType::Raster),
+                default_value: None,
+                optional: false,
+            },
+            ToolParameter {
+                name: "Output Extent Polygons File".to_owned(),
+                flags: vec!["--out_polygons".to_owned()],
+                description: "Optional output vector polygons file of flood extents.".to_owned(),
+                parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                    VectorGeometryType::Polygon,
+                )),
+                default_value: None,
+                optional: true,
+            },
+            ToolParameter {
+                name: "Output Rating Curves File".to_owned(),
+                flags: vec!["--rating_curves".to_owned()],
+                description: "Optional output CSV file of the synthetic rating curves.".to_owned(),
+                parameter_type: ParameterType::NewFile(ParameterFileType::Csv),
+                default_value: None,
+                optional: true,
+            },
+        ];
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" --hand=hand.tif --d8_pntr=pntr.tif --linkid=links.tif --link_slope=link_slope.tif --values=flows.csv -o=depth.tif --out_polygons=extent.shp
+>>.*{0} -r={1} -v --wd=\"*path*to*data*\" --hand=hand.tif --d8_pntr=pntr.tif --linkid=links.tif --link_slope=link_slope.tif --values=stages.csv --value_type=stage --mannings_n=0.035 -o=depth.tif --rating_curves=curves.csv", short_exe, name).replace("*", &sep);
+
+        FloodInundationMap {
+            name,
+            description,
+            toolbox,
+            parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl Default for FloodInundationMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WhiteboxTool for FloodInundationMap {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run(
+        &self,
+        args: Vec<String>,
+        working_directory: &str,
+        context: &ToolContext,
+    ) -> Result<(), Error> {
+        let verbose = context.verbose;
+        let mut hand_file = String::new();
+        let mut d8_file = String::new();
+        let mut linkid_file = String::new();
+        let mut slope_file = String::new();
+        let mut values_file = String::new();
+        let mut output_file = String::new();
+        let mut polygons_file = String::new();
+        let mut curves_file = String::new();
+        let mut value_type = String::from("flow");
+        let mut mannings_n = 0.05f64;
+        let mut stage_step = 0.25f64;
+        let mut max_stage = 20f64;
+        let mut esri_style = false;
+
+        if args.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no parameters.",
+            ));
+        }
+        for i in 0..args.len() {
+            let mut arg = args[i].replace("\"", "");
+            arg = arg.replace("\'", "");
+            let cmd = arg.split("="); // in case an equals sign was used
+            let vec = cmd.collect::<Vec<&str>>();
+            let mut keyval = false;
+            if vec.len() > 1 {
+                keyval = true;
+            }
+            let flag_val = vec[0].to_lowercase().replace("--", "-");
+            if flag_val == "-hand" {
+                hand_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-d8_pntr" {
+                d8_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-linkid" {
+                linkid_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-link_slope" {
+                slope_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-values" {
+                values_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-value_type" {
+                value_type = if keyval {
+                    vec[1].to_lowercase()
+                } else {
+                    args[i + 1].to_lowercase()
+                };
+            } else if flag_val == "-mannings_n" {
+                mannings_n = if keyval {
+                    vec[1]
+                        .to_string()
+                        .parse::<f64>()
+                        .unwrap_or_else(|_| panic!("Error parsing {}", flag_val))
+                } else {
+                    args[i + 1]
+                        .to_string()
+                        .parse::<f64>()
+                        .unwrap_or_else(|_| panic!("Error parsing {}", flag_val))
+                };
+            } else if flag_val == "-stage_step" {
+                stage_step = if keyval {
+                    vec[1]
+                        .to_string()
+                        .parse::<f64>()
+                        .unwrap_or_else(|_| panic!("Error parsing {}", flag_val))
+                } else {
+                    args[i + 1]
+                        .to_string()
+                        .parse::<f64>()
+                        .unwrap_or_else(|_| panic!("Error parsing {}", flag_val))
+                };
+            } else if flag_val == "-max_stage" {
+                max_stage = if keyval {
+                    vec[1]
+                        .to_string()
+                        .parse::<f64>()
+                        .unwrap_or_else(|_| panic!("Error parsing {}", flag_val))
+                } else {
+                    args[i + 1]
+                        .to_string()
+                        .parse::<f64>()
+                        .unwrap_or_else(|_| panic!("Error parsing {}", flag_val))
+                };
+            } else if flag_val == "-esri_pntr" || flag_val == "-esri_style" {
+                if vec.len() == 1 || !vec[1].to_string().to_lowercase().contains("false") {
+                    esri_style = true;
+                }
+            } else if flag_val == "-o" || flag_val == "-output" {
+                output_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-out_polygons" {
+                polygons_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-rating_curves" {
+                curves_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            }
+        }
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28);
+            // 28 = length of the 'Powered by' by statement.
+            context.info("*".repeat(welcome_len));
+            context.info(format!(
+                "* Welcome to {} {}*",
+                tool_name,
+                " ".repeat(welcome_len - 15 - tool_name.len())
+            ));
+            context.info(format!(
+                "* Powered by WhiteboxTools {}*",
+                " ".repeat(welcome_len - 28)
+            ));
+            context.info(format!(
+                "* www.whiteboxgeo.com {}*",
+                " ".repeat(welcome_len - 23)
+            ));
+            context.info("*".repeat(welcome_len));
+        }
+
+        let flow_values = if value_type.contains("flow") {
+            true
+        } else if value_type.contains("stage") {
+            false
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The --value_type parameter must be one of 'stage' and 'flow'.",
+            ));
+        };
+        if mannings_n <= 0f64 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Manning's n must be greater than zero.",
+            ));
+        }
+        if stage_step <= 0f64 || max_stage < stage_step {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The stage step must be greater than zero and no larger than the maximum stage.",
+            ));
+        }
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        if !hand_file.contains(&sep) && !hand_file.contains("/") {
+            hand_file = format!("{}{}", working_directory, hand_file);
+        }
+        if !d8_file.contains(&sep) && !d8_file.contains("/") {
+            d8_file = format!("{}{}", working_directory, d8_file);
+        }
+        if !linkid_file.contains(&sep) && !linkid_file.contains("/") {
+            linkid_file = format!("{}{}", working_directory, linkid_file);
+        }
+        if !slope_file.contains(&sep) && !slope_file.contains("/") {
+            slope_file = format!("{}{}", working_directory, slope_file);
+        }
+        if !values_file.contains(&sep) && !values_file.contains("/") {
+            values_file = format!("{}{}", working_directory, values_file);
+        }
+        if !output_file.contains(&sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+        if !polygons_file.is_empty()
+            && !polygons_file.contains(&sep)
+            && !polygons_file.contains("/")
+        {
+            polygons_file = format!("{}{}", working_directory, polygons_file);
+        }
+        if !curves_file.is_empty() && !curves_file.contains(&sep) && !curves_file.contains("/") {
+            curves_file = format!("{}{}", working_directory, curves_file);
+        }
+
+        if verbose {
+            context.info("Reading data...")
+        };
+        let hand = Raster::new(&hand_file, "r")?;
+        let pntr = Raster::new(&d8_file, "r")?;
+        let links = Raster::new(&linkid_file, "r")?;
+        let link_slope = Raster::new(&slope_file, "r")?;
+
+        let start = Instant::now();
+
+        let rows = hand.configs.rows as isize;
+        let columns = hand.configs.columns as isize;
+        let hand_nodata = hand.configs.nodata;
+        let pntr_nodata = pntr.configs.nodata;
+        let links_nodata = links.configs.nodata;
+        let slope_nodata = link_slope.configs.nodata;
+
+        // make sure the input files have the same size
+        for configs in &[&pntr.configs, &links.configs, &link_slope.configs] {
+            if configs.rows != hand.configs.rows || configs.columns != hand.configs.columns {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input files must have the same number of rows and columns and spatial extent.",
+                ));
+            }
+        }
+
+        // Rating curves are calculated in metres, even for rasters in geographic coordinates.
+        let mut cell_size_x = hand.configs.resolution_x;
+        let mut cell_size_y = hand.configs.resolution_y;
+        if hand.is_in_geographic_coordinates() {
+            let mid_lat = ((hand.configs.north + hand.configs.south) / 2.0).to_radians();
+            cell_size_x *= 111320.0 * mid_lat.cos();
+            cell_size_y *= 111320.0;
+        }
+        let diag_cell_size = (cell_size_x * cell_size_x + cell_size_y * cell_size_y).sqrt();
+        let grid_lengths = [
+            diag_cell_size,
+            cell_size_x,
+            diag_cell_size,
+            cell_size_y,
+            diag_cell_size,
+            cell_size_x,
+            diag_cell_size,
+            cell_size_y,
+        ];
+        let cell_area = cell_size_x * cell_size_y;
+
+        // Create a mapping from the pointer values to cells offsets.
+        let mut pntr_matches: [i8; 129] = [0i8; 129];
+        if !esri_style {
+            // This maps Whitebox-style D8 pointer values
+            // onto the cell offsets in dx and dy.
+            pntr_matches[1] = 0i8;
+            pntr_matches[2] = 1i8;
+            pntr_matches[4] = 2i8;
+            pntr_matches[8] = 3i8;
+            pntr_matches[16] = 4i8;
+            pntr_matches[32] = 5i8;
+            pntr_matches[64] = 6i8;
+            pntr_matches[128] = 7i8;
+        } else {
+            // This maps Esri-style D8 pointer values
+            // onto the cell offsets in dx and dy.
+            pntr_matches[1] = 1i8;
+            pntr_matches[2] = 2i8;
+            pntr_matches[4] = 3i8;
+            pntr_matches[8] = 4i8;
+            pntr_matches[16] = 5i8;
+            pntr_matches[32] = 6i8;
+            pntr_matches[64] = 7i8;
+            pntr_matches[128] = 0i8;
+        }
+        let dx = [1, 1, 1, 0, -1, -1, -1, 0];
+        let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
+
+        // Find the reaches, along with their lengths and slopes.
+        let mut flow_dir: Array2D<i8> = Array2D::new(rows, columns, -2, -2)?;
+        let mut reach_index: HashMap<i64, usize> = HashMap::new();
+        let mut reach_ids: Vec<i64> = vec![];
+        let mut reach_lengths: Vec<f64> = vec![];
+        let mut reach_slopes: Vec<f64> = vec![];
+        // Each cell's catchment holds the index of its reach plus one, zero if it doesn't
+        // drain to a stream, and -1 if it isn't yet known.
+        let mut catchment: Array2D<i32> = Array2D::new(rows, columns, -1, 0)?;
+        let mut z: f64;
+        for row in 0..rows {
+            for col in 0..columns {
+                z = pntr.get_value(row, col);
+                if z != pntr_nodata {
+                    if z > 0.0 {
+                        flow_dir.set_value(row, col, pntr_matches[z as usize]);
+                    } else {
+                        flow_dir.set_value(row, col, -1i8);
+                    }
+                }
+                z = links.get_value(row, col);
+                if z != links_nodata && z > 0.0 {
+                    let id = z.round() as i64;
+                    let reach = *reach_index.entry(id).or_insert_with(|| {
+                        reach_ids.push(id);
+                        reach_lengths.push(0f64);
+                        reach_slopes.push(f64::NAN);
+                        reach_ids.len() - 1
+                    });
+                    let dir = flow_dir.get_value(row, col);
+                    reach_lengths[reach] += if dir >= 0 {
+                        grid_lengths[dir as usize]
+                    } else {
+                        (cell_size_x + cell_size_y) / 2.0
+                    };
+                    z = link_slope.get_value(row, col);
+                    if z != slope_nodata && reach_slopes[reach].is_nan() {
+                        reach_slopes[reach] = z.to_radians().tan();
+                    }
+                    catchment.set_value(row, col, reach as i32 + 1);
+                } else if hand.get_value(row, col) == hand_nodata {
+                    catchment.set_value(row, col, 0);
+                }
+            }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Finding reaches", progress)?;
+                old_progress = progress;
+            }
+        }
+        drop(pntr);
+        drop(links);
+        drop(link_slope);
+
+        let num_reaches = reach_ids.len();
+        if num_reaches == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The stream link ID raster does not contain any stream links.",
+            ));
+        }
+        let mut num_flat_reaches = 0;
+        for slope in reach_slopes.iter_mut() {
+            // Manning's equation needs a non-zero slope; level reaches are given a minimal one.
+            if slope.is_nan() || *slope < 0.00001 {
+                *slope = 0.00001;
+                num_flat_reaches += 1;
+            }
+        }
+
+        // Assign each cell to the reach that it drains to.
+        let mut path: Vec<(isize, isize)> = vec![];
+        for row in 0..rows {
+            for col in 0..columns {
+                if catchment.get_value(row, col) != -1 {
+                    continue;
+                }
+                let (mut y, mut x) = (row, col);
+                let mut reach = 0;
+                loop {
+                    let value = catchment.get_value(y, x);
+                    if value != -1 {
+                        reach = value;
+                        break;
+                    }
+                    path.push((y, x));
+                    let dir = flow_dir.get_value(y, x);
+                    if dir < 0 {
+                        break;
+                    }
+                    y += dy[dir as usize];
+                    x += dx[dir as usize];
+                }
+                for (y, x) in path.drain(..) {
+                    catchment.set_value(y, x, reach);
+                }
+            }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Mapping reach catchments", progress)?;
+                old_progress = progress;
+            }
+        }
+        drop(flow_dir);
+
+        // Tally the catchment cells by the first stage that inundates them. The bed area
+        // of a cell is its planimetric area adjusted for the gradient of the HAND surface.
+        let num_stages = (max_stage / stage_step).ceil() as usize;
+        let stride = num_stages + 1;
+        let mut cell_counts = vec![0f64; num_reaches * stride];
+        let mut hand_sums = vec![0f64; num_reaches * stride];
+        let mut bed_areas = vec![0f64; num_reaches * stride];
+        let neighbour = |row: isize, col: isize, centre: f64| -> f64 {
+            let z = hand.get_value(row, col);
+            if z != hand_nodata {
+                z
+            } else {
+                centre
+            }
+        };
+        for row in 0..rows {
+            for col in 0..columns {
+                let reach = catchment.get_value(row, col);
+                z = hand.get_value(row, col);
+                if reach <= 0 || z == hand_nodata {
+                    continue;
+                }
+                let z = z.max(0f64);
+                let stage = (z / stage_step).floor() as usize + 1;
+                if stage > num_stages {
+                    continue;
+                }
+                let dzdx =
+                    (neighbour(row, col + 1, z) - neighbour(row, col - 1, z)) / (2.0 * cell_size_x);
+                let dzdy =
+                    (neighbour(row - 1, col, z) - neighbour(row + 1, col, z)) / (2.0 * cell_size_y);
+                let i = (reach as usize - 1) * stride + stage;
+                cell_counts[i] += 1f64;
+                hand_sums[i] += z;
+                bed_areas[i] += cell_area * (1.0 + dzdx * dzdx + dzdy * dzdy).sqrt();
+            }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Building rating curves", progress)?;
+                old_progress = progress;
+            }
+        }
+
+        // Build the synthetic rating curves by accumulating the tallies up the stages.
+        let mut curves = vec![RatingPoint::default(); num_reaches * stride];
+        for reach in 0..num_reaches {
+            let (mut count, mut hand_sum, mut bed_area) = (0f64, 0f64, 0f64);
+            for k in 1..=num_stages {
+                let i = reach * stride + k;
+                count += cell_counts[i];
+                hand_sum += hand_sums[i];
+                bed_area += bed_areas[i];
+                let stage = k as f64 * stage_step;
+                let volume = (count * stage - hand_sum) * cell_area;
+                let area = volume / reach_lengths[reach];
+                let wetted_perimeter = bed_area / reach_lengths[reach];
+                let hydraulic_radius = if wetted_perimeter > 0f64 {
+                    area / wetted_perimeter
+                } else {
+                    0f64
+                };
+                curves[i] = RatingPoint {
+                    stage,
+                    num_cells: count as usize,
+                    surface_area: count * cell_area,
+                    bed_area,
+                    volume,
+                    area,
+                    wetted_perimeter,
+                    hydraulic_radius,
+                    discharge: area * hydraulic_radius.powf(2.0 / 3.0) * reach_slopes[reach].sqrt()
+                        / mannings_n,
+                };
+            }
+        }
+        drop(cell_counts);
+        drop(hand_sums);
+        drop(bed_areas);
+
+        // Read the reach values and convert them into stages.
+        if verbose {
+            context.info("Reading reach values...")
+        };
+        let values = read_reach_values(&values_file)?;
+        let mut reach_stages = vec![0f64; num_reaches];
+        let mut reach_flows = vec![0f64; num_reaches];
+        let mut num_unknown_reaches = 0;
+        let mut num_exceeding_reaches = 0;
+        for (id, value) in &values {
+            let reach = match reach_index.get(id) {
+                Some(reach) => *reach,
+                None => {
+                    num_unknown_reaches += 1;
+                    continue;
+                }
+            };
+            let curve = &curves[reach * stride..(reach + 1) * stride];
+            if flow_values {
+                reach_flows[reach] = *value;
+                reach_stages[reach] = if *value <= 0f64 {
+                    0f64
+                } else if *value > curve[num_stages].discharge {
+                    num_exceeding_reaches += 1;
+                    curve[num_stages].stage
+                } else {
+                    let mut stage = 0f64;
+                    for k in 1..=num_stages {
+                        if curve[k].discharge >= *value {
+                            let (q0, q1) = (curve[k - 1].discharge, curve[k].discharge);
+                            let (h0, h1) = (curve[k - 1].stage, curve[k].stage);
+                            stage = if q1 > q0 {
+                                h0 + (value - q0) / (q1 - q0) * (h1 - h0)
+                            } else {
+                                h1
+                            };
+                            break;
+                        }
+                    }
+                    stage
+                };
+            } else {
+                let stage = value.max(0f64).min(curve[num_stages].stage);
+                if *value > curve[num_stages].stage {
+                    num_exceeding_reaches += 1;
+                }
+                reach_stages[reach] = stage;
+                // The discharge at the stage is interpolated from the rating curve.
+                let k = ((stage / stage_step).ceil() as usize)
+                    .max(1)
+                    .min(num_stages);
+                let (q0, q1) = (curve[k - 1].discharge, curve[k].discharge);
+                let (h0, h1) = (curve[k - 1].stage, curve[k].stage);
+                reach_flows[reach] = q0 + (stage - h0) / (h1 - h0) * (q1 - q0);
+            }
+        }
+
+        // Map the inundation depths.
+        let out_nodata = -32768f64;
+        let mut output = Raster::initialize_using_file(&output_file, &hand);
+        output.widen_to_f64();
+        output.configs.nodata = out_nodata;
+        output.configs.data_type = DataType::F32;
+        output.configs.photometric_interp = PhotometricInterpretation::Continuous;
+        output.reinitialize_values(out_nodata);
+        let mut flooded_cells = vec![0usize; num_reaches];
+        let mut max_depths = vec![0f64; num_reaches];
+        for row in 0..rows {
+            for col in 0..columns {
+                let reach = catchment.get_value(row, col);
+                z = hand.get_value(row, col);
+                if reach <= 0 || z == hand_nodata {
+                    continue;
+                }
+                let reach = reach as usize - 1;
+                let depth = reach_stages[reach] - z.max(0f64);
+                if depth > 0f64 {
+                    output.set_value(row, col, depth);
+                    flooded_cells[reach] += 1;
+                    if depth > max_depths[reach] {
+                        max_depths[reach] = depth;
+                    }
+                } else {
+                    output.set_value(row, col, 0f64);
+                }
+            }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Mapping inundation depths", progress)?;
+                old_progress = progress;
+            }
+        }
+
+        if !polygons_file.is_empty() {
+            if verbose {
+                context.info("Tracing flood extents...")
+            };
+            let polygons = trace_grid_polygons(rows, columns, |row, col| {
+                let reach = catchment.get_value(row, col);
+                if reach > 0 && output.get_value(row, col) > 0f64 {
+                    reach as i64
+                } else {
+                    0
+                }
+            });
+            let mut extents = Shapefile::new(&polygons_file, ShapeType::Polygon)?;
+            extents.projection = hand.configs.coordinate_ref_system_wkt.clone();
+            extents.attributes.add_field(&AttributeField::new(
+                "FID",
+                FieldDataType::Int,
+                10u8,
+                0u8,
+            ));
+            extents.attributes.add_field(&AttributeField::new(
+                "REACH_ID",
+                FieldDataType::Int,
+                10u8,
+                0u8,
+            ));
+            extents.attributes.add_field(&AttributeField::new(
+                "STAGE",
+                FieldDataType::Real,
+                12u8,
+                4u8,
+            ));
+            extents.attributes.add_field(&AttributeField::new(
+                "FLOW",
+                FieldDataType::Real,
+                16u8,
+                4u8,
+            ));
+            extents.attributes.add_field(&AttributeField::new(
+                "AREA",
+                FieldDataType::Real,
+                16u8,
+                2u8,
+            ));
+            extents.attributes.add_field(&AttributeField::new(
+                "MAX_DEPTH",
+                FieldDataType::Real,
+                12u8,
+                4u8,
+            ));
+            let west = hand.configs.west;
+            let north = hand.configs.north;
+            let res_x = hand.configs.resolution_x;
+            let res_y = hand.configs.resolution_y;
+            for (fid, polygon) in polygons.iter().enumerate() {
+                let reach = polygon.id as usize - 1;
+                let mut geometry = ShapefileGeometry::new(ShapeType::Polygon);
+                for ring in &polygon.rings {
+                    let points: Vec<Point2D> = ring
+                        .iter()
+                        .map(|(r, c)| {
+                            Point2D::new(west + *c as f64 * res_x, north - *r as f64 * res_y)
+                        })
+                        .collect();
+                    geometry.add_part(&points);
+                }
+                extents.add_record(geometry);
+                extents.attributes.add_record(
+                    vec![
+                        FieldData::Int(fid as i32 + 1),
+                        FieldData::Int(reach_ids[reach] as i32),
+                        FieldData::Real(reach_stages[reach]),
+                        FieldData::Real(reach_flows[reach]),
+                        FieldData::Real(flooded_cells[reach] as f64 * cell_area),
+                        FieldData::Real(max_depths[reach]),
+                    ],
+                    false,
+                );
+            }
+            if verbose {
+                context.info("Saving extent polygons...")
+            };
+            extents.write()?;
+        }
+
+        if !curves_file.is_empty() {
+            let f = File::create(&curves_file)?;
+            let mut writer = BufWriter::new(f);
+            writeln!(writer, "REACH_ID,STAGE,NUM_CELLS,SURFACE_AREA,BED_AREA,VOLUME,AREA,WETTED_PERIMETER,HYDRAULIC_RADIUS,SLOPE,LENGTH,DISCHARGE")?;
+            for reach in 0..num_reaches {
+                for k in 1..=num_stages {
+                    let p = &curves[reach * stride + k];
+                    writeln!(
+                        writer,
+                        "{},{},{},{},{},{},{},{},{},{},{},{}",
+                        reach_ids[reach],
+                        p.stage,
+                        p.num_cells,
+                        p.surface_area,
+                        p.bed_area,
+                        p.volume,
+                        p.area,
+                        p.wetted_perimeter,
+                        p.hydraulic_radius,
+                        reach_slopes[reach],
+                        reach_lengths[reach],
+                        p.discharge
+                    )?;
+                }
+            }
+            writer.flush()?;
+        }
+
+        let flooded_area = flooded_cells.iter().sum::<usize>() as f64 * cell_area;
+        context.set_result("num_reaches", num_reaches);
+        context.set_result("num_mapped_reaches", values.len() - num_unknown_reaches);
+        context.set_result("flooded_area", flooded_area);
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("HAND file: {}", hand_file));
+        output.add_metadata_entry(format!("D8 pointer file: {}", d8_file));
+        output.add_metadata_entry(format!("Link ID file: {}", linkid_file));
+        output.add_metadata_entry(format!("Link slope file: {}", slope_file));
+        output.add_metadata_entry(format!("Values file: {}", values_file));
+        output.add_metadata_entry(format!(
+            "Value type: {}",
+            if flow_values { "flow" } else { "stage" }
+        ));
+        output.add_metadata_entry(format!("Manning's n: {}", mannings_n));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            context.info("Saving data...")
+        };
+        match output.write() {
+            Ok(_) => {
+                if verbose {
+                    context.info("Output file written")
+                }
+            }
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            context.info(format!("Flooded area: {:.2}", flooded_area));
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        if num_unknown_reaches > 0 {
+            context.warning(format!(
+                "WARNING: {} of the reach IDs in the values file were not found in the stream link ID raster.",
+                num_unknown_reaches
+            ));
+        }
+        if num_exceeding_reaches > 0 {
+            context.warning(format!(
+                "WARNING: The values of {} reaches exceed their rating curves and were mapped at the maximum stage ({}).",
+                num_exceeding_reaches,
+                num_stages as f64 * stage_step
+            ));
+        }
+        if num_flat_reaches > 0 {
+            context.warning(format!(
+                "WARNING: {} reaches had no positive slope and were assigned a minimal slope.",
+                num_flat_reaches
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// One stage of a reach's synthetic rating curve.
+#[derive(Clone, Copy, Default)]
+struct RatingPoint {
+    stage: f64,
+    num_cells: usize,
+    surface_area: f64,
+    bed_area: f64,
+    volume: f64,
+    area: f64,
+    wetted_perimeter: f64,
+    hydraulic_radius: f64,
+    discharge: f64,
+}
+
+/// Reads the reach IDs and values from a delimited text file, skipping a header row if present.
+fn read_reach_values(file_name: &str) -> Result<Vec<(i64, f64)>, Error> {
+    let f = File::open(file_name)?;
+    let f = BufReader::new(f);
+    let mut values = vec![];
+    for (line_num, line) in f.lines().enumerate() {
+        let line = line?;
+        let line_vec: Vec<&str> = line
+            .split(&[',', ';', '\t', ' '][..])
+            .filter(|s| !s.trim().is_empty())
+            .collect();
+        if line_vec.is_empty() {
+            continue;
+        }
+        let id = line_vec[0].trim().parse::<f64>();
+        let value = if line_vec.len() > 1 {
+            line_vec[1].trim().parse::<f64>().ok()
+        } else {
+            None
+        };
+        match (id, value) {
+            (Ok(id), Some(value)) => values.push((id.round() as i64, value)),
+            _ if line_num == 0 => {} // header row
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "Error parsing line {} of the values file; each row must contain a reach ID and a value.",
+                        line_num + 1
+                    ),
+                ))
+            }
+        }
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod test {
+    use super::read_reach_values;
+    use crate::tools::{ToolManager, ToolReport};
+    use std::env;
+    use std::f64;
+    use std::fs;
+    use whitebox_raster::*;
+
+    const ROWS: isize = 10;
+    const COLUMNS: isize = 21;
+    const STREAM: isize = 10;
+    const SLOPE: f64 = 0.01;
+    const MANNINGS_N: f64 = 0.05;
+
+    fn write_raster(file_name: &str, value: impl Fn(isize, isize) -> f64) {
+        let configs = RasterConfigs {
+            rows: ROWS as usize,
+            columns: COLUMNS as usize,
+            north: 4_000_010.0,
+            south: 4_000_000.0,
+            east: 500_021.0,
+            west: 500_000.0,
+            resolution_x: 1.0,
+            resolution_y: 1.0,
+            nodata: -32768.0,
+            data_type: DataType::F64,
+            ..Default::default()
+        };
+        let mut raster = Raster::initialize_using_config(file_name, &configs);
+        for row in 0..ROWS {
+            for col in 0..COLUMNS {
+                raster.set_value(row, col, value(row, col));
+            }
+        }
+        raster.write().unwrap();
+    }
+
+    // Writes the inputs for a straight, V-shaped valley of 1 m cells, whose sides rise 1 m
+    // per cell on either side of a stream that flows north along the middle column.
+    fn write_v_channel(prefix: &str) {
+        write_raster(&format!("mem://{}_hand", prefix), |_, col| {
+            (col - STREAM).abs() as f64
+        });
+        write_raster(&format!("mem://{}_pntr", prefix), |row, col| {
+            if col < STREAM {
+                2.0 // east
+            } else if col > STREAM {
+                32.0 // west
+            } else if row > 0 {
+                128.0 // north
+            } else {
+                0.0
+            }
+        });
+        write_raster(&format!("mem://{}_links", prefix), |_, col| {
+            if col == STREAM {
+                1.0
+            } else {
+                0.0
+            }
+        });
+        write_raster(&format!("mem://{}_slope", prefix), |_, _| {
+            SLOPE.atan().to_degrees()
+        });
+    }
+
+    fn remove_v_channel(prefix: &str) {
+        for suffix in &["hand", "pntr", "links", "slope", "depth"] {
+            MEMORY_RASTERS.remove(&format!("mem://{}_{}", prefix, suffix));
+        }
+    }
+
+    // The discharge of the valley at the stages of the rating curve with a 0.5 m step. The
+    // stream cell is flooded from the first stage and the cells beside it from the third,
+    // which gives a volume of 5, 10, 25 and 40 m^3 over the 10 m reach. The bed of each
+    // sloping cell is sqrt(2) m^2.
+    fn discharge(stage: usize) -> f64 {
+        let (volume, bed_area) = match stage {
+            1 => (5.0, 10.0),
+            2 => (10.0, 10.0),
+            3 => (25.0, 10.0 + 20.0 * 2f64.sqrt()),
+            _ => (40.0, 10.0 + 20.0 * 2f64.sqrt()),
+        };
+        let area = volume / 10.0;
+        let hydraulic_radius = area / (bed_area / 10.0);
+        area * hydraulic_radius.powf(2.0 / 3.0) * SLOPE.sqrt() / MANNINGS_N
+    }
+
+    fn run(prefix: &str, value_type: &str, values: &str) -> (ToolReport, String) {
+        let dir = env::temp_dir();
+        let values_file = dir.join(format!("{}_values.csv", prefix));
+        let curves_file = dir.join(format!("{}_curves.csv", prefix));
+        fs::write(&values_file, values).unwrap();
+        let tm = ToolManager::new(&format!("{}/", dir.to_string_lossy()), &false).unwrap();
+        let report = tm.run_tool_report(
+            "FloodInundationMap",
+            vec![
+                format!("--hand=mem://{}_hand", prefix),
+                format!("--d8_pntr=mem://{}_pntr", prefix),
+                format!("--linkid=mem://{}_links", prefix),
+                format!("--link_slope=mem://{}_slope", prefix),
+                format!("--values={}", values_file.to_string_lossy()),
+                format!("--value_type={}", value_type),
+                format!("--mannings_n={}", MANNINGS_N),
+                "--stage_step=0.5".to_string(),
+                "--max_stage=1.8".to_string(),
+                format!("-o=mem://{}_depth", prefix),
+                format!("--rating_curves={}", curves_file.to_string_lossy()),
+            ],
+        );
+        let curves = fs::read_to_string(&curves_file).unwrap_or_default();
+        let _ = fs::remove_file(&values_file);
+        let _ = fs::remove_file(&curves_file);
+        (report, curves)
+    }
+
+    fn depths(prefix: &str) -> Vec<f64> {
+        let output = Raster::new(&format!("mem://{}_depth", prefix), "r").unwrap();
+        (0..COLUMNS).map(|col| output.get_value(5, col)).collect()
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() <= 1e-9 * expected.abs().max(1.0),
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_v_channel_rating_curve() {
+        write_v_channel("fim_curve");
+        // a flow midway between those of the third and fourth stages
+        let flow = (discharge(3) + discharge(4)) / 2.0;
+        let (report, curves) = run("fim_curve", "flow", &format!("REACH,FLOW\n1,{}\n", flow));
+        assert!(report.is_ok(), "{:?}", report.error);
+
+        // the curve rises to the stage step above the maximum stage
+        let lines: Vec<Vec<f64>> = curves
+            .lines()
+            .skip(1)
+            .map(|line| line.split(',').map(|v| v.parse().unwrap()).collect())
+            .collect();
+        assert_eq!(lines.len(), 4);
+        for (k, line) in lines.iter().enumerate() {
+            assert_eq!(line[0], 1.0);
+            assert_close(line[1], (k + 1) as f64 * 0.5);
+            assert_close(line[11], discharge(k + 1));
+        }
+        let top = &lines[3];
+        assert_eq!(top[2], 30.0); // cells
+        assert_close(top[5], 40.0); // volume
+        assert_close(top[6], 4.0); // cross-sectional area
+        assert_close(top[7], 1.0 + 2.0 * 2f64.sqrt()); // wetted perimeter
+        assert_close(top[9], SLOPE);
+        assert_close(top[10], 10.0); // length
+
+        // the flow is mapped at a stage of 1.75 m
+        let depths = depths("fim_curve");
+        assert_close(depths[STREAM as usize], 1.75);
+        assert_close(depths[STREAM as usize - 1], 0.75);
+        assert_close(depths[STREAM as usize + 1], 0.75);
+        assert_eq!(depths[STREAM as usize - 2], 0.0);
+        assert_eq!(report.results["flooded_area"], 30.0);
+        assert!(report.warnings.is_empty(), "{:?}", report.warnings);
+        remove_v_channel("fim_curve");
+    }
+
+    #[test]
+    fn test_v_channel_values() {
+        write_v_channel("fim_values");
+        // stages are mapped directly, and unknown reaches are reported
+        let (report, _) = run("fim_values", "stage", "1 0.4\n7 1.0\n");
+        assert!(report.is_ok(), "{:?}", report.error);
+        let stage_depths = depths("fim_values");
+        assert_close(stage_depths[STREAM as usize], 0.4);
+        assert_eq!(stage_depths[STREAM as usize - 1], 0.0);
+        assert_eq!(report.results["num_mapped_reaches"], 1);
+        assert_eq!(report.results["flooded_area"], 10.0);
+        assert!(report
+            .warnings
+            .iter()
+            .any(|w| w.contains("1 of the reach IDs")));
+
+        // flows beyond the curve are mapped at its top stage, which is named in the warning
+        let (report, _) = run("fim_values", "flow", &format!("1,{}\n", 2.0 * discharge(4)));
+        assert!(report.is_ok(), "{:?}", report.error);
+        assert_close(depths("fim_values")[STREAM as usize], 2.0);
+        assert!(
+            report
+                .warnings
+                .iter()
+                .any(|w| w.contains("1 reaches") && w.contains("maximum stage (2)")),
+            "{:?}",
+            report.warnings
+        );
+        remove_v_channel("fim_values");
+    }
+
+    #[test]
+    fn test_read_reach_values() {
+        let file_name = env::temp_dir().join(format!("fim_values_{}.csv", std::process::id()));
+        let file_name = file_name.to_string_lossy().to_string();
+        fs::write(&file_name, "ID;STAGE\n1;2.5\n\n2\t3\n 3 , 4.5 \n4.0 -1\n").unwrap();
+        assert_eq!(
+            read_reach_values(&file_name).unwrap(),
+            vec![(1, 2.5), (2, 3.0), (3, 4.5), (4, -1.0)]
+        );
+        // only the first row may be a header
+        fs::write(&file_name, "1,2.5\nID,STAGE\n").unwrap();
+        assert_eq!(
+            read_reach_values(&file_name).unwrap_err().kind(),
+            std::io::ErrorKind::InvalidData
+        );
+        let _ = fs::remove_file(&file_name);
+    }
+}
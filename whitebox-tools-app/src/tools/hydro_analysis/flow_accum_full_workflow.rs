@@ -174,8 +174,9 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut outdem_file = String::new();
         let mut pntr_file = String::new();
@@ -266,11 +267,11 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -292,7 +293,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -338,10 +339,10 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data = vec![nodata; columns as usize];
                     for col in 0..columns {
-                        z = input[(row, col)];
+                        z = input.get_value(row, col);
                         if z != nodata {
                             for c in 0..8 {
-                                n[c] = input[(row + dy[c], col + dx[c])];
+                                n[c] = input.get_value(row + dy[c], col + dx[c]);
                                 if n[c] != nodata {
                                     n[c] = n[c] * z_factor;
                                 } else {
@@ -359,7 +360,9 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                             }
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -369,12 +372,10 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
             let data = rx.recv().expect("Error receiving data from thread.");
             aspect.set_row_data(data.0, data.1);
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Calculating aspect: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Calculating aspect", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -383,6 +384,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
         let small_num = 1.0 / elev_multiplier as f64;
 
         let mut output = Raster::initialize_using_file(&outdem_file, &input);
+        output.widen_to_f64();
         let background_val = (i32::min_value() + 1) as f64;
         output.reinitialize_values(background_val);
 
@@ -438,11 +440,11 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
             for n in 0..8 {
                 row_n = row + dy[n];
                 col_n = col + dx[n];
-                zin_n = input[(row_n, col_n)];
-                zout_n = output[(row_n, col_n)];
+                zin_n = input.get_value(row_n, col_n);
+                zout_n = output.get_value(row_n, col_n);
                 if zout_n == background_val {
                     if zin_n == nodata {
-                        output[(row_n, col_n)] = nodata;
+                        output.set_value(row_n, col_n, nodata);
                         queue.push_back((row_n, col_n));
                     } else {
                         // see if it's the lowest of its neighbours
@@ -450,13 +452,13 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                         for p in 0..8 {
                             y = row_n + dy[p];
                             x = col_n + dx[p];
-                            if input[(y, x)] < zin_n && input[(y, x)] != nodata {
+                            if input.get_value(y, x) < zin_n && input.get_value(y, x) != nodata {
                                 is_lowest = false;
                                 break;
                             }
                         }
                         if is_lowest {
-                            output[(row_n, col_n)] = zin_n;
+                            output.set_value(row_n, col_n, zin_n);
                             // Push it onto the priority queue for the priority flood operation
                             minheap.push(GridCell {
                                 row: row_n,
@@ -470,12 +472,10 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -491,13 +491,13 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
             let cell = minheap.pop().expect("Error during pop operation.");
             row = cell.row;
             col = cell.column;
-            zout = output[(row, col)];
+            zout = output.get_value(row, col);
             for n in 0..8 {
                 row_n = row + dy[n];
                 col_n = col + dx[n];
-                zout_n = output[(row_n, col_n)];
+                zout_n = output.get_value(row_n, col_n);
                 if zout_n == background_val {
-                    zin_n = input[(row_n, col_n)];
+                    zin_n = input.get_value(row_n, col_n);
                     if zin_n != nodata {
                         flow_dir[(row_n, col_n)] = back_link[n];
 
@@ -505,7 +505,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                         // output[(row_n, col_n)] = zin_n;
                         // minheap.push(GridCell{ row: row_n, column: col_n, priority: zin_n });
 
-                        output[(row_n, col_n)] = zin_n;
+                        output.set_value(row_n, col_n, zin_n);
                         minheap.push(GridCell {
                             row: row_n,
                             column: col_n,
@@ -515,7 +515,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                             // Trace the flowpath back to a lower cell, if it exists.
                             x = col_n;
                             y = row_n;
-                            z_target = output[(row_n, col_n)];
+                            z_target = output.get_value(row_n, col_n);
                             flag = true;
                             while flag {
                                 dir = flow_dir[(y, x)];
@@ -523,8 +523,8 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                                     y += dy[dir as usize];
                                     x += dx[dir as usize];
                                     z_target -= small_num;
-                                    if output[(y, x)] > z_target {
-                                        output[(y, x)] = z_target;
+                                    if output.get_value(y, x) > z_target {
+                                        output.set_value(y, x, z_target);
                                     } else {
                                         flag = false;
                                     }
@@ -535,7 +535,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                         }
                     } else {
                         // Interior nodata cells are still treated as nodata and are not filled.
-                        output[(row_n, col_n)] = nodata;
+                        output.set_value(row_n, col_n, nodata);
                         num_solved_cells += 1;
                     }
                 } else if zout_n > zout && zout_n != nodata && aspect[(row_n, col_n)] != nodata {
@@ -574,13 +574,11 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                 }
             }
 
-            if verbose {
-                num_solved_cells += 1;
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            num_solved_cells += 1;
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -597,12 +595,12 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
         ));
 
         if verbose {
-            println!("Saving DEM data...")
+            context.info("Saving DEM data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -626,7 +624,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data: Vec<i8> = vec![-1i8; columns as usize];
                     for col in 0..columns {
-                        z = input[(row, col)];
+                        z = input.get_value(row, col);
                         if z != nodata {
                             count = 0i8;
                             for i in 0..8 {
@@ -639,12 +637,15 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                             data[col as usize] = -1i8;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
 
         let mut output = Raster::initialize_using_file(&accum_file, &input);
+        output.widen_to_f64();
         output.reinitialize_values(1.0);
         let mut stack = Vec::with_capacity((rows * columns) as usize);
         let mut num_solved_cells = 0;
@@ -659,12 +660,10 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Num. inflowing neighbours: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Num. inflowing neighbours", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -674,7 +673,7 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
             let cell = stack.pop().expect("Error during pop operation.");
             row = cell.0;
             col = cell.1;
-            fa = output[(row, col)];
+            fa = output.get_value(row, col);
             num_inflowing.decrement(row, col, 1i8);
             dir = flow_dir[(row, col)];
             if dir >= 0 {
@@ -687,13 +686,11 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
                 }
             }
 
-            if verbose {
-                num_solved_cells += 1;
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Flow accumulation: {}%", progress);
-                    old_progress = progress;
-                }
+            num_solved_cells += 1;
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Flow accumulation", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -724,54 +721,65 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
         if log_transform {
             for row in 0..rows {
                 for col in 0..columns {
-                    if input[(row, col)] == nodata {
-                        output[(row, col)] = nodata;
+                    if input.get_value(row, col) == nodata {
+                        output.set_value(row, col, nodata);
                     } else {
                         let dir = flow_dir[(row, col)];
                         if dir >= 0 {
-                            output[(row, col)] =
-                                (output[(row, col)] * cell_area / flow_widths[dir as usize]).ln();
-                            pntr[(row, col)] = pntr_vals[flow_dir[(row, col)] as usize];
+                            output.set_value(
+                                row,
+                                col,
+                                (output.get_value(row, col) * cell_area
+                                    / flow_widths[dir as usize])
+                                    .ln(),
+                            );
+                            pntr.set_value(row, col, pntr_vals[flow_dir[(row, col)] as usize]);
                         } else {
-                            output[(row, col)] =
-                                (output[(row, col)] * cell_area / flow_widths[3]).ln();
-                            pntr[(row, col)] = 0f64;
+                            output.set_value(
+                                row,
+                                col,
+                                (output.get_value(row, col) * cell_area / flow_widths[3]).ln(),
+                            );
+                            pntr.set_value(row, col, 0f64);
                         }
                     }
                 }
 
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Correcting values: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Correcting values", progress)?;
+                    old_progress = progress;
                 }
             }
         } else {
             for row in 0..rows {
                 for col in 0..columns {
-                    if input[(row, col)] == nodata {
-                        output[(row, col)] = nodata;
+                    if input.get_value(row, col) == nodata {
+                        output.set_value(row, col, nodata);
                     } else {
                         let dir = flow_dir[(row, col)];
                         if dir >= 0 {
-                            output[(row, col)] =
-                                output[(row, col)] * cell_area / flow_widths[dir as usize];
-                            pntr[(row, col)] = pntr_vals[flow_dir[(row, col)] as usize];
+                            output.set_value(
+                                row,
+                                col,
+                                output.get_value(row, col) * cell_area / flow_widths[dir as usize],
+                            );
+                            pntr.set_value(row, col, pntr_vals[flow_dir[(row, col)] as usize]);
                         } else {
-                            output[(row, col)] = output[(row, col)] * cell_area / flow_widths[3];
-                            pntr[(row, col)] = 0f64;
+                            output.set_value(
+                                row,
+                                col,
+                                output.get_value(row, col) * cell_area / flow_widths[3],
+                            );
+                            pntr.set_value(row, col, 0f64);
                         }
                     }
                 }
 
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Correcting values: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Correcting values", progress)?;
+                    old_progress = progress;
                 }
             }
         }
@@ -788,12 +796,12 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
         pntr.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving flow pointer data...")
+            context.info("Saving flow pointer data...")
         };
         let _ = match pntr.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -811,21 +819,18 @@ impl WhiteboxTool for FlowAccumulationFullWorkflow {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving accumulation data...")
+            context.info("Saving accumulation data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
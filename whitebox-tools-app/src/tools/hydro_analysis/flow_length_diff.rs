@@ -121,8 +121,9 @@ impl WhiteboxTool for FlowLengthDiff {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut d8_file = String::new();
         let mut output_file = String::new();
         let mut esri_style = false;
@@ -168,11 +169,11 @@ impl WhiteboxTool for FlowLengthDiff {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -188,7 +189,7 @@ impl WhiteboxTool for FlowLengthDiff {
         }
 
         if verbose {
-            println!("Reading pointer data...")
+            context.info("Reading pointer data...")
         };
         let pntr = Raster::new(&d8_file, "r")?;
         let rows = pntr.configs.rows as isize;
@@ -247,14 +248,14 @@ impl WhiteboxTool for FlowLengthDiff {
         let (mut x, mut y): (isize, isize);
         for row in 0..rows {
             for col in 0..columns {
-                if pntr[(row, col)] >= 0.0 && pntr[(row, col)] != nodata {
+                if pntr.get_value(row, col) >= 0.0 && pntr.get_value(row, col) != nodata {
                     dist = 0f64;
                     flag = false;
                     x = col;
                     y = row;
                     while !flag {
                         // find its downslope neighbour
-                        dir = pntr[(y, x)];
+                        dir = pntr.get_value(y, x);
                         if dir > 0f64 && dir != nodata {
                             if dir > 128f64 || pntr_matches[dir as usize] == 999 {
                                 return Err(Error::new(ErrorKind::InvalidInput,
@@ -283,7 +284,7 @@ impl WhiteboxTool for FlowLengthDiff {
                         dfl[(y, x)] = dist;
 
                         // find its downslope neighbour
-                        dir = pntr[(y, x)];
+                        dir = pntr.get_value(y, x);
                         if dir > 0f64 && dir != nodata {
                             // move x and y accordingly
                             c = pntr_matches[dir as usize];
@@ -304,12 +305,10 @@ impl WhiteboxTool for FlowLengthDiff {
                     dfl[(row, col)] = out_nodata;
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -335,20 +334,18 @@ impl WhiteboxTool for FlowLengthDiff {
                         }
                     }
                     if max_abs_diff != f64::NEG_INFINITY {
-                        output[(row, col)] = max_abs_diff;
+                        output.set_value(row, col, max_abs_diff);
                     } else {
-                        output[(row, col)] = out_nodata;
+                        output.set_value(row, col, out_nodata);
                     }
                 } else {
-                    output[(row, col)] = out_nodata;
+                    output.set_value(row, col, out_nodata);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -364,21 +361,18 @@ impl WhiteboxTool for FlowLengthDiff {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
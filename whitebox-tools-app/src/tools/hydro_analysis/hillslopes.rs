@@ -143,8 +143,9 @@ impl WhiteboxTool for Hillslopes {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut d8_file = String::new();
         let mut streams_file = String::new();
         let mut output_file = String::new();
@@ -197,11 +198,11 @@ impl WhiteboxTool for Hillslopes {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -220,7 +221,7 @@ impl WhiteboxTool for Hillslopes {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let pntr = Raster::new(&d8_file, "r")?;
@@ -262,11 +263,11 @@ impl WhiteboxTool for Hillslopes {
         let mut current_id = 1f64;
         for row in 0..rows {
             for col in 0..columns {
-                if streams[(row, col)] > 0.0 && streams[(row, col)] != nodata {
+                if streams.get_value(row, col) > 0.0 && streams.get_value(row, col) != nodata {
                     count = 0i8;
                     for i in 0..8 {
-                        if streams[(row + dy[i], col + dx[i])] > 0.0
-                            && pntr[(row + dy[i], col + dx[i])] == inflowing_vals[i]
+                        if streams.get_value(row + dy[i], col + dx[i]) > 0.0
+                            && pntr.get_value(row + dy[i], col + dx[i]) == inflowing_vals[i]
                         {
                             count += 1;
                         }
@@ -280,7 +281,7 @@ impl WhiteboxTool for Hillslopes {
                         current_id += 1f64;
                     }
                 } else {
-                    if pntr[(row, col)] != pntr_nodata {
+                    if pntr.get_value(row, col) != pntr_nodata {
                         pourpts[(row, col)] = 0.0;
                     } else {
                         pourpts[(row, col)] = nodata;
@@ -288,12 +289,10 @@ impl WhiteboxTool for Hillslopes {
                     num_solved_cells += 1;
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Processing streams: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Processing streams", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -339,7 +338,7 @@ impl WhiteboxTool for Hillslopes {
             val = pourpts[(row, col)];
 
             // find the downstream cell
-            dir = pntr[(row, col)] as usize;
+            dir = pntr.get_value(row, col) as usize;
             if dir > 0 {
                 if dir > 128 || pntr_matches[dir] == 999 {
                     return Err(Error::new(ErrorKind::InvalidInput,
@@ -361,12 +360,10 @@ impl WhiteboxTool for Hillslopes {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Processing streams: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Processing streams", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -381,6 +378,7 @@ impl WhiteboxTool for Hillslopes {
 
         // Now perform the watershedding operation
         let mut output = Raster::initialize_using_file(&output_file, &streams);
+        output.widen_to_f64();
         output.configs.data_type = DataType::F32;
         output.configs.palette = "qual.plt".to_string();
         output.configs.photometric_interp = PhotometricInterpretation::Categorical;
@@ -390,20 +388,18 @@ impl WhiteboxTool for Hillslopes {
         let mut z: f64;
         for row in 0..rows {
             for col in 0..columns {
-                if pntr[(row, col)] == pntr_nodata {
-                    output[(row, col)] = nodata;
+                if pntr.get_value(row, col) == pntr_nodata {
+                    output.set_value(row, col, nodata);
                 }
                 z = pourpts[(row, col)];
                 if z != nodata && z > 0.0 {
-                    output[(row, col)] = z;
+                    output.set_value(row, col, z);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Watershedding (Loop 1 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Watershedding (Loop 1 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -412,20 +408,20 @@ impl WhiteboxTool for Hillslopes {
         let mut outlet_id: f64;
         for row in 0..rows {
             for col in 0..columns {
-                if output[(row, col)] == low_value {
+                if output.get_value(row, col) == low_value {
                     flag = false;
                     x = col;
                     y = row;
                     outlet_id = nodata;
                     while !flag {
-                        dir = pntr[(y, x)] as usize;
+                        dir = pntr.get_value(y, x) as usize;
                         if dir > 0 {
                             c = pntr_matches[dir];
                             y += dy[c];
                             x += dx[c];
 
                             // if the new cell already has a value in the output, use that as the outletID
-                            z = output[(y, x)];
+                            z = output.get_value(y, x);
                             if z != low_value {
                                 outlet_id = z;
                                 flag = true;
@@ -438,48 +434,44 @@ impl WhiteboxTool for Hillslopes {
                     flag = false;
                     x = col;
                     y = row;
-                    output[(y, x)] = outlet_id;
+                    output.set_value(y, x, outlet_id);
                     while !flag {
                         // find its downslope neighbour
-                        dir = pntr[(y, x)] as usize;
+                        dir = pntr.get_value(y, x) as usize;
                         if dir > 0 {
                             c = pntr_matches[dir];
                             y += dy[c];
                             x += dx[c];
 
                             // if the new cell already has a value in the output, use that as the outletID
-                            if output[(y, x)] != low_value {
+                            if output.get_value(y, x) != low_value {
                                 flag = true;
                             }
                         } else {
                             flag = true;
                         }
-                        output[(y, x)] = outlet_id;
+                        output.set_value(y, x, outlet_id);
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Watershedding (Loop 2 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Watershedding (Loop 2 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
         // Replace all stream cells with 0's
         for row in 0..rows {
             for col in 0..columns {
-                if streams[(row, col)] > 0f64 && streams[(row, col)] != nodata {
-                    output[(row, col)] = 0f64;
+                if streams.get_value(row, col) > 0f64 && streams.get_value(row, col) != nodata {
+                    output.set_value(row, col, 0f64);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Watershedding (Loop 1 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Watershedding (Loop 1 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -501,33 +493,36 @@ impl WhiteboxTool for Hillslopes {
         for row in 0..rows {
             for col in 0..columns {
                 if visited[(row, col)] > 0
-                    && pntr[(row, col)] != pntr_nodata
-                    && output[(row, col)] > 0f64
+                    && pntr.get_value(row, col) != pntr_nodata
+                    && output.get_value(row, col) > 0f64
                 {
                     current_id += 1f64;
-                    old_id = output[(row, col)];
+                    old_id = output.get_value(row, col);
                     stack.push((row, col));
                     while !stack.is_empty() {
                         let cell = stack.pop().expect("Error during pop operation.");
                         row2 = cell.0;
                         col2 = cell.1;
-                        output[(row2, col2)] = current_id;
+                        output.set_value(row2, col2, current_id);
                         visited[(row2, col2)] = 0;
 
                         for n in 0..8 {
                             y = row2 + dy[n];
                             x = col2 + dx[n];
-                            if output[(y, x)] == old_id && visited[(y, x)] > 0 {
+                            if output.get_value(y, x) == old_id && visited[(y, x)] > 0 {
                                 let diag = card1[n];
                                 if diag == 8 {
                                     // its a cardinal direction
                                     stack.push((y, x));
                                 } else {
                                     // clumping can't cross a stream via a diagonal
-                                    if streams[(row2 + dy[card2[diag]], col2 + dx[card2[diag]])]
+                                    if streams
+                                        .get_value(row2 + dy[card2[diag]], col2 + dx[card2[diag]])
                                         == 0f64
-                                        || streams[(row2 + dy[card3[diag]], col2 + dx[card3[diag]])]
-                                            == 0f64
+                                        || streams.get_value(
+                                            row2 + dy[card3[diag]],
+                                            col2 + dx[card3[diag]],
+                                        ) == 0f64
                                     {
                                         stack.push((y, x));
                                     }
@@ -537,12 +532,10 @@ impl WhiteboxTool for Hillslopes {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Grouping hillslopes: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Grouping hillslopes", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -556,21 +549,18 @@ impl WhiteboxTool for Hillslopes {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
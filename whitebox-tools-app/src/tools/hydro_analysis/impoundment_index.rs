@@ -156,8 +156,9 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut out_type = 0; // 0 = area; 1 = volume
@@ -228,11 +229,11 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -262,7 +263,7 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
         */
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         // let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -400,12 +401,10 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
                     crest_elev.set_value(row, col, nodata);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Calculating dam heights: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Calculating dam heights", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -499,12 +498,10 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Calculating flow directions: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Calculating flow directions", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -552,13 +549,11 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
                 stack.push((row, col));
             }
 
-            if verbose {
-                num_solved_cells += 1;
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Calculating flow directions: {}%", progress);
-                    old_progress = progress;
-                }
+            num_solved_cells += 1;
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Calculating flow directions", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -586,6 +581,7 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
         let mut diff: f64;
         let mut max_depth: f64;
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.widen_to_f64();
         output.reinitialize_values(0.0);
         while !stack.is_empty() {
             let cell = stack.pop().expect("Error during pop operation.");
@@ -644,13 +640,11 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
                 }
             }
 
-            if verbose {
-                num_solved_cells += 1;
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Calculating index: {}%", progress);
-                    old_progress = progress;
-                }
+            num_solved_cells += 1;
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Calculating index", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -676,12 +670,10 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
                     output.set_value(row, col, nodata);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Outputting dam heights: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Outputting dam heights", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -704,12 +696,12 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving index data...")
+            context.info("Saving index data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -730,22 +722,19 @@ impl WhiteboxTool for ImpoundmentSizeIndex {
         output_hgt.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving dam height data...")
+            context.info("Saving dam height data...")
         };
         let _ = match output_hgt.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
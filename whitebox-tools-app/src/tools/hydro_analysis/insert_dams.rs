@@ -142,8 +142,9 @@ impl WhiteboxTool for InsertDams {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut dam_file = String::new();
@@ -205,11 +206,11 @@ impl WhiteboxTool for InsertDams {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -225,7 +226,7 @@ impl WhiteboxTool for InsertDams {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r").expect("Error reading input DEM file.");
@@ -234,6 +235,7 @@ impl WhiteboxTool for InsertDams {
         let dam_pts = Shapefile::read(&dam_file).expect("Error reading input dam file.");
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.widen_to_f64();
         output
             .set_data_from_raster(&input)
             .expect("Error copying data to output file.");
@@ -444,17 +446,15 @@ impl WhiteboxTool for InsertDams {
             } else {
                 // No dam was found that covered the point
                 if verbose {
-                    println!("Warning: No dam could be identified for Point {} due to its position in non-impoundable terrain.", record_num+1);
+                    context.warning(format!("Warning: No dam could be identified for Point {} due to its position in non-impoundable terrain.", record_num+1));
                 }
             }
 
-            if verbose {
-                progress =
-                    (100.0_f64 * record_num as f64 / (dam_pts.num_records - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * record_num as f64 / (dam_pts.num_records - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -468,22 +468,19 @@ impl WhiteboxTool for InsertDams {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -2,10 +2,11 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 03/12/2017
-Last Modified: 24/07/2020
+Last Modified: 17/10/2026
 License: MIT
 */
 
+use super::basin_polygons::{contributing_area, trace_basin_polygons, write_basin_polygons};
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
 use crate::tools::*;
@@ -32,6 +33,10 @@ use std::thread;
 /// the event that there is no downstream basin, i.e. if it drains to an edge. The output CSV file will have the
 /// same name as the output raster, but with a *.csv file extension.
 ///
+/// The isobasins may optionally also be output as a vector of polygons (`--out_polygons`), with one polygon
+/// for each isobasin. The attribute table contains the basin ID, area, perimeter, outlet coordinates, and the
+/// mean elevation of each isobasin within the input DEM.
+///
 /// # See Also
 /// `Watershed`, `Basins`, `BreachDepressions`, `FillDepressions`
 pub struct Isobasins {
@@ -88,6 +93,17 @@ impl Isobasins {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Output Polygons File".to_owned(),
+            flags: vec!["--out_polygons".to_owned()],
+            description: "Optional output vector polygons file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -147,12 +163,14 @@ impl WhiteboxTool for Isobasins {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut target_size = -1;
         let mut output_connections = false;
+        let mut polygons_file = String::new();
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -192,6 +210,12 @@ impl WhiteboxTool for Isobasins {
                 if vec.len() == 1 || !vec[1].to_string().to_lowercase().contains("false") {
                     output_connections = true;
                 }
+            } else if flag_val == "-out_polygons" {
+                polygons_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
             }
         }
 
@@ -199,11 +223,11 @@ impl WhiteboxTool for Isobasins {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if target_size == -1 {
@@ -226,9 +250,12 @@ impl WhiteboxTool for Isobasins {
         if !output_file.contains(&sep) && !output_file.contains("/") {
             output_file = format!("{}{}", working_directory, output_file);
         }
+        if !polygons_file.is_empty() && !polygons_file.contains(&sep) && !polygons_file.contains("/") {
+            polygons_file = format!("{}{}", working_directory, polygons_file);
+        }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -277,13 +304,13 @@ impl WhiteboxTool for Isobasins {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data: Vec<i8> = vec![-1i8; columns as usize];
                     for col in 0..columns {
-                        z = input[(row, col)];
+                        z = input.get_value(row, col);
                         if z != nodata {
                             dir = 0i8;
                             max_slope = f64::MIN;
                             neighbouring_nodata = false;
                             for i in 0..8 {
-                                z_n = input[(row + dy[i], col + dx[i])];
+                                z_n = input.get_value(row + dy[i], col + dx[i]);
                                 if z_n != nodata {
                                     slope = (z - z_n) / grid_lengths[i];
                                     if slope > max_slope && slope > 0f64 {
@@ -306,7 +333,9 @@ impl WhiteboxTool for Isobasins {
                             data[col as usize] = -1i8;
                         }
                     }
-                    tx.send((row, data, interior_pit_found)).unwrap();
+                    if tx.send((row, data, interior_pit_found)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -318,12 +347,10 @@ impl WhiteboxTool for Isobasins {
             if pit {
                 interior_pit_found = true;
             }
-            if verbose {
-                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Flow directions: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Flow directions", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -360,7 +387,9 @@ impl WhiteboxTool for Isobasins {
                             data[col as usize] = -1i8;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -378,12 +407,10 @@ impl WhiteboxTool for Isobasins {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Num. inflowing neighbours: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Num. inflowing neighbours", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -392,6 +419,7 @@ impl WhiteboxTool for Isobasins {
         /////////////////////////////////
         let mut accum: Array2D<usize> = Array2D::new(rows, columns, 1, 0)?;
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.widen_to_f64();
         output.configs.data_type = DataType::I32;
         let out_nodata = -32768f64;
         output.configs.nodata = out_nodata;
@@ -462,13 +490,11 @@ impl WhiteboxTool for Isobasins {
                 outlet_id += 1f64;
             }
 
-            if verbose {
-                num_solved_cells += 1;
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Finding pour points: {}%", progress);
-                    old_progress = progress;
-                }
+            num_solved_cells += 1;
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Finding pour points", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -532,12 +558,10 @@ impl WhiteboxTool for Isobasins {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Labelling basins: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Labelling basins", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -563,12 +587,10 @@ impl WhiteboxTool for Isobasins {
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Labelling basins connections: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Labelling basins connections", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -600,7 +622,7 @@ impl WhiteboxTool for Isobasins {
             let _ = writer.flush();
 
             if verbose {
-                println!("Please see {} for basin connection table.", csv_file);
+                context.info(format!("Please see {} for basin connection table.", csv_file));
             }
         }
 
@@ -617,28 +639,48 @@ impl WhiteboxTool for Isobasins {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
+        if !polygons_file.is_empty() {
+            if verbose {
+                context.info("Creating isobasin polygons...")
+            };
+            let accum = contributing_area(rows, columns, |row, col| flow_dir.get_value(row, col))?;
+            let polygons = trace_basin_polygons(
+                &output,
+                &accum,
+                Some(&input),
+                context,
+            )?;
+            write_basin_polygons(
+                &polygons_file,
+                &polygons,
+                &output.configs,
+                true,
+                &[],
+                |_| vec![],
+            )?;
+            if verbose {
+                context.info("Polygons file written")
+            }
+        }
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
         if interior_pit_found {
-            println!("**********************************************************************************");
-            println!("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
+            context.info("**********************************************************************************");
+            context.warning("WARNING: Interior pit cells were found within the input DEM. It is likely that the 
             DEM needs to be processed to remove topographic depressions and flats prior to
-            running this tool.");
-            println!("**********************************************************************************");
+            running this tool.".to_string());
+            context.info("**********************************************************************************");
         }
 
         Ok(())
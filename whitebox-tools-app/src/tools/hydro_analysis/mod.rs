@@ -1,6 +1,7 @@
 // private sub-module defined in other files
 mod average_flowpath_slope;
 mod average_upslope_flowpath_length;
+mod basin_polygons;
 mod basins;
 mod breach_depressions;
 mod breach_depressions_least_cost;
@@ -27,6 +28,7 @@ mod fill_pits;
 mod find_noflow_cells;
 mod find_parallel_flow;
 mod flatten_lakes;
+mod flood_inundation_map;
 mod flood_order;
 mod flow_accum_full_workflow;
 mod flow_length_diff;
@@ -46,6 +48,7 @@ mod snap_pour_points;
 mod stochastic_depression_analysis;
 mod strahler_basins;
 mod subbasins;
+mod tiled_depressions;
 mod trace_downslope_flowpaths;
 mod unnest_basins;
 mod upslope_depression_storage;
@@ -80,6 +83,7 @@ pub use self::fill_pits::FillSingleCellPits;
 pub use self::find_noflow_cells::FindNoFlowCells;
 pub use self::find_parallel_flow::FindParallelFlow;
 pub use self::flatten_lakes::FlattenLakes;
+pub use self::flood_inundation_map::FloodInundationMap;
 pub use self::flood_order::FloodOrder;
 pub use self::flow_accum_full_workflow::FlowAccumulationFullWorkflow;
 pub use self::flow_length_diff::FlowLengthDiff;
@@ -143,8 +143,9 @@ impl WhiteboxTool for RaiseWalls {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut breach_file = String::new();
         let mut dem_file = String::new();
@@ -210,11 +211,11 @@ impl WhiteboxTool for RaiseWalls {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -233,7 +234,7 @@ impl WhiteboxTool for RaiseWalls {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -253,6 +254,7 @@ impl WhiteboxTool for RaiseWalls {
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &dem);
+        output.widen_to_f64();
         output.set_data_from_raster(&dem)?;
         let rows = output.configs.rows as isize;
         let columns = output.configs.columns as isize;
@@ -396,17 +398,10 @@ impl WhiteboxTool for RaiseWalls {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
-                if progress != old_progress {
-                    println!(
-                        "Rasterizing {} of {}: {}%",
-                        record_num + 1,
-                        num_records,
-                        progress
-                    );
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
+            if progress != old_progress {
+                context.progress(&format!("Rasterizing {} of {}", record_num + 1, num_records), progress)?;
+                old_progress = progress;
             }
         }
 
@@ -439,12 +434,10 @@ impl WhiteboxTool for RaiseWalls {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -585,17 +578,10 @@ impl WhiteboxTool for RaiseWalls {
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
-                    if progress != old_progress {
-                        println!(
-                            "Rasterizing {} of {}: {}%",
-                            record_num + 1,
-                            num_records,
-                            progress
-                        );
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Rasterizing {} of {}", record_num + 1, num_records), progress)?;
+                    old_progress = progress;
                 }
             }
         }
@@ -609,26 +595,23 @@ impl WhiteboxTool for RaiseWalls {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if !output_something && verbose {
-            println!("Warning: No polygons were output to the raster.");
+            context.warning("Warning: No polygons were output to the raster.");
         }
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
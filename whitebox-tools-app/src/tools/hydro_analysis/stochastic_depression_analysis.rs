@@ -193,8 +193,9 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut rmse = 1f64;
@@ -272,11 +273,11 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -350,10 +351,10 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
 
         if iterations > i16::max_value() as usize {
             if verbose {
-                println!(
+                context.warning(format!(
                     "Warning: Iterations cannot be higher than {}.",
                     i16::max_value()
-                );
+                ));
             }
             iterations = i16::max_value() as usize;
         }
@@ -410,7 +411,7 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
 
         for iter_num in 0..iterations {
             if verbose {
-                println!("Iteration {}...", iter_num + 1);
+                context.info(format!("Iteration {}...", iter_num + 1));
             }
 
             /////////////////////////////
@@ -431,7 +432,9 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
                                 (sn_val * multiplier * range_in_cells * 2f64) as i32;
                         }
 
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -543,7 +546,9 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
                                 }
                             }
 
-                            tx.send((row, data, min_value, max_value)).unwrap();
+                            if tx.send((row, data, min_value, max_value)).is_err() {
+                                break;
+                            }
                         }
                     });
                 }
@@ -738,12 +743,10 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
             drop(minheap);
             drop(dep_filled);
 
-            if verbose {
-                progress = (100.0_f64 * (iter_num + 1) as f64 / iterations as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (iter_num + 1) as f64 / iterations as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -773,21 +776,18 @@ impl WhiteboxTool for StochasticDepressionAnalysis {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
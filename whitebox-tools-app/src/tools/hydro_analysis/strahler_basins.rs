@@ -2,10 +2,11 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 13/07/2017
-Last Modified: 18/10/2019
+Last Modified: 17/10/2026
 License: MIT
 */
 
+use super::basin_polygons::{contributing_area, trace_basin_polygons, write_basin_polygons};
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
 use crate::tools::*;
@@ -26,6 +27,12 @@ use std::path;
 ///
 /// NoData values in the input flow pointer raster are assigned NoData values in the output image.
 ///
+/// The basins may optionally also be output as a vector of polygons (`--out_polygons`). Because all of the basins
+/// of one stream order share an ID, the output contains one multi-part polygon for each order, whose outlet
+/// coordinates are those of the part with the largest contributing area. The attribute table contains the basin
+/// ID (i.e. the stream order), area, perimeter, and outlet coordinates, along with the mean elevation of each
+/// polygon when a DEM (`--dem`) is specified.
+///
 /// # See Also
 /// `HortonStreamOrder`, `Watershed`, `D8Pointer`, `BreachDepressions`, `FillDepressions`
 pub struct StrahlerOrderBasins {
@@ -81,6 +88,26 @@ impl StrahlerOrderBasins {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["--dem".to_owned()],
+            description: "Optional input DEM, used to calculate the mean elevation of the output polygons.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Polygons File".to_owned(),
+            flags: vec!["--out_polygons".to_owned()],
+            description: "Optional output vector polygons file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -136,12 +163,15 @@ impl WhiteboxTool for StrahlerOrderBasins {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut d8_file = String::new();
         let mut streams_file = String::new();
         let mut output_file = String::new();
         let mut esri_style = false;
+        let mut dem_file = String::new();
+        let mut polygons_file = String::new();
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -183,6 +213,18 @@ impl WhiteboxTool for StrahlerOrderBasins {
                 if vec.len() == 1 || !vec[1].to_string().to_lowercase().contains("false") {
                     esri_style = true;
                 }
+            } else if vec[0].to_lowercase() == "-dem" || vec[0].to_lowercase() == "--dem" {
+                if keyval {
+                    dem_file = vec[1].to_string();
+                } else {
+                    dem_file = args[i + 1].to_string();
+                }
+            } else if vec[0].to_lowercase() == "-out_polygons" || vec[0].to_lowercase() == "--out_polygons" {
+                if keyval {
+                    polygons_file = vec[1].to_string();
+                } else {
+                    polygons_file = args[i + 1].to_string();
+                }
             }
         }
 
@@ -190,11 +232,11 @@ impl WhiteboxTool for StrahlerOrderBasins {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -211,12 +253,30 @@ impl WhiteboxTool for StrahlerOrderBasins {
         if !output_file.contains(&sep) && !output_file.contains("/") {
             output_file = format!("{}{}", working_directory, output_file);
         }
+        if !dem_file.is_empty() && !dem_file.contains(&sep) && !dem_file.contains("/") {
+            dem_file = format!("{}{}", working_directory, dem_file);
+        }
+        if !polygons_file.is_empty() && !polygons_file.contains(&sep) && !polygons_file.contains("/") {
+            polygons_file = format!("{}{}", working_directory, polygons_file);
+        }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let pntr = Raster::new(&d8_file, "r")?;
+        let dem = if !polygons_file.is_empty() && !dem_file.is_empty() {
+            let dem = Raster::new(&dem_file, "r")?;
+            if dem.configs.rows != pntr.configs.rows || dem.configs.columns != pntr.configs.columns {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input files must have the same number of rows and columns and spatial extent.",
+                ));
+            }
+            Some(dem)
+        } else {
+            None
+        };
         let streams = Raster::new(&streams_file, "r")?;
 
         let start = Instant::now();
@@ -283,13 +343,15 @@ impl WhiteboxTool for StrahlerOrderBasins {
         let mut dir: usize;
         for row in 0..rows {
             for col in 0..columns {
-                if streams[(row, col)] > 0.0 {
+                if streams.get_value(row, col) > 0.0 {
                     // see if it is a headwater location
                     num_neighbouring_stream_cells = 0i8;
                     for c in 0..8 {
                         x = col + dx[c];
                         y = row + dy[c];
-                        if streams[(y, x)] > 0.0 && pntr[(y, x)] == inflowing_vals[c] {
+                        if streams.get_value(y, x) > 0.0
+                            && pntr.get_value(y, x) == inflowing_vals[c]
+                        {
                             num_neighbouring_stream_cells += 1;
                         }
                     }
@@ -302,8 +364,8 @@ impl WhiteboxTool for StrahlerOrderBasins {
                         flag = true;
                         while flag {
                             // find the downslope neighbour
-                            if pntr[(y, x)] > 0.0 {
-                                dir = pntr[(y, x)] as usize;
+                            if pntr.get_value(y, x) > 0.0 {
+                                dir = pntr.get_value(y, x) as usize;
                                 if dir > 128 || pntr_matches[dir] == 999 {
                                     return Err(Error::new(ErrorKind::InvalidInput,
                                         "An unexpected value has been identified in the pointer image. This tool requires a pointer grid that has been created using either the D8 or Rho8 tools."));
@@ -312,7 +374,7 @@ impl WhiteboxTool for StrahlerOrderBasins {
                                 x += dx[pntr_matches[dir]];
                                 y += dy[pntr_matches[dir]];
 
-                                if streams[(y, x)] <= 0.0 {
+                                if streams.get_value(y, x) <= 0.0 {
                                     //it's not a stream cell
                                     flag = false;
                                 } else {
@@ -326,8 +388,8 @@ impl WhiteboxTool for StrahlerOrderBasins {
                                         for d in 0..8 {
                                             x2 = x + dx[d];
                                             y2 = y + dy[d];
-                                            if streams[(y2, x2)] > 0.0
-                                                && pntr[(y2, x2)] == inflowing_vals[d]
+                                            if streams.get_value(y2, x2) > 0.0
+                                                && pntr.get_value(y2, x2) == inflowing_vals[d]
                                                 && pourpts[(y2, x2)] == current_order
                                             {
                                                 num_neighbouring_stream_cells += 1;
@@ -345,7 +407,7 @@ impl WhiteboxTool for StrahlerOrderBasins {
                                     }
                                 }
                             } else {
-                                if streams[(y, x)] > 0.0 {
+                                if streams.get_value(y, x) > 0.0 {
                                     //it is a valid stream cell and probably just has no downslope neighbour (e.g. at the edge of the grid)
                                     pourpts.increment(y, x, 1.0);
                                 }
@@ -358,17 +420,16 @@ impl WhiteboxTool for StrahlerOrderBasins {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         // Now perform the watershedding operation
         let mut output = Raster::initialize_using_file(&output_file, &streams);
+        output.widen_to_f64();
         output.configs.data_type = DataType::F32;
         output.configs.palette = "qual.plt".to_string();
         output.configs.photometric_interp = PhotometricInterpretation::Categorical;
@@ -378,20 +439,18 @@ impl WhiteboxTool for StrahlerOrderBasins {
         let mut z: f64;
         for row in 0..rows {
             for col in 0..columns {
-                if pntr[(row, col)] == pntr_nodata {
-                    output[(row, col)] = nodata;
+                if pntr.get_value(row, col) == pntr_nodata {
+                    output.set_value(row, col, nodata);
                 }
                 z = pourpts[(row, col)];
                 if z != nodata && z > 0.0 {
-                    output[(row, col)] = z;
+                    output.set_value(row, col, z);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Watershedding (Loop 1 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Watershedding (Loop 1 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -401,20 +460,20 @@ impl WhiteboxTool for StrahlerOrderBasins {
         let mut c: usize;
         for row in 0..rows {
             for col in 0..columns {
-                if output[(row, col)] == low_value {
+                if output.get_value(row, col) == low_value {
                     flag = false;
                     x = col;
                     y = row;
                     outlet_id = nodata;
                     while !flag {
-                        dir = pntr[(y, x)] as usize;
+                        dir = pntr.get_value(y, x) as usize;
                         if dir > 0 {
                             c = pntr_matches[dir];
                             y += dy[c];
                             x += dx[c];
 
                             // if the new cell already has a value in the output, use that as the outletID
-                            z = output[(y, x)];
+                            z = output.get_value(y, x);
                             if z != low_value {
                                 outlet_id = z;
                                 flag = true;
@@ -427,32 +486,30 @@ impl WhiteboxTool for StrahlerOrderBasins {
                     flag = false;
                     x = col;
                     y = row;
-                    output[(y, x)] = outlet_id;
+                    output.set_value(y, x, outlet_id);
                     while !flag {
                         // find its downslope neighbour
-                        dir = pntr[(y, x)] as usize;
+                        dir = pntr.get_value(y, x) as usize;
                         if dir > 0 {
                             c = pntr_matches[dir];
                             y += dy[c];
                             x += dx[c];
 
                             // if the new cell already has a value in the output, use that as the outletID
-                            if output[(y, x)] != low_value {
+                            if output.get_value(y, x) != low_value {
                                 flag = true;
                             }
                         } else {
                             flag = true;
                         }
-                        output[(y, x)] = outlet_id;
+                        output.set_value(y, x, outlet_id);
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Watershedding (Loop 2 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Watershedding (Loop 2 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -466,21 +523,48 @@ impl WhiteboxTool for StrahlerOrderBasins {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
+        if !polygons_file.is_empty() {
+            if verbose {
+                context.info("Creating basin polygons...")
+            };
+            let accum = contributing_area(rows, columns, |row, col| {
+                let z = pntr.get_value(row, col);
+                if z != pntr_nodata && z > 0f64 && z <= 128f64 && pntr_matches[z as usize] != 999 {
+                    pntr_matches[z as usize] as i8
+                } else {
+                    -1i8
+                }
+            })?;
+            let polygons = trace_basin_polygons(
+                &output,
+                &accum,
+                dem.as_ref(),
+                context,
+            )?;
+            write_basin_polygons(
+                &polygons_file,
+                &polygons,
+                &output.configs,
+                dem.is_some(),
+                &[],
+                |_| vec![],
+            )?;
+            if verbose {
+                context.info("Polygons file written")
+            }
+        }
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -0,0 +1,2789 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: FillDepressions and BreachDepressionsLeastCost solve their pits speculatively in
+parallel, each within a copy of the DEM around it, and commit them in the serial pit order,
+solving a pit again if any cell that it read has since changed. These tools aren't tiled and
+merged by spill elevation, since that would change their output. BreachDepressions floods tiles
+in parallel and merges them through a graph of their spill elevations.
+*/
+
+use crate::tools::ToolContext;
+use std::cmp::{Ordering, Reverse};
+use std::cmp::Ordering::Equal;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::f64;
+use std::io::Error;
+use std::ops::{AddAssign, SubAssign};
+use std::sync::mpsc;
+use std::sync::Mutex;
+use std::thread;
+use whitebox_common::structures::Array2D;
+use whitebox_raster::{CachedRaster, Raster, RasterConfigs};
+
+const DX: [isize; 8] = [1, 1, 1, 0, -1, -1, -1, 0];
+const DY: [isize; 8] = [-1, 0, 1, 1, 1, 0, -1, -1];
+// The width and height, in cells, of the tiles used to group the pits of a batch.
+const TILE_SIZE: isize = 128;
+// The number of pits in each batch, per thread.
+const PITS_PER_THREAD: usize = 2048;
+// The number of cells around a pit that are copied for its speculative filling. Larger
+// depressions are filled directly on the DEM.
+const FILL_MARGIN: isize = 64;
+// The approximate number of cells in each band of rows that is scanned in parallel.
+const CELLS_PER_BAND: isize = 262_144;
+
+/// A grid of cells that can be read and written one at a time. Cells outside of the grid are
+/// nodata.
+pub(super) trait Grid<T: Copy> {
+    fn rows(&self) -> isize;
+
+    fn columns(&self) -> isize;
+
+    fn get(&mut self, row: isize, column: isize) -> Result<T, Error>;
+
+    fn set(&mut self, row: isize, column: isize, value: T) -> Result<(), Error>;
+
+    /// Returns the values of the cells of a window of the grid, in row-major order.
+    fn read_window(
+        &mut self,
+        row: isize,
+        column: isize,
+        rows: isize,
+        columns: isize,
+    ) -> Result<Vec<T>, Error> {
+        let mut values = Vec::with_capacity((rows * columns) as usize);
+        for r in row..row + rows {
+            for c in column..column + columns {
+                values.push(self.get(r, c)?);
+            }
+        }
+        Ok(values)
+    }
+}
+
+impl Grid<f64> for Raster {
+    fn rows(&self) -> isize {
+        self.configs.rows as isize
+    }
+
+    fn columns(&self) -> isize {
+        self.configs.columns as isize
+    }
+
+    fn get(&mut self, row: isize, column: isize) -> Result<f64, Error> {
+        Ok(self.get_value(row, column))
+    }
+
+    fn set(&mut self, row: isize, column: isize, value: f64) -> Result<(), Error> {
+        self.set_value(row, column, value);
+        Ok(())
+    }
+}
+
+impl Grid<f64> for CachedRaster {
+    fn rows(&self) -> isize {
+        self.configs.rows as isize
+    }
+
+    fn columns(&self) -> isize {
+        self.configs.columns as isize
+    }
+
+    fn get(&mut self, row: isize, column: isize) -> Result<f64, Error> {
+        self.get_value(row, column)
+    }
+
+    fn set(&mut self, row: isize, column: isize, value: f64) -> Result<(), Error> {
+        self.set_value(row, column, value)
+    }
+
+    fn read_window(
+        &mut self,
+        row: isize,
+        column: isize,
+        rows: isize,
+        columns: isize,
+    ) -> Result<Vec<f64>, Error> {
+        let window = CachedRaster::read_window(self, row, column, rows as usize, columns as usize)?;
+        let mut values = Vec::with_capacity((rows * columns) as usize);
+        for r in 0..rows {
+            values.extend(window.get_row_data(r));
+        }
+        Ok(values)
+    }
+}
+
+impl<T: Copy + AddAssign + SubAssign> Grid<T> for Array2D<T> {
+    fn rows(&self) -> isize {
+        self.rows
+    }
+
+    fn columns(&self) -> isize {
+        self.columns
+    }
+
+    fn get(&mut self, row: isize, column: isize) -> Result<T, Error> {
+        Ok(self.get_value(row, column))
+    }
+
+    fn set(&mut self, row: isize, column: isize, value: T) -> Result<(), Error> {
+        self.set_value(row, column, value);
+        Ok(())
+    }
+}
+
+/// A grid of flags that is held in a scratch file, for DEMs that are too large for the
+/// flags to be held in memory. Every cell is initially zero and cells outside of the grid
+/// are -1.
+pub(super) struct CachedFlags {
+    raster: CachedRaster,
+}
+
+impl CachedFlags {
+    /// Creates a grid of flags with the extent of `configs`. The scratch file is named after
+    /// `file_name` and is deleted when the grid is dropped.
+    pub(super) fn new(
+        file_name: &str,
+        configs: &RasterConfigs,
+        cache_size: usize,
+    ) -> Result<CachedFlags, Error> {
+        let mut configs = configs.clone();
+        // cells that have never been written are nodata, which reads as zero.
+        configs.nodata = 0f64;
+        let mut raster = CachedRaster::initialize_using_config(file_name, &configs)?;
+        raster.set_cache_size(cache_size);
+        Ok(CachedFlags { raster })
+    }
+}
+
+impl Grid<i8> for CachedFlags {
+    fn rows(&self) -> isize {
+        self.raster.configs.rows as isize
+    }
+
+    fn columns(&self) -> isize {
+        self.raster.configs.columns as isize
+    }
+
+    fn get(&mut self, row: isize, column: isize) -> Result<i8, Error> {
+        if row < 0 || column < 0 || row >= self.rows() || column >= self.columns() {
+            return Ok(-1);
+        }
+        Ok(self.raster.get_value(row, column)? as i8)
+    }
+
+    fn set(&mut self, row: isize, column: isize, value: i8) -> Result<(), Error> {
+        self.raster.set_value(row, column, value as f64)
+    }
+}
+
+/// A cell with no lower neighbour, along with the elevation that sets the order in which it
+/// is solved.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Pit {
+    pub row: isize,
+    pub column: isize,
+    pub z: f64,
+}
+
+/// The order in which pits are solved.
+#[derive(Clone, Copy)]
+pub(super) enum PitOrder {
+    HighestFirst,
+    LowestFirst,
+}
+
+/// Sorts pits, which must be in row-major order, into the order in which they are solved.
+/// Pits of equal elevation are solved in reverse row-major order.
+pub(super) fn sort_pits(pits: &mut [Pit], order: PitOrder) {
+    match order {
+        PitOrder::HighestFirst => pits.sort_by(|a, b| a.z.partial_cmp(&b.z).unwrap_or(Equal)),
+        PitOrder::LowestFirst => pits.sort_by(|a, b| b.z.partial_cmp(&a.z).unwrap_or(Equal)),
+    }
+    pits.reverse();
+}
+
+/// The elevations of a DEM, along with the flags used while solving its depressions.
+pub(super) trait DepressionState {
+    fn elevation(&mut self, row: isize, column: isize) -> Result<f64, Error>;
+
+    fn set_elevation(&mut self, row: isize, column: isize, value: f64) -> Result<(), Error>;
+
+    fn visited(&mut self, row: isize, column: isize) -> Result<i8, Error>;
+
+    fn set_visited(&mut self, row: isize, column: isize, value: i8) -> Result<(), Error>;
+
+    fn flat(&mut self, row: isize, column: isize) -> Result<i8, Error>;
+
+    fn set_flat(&mut self, row: isize, column: isize, value: i8) -> Result<(), Error>;
+
+    /// Whether the solution in progress has reached cells that are unavailable, in which
+    /// case it will be discarded and may be abandoned.
+    fn is_incomplete(&self) -> bool {
+        false
+    }
+}
+
+/// The grids of a DEM whose depressions are being solved.
+pub(super) struct Grids<E, F> {
+    pub elevations: E,
+    pub visited: F,
+    pub flats: F,
+}
+
+impl<E: Grid<f64>, F: Grid<i8>> DepressionState for Grids<E, F> {
+    fn elevation(&mut self, row: isize, column: isize) -> Result<f64, Error> {
+        self.elevations.get(row, column)
+    }
+
+    fn set_elevation(&mut self, row: isize, column: isize, value: f64) -> Result<(), Error> {
+        self.elevations.set(row, column, value)
+    }
+
+    fn visited(&mut self, row: isize, column: isize) -> Result<i8, Error> {
+        self.visited.get(row, column)
+    }
+
+    fn set_visited(&mut self, row: isize, column: isize, value: i8) -> Result<(), Error> {
+        self.visited.set(row, column, value)
+    }
+
+    fn flat(&mut self, row: isize, column: isize) -> Result<i8, Error> {
+        self.flats.get(row, column)
+    }
+
+    fn set_flat(&mut self, row: isize, column: isize, value: i8) -> Result<(), Error> {
+        self.flats.set(row, column, value)
+    }
+}
+
+/// The solution of a single pit, e.g. the filling of the depression that drains to it.
+pub(super) trait DepressionTask: Sync {
+    /// The working storage of a thread, which is reused for each pit.
+    type Scratch: Send;
+    /// What is reported of each pit's solution.
+    type Outcome: Send;
+
+    fn scratch(&self, rows: isize, columns: isize) -> Result<Self::Scratch, Error>;
+
+    /// The number of cells around a pit that its solution is expected to reach.
+    fn margin(&self) -> isize;
+
+    fn solve<S: DepressionState>(
+        &self,
+        state: &mut S,
+        pit: &Pit,
+        scratch: &mut Self::Scratch,
+    ) -> Result<Self::Outcome, Error>;
+}
+
+// The values of a cell.
+struct CellState {
+    row: isize,
+    column: isize,
+    z: f64,
+    visited: i8,
+    flat: i8,
+}
+
+// A speculative solution of a pit: the original values of the cells that it read or wrote,
+// the new values of the cells that it changed, and its outcome.
+struct Speculation<O> {
+    reads: Vec<CellState>,
+    writes: Vec<CellState>,
+    outcome: O,
+}
+
+impl<O> Speculation<O> {
+    // Whether the cells read by the solution still hold the values that it read.
+    fn is_valid<E: Grid<f64>, F: Grid<i8>>(&self, grids: &mut Grids<E, F>) -> Result<bool, Error> {
+        for cell in &self.reads {
+            if grids.elevations.get(cell.row, cell.column)?.to_bits() != cell.z.to_bits()
+                || grids.visited.get(cell.row, cell.column)? != cell.visited
+                || grids.flats.get(cell.row, cell.column)? != cell.flat
+            {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn apply<E: Grid<f64>, F: Grid<i8>>(&self, grids: &mut Grids<E, F>) -> Result<(), Error> {
+        for cell in &self.writes {
+            grids.elevations.set(cell.row, cell.column, cell.z)?;
+            grids.visited.set(cell.row, cell.column, cell.visited)?;
+            grids.flats.set(cell.row, cell.column, cell.flat)?;
+        }
+        Ok(())
+    }
+}
+
+// A copy of part of the grids, within which pits are solved speculatively.
+struct Window {
+    row: isize,
+    column: isize,
+    rows: isize,
+    columns: isize,
+    grid_rows: isize,
+    grid_columns: isize,
+    nodata: f64,
+    elevations: Vec<f64>,
+    visited: Vec<i8>,
+    flats: Vec<i8>,
+    // the number of the solution that last touched each cell
+    stamps: Vec<u32>,
+    stamp: u32,
+    // the index and the original values of each cell touched by the current solution
+    touched: Vec<(usize, f64, i8, i8)>,
+    incomplete: bool,
+}
+
+impl Window {
+    fn read<E: Grid<f64>, F: Grid<i8>>(
+        grids: &mut Grids<E, F>,
+        row: isize,
+        column: isize,
+        rows: isize,
+        columns: isize,
+        nodata: f64,
+    ) -> Result<Window, Error> {
+        Ok(Window {
+            row,
+            column,
+            rows,
+            columns,
+            grid_rows: grids.elevations.rows(),
+            grid_columns: grids.elevations.columns(),
+            nodata,
+            elevations: grids.elevations.read_window(row, column, rows, columns)?,
+            visited: grids.visited.read_window(row, column, rows, columns)?,
+            flats: grids.flats.read_window(row, column, rows, columns)?,
+            stamps: vec![0u32; (rows * columns) as usize],
+            stamp: 0,
+            touched: vec![],
+            incomplete: false,
+        })
+    }
+
+    fn begin(&mut self) {
+        self.stamp += 1;
+        self.touched.clear();
+        self.incomplete = false;
+    }
+
+    // Returns the index of a cell within the window, recording the cell's values the first
+    // time that the current solution touches it. Cells outside of the grid have no index,
+    // nor do grid cells outside of the window, which make the solution incomplete.
+    fn index(&mut self, row: isize, column: isize) -> Option<usize> {
+        if row < 0 || column < 0 || row >= self.grid_rows || column >= self.grid_columns {
+            return None;
+        }
+        let (r, c) = (row - self.row, column - self.column);
+        if r < 0 || c < 0 || r >= self.rows || c >= self.columns {
+            self.incomplete = true;
+            return None;
+        }
+        let i = (r * self.columns + c) as usize;
+        if self.stamps[i] != self.stamp {
+            self.stamps[i] = self.stamp;
+            self.touched
+                .push((i, self.elevations[i], self.visited[i], self.flats[i]));
+        }
+        Some(i)
+    }
+
+    // Restores the cells changed by the current solution.
+    fn rollback(&mut self) {
+        for &(i, z, visited, flat) in &self.touched {
+            self.elevations[i] = z;
+            self.visited[i] = visited;
+            self.flats[i] = flat;
+        }
+    }
+
+    fn speculation<O>(&self, outcome: O) -> Speculation<O> {
+        let mut reads = Vec::with_capacity(self.touched.len());
+        let mut writes = vec![];
+        for &(i, z, visited, flat) in &self.touched {
+            let row = self.row + i as isize / self.columns;
+            let column = self.column + i as isize % self.columns;
+            reads.push(CellState {
+                row,
+                column,
+                z,
+                visited,
+                flat,
+            });
+            if self.elevations[i].to_bits() != z.to_bits()
+                || self.visited[i] != visited
+                || self.flats[i] != flat
+            {
+                writes.push(CellState {
+                    row,
+                    column,
+                    z: self.elevations[i],
+                    visited: self.visited[i],
+                    flat: self.flats[i],
+                });
+            }
+        }
+        Speculation {
+            reads,
+            writes,
+            outcome,
+        }
+    }
+}
+
+impl DepressionState for Window {
+    fn elevation(&mut self, row: isize, column: isize) -> Result<f64, Error> {
+        Ok(match self.index(row, column) {
+            Some(i) => self.elevations[i],
+            None => self.nodata,
+        })
+    }
+
+    fn set_elevation(&mut self, row: isize, column: isize, value: f64) -> Result<(), Error> {
+        if let Some(i) = self.index(row, column) {
+            self.elevations[i] = value;
+        }
+        Ok(())
+    }
+
+    fn visited(&mut self, row: isize, column: isize) -> Result<i8, Error> {
+        Ok(match self.index(row, column) {
+            Some(i) => self.visited[i],
+            None => -1,
+        })
+    }
+
+    fn set_visited(&mut self, row: isize, column: isize, value: i8) -> Result<(), Error> {
+        if let Some(i) = self.index(row, column) {
+            self.visited[i] = value;
+        }
+        Ok(())
+    }
+
+    fn flat(&mut self, row: isize, column: isize) -> Result<i8, Error> {
+        Ok(match self.index(row, column) {
+            Some(i) => self.flats[i],
+            None => -1,
+        })
+    }
+
+    fn set_flat(&mut self, row: isize, column: isize, value: i8) -> Result<(), Error> {
+        if let Some(i) = self.index(row, column) {
+            self.flats[i] = value;
+        }
+        Ok(())
+    }
+
+    fn is_incomplete(&self) -> bool {
+        self.incomplete
+    }
+}
+
+/// Solves pits, which must be in the order in which they are to be solved, returning the
+/// outcome of each. The result is identical to that of solving them one at a time.
+pub(super) fn solve_depressions<E, F, T>(
+    grids: &mut Grids<E, F>,
+    pits: &[Pit],
+    task: &T,
+    nodata: f64,
+    num_procs: isize,
+    context: &ToolContext,
+    label: &str,
+) -> Result<Vec<T::Outcome>, Error>
+where
+    E: Grid<f64>,
+    F: Grid<i8>,
+    T: DepressionTask,
+{
+    let rows = grids.elevations.rows();
+    let columns = grids.elevations.columns();
+    let num_procs = num_procs.max(1) as usize;
+    let batch_size = if num_procs > 1 {
+        PITS_PER_THREAD * num_procs
+    } else {
+        pits.len().max(1)
+    };
+    let margin = task.margin().max(1);
+    let tiles_across = (columns + TILE_SIZE - 1) / TILE_SIZE;
+    let token = context.cancellation_token();
+    let mut scratch = task.scratch(rows, columns)?;
+    let mut outcomes = Vec::with_capacity(pits.len());
+    let mut old_progress = 0usize;
+
+    for batch in pits.chunks(batch_size) {
+        context.check_cancelled()?;
+        let mut speculations: Vec<Option<Speculation<T::Outcome>>> =
+            (0..batch.len()).map(|_| None).collect();
+
+        if num_procs > 1 {
+            // Group the pits of the batch by tile, keeping the order of the pits.
+            let mut groups: Vec<Vec<usize>> = vec![];
+            let mut group_of_tile = HashMap::new();
+            for (p, pit) in batch.iter().enumerate() {
+                let tile = (pit.row / TILE_SIZE) * tiles_across + pit.column / TILE_SIZE;
+                let g = *group_of_tile.entry(tile).or_insert_with(|| {
+                    groups.push(vec![]);
+                    groups.len() - 1
+                });
+                groups[g].push(p);
+            }
+
+            let num_groups = groups.len();
+            let (job_tx, job_rx) = mpsc::sync_channel::<(Vec<usize>, Window)>(num_procs);
+            let job_rx = Mutex::new(job_rx);
+            let (tx, rx) = mpsc::channel();
+            let results = thread::scope(|s| -> Result<Vec<_>, Error> {
+                for _ in 0..num_procs {
+                    let (job_rx, tx, token) = (&job_rx, tx.clone(), token.clone());
+                    let mut scratch = task.scratch(rows, columns)?;
+                    s.spawn(move || loop {
+                        let job = match job_rx.lock() {
+                            Ok(job_rx) => job_rx.recv(),
+                            Err(_) => break,
+                        };
+                        let (group, mut window) = match job {
+                            Ok(job) => job,
+                            Err(_) => break,
+                        };
+                        let mut solutions = Vec::with_capacity(group.len());
+                        for p in group {
+                            if token.is_cancelled() {
+                                break;
+                            }
+                            window.begin();
+                            match task.solve(&mut window, &batch[p], &mut scratch) {
+                                Ok(outcome) if !window.incomplete => {
+                                    solutions.push((p, window.speculation(outcome)));
+                                }
+                                _ => window.rollback(),
+                            }
+                        }
+                        if tx.send(solutions).is_err() {
+                            break;
+                        }
+                    });
+                }
+                drop(tx);
+
+                for group in groups {
+                    context.check_cancelled()?;
+                    let (mut min_row, mut max_row) = (rows, 0);
+                    let (mut min_col, mut max_col) = (columns, 0);
+                    for &p in &group {
+                        min_row = min_row.min(batch[p].row);
+                        max_row = max_row.max(batch[p].row);
+                        min_col = min_col.min(batch[p].column);
+                        max_col = max_col.max(batch[p].column);
+                    }
+                    let row = (min_row - margin).max(0);
+                    let col = (min_col - margin).max(0);
+                    let window = Window::read(
+                        grids,
+                        row,
+                        col,
+                        (max_row + margin + 1).min(rows) - row,
+                        (max_col + margin + 1).min(columns) - col,
+                        nodata,
+                    )?;
+                    if job_tx.send((group, window)).is_err() {
+                        break;
+                    }
+                }
+                drop(job_tx);
+                Ok(rx.iter().take(num_groups).collect())
+            })?;
+            for solutions in results {
+                for (p, speculation) in solutions {
+                    speculations[p] = Some(speculation);
+                }
+            }
+        }
+
+        // Commit the solutions in order, solving again any pit whose solution is missing or
+        // was based on cells that have since been changed.
+        for (pit, speculation) in batch.iter().zip(speculations) {
+            context.check_cancelled()?;
+            let outcome = match speculation {
+                Some(speculation) if speculation.is_valid(grids)? => {
+                    speculation.apply(grids)?;
+                    speculation.outcome
+                }
+                _ => task.solve(grids, pit, &mut scratch)?,
+            };
+            outcomes.push(outcome);
+
+            let progress = (100.0_f64 * outcomes.len() as f64 / pits.len() as f64) as usize;
+            if progress != old_progress {
+                context.progress(label, progress)?;
+                old_progress = progress;
+            }
+        }
+    }
+
+    Ok(outcomes)
+}
+
+/// A band of rows of a grid, along with the rows above and below it and a column of nodata
+/// on either side.
+pub(super) struct Band {
+    pub row: isize,
+    pub rows: isize,
+    pub columns: isize,
+    values: Vec<f64>,
+}
+
+impl Band {
+    /// Returns the value of a cell, given its row and column within the grid.
+    pub fn get_value(&self, row: isize, column: isize) -> f64 {
+        self.values[((row - self.row + 1) * (self.columns + 2) + column + 1) as usize]
+    }
+}
+
+/// Applies `f` to each band of rows of a grid, in parallel, and returns the results in the
+/// order of the bands.
+pub(super) fn map_bands<G, R, Func>(
+    grid: &mut G,
+    num_procs: isize,
+    context: &ToolContext,
+    label: &str,
+    f: Func,
+) -> Result<Vec<R>, Error>
+where
+    G: Grid<f64>,
+    R: Send,
+    Func: Fn(&Band) -> R + Sync,
+{
+    let rows = grid.rows();
+    let columns = grid.columns();
+    let num_procs = num_procs.max(1) as usize;
+    let band_height = (CELLS_PER_BAND / (columns + 2)).max(1);
+    let num_bands = ((rows + band_height - 1) / band_height) as usize;
+    let mut results: Vec<Option<R>> = (0..num_bands).map(|_| None).collect();
+    let (band_tx, band_rx) = mpsc::sync_channel::<(usize, Band)>(num_procs);
+    let band_rx = Mutex::new(band_rx);
+    let (tx, rx) = mpsc::channel();
+    thread::scope(|s| -> Result<(), Error> {
+        for _ in 0..num_procs {
+            let (band_rx, tx, f) = (&band_rx, tx.clone(), &f);
+            s.spawn(move || loop {
+                let band = match band_rx.lock() {
+                    Ok(band_rx) => band_rx.recv(),
+                    Err(_) => break,
+                };
+                match band {
+                    Ok((b, band)) => {
+                        if tx.send((b, f(&band))).is_err() {
+                            break;
+                        }
+                    }
+                    Err(_) => break,
+                }
+            });
+        }
+        drop(tx);
+
+        let mut old_progress = 0usize;
+        for b in 0..num_bands {
+            context.check_cancelled()?;
+            let row = b as isize * band_height;
+            let band_rows = band_height.min(rows - row);
+            let values = grid.read_window(row - 1, -1, band_rows + 2, columns + 2)?;
+            let band = Band {
+                row,
+                rows: band_rows,
+                columns,
+                values,
+            };
+            if band_tx.send((b, band)).is_err() {
+                break;
+            }
+            let progress = (100.0_f64 * (b + 1) as f64 / num_bands as f64) as usize;
+            if progress != old_progress {
+                context.progress(label, progress)?;
+                old_progress = progress;
+            }
+        }
+        drop(band_tx);
+        for (b, result) in rx.iter() {
+            results[b] = Some(result);
+        }
+        Ok(())
+    })?;
+    let results: Vec<R> = results.into_iter().flatten().collect();
+    if results.len() != num_bands {
+        return Err(Error::other("Error receiving data from thread."));
+    }
+    Ok(results)
+}
+
+/// Returns the largest value of a grid that isn't nodata.
+pub(super) fn grid_maximum<G: Grid<f64>>(
+    grid: &mut G,
+    nodata: f64,
+    num_procs: isize,
+    context: &ToolContext,
+) -> Result<f64, Error> {
+    let maxima = map_bands(grid, num_procs, context, "Reading data", |band| {
+        let mut max_val = f64::NEG_INFINITY;
+        for row in band.row..band.row + band.rows {
+            for col in 0..band.columns {
+                let z = band.get_value(row, col);
+                if z != nodata && z > max_val {
+                    max_val = z;
+                }
+            }
+        }
+        max_val
+    })?;
+    Ok(maxima.into_iter().fold(f64::NEG_INFINITY, f64::max))
+}
+
+/// Returns the pits of a DEM, in row-major order. These are the cells, away from the edges
+/// of the grid, that have neither a lower neighbour nor a nodata neighbour.
+pub(super) fn find_pits<G: Grid<f64>>(
+    dem: &mut G,
+    nodata: f64,
+    num_procs: isize,
+    context: &ToolContext,
+) -> Result<Vec<Pit>, Error> {
+    let rows = dem.rows();
+    let pits = map_bands(dem, num_procs, context, "Finding pit cells", |band| {
+        let mut pits = vec![];
+        for row in band.row.max(1)..(band.row + band.rows).min(rows - 1) {
+            for col in 1..band.columns - 1 {
+                let z = band.get_value(row, col);
+                if z != nodata {
+                    let mut flag = true;
+                    for n in 0..8 {
+                        let zn = band.get_value(row + DY[n], col + DX[n]);
+                        if zn < z || zn == nodata {
+                            // It either has a lower neighbour or is an edge cell.
+                            flag = false;
+                            break;
+                        }
+                    }
+                    if flag {
+                        // it's a cell with undefined flow
+                        pits.push(Pit {
+                            row,
+                            column: col,
+                            z,
+                        });
+                    }
+                }
+            }
+        }
+        pits
+    })?;
+    Ok(pits.into_iter().flatten().collect())
+}
+
+/// Fills the depression that drains to a pit up to the elevation of its outlet. The outcome
+/// is the list of the depression's possible outlets, which are used to fix the flats.
+pub(super) struct FillTask {
+    pub nodata: f64,
+    /// The maximum depth of the depressions that are filled.
+    pub max_depth: f64,
+    /// Whether the cells visited while searching for the outlet of a depression that is too
+    /// deep to be filled are cleared, so that they may be visited from other pits.
+    pub clear_unfilled: bool,
+}
+
+impl DepressionTask for FillTask {
+    type Scratch = (BinaryHeap<GridCell>, VecDeque<(isize, isize)>);
+    type Outcome = Vec<(isize, isize)>;
+
+    fn scratch(&self, _rows: isize, _columns: isize) -> Result<Self::Scratch, Error> {
+        Ok((BinaryHeap::new(), VecDeque::new()))
+    }
+
+    fn margin(&self) -> isize {
+        FILL_MARGIN
+    }
+
+    fn solve<S: DepressionState>(
+        &self,
+        state: &mut S,
+        pit: &Pit,
+        scratch: &mut Self::Scratch,
+    ) -> Result<Self::Outcome, Error> {
+        let (minheap, queue) = scratch;
+        let nodata = self.nodata;
+        let (row, col) = (pit.row, pit.column);
+        let mut possible_outlets = vec![];
+        // if it's already in a solved site, don't do it a second time.
+        if state.flat(row, col)? == 1 {
+            return Ok(possible_outlets);
+        }
+        // First there is a priority region-growing operation to find the outlets.
+        let z_pit = state.elevation(row, col)?;
+        minheap.clear();
+        minheap.push(GridCell {
+            row,
+            column: col,
+            priority: z_pit,
+        });
+        state.set_visited(row, col, 1)?;
+        let mut outlet_found = false;
+        let mut outlet_z = f64::INFINITY;
+        queue.clear();
+        while let Some(cell2) = minheap.pop() {
+            if state.is_incomplete() {
+                return Ok(possible_outlets);
+            }
+            let z = cell2.priority;
+            if outlet_found && z > outlet_z {
+                break;
+            }
+            if z - z_pit > self.max_depth {
+                // No outlet could be found that was low enough.
+                break;
+            }
+            if !outlet_found {
+                for n in 0..8 {
+                    let cn = cell2.column + DX[n];
+                    let rn = cell2.row + DY[n];
+                    if state.visited(rn, cn)? == 0 {
+                        let zn = state.elevation(rn, cn)?;
+                        if !outlet_found {
+                            if zn >= z && zn != nodata {
+                                minheap.push(GridCell {
+                                    row: rn,
+                                    column: cn,
+                                    priority: zn,
+                                });
+                                state.set_visited(rn, cn, 1)?;
+                            } else if zn != nodata {
+                                // 'cell' has a lower neighbour that hasn't already passed
+                                // through minheap. Therefore, 'cell' is a pour point cell.
+                                outlet_found = true;
+                                outlet_z = z;
+                                queue.push_back((cell2.row, cell2.column));
+                                possible_outlets.push((cell2.row, cell2.column));
+                            }
+                        } else if zn == outlet_z {
+                            // We've found the outlet but are still looking for additional
+                            // depression cells.
+                            minheap.push(GridCell {
+                                row: rn,
+                                column: cn,
+                                priority: zn,
+                            });
+                            state.set_visited(rn, cn, 1)?;
+                        }
+                    }
+                }
+            } else if z == outlet_z {
+                // We've found the outlet but are still looking for additional depression
+                // cells and potential outlets.
+                let mut flag = false;
+                for n in 0..8 {
+                    let cn = cell2.column + DX[n];
+                    let rn = cell2.row + DY[n];
+                    if state.visited(rn, cn)? == 0 {
+                        let zn = state.elevation(rn, cn)?;
+                        if zn < z {
+                            flag = true;
+                        } else if zn == outlet_z {
+                            minheap.push(GridCell {
+                                row: rn,
+                                column: cn,
+                                priority: zn,
+                            });
+                            state.set_visited(rn, cn, 1)?;
+                        }
+                    }
+                }
+                if flag {
+                    // it's an outlet
+                    queue.push_back((cell2.row, cell2.column));
+                    possible_outlets.push((cell2.row, cell2.column));
+                } else {
+                    state.set_visited(cell2.row, cell2.column, 1)?;
+                }
+            }
+        }
+
+        if outlet_found {
+            // Now that we have the outlets, raise the interior of the depression, starting
+            // from the outlets.
+            while let Some(cell2) = queue.pop_front() {
+                for n in 0..8 {
+                    let rn = cell2.0 + DY[n];
+                    let cn = cell2.1 + DX[n];
+                    if state.visited(rn, cn)? == 1 {
+                        state.set_visited(rn, cn, 0)?;
+                        queue.push_back((rn, cn));
+                        let z = state.elevation(rn, cn)?;
+                        if z < outlet_z {
+                            state.set_elevation(rn, cn, outlet_z)?;
+                            state.set_flat(rn, cn, 1)?;
+                        } else if z == outlet_z {
+                            state.set_flat(rn, cn, 1)?;
+                        }
+                    }
+                }
+            }
+        } else if self.clear_unfilled {
+            queue.push_back((row, col)); // start at the pit cell and clean up visited
+            while let Some(cell2) = queue.pop_front() {
+                for n in 0..8 {
+                    let rn = cell2.0 + DY[n];
+                    let cn = cell2.1 + DX[n];
+                    if state.visited(rn, cn)? == 1 {
+                        state.set_visited(rn, cn, 0)?;
+                        queue.push_back((rn, cn));
+                    }
+                }
+            }
+        }
+        Ok(possible_outlets)
+    }
+}
+
+/// Breaches a pit by lowering the cells along the least-cost path to a cell that is low
+/// enough for the pit to drain to it, within a maximum search distance. The outcome is
+/// whether the pit was solved.
+pub(super) struct BreachTask {
+    pub nodata: f64,
+    pub small_num: f64,
+    pub max_dist: isize,
+    pub max_cost: f64,
+    pub minimize_dist: bool,
+    pub cost_dist: [f64; 8],
+}
+
+/// The working storage of a `BreachTask`, which covers the cells that the search for a
+/// breach path can reach from a pit.
+pub(super) struct BreachScratch {
+    minheap: BinaryHeap<GridCell>,
+    scanned_cells: Vec<(isize, isize)>,
+    backlink: Array2D<i8>,
+    encountered: Array2D<i8>,
+    path_length: Array2D<i16>,
+    grid_rows: isize,
+    grid_columns: isize,
+    // the grid row and column of the first cell of the search area
+    row: isize,
+    column: isize,
+}
+
+impl BreachScratch {
+    fn in_grid(&self, row: isize, column: isize) -> bool {
+        row >= 0 && column >= 0 && row < self.grid_rows && column < self.grid_columns
+    }
+
+    fn backlink(&self, row: isize, column: isize) -> i8 {
+        if !self.in_grid(row, column) {
+            return -2;
+        }
+        self.backlink
+            .get_value(row - self.row, column - self.column)
+    }
+
+    fn set_backlink(&mut self, row: isize, column: isize, value: i8) {
+        if self.in_grid(row, column) {
+            self.backlink
+                .set_value(row - self.row, column - self.column, value);
+        }
+    }
+
+    fn encountered(&self, row: isize, column: isize) -> i8 {
+        if !self.in_grid(row, column) {
+            return -1;
+        }
+        self.encountered
+            .get_value(row - self.row, column - self.column)
+    }
+
+    fn set_encountered(&mut self, row: isize, column: isize, value: i8) {
+        if self.in_grid(row, column) {
+            self.encountered
+                .set_value(row - self.row, column - self.column, value);
+        }
+    }
+
+    fn path_length(&self, row: isize, column: isize) -> i16 {
+        if !self.in_grid(row, column) {
+            return -1;
+        }
+        self.path_length
+            .get_value(row - self.row, column - self.column)
+    }
+
+    fn set_path_length(&mut self, row: isize, column: isize, value: i16) {
+        if self.in_grid(row, column) {
+            self.path_length
+                .set_value(row - self.row, column - self.column, value);
+        }
+    }
+}
+
+impl DepressionTask for BreachTask {
+    type Scratch = BreachScratch;
+    type Outcome = bool;
+
+    fn scratch(&self, rows: isize, columns: isize) -> Result<Self::Scratch, Error> {
+        let size = 2 * self.margin() + 1;
+        let (area_rows, area_columns) = (size.min(rows), size.min(columns));
+        Ok(BreachScratch {
+            minheap: BinaryHeap::new(),
+            scanned_cells: vec![],
+            backlink: Array2D::new(area_rows, area_columns, -1, -2)?,
+            encountered: Array2D::new(area_rows, area_columns, 0, -1)?,
+            path_length: Array2D::new(area_rows, area_columns, 0, -1)?,
+            grid_rows: rows,
+            grid_columns: columns,
+            row: 0,
+            column: 0,
+        })
+    }
+
+    fn margin(&self) -> isize {
+        // the search reaches one cell beyond the maximum path length.
+        self.max_dist.max(0) + 2
+    }
+
+    fn solve<S: DepressionState>(
+        &self,
+        state: &mut S,
+        pit: &Pit,
+        scratch: &mut Self::Scratch,
+    ) -> Result<Self::Outcome, Error> {
+        let nodata = self.nodata;
+        let small_num = self.small_num;
+        let backlink_dir = [4i8, 5, 6, 7, 0, 1, 2, 3];
+        let max_length = self.max_dist as i16;
+        let (row, col) = (pit.row, pit.column);
+        let z = state.elevation(row, col)?;
+
+        // Is it still a pit cell? It may have been solved during a previous depression solution.
+        for n in 0..8 {
+            let zn = state.elevation(row + DY[n], col + DX[n])?;
+            if zn < z && zn != nodata {
+                // It has a lower non-nodata cell
+                // Resolving some other pit cell resulted in a solution for this one.
+                return Ok(true);
+            }
+        }
+
+        // Centre the search area on the pit, keeping it within the grid.
+        let margin = self.margin();
+        scratch.row = (row - margin)
+            .min(scratch.grid_rows - scratch.backlink.rows)
+            .max(0);
+        scratch.column = (col - margin)
+            .min(scratch.grid_columns - scratch.backlink.columns)
+            .max(0);
+
+        // Perform the cost-accumulation operation.
+        let mut solved = false;
+        scratch.set_encountered(row, col, 1i8);
+        scratch.minheap.clear();
+        scratch.minheap.push(GridCell {
+            row,
+            column: col,
+            priority: 0f64,
+        });
+        scratch.scanned_cells.push((row, col));
+        let mut flag = true;
+        while flag {
+            let cell2 = match scratch.minheap.pop() {
+                Some(cell2) => cell2,
+                None => break,
+            };
+            if state.is_incomplete() {
+                break;
+            }
+            let accum = cell2.priority;
+            if accum > self.max_cost {
+                // There isn't a breach channel cheap enough
+                break;
+            }
+            let length = scratch.path_length(cell2.row, cell2.column);
+            let zn = state.elevation(cell2.row, cell2.column)?;
+            let cost1 = zn - z + length as f64 * small_num;
+            for n in 0..8 {
+                let mut cn = cell2.column + DX[n];
+                let mut rn = cell2.row + DY[n];
+                if scratch.encountered(rn, cn) != 1i8 {
+                    scratch.scanned_cells.push((rn, cn));
+                    // not yet encountered
+                    let length_n = length + 1;
+                    scratch.set_path_length(rn, cn, length_n);
+                    scratch.set_backlink(rn, cn, backlink_dir[n]);
+                    let mut zn = state.elevation(rn, cn)?;
+                    let mut zout = z - (length_n as f64 * small_num);
+                    if zn > zout && zn != nodata {
+                        let cost2 = zn - zout;
+                        let new_cost = if self.minimize_dist {
+                            accum + (cost1 + cost2) / 2f64 * self.cost_dist[n]
+                        } else {
+                            accum + cost2
+                        };
+                        scratch.set_encountered(rn, cn, 1i8);
+                        if length_n <= max_length {
+                            scratch.minheap.push(GridCell {
+                                row: rn,
+                                column: cn,
+                                priority: new_cost,
+                            });
+                        }
+                    } else if zn <= zout || zn == nodata {
+                        // We're at a cell that we can breach to
+                        while flag {
+                            // Find which cell to go to from here
+                            if scratch.backlink(rn, cn) > -1i8 {
+                                let b = scratch.backlink(rn, cn) as usize;
+                                rn += DY[b];
+                                cn += DX[b];
+                                zn = state.elevation(rn, cn)?;
+                                let length = scratch.path_length(rn, cn);
+                                zout = z - (length as f64 * small_num);
+                                if zn > zout {
+                                    state.set_elevation(rn, cn, zout)?;
+                                }
+                            } else {
+                                flag = false;
+                            }
+                        }
+                        solved = true;
+                        break; // don't check any more neighbours.
+                    }
+                }
+            }
+        }
+
+        // clear the intermediate rasters
+        while let Some(cell2) = scratch.scanned_cells.pop() {
+            scratch.set_backlink(cell2.0, cell2.1, -1i8);
+            scratch.set_encountered(cell2.0, cell2.1, 0i8);
+            scratch.set_path_length(cell2.0, cell2.1, 0i16);
+        }
+        Ok(solved)
+    }
+}
+
+/// Imposes a gradient on the flats of a filled DEM, so that they drain towards their outlets.
+/// The cells of each flat are raised in the order of their elevations in the original DEM,
+/// `input`, and `flats` holds the flats left by filling, which are marked with 1.
+pub(super) fn fix_flats<E, I, F>(
+    output: &mut E,
+    input: &mut I,
+    flats: &mut F,
+    mut possible_outlets: Vec<(isize, isize)>,
+    small_num: f64,
+    nodata: f64,
+    context: &ToolContext,
+) -> Result<(), Error>
+where
+    E: Grid<f64>,
+    I: Grid<f64>,
+    F: Grid<i8>,
+{
+    let mut minheap = BinaryHeap::new();
+    let mut minheap2 = BinaryHeap::new();
+    let mut old_progress = 0usize;
+    // Some of the potential outlets really will have lower cells.
+    while let Some(cell) = possible_outlets.pop() {
+        let z = output.get(cell.0, cell.1)?;
+        let mut flag = false;
+        for n in 0..8 {
+            let zn = output.get(cell.0 + DY[n], cell.1 + DX[n])?;
+            if zn < z && zn != nodata {
+                flag = true;
+                break;
+            }
+        }
+        if flag {
+            // it's confirmed as an outlet
+            minheap.push(GridCell {
+                row: cell.0,
+                column: cell.1,
+                priority: z,
+            });
+        }
+    }
+
+    let num_outlets = minheap.len();
+    let mut outlets = vec![];
+    while let Some(cell) = minheap.pop() {
+        context.check_cancelled()?;
+        if flats.get(cell.row, cell.column)? != 3 {
+            let mut z = output.get(cell.row, cell.column)?;
+            flats.set(cell.row, cell.column, 3)?;
+            outlets.clear();
+            outlets.push(cell);
+            // Are there any other outlet cells at the same elevation (likely for the same feature)
+            while let Some(cell2) = minheap.peek() {
+                if cell2.priority != z {
+                    break;
+                }
+                flats.set(cell2.row, cell2.column, 3)?;
+                outlets.push(minheap.pop().expect("Error during pop operation."));
+            }
+            minheap2.clear();
+            for cell2 in &outlets {
+                z = output.get(cell2.row, cell2.column)?;
+                for n in 0..8 {
+                    let rn = cell2.row + DY[n];
+                    let cn = cell2.column + DX[n];
+                    if flats.get(rn, cn)? != 3 {
+                        let zn = output.get(rn, cn)?;
+                        if zn == z && zn != nodata {
+                            minheap2.push(GridCell2 {
+                                row: rn,
+                                column: cn,
+                                z,
+                                priority: input.get(rn, cn)?,
+                            });
+                            output.set(rn, cn, z + small_num)?;
+                            flats.set(rn, cn, 3)?;
+                        }
+                    }
+                }
+            }
+            // Now fix the flats
+            while let Some(cell2) = minheap2.pop() {
+                z = output.get(cell2.row, cell2.column)?;
+                for n in 0..8 {
+                    let rn = cell2.row + DY[n];
+                    let cn = cell2.column + DX[n];
+                    if flats.get(rn, cn)? != 3 {
+                        let zn = output.get(rn, cn)?;
+                        if zn < z + small_num && zn >= cell2.z && zn != nodata {
+                            minheap2.push(GridCell2 {
+                                row: rn,
+                                column: cn,
+                                z: cell2.z,
+                                priority: input.get(rn, cn)?,
+                            });
+                            output.set(rn, cn, z + small_num)?;
+                            flats.set(rn, cn, 3)?;
+                        }
+                    }
+                }
+            }
+        }
+
+        let progress = (100.0_f64 * (1f64 - minheap.len() as f64 / num_outlets as f64)) as usize;
+        if progress != old_progress {
+            context.progress("Fixing flats", progress)?;
+            old_progress = progress;
+        }
+    }
+    Ok(())
+}
+
+// The flow direction of the cells on the edges of the DEM and its nodata, from which the
+// breaching flood starts, until the flow directions are found.
+const SEED: i8 = -2;
+// A cell that hasn't been labelled.
+const NO_LABEL: u32 = u32::MAX;
+// The flow direction of the cells that the flood doesn't reach, and of nodata cells.
+const UNREACHED: i8 = -3;
+// A cell whose breached elevation has been found.
+const DONE: u32 = u32::MAX;
+// The approximate number of cells in each batch of depressions that are ordered together.
+const CELLS_PER_BATCH: isize = 16_384;
+
+/// The order in which BreachDepressions floods its cells, i.e. by elevation and then in
+/// row-major order. It is also the level, or spill elevation, of a cell: the lowest cell that
+/// the flood must pass through, on its way from the edges of the DEM, to reach it.
+#[derive(Clone, Copy, Debug)]
+struct Level {
+    z: f64,
+    index: i64,
+}
+
+impl Level {
+    // The level of a cell that the flood never reaches.
+    const NONE: Level = Level {
+        z: f64::INFINITY,
+        index: i64::MAX,
+    };
+}
+
+impl PartialEq for Level {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Equal
+    }
+}
+
+impl Eq for Level {}
+
+impl PartialOrd for Level {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Level {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.z
+            .partial_cmp(&other.z)
+            .unwrap_or(Equal)
+            .then(self.index.cmp(&other.index))
+    }
+}
+
+// A tile of a grid.
+#[derive(Clone, Copy)]
+struct Tile {
+    row: isize,
+    column: isize,
+    rows: isize,
+    columns: isize,
+}
+
+impl Tile {
+    fn contains(&self, row: isize, column: isize) -> bool {
+        row >= self.row
+            && column >= self.column
+            && row < self.row + self.rows
+            && column < self.column + self.columns
+    }
+
+    // The cells of the tile, in row-major order.
+    fn cells(&self) -> impl Iterator<Item = (isize, isize)> {
+        let (row, column, columns) = (self.row, self.column, self.columns);
+        (0..self.rows * self.columns).map(move |i| (row + i / columns, column + i % columns))
+    }
+}
+
+fn tiles(rows: isize, columns: isize) -> Vec<Tile> {
+    let mut tiles = vec![];
+    for row in (0..rows).step_by(TILE_SIZE as usize) {
+        for column in (0..columns).step_by(TILE_SIZE as usize) {
+            tiles.push(Tile {
+                row,
+                column,
+                rows: TILE_SIZE.min(rows - row),
+                columns: TILE_SIZE.min(columns - column),
+            });
+        }
+    }
+    tiles
+}
+
+// Calls `f` with each pair of neighbouring cells that lie in different tiles.
+fn tile_edges(
+    rows: isize,
+    columns: isize,
+    mut f: impl FnMut(isize, isize, isize, isize) -> Result<(), Error>,
+) -> Result<(), Error> {
+    for tile in tiles(rows, columns) {
+        for (row, col) in tile.cells() {
+            if row != tile.row
+                && col != tile.column
+                && row != tile.row + tile.rows - 1
+                && col != tile.column + tile.columns - 1
+            {
+                continue;
+            }
+            // the neighbours to the east, south-east, south and south-west, so that each
+            // pair is visited once
+            for n in 1..5 {
+                let (row_n, col_n) = (row + DY[n], col + DX[n]);
+                if row_n < rows && col_n >= 0 && col_n < columns && !tile.contains(row_n, col_n)
+                {
+                    f(row, col, row_n, col_n)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// A copy of a window of a grid, addressed by the rows and columns of the grid. Cells outside of
+// the grid hold the grid's nodata value.
+struct Patch<T> {
+    row: isize,
+    column: isize,
+    rows: isize,
+    columns: isize,
+    values: Vec<T>,
+}
+
+impl<T: Copy> Patch<T> {
+    fn read<G: Grid<T>>(
+        grid: &mut G,
+        row: isize,
+        column: isize,
+        rows: isize,
+        columns: isize,
+    ) -> Result<Patch<T>, Error> {
+        Ok(Patch {
+            row,
+            column,
+            rows,
+            columns,
+            values: grid.read_window(row, column, rows, columns)?,
+        })
+    }
+
+    // Reads a tile, along with a margin of `margin` cells around it.
+    fn read_tile<G: Grid<T>>(grid: &mut G, tile: &Tile, margin: isize) -> Result<Patch<T>, Error> {
+        Patch::read(
+            grid,
+            tile.row - margin,
+            tile.column - margin,
+            tile.rows + 2 * margin,
+            tile.columns + 2 * margin,
+        )
+    }
+
+    fn contains(&self, row: isize, column: isize) -> bool {
+        row >= self.row
+            && column >= self.column
+            && row < self.row + self.rows
+            && column < self.column + self.columns
+    }
+
+    // The cells of the patch, in row-major order.
+    fn cells(&self) -> impl Iterator<Item = (isize, isize)> {
+        let (row, column, columns) = (self.row, self.column, self.columns);
+        (0..self.rows * self.columns).map(move |i| (row + i / columns, column + i % columns))
+    }
+
+    fn index(&self, row: isize, column: isize) -> usize {
+        ((row - self.row) * self.columns + column - self.column) as usize
+    }
+
+    fn get(&self, row: isize, column: isize) -> T {
+        self.values[self.index(row, column)]
+    }
+
+    fn set(&mut self, row: isize, column: isize, value: T) {
+        let i = self.index(row, column);
+        self.values[i] = value;
+    }
+
+    fn write<G: Grid<T>>(&self, grid: &mut G) -> Result<(), Error> {
+        for r in self.row.max(0)..(self.row + self.rows).min(grid.rows()) {
+            for c in self.column.max(0)..(self.column + self.columns).min(grid.columns()) {
+                grid.set(r, c, self.get(r, c))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs `work` on each of `num_jobs` jobs, in parallel. The input of each job is read by
+/// `read`, and its result is passed to `write` as soon as it is ready, both on the calling
+/// thread and with `state`, which holds the grids, so that only the calling thread accesses
+/// them. No more than two jobs per thread are held at once.
+#[allow(clippy::too_many_arguments)]
+fn run_jobs<S, J, R, Read, Work, Write>(
+    num_jobs: usize,
+    state: &mut S,
+    num_procs: isize,
+    context: &ToolContext,
+    label: &str,
+    mut read: Read,
+    work: Work,
+    mut write: Write,
+) -> Result<(), Error>
+where
+    J: Send,
+    R: Send,
+    Read: FnMut(&mut S, usize) -> Result<J, Error>,
+    Work: Fn(J) -> R + Sync,
+    Write: FnMut(&mut S, usize, R) -> Result<(), Error>,
+{
+    let num_procs = num_procs.max(1) as usize;
+    let (job_tx, job_rx) = mpsc::channel::<(usize, J)>();
+    let job_rx = Mutex::new(job_rx);
+    let (tx, rx) = mpsc::channel();
+    thread::scope(|s| -> Result<(), Error> {
+        for _ in 0..num_procs {
+            let (job_rx, tx, work) = (&job_rx, tx.clone(), &work);
+            s.spawn(move || loop {
+                let job = match job_rx.lock() {
+                    Ok(job_rx) => job_rx.recv(),
+                    Err(_) => break,
+                };
+                match job {
+                    Ok((j, job)) => {
+                        if tx.send((j, work(job))).is_err() {
+                            break;
+                        }
+                    }
+                    Err(_) => break,
+                }
+            });
+        }
+        drop(tx);
+
+        let (mut sent, mut done) = (0, 0);
+        let mut old_progress = 0usize;
+        while done < num_jobs {
+            context.check_cancelled()?;
+            if sent < num_jobs && sent - done < 2 * num_procs {
+                let job = read(state, sent)?;
+                if job_tx.send((sent, job)).is_err() {
+                    break;
+                }
+                sent += 1;
+                continue;
+            }
+            let (j, result) = match rx.recv() {
+                Ok(result) => result,
+                Err(_) => break,
+            };
+            write(state, j, result)?;
+            done += 1;
+            let progress = (100.0_f64 * done as f64 / num_jobs as f64) as usize;
+            if progress != old_progress {
+                context.progress(label, progress)?;
+                old_progress = progress;
+            }
+        }
+        drop(job_tx);
+        if done < num_jobs {
+            return Err(Error::other("Error receiving data from thread."));
+        }
+        Ok(())
+    })
+}
+
+// The regions of connected nodata cells. They are labelled within each tile and joined across
+// the edges of the tiles.
+#[derive(Default)]
+struct NodataRegions {
+    parents: Vec<u32>,
+    // whether the region touches the edge of the grid
+    outer: Vec<bool>,
+    // whether the region neighbours a cell that the flood reaches
+    reached: Vec<bool>,
+}
+
+impl NodataRegions {
+    fn root(&mut self, mut region: u32) -> u32 {
+        while self.parents[region as usize] != region {
+            let parent = self.parents[self.parents[region as usize] as usize];
+            self.parents[region as usize] = parent;
+            region = parent;
+        }
+        region
+    }
+
+    fn join(&mut self, a: u32, b: u32) {
+        let (a, b) = (self.root(a), self.root(b));
+        if a != b {
+            self.parents[a.max(b) as usize] = a.min(b);
+            self.outer[a.min(b) as usize] |= self.outer[a.max(b) as usize];
+        }
+    }
+
+    // Points each region directly at its root, and gives it the root's flags.
+    fn resolve(&mut self) {
+        for region in 0..self.parents.len() as u32 {
+            let root = self.root(region);
+            self.outer[region as usize] = self.outer[root as usize];
+        }
+    }
+}
+
+// Labels the regions of connected nodata cells within a tile of a DEM. Returns the labels,
+// which are numbered from zero, and whether each region touches the edge of the grid.
+fn label_nodata(
+    dem: &Patch<f64>,
+    nodata: f64,
+    rows: isize,
+    columns: isize,
+) -> (Patch<u32>, Vec<bool>) {
+    let mut labels = Patch {
+        row: dem.row,
+        column: dem.column,
+        rows: dem.rows,
+        columns: dem.columns,
+        values: vec![NO_LABEL; dem.values.len()],
+    };
+    let mut outer = vec![];
+    let mut queue = VecDeque::new();
+    for row in dem.row..dem.row + dem.rows {
+        for col in dem.column..dem.column + dem.columns {
+            if dem.get(row, col) != nodata || labels.get(row, col) != NO_LABEL {
+                continue;
+            }
+            let label = outer.len() as u32;
+            outer.push(false);
+            labels.set(row, col, label);
+            queue.push_back((row, col));
+            while let Some((r, c)) = queue.pop_front() {
+                if r == 0 || c == 0 || r == rows - 1 || c == columns - 1 {
+                    outer[label as usize] = true;
+                }
+                for n in 0..8 {
+                    let (r_n, c_n) = (r + DY[n], c + DX[n]);
+                    if dem.contains(r_n, c_n)
+                        && dem.get(r_n, c_n) == nodata
+                        && labels.get(r_n, c_n) == NO_LABEL
+                    {
+                        labels.set(r_n, c_n, label);
+                        queue.push_back((r_n, c_n));
+                    }
+                }
+            }
+        }
+    }
+    (labels, outer)
+}
+
+// The flood of a tile from its edges and from the cells that it shares with the edges of the
+// DEM and its nodata.
+struct TileFlood {
+    // the label of each cell, i.e. the cell on the tile's edges from which it was flooded
+    labels: Patch<u32>,
+    // the level of each cell within the tile
+    levels: Vec<Level>,
+    num_labels: u32,
+    // the lowest level at which the floods of each pair of neighbouring labels meet
+    edges: Vec<(u32, u32, Level)>,
+    // the labels of the cells on the edges of the DEM and its nodata
+    seeds: Vec<(u32, Level)>,
+}
+
+// Floods a tile of a DEM, which has a margin of one cell, from the cells on the tile's edges.
+// `labels` holds the nodata regions of the DEM, and `outer` whether each region touches the
+// edge of the grid.
+fn flood_tile(
+    tile: &Tile,
+    dem: &Patch<f64>,
+    labels: &Patch<u32>,
+    outer: &[bool],
+    nodata: f64,
+    rows: isize,
+    columns: isize,
+) -> TileFlood {
+    let key = |row: isize, col: isize| Level {
+        z: dem.get(row, col),
+        index: (row * columns + col) as i64,
+    };
+    let cell = |level: Level| (level.index as isize / columns, level.index as isize % columns);
+    let mut flood = TileFlood {
+        labels: Patch {
+            row: tile.row,
+            column: tile.column,
+            rows: tile.rows,
+            columns: tile.columns,
+            values: vec![NO_LABEL; (tile.rows * tile.columns) as usize],
+        },
+        levels: vec![Level::NONE; (tile.rows * tile.columns) as usize],
+        num_labels: 0,
+        edges: vec![],
+        seeds: vec![],
+    };
+    let local = |row: isize, col: isize| ((row - tile.row) * tile.columns + col - tile.column) as usize;
+
+    // The flood starts from each of the cells on the edges of the tile, and of the DEM and its
+    // nodata, with a label of its own.
+    let mut heap = BinaryHeap::new();
+    for (row, col) in tile.cells() {
+        if dem.get(row, col) == nodata {
+            continue;
+        }
+        let mut is_seed = false;
+        for n in 0..8 {
+            let (row_n, col_n) = (row + DY[n], col + DX[n]);
+            if row_n < 0
+                || col_n < 0
+                || row_n >= rows
+                || col_n >= columns
+                || (dem.get(row_n, col_n) == nodata && outer[labels.get(row_n, col_n) as usize])
+            {
+                is_seed = true;
+            }
+        }
+        let on_edge = row == tile.row
+            || col == tile.column
+            || row == tile.row + tile.rows - 1
+            || col == tile.column + tile.columns - 1;
+        if is_seed || on_edge {
+            let label = flood.num_labels;
+            flood.num_labels += 1;
+            flood.labels.set(row, col, label);
+            flood.levels[local(row, col)] = key(row, col);
+            if is_seed {
+                flood.seeds.push((label, key(row, col)));
+            }
+            heap.push(Reverse(key(row, col)));
+        }
+    }
+    while let Some(Reverse(level)) = heap.pop() {
+        let (row, col) = cell(level);
+        let label = flood.labels.get(row, col);
+        let level = flood.levels[local(row, col)];
+        for n in 0..8 {
+            let (row_n, col_n) = (row + DY[n], col + DX[n]);
+            if tile.contains(row_n, col_n)
+                && dem.get(row_n, col_n) != nodata
+                && flood.labels.get(row_n, col_n) == NO_LABEL
+            {
+                flood.labels.set(row_n, col_n, label);
+                flood.levels[local(row_n, col_n)] = level.max(key(row_n, col_n));
+                heap.push(Reverse(key(row_n, col_n)));
+            }
+        }
+    }
+
+    let mut edges = HashMap::new();
+    for (row, col) in tile.cells() {
+        let label = flood.labels.get(row, col);
+        if label == NO_LABEL {
+            continue;
+        }
+        for n in 1..5 {
+            let (row_n, col_n) = (row + DY[n], col + DX[n]);
+            if !tile.contains(row_n, col_n) {
+                continue;
+            }
+            let label_n = flood.labels.get(row_n, col_n);
+            if label_n != NO_LABEL && label_n != label {
+                let level = flood.levels[local(row, col)].max(flood.levels[local(row_n, col_n)]);
+                let edge = edges
+                    .entry((label.min(label_n), label.max(label_n)))
+                    .or_insert(level);
+                *edge = level.min(*edge);
+            }
+        }
+    }
+    flood.edges = edges.into_iter().map(|((a, b), level)| (a, b, level)).collect();
+    flood
+}
+
+// Returns the spill level of each label, i.e. the lowest level at which the flood from the
+// seeds reaches it across the graph of neighbouring labels.
+fn spill_levels(num_labels: usize, edges: &[(u32, u32, Level)], seeds: &[(u32, Level)]) -> Vec<Level> {
+    // the edges of each label, which are those from offsets[label] to offsets[label + 1]
+    let mut offsets = vec![0usize; num_labels + 1];
+    for &(a, b, _) in edges {
+        offsets[a as usize + 1] += 1;
+        offsets[b as usize + 1] += 1;
+    }
+    for label in 0..num_labels {
+        offsets[label + 1] += offsets[label];
+    }
+    let mut next = offsets.clone();
+    let mut neighbours = vec![(0u32, Level::NONE); 2 * edges.len()];
+    for &(a, b, level) in edges {
+        neighbours[next[a as usize]] = (b, level);
+        next[a as usize] += 1;
+        neighbours[next[b as usize]] = (a, level);
+        next[b as usize] += 1;
+    }
+
+    let mut spill = vec![Level::NONE; num_labels];
+    let mut heap: BinaryHeap<Reverse<(Level, u32)>> =
+        seeds.iter().map(|&(label, level)| Reverse((level, label))).collect();
+    while let Some(Reverse((level, label))) = heap.pop() {
+        if spill[label as usize] != Level::NONE {
+            continue;
+        }
+        spill[label as usize] = level;
+        for &(label_n, level_n) in &neighbours[offsets[label as usize]..offsets[label as usize + 1]] {
+            if spill[label_n as usize] == Level::NONE {
+                heap.push(Reverse((level.max(level_n), label_n)));
+            }
+        }
+    }
+    spill
+}
+
+// The extent of a depression, i.e. of the cells whose level is that of another cell, the
+// depression's outlet, along with the number of its cells and one of them.
+#[derive(Clone, Copy)]
+struct Depression {
+    outlet: i64,
+    row: isize,
+    column: isize,
+    last_row: isize,
+    last_column: isize,
+    num_cells: isize,
+    cell: (isize, isize),
+}
+
+impl Depression {
+    // A depression of a single cell.
+    fn new(outlet: i64, row: isize, column: isize, grid_columns: isize) -> Depression {
+        let (row_o, col_o) = (outlet as isize / grid_columns, outlet as isize % grid_columns);
+        Depression {
+            outlet,
+            row: row.min(row_o),
+            column: column.min(col_o),
+            last_row: row.max(row_o),
+            last_column: column.max(col_o),
+            num_cells: 1,
+            cell: (row, column),
+        }
+    }
+
+    fn merge(&mut self, other: &Depression) {
+        self.row = self.row.min(other.row);
+        self.column = self.column.min(other.column);
+        self.last_row = self.last_row.max(other.last_row);
+        self.last_column = self.last_column.max(other.last_column);
+        self.num_cells += other.num_cells;
+    }
+
+    fn rows(&self) -> isize {
+        self.last_row - self.row + 1
+    }
+
+    fn columns(&self) -> isize {
+        self.last_column - self.column + 1
+    }
+}
+
+// Returns the order in which the flood fills the depression that spills through the cell
+// `outlet`, i.e. the cells whose level is that of the outlet. `levels` and `dem` cover the
+// depression and its outlet.
+fn depression_order(
+    outlet: i64,
+    levels: &Patch<i64>,
+    dem: &Patch<f64>,
+    columns: isize,
+) -> Vec<(isize, isize, u32)> {
+    let key = |row: isize, col: isize| Level {
+        z: dem.get(row, col),
+        index: (row * columns + col) as i64,
+    };
+    let mut visited = vec![false; levels.values.len()];
+    let (row, col) = (outlet as isize / columns, outlet as isize % columns);
+    visited[levels.index(row, col)] = true;
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse(key(row, col)));
+    let mut order = vec![];
+    while let Some(Reverse(level)) = heap.pop() {
+        let (row, col) = (level.index as isize / columns, level.index as isize % columns);
+        if level.index != outlet {
+            order.push((row, col, order.len() as u32 + 1));
+        }
+        for n in 0..8 {
+            let (row_n, col_n) = (row + DY[n], col + DX[n]);
+            if levels.contains(row_n, col_n) && levels.get(row_n, col_n) == outlet {
+                let i = levels.index(row_n, col_n);
+                if !visited[i] {
+                    visited[i] = true;
+                    heap.push(Reverse(key(row_n, col_n)));
+                }
+            }
+        }
+    }
+    order
+}
+
+// The cells of a tile whose breached elevations are to be found, along with the grids that
+// are needed to find them.
+struct BreachJob {
+    tile: Tile,
+    // the DEM and the flow directions, with a margin of one cell
+    dem: Patch<f64>,
+    flow_dir: Patch<i8>,
+    // the lowest elevation that has reached each cell from the cells that flow to it
+    output: Patch<f64>,
+    // the number of cells that flow to each cell and haven't been solved
+    counts: Patch<u32>,
+    // the cells that are ready to be solved, or None if the tile hasn't been started
+    ready: Option<Vec<(isize, isize)>>,
+}
+
+// Solves the cells of a tile whose upstream cells have been solved, returning the elevations
+// that are passed on to cells in other tiles.
+fn breach_tile(mut job: BreachJob, small_num: f64) -> (BreachJob, Vec<(isize, isize, f64)>) {
+    let tile = job.tile;
+    let mut ready = match job.ready.take() {
+        Some(ready) => ready,
+        None => {
+            for (row, col) in tile.cells() {
+                if job.flow_dir.get(row, col) != UNREACHED {
+                    job.output.set(row, col, f64::INFINITY);
+                }
+            }
+            for row in job.flow_dir.row..job.flow_dir.row + job.flow_dir.rows {
+                for col in job.flow_dir.column..job.flow_dir.column + job.flow_dir.columns {
+                    let dir = job.flow_dir.get(row, col);
+                    if dir >= 0 {
+                        let (row_n, col_n) = (row + DY[dir as usize], col + DX[dir as usize]);
+                        if tile.contains(row_n, col_n) {
+                            job.counts.set(row_n, col_n, job.counts.get(row_n, col_n) + 1);
+                        }
+                    }
+                }
+            }
+            tile.cells()
+                .filter(|&(row, col)| {
+                    job.flow_dir.get(row, col) != UNREACHED && job.counts.get(row, col) == 0
+                })
+                .collect()
+        }
+    };
+
+    let mut sent = vec![];
+    while let Some((row, col)) = ready.pop() {
+        let z = job.dem.get(row, col);
+        let z_out = job.output.get(row, col).min(z);
+        job.output.set(row, col, z_out);
+        job.counts.set(row, col, DONE);
+        let dir = job.flow_dir.get(row, col);
+        if dir < 0 {
+            continue;
+        }
+        let (row_n, col_n) = (row + DY[dir as usize], col + DX[dir as usize]);
+        // A cell that is breached, or that isn't higher than the cell that it flows to,
+        // lowers the cells downstream of it, as far as they are higher.
+        let z_n = if z_out < z || z < job.dem.get(row_n, col_n) + small_num {
+            z_out - small_num
+        } else {
+            f64::INFINITY
+        };
+        if tile.contains(row_n, col_n) {
+            if z_n < job.output.get(row_n, col_n) {
+                job.output.set(row_n, col_n, z_n);
+            }
+            let count = job.counts.get(row_n, col_n) - 1;
+            job.counts.set(row_n, col_n, count);
+            if count == 0 {
+                ready.push((row_n, col_n));
+            }
+        } else {
+            sent.push((row_n, col_n, z_n));
+        }
+    }
+    (job, sent)
+}
+
+// The grids of a DEM that is being breached.
+struct BreachGrids<'a, E, O> {
+    dem: &'a mut E,
+    output: &'a mut O,
+    // The nodata regions of the DEM and then the labels of the tiles' floods, which are
+    // replaced by the order of the cells within their depressions.
+    labels: Array2D<u32>,
+    // the index of the cell whose elevation is each cell's level, or -1
+    levels: Array2D<i64>,
+    flow_dir: Array2D<i8>,
+    regions: NodataRegions,
+    num_labels: u32,
+    edges: Vec<(u32, u32, Level)>,
+    seeds: Vec<(u32, Level)>,
+}
+
+/// Breaches the depressions of a DEM exactly as BreachDepressions does with a single priority
+/// flood from the edges of the DEM and its nodata, in which cells of equal elevation leave the
+/// queue in row-major order. `output`, which must hold `background` in every cell, receives the
+/// breached DEM, and cells that the flood doesn't reach keep that value.
+///
+/// The flood visits the cells in the order of their levels, where the level of a cell is the
+/// lowest cell that the flood must pass through to reach it, and each depression is flooded
+/// from its outlet as soon as that is reached. The levels are found by flooding each tile from
+/// its edges, in parallel, and then solving the graph of the spill elevations between the
+/// labels of the tiles' floods, and the order within each depression by flooding it from its
+/// outlet. A cell is reached from the neighbour that the flood visits first, and the breached
+/// elevations are then passed down the resulting tree from its leaves, tile by tile, in rounds.
+pub(super) fn breach_depressions<E: Grid<f64>, O: Grid<f64>>(
+    dem: &mut E,
+    output: &mut O,
+    nodata: f64,
+    background: f64,
+    small_num: f64,
+    num_procs: isize,
+    context: &ToolContext,
+) -> Result<(), Error> {
+    let rows = dem.rows();
+    let columns = dem.columns();
+    let tiles = tiles(rows, columns);
+    let tiles_across = (columns + TILE_SIZE - 1) / TILE_SIZE;
+    let mut grids = BreachGrids {
+        dem,
+        output,
+        labels: Array2D::new(rows, columns, NO_LABEL, NO_LABEL)?,
+        levels: Array2D::new(rows, columns, -1, -1)?,
+        flow_dir: Array2D::new(rows, columns, -1, -1)?,
+        regions: NodataRegions::default(),
+        num_labels: 0,
+        edges: vec![],
+        seeds: vec![],
+    };
+
+    // Label the nodata regions, to tell the regions connected to the edges of the grid, which
+    // the flood starts from, from those within the DEM, which are walls.
+    run_jobs(
+        tiles.len(),
+        &mut grids,
+        num_procs,
+        context,
+        "Finding nodata regions",
+        |g, t| Patch::read_tile(g.dem, &tiles[t], 0),
+        |dem| label_nodata(&dem, nodata, rows, columns),
+        |g, _, (labels, outer)| {
+            let offset = g.regions.parents.len() as u32;
+            for (row, col) in labels.cells() {
+                let label = labels.get(row, col);
+                if label != NO_LABEL {
+                    g.labels.set_value(row, col, label + offset);
+                }
+            }
+            g.regions.parents.extend(offset..offset + outer.len() as u32);
+            g.regions.reached.extend(outer.iter().map(|_| false));
+            g.regions.outer.extend(outer);
+            Ok(())
+        },
+    )?;
+    tile_edges(rows, columns, |row, col, row_n, col_n| {
+        if grids.dem.get(row, col)? == nodata && grids.dem.get(row_n, col_n)? == nodata {
+            let (a, b) = (
+                grids.labels.get_value(row, col),
+                grids.labels.get_value(row_n, col_n),
+            );
+            grids.regions.join(a, b);
+        }
+        Ok(())
+    })?;
+    grids.regions.resolve();
+
+    // Flood each tile from its edges, and join the labels of the floods into a graph.
+    let outer = grids.regions.outer.clone();
+    run_jobs(
+        tiles.len(),
+        &mut grids,
+        num_procs,
+        context,
+        "Flooding tiles",
+        |g, t| {
+            Ok((
+                t,
+                Patch::read_tile(g.dem, &tiles[t], 1)?,
+                Patch::read_tile(&mut g.labels, &tiles[t], 1)?,
+            ))
+        },
+        |(t, dem, labels)| flood_tile(&tiles[t], &dem, &labels, &outer, nodata, rows, columns),
+        |g, t, flood| {
+            let offset = g.num_labels;
+            for (row, col) in tiles[t].cells() {
+                let label = flood.labels.get(row, col);
+                if label != NO_LABEL {
+                    let level = flood.levels[((row - tiles[t].row) * tiles[t].columns
+                        + col
+                        - tiles[t].column) as usize];
+                    g.labels.set_value(row, col, label + offset);
+                    g.levels.set_value(row, col, level.index);
+                    g.output.set(row, col, level.z)?;
+                }
+            }
+            for (label, level) in flood.seeds {
+                let (row, col) = (level.index as isize / columns, level.index as isize % columns);
+                g.flow_dir.set_value(row, col, SEED);
+                g.seeds.push((label + offset, level));
+            }
+            g.edges.extend(
+                flood
+                    .edges
+                    .into_iter()
+                    .map(|(a, b, level)| (a + offset, b + offset, level)),
+            );
+            g.num_labels += flood.num_labels;
+            Ok(())
+        },
+    )?;
+    tile_edges(rows, columns, |row, col, row_n, col_n| {
+        let (z, z_n) = (grids.dem.get(row, col)?, grids.dem.get(row_n, col_n)?);
+        if z != nodata && z_n != nodata {
+            let level = Level {
+                z,
+                index: (row * columns + col) as i64,
+            }
+            .max(Level {
+                z: z_n,
+                index: (row_n * columns + col_n) as i64,
+            });
+            let (a, b) = (
+                grids.labels.get_value(row, col),
+                grids.labels.get_value(row_n, col_n),
+            );
+            grids.edges.push((a, b, level));
+        }
+        Ok(())
+    })?;
+    context.check_cancelled()?;
+    let spill = spill_levels(grids.num_labels as usize, &grids.edges, &grids.seeds);
+    grids.edges = vec![];
+    grids.seeds = vec![];
+
+    // A cell's level is the higher of the spill level of its label and its level within its
+    // tile. The nodata regions that the flood reaches are marked.
+    let roots = grids.regions.parents.clone();
+    let is_reached = |label: u32| label != NO_LABEL && spill[label as usize] != Level::NONE;
+    run_jobs(
+        tiles.len(),
+        &mut grids,
+        num_procs,
+        context,
+        "Solving levels",
+        |g, t| {
+            Ok((
+                t,
+                Patch::read_tile(g.dem, &tiles[t], 1)?,
+                Patch::read_tile(&mut g.labels, &tiles[t], 1)?,
+                Patch::read_tile(&mut g.levels, &tiles[t], 0)?,
+                Patch::read_tile(g.output, &tiles[t], 0)?,
+            ))
+        },
+        |(t, dem, labels, mut levels, mut output)| {
+            let mut reached = vec![];
+            for (row, col) in tiles[t].cells() {
+                let label = labels.get(row, col);
+                if dem.get(row, col) != nodata {
+                    let level = if is_reached(label) {
+                        spill[label as usize].max(Level {
+                            z: output.get(row, col),
+                            index: levels.get(row, col),
+                        })
+                    } else {
+                        Level::NONE
+                    };
+                    if level == Level::NONE {
+                        levels.set(row, col, -1);
+                        output.set(row, col, background);
+                    } else {
+                        levels.set(row, col, level.index);
+                        output.set(row, col, level.z);
+                    }
+                } else if (0..8).any(|n| {
+                    let (row_n, col_n) = (row + DY[n], col + DX[n]);
+                    dem.get(row_n, col_n) != nodata && is_reached(labels.get(row_n, col_n))
+                }) {
+                    reached.push(roots[label as usize]);
+                }
+            }
+            (levels, output, reached)
+        },
+        |g, _, (levels, output, reached)| {
+            levels.write(&mut g.levels)?;
+            output.write(g.output)?;
+            for root in reached {
+                g.regions.reached[root as usize] = true;
+            }
+            Ok(())
+        },
+    )?;
+    drop(spill);
+
+    // Interior nodata regions are left as nodata if the flood reaches them, as are those
+    // connected to the edges of the grid.
+    for row in 0..rows {
+        for col in 0..columns {
+            if grids.dem.get(row, col)? == nodata {
+                let region = grids.labels.get_value(row, col) as usize;
+                if grids.regions.outer[region] || grids.regions.reached[roots[region] as usize] {
+                    grids.output.set(row, col, nodata)?;
+                }
+            }
+        }
+    }
+    drop(roots);
+
+    // Find the extent of each depression, and then the order in which the flood fills it. A
+    // depression of one cell needn't be flooded.
+    let mut depressions: HashMap<i64, Depression> = HashMap::new();
+    run_jobs(
+        tiles.len(),
+        &mut grids,
+        num_procs,
+        context,
+        "Finding depressions",
+        |g, t| Patch::read_tile(&mut g.levels, &tiles[t], 0),
+        |levels| {
+            let mut depressions: HashMap<i64, Depression> = HashMap::new();
+            for (row, col) in levels.cells() {
+                let outlet = levels.get(row, col);
+                if outlet >= 0 && outlet != (row * columns + col) as i64 {
+                    let depression = Depression::new(outlet, row, col, columns);
+                    depressions
+                        .entry(outlet)
+                        .and_modify(|d| d.merge(&depression))
+                        .or_insert(depression);
+                }
+            }
+            depressions
+        },
+        |_, _, found| {
+            for (outlet, depression) in found {
+                depressions
+                    .entry(outlet)
+                    .and_modify(|d| d.merge(&depression))
+                    .or_insert(depression);
+            }
+            Ok(())
+        },
+    )?;
+    grids.labels.reinitialize_values(0);
+    let mut depressions: Vec<Depression> = depressions.into_values().collect();
+    depressions.sort_by_key(|d| d.outlet);
+    depressions.retain(|d| {
+        if d.num_cells == 1 {
+            grids.labels.set_value(d.cell.0, d.cell.1, 1);
+        }
+        d.num_cells > 1
+    });
+    let mut batches = vec![];
+    let (mut first, mut num_cells) = (0, 0);
+    for (d, depression) in depressions.iter().enumerate() {
+        num_cells += depression.rows() * depression.columns();
+        if num_cells >= CELLS_PER_BATCH || d + 1 == depressions.len() {
+            batches.push(first..d + 1);
+            first = d + 1;
+            num_cells = 0;
+        }
+    }
+    run_jobs(
+        batches.len(),
+        &mut grids,
+        num_procs,
+        context,
+        "Ordering depressions",
+        |g, b| {
+            let mut windows = Vec::with_capacity(batches[b].len());
+            for d in &depressions[batches[b].clone()] {
+                windows.push((
+                    d.outlet,
+                    Patch::read(&mut g.levels, d.row, d.column, d.rows(), d.columns())?,
+                    Patch::read(g.dem, d.row, d.column, d.rows(), d.columns())?,
+                ));
+            }
+            Ok(windows)
+        },
+        |windows| {
+            windows
+                .iter()
+                .map(|(outlet, levels, dem)| depression_order(*outlet, levels, dem, columns))
+                .collect::<Vec<_>>()
+        },
+        |g, _, orders| {
+            for (row, col, rank) in orders.into_iter().flatten() {
+                g.labels.set_value(row, col, rank);
+            }
+            Ok(())
+        },
+    )?;
+    drop(depressions);
+
+    // Each cell is reached from the neighbour that the flood visits first, which is the one
+    // with the lowest level and, within a depression, the one filled first.
+    run_jobs(
+        tiles.len(),
+        &mut grids,
+        num_procs,
+        context,
+        "Finding flow directions",
+        |g, t| {
+            Ok((
+                t,
+                Patch::read_tile(&mut g.levels, &tiles[t], 1)?,
+                Patch::read_tile(g.output, &tiles[t], 1)?,
+                Patch::read_tile(&mut g.labels, &tiles[t], 1)?,
+                Patch::read_tile(&mut g.flow_dir, &tiles[t], 0)?,
+            ))
+        },
+        |(t, levels, output, ranks, mut flow_dir)| {
+            let offsets: Vec<isize> = (0..8).map(|n| DY[n] * levels.columns + DX[n]).collect();
+            for (row, col) in tiles[t].cells() {
+                let i = levels.index(row, col);
+                if levels.values[i] < 0 {
+                    flow_dir.set(row, col, UNREACHED);
+                    continue;
+                }
+                if flow_dir.get(row, col) == SEED {
+                    flow_dir.set(row, col, -1);
+                    continue;
+                }
+                let mut first = (Level::NONE, u32::MAX);
+                for (n, offset) in offsets.iter().enumerate() {
+                    let i_n = (i as isize + offset) as usize;
+                    let index = levels.values[i_n];
+                    if index >= 0 {
+                        let order = (
+                            Level {
+                                z: output.values[i_n],
+                                index,
+                            },
+                            ranks.values[i_n],
+                        );
+                        if order < first {
+                            first = order;
+                            flow_dir.set(row, col, n as i8);
+                        }
+                    }
+                }
+            }
+            flow_dir
+        },
+        |g, _, flow_dir| flow_dir.write(&mut g.flow_dir),
+    )?;
+    let BreachGrids {
+        dem,
+        output,
+        mut flow_dir,
+        ..
+    } = grids;
+
+    // Pass the breached elevations down the flow directions. Each round solves the cells of
+    // the tiles whose upstream cells are solved, and passes the elevations that leave a tile
+    // on to the next round. The tiles are kept from one round to the next.
+    let mut jobs: Vec<Option<BreachJob>> = tiles.iter().map(|_| None).collect();
+    let mut ready: Vec<Vec<(isize, isize)>> = tiles.iter().map(|_| vec![]).collect();
+    let mut pending: Vec<usize> = (0..tiles.len()).collect();
+    while !pending.is_empty() {
+        let mut sent = vec![];
+        run_jobs(
+            pending.len(),
+            &mut jobs,
+            num_procs,
+            context,
+            "Breaching",
+            |jobs, j| {
+                let t = pending[j];
+                Ok(match jobs[t].take() {
+                    Some(mut job) => {
+                        job.ready = Some(std::mem::take(&mut ready[t]));
+                        job
+                    }
+                    None => BreachJob {
+                        tile: tiles[t],
+                        dem: Patch::read_tile(dem, &tiles[t], 1)?,
+                        flow_dir: Patch::read_tile(&mut flow_dir, &tiles[t], 1)?,
+                        output: Patch::read_tile(output, &tiles[t], 0)?,
+                        counts: Patch {
+                            row: tiles[t].row,
+                            column: tiles[t].column,
+                            rows: tiles[t].rows,
+                            columns: tiles[t].columns,
+                            values: vec![0; (tiles[t].rows * tiles[t].columns) as usize],
+                        },
+                        ready: None,
+                    },
+                })
+            },
+            |job| breach_tile(job, small_num),
+            |jobs, j, (job, elevations)| {
+                jobs[pending[j]] = Some(job);
+                sent.extend(elevations);
+                Ok(())
+            },
+        )?;
+
+        pending.clear();
+        for (row, col, z) in sent {
+            let t = ((row / TILE_SIZE) * tiles_across + col / TILE_SIZE) as usize;
+            let job = jobs[t].as_mut().unwrap();
+            if z < job.output.get(row, col) {
+                job.output.set(row, col, z);
+            }
+            let count = job.counts.get(row, col) - 1;
+            job.counts.set(row, col, count);
+            if count == 0 {
+                if ready[t].is_empty() {
+                    pending.push(t);
+                }
+                ready[t].push((row, col));
+            }
+        }
+    }
+    for job in jobs.into_iter().flatten() {
+        job.output.write(output)?;
+    }
+    Ok(())
+}
+
+// The cells are ordered as they are in the tools' own heaps, so that the pits are solved
+// exactly as they always were.
+#[derive(PartialEq, Debug)]
+pub(super) struct GridCell {
+    row: isize,
+    column: isize,
+    priority: f64,
+}
+
+impl Eq for GridCell {}
+
+#[allow(clippy::non_canonical_partial_ord_impl)]
+impl PartialOrd for GridCell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        other.priority.partial_cmp(&self.priority)
+    }
+}
+
+impl Ord for GridCell {
+    fn cmp(&self, other: &GridCell) -> Ordering {
+        self.partial_cmp(other).unwrap()
+    }
+}
+
+#[derive(PartialEq, Debug)]
+struct GridCell2 {
+    row: isize,
+    column: isize,
+    z: f64,
+    priority: f64,
+}
+
+impl Eq for GridCell2 {}
+
+#[allow(clippy::non_canonical_partial_ord_impl)]
+impl PartialOrd for GridCell2 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        other.priority.partial_cmp(&self.priority)
+    }
+}
+
+impl Ord for GridCell2 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.partial_cmp(other).unwrap()
+    }
+}
+
+#[cfg(test)]
+pub(super) mod test {
+    use super::{
+        breach_depressions, find_pits, solve_depressions, sort_pits, BreachTask, DepressionTask,
+        FillTask, Grids, Level, PitOrder, DX, DY, TILE_SIZE,
+    };
+    use crate::tools::ToolContext;
+    use std::cmp::Reverse;
+    use std::collections::{BinaryHeap, VecDeque};
+    use std::fmt::Debug;
+    use whitebox_common::structures::Array2D;
+    use whitebox_raster::*;
+
+    const NODATA: f64 = -32768.0;
+
+    // A DEM that slopes gently towards its top edge and is roughened by pseudo-random noise,
+    // which leaves thousands of small, adjacent pits. It also holds a bowl that is wider than
+    // the copies used for speculative solutions, centred on the corner of four tiles, with
+    // smaller pits nested within it, and a patch of nodata.
+    fn synthetic_dem() -> Array2D<f64> {
+        let size = 3 * TILE_SIZE - 20;
+        let mut dem = Array2D::new(size, size, 0.0, NODATA).unwrap();
+        let mut seed = 12345u64;
+        for row in 0..size {
+            for col in 0..size {
+                seed = seed
+                    .wrapping_mul(6_364_136_223_846_793_005)
+                    .wrapping_add(1_442_695_040_888_963_407);
+                let noise = (seed >> 33) as f64 / (1u64 << 31) as f64;
+                let mut z = 100.0 + 0.02 * row as f64 + noise;
+                let (dr, dc) = ((row - TILE_SIZE) as f64, (col - TILE_SIZE) as f64);
+                let dist = (dr * dr + dc * dc).sqrt();
+                if dist < 80.0 {
+                    z -= 0.1 * (80.0 - dist);
+                    // the nested pits
+                    if (dist - 40.0).abs() < 3.0 && dc > 0.0 {
+                        z -= 2.0;
+                    }
+                }
+                if row > size - 30 && col < 30 {
+                    z = NODATA;
+                }
+                dem.set_value(row, col, z);
+            }
+        }
+        dem
+    }
+
+    /// Writes the synthetic DEM to a GeoTIFF, for the tests of the tools that use this module.
+    pub(in crate::tools::hydro_analysis) fn write_synthetic_dem(file_name: &str) {
+        let dem = synthetic_dem();
+        let configs = RasterConfigs {
+            rows: dem.rows as usize,
+            columns: dem.columns as usize,
+            north: dem.rows as f64,
+            south: 0.0,
+            east: dem.columns as f64,
+            west: 0.0,
+            resolution_x: 1.0,
+            resolution_y: 1.0,
+            nodata: NODATA,
+            data_type: DataType::F64,
+            photometric_interp: PhotometricInterpretation::Continuous,
+            ..Default::default()
+        };
+        let mut raster = Raster::initialize_using_config(file_name, &configs);
+        for row in 0..dem.rows {
+            for col in 0..dem.columns {
+                raster.set_value(row, col, dem.get_value(row, col));
+            }
+        }
+        raster.write().unwrap();
+    }
+
+    /// A 64-bit FNV-1a digest of the cell values of a raster file, used to compare the output
+    /// of a tool with that of the release that preceded this module without storing it.
+    pub(in crate::tools::hydro_analysis) fn raster_digest(file_name: &str) -> u64 {
+        let raster = Raster::new(file_name, "r").unwrap();
+        let mut digest = 0xcbf2_9ce4_8422_2325u64;
+        for row in 0..raster.configs.rows as isize {
+            for col in 0..raster.configs.columns as isize {
+                for byte in raster.get_value(row, col).to_bits().to_le_bytes() {
+                    digest = (digest ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+                }
+            }
+        }
+        digest
+    }
+
+    // Solves the depressions of the synthetic DEM with `num_procs` threads.
+    fn solve<T: DepressionTask>(
+        task: &T,
+        order: PitOrder,
+        num_procs: isize,
+    ) -> (Array2D<f64>, Vec<T::Outcome>) {
+        let context = ToolContext::new(false);
+        let dem = synthetic_dem();
+        let (rows, columns) = (dem.rows, dem.columns);
+        let mut grids = Grids {
+            elevations: dem,
+            visited: Array2D::new(rows, columns, 0, -1).unwrap(),
+            flats: Array2D::new(rows, columns, 0, -1).unwrap(),
+        };
+        let mut pits = find_pits(&mut grids.elevations, NODATA, num_procs, &context).unwrap();
+        assert!(pits.len() > 1000);
+        sort_pits(&mut pits, order);
+        let outcomes = solve_depressions(
+            &mut grids, &pits, task, NODATA, num_procs, &context, "Solving",
+        )
+        .unwrap();
+        (grids.elevations, outcomes)
+    }
+
+    fn assert_same_solution<T: DepressionTask, O: PartialEq + Debug>(
+        task: &T,
+        order: PitOrder,
+        outcome: impl Fn(&T::Outcome) -> O,
+    ) {
+        let (serial, serial_outcomes) = solve(task, order, 1);
+        for num_procs in [2, 4] {
+            let (parallel, parallel_outcomes) = solve(task, order, num_procs);
+            for row in 0..serial.rows {
+                for col in 0..serial.columns {
+                    assert_eq!(
+                        serial.get_value(row, col).to_bits(),
+                        parallel.get_value(row, col).to_bits(),
+                        "cell ({}, {}) differs with {} threads",
+                        row,
+                        col,
+                        num_procs
+                    );
+                }
+            }
+            assert!(serial_outcomes
+                .iter()
+                .map(&outcome)
+                .eq(parallel_outcomes.iter().map(&outcome)));
+        }
+    }
+
+    #[test]
+    fn test_parallel_fill_matches_serial() {
+        let task = FillTask {
+            nodata: NODATA,
+            max_depth: f64::INFINITY,
+            clear_unfilled: true,
+        };
+        assert_same_solution(&task, PitOrder::HighestFirst, |o| o.clone());
+        // depressions deeper than the maximum depth are left unfilled
+        let task = FillTask {
+            max_depth: 1.5,
+            ..task
+        };
+        assert_same_solution(&task, PitOrder::HighestFirst, |o| o.clone());
+    }
+
+    #[test]
+    fn test_parallel_breach_matches_serial() {
+        let task = BreachTask {
+            nodata: NODATA,
+            small_num: 1e-5,
+            max_dist: 30,
+            max_cost: f64::INFINITY,
+            minimize_dist: true,
+            cost_dist: [
+                2f64.sqrt(),
+                1.0,
+                2f64.sqrt(),
+                1.0,
+                2f64.sqrt(),
+                1.0,
+                2f64.sqrt(),
+                1.0,
+            ],
+        };
+        assert_same_solution(&task, PitOrder::LowestFirst, |o| *o);
+    }
+
+    // BreachDepressions' priority flood from the edges of the DEM and its nodata, on a single
+    // thread, with cells of equal elevation taken in row-major order.
+    fn flood_breach(dem: &Array2D<f64>, small_num: f64, background: f64) -> Array2D<f64> {
+        let (rows, columns) = (dem.rows, dem.columns);
+        let mut output = Array2D::new(rows, columns, background, NODATA).unwrap();
+        let mut flow_dir: Array2D<i8> = Array2D::new(rows, columns, -1, -1).unwrap();
+        let key = |row: isize, col: isize| Level {
+            z: dem.get_value(row, col),
+            index: (row * columns + col) as i64,
+        };
+        let mut heap = BinaryHeap::new();
+        let mut queue = VecDeque::new();
+        for row in 0..rows {
+            queue.push_back((row, -1));
+            queue.push_back((row, columns));
+        }
+        for col in 0..columns {
+            queue.push_back((-1, col));
+            queue.push_back((rows, col));
+        }
+        while let Some((row, col)) = queue.pop_front() {
+            for n in 0..8 {
+                let (row_n, col_n) = (row + DY[n], col + DX[n]);
+                if output.get_value(row_n, col_n) == background {
+                    let z_n = dem.get_value(row_n, col_n);
+                    output.set_value(row_n, col_n, z_n);
+                    if z_n == NODATA {
+                        queue.push_back((row_n, col_n));
+                    } else {
+                        heap.push(Reverse(key(row_n, col_n)));
+                    }
+                }
+            }
+        }
+        while let Some(Reverse(level)) = heap.pop() {
+            let (row, col) = (level.index as isize / columns, level.index as isize % columns);
+            let z_out = output.get_value(row, col);
+            for n in 0..8 {
+                let (row_n, col_n) = (row + DY[n], col + DX[n]);
+                if output.get_value(row_n, col_n) != background {
+                    continue;
+                }
+                let z_n = dem.get_value(row_n, col_n);
+                output.set_value(row_n, col_n, z_n);
+                if z_n == NODATA {
+                    // an interior nodata region
+                    queue.push_back((row_n, col_n));
+                    while let Some((r, c)) = queue.pop_front() {
+                        for n2 in 0..8 {
+                            let (r2, c2) = (r + DY[n2], c + DX[n2]);
+                            if dem.get_value(r2, c2) == NODATA
+                                && output.get_value(r2, c2) == background
+                            {
+                                output.set_value(r2, c2, NODATA);
+                                queue.push_back((r2, c2));
+                            }
+                        }
+                    }
+                    continue;
+                }
+                flow_dir.set_value(row_n, col_n, ((n + 4) % 8) as i8);
+                heap.push(Reverse(key(row_n, col_n)));
+                if z_n < z_out + small_num {
+                    let (mut y, mut x, mut z_target) = (row_n, col_n, z_n);
+                    loop {
+                        let dir = flow_dir.get_value(y, x);
+                        if dir < 0 {
+                            break;
+                        }
+                        y += DY[dir as usize];
+                        x += DX[dir as usize];
+                        z_target -= small_num;
+                        if output.get_value(y, x) > z_target {
+                            output.set_value(y, x, z_target);
+                        } else {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        output
+    }
+
+    #[test]
+    fn test_tiled_breach_matches_single_flood() {
+        // Round the synthetic DEM, so that many cells share their elevation, and add a nodata
+        // lake, straddling the tiles, with an island that the flood can't reach and a hole
+        // within the island.
+        let mut dem = synthetic_dem();
+        for row in 0..dem.rows {
+            for col in 0..dem.columns {
+                let z = dem.get_value(row, col);
+                let (dr, dc) = ((row - 2 * TILE_SIZE) as f64, (col - 250) as f64);
+                let dist = (dr * dr + dc * dc).sqrt();
+                if (dist > 10.0 && dist < 16.0) || dist < 2.0 {
+                    dem.set_value(row, col, NODATA);
+                } else if z != NODATA {
+                    dem.set_value(row, col, (z * 10.0).round() / 10.0);
+                }
+            }
+        }
+        let (small_num, background) = (0.001, (i32::MIN + 1) as f64);
+        let expected = flood_breach(&dem, small_num, background);
+        assert_eq!(expected.get_value(2 * TILE_SIZE, 245), background);
+        assert_eq!(expected.get_value(2 * TILE_SIZE, 250), background);
+        let context = ToolContext::new(false);
+        for num_procs in [1, 2, 4] {
+            let mut output = Array2D::new(dem.rows, dem.columns, background, NODATA).unwrap();
+            breach_depressions(
+                &mut dem.duplicate(),
+                &mut output,
+                NODATA,
+                background,
+                small_num,
+                num_procs,
+                &context,
+            )
+            .unwrap();
+            for row in 0..dem.rows {
+                for col in 0..dem.columns {
+                    assert_eq!(
+                        expected.get_value(row, col).to_bits(),
+                        output.get_value(row, col).to_bits(),
+                        "cell ({}, {}) differs with {} threads",
+                        row,
+                        col,
+                        num_procs
+                    );
+                }
+            }
+        }
+    }
+
+    // Writes the synthetic DEM to the temporary directory and prints the digests of the
+    // outputs that a release of the tools wrote from it there, to refresh the expected
+    // digests of the tools' golden tests. Run with --ignored --nocapture.
+    #[test]
+    #[ignore]
+    fn print_golden_digests() {
+        let dir = std::env::temp_dir().join("wbt_golden");
+        let _ = std::fs::create_dir_all(&dir);
+        let dem = dir.join("dem.tif");
+        if !dem.exists() {
+            write_synthetic_dem(dem.to_str().unwrap());
+        }
+        let mut files: Vec<_> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .filter(|p| p != &dem)
+            .collect();
+        files.sort();
+        for file in files {
+            println!("{:?}: {:#018x}", file, raster_digest(file.to_str().unwrap()));
+        }
+    }
+}
@@ -156,8 +156,9 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut seed_file = String::new();
         let mut flowdir_file = String::new();
         let mut output_file = String::new();
@@ -220,11 +221,11 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -246,7 +247,7 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
         // let seeds = Raster::new(&seed_file, "r")?;
 
         if verbose {
-            println!("Reading flow direction data...")
+            context.info("Reading flow direction data...")
         };
         let flowdir = Raster::new(&flowdir_file, "r")?;
 
@@ -265,6 +266,7 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &flowdir);
+        output.widen_to_f64();
         output.reinitialize_values(background_val);
 
         let seeds = Shapefile::read(&seed_file)?;
@@ -284,13 +286,11 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
             seed_rows.push(flowdir.get_row_from_y(record.points[0].y));
             seed_cols.push(flowdir.get_column_from_x(record.points[0].x));
 
-            if verbose {
-                progress =
-                    (100.0_f64 * record_num as f64 / (seeds.num_records - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Locating seed points: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * record_num as f64 / (seeds.num_records - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Locating seed points", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -382,12 +382,10 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / (seed_cols.len() - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / (seed_cols.len() - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -397,12 +395,10 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
                     output.set_value(row, col, nodata);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -422,21 +418,18 @@ impl WhiteboxTool for TraceDownslopeFlowpaths {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
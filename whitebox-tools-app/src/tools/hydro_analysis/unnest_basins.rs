@@ -2,10 +2,13 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 27/04/2018
-Last Modified: 18/10/2019
+Last Modified: 17/10/2026
 License: MIT
 */
 
+use super::basin_polygons::{
+    contributing_area, pour_point_fields, trace_basin_polygons, write_basin_polygons,
+};
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
 use crate::tools::*;
@@ -32,6 +35,13 @@ use std::path;
 /// or target, cells are denoted in the input pour-point image as any non-zero,
 /// non-NoData value. The flow pointer raster should be generated using the D8
 /// algorithm.
+///
+/// The whole watersheds may optionally also be output as a single vector of polygons (`--out_polygons`),
+/// with one polygon for each outlet, which will overlap where outlets are nested. The attribute table contains
+/// the outlet ID, area, perimeter, outlet coordinates, and the mean elevation when a DEM (`--dem`) is specified,
+/// along with the nesting order (NEST_ORDER), the ID of the next outlet downstream (DOWNSTREAM, or zero if there
+/// is none), the number of outlets that drain directly into the outlet (NUM_UPSTRM), and the attributes of
+/// the corresponding pour point.
 pub struct UnnestBasins {
     name: String,
     description: String,
@@ -86,6 +96,26 @@ impl UnnestBasins {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["--dem".to_owned()],
+            description: "Optional input DEM, used to calculate the mean elevation of the output polygons.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Polygons File".to_owned(),
+            flags: vec!["--out_polygons".to_owned()],
+            description: "Optional output vector polygons file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -141,12 +171,15 @@ impl WhiteboxTool for UnnestBasins {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut d8_file = String::new();
         let mut pourpts_file = String::new();
         let mut output_file = String::new();
         let mut esri_style = false;
+        let mut dem_file = String::new();
+        let mut polygons_file = String::new();
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -186,6 +219,18 @@ impl WhiteboxTool for UnnestBasins {
                 if vec.len() == 1 || !vec[1].to_string().to_lowercase().contains("false") {
                     esri_style = true;
                 }
+            } else if flag_val == "-dem" {
+                dem_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-out_polygons" {
+                polygons_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
             }
         }
 
@@ -193,11 +238,11 @@ impl WhiteboxTool for UnnestBasins {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -214,14 +259,32 @@ impl WhiteboxTool for UnnestBasins {
         if !output_file.contains(&sep) && !output_file.contains("/") {
             output_file = format!("{}{}", working_directory, output_file);
         }
+        if !dem_file.is_empty() && !dem_file.contains(&sep) && !dem_file.contains("/") {
+            dem_file = format!("{}{}", working_directory, dem_file);
+        }
+        if !polygons_file.is_empty() && !polygons_file.contains(&sep) && !polygons_file.contains("/") {
+            polygons_file = format!("{}{}", working_directory, polygons_file);
+        }
 
         let start = Instant::now();
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let pntr = Raster::new(&d8_file, "r")?;
+        let dem = if !polygons_file.is_empty() && !dem_file.is_empty() {
+            let dem = Raster::new(&dem_file, "r")?;
+            if dem.configs.rows != pntr.configs.rows || dem.configs.columns != pntr.configs.columns {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input files must have the same number of rows and columns and spatial extent.",
+                ));
+            }
+            Some(dem)
+        } else {
+            None
+        };
 
         let pourpts = Shapefile::read(&pourpts_file)?;
 
@@ -256,12 +319,10 @@ impl WhiteboxTool for UnnestBasins {
             outlet_rows[outlet] = row;
             outlet_columns[outlet] = col;
 
-            if verbose {
-                progress = (100.0_f64 * outlet as f64 / pourpts.num_records as f64) as usize;
-                if progress != old_progress {
-                    println!("Locating pour points: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * outlet as f64 / pourpts.num_records as f64) as usize;
+            if progress != old_progress {
+                context.progress("Locating pour points", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -306,12 +367,10 @@ impl WhiteboxTool for UnnestBasins {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Initializing: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Initializing", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -353,15 +412,20 @@ impl WhiteboxTool for UnnestBasins {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * outlet as f64 / pourpts.num_records as f64) as usize;
-                if progress != old_progress {
-                    println!("Calculating outlet nesting order: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * outlet as f64 / pourpts.num_records as f64) as usize;
+            if progress != old_progress {
+                context.progress("Calculating outlet nesting order", progress)?;
+                old_progress = progress;
             }
         }
 
+        // the contributing areas locate the outlets of the basin polygons of every order
+        let accum = if !polygons_file.is_empty() {
+            Some(contributing_area(rows, columns, |row, col| flow_dir.get_value(row, col))?)
+        } else {
+            None
+        };
+        let mut polygons = vec![];
         for order in 1..max_nesting_order + 1 {
             let start2 = Instant::now();
             // there will be an output file for each nesting order
@@ -370,6 +434,7 @@ impl WhiteboxTool for UnnestBasins {
             let output_file_order = output_file.replace(ext, &format!("_{}{}", order, ext));
 
             let mut output = Raster::initialize_using_file(&output_file_order, &pntr);
+            output.widen_to_f64();
             output.configs.nodata = nodata;
             output.configs.data_type = DataType::I16;
             output.configs.photometric_interp = PhotometricInterpretation::Categorical;
@@ -438,15 +503,10 @@ impl WhiteboxTool for UnnestBasins {
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!(
-                            "Progress (Loop {} of {}): {}%",
-                            order, max_nesting_order, progress
-                        );
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Progress (Loop {} of {})", order, max_nesting_order), progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -460,25 +520,81 @@ impl WhiteboxTool for UnnestBasins {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time2));
 
             if verbose {
-                println!("Saving data for nesting order {}...", order)
+                context.info(format!("Saving data for nesting order {}...", order))
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
             };
+
+            if let Some(accum) = &accum {
+                if verbose {
+                    context.info(format!("Creating watershed polygons for nesting order {}...", order))
+                };
+                polygons.extend(trace_basin_polygons(&output, accum, dem.as_ref(), context)?);
+            }
+        }
+
+        if !polygons_file.is_empty() {
+            // find the next outlet downstream of each outlet
+            let mut downstream = vec![0usize; pourpts.num_records + 1];
+            let mut num_upstream = vec![0usize; pourpts.num_records + 1];
+            for outlet in 1..pourpts.num_records + 1 {
+                y = outlet_rows[outlet];
+                x = outlet_columns[outlet];
+                dir = flow_dir.get_value(y, x);
+                while dir >= 0 {
+                    x += dx[dir as usize];
+                    y += dy[dir as usize];
+                    if outlet_points.get_value(y, x) > 0 {
+                        downstream[outlet] = outlet_points.get_value(y, x) as usize;
+                        num_upstream[downstream[outlet]] += 1;
+                        break;
+                    }
+                    dir = flow_dir.get_value(y, x);
+                }
+            }
+
+            polygons.sort_by_key(|p| p.id);
+            let mut fields = vec![
+                AttributeField::new("NEST_ORDER", FieldDataType::Int, 4u8, 0u8),
+                AttributeField::new("DOWNSTREAM", FieldDataType::Int, 10u8, 0u8),
+                AttributeField::new("NUM_UPSTRM", FieldDataType::Int, 6u8, 0u8),
+            ];
+            let (pourpt_fields, indices) =
+                pour_point_fields(&pourpts, &["NEST_ORDER", "DOWNSTREAM", "NUM_UPSTRM"]);
+            fields.extend(pourpt_fields);
+            write_basin_polygons(
+                &polygons_file,
+                &polygons,
+                &pntr.configs,
+                dem.is_some(),
+                &fields,
+                |polygon| {
+                    let outlet = polygon.id as usize;
+                    let mut values = vec![
+                        FieldData::Int(nesting_order[outlet] as i32),
+                        FieldData::Int(downstream[outlet] as i32),
+                        FieldData::Int(num_upstream[outlet] as i32),
+                    ];
+                    let record = pourpts.attributes.get_record(outlet - 1);
+                    values.extend(indices.iter().map(|i| record[*i].clone()));
+                    values
+                },
+            )?;
+            if verbose {
+                context.info("Polygons file written")
+            }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -124,8 +124,9 @@ impl WhiteboxTool for UpslopeDepressionStorage {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
 
@@ -164,11 +165,11 @@ impl WhiteboxTool for UpslopeDepressionStorage {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -184,7 +185,7 @@ impl WhiteboxTool for UpslopeDepressionStorage {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -225,6 +226,7 @@ impl WhiteboxTool for UpslopeDepressionStorage {
         let small_num = 1.0_f32 / elev_multiplier as f32;
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.widen_to_f64();
 
         // drop(input); // input is no longer needed.
 
@@ -257,12 +259,10 @@ impl WhiteboxTool for UpslopeDepressionStorage {
                     num_cells_visited += 1;
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Finding edges: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Finding edges", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -302,13 +302,11 @@ impl WhiteboxTool for UpslopeDepressionStorage {
                     num_cells_visited += 1;
                 }
             }
-            if verbose {
-                progress =
-                    (100.0_f64 * num_cells_visited as f64 / (rows * columns - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Filling: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * num_cells_visited as f64 / (rows * columns - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Filling", progress)?;
+                old_progress = progress;
             }
         }
         drop(input);
@@ -458,7 +456,9 @@ impl WhiteboxTool for UpslopeDepressionStorage {
                             data[col as usize] = count;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -476,12 +476,10 @@ impl WhiteboxTool for UpslopeDepressionStorage {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Num. inflowing neighbours: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Num. inflowing neighbours", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -533,13 +531,11 @@ impl WhiteboxTool for UpslopeDepressionStorage {
                 }
             }
 
-            if verbose {
-                num_solved += 1;
-                progress = (100.0_f64 * num_solved as f64 / (rows * columns - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Flow accumulation: {}%", progress);
-                    old_progress = progress;
-                }
+            num_solved += 1;
+            progress = (100.0_f64 * num_solved as f64 / (rows * columns - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Flow accumulation", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -554,13 +550,11 @@ impl WhiteboxTool for UpslopeDepressionStorage {
                     );
                 }
             }
-            if verbose {
-                progress =
-                    (100.0_f64 * num_cells_visited as f64 / (rows * columns - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Final calculation: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * num_cells_visited as f64 / (rows * columns - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Final calculation", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -573,21 +567,18 @@ impl WhiteboxTool for UpslopeDepressionStorage {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
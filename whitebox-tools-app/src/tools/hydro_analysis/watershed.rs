@@ -2,10 +2,13 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 22/06/2017
-Last Modified: 14/02/2020
+Last Modified: 17/10/2026
 License: MIT
 */
 
+use super::basin_polygons::{
+    contributing_area, pour_point_fields, trace_basin_polygons, write_basin_polygons,
+};
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
 use crate::tools::*;
@@ -40,6 +43,11 @@ use std::path;
 /// By default, the pointer raster is assumed to use the clockwise indexing method used by WhiteboxTools.
 /// If the pointer file contains ESRI flow direction values instead, the `--esri_pntr` parameter must be specified.
 ///
+/// The watersheds may optionally also be output as a vector of polygons (`--out_polygons`), with one
+/// polygon for each watershed, including any holes. The attribute table contains the watershed ID,
+/// area, perimeter, and outlet coordinates, along with the mean elevation of each watershed when a DEM
+/// (`--dem`) is specified, and the attributes of the corresponding vector pour point.
+///
 /// There are several tools that perform similar watershedding operations in WhiteboxTools. `Watershed` is appropriate
 /// to use when you have a set of specific locations for which you need to derive the watershed areas. Use the `Basins`
 /// tool instead when you simply want to find the watersheds draining to each outlet situated along the edge of a
@@ -118,6 +126,26 @@ impl Watershed {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Input DEM File".to_owned(),
+            flags: vec!["--dem".to_owned()],
+            description: "Optional input DEM, used to calculate the mean elevation of the output polygons.".to_owned(),
+            parameter_type: ParameterType::ExistingFile(ParameterFileType::Raster),
+            default_value: None,
+            optional: true,
+        });
+
+        parameters.push(ToolParameter {
+            name: "Output Polygons File".to_owned(),
+            flags: vec!["--out_polygons".to_owned()],
+            description: "Optional output vector polygons file.".to_owned(),
+            parameter_type: ParameterType::NewFile(ParameterFileType::Vector(
+                VectorGeometryType::Polygon,
+            )),
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -173,12 +201,15 @@ impl WhiteboxTool for Watershed {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut d8_file = String::new();
         let mut pourpts_file = String::new();
         let mut output_file = String::new();
         let mut esri_style = false;
+        let mut dem_file = String::new();
+        let mut polygons_file = String::new();
 
         if args.len() == 0 {
             return Err(Error::new(
@@ -218,6 +249,18 @@ impl WhiteboxTool for Watershed {
                 if vec.len() == 1 || !vec[1].to_string().to_lowercase().contains("false") {
                     esri_style = true;
                 }
+            } else if flag_val == "-dem" {
+                dem_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-out_polygons" {
+                polygons_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
             }
         }
 
@@ -225,11 +268,11 @@ impl WhiteboxTool for Watershed {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -246,12 +289,31 @@ impl WhiteboxTool for Watershed {
         if !output_file.contains(&sep) && !output_file.contains("/") {
             output_file = format!("{}{}", working_directory, output_file);
         }
+        if !dem_file.is_empty() && !dem_file.contains(&sep) && !dem_file.contains("/") {
+            dem_file = format!("{}{}", working_directory, dem_file);
+        }
+        if !polygons_file.is_empty() && !polygons_file.contains(&sep) && !polygons_file.contains("/") {
+            polygons_file = format!("{}{}", working_directory, polygons_file);
+        }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let pntr = Raster::new(&d8_file, "r")?;
+        let dem = if !polygons_file.is_empty() && !dem_file.is_empty() {
+            let dem = Raster::new(&dem_file, "r")?;
+            if dem.configs.rows != pntr.configs.rows || dem.configs.columns != pntr.configs.columns {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The input files must have the same number of rows and columns and spatial extent.",
+                ));
+            }
+            Some(dem)
+        } else {
+            None
+        };
+        let mut pourpts_vector: Option<Shapefile> = None;
 
         let start = Instant::now();
 
@@ -295,6 +357,7 @@ impl WhiteboxTool for Watershed {
 
         let mut flow_dir: Array2D<i8> = Array2D::new(rows, columns, -2, -2)?;
         let mut output = Raster::initialize_using_file(&output_file, &pntr);
+        output.widen_to_f64();
         output.configs.nodata = nodata;
         output.configs.data_type = DataType::I32;
         output.configs.photometric_interp = PhotometricInterpretation::Categorical;
@@ -302,9 +365,7 @@ impl WhiteboxTool for Watershed {
         let low_value = f64::MIN;
         output.reinitialize_values(low_value);
 
-        if pourpts_file.to_lowercase().ends_with(".shp") {
-            // Note that this only works because at the moment, Shapefiles are the only supported vector.
-            // If additional vector formats are added in the future, this will need updating.
+        if get_vector_type_from_file(&pourpts_file) != VectorType::Unknown {
             let pourpts = Shapefile::read(&pourpts_file)?;
 
             // make sure the input vector file is of points type
@@ -320,19 +381,17 @@ impl WhiteboxTool for Watershed {
                 let col = pntr.get_column_from_x(record.points[0].x);
                 output.set_value(row, col, (record_num + 1) as f64);
 
-                if verbose {
-                    progress =
-                        (100.0_f64 * record_num as f64 / (pourpts.num_records - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Locating pour points: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress =
+                    (100.0_f64 * record_num as f64 / (pourpts.num_records - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Locating pour points", progress)?;
+                    old_progress = progress;
                 }
             }
 
             for row in 0..rows {
                 for col in 0..columns {
-                    z = pntr[(row, col)];
+                    z = pntr.get_value(row, col);
                     if z != pntr_nodata {
                         if z > 0.0 {
                             flow_dir.set_value(row, col, pntr_matches[z as usize]);
@@ -343,14 +402,13 @@ impl WhiteboxTool for Watershed {
                         output.set_value(row, col, nodata);
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Initializing: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Initializing", progress)?;
+                    old_progress = progress;
                 }
             }
+            pourpts_vector = Some(pourpts);
         } else {
             // it's a raster
             let pourpts = Raster::new(&pourpts_file, "r")?;
@@ -380,12 +438,10 @@ impl WhiteboxTool for Watershed {
                         output.set_value(row, col, z);
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Initializing: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Initializing", progress)?;
+                    old_progress = progress;
                 }
             }
         };
@@ -396,7 +452,7 @@ impl WhiteboxTool for Watershed {
         let mut outlet_id: f64;
         for row in 0..rows {
             for col in 0..columns {
-                if output[(row, col)] == low_value {
+                if output.get_value(row, col) == low_value {
                     flag = false;
                     x = col;
                     y = row;
@@ -410,7 +466,7 @@ impl WhiteboxTool for Watershed {
                             y += dy[dir as usize];
 
                             // if the new cell already has a value in the output, use that as the outletID
-                            z = output[(y, x)];
+                            z = output.get_value(y, x);
                             if z != low_value {
                                 outlet_id = z;
                                 flag = true;
@@ -423,7 +479,7 @@ impl WhiteboxTool for Watershed {
                     flag = false;
                     x = col;
                     y = row;
-                    output[(y, x)] = outlet_id;
+                    output.set_value(y, x, outlet_id);
                     while !flag {
                         // find its downslope neighbour
                         dir = flow_dir[(y, x)];
@@ -433,22 +489,20 @@ impl WhiteboxTool for Watershed {
                             y += dy[dir as usize];
 
                             // if the new cell already has a value in the output, use that as the outletID
-                            if output[(y, x)] != low_value {
+                            if output.get_value(y, x) != low_value {
                                 flag = true;
                             }
                         } else {
                             flag = true;
                         }
-                        output[(y, x)] = outlet_id;
+                        output.set_value(y, x, outlet_id);
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -462,21 +516,52 @@ impl WhiteboxTool for Watershed {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
+        if !polygons_file.is_empty() {
+            if verbose {
+                context.info("Creating watershed polygons...")
+            };
+            let accum = contributing_area(rows, columns, |row, col| flow_dir.get_value(row, col))?;
+            let polygons = trace_basin_polygons(
+                &output,
+                &accum,
+                dem.as_ref(),
+                context,
+            )?;
+            let (fields, indices) = match &pourpts_vector {
+                Some(pourpts) => pour_point_fields(pourpts, &[]),
+                None => (vec![], vec![]),
+            };
+            write_basin_polygons(
+                &polygons_file,
+                &polygons,
+                &output.configs,
+                dem.is_some(),
+                &fields,
+                |polygon| match &pourpts_vector {
+                    Some(pourpts) if polygon.id >= 1 && (polygon.id as usize) <= pourpts.num_records => {
+                        let record = pourpts.attributes.get_record(polygon.id as usize - 1);
+                        indices.iter().map(|i| record[*i].clone()).collect()
+                    }
+                    _ => vec![FieldData::Null; indices.len()],
+                },
+            )?;
+            if verbose {
+                context.info("Polygons file written")
+            }
+        }
+
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -133,8 +133,9 @@ impl WhiteboxTool for BalanceContrastEnhancement {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut e = 100f64;
@@ -185,11 +186,11 @@ impl WhiteboxTool for BalanceContrastEnhancement {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -205,7 +206,7 @@ impl WhiteboxTool for BalanceContrastEnhancement {
         }
 
         if verbose {
-            println!("Reading image data...")
+            context.info("Reading image data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
@@ -246,7 +247,7 @@ impl WhiteboxTool for BalanceContrastEnhancement {
                 let mut b_sqr_total = 0f64;
                 for row in (0..rows).filter(|rt| rt % num_procs == tid) {
                     for col in 0..columns {
-                        z = input[(row, col)];
+                        z = input.get_value(row, col);
                         if z != nodata {
                             num_pixels += 1f64;
                             r = z as u32 & 0xFF;
@@ -282,7 +283,7 @@ impl WhiteboxTool for BalanceContrastEnhancement {
                         }
                     }
                 }
-                tx.send((
+                let _ = tx.send((
                     r_l,
                     r_h,
                     r_e,
@@ -296,8 +297,7 @@ impl WhiteboxTool for BalanceContrastEnhancement {
                     b_e,
                     b_sqr_total,
                     num_pixels,
-                ))
-                .unwrap();
+                ));
             });
         }
 
@@ -361,12 +361,10 @@ impl WhiteboxTool for BalanceContrastEnhancement {
 
             num_pixels += tnum_pixels;
 
-            if verbose {
-                progress = (100.0_f64 * tid as f64 / (num_procs - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (Loop 1 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * tid as f64 / (num_procs - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (Loop 1 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -405,7 +403,7 @@ impl WhiteboxTool for BalanceContrastEnhancement {
                 for row in (0..rows).filter(|rt| rt % num_procs == tid) {
                     let mut data = vec![nodata; columns as usize];
                     for col in 0..columns {
-                        z = input[(row, col)];
+                        z = input.get_value(row, col);
                         if z != nodata {
                             r = z as u32 & 0xFF;
                             g = (z as u32 >> 8) & 0xFF;
@@ -444,7 +442,9 @@ impl WhiteboxTool for BalanceContrastEnhancement {
                                 ((a << 24) | (b_out << 16) | (g_out << 8) | r_out) as f64;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -455,12 +455,10 @@ impl WhiteboxTool for BalanceContrastEnhancement {
         for row in 0..rows {
             let data = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(data.0, data.1);
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (Loop 2 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (Loop 2 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -474,21 +472,18 @@ impl WhiteboxTool for BalanceContrastEnhancement {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -152,8 +152,9 @@ impl WhiteboxTool for ChangeVectorAnalysis {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input1_files_str = String::new();
         let mut input2_files_str = String::new();
         let mut magnitude_file = String::new();
@@ -206,11 +207,11 @@ impl WhiteboxTool for ChangeVectorAnalysis {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let start = Instant::now();
@@ -279,8 +280,10 @@ impl WhiteboxTool for ChangeVectorAnalysis {
 
         // Create the output files
         let mut out_magnitude = Raster::initialize_using_file(&magnitude_file, &input1);
+        out_magnitude.widen_to_f64();
         out_magnitude.configs.data_type = DataType::F32;
         let mut out_direction = Raster::initialize_using_file(&direction_file, &input1);
+        out_direction.widen_to_f64();
         out_direction.reinitialize_values(0f64);
 
         let mut nodata_detected: Array2D<i8> = Array2D::new(rows, columns, -1i8, -1i8)?;
@@ -293,7 +296,7 @@ impl WhiteboxTool for ChangeVectorAnalysis {
 
         for i in 0..num_files {
             if verbose {
-                println!("Reading file {} of {}", i + 1, num_files);
+                context.info(format!("Reading file {} of {}", i + 1, num_files));
             }
             if !input1_files[i].trim().is_empty() && !input2_files[i].trim().is_empty() {
                 let mut input1_file = input1_files[i].trim().to_owned();
@@ -340,12 +343,10 @@ impl WhiteboxTool for ChangeVectorAnalysis {
                             nodata_detected.set_value(row, col, 1i8);
                         }
                     }
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -362,17 +363,15 @@ impl WhiteboxTool for ChangeVectorAnalysis {
                     out_direction.set_value(row, col, nodata);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
 
         let elapsed_time = get_formatted_elapsed_time(start);
@@ -385,7 +384,7 @@ impl WhiteboxTool for ChangeVectorAnalysis {
         let _ = match out_magnitude.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -402,7 +401,7 @@ impl WhiteboxTool for ChangeVectorAnalysis {
         let _ = match out_direction.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -427,13 +426,10 @@ impl WhiteboxTool for ChangeVectorAnalysis {
             s.push_str(&format!("{}\n", line));
         }
 
-        println!("{}", s);
+        context.info(&s);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
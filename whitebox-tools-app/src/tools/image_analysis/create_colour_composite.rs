@@ -178,8 +178,9 @@ impl WhiteboxTool for CreateColourComposite {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input1_file = String::new();
         let mut input2_file = String::new();
         let mut input3_file = String::new();
@@ -251,11 +252,11 @@ impl WhiteboxTool for CreateColourComposite {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -282,15 +283,15 @@ impl WhiteboxTool for CreateColourComposite {
         }
 
         if verbose {
-            println!("Reading red band data...")
+            context.info("Reading red band data...")
         };
         let input_r = Arc::new(Raster::new(&input1_file, "r")?);
         if verbose {
-            println!("Reading green band data...")
+            context.info("Reading green band data...")
         };
         let input_g = Arc::new(Raster::new(&input2_file, "r")?);
         if verbose {
-            println!("Reading blue band data...")
+            context.info("Reading blue band data...")
         };
         let input_b = Arc::new(Raster::new(&input3_file, "r")?);
 
@@ -315,7 +316,7 @@ impl WhiteboxTool for CreateColourComposite {
         let input_a = match input4_used {
             true => {
                 if verbose {
-                    println!("Reading opacity data...")
+                    context.info("Reading opacity data...")
                 };
                 let opacity = Raster::new(&input4_file, "r")?;
                 a_min = opacity.configs.display_min;
@@ -378,9 +379,9 @@ impl WhiteboxTool for CreateColourComposite {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data = vec![nodata_r; columns as usize];
                     for col in 0..columns {
-                        red_val = input_r[(row, col)];
-                        green_val = input_g[(row, col)];
-                        blue_val = input_b[(row, col)];
+                        red_val = input_r.get_value(row, col);
+                        green_val = input_g.get_value(row, col);
+                        blue_val = input_b.get_value(row, col);
                         if red_val != nodata_r && green_val != nodata_g && blue_val != nodata_b {
                             red_val = (red_val - red_min) / red_range * 255f64;
                             if red_val < 0f64 {
@@ -421,23 +422,24 @@ impl WhiteboxTool for CreateColourComposite {
                             data[col as usize] = ((a << 24) | (b << 16) | (g << 8) | r) as f64;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &input_r);
+        output.widen_to_f64();
         output.configs.photometric_interp = PhotometricInterpretation::RGB;
         output.configs.data_type = DataType::RGBA32;
         for row in 0..rows {
             let data = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(data.0, data.1);
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -465,7 +467,7 @@ impl WhiteboxTool for CreateColourComposite {
 
             for row in 0..rows {
                 for col in 0..columns {
-                    z = output[(row, col)];
+                    z = output.get_value(row, col);
                     if z != nodata_r {
                         num_pixels += 1f64;
                         r = z as u32 & 0xFF;
@@ -500,12 +502,10 @@ impl WhiteboxTool for CreateColourComposite {
                         b_sqr_total += (b * b) as f64;
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Performing Enhancement (1 of 2): {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Performing Enhancement (1 of 2)", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -534,7 +534,7 @@ impl WhiteboxTool for CreateColourComposite {
 
             for row in 0..rows {
                 for col in 0..columns {
-                    z = output[(row, col)];
+                    z = output.get_value(row, col);
                     if z != nodata_r {
                         r = z as u32 & 0xFF;
                         g = (z as u32 >> 8) & 0xFF;
@@ -569,16 +569,17 @@ impl WhiteboxTool for CreateColourComposite {
                         g_out = g_outf as u32;
                         b_out = b_outf as u32;
 
-                        output[(row, col)] =
-                            ((a << 24) | (b_out << 16) | (g_out << 8) | r_out) as f64
+                        output.set_value(
+                            row,
+                            col,
+                            ((a << 24) | (b_out << 16) | (g_out << 8) | r_out) as f64,
+                        );
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Performing Enhancement (2 of 2): {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Performing Enhancement (2 of 2)", progress)?;
+                    old_progress = progress;
                 }
             }
         }
@@ -598,21 +599,18 @@ impl WhiteboxTool for CreateColourComposite {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -153,8 +153,9 @@ impl WhiteboxTool for DirectDecorrelationStretch {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut achromatic_factor = 0.5f64;
@@ -225,11 +226,11 @@ impl WhiteboxTool for DirectDecorrelationStretch {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -252,7 +253,7 @@ impl WhiteboxTool for DirectDecorrelationStretch {
         }
 
         if verbose {
-            println!("Reading image data...")
+            context.info("Reading image data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
         // let input = Raster::new(&input_file, "r")?;
@@ -288,7 +289,7 @@ impl WhiteboxTool for DirectDecorrelationStretch {
                     let mut histo_blue = [0usize; 256];
                     let mut num_cells = 0;
                     for col in 0..columns {
-                        z = input[(row, col)];
+                        z = input.get_value(row, col);
                         if z != nodata {
                             red = z as u32 & 0xFF;
                             green = (z as u32 >> 8) & 0xFF;
@@ -336,17 +337,21 @@ impl WhiteboxTool for DirectDecorrelationStretch {
                             num_cells += 1;
                         }
                     }
-                    tx.send((
-                        row,
-                        data_r,
-                        histo_red,
-                        data_g,
-                        histo_green,
-                        data_b,
-                        histo_blue,
-                        num_cells,
-                    ))
-                    .unwrap();
+                    if tx
+                        .send((
+                            row,
+                            data_r,
+                            histo_red,
+                            data_g,
+                            histo_green,
+                            data_b,
+                            histo_blue,
+                            num_cells,
+                        ))
+                        .is_err()
+                    {
+                        break;
+                    }
                 }
             });
         }
@@ -376,12 +381,10 @@ impl WhiteboxTool for DirectDecorrelationStretch {
             }
 
             num_cells += data.7;
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (Loop 1 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (Loop 1 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -473,7 +476,7 @@ impl WhiteboxTool for DirectDecorrelationStretch {
                 for row in (0..rows).filter(|row_val| row_val % num_procs == tid) {
                     let mut data = vec![rgb_nodata; columns as usize];
                     for col in 0..columns {
-                        z = input[(row, col)];
+                        z = input.get_value(row, col);
                         if z != nodata {
                             red = red_band[(row, col)] as u32;
                             if red < stretch_min as u32 {
@@ -509,7 +512,9 @@ impl WhiteboxTool for DirectDecorrelationStretch {
                                 ((a << 24) | (blue << 16) | (green << 8) | red) as f64;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -521,12 +526,10 @@ impl WhiteboxTool for DirectDecorrelationStretch {
         for row in 0..rows {
             let data = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(data.0, data.1);
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (Loop 2 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (Loop 2 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -541,12 +544,12 @@ impl WhiteboxTool for DirectDecorrelationStretch {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -669,10 +672,7 @@ impl WhiteboxTool for DirectDecorrelationStretch {
         // }
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
         }
 
         Ok(())
@@ -151,8 +151,9 @@ impl WhiteboxTool for DiffOfGaussianFilter {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut filter_size1 = 0usize;
@@ -218,11 +219,11 @@ impl WhiteboxTool for DiffOfGaussianFilter {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -235,18 +236,18 @@ impl WhiteboxTool for DiffOfGaussianFilter {
         }
 
         if sigma1 < 0.25 {
-            println!("sigma1 cannot be less than 0.25. The value has been modified.");
+            context.info("sigma1 cannot be less than 0.25. The value has been modified.");
             sigma1 = 0.25;
         } else if sigma1 > 20.0 {
-            println!("sigma1 cannot be greater than 20.0. The value has been modified.");
+            context.info("sigma1 cannot be greater than 20.0. The value has been modified.");
             sigma1 = 20.0;
         }
 
         if sigma2 < 0.5 {
-            println!("sigma2 cannot be less than 0.5. The value has been modified.");
+            context.info("sigma2 cannot be less than 0.5. The value has been modified.");
             sigma2 = 0.5;
         } else if sigma2 > 20.0 {
-            println!("sigma2 cannot be greater than 20. The value has been modified.");
+            context.info("sigma2 cannot be greater than 20. The value has been modified.");
             sigma2 = 20.0;
         }
 
@@ -358,7 +359,7 @@ impl WhiteboxTool for DiffOfGaussianFilter {
         let mut old_progress: usize = 1;
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -456,7 +457,9 @@ impl WhiteboxTool for DiffOfGaussianFilter {
                         }
                     }
 
-                    tx1.send((row, data)).unwrap();
+                    if tx1.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -464,12 +467,10 @@ impl WhiteboxTool for DiffOfGaussianFilter {
         for row in 0..rows {
             let data = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(data.0, data.1);
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -485,22 +486,19 @@ impl WhiteboxTool for DiffOfGaussianFilter {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
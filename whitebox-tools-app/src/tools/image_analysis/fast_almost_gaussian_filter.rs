@@ -129,8 +129,9 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut sigma = 1.8;
@@ -181,11 +182,11 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -201,7 +202,7 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
         let mut old_progress: usize = 1;
 
         if sigma < 1.8 {
-            println!("Warning: Sigma values less than 1.8 cannot be achieved using this filter. Perhaps use the GaussianFilter tool instead.");
+            context.warning("Warning: Sigma values less than 1.8 cannot be achieved using this filter. Perhaps use the GaussianFilter tool instead.");
             sigma = 1.8;
         }
 
@@ -221,7 +222,7 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
             (((m * wl * wl) as f64 + ((n - m) as f64) * (wu * wu) as f64 - n as f64) / 12f64)
                 .sqrt();
         if verbose {
-            println!("Actual sigma: {:.3}", sigma_actual);
+            context.info(format!("Actual sigma: {:.3}", sigma_actual));
         }
 
         let input = Raster::new(&input_file, "r")?;
@@ -244,6 +245,7 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
         let mut integral: Array2D<f64> = Array2D::new(rows, columns, 0f64, nodata)?;
         let mut integral_n: Array2D<i32> = Array2D::new(rows, columns, 0, -1)?;
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.widen_to_f64();
 
         let mut val: f64;
         let mut sum: f64;
@@ -255,7 +257,7 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
 
         for iteration_num in 0..n {
             if verbose {
-                println!("Loop {} of {}", iteration_num + 1, n);
+                context.info(format!("Loop {} of {}", iteration_num + 1, n));
             }
 
             let midpoint = if iteration_num <= m {
@@ -362,17 +364,10 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
                             }
                         }
                     }
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!(
-                                "Progress (Loop {} of {}): {}%",
-                                iteration_num + 1,
-                                n,
-                                progress
-                            );
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress(&format!("Progress (Loop {} of {})", iteration_num + 1, n), progress)?;
+                        old_progress = progress;
                     }
                 }
             } else {
@@ -430,17 +425,10 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
                             output.set_value(row, col, val);
                         }
                     }
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!(
-                                "Progress (Loop {} of {}): {}%",
-                                iteration_num + 1,
-                                n,
-                                progress
-                            );
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress(&format!("Progress (Loop {} of {})", iteration_num + 1, n), progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -456,22 +444,19 @@ impl WhiteboxTool for FastAlmostGaussianFilter {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
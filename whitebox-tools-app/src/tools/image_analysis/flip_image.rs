@@ -122,8 +122,9 @@ impl WhiteboxTool for FlipImage {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut direction = String::from("v");
@@ -177,11 +178,11 @@ impl WhiteboxTool for FlipImage {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -224,18 +225,22 @@ impl WhiteboxTool for FlipImage {
                         for row in (0..rows).filter(|r| r % num_procs == tid) {
                             let mut data = vec![nodata; columns as usize];
                             for col in 0..columns {
-                                data[col as usize] = input[(rows_less_one - row, col)];
+                                data[col as usize] = input.get_value(rows_less_one - row, col);
+                            }
+                            if tx.send((row, data)).is_err() {
+                                break;
                             }
-                            tx.send((row, data)).unwrap();
                         }
                     }
                     "h" => {
                         for row in (0..rows).filter(|r| r % num_procs == tid) {
                             let mut data = vec![nodata; columns as usize];
                             for col in 0..columns {
-                                data[col as usize] = input[(row, cols_less_one - col)];
+                                data[col as usize] = input.get_value(row, cols_less_one - col);
+                            }
+                            if tx.send((row, data)).is_err() {
+                                break;
                             }
-                            tx.send((row, data)).unwrap();
                         }
                     }
                     _ => {
@@ -244,9 +249,11 @@ impl WhiteboxTool for FlipImage {
                             let mut data = vec![nodata; columns as usize];
                             for col in 0..columns {
                                 data[col as usize] =
-                                    input[(rows_less_one - row, cols_less_one - col)];
+                                    input.get_value(rows_less_one - row, cols_less_one - col);
+                            }
+                            if tx.send((row, data)).is_err() {
+                                break;
                             }
-                            tx.send((row, data)).unwrap();
                         }
                     }
                 }
@@ -256,12 +263,10 @@ impl WhiteboxTool for FlipImage {
         for row in 0..rows {
             let data = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(data.0, data.1);
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -275,21 +280,18 @@ impl WhiteboxTool for FlipImage {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -139,8 +139,9 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file1 = String::new();
         let mut input_file2 = String::new();
         let mut output_file = String::new();
@@ -191,11 +192,11 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -214,7 +215,7 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
         }
 
         if verbose {
-            println!("Reading input data...")
+            context.info("Reading input data...")
         };
         let input1 = Arc::new(Raster::new(&input_file1, "r")?);
         // let input2 = Arc::new(Raster::new(&input_file2, "r")?);
@@ -253,7 +254,7 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
         let mut bin_num;
         for row in 0..rows1 {
             for col in 0..columns1 {
-                z = input1[(row, col)];
+                z = input1.get_value(row, col);
                 if z != nodata1 {
                     numcells1 += 1f64;
                     bin_num = ((z - min_value1) / bin_size) as usize;
@@ -263,12 +264,10 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
                     histogram[bin_num] += 1f64;
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows1 - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Loop 1 of 3: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows1 - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Loop 1 of 3", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -294,7 +293,7 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
 
         for row in 0..rows2 {
             for col in 0..columns2 {
-                z = input2[(row, col)];
+                z = input2.get_value(row, col);
                 if z != nodata2 {
                     numcells2 += 1f64;
                     bin_num = ((z - min_value2) / bin_size) as usize;
@@ -304,12 +303,10 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
                     histogram2[bin_num] += 1f64;
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows2 - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Loop 2 of 3: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows2 - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Loop 2 of 3", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -432,7 +429,7 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
                 for row in (0..rows1).filter(|r| r % num_procs == tid) {
                     let mut data: Vec<f64> = vec![nodata1; columns1 as usize];
                     for col in 0..columns1 {
-                        z = input1[(row, col)];
+                        z = input1.get_value(row, col);
                         if z != nodata1 {
                             bin_num = ((z - min_value1) / bin_size) as usize;
                             if bin_num > num_bins_less_one1 {
@@ -461,7 +458,9 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
                             data[col as usize] = x_val;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -470,12 +469,10 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
         for r in 0..rows1 {
             let (row, data) = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(row, data);
-            if verbose {
-                progress = (100.0_f64 * r as f64 / (rows1 - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Loop 3 of 3: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * r as f64 / (rows1 - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Loop 3 of 3", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -489,21 +486,18 @@ impl WhiteboxTool for HistogramMatchingTwoImages {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -195,8 +195,9 @@ impl WhiteboxTool for IhsToRgb {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut red_file = String::new();
         let mut green_file = String::new();
         let mut blue_file = String::new();
@@ -271,11 +272,11 @@ impl WhiteboxTool for IhsToRgb {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -309,15 +310,15 @@ impl WhiteboxTool for IhsToRgb {
         }
 
         if verbose {
-            println!("Reading intensity band data...")
+            context.info("Reading intensity band data...")
         };
         let input_i = Arc::new(Raster::new(&intensity_file, "r")?);
         if verbose {
-            println!("Reading hue band data...")
+            context.info("Reading hue band data...")
         };
         let input_h = Arc::new(Raster::new(&hue_file, "r")?);
         if verbose {
-            println!("Reading saturation band data...")
+            context.info("Reading saturation band data...")
         };
         let input_s = Arc::new(Raster::new(&saturation_file, "r")?);
 
@@ -368,9 +369,9 @@ impl WhiteboxTool for IhsToRgb {
                         let mut green_data = vec![nodata_i; columns as usize];
                         let mut blue_data = vec![nodata_i; columns as usize];
                         for col in 0..columns {
-                            i = input_i[(row, col)];
-                            h = input_h[(row, col)];
-                            s = input_s[(row, col)];
+                            i = input_i.get_value(row, col);
+                            h = input_h.get_value(row, col);
+                            s = input_s.get_value(row, col);
                             if i != nodata_i && h != nodata_h && s != nodata_s {
                                 let (r, g, b) = hsi2rgb(h, s, i);
 
@@ -379,7 +380,9 @@ impl WhiteboxTool for IhsToRgb {
                                 blue_data[col as usize] = b as f64;
                             }
                         }
-                        tx.send((row, red_data, green_data, blue_data)).unwrap();
+                        if tx.send((row, red_data, green_data, blue_data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -401,12 +404,10 @@ impl WhiteboxTool for IhsToRgb {
                 output_r.set_row_data(data.0, data.1);
                 output_g.set_row_data(data.0, data.2);
                 output_b.set_row_data(data.0, data.3);
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -423,12 +424,12 @@ impl WhiteboxTool for IhsToRgb {
             output_r.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving red data...")
+                context.info("Saving red data...")
             };
             let _ = match output_r.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
@@ -447,12 +448,12 @@ impl WhiteboxTool for IhsToRgb {
             );
 
             if verbose {
-                println!("Saving green data...")
+                context.info("Saving green data...")
             };
             let _ = match output_g.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
@@ -471,26 +472,23 @@ impl WhiteboxTool for IhsToRgb {
             );
 
             if verbose {
-                println!("Saving blue data...")
+                context.info("Saving blue data...")
             };
             let _ = match output_b.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
             };
 
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-                );
+                context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
             }
         } else {
             if verbose {
-                println!("Creating a colour composite output...");
+                context.info("Creating a colour composite output...");
             }
             let (tx, rx) = mpsc::channel();
             for tid in 0..num_procs {
@@ -504,15 +502,17 @@ impl WhiteboxTool for IhsToRgb {
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
                         let mut data = vec![0f64; columns as usize];
                         for col in 0..columns {
-                            i = input_i[(row, col)];
-                            h = input_h[(row, col)];
-                            s = input_s[(row, col)];
+                            i = input_i.get_value(row, col);
+                            h = input_h.get_value(row, col);
+                            s = input_s.get_value(row, col);
                             if i != nodata_i && h != nodata_h && s != nodata_s {
                                 value = hsi2value(h, s, i);
                                 data[col as usize] = value;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -524,12 +524,10 @@ impl WhiteboxTool for IhsToRgb {
             for row in 0..rows {
                 let data = rx.recv().expect("Error receiving data from thread.");
                 output.set_row_data(data.0, data.1);
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -545,21 +543,18 @@ impl WhiteboxTool for IhsToRgb {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                context.info("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
         }
 
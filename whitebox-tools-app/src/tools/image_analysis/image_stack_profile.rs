@@ -53,7 +53,7 @@ impl ImageStackProfile {
         parameters.push(ToolParameter {
             name: "Input Files".to_owned(),
             flags: vec!["-i".to_owned(), "--inputs".to_owned()],
-            description: "Input multispectral image files.".to_owned(),
+            description: "Input multispectral image files, or a single multiband image.".to_owned(),
             parameter_type: ParameterType::FileList(ParameterFileType::Raster),
             default_value: None,
             optional: false,
@@ -141,8 +141,9 @@ impl WhiteboxTool for ImageStackProfile {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_files_str = String::new();
         let mut points_file = String::new();
         let mut output_file = String::new();
@@ -188,11 +189,11 @@ impl WhiteboxTool for ImageStackProfile {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -200,13 +201,8 @@ impl WhiteboxTool for ImageStackProfile {
         let mut progress: usize;
         let mut old_progress: usize = 1;
 
-        let mut cmd = input_files_str.split(";");
-        let mut input_files = cmd.collect::<Vec<&str>>();
-        if input_files.len() == 1 {
-            cmd = input_files_str.split(",");
-            input_files = cmd.collect::<Vec<&str>>();
-        }
-        let num_files = input_files.len();
+        let inputs = RasterInputs::from_list(&input_files_str, working_directory)?;
+        let num_files = inputs.len();
         if num_files < 2 {
             return Err(Error::new(ErrorKind::InvalidInput,
                 "There is something incorrect about the input files. At least two inputs are required to operate this tool."));
@@ -225,7 +221,7 @@ impl WhiteboxTool for ImageStackProfile {
         }
 
         if verbose {
-            println!("Reading points data...")
+            context.info("Reading points data...")
         };
         let points = Shapefile::read(&points_file)?;
 
@@ -254,42 +250,33 @@ impl WhiteboxTool for ImageStackProfile {
         let mut z: f64;
         let (mut row, mut col): (isize, isize);
         for i in 0..num_files {
-            if !input_files[i].trim().is_empty() {
-                let mut input_file = input_files[i].trim().to_owned();
-                if !input_file.contains(&sep) && !input_file.contains("/") {
-                    input_file = format!("{}{}", working_directory, input_file);
-                }
+            let image = inputs.get(i)?;
+            let nodata = image.configs.nodata;
+            file_names.push(inputs.get_short_name(i));
 
-                let image = Raster::new(&input_file, "r")?;
-                let nodata = image.configs.nodata;
-                file_names.push(image.get_short_filename());
-
-                for record_num in 0..num_points {
-                    if i == 0 {
-                        series_names.push(format!("Point {}", record_num + 1));
-                    }
-                    let record = points.get_record(record_num);
-                    row = image.get_row_from_y(record.points[0].y);
-                    col = image.get_column_from_x(record.points[0].x);
-                    z = image.get_value(row, col);
-                    if z != nodata {
-                        xdata[record_num][i] = (i + 1) as f64;
-                        ydata[record_num][i] = z;
-                    } else {
-                        xdata[record_num][i] = (i + 1) as f64;
-                        ydata[record_num][i] = 0f64; // I'm not sure about this approach.
-                                                     // It would be better if it was a break in the line, but this
-                                                     // cannot be represented as of yet.
-                    }
+            for record_num in 0..num_points {
+                if i == 0 {
+                    series_names.push(format!("Point {}", record_num + 1));
                 }
-            }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / (num_files - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
+                let record = points.get_record(record_num);
+                row = image.get_row_from_y(record.points[0].y);
+                col = image.get_column_from_x(record.points[0].x);
+                z = image.get_value(row, col);
+                if z != nodata {
+                    xdata[record_num][i] = (i + 1) as f64;
+                    ydata[record_num][i] = z;
+                } else {
+                    xdata[record_num][i] = (i + 1) as f64;
+                    ydata[record_num][i] = 0f64; // I'm not sure about this approach.
+                                                 // It would be better if it was a break in the line, but this
+                                                 // cannot be represented as of yet.
                 }
             }
+            progress = (100.0_f64 * i as f64 / (num_files - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
+            }
         }
 
         let f = File::create(output_file.clone())?;
@@ -376,10 +363,10 @@ impl WhiteboxTool for ImageStackProfile {
         let _ = writer.flush();
 
         if verbose {
-            println!(
+            context.info(format!(
                 "\n{}",
                 &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         if verbose {
@@ -407,7 +394,7 @@ impl WhiteboxTool for ImageStackProfile {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            context.info(format!("Complete! Please see {} for output.", output_file));
         }
 
         Ok(())
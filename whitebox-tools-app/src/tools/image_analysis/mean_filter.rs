@@ -2,13 +2,14 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 25/06/2017
-Last Modified: 22/10/2019
+Last Modified: 17/10/2026
 License: MIT
 */
 
 use whitebox_raster::*;
 use whitebox_common::structures::Array2D;
 use num_cpus;
+use std::collections::VecDeque;
 use std::env;
 use std::f64;
 use std::f64::consts::PI;
@@ -121,6 +122,156 @@ impl MeanFilter {
             example_usage: usage,
         }
     }
+
+    // Filters an image that is too large to be held in memory, `band_height` rows at a time.
+    // The rows of the integral images are accumulated from the top of the image, exactly as
+    // they are for an image held in memory, and only those rows that the neighbourhoods of
+    // the current band need are kept.
+    fn run_cached(
+        &self,
+        mut input: CachedRaster,
+        input_file: &str,
+        output_file: &str,
+        filter_size: FilterSize,
+        band_height: isize,
+        context: &ToolContext,
+    ) -> Result<(), Error> {
+        let verbose = context.verbose;
+        let midpoint_x = (filter_size.x as f64 / 2f64).floor() as isize;
+        let midpoint_y = (filter_size.y as f64 / 2f64).floor() as isize;
+        let mut progress: usize;
+        let mut old_progress: usize = 1;
+
+        let start = Instant::now();
+
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+        let nodata = input.configs.nodata;
+
+        let is_rgb_image = input.configs.data_type == DataType::RGB24
+            || input.configs.data_type == DataType::RGBA32
+            || input.configs.photometric_interp == PhotometricInterpretation::RGB;
+
+        let mut output = CachedRaster::initialize_using_file(output_file, &input)?;
+        let mut num_procs = num_cpus::get() as isize;
+        let configs = whitebox_common::configs::get_configs()?;
+        let max_procs = configs.max_procs;
+        if max_procs > 0 && max_procs < num_procs {
+            num_procs = max_procs;
+        }
+
+        // The minimum value is needed before the integral images can be accumulated.
+        let mut min_val = f64::INFINITY;
+        if !is_rgb_image {
+            let mut band_start = 0isize;
+            while band_start < rows {
+                let band_rows = band_height.min(rows - band_start);
+                let band = input.read_window(band_start, 0, band_rows as usize, columns as usize)?;
+                for row in 0..band_rows {
+                    min_val = min_val.min(row_minimum(&band.get_row_data(row), nodata));
+                }
+                band_start += band_rows;
+                progress = (100.0_f64 * band_start as f64 / rows as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Finding the minimum value", progress)?;
+                    old_progress = progress;
+                }
+            }
+        }
+        if min_val == f64::INFINITY {
+            min_val = 0f64;
+        }
+
+        // the rows of the integral images, starting with row first_integral_row
+        let mut integral: VecDeque<Arc<IntegralRow>> = VecDeque::new();
+        let mut first_integral_row = 0isize;
+        let mut band_start = 0isize;
+        while band_start < rows {
+            let band_rows = band_height.min(rows - band_start);
+            let last_row = (band_start + band_rows - 1 + midpoint_y).min(rows - 1);
+            let window = Arc::new(input.read_window(
+                band_start,
+                0,
+                (last_row - band_start + 1) as usize,
+                columns as usize,
+            )?);
+            let mut row = first_integral_row + integral.len() as isize;
+            while row <= last_row {
+                let values = filter_values(window.get_row_data(row - band_start), nodata, is_rgb_image);
+                let next = IntegralRow::next(&values, nodata, min_val, integral.back().map(|r| r.as_ref()));
+                integral.push_back(Arc::new(next));
+                row += 1;
+            }
+
+            let i: Arc<Vec<Arc<IntegralRow>>> = Arc::new(integral.iter().cloned().collect());
+            let (tx, rx) = mpsc::channel();
+            for tid in 0..num_procs {
+                let window = window.clone();
+                let i = i.clone();
+                let tx1 = tx.clone();
+                thread::spawn(move || {
+                    for band_row in (0..band_rows).filter(|r| r % num_procs == tid) {
+                        let row = band_start + band_row;
+                        let y1 = (row - midpoint_y - 1).max(0) - first_integral_row;
+                        let y2 = (row + midpoint_y).min(rows - 1) - first_integral_row;
+                        let data = filter_row(
+                            &window.get_row_data(band_row),
+                            &i[y1 as usize],
+                            &i[y2 as usize],
+                            midpoint_x,
+                            nodata,
+                            min_val,
+                            is_rgb_image,
+                        );
+                        if tx1.send((band_row, data)).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+
+            let mut band: Array2D<f64> = Array2D::new(band_rows, columns, nodata, nodata)?;
+            for _ in 0..band_rows {
+                let data = rx.recv().expect("Error receiving data from thread.");
+                band.set_row_data(data.0, data.1);
+            }
+            output.write_window(band_start, 0, &band)?;
+            band_start += band_rows;
+
+            // discard the rows above the neighbourhoods of the next band
+            while first_integral_row < band_start - midpoint_y - 1 {
+                integral.pop_front();
+                first_integral_row += 1;
+            }
+
+            progress = (100.0_f64 * band_start as f64 / rows as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Filter size x: {}", filter_size.x));
+        output.add_metadata_entry(format!("Filter size y: {}", filter_size.y));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            context.info("Saving data...")
+        };
+        output.write()?;
+        if verbose {
+            context.info("Output file written");
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        Ok(())
+    }
 }
 
 impl WhiteboxTool for MeanFilter {
@@ -155,8 +306,9 @@ impl WhiteboxTool for MeanFilter {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut filter_size_x = 3usize;
@@ -233,11 +385,11 @@ impl WhiteboxTool for MeanFilter {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -269,8 +421,14 @@ impl WhiteboxTool for MeanFilter {
             output_file = format!("{}{}", working_directory, output_file);
         }
 
+        // An image that is too large to be read into memory is processed one band of rows at a time.
+        if let Some(input) = CachedRaster::open_if_exceeds_memory(&input_file, 4)? {
+            let band_height = input.get_row_band_height() as isize;
+            return self.run_cached(input, &input_file, &output_file, FilterSize { x: filter_size_x, y: filter_size_y }, band_height, context);
+        }
+
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -278,7 +436,6 @@ impl WhiteboxTool for MeanFilter {
         let start = Instant::now();
 
         let rows = input.configs.rows as isize;
-        let columns = input.configs.columns as isize;
         let nodata = input.configs.nodata;
 
         let is_rgb_image = if input.configs.data_type == DataType::RGB24
@@ -290,66 +447,30 @@ impl WhiteboxTool for MeanFilter {
             false
         };
 
-        let min_val = if !is_rgb_image {
-            input.configs.minimum
-        } else {
-            0f64
-        };
+        let mut min_val = f64::INFINITY;
+        if !is_rgb_image {
+            for row in 0..rows {
+                min_val = min_val.min(row_minimum(&input.get_row_data(row), nodata));
+            }
+        }
+        if min_val == f64::INFINITY {
+            min_val = 0f64;
+        }
 
         // create the integral images
-        let mut integral: Array2D<f64> = Array2D::new(rows, columns, 0f64, nodata)?;
-        let mut integral_n: Array2D<i32> = Array2D::new(rows, columns, 0, -1)?;
-
-        let input_fn: Box<dyn Fn(isize, isize) -> f64> = if !is_rgb_image {
-            Box::new(|row: isize, col: isize| -> f64 { input.get_value(row, col) })
-        } else {
-            Box::new(|row: isize, col: isize| -> f64 {
-                let value = input.get_value(row, col);
-                if value != nodata {
-                    return value2i(value);
-                }
-                nodata
-            })
-        };
-
-        let mut val: f64;
-        let mut sum: f64;
-        let mut sum_n: i32;
-        let mut i_prev: f64;
-        let mut n_prev: i32;
+        let mut integral: Vec<IntegralRow> = Vec::with_capacity(rows as usize);
         for row in 0..rows {
-            sum = 0f64;
-            sum_n = 0;
-            for col in 0..columns {
-                val = input_fn(row, col);
-                if val == nodata {
-                    val = 0f64;
-                } else {
-                    val -= min_val;
-                    sum_n += 1;
-                }
-                sum += val;
-                if row > 0 {
-                    i_prev = integral[(row - 1, col)];
-                    n_prev = integral_n[(row - 1, col)];
-                    integral[(row, col)] = sum + i_prev;
-                    integral_n[(row, col)] = sum_n + n_prev;
-                } else {
-                    integral[(row, col)] = sum;
-                    integral_n[(row, col)] = sum_n;
-                }
-            }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Creating integral images: {}%", progress);
-                    old_progress = progress;
-                }
+            let values = filter_values(input.get_row_data(row), nodata, is_rgb_image);
+            let next = IntegralRow::next(&values, nodata, min_val, integral.last());
+            integral.push(next);
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Creating integral images", progress)?;
+                old_progress = progress;
             }
         }
 
         let i = Arc::new(integral); // wrap integral in an Arc
-        let i_n = Arc::new(integral_n); // wrap integral_n in an Arc
         let mut output = Raster::initialize_using_file(&output_file, &input);
         let (tx, rx) = mpsc::channel();
         let mut num_procs = num_cpus::get() as isize;
@@ -361,75 +482,23 @@ impl WhiteboxTool for MeanFilter {
         for tid in 0..num_procs {
             let input = input.clone();
             let i = i.clone();
-            let i_n = i_n.clone();
             let tx1 = tx.clone();
             thread::spawn(move || {
-                let input_fn: Box<dyn Fn(isize, isize) -> f64> = if !is_rgb_image {
-                    Box::new(|row: isize, col: isize| -> f64 { input.get_value(row, col) })
-                } else {
-                    Box::new(|row: isize, col: isize| -> f64 {
-                        let value = input.get_value(row, col);
-                        if value != nodata {
-                            return value2i(value);
-                        }
-                        nodata
-                    })
-                };
-
-                let output_fn: Box<dyn Fn(isize, isize, f64) -> f64> = if !is_rgb_image {
-                    // simply return the value.
-                    Box::new(|_: isize, _: isize, value: f64| -> f64 { value })
-                } else {
-                    // convert it back into an rgb value, using the modified intensity value.
-                    Box::new(|row: isize, col: isize, value: f64| -> f64 {
-                        if value != nodata {
-                            let (h, s, _) = value2hsi(input.get_value(row, col));
-                            return hsi2value(h, s, value);
-                        }
-                        nodata
-                    })
-                };
-
-                let (mut x1, mut x2, mut y1, mut y2): (isize, isize, isize, isize);
-                let mut n: i32;
-                let mut sum: f64;
-                let mut mean: f64;
-                let mut z: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
-                    y1 = row - midpoint_y - 1;
-                    if y1 < 0 {
-                        y1 = 0;
-                    }
-
-                    y2 = row + midpoint_y;
-                    if y2 >= rows {
-                        y2 = rows - 1;
-                    }
-                    let mut data = vec![nodata; columns as usize];
-                    for col in 0..columns {
-                        z = input_fn(row, col);
-                        if z != nodata {
-                            x1 = col - midpoint_x - 1;
-                            if x1 < 0 {
-                                x1 = 0;
-                            }
-
-                            x2 = col + midpoint_x;
-                            if x2 >= columns {
-                                x2 = columns - 1;
-                            }
-                            n = i_n[(y2, x2)] + i_n[(y1, x1)] - i_n[(y1, x2)] - i_n[(y2, x1)];
-                            if n > 0 {
-                                sum = i[(y2, x2)] + i[(y1, x1)] - i[(y1, x2)] - i[(y2, x1)];
-                                mean = sum / n as f64 + min_val;
-                                data[col as usize] = output_fn(row, col, mean);
-                            } else {
-                                data[col as usize] = output_fn(row, col, 0f64);
-                            }
-                        }
+                    let y1 = (row - midpoint_y - 1).max(0);
+                    let y2 = (row + midpoint_y).min(rows - 1);
+                    let data = filter_row(
+                        &input.get_row_data(row),
+                        &i[y1 as usize],
+                        &i[y2 as usize],
+                        midpoint_x,
+                        nodata,
+                        min_val,
+                        is_rgb_image,
+                    );
+                    if tx1.send((row, data)).is_err() {
+                        break;
                     }
-
-                    tx1.send((row, data)).unwrap();
                 }
             });
         }
@@ -437,12 +506,10 @@ impl WhiteboxTool for MeanFilter {
         for row in 0..rows {
             let data = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(data.0, data.1);
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -457,28 +524,135 @@ impl WhiteboxTool for MeanFilter {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
     }
 }
 
+// The dimensions of the filter neighbourhood, in cells.
+#[derive(Clone, Copy)]
+struct FilterSize {
+    x: usize,
+    y: usize,
+}
+
+// A row of the integral images of the values being filtered, less the minimum value, and
+// of the number of valid cells.
+struct IntegralRow {
+    sum: Vec<f64>,
+    n: Vec<i32>,
+}
+
+impl IntegralRow {
+    // Accumulates the next row of the integral images from a row of values and the previous
+    // row, if there is one. Both the in-memory and the block-by-block filtering accumulate
+    // every row from the top of the image, so that they produce identical results.
+    fn next(values: &[f64], nodata: f64, min_val: f64, prev: Option<&IntegralRow>) -> IntegralRow {
+        let mut row = IntegralRow {
+            sum: Vec::with_capacity(values.len()),
+            n: Vec::with_capacity(values.len()),
+        };
+        let mut sum = 0f64;
+        let mut sum_n = 0i32;
+        for (col, &value) in values.iter().enumerate() {
+            let mut val = value;
+            if val == nodata {
+                val = 0f64;
+            } else {
+                val -= min_val;
+                sum_n += 1;
+            }
+            sum += val;
+            match prev {
+                Some(prev) => {
+                    row.sum.push(sum + prev.sum[col]);
+                    row.n.push(sum_n + prev.n[col]);
+                }
+                None => {
+                    row.sum.push(sum);
+                    row.n.push(sum_n);
+                }
+            }
+        }
+        row
+    }
+}
+
+// Returns the values that are filtered, i.e. the intensities of an RGB image.
+fn filter_values(mut values: Vec<f64>, nodata: f64, is_rgb_image: bool) -> Vec<f64> {
+    if is_rgb_image {
+        for value in values.iter_mut() {
+            if *value != nodata {
+                *value = value2i(*value);
+            }
+        }
+    }
+    values
+}
+
+fn row_minimum(values: &[f64], nodata: f64) -> f64 {
+    values
+        .iter()
+        .filter(|v| **v != nodata && v.is_finite())
+        .fold(f64::INFINITY, |min, v| min.min(*v))
+}
+
+// Filters a row of the input image, given the rows of the integral images that bound the
+// neighbourhoods of its cells above (`top`) and below (`bottom`).
+fn filter_row(
+    values: &[f64],
+    top: &IntegralRow,
+    bottom: &IntegralRow,
+    midpoint_x: isize,
+    nodata: f64,
+    min_val: f64,
+    is_rgb_image: bool,
+) -> Vec<f64> {
+    let columns = values.len() as isize;
+    let mut data = vec![nodata; values.len()];
+    for col in 0..columns {
+        let value = values[col as usize];
+        let z = if is_rgb_image && value != nodata {
+            value2i(value)
+        } else {
+            value
+        };
+        if z != nodata {
+            let x1 = (col - midpoint_x - 1).max(0) as usize;
+            let x2 = (col + midpoint_x).min(columns - 1) as usize;
+            let n = bottom.n[x2] + top.n[x1] - top.n[x2] - bottom.n[x1];
+            let mean = if n > 0 {
+                let sum = bottom.sum[x2] + top.sum[x1] - top.sum[x2] - bottom.sum[x1];
+                sum / n as f64 + min_val
+            } else {
+                0f64
+            };
+            data[col as usize] = if is_rgb_image {
+                // convert it back into an rgb value, using the modified intensity value.
+                let (h, s, _) = value2hsi(value);
+                hsi2value(h, s, mean)
+            } else {
+                mean
+            };
+        }
+    }
+    data
+}
+
 fn value2i(value: f64) -> f64 {
     let r = (value as u32 & 0xFF) as f64 / 255f64;
     let g = ((value as u32 >> 8) & 0xFF) as f64 / 255f64;
@@ -554,3 +728,72 @@ fn hsi2value(h: f64, s: f64, i: f64) -> f64 {
 
     ((255 << 24) | (b << 16) | (g << 8) | r) as f64
 }
+
+#[cfg(test)]
+mod test {
+    use super::{FilterSize, MeanFilter};
+    use crate::tools::{temp_file, ToolContext, WhiteboxTool};
+    use whitebox_raster::*;
+
+    #[test]
+    fn test_cached_filter_matches_in_memory() {
+        let input_file = temp_file("mean_filter", "input.tif");
+        let configs = RasterConfigs {
+            rows: 37,
+            columns: 23,
+            north: 37.0,
+            south: 0.0,
+            east: 23.0,
+            west: 0.0,
+            resolution_x: 1.0,
+            resolution_y: 1.0,
+            nodata: -32768.0,
+            data_type: DataType::F64,
+            photometric_interp: PhotometricInterpretation::Continuous,
+            ..Default::default()
+        };
+        let mut input = Raster::initialize_using_config(&input_file, &configs);
+        for row in 0..37 {
+            for col in 0..23 {
+                if (row * 23 + col) % 17 != 0 {
+                    let z = 2500.0 + (row as f64 * 0.37).sin() * 100.0 + col as f64 * 0.123;
+                    input.set_value(row, col, z);
+                }
+            }
+        }
+        input.write().unwrap();
+
+        let tool = MeanFilter::new();
+        let context = ToolContext::new(false);
+        let in_memory_file = temp_file("mean_filter", "in_memory.tif");
+        let args = vec![
+            format!("-i={}", input_file),
+            format!("-o={}", in_memory_file),
+            "--filterx=5".to_string(),
+            "--filtery=7".to_string(),
+        ];
+        tool.run(args, "", &context).unwrap();
+        let expected = Raster::new(&in_memory_file, "r").unwrap();
+
+        for &band_height in &[1isize, 4, 10] {
+            let cached_file = temp_file("mean_filter", &format!("cached_{}.tif", band_height));
+            let cached = CachedRaster::new(&input_file, "r").unwrap();
+            tool.run_cached(
+                cached,
+                &input_file,
+                &cached_file,
+                FilterSize { x: 5, y: 7 },
+                band_height,
+                &context,
+            )
+            .unwrap();
+            let output = Raster::new(&cached_file, "r").unwrap();
+            for row in 0..37 {
+                assert_eq!(output.get_row_data(row), expected.get_row_data(row));
+            }
+            let _ = std::fs::remove_file(cached_file);
+        }
+        let _ = std::fs::remove_file(input_file);
+        let _ = std::fs::remove_file(in_memory_file);
+    }
+}
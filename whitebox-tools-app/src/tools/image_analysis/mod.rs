@@ -121,6 +121,7 @@ pub use self::prewitt_filter::PrewittFilter;
 pub use self::range_filter::RangeFilter;
 pub use self::remove_spurs::RemoveSpurs;
 pub use self::resample::Resample;
+pub(crate) use self::resample::{interpolate_raster_value, ResampleMethod};
 pub use self::rgb_to_ihs::RgbToIhs;
 pub use self::roberts_filter::RobertsCrossFilter;
 pub use self::scharr_filter::ScharrFilter;
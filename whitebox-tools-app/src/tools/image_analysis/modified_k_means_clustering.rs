@@ -59,7 +59,7 @@ impl ModifiedKMeansClustering {
         parameters.push(ToolParameter {
             name: "Input Files".to_owned(),
             flags: vec!["-i".to_owned(), "--inputs".to_owned()],
-            description: "Input raster files.".to_owned(),
+            description: "Input raster files, or a single multiband raster.".to_owned(),
             parameter_type: ParameterType::FileList(ParameterFileType::Raster),
             default_value: None,
             optional: false,
@@ -193,8 +193,9 @@ impl WhiteboxTool for ModifiedKMeansClustering {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_files_str = String::new();
         let mut output_file = String::new();
         let mut output_html_file = String::new();
@@ -315,11 +316,11 @@ impl WhiteboxTool for ModifiedKMeansClustering {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -339,13 +340,8 @@ impl WhiteboxTool for ModifiedKMeansClustering {
             output_html_file.push_str(".html");
         }
 
-        let mut cmd = input_files_str.split(";");
-        let mut input_files = cmd.collect::<Vec<&str>>();
-        if input_files.len() == 1 {
-            cmd = input_files_str.split(",");
-            input_files = cmd.collect::<Vec<&str>>();
-        }
-        let num_files = input_files.len();
+        let mut inputs = RasterInputs::from_list(&input_files_str, working_directory)?;
+        let num_files = inputs.len();
         if num_files < 2 {
             return Err(Error::new(ErrorKind::InvalidInput,
                                 "There is something incorrect about the input files. At least two inputs are required to operate this tool."));
@@ -383,37 +379,31 @@ impl WhiteboxTool for ModifiedKMeansClustering {
         let mut input_raster: Vec<Raster> = Vec::with_capacity(num_files);
 
         for i in 0..num_files {
-            println!("Reading file {} of {}", i + 1, num_files);
-            if !input_files[i].trim().is_empty() {
-                let mut input_file = input_files[i].trim().to_owned();
-                if !input_file.contains(&sep) && !input_file.contains("/") {
-                    input_file = format!("{}{}", working_directory, input_file);
+            context.info(format!("Reading file {} of {}", i + 1, num_files));
+            input_raster.push(inputs.take(i)?);
+            nodata.push(input_raster[i].configs.nodata);
+            minimum.push(input_raster[i].configs.minimum);
+            maximum.push(input_raster[i].configs.maximum);
+
+            if rows == -1 || columns == -1 {
+                rows = input_raster[i].configs.rows as isize;
+                columns = input_raster[i].configs.columns as isize;
+                if num_classes < 2 || num_classes as isize > (rows * columns) {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "Number of classes should be between 2 and rows x columns.",
+                    ));
                 }
-                input_raster.push(Raster::new(&input_file, "r")?);
-                nodata.push(input_raster[i].configs.nodata);
-                minimum.push(input_raster[i].configs.minimum);
-                maximum.push(input_raster[i].configs.maximum);
-
-                if rows == -1 || columns == -1 {
-                    rows = input_raster[i].configs.rows as isize;
-                    columns = input_raster[i].configs.columns as isize;
-                    if num_classes < 2 || num_classes as isize > (rows * columns) {
-                        return Err(Error::new(
-                            ErrorKind::InvalidInput,
-                            "Number of classes should be between 2 and rows x columns.",
-                        ));
-                    }
-                // if min_class_size > ((rows * columns) as usize / num_classes) {
-                //     return Err(Error::new(ErrorKind::InvalidInput,
-                //         "Min class size should be less than rows x columns / num_classes."));
-                // }
-                } else {
-                    if input_raster[i].configs.rows as isize != rows
-                        || input_raster[i].configs.columns as isize != columns
-                    {
-                        return Err(Error::new(ErrorKind::InvalidInput,
-                            "All input images must share the same dimensions (rows and columns) and spatial extent."));
-                    }
+            // if min_class_size > ((rows * columns) as usize / num_classes) {
+            //     return Err(Error::new(ErrorKind::InvalidInput,
+            //         "Min class size should be less than rows x columns / num_classes."));
+            // }
+            } else {
+                if input_raster[i].configs.rows as isize != rows
+                    || input_raster[i].configs.columns as isize != columns
+                {
+                    return Err(Error::new(ErrorKind::InvalidInput,
+                        "All input images must share the same dimensions (rows and columns) and spatial extent."));
                 }
             }
         }
@@ -427,6 +417,7 @@ impl WhiteboxTool for ModifiedKMeansClustering {
 
         let out_nodata = nodata[0];
         let mut output = Raster::initialize_using_file(&output_file, &input_raster[0]);
+        output.widen_to_f64();
         let mut class_centres = vec![vec![0f64; num_files]; num_classes];
 
         // if initialization_mode == 0 {
@@ -524,7 +515,9 @@ impl WhiteboxTool for ModifiedKMeansClustering {
                                 }
                             }
                         }
-                        tx.send((row, data, class_centre_data)).unwrap();
+                        if tx.send((row, data, class_centre_data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -554,17 +547,10 @@ impl WhiteboxTool for ModifiedKMeansClustering {
                     }
                 }
 
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!(
-                            "Progress (loop {} of {}): {}%",
-                            loop_num + 1,
-                            max_iterations,
-                            progress
-                        );
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Progress (loop {} of {})", loop_num + 1, max_iterations), progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -621,14 +607,14 @@ impl WhiteboxTool for ModifiedKMeansClustering {
 
             num_classes = class_n.len();
 
-            println!("Number of clusters: {}", num_classes);
+            context.info(format!("Number of clusters: {}", num_classes));
 
             percent_changed = 100f64 * cells_changed / n;
             ydata[0][loop_num] = percent_changed;
-            println!(
+            context.info(format!(
                 "Cells changed {} ({:.4} percent)",
                 cells_changed, percent_changed
-            );
+            ));
             if percent_changed < percent_changed_threshold {
                 break;
             }
@@ -650,21 +636,18 @@ impl WhiteboxTool for ModifiedKMeansClustering {
         output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         if !output_html_file.trim().is_empty() {
@@ -696,7 +679,7 @@ impl WhiteboxTool for ModifiedKMeansClustering {
                     &format!(
                         "<strong>Image {}</strong>: {}<br>",
                         i + 1,
-                        input_files[i].clone()
+                        inputs.get_short_name(i)
                     )
                     .as_bytes(),
                 )?;
@@ -863,7 +846,7 @@ impl WhiteboxTool for ModifiedKMeansClustering {
                     let _ = output.stdout;
                 }
 
-                println!("Complete! Please see {} for output.", output_html_file);
+                context.info(format!("Complete! Please see {} for output.", output_html_file));
             }
         }
 
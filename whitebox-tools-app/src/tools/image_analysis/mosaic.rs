@@ -148,8 +148,9 @@ impl WhiteboxTool for Mosaic {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_files = String::new();
         let mut output_file = String::new();
         let mut method = String::from("nn");
@@ -207,11 +208,11 @@ impl WhiteboxTool for Mosaic {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -281,7 +282,7 @@ impl WhiteboxTool for Mosaic {
         */
 
         let num_files = input_vec.len();
-        println!("Number of tiles: {}", num_files);
+        context.info(format!("Number of tiles: {}", num_files));
         if num_files < 2 {
             return Err(Error::new(ErrorKind::InvalidInput,
                 "There is something incorrect about the input files. At least two inputs are required to operate this tool."));
@@ -291,7 +292,7 @@ impl WhiteboxTool for Mosaic {
 
         // read the input files
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let mut inputs: Vec<Raster> = Vec::with_capacity(num_files);
         let mut nodata_vals: Vec<f64> = Vec::with_capacity(num_files);
@@ -385,19 +386,17 @@ impl WhiteboxTool for Mosaic {
 
                     i += 1;
                 } else {
-                    println!("Warning: Error reading file {}", value);
+                    context.warning(format!("Warning: Error reading file {}", value));
                 }
             } else {
                 return Err(Error::new(ErrorKind::InvalidInput,
                     "There is a problem with the list of input files. At least one specified input is empty."));
             }
 
-            if verbose {
-                progress = (100.0_f64 * a as f64 / (num_files - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * a as f64 / (num_files - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -429,10 +428,7 @@ impl WhiteboxTool for Mosaic {
         configs.palette = inputs[0].configs.palette.clone();
 
         if verbose {
-            println!(
-                "Output image size: ({} x {})",
-                configs.rows, configs.columns
-            );
+            context.info(format!("Output image size: ({} x {})", configs.rows, configs.columns));
         }
 
         let mut output = Raster::initialize_using_config(&output_file, &configs);
@@ -491,7 +487,9 @@ impl WhiteboxTool for Mosaic {
                                 }
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -503,12 +501,10 @@ impl WhiteboxTool for Mosaic {
                         output.set_value(row, col, data[col as usize]);
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
         } else if method == "cc" {
@@ -588,7 +584,9 @@ impl WhiteboxTool for Mosaic {
                                 }
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -599,12 +597,10 @@ impl WhiteboxTool for Mosaic {
                         output.set_value(row, col as isize, data[col]);
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
         } else {
@@ -679,7 +675,9 @@ impl WhiteboxTool for Mosaic {
                                 }
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -690,12 +688,10 @@ impl WhiteboxTool for Mosaic {
                         output.set_value(row, col as isize, data[col]);
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
         }
@@ -708,21 +704,18 @@ impl WhiteboxTool for Mosaic {
         output.add_metadata_entry(format!("Resampling method: {}", method));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
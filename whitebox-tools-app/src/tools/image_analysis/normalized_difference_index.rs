@@ -193,8 +193,9 @@ impl WhiteboxTool for NormalizedDifferenceIndex {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input1_file = String::new();
         let mut input2_file = String::new();
         let mut output_file = String::new();
@@ -277,11 +278,11 @@ impl WhiteboxTool for NormalizedDifferenceIndex {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -300,7 +301,7 @@ impl WhiteboxTool for NormalizedDifferenceIndex {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let nir = Arc::new(Raster::new(&input1_file, "r")?);
@@ -339,8 +340,8 @@ impl WhiteboxTool for NormalizedDifferenceIndex {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data = vec![nir_nodata; columns as usize];
                     for col in 0..columns {
-                        z_nir = nir[(row, col)];
-                        z_red = red[(row, col)];
+                        z_nir = nir.get_value(row, col);
+                        z_red = red.get_value(row, col);
                         if z_nir != nir_nodata && z_red != red_nodata {
                             if z_nir + z_red != 0.0 || correction_factor > 0f64 {
                                 data[col as usize] =
@@ -350,7 +351,9 @@ impl WhiteboxTool for NormalizedDifferenceIndex {
                             }
                         }
                     }
-                    tx1.send((row, data)).unwrap();
+                    if tx1.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -358,18 +361,16 @@ impl WhiteboxTool for NormalizedDifferenceIndex {
         for row in 0..rows {
             let data = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(data.0, data.1);
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         if clip_amount > 0.0 {
             if verbose {
-                println!("Clipping output...");
+                context.info("Clipping output...");
             }
             output.clip_min_and_max_by_percent(clip_amount);
         }
@@ -388,21 +389,18 @@ impl WhiteboxTool for NormalizedDifferenceIndex {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -181,8 +181,9 @@ impl WhiteboxTool for PanchromaticSharpening {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut red_file = String::new();
         let mut green_file = String::new();
         let mut blue_file = String::new();
@@ -263,11 +264,11 @@ impl WhiteboxTool for PanchromaticSharpening {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -312,7 +313,7 @@ impl WhiteboxTool for PanchromaticSharpening {
 
         if use_composite {
             if verbose {
-                println!("Reading multispec image data...")
+                context.info("Reading multispec image data...")
             };
             let input_c = Raster::new(&composite_file, "r")?;
 
@@ -328,15 +329,15 @@ impl WhiteboxTool for PanchromaticSharpening {
             input = input_c.get_data_as_array2d();
         } else {
             if verbose {
-                println!("Reading red band data...")
+                context.info("Reading red band data...")
             };
             let input_r = Raster::new(&red_file, "r")?;
             if verbose {
-                println!("Reading green band data...")
+                context.info("Reading green band data...")
             };
             let input_g = Raster::new(&green_file, "r")?;
             if verbose {
-                println!("Reading blue band data...")
+                context.info("Reading blue band data...")
             };
             let input_b = Raster::new(&blue_file, "r")?;
 
@@ -377,9 +378,9 @@ impl WhiteboxTool for PanchromaticSharpening {
             let b_range = input_b.configs.display_max - input_b.configs.display_min;
             for row in 0..rows_ms {
                 for col in 0..columns_ms {
-                    r = input_r[(row, col)];
-                    g = input_g[(row, col)];
-                    b = input_b[(row, col)];
+                    r = input_r.get_value(row, col);
+                    g = input_g.get_value(row, col);
+                    b = input_b.get_value(row, col);
                     if r != nodata_r && g != nodata_g && b != nodata_b {
                         r = (r - r_min) / r_range * 255f64;
                         if r < 0f64 {
@@ -418,7 +419,7 @@ impl WhiteboxTool for PanchromaticSharpening {
         let input = Arc::new(input);
 
         if verbose {
-            println!("Reading pan image data...")
+            context.info("Reading pan image data...")
         };
         let pan = Arc::new(Raster::new(&pan_file, "r")?);
         let rows_pan = pan.configs.rows as isize;
@@ -460,7 +461,7 @@ impl WhiteboxTool for PanchromaticSharpening {
                         for col in 0..columns_pan {
                             x = pan.get_x_from_column(col);
                             source_col = get_column_from_x(x);
-                            z_pan = pan[(row, col)];
+                            z_pan = pan.get_value(row, col);
                             z_ms = input[(source_row, source_col)];
 
                             if z_ms != nodata_ms && z_pan != nodata_pan {
@@ -496,7 +497,9 @@ impl WhiteboxTool for PanchromaticSharpening {
                                     ((255 << 24) | (b_out << 16) | (g_out << 8) | r_out) as f64;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -504,12 +507,10 @@ impl WhiteboxTool for PanchromaticSharpening {
             for row in 0..rows_pan {
                 let data = rx.recv().expect("Error receiving data from thread.");
                 output.set_row_data(data.0, data.1);
-                if verbose {
-                    progress = (100.0_f64 * (row + 1) as f64 / rows_pan as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * (row + 1) as f64 / rows_pan as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
         } else {
@@ -544,7 +545,7 @@ impl WhiteboxTool for PanchromaticSharpening {
                             }
                         }
                     }
-                    tx.send(overall_max).unwrap();
+                    let _ = tx.send(overall_max);
                 });
             }
 
@@ -554,12 +555,10 @@ impl WhiteboxTool for PanchromaticSharpening {
                 if data > overall_max {
                     overall_max = data;
                 }
-                if verbose {
-                    progress = (100.0_f64 * tid as f64 / (num_procs - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * tid as f64 / (num_procs - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -588,7 +587,7 @@ impl WhiteboxTool for PanchromaticSharpening {
                         for col in 0..columns_pan {
                             x = pan.get_x_from_column(col);
                             source_col = get_column_from_x(x);
-                            z_pan = pan[(row, col)];
+                            z_pan = pan.get_value(row, col);
                             z_ms = input[(source_row, source_col)];
                             if z_ms != nodata_ms && z_pan != nodata_pan {
                                 p = (z_pan - pan_min) / pan_range;
@@ -670,7 +669,9 @@ impl WhiteboxTool for PanchromaticSharpening {
                                     ((255 << 24) | (b_out << 16) | (g_out << 8) | r_out) as f64;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -678,12 +679,10 @@ impl WhiteboxTool for PanchromaticSharpening {
             for row in 0..rows_pan {
                 let data = rx.recv().expect("Error receiving data from thread.");
                 output.set_row_data(data.0, data.1);
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows_pan - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows_pan - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
         }
@@ -706,21 +705,18 @@ impl WhiteboxTool for PanchromaticSharpening {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -13,6 +13,7 @@ use std::env;
 use std::f64;
 use std::io::{Error, ErrorKind};
 use std::path;
+use std::str::FromStr;
 use std::sync::mpsc;
 use std::sync::Arc;
 use std::thread;
@@ -153,8 +154,9 @@ impl WhiteboxTool for Resample {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_files = String::new();
         let mut output_file = String::new();
         let mut base_file = String::new();
@@ -223,30 +225,20 @@ impl WhiteboxTool for Resample {
                 } else {
                     args[i + 1].to_string()
                 };
-                if method.to_lowercase().contains("nn") || method.to_lowercase().contains("nearest")
-                {
-                    method = "nn".to_string();
-                } else if method.to_lowercase().contains("bilinear")
-                    || method.to_lowercase().contains("bi")
-                {
-                    method = "bilinear".to_string();
-                } else if method.to_lowercase().contains("cc")
-                    || method.to_lowercase().contains("cubic")
-                {
-                    method = "cc".to_string();
-                }
             }
         }
+        // unrecognized methods are treated as bilinear
+        let method = method.parse().unwrap_or(ResampleMethod::Bilinear);
 
         if verbose {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -287,7 +279,7 @@ impl WhiteboxTool for Resample {
 
         // read the input files
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let mut inputs: Vec<Raster> = Vec::with_capacity(num_files);
         let mut nodata_vals: Vec<f64> = Vec::with_capacity(num_files);
@@ -384,7 +376,6 @@ impl WhiteboxTool for Resample {
         let x = Arc::new(x);
         let y = Arc::new(y);
         let inputs = Arc::new(inputs);
-        let nodata_vals = Arc::new(nodata_vals);
         let mut num_procs = num_cpus::get() as isize;
         let configs = whitebox_common::configs::get_configs()?;
         let max_procs = configs.max_procs;
@@ -392,217 +383,48 @@ impl WhiteboxTool for Resample {
             num_procs = max_procs;
         }
         let (tx, rx) = mpsc::channel();
-        if method == "nn" {
-            for tid in 0..num_procs {
-                let inputs = inputs.clone();
-                let nodata_vals = nodata_vals.clone();
-                let x = x.clone();
-                let y = y.clone();
-                let tx = tx.clone();
-                thread::spawn(move || {
-                    let mut z: f64;
-                    let (mut col_src, mut row_src): (isize, isize);
-                    for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut data = vec![nodata; columns as usize];
-                        for col in 0..columns {
-                            for i in 0..num_files {
-                                row_src = inputs[i].get_row_from_y(y[row as usize]);
-                                col_src = inputs[i].get_column_from_x(x[col as usize]);
-                                // row_src = ((inputs[i].configs.north - y[row as usize]) / inputs[i].configs.resolution_y).round() as isize;
-                                // col_src = ((x[col as usize] - inputs[i].configs.west) / inputs[i].configs.resolution_x).round() as isize;
-                                z = inputs[i].get_value(row_src, col_src);
-                                if z != nodata_vals[i] {
-                                    data[col as usize] = z;
-                                    break;
-                                }
-                            }
-                        }
-                        tx.send((row, data)).unwrap();
-                    }
-                });
-            }
-            for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
-                for col in 0..columns {
-                    if data[col as usize] != nodata {
-                        output.set_value(row, col, data[col as usize]);
-                    }
-                }
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
-                }
-            }
-        } else if method == "cc" {
+        if method != ResampleMethod::NearestNeighbour {
             output.configs.photometric_interp = PhotometricInterpretation::Continuous;
             output.configs.data_type = DataType::F32;
-
-            for tid in 0..num_procs {
-                let inputs = inputs.clone();
-                let nodata_vals = nodata_vals.clone();
-                let x = x.clone();
-                let y = y.clone();
-                let tx = tx.clone();
-                thread::spawn(move || {
-                    let mut z: f64;
-                    let shift_x = [-1, 0, 1, 2, -1, 0, 1, 2, -1, 0, 1, 2, -1, 0, 1, 2];
-                    let shift_y = [-1, -1, -1, -1, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2];
-                    let num_neighbours = 16;
-                    let mut neighbour = [[0f64; 2]; 16];
-                    let (mut col_src, mut row_src): (f64, f64);
-                    let (mut col_n, mut row_n): (isize, isize);
-                    let (mut origin_row, mut origin_col): (isize, isize);
-                    let (mut dx, mut dy): (f64, f64);
-                    let mut sum_dist: f64;
-                    for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut data = vec![nodata; columns as usize];
-                        for col in 0..columns {
-                            let mut flag = true;
-                            for i in 0..num_files {
-                                if !flag {
-                                    break;
-                                }
-                                // row_src = inputs[i].get_row_from_y(y[row as usize]);
-                                // col_src = inputs[i].get_column_from_x(x[col as usize]);
-                                row_src = (inputs[i].configs.north - y[row as usize])
-                                    / inputs[i].configs.resolution_y;
-                                col_src = (x[col as usize] - inputs[i].configs.west)
-                                    / inputs[i].configs.resolution_x;
-                                origin_row = row_src.floor() as isize;
-                                origin_col = col_src.floor() as isize;
-                                sum_dist = 0f64;
-                                for n in 0..num_neighbours {
-                                    row_n = origin_row + shift_y[n];
-                                    col_n = origin_col + shift_x[n];
-                                    neighbour[n][0] = inputs[i].get_value(row_n, col_n);
-                                    dy = row_n as f64 - row_src;
-                                    dx = col_n as f64 - col_src;
-
-                                    if (dx + dy) != 0f64 && neighbour[n][0] != nodata_vals[i] {
-                                        neighbour[n][1] = 1f64 / (dx * dx + dy * dy);
-                                        sum_dist += neighbour[n][1];
-                                    } else if neighbour[n][0] == nodata_vals[i] {
-                                        neighbour[n][1] = 0f64;
-                                    } else {
-                                        data[col as usize] = neighbour[n][0];
-                                        flag = false;
-                                    }
-                                }
-
-                                if sum_dist > 0f64 && flag {
-                                    z = 0f64;
-                                    for n in 0..num_neighbours {
-                                        z += (neighbour[n][0] * neighbour[n][1]) / sum_dist;
-                                    }
-                                    data[col as usize] = z;
-                                    flag = false;
-                                }
+        }
+        for tid in 0..num_procs {
+            let inputs = inputs.clone();
+            let x = x.clone();
+            let y = y.clone();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                for row in (0..rows).filter(|r| r % num_procs == tid) {
+                    let mut data = vec![nodata; columns as usize];
+                    for col in 0..columns {
+                        for i in 0..num_files {
+                            if let Some(z) = interpolate_raster_value(
+                                &inputs[i],
+                                x[col as usize],
+                                y[row as usize],
+                                method,
+                            ) {
+                                data[col as usize] = z;
+                                break;
                             }
                         }
-                        tx.send((row, data)).unwrap();
                     }
-                });
-            }
-            for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
-                for col in 0..columns as usize {
-                    if data[col] != nodata {
-                        output.set_value(row, col as isize, data[col]);
+                    if tx.send((row, data)).is_err() {
+                        break;
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+            });
+        }
+        for r in 0..rows {
+            let (row, data) = rx.recv().expect("Error receiving data from thread.");
+            for (col, &z) in data.iter().enumerate() {
+                if z != nodata {
+                    output.set_value(row, col as isize, z);
                 }
             }
-        } else {
-            // bilinear
-            output.configs.photometric_interp = PhotometricInterpretation::Continuous;
-            output.configs.data_type = DataType::F32;
-            for tid in 0..num_procs {
-                let inputs = inputs.clone();
-                let nodata_vals = nodata_vals.clone();
-                let x = x.clone();
-                let y = y.clone();
-                let tx = tx.clone();
-                thread::spawn(move || {
-                    let mut z: f64;
-                    let shift_x = [0, 1, 0, 1];
-                    let shift_y = [0, 0, 1, 1];
-                    let num_neighbours = 4;
-                    let mut neighbour = [[0f64; 2]; 4];
-                    let (mut col_src, mut row_src): (f64, f64);
-                    let (mut col_n, mut row_n): (isize, isize);
-                    let (mut origin_col, mut origin_row): (isize, isize);
-                    let (mut dx, mut dy): (f64, f64);
-                    let mut sum_dist: f64;
-                    for row in (0..rows).filter(|r| r % num_procs == tid) {
-                        let mut data = vec![nodata; columns as usize];
-                        for col in 0..columns {
-                            let mut flag = true;
-                            for i in 0..num_files {
-                                if !flag {
-                                    break;
-                                }
-                                row_src = (inputs[i].configs.north - y[row as usize])
-                                    / inputs[i].configs.resolution_y;
-                                col_src = (x[col as usize] - inputs[i].configs.west)
-                                    / inputs[i].configs.resolution_x;
-                                origin_row = row_src.floor() as isize;
-                                origin_col = col_src.floor() as isize;
-                                sum_dist = 0f64;
-                                for n in 0..num_neighbours {
-                                    row_n = origin_row + shift_y[n];
-                                    col_n = origin_col + shift_x[n];
-                                    neighbour[n][0] = inputs[i].get_value(row_n, col_n);
-                                    dy = row_n as f64 - row_src;
-                                    dx = col_n as f64 - col_src;
-
-                                    if (dx + dy) != 0f64 && neighbour[n][0] != nodata_vals[i] {
-                                        neighbour[n][1] = 1f64 / (dx * dx + dy * dy);
-                                        sum_dist += neighbour[n][1];
-                                    } else if neighbour[n][0] == nodata_vals[i] {
-                                        neighbour[n][1] = 0f64;
-                                    } else {
-                                        data[col as usize] = neighbour[n][0];
-                                        flag = false;
-                                    }
-                                }
-
-                                if sum_dist > 0f64 && flag {
-                                    z = 0f64;
-                                    for n in 0..num_neighbours {
-                                        z += (neighbour[n][0] * neighbour[n][1]) / sum_dist;
-                                    }
-                                    data[col as usize] = z;
-                                    flag = false;
-                                }
-                            }
-                        }
-                        tx.send((row, data)).unwrap();
-                    }
-                });
-            }
-            for r in 0..rows {
-                let (row, data) = rx.recv().expect("Error receiving data from thread.");
-                for col in 0..columns as usize {
-                    if data[col] != nodata {
-                        output.set_value(row, col as isize, data[col]);
-                    }
-                }
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
-                }
+            progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -613,23 +435,106 @@ impl WhiteboxTool for Resample {
         ));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
     }
 }
+
+/// The interpolation methods used by `Resample`, which are shared with other tools that
+/// warp rasters (e.g. `ReprojectRaster`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum ResampleMethod {
+    NearestNeighbour,
+    Bilinear,
+    CubicConvolution,
+}
+
+impl FromStr for ResampleMethod {
+    type Err = Error;
+
+    /// Parses a --method parameter value, e.g. 'nn', 'nearest', 'bilinear' or 'cc'.
+    fn from_str(method: &str) -> Result<ResampleMethod, Error> {
+        let method = method.to_lowercase();
+        if method.contains("nn") || method.contains("nearest") {
+            Ok(ResampleMethod::NearestNeighbour)
+        } else if method.contains("bilinear") || method.contains("bi") {
+            Ok(ResampleMethod::Bilinear)
+        } else if method.contains("cc") || method.contains("cubic") {
+            Ok(ResampleMethod::CubicConvolution)
+        } else {
+            Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("Unrecognized resampling method '{}'.", method),
+            ))
+        }
+    }
+}
+
+/// Returns the value of the `input` raster at the location (`x`, `y`), using the specified
+/// interpolation method, or `None` if the location is outside of the raster or NoData.
+pub(crate) fn interpolate_raster_value(
+    input: &Raster,
+    x: f64,
+    y: f64,
+    method: ResampleMethod,
+) -> Option<f64> {
+    let nodata = input.configs.nodata;
+    if method == ResampleMethod::NearestNeighbour {
+        let z = input.get_value(input.get_row_from_y(y), input.get_column_from_x(x));
+        return if z != nodata { Some(z) } else { None };
+    }
+    let (shift_x, shift_y): (&[isize], &[isize]) = if method == ResampleMethod::CubicConvolution {
+        (
+            &[-1, 0, 1, 2, -1, 0, 1, 2, -1, 0, 1, 2, -1, 0, 1, 2],
+            &[-1, -1, -1, -1, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2],
+        )
+    } else {
+        (&[0, 1, 0, 1], &[0, 0, 1, 1])
+    };
+    let mut neighbour = [[0f64; 2]; 16];
+    let row_src = (input.configs.north - y) / input.configs.resolution_y;
+    let col_src = (x - input.configs.west) / input.configs.resolution_x;
+    let origin_row = row_src.floor() as isize;
+    let origin_col = col_src.floor() as isize;
+    let mut sum_dist = 0f64;
+    let mut coincident = None;
+    for n in 0..shift_x.len() {
+        let row_n = origin_row + shift_y[n];
+        let col_n = origin_col + shift_x[n];
+        neighbour[n][0] = input.get_value(row_n, col_n);
+        let dy = row_n as f64 - row_src;
+        let dx = col_n as f64 - col_src;
+        if (dx + dy) != 0f64 && neighbour[n][0] != nodata {
+            neighbour[n][1] = 1f64 / (dx * dx + dy * dy);
+            sum_dist += neighbour[n][1];
+        } else if neighbour[n][0] == nodata {
+            neighbour[n][1] = 0f64;
+        } else {
+            coincident = Some(neighbour[n][0]);
+        }
+    }
+    if coincident.is_some() {
+        return coincident;
+    }
+    if sum_dist > 0f64 {
+        let mut z = 0f64;
+        for n in neighbour.iter().take(shift_x.len()) {
+            z += (n[0] * n[1]) / sum_dist;
+        }
+        return Some(z);
+    }
+    None
+}
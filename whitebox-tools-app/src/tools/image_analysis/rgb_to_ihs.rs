@@ -193,8 +193,9 @@ impl WhiteboxTool for RgbToIhs {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut red_file = String::new();
         let mut green_file = String::new();
         let mut blue_file = String::new();
@@ -285,11 +286,11 @@ impl WhiteboxTool for RgbToIhs {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -328,15 +329,15 @@ impl WhiteboxTool for RgbToIhs {
 
         if !use_composite {
             if verbose {
-                println!("Reading red band data...")
+                context.info("Reading red band data...")
             };
             let input_r = Arc::new(Raster::new(&red_file, "r")?);
             if verbose {
-                println!("Reading green band data...")
+                context.info("Reading green band data...")
             };
             let input_g = Arc::new(Raster::new(&green_file, "r")?);
             if verbose {
-                println!("Reading blue band data...")
+                context.info("Reading blue band data...")
             };
             let input_b = Arc::new(Raster::new(&blue_file, "r")?);
 
@@ -390,9 +391,9 @@ impl WhiteboxTool for RgbToIhs {
                         let mut hue_data = vec![nodata_r; columns as usize];
                         let mut saturation_data = vec![nodata_r; columns as usize];
                         for col in 0..columns {
-                            red = input_r[(row, col)];
-                            green = input_g[(row, col)];
-                            blue = input_b[(row, col)];
+                            red = input_r.get_value(row, col);
+                            green = input_g.get_value(row, col);
+                            blue = input_b.get_value(row, col);
                             if red != nodata_r && green != nodata_g && blue != nodata_b {
                                 // r = ((red - red_min) / (red_max - red_min) * 255f64) as u32;
                                 // if r > 255u32 {
@@ -419,8 +420,9 @@ impl WhiteboxTool for RgbToIhs {
                                 intensity_data[col as usize] = i;
                             }
                         }
-                        tx.send((row, intensity_data, hue_data, saturation_data))
-                            .unwrap();
+                        if tx.send((row, intensity_data, hue_data, saturation_data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -442,12 +444,10 @@ impl WhiteboxTool for RgbToIhs {
                 output_i.set_row_data(data.0, data.1);
                 output_h.set_row_data(data.0, data.2);
                 output_s.set_row_data(data.0, data.3);
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -461,12 +461,12 @@ impl WhiteboxTool for RgbToIhs {
             output_i.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving intensity data...")
+                context.info("Saving intensity data...")
             };
             let _ = match output_i.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
@@ -480,12 +480,12 @@ impl WhiteboxTool for RgbToIhs {
             output_h.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving hue data...")
+                context.info("Saving hue data...")
             };
             let _ = match output_h.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
@@ -501,26 +501,23 @@ impl WhiteboxTool for RgbToIhs {
             );
 
             if verbose {
-                println!("Saving saturation data...")
+                context.info("Saving saturation data...")
             };
             let _ = match output_s.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
             };
 
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-                );
+                context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
             }
         } else {
             if verbose {
-                println!("Reading image data...")
+                context.info("Reading image data...")
             };
             let input = Arc::new(Raster::new(&composite_file, "r")?);
             let rows = input.configs.rows as isize;
@@ -541,7 +538,7 @@ impl WhiteboxTool for RgbToIhs {
                     let mut z: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
                         for col in 0..columns {
-                            z = input[(row, col)];
+                            z = input.get_value(row, col);
                             if z != nodata {
                                 r = (z as u32 & 0xFF) as f64;
                                 g = ((z as u32 >> 8) & 0xFF) as f64;
@@ -570,7 +567,7 @@ impl WhiteboxTool for RgbToIhs {
                             }
                         }
                     }
-                    tx.send((overall_min, overall_max)).unwrap();
+                    let _ = tx.send((overall_min, overall_max));
                 });
             }
 
@@ -584,12 +581,10 @@ impl WhiteboxTool for RgbToIhs {
                 if data.1 > overall_max {
                     overall_max = data.1;
                 }
-                if verbose {
-                    progress = (100.0_f64 * tid as f64 / (num_procs - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * tid as f64 / (num_procs - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -609,7 +604,7 @@ impl WhiteboxTool for RgbToIhs {
                         let mut hue_data = vec![nodata; columns as usize];
                         let mut saturation_data = vec![nodata; columns as usize];
                         for col in 0..columns {
-                            z = input[(row, col)];
+                            z = input.get_value(row, col);
                             if z != nodata {
                                 // r = (z as u32 & 0xFF) as f64;
                                 // g = ((z as u32 >> 8) & 0xFF) as f64;
@@ -670,8 +665,9 @@ impl WhiteboxTool for RgbToIhs {
                                 saturation_data[col as usize] = s;
                             }
                         }
-                        tx.send((row, intensity_data, hue_data, saturation_data))
-                            .unwrap();
+                        if tx.send((row, intensity_data, hue_data, saturation_data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -693,12 +689,10 @@ impl WhiteboxTool for RgbToIhs {
                 output_i.set_row_data(data.0, data.1);
                 output_h.set_row_data(data.0, data.2);
                 output_s.set_row_data(data.0, data.3);
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -714,12 +708,12 @@ impl WhiteboxTool for RgbToIhs {
             );
 
             if verbose {
-                println!("Saving intensity data...")
+                context.info("Saving intensity data...")
             };
             let _ = match output_i.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
@@ -735,12 +729,12 @@ impl WhiteboxTool for RgbToIhs {
             );
 
             if verbose {
-                println!("Saving hue data...")
+                context.info("Saving hue data...")
             };
             let _ = match output_h.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
@@ -756,21 +750,18 @@ impl WhiteboxTool for RgbToIhs {
             );
 
             if verbose {
-                println!("Saving saturation data...")
+                context.info("Saving saturation data...")
             };
             let _ = match output_s.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-                );
+                context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
             }
         }
 
@@ -142,8 +142,9 @@ impl WhiteboxTool for SplitColourComposite {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut red_file = String::new();
         let mut green_file = String::new();
@@ -195,11 +196,11 @@ impl WhiteboxTool for SplitColourComposite {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -221,7 +222,7 @@ impl WhiteboxTool for SplitColourComposite {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -263,7 +264,9 @@ impl WhiteboxTool for SplitColourComposite {
                             data_b[col as usize] = blue as f64;
                         }
                     }
-                    tx.send((row, data_r, data_g, data_b)).unwrap();
+                    if tx.send((row, data_r, data_g, data_b)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -288,12 +291,10 @@ impl WhiteboxTool for SplitColourComposite {
             output_r.set_row_data(data.0, data.1);
             output_g.set_row_data(data.0, data.2);
             output_b.set_row_data(data.0, data.3);
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -306,12 +307,12 @@ impl WhiteboxTool for SplitColourComposite {
         output_r.add_metadata_entry(format!("Input file: {}", input_file));
         output_r.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         if verbose {
-            println!("Saving red image...")
+            context.info("Saving red image...")
         };
         let _ = match output_r.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -324,12 +325,12 @@ impl WhiteboxTool for SplitColourComposite {
         output_g.add_metadata_entry(format!("Input file: {}", input_file));
         output_g.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         if verbose {
-            println!("Saving green image...")
+            context.info("Saving green image...")
         };
         let _ = match output_g.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -342,21 +343,18 @@ impl WhiteboxTool for SplitColourComposite {
         output_b.add_metadata_entry(format!("Input file: {}", input_file));
         output_b.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         if verbose {
-            println!("Saving blue image...")
+            context.info("Saving blue image...")
         };
         let _ = match output_b.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -162,8 +162,9 @@ impl WhiteboxTool for TophatTransform {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut filter_size_x = 11usize;
@@ -258,11 +259,11 @@ impl WhiteboxTool for TophatTransform {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -295,7 +296,7 @@ impl WhiteboxTool for TophatTransform {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -376,7 +377,9 @@ impl WhiteboxTool for TophatTransform {
                                 }
                             }
                         }
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -385,12 +388,10 @@ impl WhiteboxTool for TophatTransform {
             for row in 0..rows {
                 let data = rx.recv().expect("Error receiving data from thread.");
                 erosion.set_row_data(data.0, data.1);
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress (Loop 1 of 2): {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress (Loop 1 of 2)", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -455,11 +456,13 @@ impl WhiteboxTool for TophatTransform {
                                     }
                                 }
                                 if max_val > f64::NEG_INFINITY {
-                                    data[col as usize] = input_data[(row, col)] - max_val;
+                                    data[col as usize] = input_data.get_value(row, col) - max_val;
                                 }
                             }
                         }
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -468,12 +471,10 @@ impl WhiteboxTool for TophatTransform {
             for row in 0..rows {
                 let data = rx.recv().expect("Error receiving data from thread.");
                 output.set_row_data(data.0, data.1);
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress (Loop 2 of 2): {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress (Loop 2 of 2)", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -489,22 +490,19 @@ impl WhiteboxTool for TophatTransform {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                context.info("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
             };
 
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
         } else {
             // black top-hat transform
@@ -571,7 +569,9 @@ impl WhiteboxTool for TophatTransform {
                                 }
                             }
                         }
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -580,12 +580,10 @@ impl WhiteboxTool for TophatTransform {
             for row in 0..rows {
                 let data = rx.recv().expect("Error receiving data from thread.");
                 dilation.set_row_data(data.0, data.1);
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress (Loop 1 of 2): {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress (Loop 1 of 2)", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -650,11 +648,13 @@ impl WhiteboxTool for TophatTransform {
                                     }
                                 }
                                 if min_val < f64::INFINITY {
-                                    data[col as usize] = min_val - input_data[(row, col)];
+                                    data[col as usize] = min_val - input_data.get_value(row, col);
                                 }
                             }
                         }
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -663,12 +663,10 @@ impl WhiteboxTool for TophatTransform {
             for row in 0..rows {
                 let data = rx.recv().expect("Error receiving data from thread.");
                 output.set_row_data(data.0, data.1);
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress (Loop 2 of 2): {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress (Loop 2 of 2)", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -684,21 +682,18 @@ impl WhiteboxTool for TophatTransform {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                context.info("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
             };
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
         }
 
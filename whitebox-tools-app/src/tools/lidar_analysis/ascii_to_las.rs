@@ -22,7 +22,7 @@ use whitebox_common::spatial_ref_system::esri_wkt_from_epsg;
 /// specify the name(s) of the input ASCII file(s) (`--inputs`). Each input file will have a correspondingly named
 /// output file with a `.las` file extension. The output point data, each on a separate line, will take the format:
 ///
-/// ```
+/// ```text
 /// x,y,z,intensity,class,return,num_returns"
 /// ```
 ///
@@ -44,7 +44,7 @@ use whitebox_common::spatial_ref_system::esri_wkt_from_epsg;
 /// The `x`, `y`, and `z` patterns must always be specified. If the `rn` pattern is used, the `nr` pattern must
 /// also be specified. Examples of valid pattern string include:
 ///
-/// ```
+/// ```text
 /// 'x,y,z,i'
 /// 'x,y,z,i,rn,nr'
 /// 'x,y,z,i,c,rn,nr,sa'
@@ -162,8 +162,9 @@ impl WhiteboxTool for AsciiToLas {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_files: String = String::new();
         let mut pattern_string = String::new();
         let mut proj_string = String::new();
@@ -225,11 +226,11 @@ impl WhiteboxTool for AsciiToLas {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep = std::path::MAIN_SEPARATOR;
@@ -305,7 +306,7 @@ impl WhiteboxTool for AsciiToLas {
                     pattern_has_b = true;
                     pattern_numeric[a] = 11usize
                 }
-                _ => println!("Unrecognized pattern {}", pattern_vec[a]),
+                _ => context.info(format!("Unrecognized pattern {}", pattern_vec[a])),
             }
         }
 
@@ -372,7 +373,7 @@ impl WhiteboxTool for AsciiToLas {
                 output.use_point_userdata = true;
 
                 if verbose {
-                    println!("Parsing file {}...", output.get_short_filename());
+                    context.info(format!("Parsing file {}...", output.get_short_filename()));
                 }
                 let f = File::open(input_file.clone())?;
                 let f = BufReader::new(f);
@@ -473,7 +474,7 @@ impl WhiteboxTool for AsciiToLas {
                                             .parse::<u16>()
                                             .expect("Error parsing data.")
                                     }
-                                    _ => println!("unrecognized pattern"),
+                                    _ => context.info("unrecognized pattern"),
                                 }
                             }
 
@@ -564,30 +565,28 @@ impl WhiteboxTool for AsciiToLas {
                 // output.add_vlr(vlr3);
 
                 if verbose {
-                    println!("Writing output LAS file {}...", output.get_short_filename());
+                    context.info(format!("Writing output LAS file {}...", output.get_short_filename()));
                 }
                 let _ = match output.write() {
                     Ok(_) => {
                         if verbose {
-                            println!("Complete!")
+                            context.info("Complete!")
                         }
                     }
-                    Err(e) => println!("error while writing: {:?}", e),
+                    Err(e) => context.error(format!("error while writing: {:?}", e)),
                 };
             }
-            if verbose {
-                progress = (100.0_f64 * (i + 1) as f64 / num_files as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (i + 1) as f64 / num_files as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
             i += 1;
         }
 
         if verbose {
             let elapsed_time = get_formatted_elapsed_time(start);
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -134,8 +134,9 @@ impl WhiteboxTool for LidarBlockMaximum {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut grid_res: f64 = 1.0;
@@ -238,6 +239,13 @@ impl WhiteboxTool for LidarBlockMaximum {
                                 .replace(".zlidar", ".tif")
                                 .replace(".ZLIDAR", ".tif"),
                         )
+                    } else if s.to_lowercase().ends_with(".laz") {
+                        inputs.push(s);
+                        outputs.push(
+                            inputs[inputs.len() - 1]
+                                .replace(".laz", ".tif")
+                                .replace(".LAZ", ".tif"),
+                        )
                     } else if s.to_lowercase().ends_with(".zip") {
                         inputs.push(s);
                         outputs.push(
@@ -260,7 +268,8 @@ impl WhiteboxTool for LidarBlockMaximum {
                     .clone()
                     .replace(".las", ".tif")
                     .replace(".LAS", ".tif")
-                    .replace(".zlidar", ".tif");
+                    .replace(".zlidar", ".tif")
+                    .replace(".laz", ".tif");
             }
             if !output_file.contains(path::MAIN_SEPARATOR) && !output_file.contains("/") {
                 output_file = format!("{}{}", working_directory, output_file);
@@ -272,11 +281,11 @@ impl WhiteboxTool for LidarBlockMaximum {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         for k in 0..inputs.len() {
@@ -284,12 +293,12 @@ impl WhiteboxTool for LidarBlockMaximum {
             output_file = outputs[k].replace("\"", "").clone();
 
             if verbose && inputs.len() > 1 {
-                println!(
+                context.info(format!(
                     "Gridding {} of {} ({})",
                     k + 1,
                     inputs.len(),
                     input_file.clone()
-                );
+                ));
             }
 
             if !input_file.contains(path::MAIN_SEPARATOR) {
@@ -300,7 +309,7 @@ impl WhiteboxTool for LidarBlockMaximum {
             }
 
             if verbose && inputs.len() == 1 {
-                println!("Reading input LAS file...");
+                context.info("Reading input LAS file...");
             }
             let input = match LasFile::new(&input_file, "r") {
                 Ok(lf) => lf,
@@ -310,7 +319,7 @@ impl WhiteboxTool for LidarBlockMaximum {
             let start_run = Instant::now();
 
             if verbose && inputs.len() == 1 {
-                println!("Performing analysis...");
+                context.info("Performing analysis...");
             }
 
             let n_points = input.header.number_of_points as usize;
@@ -344,6 +353,7 @@ impl WhiteboxTool for LidarBlockMaximum {
             configs.palette = palette.clone();
 
             let mut output = Raster::initialize_using_config(&output_file, &configs);
+            output.widen_to_f64();
 
             let input = Arc::new(input); // wrap input in an Arc
             let num_procs = num_cpus::get();
@@ -361,7 +371,9 @@ impl WhiteboxTool for LidarBlockMaximum {
                             .floor()) as isize;
                         row = (((rows - 1) as f64 * (north - half_grid_res - p.y) / ns_range)
                             .floor()) as isize;
-                        tx.send((row, col, p.z)).unwrap();
+                        if tx.send((row, col, p.z)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -376,15 +388,13 @@ impl WhiteboxTool for LidarBlockMaximum {
                 row = data.0;
                 col = data.1;
                 z = data.2;
-                if output[(row, col)] == nodata || z > output[(row, col)] {
+                if output.get_value(row, col) == nodata || z > output.get_value(row, col) {
                     output.set_value(row, col, z);
                 }
-                if verbose {
-                    progress = (100.0_f64 * i as f64 / num_points) as i32;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * i as f64 / num_points) as i32;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -400,12 +410,12 @@ impl WhiteboxTool for LidarBlockMaximum {
             ));
 
             if verbose {
-                println!("Saving data...")
+                context.info("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
@@ -414,10 +424,7 @@ impl WhiteboxTool for LidarBlockMaximum {
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
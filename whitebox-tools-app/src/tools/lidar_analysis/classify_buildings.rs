@@ -132,8 +132,9 @@ impl WhiteboxTool for ClassifyBuildingsInLidar {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut polygons_file = String::new();
         let mut output_file = String::new();
@@ -179,11 +180,11 @@ impl WhiteboxTool for ClassifyBuildingsInLidar {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -202,7 +203,7 @@ impl WhiteboxTool for ClassifyBuildingsInLidar {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -243,7 +244,7 @@ impl WhiteboxTool for ClassifyBuildingsInLidar {
         }
 
         if verbose {
-            println!("Performing reclassification...")
+            context.info("Performing reclassification...")
         };
 
         let n_points = input.header.number_of_points as usize;
@@ -318,13 +319,9 @@ impl WhiteboxTool for ClassifyBuildingsInLidar {
                             }
                         }
                     }
-                    match tx.send((point_in_poly, point_num)) {
-                        Ok(_) => {} // do nothing
-                        Err(_) => panic!(
-                            "Error performing clipping operation on point num. {}",
-                            point_num
-                        ),
-                    };
+                    if tx.send((point_in_poly, point_num)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -335,7 +332,7 @@ impl WhiteboxTool for ClassifyBuildingsInLidar {
         for i in 0..n_points {
             let data = rx.recv().expect("Error receiving data from thread.");
             if !data.0 {
-                output.add_point_record(input.get_record(data.1));
+                output.add_point_record_from(&input, data.1);
             } else {
                 num_building_points += 1;
                 let pr = input.get_record(data.1);
@@ -466,46 +463,38 @@ impl WhiteboxTool for ClassifyBuildingsInLidar {
                         };
                     }
                 }
-                output.add_point_record(pr2);
+                output.add_point_record_with_extra_bytes(pr2, &input, data.1);
             }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
-        println!(
-            "Number of building points classified: {}",
-            num_building_points
-        );
+        context.info(format!("Number of building points classified: {}", num_building_points));
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            context.info("Writing output LAS file...");
         }
         if output.header.number_of_points > 0 {
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Complete!")
+                        context.info("Complete!")
                     }
                 }
-                Err(e) => println!("error while writing: {:?}", e),
+                Err(e) => context.error(format!("error while writing: {:?}", e)),
             };
         } else {
             if verbose {
-                println!("Warning: the file {} does not appear to contain any points within the clip polygon. No output file has been created.", output.get_short_filename());
+                context.warning(format!("Warning: the file {} does not appear to contain any points within the clip polygon. No output file has been created.", output.get_short_filename()));
             }
         }
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -147,8 +147,9 @@ impl WhiteboxTool for ClassifyOverlapPoints {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut grid_res: f64 = 1.0;
@@ -220,20 +221,20 @@ impl WhiteboxTool for ClassifyOverlapPoints {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
-        println!("Reading input LAS file...");
+        context.info("Reading input LAS file...");
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
             Err(err) => panic!("Error reading file {}: {}", input_file, err),
         };
 
-        println!("Performing analysis...");
+        context.info("Performing analysis...");
 
         // Make sure that the input LAS file have GPS time data?
         if input.header.point_format == 0u8 || input.header.point_format == 2u8 {
@@ -359,12 +360,10 @@ impl WhiteboxTool for ClassifyOverlapPoints {
             frs.insert(x, y, i);
             gps_times[i] = gps_time;
             scan_angles[i] = sa.abs();
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as usize;
-                if progress != old_progress {
-                    println!("Binning points: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as usize;
+            if progress != old_progress {
+                context.progress("Binning points", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -436,12 +435,10 @@ impl WhiteboxTool for ClassifyOverlapPoints {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -452,21 +449,19 @@ impl WhiteboxTool for ClassifyOverlapPoints {
             // filter points
             for i in 0..n_points {
                 if !filtered[i] {
-                    output.add_point_record(input.get_record(i));
+                    output.add_point_record_from(&input, i);
                 }
-                if verbose {
-                    progress = (100.0_f64 * i as f64 / num_points) as usize;
-                    if progress != old_progress {
-                        println!("Saving data: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * i as f64 / num_points) as usize;
+                if progress != old_progress {
+                    context.progress("Saving data", progress)?;
+                    old_progress = progress;
                 }
             }
         } else {
             // set overlap flag
             for i in 0..n_points {
                 if !overlapping[i] {
-                    output.add_point_record(input.get_record(i));
+                    output.add_point_record_from(&input, i);
                 } else {
                     let pr = input.get_record(i);
                     // pr.point_data.set_overlap(true); // change to this when 1.4 output is supported
@@ -597,35 +592,30 @@ impl WhiteboxTool for ClassifyOverlapPoints {
                             };
                         }
                     }
-                    output.add_point_record(pr2);
+                    output.add_point_record_with_extra_bytes(pr2, &input, i);
                 }
-                if verbose {
-                    progress = (100.0_f64 * i as f64 / num_points) as usize;
-                    if progress != old_progress {
-                        println!("Saving data: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * i as f64 / num_points) as usize;
+                if progress != old_progress {
+                    context.progress("Saving data", progress)?;
+                    old_progress = progress;
                 }
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!("Writing output LAS file...");
+            context.info("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    context.info("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => context.error(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
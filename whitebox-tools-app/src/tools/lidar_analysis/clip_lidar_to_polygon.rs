@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 25/04/2018
-Last Modified: 26/07/2019
+Last Modified: 17/10/2026
 License: MIT
 */
 
@@ -24,6 +24,10 @@ use std::thread;
 /// parts will be respected during clipping, i.e. LiDAR points within polygon holes will be removed from the output LAS
 /// file.
 ///
+/// Only the points within the extent of the clip polygons are read from the input file. When the input is a
+/// COPC (Cloud Optimized Point Cloud) file, only the parts of the file that overlap the polygons are decompressed,
+/// which makes it practical to clip small areas from very large point clouds without first tiling them.
+///
 /// Use the `ErasePolygonFromLidar` tool to perform the complementary operation of removing points from a LAS file
 /// that are contained within a set of polygons.
 ///
@@ -129,8 +133,9 @@ impl WhiteboxTool for ClipLidarToPolygon {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut polygons_file = String::new();
         let mut output_file = String::new();
@@ -176,11 +181,11 @@ impl WhiteboxTool for ClipLidarToPolygon {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -199,12 +204,33 @@ impl WhiteboxTool for ClipLidarToPolygon {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
-        let input = match LasFile::new(&input_file, "r") {
+        let polygons = Shapefile::read(&polygons_file)?;
+
+        // Only the points within the extent of the polygons are read. For COPC files,
+        // this means that only the octree nodes overlapping the polygons are decoded.
+        let polygons_bb = BoundingBox::new(
+            polygons.header.x_min,
+            polygons.header.x_max,
+            polygons.header.y_min,
+            polygons.header.y_max,
+        );
+        let input = match LasFile::read_within(&input_file, &polygons_bb, None) {
             Ok(lf) => lf,
-            Err(err) => panic!("Error reading file {}: {}", input_file, err),
+            Err(err) => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Error reading file {}: {}", input_file, err),
+                ))
+            }
         };
+        if input.header.number_of_points == 0 {
+            if verbose {
+                context.warning(format!("Warning: the file {} does not appear to contain any points within the clip polygon. No output file has been created.", input.get_short_filename()));
+            }
+            return Ok(());
+        }
 
         let lidar_bb = BoundingBox::new(
             input.header.min_x,
@@ -213,7 +239,6 @@ impl WhiteboxTool for ClipLidarToPolygon {
             input.header.max_y,
         );
 
-        let polygons = Shapefile::read(&polygons_file)?;
         let num_records = polygons.num_records;
 
         let start = Instant::now();
@@ -240,7 +265,7 @@ impl WhiteboxTool for ClipLidarToPolygon {
         }
 
         if verbose {
-            println!("Performing clip...")
+            context.info("Performing clip...")
         };
 
         let n_points = input.header.number_of_points as usize;
@@ -315,13 +340,9 @@ impl WhiteboxTool for ClipLidarToPolygon {
                             }
                         }
                     }
-                    match tx.send((point_in_poly, point_num)) {
-                        Ok(_) => {} // do nothing
-                        Err(_) => panic!(
-                            "Error performing clipping operation on point num. {}",
-                            point_num
-                        ),
-                    };
+                    if tx.send((point_in_poly, point_num)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -331,14 +352,12 @@ impl WhiteboxTool for ClipLidarToPolygon {
         for i in 0..n_points {
             let data = rx.recv().expect("Error receiving data from thread.");
             if data.0 {
-                output.add_point_record(input.get_record(data.1));
+                output.add_point_record_from(&input, data.1);
             }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -417,27 +436,24 @@ impl WhiteboxTool for ClipLidarToPolygon {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            context.info("Writing output LAS file...");
         }
         if output.header.number_of_points > 0 {
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Complete!")
+                        context.info("Complete!")
                     }
                 }
-                Err(e) => println!("error while writing: {:?}", e),
+                Err(e) => context.error(format!("error while writing: {:?}", e)),
             };
         } else {
             if verbose {
-                println!("Warning: the file {} does not appear to contain any points within the clip polygon. No output file has been created.", output.get_short_filename());
+                context.warning(format!("Warning: the file {} does not appear to contain any points within the clip polygon. No output file has been created.", output.get_short_filename()));
             }
         }
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
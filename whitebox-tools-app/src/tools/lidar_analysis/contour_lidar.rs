@@ -171,8 +171,9 @@ impl WhiteboxTool for ContourLidar {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut max_triangle_edge_length = f64::INFINITY;
@@ -282,11 +283,11 @@ impl WhiteboxTool for ContourLidar {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -339,12 +340,10 @@ impl WhiteboxTool for ContourLidar {
             points.push(Point2D::new(pd.x, pd.y));
             z_values.push(pd.z);
             
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -357,7 +356,7 @@ impl WhiteboxTool for ContourLidar {
             ));
         }
         if verbose {
-            println!("Performing triangulation...");
+            context.info("Performing triangulation...");
         }
         // this is where the heavy-lifting is
         let result = triangulate(&points).expect("No triangulation exists.");
@@ -459,7 +458,7 @@ impl WhiteboxTool for ContourLidar {
                     }
 
                     if num_intersections != 2 && verbose {
-                        println!("Warning: An error occurred during the contouring operation.");
+                        context.warning("Warning: An error occurred during the contouring operation.");
                     }
 
                     if intersect2 && intersect3 {
@@ -485,12 +484,10 @@ impl WhiteboxTool for ContourLidar {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * i as f64 / (result.triangles.len() - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (Loop 1 of 3): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / (result.triangles.len() - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (Loop 1 of 3)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -627,12 +624,10 @@ impl WhiteboxTool for ContourLidar {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / (num_points - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (Loop 2 of 3): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / (num_points - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (Loop 2 of 3)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -756,22 +751,20 @@ impl WhiteboxTool for ContourLidar {
                     fid += 1;
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / (num_points - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (Loop 3 of 3): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / (num_points - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (Loop 3 of 3)", progress)?;
+                old_progress = progress;
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -780,7 +773,7 @@ impl WhiteboxTool for ContourLidar {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(&format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 24/07/2019
-Last Modified: 16/01/2020
+Last Modified: 17/10/2026
 License: MIT
 */
 
@@ -155,8 +155,9 @@ impl WhiteboxTool for FilterLidarClasses {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut include_class_vals = vec![true; 256];
@@ -233,11 +234,11 @@ impl WhiteboxTool for FilterLidarClasses {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -248,61 +249,57 @@ impl WhiteboxTool for FilterLidarClasses {
             output_file = format!("{}{}", working_directory, output_file);
         }
 
-        if verbose {
-            println!("Reading input LAS file...");
-        }
-        let input = match LasFile::new(&input_file, "r") {
-            Ok(lf) => lf,
-            Err(err) => panic!("Error reading file {}: {}", input_file, err),
-        };
-
         let start = Instant::now();
 
+        // the points are read and written in chunks, so that large files can be
+        // processed without holding all of their points in memory
+        let mut reader = match LasReader::new(&input_file) {
+            Ok(r) => r,
+            Err(err) => {
+                return Err(Error::new(
+                    err.kind(),
+                    format!("Error reading file {}: {}", input_file, err),
+                ))
+            }
+        };
+
         if verbose {
-            println!("Performing analysis...");
+            context.info("Performing analysis...");
         }
 
-        let n_points = input.header.number_of_points as usize;
-        let num_points: f64 = (input.header.number_of_points - 1) as f64; // used for progress calculation only
+        let num_points: f64 = (reader.get_header_file().header.number_of_points - 1) as f64; // used for progress calculation only
 
         let mut progress: i32;
         let mut old_progress: i32 = -1;
 
-        // now output the data
-        let mut output = LasFile::initialize_using_file(&output_file, &input);
-        output.header.system_id = "EXTRACTION".to_string();
+        let mut output = LasWriter::new(&output_file, reader.get_header_file())?;
+        output.header_mut().system_id = "EXTRACTION".to_string();
 
-        for i in 0..n_points {
-            if include_class_vals[input[i].classification() as usize] {
-                output.add_point_record(input.get_record(i));
-            }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
+        let mut n = 0usize;
+        for chunk in &mut reader {
+            let input = chunk?;
+            for i in 0..input.header.number_of_points as usize {
+                if include_class_vals[input[i].classification() as usize] {
+                    output.add_point_record_from(&input, i)?;
+                }
+                progress = (100.0_f64 * n as f64 / num_points) as i32;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    context.progress("Progress", progress)?;
                     old_progress = progress;
                 }
+                n += 1;
             }
         }
 
-        let elapsed_time = get_formatted_elapsed_time(start);
-
         if verbose {
-            println!("Writing output LAS file...");
+            context.info("Writing output LAS file...");
         }
-        let _ = match output.write() {
-            Ok(_) => {
-                if verbose {
-                    println!("Complete!")
-                }
-            }
-            Err(e) => println!("error while writing: {:?}", e),
-        };
+        output.close()?;
+
+        let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info("Complete!");
+            context.info(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -113,8 +113,9 @@ impl WhiteboxTool for FindFlightlineEdgePoints {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
 
@@ -153,11 +154,11 @@ impl WhiteboxTool for FindFlightlineEdgePoints {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -169,7 +170,7 @@ impl WhiteboxTool for FindFlightlineEdgePoints {
         }
 
         if verbose {
-            println!("Reading input LAS file...");
+            context.info("Reading input LAS file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -189,41 +190,36 @@ impl WhiteboxTool for FindFlightlineEdgePoints {
         let mut num_output_points = 0;
         for i in 0..input.header.number_of_points as usize {
             if input.get_point_info(i).edge_of_flightline_flag() {
-                output.add_point_record(input.get_record(i));
+                output.add_point_record_from(&input, i);
                 num_output_points += 1;
             }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
-        println!("");
+        context.info("");
         if num_output_points > 0 {
             if verbose {
-                println!("Writing output LAS file...");
+                context.info("Writing output LAS file...");
             }
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Complete!")
+                        context.info("Complete!")
                     }
                 }
-                Err(e) => println!("error while writing: {:?}", e),
+                Err(e) => context.error(format!("error while writing: {:?}", e)),
             };
         } else {
-            println!("No points were flagged as flightline edge features in the LAS files.");
+            context.info("No points were flagged as flightline edge features in the LAS files.");
         }
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
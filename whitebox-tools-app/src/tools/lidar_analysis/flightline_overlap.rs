@@ -124,8 +124,9 @@ impl WhiteboxTool for FlightlineOverlap {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut grid_res: f64 = 1.0;
@@ -228,6 +229,13 @@ impl WhiteboxTool for FlightlineOverlap {
                                 .replace(".zlidar", ".tif")
                                 .replace(".ZLIDAR", ".tif"),
                         )
+                    } else if s.to_lowercase().ends_with(".laz") {
+                        inputs.push(s);
+                        outputs.push(
+                            inputs[inputs.len() - 1]
+                                .replace(".laz", ".tif")
+                                .replace(".LAZ", ".tif"),
+                        )
                     } else if s.to_lowercase().ends_with(".zip") {
                         inputs.push(s);
                         outputs.push(
@@ -250,7 +258,8 @@ impl WhiteboxTool for FlightlineOverlap {
                     .clone()
                     .replace(".las", ".tif")
                     .replace(".LAS", ".tif")
-                    .replace(".zlidar", ".tif");
+                    .replace(".zlidar", ".tif")
+                    .replace(".laz", ".tif");
             }
             outputs.push(output_file);
         }
@@ -259,11 +268,11 @@ impl WhiteboxTool for FlightlineOverlap {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         for k in 0..inputs.len() {
@@ -271,12 +280,12 @@ impl WhiteboxTool for FlightlineOverlap {
             output_file = outputs[k].replace("\"", "").clone();
 
             if verbose && inputs.len() > 1 {
-                println!(
+                context.info(format!(
                     "Gridding {} of {} ({})",
                     k + 1,
                     inputs.len(),
                     input_file.clone()
-                );
+                ));
             }
 
             if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -287,7 +296,7 @@ impl WhiteboxTool for FlightlineOverlap {
             }
 
             if verbose && inputs.len() == 1 {
-                println!("Reading input LAS file...");
+                context.info("Reading input LAS file...");
             }
             let input = match LasFile::new(&input_file, "r") {
                 Ok(lf) => lf,
@@ -297,7 +306,7 @@ impl WhiteboxTool for FlightlineOverlap {
             let start_run = Instant::now();
 
             if verbose && inputs.len() == 1 {
-                println!("Performing analysis...");
+                context.info("Performing analysis...");
             }
 
             // Make sure that the input LAS file have GPS time data?
@@ -413,12 +422,10 @@ impl WhiteboxTool for FlightlineOverlap {
                 };
                 frs.insert(x, y, i);
                 gps_times[i] = gps_time;
-                if verbose {
-                    progress = (100.0_f64 * i as f64 / num_points) as usize;
-                    if progress != old_progress {
-                        println!("Binning points: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * i as f64 / num_points) as usize;
+                if progress != old_progress {
+                    context.progress("Binning points", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -492,12 +499,10 @@ impl WhiteboxTool for FlightlineOverlap {
                         output.set_value(row, col, nodata);
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -513,12 +518,12 @@ impl WhiteboxTool for FlightlineOverlap {
             ));
 
             if verbose {
-                println!("Saving data...")
+                context.info("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
@@ -527,10 +532,7 @@ impl WhiteboxTool for FlightlineOverlap {
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
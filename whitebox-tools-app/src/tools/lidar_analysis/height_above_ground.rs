@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 08/11/2019
-Last Modified: 15/12/2019
+Last Modified: 17/10/2026
 License: MIT
 
 NOTES:
@@ -31,6 +31,10 @@ use std::thread;
 /// will return an error. The `LidarTophatTransform` tool can be used to perform the normalization if a ground
 /// classification is lacking.
 ///
+/// When the `--extra_bytes` flag is specified, the point z-values are left unchanged and the heights are instead
+/// stored in a 'HeightAboveGround' extra-bytes attribute of each point. This allows software that supports LAS
+/// extra bytes to use both the elevation and the normalized height of each point.
+///
 /// # See Also
 /// `LidarTophatTransform`
 pub struct HeightAboveGround {
@@ -68,6 +72,15 @@ impl HeightAboveGround {
             optional: true,
         });
 
+        parameters.push(ToolParameter {
+            name: "Store heights in an extra-bytes attribute?".to_owned(),
+            flags: vec!["--extra_bytes".to_owned()],
+            description: "Optional flag indicating whether heights are stored in a 'HeightAboveGround' extra-bytes attribute, rather than replacing the point z-values.".to_owned(),
+            parameter_type: ParameterType::Boolean,
+            default_value: None,
+            optional: true,
+        });
+
         let sep: String = path::MAIN_SEPARATOR.to_string();
         let p = format!("{}", env::current_dir().unwrap().display());
         let e = format!("{}", env::current_exe().unwrap().display());
@@ -134,10 +147,12 @@ impl WhiteboxTool for HeightAboveGround {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
+        let mut extra_bytes = false;
 
         // read the arguments
         if args.len() == 0 {
@@ -168,6 +183,10 @@ impl WhiteboxTool for HeightAboveGround {
                 } else {
                     args[i + 1].to_string()
                 };
+            } else if flag_val == "-extra_bytes"
+                && (vec.len() == 1 || !vec[1].to_string().to_lowercase().contains("false"))
+            {
+                extra_bytes = true;
             }
         }
 
@@ -175,11 +194,11 @@ impl WhiteboxTool for HeightAboveGround {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -214,12 +233,10 @@ impl WhiteboxTool for HeightAboveGround {
                 tree.add([p1.x, p1.y], i).unwrap();
             }
 
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Reading points: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Reading points", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -239,6 +256,7 @@ impl WhiteboxTool for HeightAboveGround {
             let tree = tree.clone();
             let input = input.clone();
             let tx = tx.clone();
+            let context = context.clone();
             thread::spawn(move || {
                 let mut pd1: PointData;
                 // let mut p2: PointData;
@@ -269,19 +287,41 @@ impl WhiteboxTool for HeightAboveGround {
                         old_progress = (100.0_f64 * *num_solved_pts as f64 / num_points) as i32;
                         *num_solved_pts += 1;
                         progress = (100.0_f64 * *num_solved_pts as f64 / num_points) as i32;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
+                        if progress != old_progress && context.progress("Progress", progress).is_err()
+                        {
+                            break;
                         }
+                    } else if context.is_cancelled() {
+                        break;
                     }
                 }
-                tx.send(residuals).unwrap();
+                // the receiver is gone if the run was cancelled
+                let _ = tx.send(residuals);
             });
         }
 
         let mut output = LasFile::initialize_using_file(&output_file, &input);
+        let hag_attribute = if extra_bytes {
+            let mut definition = ExtraByteDefinition::new(
+                "HeightAboveGround",
+                ExtraByteType::I32,
+                "Height above ground",
+            );
+            definition.scale = Some(input.header.z_scale_factor);
+            Some(output.add_extra_byte_definition(definition))
+        } else {
+            None
+        };
         for n in 0..num_procs {
             let residuals = rx.recv().expect("Error receiving data from thread.");
+            context.check_cancelled()?;
             for (i, z) in residuals {
+                if let Some(attribute) = hag_attribute {
+                    output.add_point_record_from(&input, i);
+                    let point_num = output.header.number_of_points as usize - 1;
+                    output.set_extra_byte_value(point_num, attribute, z);
+                    continue;
+                }
                 let pr = input.get_record(i);
                 let pr2: LidarPointRecord;
                 match pr {
@@ -410,36 +450,31 @@ impl WhiteboxTool for HeightAboveGround {
                         };
                     }
                 }
-                output.add_point_record(pr2);
+                output.add_point_record_with_extra_bytes(pr2, &input, i);
             }
-            if verbose {
-                progress = (100.0_f64 * (n + 1) as f64 / num_procs as f64) as i32;
-                if progress != old_progress {
-                    println!("Creating output: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (n + 1) as f64 / num_procs as f64) as i32;
+            if progress != old_progress {
+                context.progress("Creating output", progress)?;
+                old_progress = progress;
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            context.info("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    context.info("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => context.error(format!("error while writing: {:?}", e)),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 16/07/2017
-Last Modified: 29/02/2020
+Last Modified: 17/10/2026
 License: MIT
 */
 
@@ -22,7 +22,7 @@ use std::path;
 /// with each column corresponding to a field in the table and each row a point value. Fields are separated by
 /// commas in the ASCII formated file. The output point data, each on a separate line, will take the format:
 ///
-/// ```
+/// ```text
 /// X,Y,Z,INTENSITY,CLASS,RETURN,NUM_RETURN,SCAN_ANGLE
 /// ```
 ///
@@ -123,8 +123,9 @@ impl WhiteboxTool for LasToAscii {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_files: String = String::new();
 
         // read the arguments
@@ -157,11 +158,11 @@ impl WhiteboxTool for LasToAscii {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep = std::path::MAIN_SEPARATOR;
@@ -186,25 +187,28 @@ impl WhiteboxTool for LasToAscii {
                     input_file = format!("{}{}", working_directory, input_file);
                 }
 
-                let input: LasFile = match LasFile::new(&input_file, "r") {
-                    Ok(lf) => lf,
-                    Err(_) => {
+                // the points are read in chunks, so that large files can be converted
+                // without holding all of their points in memory
+                let mut reader = match LasReader::new(&input_file) {
+                    Ok(r) => r,
+                    Err(err) => {
                         return Err(Error::new(
-                            ErrorKind::NotFound,
-                            format!("No such file or directory ({})", input_file),
+                            err.kind(),
+                            format!("Error reading file {}: {}", input_file, err),
                         ))
                     }
                 };
 
-                let has_rgb = input.has_rgb();
+                let point_format = reader.get_header_file().header.point_format;
+                let has_rgb = [2u8, 3, 5, 7, 8, 10].contains(&point_format);
                 let mut rgb: ColourData;
-                let has_time = input.has_gps_time();
+                let has_time = point_format != 0 && point_format != 2;
                 let file_extension = get_file_extension(&input_file);
                 let output_file = input_file.replace(&format!(".{}", file_extension), ".csv");
                 let f = File::create(output_file)?;
                 let mut writer = BufWriter::new(f);
 
-                let n_points = input.header.number_of_points as usize;
+                let n_points = reader.get_header_file().header.number_of_points as usize;
 
                 // let x_prec = get_precision(&(input.header.x_scale_factor.to_string()));
                 // let y_prec = get_precision(&(input.header.y_scale_factor.to_string()));
@@ -229,96 +233,105 @@ impl WhiteboxTool for LasToAscii {
                             .as_bytes(),
                     )?;
                 }
-                for k in 0..n_points {
-                    let pd = input[k];
-                    let p = input.get_transformed_coords(k);
-                    let s = if !has_rgb && !has_time {
-                        format!(
-                            "{},{},{},{},{},{},{},{}\n",
-                            p.x,
-                            p.y,
-                            p.z,
-                            pd.intensity,
-                            pd.classification(),
-                            pd.return_number(),
-                            pd.number_of_returns(),
-                            pd.scan_angle
-                        )
-                    } else if !has_rgb && has_time {
-                        format!(
-                            "{},{},{},{},{},{},{},{},{}\n",
-                            p.x,
-                            p.y,
-                            p.z,
-                            pd.intensity,
-                            pd.classification(),
-                            pd.return_number(),
-                            pd.number_of_returns(),
-                            pd.scan_angle,
-                            input.get_gps_time(k).expect("Error retrieving GPS time.")
-                        )
-                    } else if has_rgb && !has_time {
-                        rgb = match input.get_rgb(k) {
-                            Ok(v) => v,
-                            Err(_) => {
-                                return Err(Error::new(
-                                    ErrorKind::NotFound,
-                                    "RGB data not read correctly in LAS file.",
-                                ))
-                            }
-                        };
-                        format!(
-                            "{},{},{},{},{},{},{},{},{},{},{}\n",
-                            p.x,
-                            p.y,
-                            p.z,
-                            pd.intensity,
-                            pd.classification(),
-                            pd.return_number(),
-                            pd.number_of_returns(),
-                            pd.scan_angle,
-                            rgb.red,
-                            rgb.green,
-                            rgb.blue
-                        )
-                    } else {
-                        rgb = match input.get_rgb(k) {
-                            Ok(v) => v,
-                            Err(_) => {
-                                return Err(Error::new(
-                                    ErrorKind::NotFound,
-                                    "RGB data not read correctly in LAS file.",
-                                ))
-                            }
+                let mut n = 0usize;
+                for chunk in &mut reader {
+                    let input = chunk?;
+                    for k in 0..input.header.number_of_points as usize {
+                        let pd = input[k];
+                        let p = input.get_transformed_coords(k);
+                        let s = if !has_rgb && !has_time {
+                            format!(
+                                "{},{},{},{},{},{},{},{}\n",
+                                p.x,
+                                p.y,
+                                p.z,
+                                pd.intensity,
+                                pd.classification(),
+                                pd.return_number(),
+                                pd.number_of_returns(),
+                                pd.scan_angle
+                            )
+                        } else if !has_rgb && has_time {
+                            format!(
+                                "{},{},{},{},{},{},{},{},{}\n",
+                                p.x,
+                                p.y,
+                                p.z,
+                                pd.intensity,
+                                pd.classification(),
+                                pd.return_number(),
+                                pd.number_of_returns(),
+                                pd.scan_angle,
+                                input.get_gps_time(k).ok_or_else(|| Error::new(
+                                    ErrorKind::InvalidData,
+                                    "Error retrieving GPS time.",
+                                ))?
+                            )
+                        } else if has_rgb && !has_time {
+                            rgb = match input.get_rgb(k) {
+                                Ok(v) => v,
+                                Err(_) => {
+                                    return Err(Error::new(
+                                        ErrorKind::NotFound,
+                                        "RGB data not read correctly in LAS file.",
+                                    ))
+                                }
+                            };
+                            format!(
+                                "{},{},{},{},{},{},{},{},{},{},{}\n",
+                                p.x,
+                                p.y,
+                                p.z,
+                                pd.intensity,
+                                pd.classification(),
+                                pd.return_number(),
+                                pd.number_of_returns(),
+                                pd.scan_angle,
+                                rgb.red,
+                                rgb.green,
+                                rgb.blue
+                            )
+                        } else {
+                            rgb = match input.get_rgb(k) {
+                                Ok(v) => v,
+                                Err(_) => {
+                                    return Err(Error::new(
+                                        ErrorKind::NotFound,
+                                        "RGB data not read correctly in LAS file.",
+                                    ))
+                                }
+                            };
+                            format!(
+                                "{},{},{},{},{},{},{},{},{},{},{},{}\n",
+                                p.x,
+                                p.y,
+                                p.z,
+                                pd.intensity,
+                                pd.classification(),
+                                pd.return_number(),
+                                pd.number_of_returns(),
+                                pd.scan_angle,
+                                input.get_gps_time(k).ok_or_else(|| Error::new(
+                                    ErrorKind::InvalidData,
+                                    "Error retrieving GPS time.",
+                                ))?,
+                                rgb.red,
+                                rgb.green,
+                                rgb.blue
+                            )
                         };
-                        format!(
-                            "{},{},{},{},{},{},{},{},{},{},{},{}\n",
-                            p.x,
-                            p.y,
-                            p.z,
-                            pd.intensity,
-                            pd.classification(),
-                            pd.return_number(),
-                            pd.number_of_returns(),
-                            pd.scan_angle,
-                            input.get_gps_time(k).expect("Error retrieving GPS time."),
-                            rgb.red,
-                            rgb.green,
-                            rgb.blue
-                        )
-                    };
-                    writer.write_all(s.as_bytes())?;
+                        writer.write_all(s.as_bytes())?;
 
-                    if verbose {
-                        progress = (100.0_f64 * k as f64 / (n_points - 1) as f64) as usize;
+                        progress = (100.0_f64 * n as f64 / (n_points - 1) as f64) as usize;
                         if progress != old_progress {
                             if num_files > 1 {
-                                println!("Creating file: {} of {}: {}%", i, num_files, progress);
+                                context.progress(&format!("Creating file: {} of {}", i, num_files), progress)?;
                             } else {
-                                println!("Progress: {}%", progress);
+                                context.progress("Progress", progress)?;
                             }
                             old_progress = progress;
                         }
+                        n += 1;
                     }
                 }
                 let _ = writer.flush();
@@ -328,7 +341,7 @@ impl WhiteboxTool for LasToAscii {
 
         if verbose {
             let elapsed_time = get_formatted_elapsed_time(start);
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -124,8 +124,9 @@ impl WhiteboxTool for LasToMultipointShapefile {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
 
         // read the arguments
@@ -158,11 +159,11 @@ impl WhiteboxTool for LasToMultipointShapefile {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let mut progress: usize;
@@ -228,6 +229,7 @@ impl WhiteboxTool for LasToMultipointShapefile {
             let inputs = inputs.clone();
             let tile_list = tile_list.clone();
             let tx = tx.clone();
+            let context = context.clone();
             thread::spawn(move || {
                 let mut tile = 0;
                 while tile < num_tiles {
@@ -248,16 +250,16 @@ impl WhiteboxTool for LasToMultipointShapefile {
                         .replace(".ZLIDAR", ".shp");
 
                     if verbose && num_tiles == 1 {
-                        println!("Reading input LAS file...");
+                        context.info("Reading input LAS file...");
                     }
 
                     let path = path::Path::new(&input_file);
                     let filenm = path.file_stem().unwrap();
                     let short_filename = filenm.to_str().unwrap().to_string();
                     if verbose && num_tiles > 1 {
-                        println!("Processing {}", short_filename);
+                        context.info(format!("Processing {}", short_filename));
                     } else if verbose {
-                        println!("Performing analysis...");
+                        context.info("Performing analysis...");
                     }
                     let ret_val = match LasFile::new(&input_file, "r") {
                         Ok(mut input) => {
@@ -294,13 +296,17 @@ impl WhiteboxTool for LasToMultipointShapefile {
                                 .attributes
                                 .add_record(vec![FieldData::Int(1i32)], false);
 
-                            // output the file
-                            let v = match output.write() {
-                                Ok(_) => (true, String::new()),
-                                Err(err) => (
-                                    false,
-                                    format!("Error reading file {}:\n{:?}", input_file, err),
-                                ),
+                            // output the file, unless the run was cancelled
+                            let v = if context.is_cancelled() {
+                                (true, String::new())
+                            } else {
+                                match output.write() {
+                                    Ok(_) => (true, String::new()),
+                                    Err(err) => (
+                                        false,
+                                        format!("Error reading file {}:\n{:?}", input_file, err),
+                                    ),
+                                }
                             };
 
                             v
@@ -311,7 +317,9 @@ impl WhiteboxTool for LasToMultipointShapefile {
                         ),
                     };
                     // send the data to the main thread to be output
-                    tx.send(ret_val).unwrap();
+                    if tx.send(ret_val).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -320,23 +328,22 @@ impl WhiteboxTool for LasToMultipointShapefile {
             match rx.recv() {
                 Ok(data) => {
                     if !data.0 {
-                        println!("{}", data.1);
+                        context.info(&data.1);
                     }
                 }
-                Err(val) => println!("Error: {:?}", val),
+                Err(val) => context.error(format!("Error: {:?}", val)),
             }
-            if verbose {
-                progress = (100.0_f64 * tile as f64 / (num_tiles - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress ({} of {}): {}%", (tile + 1), num_tiles, progress);
-                    old_progress = progress;
-                }
+            context.check_cancelled()?;
+            progress = (100.0_f64 * tile as f64 / (num_tiles - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress(&format!("Progress ({} of {})", (tile + 1), num_tiles), progress)?;
+                old_progress = progress;
             }
         }
 
         if verbose {
             let elapsed_time = get_formatted_elapsed_time(start);
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -150,8 +150,9 @@ impl WhiteboxTool for LasToZlidar {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_files: String = String::new();
         let mut output_directory: String = String::new();
         let mut compression_method = String::from("brotli");
@@ -211,11 +212,11 @@ impl WhiteboxTool for LasToZlidar {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep = std::path::MAIN_SEPARATOR;
@@ -270,7 +271,7 @@ impl WhiteboxTool for LasToZlidar {
         for a in (0..inputs.len()).rev() {
             if !inputs[a].to_lowercase().ends_with(".las") {
                 if verbose {
-                    println!("WARNGING: Non-LAS input file detected. This file will be excluded from the analysis.");
+                    context.info("WARNGING: Non-LAS input file detected. This file will be excluded from the analysis.");
                 }
                 inputs.remove(a);
             }
@@ -298,6 +299,7 @@ impl WhiteboxTool for LasToZlidar {
             let output_directory = output_directory.clone();
             let compression_method = compression_method.clone();
             let tx = tx.clone();
+            let context = context.clone();
             thread::spawn(move || {
                 // let mut k = 0;
                 let mut progress: usize;
@@ -343,30 +345,34 @@ impl WhiteboxTool for LasToZlidar {
                                 for p in 0..n_points {
                                     let pr = input.get_record(p);
                                     output.add_point_record(pr);
-                                    if verbose && num_files == 1 {
+                                    if num_files == 1 {
                                         progress = (100.0_f64 * (p + 1) as f64
                                             / (n_points - 1) as f64)
                                             as usize;
                                         if progress != old_progress {
-                                            println!("Creating output: {}%", progress);
+                                            if context.progress("Creating output", progress).is_err() {
+                                                break;
+                                            }
                                             old_progress = progress;
                                         }
                                     }
                                 }
-                                let _ = match output.write() {
-                                    Ok(_) => {
-                                        // do nothing
+                                // the output of a cancelled run is not written.
+                                if !context.is_cancelled() {
+                                    if let Err(e) = output.write() {
+                                        context.error(format!("Error while writing: {:?}", e));
                                     }
-                                    Err(e) => println!("Error while writing: {:?}", e),
-                                };
-                                tx.send(short_filename.clone()).unwrap();
+                                }
+                                if tx.send(short_filename.clone()).is_err() {
+                                    break;
+                                }
                             }
                             Err(_) => {
                                 panic!("Error reading file: {}", input_file);
                             }
                         };
-                    } else {
-                        tx.send(format!("Empty file name for tile {}.", k)).unwrap();
+                    } else if tx.send(format!("Empty file name for tile {}.", k)).is_err() {
+                        break;
                     }
                 }
             });
@@ -376,36 +382,35 @@ impl WhiteboxTool for LasToZlidar {
         let mut old_progress: usize = 1;
         for tile in 0..num_files {
             let file_nm = rx.recv().expect("Error receiving data from thread.");
+            context.check_cancelled()?;
             if verbose && !file_nm.contains("Empty") && num_files > 1 && tile < 99 {
-                println!(
+                context.info(format!(
                     "Completed conversion of {} ({} of {})",
                     file_nm,
                     tile + 1,
                     num_files
-                );
+                ));
             } else if verbose && tile == 99 {
-                println!(
+                context.info(format!(
                     "Completed conversion of {} ({} of {})",
                     file_nm,
                     tile + 1,
                     num_files
-                );
-                println!("...");
+                ));
+                context.info("...");
             } else if file_nm.to_lowercase().contains("empty file name") {
-                println!("{}", file_nm);
+                context.info(&file_nm);
             }
-            if verbose {
-                progress = (100.0_f64 * tile as f64 / (num_files - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * tile as f64 / (num_files - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         if verbose {
             let elapsed_time = get_formatted_elapsed_time(start);
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
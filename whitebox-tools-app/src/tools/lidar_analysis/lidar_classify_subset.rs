@@ -177,8 +177,9 @@ impl WhiteboxTool for LidarClassifySubset {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_base_file: String = "".to_string();
         let mut input_subset_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -239,11 +240,11 @@ impl WhiteboxTool for LidarClassifySubset {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -272,7 +273,7 @@ impl WhiteboxTool for LidarClassifySubset {
         }
 
         if verbose {
-            println!("Reading input files...");
+            context.info("Reading input files...");
         }
         let base_lidar = LasFile::new(&input_base_file, "r")?;
         let subset_lidar = LasFile::new(&input_subset_file, "r")?;
@@ -280,7 +281,7 @@ impl WhiteboxTool for LidarClassifySubset {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            context.info("Performing analysis...");
         }
 
         let num_points = (base_lidar.header.number_of_points - 1) as f64;
@@ -289,7 +290,7 @@ impl WhiteboxTool for LidarClassifySubset {
 
         let capacity_per_node = 128;
         let mut kdtree = KdTree::with_capacity(3, capacity_per_node);
-        println!("Creating tree...");
+        context.info("Creating tree...");
         for i in 0..subset_lidar.header.number_of_points as usize {
             // let p: PointData = subset_lidar.get_point_info(i);
             let p = subset_lidar.get_transformed_coords(i);
@@ -305,7 +306,7 @@ impl WhiteboxTool for LidarClassifySubset {
         // now output the data
         let mut output = LasFile::initialize_using_file(&output_file, &base_lidar);
 
-        println!("Performing analysis...");
+        context.info("Performing analysis...");
 
         for i in 0..base_lidar.header.number_of_points as usize {
             let pd: PointData = base_lidar.get_point_info(i);
@@ -582,34 +583,29 @@ impl WhiteboxTool for LidarClassifySubset {
                 output.add_point_record(pr2);
             }
 
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
-        println!("");
+        context.info("");
         if verbose {
-            println!("Writing output LAS file...");
+            context.info("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    context.info("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => context.error(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
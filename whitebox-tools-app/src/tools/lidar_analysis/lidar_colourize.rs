@@ -134,8 +134,9 @@ impl WhiteboxTool for LidarColourize {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_lidar_file: String = "".to_string();
         let mut input_image_file: String = "".to_string();
         let mut output_file: String = "".to_string();
@@ -182,11 +183,11 @@ impl WhiteboxTool for LidarColourize {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -201,7 +202,7 @@ impl WhiteboxTool for LidarColourize {
         }
 
         if verbose {
-            println!("Reading input files...");
+            context.info("Reading input files...");
         }
         let in_lidar = Arc::new(LasFile::new(&input_lidar_file, "r")?);
         let in_image = Arc::new(Raster::new(&input_image_file, "r")?);
@@ -209,7 +210,7 @@ impl WhiteboxTool for LidarColourize {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            context.info("Performing analysis...");
         }
 
         let n_points = in_lidar.header.number_of_points as usize;
@@ -239,9 +240,13 @@ impl WhiteboxTool for LidarColourize {
                     col = in_image.get_column_from_x(p.x);
                     value = in_image.get_value(row, col);
                     if value != nodata {
-                        tx.send((i, value as u32)).unwrap();
+                        if tx.send((i, value as u32)).is_err() {
+                            break;
+                        }
                     } else {
-                        tx.send((i, 0u32)).unwrap();
+                        if tx.send((i, 0u32)).is_err() {
+                            break;
+                        }
                     }
                 }
             });
@@ -251,12 +256,10 @@ impl WhiteboxTool for LidarColourize {
         for i in 0..n_points {
             let data = rx.recv().expect("Error receiving data from thread.");
             colour_values[data.0] = data.1;
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -311,34 +314,29 @@ impl WhiteboxTool for LidarColourize {
                 });
             }
 
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Saving data: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Saving data", progress)?;
+                old_progress = progress;
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
-        println!("");
+        context.info("");
         if verbose {
-            println!("Writing output LAS file...");
+            context.info("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    context.info("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => context.error(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -172,8 +172,9 @@ impl WhiteboxTool for LidarConstructVectorTIN {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut return_type = "all".to_string();
@@ -266,11 +267,11 @@ impl WhiteboxTool for LidarConstructVectorTIN {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let start = Instant::now();
@@ -344,6 +345,13 @@ impl WhiteboxTool for LidarConstructVectorTIN {
                                 .replace(".zlidar", ".tif")
                                 .replace(".ZLIDAR", ".tif"),
                         )
+                    } else if s.to_lowercase().ends_with(".laz") {
+                        inputs.push(s);
+                        outputs.push(
+                            inputs[inputs.len() - 1]
+                                .replace(".laz", ".tif")
+                                .replace(".LAZ", ".tif"),
+                        )
                     } else if s.to_lowercase().ends_with(".zip") {
                         inputs.push(s);
                         outputs.push(
@@ -369,7 +377,8 @@ impl WhiteboxTool for LidarConstructVectorTIN {
                     .clone()
                     .replace(".las", ".tif")
                     .replace(".LAS", ".tif")
-                    .replace(".zlidar", ".tif");
+                    .replace(".zlidar", ".tif")
+                    .replace(".laz", ".tif");
             }
             if !output_file.contains(path::MAIN_SEPARATOR) && !output_file.contains("/") {
                 output_file = format!("{}{}", working_directory, output_file);
@@ -378,7 +387,7 @@ impl WhiteboxTool for LidarConstructVectorTIN {
         }
 
         if verbose {
-            println!("Performing interpolation...");
+            context.info("Performing interpolation...");
         }
 
         let num_tiles = inputs.len();
@@ -394,6 +403,7 @@ impl WhiteboxTool for LidarConstructVectorTIN {
             // copy over the string parameters
             let include_class_vals = include_class_vals.clone();
             let tx = tx.clone();
+            let context = context.clone();
             thread::spawn(move || {
                 let mut tile = 0;
                 while tile < num_tiles {
@@ -412,7 +422,7 @@ impl WhiteboxTool for LidarConstructVectorTIN {
                     let mut z_values = vec![];
 
                     if verbose && inputs.len() == 1 {
-                        println!("Reading input LAS file...");
+                        context.info("Reading input LAS file...");
                     }
 
                     let mut progress: usize;
@@ -445,10 +455,12 @@ impl WhiteboxTool for LidarConstructVectorTIN {
                                 }
                             }
                         }
-                        if verbose && inputs.len() == 1 {
+                        if inputs.len() == 1 {
                             progress = (100.0_f64 * i as f64 / num_points) as usize;
                             if progress != old_progress {
-                                println!("Reading points: {}%", progress);
+                                if context.progress("Reading points", progress).is_err() {
+                                    break;
+                                }
                                 old_progress = progress;
                             }
                         }
@@ -492,7 +504,7 @@ impl WhiteboxTool for LidarConstructVectorTIN {
 
                     // do the triangulation
                     if num_tiles == 1 && verbose {
-                        println!("Performing triangulation...");
+                        context.info("Performing triangulation...");
                     }
                     let result = triangulate(&points).expect("No triangulation exists.");
                     let (mut p1, mut p2, mut p3): (usize, usize, usize);
@@ -559,30 +571,37 @@ impl WhiteboxTool for LidarConstructVectorTIN {
 
                         rec_num += 1i32;
 
-                        if verbose && num_tiles == 1 {
+                        if num_tiles == 1 {
                             progress = (100.0_f64 * i as f64 / (result.triangles.len() - 1) as f64)
                                 as usize;
                             if progress != old_progress {
-                                println!("Creating polygons: {}%", progress);
+                                if context.progress("Creating polygons", progress).is_err() {
+                                    break;
+                                }
                                 old_progress = progress;
                             }
                         }
                     }
 
-                    if verbose && inputs.len() == 1 {
-                        println!("Saving data...")
-                    };
+                    // the output of a cancelled run is not written.
+                    if !context.is_cancelled() {
+                        if verbose && inputs.len() == 1 {
+                            context.info("Saving data...")
+                        };
 
-                    let _ = match output.write() {
-                        Ok(_) => {
-                            if verbose {
-                                println!("Output file written")
+                        let _ = match output.write() {
+                            Ok(_) => {
+                                if verbose {
+                                    context.info("Output file written")
+                                }
                             }
-                        }
-                        Err(e) => panic!("Error reading file {}:\n{:?}", input_file, e),
-                    };
+                            Err(e) => panic!("Error reading file {}:\n{:?}", input_file, e),
+                        };
+                    }
 
-                    tx.send(tile).unwrap();
+                    if tx.send(tile).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -591,8 +610,9 @@ impl WhiteboxTool for LidarConstructVectorTIN {
         let mut old_progress: i32 = -1;
         for tile in 0..inputs.len() {
             let tile_completed = rx.recv().expect("Error receiving data from thread.");
+            context.check_cancelled()?;
             if verbose {
-                println!(
+                context.info(format!(
                     "Finished TINing {} ({} of {})",
                     inputs[tile_completed]
                         .replace("\"", "")
@@ -600,24 +620,19 @@ impl WhiteboxTool for LidarConstructVectorTIN {
                         .replace(".las", ""),
                     tile + 1,
                     inputs.len()
-                );
+                ));
             }
-            if verbose {
-                progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            context.info(&format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -216,8 +216,9 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut grid_res: f64 = 1.0;
@@ -324,11 +325,11 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let start = Instant::now();
@@ -362,6 +363,13 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
                                 .replace(".zlidar", ".tif")
                                 .replace(".ZLIDAR", ".tif"),
                         )
+                    } else if s.to_lowercase().ends_with(".laz") {
+                        inputs.push(s);
+                        outputs.push(
+                            inputs[inputs.len() - 1]
+                                .replace(".laz", ".tif")
+                                .replace(".LAZ", ".tif"),
+                        )
                     } else if s.to_lowercase().ends_with(".zip") {
                         inputs.push(s);
                         outputs.push(
@@ -387,7 +395,8 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
                     .clone()
                     .replace(".las", ".tif")
                     .replace(".LAS", ".tif")
-                    .replace(".zlidar", ".tif");
+                    .replace(".zlidar", ".tif")
+                    .replace(".laz", ".tif");
             }
             if !output_file.contains(path::MAIN_SEPARATOR) && !output_file.contains("/") {
                 output_file = format!("{}{}", working_directory, output_file);
@@ -415,7 +424,7 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
         }
 
         if verbose {
-            println!("Performing interpolation...");
+            context.info("Performing interpolation...");
         }
 
         let num_tiles = inputs.len();
@@ -430,6 +439,7 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
             let bounding_boxes = bounding_boxes.clone();
             let tool_name = self.get_tool_name();
             let tx2 = tx2.clone();
+            let context = context.clone();
             thread::spawn(move || {
                 for tile in (0..num_tiles).filter(|t| t % num_procs == tid) {
                     let start_run = Instant::now();
@@ -452,7 +462,7 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
                     let mut z_values = vec![];
 
                     if verbose && inputs.len() == 1 {
-                        println!("Reading input LiDAR file...");
+                        context.info("Reading input LiDAR file...");
                     }
 
                     let mut progress: i32;
@@ -460,8 +470,11 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
                     let mut epsg_code = 0u16;
                     for m in 0..inputs.len() {
                         if bounding_boxes[m].overlaps(bb) {
-                            match LasFile::new(&inputs[m].replace("\"", "").clone(), "r") {
+                            match LasFile::read_within(&inputs[m].replace("\"", ""), &bb, None) {
                                 Ok(input) => {
+                                    if input.header.number_of_points == 0 {
+                                        continue;
+                                    }
                                     let n_points = input.header.number_of_points as usize;
                                     let num_points: f64 =
                                         (input.header.number_of_points - 1) as f64; // used for progress calculation only
@@ -483,11 +496,13 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
                                                 }
                                             }
                                         }
-                                        if verbose && inputs.len() == 1 {
+                                        if inputs.len() == 1 {
                                             progress =
                                                 (100.0_f64 * (i + 1) as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                if context.progress("Reading points", progress).is_err() {
+                                                    break;
+                                                }
                                                 old_progress = progress;
                                             }
                                         }
@@ -499,11 +514,13 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
                     }
 
                     if points.len() < 3 {
-                        println!(
+                        context.warning(format!(
                             "Warning: No eligible points found in {}",
                             inputs[tile].clone()
-                        );
-                        tx2.send(tile).unwrap();
+                        ));
+                        if tx2.send(tile).is_err() {
+                            break;
+                        }
                     } else {
                         let num_points = points.len();
                         let mut remove_pt = vec![false; num_points];
@@ -529,10 +546,12 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
                                     }
                                 }
                             }
-                            if verbose && inputs.len() == 1 {
+                            if inputs.len() == 1 {
                                 progress = (100.0_f64 * (i + 1) as f64 / num_points as f64) as i32;
                                 if progress != old_progress {
-                                    println!("Filtering points: {}%", progress);
+                                    if context.progress("Filtering points", progress).is_err() {
+                                        break;
+                                    }
                                     old_progress = progress;
                                 }
                             }
@@ -589,7 +608,7 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
 
                         // do the triangulation
                         if num_tiles == 1 && verbose {
-                            println!("Performing triangulation...");
+                            context.info("Performing triangulation...");
                         }
                         let result = triangulate(&points2).expect("No triangulation exists.");
                         let num_triangles = result.triangles.len() / 3;
@@ -661,12 +680,14 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
                                     }
                                 }
 
-                                if verbose && num_tiles == 1 {
+                                if num_tiles == 1 {
                                     progress = (100.0_f64 * triangle as f64
                                         / (num_triangles - 1) as f64)
                                         as i32;
                                     if progress != old_progress {
-                                        println!("Progress: {}%", progress);
+                                        if context.progress("Progress", progress).is_err() {
+                                            break;
+                                        }
                                         old_progress = progress;
                                     }
                                 }
@@ -688,13 +709,18 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
                             elapsed_time_run
                         ));
 
-                        if verbose && inputs.len() == 1 {
-                            println!("Saving data...")
-                        };
+                        // the output of a cancelled run is not written.
+                        if !context.is_cancelled() {
+                            if verbose && inputs.len() == 1 {
+                                context.info("Saving data...")
+                            };
 
-                        let _ = output.write().expect("Error writing file.");
+                            let _ = output.write().expect("Error writing file.");
+                        }
 
-                        tx2.send(tile).unwrap();
+                        if tx2.send(tile).is_err() {
+                            break;
+                        }
                     }
                 }
             });
@@ -704,9 +730,10 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
         let mut old_progress: i32 = -1;
         for tile in 0..inputs.len() {
             let tile_completed = rx2.recv().unwrap();
+            context.check_cancelled()?;
             if verbose {
                 if tile <= 98 {
-                    println!(
+                    context.info(format!(
                         "Finished {} ({} of {})",
                         inputs[tile_completed]
                             .replace("\"", "")
@@ -714,9 +741,9 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
                             .replace(".las", ""),
                         tile + 1,
                         inputs.len()
-                    );
+                    ));
                 } else if tile == 99 {
-                    println!(
+                    context.info(format!(
                         "Finished {} ({} of {})",
                         inputs[tile_completed]
                             .replace("\"", "")
@@ -724,24 +751,21 @@ impl WhiteboxTool for LidarDigitalSurfaceModel {
                             .replace(".las", ""),
                         tile + 1,
                         inputs.len()
-                    );
-                    println!("...");
-                }
-                progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
+                    ));
+                    context.info("...");
                 }
             }
+            progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
+            }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
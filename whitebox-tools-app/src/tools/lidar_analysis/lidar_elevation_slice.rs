@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 02/06/2017
-Last Modified: 18/10/2019
+Last Modified: 17/10/2026
 License: MIT
 */
 
@@ -170,8 +170,9 @@ impl WhiteboxTool for LidarElevationSlice {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut minz = -f64::INFINITY;
@@ -260,9 +261,9 @@ impl WhiteboxTool for LidarElevationSlice {
         }
 
         if verbose {
-            println!("***********************************");
-            println!("* Welcome to lidar_elevation_slice *");
-            println!("************************************");
+            context.info("***********************************");
+            context.info("* Welcome to lidar_elevation_slice *");
+            context.info("************************************");
         }
 
         if in_class_value > 31 || out_class_value > 31 {
@@ -280,208 +281,204 @@ impl WhiteboxTool for LidarElevationSlice {
             output_file = format!("{}{}", working_directory, output_file);
         }
 
-        if verbose {
-            println!("Reading input LAS file...");
-        }
-        let input: LasFile = match LasFile::new(&input_file, "r") {
-            Ok(lf) => lf,
-            Err(_) => {
+        // the points are read and written in chunks, so that large files can be
+        // processed without holding all of their points in memory
+        let mut reader = match LasReader::new(&input_file) {
+            Ok(r) => r,
+            Err(err) => {
                 return Err(Error::new(
-                    ErrorKind::NotFound,
-                    format!("No such file or directory ({})", input_file),
+                    err.kind(),
+                    format!("Error reading file {}: {}", input_file, err),
                 ))
             }
         };
-        let mut output = LasFile::initialize_using_file(&output_file, &input);
-        output.header.system_id = "EXTRACTION".to_string();
+        let mut output = LasWriter::new(&output_file, reader.get_header_file())?;
+        output.header_mut().system_id = "EXTRACTION".to_string();
 
         if verbose {
-            println!("Performing analysis...");
+            context.info("Performing analysis...");
         }
         let mut z: f64;
         let mut progress: i32;
         let mut old_progress: i32 = -1;
         let mut num_points_filtered: i64 = 0;
-        let num_points: f64 = (input.header.number_of_points - 1) as f64;
+        let num_points: f64 = (reader.get_header_file().header.number_of_points - 1) as f64;
+        let mut n = 0usize;
 
-        if filter {
-            for i in 0..input.header.number_of_points as usize {
-                // z = input.get_point_info(i).z;
-                z = input.get_transformed_coords(i).z;
-                if z >= minz && z <= maxz {
-                    output.add_point_record(input.get_record(i));
-                    num_points_filtered += 1;
-                }
-                if verbose {
-                    progress = (100.0_f64 * i as f64 / num_points) as i32;
+        for chunk in &mut reader {
+            let input = chunk?;
+            if filter {
+                for i in 0..input.header.number_of_points as usize {
+                    // z = input.get_point_info(i).z;
+                    z = input.get_transformed_coords(i).z;
+                    if z >= minz && z <= maxz {
+                        output.add_point_record_from(&input, i)?;
+                        num_points_filtered += 1;
+                    }
+                    progress = (100.0_f64 * n as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Progress: {}%", progress);
+                        context.progress("Progress", progress)?;
                         old_progress = progress;
                     }
+                    n += 1;
                 }
-            }
-        } else {
-            for i in 0..input.header.number_of_points as usize {
-                let mut class_val = out_class_value; // outside elevation slice
-                // z = input.get_point_info(i).z;
-                z = input.get_transformed_coords(i).z;
-                if z >= minz && z <= maxz {
-                    class_val = in_class_value; // inside elevation slice
-                }
-                let pr = input.get_record(i);
-                let pr2: LidarPointRecord;
-                match pr {
-                    LidarPointRecord::PointRecord0 { mut point_data } => {
-                        point_data.set_classification(class_val);
-                        pr2 = LidarPointRecord::PointRecord0 {
-                            point_data: point_data,
-                        };
-                    }
-                    LidarPointRecord::PointRecord1 {
-                        mut point_data,
-                        gps_data,
-                    } => {
-                        point_data.set_classification(class_val);
-                        pr2 = LidarPointRecord::PointRecord1 {
-                            point_data: point_data,
-                            gps_data: gps_data,
-                        };
-                    }
-                    LidarPointRecord::PointRecord2 {
-                        mut point_data,
-                        colour_data,
-                    } => {
-                        point_data.set_classification(class_val);
-                        pr2 = LidarPointRecord::PointRecord2 {
-                            point_data: point_data,
-                            colour_data: colour_data,
-                        };
-                    }
-                    LidarPointRecord::PointRecord3 {
-                        mut point_data,
-                        gps_data,
-                        colour_data,
-                    } => {
-                        point_data.set_classification(class_val);
-                        pr2 = LidarPointRecord::PointRecord3 {
-                            point_data: point_data,
-                            gps_data: gps_data,
-                            colour_data: colour_data,
-                        };
-                    }
-                    LidarPointRecord::PointRecord4 {
-                        mut point_data,
-                        gps_data,
-                        wave_packet,
-                    } => {
-                        point_data.set_classification(class_val);
-                        pr2 = LidarPointRecord::PointRecord4 {
-                            point_data: point_data,
-                            gps_data: gps_data,
-                            wave_packet: wave_packet,
-                        };
-                    }
-                    LidarPointRecord::PointRecord5 {
-                        mut point_data,
-                        gps_data,
-                        colour_data,
-                        wave_packet,
-                    } => {
-                        point_data.set_classification(class_val);
-                        pr2 = LidarPointRecord::PointRecord5 {
-                            point_data: point_data,
-                            gps_data: gps_data,
-                            colour_data: colour_data,
-                            wave_packet: wave_packet,
-                        };
-                    }
-                    LidarPointRecord::PointRecord6 {
-                        mut point_data,
-                        gps_data,
-                    } => {
-                        point_data.set_classification(class_val);
-                        pr2 = LidarPointRecord::PointRecord6 {
-                            point_data: point_data,
-                            gps_data: gps_data,
-                        };
-                    }
-                    LidarPointRecord::PointRecord7 {
-                        mut point_data,
-                        gps_data,
-                        colour_data,
-                    } => {
-                        point_data.set_classification(class_val);
-                        pr2 = LidarPointRecord::PointRecord7 {
-                            point_data: point_data,
-                            gps_data: gps_data,
-                            colour_data: colour_data,
-                        };
-                    }
-                    LidarPointRecord::PointRecord8 {
-                        mut point_data,
-                        gps_data,
-                        colour_data,
-                    } => {
-                        point_data.set_classification(class_val);
-                        pr2 = LidarPointRecord::PointRecord8 {
-                            point_data: point_data,
-                            gps_data: gps_data,
-                            colour_data: colour_data,
-                        };
-                    }
-                    LidarPointRecord::PointRecord9 {
-                        mut point_data,
-                        gps_data,
-                        wave_packet,
-                    } => {
-                        point_data.set_classification(class_val);
-                        pr2 = LidarPointRecord::PointRecord9 {
-                            point_data: point_data,
-                            gps_data: gps_data,
-                            wave_packet: wave_packet,
-                        };
-                    }
-                    LidarPointRecord::PointRecord10 {
-                        mut point_data,
-                        gps_data,
-                        colour_data,
-                        wave_packet,
-                    } => {
-                        point_data.set_classification(class_val);
-                        pr2 = LidarPointRecord::PointRecord10 {
-                            point_data: point_data,
-                            gps_data: gps_data,
-                            colour_data: colour_data,
-                            wave_packet: wave_packet,
-                        };
+            } else {
+                for i in 0..input.header.number_of_points as usize {
+                    let mut class_val = out_class_value; // outside elevation slice
+                    // z = input.get_point_info(i).z;
+                    z = input.get_transformed_coords(i).z;
+                    if z >= minz && z <= maxz {
+                        class_val = in_class_value; // inside elevation slice
                     }
-                }
-                output.add_point_record(pr2);
-                if verbose {
-                    progress = (100.0_f64 * i as f64 / num_points) as i32;
+                    let pr = input.get_record(i);
+                    let pr2 = match pr {
+                        LidarPointRecord::PointRecord0 { mut point_data } => {
+                            point_data.set_classification(class_val);
+                            LidarPointRecord::PointRecord0 { point_data }
+                        }
+                        LidarPointRecord::PointRecord1 {
+                            mut point_data,
+                            gps_data,
+                        } => {
+                            point_data.set_classification(class_val);
+                            LidarPointRecord::PointRecord1 {
+                                point_data,
+                                gps_data,
+                            }
+                        }
+                        LidarPointRecord::PointRecord2 {
+                            mut point_data,
+                            colour_data,
+                        } => {
+                            point_data.set_classification(class_val);
+                            LidarPointRecord::PointRecord2 {
+                                point_data,
+                                colour_data,
+                            }
+                        }
+                        LidarPointRecord::PointRecord3 {
+                            mut point_data,
+                            gps_data,
+                            colour_data,
+                        } => {
+                            point_data.set_classification(class_val);
+                            LidarPointRecord::PointRecord3 {
+                                point_data,
+                                gps_data,
+                                colour_data,
+                            }
+                        }
+                        LidarPointRecord::PointRecord4 {
+                            mut point_data,
+                            gps_data,
+                            wave_packet,
+                        } => {
+                            point_data.set_classification(class_val);
+                            LidarPointRecord::PointRecord4 {
+                                point_data,
+                                gps_data,
+                                wave_packet,
+                            }
+                        }
+                        LidarPointRecord::PointRecord5 {
+                            mut point_data,
+                            gps_data,
+                            colour_data,
+                            wave_packet,
+                        } => {
+                            point_data.set_classification(class_val);
+                            LidarPointRecord::PointRecord5 {
+                                point_data,
+                                gps_data,
+                                colour_data,
+                                wave_packet,
+                            }
+                        }
+                        LidarPointRecord::PointRecord6 {
+                            mut point_data,
+                            gps_data,
+                        } => {
+                            point_data.set_classification(class_val);
+                            LidarPointRecord::PointRecord6 {
+                                point_data,
+                                gps_data,
+                            }
+                        }
+                        LidarPointRecord::PointRecord7 {
+                            mut point_data,
+                            gps_data,
+                            colour_data,
+                        } => {
+                            point_data.set_classification(class_val);
+                            LidarPointRecord::PointRecord7 {
+                                point_data,
+                                gps_data,
+                                colour_data,
+                            }
+                        }
+                        LidarPointRecord::PointRecord8 {
+                            mut point_data,
+                            gps_data,
+                            colour_data,
+                        } => {
+                            point_data.set_classification(class_val);
+                            LidarPointRecord::PointRecord8 {
+                                point_data,
+                                gps_data,
+                                colour_data,
+                            }
+                        }
+                        LidarPointRecord::PointRecord9 {
+                            mut point_data,
+                            gps_data,
+                            wave_packet,
+                        } => {
+                            point_data.set_classification(class_val);
+                            LidarPointRecord::PointRecord9 {
+                                point_data,
+                                gps_data,
+                                wave_packet,
+                            }
+                        }
+                        LidarPointRecord::PointRecord10 {
+                            mut point_data,
+                            gps_data,
+                            colour_data,
+                            wave_packet,
+                        } => {
+                            point_data.set_classification(class_val);
+                            LidarPointRecord::PointRecord10 {
+                                point_data,
+                                gps_data,
+                                colour_data,
+                                wave_packet,
+                            }
+                        }
+                    };
+                    output.add_point_record_with_extra_bytes(pr2, &input, i)?;
+                    progress = (100.0_f64 * n as f64 / num_points) as i32;
                     if progress != old_progress {
-                        println!("Saving data: {}%", progress);
+                        context.progress("Saving data", progress)?;
                         old_progress = progress;
                     }
+                    n += 1;
                 }
             }
+        }
+        if !filter {
             num_points_filtered = 1;
         }
 
+        if verbose {
+            context.info("Writing output LAS file...");
+        }
+        output.close()?;
         if num_points_filtered > 0 {
             if verbose {
-                println!("Writing output LAS file...");
+                context.info("Complete!")
             }
-            let _ = match output.write() {
-                Ok(_) => {
-                    if verbose {
-                        println!("Complete!")
-                    }
-                }
-                Err(e) => println!("error while writing: {:?}", e),
-            };
         } else {
-            println!("No points were contained in the elevation slice.");
+            context.info("No points were contained in the elevation slice.");
         }
 
         Ok(())
@@ -208,8 +208,9 @@ impl WhiteboxTool for LidarGroundPointFilter {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut search_radius: f64 = -1.0;
@@ -319,11 +320,11 @@ impl WhiteboxTool for LidarGroundPointFilter {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -335,7 +336,7 @@ impl WhiteboxTool for LidarGroundPointFilter {
         }
 
         if verbose {
-            println!("Reading input LAS file...");
+            context.info("Reading input LAS file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -345,11 +346,11 @@ impl WhiteboxTool for LidarGroundPointFilter {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            context.info("Performing analysis...");
         }
 
         if slope_threshold > 88f64 {
-            eprintln!("Warning: the slope threshold cannot be greater than 88 degrees.");
+            context.warning("Warning: the slope threshold cannot be greater than 88 degrees.");
             slope_threshold = 88f64;
         }
 
@@ -375,12 +376,10 @@ impl WhiteboxTool for LidarGroundPointFilter {
                     residuals[i] = p.z;
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Binning points: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Binning points", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -417,9 +416,9 @@ impl WhiteboxTool for LidarGroundPointFilter {
                                     min_z = z_n;
                                 }
                             }
-                            tx.send((point_num, min_z)).unwrap();
+                            let _ = tx.send((point_num, min_z));
                         } else {
-                            tx.send((point_num, f64::MAX)).unwrap();
+                            let _ = tx.send((point_num, f64::MAX));
                         }
                     }
                 });
@@ -428,12 +427,10 @@ impl WhiteboxTool for LidarGroundPointFilter {
             for i in 0..n_points {
                 let data = rx.recv().expect("Error receiving data from thread.");
                 neighbourhood_min[data.0] = data.1;
-                if verbose {
-                    progress = (100.0_f64 * i as f64 / num_points) as i32;
-                    if progress != old_progress {
-                        println!("Erosion: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * i as f64 / num_points) as i32;
+                if progress != old_progress {
+                    context.progress("Erosion", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -465,9 +462,9 @@ impl WhiteboxTool for LidarGroundPointFilter {
                                     max_z = z_n;
                                 }
                             }
-                            tx.send((point_num, max_z)).unwrap();
+                            let _ = tx.send((point_num, max_z));
                         } else {
-                            tx.send((point_num, f64::MIN)).unwrap();
+                            let _ = tx.send((point_num, f64::MIN));
                         }
                     }
                 });
@@ -479,12 +476,10 @@ impl WhiteboxTool for LidarGroundPointFilter {
                     let z = input.get_transformed_coords(data.0).z;
                     residuals[data.0] = z - data.1;
                 }
-                if verbose {
-                    progress = (100.0_f64 * i as f64 / num_points) as i32;
-                    if progress != old_progress {
-                        println!("Dilation: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * i as f64 / num_points) as i32;
+                if progress != old_progress {
+                    context.progress("Dilation", progress)?;
+                    old_progress = progress;
                 }
             }
         }
@@ -528,12 +523,18 @@ impl WhiteboxTool for LidarGroundPointFilter {
                             }
                         }
                         if max_slope > slope_threshold {
-                            tx.send((point_num, true)).unwrap();
+                            if tx.send((point_num, true)).is_err() {
+                                break;
+                            }
                         } else {
-                            tx.send((point_num, false)).unwrap();
+                            if tx.send((point_num, false)).is_err() {
+                                break;
+                            }
                         }
                     } else {
-                        tx.send((point_num, true)).unwrap();
+                        if tx.send((point_num, true)).is_err() {
+                            break;
+                        }
                     }
                 }
             });
@@ -542,12 +543,10 @@ impl WhiteboxTool for LidarGroundPointFilter {
         for i in 0..n_points {
             let data = rx.recv().expect("Error receiving data from thread.");
             is_off_terrain[data.0] = data.1;
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Slope-based Filter: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Slope-based Filter", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -565,16 +564,14 @@ impl WhiteboxTool for LidarGroundPointFilter {
 
             for point_num in 0..n_points {
                 if !is_off_terrain[point_num] {
-                    output.add_point_record(input.get_record(point_num));
+                    output.add_point_record_from(&input, point_num);
                 } else {
                     num_points_filtered += 1;
                 }
-                if verbose {
-                    progress = (100.0_f64 * point_num as f64 / num_points) as i32;
-                    if progress != old_progress {
-                        println!("Saving data: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * point_num as f64 / num_points) as i32;
+                if progress != old_progress {
+                    context.progress("Saving data", progress)?;
+                    old_progress = progress;
                 }
             }
         } else {
@@ -759,44 +756,39 @@ impl WhiteboxTool for LidarGroundPointFilter {
                             };
                         }
                     }
-                    output.add_point_record(pr2);
+                    output.add_point_record_with_extra_bytes(pr2, &input, point_num);
                 } else {
                     // Keep the classes of classified noise unaltered
-                    output.add_point_record(input.get_record(point_num));
+                    output.add_point_record_from(&input, point_num);
                 }
-                if verbose {
-                    progress = (100.0_f64 * point_num as f64 / num_points) as i32;
-                    if progress != old_progress {
-                        println!("Saving data: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * point_num as f64 / num_points) as i32;
+                if progress != old_progress {
+                    context.progress("Saving data", progress)?;
+                    old_progress = progress;
                 }
             }
             num_points_filtered = 1; // so it passes the saving
         }
 
         if num_points_filtered == 0 {
-            println!("Warning: No points were filtered from the point cloud.");
+            context.warning("Warning: No points were filtered from the point cloud.");
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            context.info("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    context.info("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => context.error(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
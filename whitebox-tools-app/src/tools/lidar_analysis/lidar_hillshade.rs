@@ -144,8 +144,9 @@ impl WhiteboxTool for LidarHillshade {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut search_radius: f64 = -1.0;
@@ -224,11 +225,11 @@ impl WhiteboxTool for LidarHillshade {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         azimuth = (azimuth - 90f64).to_radians();
@@ -245,7 +246,7 @@ impl WhiteboxTool for LidarHillshade {
         }
 
         if verbose {
-            println!("Reading input LAS file...");
+            context.info("Reading input LAS file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -255,7 +256,7 @@ impl WhiteboxTool for LidarHillshade {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            context.info("Performing analysis...");
         }
 
         let n_points = input.header.number_of_points as usize;
@@ -268,12 +269,10 @@ impl WhiteboxTool for LidarHillshade {
         for i in 0..n_points {
             let p = input.get_transformed_coords(i);
             frs.insert(p.x, p.y, p.z, i);
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Binning points: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Binning points", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -298,7 +297,9 @@ impl WhiteboxTool for LidarHillshade {
                         let p2 = input.get_transformed_coords(index_n);
                         data.push(Vector3::new(p2.x, p2.y, p2.z));
                     }
-                    tx.send((i, plane_from_points(&data))).unwrap();
+                    if tx.send((i, plane_from_points(&data))).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -306,12 +307,10 @@ impl WhiteboxTool for LidarHillshade {
         for i in 0..n_points {
             let data = rx.recv().expect("Error receiving data from thread.");
             normal_values[data.0] = data.1;
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Calculating point normals: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Calculating point normals", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -359,34 +358,29 @@ impl WhiteboxTool for LidarHillshade {
                 colour_data: rgb,
             };
             output.add_point_record(lpr);
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Saving data: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Saving data", progress)?;
+                old_progress = progress;
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
-        println!("");
+        context.info("");
         if verbose {
-            println!("Writing output LAS file...");
+            context.info("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    context.info("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => context.error(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -157,8 +157,9 @@ impl WhiteboxTool for LidarHistogram {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut parameter = "elevation".to_string();
@@ -217,11 +218,11 @@ impl WhiteboxTool for LidarHistogram {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -239,7 +240,7 @@ impl WhiteboxTool for LidarHistogram {
         }
 
         if verbose {
-            println!("Reading input LAS file...");
+            context.info("Reading input LAS file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -257,7 +258,7 @@ impl WhiteboxTool for LidarHistogram {
             "class" => 3,
             "time" => 4,
             _ => {
-                println!("Warning: unrecognized parameter; elevation will be used");
+                context.warning("Warning: unrecognized parameter; elevation will be used");
                 0 // elevation
             }
         };
@@ -287,12 +288,10 @@ impl WhiteboxTool for LidarHistogram {
             if z > max {
                 max = z;
             }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Calculating min and max: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Calculating min and max", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -315,12 +314,10 @@ impl WhiteboxTool for LidarHistogram {
                 };
                 bin = ((z - min) / bin_width).floor() as isize;
                 freq_data[bin as usize] += 1;
-                if verbose {
-                    progress = (100.0_f64 * i as f64 / num_points) as i32;
-                    if progress != old_progress {
-                        println!("Clipping the tails: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * i as f64 / num_points) as i32;
+                if progress != old_progress {
+                    context.progress("Clipping the tails", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -381,12 +378,10 @@ impl WhiteboxTool for LidarHistogram {
                 if bin >= 0 && bin < num_bins as isize {
                     freq_data[bin as usize] += 1;
                 }
-                if verbose {
-                    progress = (100.0_f64 * i as f64 / num_points) as i32;
-                    if progress != old_progress {
-                        println!("Binning the data: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * i as f64 / num_points) as i32;
+                if progress != old_progress {
+                    context.progress("Binning the data", progress)?;
+                    old_progress = progress;
                 }
             }
         } else {
@@ -402,12 +397,10 @@ impl WhiteboxTool for LidarHistogram {
                 if bin >= 0 && bin < num_bins as isize {
                     freq_data[bin as usize] += 1;
                 }
-                if verbose {
-                    progress = (100.0_f64 * i as f64 / num_points) as i32;
-                    if progress != old_progress {
-                        println!("Binning the data: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * i as f64 / num_points) as i32;
+                if progress != old_progress {
+                    context.progress("Binning the data", progress)?;
+                    old_progress = progress;
                 }
             }
         }
@@ -475,10 +468,10 @@ impl WhiteboxTool for LidarHistogram {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
+            context.info(format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         if verbose {
@@ -506,7 +499,7 @@ impl WhiteboxTool for LidarHistogram {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                context.info(format!("Complete! Please see {} for output.", output_file));
             }
         }
 
@@ -246,8 +246,9 @@ impl WhiteboxTool for LidarIdwInterpolation {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut interp_parameter = "elevation".to_string();
@@ -431,11 +432,11 @@ impl WhiteboxTool for LidarIdwInterpolation {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let start = Instant::now();
@@ -469,6 +470,13 @@ impl WhiteboxTool for LidarIdwInterpolation {
                                 .replace(".zlidar", ".tif")
                                 .replace(".ZLIDAR", ".tif"),
                         )
+                    } else if s.to_lowercase().ends_with(".laz") {
+                        inputs.push(s);
+                        outputs.push(
+                            inputs[inputs.len() - 1]
+                                .replace(".laz", ".tif")
+                                .replace(".LAZ", ".tif"),
+                        )
                     } else if s.to_lowercase().ends_with(".zip") {
                         inputs.push(s);
                         outputs.push(
@@ -494,7 +502,8 @@ impl WhiteboxTool for LidarIdwInterpolation {
                     .clone()
                     .replace(".las", ".tif")
                     .replace(".LAS", ".tif")
-                    .replace(".zlidar", ".tif");
+                    .replace(".zlidar", ".tif")
+                    .replace(".laz", ".tif");
             }
             if !output_file.contains(path::MAIN_SEPARATOR) && !output_file.contains("/") {
                 output_file = format!("{}{}", working_directory, output_file);
@@ -519,7 +528,7 @@ impl WhiteboxTool for LidarIdwInterpolation {
         }
 
         if verbose {
-            println!("Performing interpolation...");
+            context.info("Performing interpolation...");
         }
 
         let num_tiles = inputs.len();
@@ -547,6 +556,7 @@ impl WhiteboxTool for LidarIdwInterpolation {
             let exclude_cls_str = exclude_cls_str.clone();
             let include_class_vals = include_class_vals.clone();
             let tx2 = tx2.clone();
+            let context = context.clone();
             thread::spawn(move || {
                 let mut tile = 0;
                 while tile < num_tiles {
@@ -574,7 +584,7 @@ impl WhiteboxTool for LidarIdwInterpolation {
                         FixedRadiusSearch2D::new(search_radius, DistanceMetric::Euclidean);
 
                     if verbose && inputs.len() == 1 {
-                        println!("Reading input LAS file...");
+                        context.info("Reading input LAS file...");
                     }
 
                     let mut progress: i32;
@@ -582,15 +592,21 @@ impl WhiteboxTool for LidarIdwInterpolation {
 
                     for m in 0..inputs.len() {
                         if bounding_boxes[m].overlaps(bb) {
-                            let input =
-                                match LasFile::new(&inputs[m].replace("\"", "").clone(), "r") {
-                                    Ok(lf) => lf,
-                                    Err(err) => panic!(
-                                        "Error reading file {}: {}",
-                                        inputs[m].replace("\"", ""),
-                                        err
-                                    ),
-                                };
+                            let input = match LasFile::read_within(
+                                &inputs[m].replace("\"", ""),
+                                &bb,
+                                None,
+                            ) {
+                                Ok(lf) => lf,
+                                Err(err) => panic!(
+                                    "Error reading file {}: {}",
+                                    inputs[m].replace("\"", ""),
+                                    err
+                                ),
+                            };
+                            if input.header.number_of_points == 0 {
+                                continue;
+                            }
 
                             let n_points = input.header.number_of_points as usize;
                             let num_points: f64 = (input.header.number_of_points - 1) as f64; // used for progress calculation only
@@ -617,10 +633,12 @@ impl WhiteboxTool for LidarIdwInterpolation {
                                             }
                                         }
 
-                                        if verbose && inputs.len() == 1 {
+                                        if inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                if context.progress("Binning points", progress).is_err() {
+                                                    break;
+                                                }
                                                 old_progress = progress;
                                             }
                                         }
@@ -645,10 +663,12 @@ impl WhiteboxTool for LidarIdwInterpolation {
                                                 }
                                             }
                                         }
-                                        if verbose && inputs.len() == 1 {
+                                        if inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                if context.progress("Binning points", progress).is_err() {
+                                                    break;
+                                                }
                                                 old_progress = progress;
                                             }
                                         }
@@ -673,10 +693,12 @@ impl WhiteboxTool for LidarIdwInterpolation {
                                                 }
                                             }
                                         }
-                                        if verbose && inputs.len() == 1 {
+                                        if inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                if context.progress("Binning points", progress).is_err() {
+                                                    break;
+                                                }
                                                 old_progress = progress;
                                             }
                                         }
@@ -705,10 +727,12 @@ impl WhiteboxTool for LidarIdwInterpolation {
                                                 }
                                             }
                                         }
-                                        if verbose && inputs.len() == 1 {
+                                        if inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                if context.progress("Binning points", progress).is_err() {
+                                                    break;
+                                                }
                                                 old_progress = progress;
                                             }
                                         }
@@ -737,10 +761,12 @@ impl WhiteboxTool for LidarIdwInterpolation {
                                                 }
                                             }
                                         }
-                                        if verbose && inputs.len() == 1 {
+                                        if inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                if context.progress("Reading points", progress).is_err() {
+                                                    break;
+                                                }
                                                 old_progress = progress;
                                             }
                                         }
@@ -769,10 +795,12 @@ impl WhiteboxTool for LidarIdwInterpolation {
                                                 }
                                             }
                                         }
-                                        if verbose && inputs.len() == 1 {
+                                        if inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                if context.progress("Reading points", progress).is_err() {
+                                                    break;
+                                                }
                                                 old_progress = progress;
                                             }
                                         }
@@ -780,7 +808,7 @@ impl WhiteboxTool for LidarIdwInterpolation {
                                 }
                                 "rgb" => {
                                     if !input.has_rgb() {
-                                        println!("Error: The input LAS file does not contain RGB colour data. The interpolation will not proceed.");
+                                        context.error("Error: The input LAS file does not contain RGB colour data. The interpolation will not proceed.");
                                         break;
                                     }
                                     let mut clr: ColourData;
@@ -814,10 +842,12 @@ impl WhiteboxTool for LidarIdwInterpolation {
                                                 }
                                             }
                                         }
-                                        if verbose && inputs.len() == 1 {
+                                        if inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                if context.progress("Reading points", progress).is_err() {
+                                                    break;
+                                                }
                                                 old_progress = progress;
                                             }
                                         }
@@ -843,10 +873,12 @@ impl WhiteboxTool for LidarIdwInterpolation {
                                                 }
                                             }
                                         }
-                                        if verbose && inputs.len() == 1 {
+                                        if inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                if context.progress("Binning points", progress).is_err() {
+                                                    break;
+                                                }
                                                 old_progress = progress;
                                             }
                                         }
@@ -857,8 +889,10 @@ impl WhiteboxTool for LidarIdwInterpolation {
                     }
 
                     if frs.size() == 0 {
-                        println!("Warning: No points found in {}.", inputs[tile].clone());
-                        tx2.send(tile).unwrap();
+                        context.warning(format!("Warning: No points found in {}.", inputs[tile].clone()));
+                        if tx2.send(tile).is_err() {
+                            break;
+                        }
                     } else {
                         let west: f64 = bounding_boxes[tile].min_x;
                         let north: f64 = bounding_boxes[tile].max_y;
@@ -944,10 +978,12 @@ impl WhiteboxTool for LidarIdwInterpolation {
                                         }
                                     }
                                 }
-                                if verbose && inputs.len() == 1 {
+                                if inputs.len() == 1 {
                                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                                     if progress != old_progress {
-                                        println!("Progress: {}%", progress);
+                                        if context.progress("Progress", progress).is_err() {
+                                            break;
+                                        }
                                         old_progress = progress;
                                     }
                                 }
@@ -1024,7 +1060,9 @@ impl WhiteboxTool for LidarIdwInterpolation {
                                                 }
                                             }
                                         }
-                                        tx1.send((row, data)).unwrap();
+                                        if tx1.send((row, data)).is_err() {
+                                            break;
+                                        }
                                     }
                                 });
                             }
@@ -1032,12 +1070,12 @@ impl WhiteboxTool for LidarIdwInterpolation {
                             for row in 0..rows {
                                 let data = rx.recv().expect("Error receiving data from thread.");
                                 output.set_row_data(data.0, data.1);
-                                if verbose {
-                                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
-                                    if progress != old_progress {
-                                        println!("Progress: {}%", progress);
-                                        old_progress = progress;
+                                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
+                                if progress != old_progress {
+                                    if context.progress("Progress", progress).is_err() {
+                                        break;
                                     }
+                                    old_progress = progress;
                                 }
                             }
                         }
@@ -1063,13 +1101,18 @@ impl WhiteboxTool for LidarIdwInterpolation {
                             elapsed_time_run
                         ));
 
-                        if verbose && inputs.len() == 1 {
-                            println!("Saving data...")
-                        };
+                        // the output of a cancelled run is not written.
+                        if !context.is_cancelled() {
+                            if verbose && inputs.len() == 1 {
+                                context.info("Saving data...")
+                            };
 
-                        let _ = output.write().unwrap();
+                            let _ = output.write().unwrap();
+                        }
 
-                        tx2.send(tile).unwrap();
+                        if tx2.send(tile).is_err() {
+                            break;
+                        }
                     }
                 }
             });
@@ -1079,9 +1122,10 @@ impl WhiteboxTool for LidarIdwInterpolation {
         let mut old_progress: i32 = -1;
         for tile in 0..inputs.len() {
             let tile_completed = rx2.recv().unwrap();
+            context.check_cancelled()?;
             if verbose {
                 if tile <= 98 {
-                    println!(
+                    context.info(format!(
                         "Finished {} ({} of {})",
                         inputs[tile_completed]
                             .replace("\"", "")
@@ -1089,9 +1133,9 @@ impl WhiteboxTool for LidarIdwInterpolation {
                             .replace(".las", ""),
                         tile + 1,
                         inputs.len()
-                    );
+                    ));
                 } else if tile == 99 {
-                    println!(
+                    context.info(format!(
                         "Finished {} ({} of {})",
                         inputs[tile_completed]
                             .replace("\"", "")
@@ -1099,24 +1143,21 @@ impl WhiteboxTool for LidarIdwInterpolation {
                             .replace(".las", ""),
                         tile + 1,
                         inputs.len()
-                    );
-                    println!("...");
-                }
-                progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
+                    ));
+                    context.info("...");
                 }
             }
+            progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
+            }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 01/06/2017
-Last Modified: 13/06/2021
+Last Modified: 17/10/2026
 License: MIT
 */
 
@@ -143,8 +143,9 @@ impl WhiteboxTool for LidarInfo {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file = String::new();
         let mut show_vlrs = false;
@@ -193,11 +194,11 @@ impl WhiteboxTool for LidarInfo {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep = std::path::MAIN_SEPARATOR;
@@ -210,7 +211,9 @@ impl WhiteboxTool for LidarInfo {
         }
 
         if output_file.len() == 0 {
-            output_file = input_file.replace(".las", "_summary.html");
+            output_file = input_file
+                .replace(".las", "_summary.html")
+                .replace(".laz", "_summary.html");
         }
 
         let f = File::create(output_file.clone())?;
@@ -329,6 +332,69 @@ impl WhiteboxTool for LidarInfo {
             }
         }
 
+        let percent = |count: f64| count / num_points as f64 * 100f64;
+        context.set_result("num_points", num_points);
+        context.set_result("point_format", input.header.point_format);
+        context.set_result(
+            "version",
+            format!(
+                "{}.{}",
+                input.header.version_major, input.header.version_minor
+            ),
+        );
+        context.set_result(
+            "extent",
+            serde_json::json!({
+                "min_x": input.header.min_x,
+                "max_x": input.header.max_x,
+                "min_y": input.header.min_y,
+                "max_y": input.header.max_y,
+                "min_z": input.header.min_z,
+                "max_z": input.header.max_z,
+            }),
+        );
+        context.set_result("min_intensity", min_i);
+        context.set_result("max_intensity", max_i);
+        let returns: Vec<serde_json::Value> = (0..5)
+            .filter(|&i| ret_array[i] > 0)
+            .map(|i| {
+                serde_json::json!({
+                    "return": i + 1,
+                    "count": ret_array[i],
+                    "percent": percent(ret_array[i] as f64),
+                })
+            })
+            .collect();
+        context.set_result("returns", returns);
+        let positions: Vec<serde_json::Value> = [
+            ("only", num_only),
+            ("first", num_first),
+            ("intermediate", num_intermediate),
+            ("last", num_last),
+        ]
+        .iter()
+        .map(|&(position, count)| {
+            serde_json::json!({
+                "position": position,
+                "count": count,
+                "percent": percent(count as f64),
+            })
+        })
+        .collect();
+        context.set_result("return_positions", positions);
+        let classes: Vec<serde_json::Value> = (0..256)
+            .filter(|&i| class_array[i] > 0)
+            .map(|i| {
+                serde_json::json!({
+                    "class": i,
+                    "name": convert_class_val_to_class_string(i as u8),
+                    "count": class_array[i],
+                    "percent": percent(class_array[i] as f64),
+                })
+            })
+            .collect();
+        context.set_result("classes", classes);
+
         // println!("\n\nMin I: {}\nMax I: {}", min_i, max_i);
         let s1 = &format!(
             "<br>Min Intensity: {}<br>Max Intensity: {}</p>",
@@ -459,6 +525,19 @@ impl WhiteboxTool for LidarInfo {
         writer.write_all(s.as_bytes())?;
 
         if show_vlrs {
+            let vlrs: Vec<serde_json::Value> = input
+                .vlr_data
+                .iter()
+                .map(|vlr| {
+                    serde_json::json!({
+                        "user_id": vlr.user_id.trim_end_matches('\0'),
+                        "record_id": vlr.record_id,
+                        "description": vlr.description.trim_end_matches('\0'),
+                        "record_length": vlr.record_length_after_header,
+                    })
+                })
+                .collect();
+            context.set_result("vlrs", vlrs);
             s = "<h2>Variable Length Records</h2>";
             writer.write_all(s.as_bytes())?;
             if input.header.number_of_vlrs > 0 {
@@ -473,6 +552,7 @@ impl WhiteboxTool for LidarInfo {
         }
 
         if show_geokeys {
+            context.set_result("geokeys", input.geokeys.interpret_geokeys());
             s = "<h2>Geokeys</h2>";
             writer.write_all(s.as_bytes())?;
             let s1 = &format!(
@@ -512,7 +592,7 @@ impl WhiteboxTool for LidarInfo {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                context.info(format!("Complete! Please see {} for output.", output_file));
             }
         }
 
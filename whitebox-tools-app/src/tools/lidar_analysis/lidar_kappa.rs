@@ -156,8 +156,9 @@ impl WhiteboxTool for LidarKappaIndex {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file1 = String::new();
         let mut input_file2 = String::new();
         let mut output_file = String::new();
@@ -223,11 +224,11 @@ impl WhiteboxTool for LidarKappaIndex {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -252,7 +253,7 @@ impl WhiteboxTool for LidarKappaIndex {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let start = Instant::now();
 
@@ -297,6 +298,7 @@ impl WhiteboxTool for LidarKappaIndex {
         configs.photometric_interp = PhotometricInterpretation::Continuous;
 
         let mut class_accuracy = Raster::initialize_using_config(&class_accuracy_file, &configs);
+        class_accuracy.widen_to_f64();
         class_accuracy.reinitialize_values(0f64);
 
         // Create an Array2D to store the number of points in each grid cell
@@ -315,12 +317,10 @@ impl WhiteboxTool for LidarKappaIndex {
             // p1 = input2.get_point_info(i);
             p1 = input2.get_transformed_coords(i);
             frs.insert(p1.x, p1.y, p1.z, i);
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points2 as f64) as i32;
-                if progress != old_progress {
-                    println!("Binning points: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points2 as f64) as i32;
+            if progress != old_progress {
+                context.progress("Binning points", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -352,12 +352,10 @@ impl WhiteboxTool for LidarKappaIndex {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points1 as f64) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points1 as f64) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -373,12 +371,10 @@ impl WhiteboxTool for LidarKappaIndex {
                     class_accuracy.set_value(row, col, nodata);
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -700,7 +696,7 @@ impl WhiteboxTool for LidarKappaIndex {
                 let _ = output.stdout;
             }
 
-            println!("Complete!\nPlease see {} for output.", output_file);
+            context.info(format!("Complete!\nPlease see {} for output.", output_file));
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
@@ -714,22 +710,22 @@ impl WhiteboxTool for LidarKappaIndex {
             .add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match class_accuracy.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
+            context.info(format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         Ok(())
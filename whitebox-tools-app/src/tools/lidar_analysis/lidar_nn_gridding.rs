@@ -248,8 +248,9 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         // let mut lakes_file: String = "".to_string();
@@ -383,11 +384,11 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let start = Instant::now();
@@ -437,6 +438,13 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                 .replace(".zlidar", ".tif")
                                 .replace(".ZLIDAR", ".tif"),
                         )
+                    } else if s.to_lowercase().ends_with(".laz") {
+                        inputs.push(s);
+                        outputs.push(
+                            inputs[inputs.len() - 1]
+                                .replace(".laz", ".tif")
+                                .replace(".LAZ", ".tif"),
+                        )
                     } else if s.to_lowercase().ends_with(".zip") {
                         inputs.push(s);
                         outputs.push(
@@ -462,7 +470,8 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                     .clone()
                     .replace(".las", ".tif")
                     .replace(".LAS", ".tif")
-                    .replace(".zlidar", ".tif");
+                    .replace(".zlidar", ".tif")
+                    .replace(".laz", ".tif");
             }
             if !output_file.contains(path::MAIN_SEPARATOR) && !output_file.contains("/") {
                 output_file = format!("{}{}", working_directory, output_file);
@@ -487,7 +496,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
         }
 
         if verbose {
-            println!("Performing interpolation...");
+            context.info("Performing interpolation...");
         }
 
         let num_tiles = inputs.len();
@@ -515,6 +524,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
             let exclude_cls_str = exclude_cls_str.clone();
             let include_class_vals = include_class_vals.clone();
             let tx2 = tx2.clone();
+            let context = context.clone();
             thread::spawn(move || {
                 let mut tile = 0;
                 while tile < num_tiles {
@@ -542,7 +552,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                         FixedRadiusSearch2D::new(search_radius, DistanceMetric::SquaredEuclidean);
 
                     if verbose && inputs.len() == 1 {
-                        println!("Reading input LAS file...");
+                        context.info("Reading input LAS file...");
                     }
 
                     let mut progress: i32;
@@ -550,15 +560,21 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
 
                     for m in 0..inputs.len() {
                         if bounding_boxes[m].overlaps(bb) {
-                            let input =
-                                match LasFile::new(&inputs[m].replace("\"", "").clone(), "r") {
-                                    Ok(lf) => lf,
-                                    Err(err) => panic!(
-                                        "Error reading file {}: {}",
-                                        inputs[m].replace("\"", ""),
-                                        err
-                                    ),
-                                };
+                            let input = match LasFile::read_within(
+                                &inputs[m].replace("\"", ""),
+                                &bb,
+                                None,
+                            ) {
+                                Ok(lf) => lf,
+                                Err(err) => panic!(
+                                    "Error reading file {}: {}",
+                                    inputs[m].replace("\"", ""),
+                                    err
+                                ),
+                            };
+                            if input.header.number_of_points == 0 {
+                                continue;
+                            }
 
                             let n_points = input.header.number_of_points as usize;
                             let num_points: f64 = (input.header.number_of_points - 1) as f64; // used for progress calculation only
@@ -583,10 +599,12 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                                 }
                                             }
                                         }
-                                        if verbose && inputs.len() == 1 {
+                                        if inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                if context.progress("Binning points", progress).is_err() {
+                                                    break;
+                                                }
                                                 old_progress = progress;
                                             }
                                         }
@@ -611,10 +629,12 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                                 }
                                             }
                                         }
-                                        if verbose && inputs.len() == 1 {
+                                        if inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                if context.progress("Binning points", progress).is_err() {
+                                                    break;
+                                                }
                                                 old_progress = progress;
                                             }
                                         }
@@ -639,10 +659,12 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                                 }
                                             }
                                         }
-                                        if verbose && inputs.len() == 1 {
+                                        if inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                if context.progress("Binning points", progress).is_err() {
+                                                    break;
+                                                }
                                                 old_progress = progress;
                                             }
                                         }
@@ -671,10 +693,12 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                                 }
                                             }
                                         }
-                                        if verbose && inputs.len() == 1 {
+                                        if inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                if context.progress("Binning points", progress).is_err() {
+                                                    break;
+                                                }
                                                 old_progress = progress;
                                             }
                                         }
@@ -703,10 +727,12 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                                 }
                                             }
                                         }
-                                        if verbose && inputs.len() == 1 {
+                                        if inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                if context.progress("Reading points", progress).is_err() {
+                                                    break;
+                                                }
                                                 old_progress = progress;
                                             }
                                         }
@@ -735,10 +761,12 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                                 }
                                             }
                                         }
-                                        if verbose && inputs.len() == 1 {
+                                        if inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                if context.progress("Reading points", progress).is_err() {
+                                                    break;
+                                                }
                                                 old_progress = progress;
                                             }
                                         }
@@ -746,7 +774,7 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                 }
                                 "rgb" => {
                                     if !input.has_rgb() {
-                                        println!("Error: The input LAS file does not contain RGB colour data. The interpolation will not proceed.");
+                                        context.error("Error: The input LAS file does not contain RGB colour data. The interpolation will not proceed.");
                                         break;
                                     }
                                     let mut clr: ColourData;
@@ -780,10 +808,12 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                                 }
                                             }
                                         }
-                                        if verbose && inputs.len() == 1 {
+                                        if inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Reading points: {}%", progress);
+                                                if context.progress("Reading points", progress).is_err() {
+                                                    break;
+                                                }
                                                 old_progress = progress;
                                             }
                                         }
@@ -809,10 +839,12 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                                 }
                                             }
                                         }
-                                        if verbose && inputs.len() == 1 {
+                                        if inputs.len() == 1 {
                                             progress = (100.0_f64 * i as f64 / num_points) as i32;
                                             if progress != old_progress {
-                                                println!("Binning points: {}%", progress);
+                                                if context.progress("Binning points", progress).is_err() {
+                                                    break;
+                                                }
                                                 old_progress = progress;
                                             }
                                         }
@@ -823,8 +855,10 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                     }
 
                     if frs.size() == 0 {
-                        println!("No points found in {}", inputs[tile].clone());
-                        tx2.send(tile).unwrap();
+                        context.warning(format!("Warning: No points found in {}", inputs[tile].clone()));
+                        if tx2.send(tile).is_err() {
+                            break;
+                        }
                     } else {
                         let west: f64 = bounding_boxes[tile].min_x;
                         let north: f64 = bounding_boxes[tile].max_y;
@@ -883,10 +917,12 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                         output.set_value(row, col, val);
                                     }
                                 }
-                                if verbose && inputs.len() == 1 {
+                                if inputs.len() == 1 {
                                     progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                                     if progress != old_progress {
-                                        println!("Progress: {}%", progress);
+                                        if context.progress("Progress", progress).is_err() {
+                                            break;
+                                        }
                                         old_progress = progress;
                                     }
                                 }
@@ -930,7 +966,9 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                                                 data[col as usize] = val;
                                             }
                                         }
-                                        tx1.send((row, data)).unwrap();
+                                        if tx1.send((row, data)).is_err() {
+                                            break;
+                                        }
                                     }
                                 });
                             }
@@ -938,12 +976,12 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                             for row in 0..rows {
                                 let data = rx.recv().expect("Error receiving data from thread.");
                                 output.set_row_data(data.0, data.1);
-                                if verbose {
-                                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
-                                    if progress != old_progress {
-                                        println!("Progress: {}%", progress);
-                                        old_progress = progress;
+                                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
+                                if progress != old_progress {
+                                    if context.progress("Progress", progress).is_err() {
+                                        break;
                                     }
+                                    old_progress = progress;
                                 }
                             }
                         }
@@ -968,13 +1006,18 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                             elapsed_time_run
                         ));
 
-                        if verbose && inputs.len() == 1 {
-                            println!("Saving data...")
-                        };
+                        // the output of a cancelled run is not written.
+                        if !context.is_cancelled() {
+                            if verbose && inputs.len() == 1 {
+                                context.info("Saving data...")
+                            };
 
-                        let _ = output.write().unwrap();
+                            let _ = output.write().unwrap();
+                        }
 
-                        tx2.send(tile).unwrap();
+                        if tx2.send(tile).is_err() {
+                            break;
+                        }
                     }
                 }
             });
@@ -984,9 +1027,10 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
         let mut old_progress: i32 = -1;
         for tile in 0..inputs.len() {
             let tile_completed = rx2.recv().unwrap();
+            context.check_cancelled()?;
             if verbose {
                 if tile <= 98 {
-                    println!(
+                    context.info(format!(
                         "Finished {} ({} of {})",
                         inputs[tile_completed]
                             .replace("\"", "")
@@ -994,9 +1038,9 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                             .replace(".las", ""),
                         tile + 1,
                         inputs.len()
-                    );
+                    ));
                 } else if tile == 99 {
-                    println!(
+                    context.info(format!(
                         "Finished {} ({} of {})",
                         inputs[tile_completed]
                             .replace("\"", "")
@@ -1004,24 +1048,21 @@ impl WhiteboxTool for LidarNearestNeighbourGridding {
                             .replace(".las", ""),
                         tile + 1,
                         inputs.len()
-                    );
-                    println!("...");
-                }
-                progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
+                    ));
+                    context.info("...");
                 }
             }
+            progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
+            }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
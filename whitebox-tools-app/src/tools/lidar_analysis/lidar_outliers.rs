@@ -156,8 +156,9 @@ impl WhiteboxTool for LidarRemoveOutliers {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut search_radius = 2f64;
@@ -233,11 +234,11 @@ impl WhiteboxTool for LidarRemoveOutliers {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -249,7 +250,7 @@ impl WhiteboxTool for LidarRemoveOutliers {
         }
 
         if verbose {
-            println!("Reading input LAS file...");
+            context.info("Reading input LAS file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -259,7 +260,7 @@ impl WhiteboxTool for LidarRemoveOutliers {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            context.info("Performing analysis...");
         }
 
         let n_points = input.header.number_of_points as usize;
@@ -277,12 +278,10 @@ impl WhiteboxTool for LidarRemoveOutliers {
             if !pd.is_classified_noise() && !pd.withheld() {
                 frs.insert(p.x, p.y, p.z);
             }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Adding points to search tree: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Adding points to search tree", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -312,9 +311,9 @@ impl WhiteboxTool for LidarRemoveOutliers {
                             }
                         }
                         if n > 0f64 {
-                            tx.send((point_num, p.z - avg_z / n)).unwrap();
+                            let _ = tx.send((point_num, p.z - avg_z / n));
                         } else {
-                            tx.send((point_num, p.z)).unwrap();
+                            let _ = tx.send((point_num, p.z));
                         }
                     }
                 });
@@ -349,13 +348,13 @@ impl WhiteboxTool for LidarRemoveOutliers {
                                 // even num neighbours
                                 median = (z_values[n / 2 - 1] + z_values[n / 2]) / 2f64;
                             }
-                            tx.send((point_num, p.z - median)).unwrap();
+                            let _ = tx.send((point_num, p.z - median));
                         } else if n == 2 {
                             median = (z_values[0] + z_values[1]) / 2f64;
-                            tx.send((point_num, p.z - median)).unwrap();
+                            let _ = tx.send((point_num, p.z - median));
                         } else {
                             // n == 0 or n == 1 {
-                            tx.send((point_num, p.z)).unwrap();
+                            let _ = tx.send((point_num, p.z));
                         }
                     }
                 });
@@ -366,12 +365,10 @@ impl WhiteboxTool for LidarRemoveOutliers {
         for i in 0..n_points {
             let data = rx.recv().expect("Error receiving data from thread.");
             residuals[data.0] = data.1;
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -383,16 +380,14 @@ impl WhiteboxTool for LidarRemoveOutliers {
             for i in 0..n_points {
                 pd = input.get_point_info(i);
                 if residuals[i].abs() < elev_diff && !pd.is_classified_noise() {
-                    output.add_point_record(input.get_record(i));
+                    output.add_point_record_from(&input, i);
                 } else {
                     num_points_filtered += 1;
                 }
-                if verbose {
-                    progress = (100.0_f64 * i as f64 / num_points) as i32;
-                    if progress != old_progress {
-                        println!("Saving data: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * i as f64 / num_points) as i32;
+                if progress != old_progress {
+                    context.progress("Saving data", progress)?;
+                    old_progress = progress;
                 }
             }
         } else {
@@ -533,40 +528,35 @@ impl WhiteboxTool for LidarRemoveOutliers {
                         };
                     }
                 }
-                output.add_point_record(pr2);
-                if verbose {
-                    progress = (100.0_f64 * point_num as f64 / num_points) as i32;
-                    if progress != old_progress {
-                        println!("Saving data: {}%", progress);
-                        old_progress = progress;
-                    }
+                output.add_point_record_with_extra_bytes(pr2, &input, point_num);
+                progress = (100.0_f64 * point_num as f64 / num_points) as i32;
+                if progress != old_progress {
+                    context.progress("Saving data", progress)?;
+                    old_progress = progress;
                 }
             }
             num_points_filtered = 1; // so it passes the saving
         }
 
         if num_points_filtered == 0 {
-            println!("Warning: No outlier points were filtered from the point cloud.");
+            context.warning("Warning: No outlier points were filtered from the point cloud.");
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            context.info("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    context.info("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => context.error(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -192,8 +192,9 @@ impl WhiteboxTool for LidarPointDensity {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut return_type = "all".to_string();
@@ -319,11 +320,11 @@ impl WhiteboxTool for LidarPointDensity {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let start = Instant::now();
@@ -396,6 +397,13 @@ impl WhiteboxTool for LidarPointDensity {
                                 .replace(".zlidar", ".tif")
                                 .replace(".ZLIDAR", ".tif"),
                         )
+                    } else if s.to_lowercase().ends_with(".laz") {
+                        inputs.push(s);
+                        outputs.push(
+                            inputs[inputs.len() - 1]
+                                .replace(".laz", ".tif")
+                                .replace(".LAZ", ".tif"),
+                        )
                     } else if s.to_lowercase().ends_with(".zip") {
                         inputs.push(s);
                         outputs.push(
@@ -421,7 +429,8 @@ impl WhiteboxTool for LidarPointDensity {
                     .clone()
                     .replace(".las", ".tif")
                     .replace(".LAS", ".tif")
-                    .replace(".zlidar", ".tif");
+                    .replace(".zlidar", ".tif")
+                    .replace(".laz", ".tif");
             }
             if !output_file.contains(path::MAIN_SEPARATOR) && !output_file.contains("/") {
                 output_file = format!("{}{}", working_directory, output_file);
@@ -446,7 +455,7 @@ impl WhiteboxTool for LidarPointDensity {
         }
 
         if verbose {
-            println!("Performing analysis...");
+            context.info("Performing analysis...");
         }
 
         let num_tiles = inputs.len();
@@ -468,6 +477,7 @@ impl WhiteboxTool for LidarPointDensity {
             let exclude_cls_str = exclude_cls_str.clone();
             let include_class_vals = include_class_vals.clone();
             let tx2 = tx2.clone();
+            let context = context.clone();
             thread::spawn(move || {
                 let mut tile = 0;
                 while tile < num_tiles {
@@ -495,7 +505,7 @@ impl WhiteboxTool for LidarPointDensity {
                         FixedRadiusSearch2D::new(search_radius, DistanceMetric::SquaredEuclidean);
 
                     if verbose && inputs.len() == 1 {
-                        println!("Reading input LAS file...");
+                        context.info("Reading input LAS file...");
                     }
 
                     let mut progress: i32;
@@ -503,15 +513,21 @@ impl WhiteboxTool for LidarPointDensity {
 
                     for m in 0..inputs.len() {
                         if bounding_boxes[m].overlaps(bb) {
-                            let input =
-                                match LasFile::new(&inputs[m].replace("\"", "").clone(), "r") {
-                                    Ok(lf) => lf,
-                                    Err(err) => panic!(
-                                        "Error reading file {}: {}",
-                                        inputs[m].replace("\"", ""),
-                                        err
-                                    ),
-                                };
+                            let input = match LasFile::read_within(
+                                &inputs[m].replace("\"", ""),
+                                &bb,
+                                None,
+                            ) {
+                                Ok(lf) => lf,
+                                Err(err) => panic!(
+                                    "Error reading file {}: {}",
+                                    inputs[m].replace("\"", ""),
+                                    err
+                                ),
+                            };
+                            if input.header.number_of_points == 0 {
+                                continue;
+                            }
 
                             let n_points = input.header.number_of_points as usize;
                             let num_points: f64 = (input.header.number_of_points - 1) as f64; // used for progress calculation only
@@ -535,10 +551,12 @@ impl WhiteboxTool for LidarPointDensity {
                                         }
                                     }
                                 }
-                                if verbose && inputs.len() == 1 {
+                                if inputs.len() == 1 {
                                     progress = (100.0_f64 * i as f64 / num_points) as i32;
                                     if progress != old_progress {
-                                        println!("Binning points: {}%", progress);
+                                        if context.progress("Binning points", progress).is_err() {
+                                            break;
+                                        }
                                         old_progress = progress;
                                     }
                                 }
@@ -585,10 +603,12 @@ impl WhiteboxTool for LidarPointDensity {
                                 let ret = frs.search(x, y);
                                 output.set_value(row, col, ret.len() as f64 / search_area);
                             }
-                            if verbose && inputs.len() == 1 {
+                            if inputs.len() == 1 {
                                 progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
                                 if progress != old_progress {
-                                    println!("Progress: {}%", progress);
+                                    if context.progress("Progress", progress).is_err() {
+                                        break;
+                                    }
                                     old_progress = progress;
                                 }
                             }
@@ -616,7 +636,9 @@ impl WhiteboxTool for LidarPointDensity {
                                         let ret = frs.search(x, y);
                                         data[col as usize] = ret.len() as f64 / search_area;
                                     }
-                                    tx1.send((row, data)).unwrap();
+                                    if tx1.send((row, data)).is_err() {
+                                        break;
+                                    }
                                 }
                             });
                         }
@@ -624,12 +646,12 @@ impl WhiteboxTool for LidarPointDensity {
                         for row in 0..rows {
                             let data = rx.recv().expect("Error receiving data from thread.");
                             output.set_row_data(data.0, data.1);
-                            if verbose {
-                                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
-                                if progress != old_progress {
-                                    println!("Progress: {}%", progress);
-                                    old_progress = progress;
+                            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
+                            if progress != old_progress {
+                                if context.progress("Progress", progress).is_err() {
+                                    break;
                                 }
+                                old_progress = progress;
                             }
                         }
                     }
@@ -650,13 +672,18 @@ impl WhiteboxTool for LidarPointDensity {
                         elapsed_time_run
                     ));
 
-                    if verbose && inputs.len() == 1 {
-                        println!("Saving data...")
-                    };
+                    // the output of a cancelled run is not written.
+                    if !context.is_cancelled() {
+                        if verbose && inputs.len() == 1 {
+                            context.info("Saving data...")
+                        };
 
-                    let _ = output.write().unwrap();
+                        let _ = output.write().unwrap();
+                    }
 
-                    tx2.send(tile).unwrap();
+                    if tx2.send(tile).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -665,8 +692,9 @@ impl WhiteboxTool for LidarPointDensity {
         let mut old_progress: i32 = -1;
         for tile in 0..inputs.len() {
             let tile_completed = rx2.recv().unwrap();
+            context.check_cancelled()?;
             if verbose {
-                println!(
+                context.info(format!(
                     "Finished interpolating {} ({} of {})",
                     inputs[tile_completed]
                         .replace("\"", "")
@@ -674,24 +702,19 @@ impl WhiteboxTool for LidarPointDensity {
                         .replace(".las", ""),
                     tile + 1,
                     inputs.len()
-                );
+                ));
             }
-            if verbose {
-                progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -215,8 +215,9 @@ impl WhiteboxTool for LidarPointStats {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut grid_res: f64 = 1.0;
         let mut num_points = false;
@@ -340,11 +341,11 @@ impl WhiteboxTool for LidarPointStats {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let num_tiles = inputs.len();
@@ -358,6 +359,7 @@ impl WhiteboxTool for LidarPointStats {
             // copy over the string parameters
             let tool_name = self.get_tool_name();
             let tx2 = tx2.clone();
+            let context = context.clone();
             thread::spawn(move || {
                 let mut tile = 0;
                 while tile < num_tiles {
@@ -371,7 +373,7 @@ impl WhiteboxTool for LidarPointStats {
 
                     let input_file = inputs[tile].replace("\"", "").clone();
                     if verbose && inputs.len() == 1 {
-                        println!("Reading input LAS file...");
+                        context.info("Reading input LAS file...");
                     }
                     let input = match LasFile::new(&input_file, "r") {
                         Ok(lf) => lf,
@@ -417,6 +419,7 @@ impl WhiteboxTool for LidarPointStats {
                             .clone();
                         let mut out_num_pnts =
                             Raster::initialize_using_config(&out_file_num_pnts, &configs);
+                        out_num_pnts.widen_to_f64();
                         out_num_pnts.reinitialize_values(0f64);
 
                         let out_file_num_pulses = input_file
@@ -425,6 +428,7 @@ impl WhiteboxTool for LidarPointStats {
                             .clone();
                         let mut out_num_pulses =
                             Raster::initialize_using_config(&out_file_num_pulses, &configs);
+                        out_num_pulses.widen_to_f64();
                         out_num_pulses.reinitialize_values(0f64);
 
                         let start_run = Instant::now();
@@ -446,19 +450,29 @@ impl WhiteboxTool for LidarPointStats {
                                 out_num_pulses.increment(row, col, 1f64);
                             }
 
-                            if verbose && inputs.len() == 1 {
+                            if inputs.len() == 1 {
                                 progress = (100.0_f64 * i as f64 / num_points_float) as i32;
                                 if progress != old_progress {
-                                    println!("Progress: {}%", progress);
+                                    if context.progress("Progress", progress).is_err() {
+                                        break;
+                                    }
                                     old_progress = progress;
                                 }
                             }
                         }
 
+                        // the outputs of a cancelled run are not written.
+                        if context.is_cancelled() {
+                            if tx2.send(tile).is_err() {
+                                break;
+                            }
+                            continue;
+                        }
+
                         let elapsed_time_run = get_formatted_elapsed_time(start_run);
 
                         if verbose && inputs.len() == 1 {
-                            println!("Saving data...")
+                            context.info("Saving data...")
                         };
 
                         if avg_points_per_pulse {
@@ -619,19 +633,29 @@ impl WhiteboxTool for LidarPointStats {
                                 );
                             }
 
-                            if verbose && inputs.len() == 1 {
+                            if inputs.len() == 1 {
                                 progress = (100.0_f64 * i as f64 / num_points_float) as i32;
                                 if progress != old_progress {
-                                    println!("Progress: {}%", progress);
+                                    if context.progress("Progress", progress).is_err() {
+                                        break;
+                                    }
                                     old_progress = progress;
                                 }
                             }
                         }
 
+                        // the outputs of a cancelled run are not written.
+                        if context.is_cancelled() {
+                            if tx2.send(tile).is_err() {
+                                break;
+                            }
+                            continue;
+                        }
+
                         let elapsed_time_run = get_formatted_elapsed_time(start_run);
 
                         if verbose && inputs.len() == 1 {
-                            println!("Saving data...")
+                            context.info("Saving data...")
                         };
 
                         if z_range {
@@ -709,19 +733,29 @@ impl WhiteboxTool for LidarPointStats {
                                 out_predominant_class.set_value(row, col, class as f64);
                             }
 
-                            if verbose && inputs.len() == 1 {
+                            if inputs.len() == 1 {
                                 progress = (100.0_f64 * i as f64 / num_points_float) as i32;
                                 if progress != old_progress {
-                                    println!("Progress: {}%", progress);
+                                    if context.progress("Progress", progress).is_err() {
+                                        break;
+                                    }
                                     old_progress = progress;
                                 }
                             }
                         }
 
+                        // the outputs of a cancelled run are not written.
+                        if context.is_cancelled() {
+                            if tx2.send(tile).is_err() {
+                                break;
+                            }
+                            continue;
+                        }
+
                         let elapsed_time_run = get_formatted_elapsed_time(start_run);
 
                         if verbose && inputs.len() == 1 {
-                            println!("Saving data...")
+                            context.info("Saving data...")
                         };
 
                         out_predominant_class.add_metadata_entry(format!(
@@ -740,7 +774,9 @@ impl WhiteboxTool for LidarPointStats {
                         drop(out_predominant_class);
                     }
 
-                    tx2.send(tile).unwrap();
+                    if tx2.send(tile).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -749,8 +785,9 @@ impl WhiteboxTool for LidarPointStats {
         let mut old_progress: i32 = -1;
         for tile in 0..inputs.len() {
             let tile_completed = rx2.recv().unwrap();
+            context.check_cancelled()?;
             if verbose {
-                println!(
+                context.info(format!(
                     "Finished {} ({} of {})",
                     inputs[tile_completed]
                         .replace("\"", "")
@@ -759,24 +796,19 @@ impl WhiteboxTool for LidarPointStats {
                         .replace(".zlidar", ""),
                     tile + 1,
                     inputs.len()
-                );
+                ));
             }
-            if verbose {
-                progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -222,8 +222,9 @@ impl WhiteboxTool for LidarRansacPlanes {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut search_radius = 2f64;
@@ -351,11 +352,11 @@ impl WhiteboxTool for LidarRansacPlanes {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -367,7 +368,7 @@ impl WhiteboxTool for LidarRansacPlanes {
         }
 
         if verbose {
-            println!("Reading input LAS file...");
+            context.info("Reading input LAS file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -377,14 +378,14 @@ impl WhiteboxTool for LidarRansacPlanes {
         if acceptable_model_size < 5 {
             acceptable_model_size = 5;
             if verbose {
-                println!("Warning: The --model_size parameter must be at least 5.");
+                context.warning("Warning: The --model_size parameter must be at least 5.");
             }
         }
 
         if num_samples < 5 {
             num_samples = 5;
             if verbose {
-                println!("Warning: The --num_samples parameter must be at least 5.");
+                context.warning("Warning: The --num_samples parameter must be at least 5.");
             }
         }
 
@@ -400,7 +401,7 @@ impl WhiteboxTool for LidarRansacPlanes {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            context.info("Performing analysis...");
         }
 
         let n_points = input.header.number_of_points as usize;
@@ -419,12 +420,10 @@ impl WhiteboxTool for LidarRansacPlanes {
             if (!last_returns || (last_returns && pd.is_late_return())) && !pd.is_classified_noise() {
                 frs.insert(p.x, p.y, p.z, i);
             }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Adding points to search tree: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Adding points to search tree", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -542,7 +541,9 @@ impl WhiteboxTool for LidarRansacPlanes {
                             model_points.clear();
                         }
                     }
-                    tx.send(model_points).unwrap();
+                    if tx.send(model_points).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -555,12 +556,10 @@ impl WhiteboxTool for LidarRansacPlanes {
             for index in model_points {
                 is_a_planar_surface[index] = true;
             }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -571,16 +570,14 @@ impl WhiteboxTool for LidarRansacPlanes {
         if filter {
             for i in 0..n_points {
                 if is_a_planar_surface[i] {
-                    output.add_point_record(input.get_record(i));
+                    output.add_point_record_from(&input, i);
                 } else {
                     num_points_filtered += 1;
                 }
-                if verbose {
-                    progress = (100.0_f64 * i as f64 / num_points) as i32;
-                    if progress != old_progress {
-                        println!("Saving data: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * i as f64 / num_points) as i32;
+                if progress != old_progress {
+                    context.progress("Saving data", progress)?;
+                    old_progress = progress;
                 }
             }
         } else {
@@ -718,13 +715,11 @@ impl WhiteboxTool for LidarRansacPlanes {
                         };
                     }
                 }
-                output.add_point_record(pr2);
-                if verbose {
-                    progress = (100.0_f64 * point_num as f64 / num_points) as i32;
-                    if progress != old_progress {
-                        println!("Saving data: {}%", progress);
-                        old_progress = progress;
-                    }
+                output.add_point_record_with_extra_bytes(pr2, &input, point_num);
+                progress = (100.0_f64 * point_num as f64 / num_points) as i32;
+                if progress != old_progress {
+                    context.progress("Saving data", progress)?;
+                    old_progress = progress;
                 }
             }
             num_points_filtered = 1; // so it passes the saving
@@ -733,27 +728,24 @@ impl WhiteboxTool for LidarRansacPlanes {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if num_points_filtered == 0 {
-            println!("Warning: No outlier points were filtered from the point cloud.");
+            context.warning("Warning: No outlier points were filtered from the point cloud.");
         } else if num_points_filtered == n_points {
-            println!("Warning: All of the points have been filtered from the point cloud.")
+            context.warning("Warning: All of the points have been filtered from the point cloud.")
         }
 
         if verbose {
-            println!("Writing output LAS file...");
+            context.info("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    context.info("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => context.error(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
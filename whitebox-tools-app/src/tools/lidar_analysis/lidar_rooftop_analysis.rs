@@ -232,8 +232,9 @@ impl WhiteboxTool for LidarRooftopAnalysis {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut polygons_file = String::new();
         let mut output_file = String::new();
@@ -419,11 +420,11 @@ impl WhiteboxTool for LidarRooftopAnalysis {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -450,20 +451,20 @@ impl WhiteboxTool for LidarRooftopAnalysis {
         // }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         if acceptable_model_size < 5 {
             acceptable_model_size = 5;
             if verbose {
-                println!("Warning: The --model_size parameter must be at least 5.");
+                context.warning("Warning: The --model_size parameter must be at least 5.");
             }
         }
 
         if num_samples < 5 {
             num_samples = 5;
             if verbose {
-                println!("Warning: The --num_samples parameter must be at least 5.");
+                context.warning("Warning: The --num_samples parameter must be at least 5.");
             }
         }
 
@@ -559,7 +560,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
         }
 
         if verbose {
-            println!("Clipping lidar to building footprints...")
+            context.info("Clipping lidar to building footprints...")
         };
 
         // let input = LasFile::new(&inputs[0], "r").expect("Error reading input LAS file");
@@ -581,7 +582,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
             match LasFile::new(&input_file, "r") {
                 Ok(mut input) => {
                     if verbose && file_num < 100 {
-                        println!("Clipping: {}", input.get_short_filename());
+                        context.info(format!("Clipping: {}", input.get_short_filename()));
                     }
                     projection = input.get_wkt();
                     let n_points = input.header.number_of_points as usize;
@@ -592,6 +593,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
                         let building_tree = building_tree.clone();
                         let polygons = polygons.clone();
                         let tx = tx.clone();
+                        let context = context.clone();
                         thread::spawn(move || {
                             let mut progress: usize;
                             let mut old_progress = 1usize;
@@ -673,22 +675,20 @@ impl WhiteboxTool for LidarRooftopAnalysis {
                                     }
                                 }
 
-                                if num_files == 1 && tid == 0 && verbose {
+                                if num_files == 1 && tid == 0 {
                                     progress =
                                         (100.0_f64 * point_num as f64 / n_points as f64) as usize;
                                     if progress != old_progress {
-                                        println!("Progress: {}%", progress);
+                                        if context.progress("Progress", progress).is_err() {
+                                            break;
+                                        }
                                         old_progress = progress;
                                     }
+                                } else if context.is_cancelled() {
+                                    break;
                                 }
                             }
-                            match tx.send(building_points) {
-                                Ok(_) => {} // do nothing
-                                Err(_) => panic!(
-                                    "Error performing clipping operation on file: {}.",
-                                    input.get_short_filename()
-                                ),
-                            };
+                            let _ = tx.send(building_points);
                         });
                     }
 
@@ -702,6 +702,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
                             building[p.0] = p.1;
                         }
                     }
+                    context.check_cancelled()?;
 
                     for p in 0..n_points {
                         if point_in_building[p] {
@@ -714,27 +715,27 @@ impl WhiteboxTool for LidarRooftopAnalysis {
                             frs.insert(xyz.x, xyz.y, xyz.z, point_num);
                             point_num += 1
                         }
-                        if num_files == 1 && verbose {
+                        if num_files == 1 {
                             progress = (100.0_f64 * p as f64 / n_points as f64) as i32;
                             if progress != old_progress {
-                                println!("Progress: {}%", progress);
+                                context.progress("Progress", progress)?;
                                 old_progress = progress;
                             }
                         }
                     }
 
-                    if num_files > 1 && verbose {
+                    if num_files > 1 {
                         file_num += 1;
                         progress = (100.0_f64 * file_num as f64 / num_files as f64) as i32;
                         if progress != old_progress {
-                            println!("Progress ({} of {}): {}%", file_num, num_files, progress);
+                            context.progress(&format!("Progress ({} of {})", file_num, num_files), progress)?;
                             old_progress = progress;
                         }
                     }
                 }
                 Err(err) => {
                     if verbose {
-                        println!("Error reading file {}: {}", input_file, err);
+                        context.error(format!("Error reading file {}: {}", input_file, err));
                     }
                 }
             };
@@ -746,7 +747,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
         ////////////
 
         if verbose {
-            println!("Building roof facet models...");
+            context.info("Building roof facet models...");
         }
 
         let n_points = las_points_transformed.len();
@@ -853,7 +854,9 @@ impl WhiteboxTool for LidarRooftopAnalysis {
                             model_points.clear();
                         }
                     }
-                    tx.send((best_model, min_rmse, model_points)).unwrap();
+                    if tx.send((best_model, min_rmse, model_points)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -870,12 +873,10 @@ impl WhiteboxTool for LidarRooftopAnalysis {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -883,7 +884,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
         // // Perform the segmentation operation //
         ////////////////////////////////////////
         if verbose {
-            println!("Segmenting the point cloud...");
+            context.info("Segmenting the point cloud...");
         }
         // let mut p: PointData;
         // let mut pn: PointData;
@@ -930,12 +931,10 @@ impl WhiteboxTool for LidarRooftopAnalysis {
 
             while !stack.is_empty() {
                 solved_points += 1;
-                if verbose {
-                    progress = (100f64 * solved_points as f64 / num_points) as i32;
-                    if progress != old_progress {
-                        println!("Segmenting the point cloud: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100f64 * solved_points as f64 / num_points) as i32;
+                if progress != old_progress {
+                    context.progress("Segmenting the point cloud", progress)?;
+                    old_progress = progress;
                 }
                 point_id = stack.pop().expect("Error during pop operation.");
                 is_planar = if model_rmse[point_id] < f64::MAX {
@@ -1625,12 +1624,10 @@ impl WhiteboxTool for LidarRooftopAnalysis {
                 }
                 // */
             }
-            if verbose {
-                progress = (100.0_f64 * building_id as f64 / num_buildings as f64) as i32;
-                if progress != old_progress {
-                    println!("Creating roof segment polygons: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * building_id as f64 / num_buildings as f64) as i32;
+            if progress != old_progress {
+                context.progress("Creating roof segment polygons", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -1680,7 +1677,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
         */
 
         if verbose {
-            println!("Saving polygon data...");
+            context.info("Saving polygon data...");
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
@@ -1688,7 +1685,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -1721,10 +1718,7 @@ impl WhiteboxTool for LidarRooftopAnalysis {
         */
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
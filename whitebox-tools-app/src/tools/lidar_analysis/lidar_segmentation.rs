@@ -260,8 +260,9 @@ impl WhiteboxTool for LidarSegmentation {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut search_radius = 2f64;
@@ -418,11 +419,11 @@ impl WhiteboxTool for LidarSegmentation {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -434,7 +435,7 @@ impl WhiteboxTool for LidarSegmentation {
         }
 
         if verbose {
-            println!("Reading input LAS file...");
+            context.info("Reading input LAS file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -444,14 +445,14 @@ impl WhiteboxTool for LidarSegmentation {
         if acceptable_model_size < 5 {
             acceptable_model_size = 5;
             if verbose {
-                println!("Warning: The --model_size parameter must be at least 5.");
+                context.warning("Warning: The --model_size parameter must be at least 5.");
             }
         }
 
         if num_samples < 5 {
             num_samples = 5;
             if verbose {
-                println!("Warning: The --num_samples parameter must be at least 5.");
+                context.warning("Warning: The --num_samples parameter must be at least 5.");
             }
         }
 
@@ -468,7 +469,7 @@ impl WhiteboxTool for LidarSegmentation {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            context.info("Performing analysis...");
         }
 
         let n_points = input.header.number_of_points as usize;
@@ -486,12 +487,10 @@ impl WhiteboxTool for LidarSegmentation {
             if !pd.withheld() && !pd.is_classified_noise() {
                 frs.insert(p.x, p.y, p.z, i);
             }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Adding points to search tree: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Adding points to search tree", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -588,10 +587,14 @@ impl WhiteboxTool for LidarSegmentation {
                                 model_points.clear();
                             }
                         }
-                        tx.send((best_model, min_rmse, model_points)).unwrap();
+                        if tx.send((best_model, min_rmse, model_points)).is_err() {
+                            break;
+                        }
                     } else {
                         let model_points: Vec<usize> = vec![];
-                        tx.send((best_model, f64::MAX, model_points)).unwrap();
+                        if tx.send((best_model, f64::MAX, model_points)).is_err() {
+                            break;
+                        }
                     }
                 }
             });
@@ -609,12 +612,10 @@ impl WhiteboxTool for LidarSegmentation {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -622,7 +623,7 @@ impl WhiteboxTool for LidarSegmentation {
         // Perform the segmentation operation //
         ////////////////////////////////////////
         if verbose {
-            println!("Segmenting the point cloud...");
+            context.info("Segmenting the point cloud...");
         }
         let mut p: Point3D;
         let mut pd: PointData;
@@ -661,12 +662,10 @@ impl WhiteboxTool for LidarSegmentation {
 
             while !stack.is_empty() {
                 solved_points += 1;
-                if verbose {
-                    progress = (100f64 * solved_points as f64 / num_points) as i32;
-                    if progress != old_progress {
-                        println!("Segmenting the point cloud: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100f64 * solved_points as f64 / num_points) as i32;
+                if progress != old_progress {
+                    context.progress("Segmenting the point cloud", progress)?;
+                    old_progress = progress;
                 }
                 point_id = stack.pop().expect("Error during pop operation.");
                 is_planar = if model_rmse[point_id] < f64::MAX {
@@ -725,7 +724,7 @@ impl WhiteboxTool for LidarSegmentation {
         /////////////////////
 
         if verbose {
-            println!("Saving data...");
+            context.info("Saving data...");
         }
 
         let mut segment_size = vec![0usize; current_segment + 1];
@@ -783,33 +782,28 @@ impl WhiteboxTool for LidarSegmentation {
                 colour_data: rgb,
             };
             output.add_point_record(lpr);
-            if verbose {
-                progress = (100.0_f64 * point_num as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Saving data: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * point_num as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Saving data", progress)?;
+                old_progress = progress;
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            context.info("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    context.info("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => context.error(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -153,8 +153,9 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut search_radius = 5f64;
@@ -254,11 +255,11 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -270,7 +271,7 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
         }
 
         if verbose {
-            println!("Reading input LAS file...");
+            context.info("Reading input LAS file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -282,7 +283,7 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing tophat transform...");
+            context.info("Performing tophat transform...");
         }
 
         if max_norm_diff < 0f64 {
@@ -305,12 +306,10 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
             // let p: PointData = input.get_point_info(i);
             let p = input.get_transformed_coords(i);
             frs2d.insert(p.x, p.y, i);
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Binning points in 2D: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Binning points in 2D", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -346,7 +345,9 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
                             min_z = z_n;
                         }
                     }
-                    tx.send((point_num, min_z)).unwrap();
+                    if tx.send((point_num, min_z)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -354,12 +355,10 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
         for point_num in 0..n_points {
             let data = rx.recv().expect("Error receiving data from thread.");
             neighbourhood_min[data.0] = data.1;
-            if verbose {
-                progress = (100.0_f64 * point_num as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Erosion: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * point_num as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Erosion", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -386,7 +385,9 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
                             max_z = z_n;
                         }
                     }
-                    tx.send((point_num, max_z)).unwrap();
+                    if tx.send((point_num, max_z)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -395,12 +396,10 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
             let data = rx.recv().expect("Error receiving data from thread.");
             let z = input.get_transformed_coords(data.0).z;
             residuals[data.0] = z - data.1;
-            if verbose {
-                progress = (100.0_f64 * point_num as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Dilation: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * point_num as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Dilation", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -408,7 +407,7 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
         // Calculate the normals for each point in the dataset //
         /////////////////////////////////////////////////////////
         if verbose {
-            println!("Calculating point normals...");
+            context.info("Calculating point normals...");
         }
         let mut frs3d: FixedRadiusSearch3D<usize> =
             FixedRadiusSearch3D::new(search_radius as f64, DistanceMetric::SquaredEuclidean);
@@ -417,12 +416,10 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
             let p = input.get_transformed_coords(point_num);
             frs3d.insert(p.x, p.y, residuals[point_num], point_num);
             // using the top-hat transformed data to calculate normals
-            if verbose {
-                progress = (100.0_f64 * point_num as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Binning points in 3D: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * point_num as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Binning points in 3D", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -447,7 +444,9 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
                         let p2 = input.get_transformed_coords(index_n);
                         data.push(Vector3::new(p2.x, p2.y, residuals[index_n]));
                     }
-                    tx.send((point_num, plane_from_points(&data))).unwrap();
+                    if tx.send((point_num, plane_from_points(&data))).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -462,12 +461,10 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
                 is_ground_point[data.0] = true;
                 stack.push(data.0);
             }
-            if verbose {
-                progress = (100.0_f64 * point_num as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Calculating point normals: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * point_num as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Calculating point normals", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -475,7 +472,7 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
         // Perform the segmentation operation //
         ////////////////////////////////////////
         if verbose {
-            println!("Segmenting the point cloud...");
+            context.info("Segmenting the point cloud...");
         }
         let mut point_id: usize;
         let mut norm_diff: f64;
@@ -509,12 +506,10 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
                 }
             }
 
-            if verbose {
-                progress = (100f64 * (1f64 - stack.len() as f64 / num_points)) as i32;
-                if progress != old_progress {
-                    println!("Segmenting the point cloud: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100f64 * (1f64 - stack.len() as f64 / num_points)) as i32;
+            if progress != old_progress {
+                context.progress("Segmenting the point cloud", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -528,16 +523,14 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
 
             for point_num in 0..n_points {
                 if is_ground_point[point_num] {
-                    output.add_point_record(input.get_record(point_num));
+                    output.add_point_record_from(&input, point_num);
                 } else {
                     num_points_filtered += 1;
                 }
-                if verbose {
-                    progress = (100.0_f64 * point_num as f64 / num_points) as i32;
-                    if progress != old_progress {
-                        println!("Saving data: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * point_num as f64 / num_points) as i32;
+                if progress != old_progress {
+                    context.progress("Saving data", progress)?;
+                    old_progress = progress;
                 }
             }
         } else {
@@ -675,41 +668,36 @@ impl WhiteboxTool for LidarSegmentationBasedFilter {
                         };
                     }
                 }
-                output.add_point_record(pr2);
-                if verbose {
-                    progress = (100.0_f64 * point_num as f64 / num_points) as i32;
-                    if progress != old_progress {
-                        println!("Saving data: {}%", progress);
-                        old_progress = progress;
-                    }
+                output.add_point_record_with_extra_bytes(pr2, &input, point_num);
+                progress = (100.0_f64 * point_num as f64 / num_points) as i32;
+                if progress != old_progress {
+                    context.progress("Saving data", progress)?;
+                    old_progress = progress;
                 }
             }
             num_points_filtered = 1; // so it passes the saving
         }
 
         if num_points_filtered == 0 {
-            println!("Warning: No points were filtered from the point cloud.");
+            context.warning("Warning: No points were filtered from the point cloud.");
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
-        println!("");
+        context.info("");
         if verbose {
-            println!("Writing output LAS file...");
+            context.info("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    context.info("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => context.error(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
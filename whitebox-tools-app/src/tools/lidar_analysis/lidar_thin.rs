@@ -146,8 +146,9 @@ impl WhiteboxTool for LidarThin {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut grid_res: f64 = 1.0;
@@ -227,20 +228,20 @@ impl WhiteboxTool for LidarThin {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
-        println!("Reading input LAS file...");
+        context.info("Reading input LAS file...");
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
             Err(err) => panic!("Error reading file {}: {}", input_file, err),
         };
 
-        println!("Performing analysis...");
+        context.info("Performing analysis...");
 
         // Make sure that the input LAS file have GPS time data?
         if input.header.point_format == 0u8 || input.header.point_format == 2u8 {
@@ -281,12 +282,10 @@ impl WhiteboxTool for LidarThin {
                         pt_id.set_value(row, col, i);
                         filtered[i] = false;
                     }
-                    if verbose {
-                        progress = (100.0_f64 * i as f64 / num_points) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * i as f64 / num_points) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -305,12 +304,10 @@ impl WhiteboxTool for LidarThin {
                         pt_id.set_value(row, col, i);
                         filtered[prev_id] = true;
                     }
-                    if verbose {
-                        progress = (100.0_f64 * i as f64 / num_points) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * i as f64 / num_points) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -331,12 +328,10 @@ impl WhiteboxTool for LidarThin {
                     } else {
                         filtered[i] = true;
                     }
-                    if verbose {
-                        progress = (100.0_f64 * i as f64 / num_points) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * i as f64 / num_points) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -357,12 +352,10 @@ impl WhiteboxTool for LidarThin {
                     } else {
                         filtered[i] = true;
                     }
-                    if verbose {
-                        progress = (100.0_f64 * i as f64 / num_points) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * i as f64 / num_points) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -394,12 +387,10 @@ impl WhiteboxTool for LidarThin {
                     } else {
                         filtered[i] = true;
                     }
-                    if verbose {
-                        progress = (100.0_f64 * i as f64 / num_points) as usize;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * i as f64 / num_points) as usize;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -423,14 +414,12 @@ impl WhiteboxTool for LidarThin {
         if !save_filtered {
             for i in 0..n_points {
                 if !filtered[i] {
-                    output.add_point_record(input.get_record(i));
+                    output.add_point_record_from(&input, i);
                 }
-                if verbose {
-                    progress = (100.0_f64 * i as f64 / num_points) as usize;
-                    if progress != old_progress {
-                        println!("Saving data: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * i as f64 / num_points) as usize;
+                if progress != old_progress {
+                    context.progress("Saving data", progress)?;
+                    old_progress = progress;
                 }
             }
             end = get_formatted_elapsed_time(start);
@@ -445,40 +434,38 @@ impl WhiteboxTool for LidarThin {
 
             for i in 0..n_points {
                 if !filtered[i] {
-                    output.add_point_record(input.get_record(i));
+                    output.add_point_record_from(&input, i);
                 } else {
-                    filtered_output.add_point_record(input.get_record(i));
+                    filtered_output.add_point_record_from(&input, i);
                 }
-                if verbose {
-                    progress = (100.0_f64 * i as f64 / num_points) as usize;
-                    if progress != old_progress {
-                        println!("Saving data: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * i as f64 / num_points) as usize;
+                if progress != old_progress {
+                    context.progress("Saving data", progress)?;
+                    old_progress = progress;
                 }
             }
 
             end = get_formatted_elapsed_time(start);
 
             let _ = match filtered_output.write() {
-                Ok(_) => println!("Filtered points LAS file saved"),
-                Err(e) => println!("error while writing: {:?}", e),
+                Ok(_) => context.info("Filtered points LAS file saved"),
+                Err(e) => context.error(format!("error while writing: {:?}", e)),
             };
         }
 
         if verbose {
-            println!("Writing output LAS file...");
+            context.info("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    context.info("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => context.error(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!("{}", &format!("Elapsed Time (excluding I/O): {}", end));
+            context.info(format!("Elapsed Time (excluding I/O): {}", end));
         }
 
         Ok(())
@@ -156,8 +156,9 @@ impl WhiteboxTool for LidarTile {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = String::new();
         let mut width_x = 1000.0;
         let mut width_y = 1000.0;
@@ -257,11 +258,11 @@ impl WhiteboxTool for LidarTile {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep = std::path::MAIN_SEPARATOR;
@@ -275,7 +276,7 @@ impl WhiteboxTool for LidarTile {
         }
 
         if verbose {
-            println!("Performing analysis...");
+            context.info("Performing analysis...");
         }
 
         let input = match LasFile::new(&input_file, "r") {
@@ -318,12 +319,10 @@ impl WhiteboxTool for LidarTile {
             row = (((p.y - origin_y) / width_y) - start_y_grid).floor() as usize; // relative to the grid edge
             tile_data[i] = row * cols + col;
 
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Progress (Loop 1 of 3): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Progress (Loop 1 of 3)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -339,12 +338,10 @@ impl WhiteboxTool for LidarTile {
             if first_point_num[tile_data[i]] == n_points_plus_one {
                 first_point_num[tile_data[i]] = i;
             }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Progress (Loop 2 of 3): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Progress (Loop 2 of 3)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -401,7 +398,7 @@ impl WhiteboxTool for LidarTile {
 
                 for i in first_point_num[tile_num]..last_point_num[tile_num] {
                     if tile_data[i] == tile_num {
-                        output.add_point_record(input.get_record(i));
+                        output.add_point_record_from(&input, i);
                     }
                 }
                 let _ = match output.write() {
@@ -416,18 +413,16 @@ impl WhiteboxTool for LidarTile {
                 num_tiles_created += 1;
             }
 
-            if verbose {
-                progress = (100.0_f64 * tile_num as f64 / (num_tiles - 1) as f64) as i32;
-                if progress != old_progress {
-                    println!("Progress (Loop 3 of 3): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * tile_num as f64 / (num_tiles - 1) as f64) as i32;
+            if progress != old_progress {
+                context.progress("Progress (Loop 3 of 3)", progress)?;
+                old_progress = progress;
             }
         }
 
         if num_tiles_created > 0 {
             if verbose {
-                println!("Successfully created {} tiles.", num_tiles_created);
+                context.info(format!("Successfully created {} tiles.", num_tiles_created));
             }
         } else if num_tiles_created == 0 {
             return Err(Error::new(
@@ -153,8 +153,9 @@ impl WhiteboxTool for LidarTileFootprint {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut is_convex_hull = false;
@@ -255,11 +256,11 @@ impl WhiteboxTool for LidarTileFootprint {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if contains_laz && is_convex_hull {
@@ -286,6 +287,7 @@ impl WhiteboxTool for LidarTileFootprint {
             let tile_list = tile_list.clone();
             let wkt = wkt.clone();
             let tx = tx.clone();
+            let context = context.clone();
             thread::spawn(move || {
                 let mut tile = 0;
                 while tile < num_tiles {
@@ -300,16 +302,16 @@ impl WhiteboxTool for LidarTileFootprint {
                     let input_file = inputs[tile].replace("\"", "").clone();
 
                     if verbose && num_tiles == 1 {
-                        println!("Reading input LAS file...");
+                        context.info("Reading input LAS file...");
                     }
 
                     let path = path::Path::new(&input_file);
                     let filenm = path.file_stem().unwrap();
                     let short_filename = filenm.to_str().unwrap().to_string();
                     if verbose && num_tiles > 1 && num_tiles < 500 {
-                        println!("Processing {}", short_filename);
+                        context.info(format!("Processing {}", short_filename));
                     } else if verbose && num_tiles == 1 && num_tiles < 500 {
-                        println!("Performing analysis...");
+                        context.info("Performing analysis...");
                     }
                     if is_convex_hull {
                         match LasFile::new(&input_file, "r") {
@@ -317,10 +319,10 @@ impl WhiteboxTool for LidarTileFootprint {
                                 let n_points = input.header.get_number_of_points() as usize;
 
                                 if n_points == 0usize {
-                                    println!(
+                                    context.warning(format!(
                                         "Warning {} does not contain any points.",
                                         short_filename
-                                    );
+                                    ));
                                 }
 
                                 // read the points into a Vec<Point2D>
@@ -344,26 +346,34 @@ impl WhiteboxTool for LidarTileFootprint {
                                     *data = input.get_wkt();
                                 }
                                 // send the data to the main thread to be output
-                                tx.send((
-                                    hull_points,
-                                    short_filename,
-                                    n_points,
-                                    input.header.min_z,
-                                    input.header.max_z,
-                                    input.get_wkt(),
-                                ))
-                                .unwrap();
+                                if tx
+                                    .send((
+                                        hull_points,
+                                        short_filename,
+                                        n_points,
+                                        input.header.min_z,
+                                        input.header.max_z,
+                                        input.get_wkt(),
+                                    ))
+                                    .is_err()
+                                {
+                                    break;
+                                }
                             }
                             Err(err) => {
-                                tx.send((
-                                    vec![],
-                                    format!("Error reading file {}:\n{}", input_file, err),
-                                    0,
-                                    0f64,
-                                    0f64,
-                                    "".to_string(),
-                                ))
-                                .unwrap();
+                                if tx
+                                    .send((
+                                        vec![],
+                                        format!("Error reading file {}:\n{}", input_file, err),
+                                        0,
+                                        0f64,
+                                        0f64,
+                                        "".to_string(),
+                                    ))
+                                    .is_err()
+                                {
+                                    break;
+                                }
                             }
                         };
                     } else {
@@ -377,32 +387,40 @@ impl WhiteboxTool for LidarTileFootprint {
                                 bounding_points.push(Point2D::new(header.min_x, header.max_y));
 
                                 if header.get_number_of_points() == 0u64 {
-                                    println!(
+                                    context.warning(format!(
                                         "Warning {} does not contain any points.",
                                         short_filename
-                                    );
+                                    ));
                                 }
 
-                                tx.send((
-                                    bounding_points,
-                                    short_filename,
-                                    header.get_number_of_points() as usize,
-                                    header.min_z,
-                                    header.max_z,
-                                    "".to_string(),
-                                ))
-                                .unwrap();
+                                if tx
+                                    .send((
+                                        bounding_points,
+                                        short_filename,
+                                        header.get_number_of_points() as usize,
+                                        header.min_z,
+                                        header.max_z,
+                                        "".to_string(),
+                                    ))
+                                    .is_err()
+                                {
+                                    break;
+                                }
                             }
                             Err(err) => {
-                                tx.send((
-                                    vec![],
-                                    format!("Error reading file {}:\n{}", input_file, err),
-                                    0,
-                                    0f64,
-                                    0f64,
-                                    "".to_string(),
-                                ))
-                                .unwrap();
+                                if tx
+                                    .send((
+                                        vec![],
+                                        format!("Error reading file {}:\n{}", input_file, err),
+                                        0,
+                                        0f64,
+                                        0f64,
+                                        "".to_string(),
+                                    ))
+                                    .is_err()
+                                {
+                                    break;
+                                }
                             }
                         }
                     }
@@ -466,17 +484,16 @@ impl WhiteboxTool for LidarTileFootprint {
                         }
                     } else {
                         // there was an error, likely reading a LAS file.
-                        println!("{}", data.1);
+                        context.info(&data.1);
                     }
                 }
-                Err(val) => println!("Error: {:?}", val),
+                Err(val) => context.error(format!("Error: {:?}", val)),
             }
-            if verbose {
-                progress = (100.0_f64 * tile as f64 / (num_tiles - 1) as f64) as i32;
-                if progress != old_progress {
-                    println!("Progress ({} of {}): {}%", (tile + 1), num_tiles, progress);
-                    old_progress = progress;
-                }
+            context.check_cancelled()?;
+            progress = (100.0_f64 * tile as f64 / (num_tiles - 1) as f64) as i32;
+            if progress != old_progress {
+                context.progress(&format!("Progress ({} of {})", (tile + 1), num_tiles), progress)?;
+                old_progress = progress;
             }
         }
 
@@ -495,18 +512,18 @@ impl WhiteboxTool for LidarTileFootprint {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -233,8 +233,9 @@ impl WhiteboxTool for LidarTINGridding {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut interp_parameter = "elevation".to_string();
@@ -384,11 +385,11 @@ impl WhiteboxTool for LidarTINGridding {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let start = Instant::now();
@@ -440,6 +441,13 @@ impl WhiteboxTool for LidarTINGridding {
                                 .replace(".zlidar", ".tif")
                                 .replace(".ZLIDAR", ".tif"),
                         )
+                    } else if s.to_lowercase().ends_with(".laz") {
+                        inputs.push(s);
+                        outputs.push(
+                            inputs[inputs.len() - 1]
+                                .replace(".laz", ".tif")
+                                .replace(".LAZ", ".tif"),
+                        )
                     } else if s.to_lowercase().ends_with(".zip") {
                         inputs.push(s);
                         outputs.push(
@@ -465,7 +473,8 @@ impl WhiteboxTool for LidarTINGridding {
                     .clone()
                     .replace(".las", ".tif")
                     .replace(".LAS", ".tif")
-                    .replace(".zlidar", ".tif");
+                    .replace(".zlidar", ".tif")
+                    .replace(".laz", ".tif");
             }
             if !output_file.contains(path::MAIN_SEPARATOR) && !output_file.contains("/") {
                 output_file = format!("{}{}", working_directory, output_file);
@@ -493,7 +502,7 @@ impl WhiteboxTool for LidarTINGridding {
         }
 
         if verbose {
-            println!("Performing interpolation...");
+            context.info("Performing interpolation...");
         }
 
         let num_tiles = inputs.len();
@@ -517,6 +526,7 @@ impl WhiteboxTool for LidarTINGridding {
             let exclude_cls_str = exclude_cls_str.clone();
             let include_class_vals = include_class_vals.clone();
             let tx2 = tx2.clone();
+            let context = context.clone();
             thread::spawn(move || {
                 for tile in (0..num_tiles).filter(|t| t % num_procs == tid) {
                     // let mut tile = 0;
@@ -545,7 +555,7 @@ impl WhiteboxTool for LidarTINGridding {
                     let mut z_values: Vec<f64> = vec![];
 
                     if verbose && inputs.len() == 1 {
-                        println!("Reading input LiDAR file...");
+                        context.info("Reading input LiDAR file...");
                     }
 
                     let mut progress: i32;
@@ -563,8 +573,11 @@ impl WhiteboxTool for LidarTINGridding {
                             //         ),
                             //     };
 
-                            match LasFile::new(&inputs[m].replace("\"", "").clone(), "r") {
+                            match LasFile::read_within(&inputs[m].replace("\"", ""), &bb, None) {
                                 Ok(input) => {
+                                    if input.header.number_of_points == 0 {
+                                        continue;
+                                    }
                                     let n_points = input.header.number_of_points as usize;
                                     let num_points: f64 =
                                         (input.header.number_of_points - 1) as f64; // used for progress calculation only
@@ -596,11 +609,13 @@ impl WhiteboxTool for LidarTINGridding {
                                                         }
                                                     }
                                                 }
-                                                if verbose && inputs.len() == 1 {
+                                                if inputs.len() == 1 {
                                                     progress =
                                                         (100.0_f64 * i as f64 / num_points) as i32;
                                                     if progress != old_progress {
-                                                        println!("Reading points: {}%", progress);
+                                                        if context.progress("Reading points", progress).is_err() {
+                                                            break;
+                                                        }
                                                         old_progress = progress;
                                                     }
                                                 }
@@ -631,11 +646,13 @@ impl WhiteboxTool for LidarTINGridding {
                                                         }
                                                     }
                                                 }
-                                                if verbose && inputs.len() == 1 {
+                                                if inputs.len() == 1 {
                                                     progress =
                                                         (100.0_f64 * i as f64 / num_points) as i32;
                                                     if progress != old_progress {
-                                                        println!("Reading points: {}%", progress);
+                                                        if context.progress("Reading points", progress).is_err() {
+                                                            break;
+                                                        }
                                                         old_progress = progress;
                                                     }
                                                 }
@@ -666,11 +683,13 @@ impl WhiteboxTool for LidarTINGridding {
                                                         }
                                                     }
                                                 }
-                                                if verbose && inputs.len() == 1 {
+                                                if inputs.len() == 1 {
                                                     progress =
                                                         (100.0_f64 * i as f64 / num_points) as i32;
                                                     if progress != old_progress {
-                                                        println!("Reading points: {}%", progress);
+                                                        if context.progress("Reading points", progress).is_err() {
+                                                            break;
+                                                        }
                                                         old_progress = progress;
                                                     }
                                                 }
@@ -701,11 +720,13 @@ impl WhiteboxTool for LidarTINGridding {
                                                         }
                                                     }
                                                 }
-                                                if verbose && inputs.len() == 1 {
+                                                if inputs.len() == 1 {
                                                     progress =
                                                         (100.0_f64 * i as f64 / num_points) as i32;
                                                     if progress != old_progress {
-                                                        println!("Reading points: {}%", progress);
+                                                        if context.progress("Reading points", progress).is_err() {
+                                                            break;
+                                                        }
                                                         old_progress = progress;
                                                     }
                                                 }
@@ -737,11 +758,13 @@ impl WhiteboxTool for LidarTINGridding {
                                                         }
                                                     }
                                                 }
-                                                if verbose && inputs.len() == 1 {
+                                                if inputs.len() == 1 {
                                                     progress =
                                                         (100.0_f64 * i as f64 / num_points) as i32;
                                                     if progress != old_progress {
-                                                        println!("Reading points: {}%", progress);
+                                                        if context.progress("Reading points", progress).is_err() {
+                                                            break;
+                                                        }
                                                         old_progress = progress;
                                                     }
                                                 }
@@ -774,11 +797,13 @@ impl WhiteboxTool for LidarTINGridding {
                                                         }
                                                     }
                                                 }
-                                                if verbose && inputs.len() == 1 {
+                                                if inputs.len() == 1 {
                                                     progress =
                                                         (100.0_f64 * i as f64 / num_points) as i32;
                                                     if progress != old_progress {
-                                                        println!("Reading points: {}%", progress);
+                                                        if context.progress("Reading points", progress).is_err() {
+                                                            break;
+                                                        }
                                                         old_progress = progress;
                                                     }
                                                 }
@@ -812,11 +837,13 @@ impl WhiteboxTool for LidarTINGridding {
                                                         }
                                                     }
                                                 }
-                                                if verbose && inputs.len() == 1 {
+                                                if inputs.len() == 1 {
                                                     progress =
                                                         (100.0_f64 * i as f64 / num_points) as i32;
                                                     if progress != old_progress {
-                                                        println!("Reading points: {}%", progress);
+                                                        if context.progress("Reading points", progress).is_err() {
+                                                            break;
+                                                        }
                                                         old_progress = progress;
                                                     }
                                                 }
@@ -848,11 +875,13 @@ impl WhiteboxTool for LidarTINGridding {
                                                         }
                                                     }
                                                 }
-                                                if verbose && inputs.len() == 1 {
+                                                if inputs.len() == 1 {
                                                     progress =
                                                         (100.0_f64 * i as f64 / num_points) as i32;
                                                     if progress != old_progress {
-                                                        println!("Reading points: {}%", progress);
+                                                        if context.progress("Reading points", progress).is_err() {
+                                                            break;
+                                                        }
                                                         old_progress = progress;
                                                     }
                                                 }
@@ -866,11 +895,13 @@ impl WhiteboxTool for LidarTINGridding {
                     }
 
                     if points.len() < 3 {
-                        println!(
+                        context.warning(format!(
                             "Warning: No eligible points found in {}",
                             inputs[tile].clone()
-                        );
-                        tx2.send(tile).unwrap();
+                        ));
+                        if tx2.send(tile).is_err() {
+                            break;
+                        }
                     } else {
                         let west: f64 = bounding_boxes[tile].min_x;
                         let north: f64 = bounding_boxes[tile].max_y;
@@ -907,7 +938,7 @@ impl WhiteboxTool for LidarTINGridding {
 
                         // do the triangulation
                         if num_tiles == 1 && verbose {
-                            println!("Performing triangulation...");
+                            context.info("Performing triangulation...");
                         }
                         let result = triangulate(&points).expect("No triangulation exists.");
                         let num_triangles = result.triangles.len() / 3;
@@ -993,12 +1024,14 @@ impl WhiteboxTool for LidarTINGridding {
                                         }
                                     }
 
-                                    if verbose && num_tiles == 1 {
+                                    if num_tiles == 1 {
                                         progress = (100.0_f64 * triangle as f64
                                             / (num_triangles - 1) as f64)
                                             as i32;
                                         if progress != old_progress {
-                                            println!("Progress: {}%", progress);
+                                            if context.progress("Progress", progress).is_err() {
+                                                break;
+                                            }
                                             old_progress = progress;
                                         }
                                     }
@@ -1099,12 +1132,14 @@ impl WhiteboxTool for LidarTINGridding {
                                         }
                                     }
 
-                                    if verbose && num_tiles == 1 {
+                                    if num_tiles == 1 {
                                         progress = (100.0_f64 * triangle as f64
                                             / (num_triangles - 1) as f64)
                                             as i32;
                                         if progress != old_progress {
-                                            println!("Progress: {}%", progress);
+                                            if context.progress("Progress", progress).is_err() {
+                                                break;
+                                            }
                                             old_progress = progress;
                                         }
                                     }
@@ -1130,13 +1165,18 @@ impl WhiteboxTool for LidarTINGridding {
                             elapsed_time_run
                         ));
 
-                        if verbose && inputs.len() == 1 {
-                            println!("Saving data...")
-                        };
+                        // the output of a cancelled run is not written.
+                        if !context.is_cancelled() {
+                            if verbose && inputs.len() == 1 {
+                                context.info("Saving data...")
+                            };
 
-                        let _ = output.write().expect("Error writing file.");
+                            let _ = output.write().expect("Error writing file.");
+                        }
 
-                        tx2.send(tile).unwrap();
+                        if tx2.send(tile).is_err() {
+                            break;
+                        }
                     }
                 }
             });
@@ -1146,9 +1186,10 @@ impl WhiteboxTool for LidarTINGridding {
         let mut old_progress: i32 = -1;
         for tile in 0..inputs.len() {
             let tile_completed = rx2.recv().unwrap();
+            context.check_cancelled()?;
             if verbose {
                 if tile <= 98 {
-                    println!(
+                    context.info(format!(
                         "Finished {} ({} of {})",
                         inputs[tile_completed]
                             .replace("\"", "")
@@ -1156,9 +1197,9 @@ impl WhiteboxTool for LidarTINGridding {
                             .replace(".las", ""),
                         tile + 1,
                         inputs.len()
-                    );
+                    ));
                 } else if tile == 99 {
-                    println!(
+                    context.info(format!(
                         "Finished {} ({} of {})",
                         inputs[tile_completed]
                             .replace("\"", "")
@@ -1166,24 +1207,21 @@ impl WhiteboxTool for LidarTINGridding {
                             .replace(".las", ""),
                         tile + 1,
                         inputs.len()
-                    );
-                    println!("...");
-                }
-                progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
+                    ));
+                    context.info("...");
                 }
             }
+            progress = (100.0_f64 * tile as f64 / (inputs.len() - 1) as f64) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
+            }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
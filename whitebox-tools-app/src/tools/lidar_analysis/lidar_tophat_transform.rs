@@ -135,8 +135,9 @@ impl WhiteboxTool for LidarTophatTransform {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut output_file: String = "".to_string();
         let mut search_radius = -1f64;
@@ -189,11 +190,11 @@ impl WhiteboxTool for LidarTophatTransform {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep = path::MAIN_SEPARATOR;
@@ -205,7 +206,7 @@ impl WhiteboxTool for LidarTophatTransform {
         }
 
         if verbose {
-            println!("Reading input LAS file...");
+            context.info("Reading input LAS file...");
         }
         let input = match LasFile::new(&input_file, "r") {
             Ok(lf) => lf,
@@ -215,7 +216,7 @@ impl WhiteboxTool for LidarTophatTransform {
         let start = Instant::now();
 
         if verbose {
-            println!("Performing analysis...");
+            context.info("Performing analysis...");
         }
 
         let n_points = input.header.number_of_points as usize;
@@ -232,12 +233,10 @@ impl WhiteboxTool for LidarTophatTransform {
                 // low points really mess with the tophat-transform so exclude classified noise
                 frs.insert(p.x, p.y, i);
             }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Binning points: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Binning points", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -276,7 +275,9 @@ impl WhiteboxTool for LidarTophatTransform {
                     } else {
                         min_z = 0f64;
                     }
-                    tx.send((i, min_z)).unwrap();
+                    if tx.send((i, min_z)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -284,12 +285,10 @@ impl WhiteboxTool for LidarTophatTransform {
         for i in 0..n_points {
             let data = rx.recv().expect("Error receiving data from thread.");
             neighbourhood_min[data.0] = data.1;
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Erosion: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Erosion", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -323,7 +322,9 @@ impl WhiteboxTool for LidarTophatTransform {
                     } else {
                         max_z = 0f64;
                     }
-                    tx.send((i, max_z)).unwrap();
+                    if tx.send((i, max_z)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -333,12 +334,10 @@ impl WhiteboxTool for LidarTophatTransform {
             // let z = input.get_point_info(data.0).z;
             let z = input.get_transformed_coords(data.0).z;
             residuals[data.0] = z - data.1;
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Dilation: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Dilation", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -476,33 +475,28 @@ impl WhiteboxTool for LidarTophatTransform {
                 }
             }
             output.add_point_record(pr2);
-            if verbose {
-                progress = (100.0_f64 * i as f64 / num_points) as i32;
-                if progress != old_progress {
-                    println!("Saving data: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Saving data", progress)?;
+                old_progress = progress;
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Writing output LAS file...");
+            context.info("Writing output LAS file...");
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Complete!")
+                    context.info("Complete!")
                 }
             }
-            Err(e) => println!("error while writing: {:?}", e),
+            Err(e) => context.error(format!("error while writing: {:?}", e)),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
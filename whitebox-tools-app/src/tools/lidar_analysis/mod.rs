@@ -46,6 +46,7 @@ mod lidar_tin_gridding;
 mod lidar_tophat_transform;
 mod normal_vectors;
 mod remove_duplicates;
+mod reproject_lidar;
 mod select_tiles_by_polygon;
 mod zlidar_to_las;
 
@@ -97,5 +98,6 @@ pub use self::lidar_tin_gridding::LidarTINGridding;
 pub use self::lidar_tophat_transform::LidarTophatTransform;
 pub use self::normal_vectors::NormalVectors;
 pub use self::remove_duplicates::LidarRemoveDuplicates;
+pub use self::reproject_lidar::ReprojectLidar;
 pub use self::select_tiles_by_polygon::SelectTilesByPolygon;
 pub use self::zlidar_to_las::ZlidarToLas;
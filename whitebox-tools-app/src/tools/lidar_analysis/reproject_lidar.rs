@@ -0,0 +1,368 @@
+/*
+This tool is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+*/
+
+use whitebox_lidar::*;
+use whitebox_common::spatial_ref_system::{esri_wkt_from_epsg, CoordinateTransform, SpatialReference};
+use crate::tools::*;
+use byteorder::{LittleEndian, WriteBytesExt};
+use std::env;
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path;
+
+/// This tool transforms the point coordinates of a LAS file (`--input`) from its native
+/// coordinate reference system into the coordinate reference system identified by an EPSG
+/// code (`--epsg`). The source system is read from the GeoTIFF keys or OGC WKT variable length
+/// records (VLRs) of the input file, or it may be specified explicitly using the `--src_epsg`
+/// parameter. Transverse Mercator (including UTM and Gauss-Kruger), Lambert Conformal Conic,
+/// Albers Equal Area, Polar Stereographic, Mercator and Web Mercator projections are supported,
+/// as are geographic coordinates. When the source and target systems are based on different
+/// datums, a seven-parameter Helmert datum transformation is applied to the horizontal
+/// coordinates.
+///
+/// Point elevations are not modified by this tool, since LiDAR elevations are usually
+/// referenced to a vertical datum (geoid) rather than to the ellipsoid. The coordinate reference
+/// system VLRs of the input are replaced in the output file by a GeoTIFF key directory that
+/// references the target EPSG code, and all other VLRs are copied. The header scale factors and
+/// offsets are updated to suit the range of the transformed coordinates.
+///
+/// # See Also
+/// `ReprojectRaster`, `ReprojectVector`, `LidarInfo`
+pub struct ReprojectLidar {
+    name: String,
+    description: String,
+    toolbox: String,
+    parameters: Vec<ToolParameter>,
+    example_usage: String,
+}
+
+impl ReprojectLidar {
+    pub fn new() -> ReprojectLidar {
+        // public constructor
+        let name = "ReprojectLidar".to_string();
+        let toolbox = "LiDAR Tools".to_string();
+        let description =
+            "Transforms a LiDAR point cloud into a different coordinate reference system."
+                .to_string();
+
+        let parameters = vec![
+            ToolParameter {
+                name: "Input File".to_owned(),
+                flags: vec!["-i".to_owned(), "--input".to_owned()],
+                description: "Input LiDAR file.".to_owned(),
+                parameter_type: ParameterType::ExistingFile(ParameterFileType::Lidar),
+                default_value: None,
+                optional: false,
+            },
+            ToolParameter {
+                name: "Output File".to_owned(),
+                flags: vec!["-o".to_owned(), "--output".to_owned()],
+                description: "Output LiDAR file.".to_owned(),
+                parameter_type: ParameterType::NewFile(ParameterFileType::Lidar),
+                default_value: None,
+                optional: false,
+            },
+            ToolParameter {
+                name: "Target EPSG Code".to_owned(),
+                flags: vec!["--epsg".to_owned()],
+                description: "EPSG code of the output coordinate reference system.".to_owned(),
+                parameter_type: ParameterType::Integer,
+                default_value: None,
+                optional: false,
+            },
+            ToolParameter {
+                name: "Source EPSG Code (optional)".to_owned(),
+                flags: vec!["--src_epsg".to_owned()],
+                description: "Optional EPSG code of the input coordinate reference system, overriding that of the input file.".to_owned(),
+                parameter_type: ParameterType::Integer,
+                default_value: None,
+                optional: true,
+            },
+        ];
+
+        let sep: String = path::MAIN_SEPARATOR.to_string();
+        let p = format!("{}", env::current_dir().unwrap().display());
+        let e = format!("{}", env::current_exe().unwrap().display());
+        let mut short_exe = e
+            .replace(&p, "")
+            .replace(".exe", "")
+            .replace(".", "")
+            .replace(&sep, "");
+        if e.contains(".exe") {
+            short_exe += ".exe";
+        }
+        let usage = format!(">>.*{0} -r={1} -v --wd=\"*path*to*data*\" -i=\"input.las\" -o=\"output.las\" --epsg=26917", short_exe, name).replace("*", &sep);
+
+        ReprojectLidar {
+            name,
+            description,
+            toolbox,
+            parameters,
+            example_usage: usage,
+        }
+    }
+}
+
+impl Default for ReprojectLidar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WhiteboxTool for ReprojectLidar {
+    fn get_source_file(&self) -> String {
+        String::from(file!())
+    }
+
+    fn get_tool_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.description.clone()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        match serde_json::to_string(&self.parameters) {
+            Ok(json_str) => format!("{{\"parameters\":{}}}", json_str),
+            Err(err) => format!("{:?}", err),
+        }
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.example_usage.clone()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.toolbox.clone()
+    }
+
+    fn run(
+        &self,
+        args: Vec<String>,
+        working_directory: &str,
+        context: &ToolContext,
+    ) -> Result<(), Error> {
+        let verbose = context.verbose;
+        let mut input_file = String::new();
+        let mut output_file = String::new();
+        let mut epsg = 0u16;
+        let mut src_epsg = 0u16;
+
+        // read the arguments
+        if args.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Tool run with no parameters.",
+            ));
+        }
+        for i in 0..args.len() {
+            let mut arg = args[i].replace("\"", "");
+            arg = arg.replace("\'", "");
+            let cmd = arg.split("="); // in case an equals sign was used
+            let vec = cmd.collect::<Vec<&str>>();
+            let mut keyval = false;
+            if vec.len() > 1 {
+                keyval = true;
+            }
+            let flag_val = vec[0].to_lowercase().replace("--", "-");
+            if flag_val == "-i" || flag_val == "-input" {
+                input_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-o" || flag_val == "-output" {
+                output_file = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+            } else if flag_val == "-epsg" || flag_val == "-src_epsg" {
+                let val = if keyval {
+                    vec[1].to_string()
+                } else {
+                    args[i + 1].to_string()
+                };
+                let code = val.trim().parse::<u16>().map_err(|_| {
+                    Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("Error parsing {}; EPSG codes must be integers.", flag_val),
+                    )
+                })?;
+                if flag_val == "-epsg" {
+                    epsg = code;
+                } else {
+                    src_epsg = code;
+                }
+            }
+        }
+
+        if verbose {
+            let tool_name = self.get_tool_name();
+            let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28);
+            // 28 = length of the 'Powered by' by statement.
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
+        }
+
+        let sep = path::MAIN_SEPARATOR;
+        if !input_file.contains(sep) && !input_file.contains("/") {
+            input_file = format!("{}{}", working_directory, input_file);
+        }
+        if !output_file.contains(sep) && !output_file.contains("/") {
+            output_file = format!("{}{}", working_directory, output_file);
+        }
+
+        if epsg == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The target EPSG code (--epsg) must be specified.",
+            ));
+        }
+
+        if verbose {
+            context.info("Reading input LAS file...");
+        }
+        let input = LasFile::new(&input_file, "r")?;
+
+        let start = Instant::now();
+
+        let src_sr = if src_epsg != 0 {
+            SpatialReference::from_epsg(src_epsg)?
+        } else if input.get_epsg_code() != 0 {
+            SpatialReference::from_epsg(input.get_epsg_code())?
+        } else if !input.wkt.trim().is_empty() {
+            SpatialReference::from_wkt(&input.wkt)?
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The coordinate reference system of the input LAS file is unknown. Please specify it using --src_epsg.",
+            ));
+        };
+        let dst_sr = SpatialReference::from_epsg(epsg)?;
+        let transform = CoordinateTransform::new(&src_sr, &dst_sr)?;
+
+        // Choose scale factors and offsets that suit the transformed coordinates.
+        let extent = transform.transform_bounding_box(&input.get_extent(), 20)?;
+        let mut header = input.header.clone();
+        let mut xy_scale = if dst_sr.is_geographic() {
+            0.0000001
+        } else if src_sr.is_geographic() {
+            0.001
+        } else {
+            input.header.x_scale_factor.min(input.header.y_scale_factor)
+        };
+        let range = extent.get_width().max(extent.get_height());
+        while range / xy_scale > i32::MAX as f64 {
+            xy_scale *= 10f64;
+        }
+        header.x_scale_factor = xy_scale;
+        header.y_scale_factor = xy_scale;
+        header.x_offset = (extent.min_x / xy_scale).floor() * xy_scale;
+        header.y_offset = (extent.min_y / xy_scale).floor() * xy_scale;
+
+        let mut output = LasFile::new(&output_file, "w")?;
+        output.add_header(header);
+
+        // Copy all VLRs other than those describing the coordinate reference system.
+        let mut has_wkt_vlr = input.header.point_format >= 6;
+        for vlr in &input.vlr_data {
+            if vlr.user_id.trim_end_matches('\0') == "LASF_Projection" {
+                if vlr.record_id == 2112 {
+                    has_wkt_vlr = true;
+                }
+                if vlr.record_id == 2112
+                    || vlr.record_id == 34_735
+                    || vlr.record_id == 34_736
+                    || vlr.record_id == 34_737
+                {
+                    continue;
+                }
+            }
+            output.add_vlr(vlr.clone());
+        }
+
+        let model_type = if dst_sr.is_geographic() { 2u16 } else { 1u16 };
+        let cs_key = if dst_sr.is_geographic() { 2048u16 } else { 3072u16 };
+        let geokeys = [1u16, 1, 0, 2, 1024, 0, 1, model_type, cs_key, 0, 1, epsg];
+        let mut vlr = Vlr {
+            user_id: String::from("LASF_Projection"),
+            record_id: 34_735u16,
+            description: String::from("GeoTiff Projection Keys"),
+            ..Default::default()
+        };
+        for key in geokeys.iter() {
+            vlr.binary_data.write_u16::<LittleEndian>(*key)?;
+        }
+        vlr.record_length_after_header = vlr.binary_data.len() as u16;
+        output.add_vlr(vlr);
+
+        let wkt = esri_wkt_from_epsg(epsg);
+        if has_wkt_vlr {
+            let binary_data = format!("{}\0", wkt).as_bytes().to_vec();
+            let vlr = Vlr {
+                user_id: String::from("LASF_Projection"),
+                record_id: 2112u16,
+                description: String::from("OGC WKT Coordinate System"),
+                record_length_after_header: binary_data.len() as u16,
+                binary_data,
+                ..Default::default()
+            };
+            output.add_vlr(vlr);
+        }
+        output.wkt = wkt;
+
+        let n_points = input.header.number_of_points as usize;
+        let num_points: f64 = (input.header.number_of_points - 1) as f64; // used for progress calculation only
+
+        let mut progress: i32;
+        let mut old_progress: i32 = -1;
+
+        let (x_offset, y_offset) = (output.header.x_offset, output.header.y_offset);
+        for i in 0..n_points {
+            let p = input.get_transformed_coords(i);
+            let (x, y) = transform.transform(p.x, p.y).map_err(|e| {
+                Error::new(e.kind(), format!("Error transforming point {}: {}", i + 1, e))
+            })?;
+            let mut record = input.get_record(i);
+            let mut point_data = record.get_point_data();
+            point_data.x = ((x - x_offset) / xy_scale).round() as i32;
+            point_data.y = ((y - y_offset) / xy_scale).round() as i32;
+            record.set_point_data(point_data);
+            output.add_point_record(record);
+            progress = (100.0_f64 * i as f64 / num_points) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
+            }
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+
+        if verbose {
+            context.info("Writing output LAS file...");
+        }
+        match output.write() {
+            Ok(_) => {
+                if verbose {
+                    context.info("Complete!")
+                }
+            }
+            Err(e) => return Err(e),
+        };
+        if verbose {
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        Ok(())
+    }
+}
@@ -10,7 +10,7 @@ use whitebox_common::algorithms;
 use whitebox_lidar::*;
 use whitebox_common::structures::{BoundingBox, Point2D};
 use crate::tools::*;
-use whitebox_vector::{ShapeType, Shapefile};
+use whitebox_vector::{ShapeType, Shapefile, ShapefileGeometry};
 use num_cpus;
 use std::env;
 use std::f64;
@@ -26,7 +26,9 @@ use std::thread;
 /// LiDAR tile bounding box and the polygons. This representation of overlapping geometry aids with performance. This
 /// approach generally works well when the polygon size is large relative to the LiDAR tiles. If, however, the input
 /// polygon is small relative to the tile size, this approach may miss some copying some tiles. It is advisable to
-/// buffer the polygon if this occurs.
+/// buffer the polygon if this occurs. COPC (Cloud Optimized Point Cloud) tiles are not missed in this way, since the
+/// points of a COPC tile that are within the extent of a polygon can be read without decompressing the whole tile,
+/// and are tested against the polygon directly.
 ///
 /// **A note on LAZ file inputs:** While WhiteboxTools does not currently support the reading and writing of the compressed
 /// LiDAR format `LAZ`, it is able to read `LAZ` file headers. Because this tool only requires information contained
@@ -143,8 +145,9 @@ impl WhiteboxTool for SelectTilesByPolygon {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_directory: String = "".to_string();
         let mut output_directory: String = "".to_string();
         let mut polygons_file = String::new();
@@ -201,11 +204,11 @@ impl WhiteboxTool for SelectTilesByPolygon {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let mut inputs = vec![];
@@ -274,6 +277,7 @@ impl WhiteboxTool for SelectTilesByPolygon {
             let tile_list = tile_list.clone();
             let tx = tx.clone();
             let report_copy = report_copy.clone();
+            let context = context.clone();
             // copy over the string parameters
             let input_directory = input_directory.clone();
             let output_directory = output_directory.clone();
@@ -421,6 +425,33 @@ impl WhiteboxTool for SelectTilesByPolygon {
                         }
                     }
 
+                    // A polygon that is small relative to the tile can fall between the points
+                    // tested above. The points of a COPC tile can be read by area, and so the
+                    // points within the extents of the polygons that overlap it are tested too.
+                    if !point_in_poly && LasFile::is_copc(&input_file).unwrap_or(false) {
+                        let tile_bb = BoundingBox::new(west, east, south, north);
+                        for record_num in 0..polygons.num_records {
+                            if !bb[record_num].overlaps(tile_bb) {
+                                continue;
+                            }
+                            let input =
+                                match LasFile::read_within(&input_file, &bb[record_num], None) {
+                                    Ok(lf) => lf,
+                                    Err(err) => {
+                                        panic!("Error reading file {}: {}", input_file, err)
+                                    }
+                                };
+                            let record = polygons.get_record(record_num);
+                            point_in_poly = (0..input.header.number_of_points as usize).any(|i| {
+                                let p = input.get_transformed_coords(i);
+                                is_point_in_record(&Point2D { x: p.x, y: p.y }, record)
+                            });
+                            if point_in_poly {
+                                break;
+                            }
+                        }
+                    }
+
                     if point_in_poly {
                         // copy the tile into the output directory
                         let output_file = inputs[k]
@@ -435,11 +466,11 @@ impl WhiteboxTool for SelectTilesByPolygon {
                                     let report_copy =
                                         report_copy.lock().expect("Error unlocking mutex");
                                     if *report_copy {
-                                        println!(
+                                        context.info(format!(
                                             "Copied \"{}\" to \"{}\"",
                                             input_file.replace(&input_directory, "").clone(),
                                             output_directory.clone()
-                                        )
+                                        ))
                                     }
                                 }
                             }
@@ -447,7 +478,9 @@ impl WhiteboxTool for SelectTilesByPolygon {
                         }
                     }
 
-                    tx.send(point_in_poly).unwrap();
+                    if tx.send(point_in_poly).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -457,33 +490,53 @@ impl WhiteboxTool for SelectTilesByPolygon {
         let mut num_files_copied = 0;
         for tile in 0..num_tiles {
             let in_poly = rx.recv().expect("Error receiving data from thread.");
+            context.check_cancelled()?;
             if in_poly {
                 num_files_copied += 1;
                 if num_files_copied == 50 {
                     if verbose {
-                        println!("...");
+                        context.info("...");
                     }
                     let mut report_copy = report_copy.lock().expect("Error unlocking mutex");
                     *report_copy = false;
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * tile as f64 / (num_tiles - 1) as f64) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * tile as f64 / (num_tiles - 1) as f64) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Number of files copied: {}", num_files_copied);
+            context.info(format!("Number of files copied: {}", num_files_copied));
 
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
     }
 }
+
+/// Returns true if the point is within one of the parts of the polygon and not within one of
+/// its holes.
+fn is_point_in_record(p: &Point2D, record: &ShapefileGeometry) -> bool {
+    let mut in_part = false;
+    for part in 0..record.num_parts as usize {
+        let start = record.parts[part] as usize;
+        let end = if part < record.num_parts as usize - 1 {
+            record.parts[part + 1] as usize
+        } else {
+            record.num_points as usize
+        };
+        if algorithms::point_in_poly(p, &record.points[start..end]) {
+            if part > 0 && record.is_hole(part as i32) {
+                return false;
+            }
+            in_part = true;
+        }
+    }
+    in_part
+}
@@ -132,8 +132,9 @@ impl WhiteboxTool for Anova {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut feature_file = String::new();
         let mut output_file = String::new();
@@ -179,11 +180,11 @@ impl WhiteboxTool for Anova {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -205,7 +206,7 @@ impl WhiteboxTool for Anova {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -344,8 +345,9 @@ impl WhiteboxTool for Anova {
                             overall_sum_sqr += z * z;
                         }
                     }
-                    tx.send((overall_n, overall_sum, overall_sum_sqr, min_id, max_id))
-                        .unwrap();
+                    if tx.send((overall_n, overall_sum, overall_sum_sqr, min_id, max_id)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -367,12 +369,10 @@ impl WhiteboxTool for Anova {
                 max_id = e;
             }
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (loop 1 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (loop 1 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -403,12 +403,10 @@ impl WhiteboxTool for Anova {
                     sum_sqr[vec_id] += z * z;
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (loop 2 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (loop 2 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -443,7 +441,7 @@ impl WhiteboxTool for Anova {
         let ms_w = ss_w / df_w as f64;
         let f = ms_b / ms_w;
 
-        println!("Calclating the p-value...");
+        context.info("Calclating the p-value...");
         // Formula Used:
         // P value = [ 1 / Β(ndf/2,ddf/2) ] × [ (ndf × x) / (ndf × x + ddf) ]^ndf/2 × [1 - (ndf × x) / (ndf × x + ddf) ]^ddf/2 × x-1
         // Where,
@@ -454,7 +452,7 @@ impl WhiteboxTool for Anova {
         // from: https://www.easycalculation.com/statistics/f-test-p-value.php
         let p = f_call(f_spin(f, df_b, df_w));
 
-        println!("Saving output...");
+        context.info("Saving output...");
 
         s = "<br><table align=\"center\">
         <caption>Group Summaries</caption>
@@ -654,15 +652,15 @@ impl WhiteboxTool for Anova {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            context.info(format!("Complete! Please see {} for output.", output_file));
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
+            context.info(format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         Ok(())
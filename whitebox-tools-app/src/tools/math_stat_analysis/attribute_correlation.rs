@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 29/04/2018
-Last Modified: 12/10/2018
+Last Modified: 17/10/2026
 License: MIT
 
 NOTES: Correlation is calculated for each pair of numeric attributes.
@@ -144,8 +144,9 @@ impl WhiteboxTool for AttributeCorrelation {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
 
@@ -184,11 +185,11 @@ impl WhiteboxTool for AttributeCorrelation {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -214,7 +215,7 @@ impl WhiteboxTool for AttributeCorrelation {
         }
 
         if verbose {
-            println!("Reading vector data...")
+            context.info("Reading vector data...")
         };
         let vector_data = Shapefile::read(&input_file)?;
 
@@ -241,7 +242,7 @@ impl WhiteboxTool for AttributeCorrelation {
         let mut field_averages = vec![0f64; num_fields];
         let mut correlation_matrix = vec![vec![-99f64; num_fields]; num_fields];
         if verbose {
-            println!("Calculating attribute averages...");
+            context.info("Calculating attribute averages...");
         }
 
         for record_num in 0..vector_data.num_records {
@@ -268,13 +269,11 @@ impl WhiteboxTool for AttributeCorrelation {
                 }
             }
 
-            if verbose {
-                progress =
-                    (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -285,7 +284,7 @@ impl WhiteboxTool for AttributeCorrelation {
         }
 
         if verbose {
-            println!("Calculating the correlation matrix:");
+            context.info("Calculating the correlation matrix:");
         }
         let mut i = 0;
         let nodata = -32768f64;
@@ -332,27 +331,35 @@ impl WhiteboxTool for AttributeCorrelation {
             }
             i += 1;
 
-            if verbose {
-                progress = (100.0_f64 * a as f64 / (num_fields - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!(
-                        "Calculating the correlation matrix ({} of {}): {}%",
-                        (a + 1),
-                        num_fields,
-                        progress
-                    );
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * a as f64 / (num_fields - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress(&format!("Calculating the correlation matrix ({} of {})", (a + 1), num_fields), progress)?;
+                old_progress = progress;
             }
         }
 
+        // only the lower triangle of the matrix is calculated, since it is symmetric
+        let numeric_fields: Vec<usize> = (0..num_fields).filter(|&a| is_numeric[a]).collect();
+        let matrix: Vec<Vec<f64>> = numeric_fields
+            .iter()
+            .map(|&a| {
+                numeric_fields
+                    .iter()
+                    .map(|&b| correlation_matrix[a.max(b)][a.min(b)])
+                    .collect()
+            })
+            .collect();
+        let names: Vec<&String> = numeric_fields.iter().map(|&a| &field_names[a]).collect();
+        context.set_result("fields", names);
+        context.set_result("correlation_matrix", matrix);
+
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
+            context.info(format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         let f = File::create(output_file.clone())?;
@@ -452,7 +459,7 @@ impl WhiteboxTool for AttributeCorrelation {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                context.info(format!("Complete! Please see {} for output.", output_file));
             }
         }
 
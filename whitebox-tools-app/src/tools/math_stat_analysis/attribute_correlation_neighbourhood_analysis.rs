@@ -182,8 +182,9 @@ impl WhiteboxTool for AttributeCorrelationNeighbourhoodAnalysis {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut field_name1 = String::new();
         let mut field_name2 = String::new();
@@ -271,11 +272,11 @@ impl WhiteboxTool for AttributeCorrelationNeighbourhoodAnalysis {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -288,7 +289,7 @@ impl WhiteboxTool for AttributeCorrelationNeighbourhoodAnalysis {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let mut input = Shapefile::read(&input_file)?;
@@ -347,12 +348,10 @@ impl WhiteboxTool for AttributeCorrelationNeighbourhoodAnalysis {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
-                if progress != old_progress {
-                    println!("Reading points: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
+            if progress != old_progress {
+                context.progress("Reading points", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -685,33 +684,28 @@ impl WhiteboxTool for AttributeCorrelationNeighbourhoodAnalysis {
                         .set_value(record_num, "PVALUE", FieldData::Real(pvalue));
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * (record_num + 1) as f64 / points.len() as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (record_num + 1) as f64 / points.len() as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match input.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         // } else if stat_type == "kendall" { // Perform Kendall's Tau-b correlation
@@ -153,8 +153,9 @@ impl WhiteboxTool for CrispnessIndex {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
 
@@ -192,11 +193,11 @@ impl WhiteboxTool for CrispnessIndex {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -220,7 +221,7 @@ impl WhiteboxTool for CrispnessIndex {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -251,7 +252,7 @@ impl WhiteboxTool for CrispnessIndex {
                     let mut s = 0.0;
                     let mut warning = false;
                     for col in 0..columns {
-                        z = input[(row, col)];
+                        z = input.get_value(row, col);
                         if z != nodata {
                             if z < 0f64 || z > 1f64 {
                                 warning = true;
@@ -260,7 +261,9 @@ impl WhiteboxTool for CrispnessIndex {
                             s += z;
                         }
                     }
-                    tx.send((n, s, warning)).unwrap();
+                    if tx.send((n, s, warning)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -276,12 +279,10 @@ impl WhiteboxTool for CrispnessIndex {
                 warning = true;
             }
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (Loop 1 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (Loop 1 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -296,12 +297,14 @@ impl WhiteboxTool for CrispnessIndex {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut total_dev = 0f64;
                     for col in 0..columns {
-                        z = input[(row, col)];
+                        z = input.get_value(row, col);
                         if z != nodata {
                             total_dev += (z - mean) * (z - mean);
                         }
                     }
-                    tx.send(total_dev).unwrap();
+                    if tx.send(total_dev).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -310,12 +313,10 @@ impl WhiteboxTool for CrispnessIndex {
         for row in 0..rows {
             total_dev += rx.recv().expect("Error receiving data from thread.");
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (Loop 2 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (Loop 2 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -326,8 +327,8 @@ impl WhiteboxTool for CrispnessIndex {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if warning {
-            println!("WARNING: This tool is intended to be applied to membership probability (MP) rasters, with probability values 
-ranging from 0-1. The input image contains values outside this range.");
+            context.warning("WARNING: This tool is intended to be applied to membership probability (MP) rasters, with probability values 
+ranging from 0-1. The input image contains values outside this range.".to_string());
         }
 
         // println!("\nNumber of non-nodata grid cells: {}", num_cells);
@@ -336,10 +337,10 @@ ranging from 0-1. The input image contains values outside this range.");
         // println!("SSb: {}", denominator);
         // println!("Crispness index: {}", crispness);
         if verbose {
-            println!(
+            context.info(format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         let f = File::create(output_file.clone())?;
@@ -480,7 +481,7 @@ ranging from 0-1. The input image contains values outside this range. <em>Theref
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                context.info(format!("Complete! Please see {} for output.", output_file));
             }
         }
 
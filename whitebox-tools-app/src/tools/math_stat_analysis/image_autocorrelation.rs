@@ -183,8 +183,9 @@ impl WhiteboxTool for ImageAutocorrelation {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_files: String = String::new();
         let mut output_file = String::new();
         let mut contiguity = String::new();
@@ -230,11 +231,11 @@ impl WhiteboxTool for ImageAutocorrelation {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -314,7 +315,7 @@ impl WhiteboxTool for ImageAutocorrelation {
         let mut rows: isize = 0;
         let mut columns: isize = 0;
         if verbose {
-            println!("Calculating image averages...");
+            context.info("Calculating image averages...");
         }
         for a in 0..num_files {
             let value = &file_names[a]; //files_vec[a];
@@ -350,7 +351,7 @@ impl WhiteboxTool for ImageAutocorrelation {
                             }
                         }
                     }
-                    tx.send((total, n)).unwrap();
+                    let _ = tx.send((total, n));
                 });
             }
             for np in 0..num_procs {
@@ -358,10 +359,10 @@ impl WhiteboxTool for ImageAutocorrelation {
                 image_totals[a] += total;
                 n[a] += image_n;
 
-                if verbose && num_procs > 1 {
+                if num_procs > 1 {
                     progress = (100.0_f64 * np as f64 / (num_procs - 1) as f64) as usize;
                     if progress != old_progress {
-                        println!("Progress (Loop 1 of 2): {}%", progress);
+                        context.progress("Progress (Loop 1 of 2)", progress)?;
                         old_progress = progress;
                     }
                 }
@@ -400,12 +401,10 @@ impl WhiteboxTool for ImageAutocorrelation {
                         s2 += wij * wij;
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress (Loop 2 of 2): {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress (Loop 2 of 2)", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -434,22 +433,20 @@ impl WhiteboxTool for ImageAutocorrelation {
             z_r[a] = (i[a] - e_i[a]) / var_randomization[a].sqrt();
             p_value_r[a] = 2f64 * (1f64 - distribution.cdf(z_r[a].abs()));
 
-            if verbose {
-                progress = (100.0_f64 * a as f64 / (num_files - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Loop {} of {}: {}%", (a + 1), files_vec.len(), progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * a as f64 / (num_files - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress(&format!("Loop {} of {}", (a + 1), files_vec.len()), progress)?;
+                old_progress = progress;
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
+            context.info(format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         let f = File::create(output_file.clone())?;
@@ -577,7 +574,7 @@ impl WhiteboxTool for ImageAutocorrelation {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                context.info(format!("Complete! Please see {} for output.", output_file));
             }
         }
 
@@ -149,8 +149,9 @@ impl WhiteboxTool for ImageCorrelation {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_files: String = String::new();
         let mut output_file = String::new();
 
@@ -189,11 +190,11 @@ impl WhiteboxTool for ImageCorrelation {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -251,7 +252,7 @@ impl WhiteboxTool for ImageCorrelation {
         let mut rows: isize = 0;
         let mut columns: isize = 0;
         if verbose {
-            println!("Calculating image averages...");
+            context.info("Calculating image averages...");
         }
         for a in 0..num_files {
             let value = &file_names[a]; //files_vec[a];
@@ -280,7 +281,7 @@ impl WhiteboxTool for ImageCorrelation {
                     let mut z: f64;
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
                         for col in 0..columns {
-                            z = input[(row, col)];
+                            z = input.get_value(row, col);
                             if z != nodata {
                                 // image_totals[a] += z;
                                 // image_n[a] += 1f64;
@@ -289,7 +290,7 @@ impl WhiteboxTool for ImageCorrelation {
                             }
                         }
                     }
-                    tx.send((total, n)).unwrap();
+                    let _ = tx.send((total, n));
                 });
             }
             for _ in 0..num_procs {
@@ -299,23 +300,16 @@ impl WhiteboxTool for ImageCorrelation {
             }
             image_averages[a] = image_totals[a] / image_n[a];
 
-            if verbose {
-                progress = (100.0_f64 * a as f64 / (num_files - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!(
-                        "Calculating image averages ({} of {}): {}%",
-                        (a + 1),
-                        files_vec.len(),
-                        progress
-                    );
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * a as f64 / (num_files - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress(&format!("Calculating image averages ({} of {})", (a + 1), files_vec.len()), progress)?;
+                old_progress = progress;
             }
         }
         let image_averages = Arc::new(image_averages);
 
         if verbose {
-            println!("Calculating the correlation matrix:");
+            context.info("Calculating the correlation matrix:");
         }
         let mut i = 0;
         for a in 0..num_files {
@@ -343,8 +337,8 @@ impl WhiteboxTool for ImageCorrelation {
                             let mut total_product_deviations = 0f64;
                             for row in (0..rows).filter(|r| r % num_procs == tid) {
                                 for col in 0..columns {
-                                    z1 = image1[(row, col)];
-                                    z2 = image2[(row, col)];
+                                    z1 = image1.get_value(row, col);
+                                    z2 = image2.get_value(row, col);
                                     if z1 != nodata1 && z2 != nodata2 {
                                         image1_total_deviation +=
                                             (z1 - image_averages[a]) * (z1 - image_averages[a]);
@@ -355,12 +349,11 @@ impl WhiteboxTool for ImageCorrelation {
                                     }
                                 }
                             }
-                            tx.send((
+                            let _ = tx.send((
                                 image1_total_deviation,
                                 image2_total_deviation,
                                 total_product_deviations,
-                            ))
-                            .unwrap();
+                            ));
                         });
                     }
                     let mut image1_total_deviation = 0f64;
@@ -379,27 +372,20 @@ impl WhiteboxTool for ImageCorrelation {
             }
             i += 1;
 
-            if verbose {
-                progress = (100.0_f64 * a as f64 / (num_files - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!(
-                        "Calculating the correlation matrix ({} of {}): {}%",
-                        (a + 1),
-                        files_vec.len(),
-                        progress
-                    );
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * a as f64 / (num_files - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress(&format!("Calculating the correlation matrix ({} of {})", (a + 1), files_vec.len()), progress)?;
+                old_progress = progress;
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
+            context.info(format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         let f = File::create(output_file.clone())?;
@@ -485,7 +471,7 @@ impl WhiteboxTool for ImageCorrelation {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                context.info(format!("Complete! Please see {} for output.", output_file));
             }
         }
 
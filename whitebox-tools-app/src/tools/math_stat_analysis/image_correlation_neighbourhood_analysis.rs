@@ -174,8 +174,9 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file1 = String::new();
         let mut input_file2 = String::new();
         let mut output_file1 = String::new();
@@ -256,11 +257,11 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if filter_size < 3 {
@@ -297,7 +298,7 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let image1 = Arc::new(Raster::new(&input_file1, "r")?);
@@ -416,7 +417,9 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
                                 }
                             }
                         }
-                        tx.send((row, data1, data2)).unwrap();
+                        if tx.send((row, data1, data2)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -426,12 +429,10 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
                 output_val.set_row_data(row, data1);
                 output_sig.set_row_data(row, data2);
 
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Performing Correlation: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Performing Correlation", progress)?;
+                    old_progress = progress;
                 }
             }
         } else if stat_type == "kendall" {
@@ -608,7 +609,9 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
                                 }
                             }
                         }
-                        tx.send((row, data1, data2)).unwrap();
+                        if tx.send((row, data1, data2)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -618,12 +621,10 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
                 output_val.set_row_data(row, data1);
                 output_sig.set_row_data(row, data2);
 
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Performing Correlation: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Performing Correlation", progress)?;
+                    old_progress = progress;
                 }
             }
         } else {
@@ -781,8 +782,9 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
                                 }
                             }
                         }
-                        tx.send((row, data1, data2, num_ties, max_num_ties))
-                            .unwrap();
+                        if tx.send((row, data1, data2, num_ties, max_num_ties)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -799,17 +801,15 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
                     max_ties = max_row_ties;
                 }
 
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Performing Correlation: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Performing Correlation", progress)?;
+                    old_progress = progress;
                 }
             }
 
             if num_ties > 0 {
-                println!("Warning: There was a maximum of {} ties in a test and as a result p-values \nmay be misleading. You may want to consider using Kendall's Tau instead.", max_ties);
+                context.warning(format!("Warning: There was a maximum of {} ties in a test and as a result p-values \nmay be misleading. You may want to consider using Kendall's Tau instead.", max_ties));
             }
         }
 
@@ -828,12 +828,12 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
         );
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output_val.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -854,17 +854,14 @@ impl WhiteboxTool for ImageCorrelationNeighbourhoodAnalysis {
         let _ = match output_sig.write() {
             Ok(_) => {
                 if verbose {
-                    println!(" ")
+                    context.info(" ")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         // println!("Number of total cells: {}", valid);
@@ -188,8 +188,9 @@ impl WhiteboxTool for ImageRegression {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file1 = String::new();
         let mut input_file2 = String::new();
         let mut output_file = String::new();
@@ -267,11 +268,11 @@ impl WhiteboxTool for ImageRegression {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -316,7 +317,7 @@ impl WhiteboxTool for ImageRegression {
         let start = Instant::now();
 
         if verbose {
-            println!("Loop 1 of 2...");
+            context.info("Loop 1 of 2...");
         }
 
         let mut num_procs = num_cpus::get() as isize;
@@ -353,7 +354,7 @@ impl WhiteboxTool for ImageRegression {
                         }
                     }
                 }
-                tx.send((sum_x, sum_y, sum_xy, sum_xx, sum_yy, n)).unwrap();
+                let _ = tx.send((sum_x, sum_y, sum_xy, sum_xx, sum_yy, n));
             });
         }
 
@@ -382,7 +383,7 @@ impl WhiteboxTool for ImageRegression {
         let x_mean = sum_x / n;
 
         if verbose {
-            println!("Loop 2 of 2...");
+            context.info("Loop 2 of 2...");
         }
         let (tx, rx) = mpsc::channel();
         for tid in 0..num_procs {
@@ -397,8 +398,8 @@ impl WhiteboxTool for ImageRegression {
                 let mut ss_error = 0f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     for col in 0..columns {
-                        x = input1[(row, col)];
-                        y = input2[(row, col)];
+                        x = input1.get_value(row, col);
+                        y = input2.get_value(row, col);
                         if x != nodata1 && y != nodata2 {
                             y_estimate = slope * x + intercept;
                             ss_error += (y - y_estimate) * (y - y_estimate);
@@ -406,7 +407,7 @@ impl WhiteboxTool for ImageRegression {
                         }
                     }
                 }
-                tx.send((ss_error, ss_total)).unwrap();
+                let _ = tx.send((ss_error, ss_total));
             });
         }
 
@@ -458,8 +459,8 @@ impl WhiteboxTool for ImageRegression {
                     for row in (0..rows).filter(|r| r % num_procs == tid) {
                         let mut data: Vec<f64> = vec![nodata1; columns as usize];
                         for col in 0..columns {
-                            x = input1[(row, col)];
-                            y = input2[(row, col)];
+                            x = input1.get_value(row, col);
+                            y = input2.get_value(row, col);
                             if x != nodata1 && y != nodata2 {
                                 y_estimate = slope * x + intercept;
                                 residual = if standardize_residuals {
@@ -470,7 +471,9 @@ impl WhiteboxTool for ImageRegression {
                                 data[col as usize] = residual;
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -481,12 +484,10 @@ impl WhiteboxTool for ImageRegression {
                 let (row, data) = rx.recv().expect("Error receiving data from thread.");
                 output.set_row_data(row, data);
 
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Creating residuals image: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Creating residuals image", progress)?;
+                    old_progress = progress;
                 }
             }
             output.add_metadata_entry(format!(
@@ -499,12 +500,12 @@ impl WhiteboxTool for ImageRegression {
             ));
 
             if verbose {
-                println!("Saving data...")
+                context.info("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
@@ -802,7 +803,7 @@ impl WhiteboxTool for ImageRegression {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("\n{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("\n{}", &format!("Elapsed Time: {}", elapsed_time)));
         }
 
         if verbose {
@@ -830,7 +831,7 @@ impl WhiteboxTool for ImageRegression {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                context.info(format!("Complete! Please see {} for output.", output_file));
             }
         }
 
@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 24/09/2017
-Last Modified: 12/10/2018
+Last Modified: 17/10/2026
 License: MIT
 */
 
@@ -140,8 +140,9 @@ impl WhiteboxTool for KappaIndex {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file1 = String::new();
         let mut input_file2 = String::new();
         let mut output_file = String::new();
@@ -192,11 +193,11 @@ impl WhiteboxTool for KappaIndex {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -218,7 +219,7 @@ impl WhiteboxTool for KappaIndex {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input1 = Raster::new(&input_file1, "r")?;
@@ -260,12 +261,10 @@ impl WhiteboxTool for KappaIndex {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / rows as f64) as i32;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / rows as f64) as i32;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -305,6 +304,32 @@ impl WhiteboxTool for KappaIndex {
             / (n as f64 - expected_frequency as f64);
         overall_accuracy = agreements as f64 / n as f64;
 
+        // the rows of the contingency table are the classification data and its columns
+        // the reference data
+        let classes: Vec<usize> = (0..range as usize).filter(|&a| active_class[a]).collect();
+        let contingency_table: Vec<Vec<usize>> = classes
+            .iter()
+            .map(|&a| classes.iter().map(|&b| error_matrix[a][b]).collect())
+            .collect();
+        let class_statistics: Vec<serde_json::Value> = classes
+            .iter()
+            .map(|&a| {
+                let row_total: usize = classes.iter().map(|&b| error_matrix[a][b]).sum();
+                let col_total: usize = classes.iter().map(|&b| error_matrix[b][a]).sum();
+                serde_json::json!({
+                    "class": a,
+                    "users_accuracy": 100.0 * error_matrix[a][a] as f64 / row_total as f64,
+                    "producers_accuracy": 100.0 * error_matrix[a][a] as f64 / col_total as f64,
+                })
+            })
+            .collect();
+        context.set_result("classes", &classes);
+        context.set_result("contingency_table", contingency_table);
+        context.set_result("class_statistics", class_statistics);
+        context.set_result("num_samples", n);
+        context.set_result("overall_accuracy", overall_accuracy * 100.0);
+        context.set_result("kappa", kappa);
+
         let mut f = File::create(output_file.as_str()).unwrap();
 
         let mut s = "<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Transitional//EN\" \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd\">
@@ -577,15 +602,15 @@ impl WhiteboxTool for KappaIndex {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            context.info(format!("Complete! Please see {} for output.", output_file));
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
+            context.info(format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         Ok(())
@@ -138,8 +138,9 @@ impl WhiteboxTool for KsTestForNormality {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut num_samples = 0usize;
@@ -191,11 +192,11 @@ impl WhiteboxTool for KsTestForNormality {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -259,12 +260,10 @@ impl WhiteboxTool for KsTestForNormality {
                         freq_data[bin_num] += 1;
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / rows as f64) as i32;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / rows as f64) as i32;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -277,12 +276,10 @@ impl WhiteboxTool for KsTestForNormality {
                         total_deviation += (z - mean) * (z - mean);
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / rows as f64) as i32;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / rows as f64) as i32;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
         } else {
@@ -314,12 +311,10 @@ impl WhiteboxTool for KsTestForNormality {
                     cell_index = row * columns + col;
                     sample_cells.push(cell_index);
 
-                    if verbose {
-                        progress = (100.0_f64 * sample_num as f64 / num_samples as f64) as i32;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * sample_num as f64 / num_samples as f64) as i32;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -333,12 +328,10 @@ impl WhiteboxTool for KsTestForNormality {
                 z = input.get_value(row, col);
                 total_deviation += (z - mean) * (z - mean);
 
-                if verbose {
-                    progress = (100.0_f64 * sample_num as f64 / num_samples as f64) as i32;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * sample_num as f64 / num_samples as f64) as i32;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
         }
@@ -487,15 +480,12 @@ impl WhiteboxTool for KsTestForNormality {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            context.info(format!("Complete! Please see {} for output.", output_file));
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
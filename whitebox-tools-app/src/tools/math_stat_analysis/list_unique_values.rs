@@ -2,7 +2,7 @@
 This tool is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 12/04/2018
-Last Modified: 13/10/2018
+Last Modified: 17/10/2026
 License: MIT
 */
 
@@ -148,8 +148,9 @@ impl WhiteboxTool for ListUniqueValues {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut field_name = String::new();
         let mut output_file = String::new();
@@ -195,11 +196,11 @@ impl WhiteboxTool for ListUniqueValues {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -217,7 +218,7 @@ impl WhiteboxTool for ListUniqueValues {
         }
 
         if verbose {
-            println!("Reading vector data...")
+            context.info("Reading vector data...")
         };
 
         let vector_data = Shapefile::read(&input_file)?;
@@ -238,29 +239,42 @@ impl WhiteboxTool for ListUniqueValues {
             *count += 1;
             // }
 
-            if verbose {
-                progress =
-                    (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Reading attribute data: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * record_num as f64 / (vector_data.num_records - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Reading attribute data", progress)?;
+                old_progress = progress;
             }
         }
 
+        let mut categories: Vec<(&String, &i32)> = freq_data.iter().collect();
+        categories.sort();
+        let categories: Vec<serde_json::Value> = categories
+            .iter()
+            .map(|(category, count)| {
+                serde_json::json!({
+                    "category": category,
+                    "frequency": count,
+                })
+            })
+            .collect();
+        context.set_result("field", &field_name);
+        context.set_result("num_categories", freq_data.len());
+        context.set_result("categories", categories);
+
         if freq_data.len() > 250 {
             if verbose {
-                println!("Warning: There are a large number of categories. A continuous attribute variable may have been input incorrectly.");
+                context.warning("Warning: There are a large number of categories. A continuous attribute variable may have been input incorrectly.");
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
+            context.info(format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         let f = File::create(output_file.clone())?;
@@ -358,7 +372,7 @@ impl WhiteboxTool for ListUniqueValues {
                 let _ = output.stdout;
             }
             if verbose {
-                println!("Complete! Please see {} for output.", output_file);
+                context.info(format!("Complete! Please see {} for output.", output_file));
             }
         }
 
@@ -89,7 +89,7 @@ impl PrincipalComponentAnalysis {
         parameters.push(ToolParameter {
             name: "Input Files".to_owned(),
             flags: vec!["-i".to_owned(), "--inputs".to_owned()],
-            description: "Input raster files.".to_owned(),
+            description: "Input raster files, or a single multiband raster.".to_owned(),
             parameter_type: ParameterType::FileList(ParameterFileType::Raster),
             default_value: None,
             optional: false,
@@ -177,8 +177,9 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_files_str = String::new();
         let mut output_html_file = String::new();
         let mut num_comp = 0usize;
@@ -237,11 +238,11 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -255,7 +256,8 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
             cmd = input_files_str.split(",");
             input_files = cmd.collect::<Vec<&str>>();
         }
-        let num_files = input_files.len();
+        let mut inputs = RasterInputs::from_list(&input_files_str, working_directory)?;
+        let num_files = inputs.len();
         if num_files < 3 {
             return Err(Error::new(ErrorKind::InvalidInput,
                 "There is something incorrect about the input files. At least three inputs are required to operate this tool."));
@@ -293,41 +295,29 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
         let mut input_raster: Vec<Raster> = Vec::with_capacity(num_files);
         let mut file_names = vec![];
         if verbose {
-            println!("Calculating image means...");
+            context.info("Calculating image means...");
         }
         for i in 0..num_files {
-            if !input_files[i].trim().is_empty() {
-                // quality control on the image file name.
-                let mut input_file = input_files[i].trim().to_owned();
-                if !input_file.contains(&sep) && !input_file.contains("/") {
-                    input_file = format!("{}{}", working_directory, input_file);
-                }
-
-                // read the image
-                // let input = Raster::new(&input_file, "r")?;
-                input_raster.push(Raster::new(&input_file, "r")?);
-
-                // get the nodata value, the number of valid cells, and the average
-                nodata[i] = input_raster[i].configs.nodata;
-                num_cells[i] = input_raster[i].num_valid_cells() as f64;
-                average[i] = input_raster[i].calculate_mean();
-                file_names.push(input_raster[i].get_short_filename());
-
-                // initialize the rows and column and check that each image has the same dimensions
-                if rows == -1 || columns == -1 {
-                    rows = input_raster[i].configs.rows as isize;
-                    columns = input_raster[i].configs.columns as isize;
-                } else {
-                    if input_raster[i].configs.rows as isize != rows
-                        || input_raster[i].configs.columns as isize != columns
-                    {
-                        return Err(Error::new(ErrorKind::InvalidInput,
-                            "All input images must share the same dimensions (rows and columns) and spatial extent."));
-                    }
-                }
+            // read the image
+            input_raster.push(inputs.take(i)?);
+
+            // get the nodata value, the number of valid cells, and the average
+            nodata[i] = input_raster[i].configs.nodata;
+            num_cells[i] = input_raster[i].num_valid_cells() as f64;
+            average[i] = input_raster[i].calculate_mean();
+            file_names.push(inputs.get_short_name(i));
+
+            // initialize the rows and column and check that each image has the same dimensions
+            if rows == -1 || columns == -1 {
+                rows = input_raster[i].configs.rows as isize;
+                columns = input_raster[i].configs.columns as isize;
             } else {
-                return Err(Error::new(ErrorKind::InvalidInput,
-                    "There is something incorrect about the input files. At least one is an empty string."));
+                if input_raster[i].configs.rows as isize != rows
+                    || input_raster[i].configs.columns as isize != columns
+                {
+                    return Err(Error::new(ErrorKind::InvalidInput,
+                        "All input images must share the same dimensions (rows and columns) and spatial extent."));
+                }
             }
         }
 
@@ -361,12 +351,10 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -609,7 +597,7 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
                 let _ = output.stdout;
             }
 
-            println!("Please see {} for output report.", output_html_file);
+            context.info(format!("Please see {} for output report.", output_html_file));
         }
 
         // Output the component images
@@ -634,12 +622,10 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
                         output.set_value(row, col, z1);
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Saving component image {}: {}%", (a + 1), progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Saving component image {}", (a + 1)), progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -651,7 +637,7 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
             output.add_metadata_entry(format!("Elapsed Time (including I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving component image {}...", (a + 1))
+                context.info(format!("Saving component image {}...", (a + 1)))
             };
             let _ = match output.write() {
                 Ok(_) => (),
@@ -662,10 +648,7 @@ impl WhiteboxTool for PrincipalComponentAnalysis {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (including I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (including I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -133,8 +133,9 @@ impl WhiteboxTool for RasterSummaryStats {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
 
         if args.len() == 0 {
@@ -165,11 +166,11 @@ impl WhiteboxTool for RasterSummaryStats {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -182,7 +183,7 @@ impl WhiteboxTool for RasterSummaryStats {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -214,7 +215,7 @@ impl WhiteboxTool for RasterSummaryStats {
                     let mut minz = f64::INFINITY;
                     let mut maxz = f64::NEG_INFINITY;
                     for col in 0..columns {
-                        z = input[(row, col)];
+                        z = input.get_value(row, col);
                         if z != nodata {
                             n += 1;
                             s += z;
@@ -227,7 +228,9 @@ impl WhiteboxTool for RasterSummaryStats {
                             }
                         }
                     }
-                    tx.send((n, s, sq, minz, maxz)).unwrap();
+                    if tx.send((n, s, sq, minz, maxz)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -248,12 +251,10 @@ impl WhiteboxTool for RasterSummaryStats {
             if e > maxz {
                 maxz = e;
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -263,23 +264,29 @@ impl WhiteboxTool for RasterSummaryStats {
 
         let elapsed_time = get_formatted_elapsed_time(start);
 
-        println!("\nNumber of non-nodata grid cells: {}", num_cells);
-        println!(
-            "Number of nodata grid cells: {}",
-            input.num_cells() - num_cells
-        );
-        println!("Image minimum: {}", minz);
-        println!("Image maximum: {}", maxz);
-        println!("Image range: {}", maxz - minz);
-        println!("Image total: {}", sum);
-        println!("Image average: {}", mean);
-        println!("Image variance: {}", variance);
-        println!("Image standard deviation: {}", std_dev);
+        context.info(format!("\nNumber of non-nodata grid cells: {}", num_cells));
+        context.info(format!("Number of nodata grid cells: {}", input.num_cells() - num_cells));
+        context.info(format!("Image minimum: {}", minz));
+        context.info(format!("Image maximum: {}", maxz));
+        context.info(format!("Image range: {}", maxz - minz));
+        context.info(format!("Image total: {}", sum));
+        context.info(format!("Image average: {}", mean));
+        context.info(format!("Image variance: {}", variance));
+        context.info(format!("Image standard deviation: {}", std_dev));
+        context.set_result("num_valid_cells", num_cells);
+        context.set_result("num_nodata_cells", input.num_cells() - num_cells);
+        context.set_result("minimum", minz);
+        context.set_result("maximum", maxz);
+        context.set_result("range", maxz - minz);
+        context.set_result("total", sum);
+        context.set_result("average", mean);
+        context.set_result("variance", variance);
+        context.set_result("std_dev", std_dev);
         if verbose {
-            println!(
+            context.info(format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         Ok(())
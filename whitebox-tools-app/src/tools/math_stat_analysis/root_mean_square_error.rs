@@ -129,8 +129,9 @@ impl WhiteboxTool for RootMeanSquareError {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut base_file = String::new();
 
@@ -168,11 +169,11 @@ impl WhiteboxTool for RootMeanSquareError {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -188,7 +189,7 @@ impl WhiteboxTool for RootMeanSquareError {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -229,8 +230,8 @@ impl WhiteboxTool for RootMeanSquareError {
                         let mut sq = 0.0f64;
                         let mut data: Vec<f32> = Vec::with_capacity(columns as usize);
                         for col in 0..columns {
-                            z1 = input[(row, col)];
-                            z2 = base_raster[(row, col)];
+                            z1 = input.get_value(row, col);
+                            z2 = base_raster.get_value(row, col);
                             if z1 != nodata && z2 != nodata_base {
                                 z_diff = z2 - z1;
                                 n += 1;
@@ -239,7 +240,9 @@ impl WhiteboxTool for RootMeanSquareError {
                                 data.push(z_diff.abs() as f32);
                             }
                         }
-                        tx.send((n, s, sq, data)).unwrap();
+                        if tx.send((n, s, sq, data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -258,12 +261,10 @@ impl WhiteboxTool for RootMeanSquareError {
                     abs_residuals.push(d[i]);
                 }
 
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -271,21 +272,18 @@ impl WhiteboxTool for RootMeanSquareError {
             let mean_vertical_error = sum / num_cells as f64;
             let ninety_percent_cell = (0.9 * num_cells as f64) as usize;
             if verbose {
-                println!("Sorting the residuals...");
+                context.info("Sorting the residuals...");
             }
             abs_residuals.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
             let le90 = abs_residuals[ninety_percent_cell];
 
-            println!("\nVertical Accuracy Analysis:\n");
-            println!("Comparison File: {}", input_file);
-            println!("Base File: {}", base_file);
-            println!("Mean vertical error: {:.5}", mean_vertical_error);
-            println!("RMSE: {:.5}", rmse);
-            println!(
-                "Accuracy at 95-percent confidence limit: {:.5}",
-                rmse * 1.96f64
-            );
-            println!("LE90: {:.5}", le90);
+            context.info("\nVertical Accuracy Analysis:\n");
+            context.info(format!("Comparison File: {}", input_file));
+            context.info(format!("Base File: {}", base_file));
+            context.info(format!("Mean vertical error: {:.5}", mean_vertical_error));
+            context.info(format!("RMSE: {:.5}", rmse));
+            context.info(format!("Accuracy at 95-percent confidence limit: {:.5}", rmse * 1.96f64));
+            context.info(format!("LE90: {:.5}", le90));
         } else {
             /* The two grids are not of the same resolution. Bilinear resampling will have to be
             carried out to estimate z-values. Base image = source; input image = destination */
@@ -328,7 +326,7 @@ impl WhiteboxTool for RootMeanSquareError {
                         let mut sq = 0.0f64;
                         let mut data: Vec<f32> = Vec::with_capacity(columns as usize);
                         for col in 0..columns {
-                            z1 = input[(row, col)];
+                            z1 = input.get_value(row, col);
                             if z1 != nodata {
                                 x = input.get_x_from_column(col);
                                 src_row = (src_north - y) / src_resolution_y;
@@ -338,10 +336,10 @@ impl WhiteboxTool for RootMeanSquareError {
                                 dx = src_col - src_col.floor();
                                 dy = src_row - src_row.floor();
 
-                                n0 = base_raster[(origin_row, origin_col)];
-                                n1 = base_raster[(origin_row, origin_col + 1)];
-                                n2 = base_raster[(origin_row + 1, origin_col)];
-                                n3 = base_raster[(origin_row + 1, origin_col + 1)];
+                                n0 = base_raster.get_value(origin_row, origin_col);
+                                n1 = base_raster.get_value(origin_row, origin_col + 1);
+                                n2 = base_raster.get_value(origin_row + 1, origin_col);
+                                n3 = base_raster.get_value(origin_row + 1, origin_col + 1);
 
                                 if n0 != nodata_base
                                     && n1 != nodata_base
@@ -393,7 +391,9 @@ impl WhiteboxTool for RootMeanSquareError {
                                 }
                             }
                         }
-                        tx.send((n, s, sq, data)).unwrap();
+                        if tx.send((n, s, sq, data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -412,12 +412,10 @@ impl WhiteboxTool for RootMeanSquareError {
                     abs_residuals.push(d[i]);
                 }
 
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -425,29 +423,26 @@ impl WhiteboxTool for RootMeanSquareError {
             let mean_vertical_error = sum / num_cells as f64;
             let ninety_percent_cell = (0.9 * num_cells as f64) as usize;
             if verbose {
-                println!("Sorting the residuals...");
+                context.info("Sorting the residuals...");
             }
             abs_residuals.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Equal));
             let le90 = abs_residuals[ninety_percent_cell];
 
-            println!("\nVertical Accuracy Analysis:\n");
-            println!("Comparison File: {}", input_file);
-            println!("Base File: {}", base_file);
-            println!("Mean vertical error: {:.5}", mean_vertical_error);
-            println!("RMSE: {:.5}", rmse);
-            println!(
-                "Accuracy at 95-percent confidence limit: {:.5}",
-                rmse * 1.96f64
-            );
-            println!("LE90: {:.5}", le90);
+            context.info("\nVertical Accuracy Analysis:\n");
+            context.info(format!("Comparison File: {}", input_file));
+            context.info(format!("Base File: {}", base_file));
+            context.info(format!("Mean vertical error: {:.5}", mean_vertical_error));
+            context.info(format!("RMSE: {:.5}", rmse));
+            context.info(format!("Accuracy at 95-percent confidence limit: {:.5}", rmse * 1.96f64));
+            context.info(format!("LE90: {:.5}", le90));
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
+            context.info(format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         Ok(())
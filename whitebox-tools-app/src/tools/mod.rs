@@ -6,16 +6,43 @@ pub mod lidar_analysis;
 pub mod math_stat_analysis;
 pub mod stream_network_analysis;
 pub mod terrain_analysis;
+mod batch;
+mod plugin_host;
+mod provenance;
+mod schema;
+mod server;
+mod tool_args;
+mod tool_context;
+mod tool_report;
+mod workflow;
 
+#[doc(hidden)]
+pub use self::plugin_host::{set_plugin_settings, CORE_VERSION, RUSTC_VERSION};
+pub use self::batch::BatchResult;
+pub use self::plugin_host::{
+    PluginSettingsHook, ToolPluginDeclaration, ToolRegistrar, TOOL_PLUGIN_ABI_VERSION,
+};
+pub use self::provenance::read_provenance;
+use self::plugin_host::{plugin_directory, PluginHost};
+pub use self::tool_args::{ToolArgValue, ToolArgs};
+use self::tool_args::validate_command_line;
+pub use self::tool_context::{
+    CancellationToken, LogLevel, LogSink, ProgressSink, StdoutSink, ToolContext, ToolResults,
+};
+pub use self::server::DEFAULT_SERVER_ADDRESS;
+pub use self::tool_report::{ErrorCode, FileReport, ReportError, ReportStatus, ToolReport};
+pub use self::workflow::{Workflow, WorkflowStep};
 use whitebox_common::utils::get_formatted_elapsed_time;
 use serde_json;
-use std::io::{Error, ErrorKind};
+use std::io::{BufRead, BufReader, Error, ErrorKind};
 use std::time::Instant;
 use std::path;
 use std::fs;
 use std::collections::HashMap;
-use std::process::Command;
+use std::process::{Command, Stdio};
 use std::env;
+use std::panic;
+use std::any::Any;
 // use std::io;
 // use std::path::PathBuf;
 
@@ -24,6 +51,7 @@ pub struct ToolManager {
     pub working_dir: String,
     pub verbose: bool,
     tool_names: Vec<String>,
+    plugins: PluginHost,
 }
 
 impl ToolManager {
@@ -53,6 +81,8 @@ impl ToolManager {
         tool_names.push("RasterToVectorPolygons".to_string());
         tool_names.push("ReinitializeAttributeTable".to_string());
         tool_names.push("RemovePolygonHoles".to_string());
+        tool_names.push("ReprojectRaster".to_string());
+        tool_names.push("ReprojectVector".to_string());
         tool_names.push("SetNodataValue".to_string());
         tool_names.push("SinglePartToMultiPart".to_string());
         tool_names.push("VectorLinesToRaster".to_string());
@@ -180,6 +210,7 @@ impl ToolManager {
         tool_names.push("FindNoFlowCells".to_string());
         tool_names.push("FindParallelFlow".to_string());
         tool_names.push("FlattenLakes".to_string());
+        tool_names.push("FloodInundationMap".to_string());
         tool_names.push("FloodOrder".to_string());
         tool_names.push("FlowAccumulationFullWorkflow".to_string());
         tool_names.push("FlowLengthDiff".to_string());
@@ -321,6 +352,7 @@ impl ToolManager {
         tool_names.push("LidarTINGridding".to_string());
         tool_names.push("LidarTophatTransform".to_string());
         tool_names.push("NormalVectors".to_string());
+        tool_names.push("ReprojectLidar".to_string());
         tool_names.push("SelectTilesByPolygon".to_string());
         tool_names.push("ZlidarToLas".to_string());
 
@@ -501,12 +533,29 @@ impl ToolManager {
         tool_names.push("VisibilityIndex".to_string());
         tool_names.push("WetnessIndex".to_string());
 
+        // the tools within the plugin libraries are listed and run like any other tool
+        let plugins = PluginHost::load(&plugin_directory()?, &tool_names);
+        if *verbose_mode {
+            for warning in &plugins.warnings {
+                eprintln!("Warning: {}", warning);
+            }
+        }
+        tool_names.extend(plugins.tool_names());
+
         tool_names.sort();
 
+        // the tools expect the working directory to end with a separator
+        let mut working_dir = working_directory.to_string();
+        let sep = path::MAIN_SEPARATOR.to_string();
+        if !working_dir.is_empty() && !working_dir.ends_with(&sep) && !working_dir.ends_with("/") {
+            working_dir.push_str(&sep);
+        }
+
         let tm = ToolManager {
-            working_dir: working_directory.to_string(),
+            working_dir,
             verbose: *verbose_mode,
             tool_names: tool_names,
+            plugins,
         };
         Ok(tm)
     }
@@ -538,6 +587,8 @@ impl ToolManager {
                 Some(Box::new(data_tools::ReinitializeAttributeTable::new()))
             }
             "removepolygonholes" => Some(Box::new(data_tools::RemovePolygonHoles::new())),
+            "reprojectraster" => Some(Box::new(data_tools::ReprojectRaster::new())),
+            "reprojectvector" => Some(Box::new(data_tools::ReprojectVector::new())),
             "setnodatavalue" => Some(Box::new(data_tools::SetNodataValue::new())),
             "singleparttomultipart" => Some(Box::new(data_tools::SinglePartToMultiPart::new())),
             "vectorlinestoraster" => Some(Box::new(data_tools::VectorLinesToRaster::new())),
@@ -707,6 +758,7 @@ impl ToolManager {
             "findnoflowcells" => Some(Box::new(hydro_analysis::FindNoFlowCells::new())),
             "findparallelflow" => Some(Box::new(hydro_analysis::FindParallelFlow::new())),
             "flattenlakes" => Some(Box::new(hydro_analysis::FlattenLakes::new())),
+            "floodinundationmap" => Some(Box::new(hydro_analysis::FloodInundationMap::new())),
             "floodorder" => Some(Box::new(hydro_analysis::FloodOrder::new())),
             "flowaccumulationfullworkflow" => {
                 Some(Box::new(hydro_analysis::FlowAccumulationFullWorkflow::new()))
@@ -910,6 +962,7 @@ impl ToolManager {
             "lidartingridding" => Some(Box::new(lidar_analysis::LidarTINGridding::new())),
             "lidartophattransform" => Some(Box::new(lidar_analysis::LidarTophatTransform::new())),
             "normalvectors" => Some(Box::new(lidar_analysis::NormalVectors::new())),
+            "reprojectlidar" => Some(Box::new(lidar_analysis::ReprojectLidar::new())),
             "selecttilesbypolygon" => Some(Box::new(lidar_analysis::SelectTilesByPolygon::new())),
             "zlidartolas" => Some(Box::new(lidar_analysis::ZlidarToLas::new())),
 
@@ -1181,15 +1234,13 @@ impl ToolManager {
             "visibilityindex" => Some(Box::new(terrain_analysis::VisibilityIndex::new())),
             "wetnessindex" => Some(Box::new(terrain_analysis::WetnessIndex::new())),
 
-            _ => None,
+            _ => self.plugins.get_tool(tool_name),
         }
     }
 
     fn get_plugin_list(&self) -> Result<HashMap<String, serde_json::Value>, Error> {
         // let exe_path = std::env::current_dir()?.to_str().unwrap_or("No exe path found.").to_string();
-        let mut dir = env::current_exe()?;
-        dir.pop();
-        dir.push("plugins");
+        let dir = plugin_directory()?;
         let plugin_directory = dir.to_str().unwrap_or("No exe path found.").to_string();
         // let plugin_directory = exe_path + &path::MAIN_SEPARATOR.to_string() + "plugins";
         // println!("{}", plugin_directory);
@@ -1218,8 +1269,27 @@ impl ToolManager {
     }
 
     pub fn run_tool(&self, tool_name: String, args: Vec<String>) -> Result<(), Error> {
-        match self.get_tool(tool_name.as_ref()) {
-            Some(tool) => return tool.run(args, &self.working_dir, self.verbose),
+        self.run_tool_in_context(&tool_name, args, &ToolContext::new(self.verbose))
+    }
+
+    /// Runs a tool, sending its progress updates and messages to the sinks of
+    /// `context`, which can also be used to cancel it. The arguments are validated
+    /// against the tool's parameters before it is run, and invalid arguments are
+    /// returned as errors of kind `InvalidInput`.
+    pub fn run_tool_in_context(
+        &self,
+        tool_name: &str,
+        args: Vec<String>,
+        context: &ToolContext,
+    ) -> Result<(), Error> {
+        for warning in self.validate_args(tool_name, &args)? {
+            context.warning(warning);
+        }
+        match self.get_tool(tool_name) {
+            Some(tool) => {
+                let _provenance = self.begin_provenance(tool.as_ref(), &args)?;
+                tool.run(args, &self.working_dir, context)
+            }
             None => {
                 // Check the 'plugins' folder to see if the tool is in the Enterprise plugins.
                 // if yes, then run it.
@@ -1231,7 +1301,8 @@ impl ToolManager {
                     } else {
                         ""
                     };
-                    // environment flags should be consumed by plugins using the settings.json file instead.
+                    // environment flags aren't passed on as arguments; plugins receive the
+                    // settings of the run through environment variables instead.
                     let mut args2 = vec![];
                     for a in 0..args.len() {
                         if args[a] != "-v" && args[a] != "--compress_rasters" {
@@ -1254,14 +1325,37 @@ impl ToolManager {
                     let mut child = Command::new(exe)
                         .arg("run")
                         .args(&args2)
-                        .spawn()
-                        .expect("failed to execute process");
+                        .envs(whitebox_common::configs::get_configs()?.to_env_vars())
+                        .stdout(Stdio::piped())
+                        .spawn()?;
+
+                    // pass the plugin's output on to the context, stopping the plugin
+                    // if the tool is cancelled
+                    if let Some(stdout) = child.stdout.take() {
+                        for line in BufReader::new(stdout).lines() {
+                            let line = line?;
+                            let result = match parse_progress(&line) {
+                                Some((label, percent)) => context.progress(label, percent),
+                                None => {
+                                    context.info(&line);
+                                    context.check_cancelled()
+                                }
+                            };
+                            if let Err(e) = result {
+                                let _ = child.kill();
+                                let _ = child.wait();
+                                return Err(e);
+                            }
+                        }
+                    }
 
-                    let ecode = child.wait()
-                        .expect("failed to wait on child");
+                    let ecode = child.wait()?;
                     
                     if !ecode.success() {
-                        println!("Failure to run plugin subprocess.");
+                        return Err(Error::other(format!(
+                            "Failure to run plugin subprocess ({}).",
+                            ecode
+                        )));
                     }
                 } else {
                     return Err(Error::new(
@@ -1274,6 +1368,68 @@ impl ToolManager {
         }
     }
 
+    /// Runs a tool with typed arguments, e.g. from Rust code that uses WhiteboxTools
+    /// as a library. The arguments are validated against the tool's parameters before
+    /// it is run, and errors, including panics within the tool, are returned rather
+    /// than ending the process.
+    pub fn run_tool_with(&self, tool_name: &str, args: ToolArgs) -> Result<(), Error> {
+        self.run_tool_with_context(tool_name, args, &ToolContext::new(self.verbose))
+    }
+
+    /// Runs a tool with typed arguments in the given context; see `run_tool_with` and
+    /// `run_tool_in_context`.
+    pub fn run_tool_with_context(
+        &self,
+        tool_name: &str,
+        args: ToolArgs,
+        context: &ToolContext,
+    ) -> Result<(), Error> {
+        let parameters = self.get_tool_parameter_list(tool_name)?;
+        let args = args.to_command_line(tool_name, &parameters, &self.working_dir)?;
+        match panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            self.run_tool_in_context(tool_name, args, context)
+        })) {
+            Ok(result) => result,
+            Err(e) => Err(Error::other(format!(
+                "{} failed: {}",
+                tool_name,
+                panic_message(e.as_ref())
+            ))),
+        }
+    }
+
+    /// Returns the parameters of a tool or plugin, as described by its JSON parameters.
+    fn get_tool_parameter_list(&self, tool_name: &str) -> Result<Vec<ToolParameter>, Error> {
+        let v = self.get_tool_parameter_json(tool_name)?;
+        Ok(serde_json::from_value(v["parameters"].clone())?)
+    }
+
+    // The parameters of a tool in the json form printed by --toolparameters, which for
+    // plugins also holds the plugin's other details.
+    fn get_tool_parameter_json(&self, tool_name: &str) -> Result<serde_json::Value, Error> {
+        match self.get_tool(tool_name) {
+            Some(tool) => Ok(serde_json::from_str(&tool.get_tool_parameters())?),
+            None => match self.get_plugin_list()?.remove(&tool_name.to_lowercase()) {
+                Some(plugin_data) => Ok(plugin_data),
+                None => Err(Error::new(
+                    ErrorKind::NotFound,
+                    format!("Unrecognized tool name {}.", tool_name),
+                )),
+            },
+        }
+    }
+
+    // Checks the command-line arguments of a tool, returning any warnings; see
+    // `validate_command_line`.
+    fn validate_args(&self, tool_name: &str, args: &[String]) -> Result<Vec<String>, Error> {
+        let parameters = self.get_tool_parameter_list(tool_name)?;
+        let tool_name = match self.get_tool(tool_name) {
+            Some(tool) => tool.get_tool_name(),
+            None => tool_name.to_string(),
+        };
+        validate_command_line(&tool_name, &parameters, args, &self.working_dir)
+    }
+
     pub fn tool_help(&self, tool_name: String) -> Result<(), Error> {
         if !tool_name.is_empty() {
             match self.get_tool(tool_name.as_ref()) {
@@ -1566,6 +1722,8 @@ Example usage:
     }
 }
 
+// Changing this trait changes the interface of the tool plugin libraries, and requires
+// TOOL_PLUGIN_ABI_VERSION to be incremented.
 pub trait WhiteboxTool {
     fn get_tool_name(&self) -> String;
     fn get_tool_description(&self) -> String;
@@ -1577,10 +1735,29 @@ pub trait WhiteboxTool {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error>;
 }
 
+/// Splits a line of tool output, such as "Progress: 45%", into its label and
+/// percentage.
+fn parse_progress(line: &str) -> Option<(&str, usize)> {
+    let (label, value) = line.trim_end().rsplit_once(": ")?;
+    let percent = value.strip_suffix('%')?.parse::<usize>().ok()?;
+    Some((label, percent))
+}
+
+/// Returns the message of a panic that was caught while running a tool.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown error".to_string()
+    }
+}
+
 fn get_help<'a>(wt: Box<dyn WhiteboxTool + 'a>) -> String {
     let tool_name = wt.get_tool_name();
     let description = wt.get_tool_description();
@@ -1638,7 +1815,7 @@ fn get_name_and_description<'a>(wt: Box<dyn WhiteboxTool + 'a>) -> (String, Stri
 }
 
 #[derive(Serialize, Deserialize, Debug)]
-struct ToolParameter {
+pub(crate) struct ToolParameter {
     name: String,
     flags: Vec<String>,
     description: String,
@@ -1705,3 +1882,14 @@ enum AttributeType {
     Boolean,
     Date,
 }
+
+/// Returns the path of the file `name` in the temporary directory, with a prefix that keeps
+/// the files of the tests of different tools apart.
+#[cfg(test)]
+pub(crate) fn temp_file(prefix: &str, name: &str) -> String {
+    env::temp_dir()
+        .join(format!("wbt_{}_{}", prefix, name))
+        .to_str()
+        .unwrap()
+        .to_string()
+}
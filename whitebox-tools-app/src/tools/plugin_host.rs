@@ -0,0 +1,422 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: Besides the executable plugins, which are described by a JSON file and run as
+separate processes, the 'plugins' directory may contain dynamic libraries (.so, .dylib
+or .dll files) of tools. Such a library is a cdylib crate that depends on this crate,
+implements WhiteboxTool for each of its tools, and exports them with the
+export_tool_plugin! macro, e.g.:
+
+    whitebox_tools::export_tool_plugin!(register);
+
+    fn register(registrar: &mut dyn ToolRegistrar) {
+        registrar.register_tool(Box::new(MyTool::new()));
+    }
+
+Rust has no stable ABI, so a library is only loaded if it was built against the same
+version of the tool plugin interface (TOOL_PLUGIN_ABI_VERSION), and with the same
+version of rustc and of whitebox_common, as the host; otherwise it is skipped. The
+example tool plugin in the examples directory shows a complete library. The
+tools of a library are run in-process, like the built-in tools, but a library links
+its own copies of the statics of whitebox_common and of the raster, vector and LiDAR
+crates. Two consequences follow:
+
+ - The settings given on the command line, and those set for the jobs of a batch,
+   are held by the host's statics. Before each run of a plugin tool, the host passes
+   the settings of the run to the library through the settings hook that
+   export_tool_plugin! also exports, and the library makes them the settings of the
+   calling thread until the run ends. As with the built-in tools, threads spawned by
+   the tool see the library's settings without them, i.e. the settings file and
+   environment variables.
+ - The tools have their own copies of the in-memory (mem://) file stores, and so
+   can't read or write the in-memory files of the host.
+
+A library stays loaded for as long as any of its tools exist.
+*/
+
+use super::{ToolContext, WhiteboxTool};
+use libloading::Library;
+use std::env;
+use std::fs;
+use std::io::Error;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use whitebox_common::configs;
+use whitebox_common::plugins::PluginDeclaration;
+#[doc(hidden)]
+pub use whitebox_common::plugins::{CORE_VERSION, RUSTC_VERSION};
+
+/// The version of the interface through which the host calls plugin libraries of tools,
+/// i.e. of the `WhiteboxTool` and `ToolRegistrar` traits, of `ToolContext`, and of the
+/// `PluginSettingsHook`, which plugins are built against. It must be incremented whenever
+/// any of them changes, since the host would otherwise call the tools of older libraries
+/// through the wrong ABI.
+pub const TOOL_PLUGIN_ABI_VERSION: u32 = 2;
+
+/// The registrar that the `register` function of a plugin library adds its tools to.
+pub trait ToolRegistrar {
+    fn register_tool(&mut self, tool: Box<dyn WhiteboxTool + Send + Sync>);
+}
+
+/// The declaration exported by a plugin library of tools.
+pub type ToolPluginDeclaration = PluginDeclaration<dyn ToolRegistrar>;
+
+/// The function, exported by a plugin library of tools, through which the host passes it
+/// the settings of a run, e.g. `("max_procs", "4")`. The settings apply to the tools that
+/// the library runs on the calling thread, until the hook is called again.
+pub type PluginSettingsHook = fn(Vec<(String, String)>) -> Result<(), Error>;
+
+/// The settings hook of plugin libraries, which sets the library's own copy of the settings
+/// of the calling thread.
+#[doc(hidden)]
+pub fn set_plugin_settings(settings: Vec<(String, String)>) -> Result<(), Error> {
+    configs::set_thread_overrides(settings)
+}
+
+/// Exports the declaration of a plugin library of tools. The argument is the library's
+/// registration function, a `fn(&mut dyn ToolRegistrar)`.
+#[macro_export]
+macro_rules! export_tool_plugin {
+    ($register:expr) => {
+        #[doc(hidden)]
+        #[no_mangle]
+        pub static tool_plugin_declaration: $crate::tools::ToolPluginDeclaration =
+            $crate::tools::ToolPluginDeclaration {
+                abi_version: $crate::tools::TOOL_PLUGIN_ABI_VERSION,
+                rustc_version: $crate::tools::RUSTC_VERSION,
+                core_version: $crate::tools::CORE_VERSION,
+                register: {
+                    unsafe extern "C" fn __register_tools(
+                        registrar: &mut (dyn $crate::tools::ToolRegistrar + 'static),
+                    ) {
+                        $register(registrar)
+                    }
+                    __register_tools
+                },
+            };
+
+        #[doc(hidden)]
+        #[no_mangle]
+        pub static tool_plugin_settings_hook: $crate::tools::PluginSettingsHook =
+            $crate::tools::set_plugin_settings;
+    };
+}
+
+/// Returns the directory of plugins, which is next to the executable.
+pub(crate) fn plugin_directory() -> Result<PathBuf, Error> {
+    let mut dir = env::current_exe()?;
+    dir.pop();
+    dir.push("plugins");
+    Ok(dir)
+}
+
+/// The tools that were loaded from the plugin libraries.
+#[derive(Default)]
+pub(crate) struct PluginHost {
+    tools: Vec<PluginTool>,
+    /// Descriptions of the libraries and tools that could not be loaded.
+    pub warnings: Vec<String>,
+}
+
+impl PluginHost {
+    /// Loads the tools of each library within the directory, skipping those with the same
+    /// names as `existing_tools` or as a tool that was loaded earlier.
+    pub fn load(directory: &Path, existing_tools: &[String]) -> PluginHost {
+        let mut host = PluginHost::default();
+        let mut files: Vec<PathBuf> = match fs::read_dir(directory) {
+            Ok(entries) => entries
+                .filter_map(|e| e.ok().map(|e| e.path()))
+                .filter(|p| {
+                    p.extension()
+                        .is_some_and(|e| e.eq_ignore_ascii_case(env::consts::DLL_EXTENSION))
+                })
+                .collect(),
+            Err(_) => return host,
+        };
+        files.sort();
+        for file in files {
+            match load_library(&file) {
+                Ok((library, settings_hook, tools)) => {
+                    let library = Arc::new(library);
+                    for tool in tools {
+                        let name = tool.get_tool_name();
+                        let key = tool_key(&name);
+                        if existing_tools.iter().any(|t| tool_key(t) == key)
+                            || host.tools.iter().any(|t| tool_key(&t.name) == key)
+                        {
+                            host.warnings.push(format!(
+                                "The plugin tool {} in {} was not loaded because a tool with the same name already exists.",
+                                name,
+                                file.display()
+                            ));
+                            continue;
+                        }
+                        host.tools.push(PluginTool {
+                            name,
+                            tool: Arc::from(tool),
+                            settings_hook,
+                            _library: library.clone(),
+                        });
+                    }
+                }
+                Err(msg) => host.warnings.push(format!(
+                    "The plugin {} was not loaded: {}",
+                    file.display(),
+                    msg
+                )),
+            }
+        }
+        host
+    }
+
+    /// The names of the loaded tools.
+    pub fn tool_names(&self) -> Vec<String> {
+        self.tools.iter().map(|t| t.name.clone()).collect()
+    }
+
+    pub fn get_tool(&self, tool_name: &str) -> Option<Box<dyn WhiteboxTool + 'static>> {
+        let key = tool_key(tool_name);
+        self.tools
+            .iter()
+            .find(|t| tool_key(&t.name) == key)
+            .map(|t| Box::new(t.clone()) as Box<dyn WhiteboxTool>)
+    }
+
+    pub fn contains(&self, tool_name: &str) -> bool {
+        let key = tool_key(tool_name);
+        self.tools.iter().any(|t| tool_key(&t.name) == key)
+    }
+}
+
+// Tools are matched the same way as the built-in tools, ignoring case and underscores.
+fn tool_key(tool_name: &str) -> String {
+    tool_name.to_lowercase().replace("_", "")
+}
+
+struct Registrar {
+    tools: Vec<Box<dyn WhiteboxTool + Send + Sync>>,
+}
+
+impl ToolRegistrar for Registrar {
+    fn register_tool(&mut self, tool: Box<dyn WhiteboxTool + Send + Sync>) {
+        self.tools.push(tool);
+    }
+}
+
+type LoadedLibrary = (
+    Library,
+    PluginSettingsHook,
+    Vec<Box<dyn WhiteboxTool + Send + Sync>>,
+);
+
+fn load_library(file: &Path) -> Result<LoadedLibrary, String> {
+    // Loading a library runs its initialization code, and the declaration is only
+    // trusted once its versions have been checked against those of the host. The ABI
+    // version is checked first, since the layout of the rest of the declaration may
+    // differ between versions.
+    unsafe {
+        let library = Library::new(file).map_err(|e| e.to_string())?;
+        let declaration = *library
+            .get::<*const ToolPluginDeclaration>(b"tool_plugin_declaration\0")
+            .map_err(|_| "it does not export a tool_plugin_declaration.".to_string())?;
+        let abi_version = (*declaration).abi_version;
+        if abi_version != TOOL_PLUGIN_ABI_VERSION {
+            return Err(format!(
+                "it was built against version {} of the tool plugin interface, but these tools use version {}.",
+                abi_version, TOOL_PLUGIN_ABI_VERSION
+            ));
+        }
+        let declaration = *declaration;
+        if !declaration.is_compatible(TOOL_PLUGIN_ABI_VERSION) {
+            return Err(format!(
+                "it was built with rustc {} and whitebox_common {}, but these tools were built with rustc {} and whitebox_common {}.",
+                declaration.rustc_version, declaration.core_version, RUSTC_VERSION, CORE_VERSION
+            ));
+        }
+        let settings_hook = *library
+            .get::<*const PluginSettingsHook>(b"tool_plugin_settings_hook\0")
+            .map_err(|_| "it does not export a tool_plugin_settings_hook.".to_string())?;
+        let settings_hook = *settings_hook;
+        let mut registrar = Registrar { tools: vec![] };
+        (declaration.register)(&mut registrar);
+        Ok((library, settings_hook, registrar.tools))
+    }
+}
+
+// A tool from a plugin library, which keeps the library loaded while the tool exists.
+#[derive(Clone)]
+struct PluginTool {
+    name: String,
+    // declared before the library so that it is dropped first
+    tool: Arc<dyn WhiteboxTool + Send + Sync>,
+    settings_hook: PluginSettingsHook,
+    _library: Arc<Library>,
+}
+
+impl WhiteboxTool for PluginTool {
+    fn get_tool_name(&self) -> String {
+        self.tool.get_tool_name()
+    }
+
+    fn get_tool_description(&self) -> String {
+        self.tool.get_tool_description()
+    }
+
+    fn get_tool_parameters(&self) -> String {
+        self.tool.get_tool_parameters()
+    }
+
+    fn get_example_usage(&self) -> String {
+        self.tool.get_example_usage()
+    }
+
+    fn get_toolbox(&self) -> String {
+        self.tool.get_toolbox()
+    }
+
+    fn get_source_file(&self) -> String {
+        self.tool.get_source_file()
+    }
+
+    fn run(
+        &self,
+        args: Vec<String>,
+        working_directory: &str,
+        context: &ToolContext,
+    ) -> Result<(), Error> {
+        // the library can't see the settings held by the host's statics, e.g. those given
+        // on the command line, so the tool is given the settings of the run explicitly
+        (self.settings_hook)(configs::get_configs()?.to_settings())?;
+        let result = self.tool.run(args, working_directory, context);
+        (self.settings_hook)(vec![])?;
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PluginHost;
+    use crate::tools::{CancellationToken, ToolContext};
+    use std::env;
+    use std::fs;
+    use std::io::ErrorKind;
+    use std::path::PathBuf;
+    use whitebox_common::configs;
+    use whitebox_raster::*;
+
+    // The example plugin (examples/example_tool_plugin.rs) is built by `cargo test`, along
+    // with the other targets of the crate, into the examples directory next to the deps
+    // directory that holds the test executable. Its file name may include a hash, and
+    // the most recently built library is used.
+    fn example_plugin() -> Option<PathBuf> {
+        let mut dir = env::current_exe().unwrap();
+        dir.pop();
+        if dir.ends_with("deps") {
+            dir.pop();
+        }
+        let prefix = format!("{}example_tool_plugin", env::consts::DLL_PREFIX);
+        fs::read_dir(dir.join("examples"))
+            .ok()?
+            .filter_map(|e| e.ok())
+            .filter(|e| {
+                let name = e.file_name().to_string_lossy().to_string();
+                name.starts_with(&prefix) && name.ends_with(env::consts::DLL_SUFFIX)
+            })
+            .max_by_key(|e| e.metadata().and_then(|m| m.modified()).ok())
+            .map(|e| e.path())
+    }
+
+    #[test]
+    fn test_load_example_plugin() {
+        let library = example_plugin()
+            .expect("the example plugin was not found; build it with `cargo build --example example_tool_plugin`");
+        let dir = env::temp_dir().join("wbt_plugin_host_test");
+        fs::create_dir_all(&dir).unwrap();
+        fs::copy(
+            &library,
+            dir.join(format!(
+                "{}example_tool_plugin{}",
+                env::consts::DLL_PREFIX,
+                env::consts::DLL_SUFFIX
+            )),
+        )
+        .unwrap();
+
+        let host = PluginHost::load(&dir, &[]);
+        assert!(host.warnings.is_empty(), "{:?}", host.warnings);
+        assert_eq!(host.tool_names(), vec!["CountValidCells".to_string()]);
+        assert!(host.contains("count_valid_cells"));
+
+        // a plugin tool doesn't replace an existing tool of the same name
+        let shadowed = PluginHost::load(&dir, &["CountValidCells".to_string()]);
+        assert!(shadowed.tool_names().is_empty());
+        assert_eq!(shadowed.warnings.len(), 1);
+
+        let input_file = env::temp_dir()
+            .join("wbt_plugin_host_test_input.tif")
+            .to_str()
+            .unwrap()
+            .to_string();
+        let configs = RasterConfigs {
+            rows: 6,
+            columns: 5,
+            north: 6.0,
+            south: 0.0,
+            east: 5.0,
+            west: 0.0,
+            resolution_x: 1.0,
+            resolution_y: 1.0,
+            nodata: -32768.0,
+            data_type: DataType::F32,
+            photometric_interp: PhotometricInterpretation::Continuous,
+            ..Default::default()
+        };
+        let mut input = Raster::initialize_using_config(&input_file, &configs);
+        for row in 0..6 {
+            for col in 0..5 {
+                if row != col {
+                    input.set_value(row, col, (row * col) as f64);
+                }
+            }
+        }
+        input.write().unwrap();
+
+        // the tool is called through the plugin's vtable with the host's context
+        let tool = host.get_tool("CountValidCells").unwrap();
+        let args = vec![format!("-i={}", input_file)];
+        let context = ToolContext::new(false);
+        tool.run(args.clone(), "", &context).unwrap();
+        assert_eq!(
+            context.results().get("num_valid_cells"),
+            Some(serde_json::json!(25))
+        );
+
+        // the library has its own copy of the settings statics, and is passed the settings
+        // of the run, e.g. those given on the command line or for the jobs of a batch
+        configs::set_thread_overrides(vec![("max_procs".to_string(), "3".to_string())]).unwrap();
+        let context = ToolContext::new(false);
+        tool.run(args.clone(), "", &context).unwrap();
+        configs::set_thread_overrides(vec![]).unwrap();
+        assert_eq!(
+            context.results().get("num_procs"),
+            Some(serde_json::json!(3))
+        );
+        assert_eq!(
+            context.results().get("num_valid_cells"),
+            Some(serde_json::json!(25))
+        );
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let context = ToolContext::new(false).with_cancellation_token(token);
+        let err = tool.run(args, "", &context).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Interrupted);
+
+        let _ = fs::remove_file(input_file);
+        let _ = fs::remove_dir_all(dir);
+    }
+}
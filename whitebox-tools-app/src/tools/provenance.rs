@@ -0,0 +1,173 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: Each run of a built-in tool records its provenance, i.e. the tool, its version,
+the full set of parameters (including the defaults of those that weren't given) and
+the modification time of each input file, along with its hash if the
+hash_provenance_inputs setting is on, and the raster, vector and LiDAR
+writers embed the record in every output of the run. Because an input of one run is
+often the output of an earlier one, the records of a file and of its inputs, and of
+their inputs in turn, form its lineage, which is printed by the --provenance command.
+Plugins, whether they run as separate processes or are loaded from libraries, have
+their own copies of the libraries that write files, and so their outputs carry no
+provenance.
+*/
+
+use super::tool_args::parameter_values;
+use super::{ParameterType, ToolManager, ToolParameter, WhiteboxTool};
+use std::collections::HashSet;
+use std::io::Error;
+use std::path;
+use std::path::Path;
+use whitebox_common::configs::get_configs;
+use whitebox_common::memory_store::is_memory_file;
+use whitebox_common::provenance::{
+    begin_tool_run, dataset_modified, sha256_dataset, Provenance, ProvenanceInput,
+    ProvenanceScope,
+};
+
+/// Reads the provenance record embedded in a raster, vector or LiDAR file, i.e. the
+/// record of the tool run that wrote it. Files written outside of a tool run, and those
+/// in formats that can't hold a record, have none.
+pub fn read_provenance(file_name: &str) -> Result<Option<Provenance>, Error> {
+    let extension = Path::new(file_name)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    match extension.as_str() {
+        "las" | "laz" | "zlidar" | "zip" => whitebox_lidar::read_provenance(file_name),
+        "shp" | "geojson" | "json" | "fgb" | "gpkg" => whitebox_vector::read_provenance(file_name),
+        _ => whitebox_raster::read_provenance(file_name),
+    }
+}
+
+impl ToolManager {
+    /// Prints the lineage of a file: the tool run that wrote it, and in turn the runs that
+    /// wrote each of that run's inputs. Inputs that have changed since they were used are
+    /// flagged.
+    pub fn provenance(&self, file_name: String) -> Result<(), Error> {
+        let file_name = self.resolve_file_name(file_name.trim());
+        let provenance = read_provenance(&file_name)?;
+        println!("{}", file_name);
+        let mut visited = HashSet::new();
+        visited.insert(file_name.clone());
+        match provenance {
+            Some(p) => print_lineage(&p, 1, &mut visited),
+            None => println!("  No provenance record; the file was not written by a tool."),
+        }
+        Ok(())
+    }
+
+    // Starts a run of `tool` with `args`, whose provenance is current until the returned
+    // scope is dropped. The inputs are only hashed if the hash_provenance_inputs setting
+    // is on.
+    pub(super) fn begin_provenance(
+        &self,
+        tool: &dyn WhiteboxTool,
+        args: &[String],
+    ) -> Result<ProvenanceScope, Error> {
+        let hash_inputs = get_configs()?.hash_provenance_inputs;
+        let mut provenance = Provenance::new(&tool.get_tool_name(), env!("CARGO_PKG_VERSION"));
+        let parameters: Vec<ToolParameter> =
+            serde_json::from_str::<serde_json::Value>(&tool.get_tool_parameters())
+                .ok()
+                .and_then(|v| serde_json::from_value(v["parameters"].clone()).ok())
+                .unwrap_or_default();
+
+        let values = parameter_values(&parameters, args);
+
+        for (parameter, value) in parameters.iter().zip(values) {
+            let value = match value.or_else(|| parameter.default_value.clone()) {
+                Some(v) => v,
+                None => continue,
+            };
+            let files: Vec<&str> = match &parameter.parameter_type {
+                ParameterType::ExistingFile(_) => value.split(';').collect(),
+                ParameterType::ExistingFileOrFloat(_) if value.trim().parse::<f64>().is_err() => {
+                    vec![value.as_str()]
+                }
+                ParameterType::FileList(_) => value.split([';', ',']).collect(),
+                _ => vec![],
+            };
+            for file in files.iter().map(|f| f.trim()).filter(|f| !f.is_empty()) {
+                provenance
+                    .inputs
+                    .push(ProvenanceInput::new(&self.resolve_file_name(file), hash_inputs));
+            }
+            let name = parameter
+                .flags
+                .iter()
+                .max_by_key(|f| f.len())
+                .map(|f| f.trim_start_matches('-').to_string())
+                .unwrap_or_else(|| parameter.name.clone());
+            provenance.parameters.insert(name, value);
+        }
+
+        Ok(begin_tool_run(provenance))
+    }
+
+    // File names without a directory are within the working directory, as they are in
+    // the tools.
+    pub(super) fn resolve_file_name(&self, file_name: &str) -> String {
+        if is_memory_file(file_name)
+            || file_name.contains(path::MAIN_SEPARATOR)
+            || file_name.contains('/')
+        {
+            file_name.to_string()
+        } else {
+            format!("{}{}", self.working_dir, file_name)
+        }
+    }
+}
+
+// Inputs that were hashed are compared by their hashes, and others by their modification
+// times.
+fn has_changed(input: &ProvenanceInput) -> bool {
+    if !input.sha256.is_empty() {
+        sha256_dataset(&input.file).map_or(true, |h| h != input.sha256)
+    } else if !input.modified.is_empty() {
+        dataset_modified(&input.file).is_none_or(|m| m != input.modified)
+    } else {
+        false
+    }
+}
+
+fn print_lineage(provenance: &Provenance, depth: usize, visited: &mut HashSet<String>) {
+    let indent = "  ".repeat(depth);
+    println!(
+        "{}{} (WhiteboxTools v{}), run {}",
+        indent, provenance.tool, provenance.version, provenance.created
+    );
+    for (name, value) in &provenance.parameters {
+        println!("{}  --{}={}", indent, name, value);
+    }
+    for input in &provenance.inputs {
+        let status = if is_memory_file(&input.file) {
+            " (in memory)"
+        } else if !Path::new(&input.file).exists() {
+            " (no longer exists)"
+        } else if has_changed(input) {
+            " (changed since it was used)"
+        } else {
+            ""
+        };
+        println!("{}  input {}{}", indent, input.file, status);
+        if !visited.insert(input.file.clone()) {
+            println!("{}    (lineage shown above)", indent);
+            continue;
+        }
+        match read_provenance(&input.file) {
+            Ok(Some(p)) => print_lineage(&p, depth + 2, visited),
+            Ok(None) => println!("{}    No provenance record.", indent),
+            Err(e) => println!(
+                "{}    The provenance record could not be read: {}",
+                indent, e
+            ),
+        }
+    }
+}
@@ -0,0 +1,180 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: The parameters that each tool describes in get_tool_parameters are exported as
+a JSON Schema (draft 2020-12) of the tool's arguments, and the schemas of all of the
+tools are collected into an OpenAPI 3.1 document, along with the endpoints of the local
+service (--serve), so that clients can build forms and check arguments without knowing
+the tools in advance. The arguments are an object
+keyed by the long flag of each parameter without its leading dashes, as they are in
+ToolArgs, e.g. {"dem": "dem.tif", "output": "slope.tif", "units": "percent"}. The
+parameter types that JSON Schema can't express, e.g. that a file must be a polygon
+vector, are given by the x-whitebox-parameter-type keyword, which holds the type as
+it is printed by --toolparameters, and are checked by the validator that is run
+before each tool.
+*/
+
+use super::server::openapi_paths;
+use super::tool_args::{flag_name, is_required};
+use super::{ParameterType, ToolManager, ToolParameter};
+use serde_json::{json, Map, Value};
+use std::io::{Error, ErrorKind};
+
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+impl ToolManager {
+    /// Returns the JSON Schema of the arguments of a tool.
+    pub fn tool_schema(&self, tool_name: &str) -> Result<Value, Error> {
+        let mut schema = self.arguments_schema(tool_name)?;
+        schema.insert("$schema".to_string(), json!(JSON_SCHEMA_DIALECT));
+        Ok(Value::Object(schema))
+    }
+
+    /// Returns an OpenAPI document that describes the endpoints of the local service and
+    /// the arguments of all of the tools, each of which has a schema, named after the
+    /// tool, among its components.
+    pub fn schema(&self) -> Result<Value, Error> {
+        let mut tool_names = self.tool_names.clone();
+        for plugin_data in self.get_plugin_list()?.values() {
+            if let Some(name) = plugin_data["tool_name"].as_str() {
+                tool_names.push(name.to_string());
+            }
+        }
+        tool_names.sort();
+        let mut schemas = Map::new();
+        for tool_name in &tool_names {
+            let schema = self.arguments_schema(tool_name)?;
+            let title = schema["title"].as_str().unwrap_or(tool_name).to_string();
+            schemas.insert(title, Value::Object(schema));
+        }
+        schemas.insert(
+            "Error".to_string(),
+            json!({
+                "type": "object",
+                "properties": {
+                    "error": {
+                        "type": "object",
+                        "properties": {
+                            "code": { "type": "string" },
+                            "message": { "type": "string" },
+                        },
+                    },
+                },
+            }),
+        );
+        Ok(json!({
+            "openapi": "3.1.0",
+            "info": {
+                "title": "WhiteboxTools",
+                "version": env!("CARGO_PKG_VERSION"),
+                "description": "The local service of the WhiteboxTools geospatial analysis tools.",
+            },
+            "jsonSchemaDialect": JSON_SCHEMA_DIALECT,
+            "paths": openapi_paths(),
+            "components": { "schemas": schemas },
+        }))
+    }
+
+    fn arguments_schema(&self, tool_name: &str) -> Result<Map<String, Value>, Error> {
+        let (title, description, toolbox) = match self.get_tool(tool_name) {
+            Some(tool) => (
+                tool.get_tool_name(),
+                tool.get_tool_description(),
+                tool.get_toolbox(),
+            ),
+            None => {
+                let plugin_list = self.get_plugin_list()?;
+                let plugin_data = plugin_list.get(&tool_name.to_lowercase()).ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::NotFound,
+                        format!("Unrecognized tool name {}.", tool_name),
+                    )
+                })?;
+                let field = |key: &str| plugin_data[key].as_str().unwrap_or("").to_string();
+                (
+                    field("tool_name"),
+                    field("short_description"),
+                    field("toolbox"),
+                )
+            }
+        };
+        let parameters = self.get_tool_parameter_list(tool_name)?;
+        let mut properties = Map::new();
+        for parameter in &parameters {
+            properties.insert(flag_name(parameter), parameter_schema(parameter));
+        }
+        let required: Vec<String> = parameters
+            .iter()
+            .filter(|p| is_required(p))
+            .map(flag_name)
+            .collect();
+
+        let mut schema = Map::new();
+        schema.insert("title".to_string(), json!(title));
+        schema.insert("description".to_string(), json!(description));
+        schema.insert("type".to_string(), json!("object"));
+        schema.insert("properties".to_string(), Value::Object(properties));
+        schema.insert("required".to_string(), json!(required));
+        schema.insert("additionalProperties".to_string(), json!(false));
+        schema.insert("x-whitebox-toolbox".to_string(), json!(toolbox));
+        Ok(schema)
+    }
+}
+
+fn parameter_schema(parameter: &ToolParameter) -> Value {
+    let mut schema = match &parameter.parameter_type {
+        ParameterType::Boolean => json!({ "type": "boolean" }),
+        ParameterType::Integer => json!({ "type": "integer" }),
+        ParameterType::Float => json!({ "type": "number" }),
+        ParameterType::StringOrNumber | ParameterType::ExistingFileOrFloat(_) => {
+            json!({ "type": ["string", "number"] })
+        }
+        // lists are given either as arrays or as strings of separated values
+        ParameterType::StringList | ParameterType::FileList(_) => {
+            json!({ "type": ["string", "array"], "items": { "type": "string" } })
+        }
+        ParameterType::OptionList(options) => json!({ "type": "string", "enum": options }),
+        ParameterType::String
+        | ParameterType::VectorAttributeField(_, _)
+        | ParameterType::ExistingFile(_)
+        | ParameterType::NewFile(_)
+        | ParameterType::Directory => json!({ "type": "string" }),
+    };
+    schema["title"] = json!(parameter.name);
+    schema["description"] = json!(parameter.description);
+    if let Some(default) = &parameter.default_value {
+        schema["default"] = typed_default(&parameter.parameter_type, default);
+    }
+    schema["x-whitebox-flags"] = json!(parameter.flags);
+    schema["x-whitebox-parameter-type"] =
+        serde_json::to_value(&parameter.parameter_type).unwrap_or(Value::Null);
+    schema
+}
+
+// The default value of a parameter as a JSON value of the parameter's type, if it can
+// be read as one, or otherwise as a string.
+fn typed_default(parameter_type: &ParameterType, default: &str) -> Value {
+    let value = match parameter_type {
+        ParameterType::Boolean => default
+            .trim()
+            .to_lowercase()
+            .parse::<bool>()
+            .ok()
+            .map(Value::from),
+        ParameterType::Integer => default.trim().parse::<i64>().ok().map(Value::from),
+        ParameterType::Float
+        | ParameterType::StringOrNumber
+        | ParameterType::ExistingFileOrFloat(_) => default
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number),
+        _ => None,
+    };
+    value.unwrap_or_else(|| json!(default))
+}
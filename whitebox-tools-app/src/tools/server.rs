@@ -0,0 +1,1018 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: In service mode (--serve), WhiteboxTools runs as a long-lived local HTTP
+service that answers requests with JSON, so that a client can run many tools without
+starting a new process for each, and can follow and cancel the runs while they are in
+progress. The endpoints are:
+
+GET  /tools                 Lists the tools, with their descriptions and toolboxes.
+GET  /tools/{name}          The parameters of a tool, as printed by --toolparameters.
+GET  /tools/{name}/schema   The JSON Schema of a tool's arguments.
+GET  /openapi.json          The OpenAPI document of the service (see --schema).
+POST /jobs                  Starts a tool run, e.g. {"tool": "Slope", "args": {...}}.
+GET  /jobs                  Lists the jobs and their statuses.
+GET  /jobs/{id}             The status, progress and messages of a job, and once it
+                            has finished, its report.
+POST /jobs/{id}/cancel      Asks a job to stop.
+POST /shutdown              Cancels the running jobs and stops the service.
+
+The args of a job are either an object keyed by parameter name, as for ToolArgs, e.g.
+{"dem": "dem.tif", "output": "slope.tif", "zfactor": 2.0}, or a list of command-line
+arguments, e.g. ["-i=dem.tif", "-o=slope.tif"]. They are validated before the job is
+started, so that invalid arguments are reported in the response. Each job runs on
+its own thread in verbose mode, so that its progress can be followed, and its file
+names are relative to the working directory of the service. The report of a finished
+job is the ToolReport of --json mode. Only the 100 most recent finished jobs are kept,
+so that a long-running service doesn't accumulate the jobs of earlier clients; the
+older ones are forgotten, and their ids are not reused. Errors are returned as
+{"error": {"code": ..., "message": ...}}, with the codes of ErrorCode.
+The service only listens on the loopback interface, since the tools read and write
+files with the permissions of the user who started it. For the same reason, it refuses
+requests that carry an Origin header or name a host other than the loopback interface,
+which a web page could otherwise send to it through the user's browser, and POST
+requests must have the content type application/json. Connections are answered by a
+fixed pool of threads, so that a slow client doesn't hold up the others while the number
+of threads stays bounded; further connections wait in a short queue, and beyond it, in the
+listener's backlog.
+*/
+
+use super::tool_report::CollectingSink;
+use super::{
+    CancellationToken, ErrorCode, ReportError, ToolArgValue, ToolArgs, ToolManager, ToolReport,
+};
+use serde_json::{json, Value};
+use std::io::{BufRead, BufReader, Error, ErrorKind, Read, Write};
+use std::net::{IpAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::sync_channel;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// The address on which the service listens unless another is given.
+pub const DEFAULT_SERVER_ADDRESS: &str = "127.0.0.1:8125";
+
+// The largest request body that is read, in bytes.
+const MAX_BODY_SIZE: usize = 1 << 20;
+
+// The limits that keep a long-running service from growing without bound.
+#[derive(Clone, Copy)]
+struct Limits {
+    // The number of threads that answer connections.
+    connection_threads: usize,
+    // The number of finished jobs that are kept.
+    finished_jobs: usize,
+}
+
+const DEFAULT_LIMITS: Limits = Limits {
+    connection_threads: 8,
+    finished_jobs: 100,
+};
+
+// A tool run started by a client.
+struct Job {
+    id: usize,
+    tool: String,
+    args: Vec<String>,
+    sink: Arc<CollectingSink>,
+    token: CancellationToken,
+    report: Mutex<Option<ToolReport>>,
+}
+
+impl Job {
+    fn is_finished(&self) -> bool {
+        self.report.lock().unwrap().is_some()
+    }
+
+    fn to_json(&self, detailed: bool) -> Value {
+        let report = self.report.lock().unwrap().clone();
+        let status = match &report {
+            None if self.token.is_cancelled() => "cancelling",
+            None => "running",
+            Some(r) if r.is_ok() => "succeeded",
+            Some(r) => match &r.error {
+                Some(e) if e.code == ErrorCode::Cancelled => "cancelled",
+                _ => "failed",
+            },
+        };
+        let progress = self
+            .sink
+            .latest_progress()
+            .map(|(label, percent)| json!({ "label": label, "percent": percent }));
+        let mut job = json!({
+            "id": self.id,
+            "tool": self.tool,
+            "status": status,
+            "progress": progress,
+        });
+        if detailed {
+            // the messages of a finished job are those of its report
+            let (messages, warnings) = match &report {
+                Some(r) => (r.messages.clone(), r.warnings.clone()),
+                None => (self.sink.messages(), self.sink.warnings()),
+            };
+            job["args"] = json!(self.args);
+            job["messages"] = json!(messages);
+            job["warnings"] = json!(warnings);
+            job["report"] = json!(report);
+        }
+        job
+    }
+}
+
+// The jobs started by clients, in the order in which they were started.
+#[derive(Default)]
+struct Jobs {
+    list: Vec<Arc<Job>>,
+    last_id: usize,
+}
+
+impl Jobs {
+    // Forgets the oldest finished jobs beyond the most recent `keep` of them.
+    fn prune(&mut self, keep: usize) {
+        let finished = self.list.iter().filter(|j| j.is_finished()).count();
+        let mut excess = finished.saturating_sub(keep);
+        self.list.retain(|j| {
+            if excess > 0 && j.is_finished() {
+                excess -= 1;
+                false
+            } else {
+                true
+            }
+        });
+    }
+}
+
+struct Request {
+    method: String,
+    path: String,
+    host: Option<String>,
+    origin: Option<String>,
+    content_type: Option<String>,
+    body: Vec<u8>,
+}
+
+impl Request {
+    // Refuses the requests that a web page could send to the service through the user's
+    // browser: those from another origin, those addressed to another host name that
+    // resolves to the loopback interface, and POST requests that a page could send
+    // without the browser first asking the service's permission.
+    fn check(&self) -> Result<(), Response> {
+        if let Some(origin) = &self.origin {
+            return Err(Response::error(
+                403,
+                ErrorCode::PermissionDenied,
+                format!("Requests from web pages ({}) are not accepted.", origin),
+            ));
+        }
+        if let Some(host) = &self.host {
+            if !is_loopback_host(host) {
+                return Err(Response::error(
+                    403,
+                    ErrorCode::PermissionDenied,
+                    format!("Requests for the host {} are not accepted.", host),
+                ));
+            }
+        }
+        let is_json = self.content_type.as_ref().is_some_and(|t| {
+            t.split(';')
+                .next()
+                .unwrap_or("")
+                .trim()
+                .eq_ignore_ascii_case("application/json")
+        });
+        if self.method == "POST" && !is_json {
+            return Err(Response::error(
+                415,
+                ErrorCode::InvalidInput,
+                "POST requests must have the content type application/json.".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+// An HTTP status code and the JSON body that is sent with it.
+struct Response {
+    status: u16,
+    body: Value,
+}
+
+impl Response {
+    fn ok(body: Value) -> Response {
+        Response { status: 200, body }
+    }
+
+    fn error(status: u16, code: ErrorCode, message: String) -> Response {
+        Response {
+            status,
+            body: json!({ "error": ReportError { code, message } }),
+        }
+    }
+
+    fn invalid_input(message: String) -> Response {
+        Response::error(400, ErrorCode::InvalidInput, message)
+    }
+
+    // The response for an error returned while looking up or validating a tool run.
+    fn from_error(error: &Error) -> Response {
+        match error.kind() {
+            ErrorKind::NotFound => Response::error(404, ErrorCode::UnknownTool, error.to_string()),
+            ErrorKind::InvalidInput => Response::invalid_input(error.to_string()),
+            _ => Response::error(500, ErrorCode::from_error(error), error.to_string()),
+        }
+    }
+}
+
+impl ToolManager {
+    /// Runs the local HTTP service on `address`, e.g. 127.0.0.1:8125, until a client
+    /// asks it to shut down. Only loopback addresses are accepted.
+    pub fn serve(&self, address: &str) -> Result<(), Error> {
+        let addresses: Vec<_> = address.to_socket_addrs()?.collect();
+        if addresses.is_empty() || addresses.iter().any(|a| !a.ip().is_loopback()) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "The service only listens on the loopback interface, e.g. on {}; {} is not a loopback address.",
+                    DEFAULT_SERVER_ADDRESS, address
+                ),
+            ));
+        }
+        let listener = TcpListener::bind(&addresses[..])?;
+        println!(
+            "WhiteboxTools is listening on http://{}",
+            listener.local_addr()?
+        );
+        self.serve_listener(listener, DEFAULT_LIMITS)
+    }
+
+    // Answers the connections to a listener until a client asks the service to shut down.
+    fn serve_listener(&self, listener: TcpListener, limits: Limits) -> Result<(), Error> {
+        let address = listener.local_addr()?;
+        let jobs = Mutex::new(Jobs::default());
+        let shutdown = AtomicBool::new(false);
+        let (tx, rx) = sync_channel::<TcpStream>(limits.connection_threads);
+        let rx = Mutex::new(rx);
+        thread::scope(|scope| {
+            for _ in 0..limits.connection_threads.max(1) {
+                let (jobs, shutdown, rx) = (&jobs, &shutdown, &rx);
+                scope.spawn(move || loop {
+                    let stream = match rx.lock().unwrap().recv() {
+                        Ok(stream) => stream,
+                        Err(_) => break, // the listener has stopped
+                    };
+                    if self.answer(stream, jobs, shutdown, limits, scope) {
+                        shutdown.store(true, Ordering::SeqCst);
+                        // wakes the listener, which is waiting for the next connection
+                        let _ = TcpStream::connect(address);
+                    }
+                });
+            }
+            for stream in listener.incoming() {
+                if shutdown.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    // waits while the queue is full, leaving further connections in
+                    // the listener's backlog
+                    if tx.send(stream).is_err() {
+                        break;
+                    }
+                }
+            }
+            drop(tx);
+            // the scope waits for the cancelled jobs to stop
+            for job in jobs.lock().unwrap().list.iter() {
+                job.token.cancel();
+            }
+        });
+        Ok(())
+    }
+
+    // Reads a request from a connection and answers it, returning true if the client
+    // asked the service to shut down.
+    fn answer<'scope, 'env>(
+        &'env self,
+        mut stream: TcpStream,
+        jobs: &'scope Mutex<Jobs>,
+        shutdown: &AtomicBool,
+        limits: Limits,
+        scope: &'scope thread::Scope<'scope, 'env>,
+    ) -> bool {
+        let request = match read_request(&mut stream) {
+            Ok(request) => request,
+            Err(e) => {
+                let _ = write_response(&mut stream, &Response::invalid_input(e.to_string()));
+                return false;
+            }
+        };
+        let response = match request.check() {
+            Ok(()) if shutdown.load(Ordering::SeqCst) => Response::error(
+                503,
+                ErrorCode::Cancelled,
+                "The service is shutting down.".to_string(),
+            ),
+            Ok(()) => self.handle_request(&request, jobs, limits, scope),
+            Err(response) => response,
+        };
+        let _ = write_response(&mut stream, &response);
+        response.status == 200 && request.method == "POST" && request.path == "/shutdown"
+    }
+
+    fn handle_request<'scope, 'env>(
+        &'env self,
+        request: &Request,
+        jobs: &'scope Mutex<Jobs>,
+        limits: Limits,
+        scope: &'scope thread::Scope<'scope, 'env>,
+    ) -> Response {
+        let segments: Vec<&str> = request.path.split('/').filter(|s| !s.is_empty()).collect();
+        let result = match (request.method.as_str(), segments.as_slice()) {
+            ("GET", []) => Ok(json!({
+                "service": "WhiteboxTools",
+                "version": env!("CARGO_PKG_VERSION"),
+            })),
+            ("GET", ["openapi.json"]) => self.schema(),
+            ("GET", ["tools"]) => self.tool_list(),
+            ("GET", ["tools", name]) => self.get_tool_parameter_json(name),
+            ("GET", ["tools", name, "schema"]) => self.tool_schema(name),
+            ("GET", ["jobs"]) => {
+                let jobs = jobs.lock().unwrap();
+                Ok(json!(jobs
+                    .list
+                    .iter()
+                    .map(|j| j.to_json(false))
+                    .collect::<Vec<_>>()))
+            }
+            ("POST", ["jobs"]) => return self.start_job(&request.body, jobs, limits, scope),
+            ("GET", ["jobs", id]) => {
+                return match find_job(jobs, id) {
+                    Some(job) => Response::ok(job.to_json(true)),
+                    None => job_not_found(id),
+                }
+            }
+            ("POST", ["jobs", id, "cancel"]) => {
+                return match find_job(jobs, id) {
+                    Some(job) => {
+                        job.token.cancel();
+                        Response::ok(job.to_json(false))
+                    }
+                    None => job_not_found(id),
+                }
+            }
+            ("POST", ["shutdown"]) => Ok(json!({ "status": "shutting down" })),
+            _ => {
+                return Response::error(
+                    404,
+                    ErrorCode::NotFound,
+                    format!("There is no endpoint {} {}.", request.method, request.path),
+                )
+            }
+        };
+        match result {
+            Ok(body) => Response::ok(body),
+            Err(e) => Response::from_error(&e),
+        }
+    }
+
+    // Validates a job and starts it on a thread of its own.
+    fn start_job<'scope, 'env>(
+        &'env self,
+        body: &[u8],
+        jobs: &'scope Mutex<Jobs>,
+        limits: Limits,
+        scope: &'scope thread::Scope<'scope, 'env>,
+    ) -> Response {
+        let body: Value = match serde_json::from_slice(body) {
+            Ok(body) => body,
+            Err(e) => return Response::invalid_input(format!("Invalid JSON: {}", e)),
+        };
+        let tool_name = match body["tool"].as_str() {
+            Some(tool_name) => tool_name,
+            None => {
+                return Response::invalid_input(
+                    "A job must name a tool, e.g. {\"tool\": \"Slope\", \"args\": {...}}."
+                        .to_string(),
+                )
+            }
+        };
+        let args = match self.job_args(tool_name, &body["args"]) {
+            Ok(args) => args,
+            Err(e) => return Response::from_error(&e),
+        };
+        if let Err(e) = self.validate_args(tool_name, &args) {
+            return Response::from_error(&e);
+        }
+
+        let mut job_list = jobs.lock().unwrap();
+        job_list.last_id += 1;
+        let job = Arc::new(Job {
+            id: job_list.last_id,
+            tool: self
+                .get_tool(tool_name)
+                .map(|tool| tool.get_tool_name())
+                .unwrap_or_else(|| tool_name.to_string()),
+            args,
+            sink: Arc::new(CollectingSink::default()),
+            token: CancellationToken::new(),
+            report: Mutex::new(None),
+        });
+        job_list.list.push(job.clone());
+        drop(job_list);
+        let response = Response {
+            status: 202,
+            body: job.to_json(true),
+        };
+        scope.spawn(move || {
+            // jobs run in verbose mode, so that their progress reaches the sink
+            let report = self.run_tool_report_with(
+                &job.tool,
+                job.args.clone(),
+                &job.sink,
+                job.token.clone(),
+                true,
+            );
+            *job.report.lock().unwrap() = Some(report);
+            jobs.lock().unwrap().prune(limits.finished_jobs);
+        });
+        response
+    }
+
+    // The command-line arguments of a job, which are given either as a list of
+    // command-line arguments or as an object keyed by parameter name.
+    fn job_args(&self, tool_name: &str, args: &Value) -> Result<Vec<String>, Error> {
+        let invalid = |msg: &str| Error::new(ErrorKind::InvalidInput, msg.to_string());
+        match args {
+            Value::Null => Ok(vec![]),
+            Value::Array(values) => values
+                .iter()
+                .map(|v| {
+                    v.as_str()
+                        .map(|s| s.to_string())
+                        .ok_or_else(|| invalid("The args of a job must be strings."))
+                })
+                .collect(),
+            Value::Object(values) => {
+                let parameters = self.get_tool_parameter_list(tool_name)?;
+                let mut tool_args = ToolArgs::new();
+                for (name, value) in values {
+                    let value = match value {
+                        Value::Bool(b) => ToolArgValue::Boolean(*b),
+                        Value::Number(n) => match n.as_i64() {
+                            Some(i) => ToolArgValue::Integer(i),
+                            None => ToolArgValue::Float(n.as_f64().unwrap_or(f64::NAN)),
+                        },
+                        Value::String(s) => ToolArgValue::String(s.clone()),
+                        Value::Array(items) => ToolArgValue::List(
+                            items
+                                .iter()
+                                .map(|item| match item {
+                                    Value::String(s) => s.clone(),
+                                    item => item.to_string(),
+                                })
+                                .collect(),
+                        ),
+                        _ => {
+                            return Err(invalid(&format!(
+                                "The value of the argument '{}' must be a Boolean, a number, a string or a list.",
+                                name
+                            )))
+                        }
+                    };
+                    tool_args.insert(name, value);
+                }
+                tool_args.to_command_line(tool_name, &parameters, &self.working_dir)
+            }
+            _ => Err(invalid(
+                "The args of a job must be either a list of command-line arguments or an object keyed by parameter name.",
+            )),
+        }
+    }
+
+    // The name, description and toolbox of each tool, including plugins.
+    fn tool_list(&self) -> Result<Value, Error> {
+        let mut tools = vec![];
+        for tool_name in &self.tool_names {
+            if let Some(tool) = self.get_tool(tool_name) {
+                tools.push(json!({
+                    "name": tool.get_tool_name(),
+                    "description": tool.get_tool_description(),
+                    "toolbox": tool.get_toolbox(),
+                }));
+            }
+        }
+        for plugin_data in self.get_plugin_list()?.values() {
+            tools.push(json!({
+                "name": plugin_data["tool_name"],
+                "description": plugin_data["short_description"],
+                "toolbox": plugin_data["toolbox"],
+            }));
+        }
+        tools.sort_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+        Ok(Value::Array(tools))
+    }
+}
+
+fn find_job(jobs: &Mutex<Jobs>, id: &str) -> Option<Arc<Job>> {
+    let id = id.parse::<usize>().ok()?;
+    jobs.lock()
+        .unwrap()
+        .list
+        .iter()
+        .find(|j| j.id == id)
+        .cloned()
+}
+
+fn job_not_found(id: &str) -> Response {
+    Response::error(
+        404,
+        ErrorCode::NotFound,
+        format!("There is no job with the id {}.", id),
+    )
+}
+
+fn read_request(stream: &mut TcpStream) -> Result<Request, Error> {
+    stream.set_read_timeout(Some(Duration::from_secs(10)))?;
+    let malformed = || Error::new(ErrorKind::InvalidData, "Malformed HTTP request.");
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let (method, target) = match (parts.next(), parts.next()) {
+        (Some(method), Some(target)) => (method.to_uppercase(), target.to_string()),
+        _ => return Err(malformed()),
+    };
+    let mut content_length = 0usize;
+    let (mut host, mut origin, mut content_type) = (None, None, None);
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            let (name, value) = (name.trim().to_lowercase(), value.trim());
+            match name.as_str() {
+                "content-length" => content_length = value.parse().map_err(|_| malformed())?,
+                "host" => host = Some(value.to_string()),
+                "origin" => origin = Some(value.to_string()),
+                "content-type" => content_type = Some(value.to_string()),
+                _ => {}
+            }
+        }
+    }
+    if content_length > MAX_BODY_SIZE {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "The request body is larger than the limit of {} bytes.",
+                MAX_BODY_SIZE
+            ),
+        ));
+    }
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body)?;
+    // the query string, if any, is ignored
+    let path = target.split('?').next().unwrap_or("").to_string();
+    Ok(Request {
+        method,
+        path,
+        host,
+        origin,
+        content_type,
+        body,
+    })
+}
+
+// Whether the value of a Host header, e.g. localhost:8125 or [::1]:8125, names the
+// loopback interface.
+fn is_loopback_host(host: &str) -> bool {
+    let name = match host.strip_prefix('[') {
+        Some(rest) => rest.split(']').next().unwrap_or(""),
+        None => host.rsplit_once(':').map_or(host, |(name, _)| name),
+    };
+    name.eq_ignore_ascii_case("localhost")
+        || name.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback())
+}
+
+fn write_response(stream: &mut TcpStream, response: &Response) -> Result<(), Error> {
+    let body = serde_json::to_string(&response.body)?;
+    let reason = match response.status {
+        200 => "OK",
+        202 => "Accepted",
+        400 => "Bad Request",
+        403 => "Forbidden",
+        404 => "Not Found",
+        415 => "Unsupported Media Type",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
+
+/// The paths of the service, for its OpenAPI document.
+pub(super) fn openapi_paths() -> Value {
+    let json_response = |description: &str| json!({ "description": description, "content": { "application/json": {} } });
+    let error_response = |description: &str| {
+        json!({
+            "description": description,
+            "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Error" } } }
+        })
+    };
+    let name =
+        json!([{ "name": "name", "in": "path", "required": true, "schema": { "type": "string" } }]);
+    let id =
+        json!([{ "name": "id", "in": "path", "required": true, "schema": { "type": "integer" } }]);
+    json!({
+        "/tools": {
+            "get": {
+                "summary": "Lists the tools, with their descriptions and toolboxes.",
+                "responses": { "200": json_response("The tools.") }
+            }
+        },
+        "/tools/{name}": {
+            "get": {
+                "summary": "Returns the parameters of a tool.",
+                "parameters": name,
+                "responses": {
+                    "200": json_response("The tool's parameters."),
+                    "404": error_response("There is no tool of that name.")
+                }
+            }
+        },
+        "/tools/{name}/schema": {
+            "get": {
+                "summary": "Returns the JSON Schema of a tool's arguments.",
+                "parameters": name,
+                "responses": {
+                    "200": json_response("The schema, which is also among the components of this document."),
+                    "404": error_response("There is no tool of that name.")
+                }
+            }
+        },
+        "/openapi.json": {
+            "get": {
+                "summary": "Returns this document.",
+                "responses": { "200": json_response("The OpenAPI document of the service.") }
+            }
+        },
+        "/jobs": {
+            "get": {
+                "summary": "Lists the jobs and their statuses.",
+                "responses": { "200": json_response("The jobs.") }
+            },
+            "post": {
+                "summary": "Starts a tool run.",
+                "requestBody": {
+                    "required": true,
+                    "content": { "application/json": { "schema": {
+                        "type": "object",
+                        "properties": {
+                            "tool": { "type": "string" },
+                            "args": {
+                                "description": "The arguments of the tool, keyed by parameter name as in the tool's schema, or as a list of command-line arguments.",
+                                "type": ["object", "array"],
+                                "items": { "type": "string" }
+                            }
+                        },
+                        "required": ["tool"]
+                    } } }
+                },
+                "responses": {
+                    "202": json_response("The job was started."),
+                    "400": error_response("The arguments are invalid."),
+                    "404": error_response("There is no tool of that name.")
+                }
+            }
+        },
+        "/jobs/{id}": {
+            "get": {
+                "summary": "Returns the status, progress and messages of a job, and its report once it has finished.",
+                "parameters": id,
+                "responses": {
+                    "200": json_response("The job."),
+                    "404": error_response("There is no job with that id.")
+                }
+            }
+        },
+        "/jobs/{id}/cancel": {
+            "post": {
+                "summary": "Asks a job to stop.",
+                "parameters": id,
+                "responses": {
+                    "200": json_response("The job, which stops at its next progress update."),
+                    "404": error_response("There is no job with that id.")
+                }
+            }
+        },
+        "/shutdown": {
+            "post": {
+                "summary": "Cancels the running jobs and stops the service.",
+                "responses": { "200": json_response("The service is stopping.") }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{is_loopback_host, Job, Jobs, Limits, DEFAULT_LIMITS};
+    use crate::tools::tool_report::CollectingSink;
+    use crate::tools::ToolManager;
+    use crate::tools::{CancellationToken, ToolReport};
+    use serde_json::{json, Value};
+    use std::env;
+    use std::io::{Read, Write};
+    use std::net::{SocketAddr, TcpListener, TcpStream};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::{Duration, Instant};
+    use whitebox_raster::*;
+
+    // Starts the service on an ephemeral port of the loopback interface.
+    fn start_service(limits: Limits) -> (SocketAddr, thread::JoinHandle<()>) {
+        let working_directory = format!("{}/", env::temp_dir().to_string_lossy());
+        let tm = ToolManager::new(&working_directory, &false).unwrap();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let handle = thread::spawn(move || tm.serve_listener(listener, limits).unwrap());
+        (address, handle)
+    }
+
+    // Sends a request with the given headers and returns the status and JSON body of
+    // the response.
+    fn send(address: SocketAddr, request_line: &str, headers: &[&str], body: &str) -> (u16, Value) {
+        let mut stream = TcpStream::connect(address).unwrap();
+        let mut request = format!("{}\r\n", request_line);
+        for header in headers {
+            request.push_str(&format!("{}\r\n", header));
+        }
+        request.push_str(&format!("Content-Length: {}\r\n\r\n{}", body.len(), body));
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let status = response.split_whitespace().nth(1).unwrap().parse().unwrap();
+        let body = response.split("\r\n\r\n").nth(1).unwrap();
+        (status, serde_json::from_str(body).unwrap())
+    }
+
+    fn get(address: SocketAddr, path: &str) -> (u16, Value) {
+        let host = format!("Host: {}", address);
+        send(address, &format!("GET {} HTTP/1.1", path), &[&host], "")
+    }
+
+    fn post(address: SocketAddr, path: &str, body: &Value) -> (u16, Value) {
+        let host = format!("Host: {}", address);
+        send(
+            address,
+            &format!("POST {} HTTP/1.1", path),
+            &[&host, "Content-Type: application/json"],
+            &body.to_string(),
+        )
+    }
+
+    // Polls a job until it has finished, returning its final state.
+    fn wait_for_job(address: SocketAddr, id: u64) -> Value {
+        let start = Instant::now();
+        loop {
+            let (status, job) = get(address, &format!("/jobs/{}", id));
+            assert_eq!(status, 200);
+            if job["status"] != json!("running") && job["status"] != json!("cancelling") {
+                return job;
+            }
+            assert!(start.elapsed() < Duration::from_secs(60), "{}", job);
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    fn write_dem(file_name: &str, size: usize) {
+        let configs = RasterConfigs {
+            rows: size,
+            columns: size,
+            north: size as f64,
+            south: 0.0,
+            east: size as f64,
+            west: 0.0,
+            resolution_x: 1.0,
+            resolution_y: 1.0,
+            nodata: -32768.0,
+            data_type: DataType::F32,
+            ..Default::default()
+        };
+        let mut dem = Raster::initialize_using_config(file_name, &configs);
+        for row in 0..size as isize {
+            for col in 0..size as isize {
+                dem.set_value(row, col, (row + 2 * col) as f64);
+            }
+        }
+        dem.write().unwrap();
+    }
+
+    #[test]
+    fn test_is_loopback_host() {
+        assert!(is_loopback_host("localhost"));
+        assert!(is_loopback_host("LOCALHOST:8125"));
+        assert!(is_loopback_host("127.0.0.1:8125"));
+        assert!(is_loopback_host("[::1]:8125"));
+        assert!(!is_loopback_host("example.com:8125"));
+        assert!(!is_loopback_host("192.168.1.2"));
+    }
+
+    #[test]
+    fn test_service() {
+        let (address, handle) = start_service(DEFAULT_LIMITS);
+
+        // a connection that never sends its request doesn't hold up the others
+        let idle = TcpStream::connect(address).unwrap();
+
+        let (status, body) = get(address, "/");
+        assert_eq!(status, 200);
+        assert_eq!(body["service"], json!("WhiteboxTools"));
+        let (status, body) = get(address, "/tools/slope");
+        assert_eq!(status, 200);
+        assert!(body["parameters"].is_array());
+        let (status, body) = get(address, "/tools/NoSuchTool");
+        assert_eq!(status, 404);
+        assert_eq!(body["error"]["code"], json!("unknown_tool"));
+        let (status, body) = get(address, "/no/such/endpoint");
+        assert_eq!(status, 404);
+        assert_eq!(body["error"]["code"], json!("not_found"));
+        let (status, _) = get(address, "/jobs/7");
+        assert_eq!(status, 404);
+
+        // requests that a web page could send through a browser are refused
+        let request_line = "GET /tools HTTP/1.1";
+        let host = format!("Host: {}", address);
+        let (status, body) = send(
+            address,
+            request_line,
+            &[&host, "Origin: http://example.com"],
+            "",
+        );
+        assert_eq!(status, 403);
+        assert_eq!(body["error"]["code"], json!("permission_denied"));
+        let (status, _) = send(address, request_line, &["Host: example.com:8125"], "");
+        assert_eq!(status, 403);
+        let (status, _) = send(
+            address,
+            "POST /jobs HTTP/1.1",
+            &[&host, "Content-Type: text/plain"],
+            "{\"tool\": \"Slope\"}",
+        );
+        assert_eq!(status, 415);
+
+        // invalid jobs are reported without being started
+        let (status, body) = post(address, "/jobs", &json!({ "tool": "Slope", "args": {} }));
+        assert_eq!(status, 400);
+        assert_eq!(body["error"]["code"], json!("invalid_input"));
+
+        write_dem("mem://server_dem", 10);
+        let (status, job) = post(
+            address,
+            "/jobs",
+            &json!({ "tool": "slope", "args": { "dem": "mem://server_dem", "output": "mem://server_slope" } }),
+        );
+        assert_eq!(status, 202);
+        assert_eq!(job["tool"], json!("Slope"));
+        let job = wait_for_job(address, job["id"].as_u64().unwrap());
+        assert_eq!(job["status"], json!("succeeded"), "{}", job);
+        assert_eq!(job["report"]["outputs"], json!(["mem://server_slope"]));
+        assert!(MEMORY_RASTERS.contains("mem://server_slope"));
+
+        // a job that has already finished when it is cancelled keeps its status
+        write_dem("mem://server_large_dem", 1000);
+        let (status, job) = post(
+            address,
+            "/jobs",
+            &json!({ "tool": "Slope", "args": ["-i=mem://server_large_dem", "-o=mem://server_large_slope"] }),
+        );
+        assert_eq!(status, 202);
+        let id = job["id"].as_u64().unwrap();
+        let (status, cancelled) = post(address, &format!("/jobs/{}/cancel", id), &json!({}));
+        assert_eq!(status, 200);
+        let job = wait_for_job(address, id);
+        if cancelled["status"] == json!("cancelling") {
+            assert_eq!(job["status"], json!("cancelled"), "{}", job);
+            assert_eq!(job["report"]["error"]["code"], json!("cancelled"));
+        } else {
+            assert_eq!(job["status"], json!("succeeded"), "{}", job);
+        }
+        let (status, jobs) = get(address, "/jobs");
+        assert_eq!(status, 200);
+        assert_eq!(jobs.as_array().unwrap().len(), 2);
+
+        drop(idle);
+        let (status, _) = post(address, "/shutdown", &json!({}));
+        assert_eq!(status, 200);
+        handle.join().unwrap();
+        for file_name in &[
+            "mem://server_dem",
+            "mem://server_slope",
+            "mem://server_large_dem",
+            "mem://server_large_slope",
+        ] {
+            MEMORY_RASTERS.remove(file_name);
+        }
+    }
+
+    fn job(id: usize, finished: bool) -> Arc<Job> {
+        Arc::new(Job {
+            id,
+            tool: "Slope".to_string(),
+            args: vec![],
+            sink: Arc::new(CollectingSink::default()),
+            token: CancellationToken::new(),
+            report: Mutex::new(if finished {
+                Some(ToolReport::new("Slope".to_string()))
+            } else {
+                None
+            }),
+        })
+    }
+
+    #[test]
+    fn test_prune_jobs() {
+        let mut jobs = Jobs {
+            list: vec![
+                job(1, true),
+                job(2, false),
+                job(3, true),
+                job(4, true),
+                job(5, false),
+            ],
+            last_id: 5,
+        };
+        jobs.prune(1);
+        let ids: Vec<usize> = jobs.list.iter().map(|j| j.id).collect();
+        assert_eq!(ids, vec![2, 4, 5]);
+        assert_eq!(jobs.last_id, 5);
+        jobs.prune(0);
+        let ids: Vec<usize> = jobs.list.iter().map(|j| j.id).collect();
+        assert_eq!(ids, vec![2, 5]);
+    }
+
+    #[test]
+    fn test_service_limits() {
+        let (address, handle) = start_service(Limits {
+            connection_threads: 1,
+            finished_jobs: 1,
+        });
+
+        // with a single connection thread, a connection that never sends its request
+        // holds up the next one until it is closed
+        let idle = TcpStream::connect(address).unwrap();
+        let waiting = thread::spawn(move || get(address, "/"));
+        thread::sleep(Duration::from_millis(200));
+        assert!(!waiting.is_finished());
+        drop(idle);
+        let (status, _) = waiting.join().unwrap();
+        assert_eq!(status, 200);
+
+        // only the most recent finished job is kept, and ids are not reused
+        write_dem("mem://server_limits_dem", 10);
+        let mut ids = vec![];
+        for i in 0..3 {
+            let (status, job) = post(
+                address,
+                "/jobs",
+                &json!({ "tool": "Slope", "args": { "dem": "mem://server_limits_dem", "output": format!("mem://server_limits_slope{}", i) } }),
+            );
+            assert_eq!(status, 202);
+            let id = job["id"].as_u64().unwrap();
+            let job = wait_for_job(address, id);
+            assert_eq!(job["status"], json!("succeeded"), "{}", job);
+            ids.push(id);
+        }
+        assert_eq!(ids, vec![1, 2, 3]);
+        let (status, jobs) = get(address, "/jobs");
+        assert_eq!(status, 200);
+        assert_eq!(jobs.as_array().unwrap().len(), 1);
+        assert_eq!(jobs[0]["id"], json!(3));
+        let (status, _) = get(address, "/jobs/1");
+        assert_eq!(status, 404);
+
+        let (status, _) = post(address, "/shutdown", &json!({}));
+        assert_eq!(status, 200);
+        handle.join().unwrap();
+        MEMORY_RASTERS.remove("mem://server_limits_dem");
+        for i in 0..3 {
+            MEMORY_RASTERS.remove(&format!("mem://server_limits_slope{}", i));
+        }
+    }
+}
@@ -196,8 +196,9 @@ impl WhiteboxTool for ExtractValleys {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut variant = String::from("lq");
@@ -267,7 +268,7 @@ impl WhiteboxTool for ExtractValleys {
 
                 //the filter dimensions must be odd numbers such that there is a middle pixel
                 if (filter_size as f64 / 2f64).floor() == filter_size as f64 / 2f64 {
-                    println!("WARNING: Filter dimensions must be odd numbers. The specified filter dimension has been modified.");
+                    context.warning("WARNING: Filter dimensions must be odd numbers. The specified filter dimension has been modified.");
                     filter_size += 1;
                 }
             }
@@ -277,11 +278,11 @@ impl WhiteboxTool for ExtractValleys {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -304,6 +305,7 @@ impl WhiteboxTool for ExtractValleys {
         let nodata = input.configs.nodata;
 
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.widen_to_f64();
 
         match &variant as &str {
             "lq" => {
@@ -350,13 +352,13 @@ impl WhiteboxTool for ExtractValleys {
                         for row in (0..rows).filter(|r| r % num_procs == tid) {
                             let mut data = vec![nodata; columns as usize];
                             for col in 0..columns {
-                                z = input[(row, col)];
+                                z = input.get_value(row, col);
                                 if z != nodata {
                                     let mut cell_data = vec![1f64; num_cells_in_filter];
                                     n = 0f64;
                                     for i in 0..num_cells_in_filter {
                                         if filter_shape[i] {
-                                            zn = input[(row + dy[i], col + dx[i])];
+                                            zn = input.get_value(row + dy[i], col + dx[i]);
                                             if zn != nodata {
                                                 cell_data[i] = zn;
                                                 n += 1f64;
@@ -377,7 +379,9 @@ impl WhiteboxTool for ExtractValleys {
                                     data[col as usize] = nodata;
                                 }
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                break;
+                            }
                         }
                     });
                 }
@@ -385,12 +389,10 @@ impl WhiteboxTool for ExtractValleys {
                 for row in 0..rows {
                     let data = rx.recv().expect("Error receiving data from thread.");
                     output.set_row_data(data.0, data.1);
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -414,15 +416,15 @@ impl WhiteboxTool for ExtractValleys {
                         for row in (0..rows).filter(|r| r % num_procs == tid) {
                             let mut data = vec![nodata; columns as usize];
                             for col in 0..columns {
-                                z = input[(row, col)];
+                                z = input.get_value(row, col);
                                 if z != nodata {
-                                    zn1 = input[(row + dy[0], col + dx[0])];
-                                    zn2 = input[(row + dy[1], col + dx[1])];
+                                    zn1 = input.get_value(row + dy[0], col + dx[0]);
+                                    zn2 = input.get_value(row + dy[1], col + dx[1]);
                                     if zn1 != nodata && zn2 != nodata && zn1 > z && zn2 > z {
                                         data[col as usize] = 1f64;
                                     } else {
-                                        zn1 = input[(row + dy[2], col + dx[2])];
-                                        zn2 = input[(row + dy[3], col + dx[3])];
+                                        zn1 = input.get_value(row + dy[2], col + dx[2]);
+                                        zn2 = input.get_value(row + dy[3], col + dx[3]);
                                         if zn1 != nodata && zn2 != nodata && zn1 > z && zn2 > z {
                                             data[col as usize] = 1f64;
                                         } else {
@@ -431,7 +433,9 @@ impl WhiteboxTool for ExtractValleys {
                                     }
                                 }
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                break;
+                            }
                         }
                     });
                 }
@@ -439,12 +443,10 @@ impl WhiteboxTool for ExtractValleys {
                 for row in 0..rows {
                     let data = rx.recv().expect("Error receiving data from thread.");
                     output.set_row_data(data.0, data.1);
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -461,12 +463,12 @@ impl WhiteboxTool for ExtractValleys {
                 let num_scan_cells = dx.len();
                 for row in 0..rows {
                     for col in 0..columns {
-                        z = input[(row, col)];
+                        z = input.get_value(row, col);
                         if z != nodata {
                             maxz = z;
                             which_cell = 3;
                             for n in 0..num_scan_cells {
-                                z = input[(row + dy[n], col + dx[n])];
+                                z = input.get_value(row + dy[n], col + dx[n]);
                                 if z != nodata {
                                     if z > maxz {
                                         maxz = z;
@@ -476,22 +478,20 @@ impl WhiteboxTool for ExtractValleys {
                             }
                             output.set_value(row + dy[which_cell], col + dx[which_cell], 0f64);
                         } else {
-                            output[(row, col)] = nodata;
+                            output.set_value(row, col, nodata);
                         }
                     }
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
-                        if progress != old_progress {
-                            println!("Progress: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as i32;
+                    if progress != old_progress {
+                        context.progress("Progress", progress)?;
+                        old_progress = progress;
                     }
                 }
             }
         }
 
         if line_thin {
-            println!("Line thinning operation...");
+            context.info("Line thinning operation...");
             let mut did_something = true;
             let mut loop_num = 0;
             let dx = [1, 1, 1, 0, -1, -1, -1, 0];
@@ -527,11 +527,11 @@ impl WhiteboxTool for ExtractValleys {
                 for a in 0..8 {
                     for row in 0..rows {
                         for col in 0..columns {
-                            z = output[(row, col)];
+                            z = output.get_value(row, col);
                             if z > 0.0 && z != nodata {
                                 // fill the neighbours array
                                 for i in 0..8 {
-                                    neighbours[i] = output[(row + dy[i], col + dx[i])];
+                                    neighbours[i] = output.get_value(row + dy[i], col + dx[i]);
                                 }
 
                                 // scan through element
@@ -542,18 +542,16 @@ impl WhiteboxTool for ExtractValleys {
                                     }
                                 }
                                 if pattern_match {
-                                    output[(row, col)] = 0.0;
+                                    output.set_value(row, col, 0.0);
                                     did_something = true;
                                 }
                             }
                         }
                     }
-                    if verbose {
-                        progress = (100.0_f64 * a as f64 / 7.0) as i32;
-                        if progress != old_progress {
-                            println!("Loop Number {}: {}%", loop_num, progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * a as f64 / 7.0) as i32;
+                    if progress != old_progress {
+                        context.progress(&format!("Loop Number {}", loop_num), progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -573,21 +571,18 @@ impl WhiteboxTool for ExtractValleys {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -166,8 +166,9 @@ impl WhiteboxTool for HortonStreamOrder {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut d8_file = String::new();
         let mut streams_file = String::new();
         let mut output_file = String::new();
@@ -227,11 +228,11 @@ impl WhiteboxTool for HortonStreamOrder {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -250,12 +251,12 @@ impl WhiteboxTool for HortonStreamOrder {
         }
 
         if verbose {
-            println!("Reading pointer data...")
+            context.info("Reading pointer data...")
         };
         let pntr = Raster::new(&d8_file, "r")?;
         let pntr_nodata = pntr.configs.nodata;
         if verbose {
-            println!("Reading streams data...")
+            context.info("Reading streams data...")
         };
         let streams = Raster::new(&streams_file, "r")?;
 
@@ -283,6 +284,7 @@ impl WhiteboxTool for HortonStreamOrder {
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &streams);
+        output.widen_to_f64();
         let mut stack = Vec::with_capacity((rows * columns) as usize);
         let mut channel_heads = vec![];
         let mut max_order = vec![];
@@ -313,11 +315,11 @@ impl WhiteboxTool for HortonStreamOrder {
         let mut current_id = 1i32;
         for row in 0..rows {
             for col in 0..columns {
-                if streams[(row, col)] > 0.0 {
+                if streams.get_value(row, col) > 0.0 {
                     count = 0i8;
                     for i in 0..8 {
-                        if streams[(row + dy[i], col + dx[i])] > 0.0
-                            && pntr[(row + dy[i], col + dx[i])] == inflowing_vals[i]
+                        if streams.get_value(row + dy[i], col + dx[i]) > 0.0
+                            && pntr.get_value(row + dy[i], col + dx[i]) == inflowing_vals[i]
                         {
                             count += 1;
                         }
@@ -331,24 +333,22 @@ impl WhiteboxTool for HortonStreamOrder {
                         current_id += 1;
                         channel_heads.push((row, col));
                         max_order.push(1f64);
-                        output[(row, col)] = 1f64;
+                        output.set_value(row, col, 1f64);
                         trib_length[(row, col)] = 0f64;
                     }
                 } else {
-                    if pntr[(row, col)] != pntr_nodata {
-                        output[(row, col)] = background_val;
+                    if pntr.get_value(row, col) != pntr_nodata {
+                        output.set_value(row, col, background_val);
                     } else {
-                        output[(row, col)] = nodata;
+                        output.set_value(row, col, nodata);
                     }
                     num_solved_cells += 1;
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -395,10 +395,10 @@ impl WhiteboxTool for HortonStreamOrder {
             col = cell.1;
 
             trib = trib_id[(row, col)];
-            order_val = output[(row, col)];
+            order_val = output.get_value(row, col);
 
             // find the downstream cell
-            dir = pntr[(row, col)] as usize;
+            dir = pntr.get_value(row, col) as usize;
             if dir > 0 {
                 if dir > 128 || pntr_matches[dir] == 999 {
                     return Err(Error::new(ErrorKind::InvalidInput,
@@ -415,11 +415,11 @@ impl WhiteboxTool for HortonStreamOrder {
                     trib_id[(row_n, col_n)] = trib;
                 }
 
-                order_val_n = output[(row_n, col_n)];
+                order_val_n = output.get_value(row_n, col_n);
                 if order_val == order_val_n {
-                    output[(row_n, col_n)] = order_val + 1.0;
+                    output.set_value(row_n, col_n, order_val + 1.0);
                 } else if order_val > order_val_n {
-                    output[(row_n, col_n)] = order_val
+                    output.set_value(row_n, col_n, order_val);
                 }
 
                 num_inflowing.decrement(row_n, col_n, 1);
@@ -429,15 +429,15 @@ impl WhiteboxTool for HortonStreamOrder {
 
                 // see if a tributary needs updating
                 trib = trib_id[(row_n, col_n)];
-                order_val = output[(row_n, col_n)];
+                order_val = output.get_value(row_n, col_n);
                 if max_order[(trib - 1) as usize] < order_val {
                     max_order[(trib - 1) as usize] = order_val;
                     let cell = channel_heads[(trib - 1) as usize];
                     let mut y = cell.0;
                     let mut x = cell.1;
-                    while output[(y, x)] != nodata {
-                        output[(y, x)] = order_val;
-                        dir = pntr[(y, x)] as usize;
+                    while output.get_value(y, x) != nodata {
+                        output.set_value(y, x, order_val);
+                        dir = pntr.get_value(y, x) as usize;
                         if dir > 0 {
                             if dir > 128 || pntr_matches[dir] == 999 {
                                 return Err(Error::new(ErrorKind::InvalidInput,
@@ -454,12 +454,10 @@ impl WhiteboxTool for HortonStreamOrder {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -475,21 +473,18 @@ impl WhiteboxTool for HortonStreamOrder {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
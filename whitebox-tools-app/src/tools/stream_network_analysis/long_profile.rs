@@ -171,8 +171,9 @@ impl WhiteboxTool for LongProfile {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut d8_file = String::new();
         let mut streams_file = String::new();
         let mut dem_file = String::new();
@@ -230,11 +231,11 @@ impl WhiteboxTool for LongProfile {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -256,15 +257,15 @@ impl WhiteboxTool for LongProfile {
         }
 
         if verbose {
-            println!("Reading pointer data...")
+            context.info("Reading pointer data...")
         };
         let pntr = Raster::new(&d8_file, "r")?;
         if verbose {
-            println!("Reading streams data...")
+            context.info("Reading streams data...")
         };
         let streams = Raster::new(&streams_file, "r")?;
         if verbose {
-            println!("Reading DEM data...")
+            context.info("Reading DEM data...")
         };
         let dem = Raster::new(&dem_file, "r")?;
 
@@ -297,7 +298,7 @@ impl WhiteboxTool for LongProfile {
 
         // find all the stream head cells and add them to a list
         if verbose {
-            println!("Finding channel heads...");
+            context.info("Finding channel heads...");
         }
         let streams = Arc::new(streams);
         let pntr = Arc::new(pntr);
@@ -343,7 +344,9 @@ impl WhiteboxTool for LongProfile {
                             }
                         }
                     }
-                    tx1.send((row, heads)).unwrap();
+                    if tx1.send((row, heads)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -355,17 +358,15 @@ impl WhiteboxTool for LongProfile {
                 heads.push((r, col));
             }
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Finding channel heads: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Finding channel heads", progress)?;
+                old_progress = progress;
             }
         }
 
         if verbose {
-            println!("Traversing streams...");
+            context.info("Traversing streams...");
         }
         // Now traverse each flowpath starting from each stream head and
         // retrieve the elevation and distance from outlet data.
@@ -458,12 +459,10 @@ impl WhiteboxTool for LongProfile {
             traverse_num += 1;
             stream_lengths[h] = dist;
             // update progress here
-            if verbose {
-                progress = (100.0_f64 * h as f64 / (num_heads - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Loop 1 of 2: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * h as f64 / (num_heads - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Loop 1 of 2", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -516,12 +515,10 @@ impl WhiteboxTool for LongProfile {
                 traverse_num += 1;
             }
             // update progress here
-            if verbose {
-                progress = (100.0_f64 * h as f64 / (num_heads - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Loop 2 of 2: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * h as f64 / (num_heads - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Loop 2 of 2", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -587,10 +584,10 @@ impl WhiteboxTool for LongProfile {
         let _ = writer.flush();
 
         if verbose {
-            println!(
+            context.info(format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         if verbose {
@@ -618,7 +615,7 @@ impl WhiteboxTool for LongProfile {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            context.info(format!("Complete! Please see {} for output.", output_file));
         }
 
         Ok(())
@@ -161,8 +161,9 @@ impl WhiteboxTool for LongProfileFromPoints {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut d8_file = String::new();
         let mut points_file = String::new();
         let mut dem_file = String::new();
@@ -220,11 +221,11 @@ impl WhiteboxTool for LongProfileFromPoints {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -246,17 +247,17 @@ impl WhiteboxTool for LongProfileFromPoints {
         }
 
         if verbose {
-            println!("Reading pointer data...")
+            context.info("Reading pointer data...")
         };
         let pntr = Raster::new(&d8_file, "r")?;
 
         if verbose {
-            println!("Reading points data...")
+            context.info("Reading points data...")
         };
         let points = Shapefile::read(&points_file)?;
 
         if verbose {
-            println!("Reading DEM data...")
+            context.info("Reading DEM data...")
         };
         let dem = Raster::new(&dem_file, "r")?;
 
@@ -292,17 +293,15 @@ impl WhiteboxTool for LongProfileFromPoints {
             let col = dem.get_column_from_x(record.points[0].x);
             heads.push((row, col));
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Finding channel heads: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Finding channel heads", progress)?;
+                old_progress = progress;
             }
         }
 
         if verbose {
-            println!("Traversing flowpaths...");
+            context.info("Traversing flowpaths...");
         }
         // Now traverse each flowpath starting from each stream head and
         // retrieve the elevation and distance from outlet data.
@@ -390,12 +389,10 @@ impl WhiteboxTool for LongProfileFromPoints {
             traverse_num += 1;
             stream_lengths[h] = dist;
             // update progress here
-            if verbose {
-                progress = (100.0_f64 * h as f64 / (num_heads - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Loop 1 of 2: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * h as f64 / (num_heads - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Loop 1 of 2", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -443,12 +440,10 @@ impl WhiteboxTool for LongProfileFromPoints {
                 traverse_num += 1;
             }
             // update progress here
-            if verbose {
-                progress = (100.0_f64 * h as f64 / (num_heads - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Loop 2 of 2: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * h as f64 / (num_heads - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Loop 2 of 2", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -507,10 +502,10 @@ impl WhiteboxTool for LongProfileFromPoints {
         let _ = writer.flush();
 
         if verbose {
-            println!(
+            context.info(format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         if verbose {
@@ -538,7 +533,7 @@ impl WhiteboxTool for LongProfileFromPoints {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            context.info(format!("Complete! Please see {} for output.", output_file));
         }
 
         Ok(())
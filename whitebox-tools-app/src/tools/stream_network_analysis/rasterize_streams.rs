@@ -150,8 +150,9 @@ impl WhiteboxTool for RasterizeStreams {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut streams_file = String::new();
         let mut base_file = String::new();
         let mut output_file = String::new();
@@ -208,11 +209,11 @@ impl WhiteboxTool for RasterizeStreams {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -231,12 +232,12 @@ impl WhiteboxTool for RasterizeStreams {
         }
 
         if verbose {
-            println!("Reading streams data...")
+            context.info("Reading streams data...")
         };
         let streams = Shapefile::read(&streams_file)?;
 
         if verbose {
-            println!("Reading base raster data...")
+            context.info("Reading base raster data...")
         };
         let base = Raster::new(&base_file, "r")?;
         let rows = base.configs.rows as isize;
@@ -259,6 +260,7 @@ impl WhiteboxTool for RasterizeStreams {
 
         // create the output raster file
         let mut output = Raster::initialize_using_file(&output_file, &base);
+        output.widen_to_f64();
         if !out_nodata {
             output.reinitialize_values(background_val);
         }
@@ -390,12 +392,10 @@ impl WhiteboxTool for RasterizeStreams {
             }
 
             count += 1f64;
-            if verbose {
-                progress = (100.0_f64 * count / (streams.num_records - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Rasterizing Streams: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * count / (streams.num_records - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Rasterizing Streams", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -406,7 +406,7 @@ impl WhiteboxTool for RasterizeStreams {
         let dx4 = [1isize, 0isize, -1isize, 0isize];
         let dy4 = [0isize, 1isize, 0isize, -1isize];
         let (mut row_n, mut col_n): (isize, isize);
-        println!("Counting stream cell adjacencies...");
+        context.info("Counting stream cell adjacencies...");
         for row in 0..rows {
             for col in 0..columns {
                 id = output.get_value(row, col);
@@ -430,17 +430,15 @@ impl WhiteboxTool for RasterizeStreams {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         if !feature_id {
-            println!("Updating output...");
+            context.info("Updating output...");
             for row in 0..rows {
                 for col in 0..columns {
                     z = output.get_value(row, col);
@@ -449,20 +447,18 @@ impl WhiteboxTool for RasterizeStreams {
                         output.set_value(row, col, 1f64);
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
         }
 
         if verbose {
-            println!("Number of stream cells: {}", num_stream_cells);
-            println!("Number of stream collisions: {}", num_link_collisions);
-            println!("Number of stream adjacencies: {}", num_adjacencies);
+            context.info(format!("Number of stream cells: {}", num_stream_cells));
+            context.info(format!("Number of stream collisions: {}", num_link_collisions));
+            context.info(format!("Number of stream adjacencies: {}", num_adjacencies));
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
@@ -477,21 +473,18 @@ impl WhiteboxTool for RasterizeStreams {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -165,8 +165,9 @@ impl WhiteboxTool for StrahlerStreamOrder {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut d8_file = String::new();
         let mut streams_file = String::new();
         let mut output_file = String::new();
@@ -226,11 +227,11 @@ impl WhiteboxTool for StrahlerStreamOrder {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -249,11 +250,11 @@ impl WhiteboxTool for StrahlerStreamOrder {
         }
 
         if verbose {
-            println!("Reading pointer data...")
+            context.info("Reading pointer data...")
         };
         let pntr = Raster::new(&d8_file, "r")?;
         if verbose {
-            println!("Reading streams data...")
+            context.info("Reading streams data...")
         };
         let streams = Raster::new(&streams_file, "r")?;
 
@@ -281,6 +282,7 @@ impl WhiteboxTool for StrahlerStreamOrder {
         let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
 
         let mut output = Raster::initialize_using_file(&output_file, &streams);
+        output.widen_to_f64();
         output.reinitialize_values(0.0);
 
         // Create a mapping from the pointer values to cells offsets.
@@ -326,13 +328,15 @@ impl WhiteboxTool for StrahlerStreamOrder {
         let mut dir: usize;
         for row in 0..rows {
             for col in 0..columns {
-                if streams[(row, col)] > 0.0 {
+                if streams.get_value(row, col) > 0.0 {
                     // see if it is a headwater location
                     num_neighbouring_stream_cells = 0i8;
                     for c in 0..8 {
                         x = col + d_x[c];
                         y = row + d_y[c];
-                        if streams[(y, x)] > 0.0 && pntr[(y, x)] == inflowing_vals[c] {
+                        if streams.get_value(y, x) > 0.0
+                            && pntr.get_value(y, x) == inflowing_vals[c]
+                        {
                             num_neighbouring_stream_cells += 1;
                         }
                     }
@@ -341,12 +345,12 @@ impl WhiteboxTool for StrahlerStreamOrder {
                         x = col;
                         y = row;
                         current_order = 1f64;
-                        output[(y, x)] = current_order;
+                        output.set_value(y, x, current_order);
                         flag = true;
                         while flag {
                             // find the downslope neighbour
-                            if pntr[(y, x)] > 0.0 {
-                                dir = pntr[(y, x)] as usize;
+                            if pntr.get_value(y, x) > 0.0 {
+                                dir = pntr.get_value(y, x) as usize;
                                 if dir > 128 || pntr_matches[dir] == 999 {
                                     return Err(Error::new(ErrorKind::InvalidInput,
                                         "An unexpected value has been identified in the pointer image. This tool requires a pointer grid that has been created using either the D8 or Rho8 tools."));
@@ -355,11 +359,11 @@ impl WhiteboxTool for StrahlerStreamOrder {
                                 x += d_x[pntr_matches[dir]];
                                 y += d_y[pntr_matches[dir]];
 
-                                if streams[(y, x)] <= 0.0 {
+                                if streams.get_value(y, x) <= 0.0 {
                                     //it's not a stream cell
                                     flag = false;
                                 } else {
-                                    current_value = output[(y, x)];
+                                    current_value = output.get_value(y, x);
                                     if current_value > current_order {
                                         //flag = false; // run into a larger stream, end the downstream search
                                         break;
@@ -369,9 +373,9 @@ impl WhiteboxTool for StrahlerStreamOrder {
                                         for d in 0..8 {
                                             x2 = x + d_x[d];
                                             y2 = y + d_y[d];
-                                            if streams[(y2, x2)] > 0.0
-                                                && pntr[(y2, x2)] == inflowing_vals[d]
-                                                && output[(y2, x2)] == current_order
+                                            if streams.get_value(y2, x2) > 0.0
+                                                && pntr.get_value(y2, x2) == inflowing_vals[d]
+                                                && output.get_value(y2, x2) == current_order
                                             {
                                                 num_neighbouring_stream_cells += 1;
                                             }
@@ -387,11 +391,11 @@ impl WhiteboxTool for StrahlerStreamOrder {
                                         }
                                     }
                                     if current_value < current_order {
-                                        output[(y, x)] = current_order;
+                                        output.set_value(y, x, current_order);
                                     }
                                 }
                             } else {
-                                if streams[(y, x)] > 0.0 {
+                                if streams.get_value(y, x) > 0.0 {
                                     //it is a valid stream cell and probably just has no downslope neighbour (e.g. at the edge of the grid)
                                     output.increment(y, x, 1.0);
                                 }
@@ -400,25 +404,23 @@ impl WhiteboxTool for StrahlerStreamOrder {
                         }
                     }
                 } else {
-                    if pntr[(row, col)] != pntr_nodata {
-                        output[(row, col)] = background_val;
+                    if pntr.get_value(row, col) != pntr_nodata {
+                        output.set_value(row, col, background_val);
                     } else {
-                        output[(row, col)] = streams_nodata;
+                        output.set_value(row, col, streams_nodata);
                     }
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         if verbose {
-            println!("Max stream order: {}", max_stream_order);
+            context.info(format!("Max stream order: {}", max_stream_order));
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
@@ -437,21 +439,18 @@ impl WhiteboxTool for StrahlerStreamOrder {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
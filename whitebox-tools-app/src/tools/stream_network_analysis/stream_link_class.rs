@@ -167,8 +167,9 @@ impl WhiteboxTool for StreamLinkClass {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut d8_file = String::new();
         let mut streams_file = String::new();
         let mut output_file = String::new();
@@ -228,11 +229,11 @@ impl WhiteboxTool for StreamLinkClass {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -251,12 +252,12 @@ impl WhiteboxTool for StreamLinkClass {
         }
 
         if verbose {
-            println!("Reading pointer data...")
+            context.info("Reading pointer data...")
         };
         let pntr = Raster::new(&d8_file, "r")?;
         let pntr_nodata = pntr.configs.nodata;
         if verbose {
-            println!("Reading streams data...")
+            context.info("Reading streams data...")
         };
         let streams = Raster::new(&streams_file, "r")?;
 
@@ -281,6 +282,7 @@ impl WhiteboxTool for StreamLinkClass {
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &streams);
+        output.widen_to_f64();
         let mut stack = Vec::with_capacity((rows * columns) as usize);
 
         // calculate the number of inflowing cells
@@ -295,11 +297,11 @@ impl WhiteboxTool for StreamLinkClass {
         let mut count: i8;
         for row in 0..rows {
             for col in 0..columns {
-                if streams[(row, col)] > 0.0 {
+                if streams.get_value(row, col) > 0.0 {
                     count = 0i8;
                     for i in 0..8 {
-                        if streams[(row + dy[i], col + dx[i])] > 0.0
-                            && pntr[(row + dy[i], col + dx[i])] == inflowing_vals[i]
+                        if streams.get_value(row + dy[i], col + dx[i]) > 0.0
+                            && pntr.get_value(row + dy[i], col + dx[i]) == inflowing_vals[i]
                         {
                             count += 1;
                         }
@@ -308,23 +310,21 @@ impl WhiteboxTool for StreamLinkClass {
                     if count == 0 {
                         // It's a headwater; add it to the stack
                         stack.push((row, col));
-                        output[(row, col)] = 3f64;
+                        output.set_value(row, col, 3f64);
                     }
                 } else {
-                    if pntr[(row, col)] != pntr_nodata {
-                        output[(row, col)] = background_val;
+                    if pntr.get_value(row, col) != pntr_nodata {
+                        output.set_value(row, col, background_val);
                     } else {
-                        output[(row, col)] = nodata;
+                        output.set_value(row, col, nodata);
                     }
                     num_solved_cells += 1;
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -366,10 +366,10 @@ impl WhiteboxTool for StreamLinkClass {
             let cell = stack.pop().expect("Error during pop operation.");
             row = cell.0;
             col = cell.1;
-            val = output[(row, col)];
+            val = output.get_value(row, col);
 
             // find the downstream cell
-            dir = pntr[(row, col)] as usize;
+            dir = pntr.get_value(row, col) as usize;
             if dir > 0 {
                 if dir > 128 || pntr_matches[dir] == 999 {
                     return Err(Error::new(ErrorKind::InvalidInput,
@@ -381,14 +381,14 @@ impl WhiteboxTool for StreamLinkClass {
                 col_n = col + dx[c];
 
                 if num_inflowing[(row_n, col_n)] > 1 {
-                    output[(row_n, col_n)] = 4f64;
-                } else if output[(row_n, col_n)] == nodata {
+                    output.set_value(row_n, col_n, 4f64);
+                } else if output.get_value(row_n, col_n) == nodata {
                     // i.e. it hasn't already been assigned a value like at a confluence
                     if val == 3f64 || val == 1f64 {
-                        output[(row_n, col_n)] = 1f64;
+                        output.set_value(row_n, col_n, 1f64);
                     } else {
                         //if val == 4f64 || val == 2f64 {
-                        output[(row_n, col_n)] = 2f64;
+                        output.set_value(row_n, col_n, 2f64);
                     }
                 }
 
@@ -397,15 +397,13 @@ impl WhiteboxTool for StreamLinkClass {
                     stack.push((row_n, col_n));
                 }
             } else {
-                output[(row, col)] = 5f64;
+                output.set_value(row, col, 5f64);
             }
 
-            if verbose {
-                progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * num_solved_cells as f64 / (num_cells - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -430,29 +428,26 @@ impl WhiteboxTool for StreamLinkClass {
         output.add_metadata_entry(format!("5      Sink node (outlet)"));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
-        println!("CLASSIFICATION KEY");
-        println!("Value  Class");
-        println!("1      Exterior link");
-        println!("2      Interior link");
-        println!("3      Source node (headwater)");
-        println!("4      Link node (confluence)");
-        println!("5      Sink node (outlet)");
+        context.info("CLASSIFICATION KEY");
+        context.info("Value  Class");
+        context.info("1      Exterior link");
+        context.info("2      Interior link");
+        context.info("3      Source node (headwater)");
+        context.info("4      Link node (confluence)");
+        context.info("5      Sink node (outlet)");
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
         Ok(())
     }
@@ -166,8 +166,9 @@ impl WhiteboxTool for StreamSlopeContinuous {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut d8_file = String::new();
         let mut streams_file = String::new();
         let mut dem_file = String::new();
@@ -234,11 +235,11 @@ impl WhiteboxTool for StreamSlopeContinuous {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -260,16 +261,16 @@ impl WhiteboxTool for StreamSlopeContinuous {
         }
 
         if verbose {
-            println!("Reading pointer data...")
+            context.info("Reading pointer data...")
         };
         let pntr = Arc::new(Raster::new(&d8_file, "r")?);
         let pntr_nodata = pntr.configs.nodata;
         if verbose {
-            println!("Reading link ID data...")
+            context.info("Reading link ID data...")
         };
         let streams = Arc::new(Raster::new(&streams_file, "r")?);
         if verbose {
-            println!("Reading DEM data...")
+            context.info("Reading DEM data...")
         };
         let dem = Arc::new(Raster::new(&dem_file, "r")?);
 
@@ -379,21 +380,21 @@ impl WhiteboxTool for StreamSlopeContinuous {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data = vec![nodata; columns as usize];
                     for col in 0..columns {
-                        if streams[(row, col)] > 0f64
-                            && streams[(row, col)] != nodata
-                            && dem[(row, col)] != dem_nodata
-                            && pntr[(row, col)] != pntr_nodata
+                        if streams.get_value(row, col) > 0f64
+                            && streams.get_value(row, col) != nodata
+                            && dem.get_value(row, col) != dem_nodata
+                            && pntr.get_value(row, col) != pntr_nodata
                         {
                             // first get the average elevation and distance of the inflowing stream cells
                             z_inflowing = 0f64;
                             dist = 0f64;
                             n_inflowing = 0f64;
                             for n in 0..8 {
-                                if streams[(row + dy[n], col + dx[n])] > 0.0
-                                    && streams[(row + dy[n], col + dx[n])] != nodata
-                                    && pntr[(row + dy[n], col + dx[n])] == inflowing_vals[n]
+                                if streams.get_value(row + dy[n], col + dx[n]) > 0.0
+                                    && streams.get_value(row + dy[n], col + dx[n]) != nodata
+                                    && pntr.get_value(row + dy[n], col + dx[n]) == inflowing_vals[n]
                                 {
-                                    z_inflowing += dem[(row + dy[n], col + dx[n])];
+                                    z_inflowing += dem.get_value(row + dy[n], col + dx[n]);
                                     dist += grid_lengths[n];
                                     n_inflowing += 1f64;
                                 }
@@ -402,21 +403,21 @@ impl WhiteboxTool for StreamSlopeContinuous {
                                 z_inflowing = z_inflowing / n_inflowing;
                                 dist = dist / n_inflowing;
                             } else {
-                                z_inflowing = dem[(row, col)];
+                                z_inflowing = dem.get_value(row, col);
                             }
 
                             // now find the elevation of the downslope stream cell
-                            dir = pntr[(row, col)] as usize;
+                            dir = pntr.get_value(row, col) as usize;
                             if dir > 0 {
                                 c = pntr_matches[dir];
-                                z_dn = dem[(row + dy[c], col + dx[c])];
+                                z_dn = dem.get_value(row + dy[c], col + dx[c]);
                                 if z_dn != dem_nodata {
                                     dist += grid_lengths[c];
                                 } else {
-                                    z_dn = dem[(row, col)];
+                                    z_dn = dem.get_value(row, col);
                                 }
                             } else {
-                                z_dn = dem[(row, col)];
+                                z_dn = dem.get_value(row, col);
                             }
                             if dist > 0f64 {
                                 data[col as usize] =
@@ -426,7 +427,9 @@ impl WhiteboxTool for StreamSlopeContinuous {
                             }
                         }
                     }
-                    tx1.send((row, data)).unwrap();
+                    if tx1.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -437,12 +440,10 @@ impl WhiteboxTool for StreamSlopeContinuous {
             let data = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(data.0, data.1);
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Performing analysis: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Performing analysis", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -464,21 +465,18 @@ impl WhiteboxTool for StreamSlopeContinuous {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -143,8 +143,9 @@ impl WhiteboxTool for VectorStreamNetworkAnalysis {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut streams_file = String::new();
         let mut dem_file = String::new();
         let mut lakes_file = String::new();
@@ -206,11 +207,11 @@ impl WhiteboxTool for VectorStreamNetworkAnalysis {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -235,7 +236,7 @@ impl WhiteboxTool for VectorStreamNetworkAnalysis {
 
         // read the input DEM
         if verbose {
-            println!("Reading DEM raster...")
+            context.info("Reading DEM raster...")
         };
         let dem = Raster::new(&dem_file, "r")?;
         let rows = dem.configs.rows as isize;
@@ -318,7 +319,12 @@ impl WhiteboxTool for VectorStreamNetworkAnalysis {
         output.attributes.add_field(&AttributeField::new("TRIB_ID", FieldDataType::Int, 6u8, 0u8));
         output.attributes.add_field(&AttributeField::new("DISCONT", FieldDataType::Int, 4u8, 0u8));
         
-        let mut output_nodes = Shapefile::new(&output_file.replace(".shp", "_nodes.shp"), ShapeType::Point)?;
+        // the nodes file is written in the same vector format as the output file
+        let nodes_file = match path::Path::new(&output_file).extension().and_then(|e| e.to_str()) {
+            Some(ext) => format!("{}_nodes.{}", &output_file[..output_file.len() - ext.len() - 1], ext),
+            None => format!("{}_nodes.shp", output_file),
+        };
+        let mut output_nodes = Shapefile::new(&nodes_file, ShapeType::Point)?;
         output_nodes.attributes.add_field(&AttributeField::new("FID", FieldDataType::Int, 6u8, 0u8));
         output_nodes.attributes.add_field(&AttributeField::new("TYPE", FieldDataType::Text, 14u8, 0u8));
         
@@ -354,12 +360,10 @@ impl WhiteboxTool for VectorStreamNetworkAnalysis {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -998,22 +1002,19 @@ impl WhiteboxTool for VectorStreamNetworkAnalysis {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(&format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
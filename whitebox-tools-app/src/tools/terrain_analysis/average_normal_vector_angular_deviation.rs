@@ -144,8 +144,9 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut filter_size = 11usize;
@@ -196,11 +197,11 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -233,7 +234,7 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -249,7 +250,7 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
         // Smooth the DEM
         let mut smoothed_dem = input.get_data_as_array2d();
         if verbose {
-            println!("Smoothing the input DEM...");
+            context.info("Smoothing the input DEM...");
         }
         let mut sigma = (midpoint as f64 + 0.5) / 3f64;
         if sigma < 1.0 {
@@ -347,7 +348,9 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
                             }
                         }
 
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -385,7 +388,7 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
 
             for iteration_num in 0..n {
                 if verbose {
-                    println!("Loop {} of {}", iteration_num + 1, n);
+                    context.info(format!("Loop {} of {}", iteration_num + 1, n));
                 }
 
                 let midpoint = if iteration_num <= m {
@@ -552,7 +555,9 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
                             .to_degrees();
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -564,12 +569,10 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
             let data = rx.recv().expect("Error receiving data from thread.");
             angular_diff.set_row_data(data.0, data.1);
             // output.set_row_data(data.0, angular_diff.get_row_data(data.0));
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Calculating angular deviation data: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Calculating angular deviation data", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -604,12 +607,10 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Creating integral images: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Creating integral images", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -675,14 +676,8 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
                         }
                     }
 
-                    match tx2.send((row, data)) {
-                        Ok(_) => {}
-                        Err(_) => {
-                            println!(
-                                "Error sending data from thread {} processing row {}.",
-                                tid, row
-                            );
-                        }
+                    if tx2.send((row, data)).is_err() {
+                        break;
                     }
                 }
             });
@@ -708,12 +703,10 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Performing analysis: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Performing analysis", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -728,22 +721,19 @@ impl WhiteboxTool for AverageNormalVectorAngularDeviation {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -191,8 +191,9 @@ impl WhiteboxTool for ContoursFromPoints {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file: String = "".to_string();
         let mut field_name = String::new();
         let mut use_z = false;
@@ -316,11 +317,11 @@ impl WhiteboxTool for ContoursFromPoints {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if !input_file.contains(path::MAIN_SEPARATOR) && !input_file.contains("/") {
@@ -412,13 +413,11 @@ impl WhiteboxTool for ContoursFromPoints {
                 }
             }
 
-            if verbose {
-                progress =
-                    (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
-                if progress != old_progress {
-                    println!("Reading points: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * (record_num + 1) as f64 / input.num_records as f64) as usize;
+            if progress != old_progress {
+                context.progress("Reading points", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -431,7 +430,7 @@ impl WhiteboxTool for ContoursFromPoints {
             ));
         }
         if verbose {
-            println!("Performing triangulation...");
+            context.info("Performing triangulation...");
         }
         // this is where the heavy-lifting is
         let result = triangulate(&points).expect("No triangulation exists.");
@@ -539,7 +538,7 @@ impl WhiteboxTool for ContoursFromPoints {
                     }
 
                     if num_intersections != 2 && verbose {
-                        println!("Warning: An error occurred during the contouring operation.");
+                        context.warning("Warning: An error occurred during the contouring operation.");
                     }
 
                     if intersect2 && intersect3 {
@@ -563,12 +562,10 @@ impl WhiteboxTool for ContoursFromPoints {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * i as f64 / (result.triangles.len() - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (Loop 1 of 4): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / (result.triangles.len() - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (Loop 1 of 4)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -610,12 +607,10 @@ impl WhiteboxTool for ContoursFromPoints {
                 }
             }
                 
-            if verbose {
-                progress = (100.0_f64 * i as f64 / (num_points - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (Loop 2 of 4): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / (num_points - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (Loop 2 of 4)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -798,12 +793,10 @@ impl WhiteboxTool for ContoursFromPoints {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / (num_points - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (Loop 3 of 4): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / (num_points - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (Loop 3 of 4)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -949,22 +942,20 @@ impl WhiteboxTool for ContoursFromPoints {
                     fid += 1;
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * i as f64 / (num_points - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (Loop 4 of 4): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * i as f64 / (num_points - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (Loop 4 of 4)", progress)?;
+                old_progress = progress;
             }
         }
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -973,7 +964,7 @@ impl WhiteboxTool for ContoursFromPoints {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("{}", &format!("Elapsed Time: {}", elapsed_time));
+            context.info(format!("Elapsed Time: {}", elapsed_time));
         }
 
         Ok(())
@@ -173,8 +173,9 @@ impl WhiteboxTool for ContoursFromRaster {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut contour_interval = 10f64;
@@ -285,11 +286,11 @@ impl WhiteboxTool for ContoursFromRaster {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -302,7 +303,7 @@ impl WhiteboxTool for ContoursFromRaster {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r").expect("Error reading input raster."));
@@ -364,7 +365,9 @@ impl WhiteboxTool for ContoursFromRaster {
                             data[col as usize] = ((z - base_contour) / contour_interval).floor();
                         }
                     }
-                    tx.send((row, data)).expect("Error sending data to thread.");
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -374,12 +377,10 @@ impl WhiteboxTool for ContoursFromRaster {
             let (row, data) = rx.recv().expect("Error receiving data from thread.");
             reclassed.set_row_data(row, data);
 
-            if verbose {
-                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Reclassifying surface: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Reclassifying surface", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -460,12 +461,10 @@ impl WhiteboxTool for ContoursFromRaster {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Finding edges: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Finding edges", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -701,13 +700,11 @@ impl WhiteboxTool for ContoursFromRaster {
                     }
                 }
             }
-            if verbose {
-                progress =
-                    (100.0_f64 * line_segment as f64 / (line_segments.len() - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Tracing contours (Loop 1 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * line_segment as f64 / (line_segments.len() - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Tracing contours (Loop 1 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -905,13 +902,11 @@ impl WhiteboxTool for ContoursFromRaster {
                     }
                 }
             }
-            if verbose {
-                progress =
-                    (100.0_f64 * line_segment as f64 / (line_segments.len() - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Tracing contours (Loop 2 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * line_segment as f64 / (line_segments.len() - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Tracing contours (Loop 2 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -920,21 +915,18 @@ impl WhiteboxTool for ContoursFromRaster {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
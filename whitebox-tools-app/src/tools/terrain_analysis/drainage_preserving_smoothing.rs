@@ -204,8 +204,9 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut filter_size = 11usize;
@@ -295,11 +296,11 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if filter_size < 3 {
@@ -338,7 +339,7 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         }
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -351,7 +352,7 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
             if mid_lat <= 90.0 && mid_lat >= -90.0 {
                 mid_lat = mid_lat.to_radians();
                 z_factor = 1.0 / (111320.0 * mid_lat.cos());
-                println!("It appears that the DEM is in geographic coordinates. The z-factor has been updated: {}.", z_factor);
+                context.info(format!("It appears that the DEM is in geographic coordinates. The z-factor has been updated: {}.", z_factor));
             }
         } else if z_factor < 0.0 {
             z_factor = 1.0;
@@ -397,7 +398,9 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
                             data[col as usize] = val;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -405,12 +408,10 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
         for row in 0..rows {
             let data = rx.recv().expect("Error receiving data from thread.");
             binned_data.set_row_data(data.0, data.1);
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Binning elevations: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Binning elevations", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -531,7 +532,9 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
                             old_median = bin_nodata;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -540,12 +543,10 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
         for row in 0..rows {
             let data = rx.recv().expect("Error receiving data from thread.");
             dfm_data.set_row_data(data.0, data.1);
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Calculating topographic position: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Calculating topographic position", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -606,7 +607,9 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
                             };
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -621,24 +624,22 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
             let data = rx.recv().expect("Error receiving data from thread.");
             nv.set_row_data(data.0, data.1);
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Calculating normal vectors: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Calculating normal vectors", progress)?;
+                old_progress = progress;
             }
         }
 
         let t1 = Instant::now();
         if verbose {
-            println!(
+            context.info(format!(
                 "{}",
                 format!(
                     "Calculating normal vectors: {}",
                     get_formatted_elapsed_time(start)
                 )
-            );
+            ));
         }
 
         //////////////////////////////////////////////////////////
@@ -725,7 +726,9 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
                             data[col as usize] = Normal { a: a, b: b, c: c };
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -735,23 +738,21 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
             let data = rx.recv().expect("Error receiving data from thread.");
             nv_smooth.set_row_data(data.0, data.1);
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Smoothing normal vectors: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Smoothing normal vectors", progress)?;
+                old_progress = progress;
             }
         }
 
         if verbose {
-            println!(
+            context.info(format!(
                 "{}",
                 format!(
                     "Smoothing normal vectors: {}",
                     get_formatted_elapsed_time(t1)
                 )
-            );
+            ));
         }
 
         ///////////////////////////////////////////////////////////////////////////
@@ -772,13 +773,14 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
         let (mut xn, mut yn): (isize, isize);
         let mut zn: f64;
         let mut output = Raster::initialize_using_file(&output_file, &input);
+        output.widen_to_f64();
         output.set_data_from_raster(&input)?;
         if verbose {
-            println!("Updating elevations...");
+            context.info("Updating elevations...");
         }
         for loop_num in 0..num_iter {
             if verbose {
-                println!("Iteration {} of {}...", loop_num + 1, num_iter);
+                context.info(format!("Iteration {} of {}...", loop_num + 1, num_iter));
             }
 
             for row in 0..rows {
@@ -831,17 +833,10 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!(
-                            "Updating DEM elevations (Loop {} of {}): {}%",
-                            loop_num + 1,
-                            num_iter,
-                            progress
-                        );
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Updating DEM elevations (Loop {} of {})", loop_num + 1, num_iter), progress)?;
+                    old_progress = progress;
                 }
             }
         }
@@ -865,21 +860,18 @@ impl WhiteboxTool for DrainagePreservingSmoothing {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(&format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -129,8 +129,9 @@ impl WhiteboxTool for ElevRelativeToWatershedMinMax {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut watersheds_file = String::new();
         let mut output_file = String::new();
@@ -180,11 +181,11 @@ impl WhiteboxTool for ElevRelativeToWatershedMinMax {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -203,7 +204,7 @@ impl WhiteboxTool for ElevRelativeToWatershedMinMax {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -251,8 +252,8 @@ impl WhiteboxTool for ElevRelativeToWatershedMinMax {
                 let mut watershed: f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     for col in 0..columns {
-                        z = input[(row, col)];
-                        watershed = watersheds[(row, col)];
+                        z = input.get_value(row, col);
+                        watershed = watersheds.get_value(row, col);
                         if z != nodata && watershed != watershed_nodata {
                             watershed -= min_watershed;
                             if z < watershed_min_vals[watershed as usize] {
@@ -264,7 +265,7 @@ impl WhiteboxTool for ElevRelativeToWatershedMinMax {
                         }
                     }
                 }
-                tx.send((watershed_min_vals, watershed_max_vals)).unwrap();
+                let _ = tx.send((watershed_min_vals, watershed_max_vals));
             });
         }
 
@@ -281,12 +282,10 @@ impl WhiteboxTool for ElevRelativeToWatershedMinMax {
                     watershed_max_vals[i] = maxs[i];
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * tid as f64 / (num_procs - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * tid as f64 / (num_procs - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -303,8 +302,8 @@ impl WhiteboxTool for ElevRelativeToWatershedMinMax {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data = vec![nodata; columns as usize];
                     for col in 0..columns {
-                        z = input[(row, col)];
-                        watershed = watersheds[(row, col)];
+                        z = input.get_value(row, col);
+                        watershed = watersheds.get_value(row, col);
                         if z != nodata && watershed != watershed_nodata {
                             watershed -= min_watershed;
                             data[col as usize] = (z - watershed_min_vals[watershed as usize])
@@ -313,7 +312,9 @@ impl WhiteboxTool for ElevRelativeToWatershedMinMax {
                                 * 100f64;
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -321,12 +322,10 @@ impl WhiteboxTool for ElevRelativeToWatershedMinMax {
         for r in 0..rows {
             let (row, data) = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(row, data);
-            if verbose {
-                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -340,21 +339,18 @@ impl WhiteboxTool for ElevRelativeToWatershedMinMax {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -236,8 +236,9 @@ impl WhiteboxTool for EmbankmentMapping {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut roads_file = String::new();
         let mut output_file = String::new();
@@ -381,11 +382,11 @@ impl WhiteboxTool for EmbankmentMapping {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -435,7 +436,7 @@ impl WhiteboxTool for EmbankmentMapping {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let dem = Raster::new(&input_file, "r").expect("Error reading input DEM.");
@@ -448,7 +449,7 @@ impl WhiteboxTool for EmbankmentMapping {
         ////////////////////////////////////////////////
 
         if verbose {
-            println!("Rasterizing transportation vector...");
+            context.info("Rasterizing transportation vector...");
         }
 
         // make sure the input vector file is of polyline type
@@ -594,33 +595,23 @@ impl WhiteboxTool for EmbankmentMapping {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
-                if progress != old_progress {
-                    println!(
-                        "Rasterizing roads {} of {}: {}%",
-                        record_num + 1,
-                        num_records,
-                        progress
-                    );
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * (record_num + 1) as f64 / num_records as f64) as usize;
+            if progress != old_progress {
+                context.progress(&format!("Rasterizing roads {} of {}", record_num + 1, num_records), progress)?;
+                old_progress = progress;
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
-                "Time for transportation vector rasterization: {}",
-                elapsed_time
-            );
+            context.info(format!("Time for transportation vector rasterization: {}", elapsed_time));
         }
         let start2 = Instant::now();
 
         // Now perform the analysis. //
 
         if verbose {
-            println!("Mapping embankment cells...");
+            context.info("Mapping embankment cells...");
         }
 
         let nodata = dem.configs.nodata;
@@ -628,6 +619,7 @@ impl WhiteboxTool for EmbankmentMapping {
         let dy = [-1, 0, 1, 1, 1, 0, -1, -1];
 
         let mut output = Raster::initialize_using_file(&output_file, &dem);
+        output.widen_to_f64();
 
         let mut zn: f64;
         let mut maxval: f64;
@@ -719,8 +711,8 @@ impl WhiteboxTool for EmbankmentMapping {
                 }
             }
             progress = (100.0 * row as f64 / (rows - 1) as f64) as usize;
-            if progress != old_progress && verbose {
-                println!("Progress: {}%", progress);
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
                 old_progress = progress;
             }
         }
@@ -762,13 +754,11 @@ impl WhiteboxTool for EmbankmentMapping {
                 }
             }
 
-            if verbose {
-                num_mapped_cells += 1f64;
-                progress = (100.0 * num_mapped_cells / (num_cells as f64)) as usize;
-                if progress != old_progress {
-                    println!("Percent of total cells parameterized: {}%", progress);
-                    old_progress = progress;
-                }
+            num_mapped_cells += 1f64;
+            progress = (100.0 * num_mapped_cells / (num_cells as f64)) as usize;
+            if progress != old_progress {
+                context.progress("Percent of total cells parameterized", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -839,15 +829,15 @@ impl WhiteboxTool for EmbankmentMapping {
 
             num_mapped_cells += 1f64;
             progress = (100.0 * num_mapped_cells / total_cells_in_buffer) as usize;
-            if progress != old_progress && verbose {
-                println!("Progress: {}%", progress);
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
                 old_progress = progress;
             }
         }
 
         if verbose {
             let elapsed_time = get_formatted_elapsed_time(start2);
-            println!("Time for embankment mapping: {}", elapsed_time);
+            context.info(format!("Time for embankment mapping: {}", elapsed_time));
         }
         let start3 = Instant::now();
 
@@ -855,7 +845,7 @@ impl WhiteboxTool for EmbankmentMapping {
 
         if remove_embankments {
             if verbose {
-                println!("Creating embankment-less DEM...");
+                context.info("Creating embankment-less DEM...");
             }
             // Not let's interpolate a surface beneath the road embankments.
             let mut frs: FixedRadiusSearch2D<f64> =
@@ -882,12 +872,10 @@ impl WhiteboxTool for EmbankmentMapping {
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f32 * row as f32 / (rows - 1) as f32) as usize;
-                    if progress != old_progress {
-                        println!("Finding edge cells: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f32 * row as f32 / (rows - 1) as f32) as usize;
+                if progress != old_progress {
+                    context.progress("Finding edge cells", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -928,18 +916,16 @@ impl WhiteboxTool for EmbankmentMapping {
                         out_dem.set_value(row, col, dem.get_value(row, col));
                     }
                 }
-                if verbose {
-                    progress = (100.0_f32 * row as f32 / (rows - 1) as f32) as usize;
-                    if progress != old_progress {
-                        println!("Interpolating data holes: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f32 * row as f32 / (rows - 1) as f32) as usize;
+                if progress != old_progress {
+                    context.progress("Interpolating data holes", progress)?;
+                    old_progress = progress;
                 }
             }
 
             if verbose {
                 let elapsed_time = get_formatted_elapsed_time(start3);
-                println!("Time to create embankment-less DEM: {}", elapsed_time);
+                context.info(format!("Time to create embankment-less DEM: {}", elapsed_time));
             }
 
             total_time = get_formatted_elapsed_time(start);
@@ -951,12 +937,12 @@ impl WhiteboxTool for EmbankmentMapping {
             out_dem.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", total_time));
 
             if verbose {
-                println!("Saving data...")
+                context.info("Saving data...")
             };
             let _ = match out_dem.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
@@ -971,21 +957,18 @@ impl WhiteboxTool for EmbankmentMapping {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", total_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", total_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", total_time));
         }
 
         Ok(())
@@ -212,8 +212,9 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut filter_size = 11usize;
@@ -317,11 +318,11 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         if filter_size < 3 {
@@ -348,7 +349,7 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         }
 
         let input_dem = Raster::new(&input_file, "r")?;
@@ -361,7 +362,7 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
             if mid_lat <= 90.0 && mid_lat >= -90.0 {
                 mid_lat = mid_lat.to_radians();
                 z_factor = (1.0 / (111320.0 * mid_lat.cos())) as f32;
-                println!("It appears that the DEM is in geographic coordinates. The z-factor has been updated to {}.", z_factor);
+                context.info(format!("It appears that the DEM is in geographic coordinates. The z-factor has been updated to {}.", z_factor));
             }
         } else if z_factor < 0.0 {
             z_factor = 1.0;
@@ -438,7 +439,9 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
                             data[col as usize] = Normal { a: a, b: b };
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -449,24 +452,19 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
             let data = rx.recv().expect("Error receiving data from thread.");
             nv.set_row_data(data.0, data.1);
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Calculating normal vectors: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Calculating normal vectors", progress)?;
+                old_progress = progress;
             }
         }
 
         let t1 = Instant::now();
         if verbose {
-            println!(
-                "{}",
-                format!(
+            context.info(format!(
                     "Calculating normal vectors: {}",
                     get_formatted_elapsed_time(start)
-                )
-            );
+                ).to_string());
         }
 
         //////////////////////////////////////////////////////////
@@ -529,7 +527,9 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
                             data[col as usize] = Normal { a: a, b: b };
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -539,23 +539,18 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
             let data = rx.recv().expect("Error receiving data from thread.");
             nv_smooth.set_row_data(data.0, data.1);
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Smoothing normal vectors: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Smoothing normal vectors", progress)?;
+                old_progress = progress;
             }
         }
 
         if verbose {
-            println!(
-                "{}",
-                format!(
+            context.info(format!(
                     "Smoothing normal vectors: {}",
                     get_formatted_elapsed_time(t1)
-                )
-            );
+                ).to_string());
         }
 
         drop(nv);
@@ -585,11 +580,11 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
         // let mut output = Arc::try_unwrap(input).unwrap_err().clone();
 
         if verbose {
-            println!("Updating elevations...");
+            context.info("Updating elevations...");
         }
         for loop_num in 0..num_iter {
             if verbose {
-                println!("Iteration {} of {}...", loop_num + 1, num_iter);
+                context.info(format!("Iteration {} of {}...", loop_num + 1, num_iter));
             }
 
             for row in 0..rows {
@@ -628,17 +623,10 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!(
-                            "Updating DEM elevations (Loop {} of {}): {}%",
-                            loop_num + 1,
-                            num_iter,
-                            progress
-                        );
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Updating DEM elevations (Loop {} of {})", loop_num + 1, num_iter), progress)?;
+                    old_progress = progress;
                 }
             }
         }
@@ -667,21 +655,18 @@ impl WhiteboxTool for FeaturePreservingSmoothing {
         output_raster.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         }
         let _ = match output_raster.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -166,8 +166,9 @@ impl WhiteboxTool for FillMissingData {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut filter_size = 11usize;
@@ -236,11 +237,11 @@ impl WhiteboxTool for FillMissingData {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -263,7 +264,7 @@ impl WhiteboxTool for FillMissingData {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Raster::new(&input_file, "r")?;
@@ -281,7 +282,7 @@ impl WhiteboxTool for FillMissingData {
 
         if exclude_edge_nodata {
             if verbose {
-                println!("Finding edge-connected NoData values...");
+                context.info("Finding edge-connected NoData values...");
             }
             // Scan the raster edges, placing NoData values in a stack
             let mut stack = vec![]; // Vec::with_capacity(((rows + columns) * 5) as usize);
@@ -320,21 +321,19 @@ impl WhiteboxTool for FillMissingData {
                         edge_connected_nodata.set_value(cell.0 + dy[n], cell.1 + dx[n], 1);
                     }
                 }
-                if verbose {
-                    num_cells_popped += 1;
-                    progress = (100.0_f64 * num_cells_popped as f64 / (rows * columns - 1) as f64)
-                        as usize;
-                    if progress != old_progress {
-                        println!("Finding edge-connected NoData cells: {}%", progress);
-                        old_progress = progress;
-                    }
+                num_cells_popped += 1;
+                progress = (100.0_f64 * num_cells_popped as f64 / (rows * columns - 1) as f64)
+                    as usize;
+                if progress != old_progress {
+                    context.progress("Finding edge-connected NoData cells", progress)?;
+                    old_progress = progress;
                 }
             }
         }
 
         // Interpolate the data holes. Start by locating all the edge cells.
         if verbose {
-            println!("Interpolating data holes...")
+            context.info("Interpolating data holes...")
         };
         let mut frs: FixedRadiusSearch2D<f64> =
             FixedRadiusSearch2D::new(filter_size as f64, DistanceMetric::Euclidean);
@@ -350,18 +349,16 @@ impl WhiteboxTool for FillMissingData {
                         if input.get_value(row_n, col_n) == nodata
                             && edge_connected_nodata.get_value(row_n, col_n) == 0
                         {
-                            frs.insert(col as f64, row as f64, input[(row, col)]);
+                            frs.insert(col as f64, row as f64, input.get_value(row, col));
                             break;
                         }
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Interpolating data holes: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Interpolating data holes", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -415,10 +412,12 @@ impl WhiteboxTool for FillMissingData {
                                         data[col as usize] = nodata;
                                     }
                                 } else {
-                                    data[col as usize] = input[(row, col)];
+                                    data[col as usize] = input.get_value(row, col);
                                 }
                             }
-                            tx1.send((row, data)).unwrap();
+                            if tx1.send((row, data)).is_err() {
+                                break;
+                            }
                         }
                     }
                     _ => {
@@ -449,10 +448,12 @@ impl WhiteboxTool for FillMissingData {
                                         data[col as usize] = nodata;
                                     }
                                 } else {
-                                    data[col as usize] = input[(row, col)];
+                                    data[col as usize] = input.get_value(row, col);
                                 }
                             }
-                            tx1.send((row, data)).unwrap();
+                            if tx1.send((row, data)).is_err() {
+                                break;
+                            }
                         }
                     }
                 }
@@ -492,12 +493,10 @@ impl WhiteboxTool for FillMissingData {
         for row in 0..rows {
             let data = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(data.0, data.1);
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Performing analysis: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Performing analysis", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -512,21 +511,18 @@ impl WhiteboxTool for FillMissingData {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
@@ -143,7 +143,8 @@ impl WhiteboxTool for Geomorphons {
         self.toolbox.clone()
     }
 
-    fn run<'a>(&self, args: Vec<String>, working_directory: &'a str, verbose: bool) -> Result<(), Error> {
+    fn run<'a>(&self, args: Vec<String>, working_directory: &'a str, context: &ToolContext) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut search_radius: usize = 3;
@@ -206,11 +207,11 @@ impl WhiteboxTool for Geomorphons {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -231,7 +232,7 @@ impl WhiteboxTool for Geomorphons {
         if flat_dist >= search_radius { flat_dist = 0; }
         let flat_threshold_height = flat_thresh.tan() * (flat_dist) as f64;
 
-        if verbose { println!("Reading data...") };
+        if verbose { context.info("Reading data...") };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let mut output = Raster::initialize_using_file(&output_file, &input);
@@ -247,7 +248,7 @@ impl WhiteboxTool for Geomorphons {
 
         // generate global ternary codes
         if verbose {
-            println!("Generating global ternary codes...");
+            context.info("Generating global ternary codes...");
         }
         let max_codes: u32 = 6561; // = 3^8 for 8-bit ternary
         let num_procs = num_cpus::get() as usize;
@@ -305,7 +306,9 @@ impl WhiteboxTool for Geomorphons {
                     if code < rev_code {
                         data = code;
                     } else { data = rev_code; }
-                    tx.send((val, data)).unwrap();
+                    if tx.send((val, data)).is_err() {
+                        break;
+                    }
                 }
             });
         };
@@ -317,7 +320,7 @@ impl WhiteboxTool for Geomorphons {
         }
 
         // main loop
-        if verbose { println!("Computing geomorphons..."); }
+        if verbose { context.info("Computing geomorphons..."); }
 
         let classes: [[u8; 9]; 9] = [                               // 1  = Flat
                                     [1, 1, 1, 8, 8, 9, 9, 9,10],    // 2  = Peak // Summit
@@ -483,7 +486,9 @@ impl WhiteboxTool for Geomorphons {
                             }
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -491,12 +496,10 @@ impl WhiteboxTool for Geomorphons {
         for row in 0..rows {
             let data = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(data.0, data.1);
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -515,19 +518,19 @@ impl WhiteboxTool for Geomorphons {
                                       .replace("PT", ""));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!("{}",
-                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
+            context.info(format!("{}",
+                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")));
         }
 
         Ok(())
@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::structures::Array2D;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -130,6 +131,124 @@ impl Hillshade {
             example_usage: usage,
         }
     }
+
+    // Calculates the hillshade of a DEM that is too large to be held in memory, one band of
+    // rows at a time.
+    fn run_cached(
+        &self,
+        mut input: CachedRaster,
+        input_file: &str,
+        output_file: &str,
+        illumination: Illumination,
+        context: &ToolContext,
+    ) -> Result<(), Error> {
+        let verbose = context.verbose;
+        let start = Instant::now();
+
+        let mut configs = input.configs.clone();
+        configs.data_type = DataType::I16;
+        configs.nodata = -32768f64;
+        let mut output = CachedRaster::initialize_using_config(output_file, &configs)?;
+        output.set_cache_size(input.get_cache_size());
+        let out_nodata = output.configs.nodata;
+        let nodata = input.configs.nodata;
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+
+        let mut num_procs = num_cpus::get() as isize;
+        let configs = whitebox_common::configs::get_configs()?;
+        let max_procs = configs.max_procs;
+        if max_procs > 0 && max_procs < num_procs {
+            num_procs = max_procs;
+        }
+
+        let mut histo: [f64; 32768] = [0.0; 32768];
+        let mut num_cells = 0.0;
+        let band_height = input.get_row_band_height() as isize;
+        let mut band_start = 0isize;
+        while band_start < rows {
+            let band_rows = band_height.min(rows - band_start);
+            // the window includes the row above and below the band.
+            let window = Arc::new(input.read_window(
+                band_start - 1,
+                0,
+                (band_rows + 2) as usize,
+                columns as usize,
+            )?);
+            let (tx, rx) = mpsc::channel();
+            for tid in 0..num_procs {
+                let window = window.clone();
+                let tx1 = tx.clone();
+                thread::spawn(move || {
+                    for row in (0..band_rows).filter(|r| r % num_procs == tid) {
+                        let mut data = vec![out_nodata; columns as usize];
+                        for col in 0..columns {
+                            if window[(row + 1, col)] != nodata {
+                                data[col as usize] = calculate_hillshade(
+                                    &|r, c| window.get_value(r, c),
+                                    row + 1,
+                                    col,
+                                    nodata,
+                                    &illumination,
+                                );
+                            }
+                        }
+                        if tx1.send((row, data)).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+
+            let mut band: Array2D<f64> = Array2D::new(band_rows, columns, out_nodata, out_nodata)?;
+            for _ in 0..band_rows {
+                let data = rx.recv().expect("Error receiving data from thread.");
+                for col in 0..data.1.len() {
+                    if data.1[col] != out_nodata {
+                        histo[data.1[col] as usize] += 1.0;
+                        num_cells += 1.0;
+                    }
+                }
+                band.set_row_data(data.0, data.1);
+            }
+            output.write_window(band_start, 0, &band)?;
+            band_start += band_rows;
+
+            context.progress(
+                "Performing analysis",
+                (100.0_f64 * band_start as f64 / rows as f64) as usize,
+            )?;
+        }
+
+        let (new_min, new_max) = display_range(&histo, num_cells);
+        if new_max > new_min {
+            output.configs.display_min = new_min as f64;
+            output.configs.display_max = new_max as f64;
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.configs.palette = "grey.plt".to_string();
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Azimuth: {}", illumination.azimuth));
+        output.add_metadata_entry(format!("Altitude: {}", illumination.altitude));
+        output.add_metadata_entry(format!("Z-factor: {}", illumination.z_factor));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            context.info("Saving data...")
+        };
+        output.write()?;
+        if verbose {
+            context.info("Output file written");
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        Ok(())
+    }
 }
 
 impl WhiteboxTool for Hillshade {
@@ -171,8 +290,9 @@ impl WhiteboxTool for Hillshade {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut azimuth = 315.0f64;
@@ -250,11 +370,11 @@ impl WhiteboxTool for Hillshade {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -269,30 +389,33 @@ impl WhiteboxTool for Hillshade {
             output_file = format!("{}{}", working_directory, output_file);
         }
 
+        // A DEM that is too large to be read into memory is processed one band of rows at a time.
+        if let Some(input) = CachedRaster::open_if_exceeds_memory(&input_file, 2)? {
+            let illumination = Illumination::new(
+                azimuth,
+                altitude,
+                z_factor,
+                &input.configs,
+                input.is_in_geographic_coordinates(),
+            );
+            return self.run_cached(input, &input_file, &output_file, illumination, context);
+        }
+
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
 
         let start = Instant::now();
 
-        azimuth = (azimuth - 90f64).to_radians();
-        altitude = altitude.to_radians();
-        let sin_theta = altitude.sin();
-        let cos_theta = altitude.cos();
-        let eight_grid_res = input.configs.resolution_x * 8.0;
-
-        if input.is_in_geographic_coordinates() && z_factor < 0.0 {
-            // calculate a new z-conversion factor
-            let mut mid_lat = (input.configs.north - input.configs.south) / 2.0;
-            if mid_lat <= 90.0 && mid_lat >= -90.0 {
-                mid_lat = mid_lat.to_radians();
-                z_factor = 1.0 / (111320.0 * mid_lat.cos());
-            }
-        } else if z_factor < 0.0 {
-            z_factor = 1.0;
-        }
+        let illumination = Illumination::new(
+            azimuth,
+            altitude,
+            z_factor,
+            &input.configs,
+            input.is_in_geographic_coordinates(),
+        );
 
         let mut configs = input.configs.clone();
         configs.data_type = DataType::I16;
@@ -314,56 +437,22 @@ impl WhiteboxTool for Hillshade {
             thread::spawn(move || {
                 let nodata = input.configs.nodata;
                 let columns = input.configs.columns as isize;
-                let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
-                let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
-                let mut n: [f64; 8] = [0.0; 8];
-                let mut z: f64;
-                let (mut term1, mut term2, mut term3): (f64, f64, f64);
-                let (mut fx, mut fy): (f64, f64);
-                let mut tan_slope: f64;
-                let mut aspect: f64;
-                let half_pi = PI / 2f64;
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data = vec![out_nodata; columns as usize];
                     for col in 0..columns {
-                        z = input.get_value(row, col);
-                        if z != nodata {
-                            z = z * z_factor;
-                            for c in 0..8 {
-                                n[c] = input.get_value(row + d_y[c], col + d_x[c]);
-                                if n[c] != nodata {
-                                    n[c] = n[c] * z_factor;
-                                } else {
-                                    n[c] = z;
-                                }
-                            }
-                            // calculate slope and aspect
-                            fy = (n[6] - n[4] + 2.0 * (n[7] - n[3]) + n[0] - n[2]) / eight_grid_res;
-                            fx = (n[2] - n[4] + 2.0 * (n[1] - n[5]) + n[0] - n[6]) / eight_grid_res;
-                            tan_slope = (fx * fx + fy * fy).sqrt();
-                            if tan_slope < 0.00017 {
-                                tan_slope = 0.00017;
-                            }
-                            aspect = if fx != 0f64 {
-                                PI - ((fy / fx).atan()) + half_pi * (fx / (fx).abs())
-                            } else {
-                                PI
-                            };
-                            term1 = tan_slope / (1f64 + tan_slope * tan_slope).sqrt();
-                            term2 = sin_theta / tan_slope;
-                            term3 = cos_theta * (azimuth - aspect).sin();
-                            z = term1 * (term2 - term3);
-                            // } else {
-                            //     z = 0.5;
-                            // }
-                            z = z * 32767.0;
-                            if z < 0.0 {
-                                z = 0.0;
-                            }
-                            data[col as usize] = z.round();
+                        if input.get_value(row, col) != nodata {
+                            data[col as usize] = calculate_hillshade(
+                                &|r, c| input.get_value(r, c),
+                                row,
+                                col,
+                                nodata,
+                                &illumination,
+                            );
                         }
                     }
-                    tx1.send((row, data)).unwrap();
+                    if tx1.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -382,37 +471,14 @@ impl WhiteboxTool for Hillshade {
             }
             output.set_row_data(data.0, data.1);
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Performing analysis: {}%", progress);
-                    old_progress = progress;
-                }
-            }
-        }
-
-        let mut new_min = 0;
-        let mut new_max = 0;
-        let clip_percent = 0.01;
-        let target_cell_num = num_cells * clip_percent;
-        let mut sum = 0.0;
-        for c in 0..32768 {
-            sum += histo[c];
-            if sum >= target_cell_num {
-                new_min = c;
-                break;
-            }
-        }
-
-        sum = 0.0;
-        for c in (0..32768).rev() {
-            sum += histo[c];
-            if sum >= target_cell_num {
-                new_max = c;
-                break;
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Performing analysis", progress)?;
+                old_progress = progress;
             }
         }
 
+        let (new_min, new_max) = display_range(&histo, num_cells);
         if new_max > new_min {
             output.configs.display_min = new_min as f64;
             output.configs.display_max = new_max as f64;
@@ -425,29 +491,151 @@ impl WhiteboxTool for Hillshade {
             self.get_tool_name()
         ));
         output.add_metadata_entry(format!("Input file: {}", input_file));
-        output.add_metadata_entry(format!("Azimuth: {}", azimuth));
-        output.add_metadata_entry(format!("Altitude: {}", altitude));
-        output.add_metadata_entry(format!("Z-factor: {}", z_factor));
+        output.add_metadata_entry(format!("Azimuth: {}", illumination.azimuth));
+        output.add_metadata_entry(format!("Altitude: {}", illumination.altitude));
+        output.add_metadata_entry(format!("Z-factor: {}", illumination.z_factor));
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
     }
 }
+
+// The illumination geometry and z-conversion factor used to shade every cell of a DEM.
+#[derive(Clone, Copy)]
+struct Illumination {
+    azimuth: f64,
+    altitude: f64,
+    sin_theta: f64,
+    cos_theta: f64,
+    z_factor: f64,
+    eight_grid_res: f64,
+}
+
+impl Illumination {
+    // Converts the user-specified azimuth and altitude, in degrees, to radians and works out the
+    // z-conversion factor of a DEM with the given configs when none was specified.
+    fn new(
+        azimuth: f64,
+        altitude: f64,
+        mut z_factor: f64,
+        configs: &RasterConfigs,
+        is_geographic: bool,
+    ) -> Illumination {
+        let azimuth = (azimuth - 90f64).to_radians();
+        let altitude = altitude.to_radians();
+        if is_geographic && z_factor < 0.0 {
+            // calculate a new z-conversion factor
+            let mut mid_lat = (configs.north - configs.south) / 2.0;
+            if (-90.0..=90.0).contains(&mid_lat) {
+                mid_lat = mid_lat.to_radians();
+                z_factor = 1.0 / (111320.0 * mid_lat.cos());
+            }
+        } else if z_factor < 0.0 {
+            z_factor = 1.0;
+        }
+        Illumination {
+            azimuth,
+            altitude,
+            sin_theta: altitude.sin(),
+            cos_theta: altitude.cos(),
+            z_factor,
+            eight_grid_res: configs.resolution_x * 8.0,
+        }
+    }
+}
+
+// Calculates the hillshade value of the cell at (`row`, `col`) of a DEM, which must not be
+// nodata. The DEM is read through `input`, which may come from an in-memory `Raster` or a
+// window of a larger one.
+fn calculate_hillshade(
+    input: &impl Fn(isize, isize) -> f64,
+    row: isize,
+    col: isize,
+    nodata: f64,
+    illumination: &Illumination,
+) -> f64 {
+    let Illumination {
+        azimuth,
+        sin_theta,
+        cos_theta,
+        z_factor,
+        eight_grid_res,
+        ..
+    } = *illumination;
+    let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
+    let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
+    let mut n: [f64; 8] = [0.0; 8];
+    let half_pi = PI / 2f64;
+    let mut z = input(row, col) * z_factor;
+    for c in 0..8 {
+        n[c] = input(row + d_y[c], col + d_x[c]);
+        if n[c] != nodata {
+            n[c] *= z_factor;
+        } else {
+            n[c] = z;
+        }
+    }
+    // calculate slope and aspect
+    let fy = (n[6] - n[4] + 2.0 * (n[7] - n[3]) + n[0] - n[2]) / eight_grid_res;
+    let fx = (n[2] - n[4] + 2.0 * (n[1] - n[5]) + n[0] - n[6]) / eight_grid_res;
+    let mut tan_slope = (fx * fx + fy * fy).sqrt();
+    if tan_slope < 0.00017 {
+        tan_slope = 0.00017;
+    }
+    let aspect = if fx != 0f64 {
+        PI - ((fy / fx).atan()) + half_pi * (fx / (fx).abs())
+    } else {
+        PI
+    };
+    let term1 = tan_slope / (1f64 + tan_slope * tan_slope).sqrt();
+    let term2 = sin_theta / tan_slope;
+    let term3 = cos_theta * (azimuth - aspect).sin();
+    z = term1 * (term2 - term3);
+    z *= 32767.0;
+    if z < 0.0 {
+        z = 0.0;
+    }
+    z.round()
+}
+
+// Returns the hillshade values below and above which 1% of the cells fall, which are used as
+// the display minimum and maximum.
+fn display_range(histo: &[f64; 32768], num_cells: f64) -> (usize, usize) {
+    let mut new_min = 0;
+    let mut new_max = 0;
+    let clip_percent = 0.01;
+    let target_cell_num = num_cells * clip_percent;
+    let mut sum = 0.0;
+    for (c, &count) in histo.iter().enumerate() {
+        sum += count;
+        if sum >= target_cell_num {
+            new_min = c;
+            break;
+        }
+    }
+
+    sum = 0.0;
+    for (c, &count) in histo.iter().enumerate().rev() {
+        sum += count;
+        if sum >= target_cell_num {
+            new_max = c;
+            break;
+        }
+    }
+    (new_min, new_max)
+}
@@ -164,8 +164,9 @@ impl WhiteboxTool for MapEmbankments {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut roads_file = String::new();
         let mut railway_file = String::new();
@@ -270,11 +271,11 @@ impl WhiteboxTool for MapEmbankments {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -287,7 +288,7 @@ impl WhiteboxTool for MapEmbankments {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r").expect("Error reading input raster."));
@@ -349,7 +350,9 @@ impl WhiteboxTool for MapEmbankments {
                             data[col as usize] = ((z - base_contour) / contour_interval).floor();
                         }
                     }
-                    tx.send((row, data)).expect("Error sending data to thread.");
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -359,12 +362,10 @@ impl WhiteboxTool for MapEmbankments {
             let (row, data) = rx.recv().expect("Error receiving data from thread.");
             reclassed.set_row_data(row, data);
 
-            if verbose {
-                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Reclassifying surface: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Reclassifying surface", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -445,12 +446,10 @@ impl WhiteboxTool for MapEmbankments {
                 }
             }
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Finding edges: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Finding edges", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -686,13 +685,11 @@ impl WhiteboxTool for MapEmbankments {
                     }
                 }
             }
-            if verbose {
-                progress =
-                    (100.0_f64 * line_segment as f64 / (line_segments.len() - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Tracing contours (Loop 1 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * line_segment as f64 / (line_segments.len() - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Tracing contours (Loop 1 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -890,13 +887,11 @@ impl WhiteboxTool for MapEmbankments {
                     }
                 }
             }
-            if verbose {
-                progress =
-                    (100.0_f64 * line_segment as f64 / (line_segments.len() - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Tracing contours (Loop 2 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * line_segment as f64 / (line_segments.len() - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Tracing contours (Loop 2 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -905,21 +900,18 @@ impl WhiteboxTool for MapEmbankments {
         let elapsed_time = get_formatted_elapsed_time(start);
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(&format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
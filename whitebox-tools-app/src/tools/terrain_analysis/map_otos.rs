@@ -144,8 +144,9 @@ impl WhiteboxTool for MapOffTerrainObjects {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut max_slope = f32::INFINITY;
@@ -210,11 +211,11 @@ impl WhiteboxTool for MapOffTerrainObjects {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -238,7 +239,7 @@ impl WhiteboxTool for MapOffTerrainObjects {
         max_slope = max_slope.to_radians().tan();
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         }
 
         let input_dem = Raster::new(&input_file, "r")?;
@@ -261,10 +262,11 @@ impl WhiteboxTool for MapOffTerrainObjects {
         /////////////////////////////////////////////
 
         if verbose {
-            println!("Performing the region-growing operation...");
+            context.info("Performing the region-growing operation...");
         }
 
         let mut output = Raster::initialize_using_config(&output_file, &configs);
+        output.widen_to_f64();
         output.configs.data_type = DataType::F32;
         output.configs.nodata = -32768.0;
         output.reinitialize_values(-1f64);
@@ -308,12 +310,10 @@ impl WhiteboxTool for MapOffTerrainObjects {
                             }
 
                             num_cells_popped += 1f64;
-                            if verbose {
-                                progress = (100.0_f64 * num_cells_popped / num_cells) as usize;
-                                if progress != old_progress {
-                                    println!("Region growing: {}%", progress);
-                                    old_progress = progress;
-                                }
+                            progress = (100.0_f64 * num_cells_popped / num_cells) as usize;
+                            if progress != old_progress {
+                                context.progress("Region growing", progress)?;
+                                old_progress = progress;
                             }
                         }
 
@@ -354,21 +354,18 @@ impl WhiteboxTool for MapOffTerrainObjects {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         }
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
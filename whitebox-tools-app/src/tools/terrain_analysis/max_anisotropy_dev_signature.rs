@@ -155,8 +155,9 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut points_file = String::new();
         let mut output_file = String::new();
@@ -239,11 +240,11 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -262,7 +263,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
         }
 
         if verbose {
-            println!("Reading DEM data...")
+            context.info("Reading DEM data...")
         };
         let input = Raster::new(&input_file, "r")?;
         let start = Instant::now();
@@ -271,7 +272,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
         let nodata = input.configs.nodata;
 
         if verbose {
-            println!("Reading points data...")
+            context.info("Reading points data...")
         };
         let points = Shapefile::read(&points_file)?;
 
@@ -299,12 +300,10 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
                 series_names.push(format!("Site {}", record_num + 1));
             }
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Finding site row/column values: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Finding site row/column values", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -324,7 +323,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
             sum_sqr = 0f64;
             sum_n = 0;
             for col in 0..columns {
-                val = input[(row, col)];
+                val = input.get_value(row, col);
                 if val == nodata {
                     val = 0f64;
                 } else {
@@ -345,12 +344,10 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
                     i_n[(row, col)] = sum_n;
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Creating integral images: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Creating integral images", progress)?;
+                old_progress = progress;
             }
         }
         let mut middle_pane_radius: isize;
@@ -358,7 +355,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
         for midpoint in (min_scale..max_scale).filter(|s| (s - min_scale) % step == 0) {
             // .step_by(step) { once step_by is stabilized
             if verbose {
-                println!("Filter Size {} / {}", midpoint, max_scale);
+                context.info(format!("Filter Size {} / {}", midpoint, max_scale));
             }
             middle_pane_radius = (midpoint * 2 + 1) / 6;
 
@@ -375,7 +372,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
             let mut num_panes_valid: f64;
             for sig_site in 0..signature_sites.len() {
                 let (row, col) = signature_sites[sig_site];
-                z = input[(row, col)];
+                z = input.get_value(row, col);
                 if z != nodata {
                     // top to bottom:
                     // -midpoint  -middle_pane_radius  +middle_pane_radius  +midpoint
@@ -578,10 +575,10 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
+            context.info(format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         let f = File::create(output_file.clone())?;
@@ -661,7 +658,7 @@ impl WhiteboxTool for MaxAnisotropyDevSignature {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            context.info(format!("Complete! Please see {} for output.", output_file));
         }
 
         Ok(())
@@ -223,8 +223,9 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_mag_file = String::new();
         let mut output_scale_file = String::new();
@@ -316,22 +317,22 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
         }
 
         if step < 1 {
-            eprintln!("Warning: Step value must be at least 1.0. Value set to 1.0.");
+            context.warning("Warning: Step value must be at least 1.0. Value set to 1.0.");
             step = 1;
         }
 
         if step_nonlinearity < 1.0 {
-            eprintln!("Warning: Step nonlinearity value must be great than 1.0. Value set to 1.0.");
+            context.warning("Warning: Step nonlinearity value must be great than 1.0. Value set to 1.0.");
             step_nonlinearity = 1.0;
         }
 
         if step_nonlinearity > 4.0 {
-            eprintln!("Warning: Step nonlinearity is set too high. Value reset to 4.0.");
+            context.warning("Warning: Step nonlinearity is set too high. Value reset to 4.0.");
             step_nonlinearity = 4.0;
         }
 
         if num_steps < 1 {
-            eprintln!("Warning: Number of steps must be at least 1.");
+            context.warning("Warning: Number of steps must be at least 1.");
             num_steps = 1;
         }
 
@@ -339,11 +340,11 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -362,7 +363,7 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let input = Raster::new(&input_file, "r")?; // Memory requirements: 2.0X, assuming data is stored as f32s
         let start = Instant::now();
@@ -402,7 +403,9 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
                             data[col as usize] = val;
                         }
                     }
-                    tx1.send((row, data)).unwrap();
+                    if tx1.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -410,21 +413,20 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
         for row in 0..rows {
             let data = rx.recv().expect("Error receiving data from thread.");
             binned_data.set_row_data(data.0, data.1);
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Binning data: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Binning data", progress)?;
+                old_progress = progress;
             }
         }
 
         drop(input); // Memory requirements: 2.0X
 
         if verbose {
-            println!("Initializing grids...");
+            context.info("Initializing grids...");
         }
-        let mut output_mag = Raster::initialize_using_config(&output_mag_file, &configs); // Memory requirements: 4.0X
+        let mut output_mag = Raster::initialize_using_config(&output_mag_file, &configs);
+        output_mag.widen_to_f64(); // Memory requirements: 4.0X
         let mut output_scale = Raster::initialize_using_config(&output_scale_file, &configs); // Memory requirements: 6.0X
         output_mag.configs.data_type = DataType::F32;
 
@@ -442,13 +444,13 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
             let midpoint = min_scale
                 + (((step * (s - min_scale)) as f32).powf(step_nonlinearity)).floor() as isize;
             let filter_size = midpoint * 2 + 1;
-            println!(
+            context.info(format!(
                 "Loop {} / {} ({}x{})",
                 s - min_scale + 1,
                 num_steps,
                 filter_size,
                 filter_size
-            );
+            ));
 
             let (tx, rx) = mpsc::channel();
             for tid in 0..num_procs {
@@ -544,7 +546,9 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
 
                             old_bin_val = bin_val;
                         }
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -569,22 +573,18 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
                         }
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Performing analysis: {}%", progress);
-                        old_progress = progress;
-                    }
-                }
-            }
-            // Update progress
-            if verbose {
-                progress = (s as f32 / num_steps as f32 * 100f32) as usize;
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
                 if progress != old_progress {
-                    println!("Progress: {}%", progress);
+                    context.progress("Performing analysis", progress)?;
                     old_progress = progress;
                 }
             }
+            // Update progress
+            progress = (s as f32 / num_steps as f32 * 100f32) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
+            }
         }
 
         drop(bd);
@@ -603,12 +603,12 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
         output_mag.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving magnitude data...")
+            context.info("Saving magnitude data...")
         };
         let _ = match output_mag.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -629,22 +629,19 @@ impl WhiteboxTool for MultiscaleElevationPercentile {
         output_scale.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving scale data...")
+            context.info("Saving scale data...")
         };
         let _ = match output_scale.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
         }
 
         Ok(())
@@ -163,8 +163,9 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut points_file = String::new();
         let mut output_file = String::new();
@@ -247,11 +248,11 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -270,7 +271,7 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
         }
 
         if verbose {
-            println!("Reading DEM data...")
+            context.info("Reading DEM data...")
         };
         let input = Arc::new(Raster::new(&input_file, "r")?);
         let start = Instant::now();
@@ -289,7 +290,7 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
         }
 
         if verbose {
-            println!("Reading points data...")
+            context.info("Reading points data...")
         };
         let points = Shapefile::read(&points_file)?;
 
@@ -317,13 +318,11 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
                 series_names.push(format!("Site {}", record_num + 1));
             }
 
-            if verbose {
-                progress =
-                    (100.0_f64 * record_num as f64 / (points.num_records - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Finding site row/column values: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * record_num as f64 / (points.num_records - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Finding site row/column values", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -384,7 +383,9 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
                             };
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -399,12 +400,10 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
             let data = rx.recv().expect("Error receiving data from thread.");
             nv.set_row_data(data.0, data.1);
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Calculating normal vectors: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Calculating normal vectors", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -423,7 +422,7 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
             sum = 0f64;
             sum_n = 0;
             for col in 0..columns {
-                val = input[(row, col)];
+                val = input.get_value(row, col);
                 if val == nodata {
                     val = 0f64;
                 } else {
@@ -440,12 +439,10 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
                     integral_n[(row, col)] = sum_n;
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Creating integral images: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Creating integral images", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -465,7 +462,7 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
             // .step_by(step) { once step_by is stabilized
             // loop_num += 1;
 
-            println!("Filter Size {} / {}", midpoint, max_scale);
+            context.info(format!("Filter Size {} / {}", midpoint, max_scale));
 
             ////////////////////////////////////////////////////////////////////////////
             // Use the integral image to smooth the DEM at a scale of the filter size //
@@ -493,7 +490,7 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
                         }
                         let mut data = vec![nodata; columns as usize];
                         for col in 0..columns {
-                            z = input_data[(row, col)];
+                            z = input_data.get_value(row, col);
                             if z != nodata {
                                 x1 = col - midpoint - 1;
                                 if x1 < 0 {
@@ -512,7 +509,9 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
                             }
                         }
 
-                        tx1.send((row, data)).unwrap();
+                        if tx1.send((row, data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -586,7 +585,7 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
             let mut z: f64;
             for s in 0..signature_sites.len() {
                 let (row, col) = signature_sites[s];
-                z = input[(row, col)];
+                z = input.get_value(row, col);
                 if z != nodata {
                     y1 = row - midpoint - 1;
                     if y1 < 0 {
@@ -622,10 +621,10 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
+            context.info(format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         let f = File::create(output_file.clone())?;
@@ -705,7 +704,7 @@ impl WhiteboxTool for MultiscaleRoughnessSignature {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            context.info(format!("Complete! Please see {} for output.", output_file));
         }
 
         Ok(())
@@ -200,8 +200,9 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_mag_file = String::new();
         let mut output_scale_file = String::new();
@@ -290,22 +291,22 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
         // }
 
         if step < 1 {
-            eprintln!("Warning: Step value must be at least 1.0. Value set to 1.0.");
+            context.warning("Warning: Step value must be at least 1.0. Value set to 1.0.");
             step = 1;
         }
 
         if step_nonlinearity < 1.0 {
-            eprintln!("Warning: Step nonlinearity value must be great than 1.0. Value set to 1.0.");
+            context.warning("Warning: Step nonlinearity value must be great than 1.0. Value set to 1.0.");
             step_nonlinearity = 1.0;
         }
 
         if step_nonlinearity > 4.0 {
-            eprintln!("Warning: Step nonlinearity is set too high. Value reset to 4.0.");
+            context.warning("Warning: Step nonlinearity is set too high. Value reset to 4.0.");
             step_nonlinearity = 4.0;
         }
 
         if num_steps < 1 {
-            eprintln!("Warning: Number of steps must be at least 1.");
+            context.warning("Warning: Number of steps must be at least 1.");
             num_steps = 1;
         }
 
@@ -313,11 +314,11 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -336,7 +337,7 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let input_raster = Raster::new(&input_file, "r")?; // Memory requirements: 2.0X, assuming data is stored as f32s
         let start = Instant::now();
@@ -370,7 +371,7 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
         }
 
         if verbose {
-            println!("Initializing grids...");
+            context.info("Initializing grids...");
         }
         let mut output_mag = Array2D::<f32>::new(rows, columns, -1f32, nodata)?; // Memory requirements: 2.0X
         let mut output_scale = Array2D::<i16>::new(rows, columns, -32768i16, -32768i16)?; // Memory requirements: 2.5X
@@ -436,7 +437,7 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
             let midpoint = min_scale
                 + (((step * (s - min_scale)) as f32).powf(step_nonlinearity)).floor() as isize;
             if verbose {
-                println!("Loop {} / {}", s - min_scale + 1, num_steps);
+                context.info(format!("Loop {} / {}", s - min_scale + 1, num_steps));
             }
 
             let filter_size = midpoint * 2 + 1;
@@ -531,7 +532,9 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
                                 }
                             }
 
-                            tx1.send((row, data)).unwrap();
+                            if tx1.send((row, data)).is_err() {
+                                break;
+                            }
                         }
                     });
                 }
@@ -709,7 +712,9 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
                                 }
                             }
                         }
-                        tx.send((row, xdata, ydata, zdata)).unwrap();
+                        if tx.send((row, xdata, ydata, zdata)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -833,14 +838,8 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
                             }
                         }
 
-                        match tx2.send((row, data)) {
-                            Ok(_) => {}
-                            Err(_) => {
-                                println!(
-                                    "Error sending data from thread {} processing row {}.",
-                                    tid, row
-                                );
-                            }
+                        if tx2.send((row, data)).is_err() {
+                            break;
                         }
                     }
                 });
@@ -880,12 +879,10 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
             // drop(zc); // Memory requirements: 3.5X
 
             // Update progress
-            if verbose {
-                progress = (s as f32 / num_steps as f32 * 100f32) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (s as f32 / num_steps as f32 * 100f32) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
 
             // loop_num += 1;
@@ -916,12 +913,12 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
             .add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving magnitude data...")
+            context.info("Saving magnitude data...")
         };
         let _ = match output_mag_raster.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
@@ -950,22 +947,19 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
             .add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving scale data...")
+            context.info("Saving scale data...")
         };
         let _ = match output_scale_raster.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", "")
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time).replace("PT", ""));
         }
 
         // Memory requirements: 0.0X (wehn output_scale_raster is freed automatically at end of scope)
@@ -1047,10 +1041,7 @@ impl WhiteboxTool for MultiscaleStdDevNormals {
                 let _ = output.stdout;
             }
 
-            println!(
-                "Complete! Please see {} for signature output.",
-                signature_file
-            );
+            context.info(format!("Complete! Please see {} for signature output.", signature_file));
         }
 
         Ok(())
@@ -170,8 +170,9 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut points_file = String::new();
         let mut output_file = String::new();
@@ -250,22 +251,22 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
         }
 
         if step < 1 {
-            eprintln!("Warning: Step value must be at least 1.0. Value set to 1.0.");
+            context.warning("Warning: Step value must be at least 1.0. Value set to 1.0.");
             step = 1;
         }
 
         if step_nonlinearity < 1.0 {
-            eprintln!("Warning: Step nonlinearity value must be great than 1.0. Value set to 1.0.");
+            context.warning("Warning: Step nonlinearity value must be great than 1.0. Value set to 1.0.");
             step_nonlinearity = 1.0;
         }
 
         if step_nonlinearity > 4.0 {
-            eprintln!("Warning: Step nonlinearity is set too high. Value reset to 4.0.");
+            context.warning("Warning: Step nonlinearity is set too high. Value reset to 4.0.");
             step_nonlinearity = 4.0;
         }
 
         if num_steps < 1 {
-            eprintln!("Warning: Number of steps must be at least 1.");
+            context.warning("Warning: Number of steps must be at least 1.");
             num_steps = 1;
         }
 
@@ -273,11 +274,11 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -296,7 +297,7 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
         }
 
         if verbose {
-            println!("Reading DEM data...")
+            context.info("Reading DEM data...")
         };
         let input_raster = Raster::new(&input_file, "r")?; // Memory requirements: 2.0X, assuming data is stored as f32s
         let start = Instant::now();
@@ -322,7 +323,7 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
         }
 
         if verbose {
-            println!("Reading points data...")
+            context.info("Reading points data...")
         };
         let points = Shapefile::read(&points_file)?;
 
@@ -351,17 +352,15 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
                 series_names.push(format!("Site {}", record_num + 1));
             }
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Finding site row/column values: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Finding site row/column values", progress)?;
+                old_progress = progress;
             }
         }
 
         if verbose {
-            println!("Initializing grids...");
+            context.info("Initializing grids...");
         }
 
         // calculate the 'n' itegral image
@@ -415,7 +414,7 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
         for s in min_scale..(min_scale + num_steps) {
             let midpoint = min_scale
                 + ((((s - min_scale) * step) as f32).powf(step_nonlinearity)).floor() as isize;
-            println!("Loop {} / {}", s - min_scale + 1, num_steps);
+            context.info(format!("Loop {} / {}", s - min_scale + 1, num_steps));
 
             let filter_size = midpoint * 2 + 1;
 
@@ -509,7 +508,9 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
                                 }
                             }
 
-                            tx1.send((row, data)).unwrap();
+                            if tx1.send((row, data)).is_err() {
+                                break;
+                            }
                         }
                     });
                 }
@@ -687,7 +688,9 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
                                 }
                             }
                         }
-                        tx.send((row, xdata, ydata, zdata)).unwrap();
+                        if tx.send((row, xdata, ydata, zdata)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -791,21 +794,19 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
             // drop(zc); // Memory requirements: 3.5X
 
             // Update progress
-            if verbose {
-                progress = (s as f32 / num_steps as f32 * 100f32) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (s as f32 / num_steps as f32 * 100f32) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
         let elapsed_time = get_formatted_elapsed_time(start);
         if verbose {
-            println!(
+            context.info(format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         let f = File::create(output_file.clone())?;
@@ -880,7 +881,7 @@ impl WhiteboxTool for MultiscaleStdDevNormalsSignature {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            context.info(format!("Complete! Please see {} for output.", output_file));
         }
 
         Ok(())
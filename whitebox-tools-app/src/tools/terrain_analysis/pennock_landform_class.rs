@@ -201,8 +201,9 @@ impl WhiteboxTool for PennockLandformClass {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut z_factor = -1f64;
@@ -293,11 +294,11 @@ impl WhiteboxTool for PennockLandformClass {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -313,7 +314,7 @@ impl WhiteboxTool for PennockLandformClass {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -379,11 +380,11 @@ impl WhiteboxTool for PennockLandformClass {
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data = vec![-128f64; columns as usize];
                     for col in 0..columns {
-                        z = input[(row, col)];
+                        z = input.get_value(row, col);
                         if z != nodata {
                             z = z * z_factor;
                             for c in 0..8 {
-                                n[c] = input[(row + dy[c], col + dx[c])];
+                                n[c] = input.get_value(row + dy[c], col + dx[c]);
                                 if n[c] != nodata {
                                     n[c] = n[c] * z_factor;
                                 } else {
@@ -460,7 +461,9 @@ impl WhiteboxTool for PennockLandformClass {
                             }
                         }
                     }
-                    tx.send((row, data)).unwrap();
+                    if tx.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -468,12 +471,10 @@ impl WhiteboxTool for PennockLandformClass {
         for r in 0..rows {
             let (row, data) = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(row, data);
-            if verbose {
-                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -498,32 +499,29 @@ impl WhiteboxTool for PennockLandformClass {
         output.add_metadata_entry(format!("6      Divergent Backslope"));
         output.add_metadata_entry(format!("7      Level"));
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
 
         if verbose {
-            println!("CLASSIFICATION KEY");
-            println!("Value  Class");
-            println!("1      Convergent Footslope");
-            println!("2      Divergent Footslope");
-            println!("3      Convergent Shoulder");
-            println!("4      Divergent Shoulder");
-            println!("5      Convergent Backslope");
-            println!("6      Divergent Backslope");
-            println!("7      Level");
-
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info("CLASSIFICATION KEY");
+            context.info("Value  Class");
+            context.info("1      Convergent Footslope");
+            context.info("2      Divergent Footslope");
+            context.info("3      Convergent Shoulder");
+            context.info("4      Divergent Shoulder");
+            context.info("5      Convergent Backslope");
+            context.info("6      Divergent Backslope");
+            context.info("7      Level");
+
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
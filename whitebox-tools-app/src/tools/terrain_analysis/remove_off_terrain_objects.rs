@@ -155,8 +155,9 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut filter_size = 11usize;
@@ -222,11 +223,11 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -251,7 +252,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let input_f64 = Raster::new(&input_file, "r")?; // 2X
         let input = Arc::new(input_f64.get_data_as_f32_array2d()); // 3X
@@ -280,7 +281,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
 
         // Perform the white tophat transform
         if verbose {
-            println!("Performing tophat transform...")
+            context.info("Performing tophat transform...")
         };
 
         let mut num_procs = num_cpus::get() as isize;
@@ -341,7 +342,9 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
                             erosion[col as usize] = min_val;
                         }
                     }
-                    tx.send((row, erosion)).unwrap();
+                    if tx.send((row, erosion)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -352,12 +355,10 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
             let data = rx.recv().expect("Error receiving data from thread.");
             erosion.set_row_data(data.0, data.1);
 
-            if verbose {
-                progress = (100.0_f32 * r as f32 / (rows - 1) as f32) as usize;
-                if progress != old_progress {
-                    println!("Performing erosion: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f32 * r as f32 / (rows - 1) as f32) as usize;
+            if progress != old_progress {
+                context.progress("Performing erosion", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -419,7 +420,9 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
                             }
                         }
                     }
-                    tx.send((row, tophat, opening)).unwrap();
+                    if tx.send((row, tophat, opening)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -433,12 +436,10 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
             tophat.set_row_data(data.0, data.1);
             opening.set_row_data(data.0, data.2);
 
-            if verbose {
-                progress = (100.0_f32 * r as f32 / (rows - 1) as f32) as usize;
-                if progress != old_progress {
-                    println!("Performing dilation: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f32 * r as f32 / (rows - 1) as f32) as usize;
+            if progress != old_progress {
+                context.progress("Performing dilation", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -450,7 +451,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
 
         // Back-fill the shallow hills using region growing
         if verbose {
-            println!("Backfilling hills...")
+            context.info("Backfilling hills...")
         };
         let initial_value = f32::NEG_INFINITY;
         let mut out: Array2D<f32> =
@@ -473,12 +474,10 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
                     out.set_value(row, col, nodata);
                 }
             }
-            if verbose {
-                progress = (100.0_f32 * row as f32 / (rows - 1) as f32) as usize;
-                if progress != old_progress {
-                    println!("Finding seed cells: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f32 * row as f32 / (rows - 1) as f32) as usize;
+            if progress != old_progress {
+                context.progress("Finding seed cells", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -507,7 +506,7 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
 
         // Interpolate the data holes. Start by locating all the edge cells.
         if verbose {
-            println!("Interpolating data holes...")
+            context.info("Interpolating data holes...")
         };
         let mut frs: FixedRadiusSearch2D<f32> = FixedRadiusSearch2D::new(
             filter_size as f64 / 1.5f64,
@@ -533,12 +532,10 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
                     }
                 }
             }
-            if verbose {
-                progress = (100.0_f32 * row as f32 / (rows - 1) as f32) as usize;
-                if progress != old_progress {
-                    println!("Finding OTO edge cells: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f32 * row as f32 / (rows - 1) as f32) as usize;
+            if progress != old_progress {
+                context.progress("Finding OTO edge cells", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -575,12 +572,10 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
                     );
                 }
             }
-            if verbose {
-                progress = (100.0_f32 * row as f32 / (rows - 1) as f32) as usize;
-                if progress != old_progress {
-                    println!("Interpolating data holes: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f32 * row as f32 / (rows - 1) as f32) as usize;
+            if progress != old_progress {
+                context.progress("Interpolating data holes", progress)?;
+                old_progress = progress;
             }
         }
         drop(frs); // 3X
@@ -599,12 +594,10 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
                     output.set_value(row, col, nodata as f64);
                 }
             }
-            if verbose {
-                progress = (100.0_f32 * row as f32 / (rows - 1) as f32) as usize;
-                if progress != old_progress {
-                    println!("Outputing data: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f32 * row as f32 / (rows - 1) as f32) as usize;
+            if progress != old_progress {
+                context.progress("Outputing data", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -617,21 +610,18 @@ impl WhiteboxTool for RemoveOffTerrainObjects {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
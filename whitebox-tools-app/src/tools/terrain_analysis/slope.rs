@@ -7,6 +7,7 @@ License: MIT
 */
 
 use whitebox_raster::*;
+use whitebox_common::structures::Array2D;
 use crate::tools::*;
 use num_cpus;
 use std::env;
@@ -138,6 +139,125 @@ impl Slope {
             example_usage: usage,
         }
     }
+
+    // Calculates the slope of a DEM that is too large to be held in memory, one band of rows
+    // at a time.
+    fn run_cached(
+        &self,
+        mut input: CachedRaster,
+        input_file: &str,
+        output_file: &str,
+        mut z_factor: f64,
+        units_numeric: usize,
+        context: &ToolContext,
+    ) -> Result<(), Error> {
+        let verbose = context.verbose;
+        let start = Instant::now();
+
+        let eight_grid_res = input.configs.resolution_x * 8.0;
+
+        if input.is_in_geographic_coordinates() && z_factor < 0.0 {
+            // calculate a new z-conversion factor
+            let mut mid_lat = (input.configs.north - input.configs.south) / 2.0;
+            if (-90.0..=90.0).contains(&mid_lat) {
+                mid_lat = mid_lat.to_radians();
+                z_factor = 1.0 / (111320.0 * mid_lat.cos());
+            }
+        } else if z_factor < 0.0 {
+            z_factor = 1.0;
+        }
+
+        let mut output = CachedRaster::initialize_using_file(output_file, &input)?;
+        if output.configs.data_type != DataType::F32 && output.configs.data_type != DataType::F64 {
+            output.configs.data_type = DataType::F32;
+        }
+        let output_nodata = -9999.0;
+        output.configs.nodata = output_nodata;
+        let nodata = input.configs.nodata;
+        let rows = input.configs.rows as isize;
+        let columns = input.configs.columns as isize;
+
+        let mut num_procs = num_cpus::get() as isize;
+        let configs = whitebox_common::configs::get_configs()?;
+        let max_procs = configs.max_procs;
+        if max_procs > 0 && max_procs < num_procs {
+            num_procs = max_procs;
+        }
+
+        let band_height = input.get_row_band_height() as isize;
+        let mut band_start = 0isize;
+        while band_start < rows {
+            let band_rows = band_height.min(rows - band_start);
+            // the window includes the row above and below the band.
+            let window = Arc::new(input.read_window(
+                band_start - 1,
+                0,
+                (band_rows + 2) as usize,
+                columns as usize,
+            )?);
+            let (tx, rx) = mpsc::channel();
+            for tid in 0..num_procs {
+                let window = window.clone();
+                let tx1 = tx.clone();
+                thread::spawn(move || {
+                    for row in (0..band_rows).filter(|r| r % num_procs == tid) {
+                        let mut data = vec![output_nodata; columns as usize];
+                        for col in 0..columns {
+                            if window[(row + 1, col)] != nodata {
+                                data[col as usize] = calculate_slope(
+                                    &|r, c| window.get_value(r, c),
+                                    row + 1,
+                                    col,
+                                    nodata,
+                                    z_factor,
+                                    eight_grid_res,
+                                    units_numeric,
+                                );
+                            }
+                        }
+                        if tx1.send((row, data)).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+
+            let mut band: Array2D<f64> =
+                Array2D::new(band_rows, columns, output_nodata, output_nodata)?;
+            for _ in 0..band_rows {
+                let data = rx.recv().expect("Error receiving data from thread.");
+                band.set_row_data(data.0, data.1);
+            }
+            output.write_window(band_start, 0, &band)?;
+            band_start += band_rows;
+
+            context.progress(
+                "Performing analysis",
+                (100.0_f64 * band_start as f64 / rows as f64) as usize,
+            )?;
+        }
+
+        let elapsed_time = get_formatted_elapsed_time(start);
+        output.configs.palette = "spectrum_soft.plt".to_string();
+        output.add_metadata_entry(format!(
+            "Created by whitebox_tools\' {} tool",
+            self.get_tool_name()
+        ));
+        output.add_metadata_entry(format!("Input file: {}", input_file));
+        output.add_metadata_entry(format!("Z-factor: {}", z_factor));
+        output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+
+        if verbose {
+            context.info("Saving data...")
+        };
+        output.write()?;
+        if verbose {
+            context.info("Output file written");
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
+        }
+
+        Ok(())
+    }
 }
 
 impl WhiteboxTool for Slope {
@@ -179,8 +299,9 @@ impl WhiteboxTool for Slope {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut z_factor = -1f64;
@@ -246,11 +367,11 @@ impl WhiteboxTool for Slope {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -265,8 +386,13 @@ impl WhiteboxTool for Slope {
             output_file = format!("{}{}", working_directory, output_file);
         }
 
+        // A DEM that is too large to be read into memory is processed one band of rows at a time.
+        if let Some(input) = CachedRaster::open_if_exceeds_memory(&input_file, 2)? {
+            return self.run_cached(input, &input_file, &output_file, z_factor, units_numeric, context);
+        }
+
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
 
         let input = Arc::new(Raster::new(&input_file, "r")?);
@@ -308,36 +434,24 @@ impl WhiteboxTool for Slope {
             thread::spawn(move || {
                 let nodata = input.configs.nodata;
                 let columns = input.configs.columns as isize;
-                let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
-                let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
-                let mut n: [f64; 8] = [0.0; 8];
-                let mut z: f64;
-                let (mut fx, mut fy): (f64, f64);
                 for row in (0..rows).filter(|r| r % num_procs == tid) {
                     let mut data = vec![output_nodata; columns as usize];
                     for col in 0..columns {
-                        z = input[(row, col)];
-                        if z != nodata {
-                            for c in 0..8 {
-                                n[c] = input[(row + d_y[c], col + d_x[c])];
-                                if n[c] != nodata {
-                                    n[c] = n[c] * z_factor;
-                                } else {
-                                    n[c] = z * z_factor;
-                                }
-                            }
-                            // calculate slope
-                            fy = (n[6] - n[4] + 2.0 * (n[7] - n[3]) + n[0] - n[2]) / eight_grid_res;
-                            fx = (n[2] - n[4] + 2.0 * (n[1] - n[5]) + n[0] - n[6]) / eight_grid_res;
-
-                            data[col as usize] = match units_numeric {
-                                1 => (fx * fx + fy * fy).sqrt().atan().to_degrees(), // degrees
-                                2 => (fx * fx + fy * fy).sqrt().atan(),              // radians
-                                _ => (fx * fx + fy * fy).sqrt() * 100f64,            // percent
-                            };
+                        if input.get_value(row, col) != nodata {
+                            data[col as usize] = calculate_slope(
+                                &|r, c| input.get_value(r, c),
+                                row,
+                                col,
+                                nodata,
+                                z_factor,
+                                eight_grid_res,
+                                units_numeric,
+                            );
                         }
                     }
-                    tx1.send((row, data)).unwrap();
+                    if tx1.send((row, data)).is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -346,12 +460,10 @@ impl WhiteboxTool for Slope {
             let data = rx.recv().expect("Error receiving data from thread.");
             output.set_row_data(data.0, data.1);
 
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Performing analysis: {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Performing analysis", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -366,23 +478,55 @@ impl WhiteboxTool for Slope {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
     }
 }
+
+// Calculates the slope of the cell at (`row`, `col`) of a DEM, which must not be nodata. The
+// DEM is read through `input`, which may come from an in-memory `Raster` or a window of a
+// larger one.
+fn calculate_slope(
+    input: &impl Fn(isize, isize) -> f64,
+    row: isize,
+    col: isize,
+    nodata: f64,
+    z_factor: f64,
+    eight_grid_res: f64,
+    units_numeric: usize,
+) -> f64 {
+    let d_x = [1, 1, 1, 0, -1, -1, -1, 0];
+    let d_y = [-1, 0, 1, 1, 1, 0, -1, -1];
+    let mut n: [f64; 8] = [0.0; 8];
+    let z = input(row, col);
+    for c in 0..8 {
+        n[c] = input(row + d_y[c], col + d_x[c]);
+        if n[c] != nodata {
+            n[c] *= z_factor;
+        } else {
+            n[c] = z * z_factor;
+        }
+    }
+    // calculate slope
+    let fy = (n[6] - n[4] + 2.0 * (n[7] - n[3]) + n[0] - n[2]) / eight_grid_res;
+    let fx = (n[2] - n[4] + 2.0 * (n[1] - n[5]) + n[0] - n[6]) / eight_grid_res;
+
+    match units_numeric {
+        1 => (fx * fx + fy * fy).sqrt().atan().to_degrees(), // degrees
+        2 => (fx * fx + fy * fy).sqrt().atan(),              // radians
+        _ => (fx * fx + fy * fy).sqrt() * 100f64,            // percent
+    }
+}
@@ -147,8 +147,9 @@ impl WhiteboxTool for SlopeVsElevationPlot {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_files_str = String::new();
         let mut watershed_files_str = "".to_string();
         let mut output_file = String::new();
@@ -194,11 +195,11 @@ impl WhiteboxTool for SlopeVsElevationPlot {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -258,7 +259,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                 }
 
                 if verbose {
-                    println!("Reading data...");
+                    context.info("Reading data...");
                 }
                 let input = Arc::new(Raster::new(&input_file, "r")?);
 
@@ -308,10 +309,10 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                         for row in (0..rows).filter(|r| r % num_procs == tid) {
                             let mut data = vec![nodata; columns as usize];
                             for col in 0..columns {
-                                z = input[(row, col)];
+                                z = input.get_value(row, col);
                                 if z != nodata {
                                     for c in 0..8 {
-                                        n[c] = input[(row + d_y[c], col + d_x[c])];
+                                        n[c] = input.get_value(row + d_y[c], col + d_x[c]);
                                         if n[c] != nodata {
                                             n[c] = n[c] * z_factor;
                                         } else {
@@ -327,7 +328,9 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                                         (fx * fx + fy * fy).sqrt().atan().to_degrees();
                                 }
                             }
-                            tx1.send((row, data)).unwrap();
+                            if tx1.send((row, data)).is_err() {
+                                break;
+                            }
                         }
                     });
                 }
@@ -337,12 +340,10 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                     let data = rx.recv().expect("Error receiving data from thread.");
                     slope.set_row_data(data.0, data.1);
 
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Slope analysis: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Slope analysis", progress)?;
+                        old_progress = progress;
                     }
                 }
 
@@ -368,12 +369,10 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                             slope_data[bin] += slope.get_value(row, col);
                         }
                     }
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Binning the data: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Binning the data", progress)?;
+                        old_progress = progress;
                     }
                 }
 
@@ -411,7 +410,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                     input_file = format!("{}{}", working_directory, input_file);
                 }
                 if verbose {
-                    println!("Reading data...");
+                    context.info("Reading data...");
                 }
                 let input = Arc::new(Raster::new(&input_file, "r")?);
                 if num_files == 1 {
@@ -460,10 +459,10 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                         for row in (0..rows).filter(|r| r % num_procs == tid) {
                             let mut data = vec![nodata; columns as usize];
                             for col in 0..columns {
-                                z = input[(row, col)];
+                                z = input.get_value(row, col);
                                 if z != nodata {
                                     for c in 0..8 {
-                                        n[c] = input[(row + d_y[c], col + d_x[c])];
+                                        n[c] = input.get_value(row + d_y[c], col + d_x[c]);
                                         if n[c] != nodata {
                                             n[c] = n[c] * z_factor;
                                         } else {
@@ -479,7 +478,9 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                                         (fx * fx + fy * fy).sqrt().atan().to_degrees();
                                 }
                             }
-                            tx1.send((row, data)).unwrap();
+                            if tx1.send((row, data)).is_err() {
+                                break;
+                            }
                         }
                     });
                 }
@@ -489,12 +490,10 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                     let data = rx.recv().expect("Error receiving data from thread.");
                     slope.set_row_data(data.0, data.1);
 
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Slope analysis: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Slope analysis", progress)?;
+                        old_progress = progress;
                     }
                 }
 
@@ -514,7 +513,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                 let watershed_min = watershed.configs.minimum;
                 let watershed_max = watershed.configs.maximum;
                 let num_watersheds = (watershed_max - watershed_min) as usize + 1;
-                println!("Num. Watersheds {}", num_watersheds);
+                context.info(format!("Num. Watersheds {}", num_watersheds));
 
                 // get the number of watersheds, and the min and max elev for each watershed
                 let mut min_elevs = vec![f64::INFINITY; num_watersheds];
@@ -538,12 +537,10 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                             }
                         }
                     }
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Processing watershed data: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Processing watershed data", progress)?;
+                        old_progress = progress;
                     }
                 }
 
@@ -591,12 +588,10 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                             }
                         }
                     }
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Binning the data: {}%", progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress("Binning the data", progress)?;
+                        old_progress = progress;
                     }
                 }
 
@@ -653,10 +648,10 @@ impl WhiteboxTool for SlopeVsElevationPlot {
         let _ = writer.flush();
 
         if verbose {
-            println!(
+            context.info(format!(
                 "\n{}",
                 &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            ));
         }
 
         if verbose {
@@ -684,7 +679,7 @@ impl WhiteboxTool for SlopeVsElevationPlot {
                 let _ = output.stdout;
             }
 
-            println!("Complete! Please see {} for output.", output_file);
+            context.info(format!("Complete! Please see {} for output.", output_file));
         }
 
         Ok(())
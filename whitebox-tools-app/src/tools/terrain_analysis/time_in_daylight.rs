@@ -237,8 +237,9 @@ impl WhiteboxTool for TimeInDaylight {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut az_fraction = 10.0f32;
@@ -457,11 +458,11 @@ impl WhiteboxTool for TimeInDaylight {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let sep: String = path::MAIN_SEPARATOR.to_string();
@@ -511,7 +512,7 @@ impl WhiteboxTool for TimeInDaylight {
 
         // Calculate the almanac
         if verbose {
-            println!("Calculating the local almanac...");
+            context.info("Calculating the local almanac...");
         }
         let seconds_interval = 10;
         let almanac = generate_almanac(
@@ -523,7 +524,7 @@ impl WhiteboxTool for TimeInDaylight {
         );
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let inputf64 = Arc::new(Raster::new(&input_file, "r")?);
         let configs = inputf64.configs.clone();
@@ -560,6 +561,7 @@ impl WhiteboxTool for TimeInDaylight {
         let nodata_f32 = nodata as f32;
 
         let mut output = Raster::initialize_using_file(&output_file, &inputf64);
+        output.widen_to_f64();
 
         drop(inputf64);
 
@@ -763,7 +765,9 @@ impl WhiteboxTool for TimeInDaylight {
                                     }
                                 }
                             }
-                            tx.send((row, data)).unwrap();
+                            if tx.send((row, data)).is_err() {
+                                break;
+                            }
                         }
                     });
                 }
@@ -771,12 +775,10 @@ impl WhiteboxTool for TimeInDaylight {
                 for row in 0..rows {
                     let (r, data) = rx.recv().expect("Error receiving data from thread.");
                     horizon_angle.set_row_data(r, data);
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress (Az={}): {}%", azimuth, progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress(&format!("Progress (Az={})", azimuth), progress)?;
+                        old_progress = progress;
                     }
                 }
 
@@ -824,12 +826,10 @@ impl WhiteboxTool for TimeInDaylight {
 
                     output.increment_row_data(row, new_vals);
 
-                    if verbose {
-                        progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                        if progress != old_progress {
-                            println!("Progress (Az={}): {}%", azimuth, progress);
-                            old_progress = progress;
-                        }
+                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                    if progress != old_progress {
+                        context.progress(&format!("Progress (Az={})", azimuth), progress)?;
+                        old_progress = progress;
                     }
                 }
             }
@@ -848,12 +848,10 @@ impl WhiteboxTool for TimeInDaylight {
                         output.set_value(row, col, 1f64 - (z / total_daylight));
                     }
                 }
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!("Progress: {}%", progress);
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress("Progress", progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -870,25 +868,22 @@ impl WhiteboxTool for TimeInDaylight {
             output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
             if verbose {
-                println!("Saving data...")
+                context.info("Saving data...")
             };
             let _ = match output.write() {
                 Ok(_) => {
                     if verbose {
-                        println!("Output file written")
+                        context.info("Output file written")
                     }
                 }
                 Err(e) => return Err(e),
             };
 
             if verbose {
-                println!(
-                    "{}",
-                    &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-                );
+                context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
             }
         } else {
-            println!("No daylight was detected. Either broaden the time-span parameters or decrease the azimuth fraction.");
+            context.info("No daylight was detected. Either broaden the time-span parameters or decrease the azimuth fraction.");
         }
 
         Ok(())
@@ -159,8 +159,9 @@ impl WhiteboxTool for Viewshed {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut stations_file = String::new();
         let mut output_file = String::new();
@@ -219,11 +220,11 @@ impl WhiteboxTool for Viewshed {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let mut num_procs = num_cpus::get() as isize;
@@ -249,14 +250,14 @@ impl WhiteboxTool for Viewshed {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let dem = Arc::new(Raster::new(&input_file, "r")?);
 
         let start = Instant::now();
 
         if height < 0f64 {
-            println!("Warning: Input station height cannot be less than zero.");
+            context.warning("Warning: Input station height cannot be less than zero.");
             height = 0f64;
         }
 
@@ -277,6 +278,7 @@ impl WhiteboxTool for Viewshed {
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &dem);
+        output.widen_to_f64();
 
         // scan the stations raster and place each non-zero grid cell into Vecs
         // let mut z: f64;
@@ -305,13 +307,11 @@ impl WhiteboxTool for Viewshed {
             station_y.push(record.points[0].y);
             station_x.push(record.points[0].x);
 
-            if verbose {
-                progress =
-                    (100.0_f64 * record_num as f64 / (stations.num_records - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Locating view stations: {}%", progress);
-                    old_progress = progress;
-                }
+            progress =
+                (100.0_f64 * record_num as f64 / (stations.num_records - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Locating view stations", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -323,7 +323,7 @@ impl WhiteboxTool for Viewshed {
         let num_stn = station_x.len();
         while !station_x.is_empty() {
             stn_num += 1;
-            println!("Station {} of {}", stn_num, num_stn);
+            context.info(format!("Station {} of {}", stn_num, num_stn));
 
             stn_x = station_x.pop().expect("Error during pop operation.");
             stn_col = dem.get_column_from_x(stn_x);
@@ -365,7 +365,9 @@ impl WhiteboxTool for Viewshed {
                                 }
                             }
                         }
-                        tx.send((row, data)).unwrap();
+                        if tx.send((row, data)).is_err() {
+                            break;
+                        }
                     }
                 });
             }
@@ -374,15 +376,10 @@ impl WhiteboxTool for Viewshed {
                 let (row, data) = rx.recv().expect("Error receiving data from thread.");
                 view_angle.set_row_data(row, data);
 
-                if verbose {
-                    progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!(
-                            "Calculating view angle (Station {} of {}): {}%",
-                            stn_num, num_stn, progress
-                        );
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * r as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Calculating view angle (Station {} of {})", stn_num, num_stn), progress)?;
+                    old_progress = progress;
                 }
             }
 
@@ -676,15 +673,10 @@ impl WhiteboxTool for Viewshed {
                     }
                 }
 
-                if verbose {
-                    progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                    if progress != old_progress {
-                        println!(
-                            "Creating output: (Station {} of {}): {}%",
-                            stn_num, num_stn, progress
-                        );
-                        old_progress = progress;
-                    }
+                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+                if progress != old_progress {
+                    context.progress(&format!("Creating output: (Station {} of {})", stn_num, num_stn), progress)?;
+                    old_progress = progress;
                 }
             }
         }
@@ -698,21 +690,18 @@ impl WhiteboxTool for Viewshed {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
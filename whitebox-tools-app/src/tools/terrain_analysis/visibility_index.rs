@@ -158,8 +158,9 @@ impl WhiteboxTool for VisibilityIndex {
         &self,
         args: Vec<String>,
         working_directory: &'a str,
-        verbose: bool,
+        context: &ToolContext,
     ) -> Result<(), Error> {
+        let verbose = context.verbose;
         let mut input_file = String::new();
         let mut output_file = String::new();
         let mut height = 2f32;
@@ -224,11 +225,11 @@ impl WhiteboxTool for VisibilityIndex {
             let tool_name = self.get_tool_name();
             let welcome_len = format!("* Welcome to {} *", tool_name).len().max(28); 
             // 28 = length of the 'Powered by' by statement.
-            println!("{}", "*".repeat(welcome_len));
-            println!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len()));
-            println!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28));
-            println!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23));
-            println!("{}", "*".repeat(welcome_len));
+            context.info("*".repeat(welcome_len));
+            context.info(format!("* Welcome to {} {}*", tool_name, " ".repeat(welcome_len - 15 - tool_name.len())));
+            context.info(format!("* Powered by WhiteboxTools {}*", " ".repeat(welcome_len - 28)));
+            context.info(format!("* www.whiteboxgeo.com {}*", " ".repeat(welcome_len - 23)));
+            context.info("*".repeat(welcome_len));
         }
 
         let mut num_procs = num_cpus::get() as isize;
@@ -251,14 +252,14 @@ impl WhiteboxTool for VisibilityIndex {
         }
 
         if verbose {
-            println!("Reading data...")
+            context.info("Reading data...")
         };
         let dem = Arc::new(Raster::new(&input_file, "r")?);
 
         let start = Instant::now();
 
         if height < 0f32 {
-            println!("Warning: Input station height cannot be less than zero.");
+            context.warning("Warning: Input station height cannot be less than zero.");
             height = 0f32;
         }
 
@@ -274,7 +275,7 @@ impl WhiteboxTool for VisibilityIndex {
         let nodata = dem.configs.nodata;
 
         if verbose {
-            println!("Performing analysis. Please be patient...")
+            context.info("Performing analysis. Please be patient...")
         };
 
         let num_cells_tested =
@@ -286,6 +287,7 @@ impl WhiteboxTool for VisibilityIndex {
             let dem = dem.clone();
             let num_cells_completed = num_cells_completed.clone();
             let tx = tx.clone();
+            let context = context.clone();
             thread::spawn(move || {
                 let mut return_data: Array2D<usize> =
                     Array2D::new(rows, columns, 0usize, 0usize).unwrap();
@@ -618,30 +620,34 @@ impl WhiteboxTool for VisibilityIndex {
                             let mut num_cells_completed = num_cells_completed.lock().unwrap();
                             *num_cells_completed += cells_completed_by_thread;
                             cells_completed_by_thread = 0;
-                            if verbose {
-                                let progress = (100.0_f64 * *num_cells_completed as f64
-                                    / (num_cells_tested - 1f64))
-                                    as usize;
-                                println!("Progress (Loop 1 of 2): {}%", progress);
+                            let progress = (100.0_f64 * *num_cells_completed as f64
+                                / (num_cells_tested - 1f64))
+                                as usize;
+                            if context.progress("Progress (Loop 1 of 2)", progress).is_err() {
+                                break;
                             }
                         }
                     }
+                    if context.is_cancelled() {
+                        break;
+                    }
                 }
                 let mut num_cells_completed = num_cells_completed.lock().unwrap();
                 *num_cells_completed += cells_completed_by_thread;
-                if verbose {
-                    let progress = (100.0_f64 * *num_cells_completed as f64
-                        / (num_cells_tested - 1f64)) as usize;
-                    println!("Progress (Loop 1 of 2): {}%", progress);
-                }
-                tx.send(return_data).unwrap();
+                let progress = (100.0_f64 * *num_cells_completed as f64
+                    / (num_cells_tested - 1f64)) as usize;
+                // the main thread stops a cancelled run once it has the data of a thread
+                let _ = context.progress("Progress (Loop 1 of 2)", progress);
+                let _ = tx.send(return_data);
             });
         }
 
         let mut output = Raster::initialize_using_file(&output_file, &dem);
+        output.widen_to_f64();
         let mut z: f64;
         for _p in 0..num_procs {
             let data = rx.recv().expect("Error receiving data from thread.");
+            context.check_cancelled()?;
             for row in 0..rows {
                 for col in 0..columns {
                     if dem.get_value(row, col) != nodata {
@@ -659,12 +665,10 @@ impl WhiteboxTool for VisibilityIndex {
                     output.set_value(row, col, z / num_cells_tested)
                 }
             }
-            if verbose {
-                progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
-                if progress != old_progress {
-                    println!("Progress (Loop 2 of 2): {}%", progress);
-                    old_progress = progress;
-                }
+            progress = (100.0_f64 * row as f64 / (rows - 1) as f64) as usize;
+            if progress != old_progress {
+                context.progress("Progress (Loop 2 of 2)", progress)?;
+                old_progress = progress;
             }
         }
 
@@ -677,21 +681,18 @@ impl WhiteboxTool for VisibilityIndex {
         output.add_metadata_entry(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
 
         if verbose {
-            println!("Saving data...")
+            context.info("Saving data...")
         };
         let _ = match output.write() {
             Ok(_) => {
                 if verbose {
-                    println!("Output file written")
+                    context.info("Output file written")
                 }
             }
             Err(e) => return Err(e),
         };
         if verbose {
-            println!(
-                "{}",
-                &format!("Elapsed Time (excluding I/O): {}", elapsed_time)
-            );
+            context.info(format!("Elapsed Time (excluding I/O): {}", elapsed_time));
         }
 
         Ok(())
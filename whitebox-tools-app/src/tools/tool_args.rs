@@ -0,0 +1,992 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: ToolArgs allows the tools to be called from Rust code with typed argument
+values, rather than command-line strings. The arguments are checked against the
+parameters that each tool describes in get_tool_parameters, and only then are they
+converted into the command-line arguments that the tools parse, so that invalid
+values are reported as errors rather than causing a tool to panic. The same checks
+are applied to the command-line arguments of every run of a tool, by
+validate_command_line, before the tool is dispatched. Options are the exception: the
+tools have long accepted values other than those that they list, e.g. 'nearest' for
+'nn', and fall back on a default for those they don't recognize, so an unrecognized
+option is reported as a warning rather than an error.
+*/
+
+use super::batch::split_arg;
+use super::workflow::{memory_file_exists, same_flag};
+use super::{ParameterFileType, ParameterType, ToolParameter, VectorGeometryType};
+use std::fmt;
+use std::io::{Error, ErrorKind};
+use std::path;
+use std::path::Path;
+use whitebox_common::geopackage::split_layer_name;
+use whitebox_common::memory_store::is_memory_file;
+use whitebox_vector::{get_vector_type_from_file, read_shape_type, ShapeType, VectorType};
+
+/// The value of a tool argument.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ToolArgValue {
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    List(Vec<String>),
+}
+
+impl ToolArgValue {
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            ToolArgValue::Integer(v) => Some(*v as f64),
+            ToolArgValue::Float(v) => Some(*v),
+            ToolArgValue::String(s) => s.trim().parse::<f64>().ok(),
+            _ => None,
+        }
+    }
+
+    fn as_i64(&self) -> Option<i64> {
+        match self {
+            ToolArgValue::Integer(v) => Some(*v),
+            ToolArgValue::Float(v) if v.fract() == 0f64 => Some(*v as i64),
+            ToolArgValue::String(s) => match s.trim().parse::<i64>() {
+                Ok(v) => Some(v),
+                // e.g. 5.0, which the tools accept for integer parameters
+                Err(_) => match s.trim().parse::<f64>() {
+                    Ok(v) if v.fract() == 0f64 => Some(v as i64),
+                    _ => None,
+                },
+            },
+            _ => None,
+        }
+    }
+
+    fn as_bool(&self) -> Option<bool> {
+        match self {
+            ToolArgValue::Boolean(v) => Some(*v),
+            ToolArgValue::String(s) => match s.trim().to_lowercase().as_ref() {
+                "true" => Some(true),
+                "false" => Some(false),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn as_list(&self) -> Vec<String> {
+        match self {
+            ToolArgValue::List(v) => v.clone(),
+            ToolArgValue::String(s) => {
+                let sep = if s.contains(';') { ';' } else { ',' };
+                s.split(sep)
+                    .map(|v| v.trim().to_string())
+                    .filter(|v| !v.is_empty())
+                    .collect()
+            }
+            v => vec![v.to_string()],
+        }
+    }
+}
+
+impl fmt::Display for ToolArgValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ToolArgValue::Boolean(v) => write!(f, "{}", v),
+            ToolArgValue::Integer(v) => write!(f, "{}", v),
+            ToolArgValue::Float(v) => write!(f, "{}", v),
+            ToolArgValue::String(v) => write!(f, "{}", v),
+            ToolArgValue::List(v) => write!(f, "{}", v.join(";")),
+        }
+    }
+}
+
+impl From<bool> for ToolArgValue {
+    fn from(v: bool) -> Self {
+        ToolArgValue::Boolean(v)
+    }
+}
+
+impl From<i32> for ToolArgValue {
+    fn from(v: i32) -> Self {
+        ToolArgValue::Integer(v as i64)
+    }
+}
+
+impl From<i64> for ToolArgValue {
+    fn from(v: i64) -> Self {
+        ToolArgValue::Integer(v)
+    }
+}
+
+impl From<usize> for ToolArgValue {
+    fn from(v: usize) -> Self {
+        ToolArgValue::Integer(v as i64)
+    }
+}
+
+impl From<f32> for ToolArgValue {
+    fn from(v: f32) -> Self {
+        ToolArgValue::Float(v as f64)
+    }
+}
+
+impl From<f64> for ToolArgValue {
+    fn from(v: f64) -> Self {
+        ToolArgValue::Float(v)
+    }
+}
+
+impl From<&str> for ToolArgValue {
+    fn from(v: &str) -> Self {
+        ToolArgValue::String(v.to_string())
+    }
+}
+
+impl From<String> for ToolArgValue {
+    fn from(v: String) -> Self {
+        ToolArgValue::String(v)
+    }
+}
+
+impl From<Vec<String>> for ToolArgValue {
+    fn from(v: Vec<String>) -> Self {
+        ToolArgValue::List(v)
+    }
+}
+
+impl From<Vec<&str>> for ToolArgValue {
+    fn from(v: Vec<&str>) -> Self {
+        ToolArgValue::List(v.iter().map(|s| s.to_string()).collect())
+    }
+}
+
+/// Named arguments for a tool, used with `ToolManager::run_tool_with`. Arguments are
+/// named by any of the parameter's flags, with or without the leading dashes, e.g.
+/// `input`, `--input` or `-i`.
+#[derive(Clone, Debug, Default)]
+pub struct ToolArgs {
+    values: Vec<(String, ToolArgValue)>,
+}
+
+impl ToolArgs {
+    pub fn new() -> ToolArgs {
+        ToolArgs { values: vec![] }
+    }
+
+    /// Sets an argument, returning the arguments so that calls can be chained.
+    pub fn set<V: Into<ToolArgValue>>(mut self, name: &str, value: V) -> ToolArgs {
+        self.insert(name, value);
+        self
+    }
+
+    /// Sets an argument, replacing any existing value.
+    pub fn insert<V: Into<ToolArgValue>>(&mut self, name: &str, value: V) {
+        let name = normalize_name(name);
+        let value = value.into();
+        match self.values.iter_mut().find(|(n, _)| *n == name) {
+            Some(entry) => entry.1 = value,
+            None => self.values.push((name, value)),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ToolArgValue> {
+        let name = normalize_name(name);
+        self.values.iter().find(|(n, _)| *n == name).map(|(_, v)| v)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<ToolArgValue> {
+        let name = normalize_name(name);
+        let index = self.values.iter().position(|(n, _)| *n == name)?;
+        Some(self.values.remove(index).1)
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns an iterator over the argument names and values.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ToolArgValue)> {
+        self.values.iter().map(|(n, v)| (n.as_str(), v))
+    }
+
+    /// Validates the arguments against the parameters of the tool `tool_name` and
+    /// converts them into command-line arguments.
+    pub(crate) fn to_command_line(
+        &self,
+        tool_name: &str,
+        parameters: &[ToolParameter],
+        working_directory: &str,
+    ) -> Result<Vec<String>, Error> {
+        let mut matched = vec![None; parameters.len()];
+        for (i, (name, _)) in self.values.iter().enumerate() {
+            match parameters
+                .iter()
+                .position(|p| p.flags.iter().any(|f| normalize_name(f) == *name))
+            {
+                Some(j) => {
+                    if matched[j].is_some() {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            format!(
+                                "The parameter '{}' of {} was specified more than once.",
+                                parameters[j].name, tool_name
+                            ),
+                        ));
+                    }
+                    matched[j] = Some(i);
+                }
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("{} does not have a parameter named '{}'.", tool_name, name),
+                    ))
+                }
+            }
+        }
+
+        let mut args = vec![];
+        for (parameter, index) in parameters.iter().zip(matched) {
+            let value = match index {
+                Some(i) => &self.values[i].1,
+                None => {
+                    if is_required(parameter) {
+                        return Err(missing_value_error(tool_name, parameter));
+                    }
+                    continue;
+                }
+            };
+            if let Some(arg) = validate(parameter, value, working_directory)
+                .map_err(|msg| invalid_value_error(tool_name, parameter, value, &msg))?
+            {
+                args.push(format!("--{}={}", flag_name(parameter), arg));
+            }
+        }
+        Ok(args)
+    }
+}
+
+/// Checks the command-line arguments of a tool against its parameters: that each
+/// required parameter is given a value, that numerical values are valid, and that input
+/// files and directories exist and, for vectors, hold the expected type of geometry.
+/// Arguments that don't name a parameter are ignored, as they are by the tools. The
+/// warnings that are returned describe values that aren't among a parameter's options.
+pub(crate) fn validate_command_line(
+    tool_name: &str,
+    parameters: &[ToolParameter],
+    args: &[String],
+    working_directory: &str,
+) -> Result<Vec<String>, Error> {
+    let mut warnings = vec![];
+    for (parameter, value) in parameters.iter().zip(parameter_values(parameters, args)) {
+        // The tools treat any value of a Boolean flag but false as true, and its
+        // absence as false.
+        if let ParameterType::Boolean = parameter.parameter_type {
+            continue;
+        }
+        let value = match value {
+            Some(v) if !v.trim().is_empty() => ToolArgValue::String(v),
+            // an empty value is the same as leaving the argument out
+            _ => {
+                if is_required(parameter) {
+                    return Err(missing_value_error(tool_name, parameter));
+                }
+                continue;
+            }
+        };
+        validate(parameter, &value, working_directory)
+            .map_err(|msg| invalid_value_error(tool_name, parameter, &value, &msg))?;
+        if let Some(warning) = option_warning(tool_name, parameter, &value) {
+            warnings.push(warning);
+        }
+    }
+    Ok(warnings)
+}
+
+// Finds the value of each parameter in the arguments of a tool, which may be given
+// either as --flag=value or as --flag value. Boolean flags without a value are true.
+pub(super) fn parameter_values(
+    parameters: &[ToolParameter],
+    args: &[String],
+) -> Vec<Option<String>> {
+    let mut values: Vec<Option<String>> = vec![None; parameters.len()];
+    let mut pending: Option<usize> = None;
+    for arg in args {
+        let (flag, value) = match split_arg(arg) {
+            // a negative number that follows a flag is its value, e.g. --z_factor -1
+            (Some(f), None) if pending.is_some() && f.parse::<f64>().is_ok() => (None, Some(f)),
+            split => split,
+        };
+        let index = match flag {
+            Some(f) => {
+                let index = parameters
+                    .iter()
+                    .position(|p| p.flags.iter().any(|pf| same_flag(pf, f)));
+                if value.is_none() {
+                    // a Boolean flag, unless a value follows it
+                    if let Some(i) = index {
+                        values[i] = Some("true".to_string());
+                    }
+                    pending = index;
+                    continue;
+                }
+                index
+            }
+            None => pending,
+        };
+        pending = None;
+        if let (Some(i), Some(v)) = (index, value) {
+            values[i] = Some(v.to_string());
+        }
+    }
+    values
+}
+
+/// Whether a parameter must be given a value; Boolean parameters never need to be.
+pub(super) fn is_required(parameter: &ToolParameter) -> bool {
+    !parameter.optional
+        && parameter.default_value.is_none()
+        && !matches!(parameter.parameter_type, ParameterType::Boolean)
+}
+
+fn missing_value_error(tool_name: &str, parameter: &ToolParameter) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "The required parameter '{}' ({}) of {} was not specified.",
+            flag_name(parameter),
+            parameter.name,
+            tool_name
+        ),
+    )
+}
+
+fn invalid_value_error(
+    tool_name: &str,
+    parameter: &ToolParameter,
+    value: &ToolArgValue,
+    msg: &str,
+) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "Invalid value '{}' for the parameter '{}' of {}: {}",
+            value,
+            flag_name(parameter),
+            tool_name,
+            msg
+        ),
+    )
+}
+
+/// Returns an argument name in the form that is stored, i.e. without leading
+/// dashes and in lowercase.
+fn normalize_name(name: &str) -> String {
+    name.trim().trim_start_matches('-').to_lowercase()
+}
+
+/// The long flag of a parameter, without the leading dashes.
+pub(super) fn flag_name(parameter: &ToolParameter) -> String {
+    parameter
+        .flags
+        .iter()
+        .max_by_key(|f| f.len())
+        .map(|f| f.trim_start_matches('-').to_string())
+        .unwrap_or_default()
+}
+
+/// Checks a value against the type of a parameter, returning the command-line value,
+/// or `None` if the argument should be left out.
+fn validate(
+    parameter: &ToolParameter,
+    value: &ToolArgValue,
+    working_directory: &str,
+) -> Result<Option<String>, String> {
+    match &parameter.parameter_type {
+        ParameterType::Boolean => {
+            let v = value
+                .as_bool()
+                .ok_or_else(|| "expected a Boolean value.".to_string())?;
+            let default_true = parameter
+                .default_value
+                .as_ref()
+                .map(|d| d.to_lowercase() == "true")
+                .unwrap_or(false);
+            // Many tools only check for the presence of a flag, so false values are
+            // left out unless they override a default of true.
+            if v || default_true {
+                Ok(Some(v.to_string()))
+            } else {
+                Ok(None)
+            }
+        }
+        ParameterType::Integer => match value.as_i64() {
+            Some(v) => Ok(Some(v.to_string())),
+            None => Err("expected an integer value.".to_string()),
+        },
+        ParameterType::Float => match value.as_f64() {
+            Some(v) => Ok(Some(v.to_string())),
+            None => Err("expected a numerical value.".to_string()),
+        },
+        ParameterType::StringOrNumber | ParameterType::VectorAttributeField(_, _) => {
+            Ok(Some(value.to_string()))
+        }
+        ParameterType::String => Ok(Some(value.to_string())),
+        ParameterType::StringList => Ok(Some(value.as_list().join(","))),
+        // unrecognized options are left for the tool; see option_warning
+        ParameterType::OptionList(_) => Ok(Some(value.to_string())),
+        ParameterType::ExistingFile(file_type) => {
+            let v = value.to_string();
+            if v.trim().is_empty() {
+                return Err("expected a file name.".to_string());
+            }
+            // a few tools take several input files separated by semicolons
+            for f in v.split(';').filter(|f| !f.trim().is_empty()) {
+                check_file(f.trim(), file_type, working_directory)?;
+            }
+            Ok(Some(v))
+        }
+        ParameterType::ExistingFileOrFloat(file_type) => {
+            if let Some(v) = value.as_f64() {
+                return Ok(Some(v.to_string()));
+            }
+            let v = value.to_string();
+            check_file(&v, file_type, working_directory)?;
+            Ok(Some(v))
+        }
+        ParameterType::FileList(file_type) => {
+            let files = value.as_list();
+            for f in &files {
+                check_file(f, file_type, working_directory)?;
+            }
+            Ok(Some(files.join(";")))
+        }
+        ParameterType::NewFile(_) => {
+            let v = value.to_string();
+            if v.trim().is_empty() {
+                return Err("expected a file name.".to_string());
+            }
+            Ok(Some(v))
+        }
+        ParameterType::Directory => {
+            let v = value.to_string();
+            if v.trim().is_empty() {
+                return Err("expected a directory.".to_string());
+            }
+            // directories without a parent are relative to the working directory, as
+            // file names are
+            let dir = if !v.contains(path::MAIN_SEPARATOR) && !v.contains('/') {
+                format!("{}{}", working_directory, v)
+            } else {
+                v.clone()
+            };
+            if !Path::new(&dir).is_dir() {
+                return Err("the directory does not exist.".to_string());
+            }
+            Ok(Some(v))
+        }
+    }
+}
+
+/// Returns a warning if the value of an option parameter is neither one of its options
+/// nor one of the aliases that its description documents, e.g. 'nearest neighbour' in
+/// "options include 'nn' (nearest neighbour)".
+fn option_warning(
+    tool_name: &str,
+    parameter: &ToolParameter,
+    value: &ToolArgValue,
+) -> Option<String> {
+    let options = match &parameter.parameter_type {
+        ParameterType::OptionList(options) => options,
+        _ => return None,
+    };
+    let v = value.to_string();
+    let aliases = documented_aliases(&parameter.description);
+    let recognized = options.iter().any(|o| {
+        is_option(&v, o)
+            || aliases
+                .iter()
+                .any(|(option, alias)| option.eq_ignore_ascii_case(o) && is_option(&v, alias))
+    });
+    if recognized {
+        return None;
+    }
+    Some(format!(
+        "The value '{}' of the parameter '{}' of {} is not one of its options ({}); the tool may not recognize it.",
+        v,
+        flag_name(parameter),
+        tool_name,
+        options.join(", ")
+    ))
+}
+
+/// Finds the options that a parameter description spells out, e.g. ('cc', 'cubic
+/// convolution') in "'cc' (cubic convolution)".
+fn documented_aliases(description: &str) -> Vec<(String, String)> {
+    let mut aliases = vec![];
+    let mut rest = description;
+    while let Some(start) = rest.find('\'') {
+        let after = &rest[start + 1..];
+        let end = match after.find('\'') {
+            Some(end) => end,
+            None => break,
+        };
+        let option = &after[..end];
+        rest = &after[end + 1..];
+        let trimmed = rest.trim_start();
+        if let Some(alias) = trimmed.strip_prefix('(') {
+            if let Some(close) = alias.find(')') {
+                aliases.push((option.to_string(), alias[..close].trim().to_string()));
+            }
+        }
+    }
+    aliases
+}
+
+/// Whether an option value names an option. The tools accept abbreviations of their
+/// options, e.g. 'h' for 'horizontal' or 'sca' for 'specific contributing area', as
+/// well as the options themselves, so these are accepted too.
+fn is_option(value: &str, option: &str) -> bool {
+    let initials = |s: &str| -> String {
+        s.split_whitespace()
+            .filter_map(|w| w.chars().next())
+            .collect()
+    };
+    let value = value.trim().to_lowercase();
+    let option = option.to_lowercase();
+    !value.is_empty()
+        && (option.starts_with(&value)
+            || value.starts_with(&option)
+            || value == initials(&option)
+            || (value.contains(' ') && initials(&value) == initials(&option)))
+}
+
+fn check_file(
+    file_name: &str,
+    file_type: &ParameterFileType,
+    working_directory: &str,
+) -> Result<(), String> {
+    if file_name.trim().is_empty() {
+        return Err("expected a file name.".to_string());
+    }
+    if is_memory_file(file_name) {
+        if !memory_file_exists(file_name) {
+            return Err("the in-memory file does not exist.".to_string());
+        }
+        return check_geometry_type(file_name, file_type);
+    }
+    // the layers of a GeoPackage are named as 'file.gpkg:layer', of which only the
+    // database file need exist
+    let (database, layer) = split_layer_name(file_name);
+    // file names without a directory are relative to the working directory, as
+    // they are in the tools
+    let database = if !database.contains(path::MAIN_SEPARATOR) && !database.contains('/') {
+        format!("{}{}", working_directory, database)
+    } else {
+        database
+    };
+    if !Path::new(&database).exists() {
+        return Err("the file does not exist.".to_string());
+    }
+    match layer {
+        Some(layer) => check_geometry_type(&format!("{}:{}", database, layer), file_type),
+        None => check_geometry_type(&database, file_type),
+    }
+}
+
+/// Checks that a vector input holds the type of geometry that the parameter expects.
+/// Rasters given to parameters that accept either rasters or vectors, and files whose
+/// headers can't be read, which the tools will report, are not checked.
+fn check_geometry_type(file_name: &str, file_type: &ParameterFileType) -> Result<(), String> {
+    let expected = match file_type {
+        ParameterFileType::Vector(g) | ParameterFileType::RasterAndVector(g) => g,
+        _ => return Ok(()),
+    };
+    if let VectorGeometryType::Any = expected {
+        return Ok(());
+    }
+    if !is_memory_file(file_name) && get_vector_type_from_file(file_name) == VectorType::Unknown {
+        return Ok(());
+    }
+    let base = match read_shape_type(file_name) {
+        Ok(shape_type) => shape_type.base_shape_type(),
+        Err(_) => return Ok(()),
+    };
+    let (matches, expected) = match expected {
+        VectorGeometryType::Any => (true, "any"),
+        VectorGeometryType::Point => (
+            base == ShapeType::Point || base == ShapeType::MultiPoint,
+            "a point",
+        ),
+        VectorGeometryType::Line => (base == ShapeType::PolyLine, "a line"),
+        VectorGeometryType::Polygon => (base == ShapeType::Polygon, "a polygon"),
+        VectorGeometryType::LineOrPolygon => (
+            base == ShapeType::PolyLine || base == ShapeType::Polygon,
+            "a line or polygon",
+        ),
+    };
+    // an empty layer has no geometry type
+    if matches || base == ShapeType::Null {
+        return Ok(());
+    }
+    let actual = match base {
+        ShapeType::Point | ShapeType::MultiPoint => "a point",
+        ShapeType::PolyLine => "a line",
+        _ => "a polygon",
+    };
+    Err(format!(
+        "the file is {} vector, but {} vector is expected.",
+        actual, expected
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        documented_aliases, option_warning, validate, validate_command_line, ToolArgValue, ToolArgs,
+    };
+    use crate::tools::{
+        ParameterFileType, ParameterType, ToolManager, ToolParameter, VectorGeometryType,
+    };
+    use std::env;
+    use std::fs;
+    use std::io::ErrorKind;
+    use whitebox_common::geopackage::GeoPackage;
+    use whitebox_raster::*;
+
+    fn parameter(flag: &str, parameter_type: ParameterType, optional: bool) -> ToolParameter {
+        ToolParameter {
+            name: flag.to_string(),
+            flags: vec![format!("--{}", flag)],
+            description: String::new(),
+            parameter_type,
+            default_value: None,
+            optional,
+        }
+    }
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    fn write_raster(file_name: &str) {
+        let configs = RasterConfigs {
+            rows: 2,
+            columns: 2,
+            north: 2.0,
+            south: 0.0,
+            east: 2.0,
+            west: 0.0,
+            resolution_x: 1.0,
+            resolution_y: 1.0,
+            data_type: DataType::F32,
+            ..Default::default()
+        };
+        Raster::initialize_using_config(file_name, &configs)
+            .write()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_validate_files() {
+        let dir = env::temp_dir().join(format!("wbt_tool_args_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let working_directory = format!("{}/", dir.to_string_lossy());
+        GeoPackage::create(&format!("{}data.gpkg", working_directory)).unwrap();
+        fs::write(dir.join("a.txt"), "a").unwrap();
+        fs::write(dir.join("b.txt"), "b").unwrap();
+        write_raster("mem://tool_args_dem");
+
+        let input = parameter(
+            "input",
+            ParameterType::ExistingFile(ParameterFileType::Raster),
+            false,
+        );
+        let valid =
+            |value: &str| validate(&input, &ToolArgValue::from(value), &working_directory).is_ok();
+        // the layer of a GeoPackage need only name an existing database, with or
+        // without a directory
+        assert!(valid("data.gpkg:dem"));
+        assert!(valid(&format!("{}data.gpkg:dem", working_directory)));
+        assert!(valid("data.gpkg"));
+        assert!(!valid("missing.gpkg:dem"));
+        assert!(valid("mem://tool_args_dem"));
+        assert!(!valid("mem://tool_args_missing"));
+        assert!(valid("a.txt;b.txt"));
+        assert!(!valid("a.txt;c.txt"));
+
+        let files = parameter(
+            "inputs",
+            ParameterType::FileList(ParameterFileType::Any),
+            false,
+        );
+        let list = |value: ToolArgValue| validate(&files, &value, &working_directory);
+        assert_eq!(
+            list(ToolArgValue::from(vec!["a.txt", "b.txt"])).unwrap(),
+            Some("a.txt;b.txt".to_string())
+        );
+        assert_eq!(
+            list(ToolArgValue::from("a.txt, b.txt")).unwrap(),
+            Some("a.txt;b.txt".to_string())
+        );
+        assert!(list(ToolArgValue::from(vec!["a.txt", "c.txt"])).is_err());
+
+        // a layer that does not exist is left for the tool to report
+        let polygons = parameter(
+            "polygons",
+            ParameterType::ExistingFile(ParameterFileType::Vector(VectorGeometryType::Polygon)),
+            false,
+        );
+        assert!(validate(
+            &polygons,
+            &ToolArgValue::from("data.gpkg:lakes"),
+            &working_directory
+        )
+        .is_ok());
+
+        MEMORY_RASTERS.remove("mem://tool_args_dem");
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_validate_options() {
+        let mut method = parameter(
+            "method",
+            ParameterType::OptionList(vec![
+                "horizontal".to_string(),
+                "specific contributing area".to_string(),
+                "nn".to_string(),
+            ]),
+            false,
+        );
+        method.description =
+            "Method; options include 'horizontal', 'sca', and 'nn' (nearest neighbour)".to_string();
+        let recognized = |value: &str| {
+            // unrecognized options are left for the tool, with a warning
+            assert!(validate(&method, &ToolArgValue::from(value), "").is_ok());
+            option_warning("Resample", &method, &ToolArgValue::from(value)).is_none()
+        };
+        assert!(recognized("horizontal"));
+        assert!(recognized("h"));
+        assert!(recognized("SCA"));
+        assert!(recognized("specific contributing area"));
+        assert!(recognized("nn"));
+        // documented aliases
+        assert!(recognized("nearest"));
+        assert!(recognized("Nearest Neighbour"));
+        assert!(!recognized("vertical"));
+        assert!(
+            option_warning("Resample", &method, &ToolArgValue::from("vertical"))
+                .unwrap()
+                .contains("'method'")
+        );
+
+        // Resample's methods are listed as nn, bilinear and cc
+        let tm =
+            ToolManager::new(&format!("{}/", env::temp_dir().to_string_lossy()), &false).unwrap();
+        let resample = tm.get_tool_parameter_list("Resample").unwrap();
+        write_raster("mem://tool_args_resample");
+        let warnings = validate_command_line(
+            "Resample",
+            &resample,
+            &args(&[
+                "--inputs=mem://tool_args_resample",
+                "--output=out.tif",
+                "--cell_size=0.5",
+                "--method=nearest",
+            ]),
+            "",
+        )
+        .unwrap();
+        assert!(warnings.is_empty());
+        MEMORY_RASTERS.remove("mem://tool_args_resample");
+
+        assert_eq!(
+            documented_aliases(
+                "'nn' (nearest neighbour), 'bilinear', and 'cc' (cubic convolution)"
+            ),
+            vec![
+                ("nn".to_string(), "nearest neighbour".to_string()),
+                ("cc".to_string(), "cubic convolution".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_directory() {
+        let dir = env::temp_dir().join(format!("wbt_tool_args_dir_{}", std::process::id()));
+        fs::create_dir_all(dir.join("tiles")).unwrap();
+        let working_directory = format!("{}/", dir.to_string_lossy());
+        let directory = parameter("output_dir", ParameterType::Directory, false);
+        let valid = |value: &str| {
+            validate(&directory, &ToolArgValue::from(value), &working_directory).is_ok()
+        };
+        // directories without a parent are within the working directory
+        assert!(valid("tiles"));
+        assert!(valid(&format!("{}tiles", working_directory)));
+        assert!(!valid("missing"));
+        assert!(!valid(""));
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_validate_command_line() {
+        let parameters = vec![
+            parameter(
+                "input",
+                ParameterType::ExistingFile(ParameterFileType::Raster),
+                false,
+            ),
+            parameter("zfactor", ParameterType::Float, true),
+            parameter(
+                "units",
+                ParameterType::OptionList(vec!["degrees".to_string(), "radians".to_string()]),
+                true,
+            ),
+            parameter("verbose", ParameterType::Boolean, true),
+        ];
+        write_raster("mem://tool_args_cli_dem");
+        let check =
+            |values: &[&str]| validate_command_line("Slope", &parameters, &args(values), "");
+        assert!(check(&[
+            "--input=mem://tool_args_cli_dem",
+            "--zfactor",
+            "-2",
+            "--verbose"
+        ])
+        .is_ok());
+        assert!(check(&[
+            "--input",
+            "mem://tool_args_cli_dem",
+            "--units=rad",
+            "--unknown=1"
+        ])
+        .unwrap()
+        .is_empty());
+
+        let error = check(&["--zfactor=1"]).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert!(error.to_string().contains("'input'"));
+        let error = check(&["--input=mem://tool_args_cli_dem", "--zfactor=one"]).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert!(error.to_string().contains("'zfactor'"));
+        // an unrecognized option is a warning rather than an error
+        let warnings = check(&["--input=mem://tool_args_cli_dem", "--units=gradians"]).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("'gradians'"));
+        assert!(check(&["--input=mem://tool_args_cli_missing"]).is_err());
+        MEMORY_RASTERS.remove("mem://tool_args_cli_dem");
+    }
+
+    #[test]
+    fn test_to_command_line() {
+        let mut verbose = parameter("verbose", ParameterType::Boolean, true);
+        verbose.flags.insert(0, "-v".to_string());
+        let mut fill = parameter("fill", ParameterType::Boolean, true);
+        fill.default_value = Some("true".to_string());
+        let parameters = vec![
+            parameter(
+                "dem",
+                ParameterType::ExistingFile(ParameterFileType::Raster),
+                false,
+            ),
+            parameter("zfactor", ParameterType::Float, true),
+            parameter("fields", ParameterType::StringList, true),
+            verbose,
+            fill,
+        ];
+        write_raster("mem://tool_args_to_cli_dem");
+        let command_line = |args: ToolArgs| args.to_command_line("Slope", &parameters, "");
+
+        // names are matched with or without dashes, in any case and by any flag
+        for name in &["dem", "--dem", "-DEM"] {
+            let args = ToolArgs::new().set(name, "mem://tool_args_to_cli_dem");
+            assert_eq!(
+                command_line(args).unwrap(),
+                vec!["--dem=mem://tool_args_to_cli_dem"]
+            );
+        }
+        let args = ToolArgs::new()
+            .set("dem", "mem://tool_args_to_cli_dem")
+            .set("-V", true)
+            .set("ZFACTOR", 2);
+        assert_eq!(
+            command_line(args).unwrap(),
+            vec![
+                "--dem=mem://tool_args_to_cli_dem",
+                "--zfactor=2",
+                "--verbose=true"
+            ]
+        );
+
+        // false is left out, unless it overrides a default of true
+        let args = ToolArgs::new()
+            .set("dem", "mem://tool_args_to_cli_dem")
+            .set("verbose", false)
+            .set("fill", false);
+        assert_eq!(
+            command_line(args).unwrap(),
+            vec!["--dem=mem://tool_args_to_cli_dem", "--fill=false"]
+        );
+
+        let args = ToolArgs::new()
+            .set("dem", "mem://tool_args_to_cli_dem")
+            .set("fields", vec!["a", "b", "c"]);
+        assert_eq!(
+            command_line(args).unwrap(),
+            vec!["--dem=mem://tool_args_to_cli_dem", "--fields=a,b,c"]
+        );
+
+        let args = ToolArgs::new()
+            .set("dem", "mem://tool_args_to_cli_dem")
+            .set("slope", 1);
+        let error = command_line(args).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert!(error.to_string().contains("'slope'"));
+        let error = command_line(ToolArgs::new().set("zfactor", 1.0)).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert!(error.to_string().contains("'dem'"));
+        let args = ToolArgs::new()
+            .set("dem", "mem://tool_args_to_cli_dem")
+            .set("zfactor", "one");
+        assert_eq!(
+            command_line(args).unwrap_err().kind(),
+            ErrorKind::InvalidInput
+        );
+        MEMORY_RASTERS.remove("mem://tool_args_to_cli_dem");
+    }
+
+    #[test]
+    fn test_run_tool_with() {
+        let working_directory = format!("{}/", env::temp_dir().to_string_lossy());
+        let tm = ToolManager::new(&working_directory, &false).unwrap();
+        write_raster("mem://tool_args_run_dem");
+        let args = ToolArgs::new()
+            .set("input", "mem://tool_args_run_dem")
+            .set("output", "mem://tool_args_run_reclass");
+
+        let error = tm
+            .run_tool_with("Reclass", args.clone().set("unknown", 1))
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+
+        // Reclass panics when a reclass value isn't a number, which becomes an error
+        let error = tm
+            .run_tool_with("Reclass", args.clone().set("reclass_vals", "1;a;2"))
+            .unwrap_err();
+        assert!(error.to_string().starts_with("Reclass failed"));
+
+        tm.run_tool_with("Reclass", args.set("reclass_vals", "1;min;max"))
+            .unwrap();
+        assert!(MEMORY_RASTERS
+            .remove("mem://tool_args_run_reclass")
+            .is_some());
+        MEMORY_RASTERS.remove("mem://tool_args_run_dem");
+    }
+}
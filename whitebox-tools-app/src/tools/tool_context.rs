@@ -0,0 +1,369 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: A ToolContext is passed to the run function of each tool. It carries the
+verbose mode, the sinks that receive the tool's progress updates and messages, and
+a token that can be used to cancel the tool while it is running. The command-line
+program uses StdoutSink, which prints everything just as the tools always have.
+Tools report progress whether or not they are in verbose mode, since that is
+where they check for cancellation; the updates only reach the progress sink in
+verbose mode. A cancelled tool stops at its next progress update and returns an
+error of kind Interrupted.
+Tools may also record structured results, e.g. the statistics that they print or
+the tables of their HTML reports, which are returned by the --json output mode.
+*/
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::convert::TryInto;
+use std::fmt;
+use std::io::{Error, ErrorKind};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// The severity of a message logged by a tool.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogLevel {
+    Info,
+    Warning,
+    Error,
+}
+
+/// Receives progress updates from running tools.
+pub trait ProgressSink: Send + Sync {
+    /// Called with the stage that the tool is in, e.g. "Progress" or "Saving data",
+    /// and the percentage of that stage that is complete.
+    fn progress(&self, label: &str, percent: usize);
+}
+
+/// Receives the messages logged by running tools.
+pub trait LogSink: Send + Sync {
+    fn log(&self, level: LogLevel, message: &str);
+}
+
+/// Prints progress updates and messages to standard output, in the form used by
+/// the command-line program.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StdoutSink;
+
+impl ProgressSink for StdoutSink {
+    fn progress(&self, label: &str, percent: usize) {
+        println!("{}: {}%", label, percent);
+    }
+}
+
+impl LogSink for StdoutSink {
+    fn log(&self, _level: LogLevel, message: &str) {
+        println!("{}", message);
+    }
+}
+
+/// A flag that is shared between a running tool and the code that started it,
+/// which can set it to ask the tool to stop.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    /// Asks the tools that use this token to stop.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// The structured results recorded by a tool, keyed by name. Clones share the
+/// same results.
+#[derive(Clone, Debug, Default)]
+pub struct ToolResults {
+    values: Arc<Mutex<Map<String, Value>>>,
+}
+
+impl ToolResults {
+    pub fn new() -> ToolResults {
+        ToolResults::default()
+    }
+
+    /// Records a result, replacing any earlier result of the same name. Values that
+    /// can't be represented in JSON are recorded as null.
+    pub fn set<T: Serialize>(&self, key: &str, value: T) {
+        let value = serde_json::to_value(value).unwrap_or(Value::Null);
+        self.values
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(key.to_string(), value);
+    }
+
+    pub fn get(&self, key: &str) -> Option<Value> {
+        self.values
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(key)
+            .cloned()
+    }
+
+    /// Returns a copy of all of the results.
+    pub fn to_map(&self) -> Map<String, Value> {
+        self.values
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+}
+
+/// The context in which a tool runs. Tools in plugin libraries are passed the context
+/// of the host, so changing this type requires TOOL_PLUGIN_ABI_VERSION to be
+/// incremented.
+#[derive(Clone)]
+pub struct ToolContext {
+    pub verbose: bool,
+    progress_sink: Arc<dyn ProgressSink>,
+    log_sink: Arc<dyn LogSink>,
+    cancellation_token: CancellationToken,
+    results: ToolResults,
+}
+
+impl ToolContext {
+    /// Creates a context that prints to standard output.
+    pub fn new(verbose: bool) -> ToolContext {
+        ToolContext {
+            verbose,
+            progress_sink: Arc::new(StdoutSink),
+            log_sink: Arc::new(StdoutSink),
+            cancellation_token: CancellationToken::new(),
+            results: ToolResults::new(),
+        }
+    }
+
+    pub fn with_progress_sink(mut self, sink: Arc<dyn ProgressSink>) -> ToolContext {
+        self.progress_sink = sink;
+        self
+    }
+
+    pub fn with_log_sink(mut self, sink: Arc<dyn LogSink>) -> ToolContext {
+        self.log_sink = sink;
+        self
+    }
+
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> ToolContext {
+        self.cancellation_token = token;
+        self
+    }
+
+    pub fn with_results(mut self, results: ToolResults) -> ToolContext {
+        self.results = results;
+        self
+    }
+
+    /// Returns the token that cancels tools running in this context.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancellation_token.clone()
+    }
+
+    /// Reports progress, returning an error if the tool has been cancelled. The
+    /// update is only passed to the progress sink in verbose mode, but the
+    /// cancellation check is always made.
+    pub fn progress<P: TryInto<usize>>(&self, label: &str, percent: P) -> Result<(), Error> {
+        if self.verbose {
+            self.progress_sink
+                .progress(label, percent.try_into().unwrap_or(0));
+        }
+        self.check_cancelled()
+    }
+
+    pub fn info<T: fmt::Display>(&self, message: T) {
+        self.log_sink.log(LogLevel::Info, &message.to_string());
+    }
+
+    pub fn warning<T: fmt::Display>(&self, message: T) {
+        self.log_sink.log(LogLevel::Warning, &message.to_string());
+    }
+
+    pub fn error<T: fmt::Display>(&self, message: T) {
+        self.log_sink.log(LogLevel::Error, &message.to_string());
+    }
+
+    /// Records a structured result of the tool, e.g. a statistic or a table.
+    pub fn set_result<T: Serialize>(&self, key: &str, value: T) {
+        self.results.set(key, value);
+    }
+
+    /// Returns the results recorded by tools running in this context.
+    pub fn results(&self) -> ToolResults {
+        self.results.clone()
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation_token.is_cancelled()
+    }
+
+    /// Returns an error of kind `Interrupted` if the tool has been cancelled.
+    pub fn check_cancelled(&self) -> Result<(), Error> {
+        if self.cancellation_token.is_cancelled() {
+            return Err(Error::new(
+                ErrorKind::Interrupted,
+                "The tool was cancelled.",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for ToolContext {
+    fn default() -> ToolContext {
+        ToolContext::new(false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CancellationToken, ProgressSink, ToolContext};
+    use crate::tools::terrain_analysis::{Slope, VisibilityIndex};
+    use crate::tools::WhiteboxTool;
+    use std::io::ErrorKind;
+    use std::sync::{Arc, Mutex};
+    use whitebox_raster::*;
+
+    #[derive(Default)]
+    struct RecordingSink {
+        updates: Mutex<Vec<(String, usize)>>,
+    }
+
+    impl ProgressSink for RecordingSink {
+        fn progress(&self, label: &str, percent: usize) {
+            self.updates
+                .lock()
+                .unwrap()
+                .push((label.to_string(), percent));
+        }
+    }
+
+    #[test]
+    fn test_progress_checks_cancellation_when_not_verbose() {
+        let sink = Arc::new(RecordingSink::default());
+        let token = CancellationToken::new();
+        let context = ToolContext::new(false)
+            .with_progress_sink(sink.clone())
+            .with_cancellation_token(token.clone());
+        assert!(context.progress("Progress", 10).is_ok());
+        token.cancel();
+        let err = context.progress("Progress", 20).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Interrupted);
+        assert!(sink.updates.lock().unwrap().is_empty());
+
+        let context = ToolContext::new(true).with_progress_sink(sink.clone());
+        context.progress("Progress", 30).unwrap();
+        assert_eq!(
+            *sink.updates.lock().unwrap(),
+            vec![("Progress".to_string(), 30)]
+        );
+    }
+
+    #[test]
+    fn test_cancel_non_verbose_run() {
+        let configs = RasterConfigs {
+            rows: 50,
+            columns: 40,
+            north: 50.0,
+            south: 0.0,
+            east: 40.0,
+            west: 0.0,
+            resolution_x: 1.0,
+            resolution_y: 1.0,
+            nodata: -32768.0,
+            data_type: DataType::F32,
+            ..Default::default()
+        };
+        let mut dem = Raster::initialize_using_config("mem://cancel_dem", &configs);
+        for row in 0..50 {
+            for col in 0..40 {
+                dem.set_value(row, col, (row * 2 + col) as f64);
+            }
+        }
+        dem.write().unwrap();
+        let args = vec![
+            "-i=mem://cancel_dem".to_string(),
+            "-o=mem://cancel_slope".to_string(),
+        ];
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let context = ToolContext::new(false).with_cancellation_token(token);
+        let err = Slope::new().run(args.clone(), "", &context).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Interrupted);
+        assert!(!MEMORY_RASTERS.contains("mem://cancel_slope"));
+
+        Slope::new()
+            .run(args, "", &ToolContext::new(false))
+            .unwrap();
+        assert!(MEMORY_RASTERS.contains("mem://cancel_slope"));
+        MEMORY_RASTERS.remove("mem://cancel_dem");
+        MEMORY_RASTERS.remove("mem://cancel_slope");
+    }
+
+    #[test]
+    fn test_worker_threads_report_through_context() {
+        let configs = RasterConfigs {
+            rows: 20,
+            columns: 20,
+            north: 20.0,
+            south: 0.0,
+            east: 20.0,
+            west: 0.0,
+            resolution_x: 1.0,
+            resolution_y: 1.0,
+            nodata: -32768.0,
+            data_type: DataType::F32,
+            ..Default::default()
+        };
+        let mut dem = Raster::initialize_using_config("mem://worker_dem", &configs);
+        for row in 0..20 {
+            for col in 0..20 {
+                dem.set_value(row, col, ((row * col) % 7) as f64);
+            }
+        }
+        dem.write().unwrap();
+        let args = vec![
+            "--dem=mem://worker_dem".to_string(),
+            "-o=mem://worker_visibility".to_string(),
+            "--res_factor=1".to_string(),
+        ];
+
+        // the progress of the worker threads reaches the sink
+        let sink = Arc::new(RecordingSink::default());
+        let context = ToolContext::new(true).with_progress_sink(sink.clone());
+        VisibilityIndex::new()
+            .run(args.clone(), "", &context)
+            .unwrap();
+        assert!(sink
+            .updates
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|(label, _)| label == "Progress (Loop 1 of 2)"));
+        assert!(MEMORY_RASTERS.remove("mem://worker_visibility").is_some());
+
+        // and the worker threads stop when the run is cancelled
+        let token = CancellationToken::new();
+        token.cancel();
+        let context = ToolContext::new(true)
+            .with_progress_sink(Arc::new(RecordingSink::default()))
+            .with_cancellation_token(token);
+        let err = VisibilityIndex::new().run(args, "", &context).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Interrupted);
+        assert!(!MEMORY_RASTERS.contains("mem://worker_visibility"));
+        MEMORY_RASTERS.remove("mem://worker_dem");
+    }
+}
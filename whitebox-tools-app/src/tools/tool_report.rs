@@ -0,0 +1,477 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: In JSON output mode (--json), a tool runs quietly and the outcome of the run
+is printed as a single JSON object on the last line of standard output, e.g.
+
+{"tool":"RasterSummaryStats","status":"ok","outputs":[],"results":{"minimum":0.0,...},
+"warnings":[],"messages":["Number of non-nodata grid cells: 1000",...]}
+
+The outputs are the output files named by the tool's arguments, the results are the
+structured results that the tool recorded (statistics, tables, etc.), and the warnings
+and messages are those that the tool logged. A run that fails has the status "error"
+and an error object holding one of the stable codes of ErrorCode, e.g. "not_found",
+and a message. A batch run adds the outcome for each of its files. Some of the
+libraries print warnings directly to standard output, so readers should parse the
+last line of the output rather than all of it.
+*/
+
+use super::tool_args::parameter_values;
+use super::{
+    panic_message, BatchResult, CancellationToken, LogLevel, LogSink, ParameterType, ProgressSink,
+    ToolContext, ToolManager, ToolParameter,
+};
+use serde_json::{Map, Value};
+use std::io::{Error, ErrorKind};
+use std::mem;
+use std::panic;
+use std::sync::{Arc, Mutex};
+
+/// A stable code for the kind of error with which a tool run failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// There is no tool or plugin of the given name.
+    UnknownTool,
+    /// An argument is missing or invalid.
+    InvalidInput,
+    /// A file doesn't exist.
+    NotFound,
+    PermissionDenied,
+    /// A file couldn't be read because its contents are invalid.
+    InvalidData,
+    Cancelled,
+    ToolPanicked,
+    /// The tool failed for one or more of the files of a batch.
+    BatchFailed,
+    /// Any other error.
+    ToolError,
+}
+
+impl ErrorCode {
+    /// Returns the code for an error returned by a tool.
+    pub fn from_error(error: &Error) -> ErrorCode {
+        match error.kind() {
+            ErrorKind::InvalidInput => ErrorCode::InvalidInput,
+            ErrorKind::NotFound => ErrorCode::NotFound,
+            ErrorKind::PermissionDenied => ErrorCode::PermissionDenied,
+            ErrorKind::InvalidData | ErrorKind::UnexpectedEof => ErrorCode::InvalidData,
+            ErrorKind::Interrupted => ErrorCode::Cancelled,
+            _ => ErrorCode::ToolError,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportStatus {
+    Ok,
+    Error,
+}
+
+/// The error with which a tool run failed.
+#[derive(Clone, Debug, Serialize)]
+pub struct ReportError {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+impl ReportError {
+    fn new(code: ErrorCode, message: String) -> ReportError {
+        ReportError { code, message }
+    }
+}
+
+impl From<&Error> for ReportError {
+    fn from(error: &Error) -> ReportError {
+        ReportError::new(ErrorCode::from_error(error), error.to_string())
+    }
+}
+
+/// The outcome of a tool run in JSON output mode.
+#[derive(Clone, Debug, Serialize)]
+pub struct ToolReport {
+    pub tool: String,
+    pub status: ReportStatus,
+    /// The output files of the run, which are only given if it succeeded.
+    pub outputs: Vec<String>,
+    pub results: Map<String, Value>,
+    pub warnings: Vec<String>,
+    pub messages: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ReportError>,
+    /// The outcome for each file of a batch run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<FileReport>>,
+}
+
+/// The outcome for one of the input files of a batch run.
+#[derive(Clone, Debug, Serialize)]
+pub struct FileReport {
+    pub input: String,
+    pub status: ReportStatus,
+    pub outputs: Vec<String>,
+    pub results: Map<String, Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ReportError>,
+}
+
+impl ToolReport {
+    pub(super) fn new(tool: String) -> ToolReport {
+        ToolReport {
+            tool,
+            status: ReportStatus::Ok,
+            outputs: vec![],
+            results: Map::new(),
+            warnings: vec![],
+            messages: vec![],
+            error: None,
+            files: None,
+        }
+    }
+
+    fn finish(mut self, result: Result<(), ReportError>, sink: &CollectingSink) -> ToolReport {
+        self.warnings = mem::take(&mut *sink.warnings.lock().unwrap());
+        self.messages = mem::take(&mut *sink.messages.lock().unwrap());
+        if let Err(e) = result {
+            self.status = ReportStatus::Error;
+            self.outputs.clear();
+            self.error = Some(e);
+        }
+        self
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.status == ReportStatus::Ok
+    }
+
+    /// Returns the report as a single line of JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+// Collects the messages logged by a tool, keeping only its latest progress update.
+#[derive(Default)]
+pub(super) struct CollectingSink {
+    warnings: Mutex<Vec<String>>,
+    messages: Mutex<Vec<String>>,
+    progress: Mutex<Option<(String, usize)>>,
+}
+
+impl CollectingSink {
+    /// The label and percentage of the latest progress update.
+    pub(super) fn latest_progress(&self) -> Option<(String, usize)> {
+        self.progress.lock().unwrap().clone()
+    }
+
+    pub(super) fn warnings(&self) -> Vec<String> {
+        self.warnings.lock().unwrap().clone()
+    }
+
+    pub(super) fn messages(&self) -> Vec<String> {
+        self.messages.lock().unwrap().clone()
+    }
+}
+
+impl ProgressSink for CollectingSink {
+    fn progress(&self, label: &str, percent: usize) {
+        *self.progress.lock().unwrap() = Some((label.to_string(), percent));
+    }
+}
+
+impl LogSink for CollectingSink {
+    fn log(&self, level: LogLevel, message: &str) {
+        let message = message.trim();
+        if message.is_empty() {
+            return;
+        }
+        let list = match level {
+            LogLevel::Info => &self.messages,
+            LogLevel::Warning | LogLevel::Error => &self.warnings,
+        };
+        list.lock().unwrap().push(message.to_string());
+    }
+}
+
+impl ToolManager {
+    /// Runs a tool quietly, returning a report of its output files, its structured
+    /// results and the messages that it logged, or of the error with which it failed.
+    /// Panics within the tool are caught and reported as errors.
+    pub fn run_tool_report(&self, tool_name: &str, args: Vec<String>) -> ToolReport {
+        let sink = Arc::new(CollectingSink::default());
+        self.run_tool_report_with(tool_name, args, &sink, CancellationToken::new(), false)
+    }
+
+    /// Runs a tool (see `run_tool_report`), sending its messages to `sink` as it runs,
+    /// and stopping it if `token` is cancelled. Progress updates are only sent to
+    /// `sink` in verbose mode, but the tool can be cancelled in either mode.
+    pub(super) fn run_tool_report_with(
+        &self,
+        tool_name: &str,
+        args: Vec<String>,
+        sink: &Arc<CollectingSink>,
+        token: CancellationToken,
+        verbose: bool,
+    ) -> ToolReport {
+        let context = report_context(sink, verbose).with_cancellation_token(token);
+        let mut report = ToolReport::new(self.canonical_tool_name(tool_name));
+        let result = self.report_parameters(tool_name).and_then(|parameters| {
+            report.outputs = self.output_files(&parameters, &args);
+            match panic::catch_unwind(panic::AssertUnwindSafe(|| {
+                self.run_tool_in_context(tool_name, args, &context)
+            })) {
+                Ok(result) => result.map_err(|e| ReportError::from(&e)),
+                Err(e) => Err(ReportError::new(
+                    ErrorCode::ToolPanicked,
+                    panic_message(e.as_ref()),
+                )),
+            }
+        });
+        report.results = context.results().to_map();
+        report.finish(result, sink)
+    }
+
+    /// Runs a tool in batch mode (see `run_batch`), returning a report that gives the
+    /// outcome for each of the files of the batch. The batch fails if the tool fails
+    /// for any of them.
+    pub fn run_batch_report(
+        &self,
+        tool_name: &str,
+        args: Vec<String>,
+        batch_arg: &str,
+        max_jobs: usize,
+    ) -> ToolReport {
+        let sink = Arc::new(CollectingSink::default());
+        let context = report_context(&sink, false);
+        let mut report = ToolReport::new(self.canonical_tool_name(tool_name));
+        let result = self.report_parameters(tool_name).and_then(|parameters| {
+            let results = self
+                .run_batch_with_context(tool_name, args, batch_arg, max_jobs, &context)
+                .map_err(|e| ReportError::from(&e))?;
+            let files: Vec<FileReport> = results
+                .into_iter()
+                .map(|r| self.file_report(&parameters, r))
+                .collect();
+            let num_failed = files.iter().filter(|f| f.error.is_some()).count();
+            let num_files = files.len();
+            report.files = Some(files);
+            if num_failed > 0 {
+                return Err(ReportError::new(
+                    ErrorCode::BatchFailed,
+                    format!(
+                        "{} of the {} files of the batch failed.",
+                        num_failed, num_files
+                    ),
+                ));
+            }
+            Ok(())
+        });
+        report.finish(result, &sink)
+    }
+
+    fn canonical_tool_name(&self, tool_name: &str) -> String {
+        self.get_tool(tool_name)
+            .map(|tool| tool.get_tool_name())
+            .unwrap_or_else(|| tool_name.to_string())
+    }
+
+    fn report_parameters(&self, tool_name: &str) -> Result<Vec<ToolParameter>, ReportError> {
+        self.get_tool_parameter_list(tool_name).map_err(|e| {
+            if e.kind() == ErrorKind::NotFound {
+                ReportError::new(ErrorCode::UnknownTool, e.to_string())
+            } else {
+                ReportError::from(&e)
+            }
+        })
+    }
+
+    fn file_report(&self, parameters: &[ToolParameter], result: BatchResult) -> FileReport {
+        let (status, outputs, error) = match &result.result {
+            Ok(()) => (
+                ReportStatus::Ok,
+                self.output_files(parameters, &result.args),
+                None,
+            ),
+            Err(e) => (ReportStatus::Error, vec![], Some(ReportError::from(e))),
+        };
+        FileReport {
+            input: result.input,
+            status,
+            outputs,
+            results: result.results.to_map(),
+            error,
+        }
+    }
+
+    // The output files named by the arguments of a tool, or by the defaults of its
+    // output parameters.
+    fn output_files(&self, parameters: &[ToolParameter], args: &[String]) -> Vec<String> {
+        parameters
+            .iter()
+            .zip(parameter_values(parameters, args))
+            .filter(|(p, _)| matches!(p.parameter_type, ParameterType::NewFile(_)))
+            .filter_map(|(p, value)| value.or_else(|| p.default_value.clone()))
+            .filter(|value| !value.trim().is_empty())
+            .map(|value| self.resolve_file_name(value.trim()))
+            .collect()
+    }
+}
+
+fn report_context(sink: &Arc<CollectingSink>, verbose: bool) -> ToolContext {
+    ToolContext::new(verbose)
+        .with_progress_sink(sink.clone())
+        .with_log_sink(sink.clone())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CollectingSink, ErrorCode, ReportStatus};
+    use crate::tools::{CancellationToken, ToolManager};
+    use serde_json::{json, Value};
+    use std::env;
+    use std::io::{Error, ErrorKind};
+    use std::sync::Arc;
+    use whitebox_raster::*;
+
+    fn tool_manager() -> ToolManager {
+        let working_directory = format!("{}/", env::temp_dir().to_string_lossy());
+        ToolManager::new(&working_directory, &false).unwrap()
+    }
+
+    // Writes a 10 x 10 in-memory raster with values 0 to 99, the first of which is nodata.
+    fn write_raster(file_name: &str) {
+        let configs = RasterConfigs {
+            rows: 10,
+            columns: 10,
+            north: 10.0,
+            south: 0.0,
+            east: 10.0,
+            west: 0.0,
+            resolution_x: 1.0,
+            resolution_y: 1.0,
+            nodata: 0.0,
+            data_type: DataType::F32,
+            ..Default::default()
+        };
+        let mut raster = Raster::initialize_using_config(file_name, &configs);
+        for row in 0..10 {
+            for col in 0..10 {
+                raster.set_value(row, col, (row * 10 + col) as f64);
+            }
+        }
+        raster.write().unwrap();
+    }
+
+    #[test]
+    fn test_error_codes() {
+        // the codes are part of the --json output, and so must not change
+        let codes = [
+            (ErrorCode::UnknownTool, "unknown_tool"),
+            (ErrorCode::InvalidInput, "invalid_input"),
+            (ErrorCode::NotFound, "not_found"),
+            (ErrorCode::PermissionDenied, "permission_denied"),
+            (ErrorCode::InvalidData, "invalid_data"),
+            (ErrorCode::Cancelled, "cancelled"),
+            (ErrorCode::ToolPanicked, "tool_panicked"),
+            (ErrorCode::BatchFailed, "batch_failed"),
+            (ErrorCode::ToolError, "tool_error"),
+        ];
+        for (code, name) in codes.iter() {
+            assert_eq!(serde_json::to_value(code).unwrap(), json!(name));
+        }
+
+        let kinds = [
+            (ErrorKind::InvalidInput, ErrorCode::InvalidInput),
+            (ErrorKind::NotFound, ErrorCode::NotFound),
+            (ErrorKind::PermissionDenied, ErrorCode::PermissionDenied),
+            (ErrorKind::InvalidData, ErrorCode::InvalidData),
+            (ErrorKind::UnexpectedEof, ErrorCode::InvalidData),
+            (ErrorKind::Interrupted, ErrorCode::Cancelled),
+            (ErrorKind::Other, ErrorCode::ToolError),
+        ];
+        for (kind, code) in kinds.iter() {
+            assert_eq!(ErrorCode::from_error(&Error::new(*kind, "error")), *code);
+        }
+    }
+
+    #[test]
+    fn test_successful_report() {
+        write_raster("mem://report_dem");
+        let tm = tool_manager();
+        let report = tm.run_tool_report(
+            "raster_summary_stats",
+            vec!["-i=mem://report_dem".to_string()],
+        );
+        assert!(report.is_ok(), "{:?}", report.error);
+        assert_eq!(report.tool, "RasterSummaryStats");
+        assert_eq!(report.results["num_valid_cells"], json!(99));
+        assert_eq!(report.results["num_nodata_cells"], json!(1));
+        assert_eq!(report.results["maximum"], json!(99.0));
+
+        // the report is printed as a single line, without the error or batch fields
+        let line = report.to_json();
+        assert!(!line.contains('\n'));
+        let value: Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["status"], json!("ok"));
+        assert_eq!(value["results"]["minimum"], json!(1.0));
+        assert!(value.get("error").is_none());
+        assert!(value.get("files").is_none());
+
+        let report = tm.run_tool_report(
+            "Slope",
+            vec![
+                "-i=mem://report_dem".to_string(),
+                "-o=mem://report_slope".to_string(),
+            ],
+        );
+        assert!(report.is_ok(), "{:?}", report.error);
+        assert_eq!(report.outputs, vec!["mem://report_slope".to_string()]);
+        MEMORY_RASTERS.remove("mem://report_dem");
+        MEMORY_RASTERS.remove("mem://report_slope");
+    }
+
+    #[test]
+    fn test_error_reports() {
+        let tm = tool_manager();
+        let report = tm.run_tool_report("NoSuchTool", vec![]);
+        assert_eq!(report.status, ReportStatus::Error);
+        assert_eq!(report.error.as_ref().unwrap().code, ErrorCode::UnknownTool);
+        let value: Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(value["status"], json!("error"));
+        assert_eq!(value["error"]["code"], json!("unknown_tool"));
+        assert!(!value["error"]["message"].as_str().unwrap().is_empty());
+
+        // arguments are validated before the tool runs, and a failed run has no outputs
+        let report = tm.run_tool_report(
+            "Slope",
+            vec![
+                "-i=mem://report_missing".to_string(),
+                "-o=mem://report_missing_slope".to_string(),
+            ],
+        );
+        assert_eq!(report.error.as_ref().unwrap().code, ErrorCode::InvalidInput);
+        assert!(report.outputs.is_empty());
+
+        write_raster("mem://report_cancelled_dem");
+        let token = CancellationToken::new();
+        token.cancel();
+        let sink = Arc::new(CollectingSink::default());
+        let report = tm.run_tool_report_with(
+            "Slope",
+            vec![
+                "-i=mem://report_cancelled_dem".to_string(),
+                "-o=mem://report_cancelled_slope".to_string(),
+            ],
+            &sink,
+            token,
+            false,
+        );
+        assert_eq!(report.error.as_ref().unwrap().code, ErrorCode::Cancelled);
+        MEMORY_RASTERS.remove("mem://report_cancelled_dem");
+    }
+}
@@ -0,0 +1,513 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 17/10/2026
+Last Modified: 17/10/2026
+License: MIT
+
+Notes: A workflow is a set of tool calls, described in JSON or YAML, that are run within
+a single process. Steps pass data to one another using in-memory (mem://) files, so that
+intermediate rasters, vectors and LiDAR files are never written to disk. A step that
+reads a mem:// file depends on the step that writes it, and steps may also name other
+steps that they depend on, so the steps form a directed acyclic graph, which is run in
+an order in which every step follows those that it depends on. Each in-memory file that
+is read within the workflow is released once the last step that reads it has run; those
+that are never read remain in memory for the caller. Plugins, whether they run as
+separate processes or are loaded from libraries, can't read or write in-memory files.
+*/
+
+use super::{ParameterType, ToolArgValue, ToolArgs, ToolContext, ToolManager};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+use std::time::Instant;
+use whitebox_common::memory_store::is_memory_file;
+use whitebox_common::utils::get_formatted_elapsed_time;
+use whitebox_lidar::MEMORY_LIDAR;
+use whitebox_raster::MEMORY_RASTERS;
+use whitebox_vector::MEMORY_VECTORS;
+
+/// A set of tool calls, some of which use the outputs of others, e.g.:
+///
+/// ```text
+/// {
+///   "steps": [
+///     { "id": "fill", "tool": "FillDepressions",
+///       "args": { "dem": "dem.tif", "output": "mem://filled.tif" } },
+///     { "id": "pointer", "tool": "D8Pointer",
+///       "args": { "dem": "mem://filled.tif", "output": "mem://pointer.tif" } },
+///     { "id": "accum", "tool": "D8FlowAccumulation",
+///       "args": { "input": "mem://pointer.tif", "output": "flow_accum.tif", "pntr": true } }
+///   ]
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Workflow {
+    pub steps: Vec<WorkflowStep>,
+}
+
+/// A tool call within a workflow.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct WorkflowStep {
+    /// A name for the step that is unique within the workflow. Steps without an id are
+    /// named by their position, starting at 1.
+    #[serde(default)]
+    pub id: String,
+    pub tool: String,
+    /// The tool's arguments, named as they are with `ToolArgs`.
+    #[serde(default)]
+    pub args: serde_json::Map<String, Value>,
+    /// The ids of steps that must be run before this one, other than those that write
+    /// the in-memory files that it reads.
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+}
+
+impl Workflow {
+    pub fn from_json(json: &str) -> Result<Workflow, Error> {
+        serde_json::from_str(json).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("Error reading the workflow: {}", e),
+            )
+        })
+    }
+
+    pub fn from_yaml(yaml: &str) -> Result<Workflow, Error> {
+        serde_yaml::from_str(yaml).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("Error reading the workflow: {}", e),
+            )
+        })
+    }
+
+    /// Reads a workflow file, which is YAML if it has a .yaml or .yml extension and
+    /// JSON otherwise.
+    pub fn from_file(file_name: &str) -> Result<Workflow, Error> {
+        let contents = fs::read_to_string(file_name)?;
+        let extension = Path::new(file_name)
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        if extension == "yaml" || extension == "yml" {
+            Workflow::from_yaml(&contents)
+        } else {
+            Workflow::from_json(&contents)
+        }
+    }
+}
+
+// A step that is ready to run.
+struct PlannedStep {
+    id: String,
+    tool: String,
+    args: ToolArgs,
+    // the in-memory files that the step reads and writes
+    reads: Vec<String>,
+    writes: Vec<String>,
+    // the indices of the steps that must be run first
+    dependencies: Vec<usize>,
+}
+
+impl ToolManager {
+    /// Runs the steps of a workflow, in an order in which every step follows the steps
+    /// that it depends on.
+    pub fn run_workflow(&self, workflow: &Workflow) -> Result<(), Error> {
+        self.run_workflow_with_context(workflow, &ToolContext::new(self.verbose))
+    }
+
+    /// Runs a workflow in the given context; see `run_workflow`.
+    pub fn run_workflow_with_context(
+        &self,
+        workflow: &Workflow,
+        context: &ToolContext,
+    ) -> Result<(), Error> {
+        let start = Instant::now();
+        let steps = self.plan_workflow(workflow)?;
+        let order = order_steps(&steps)?;
+
+        // the in-memory files that are written and then read within the workflow,
+        // along with the position in the order of the last step that reads each one
+        let mut last_reads: HashMap<&str, usize> = HashMap::new();
+        for (position, &i) in order.iter().enumerate() {
+            for file_name in &steps[i].reads {
+                if steps.iter().any(|s| s.writes.contains(file_name)) {
+                    last_reads.insert(file_name, position);
+                }
+            }
+        }
+
+        for (position, &i) in order.iter().enumerate() {
+            let step = &steps[i];
+            if let Err(e) = context.check_cancelled() {
+                last_reads.keys().for_each(|f| release_memory_file(f));
+                return Err(e);
+            }
+            if context.verbose {
+                context.info(format!(
+                    "Running step {} of {}: {} ({})",
+                    position + 1,
+                    order.len(),
+                    step.id,
+                    step.tool
+                ));
+            }
+            if let Err(e) = self.run_tool_with_context(&step.tool, step.args.clone(), context) {
+                last_reads.keys().for_each(|f| release_memory_file(f));
+                return Err(Error::new(
+                    e.kind(),
+                    format!("Step '{}' ({}) failed: {}", step.id, step.tool, e),
+                ));
+            }
+            // release the in-memory files that no later step reads
+            last_reads.retain(|f, p| {
+                if *p == position {
+                    release_memory_file(f);
+                }
+                *p != position
+            });
+        }
+
+        if context.verbose {
+            context.info(format!(
+                "The workflow completed in {}",
+                get_formatted_elapsed_time(start)
+            ));
+        }
+        Ok(())
+    }
+
+    // Converts the arguments of each step and finds the steps that each depends on.
+    fn plan_workflow(&self, workflow: &Workflow) -> Result<Vec<PlannedStep>, Error> {
+        let invalid = |msg: String| Error::new(ErrorKind::InvalidInput, msg);
+        let mut steps: Vec<PlannedStep> = vec![];
+        for (i, step) in workflow.steps.iter().enumerate() {
+            let id = if step.id.trim().is_empty() {
+                (i + 1).to_string()
+            } else {
+                step.id.trim().to_string()
+            };
+            if steps.iter().any(|s| s.id == id) {
+                return Err(invalid(format!(
+                    "The workflow has more than one step with the id '{}'.",
+                    id
+                )));
+            }
+            let parameters = self.get_tool_parameter_list(&step.tool).map_err(|e| {
+                Error::new(e.kind(), format!("Step '{}' of the workflow: {}", id, e))
+            })?;
+            let mut args = ToolArgs::new();
+            let mut reads = vec![];
+            let mut writes = vec![];
+            for (name, value) in &step.args {
+                let value = match to_tool_arg_value(value) {
+                    Some(v) => v,
+                    None if value.is_null() => continue,
+                    None => {
+                        return Err(invalid(format!(
+                            "Step '{}' of the workflow: the value of '{}' must be a Boolean, number, string or list.",
+                            id, name
+                        )))
+                    }
+                };
+                let is_output = parameters
+                    .iter()
+                    .find(|p| p.flags.iter().any(|f| same_flag(f, name)))
+                    .map(|p| matches!(p.parameter_type, ParameterType::NewFile(_)))
+                    .unwrap_or(false);
+                for file_name in memory_file_names(&value) {
+                    if is_output {
+                        writes.push(file_name);
+                    } else {
+                        reads.push(file_name);
+                    }
+                }
+                args.insert(name, value);
+            }
+            if (!reads.is_empty() || !writes.is_empty())
+                && (self.get_tool(&step.tool).is_none() || self.plugins.contains(&step.tool))
+            {
+                return Err(invalid(format!(
+                    "Step '{}' of the workflow: {} is a plugin, which can't use in-memory files.",
+                    id, step.tool
+                )));
+            }
+            steps.push(PlannedStep {
+                id,
+                tool: step.tool.clone(),
+                args,
+                reads,
+                writes,
+                dependencies: vec![],
+            });
+        }
+
+        let mut writers: HashMap<String, usize> = HashMap::new();
+        for (i, step) in steps.iter().enumerate() {
+            for file_name in &step.writes {
+                if let Some(&j) = writers.get(file_name) {
+                    return Err(invalid(format!(
+                        "The in-memory file {} is written by both step '{}' and step '{}'.",
+                        file_name, steps[j].id, step.id
+                    )));
+                }
+                writers.insert(file_name.clone(), i);
+            }
+        }
+        for (i, step) in workflow.steps.iter().enumerate() {
+            let mut dependencies = vec![];
+            for id in &step.depends_on {
+                match steps.iter().position(|s| s.id == id.trim()) {
+                    Some(j) => dependencies.push(j),
+                    None => {
+                        return Err(invalid(format!(
+                            "Step '{}' of the workflow depends on an unknown step '{}'.",
+                            steps[i].id, id
+                        )))
+                    }
+                }
+            }
+            for file_name in &steps[i].reads {
+                if let Some(&j) = writers.get(file_name) {
+                    dependencies.push(j);
+                }
+            }
+            dependencies.sort_unstable();
+            dependencies.dedup();
+            steps[i].dependencies = dependencies;
+        }
+        Ok(steps)
+    }
+}
+
+/// Returns true if an in-memory file with the given name exists.
+pub(crate) fn memory_file_exists(file_name: &str) -> bool {
+    MEMORY_RASTERS.contains(file_name)
+        || MEMORY_VECTORS.contains(file_name)
+        || MEMORY_LIDAR.contains(file_name)
+}
+
+fn release_memory_file(file_name: &str) {
+    MEMORY_RASTERS.remove(file_name);
+    MEMORY_VECTORS.remove(file_name);
+    MEMORY_LIDAR.remove(file_name);
+}
+
+// Returns the order in which to run the steps, keeping to the order of the workflow
+// where the dependencies allow.
+fn order_steps(steps: &[PlannedStep]) -> Result<Vec<usize>, Error> {
+    let mut done = vec![false; steps.len()];
+    let mut order = Vec::with_capacity(steps.len());
+    while order.len() < steps.len() {
+        match (0..steps.len())
+            .find(|&i| !done[i] && steps[i].dependencies.iter().all(|&j| done[j] && j != i))
+        {
+            Some(i) => {
+                done[i] = true;
+                order.push(i);
+            }
+            None => {
+                let remaining: Vec<&str> = (0..steps.len())
+                    .filter(|&i| !done[i])
+                    .map(|i| steps[i].id.as_str())
+                    .collect();
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "The workflow contains a cycle among the steps: {}.",
+                        remaining.join(", ")
+                    ),
+                ));
+            }
+        }
+    }
+    Ok(order)
+}
+
+fn to_tool_arg_value(value: &Value) -> Option<ToolArgValue> {
+    match value {
+        Value::Bool(v) => Some(ToolArgValue::Boolean(*v)),
+        Value::Number(n) => match n.as_i64() {
+            Some(v) => Some(ToolArgValue::Integer(v)),
+            None => n.as_f64().map(ToolArgValue::Float),
+        },
+        Value::String(s) => Some(ToolArgValue::String(s.clone())),
+        Value::Array(a) => Some(ToolArgValue::List(
+            a.iter()
+                .map(|v| match v {
+                    Value::String(s) => s.clone(),
+                    v => v.to_string(),
+                })
+                .collect(),
+        )),
+        _ => None,
+    }
+}
+
+// The names of the in-memory files within an argument, which may be a list of files.
+fn memory_file_names(value: &ToolArgValue) -> Vec<String> {
+    let names: Vec<String> = match value {
+        ToolArgValue::String(s) => s.split([';', ',']).map(|s| s.to_string()).collect(),
+        ToolArgValue::List(v) => v.clone(),
+        _ => vec![],
+    };
+    names
+        .into_iter()
+        .map(|s| s.trim().to_string())
+        .filter(|s| is_memory_file(s))
+        .collect()
+}
+
+pub(super) fn same_flag(flag: &str, name: &str) -> bool {
+    flag.trim_start_matches('-')
+        .eq_ignore_ascii_case(name.trim().trim_start_matches('-'))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{order_steps, Workflow};
+    use crate::tools::ToolManager;
+    use std::env;
+    use std::io::ErrorKind;
+    use whitebox_raster::*;
+
+    fn tool_manager() -> ToolManager {
+        ToolManager::new(&format!("{}/", env::temp_dir().to_string_lossy()), &false).unwrap()
+    }
+
+    // A 5 x 5 plane that slopes down to the east.
+    fn write_dem(file_name: &str) {
+        let configs = RasterConfigs {
+            rows: 5,
+            columns: 5,
+            north: 5.0,
+            south: 0.0,
+            east: 5.0,
+            west: 0.0,
+            resolution_x: 1.0,
+            resolution_y: 1.0,
+            nodata: -32768.0,
+            data_type: DataType::F32,
+            ..Default::default()
+        };
+        let mut dem = Raster::initialize_using_config(file_name, &configs);
+        for row in 0..5 {
+            for col in 0..5 {
+                dem.set_value(row, col, 10.0 - col as f64);
+            }
+        }
+        dem.write().unwrap();
+    }
+
+    // FillDepressions, D8Pointer and D8FlowAccumulation, listed in reverse.
+    fn flow_accumulation_workflow(prefix: &str) -> Workflow {
+        Workflow::from_json(
+            &r#"{
+              "steps": [
+                { "id": "accum", "tool": "D8FlowAccumulation",
+                  "args": { "input": "mem://PREFIX_pointer", "output": "mem://PREFIX_accum", "pntr": true } },
+                { "id": "pointer", "tool": "D8Pointer",
+                  "args": { "dem": "mem://PREFIX_filled", "output": "mem://PREFIX_pointer" } },
+                { "id": "fill", "tool": "FillDepressions",
+                  "args": { "dem": "mem://PREFIX_dem", "output": "mem://PREFIX_filled" } }
+              ]
+            }"#
+            .replace("PREFIX", prefix),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_run_workflow() {
+        let tm = tool_manager();
+        let workflow = flow_accumulation_workflow("workflow_run");
+        let steps = tm.plan_workflow(&workflow).unwrap();
+        let order: Vec<&str> = order_steps(&steps)
+            .unwrap()
+            .into_iter()
+            .map(|i| steps[i].id.as_str())
+            .collect();
+        assert_eq!(order, vec!["fill", "pointer", "accum"]);
+
+        write_dem("mem://workflow_run_dem");
+        tm.run_workflow(&workflow).unwrap();
+        let accum = MEMORY_RASTERS.get("mem://workflow_run_accum").unwrap();
+        for col in 0..5 {
+            assert_eq!(accum.get_value(2, col), (col + 1) as f64);
+        }
+        // the intermediate files are released, while the input and the output, which
+        // no step reads, remain
+        assert!(!MEMORY_RASTERS.contains("mem://workflow_run_filled"));
+        assert!(!MEMORY_RASTERS.contains("mem://workflow_run_pointer"));
+        assert!(MEMORY_RASTERS.remove("mem://workflow_run_accum").is_some());
+        assert!(MEMORY_RASTERS.remove("mem://workflow_run_dem").is_some());
+    }
+
+    #[test]
+    fn test_workflow_errors() {
+        let tm = tool_manager();
+        let error_message = |workflow: &Workflow| {
+            let error = tm.run_workflow(workflow).unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::InvalidInput);
+            error.to_string()
+        };
+
+        let mut workflow = flow_accumulation_workflow("workflow_cycle");
+        workflow.steps[2].depends_on = vec!["accum".to_string()];
+        let msg = error_message(&workflow);
+        assert!(msg.contains("cycle"));
+        assert!(msg.contains("fill, pointer") || msg.contains("accum, pointer, fill"));
+
+        let mut workflow = flow_accumulation_workflow("workflow_writers");
+        workflow.steps[0].args["output"] = "mem://workflow_writers_filled".into();
+        let msg = error_message(&workflow);
+        assert!(msg.contains("written by both step 'accum' and step 'fill'"));
+
+        let mut workflow = flow_accumulation_workflow("workflow_unknown");
+        workflow.steps[1].depends_on = vec!["breach".to_string()];
+        let msg = error_message(&workflow);
+        assert!(msg.contains("unknown step 'breach'"));
+
+        // none of the steps were run
+        assert!(!MEMORY_RASTERS.contains("mem://workflow_cycle_accum"));
+    }
+
+    #[test]
+    fn test_workflow_from_yaml() {
+        let yaml = r#"
+steps:
+  - id: fill
+    tool: FillDepressions
+    args:
+      dem: dem.tif
+      output: mem://filled.tif
+      fix_flats: true
+  - tool: Slope
+    args:
+      dem: mem://filled.tif
+      output: slope.tif
+      zfactor: 1.5
+    depends_on: [fill]
+"#;
+        let workflow = Workflow::from_yaml(yaml).unwrap();
+        assert_eq!(workflow.steps.len(), 2);
+        assert_eq!(workflow.steps[0].id, "fill");
+        assert_eq!(workflow.steps[0].args["fix_flats"], true);
+        assert_eq!(workflow.steps[1].id, "");
+        assert_eq!(workflow.steps[1].tool, "Slope");
+        assert_eq!(workflow.steps[1].args["zfactor"], 1.5);
+        assert_eq!(workflow.steps[1].depends_on, vec!["fill"]);
+
+        // steps without an id are named by their position
+        let steps = tool_manager().plan_workflow(&workflow).unwrap();
+        assert_eq!(steps[1].id, "2");
+        assert_eq!(steps[1].dependencies, vec![0]);
+
+        let error = Workflow::from_yaml("steps: [").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,86 @@
+// Runs the Python wrapper (whitebox_tools.py) against the whitebox_tools executable built
+// with this package. The tests are skipped when python3 isn't available.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("wbt_python_wrapper_{}", name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// Runs `script` with the wrapper importable and `wbt` bound to a WhiteboxTools object using
+// the executable under test, returning the script's standard output.
+fn run_python(script: &str, working_dir: &Path) -> Option<String> {
+    if Command::new("python3").arg("--version").output().is_err() {
+        return None;
+    }
+    let wrapper_dir = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let exe_dir = Path::new(env!("CARGO_BIN_EXE_whitebox_tools"))
+        .parent()
+        .unwrap();
+    let prelude = format!(
+        "import sys\nsys.path.insert(0, {:?})\nfrom whitebox_tools import WhiteboxTools\nwbt = WhiteboxTools()\nwbt.set_whitebox_dir({:?})\nwbt.set_working_dir({:?})\n",
+        wrapper_dir.to_str().unwrap(),
+        exe_dir.to_str().unwrap(),
+        working_dir.to_str().unwrap(),
+    );
+    let output = Command::new("python3")
+        .arg("-c")
+        .arg(prelude + script)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Some(String::from_utf8(output.stdout).unwrap())
+}
+
+#[test]
+fn test_verbose_mode_applies_to_each_run() {
+    let dir = temp_dir("verbose");
+    fs::write(
+        dir.join("input.asc"),
+        "ncols 3\nnrows 3\nxllcorner 0\nyllcorner 0\ncellsize 1\nNODATA_value -9999\n1 -2 3\n-4 5 -6\n7 -8 9\n",
+    )
+    .unwrap();
+    // each run prints its return value and the number of lines sent to the callback
+    let script = r#"
+def run(output):
+    lines = []
+    ret = wbt.absolute_value(i='input.asc', output=output, callback=lines.append)
+    print(ret, len(lines))
+
+wbt.set_verbose_mode(False)
+run('quiet.asc')
+wbt.set_verbose_mode(True)
+run('verbose.asc')
+wbt.set_verbose_mode(False)
+run('quiet_again.asc')
+"#;
+    let stdout = match run_python(script, &dir) {
+        Some(stdout) => stdout,
+        None => return,
+    };
+    let runs: Vec<(i32, usize)> = stdout
+        .lines()
+        .map(|line| {
+            let mut values = line.split_whitespace().map(|v| v.parse().unwrap());
+            (values.next().unwrap() as i32, values.next().unwrap())
+        })
+        .collect();
+    assert_eq!(runs.len(), 3);
+    assert!(runs.iter().all(|&(ret, _)| ret == 0));
+    assert_eq!(runs[0].1, 0);
+    assert!(runs[1].1 > 0);
+    assert_eq!(runs[2].1, 0);
+    for output in &["quiet.asc", "verbose.asc", "quiet_again.asc"] {
+        assert!(dir.join(output).exists(), "{} wasn't written", output);
+    }
+    let _ = fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,865 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+
+Notes: A format-neutral, in-memory representation of a vector layer. Each format driver
+(Shapefile, GeoJSON, FlatGeobuf) converts to and from a FeatureLayer, which allows a
+vector file to be read into a Shapefile structure regardless of its on-disk format.
+*/
+
+use crate::shapefile::attributes::*;
+use crate::shapefile::geometry::*;
+use crate::shapefile::Shapefile;
+use crate::{flatgeobuf, geojson, geopackage, get_vector_type_from_file, VectorType};
+use std::f64;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+use whitebox_common::algorithms::point_in_poly;
+pub(crate) use whitebox_common::spatial_ref_system::epsg_from_wkt;
+use whitebox_common::structures::{BoundingBox, Point2D};
+
+/// A single vertex, with optional elevation (z) and measure (m) values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Coordinate {
+    pub x: f64,
+    pub y: f64,
+    pub z: Option<f64>,
+    pub m: Option<f64>,
+}
+
+impl Coordinate {
+    /// Creates a two-dimensional coordinate.
+    pub fn new(x: f64, y: f64) -> Coordinate {
+        Coordinate {
+            x,
+            y,
+            z: None,
+            m: None,
+        }
+    }
+
+    /// Creates a coordinate with an elevation value.
+    pub fn new_z(x: f64, y: f64, z: f64) -> Coordinate {
+        Coordinate {
+            x,
+            y,
+            z: Some(z),
+            m: None,
+        }
+    }
+}
+
+/// A Simple Features geometry. Polygons are stored as a list of rings, the first of which
+/// is the exterior ring and the remainder of which are holes.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FeatureGeometry {
+    Point(Coordinate),
+    LineString(Vec<Coordinate>),
+    Polygon(Vec<Vec<Coordinate>>),
+    MultiPoint(Vec<Coordinate>),
+    MultiLineString(Vec<Vec<Coordinate>>),
+    MultiPolygon(Vec<Vec<Vec<Coordinate>>>),
+}
+
+impl FeatureGeometry {
+    /// Returns the Simple Features name of the geometry type, e.g. 'MultiPolygon'.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            FeatureGeometry::Point(_) => "Point",
+            FeatureGeometry::LineString(_) => "LineString",
+            FeatureGeometry::Polygon(_) => "Polygon",
+            FeatureGeometry::MultiPoint(_) => "MultiPoint",
+            FeatureGeometry::MultiLineString(_) => "MultiLineString",
+            FeatureGeometry::MultiPolygon(_) => "MultiPolygon",
+        }
+    }
+
+    /// Returns all of the coordinates in the geometry, in order.
+    pub fn coordinates(&self) -> Vec<Coordinate> {
+        match self {
+            FeatureGeometry::Point(c) => vec![*c],
+            FeatureGeometry::LineString(cs) | FeatureGeometry::MultiPoint(cs) => cs.clone(),
+            FeatureGeometry::Polygon(parts) | FeatureGeometry::MultiLineString(parts) => {
+                parts.concat()
+            }
+            FeatureGeometry::MultiPolygon(polygons) => {
+                polygons.iter().flat_map(|rings| rings.concat()).collect()
+            }
+        }
+    }
+
+    /// Returns true if any of the geometry's coordinates has an elevation value.
+    pub fn has_z(&self) -> bool {
+        self.coordinates().iter().any(|c| c.z.is_some())
+    }
+
+    /// Returns true if any of the geometry's coordinates has a measure value.
+    pub fn has_m(&self) -> bool {
+        self.coordinates().iter().any(|c| c.m.is_some())
+    }
+
+    /// Returns the bounding box of the geometry.
+    pub fn get_bounding_box(&self) -> BoundingBox {
+        let mut bb = BoundingBox {
+            min_x: f64::INFINITY,
+            max_x: f64::NEG_INFINITY,
+            min_y: f64::INFINITY,
+            max_y: f64::NEG_INFINITY,
+        };
+        for c in self.coordinates() {
+            bb.min_x = bb.min_x.min(c.x);
+            bb.max_x = bb.max_x.max(c.x);
+            bb.min_y = bb.min_y.min(c.y);
+            bb.max_y = bb.max_y.max(c.y);
+        }
+        bb
+    }
+
+    /// Converts a Shapefile record into a geometry. Null records, and records without any
+    /// points, are returned as None. The parts of a polygon record are grouped into polygons
+    /// using the Shapefile convention that holes are wound counter-clockwise; each hole is
+    /// assigned to the exterior ring that contains it.
+    pub fn from_shapefile_geometry(sfg: &ShapefileGeometry) -> Option<FeatureGeometry> {
+        if sfg.points.is_empty() {
+            return None;
+        }
+        let coord = |i: usize| Coordinate {
+            x: sfg.points[i].x,
+            y: sfg.points[i].y,
+            z: sfg.z_array.get(i).copied(),
+            m: sfg.m_array.get(i).copied(),
+        };
+        let num_points = sfg.points.len();
+        let mut parts = vec![];
+        for p in 0..sfg.parts.len() {
+            let start = sfg.parts[p] as usize;
+            let end = if p < sfg.parts.len() - 1 {
+                sfg.parts[p + 1] as usize
+            } else {
+                num_points
+            };
+            parts.push(
+                (start..end.min(num_points))
+                    .map(coord)
+                    .collect::<Vec<Coordinate>>(),
+            );
+        }
+        match sfg.shape_type.base_shape_type() {
+            ShapeType::Null => None,
+            ShapeType::Point => Some(FeatureGeometry::Point(coord(0))),
+            ShapeType::MultiPoint => Some(FeatureGeometry::MultiPoint(
+                (0..num_points).map(coord).collect(),
+            )),
+            ShapeType::PolyLine => match parts.len() {
+                0 => None,
+                1 => Some(FeatureGeometry::LineString(parts.pop().unwrap())),
+                _ => Some(FeatureGeometry::MultiLineString(parts)),
+            },
+            _ => {
+                // polygon
+                let mut polygons: Vec<Vec<Vec<Coordinate>>> = vec![];
+                for (p, ring) in parts.into_iter().enumerate() {
+                    if !sfg.is_hole(p as i32) || polygons.is_empty() {
+                        polygons.push(vec![ring]);
+                    } else {
+                        let test_point = Point2D::new(ring[0].x, ring[0].y);
+                        let mut owner = polygons.len() - 1;
+                        for (i, rings) in polygons.iter().enumerate() {
+                            let exterior: Vec<Point2D> =
+                                rings[0].iter().map(|c| Point2D::new(c.x, c.y)).collect();
+                            if point_in_poly(&test_point, &exterior) {
+                                owner = i;
+                                break;
+                            }
+                        }
+                        polygons[owner].push(ring);
+                    }
+                }
+                match polygons.len() {
+                    0 => None,
+                    1 => Some(FeatureGeometry::Polygon(polygons.pop().unwrap())),
+                    _ => Some(FeatureGeometry::MultiPolygon(polygons)),
+                }
+            }
+        }
+    }
+
+    /// Converts the geometry into a Shapefile record of the specified ShapeType. Polygon rings
+    /// are re-oriented so that exterior rings are clockwise and holes are counter-clockwise.
+    /// Missing elevation and measure values are set to zero in Z and M ShapeTypes. A geometry
+    /// that is incompatible with the ShapeType is returned as a Null record.
+    pub fn to_shapefile_geometry(&self, shape_type: ShapeType) -> ShapefileGeometry {
+        let mut sfg = ShapefileGeometry::new(shape_type);
+        let dimension = shape_type.dimension();
+        let add_point = |sfg: &mut ShapefileGeometry, c: &Coordinate| {
+            let p = Point2D::new(c.x, c.y);
+            match dimension {
+                ShapeTypeDimension::XY => sfg.add_point(p),
+                ShapeTypeDimension::Measure => sfg.add_pointm(p, c.m.unwrap_or(0f64)),
+                ShapeTypeDimension::Z => {
+                    sfg.add_pointz(p, c.m.unwrap_or(0f64), c.z.unwrap_or(0f64))
+                }
+            }
+        };
+        let add_part = |sfg: &mut ShapefileGeometry, cs: &[Coordinate]| {
+            let points: Vec<Point2D> = cs.iter().map(|c| Point2D::new(c.x, c.y)).collect();
+            let measures: Vec<f64> = cs.iter().map(|c| c.m.unwrap_or(0f64)).collect();
+            match dimension {
+                ShapeTypeDimension::XY => sfg.add_part(&points),
+                ShapeTypeDimension::Measure => sfg.add_partm(&points, &measures),
+                ShapeTypeDimension::Z => {
+                    let z: Vec<f64> = cs.iter().map(|c| c.z.unwrap_or(0f64)).collect();
+                    sfg.add_partz(&points, &measures, &z)
+                }
+            }
+        };
+        let add_polygon = |sfg: &mut ShapefileGeometry, rings: &[Vec<Coordinate>]| {
+            for (i, ring) in rings.iter().enumerate() {
+                let area = signed_area(ring);
+                // exterior rings are clockwise (negative area), holes counter-clockwise
+                if (i == 0 && area > 0f64) || (i > 0 && area < 0f64) {
+                    let reversed: Vec<Coordinate> = ring.iter().rev().copied().collect();
+                    add_part(sfg, &reversed);
+                } else {
+                    add_part(sfg, ring);
+                }
+            }
+        };
+        match (shape_type.base_shape_type(), self) {
+            (ShapeType::Point, FeatureGeometry::Point(c)) => add_point(&mut sfg, c),
+            (ShapeType::MultiPoint, FeatureGeometry::Point(c)) => add_point(&mut sfg, c),
+            (ShapeType::MultiPoint, FeatureGeometry::MultiPoint(cs)) => {
+                for c in cs {
+                    add_point(&mut sfg, c);
+                }
+            }
+            (ShapeType::PolyLine, FeatureGeometry::LineString(cs)) => add_part(&mut sfg, cs),
+            (ShapeType::PolyLine, FeatureGeometry::MultiLineString(lines)) => {
+                for cs in lines {
+                    add_part(&mut sfg, cs);
+                }
+            }
+            (ShapeType::Polygon, FeatureGeometry::Polygon(rings)) => add_polygon(&mut sfg, rings),
+            (ShapeType::Polygon, FeatureGeometry::MultiPolygon(polygons)) => {
+                for rings in polygons {
+                    add_polygon(&mut sfg, rings);
+                }
+            }
+            _ => return ShapefileGeometry::new(ShapeType::Null),
+        }
+        sfg
+    }
+}
+
+/// Twice the signed area of a ring; positive for counter-clockwise rings.
+pub(crate) fn signed_area(ring: &[Coordinate]) -> f64 {
+    let mut area = 0f64;
+    for i in 0..ring.len() {
+        let j = (i + 1) % ring.len();
+        area += ring[i].x * ring[j].y - ring[j].x * ring[i].y;
+    }
+    area
+}
+
+/// A typed attribute value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttributeValue {
+    Null,
+    Integer(i64),
+    Real(f64),
+    Text(String),
+    Date(DateData),
+    Bool(bool),
+}
+
+impl From<&FieldData> for AttributeValue {
+    fn from(value: &FieldData) -> AttributeValue {
+        match value {
+            FieldData::Int(v) => AttributeValue::Integer(*v as i64),
+            FieldData::Real(v) => AttributeValue::Real(*v),
+            FieldData::Text(v) => AttributeValue::Text(v.clone()),
+            FieldData::Date(v) => AttributeValue::Date(*v),
+            FieldData::Bool(v) => AttributeValue::Bool(*v),
+            FieldData::Null => AttributeValue::Null,
+        }
+    }
+}
+
+impl From<&AttributeValue> for FieldData {
+    /// Integers that are outside of the range of a Shapefile integer are stored as reals.
+    fn from(value: &AttributeValue) -> FieldData {
+        match value {
+            AttributeValue::Integer(v) => {
+                if *v >= i32::MIN as i64 && *v <= i32::MAX as i64 {
+                    FieldData::Int(*v as i32)
+                } else {
+                    FieldData::Real(*v as f64)
+                }
+            }
+            AttributeValue::Real(v) => FieldData::Real(*v),
+            AttributeValue::Text(v) => FieldData::Text(v.clone()),
+            AttributeValue::Date(v) => FieldData::Date(*v),
+            AttributeValue::Bool(v) => FieldData::Bool(*v),
+            AttributeValue::Null => FieldData::Null,
+        }
+    }
+}
+
+/// The description of an attribute field. A width of zero indicates that the width (and
+/// precision) have not been specified, e.g. when read from a format that does not store them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeatureField {
+    pub name: String,
+    pub field_type: FieldDataType,
+    pub width: u8,
+    pub precision: u8,
+}
+
+impl FeatureField {
+    /// FeatureField constructor method.
+    pub fn new(name: &str, field_type: FieldDataType, width: u8, precision: u8) -> FeatureField {
+        FeatureField {
+            name: name.to_string(),
+            field_type,
+            width,
+            precision,
+        }
+    }
+}
+
+impl From<&AttributeField> for FeatureField {
+    fn from(field: &AttributeField) -> FeatureField {
+        let field_type = match field.field_type {
+            'N' | 'F' | 'I' | 'O' => {
+                if field.decimal_count == 0 {
+                    FieldDataType::Int
+                } else {
+                    FieldDataType::Real
+                }
+            }
+            'D' => FieldDataType::Date,
+            'L' => FieldDataType::Bool,
+            _ => FieldDataType::Text,
+        };
+        FeatureField {
+            name: field.name.clone(),
+            field_type,
+            width: field.field_length,
+            precision: field.decimal_count,
+        }
+    }
+}
+
+impl From<&FeatureField> for AttributeField {
+    fn from(field: &FeatureField) -> AttributeField {
+        let (width, precision) = match field.field_type {
+            FieldDataType::Int => (field.width.max(1), 0u8),
+            // a zero decimal count would cause the field to be read back as an integer
+            FieldDataType::Real => (field.width.max(3), field.precision.max(1)),
+            FieldDataType::Text => (field.width.max(1), 0u8),
+            FieldDataType::Date => (8u8, 0u8),
+            FieldDataType::Bool => (1u8, 0u8),
+        };
+        AttributeField::new(&field.name, field.field_type.clone(), width, precision)
+    }
+}
+
+/// A single feature, i.e. an optional geometry and one attribute value per layer field.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Feature {
+    pub geometry: Option<FeatureGeometry>,
+    pub attributes: Vec<AttributeValue>,
+}
+
+/// `FeatureLayer` is an in-memory vector layer that is independent of any file format.
+///
+/// Examples:
+///
+/// ```no_run
+/// # use whitebox_vector::FeatureLayer;
+/// # fn main() -> Result<(), std::io::Error> {
+/// // Read a vector file of any supported format.
+/// let layer = FeatureLayer::read("streams.geojson")?;
+///
+/// // Write it to another format.
+/// layer.write("streams.fgb")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FeatureLayer {
+    pub name: String,
+    /// The coordinate reference system of the layer, as well-known text (WKT).
+    pub projection: String,
+    pub fields: Vec<FeatureField>,
+    pub features: Vec<Feature>,
+}
+
+impl FeatureLayer {
+    /// Creates a new, empty layer.
+    pub fn new(name: &str) -> FeatureLayer {
+        FeatureLayer {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Reads a vector file, using the driver associated with the file extension.
+    pub fn read(file_name: &str) -> Result<FeatureLayer, Error> {
+        match get_vector_type_from_file(file_name) {
+            VectorType::GeoJson => geojson::read_geojson(file_name),
+            VectorType::FlatGeobuf => flatgeobuf::read_flatgeobuf(file_name),
+            VectorType::GeoPackage => geopackage::read_geopackage(file_name),
+            _ => Ok(Shapefile::read(file_name)?.to_feature_layer()),
+        }
+    }
+
+    /// Writes the layer to a vector file, using the driver associated with the file extension.
+    pub fn write(&self, file_name: &str) -> Result<(), Error> {
+        match get_vector_type_from_file(file_name) {
+            VectorType::GeoJson => geojson::write_geojson(self, file_name),
+            VectorType::FlatGeobuf => flatgeobuf::write_flatgeobuf(self, file_name),
+            VectorType::GeoPackage => geopackage::write_geopackage(self, file_name),
+            _ => Shapefile::from_feature_layer(file_name, self)?.write(),
+        }
+    }
+
+    /// Adds a field to the layer; existing features receive a null value for the field.
+    pub fn add_field(&mut self, field: FeatureField) {
+        self.fields.push(field);
+        for feature in &mut self.features {
+            feature.attributes.push(AttributeValue::Null);
+        }
+    }
+
+    /// Adds a feature to the layer. The attribute values are padded with nulls, or
+    /// truncated, to match the number of fields.
+    pub fn add_feature(
+        &mut self,
+        geometry: Option<FeatureGeometry>,
+        attributes: Vec<AttributeValue>,
+    ) {
+        let mut attributes = attributes;
+        attributes.resize(self.fields.len(), AttributeValue::Null);
+        self.features.push(Feature {
+            geometry,
+            attributes,
+        });
+    }
+
+    /// Returns the index of the field with the specified name.
+    pub fn get_field_num(&self, name: &str) -> Option<usize> {
+        self.fields.iter().position(|f| f.name == name)
+    }
+
+    /// Returns true if any feature has elevation values.
+    pub fn has_z(&self) -> bool {
+        self.features
+            .iter()
+            .any(|f| f.geometry.as_ref().is_some_and(|g| g.has_z()))
+    }
+
+    /// Returns true if any feature has measure values.
+    pub fn has_m(&self) -> bool {
+        self.features
+            .iter()
+            .any(|f| f.geometry.as_ref().is_some_and(|g| g.has_m()))
+    }
+
+    /// Returns the bounding box of all of the layer's geometries, or None if there are none.
+    pub fn get_extent(&self) -> Option<BoundingBox> {
+        let mut extent: Option<BoundingBox> = None;
+        for g in self.features.iter().filter_map(|f| f.geometry.as_ref()) {
+            let bb = g.get_bounding_box();
+            extent = Some(match extent {
+                Some(mut e) => {
+                    e.expand_to(bb);
+                    e
+                }
+                None => bb,
+            });
+        }
+        extent
+    }
+
+    /// Returns the Shapefile ShapeType able to hold every geometry in the layer. Single and
+    /// multi-part geometries of the same kind share a ShapeType; a layer that mixes points,
+    /// lines, and polygons cannot be represented as a Shapefile.
+    pub fn shape_type(&self) -> Result<ShapeType, Error> {
+        let mut base = ShapeType::Null;
+        for g in self.features.iter().filter_map(|f| f.geometry.as_ref()) {
+            let t = match g {
+                FeatureGeometry::Point(_) => ShapeType::Point,
+                FeatureGeometry::MultiPoint(_) => ShapeType::MultiPoint,
+                FeatureGeometry::LineString(_) | FeatureGeometry::MultiLineString(_) => {
+                    ShapeType::PolyLine
+                }
+                FeatureGeometry::Polygon(_) | FeatureGeometry::MultiPolygon(_) => {
+                    ShapeType::Polygon
+                }
+            };
+            base = match (base, t) {
+                (ShapeType::Null, t) => t,
+                (b, t) if b == t => b,
+                (ShapeType::Point, ShapeType::MultiPoint)
+                | (ShapeType::MultiPoint, ShapeType::Point) => ShapeType::MultiPoint,
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "The layer contains a mixture of geometry types, which cannot be stored in a Shapefile.",
+                    ))
+                }
+            };
+        }
+        let (has_z, has_m) = (self.has_z(), self.has_m());
+        Ok(match base {
+            ShapeType::Point if has_z => ShapeType::PointZ,
+            ShapeType::Point if has_m => ShapeType::PointM,
+            ShapeType::MultiPoint if has_z => ShapeType::MultiPointZ,
+            ShapeType::MultiPoint if has_m => ShapeType::MultiPointM,
+            ShapeType::PolyLine if has_z => ShapeType::PolyLineZ,
+            ShapeType::PolyLine if has_m => ShapeType::PolyLineM,
+            ShapeType::Polygon if has_z => ShapeType::PolygonZ,
+            ShapeType::Polygon if has_m => ShapeType::PolygonM,
+            t => t,
+        })
+    }
+
+    /// Sets the width and precision of any field with an unspecified (zero) width from
+    /// the values that it holds.
+    pub fn fit_field_widths(&mut self) {
+        for (i, field) in self.fields.iter_mut().enumerate() {
+            if field.width > 0 {
+                continue;
+            }
+            let values = self.features.iter().map(|f| &f.attributes[i]);
+            match field.field_type {
+                FieldDataType::Int => {
+                    let w = values
+                        .map(|v| match v {
+                            AttributeValue::Integer(v) => v.to_string().len(),
+                            _ => 1,
+                        })
+                        .max()
+                        .unwrap_or(1);
+                    field.width = w.clamp(1, 20) as u8;
+                    field.precision = 0;
+                }
+                FieldDataType::Real => {
+                    let (mut int_digits, mut decimals) = (1usize, 1usize);
+                    for v in values {
+                        let s = match v {
+                            AttributeValue::Real(v) if v.is_finite() => v.to_string(),
+                            AttributeValue::Integer(v) => v.to_string(),
+                            _ => continue,
+                        };
+                        let mut split = s.split('.');
+                        int_digits = int_digits.max(split.next().unwrap_or("").len());
+                        decimals = decimals.max(split.next().unwrap_or("").len());
+                    }
+                    let decimals = decimals.min(15);
+                    field.width = (int_digits + 1 + decimals).min(255) as u8;
+                    field.precision = decimals as u8;
+                }
+                FieldDataType::Text => {
+                    let w = values
+                        .map(|v| match v {
+                            AttributeValue::Text(s) => s.len(),
+                            AttributeValue::Null => 1,
+                            v => format!("{:?}", v).len(),
+                        })
+                        .max()
+                        .unwrap_or(1);
+                    field.width = w.clamp(1, 254) as u8;
+                }
+                FieldDataType::Date => field.width = 8,
+                FieldDataType::Bool => field.width = 1,
+            }
+        }
+    }
+}
+
+impl Shapefile {
+    /// Converts the Shapefile into a format-neutral FeatureLayer.
+    pub fn to_feature_layer(&self) -> FeatureLayer {
+        let mut layer = FeatureLayer::new(&self.get_short_filename());
+        layer.projection = self.projection.clone();
+        layer.fields = self
+            .attributes
+            .get_fields()
+            .iter()
+            .map(FeatureField::from)
+            .collect();
+        for i in 0..self.records.len() {
+            let attributes = if i < self.attributes.header.num_records as usize {
+                self.attributes
+                    .get_record(i)
+                    .iter()
+                    .map(AttributeValue::from)
+                    .collect()
+            } else {
+                vec![]
+            };
+            layer.add_feature(
+                FeatureGeometry::from_shapefile_geometry(&self.records[i]),
+                attributes,
+            );
+        }
+        layer
+    }
+
+    /// Creates a Shapefile, in write mode, holding the contents of a FeatureLayer. Features
+    /// without a geometry become Null records.
+    pub fn from_feature_layer(file_name: &str, layer: &FeatureLayer) -> Result<Shapefile, Error> {
+        let shape_type = layer.shape_type()?;
+        let mut layer = layer.clone();
+        layer.fit_field_widths();
+        let mut sf = Shapefile::new(file_name, shape_type)?;
+        sf.projection = layer.projection.clone();
+        for field in &layer.fields {
+            sf.attributes.add_field(&AttributeField::from(field));
+        }
+        for feature in &layer.features {
+            let record = match &feature.geometry {
+                Some(g) => g.to_shapefile_geometry(shape_type),
+                None => ShapefileGeometry::new(ShapeType::Null),
+            };
+            sf.records.push(record);
+            sf.num_records += 1;
+            sf.attributes.add_record(
+                feature.attributes.iter().map(FieldData::from).collect(),
+                false,
+            );
+        }
+        sf.calculate_extent();
+        Ok(sf)
+    }
+}
+
+/// Returns the file stem of a file name, used as the default layer name.
+pub(crate) fn layer_name_from_file(file_name: &str) -> String {
+    Path::new(file_name)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("")
+        .to_string()
+}
+
+/// Returns the ESRI WKT of a coordinate reference system with the specified EPSG code, or an
+/// empty string if the code is unrecognized.
+pub(crate) fn wkt_from_epsg(code: u16) -> String {
+    let wkt = whitebox_common::spatial_ref_system::esri_wkt_from_epsg(code);
+    if wkt.starts_with("Unknown") {
+        String::new()
+    } else {
+        wkt
+    }
+}
+
+/// Parses a date in the 'YYYY-MM-DD' form (or the date part of an ISO 8601 date-time).
+pub(crate) fn parse_date(s: &str) -> Option<DateData> {
+    let s = s.get(0..10)?;
+    let mut parts = s.split('-');
+    let year = parts.next()?;
+    let month = parts.next()?;
+    let day = parts.next()?;
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return None;
+    }
+    let date = DateData {
+        year: year.parse().ok()?,
+        month: month.parse().ok()?,
+        day: day.parse().ok()?,
+    };
+    if date.month < 1 || date.month > 12 || date.day < 1 || date.day > 31 {
+        return None;
+    }
+    Some(date)
+}
+
+/// Formats a date in the 'YYYY-MM-DD' form.
+pub(crate) fn format_date(date: &DateData) -> String {
+    format!("{:04}-{:02}-{:02}", date.year, date.month, date.day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn c(x: f64, y: f64) -> Coordinate {
+        Coordinate::new(x, y)
+    }
+
+    fn square(x: f64, y: f64, size: f64) -> Vec<Coordinate> {
+        // counter-clockwise
+        vec![
+            c(x, y),
+            c(x + size, y),
+            c(x + size, y + size),
+            c(x, y + size),
+            c(x, y),
+        ]
+    }
+
+    fn extent(g: &FeatureGeometry) -> (f64, f64, f64, f64) {
+        let bb = g.get_bounding_box();
+        (bb.min_x, bb.max_x, bb.min_y, bb.max_y)
+    }
+
+    fn sample_layer() -> FeatureLayer {
+        let mut layer = FeatureLayer::new("sample");
+        layer.projection = wkt_from_epsg(26917);
+        layer.add_field(FeatureField::new("FID", FieldDataType::Int, 0, 0));
+        layer.add_field(FeatureField::new("NAME", FieldDataType::Text, 0, 0));
+        layer.add_field(FeatureField::new("AREA", FieldDataType::Real, 0, 0));
+        layer.add_field(FeatureField::new("SURVEYED", FieldDataType::Date, 0, 0));
+        layer.add_field(FeatureField::new("ACTIVE", FieldDataType::Bool, 0, 0));
+        let mut hole = square(2.0, 2.0, 2.0);
+        hole.reverse();
+        layer.add_feature(
+            Some(FeatureGeometry::Polygon(vec![square(0.0, 0.0, 10.0), hole])),
+            vec![
+                AttributeValue::Integer(1),
+                AttributeValue::Text("Lake \"One\"".to_string()),
+                AttributeValue::Real(96.5),
+                AttributeValue::Date(DateData {
+                    year: 2020,
+                    month: 7,
+                    day: 4,
+                }),
+                AttributeValue::Bool(true),
+            ],
+        );
+        layer.add_feature(
+            Some(FeatureGeometry::MultiPolygon(vec![
+                vec![square(20.0, 0.0, 5.0)],
+                vec![square(30.0, 0.0, 5.0)],
+            ])),
+            vec![
+                AttributeValue::Integer(2),
+                AttributeValue::Null,
+                AttributeValue::Real(50.25),
+                AttributeValue::Null,
+                AttributeValue::Bool(false),
+            ],
+        );
+        layer
+    }
+
+    #[test]
+    fn test_shapefile_geometry_round_trip() {
+        let layer = sample_layer();
+        let shape_type = layer.shape_type().unwrap();
+        assert!(shape_type == ShapeType::Polygon);
+        for feature in &layer.features {
+            let g = feature.geometry.as_ref().unwrap();
+            let sfg = g.to_shapefile_geometry(shape_type);
+            // exterior rings are clockwise in Shapefiles
+            assert!(!sfg.is_hole(0));
+            let back = FeatureGeometry::from_shapefile_geometry(&sfg).unwrap();
+            assert_eq!(back.type_name(), g.type_name());
+            assert_eq!(extent(&back), extent(g));
+        }
+        let sfg = layer.features[0]
+            .geometry
+            .as_ref()
+            .unwrap()
+            .to_shapefile_geometry(shape_type);
+        assert_eq!(sfg.num_parts, 2);
+        assert!(sfg.is_hole(1));
+    }
+
+    #[test]
+    fn test_layer_file_round_trip() {
+        let dir = std::env::temp_dir();
+        let layer = sample_layer();
+        for ext in &["shp", "geojson", "fgb", "gpkg"] {
+            let file_name = dir
+                .join(format!("wbt_feature_layer_test.{}", ext))
+                .to_str()
+                .unwrap()
+                .to_string();
+            layer.write(&file_name).unwrap();
+            let back = FeatureLayer::read(&file_name).unwrap();
+            assert_eq!(back.features.len(), layer.features.len(), "{}", ext);
+            assert_eq!(epsg_from_wkt(&back.projection), Some(26917), "{}", ext);
+            let names: Vec<&str> = back.fields.iter().map(|f| f.name.as_str()).collect();
+            assert_eq!(
+                names,
+                vec!["FID", "NAME", "AREA", "SURVEYED", "ACTIVE"],
+                "{}",
+                ext
+            );
+            for (a, b) in layer.features.iter().zip(back.features.iter()) {
+                assert_eq!(a.attributes, b.attributes, "{}", ext);
+                let (ga, gb) = (a.geometry.as_ref().unwrap(), b.geometry.as_ref().unwrap());
+                if *ext == "fgb" {
+                    // a layer of polygons and multipolygons is stored as multipolygons
+                    assert_eq!(gb.type_name(), "MultiPolygon");
+                } else {
+                    assert_eq!(ga.type_name(), gb.type_name(), "{}", ext);
+                }
+                assert_eq!(extent(ga), extent(gb), "{}", ext);
+            }
+
+            // and the same file read through the Shapefile interface
+            let sf = Shapefile::read(&file_name).unwrap();
+            assert!(sf.header.shape_type == ShapeType::Polygon);
+            assert_eq!(sf.num_records, 2);
+            assert_eq!(sf.attributes.get_value(1, "AREA"), FieldData::Real(50.25));
+        }
+    }
+
+    #[test]
+    fn test_points_with_z() {
+        let dir = std::env::temp_dir();
+        let mut layer = FeatureLayer::new("points");
+        layer.add_field(FeatureField::new("ID", FieldDataType::Int, 0, 0));
+        for i in 0..10 {
+            layer.add_feature(
+                Some(FeatureGeometry::Point(Coordinate::new_z(
+                    i as f64,
+                    -(i as f64),
+                    100.0 + i as f64,
+                ))),
+                vec![AttributeValue::Integer(i)],
+            );
+        }
+        layer.add_feature(None, vec![AttributeValue::Integer(10)]);
+        assert!(layer.shape_type().unwrap() == ShapeType::PointZ);
+        for ext in &["geojson", "fgb"] {
+            let file_name = dir
+                .join(format!("wbt_feature_layer_points.{}", ext))
+                .to_str()
+                .unwrap()
+                .to_string();
+            layer.write(&file_name).unwrap();
+            let back = FeatureLayer::read(&file_name).unwrap();
+            assert_eq!(back.features, layer.features, "{}", ext);
+        }
+
+        // PointZ Shapefiles always carry measures, and features without a geometry are Null records
+        let file_name = dir.join("wbt_feature_layer_points.shp");
+        let file_name = file_name.to_str().unwrap();
+        layer.write(file_name).unwrap();
+        let back = FeatureLayer::read(file_name).unwrap();
+        assert_eq!(back.features.len(), 11);
+        assert_eq!(
+            back.features[3].geometry,
+            Some(FeatureGeometry::Point(Coordinate {
+                x: 3.0,
+                y: -3.0,
+                z: Some(103.0),
+                m: Some(0.0)
+            }))
+        );
+        assert_eq!(back.features[10].geometry, None);
+        assert_eq!(
+            back.features[10].attributes,
+            vec![AttributeValue::Integer(10)]
+        );
+    }
+}
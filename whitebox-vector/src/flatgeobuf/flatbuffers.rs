@@ -0,0 +1,420 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+
+Notes: A minimal reader and writer for the FlatBuffers binary format, sufficient for the
+FlatGeobuf header and feature tables. The writer lays out each buffer front-to-back, with
+every table preceded by its vtable and followed by its children, so that all offsets point
+forward; offsets to children are written as placeholders and patched once the child is
+written. All buffers are size-prefixed, as FlatGeobuf requires, and alignment is relative
+to the start of the size prefix.
+*/
+
+use std::io::{Error, ErrorKind};
+
+/// A scalar table field value.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Scalar {
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    I32(i32),
+    U64(u64),
+}
+
+impl Scalar {
+    fn size(&self) -> usize {
+        match self {
+            Scalar::Bool(_) | Scalar::U8(_) => 1,
+            Scalar::U16(_) => 2,
+            Scalar::I32(_) => 4,
+            Scalar::U64(_) => 8,
+        }
+    }
+
+    fn to_le_bytes(self) -> Vec<u8> {
+        match self {
+            Scalar::Bool(v) => vec![v as u8],
+            Scalar::U8(v) => vec![v],
+            Scalar::U16(v) => v.to_le_bytes().to_vec(),
+            Scalar::I32(v) => v.to_le_bytes().to_vec(),
+            Scalar::U64(v) => v.to_le_bytes().to_vec(),
+        }
+    }
+}
+
+/// A table field, identified by its slot (i.e. its index in the schema).
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Field {
+    Scalar(u16, Scalar),
+    /// An offset to a string, vector, or table, which is patched using `FbBuilder::set_offset`.
+    Offset(u16),
+}
+
+pub(crate) struct FbBuilder {
+    buf: Vec<u8>,
+}
+
+impl FbBuilder {
+    /// Starts a new size-prefixed buffer.
+    pub fn new() -> FbBuilder {
+        // the size prefix, followed by the offset to the root table
+        FbBuilder { buf: vec![0u8; 8] }
+    }
+
+    /// Completes the buffer, with the table at `root` as its root table.
+    pub fn finish(mut self, root: usize) -> Vec<u8> {
+        self.set_offset(4, root);
+        let size = (self.buf.len() - 4) as u32;
+        self.buf[0..4].copy_from_slice(&size.to_le_bytes());
+        self.buf
+    }
+
+    /// Sets the offset stored at `pos` to point to the object at `target`.
+    pub fn set_offset(&mut self, pos: usize, target: usize) {
+        let offset = (target - pos) as u32;
+        self.buf[pos..pos + 4].copy_from_slice(&offset.to_le_bytes());
+    }
+
+    fn pad_to(&mut self, alignment: usize, extra: usize) {
+        while !(self.buf.len() + extra).is_multiple_of(alignment) {
+            self.buf.push(0u8);
+        }
+    }
+
+    /// Writes a table, returning its position and the positions of its offset fields, in the
+    /// order in which they were specified.
+    pub fn table(&mut self, fields: &[Field]) -> (usize, Vec<usize>) {
+        // order the inline fields by decreasing size, to minimize padding
+        let mut inline: Vec<(u16, usize, Option<Scalar>)> = fields
+            .iter()
+            .map(|f| match f {
+                Field::Scalar(slot, v) => (*slot, v.size(), Some(*v)),
+                Field::Offset(slot) => (*slot, 4usize, None),
+            })
+            .collect();
+        inline.sort_by_key(|f| std::cmp::Reverse(f.1));
+        let alignment = inline.iter().map(|f| f.1).max().unwrap_or(4).max(4);
+        let mut field_offsets = vec![0usize; inline.len()];
+        let mut table_size = 4usize; // the soffset to the vtable
+        for (i, f) in inline.iter().enumerate() {
+            while !table_size.is_multiple_of(f.1) {
+                table_size += 1;
+            }
+            field_offsets[i] = table_size;
+            table_size += f.1;
+        }
+
+        // the vtable
+        let num_slots = fields
+            .iter()
+            .map(|f| match f {
+                Field::Scalar(slot, _) | Field::Offset(slot) => *slot as usize + 1,
+            })
+            .max()
+            .unwrap_or(0);
+        let mut vtable = vec![0u16; 2 + num_slots];
+        vtable[0] = (4 + 2 * num_slots) as u16;
+        vtable[1] = table_size as u16;
+        for (i, f) in inline.iter().enumerate() {
+            vtable[2 + f.0 as usize] = field_offsets[i] as u16;
+        }
+        // the vtable is placed so that the table which follows it is aligned
+        let vtable_size = 2 * vtable.len();
+        self.pad_to(alignment, vtable_size);
+        let vtable_pos = self.buf.len();
+        for v in vtable {
+            self.buf.extend_from_slice(&v.to_le_bytes());
+        }
+
+        // the table
+        let table_pos = self.buf.len();
+        self.buf.resize(table_pos + table_size, 0u8);
+        let soffset = (table_pos - vtable_pos) as i32;
+        self.buf[table_pos..table_pos + 4].copy_from_slice(&soffset.to_le_bytes());
+        let mut offset_positions = vec![];
+        for f in fields {
+            let slot = match f {
+                Field::Scalar(slot, _) | Field::Offset(slot) => *slot,
+            };
+            let i = inline.iter().position(|g| g.0 == slot).unwrap();
+            let pos = table_pos + field_offsets[i];
+            match inline[i].2 {
+                Some(v) => {
+                    let bytes = v.to_le_bytes();
+                    self.buf[pos..pos + bytes.len()].copy_from_slice(&bytes);
+                }
+                None => offset_positions.push(pos),
+            }
+        }
+        (table_pos, offset_positions)
+    }
+
+    /// Writes a string, returning its position.
+    pub fn string(&mut self, s: &str) -> usize {
+        self.pad_to(4, 0);
+        let pos = self.buf.len();
+        self.buf.extend_from_slice(&(s.len() as u32).to_le_bytes());
+        self.buf.extend_from_slice(s.as_bytes());
+        self.buf.push(0u8);
+        pos
+    }
+
+    fn vector(&mut self, element_size: usize, count: usize, bytes: &[u8]) -> usize {
+        self.pad_to(4, 0);
+        self.pad_to(element_size.max(4), 4);
+        let pos = self.buf.len();
+        self.buf.extend_from_slice(&(count as u32).to_le_bytes());
+        self.buf.extend_from_slice(bytes);
+        pos
+    }
+
+    /// Writes a vector of bytes, returning its position.
+    pub fn vector_u8(&mut self, values: &[u8]) -> usize {
+        self.vector(1, values.len(), values)
+    }
+
+    /// Writes a vector of u32 values, returning its position.
+    pub fn vector_u32(&mut self, values: &[u32]) -> usize {
+        let bytes: Vec<u8> = values
+            .iter()
+            .flat_map(|v| v.to_le_bytes().to_vec())
+            .collect();
+        self.vector(4, values.len(), &bytes)
+    }
+
+    /// Writes a vector of f64 values, returning its position.
+    pub fn vector_f64(&mut self, values: &[f64]) -> usize {
+        let bytes: Vec<u8> = values
+            .iter()
+            .flat_map(|v| v.to_le_bytes().to_vec())
+            .collect();
+        self.vector(8, values.len(), &bytes)
+    }
+
+    /// Writes a vector of offsets, returning its position and the positions of its elements,
+    /// each of which must be patched using `set_offset`.
+    pub fn vector_offsets(&mut self, count: usize) -> (usize, Vec<usize>) {
+        let pos = self.vector(4, count, &vec![0u8; 4 * count]);
+        (pos, (0..count).map(|i| pos + 4 + 4 * i).collect())
+    }
+}
+
+fn invalid_data() -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        "The FlatBuffers data are corrupt or truncated.",
+    )
+}
+
+fn read_bytes(buf: &[u8], pos: usize, n: usize) -> Result<&[u8], Error> {
+    buf.get(pos..pos.checked_add(n).ok_or_else(invalid_data)?)
+        .ok_or_else(invalid_data)
+}
+
+fn read_u16(buf: &[u8], pos: usize) -> Result<u16, Error> {
+    let b = read_bytes(buf, pos, 2)?;
+    Ok(u16::from_le_bytes([b[0], b[1]]))
+}
+
+fn read_u32(buf: &[u8], pos: usize) -> Result<u32, Error> {
+    let b = read_bytes(buf, pos, 4)?;
+    Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+/// A table within a FlatBuffers buffer.
+#[derive(Clone, Copy)]
+pub(crate) struct FbTable<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> FbTable<'a> {
+    /// Returns the root table of a size-prefixed buffer.
+    pub fn root(buf: &'a [u8]) -> Result<FbTable<'a>, Error> {
+        let offset = read_u32(buf, 4)? as usize;
+        FbTable::at(buf, 4 + offset)
+    }
+
+    fn at(buf: &'a [u8], pos: usize) -> Result<FbTable<'a>, Error> {
+        read_bytes(buf, pos, 4)?;
+        Ok(FbTable { buf, pos })
+    }
+
+    /// Returns the position of a field, or None if the field is absent.
+    fn field_pos(&self, slot: u16) -> Result<Option<usize>, Error> {
+        let soffset = read_u32(self.buf, self.pos)? as i32 as i64;
+        let vtable = self.pos as i64 - soffset;
+        if vtable < 0 {
+            return Err(invalid_data());
+        }
+        let vtable = vtable as usize;
+        let vtable_size = read_u16(self.buf, vtable)? as usize;
+        let entry = 4 + 2 * slot as usize;
+        if entry + 2 > vtable_size {
+            return Ok(None);
+        }
+        match read_u16(self.buf, vtable + entry)? {
+            0 => Ok(None),
+            offset => Ok(Some(self.pos + offset as usize)),
+        }
+    }
+
+    fn scalar_bytes(&self, slot: u16, n: usize) -> Result<Option<&'a [u8]>, Error> {
+        match self.field_pos(slot)? {
+            Some(pos) => Ok(Some(read_bytes(self.buf, pos, n)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn get_u8(&self, slot: u16, default: u8) -> Result<u8, Error> {
+        Ok(self.scalar_bytes(slot, 1)?.map_or(default, |b| b[0]))
+    }
+
+    pub fn get_u16(&self, slot: u16, default: u16) -> Result<u16, Error> {
+        Ok(self
+            .scalar_bytes(slot, 2)?
+            .map_or(default, |b| u16::from_le_bytes([b[0], b[1]])))
+    }
+
+    pub fn get_i32(&self, slot: u16, default: i32) -> Result<i32, Error> {
+        Ok(self
+            .scalar_bytes(slot, 4)?
+            .map_or(default, |b| i32::from_le_bytes([b[0], b[1], b[2], b[3]])))
+    }
+
+    pub fn get_u64(&self, slot: u16, default: u64) -> Result<u64, Error> {
+        Ok(self.scalar_bytes(slot, 8)?.map_or(default, |b| {
+            let mut a = [0u8; 8];
+            a.copy_from_slice(b);
+            u64::from_le_bytes(a)
+        }))
+    }
+
+    /// Returns the position of the object referenced by an offset field.
+    fn target(&self, slot: u16) -> Result<Option<usize>, Error> {
+        match self.field_pos(slot)? {
+            Some(pos) => Ok(Some(pos + read_u32(self.buf, pos)? as usize)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the bytes of a vector field, along with the number of elements.
+    fn vector_bytes(&self, slot: u16, element_size: usize) -> Result<(&'a [u8], usize), Error> {
+        match self.target(slot)? {
+            Some(pos) => {
+                let count = read_u32(self.buf, pos)? as usize;
+                let n = count.checked_mul(element_size).ok_or_else(invalid_data)?;
+                Ok((read_bytes(self.buf, pos + 4, n)?, count))
+            }
+            None => Ok((&[], 0)),
+        }
+    }
+
+    pub fn get_string(&self, slot: u16) -> Result<Option<String>, Error> {
+        if self.field_pos(slot)?.is_none() {
+            return Ok(None);
+        }
+        let (bytes, _) = self.vector_bytes(slot, 1)?;
+        Ok(Some(String::from_utf8_lossy(bytes).to_string()))
+    }
+
+    pub fn get_table(&self, slot: u16) -> Result<Option<FbTable<'a>>, Error> {
+        match self.target(slot)? {
+            Some(pos) => Ok(Some(FbTable::at(self.buf, pos)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn get_vector_u8(&self, slot: u16) -> Result<&'a [u8], Error> {
+        Ok(self.vector_bytes(slot, 1)?.0)
+    }
+
+    pub fn get_vector_u32(&self, slot: u16) -> Result<Vec<u32>, Error> {
+        let (bytes, _) = self.vector_bytes(slot, 4)?;
+        Ok(bytes
+            .chunks_exact(4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect())
+    }
+
+    pub fn get_vector_f64(&self, slot: u16) -> Result<Vec<f64>, Error> {
+        let (bytes, _) = self.vector_bytes(slot, 8)?;
+        Ok(bytes
+            .chunks_exact(8)
+            .map(|b| {
+                let mut a = [0u8; 8];
+                a.copy_from_slice(b);
+                f64::from_le_bytes(a)
+            })
+            .collect())
+    }
+
+    pub fn get_vector_tables(&self, slot: u16) -> Result<Vec<FbTable<'a>>, Error> {
+        let start = match self.target(slot)? {
+            Some(pos) => pos + 4,
+            None => return Ok(vec![]),
+        };
+        let (_, count) = self.vector_bytes(slot, 4)?;
+        let mut tables = Vec::with_capacity(count);
+        for i in 0..count {
+            let pos = start + 4 * i;
+            tables.push(FbTable::at(
+                self.buf,
+                pos + read_u32(self.buf, pos)? as usize,
+            )?);
+        }
+        Ok(tables)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_table_round_trip() {
+        let mut b = FbBuilder::new();
+        let (root, offsets) = b.table(&[
+            Field::Offset(0),
+            Field::Scalar(2, Scalar::U8(6)),
+            Field::Scalar(8, Scalar::U64(1234567890123)),
+            Field::Offset(1),
+            Field::Offset(7),
+        ]);
+        let name = b.string("lakes");
+        b.set_offset(offsets[0], name);
+        let envelope = b.vector_f64(&[1.5, -2.5, 3.5, 4.5]);
+        b.set_offset(offsets[1], envelope);
+        let (columns, elements) = b.vector_offsets(2);
+        b.set_offset(offsets[2], columns);
+        for (i, pos) in elements.into_iter().enumerate() {
+            let (child, child_offsets) =
+                b.table(&[Field::Offset(0), Field::Scalar(4, Scalar::I32(-(i as i32)))]);
+            b.set_offset(pos, child);
+            let child_name = b.string(&format!("column{}", i));
+            b.set_offset(child_offsets[0], child_name);
+        }
+        let buf = b.finish(root);
+        assert_eq!(buf.len() % 4, 0);
+
+        let t = FbTable::root(&buf).unwrap();
+        assert_eq!(t.get_string(0).unwrap(), Some("lakes".to_string()));
+        assert_eq!(t.get_vector_f64(1).unwrap(), vec![1.5, -2.5, 3.5, 4.5]);
+        assert_eq!(t.get_u8(2, 0).unwrap(), 6);
+        assert_eq!(t.get_u64(8, 0).unwrap(), 1234567890123);
+        assert_eq!(t.get_u16(9, 16).unwrap(), 16);
+        let columns = t.get_vector_tables(7).unwrap();
+        assert_eq!(columns.len(), 2);
+        assert_eq!(
+            columns[1].get_string(0).unwrap(),
+            Some("column1".to_string())
+        );
+        assert_eq!(columns[1].get_i32(4, 0).unwrap(), -1);
+        assert_eq!(columns[0].get_i32(4, 7).unwrap(), 0);
+        assert_eq!(columns[0].get_i32(5, -1).unwrap(), -1);
+    }
+}
@@ -0,0 +1,676 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+
+Notes: Reads and writes FlatGeobuf (version 3) files. Files are written without the
+optional packed Hilbert R-tree index; the index of files that have one is skipped when
+reading. Layers that mix single and multi-part geometries of the same kind are written
+with the multi-part geometry type, e.g. Polygon and MultiPolygon features are written
+as MultiPolygons.
+*/
+
+mod flatbuffers;
+
+use self::flatbuffers::*;
+use crate::feature_layer::*;
+use crate::shapefile::attributes::FieldDataType;
+use std::fs;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{BufWriter, Error, ErrorKind};
+
+const MAGIC: [u8; 8] = [0x66, 0x67, 0x62, 0x03, 0x66, 0x67, 0x62, 0x00];
+
+// geometry types
+const GEOMETRY_UNKNOWN: u8 = 0;
+const GEOMETRY_POINT: u8 = 1;
+const GEOMETRY_LINESTRING: u8 = 2;
+const GEOMETRY_POLYGON: u8 = 3;
+const GEOMETRY_MULTIPOINT: u8 = 4;
+const GEOMETRY_MULTILINESTRING: u8 = 5;
+const GEOMETRY_MULTIPOLYGON: u8 = 6;
+
+// column types
+const COLUMN_BYTE: u8 = 0;
+const COLUMN_UBYTE: u8 = 1;
+const COLUMN_BOOL: u8 = 2;
+const COLUMN_SHORT: u8 = 3;
+const COLUMN_USHORT: u8 = 4;
+const COLUMN_INT: u8 = 5;
+const COLUMN_UINT: u8 = 6;
+const COLUMN_LONG: u8 = 7;
+const COLUMN_ULONG: u8 = 8;
+const COLUMN_FLOAT: u8 = 9;
+const COLUMN_DOUBLE: u8 = 10;
+const COLUMN_STRING: u8 = 11;
+const COLUMN_JSON: u8 = 12;
+const COLUMN_DATETIME: u8 = 13;
+
+struct Column {
+    name: String,
+    column_type: u8,
+    width: i32,
+    precision: i32,
+}
+
+fn invalid_data(msg: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, msg.to_string())
+}
+
+fn read_u32(data: &[u8], pos: usize) -> Result<u32, Error> {
+    match data.get(pos..pos + 4) {
+        Some(b) => Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]])),
+        None => Err(invalid_data("The FlatGeobuf file is truncated.")),
+    }
+}
+
+/// Reads a FlatGeobuf file.
+pub fn read_flatgeobuf(file_name: &str) -> Result<FeatureLayer, Error> {
+    let data = fs::read(file_name)?;
+    if data.len() < 12 || data[0..3] != MAGIC[0..3] || data[4..7] != MAGIC[4..7] {
+        return Err(invalid_data(&format!(
+            "{} is not a FlatGeobuf file.",
+            file_name
+        )));
+    }
+    if data[3] != MAGIC[3] {
+        return Err(invalid_data(&format!(
+            "Unsupported FlatGeobuf version ({}).",
+            data[3]
+        )));
+    }
+
+    // the header
+    let header_end = 12 + read_u32(&data, 8)? as usize;
+    let header = match data.get(8..header_end) {
+        Some(buf) => FbTable::root(buf)?,
+        None => return Err(invalid_data("The FlatGeobuf header is truncated.")),
+    };
+    let name = match header.get_string(0)? {
+        Some(name) if !name.is_empty() => name,
+        _ => layer_name_from_file(file_name),
+    };
+    let mut layer = FeatureLayer::new(&name);
+    let geometry_type = header.get_u8(2, GEOMETRY_UNKNOWN)?;
+    let columns = read_columns(&header.get_vector_tables(7)?)?;
+    let features_count = header.get_u64(8, 0)?;
+    let index_node_size = header.get_u16(9, 16)?;
+    if let Some(crs) = header.get_table(10)? {
+        let org = crs.get_string(0)?.unwrap_or_default();
+        let code = crs.get_i32(1, 0)?;
+        if (org.is_empty() || org.eq_ignore_ascii_case("EPSG"))
+            && code > 0
+            && code <= u16::MAX as i32
+        {
+            layer.projection = wkt_from_epsg(code as u16);
+        }
+        if layer.projection.is_empty() {
+            layer.projection = crs.get_string(4)?.unwrap_or_default();
+        }
+    }
+    for column in &columns {
+        let field_type = match column.column_type {
+            COLUMN_BOOL => FieldDataType::Bool,
+            COLUMN_FLOAT | COLUMN_DOUBLE => FieldDataType::Real,
+            COLUMN_DATETIME => FieldDataType::Date,
+            t if t <= COLUMN_ULONG => FieldDataType::Int,
+            _ => FieldDataType::Text,
+        };
+        let width = column.width.clamp(0, 255) as u8;
+        let precision = column.precision.clamp(0, 255) as u8;
+        layer.add_field(FeatureField::new(
+            &column.name,
+            field_type,
+            width,
+            precision,
+        ));
+    }
+
+    // skip the spatial index, if there is one
+    let mut pos = header_end;
+    if index_node_size > 0 && features_count > 0 {
+        pos += packed_rtree_size(features_count, index_node_size);
+    }
+
+    // the features
+    let field_nums: Vec<Option<usize>> = (0..columns.len()).map(Some).collect();
+    while pos < data.len() {
+        let end = pos + 4 + read_u32(&data, pos)? as usize;
+        let feature = match data.get(pos..end) {
+            Some(buf) => FbTable::root(buf)?,
+            None => return Err(invalid_data("A FlatGeobuf feature is truncated.")),
+        };
+        let geometry = match feature.get_table(0)? {
+            Some(g) => read_geometry(&g, geometry_type)?,
+            None => None,
+        };
+        // features may, rarely, have their own column schema
+        let feature_columns = read_columns(&feature.get_vector_tables(2)?)?;
+        let attributes = if feature_columns.is_empty() {
+            read_properties(
+                feature.get_vector_u8(1)?,
+                &columns,
+                &field_nums,
+                layer.fields.len(),
+            )?
+        } else {
+            let nums: Vec<Option<usize>> = feature_columns
+                .iter()
+                .map(|c| layer.get_field_num(&c.name))
+                .collect();
+            read_properties(
+                feature.get_vector_u8(1)?,
+                &feature_columns,
+                &nums,
+                layer.fields.len(),
+            )?
+        };
+        layer.add_feature(geometry, attributes);
+        pos = end;
+    }
+    layer.fit_field_widths();
+
+    Ok(layer)
+}
+
+/// The size, in bytes, of a packed Hilbert R-tree index.
+fn packed_rtree_size(num_items: u64, node_size: u16) -> usize {
+    let node_size = node_size.max(2) as u64;
+    let mut n = num_items;
+    let mut num_nodes = n;
+    loop {
+        n = n.div_ceil(node_size);
+        num_nodes += n;
+        if n == 1 {
+            break;
+        }
+    }
+    num_nodes as usize * 40
+}
+
+fn read_columns(tables: &[FbTable]) -> Result<Vec<Column>, Error> {
+    let mut columns = Vec::with_capacity(tables.len());
+    for t in tables {
+        columns.push(Column {
+            name: t.get_string(0)?.unwrap_or_default(),
+            column_type: t.get_u8(1, COLUMN_BYTE)?,
+            width: t.get_i32(4, -1)?,
+            precision: t.get_i32(5, -1)?,
+        });
+    }
+    Ok(columns)
+}
+
+fn read_coordinates(g: &FbTable) -> Result<Vec<Coordinate>, Error> {
+    let xy = g.get_vector_f64(1)?;
+    let z = g.get_vector_f64(2)?;
+    let m = g.get_vector_f64(3)?;
+    Ok((0..xy.len() / 2)
+        .map(|i| Coordinate {
+            x: xy[2 * i],
+            y: xy[2 * i + 1],
+            z: z.get(i).copied(),
+            m: m.get(i).copied(),
+        })
+        .collect())
+}
+
+/// Divides coordinates into parts, using the end index of each part.
+fn split_parts(coords: Vec<Coordinate>, ends: &[u32]) -> Vec<Vec<Coordinate>> {
+    if ends.is_empty() {
+        return vec![coords];
+    }
+    let mut parts = vec![];
+    let mut start = 0usize;
+    for &end in ends {
+        let end = (end as usize).min(coords.len());
+        if end > start {
+            parts.push(coords[start..end].to_vec());
+        }
+        start = end;
+    }
+    parts
+}
+
+fn read_geometry(g: &FbTable, geometry_type: u8) -> Result<Option<FeatureGeometry>, Error> {
+    let geometry_type = if geometry_type == GEOMETRY_UNKNOWN {
+        g.get_u8(6, GEOMETRY_UNKNOWN)?
+    } else {
+        geometry_type
+    };
+    if geometry_type == GEOMETRY_MULTIPOLYGON {
+        let parts = g.get_vector_tables(7)?;
+        if !parts.is_empty() {
+            let mut polygons = vec![];
+            for part in &parts {
+                let rings = split_parts(read_coordinates(part)?, &part.get_vector_u32(0)?);
+                polygons.push(rings);
+            }
+            return Ok(Some(FeatureGeometry::MultiPolygon(polygons)));
+        }
+    }
+    let coords = read_coordinates(g)?;
+    if coords.is_empty() {
+        return Ok(None);
+    }
+    let geometry = match geometry_type {
+        GEOMETRY_POINT => FeatureGeometry::Point(coords[0]),
+        GEOMETRY_LINESTRING => FeatureGeometry::LineString(coords),
+        GEOMETRY_MULTIPOINT => FeatureGeometry::MultiPoint(coords),
+        GEOMETRY_POLYGON => FeatureGeometry::Polygon(split_parts(coords, &g.get_vector_u32(0)?)),
+        GEOMETRY_MULTILINESTRING => {
+            FeatureGeometry::MultiLineString(split_parts(coords, &g.get_vector_u32(0)?))
+        }
+        GEOMETRY_MULTIPOLYGON => {
+            FeatureGeometry::MultiPolygon(vec![split_parts(coords, &g.get_vector_u32(0)?)])
+        }
+        t => {
+            return Err(invalid_data(&format!(
+                "Unsupported FlatGeobuf geometry type ({}).",
+                t
+            )))
+        }
+    };
+    Ok(Some(geometry))
+}
+
+fn read_properties(
+    bytes: &[u8],
+    columns: &[Column],
+    field_nums: &[Option<usize>],
+    num_fields: usize,
+) -> Result<Vec<AttributeValue>, Error> {
+    let mut attributes = vec![AttributeValue::Null; num_fields];
+    let take = |pos: usize, n: usize| -> Result<&[u8], Error> {
+        bytes
+            .get(pos..pos + n)
+            .ok_or_else(|| invalid_data("The properties of a FlatGeobuf feature are truncated."))
+    };
+    let mut pos = 0usize;
+    while pos + 2 <= bytes.len() {
+        let b = take(pos, 2)?;
+        let index = u16::from_le_bytes([b[0], b[1]]) as usize;
+        pos += 2;
+        let column = columns.get(index).ok_or_else(|| {
+            invalid_data("A FlatGeobuf property refers to a non-existent column.")
+        })?;
+        let fixed_size = match column.column_type {
+            COLUMN_BYTE | COLUMN_UBYTE | COLUMN_BOOL => 1,
+            COLUMN_SHORT | COLUMN_USHORT => 2,
+            COLUMN_INT | COLUMN_UINT | COLUMN_FLOAT => 4,
+            COLUMN_LONG | COLUMN_ULONG | COLUMN_DOUBLE => 8,
+            _ => 0,
+        };
+        let value = if fixed_size > 0 {
+            let b = take(pos, fixed_size)?;
+            pos += fixed_size;
+            let mut a = [0u8; 8];
+            a[..fixed_size].copy_from_slice(b);
+            match column.column_type {
+                COLUMN_BYTE => AttributeValue::Integer(b[0] as i8 as i64),
+                COLUMN_UBYTE => AttributeValue::Integer(b[0] as i64),
+                COLUMN_BOOL => AttributeValue::Bool(b[0] != 0),
+                COLUMN_SHORT => AttributeValue::Integer(i16::from_le_bytes([b[0], b[1]]) as i64),
+                COLUMN_USHORT => AttributeValue::Integer(u16::from_le_bytes([b[0], b[1]]) as i64),
+                COLUMN_INT => {
+                    AttributeValue::Integer(i32::from_le_bytes([b[0], b[1], b[2], b[3]]) as i64)
+                }
+                COLUMN_UINT => {
+                    AttributeValue::Integer(u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as i64)
+                }
+                COLUMN_LONG => AttributeValue::Integer(i64::from_le_bytes(a)),
+                COLUMN_ULONG => {
+                    let v = u64::from_le_bytes(a);
+                    if v <= i64::MAX as u64 {
+                        AttributeValue::Integer(v as i64)
+                    } else {
+                        AttributeValue::Real(v as f64)
+                    }
+                }
+                COLUMN_FLOAT => {
+                    AttributeValue::Real(f32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64)
+                }
+                _ => AttributeValue::Real(f64::from_le_bytes(a)),
+            }
+        } else {
+            let b = take(pos, 4)?;
+            let len = u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as usize;
+            let s = String::from_utf8_lossy(take(pos + 4, len)?).to_string();
+            pos += 4 + len;
+            match column.column_type {
+                COLUMN_STRING | COLUMN_JSON => AttributeValue::Text(s),
+                COLUMN_DATETIME => match parse_date(&s) {
+                    Some(d) => AttributeValue::Date(d),
+                    None => AttributeValue::Null,
+                },
+                _ => AttributeValue::Null, // binary
+            }
+        };
+        if let Some(Some(field_num)) = field_nums.get(index) {
+            attributes[*field_num] = value;
+        }
+    }
+    Ok(attributes)
+}
+
+/// The FlatGeobuf geometry type of a geometry.
+fn geometry_type_of(g: &FeatureGeometry) -> u8 {
+    match g {
+        FeatureGeometry::Point(_) => GEOMETRY_POINT,
+        FeatureGeometry::LineString(_) => GEOMETRY_LINESTRING,
+        FeatureGeometry::Polygon(_) => GEOMETRY_POLYGON,
+        FeatureGeometry::MultiPoint(_) => GEOMETRY_MULTIPOINT,
+        FeatureGeometry::MultiLineString(_) => GEOMETRY_MULTILINESTRING,
+        FeatureGeometry::MultiPolygon(_) => GEOMETRY_MULTIPOLYGON,
+    }
+}
+
+fn multi_geometry_type(t: u8) -> u8 {
+    match t {
+        GEOMETRY_POINT => GEOMETRY_MULTIPOINT,
+        GEOMETRY_LINESTRING => GEOMETRY_MULTILINESTRING,
+        GEOMETRY_POLYGON => GEOMETRY_MULTIPOLYGON,
+        t => t,
+    }
+}
+
+/// The geometry type of the layer; Unknown if the layer mixes kinds of geometry.
+fn layer_geometry_type(layer: &FeatureLayer) -> u8 {
+    let mut layer_type: Option<u8> = None;
+    for g in layer.features.iter().filter_map(|f| f.geometry.as_ref()) {
+        let t = geometry_type_of(g);
+        layer_type = match layer_type {
+            None => Some(t),
+            Some(a) if a == t => Some(a),
+            Some(a) if multi_geometry_type(a) == multi_geometry_type(t) => {
+                Some(multi_geometry_type(a))
+            }
+            _ => return GEOMETRY_UNKNOWN,
+        };
+    }
+    layer_type.unwrap_or(GEOMETRY_UNKNOWN)
+}
+
+/// The column type used to store a field.
+fn column_type(layer: &FeatureLayer, field_num: usize) -> u8 {
+    match layer.fields[field_num].field_type {
+        FieldDataType::Int => {
+            let fits_i32 = layer
+                .features
+                .iter()
+                .all(|f| match f.attributes[field_num] {
+                    AttributeValue::Integer(v) => v >= i32::MIN as i64 && v <= i32::MAX as i64,
+                    _ => true,
+                });
+            if fits_i32 {
+                COLUMN_INT
+            } else {
+                COLUMN_LONG
+            }
+        }
+        FieldDataType::Real => COLUMN_DOUBLE,
+        FieldDataType::Text => COLUMN_STRING,
+        FieldDataType::Date => COLUMN_DATETIME,
+        FieldDataType::Bool => COLUMN_BOOL,
+    }
+}
+
+/// Writes a layer to a FlatGeobuf file, without a spatial index.
+pub fn write_flatgeobuf(layer: &FeatureLayer, file_name: &str) -> Result<(), Error> {
+    let geometry_type = layer_geometry_type(layer);
+    let (has_z, has_m) = (layer.has_z(), layer.has_m());
+    let column_types: Vec<u8> = (0..layer.fields.len())
+        .map(|i| column_type(layer, i))
+        .collect();
+
+    let f = File::create(file_name)?;
+    let mut writer = BufWriter::new(f);
+    writer.write_all(&MAGIC)?;
+    writer.write_all(&header_buffer(layer, geometry_type, &column_types))?;
+    for feature in &layer.features {
+        let mut b = FbBuilder::new();
+        let properties = feature_properties(feature, &column_types);
+        let mut fields = vec![];
+        if feature.geometry.is_some() {
+            fields.push(Field::Offset(0));
+        }
+        if !properties.is_empty() {
+            fields.push(Field::Offset(1));
+        }
+        let (root, offsets) = b.table(&fields);
+        let mut offsets = offsets.into_iter();
+        if let Some(g) = &feature.geometry {
+            let pos = write_geometry(&mut b, g, geometry_type, has_z, has_m);
+            b.set_offset(offsets.next().unwrap(), pos);
+        }
+        if !properties.is_empty() {
+            let pos = b.vector_u8(&properties);
+            b.set_offset(offsets.next().unwrap(), pos);
+        }
+        writer.write_all(&b.finish(root))?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+fn header_buffer(layer: &FeatureLayer, geometry_type: u8, column_types: &[u8]) -> Vec<u8> {
+    let extent = layer.get_extent();
+    let epsg = epsg_from_wkt(&layer.projection);
+    let mut b = FbBuilder::new();
+    let mut fields = vec![Field::Offset(0)];
+    if extent.is_some() {
+        fields.push(Field::Offset(1));
+    }
+    fields.push(Field::Scalar(2, Scalar::U8(geometry_type)));
+    if layer.has_z() {
+        fields.push(Field::Scalar(3, Scalar::Bool(true)));
+    }
+    if layer.has_m() {
+        fields.push(Field::Scalar(4, Scalar::Bool(true)));
+    }
+    if !layer.fields.is_empty() {
+        fields.push(Field::Offset(7));
+    }
+    fields.push(Field::Scalar(8, Scalar::U64(layer.features.len() as u64)));
+    // no spatial index
+    fields.push(Field::Scalar(9, Scalar::U16(0)));
+    if !layer.projection.is_empty() {
+        fields.push(Field::Offset(10));
+    }
+    let (root, offsets) = b.table(&fields);
+    let mut offsets = offsets.into_iter();
+
+    let pos = b.string(&layer.name);
+    b.set_offset(offsets.next().unwrap(), pos);
+    if let Some(e) = extent {
+        let pos = b.vector_f64(&[e.min_x, e.min_y, e.max_x, e.max_y]);
+        b.set_offset(offsets.next().unwrap(), pos);
+    }
+    if !layer.fields.is_empty() {
+        let (pos, elements) = b.vector_offsets(layer.fields.len());
+        b.set_offset(offsets.next().unwrap(), pos);
+        for (i, element) in elements.into_iter().enumerate() {
+            let field = &layer.fields[i];
+            let mut column_fields = vec![
+                Field::Offset(0),
+                Field::Scalar(1, Scalar::U8(column_types[i])),
+            ];
+            if field.width > 0 {
+                column_fields.push(Field::Scalar(4, Scalar::I32(field.width as i32)));
+                if field.field_type == FieldDataType::Real {
+                    column_fields.push(Field::Scalar(5, Scalar::I32(field.precision as i32)));
+                }
+            }
+            let (column, column_offsets) = b.table(&column_fields);
+            b.set_offset(element, column);
+            let pos = b.string(&field.name);
+            b.set_offset(column_offsets[0], pos);
+        }
+    }
+    if !layer.projection.is_empty() {
+        let mut crs_fields = vec![];
+        if let Some(code) = epsg {
+            crs_fields.push(Field::Offset(0));
+            crs_fields.push(Field::Scalar(1, Scalar::I32(code as i32)));
+        }
+        crs_fields.push(Field::Offset(4));
+        let (crs, crs_offsets) = b.table(&crs_fields);
+        b.set_offset(offsets.next().unwrap(), crs);
+        let mut crs_offsets = crs_offsets.into_iter();
+        if epsg.is_some() {
+            let pos = b.string("EPSG");
+            b.set_offset(crs_offsets.next().unwrap(), pos);
+        }
+        let pos = b.string(&layer.projection);
+        b.set_offset(crs_offsets.next().unwrap(), pos);
+    }
+    b.finish(root)
+}
+
+/// Encodes the non-null attributes of a feature, each as a column index followed by the value.
+fn feature_properties(feature: &Feature, column_types: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![];
+    for (i, value) in feature.attributes.iter().enumerate() {
+        let encoded: Option<Vec<u8>> = match (column_types[i], value) {
+            (_, AttributeValue::Null) => None,
+            (COLUMN_BOOL, AttributeValue::Bool(v)) => Some(vec![*v as u8]),
+            (COLUMN_BOOL, AttributeValue::Integer(v)) => Some(vec![(*v != 0) as u8]),
+            (COLUMN_INT, AttributeValue::Integer(v)) => Some((*v as i32).to_le_bytes().to_vec()),
+            (COLUMN_INT, AttributeValue::Real(v)) => {
+                Some((v.round() as i32).to_le_bytes().to_vec())
+            }
+            (COLUMN_LONG, AttributeValue::Integer(v)) => Some(v.to_le_bytes().to_vec()),
+            (COLUMN_LONG, AttributeValue::Real(v)) => {
+                Some((v.round() as i64).to_le_bytes().to_vec())
+            }
+            (COLUMN_DOUBLE, AttributeValue::Integer(v)) => Some((*v as f64).to_le_bytes().to_vec()),
+            (COLUMN_DOUBLE, AttributeValue::Real(v)) => Some(v.to_le_bytes().to_vec()),
+            (COLUMN_STRING, v) | (COLUMN_DATETIME, v) => {
+                let s = match v {
+                    AttributeValue::Integer(v) => v.to_string(),
+                    AttributeValue::Real(v) => v.to_string(),
+                    AttributeValue::Text(v) => v.clone(),
+                    AttributeValue::Date(v) => format_date(v),
+                    AttributeValue::Bool(v) => v.to_string(),
+                    AttributeValue::Null => String::new(),
+                };
+                let mut b = (s.len() as u32).to_le_bytes().to_vec();
+                b.extend_from_slice(s.as_bytes());
+                Some(b)
+            }
+            _ => None,
+        };
+        if let Some(encoded) = encoded {
+            bytes.extend_from_slice(&(i as u16).to_le_bytes());
+            bytes.extend_from_slice(&encoded);
+        }
+    }
+    bytes
+}
+
+/// Writes a geometry table, returning its position. A geometry is written as the layer's
+/// multi-part type when the layer has one, and the type of each geometry is written only
+/// when the layer's geometry type is Unknown.
+fn write_geometry(
+    b: &mut FbBuilder,
+    g: &FeatureGeometry,
+    layer_type: u8,
+    has_z: bool,
+    has_m: bool,
+) -> usize {
+    let write_type = layer_type == GEOMETRY_UNKNOWN;
+    let geometry_type = if write_type {
+        geometry_type_of(g)
+    } else {
+        layer_type
+    };
+    if geometry_type == GEOMETRY_MULTIPOLYGON {
+        let polygons = match g {
+            FeatureGeometry::Polygon(rings) => vec![rings.clone()],
+            FeatureGeometry::MultiPolygon(polygons) => polygons.clone(),
+            _ => vec![],
+        };
+        let mut fields = vec![Field::Offset(7)];
+        if write_type {
+            fields.push(Field::Scalar(6, Scalar::U8(geometry_type)));
+        }
+        let (pos, offsets) = b.table(&fields);
+        let (parts, elements) = b.vector_offsets(polygons.len());
+        b.set_offset(offsets[0], parts);
+        for (rings, element) in polygons.iter().zip(elements) {
+            let part = write_parts(b, rings, None, has_z, has_m);
+            b.set_offset(element, part);
+        }
+        return pos;
+    }
+    let parts = match g {
+        FeatureGeometry::Point(c) => vec![vec![*c]],
+        FeatureGeometry::LineString(cs) | FeatureGeometry::MultiPoint(cs) => vec![cs.clone()],
+        FeatureGeometry::Polygon(parts) | FeatureGeometry::MultiLineString(parts) => parts.clone(),
+        // multi-polygons are always written with the multi-polygon type, above
+        FeatureGeometry::MultiPolygon(_) => vec![],
+    };
+    let geometry_type = if write_type {
+        Some(geometry_type)
+    } else {
+        None
+    };
+    write_parts(b, &parts, geometry_type, has_z, has_m)
+}
+
+/// Writes a single geometry table holding one or more parts, returning its position.
+fn write_parts(
+    b: &mut FbBuilder,
+    parts: &[Vec<Coordinate>],
+    geometry_type: Option<u8>,
+    has_z: bool,
+    has_m: bool,
+) -> usize {
+    let mut ends = vec![];
+    let mut xy = vec![];
+    let mut z = vec![];
+    let mut m = vec![];
+    for part in parts {
+        for c in part {
+            xy.push(c.x);
+            xy.push(c.y);
+            z.push(c.z.unwrap_or(0f64));
+            m.push(c.m.unwrap_or(0f64));
+        }
+        ends.push((xy.len() / 2) as u32);
+    }
+    let mut fields = vec![];
+    if ends.len() > 1 {
+        fields.push(Field::Offset(0));
+    }
+    fields.push(Field::Offset(1));
+    if has_z {
+        fields.push(Field::Offset(2));
+    }
+    if has_m {
+        fields.push(Field::Offset(3));
+    }
+    if let Some(t) = geometry_type {
+        fields.push(Field::Scalar(6, Scalar::U8(t)));
+    }
+    let (pos, offsets) = b.table(&fields);
+    let mut offsets = offsets.into_iter();
+    if ends.len() > 1 {
+        let v = b.vector_u32(&ends);
+        b.set_offset(offsets.next().unwrap(), v);
+    }
+    let v = b.vector_f64(&xy);
+    b.set_offset(offsets.next().unwrap(), v);
+    if has_z {
+        let v = b.vector_f64(&z);
+        b.set_offset(offsets.next().unwrap(), v);
+    }
+    if has_m {
+        let v = b.vector_f64(&m);
+        b.set_offset(offsets.next().unwrap(), v);
+    }
+    pos
+}
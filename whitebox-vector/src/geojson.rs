@@ -0,0 +1,500 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+
+Notes: Reads and writes GeoJSON (RFC 7946) files. Coordinates are read and written as they
+are stored in the layer, i.e. they are not reprojected to WGS84. The legacy 'crs' member
+(GeoJSON 2008) is written for layers with a known EPSG code other than 4326, and is read
+when present; files without one are assumed to be in WGS84, as the standard requires.
+*/
+
+use crate::feature_layer::*;
+use crate::shapefile::attributes::FieldDataType;
+use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use std::fmt;
+use std::fs;
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{BufWriter, Error, ErrorKind};
+
+/// The members of a feature's 'properties' object.
+type Properties = [(String, Json)];
+
+/// A parsed JSON value. Unlike serde_json::Value, objects retain the order of their
+/// members, which determines the order of the attribute fields.
+#[derive(Clone, Debug, PartialEq)]
+enum Json {
+    Null,
+    Bool(bool),
+    Integer(i64),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(members) => members.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            Json::Integer(v) => Some(*v as f64),
+            Json::Number(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    fn as_array(&self) -> Option<&Vec<Json>> {
+        match self {
+            Json::Array(a) => Some(a),
+            _ => None,
+        }
+    }
+
+    fn to_json_text(&self) -> String {
+        match self {
+            Json::Null => "null".to_string(),
+            Json::Bool(v) => v.to_string(),
+            Json::Integer(v) => v.to_string(),
+            Json::Number(v) => format_number(*v),
+            Json::String(s) => json_string(s),
+            Json::Array(a) => {
+                let items: Vec<String> = a.iter().map(|v| v.to_json_text()).collect();
+                format!("[{}]", items.join(","))
+            }
+            Json::Object(members) => {
+                let items: Vec<String> = members
+                    .iter()
+                    .map(|(k, v)| format!("{}:{}", json_string(k), v.to_json_text()))
+                    .collect();
+                format!("{{{}}}", items.join(","))
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Json {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Json, D::Error> {
+        deserializer.deserialize_any(JsonVisitor)
+    }
+}
+
+struct JsonVisitor;
+
+impl<'de> Visitor<'de> for JsonVisitor {
+    type Value = Json;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a JSON value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Json, E> {
+        Ok(Json::Bool(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Json, E> {
+        Ok(Json::Integer(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Json, E> {
+        if v <= i64::MAX as u64 {
+            Ok(Json::Integer(v as i64))
+        } else {
+            Ok(Json::Number(v as f64))
+        }
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Json, E> {
+        Ok(Json::Number(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Json, E> {
+        Ok(Json::String(v.to_string()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Json, E> {
+        Ok(Json::String(v))
+    }
+
+    fn visit_unit<E>(self) -> Result<Json, E> {
+        Ok(Json::Null)
+    }
+
+    fn visit_none<E>(self) -> Result<Json, E> {
+        Ok(Json::Null)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Json, D::Error> {
+        Json::deserialize(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Json, A::Error> {
+        let mut values = vec![];
+        while let Some(v) = seq.next_element()? {
+            values.push(v);
+        }
+        Ok(Json::Array(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Json, A::Error> {
+        let mut members = vec![];
+        while let Some((k, v)) = map.next_entry::<String, Json>()? {
+            members.push((k, v));
+        }
+        Ok(Json::Object(members))
+    }
+}
+
+/// Reads a GeoJSON file. The top-level object may be a FeatureCollection, a single
+/// Feature, or a bare geometry. Attribute field types are inferred from the property
+/// values; properties of mixed type, arrays, and objects are read as text.
+pub fn read_geojson(file_name: &str) -> Result<FeatureLayer, Error> {
+    let contents = fs::read_to_string(file_name)?;
+    let root: Json = serde_json::from_str(&contents).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("Error parsing the GeoJSON file {}: {}", file_name, e),
+        )
+    })?;
+
+    let name = match root.get("name").and_then(Json::as_str) {
+        Some(name) => name.to_string(),
+        None => layer_name_from_file(file_name),
+    };
+    let mut layer = FeatureLayer::new(&name);
+    layer.projection = match read_crs(&root) {
+        Some(code) => wkt_from_epsg(code),
+        None => wkt_from_epsg(4326),
+    };
+
+    let mut features: Vec<(Option<FeatureGeometry>, &Properties)> = vec![];
+    match root.get("type").and_then(Json::as_str) {
+        Some("FeatureCollection") => {
+            let items = root
+                .get("features")
+                .and_then(Json::as_array)
+                .ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::InvalidData,
+                        "The GeoJSON FeatureCollection does not contain a 'features' array.",
+                    )
+                })?;
+            for f in items {
+                let geometry = match f.get("geometry") {
+                    Some(g) => parse_geometry(g)?,
+                    None => None,
+                };
+                features.push((geometry, feature_properties(f)));
+            }
+        }
+        Some("Feature") => {
+            let geometry = match root.get("geometry") {
+                Some(g) => parse_geometry(g)?,
+                None => None,
+            };
+            features.push((geometry, feature_properties(&root)));
+        }
+        Some(_) => features.push((parse_geometry(&root)?, &[])),
+        None => {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "The GeoJSON file does not contain a 'type' member.",
+            ))
+        }
+    }
+
+    // infer the fields, in order of first appearance
+    let mut field_names: Vec<&str> = vec![];
+    let mut field_types: Vec<Option<FieldDataType>> = vec![];
+    for (_, properties) in &features {
+        for (key, value) in properties.iter() {
+            let index = match field_names.iter().position(|n| *n == key) {
+                Some(i) => i,
+                None => {
+                    field_names.push(key);
+                    field_types.push(None);
+                    field_names.len() - 1
+                }
+            };
+            let value_type = match value {
+                Json::Null => continue,
+                Json::Integer(_) => FieldDataType::Int,
+                Json::Number(_) => FieldDataType::Real,
+                Json::Bool(_) => FieldDataType::Bool,
+                Json::String(s) if s.len() == 10 && parse_date(s).is_some() => FieldDataType::Date,
+                _ => FieldDataType::Text,
+            };
+            field_types[index] = Some(match (field_types[index].take(), value_type) {
+                (None, t) => t,
+                (Some(a), b) if a == b => a,
+                (Some(FieldDataType::Int), FieldDataType::Real)
+                | (Some(FieldDataType::Real), FieldDataType::Int) => FieldDataType::Real,
+                _ => FieldDataType::Text,
+            });
+        }
+    }
+    for (name, field_type) in field_names.iter().zip(field_types) {
+        let field_type = field_type.unwrap_or(FieldDataType::Text);
+        layer.add_field(FeatureField::new(name, field_type, 0, 0));
+    }
+
+    for (geometry, properties) in features {
+        let mut attributes = vec![AttributeValue::Null; layer.fields.len()];
+        for (key, value) in properties.iter() {
+            let index = layer.get_field_num(key).unwrap();
+            attributes[index] = match (&layer.fields[index].field_type, value) {
+                (_, Json::Null) => AttributeValue::Null,
+                (FieldDataType::Int, Json::Integer(v)) => AttributeValue::Integer(*v),
+                (FieldDataType::Real, v) => AttributeValue::Real(v.as_f64().unwrap_or(f64::NAN)),
+                (FieldDataType::Bool, Json::Bool(v)) => AttributeValue::Bool(*v),
+                (FieldDataType::Date, Json::String(s)) => match parse_date(s) {
+                    Some(d) => AttributeValue::Date(d),
+                    None => AttributeValue::Null,
+                },
+                (_, Json::String(s)) => AttributeValue::Text(s.clone()),
+                (_, v) => AttributeValue::Text(v.to_json_text()),
+            };
+        }
+        layer.add_feature(geometry, attributes);
+    }
+    layer.fit_field_widths();
+
+    Ok(layer)
+}
+
+fn feature_properties(feature: &Json) -> &Properties {
+    match feature.get("properties") {
+        Some(Json::Object(members)) => members,
+        _ => &[],
+    }
+}
+
+/// Returns the EPSG code named by a legacy 'crs' member, e.g. 'urn:ogc:def:crs:EPSG::26917'.
+fn read_crs(root: &Json) -> Option<u16> {
+    let name = root.get("crs")?.get("properties")?.get("name")?.as_str()?;
+    if name.ends_with("CRS84") {
+        return Some(4326);
+    }
+    if !name.to_uppercase().contains("EPSG") {
+        return None;
+    }
+    name.rsplit(':').next()?.trim().parse::<u16>().ok()
+}
+
+fn parse_position(j: &Json) -> Result<Coordinate, Error> {
+    let values: Vec<f64> = j
+        .as_array()
+        .map(|a| a.iter().filter_map(Json::as_f64).collect())
+        .unwrap_or_default();
+    if values.len() < 2 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "A GeoJSON position must contain at least two numbers.",
+        ));
+    }
+    Ok(Coordinate {
+        x: values[0],
+        y: values[1],
+        z: values.get(2).copied(),
+        m: None,
+    })
+}
+
+fn parse_positions(j: &Json) -> Result<Vec<Coordinate>, Error> {
+    match j.as_array() {
+        Some(a) => a.iter().map(parse_position).collect(),
+        None => Err(Error::new(
+            ErrorKind::InvalidData,
+            "Expected an array of GeoJSON positions.",
+        )),
+    }
+}
+
+fn parse_position_lists(j: &Json) -> Result<Vec<Vec<Coordinate>>, Error> {
+    match j.as_array() {
+        Some(a) => a.iter().map(parse_positions).collect(),
+        None => Err(Error::new(
+            ErrorKind::InvalidData,
+            "Expected an array of GeoJSON position arrays.",
+        )),
+    }
+}
+
+fn parse_geometry(g: &Json) -> Result<Option<FeatureGeometry>, Error> {
+    if *g == Json::Null {
+        return Ok(None);
+    }
+    let geometry_type = g.get("type").and_then(Json::as_str).unwrap_or("");
+    if geometry_type == "GeometryCollection" {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "GeoJSON GeometryCollection geometries are not supported.",
+        ));
+    }
+    let coords = match g.get("coordinates") {
+        Some(Json::Array(a)) if a.is_empty() => return Ok(None),
+        Some(c) => c,
+        None => return Ok(None),
+    };
+    let geometry =
+        match geometry_type {
+            "Point" => FeatureGeometry::Point(parse_position(coords)?),
+            "LineString" => FeatureGeometry::LineString(parse_positions(coords)?),
+            "MultiPoint" => FeatureGeometry::MultiPoint(parse_positions(coords)?),
+            "Polygon" => FeatureGeometry::Polygon(parse_position_lists(coords)?),
+            "MultiLineString" => FeatureGeometry::MultiLineString(parse_position_lists(coords)?),
+            "MultiPolygon" => match coords.as_array() {
+                Some(a) => FeatureGeometry::MultiPolygon(
+                    a.iter()
+                        .map(parse_position_lists)
+                        .collect::<Result<Vec<_>, Error>>()?,
+                ),
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "Expected an array of GeoJSON polygons.",
+                    ))
+                }
+            },
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Unrecognized GeoJSON geometry type '{}'.", geometry_type),
+                ))
+            }
+        };
+    Ok(Some(geometry))
+}
+
+/// Writes a layer to a GeoJSON file, one feature per line. Polygon rings follow the
+/// right-hand rule of RFC 7946, i.e. exterior rings are counter-clockwise. Measure values
+/// are not written, since GeoJSON positions do not support them.
+pub fn write_geojson(layer: &FeatureLayer, file_name: &str) -> Result<(), Error> {
+    let f = File::create(file_name)?;
+    let mut writer = BufWriter::new(f);
+    writeln!(writer, "{{")?;
+    writeln!(writer, "\"type\": \"FeatureCollection\",")?;
+    writeln!(writer, "\"name\": {},", json_string(&layer.name))?;
+    if let Some(code) = epsg_from_wkt(&layer.projection) {
+        if code != 4326 {
+            writeln!(
+                writer,
+                "\"crs\": {{ \"type\": \"name\", \"properties\": {{ \"name\": \"urn:ogc:def:crs:EPSG::{}\" }} }},",
+                code
+            )?;
+        }
+    }
+    writeln!(writer, "\"features\": [")?;
+    let num_features = layer.features.len();
+    for (i, feature) in layer.features.iter().enumerate() {
+        let properties: Vec<String> = layer
+            .fields
+            .iter()
+            .zip(feature.attributes.iter())
+            .map(|(field, value)| {
+                let value = match value {
+                    AttributeValue::Null => "null".to_string(),
+                    AttributeValue::Integer(v) => v.to_string(),
+                    AttributeValue::Real(v) => format_number(*v),
+                    AttributeValue::Text(v) => json_string(v),
+                    AttributeValue::Date(v) => json_string(&format_date(v)),
+                    AttributeValue::Bool(v) => v.to_string(),
+                };
+                format!("{}: {}", json_string(&field.name), value)
+            })
+            .collect();
+        let geometry = match &feature.geometry {
+            Some(g) => geometry_json(g),
+            None => "null".to_string(),
+        };
+        writeln!(
+            writer,
+            "{{ \"type\": \"Feature\", \"properties\": {{ {} }}, \"geometry\": {} }}{}",
+            properties.join(", "),
+            geometry,
+            if i < num_features - 1 { "," } else { "" }
+        )?;
+    }
+    writeln!(writer, "]")?;
+    writeln!(writer, "}}")?;
+    writer.flush()?;
+    Ok(())
+}
+
+fn json_string(s: &str) -> String {
+    serde_json::to_string(s).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+/// Formats a number so that reals retain a decimal point, and non-finite values are null.
+fn format_number(v: f64) -> String {
+    if v.is_finite() {
+        format!("{:?}", v)
+    } else {
+        "null".to_string()
+    }
+}
+
+fn position_json(c: &Coordinate) -> String {
+    match c.z {
+        Some(z) => format!("[ {}, {}, {} ]", c.x, c.y, z),
+        None => format!("[ {}, {} ]", c.x, c.y),
+    }
+}
+
+fn positions_json(cs: &[Coordinate]) -> String {
+    let items: Vec<String> = cs.iter().map(position_json).collect();
+    format!("[ {} ]", items.join(", "))
+}
+
+fn polygon_json(rings: &[Vec<Coordinate>]) -> String {
+    let items: Vec<String> = rings
+        .iter()
+        .enumerate()
+        .map(|(i, ring)| {
+            let area = signed_area(ring);
+            if (i == 0 && area < 0f64) || (i > 0 && area > 0f64) {
+                let reversed: Vec<Coordinate> = ring.iter().rev().copied().collect();
+                positions_json(&reversed)
+            } else {
+                positions_json(ring)
+            }
+        })
+        .collect();
+    format!("[ {} ]", items.join(", "))
+}
+
+fn geometry_json(g: &FeatureGeometry) -> String {
+    let coordinates = match g {
+        FeatureGeometry::Point(c) => position_json(c),
+        FeatureGeometry::LineString(cs) | FeatureGeometry::MultiPoint(cs) => positions_json(cs),
+        FeatureGeometry::Polygon(rings) => polygon_json(rings),
+        FeatureGeometry::MultiLineString(lines) => {
+            let items: Vec<String> = lines.iter().map(|cs| positions_json(cs)).collect();
+            format!("[ {} ]", items.join(", "))
+        }
+        FeatureGeometry::MultiPolygon(polygons) => {
+            let items: Vec<String> = polygons.iter().map(|rings| polygon_json(rings)).collect();
+            format!("[ {} ]", items.join(", "))
+        }
+    };
+    format!(
+        "{{ \"type\": \"{}\", \"coordinates\": {} }}",
+        g.type_name(),
+        coordinates
+    )
+}
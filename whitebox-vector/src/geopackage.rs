@@ -0,0 +1,656 @@
+/*
+This code is part of the WhiteboxTools geospatial analysis library.
+Authors: Dr. John Lindsay
+Created: 16/10/2026
+Last Modified: 16/10/2026
+License: MIT
+
+Notes: Reads and writes the feature tables of GeoPackage files. A GeoPackage may hold
+several layers, which are addressed using the 'file.gpkg:layer' syntax; without a layer
+name, the first feature table is read, and a written layer is named after the file. Writing
+a layer into an existing GeoPackage replaces any layer of the same name and leaves the other
+layers untouched. Geometries are stored in the GeoPackage binary encoding, i.e. a short
+header followed by ISO well-known binary (WKB). The optional R-tree spatial index extension
+is not created.
+*/
+
+use crate::feature_layer::*;
+use crate::shapefile::attributes::FieldDataType;
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+use rusqlite::types::{ToSql, Value, ValueRef};
+use rusqlite::{params, NO_PARAMS};
+use std::io::{Error, ErrorKind};
+use whitebox_common::geopackage::*;
+
+// WKB geometry types
+const WKB_POINT: u32 = 1;
+const WKB_LINESTRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTIPOINT: u32 = 4;
+const WKB_MULTILINESTRING: u32 = 5;
+const WKB_MULTIPOLYGON: u32 = 6;
+
+fn invalid_data(msg: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, msg)
+}
+
+/// Reads a feature table of a GeoPackage into a FeatureLayer.
+pub fn read_geopackage(file_name: &str) -> Result<FeatureLayer, Error> {
+    let (file_name, layer_name) = split_layer_name(file_name);
+    let gpkg = GeoPackage::open(&file_name)?;
+    let contents = gpkg.find_contents(layer_name.as_deref(), &["features"])?;
+    let table = &contents.table_name;
+    let mut layer = FeatureLayer::new(table);
+    layer.projection = gpkg.get_srs_wkt(contents.srs_id)?;
+
+    let geometry_column: String = gpkg
+        .conn
+        .query_row(
+            "SELECT column_name FROM gpkg_geometry_columns WHERE lower(table_name) = lower(?1)",
+            params![table],
+            |row| row.get(0),
+        )
+        .map_err(sql_error)?;
+
+    // The attribute columns exclude the integer primary key and the geometry column.
+    let mut columns = vec![];
+    {
+        let mut stmt = gpkg
+            .conn
+            .prepare(&format!("PRAGMA table_info({})", quote_identifier(table)))
+            .map_err(sql_error)?;
+        let rows = stmt
+            .query_map(NO_PARAMS, |row| {
+                Ok((
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<String>>(2)?.unwrap_or_default(),
+                    row.get::<_, i64>(5)?,
+                ))
+            })
+            .map_err(sql_error)?;
+        for row in rows {
+            let (name, declared_type, pk) = row.map_err(sql_error)?;
+            let declared_type = declared_type.to_uppercase();
+            if (pk > 0 && declared_type.starts_with("INT"))
+                || name.eq_ignore_ascii_case(&geometry_column)
+                || declared_type == "BLOB"
+            {
+                continue;
+            }
+            let field_type = field_type_from_declared(&declared_type);
+            // TEXT(n) columns carry their maximum length
+            let width = match (field_type.clone(), declared_type.find('(')) {
+                (FieldDataType::Text, Some(i)) => declared_type[i + 1..]
+                    .trim_end_matches(')')
+                    .parse::<usize>()
+                    .map_or(0, |w| w.min(254) as u8),
+                _ => 0,
+            };
+            layer.add_field(FeatureField::new(&name, field_type, width, 0));
+            columns.push(name);
+        }
+    }
+
+    let mut select = vec![quote_identifier(&geometry_column)];
+    select.extend(columns.iter().map(|c| quote_identifier(c)));
+    let mut stmt = gpkg
+        .conn
+        .prepare(&format!(
+            "SELECT {} FROM {} ORDER BY rowid",
+            select.join(", "),
+            quote_identifier(table)
+        ))
+        .map_err(sql_error)?;
+    let mut rows = stmt.query(NO_PARAMS).map_err(sql_error)?;
+    while let Some(row) = rows.next().map_err(sql_error)? {
+        let geometry = match row.get_raw(0) {
+            ValueRef::Blob(blob) => read_geometry_blob(blob)?,
+            _ => None,
+        };
+        let mut attributes = Vec::with_capacity(columns.len());
+        for (i, field) in layer.fields.iter().enumerate() {
+            attributes.push(attribute_value(row.get_raw(i + 1), &field.field_type));
+        }
+        layer.add_feature(geometry, attributes);
+    }
+    layer.fit_field_widths();
+
+    Ok(layer)
+}
+
+/// Writes a FeatureLayer as a feature table of a GeoPackage, which is created if it does
+/// not exist.
+pub fn write_geopackage(layer: &FeatureLayer, file_name: &str) -> Result<(), Error> {
+    let (file_name, layer_name) = split_layer_name(file_name);
+    let table = match layer_name {
+        Some(name) => name,
+        None if !layer.name.is_empty() => layer.name.clone(),
+        None => layer_name_from_file(&file_name),
+    };
+    let gpkg = GeoPackage::create(&file_name)?;
+    gpkg.delete_layer(&table)?;
+    let srs_id = gpkg.get_srs_id(&layer.projection)?;
+    let has_z = layer.has_z();
+    let has_m = layer.has_m();
+
+    // The geometry type of the column is that shared by every feature, if there is one.
+    let mut geometry_type = None;
+    for g in layer.features.iter().filter_map(|f| f.geometry.as_ref()) {
+        match geometry_type {
+            None => geometry_type = Some(g.type_name()),
+            Some(t) if t != g.type_name() => {
+                geometry_type = Some("Geometry");
+                break;
+            }
+            _ => {}
+        }
+    }
+    let geometry_type = geometry_type.unwrap_or("Geometry").to_uppercase();
+
+    // The names of the key and geometry columns must not collide with those of the fields,
+    // which SQLite compares without regard to case.
+    let unique_name = |name: &str| {
+        let mut unique = name.to_string();
+        while layer
+            .fields
+            .iter()
+            .any(|f| f.name.eq_ignore_ascii_case(&unique))
+        {
+            unique = format!("{}_{}", unique, 1);
+        }
+        unique
+    };
+    let fid_column = unique_name("fid");
+    let geometry_column = unique_name("geom");
+
+    let mut column_defs = vec![
+        format!(
+            "{} INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL",
+            quote_identifier(&fid_column)
+        ),
+        format!("{} {}", quote_identifier(&geometry_column), geometry_type),
+    ];
+    for field in &layer.fields {
+        let declared_type = match field.field_type {
+            FieldDataType::Int => "INTEGER",
+            FieldDataType::Real => "REAL",
+            FieldDataType::Text => "TEXT",
+            FieldDataType::Date => "DATE",
+            FieldDataType::Bool => "BOOLEAN",
+        };
+        column_defs.push(format!(
+            "{} {}",
+            quote_identifier(&field.name),
+            declared_type
+        ));
+    }
+
+    gpkg.conn.execute_batch("BEGIN").map_err(sql_error)?;
+    gpkg.conn
+        .execute_batch(&format!(
+            "CREATE TABLE IF NOT EXISTS gpkg_geometry_columns (
+                table_name TEXT NOT NULL,
+                column_name TEXT NOT NULL,
+                geometry_type_name TEXT NOT NULL,
+                srs_id INTEGER NOT NULL,
+                z TINYINT NOT NULL,
+                m TINYINT NOT NULL,
+                CONSTRAINT pk_geom_cols PRIMARY KEY (table_name, column_name),
+                CONSTRAINT fk_gc_tn FOREIGN KEY (table_name) REFERENCES gpkg_contents(table_name),
+                CONSTRAINT fk_gc_srs FOREIGN KEY (srs_id) REFERENCES gpkg_spatial_ref_sys (srs_id)
+            );
+            CREATE TABLE {} ({});",
+            quote_identifier(&table),
+            column_defs.join(", ")
+        ))
+        .map_err(sql_error)?;
+    let mut contents = ContentsEntry {
+        table_name: table.clone(),
+        data_type: "features".to_string(),
+        srs_id,
+        ..Default::default()
+    };
+    if let Some(extent) = layer.get_extent() {
+        contents.min_x = extent.min_x;
+        contents.min_y = extent.min_y;
+        contents.max_x = extent.max_x;
+        contents.max_y = extent.max_y;
+    }
+    gpkg.add_contents(&contents)?;
+    gpkg.conn
+        .execute(
+            "INSERT INTO gpkg_geometry_columns VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                table,
+                geometry_column,
+                geometry_type,
+                srs_id,
+                has_z as i32,
+                has_m as i32
+            ],
+        )
+        .map_err(sql_error)?;
+    {
+        let mut columns = vec![quote_identifier(&geometry_column)];
+        columns.extend(layer.fields.iter().map(|f| quote_identifier(&f.name)));
+        let placeholders: Vec<String> = (1..=columns.len()).map(|i| format!("?{}", i)).collect();
+        let mut stmt = gpkg
+            .conn
+            .prepare(&format!(
+                "INSERT INTO {} ({}) VALUES ({})",
+                quote_identifier(&table),
+                columns.join(", "),
+                placeholders.join(", ")
+            ))
+            .map_err(sql_error)?;
+        for feature in &layer.features {
+            let mut values = Vec::with_capacity(columns.len());
+            values.push(match &feature.geometry {
+                Some(g) => Value::Blob(write_geometry_blob(g, srs_id, has_z, has_m)),
+                None => Value::Null,
+            });
+            for value in &feature.attributes {
+                values.push(match value {
+                    AttributeValue::Null => Value::Null,
+                    AttributeValue::Integer(v) => Value::Integer(*v),
+                    AttributeValue::Real(v) => Value::Real(*v),
+                    AttributeValue::Text(v) => Value::Text(v.clone()),
+                    AttributeValue::Date(v) => Value::Text(format_date(v)),
+                    AttributeValue::Bool(v) => Value::Integer(*v as i64),
+                });
+            }
+            let params: Vec<&dyn ToSql> = values.iter().map(|v| v as &dyn ToSql).collect();
+            stmt.execute(&params).map_err(sql_error)?;
+        }
+    }
+    gpkg.conn.execute_batch("COMMIT").map_err(sql_error)
+}
+
+fn field_type_from_declared(declared_type: &str) -> FieldDataType {
+    if declared_type == "BOOLEAN" {
+        FieldDataType::Bool
+    } else if declared_type.contains("INT") {
+        FieldDataType::Int
+    } else if declared_type == "REAL" || declared_type == "FLOAT" || declared_type == "DOUBLE" {
+        FieldDataType::Real
+    } else if declared_type == "DATE" {
+        FieldDataType::Date
+    } else {
+        FieldDataType::Text
+    }
+}
+
+fn attribute_value(value: ValueRef, field_type: &FieldDataType) -> AttributeValue {
+    match value {
+        ValueRef::Null => AttributeValue::Null,
+        ValueRef::Integer(v) => match field_type {
+            FieldDataType::Bool => AttributeValue::Bool(v != 0),
+            FieldDataType::Real => AttributeValue::Real(v as f64),
+            _ => AttributeValue::Integer(v),
+        },
+        ValueRef::Real(v) => AttributeValue::Real(v),
+        ValueRef::Text(v) => {
+            let s = String::from_utf8_lossy(v).to_string();
+            match field_type {
+                FieldDataType::Date => match parse_date(&s) {
+                    Some(date) => AttributeValue::Date(date),
+                    None => AttributeValue::Null,
+                },
+                _ => AttributeValue::Text(s),
+            }
+        }
+        ValueRef::Blob(_) => AttributeValue::Null,
+    }
+}
+
+/// Decodes a geometry stored in the GeoPackage binary encoding. Empty geometries are
+/// returned as `None`.
+fn read_geometry_blob(blob: &[u8]) -> Result<Option<FeatureGeometry>, Error> {
+    if blob.len() < 8 || &blob[0..2] != b"GP" {
+        return Err(invalid_data(
+            "Invalid GeoPackage geometry; missing 'GP' magic.",
+        ));
+    }
+    let flags = blob[3];
+    if flags & 0b0010_0000 != 0 {
+        return Err(invalid_data(
+            "GeoPackage geometries of extended types are not supported.",
+        ));
+    }
+    if flags & 0b0001_0000 != 0 {
+        return Ok(None);
+    }
+    let envelope_size = match (flags >> 1) & 0b111 {
+        0 => 0,
+        1 => 32,
+        2 | 3 => 48,
+        4 => 64,
+        _ => return Err(invalid_data("Invalid GeoPackage geometry envelope.")),
+    };
+    let mut reader = WkbReader {
+        data: blob,
+        pos: 8 + envelope_size,
+        little_endian: true,
+    };
+    reader.read_geometry()
+}
+
+/// Encodes a geometry in the GeoPackage binary encoding, i.e. a header containing the
+/// spatial reference system and an xy envelope, followed by the geometry as ISO WKB.
+fn write_geometry_blob(g: &FeatureGeometry, srs_id: i32, has_z: bool, has_m: bool) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(64);
+    buf.extend_from_slice(b"GP");
+    buf.push(0); // version 1
+    let is_point = matches!(g, FeatureGeometry::Point(_));
+    // little-endian header; points, having a trivial envelope, are written without one
+    buf.push(if is_point { 0b0000_0001 } else { 0b0000_0011 });
+    buf.extend_from_slice(&srs_id.to_le_bytes());
+    if !is_point {
+        let bb = g.get_bounding_box();
+        for v in &[bb.min_x, bb.max_x, bb.min_y, bb.max_y] {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+    }
+    write_wkb(&mut buf, g, has_z, has_m);
+    buf
+}
+
+fn write_wkb(buf: &mut Vec<u8>, g: &FeatureGeometry, has_z: bool, has_m: bool) {
+    let write_header = |buf: &mut Vec<u8>, base_type: u32| {
+        buf.push(1); // little-endian
+        let t = base_type + if has_z { 1000 } else { 0 } + if has_m { 2000 } else { 0 };
+        buf.extend_from_slice(&t.to_le_bytes());
+    };
+    let write_coordinate = |buf: &mut Vec<u8>, c: &Coordinate| {
+        buf.extend_from_slice(&c.x.to_le_bytes());
+        buf.extend_from_slice(&c.y.to_le_bytes());
+        if has_z {
+            buf.extend_from_slice(&c.z.unwrap_or(0f64).to_le_bytes());
+        }
+        if has_m {
+            buf.extend_from_slice(&c.m.unwrap_or(0f64).to_le_bytes());
+        }
+    };
+    let write_coordinates = |buf: &mut Vec<u8>, cs: &[Coordinate]| {
+        buf.extend_from_slice(&(cs.len() as u32).to_le_bytes());
+        for c in cs {
+            write_coordinate(buf, c);
+        }
+    };
+    let write_rings = |buf: &mut Vec<u8>, rings: &[Vec<Coordinate>]| {
+        buf.extend_from_slice(&(rings.len() as u32).to_le_bytes());
+        for ring in rings {
+            write_coordinates(buf, ring);
+        }
+    };
+    match g {
+        FeatureGeometry::Point(c) => {
+            write_header(buf, WKB_POINT);
+            write_coordinate(buf, c);
+        }
+        FeatureGeometry::LineString(cs) => {
+            write_header(buf, WKB_LINESTRING);
+            write_coordinates(buf, cs);
+        }
+        FeatureGeometry::Polygon(rings) => {
+            write_header(buf, WKB_POLYGON);
+            write_rings(buf, rings);
+        }
+        FeatureGeometry::MultiPoint(cs) => {
+            write_header(buf, WKB_MULTIPOINT);
+            buf.extend_from_slice(&(cs.len() as u32).to_le_bytes());
+            for c in cs {
+                write_header(buf, WKB_POINT);
+                write_coordinate(buf, c);
+            }
+        }
+        FeatureGeometry::MultiLineString(lines) => {
+            write_header(buf, WKB_MULTILINESTRING);
+            buf.extend_from_slice(&(lines.len() as u32).to_le_bytes());
+            for line in lines {
+                write_header(buf, WKB_LINESTRING);
+                write_coordinates(buf, line);
+            }
+        }
+        FeatureGeometry::MultiPolygon(polygons) => {
+            write_header(buf, WKB_MULTIPOLYGON);
+            buf.extend_from_slice(&(polygons.len() as u32).to_le_bytes());
+            for rings in polygons {
+                write_header(buf, WKB_POLYGON);
+                write_rings(buf, rings);
+            }
+        }
+    }
+}
+
+/// Decodes well-known binary geometries, in either the ISO or the extended (EWKB) forms of
+/// the Z and M dimensions.
+struct WkbReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    little_endian: bool,
+}
+
+impl<'a> WkbReader<'a> {
+    fn bytes(&mut self, n: usize) -> Result<&'a [u8], Error> {
+        let data = self.data;
+        match data.get(self.pos..self.pos + n) {
+            Some(b) => {
+                self.pos += n;
+                Ok(b)
+            }
+            None => Err(invalid_data("A WKB geometry is truncated.")),
+        }
+    }
+
+    fn read_u32(&mut self) -> Result<u32, Error> {
+        let b = self.bytes(4)?;
+        Ok(if self.little_endian {
+            LittleEndian::read_u32(b)
+        } else {
+            BigEndian::read_u32(b)
+        })
+    }
+
+    fn read_f64(&mut self) -> Result<f64, Error> {
+        let b = self.bytes(8)?;
+        Ok(if self.little_endian {
+            LittleEndian::read_f64(b)
+        } else {
+            BigEndian::read_f64(b)
+        })
+    }
+
+    // Reads the byte order and type of a geometry, returning the base type and whether
+    // the coordinates have z and m values.
+    fn read_header(&mut self) -> Result<(u32, bool, bool), Error> {
+        self.little_endian = self.bytes(1)?[0] == 1;
+        let t = self.read_u32()?;
+        let (mut has_z, mut has_m) = (t & 0x8000_0000 != 0, t & 0x4000_0000 != 0);
+        let t = t & 0x0FFF_FFFF;
+        match t / 1000 {
+            1 => has_z = true,
+            2 => has_m = true,
+            3 => {
+                has_z = true;
+                has_m = true;
+            }
+            _ => {}
+        }
+        Ok((t % 1000, has_z, has_m))
+    }
+
+    fn read_coordinate(&mut self, has_z: bool, has_m: bool) -> Result<Coordinate, Error> {
+        let mut c = Coordinate::new(self.read_f64()?, self.read_f64()?);
+        if has_z {
+            c.z = Some(self.read_f64()?);
+        }
+        if has_m {
+            c.m = Some(self.read_f64()?);
+        }
+        Ok(c)
+    }
+
+    fn read_coordinates(&mut self, has_z: bool, has_m: bool) -> Result<Vec<Coordinate>, Error> {
+        let n = self.read_u32()? as usize;
+        let mut cs = Vec::with_capacity(n.min(self.data.len() / 16));
+        for _ in 0..n {
+            cs.push(self.read_coordinate(has_z, has_m)?);
+        }
+        Ok(cs)
+    }
+
+    fn read_rings(&mut self, has_z: bool, has_m: bool) -> Result<Vec<Vec<Coordinate>>, Error> {
+        let n = self.read_u32()? as usize;
+        let mut rings = vec![];
+        for _ in 0..n {
+            rings.push(self.read_coordinates(has_z, has_m)?);
+        }
+        Ok(rings)
+    }
+
+    // Reads a part of a multi-part geometry, which must be of the specified type.
+    fn read_part(&mut self, expected_type: u32) -> Result<(bool, bool), Error> {
+        let (t, has_z, has_m) = self.read_header()?;
+        if t != expected_type {
+            return Err(invalid_data(
+                "Invalid part within a multi-part WKB geometry.",
+            ));
+        }
+        Ok((has_z, has_m))
+    }
+
+    fn read_geometry(&mut self) -> Result<Option<FeatureGeometry>, Error> {
+        let (t, has_z, has_m) = self.read_header()?;
+        let geometry = match t {
+            WKB_POINT => {
+                let c = self.read_coordinate(has_z, has_m)?;
+                // an empty point has NaN coordinates
+                if c.x.is_nan() && c.y.is_nan() {
+                    return Ok(None);
+                }
+                FeatureGeometry::Point(c)
+            }
+            WKB_LINESTRING => FeatureGeometry::LineString(self.read_coordinates(has_z, has_m)?),
+            WKB_POLYGON => FeatureGeometry::Polygon(self.read_rings(has_z, has_m)?),
+            WKB_MULTIPOINT => {
+                let n = self.read_u32()? as usize;
+                let mut points = vec![];
+                for _ in 0..n {
+                    let (has_z, has_m) = self.read_part(WKB_POINT)?;
+                    points.push(self.read_coordinate(has_z, has_m)?);
+                }
+                FeatureGeometry::MultiPoint(points)
+            }
+            WKB_MULTILINESTRING => {
+                let n = self.read_u32()? as usize;
+                let mut lines = vec![];
+                for _ in 0..n {
+                    let (has_z, has_m) = self.read_part(WKB_LINESTRING)?;
+                    lines.push(self.read_coordinates(has_z, has_m)?);
+                }
+                FeatureGeometry::MultiLineString(lines)
+            }
+            WKB_MULTIPOLYGON => {
+                let n = self.read_u32()? as usize;
+                let mut polygons = vec![];
+                for _ in 0..n {
+                    let (has_z, has_m) = self.read_part(WKB_POLYGON)?;
+                    polygons.push(self.read_rings(has_z, has_m)?);
+                }
+                FeatureGeometry::MultiPolygon(polygons)
+            }
+            t => {
+                return Err(invalid_data(&format!(
+                    "Unsupported WKB geometry type ({}).",
+                    t
+                )))
+            }
+        };
+        if geometry.coordinates().is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(geometry))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_geometry_blob_round_trip() {
+        let ring = vec![
+            Coordinate::new_z(0.0, 0.0, 1.0),
+            Coordinate::new_z(2.0, 0.0, 2.0),
+            Coordinate::new_z(2.0, 3.0, 3.0),
+            Coordinate::new_z(0.0, 0.0, 1.0),
+        ];
+        let geometries = vec![
+            FeatureGeometry::Point(Coordinate::new_z(1.0, 2.0, 3.0)),
+            FeatureGeometry::LineString(ring.clone()),
+            FeatureGeometry::MultiPolygon(vec![vec![ring.clone()], vec![ring]]),
+        ];
+        for g in &geometries {
+            let blob = write_geometry_blob(g, 26917, true, false);
+            assert_eq!(LittleEndian::read_i32(&blob[4..8]), 26917);
+            assert_eq!(read_geometry_blob(&blob).unwrap().as_ref(), Some(g));
+        }
+        // an empty geometry, flagged within the header
+        let empty = [b'G', b'P', 0, 0b0001_0001, 0, 0, 0, 0];
+        assert_eq!(read_geometry_blob(&empty).unwrap(), None);
+    }
+
+    #[test]
+    fn test_big_endian_wkb() {
+        // a big-endian 2D point (1, 2), following a header without an envelope
+        let mut blob = vec![b'G', b'P', 0, 0, 0, 0, 0x10, 0xE6, 0];
+        blob.extend_from_slice(&WKB_POINT.to_be_bytes());
+        blob.extend_from_slice(&1f64.to_be_bytes());
+        blob.extend_from_slice(&2f64.to_be_bytes());
+        assert_eq!(
+            read_geometry_blob(&blob).unwrap(),
+            Some(FeatureGeometry::Point(Coordinate::new(1.0, 2.0)))
+        );
+    }
+
+    #[test]
+    fn test_multiple_layers() {
+        let file_name = std::env::temp_dir()
+            .join("wbt_geopackage_layers_test.gpkg")
+            .to_str()
+            .unwrap()
+            .to_string();
+        let _ = std::fs::remove_file(&file_name);
+        for (name, n) in &[("wells", 3), ("springs", 5)] {
+            let mut layer = FeatureLayer::new(name);
+            layer.add_field(FeatureField::new("ID", FieldDataType::Int, 0, 0));
+            layer.add_field(FeatureField::new("DRY", FieldDataType::Bool, 0, 0));
+            for i in 0..*n {
+                layer.add_feature(
+                    Some(FeatureGeometry::Point(Coordinate::new(i as f64, 1.0))),
+                    vec![AttributeValue::Integer(i), AttributeValue::Bool(i % 2 == 0)],
+                );
+            }
+            layer.add_feature(None, vec![AttributeValue::Null, AttributeValue::Null]);
+            write_geopackage(&layer, &format!("{}:{}", file_name, name)).unwrap();
+        }
+        // rewriting a layer replaces it
+        let mut wells = read_geopackage(&format!("{}:wells", file_name)).unwrap();
+        wells.features.truncate(2);
+        write_geopackage(&wells, &file_name).unwrap();
+
+        let first = read_geopackage(&file_name).unwrap();
+        assert_eq!(first.name, "springs");
+        assert_eq!(first.features.len(), 6);
+        assert_eq!(first.features[5].geometry, None);
+        assert_eq!(
+            first.features[1].attributes,
+            vec![AttributeValue::Integer(1), AttributeValue::Bool(false)]
+        );
+        let wells = read_geopackage(&format!("{}:WELLS", file_name)).unwrap();
+        assert_eq!(wells.features.len(), 2);
+        assert!(read_geopackage(&format!("{}:lakes", file_name)).is_err());
+        let _ = std::fs::remove_file(&file_name);
+    }
+}
@@ -2,11 +2,15 @@
 This code is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: June 21, 2017
-Last Modified: 12/04/2018
+Last Modified: 17/10/2026
 License: MIT
 */
 
 // private sub-module defined in other files
+pub mod feature_layer;
+mod flatgeobuf;
+mod geojson;
+mod geopackage;
 pub mod shapefile;
 
 // exports identifiers from private sub-modules in the current module namespace
@@ -18,4 +22,139 @@ pub use crate::shapefile::attributes::*;
 pub use crate::shapefile::geometry::*;
 pub use crate::shapefile::geometry::ShapeType;
 pub use crate::shapefile::Shapefile;
+pub use crate::shapefile::MEMORY_VECTORS;
+pub use crate::feature_layer::{
+    AttributeValue, Coordinate, Feature, FeatureField, FeatureGeometry, FeatureLayer,
+};
 // pub use whitebox_common::structures::Point2D;
+use std::io::{Error, ErrorKind, Read};
+use whitebox_common::provenance::Provenance;
+
+/// The vector file formats that can be read and written.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VectorType {
+    Unknown,
+    Shapefile,
+    GeoJson,
+    FlatGeobuf,
+    GeoPackage,
+}
+
+/// Returns the vector format associated with a file's extension. File names without an
+/// extension are treated as Shapefiles. The layers of a GeoPackage may be addressed using
+/// the 'file.gpkg:layer' syntax.
+pub fn get_vector_type_from_file(file_name: &str) -> VectorType {
+    if whitebox_common::geopackage::is_geopackage(file_name) {
+        return VectorType::GeoPackage;
+    }
+    let extension = std::path::Path::new(file_name)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    match extension.as_str() {
+        "shp" | "" => VectorType::Shapefile,
+        "geojson" | "json" => VectorType::GeoJson,
+        "fgb" => VectorType::FlatGeobuf,
+        _ => VectorType::Unknown,
+    }
+}
+
+/// Reads the provenance record of a vector, i.e. the record of the tool run that wrote it.
+/// Only Shapefiles, whose records are held in a sidecar file, and in-memory vectors hold
+/// provenance records.
+pub fn read_provenance(file_name: &str) -> Result<Option<Provenance>, Error> {
+    if whitebox_common::memory_store::is_memory_file(file_name) {
+        return match MEMORY_VECTORS.get(file_name) {
+            Some(sf) => Ok(sf.provenance),
+            None => Err(Error::new(
+                ErrorKind::NotFound,
+                format!("The in-memory vector {} does not exist.", file_name),
+            )),
+        };
+    }
+    if !std::path::Path::new(file_name).exists() {
+        return Err(Error::new(
+            ErrorKind::NotFound,
+            format!("The file {} does not exist.", file_name),
+        ));
+    }
+    if get_vector_type_from_file(file_name) != VectorType::Shapefile {
+        return Ok(None);
+    }
+    match std::fs::read_to_string(shapefile::provenance_file_name(file_name)) {
+        Ok(json) => Ok(Some(Provenance::from_json(&json)?)),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Returns the geometry type of a vector. Only the header of a Shapefile is read, while
+/// files in the other formats are read in full.
+pub fn read_shape_type(file_name: &str) -> Result<ShapeType, Error> {
+    if whitebox_common::memory_store::is_memory_file(file_name) {
+        return match MEMORY_VECTORS.get(file_name) {
+            Some(sf) => Ok(sf.header.shape_type),
+            None => Err(Error::new(
+                ErrorKind::NotFound,
+                format!("The in-memory vector {} does not exist.", file_name),
+            )),
+        };
+    }
+    match get_vector_type_from_file(file_name) {
+        VectorType::Shapefile => {
+            let mut header = [0u8; 36];
+            std::fs::File::open(file_name)?.read_exact(&mut header)?;
+            let file_code = i32::from_be_bytes([header[0], header[1], header[2], header[3]]);
+            let shape_type = i32::from_le_bytes([header[32], header[33], header[34], header[35]]);
+            match shape_type {
+                0 | 1 | 3 | 5 | 8 | 11 | 13 | 15 | 18 | 21 | 23 | 25 | 28 if file_code == 9994 => {
+                    Ok(ShapeType::from_int(shape_type))
+                }
+                _ => Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("{} is not a Shapefile of a supported ShapeType.", file_name),
+                )),
+            }
+        }
+        VectorType::Unknown => Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("{} is not a supported vector format.", file_name),
+        )),
+        _ => FeatureLayer::read(file_name)?.shape_type(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn test_read_shape_type() {
+        let file_name = env::temp_dir()
+            .join("whitebox_shape_type_test.shp")
+            .to_string_lossy()
+            .to_string();
+        let mut header = vec![0u8; 100];
+        header[0..4].copy_from_slice(&9994i32.to_be_bytes());
+        header[32..36].copy_from_slice(&15i32.to_le_bytes());
+        fs::write(&file_name, &header).unwrap();
+        let shape_type = read_shape_type(&file_name).unwrap();
+        assert_eq!(shape_type, ShapeType::PolygonZ);
+        assert_eq!(shape_type.base_shape_type(), ShapeType::Polygon);
+
+        header[32..36].copy_from_slice(&31i32.to_le_bytes());
+        fs::write(&file_name, &header).unwrap();
+        assert_eq!(
+            read_shape_type(&file_name).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+        fs::remove_file(&file_name).unwrap();
+
+        assert_eq!(
+            read_shape_type("mem://no_such_vector.shp").unwrap_err().kind(),
+            ErrorKind::NotFound
+        );
+    }
+}
@@ -2,10 +2,13 @@
 This code is part of the WhiteboxTools geospatial analysis library.
 Authors: Dr. John Lindsay
 Created: 21/06/2017
-Last Modified: 17/10/2018
+Last Modified: 17/10/2026
 License: MIT
 
-Notes: The logic behind working with the ESRI Shapefile format.
+Notes: The logic behind working with the ESRI Shapefile format. Other vector formats
+(GeoJSON and FlatGeobuf) are read into, and written from, this structure by way of a
+FeatureLayer, based on the file extension. Files with names beginning with mem:// are
+held in memory, within MEMORY_VECTORS, rather than being written to disk.
 */
 
 pub mod attributes;
@@ -13,6 +16,10 @@ pub mod geometry;
 
 use self::attributes::*;
 use self::geometry::*;
+use crate::feature_layer::FeatureLayer;
+use crate::{get_vector_type_from_file, VectorType};
+use whitebox_common::memory_store::{is_memory_file, MemoryStore};
+use whitebox_common::provenance::{current_provenance, Provenance};
 use whitebox_common::structures::Point2D;
 use whitebox_common::utils::{ByteOrderReader, Endianness};
 use byteorder::{BigEndian, LittleEndian, WriteBytesExt};
@@ -106,10 +113,39 @@ pub struct Shapefile {
     pub records: Vec<ShapefileGeometry>,
     pub attributes: ShapefileAttributes,
     pub projection: String,
+    /// The record of the tool run that produced the file, if it is known. It is held in a
+    /// .provenance.json sidecar file.
+    pub provenance: Option<Provenance>,
 }
 
+/// The in-memory vectors, i.e. those with file names beginning with `mem://`. Writing
+/// such a file stores a copy of it here, and reading it returns a copy.
+pub static MEMORY_VECTORS: MemoryStore<Shapefile> = MemoryStore::new();
+
 impl Shapefile {
+    /// Reads a vector file. GeoJSON (.geojson, .json), FlatGeobuf (.fgb), and GeoPackage
+    /// (.gpkg) files are converted into Shapefile records and attributes; any other file is
+    /// read as a Shapefile.
     pub fn read<'a>(file_name: &'a str) -> Result<Shapefile, Error> {
+        if is_memory_file(file_name) {
+            let mut sf = MEMORY_VECTORS.get(file_name).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::NotFound,
+                    format!("The in-memory vector {} does not exist.", file_name),
+                )
+            })?;
+            sf.file_mode = "r".to_string();
+            return Ok(sf);
+        }
+        match get_vector_type_from_file(file_name) {
+            VectorType::GeoJson | VectorType::FlatGeobuf | VectorType::GeoPackage => {
+                let layer = FeatureLayer::read(file_name)?;
+                let mut sf = Shapefile::from_feature_layer(file_name, &layer)?;
+                sf.file_mode = "r".to_string();
+                return Ok(sf);
+            }
+            _ => {}
+        }
         let mut sf = Shapefile {
             file_name: file_name.to_string(),
             file_mode: "r".to_string(),
@@ -120,7 +156,7 @@ impl Shapefile {
     }
 
     pub fn new<'a>(file_name: &'a str, file_type: ShapeType) -> Result<Shapefile, Error> {
-        let new_file_name = if file_name.contains(".") {
+        let new_file_name = if file_name.contains(".") || is_memory_file(file_name) {
             file_name.to_string()
         } else {
             // likely no extension provided; default to .shp
@@ -141,7 +177,7 @@ impl Shapefile {
         shape_type: ShapeType,
         copy_fields: bool,
     ) -> Result<Shapefile, Error> {
-        let new_file_name = if file_name.contains(".") {
+        let new_file_name = if file_name.contains(".") || is_memory_file(file_name) {
             file_name.to_string()
         } else {
             // likely no extension provided; default to .shp
@@ -352,7 +388,8 @@ impl Shapefile {
                 }
 
                 ShapeType::PointZ => {
-                    let sfg = if content_length != 36 {
+                    // the content is 28 bytes (type, x, y, z) when the optional measure is absent
+                    let sfg = if content_length > 28 {
                         // both z and m are included
                         ShapefileGeometry {
                             shape_type: ShapeType::PointZ,
@@ -566,6 +603,13 @@ impl Shapefile {
             Err(_) => println!("Warning: Projection file not located."),
         }
 
+        //////////////////////////////
+        // Read the provenance file //
+        //////////////////////////////
+        if let Ok(json) = fs::read_to_string(provenance_file_name(&self.file_name)) {
+            self.provenance = Provenance::from_json(&json).ok();
+        }
+
         ///////////////////////////////
         // Read the attributes table //
         ///////////////////////////////
@@ -711,6 +755,7 @@ impl Shapefile {
         Ok(())
     }
 
+    /// Writes the file, in the format associated with the file extension.
     pub fn write(&mut self) -> Result<(), Error> {
         if self.file_mode == "r" {
             return Err(Error::new(
@@ -719,6 +764,21 @@ impl Shapefile {
             ));
         }
 
+        self.provenance = current_provenance();
+        if is_memory_file(&self.file_name) {
+            self.num_records = self.records.len();
+            self.calculate_extent();
+            MEMORY_VECTORS.insert(&self.file_name, self.clone());
+            return Ok(());
+        }
+
+        match get_vector_type_from_file(&self.file_name) {
+            VectorType::GeoJson | VectorType::FlatGeobuf | VectorType::GeoPackage => {
+                return self.to_feature_layer().write(&self.file_name);
+            }
+            _ => {}
+        }
+
         self.num_records = self.records.len(); // make sure they are the same.
         if self.num_records == 0 {
             return Err(Error::new(
@@ -1111,6 +1171,21 @@ impl Shapefile {
             writer.write_all(self.projection.as_bytes())?;
         }
 
+        ///////////////////////////////
+        // Write the provenance file //
+        ///////////////////////////////
+
+        let provenance_file = provenance_file_name(&self.file_name);
+        match &self.provenance {
+            Some(provenance) => fs::write(&provenance_file, provenance.to_json())?,
+            None => {
+                // a sidecar left by an earlier run would describe the wrong file
+                if Path::new(&provenance_file).exists() {
+                    fs::remove_file(&provenance_file)?;
+                }
+            }
+        }
+
         ///////////////////////////////
         // Write the attributes file //
         ///////////////////////////////
@@ -1287,7 +1362,7 @@ impl Shapefile {
         Ok(())
     }
 
-    fn calculate_extent(&mut self) {
+    pub(crate) fn calculate_extent(&mut self) {
         match self.header.shape_type {
             ShapeType::Null => {
                 self.header.x_min = 0f64;
@@ -1309,6 +1384,9 @@ impl Shapefile {
                 self.header.z_min = 0f64;
                 self.header.z_max = 0f64;
                 for sg in &self.records {
+                    if sg.points.is_empty() {
+                        continue; // a Null record
+                    }
                     if sg.points[0].x < self.header.x_min {
                         self.header.x_min = sg.points[0].x;
                     }
@@ -1359,6 +1437,9 @@ impl Shapefile {
                 self.header.z_min = 0f64;
                 self.header.z_max = 0f64;
                 for sg in &self.records {
+                    if sg.points.is_empty() {
+                        continue; // a Null record
+                    }
                     if sg.points[0].x < self.header.x_min {
                         self.header.x_min = sg.points[0].x;
                     }
@@ -1422,6 +1503,9 @@ impl Shapefile {
                 self.header.z_min = f64::INFINITY;
                 self.header.z_max = f64::NEG_INFINITY;
                 for sg in &self.records {
+                    if sg.points.is_empty() {
+                        continue; // a Null record
+                    }
                     if sg.points[0].x < self.header.x_min {
                         self.header.x_min = sg.points[0].x;
                     }
@@ -1490,3 +1574,12 @@ impl Shapefile {
         }
     }
 }
+
+/// The name of the sidecar file that holds the provenance of a Shapefile, e.g.
+/// roads.provenance.json for roads.shp.
+pub(crate) fn provenance_file_name(file_name: &str) -> String {
+    Path::new(file_name)
+        .with_extension("provenance.json")
+        .to_string_lossy()
+        .to_string()
+}
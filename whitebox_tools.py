@@ -96,52 +96,6 @@ class WhiteboxTools(object):
         '''
         self.verbose = val
 
-        try:
-            callback = self.default_callback
-
-            os.chdir(self.exe_path)
-            args2 = []
-            args2.append("." + path.sep + self.exe_name)
-            
-            if self.verbose:
-                args2.append("-v")
-            else:
-                args2.append("-v=false")
-
-            print(args2)
-
-            proc = None
-
-            if running_windows and self.start_minimized == True:
-                si = STARTUPINFO()
-                si.dwFlags = STARTF_USESHOWWINDOW
-                si.wShowWindow = 6 #Set window minimized
-                proc = Popen(args2, shell=False, stdout=PIPE,
-                            stderr=STDOUT, bufsize=1, universal_newlines=True,
-                            startupinfo=si)
-            else:
-                proc = Popen(args2, shell=False, stdout=PIPE,
-                            stderr=STDOUT, bufsize=1, universal_newlines=True)
-
-            while proc is not None:
-                line = proc.stdout.readline()
-                sys.stdout.flush()
-                if line != '':
-                    if not self.cancel_op:
-                        callback(line.strip())
-                    else:
-                        self.cancel_op = False
-                        proc.terminate()
-                        return 2
-
-                else:
-                    break
-
-            return 0
-        except (OSError, ValueError, CalledProcessError) as err:
-            callback(str(err))
-            return 1
-
     def set_default_callback(self, callback_func):
         '''
         Sets the default callback used for handling tool text outputs.
@@ -182,10 +136,11 @@ class WhiteboxTools(object):
             # args_str = args_str[:-1]
             # a.append("--args=\"{}\"".format(args_str))
 
-            # if self.verbose:
-            #     args2.append("-v")
-            # else:
-            #     args2.append("-v=false")
+            # settings given on the command line apply to this run only
+            if self.verbose:
+                args2.append("-v")
+            else:
+                args2.append("-v=false")
 
             if self.__compress_rasters:
                 args2.append("--compress_rasters")
@@ -778,6 +733,46 @@ class WhiteboxTools(object):
         args.append("--output='{}'".format(output))
         return self.run_tool('remove_polygon_holes', args, callback) # returns 1 if error
 
+    def reproject_raster(self, i, output, epsg, src_epsg=None, cell_size=None, method="cc", callback=None):
+        """Transforms a raster into a different coordinate reference system.
+
+        Keyword arguments:
+
+        i -- Input raster file. 
+        output -- Output raster file. 
+        epsg -- EPSG code of the output coordinate reference system. 
+        src_epsg -- Optional EPSG code of the input coordinate reference system, overriding that of the input file. 
+        cell_size -- Optionally specified cell size of output raster, in target coordinate system units. 
+        method -- Resampling method; options include 'nn' (nearest neighbour), 'bilinear', and 'cc' (cubic convolution). 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--input='{}'".format(i))
+        args.append("--output='{}'".format(output))
+        args.append("--epsg='{}'".format(epsg))
+        if src_epsg is not None: args.append("--src_epsg='{}'".format(src_epsg))
+        if cell_size is not None: args.append("--cell_size='{}'".format(cell_size))
+        args.append("--method={}".format(method))
+        return self.run_tool('reproject_raster', args, callback) # returns 1 if error
+
+    def reproject_vector(self, i, output, epsg, src_epsg=None, callback=None):
+        """Transforms a vector file into a different coordinate reference system.
+
+        Keyword arguments:
+
+        i -- Input vector file. 
+        output -- Output vector file. 
+        epsg -- EPSG code of the output coordinate reference system. 
+        src_epsg -- Optional EPSG code of the input coordinate reference system, overriding that of the input file. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--input='{}'".format(i))
+        args.append("--output='{}'".format(output))
+        args.append("--epsg='{}'".format(epsg))
+        if src_epsg is not None: args.append("--src_epsg='{}'".format(src_epsg))
+        return self.run_tool('reproject_vector', args, callback) # returns 1 if error
+
     def set_nodata_value(self, i, output, back_value=0.0, callback=None):
         """Assign a specified value in an input image to the NoData value.
 
@@ -3756,7 +3751,7 @@ class WhiteboxTools(object):
         args.append("--output='{}'".format(output))
         return self.run_tool('average_upslope_flowpath_length', args, callback) # returns 1 if error
 
-    def basins(self, d8_pntr, output, esri_pntr=False, callback=None):
+    def basins(self, d8_pntr, output, esri_pntr=False, dem=None, out_polygons=None, callback=None):
         """Identifies drainage basins that drain to the DEM edge.
 
         Keyword arguments:
@@ -3764,12 +3759,16 @@ class WhiteboxTools(object):
         d8_pntr -- Input raster D8 pointer file. 
         output -- Output raster file. 
         esri_pntr -- D8 pointer uses the ESRI style scheme. 
+        dem -- Optional input DEM, used to calculate the mean elevation of the output polygons. 
+        out_polygons -- Optional output vector polygons file. 
         callback -- Custom function for handling tool text outputs.
         """
         args = []
         args.append("--d8_pntr='{}'".format(d8_pntr))
         args.append("--output='{}'".format(output))
         if esri_pntr: args.append("--esri_pntr")
+        if dem is not None: args.append("--dem='{}'".format(dem))
+        if out_polygons is not None: args.append("--out_polygons='{}'".format(out_polygons))
         return self.run_tool('basins', args, callback) # returns 1 if error
 
     def breach_depressions(self, dem, output, max_depth=None, max_length=None, flat_increment=None, fill_pits=False, callback=None):
@@ -4226,6 +4225,42 @@ class WhiteboxTools(object):
         args.append("--output='{}'".format(output))
         return self.run_tool('flatten_lakes', args, callback) # returns 1 if error
 
+    def flood_inundation_map(self, hand, d8_pntr, linkid, link_slope, values, output, value_type="flow", mannings_n=0.05, stage_step=0.25, max_stage=20.0, esri_pntr=False, out_polygons=None, rating_curves=None, callback=None):
+        """Maps flood depths and extents from height above the nearest drainage and reach stages or flows.
+
+        Keyword arguments:
+
+        hand -- Input raster of height above the nearest drainage (ElevationAboveStream). 
+        d8_pntr -- Input D8 pointer raster file. 
+        linkid -- Input raster stream link ID file (StreamLinkIdentifier). 
+        link_slope -- Input raster of stream link slopes, in degrees (StreamLinkSlope). 
+        values -- Input CSV file of reach (link) IDs and their stage or flow values. 
+        value_type -- Type of the reach values; one of 'stage' and 'flow' (default). 
+        mannings_n -- Manning's roughness coefficient of the channels and floodplains. 
+        stage_step -- Stage height increment of the synthetic rating curves. 
+        max_stage -- Maximum stage height of the synthetic rating curves. 
+        esri_pntr -- D8 pointer uses the ESRI style scheme. 
+        output -- Output raster file of inundation depths. 
+        out_polygons -- Optional output vector polygons file of flood extents. 
+        rating_curves -- Optional output CSV file of the synthetic rating curves. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--hand='{}'".format(hand))
+        args.append("--d8_pntr='{}'".format(d8_pntr))
+        args.append("--linkid='{}'".format(linkid))
+        args.append("--link_slope='{}'".format(link_slope))
+        args.append("--values='{}'".format(values))
+        args.append("--value_type={}".format(value_type))
+        args.append("--mannings_n={}".format(mannings_n))
+        args.append("--stage_step={}".format(stage_step))
+        args.append("--max_stage={}".format(max_stage))
+        if esri_pntr: args.append("--esri_pntr")
+        args.append("--output='{}'".format(output))
+        if out_polygons is not None: args.append("--out_polygons='{}'".format(out_polygons))
+        if rating_curves is not None: args.append("--rating_curves='{}'".format(rating_curves))
+        return self.run_tool('flood_inundation_map', args, callback) # returns 1 if error
+
     def flood_order(self, dem, output, callback=None):
         """Assigns each DEM grid cell its order in the sequence of inundations that are encountered during a search starting from the edges, moving inward at increasing elevations.
 
@@ -4336,7 +4371,7 @@ class WhiteboxTools(object):
         args.append("--damlength='{}'".format(damlength))
         return self.run_tool('insert_dams', args, callback) # returns 1 if error
 
-    def isobasins(self, dem, output, size, connections=False, callback=None):
+    def isobasins(self, dem, output, size, connections=False, out_polygons=None, callback=None):
         """Divides a landscape into nearly equal sized drainage basins (i.e. watersheds).
 
         Keyword arguments:
@@ -4345,6 +4380,7 @@ class WhiteboxTools(object):
         output -- Output raster file. 
         size -- Target basin size, in grid cells. 
         connections -- Output upstream-downstream flow connections among basins?. 
+        out_polygons -- Optional output vector polygons file. 
         callback -- Custom function for handling tool text outputs.
         """
         args = []
@@ -4352,6 +4388,7 @@ class WhiteboxTools(object):
         args.append("--output='{}'".format(output))
         args.append("--size='{}'".format(size))
         if connections: args.append("--connections")
+        if out_polygons is not None: args.append("--out_polygons='{}'".format(out_polygons))
         return self.run_tool('isobasins', args, callback) # returns 1 if error
 
     def jenson_snap_pour_points(self, pour_pts, streams, output, snap_dist, callback=None):
@@ -4546,7 +4583,7 @@ class WhiteboxTools(object):
         args.append("--iterations={}".format(iterations))
         return self.run_tool('stochastic_depression_analysis', args, callback) # returns 1 if error
 
-    def strahler_order_basins(self, d8_pntr, streams, output, esri_pntr=False, callback=None):
+    def strahler_order_basins(self, d8_pntr, streams, output, esri_pntr=False, dem=None, out_polygons=None, callback=None):
         """Identifies Strahler-order basins from an input stream network.
 
         Keyword arguments:
@@ -4555,6 +4592,8 @@ class WhiteboxTools(object):
         streams -- Input raster streams file. 
         output -- Output raster file. 
         esri_pntr -- D8 pointer uses the ESRI style scheme. 
+        dem -- Optional input DEM, used to calculate the mean elevation of the output polygons. 
+        out_polygons -- Optional output vector polygons file. 
         callback -- Custom function for handling tool text outputs.
         """
         args = []
@@ -4562,9 +4601,11 @@ class WhiteboxTools(object):
         args.append("--streams='{}'".format(streams))
         args.append("--output='{}'".format(output))
         if esri_pntr: args.append("--esri_pntr")
+        if dem is not None: args.append("--dem='{}'".format(dem))
+        if out_polygons is not None: args.append("--out_polygons='{}'".format(out_polygons))
         return self.run_tool('strahler_order_basins', args, callback) # returns 1 if error
 
-    def subbasins(self, d8_pntr, streams, output, esri_pntr=False, callback=None):
+    def subbasins(self, d8_pntr, streams, output, esri_pntr=False, dem=None, out_polygons=None, callback=None):
         """Identifies the catchments, or sub-basin, draining to each link in a stream network.
 
         Keyword arguments:
@@ -4573,6 +4614,8 @@ class WhiteboxTools(object):
         streams -- Input raster streams file. 
         output -- Output raster file. 
         esri_pntr -- D8 pointer uses the ESRI style scheme. 
+        dem -- Optional input DEM, used to calculate the mean elevation of the output polygons. 
+        out_polygons -- Optional output vector polygons file. 
         callback -- Custom function for handling tool text outputs.
         """
         args = []
@@ -4580,6 +4623,8 @@ class WhiteboxTools(object):
         args.append("--streams='{}'".format(streams))
         args.append("--output='{}'".format(output))
         if esri_pntr: args.append("--esri_pntr")
+        if dem is not None: args.append("--dem='{}'".format(dem))
+        if out_polygons is not None: args.append("--out_polygons='{}'".format(out_polygons))
         return self.run_tool('subbasins', args, callback) # returns 1 if error
 
     def trace_downslope_flowpaths(self, seed_pts, d8_pntr, output, esri_pntr=False, zero_background=False, callback=None):
@@ -4602,7 +4647,7 @@ class WhiteboxTools(object):
         if zero_background: args.append("--zero_background")
         return self.run_tool('trace_downslope_flowpaths', args, callback) # returns 1 if error
 
-    def unnest_basins(self, d8_pntr, pour_pts, output, esri_pntr=False, callback=None):
+    def unnest_basins(self, d8_pntr, pour_pts, output, esri_pntr=False, dem=None, out_polygons=None, callback=None):
         """Extract whole watersheds for a set of outlet points.
 
         Keyword arguments:
@@ -4611,6 +4656,8 @@ class WhiteboxTools(object):
         pour_pts -- Input vector pour points (outlet) file. 
         output -- Output raster file. 
         esri_pntr -- D8 pointer uses the ESRI style scheme. 
+        dem -- Optional input DEM, used to calculate the mean elevation of the output polygons. 
+        out_polygons -- Optional output vector polygons file. 
         callback -- Custom function for handling tool text outputs.
         """
         args = []
@@ -4618,6 +4665,8 @@ class WhiteboxTools(object):
         args.append("--pour_pts='{}'".format(pour_pts))
         args.append("--output='{}'".format(output))
         if esri_pntr: args.append("--esri_pntr")
+        if dem is not None: args.append("--dem='{}'".format(dem))
+        if out_polygons is not None: args.append("--out_polygons='{}'".format(out_polygons))
         return self.run_tool('unnest_basins', args, callback) # returns 1 if error
 
     def upslope_depression_storage(self, dem, output, callback=None):
@@ -4634,7 +4683,7 @@ class WhiteboxTools(object):
         args.append("--output='{}'".format(output))
         return self.run_tool('upslope_depression_storage', args, callback) # returns 1 if error
 
-    def watershed(self, d8_pntr, pour_pts, output, esri_pntr=False, callback=None):
+    def watershed(self, d8_pntr, pour_pts, output, esri_pntr=False, dem=None, out_polygons=None, callback=None):
         """Identifies the watershed, or drainage basin, draining to a set of target cells.
 
         Keyword arguments:
@@ -4643,6 +4692,8 @@ class WhiteboxTools(object):
         pour_pts -- Input pour points (outlet) file. 
         output -- Output raster file. 
         esri_pntr -- D8 pointer uses the ESRI style scheme. 
+        dem -- Optional input DEM, used to calculate the mean elevation of the output polygons. 
+        out_polygons -- Optional output vector polygons file. 
         callback -- Custom function for handling tool text outputs.
         """
         args = []
@@ -4650,6 +4701,8 @@ class WhiteboxTools(object):
         args.append("--pour_pts='{}'".format(pour_pts))
         args.append("--output='{}'".format(output))
         if esri_pntr: args.append("--esri_pntr")
+        if dem is not None: args.append("--dem='{}'".format(dem))
+        if out_polygons is not None: args.append("--out_polygons='{}'".format(out_polygons))
         return self.run_tool('watershed', args, callback) # returns 1 if error
 
     ##########################
@@ -6178,18 +6231,20 @@ class WhiteboxTools(object):
         args.append("--resolution={}".format(resolution))
         return self.run_tool('flightline_overlap', args, callback) # returns 1 if error
 
-    def height_above_ground(self, i=None, output=None, callback=None):
+    def height_above_ground(self, i=None, output=None, extra_bytes=False, callback=None):
         """Normalizes a LiDAR point cloud, providing the height above the nearest ground-classified point.
 
         Keyword arguments:
 
         i -- Input LiDAR file (including extension). 
         output -- Output raster file (including extension). 
+        extra_bytes -- Optional flag indicating whether heights are stored in a 'HeightAboveGround' extra-bytes attribute, rather than replacing the point z-values. 
         callback -- Custom function for handling tool text outputs.
         """
         args = []
         if i is not None: args.append("--input='{}'".format(i))
         if output is not None: args.append("--output='{}'".format(output))
+        if extra_bytes: args.append("--extra_bytes")
         return self.run_tool('height_above_ground', args, callback) # returns 1 if error
 
     def las_to_ascii(self, inputs, callback=None):
@@ -6992,6 +7047,24 @@ class WhiteboxTools(object):
         args.append("--radius={}".format(radius))
         return self.run_tool('normal_vectors', args, callback) # returns 1 if error
 
+    def reproject_lidar(self, i, output, epsg, src_epsg=None, callback=None):
+        """Transforms a LiDAR point cloud into a different coordinate reference system.
+
+        Keyword arguments:
+
+        i -- Input LiDAR file. 
+        output -- Output LiDAR file. 
+        epsg -- EPSG code of the output coordinate reference system. 
+        src_epsg -- Optional EPSG code of the input coordinate reference system, overriding that of the input file. 
+        callback -- Custom function for handling tool text outputs.
+        """
+        args = []
+        args.append("--input='{}'".format(i))
+        args.append("--output='{}'".format(output))
+        args.append("--epsg='{}'".format(epsg))
+        if src_epsg is not None: args.append("--src_epsg='{}'".format(src_epsg))
+        return self.run_tool('reproject_lidar', args, callback) # returns 1 if error
+
     def select_tiles_by_polygon(self, indir, outdir, polygons, callback=None):
         """Copies LiDAR tiles overlapping with a polygon into an output directory.
 